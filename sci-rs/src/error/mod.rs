@@ -25,3 +25,14 @@ impl fmt::Display for Error {
 }
 
 impl error::Error for Error {}
+
+#[cfg(feature = "alloc")]
+impl Error {
+    /// Convert into the [sci_rs_core::Error] returned by newer parts of the crate.
+    pub(crate) fn into_core(self) -> sci_rs_core::Error {
+        match self {
+            Error::InvalidArg { arg, reason } => sci_rs_core::Error::InvalidArg { arg, reason },
+            Error::ConflictArg { reason } => sci_rs_core::Error::ConflictArg { reason },
+        }
+    }
+}
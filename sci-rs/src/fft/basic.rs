@@ -0,0 +1,1450 @@
+use super::Planner;
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use nalgebra::Complex;
+use ndarray::{
+    Array, ArrayBase, ArrayView, ArrayView1, ArrayViewMut, ArrayViewMut1, Axis, Data, Dimension,
+    Zip,
+};
+use num_traits::{Float, Zero};
+use rustfft::FftNum;
+use sci_rs_core::{Error, Result};
+
+/// Normalization mode of the discrete Fourier transforms.
+///
+/// The mode indicates which direction of the forward/backward pair of transforms is scaled and
+/// with what normalization factor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FftNorm {
+    /// No normalization on the forward transforms and scaling by ``1/n`` on the backward
+    /// transforms.
+    #[default]
+    Backward,
+    /// Scaling by ``1/sqrt(n)`` in both directions, making the transforms unitary.
+    Ortho,
+    /// Scaling by ``1/n`` on the forward transforms and no normalization on the backward
+    /// transforms.
+    Forward,
+}
+
+impl FftNorm {
+    /// Scale factor of a forward (or `inverse`) transform of length `n`.
+    pub(crate) fn factor<F: Float>(self, n: usize, inverse: bool) -> F {
+        let n = F::from(n).unwrap();
+        match (self, inverse) {
+            (FftNorm::Ortho, _) => n.sqrt().recip(),
+            (FftNorm::Backward, true) | (FftNorm::Forward, false) => n.recip(),
+            _ => F::one(),
+        }
+    }
+}
+
+/// Compute the 1-D discrete Fourier Transform.
+///
+/// This function computes the 1-D *n*-point discrete Fourier Transform (DFT) with the efficient
+/// Fast Fourier Transform (FFT) algorithm.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   Input array, can be complex.
+/// * `n`: int, optional
+///   Length of the transformed axis of the output. If `n` is smaller than the length of the
+///   input, the input is cropped. If it is larger, the input is padded with zeros. If `n` is not
+///   given, the length of the input along the axis specified by `axis` is used.
+/// * `axis`: int, optional
+///   Axis over which to compute the FFT. If not given, the last axis is used.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode. Default is [FftNorm::Backward], meaning no normalization on the forward
+///   transforms and scaling by ``1/n`` on the [ifft].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `out`: complex ndarray
+///   The truncated or zero-padded input, transformed along the axis indicated by `axis`, or the
+///   last one if `axis` is not specified.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `axis` is out of range or `n` is zero.
+///
+/// ## Notes
+/// FFT (Fast Fourier Transform) refers to a way the discrete Fourier Transform (DFT) can be
+/// calculated efficiently, by using symmetries in the calculated terms. The symmetry is highest
+/// when `n` is a power of 2, and the transform is therefore most efficient for these sizes.
+///
+/// The DFT is defined, with the conventions used in this implementation, as
+/// ``y[k] = sum(x[m] * exp(-2j * pi * k * m / n), m = 0, ..., n - 1)``.
+///
+/// ## References
+/// 1. Cooley, James W., and John W. Tukey, 1965, "An algorithm for the machine calculation of
+///    complex Fourier series," *Math. Comput.* 19: 297-301.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import scipy.fft
+/// >>> import numpy as np
+/// >>> scipy.fft.fft(np.exp(2j * np.pi * np.arange(8) / 8))
+/// array([-3.44509285e-16+1.14423775e-17j,  8.00000000e+00-8.52069395e-16j,
+///         2.33486982e-16+1.22464680e-16j,  0.00000000e+00+1.22464680e-16j,
+///         9.95799250e-17+2.33486982e-16j, -8.88178420e-16+1.17281316e-16j,
+///         1.14423775e-17+1.22464680e-16j,  0.00000000e+00+1.22464680e-16j])
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::Complex;
+/// use ndarray::Array1;
+/// use sci_rs::fft::fft;
+///
+/// let x = Array1::from_shape_fn(8, |m| {
+///     Complex::from_polar(1.0f64, 2.0 * std::f64::consts::PI * m as f64 / 8.0)
+/// });
+/// let y: Array1<Complex<f64>> = fft(&x, None, None, None, None, None).unwrap();
+/// assert!((y[1] - Complex::new(8.0, 0.0)).norm() < 1e-12);
+/// assert!(y.iter().enumerate().all(|(k, y)| k == 1 || y.norm() < 1e-12));
+/// ```
+pub fn fft<F, T, S, D>(
+    x: &ArrayBase<S, D>,
+    n: Option<usize>,
+    axis: Option<isize>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<Complex<F>, D>>
+where
+    F: Float + FftNum,
+    T: Copy + Into<Complex<F>> + Sync,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, x)?;
+    let workers = check_workers(workers)?;
+    Planner::with(plan, |planner| {
+        c2c(
+            x,
+            n,
+            axis,
+            norm.unwrap_or_default(),
+            false,
+            workers,
+            planner,
+        )
+    })
+}
+
+/// Compute the 1-D inverse discrete Fourier Transform.
+///
+/// This function computes the inverse of the 1-D *n*-point discrete Fourier transform computed by
+/// [fft]. In other words, ``ifft(fft(x)) == x`` to within numerical accuracy.
+///
+/// The input should be ordered in the same way as is returned by [fft], i.e.,
+///
+/// * ``x[0]`` should contain the zero frequency term,
+/// * ``x[1..n/2]`` should contain the positive-frequency terms,
+/// * ``x[n/2 + 1..]`` should contain the negative-frequency terms, in increasing order starting
+///   from the most negative frequency.
+///
+/// For an even number of input points, ``x[n/2]`` represents the sum of the values at the positive
+/// and negative Nyquist frequencies, as the two are aliased together.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   Input array, can be complex.
+/// * `n`: int, optional
+///   Length of the transformed axis of the output. If `n` is smaller than the length of the
+///   input, the input is cropped. If it is larger, the input is padded with zeros. If `n` is not
+///   given, the length of the input along the axis specified by `axis` is used. Note that padding
+///   with zeros appends them after the negative frequencies, which is rarely what is intended.
+/// * `axis`: int, optional
+///   Axis over which to compute the inverse DFT. If not given, the last axis is used.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode, see [fft]. Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `out`: complex ndarray
+///   The truncated or zero-padded input, transformed along the axis indicated by `axis`, or the
+///   last one if `axis` is not specified.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `axis` is out of range or `n` is zero.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import scipy.fft
+/// >>> scipy.fft.ifft([0, 4, 0, 0])
+/// array([ 1.+0.j,  0.+1.j, -1.+0.j,  0.-1.j]) # may vary
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::Complex;
+/// use ndarray::{array, Array1};
+/// use sci_rs::fft::ifft;
+///
+/// let y: Array1<Complex<f64>> = ifft(&array![0., 4., 0., 0.], None, None, None, None, None).unwrap();
+/// let expected = [
+///     Complex::new(1., 0.),
+///     Complex::new(0., 1.),
+///     Complex::new(-1., 0.),
+///     Complex::new(0., -1.),
+/// ];
+/// assert!(y.iter().zip(expected).all(|(y, e)| (y - e).norm() < 1e-12));
+/// ```
+pub fn ifft<F, T, S, D>(
+    x: &ArrayBase<S, D>,
+    n: Option<usize>,
+    axis: Option<isize>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<Complex<F>, D>>
+where
+    F: Float + FftNum,
+    T: Copy + Into<Complex<F>> + Sync,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, x)?;
+    let workers = check_workers(workers)?;
+    Planner::with(plan, |planner| {
+        c2c(x, n, axis, norm.unwrap_or_default(), true, workers, planner)
+    })
+}
+
+/// Compute the 1-D discrete Fourier Transform for real input.
+///
+/// This function computes the 1-D *n*-point discrete Fourier Transform (DFT) of a real-valued
+/// array by means of an efficient algorithm called the Fast Fourier Transform (FFT).
+///
+/// ## Parameters
+/// * `x`: array_like
+///   Input array
+/// * `n`: int, optional
+///   Number of points along transformation axis in the input to use. If `n` is smaller than the
+///   length of the input, the input is cropped. If it is larger, the input is padded with zeros.
+///   If `n` is not given, the length of the input along the axis specified by `axis` is used.
+/// * `axis`: int, optional
+///   Axis over which to compute the FFT. If not given, the last axis is used.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode, see [fft]. Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `out`: complex ndarray
+///   The truncated or zero-padded input, transformed along the axis indicated by `axis`, or the
+///   last one if `axis` is not specified. If `n` is even, the length of the transformed axis is
+///   ``(n/2)+1``. If `n` is odd, the length is ``(n+1)/2``.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `axis` is out of range or `n` is zero.
+///
+/// ## Notes
+/// When the DFT is computed for purely real input, the output is Hermitian-symmetric, i.e., the
+/// negative frequency terms are just the complex conjugates of the corresponding
+/// positive-frequency terms, and the negative-frequency terms are therefore redundant. This
+/// function does not compute the negative frequency terms.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import scipy.fft
+/// >>> scipy.fft.fft([0, 1, 0, 0])
+/// array([ 1.+0.j,  0.-1.j, -1.+0.j,  0.+1.j]) # may vary
+/// >>> scipy.fft.rfft([0, 1, 0, 0])
+/// array([ 1.+0.j,  0.-1.j, -1.+0.j]) # may vary
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::Complex;
+/// use ndarray::array;
+/// use sci_rs::fft::rfft;
+///
+/// let y = rfft(&array![0., 1., 0., 0.], None, None, None, None, None).unwrap();
+/// assert_eq!(y, array![Complex::new(1., 0.), Complex::new(0., -1.), Complex::new(-1., 0.)]);
+/// ```
+pub fn rfft<F, S, D>(
+    x: &ArrayBase<S, D>,
+    n: Option<usize>,
+    axis: Option<isize>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<Complex<F>, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, x)?;
+    let workers = check_workers(workers)?;
+    Planner::with(plan, |planner| {
+        r2c(x, n, axis, norm.unwrap_or_default(), workers, planner)
+    })
+}
+
+/// Computes the inverse of [rfft].
+///
+/// This function computes the inverse of the 1-D *n*-point discrete Fourier Transform of real
+/// input computed by [rfft]. In other words, ``irfft(rfft(x), len(x)) == x`` to within numerical
+/// accuracy.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   The input array.
+/// * `n`: int, optional
+///   Length of the transformed axis of the output. For `n` output points, ``n/2+1`` input points
+///   are necessary. If the input is longer than this, it is cropped. If it is shorter than this,
+///   it is padded with zeros. If `n` is not given, it is taken to be ``2*(m-1)``, where `m` is
+///   the length of the input along the axis specified by `axis`.
+/// * `axis`: int, optional
+///   Axis over which to compute the inverse FFT. If not given, the last axis is used.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode, see [fft]. Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `out`: ndarray
+///   The truncated or zero-padded input, transformed along the axis indicated by `axis`, or the
+///   last one if `axis` is not specified. The length of the transformed axis is `n`, or, if `n`
+///   is not given, ``2*(m-1)``.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `axis` is out of range or the number of output points is zero.
+///
+/// ## Notes
+/// The imaginary parts of the zero frequency term, and of the Nyquist term for even `n`, are
+/// ignored since they cannot be represented in a real signal.
+///
+/// To get an odd number of output points, `n` must be specified.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import scipy.fft
+/// >>> scipy.fft.irfft([1, -1j, -1])
+/// array([0.,  1.,  0.,  0.])
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::Complex;
+/// use ndarray::{array, Array1};
+/// use sci_rs::fft::irfft;
+///
+/// let x = array![Complex::new(1., 0.), Complex::new(0., -1.), Complex::new(-1., 0.)];
+/// let y: Array1<f64> = irfft(&x, None, None, None, None, None).unwrap();
+/// assert!(y.iter().zip([0., 1., 0., 0.]).all(|(y, e)| (y - e).abs() < 1e-12));
+/// ```
+pub fn irfft<F, T, S, D>(
+    x: &ArrayBase<S, D>,
+    n: Option<usize>,
+    axis: Option<isize>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    T: Copy + Into<Complex<F>> + Sync,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, x)?;
+    let workers = check_workers(workers)?;
+    Planner::with(plan, |planner| {
+        c2r(x, n, axis, norm.unwrap_or_default(), workers, planner)
+    })
+}
+
+/// Compute the 2-D discrete Fourier Transform.
+///
+/// This function computes the N-D discrete Fourier Transform over any axes in an M-D array by
+/// means of the Fast Fourier Transform (FFT). By default, the transform is computed over the last
+/// two axes of the input array, i.e., a 2-dimensional FFT.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   Input array, can be complex
+/// * `s`: sequence of ints, optional
+///   Shape (length of each transformed axis) of the output, see [fftn].
+/// * `axes`: sequence of ints, optional
+///   Axes over which to compute the FFT. If not given, the last two axes are used.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode, see [fft]. Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `out`: complex ndarray
+///   The truncated or zero-padded input, transformed along the axes indicated by `axes`, or the
+///   last two axes if `axes` is not given.
+///
+/// ## Errors
+/// As for [fftn].
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import scipy.fft
+/// >>> import numpy as np
+/// >>> x = np.mgrid[:5, :5][0]
+/// >>> scipy.fft.fft2(x)
+/// array([[ 50.  +0.j        ,   0.  +0.j        ,   0.  +0.j        , # may vary
+///           0.  +0.j        ,   0.  +0.j        ],
+///        [-12.5+17.20477401j,   0.  +0.j        ,   0.  +0.j        ,
+///           0.  +0.j        ,   0.  +0.j        ],
+///        [-12.5 +4.0614962j ,   0.  +0.j        ,   0.  +0.j        ,
+///           0.  +0.j        ,   0.  +0.j        ],
+///        [-12.5 -4.0614962j ,   0.  +0.j        ,   0.  +0.j        ,
+///           0.  +0.j        ,   0.  +0.j        ],
+///        [-12.5-17.20477401j,   0.  +0.j        ,   0.  +0.j        ,
+///           0.  +0.j        ,   0.  +0.j        ]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::Complex;
+/// use ndarray::{Array2, Ix2};
+/// use sci_rs::fft::fft2;
+///
+/// let x = Array2::from_shape_fn((5, 5), |(i, _)| i as f64);
+/// let y = fft2(&x, None, None, None, None, None).unwrap();
+/// assert!((y[[0, 0]] - Complex::new(50., 0.)).norm() < 1e-10);
+/// assert!((y[[1, 0]] - Complex::new(-12.5, 17.20477401)).norm() < 1e-8);
+/// assert!(y.column(1).iter().all(|y| y.norm() < 1e-10));
+/// ```
+pub fn fft2<F, T, S, D>(
+    x: &ArrayBase<S, D>,
+    s: Option<&[usize]>,
+    axes: Option<&[isize]>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<Complex<F>, D>>
+where
+    F: Float + FftNum,
+    T: Copy + Into<Complex<F>> + Sync,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    fftn(x, s, Some(axes.unwrap_or(&[-2, -1])), norm, workers, plan)
+}
+
+/// Compute the 2-D inverse discrete Fourier Transform.
+///
+/// This function computes the inverse of the 2-D discrete Fourier Transform over any number of
+/// axes in an M-D array by means of the Fast Fourier Transform (FFT). In other words,
+/// ``ifft2(fft2(x)) == x`` to within numerical accuracy. By default, the inverse transform is
+/// computed over the last two axes of the input array.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   Input array, can be complex.
+/// * `s`: sequence of ints, optional
+///   Shape (length of each axis) of the output, see [fftn].
+/// * `axes`: sequence of ints, optional
+///   Axes over which to compute the FFT. If not given, the last two axes are used.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode, see [fft]. Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `out`: complex ndarray
+///   The truncated or zero-padded input, transformed along the axes indicated by `axes`, or the
+///   last two axes if `axes` is not given.
+///
+/// ## Errors
+/// As for [fftn].
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import scipy.fft
+/// >>> import numpy as np
+/// >>> x = 4 * np.eye(4)
+/// >>> scipy.fft.ifft2(x)
+/// array([[1.+0.j,  0.+0.j,  0.+0.j,  0.+0.j], # may vary
+///        [0.+0.j,  0.+0.j,  0.+0.j,  1.+0.j],
+///        [0.+0.j,  0.+0.j,  1.+0.j,  0.+0.j],
+///        [0.+0.j,  1.+0.j,  0.+0.j,  0.+0.j]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::Complex;
+/// use ndarray::Array2;
+/// use sci_rs::fft::ifft2;
+///
+/// let x = Array2::<f64>::eye(4) * 4.;
+/// let y = ifft2(&x, None, None, None, None, None).unwrap();
+/// for ((i, j), y) in y.indexed_iter() {
+///     let expected = if (i + j) % 4 == 0 { 1. } else { 0. };
+///     assert!((y - Complex::new(expected, 0.)).norm() < 1e-12);
+/// }
+/// ```
+pub fn ifft2<F, T, S, D>(
+    x: &ArrayBase<S, D>,
+    s: Option<&[usize]>,
+    axes: Option<&[isize]>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<Complex<F>, D>>
+where
+    F: Float + FftNum,
+    T: Copy + Into<Complex<F>> + Sync,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    ifftn(x, s, Some(axes.unwrap_or(&[-2, -1])), norm, workers, plan)
+}
+
+/// Compute the N-D discrete Fourier Transform.
+///
+/// This function computes the N-D discrete Fourier Transform over any number of axes in an M-D
+/// array by means of the Fast Fourier Transform (FFT).
+///
+/// ## Parameters
+/// * `x`: array_like
+///   Input array, can be complex.
+/// * `s`: sequence of ints, optional
+///   Shape (length of each transformed axis) of the output (``s[0]`` refers to axis 0, ``s[1]``
+///   to axis 1, etc.). Along any axis, if the given shape is smaller than that of the input, the
+///   input is cropped. If it is larger, the input is padded with zeros. If `s` is not given, the
+///   shape of the input along the axes specified by `axes` is used.
+/// * `axes`: sequence of ints, optional
+///   Axes over which to compute the FFT. If not given, the last ``len(s)`` axes are used, or all
+///   axes if `s` is also not specified.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode, see [fft]. Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `out`: complex ndarray
+///   The truncated or zero-padded input, transformed along the axes indicated by `axes`, or by a
+///   combination of `s` and `x`, as explained in the parameters section above.
+///
+/// ## Errors
+/// [Error::InvalidArg] if an element of `axes` is larger than the number of axes of `x`, if an
+/// axis is repeated, if `s` and `axes` have different lengths, or if `s` holds a zero.
+///
+/// ## Notes
+/// The output, analogously to [fft], contains the term for zero frequency in the low-order corner
+/// of all axes, the positive frequency terms in the first half of all axes, the term for the
+/// Nyquist frequency in the middle of all axes and the negative frequency terms in the second
+/// half of all axes, in order of decreasingly negative frequency.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import scipy.fft
+/// >>> import numpy as np
+/// >>> x = np.mgrid[:3, :3, :3][0]
+/// >>> scipy.fft.fftn(x, axes=(1, 2))
+/// array([[[ 0.+0.j,   0.+0.j,   0.+0.j], # may vary
+///         [ 0.+0.j,   0.+0.j,   0.+0.j],
+///         [ 0.+0.j,   0.+0.j,   0.+0.j]],
+///        [[ 9.+0.j,   0.+0.j,   0.+0.j],
+///         [ 0.+0.j,   0.+0.j,   0.+0.j],
+///         [ 0.+0.j,   0.+0.j,   0.+0.j]],
+///        [[18.+0.j,   0.+0.j,   0.+0.j],
+///         [ 0.+0.j,   0.+0.j,   0.+0.j],
+///         [ 0.+0.j,   0.+0.j,   0.+0.j]]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::Complex;
+/// use ndarray::Array3;
+/// use sci_rs::fft::fftn;
+///
+/// let x = Array3::from_shape_fn((3, 3, 3), |(i, _, _)| i as f64);
+/// let y = fftn(&x, None, Some(&[1, 2]), None, None, None).unwrap();
+/// for ((i, j, k), y) in y.indexed_iter() {
+///     let expected = if j == 0 && k == 0 { 9. * i as f64 } else { 0. };
+///     assert!((y - Complex::new(expected, 0.)).norm() < 1e-12);
+/// }
+/// ```
+pub fn fftn<F, T, S, D>(
+    x: &ArrayBase<S, D>,
+    s: Option<&[usize]>,
+    axes: Option<&[isize]>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<Complex<F>, D>>
+where
+    F: Float + FftNum,
+    T: Copy + Into<Complex<F>> + Sync,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let (shape, axes) = init_nd_shape_and_axes(x.shape(), s, axes)?;
+    let workers = check_workers(workers)?;
+    Planner::with(plan, |planner| {
+        c2cn(
+            x,
+            &shape,
+            &axes,
+            norm.unwrap_or_default(),
+            false,
+            workers,
+            planner,
+        )
+    })
+}
+
+/// Compute the N-D inverse discrete Fourier Transform.
+///
+/// This function computes the inverse of the N-D discrete Fourier Transform over any number of
+/// axes in an M-D array by means of the Fast Fourier Transform (FFT). In other words,
+/// ``ifftn(fftn(x)) == x`` to within numerical accuracy.
+///
+/// The input, analogously to [ifft], should be ordered in the same way as is returned by [fftn],
+/// i.e., it should have the term for zero frequency in all axes in the low-order corner, the
+/// positive frequency terms in the first half of all axes, the term for the Nyquist frequency in
+/// the middle of all axes and the negative frequency terms in the second half of all axes, in
+/// order of decreasingly negative frequency.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   Input array, can be complex.
+/// * `s`: sequence of ints, optional
+///   Shape (length of each transformed axis) of the output, see [fftn].
+/// * `axes`: sequence of ints, optional
+///   Axes over which to compute the IFFT. If not given, the last ``len(s)`` axes are used, or
+///   all axes if `s` is also not specified.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode, see [fft]. Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `out`: complex ndarray
+///   The truncated or zero-padded input, transformed along the axes indicated by `axes`, or by a
+///   combination of `s` or `x`, as explained in the parameters section above.
+///
+/// ## Errors
+/// As for [fftn].
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import scipy.fft
+/// >>> import numpy as np
+/// >>> x = np.eye(4)
+/// >>> scipy.fft.ifftn(scipy.fft.fftn(x, axes=(0,)), axes=(1,))
+/// array([[1.+0.j,  0.+0.j,  0.+0.j,  0.+0.j], # may vary
+///        [0.+0.j,  1.+0.j,  0.+0.j,  0.+0.j],
+///        [0.+0.j,  0.+0.j,  1.+0.j,  0.+0.j],
+///        [0.+0.j,  0.+0.j,  0.+0.j,  1.+0.j]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::Complex;
+/// use ndarray::Array2;
+/// use sci_rs::fft::{fftn, ifftn};
+///
+/// let x = Array2::<f64>::eye(4);
+/// let y = fftn(&x, None, Some(&[0]), None, None, None).unwrap();
+/// let z: Array2<Complex<f64>> = ifftn(&y, None, Some(&[1]), None, None, None).unwrap();
+/// for ((i, j), z) in z.indexed_iter() {
+///     let expected = if i == j { 1. } else { 0. };
+///     assert!((z - Complex::new(expected, 0.)).norm() < 1e-12);
+/// }
+/// ```
+pub fn ifftn<F, T, S, D>(
+    x: &ArrayBase<S, D>,
+    s: Option<&[usize]>,
+    axes: Option<&[isize]>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<Complex<F>, D>>
+where
+    F: Float + FftNum,
+    T: Copy + Into<Complex<F>> + Sync,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let (shape, axes) = init_nd_shape_and_axes(x.shape(), s, axes)?;
+    let workers = check_workers(workers)?;
+    Planner::with(plan, |planner| {
+        c2cn(
+            x,
+            &shape,
+            &axes,
+            norm.unwrap_or_default(),
+            true,
+            workers,
+            planner,
+        )
+    })
+}
+
+/// Compute the N-D discrete Fourier Transform for real input.
+///
+/// This function computes the N-D discrete Fourier Transform over any number of axes in an M-D
+/// real array by means of the Fast Fourier Transform (FFT). By default, all axes are transformed,
+/// with the real transform performed over the last axis, while the remaining transforms are
+/// complex.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   Input array, taken to be real.
+/// * `s`: sequence of ints, optional
+///   Shape (length along each transformed axis) to use from the input. (``s[0]`` refers to axis
+///   0, ``s[1]`` to axis 1, etc.). The final element of `s` corresponds to `n` for ``rfft(x,
+///   n)``, while for the remaining axes, it corresponds to `n` for ``fft(x, n)``. Along any axis,
+///   if the given shape is smaller than that of the input, the input is cropped. If it is larger,
+///   the input is padded with zeros. If `s` is not given, the shape of the input along the axes
+///   specified by `axes` is used.
+/// * `axes`: sequence of ints, optional
+///   Axes over which to compute the FFT. If not given, the last ``len(s)`` axes are used, or all
+///   axes if `s` is also not specified.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode, see [fft]. Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `out`: complex ndarray
+///   The truncated or zero-padded input, transformed along the axes indicated by `axes`, or by a
+///   combination of `s` and `x`, as explained in the parameters section above. The length of the
+///   last axis transformed will be ``s[-1]/2+1``, while the remaining transformed axes will have
+///   lengths according to `s`, or unchanged from the input.
+///
+/// ## Errors
+/// As for [fftn].
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import scipy.fft
+/// >>> import numpy as np
+/// >>> x = np.ones((2, 2, 2))
+/// >>> scipy.fft.rfftn(x)
+/// array([[[8.+0.j,  0.+0.j], # may vary
+///         [0.+0.j,  0.+0.j]],
+///        [[0.+0.j,  0.+0.j],
+///         [0.+0.j,  0.+0.j]]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::Complex;
+/// use ndarray::Array3;
+/// use sci_rs::fft::rfftn;
+///
+/// let x = Array3::<f64>::ones((2, 2, 2));
+/// let y = rfftn(&x, None, None, None, None, None).unwrap();
+/// assert_eq!(y.dim(), (2, 2, 2));
+/// assert_eq!(y[[0, 0, 0]], Complex::new(8., 0.));
+/// assert!(y.iter().skip(1).all(|y| y.norm() < 1e-12));
+/// ```
+pub fn rfftn<F, S, D>(
+    x: &ArrayBase<S, D>,
+    s: Option<&[usize]>,
+    axes: Option<&[isize]>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<Complex<F>, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let (shape, axes) = init_nd_shape_and_axes(x.shape(), s, axes)?;
+    let norm = norm.unwrap_or_default();
+    let workers = check_workers(workers)?;
+    let Some((&last, rest)) = axes.split_last() else {
+        return Ok(x.mapv(Complex::from));
+    };
+    let (&n, shape) = shape.split_last().unwrap();
+    Planner::with(plan, |planner| {
+        let y = r2c(x, Some(n), last, norm, workers, planner)?;
+        c2cn(&y, shape, rest, norm, false, workers, planner)
+    })
+}
+
+/// Computes the inverse of [rfftn].
+///
+/// This function computes the inverse of the N-D discrete Fourier Transform for real input over
+/// any number of axes in an M-D array by means of the Fast Fourier Transform (FFT). In other
+/// words, ``irfftn(rfftn(x), x.shape) == x`` to within numerical accuracy.
+///
+/// The input should be ordered in the same way as is returned by [rfftn], i.e., as for [irfft]
+/// for the final transformation axis, and as for [ifftn] along all the other axes.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   Input array.
+/// * `s`: sequence of ints, optional
+///   Shape (length of each transformed axis) of the output (``s[0]`` refers to axis 0, ``s[1]``
+///   to axis 1, etc.). `s` is also the number of input points used along this axis, except for
+///   the last axis, where ``s[-1]/2+1`` points of the input are used. If `s` is not given, the
+///   shape of the input along the axes specified by axes is used, except for the last axis which
+///   is taken to be ``2*(m-1)``, where `m` is the length of the input along that axis.
+/// * `axes`: sequence of ints, optional
+///   Axes over which to compute the inverse FFT. If not given, the last ``len(s)`` axes are used,
+///   or all axes if `s` is also not specified.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode, see [fft]. Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `out`: ndarray
+///   The truncated or zero-padded input, transformed along the axes indicated by `axes`, or by a
+///   combination of `s` or `x`, as explained in the parameters section above. The length of each
+///   transformed axis is as given by the corresponding element of `s`, or the length of the input
+///   in every axis except for the last one if `s` is not given. In the final transformed axis the
+///   length of the output when `s` is not given is ``2*(m-1)``.
+///
+/// ## Errors
+/// As for [fftn], and [Error::InvalidArg] if the default length of the last axis is zero.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import scipy.fft
+/// >>> import numpy as np
+/// >>> x = np.zeros((3, 2, 2))
+/// >>> x[0, 0, 0] = 3 * 2 * 2
+/// >>> scipy.fft.irfftn(x)
+/// array([[[1.,  1.],
+///         [1.,  1.]],
+///        [[1.,  1.],
+///         [1.,  1.]],
+///        [[1.,  1.],
+///         [1.,  1.]]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::Array3;
+/// use sci_rs::fft::irfftn;
+///
+/// let mut x = Array3::<f64>::zeros((3, 2, 2));
+/// x[[0, 0, 0]] = 12.;
+/// let y: Array3<f64> = irfftn(&x, None, None, None, None, None).unwrap();
+/// assert_eq!(y.dim(), (3, 2, 2));
+/// assert!(y.iter().all(|y| (y - 1.).abs() < 1e-12));
+/// ```
+pub fn irfftn<F, T, S, D>(
+    x: &ArrayBase<S, D>,
+    s: Option<&[usize]>,
+    axes: Option<&[isize]>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    T: Copy + Into<Complex<F>> + Sync,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let explicit_shape = s.is_some();
+    let (mut shape, axes) = init_nd_shape_and_axes(x.shape(), s, axes)?;
+    let norm = norm.unwrap_or_default();
+    let workers = check_workers(workers)?;
+    let Some((&last, rest)) = axes.split_last() else {
+        return Ok(x.mapv(|x| x.into().re));
+    };
+    if !explicit_shape {
+        let m = shape.last_mut().unwrap();
+        *m = 2 * (*m - 1);
+    }
+    let (&n, shape) = shape.split_last().unwrap();
+    Planner::with(plan, |planner| {
+        let y = c2cn(x, shape, rest, norm, true, workers, planner)?;
+        c2r(&y, Some(n), last, norm, workers, planner)
+    })
+}
+
+/// Normalize `s` and `axes` of an N-D transform of an array of `shape`, like scipy's
+/// `_init_nd_shape_and_axes`. Returns the length of each transformed axis and the axes.
+pub(crate) fn init_nd_shape_and_axes(
+    shape: &[usize],
+    s: Option<&[usize]>,
+    axes: Option<&[isize]>,
+) -> Result<(Vec<usize>, Vec<usize>)> {
+    let ndim = shape.len();
+    let axes: Vec<usize> = match (axes, s) {
+        (Some(axes), _) => axes
+            .iter()
+            .map(|&a| {
+                let d = if a < 0 { a + ndim as isize } else { a };
+                if d < 0 || d >= ndim as isize {
+                    return Err(Error::InvalidArg {
+                        arg: "axes".into(),
+                        reason: "axes exceeds dimensionality of input".into(),
+                    });
+                }
+                Ok(d as usize)
+            })
+            .collect::<Result<_>>()?,
+        (None, Some(s)) => {
+            if s.len() > ndim {
+                return Err(Error::InvalidArg {
+                    arg: "s".into(),
+                    reason: "s has more entries than the input has dimensions".into(),
+                });
+            }
+            (ndim - s.len()..ndim).collect()
+        }
+        (None, None) => (0..ndim).collect(),
+    };
+    let mut sorted = axes.clone();
+    sorted.sort_unstable();
+    if sorted.windows(2).any(|w| w[0] == w[1]) {
+        return Err(Error::InvalidArg {
+            arg: "axes".into(),
+            reason: "all axes must be unique".into(),
+        });
+    }
+
+    let shape = match s {
+        Some(s) => {
+            if s.len() != axes.len() {
+                return Err(Error::InvalidArg {
+                    arg: "s".into(),
+                    reason: "when given, axes and shape arguments have to be of the same length"
+                        .into(),
+                });
+            }
+            if s.contains(&0) {
+                return Err(Error::InvalidArg {
+                    arg: "s".into(),
+                    reason: "invalid number of data points specified".into(),
+                });
+            }
+            s.to_vec()
+        }
+        None => axes.iter().map(|&d| shape[d]).collect(),
+    };
+    Ok((shape, axes))
+}
+
+/// Complex transform of `x` along each of `axes`, with lengths `shape`.
+fn c2cn<F, T, S, D>(
+    x: &ArrayBase<S, D>,
+    shape: &[usize],
+    axes: &[usize],
+    norm: FftNorm,
+    inverse: bool,
+    workers: usize,
+    planner: &mut Planner<F>,
+) -> Result<Array<Complex<F>, D>>
+where
+    F: Float + FftNum,
+    T: Copy + Into<Complex<F>> + Sync,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let mut y = x.mapv(Into::into);
+    for (&axis, &n) in axes.iter().zip(shape) {
+        y = c2c(&y, Some(n), axis, norm, inverse, workers, planner)?;
+    }
+    Ok(y)
+}
+
+/// Complex transform of `x` along `axis`, cropped or zero-padded to `n` points.
+pub(crate) fn c2c<F, T, S, D>(
+    x: &ArrayBase<S, D>,
+    n: Option<usize>,
+    axis: usize,
+    norm: FftNorm,
+    inverse: bool,
+    workers: usize,
+    planner: &mut Planner<F>,
+) -> Result<Array<Complex<F>, D>>
+where
+    F: Float + FftNum,
+    T: Copy + Into<Complex<F>> + Sync,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let axis = Axis(axis);
+    let n = check_points(n.unwrap_or(x.len_of(axis)))?;
+    let mut out_dim = x.raw_dim();
+    out_dim[axis.index()] = n;
+    let mut out = Array::zeros(out_dim);
+
+    let fft = planner.plan_fft(n, inverse);
+    let scale = norm.factor::<F>(n, inverse);
+    for_each_lane(
+        x.view(),
+        out.view_mut(),
+        axis,
+        workers,
+        || {
+            let scratch = vec![Complex::zero(); fft.get_inplace_scratch_len()];
+            (vec![Complex::zero(); n], scratch)
+        },
+        |(buffer, scratch), lane, mut out| {
+            buffer.fill(Complex::zero());
+            buffer
+                .iter_mut()
+                .zip(lane)
+                .for_each(|(b, &x)| *b = x.into());
+            fft.process_with_scratch(buffer, scratch);
+            out.iter_mut()
+                .zip(buffer.iter())
+                .for_each(|(o, &b)| *o = b * scale);
+        },
+    )?;
+    Ok(out)
+}
+
+/// Real to complex transform of `x` along `axis`, cropped or zero-padded to `n` points. Only the
+/// ``n/2+1`` non-negative frequency terms are returned.
+pub(crate) fn r2c<F, S, D>(
+    x: &ArrayBase<S, D>,
+    n: Option<usize>,
+    axis: usize,
+    norm: FftNorm,
+    workers: usize,
+    planner: &mut Planner<F>,
+) -> Result<Array<Complex<F>, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = Axis(axis);
+    let n = check_points(n.unwrap_or(x.len_of(axis)))?;
+    let mut out_dim = x.raw_dim();
+    out_dim[axis.index()] = n / 2 + 1;
+    let mut out = Array::zeros(out_dim);
+
+    let r2c = planner.plan_rfft(n);
+    let scale = norm.factor::<F>(n, false);
+    for_each_lane(
+        x.view(),
+        out.view_mut(),
+        axis,
+        workers,
+        || {
+            let input = r2c.make_input_vec();
+            (input, r2c.make_output_vec(), r2c.make_scratch_vec())
+        },
+        |(input, output, scratch), lane, mut out| {
+            input.fill(F::zero());
+            input.iter_mut().zip(lane).for_each(|(i, &x)| *i = x);
+            r2c.process_with_scratch(input, output, scratch)
+                .expect("buffers are sized by the plan");
+            out.iter_mut()
+                .zip(output.iter())
+                .for_each(|(o, &y)| *o = y * scale);
+        },
+    )?;
+    Ok(out)
+}
+
+/// Complex to real transform of the non-negative frequency terms `x` along `axis`, giving `n`
+/// points. `n` defaults to ``2*(m-1)`` for `m` input points.
+pub(crate) fn c2r<F, T, S, D>(
+    x: &ArrayBase<S, D>,
+    n: Option<usize>,
+    axis: usize,
+    norm: FftNorm,
+    workers: usize,
+    planner: &mut Planner<F>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    T: Copy + Into<Complex<F>> + Sync,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let axis = Axis(axis);
+    let n = check_points(n.unwrap_or(2 * x.len_of(axis).saturating_sub(1)))?;
+    let mut out_dim = x.raw_dim();
+    out_dim[axis.index()] = n;
+    let mut out = Array::zeros(out_dim);
+
+    let c2r = planner.plan_irfft(n);
+    let scale = norm.factor::<F>(n, true);
+    for_each_lane(
+        x.view(),
+        out.view_mut(),
+        axis,
+        workers,
+        || {
+            let input = c2r.make_input_vec();
+            (input, c2r.make_output_vec(), c2r.make_scratch_vec())
+        },
+        |(input, output, scratch), lane, mut out| {
+            input.fill(Complex::zero());
+            input.iter_mut().zip(lane).for_each(|(i, &x)| *i = x.into());
+            // A real signal has a real spectrum at DC and Nyquist, the imaginary parts are ignored.
+            input[0].im = F::zero();
+            if n.is_multiple_of(2) {
+                input[n / 2].im = F::zero();
+            }
+            c2r.process_with_scratch(input, output, scratch)
+                .expect("buffers are sized by the plan");
+            out.iter_mut()
+                .zip(output.iter())
+                .for_each(|(o, &y)| *o = y * scale);
+        },
+    )?;
+    Ok(out)
+}
+
+/// Check that a transform has a positive number of data points.
+/// Number of threads for the lanes of the transforms, 1 if not given.
+pub(crate) fn check_workers(workers: Option<usize>) -> Result<usize> {
+    match workers.unwrap_or(1) {
+        0 => Err(Error::InvalidArg {
+            arg: "workers".into(),
+            reason: "workers must not be zero".into(),
+        }),
+        workers => Ok(workers),
+    }
+}
+
+/// Call `f` on each lane of `x` along `axis` and the matching lane of `out`, with a state made by
+/// `init` for each thread. With the `parallel` feature the lanes are split across a pool of
+/// `workers` threads, otherwise they are all done on the calling thread.
+pub(crate) fn for_each_lane<A, B, D, T>(
+    x: ArrayView<A, D>,
+    mut out: ArrayViewMut<B, D>,
+    axis: Axis,
+    workers: usize,
+    init: impl Fn() -> T + Send + Sync,
+    f: impl Fn(&mut T, ArrayView1<A>, ArrayViewMut1<B>) + Send + Sync,
+) -> Result<()>
+where
+    A: Sync,
+    B: Send,
+    D: Dimension,
+{
+    let lanes = Zip::from(x.lanes(axis)).and(out.lanes_mut(axis));
+    #[cfg(feature = "parallel")]
+    if workers > 1 {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        thread_pool(workers)?.install(|| {
+            lanes
+                .into_par_iter()
+                .for_each_init(init, |state, (x, out)| f(state, x, out))
+        });
+        return Ok(());
+    }
+    let mut state = init();
+    lanes.for_each(|x, out| f(&mut state, x, out));
+    Ok(())
+}
+
+/// The pool of `workers` threads, built on first use and shared by the later transforms.
+#[cfg(feature = "parallel")]
+fn thread_pool(workers: usize) -> Result<std::sync::Arc<rayon::ThreadPool>> {
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+
+    static POOLS: Mutex<BTreeMap<usize, Arc<rayon::ThreadPool>>> = Mutex::new(BTreeMap::new());
+    let mut pools = POOLS.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(pool) = pools.get(&workers) {
+        return Ok(pool.clone());
+    }
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(workers)
+        .build()
+        .map_err(|e| Error::InvalidArg {
+            arg: "workers".into(),
+            reason: e.to_string(),
+        })?;
+    let pool = Arc::new(pool);
+    pools.insert(workers, pool.clone());
+    Ok(pool)
+}
+
+pub(crate) fn check_points(n: usize) -> Result<usize> {
+    if n == 0 {
+        return Err(Error::InvalidArg {
+            arg: "n".into(),
+            reason: "invalid number of data points (0) specified".into(),
+        });
+    }
+    Ok(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array1, Array2};
+
+    #[track_caller]
+    fn assert_complex_eq<D: Dimension>(a: &Array<Complex<f64>, D>, b: &Array<Complex<f64>, D>) {
+        assert_eq!(a.shape(), b.shape());
+        for (a, b) in a.iter().zip(b) {
+            assert_abs_diff_eq!(a.re, b.re, epsilon = 1e-10);
+            assert_abs_diff_eq!(a.im, b.im, epsilon = 1e-10);
+        }
+    }
+
+    /// Direct evaluation of the DFT definition.
+    fn dft(x: &[Complex<f64>], inverse: bool) -> Array1<Complex<f64>> {
+        let n = x.len();
+        let sign = if inverse { 1. } else { -1. };
+        Array1::from_shape_fn(n, |k| {
+            x.iter()
+                .enumerate()
+                .map(|(m, &x)| {
+                    x * Complex::from_polar(
+                        1.,
+                        sign * 2. * core::f64::consts::PI * (k * m) as f64 / n as f64,
+                    )
+                })
+                .sum()
+        })
+    }
+
+    #[test]
+    fn definition() {
+        let x = Array1::from_shape_fn(7, |i| Complex::new(i as f64 - 2., (i * i % 5) as f64));
+        let slice = x.as_slice().unwrap();
+        let y = fft(&x, None, None, None, None, None).unwrap();
+        assert_complex_eq(&y, &dft(slice, false));
+        let z = ifft(&y, None, None, None, None, None).unwrap();
+        assert_complex_eq(&z, &x);
+
+        // Padding and cropping.
+        let mut padded = x.to_vec();
+        padded.extend([Complex::zero(); 3]);
+        assert_complex_eq(
+            &fft(&x, Some(10), None, None, None, None).unwrap(),
+            &dft(&padded, false),
+        );
+        assert_complex_eq(
+            &fft(&x, Some(4), None, None, None, None).unwrap(),
+            &dft(&slice[..4], false),
+        );
+    }
+
+    #[test]
+    fn normalization() {
+        let x = array![1., -2., 0.5, 3., 4.];
+        let backward: Array1<Complex<f64>> = fft(&x, None, None, None, None, None).unwrap();
+        let ortho: Array1<Complex<f64>> =
+            fft(&x, None, None, Some(FftNorm::Ortho), None, None).unwrap();
+        let forward: Array1<Complex<f64>> =
+            fft(&x, None, None, Some(FftNorm::Forward), None, None).unwrap();
+        assert_complex_eq(&ortho, &backward.mapv(|y| y / 5f64.sqrt()));
+        assert_complex_eq(&forward, &backward.mapv(|y| y / 5.));
+        // Each mode is undone by the inverse with the same mode.
+        for (norm, y) in [(FftNorm::Ortho, &ortho), (FftNorm::Forward, &forward)] {
+            let z = ifft(y, None, None, Some(norm), None, None).unwrap();
+            assert_complex_eq(&z, &x.mapv(Complex::from));
+        }
+        // Parseval's theorem holds for the unitary transform.
+        let energy: f64 = x.iter().map(|x| x * x).sum();
+        assert_abs_diff_eq!(
+            ortho.iter().map(|y| y.norm_sqr()).sum::<f64>(),
+            energy,
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn axis() {
+        let x = Array2::from_shape_fn((3, 4), |(i, j)| Complex::new((i * 4 + j) as f64, 1.));
+        let rows = fft(&x, None, Some(-1), None, None, None).unwrap();
+        let cols = fft(&x, None, Some(0), None, None, None).unwrap();
+        for i in 0..3 {
+            let row = x.row(i).to_vec();
+            assert_complex_eq(&rows.row(i).to_owned(), &dft(&row, false));
+        }
+        for j in 0..4 {
+            let col = x.column(j).to_vec();
+            assert_complex_eq(&cols.column(j).to_owned(), &dft(&col, false));
+        }
+        assert!(fft::<f64, _, _, _>(&x, None, Some(2), None, None, None).is_err());
+        assert!(ifft::<f64, _, _, _>(&x, Some(0), None, None, None, None).is_err());
+    }
+    #[test]
+    fn real_transforms() {
+        for n in [6, 7] {
+            let x = Array1::from_shape_fn(n, |i| (i as f64 * 1.3).sin() + 0.2 * i as f64);
+            let full: Array1<Complex<f64>> = fft(&x, None, None, None, None, None).unwrap();
+            let half = rfft(&x, None, None, None, None, None).unwrap();
+            assert_eq!(half.len(), n / 2 + 1);
+            assert_complex_eq(&half, &full.slice(ndarray::s![..n / 2 + 1]).to_owned());
+            let y: Array1<f64> = irfft(&half, Some(n), None, None, None, None).unwrap();
+            for (y, x) in y.iter().zip(&x) {
+                assert_abs_diff_eq!(y, x, epsilon = 1e-12);
+            }
+        }
+        // Without `n` an even length is assumed.
+        let half = rfft(&array![1., 2., 3.], None, None, None, None, None).unwrap();
+        let y: Array1<f64> = irfft(&half, None, None, None, None, None).unwrap();
+        assert_eq!(y.len(), 2);
+        assert!(irfft::<f64, _, _, _>(&array![1.], None, None, None, None, None).is_err());
+    }
+
+    #[test]
+    fn n_dimensional() {
+        let x = ndarray::Array3::from_shape_fn((3, 4, 5), |(i, j, k)| {
+            Complex::new((i * 7 + j * 3 + k) as f64 % 5., (i + 2 * k) as f64 % 3.)
+        });
+        let expected = fft(
+            &fft(
+                &fft(&x, None, Some(0), None, None, None).unwrap(),
+                None,
+                Some(1),
+                None,
+                None,
+                None,
+            )
+            .unwrap(),
+            None,
+            Some(2),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_complex_eq(&fftn(&x, None, None, None, None, None).unwrap(), &expected);
+        assert_complex_eq(&ifftn(&expected, None, None, None, None, None).unwrap(), &x);
+
+        // fft2 defaults to the last two axes, `s` crops and pads.
+        let expected = fft(
+            &fft(&x, Some(6), Some(1), None, None, None).unwrap(),
+            Some(3),
+            Some(2),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let y = fft2(&x, Some(&[6, 3]), None, None, None, None).unwrap();
+        assert_eq!(y.dim(), (3, 6, 3));
+        assert_complex_eq(&y, &expected);
+        let y = fftn(&x, Some(&[6, 3]), None, None, None, None).unwrap();
+        assert_complex_eq(&y, &expected);
+        let z = ifft2(&y, None, Some(&[2, 1]), Some(FftNorm::Ortho), None, None).unwrap();
+        let expected = ifft(
+            &ifft(&y, None, Some(2), Some(FftNorm::Ortho), None, None).unwrap(),
+            None,
+            Some(1),
+            Some(FftNorm::Ortho),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_complex_eq(&z, &expected);
+
+        // Real transforms halve the last of the axes.
+        let r = x.mapv(|x| x.re);
+        let full = fftn(&r, None, Some(&[2, 0]), None, None, None).unwrap();
+        let half = rfftn(&r, None, Some(&[2, 0]), None, None, None).unwrap();
+        assert_eq!(half.dim(), (2, 4, 5));
+        assert_complex_eq(&half, &full.slice(ndarray::s![..2, .., ..]).to_owned());
+        let back: ndarray::Array3<f64> =
+            irfftn(&half, Some(&[5, 3]), Some(&[2, 0]), None, None, None).unwrap();
+        for (b, r) in back.iter().zip(&r) {
+            assert_abs_diff_eq!(b, r, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn n_dimensional_args() {
+        let x = Array2::<f64>::zeros((2, 3));
+        assert!(fftn::<f64, _, _, _>(&x, None, Some(&[0, 0]), None, None, None).is_err());
+        assert!(fftn::<f64, _, _, _>(&x, None, Some(&[2]), None, None, None).is_err());
+        assert!(fftn::<f64, _, _, _>(&x, Some(&[2, 3, 4]), None, None, None, None).is_err());
+        assert!(fftn::<f64, _, _, _>(&x, Some(&[2]), Some(&[0, 1]), None, None, None).is_err());
+        assert!(fftn::<f64, _, _, _>(&x, Some(&[2, 0]), None, None, None, None).is_err());
+        assert_eq!(
+            fftn::<f64, _, _, _>(&x, Some(&[5]), None, None, None, None)
+                .unwrap()
+                .dim(),
+            (2, 5)
+        );
+        assert_eq!(
+            rfftn(&x, None, Some(&[]), None, None, None).unwrap().dim(),
+            (2, 3)
+        );
+    }
+
+    #[test]
+    fn reused_planner() {
+        let x = Array2::from_shape_fn((3, 8), |(i, j)| (i as f64 - j as f64).sin());
+        let mut planner = Planner::new();
+        for _ in 0..2 {
+            for n in [5, 8, 12] {
+                let y = fft(&x, Some(n), None, None, None, Some(&mut planner)).unwrap();
+                assert_complex_eq(&y, &fft(&x, Some(n), None, None, None, None).unwrap());
+                let z = ifft(&y, None, None, None, None, Some(&mut planner)).unwrap();
+                assert_complex_eq(&z, &ifft(&y, None, None, None, None, None).unwrap());
+                let half = rfftn(&x, Some(&[4, n]), None, None, None, Some(&mut planner)).unwrap();
+                assert_complex_eq(
+                    &half,
+                    &rfftn(&x, Some(&[4, n]), None, None, None, None).unwrap(),
+                );
+                let back: Array2<f64> =
+                    irfftn(&half, Some(&[4, n]), None, None, None, Some(&mut planner)).unwrap();
+                let expected: Array2<f64> =
+                    irfftn(&half, Some(&[4, n]), None, None, None, None).unwrap();
+                assert_eq!(back, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn workers() {
+        let x = Array2::from_shape_fn((7, 9), |(i, j)| ((i * 3 + j * 5) % 7) as f64 - 3.);
+        for workers in [Some(1), Some(3)] {
+            let y = fftn(&x, None, None, None, workers, None).unwrap();
+            assert_complex_eq(&y, &fftn(&x, None, None, None, None, None).unwrap());
+            let half = rfft(&x, None, Some(0), None, workers, None).unwrap();
+            assert_complex_eq(&half, &rfft(&x, None, Some(0), None, None, None).unwrap());
+            let back: Array2<f64> =
+                irfftn(&half, Some(&[7]), Some(&[0]), None, workers, None).unwrap();
+            for (a, b) in back.iter().zip(&x) {
+                assert_abs_diff_eq!(a, b, epsilon = 1e-10);
+            }
+        }
+        assert!(fft::<f64, _, _, _>(&x, None, None, None, Some(0), None).is_err());
+        #[cfg(feature = "parallel")]
+        {
+            let pool = thread_pool(3).unwrap();
+            assert_eq!(pool.current_num_threads(), 3);
+            assert!(std::sync::Arc::ptr_eq(&pool, &thread_pool(3).unwrap()));
+        }
+    }
+}
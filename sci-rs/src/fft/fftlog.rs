@@ -0,0 +1,618 @@
+use super::{c2r, check_points, check_workers, r2c, FftNorm, Planner};
+use core::f64::consts::{LN_2, PI};
+use nalgebra::Complex;
+use ndarray::{Array, Array1, ArrayBase, Axis, Data, Dimension, Slice};
+use num_traits::Float;
+use rustfft::FftNum;
+use sci_rs_core::{Error, Result};
+
+/// Compute the fast Hankel transform.
+///
+/// Computes the discrete Hankel transform of a logarithmically spaced periodic sequence using the
+/// FFTLog algorithm [1], [2].
+///
+/// ## Parameters
+/// * `a`: array_like (..., n)
+///   Real periodic input array, uniformly logarithmically spaced. For multidimensional input, the
+///   transform is performed over the last axis.
+/// * `dln`: float
+///   Uniform logarithmic spacing of the input array.
+/// * `mu`: float
+///   Order of the Hankel transform, any positive or negative real number.
+/// * `offset`: float, optional
+///   Offset of the uniform logarithmic spacing of the output array. Defaults to 0.
+/// * `bias`: float, optional
+///   Exponent of power law bias, any positive or negative real number. Defaults to 0.
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `A`: array_like (..., n)
+///   The transformed output array, which is real, periodic, uniformly logarithmically spaced, and
+///   of the same shape as the input array.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `a` has no axes or an empty last axis, or `workers` is zero.
+///
+/// ## Notes
+/// This function computes a discrete version of the Hankel transform
+///
+/// ``A(k) = ∫_0^∞ a(r) J_μ(kr) k dr``
+///
+/// where `J_μ` is the Bessel function of order `mu`. The index `mu` may be any real number,
+/// positive or negative. Note that the numerical Hankel transform uses an integrand of `k dr`,
+/// while the mathematical Hankel transform is commonly defined using `r dr`.
+///
+/// The input array `a` is a periodic sequence of length `n`, uniformly logarithmically spaced
+/// with spacing `dln`,
+///
+/// ``a_j = a(r_j), r_j = r_c exp[(j-j_c) dln]``
+///
+/// centred about the point `r_c`. Note that the central index ``j_c = (n-1)/2`` is half-integral
+/// if `n` is even, so that `r_c` falls between two input elements. Similarly, the output array
+/// `A` is a periodic sequence of length `n`, also uniformly logarithmically spaced with spacing
+/// `dln`
+///
+/// ``A_j = A(k_j), k_j = k_c exp[(j-j_c) dln]``
+///
+/// centred about the point `k_c`.
+///
+/// The centre points `r_c` and `k_c` of the periodic intervals may be chosen arbitrarily, but it
+/// would be usual to choose the product ``k_c r_c = k_j r_{n-1-j} = k_{n-1-j} r_j`` to be unity.
+/// This can be changed using the `offset` parameter, which controls the logarithmic offset
+/// ``log(k_c) = offset - log(r_c)`` of the output array. Choosing an optimal value for `offset`
+/// may reduce ringing of the discrete Hankel transform, see [fhtoffset].
+///
+/// If the `bias` parameter is nonzero, this function computes a discrete version of the biased
+/// Hankel transform
+///
+/// ``A(k) = ∫_0^∞ a_q(r) (kr)^q J_μ(kr) k dr``
+///
+/// where `q` is the value of `bias`, and a power law bias ``a_q(r) = a(r) (kr)^{-q}`` is applied
+/// to the input sequence. Biasing the transform can help approximate the continuous transform of
+/// `a(r)` if there is a value `q` such that `a_q(r)` is close to a periodic sequence, in which
+/// case the resulting `A(k)` will be close to the continuous transform.
+///
+/// A transform that is singular for the given `mu` and `bias` has its zero frequency coefficient
+/// set to zero instead of being infinite, which may still give the correct transform. A
+/// different `bias` avoids the singularity.
+///
+/// ## References
+/// 1. Talman J. D., 1978, J. Comp. Phys., 29, 35
+/// 2. Hamilton A. J. S., 2000, MNRAS, 312, 257 (astro-ph/9905191)
+///
+/// ## Examples
+/// This example is the adapted version of ``fftlogtest.f`` which is provided in [2]. It evaluates
+/// the integral
+///
+/// ``∫_0^∞ r^{μ+1} exp(-r^2/2) J_μ(kr) k dr = k^{μ+1} exp(-k^2/2)``
+///
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy import fft
+/// >>> mu = 0.0                     # Order mu of Bessel function
+/// >>> r = np.logspace(-7, 1, 128)  # Input evaluation points
+/// >>> dln = np.log(r[1]/r[0])      # Step size
+/// >>> offset = fft.fhtoffset(dln, initial=-6*np.log(10), mu=mu)
+/// >>> k = np.exp(offset)/r[::-1]   # Output evaluation points
+/// >>> def f(x, mu):
+/// ...     """Analytical function: x^(mu+1) exp(-x^2/2)."""
+/// ...     return x**(mu + 1)*np.exp(-x**2/2)
+/// >>> a_r = f(r, mu)
+/// >>> fht = fft.fht(a_r, dln, mu=mu, offset=offset)
+/// >>> a_k = f(k, mu)
+/// >>> rel_err = abs((fht-a_k)/a_k)
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::Array1;
+/// use sci_rs::fft::{fht, fhtoffset};
+///
+/// let mu = 0.0f64;
+/// let r = Array1::from_shape_fn(128, |i| 10f64.powf(-7. + 8. * i as f64 / 127.));
+/// let dln = (r[1] / r[0]).ln();
+/// let offset = fhtoffset(dln, mu, Some(-6. * 10f64.ln()), None);
+/// let k = r.mapv(|r| offset.exp() / r);
+/// let f = |x: f64| x.powf(mu + 1.) * (-x * x / 2.).exp();
+/// let a = fht(&r.mapv(f), dln, mu, Some(offset), None, None, None).unwrap();
+/// // The output points are the reversed input points.
+/// for (a, k) in a.iter().zip(k.iter().rev()) {
+///     assert!((a - f(*k)).abs() < 1e-4);
+/// }
+/// ```
+pub fn fht<F, S, D>(
+    a: &ArrayBase<S, D>,
+    dln: F,
+    mu: F,
+    offset: Option<F>,
+    bias: Option<F>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let workers = check_workers(workers)?;
+    Planner::with(plan, |planner| {
+        fhtq(a, dln, mu, offset, bias, false, workers, planner)
+    })
+}
+
+/// Compute the inverse fast Hankel transform.
+///
+/// Computes the discrete inverse Hankel transform of a logarithmically spaced periodic sequence.
+/// This is the inverse operation to [fht].
+///
+/// ## Parameters
+/// * `A`: array_like (..., n)
+///   Real periodic input array, uniformly logarithmically spaced. For multidimensional input, the
+///   transform is performed over the last axis.
+/// * `dln`: float
+///   Uniform logarithmic spacing of the input array.
+/// * `mu`: float
+///   Order of the Hankel transform, any positive or negative real number.
+/// * `offset`: float, optional
+///   Offset of the uniform logarithmic spacing of the output array. Defaults to 0.
+/// * `bias`: float, optional
+///   Exponent of power law bias, any positive or negative real number. Defaults to 0.
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `a`: array_like (..., n)
+///   The transformed output array, which is real, periodic, uniformly logarithmically spaced, and
+///   of the same shape as the input array.
+///
+/// ## Errors
+/// As for [fht].
+///
+/// ## Notes
+/// This function computes a discrete version of the Hankel transform
+///
+/// ``a(r) = ∫_0^∞ A(k) J_μ(kr) r dk``
+///
+/// where `J_μ` is the Bessel function of order `mu`. The index `mu` may be any real number,
+/// positive or negative. Note that the numerical inverse Hankel transform uses an integrand of
+/// `r dk`, while the mathematical inverse Hankel transform is commonly defined using `k dk`.
+///
+/// See [fht] for further details. An inverse transform that is singular for the given `mu` and
+/// `bias` has its zero frequency coefficient treated as infinite.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy import fft
+/// >>> a = np.array([1., 2., -1., 0.5, 3., 0., -2.])
+/// >>> np.allclose(fft.ifht(fft.fht(a, 0.2, 0.5, bias=0.3), 0.2, 0.5, bias=0.3), a)
+/// True
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::fft::{fht, ifht};
+///
+/// let a = array![1., 2., -1., 0.5, 3., 0., -2.];
+/// let big_a = fht(&a, 0.2, 0.5, None, Some(0.3), None, None).unwrap();
+/// let b = ifht(&big_a, 0.2, 0.5, None, Some(0.3), None, None).unwrap();
+/// assert!(a.iter().zip(&b).all(|(a, b): (&f64, _)| (a - b).abs() < 1e-12));
+/// ```
+pub fn ifht<F, S, D>(
+    a: &ArrayBase<S, D>,
+    dln: F,
+    mu: F,
+    offset: Option<F>,
+    bias: Option<F>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let workers = check_workers(workers)?;
+    Planner::with(plan, |planner| {
+        fhtq(a, dln, mu, offset, bias, true, workers, planner)
+    })
+}
+
+/// Return optimal offset for a fast Hankel transform.
+///
+/// Returns an offset close to `initial` that fulfils the low-ringing condition of [2] for the
+/// fast Hankel transform [fht] with logarithmic spacing `dln`, order `mu` and bias `bias`.
+///
+/// ## Parameters
+/// * `dln`: float
+///   Uniform logarithmic spacing of the transform.
+/// * `mu`: float
+///   Order of the Hankel transform, any positive or negative real number.
+/// * `initial`: float, optional
+///   Initial value for the offset. Returns the closest value that fulfils the low-ringing
+///   condition. Defaults to 0.
+/// * `bias`: float, optional
+///   Exponent of power law bias, any positive or negative real number. Defaults to 0.
+///
+/// ## Returns
+/// * `offset`: float
+///   Optimal offset of the uniform logarithmic spacing of the transform that fulfils a
+///   low-ringing condition.
+///
+/// ## References
+/// 1. Hamilton A. J. S., 2000, MNRAS, 312, 257 (astro-ph/9905191)
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> from scipy.fft import fhtoffset
+/// >>> dln = 0.1
+/// >>> mu = 2.0
+/// >>> initial = 0.5
+/// >>> bias = 0.0
+/// >>> offset = fhtoffset(dln, mu, initial, bias)
+/// >>> offset
+/// 0.5454581477676637
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::fft::fhtoffset;
+///
+/// let offset = fhtoffset(0.1, 2.0, Some(0.5), Some(0.0));
+/// assert!((offset - 0.5454581477676637f64).abs() < 1e-12);
+/// ```
+pub fn fhtoffset<F: Float>(dln: F, mu: F, initial: Option<F>, bias: Option<F>) -> F {
+    let (dln, mu) = (dln.to_f64().unwrap(), mu.to_f64().unwrap());
+    let lnkr = initial.map_or(0., |x| x.to_f64().unwrap());
+    let q = bias.map_or(0., |x| x.to_f64().unwrap());
+    let xp = (mu + 1. + q) / 2.;
+    let xm = (mu + 1. - q) / 2.;
+    let y = PI / (2. * dln);
+    let zp = loggamma(Complex::new(xp, y));
+    let zm = loggamma(Complex::new(xm, y));
+    let arg = (LN_2 - lnkr) / dln + (zp.im + zm.im) / PI;
+    F::from(lnkr + (arg - arg.round_ties_even()) * dln).unwrap()
+}
+
+/// Forward or `inverse` fast Hankel transform along the last axis of `a`, with the FFTs of
+/// `planner` on `workers` threads.
+#[allow(clippy::too_many_arguments)]
+fn fhtq<F, S, D>(
+    a: &ArrayBase<S, D>,
+    dln: F,
+    mu: F,
+    offset: Option<F>,
+    bias: Option<F>,
+    inverse: bool,
+    workers: usize,
+    planner: &mut Planner<F>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let Some(last) = a.ndim().checked_sub(1) else {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "a must have at least one axis".into(),
+        });
+    };
+    let n = check_points(a.len_of(Axis(last)))?;
+    let offset = offset.unwrap_or_else(F::zero);
+    let q = bias.unwrap_or_else(F::zero);
+
+    // The power law bias of the input and output points, which cancel for the inverse.
+    let j_c = F::from(n - 1).unwrap() / F::from(2).unwrap();
+    let log_r = Array1::from_shape_fn(n, |j| (F::from(j).unwrap() - j_c) * dln);
+    let (w_in, w_out) = if inverse {
+        (
+            log_r.mapv(|x| (q * (x + offset)).exp()),
+            log_r.mapv(|x| (q * x).exp()),
+        )
+    } else {
+        (
+            log_r.mapv(|x| (-q * x).exp()),
+            log_r.mapv(|x| (-q * (x + offset)).exp()),
+        )
+    };
+
+    let mut a = a.to_owned();
+    if !q.is_zero() {
+        scale_lanes(&mut a, last, &w_in);
+    }
+    // The inverse divides by the conjugate coefficients, an infinite one giving zero.
+    let u: Vec<Complex<F>> = fhtcoeff(n, dln, mu, offset, q, inverse)
+        .into_iter()
+        .map(|u| match inverse {
+            false => u,
+            true if u.re.is_infinite() => Complex::new(F::zero(), F::zero()),
+            true => u.conj().inv(),
+        })
+        .collect();
+    let mut sp = r2c(&a, None, last, FftNorm::Backward, workers, planner)?;
+    for mut lane in sp.lanes_mut(Axis(last)) {
+        lane.iter_mut().zip(&u).for_each(|(s, &u)| *s = *s * u);
+    }
+    let y = c2r(&sp, Some(n), last, FftNorm::Backward, workers, planner)?;
+    let mut y = y
+        .slice_axis(Axis(last), Slice::new(0, None, -1))
+        .as_standard_layout()
+        .into_owned();
+    if !q.is_zero() {
+        scale_lanes(&mut y, last, &w_out);
+    }
+    Ok(y)
+}
+
+/// Multiply each lane of `a` along `axis` by `w`.
+fn scale_lanes<F: Float, D: Dimension>(a: &mut Array<F, D>, axis: usize, w: &Array1<F>) {
+    for mut lane in a.lanes_mut(Axis(axis)) {
+        lane.iter_mut().zip(w).for_each(|(a, &w)| *a = *a * w);
+    }
+}
+
+/// Coefficients ``2^q Gamma(xp + iy) / Gamma(xm - iy) exp(2iy (ln 2 - offset))`` of the fast
+/// Hankel transform of `n` points, like scipy's `fhtcoeff`.
+fn fhtcoeff<F: Float>(
+    n: usize,
+    dln: F,
+    mu: F,
+    offset: F,
+    bias: F,
+    inverse: bool,
+) -> Vec<Complex<F>> {
+    let (dln, mu) = (dln.to_f64().unwrap(), mu.to_f64().unwrap());
+    let (lnkr, q) = (offset.to_f64().unwrap(), bias.to_f64().unwrap());
+    let xp = (mu + 1. + q) / 2.;
+    let xm = (mu + 1. - q) / 2.;
+    let m = n / 2;
+    let mut u: Vec<Complex<f64>> = (0..=m)
+        .map(|i| {
+            let y = PI * i as f64 / (n as f64 * dln);
+            let v = loggamma(Complex::new(xm, y));
+            let w = loggamma(Complex::new(xp, y));
+            Complex::new(w.re - v.re + LN_2 * q, w.im + v.im + 2. * y * (LN_2 - lnkr)).exp()
+        })
+        .collect();
+    // The last coefficient is real.
+    u[m].im = 0.;
+
+    if !u[0].re.is_finite() {
+        // Gamma(xp) is infinite, and the ratio is finite only if Gamma(xm) is infinite too.
+        let pole = |x: f64| x <= 0. && x == x.round();
+        u[0] = match (pole(xp), pole(xm)) {
+            (true, true) => {
+                let sign = if ((xm - xp) as i64).rem_euclid(2) == 0 {
+                    1.
+                } else {
+                    -1.
+                };
+                let ratio =
+                    loggamma(Complex::from(1. - xm)).re - loggamma(Complex::from(1. - xp)).re;
+                Complex::from(sign * 2f64.powf(q) * ratio.exp())
+            }
+            _ => Complex::from(f64::INFINITY),
+        };
+    }
+    // A singular transform (or inverse) gets the coefficient that is (potentially) correct.
+    if u[0].re.is_infinite() && !inverse {
+        u[0] = Complex::from(0.);
+    } else if u[0].re == 0. && inverse {
+        u[0] = Complex::from(f64::INFINITY);
+    }
+    u.into_iter()
+        .map(|u| Complex::new(F::from(u.re).unwrap(), F::from(u.im).unwrap()))
+        .collect()
+}
+
+/// Principal branch of the logarithm of the gamma function for complex `z`, up to multiples of
+/// ``2 pi i`` in the imaginary part.
+///
+/// Shifts `z` up with ``loggamma(z) = loggamma(z + 1) - ln(z)`` and sums the Stirling series.
+/// The poles at the non-positive integers give an infinite real part.
+fn loggamma(z: Complex<f64>) -> Complex<f64> {
+    const COEFFS: [f64; 8] = [
+        1. / 12.,
+        -1. / 360.,
+        1. / 1260.,
+        -1. / 1680.,
+        1. / 1188.,
+        -691. / 360360.,
+        1. / 156.,
+        -3617. / 122400.,
+    ];
+    let mut z = z;
+    let mut shift = Complex::new(0., 0.);
+    while z.re < 15. {
+        shift += z.ln();
+        z += 1.;
+    }
+    let z2 = (z * z).inv();
+    let mut zk = z.inv();
+    let mut series = Complex::new(0., 0.);
+    for c in COEFFS {
+        series += zk * c;
+        zk *= z2;
+    }
+    (z - 0.5) * z.ln() - z + 0.5 * (2. * PI).ln() + series - shift
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array2};
+
+    #[track_caller]
+    fn assert_vec_eq(a: &[f64], b: &[f64], epsilon: f64) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = epsilon);
+        }
+    }
+
+    #[test]
+    fn log_gamma() {
+        let cases = [
+            (
+                Complex::new(1., 1.),
+                Complex::new(-0.650923199301856, -0.301640320467533),
+            ),
+            (
+                Complex::new(-2.5, 3.),
+                Complex::new(-7.47823604205032, -5.72610427191039),
+            ),
+            (
+                Complex::new(0.5, 100.),
+                Complex::new(-156.160694146285, 360.517435267906),
+            ),
+        ];
+        for (z, expected) in cases {
+            let w = loggamma(z);
+            assert_abs_diff_eq!(w.re, expected.re, epsilon = 1e-12);
+            // Only the exponential and the imaginary part modulo 2 pi are used.
+            let d = (w.im - expected.im) / (2. * PI);
+            assert_abs_diff_eq!(d, d.round(), epsilon = 1e-12);
+        }
+        assert_abs_diff_eq!(
+            loggamma(Complex::from(0.5)).re,
+            PI.sqrt().ln(),
+            epsilon = 1e-14
+        );
+        assert_abs_diff_eq!(loggamma(Complex::from(6.)).re, 120f64.ln(), epsilon = 1e-13);
+        assert!(loggamma(Complex::from(-2.)).re.is_infinite());
+    }
+
+    #[test]
+    fn transforms() {
+        assert_abs_diff_eq!(
+            fhtoffset(0.1, 0.5, None, None),
+            -0.027642801500699223,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            fhtoffset(0.05, 1.0, Some(0.3), Some(0.4)),
+            0.31535746155389965,
+            epsilon = 1e-12
+        );
+
+        let a = array![1.0, 2.0, -1.0, 0.5, 3.0, 0.0, -2.0];
+        let cases = [
+            (
+                0.5,
+                None,
+                None,
+                [
+                    -0.6666894158731927,
+                    2.379400201148908,
+                    0.9567619165044479,
+                    -2.2765645272261246,
+                    0.27438254080347285,
+                    2.6314862000241503,
+                    0.20122308461833693,
+                ],
+            ),
+            (
+                1.5,
+                Some(0.1),
+                Some(0.3),
+                [
+                    -3.5837643875907226,
+                    3.174834131800703,
+                    4.525233890642337,
+                    -2.7717593596748906,
+                    -1.6284885075969564,
+                    3.077684593306448,
+                    1.2145941700828242,
+                ],
+            ),
+        ];
+        for (mu, offset, bias, expected) in cases {
+            let y = fht(&a, 0.2, mu, offset, bias, None, None).unwrap();
+            assert_vec_eq(y.as_slice().unwrap(), &expected, 1e-10);
+            let b = ifht(&y, 0.2, mu, offset, bias, None, None).unwrap();
+            assert_vec_eq(b.as_slice().unwrap(), a.as_slice().unwrap(), 1e-10);
+        }
+
+        // Even length, along the last axis of each row.
+        let a = array![1.0, 2.0, -1.0, 0.5, 3.0, 0.0, -2.0, 1.0];
+        let expected = [
+            -0.2725577691853285,
+            0.4817384717410085,
+            1.2337554732505835,
+            0.4160281893212514,
+            0.5263463190736511,
+            1.7711194926204288,
+            2.127668701642207,
+            2.2020191619883733,
+        ];
+        let rows = Array2::from_shape_fn((2, 8), |(i, j)| a[j] * (i + 1) as f64);
+        let y = fht(&rows, 0.3, 0.0, Some(-0.2), Some(-0.5), None, None).unwrap();
+        for (i, row) in y.outer_iter().enumerate() {
+            let expected: Vec<f64> = expected.iter().map(|e| e * (i + 1) as f64).collect();
+            assert_vec_eq(row.as_slice().unwrap(), &expected, 1e-10);
+        }
+        // A reused planner, on several threads, gives the same transforms.
+        let mut planner = Planner::new();
+        for _ in 0..2 {
+            let z = fht(
+                &rows,
+                0.3,
+                0.0,
+                Some(-0.2),
+                Some(-0.5),
+                Some(2),
+                Some(&mut planner),
+            );
+            assert_eq!(z.unwrap(), y);
+            let b = ifht(
+                &y,
+                0.3,
+                0.0,
+                Some(-0.2),
+                Some(-0.5),
+                Some(2),
+                Some(&mut planner),
+            );
+            assert_vec_eq(
+                b.unwrap().as_slice().unwrap(),
+                rows.as_slice().unwrap(),
+                1e-10,
+            );
+        }
+        assert!(fht(&rows, 0.3, 0.0, None, None, Some(0), None).is_err());
+
+        assert!(fht(&Array1::<f64>::zeros(0), 0.1, 0., None, None, None, None).is_err());
+    }
+
+    #[test]
+    fn singular() {
+        // The bias weights of the input points, with which the biased input is constant.
+        let ones = Array1::from_elem(4, 1.);
+        let w = Array1::from_shape_fn(4, |j| (-0.5 * (j as f64 - 1.5) * 0.1).exp());
+
+        // Gamma(xp) = Gamma(0) is infinite, so the zero frequency coefficient is dropped, and
+        // with it the constant biased input.
+        let y = fht(&(&ones / &w), 0.1, -1.5, None, Some(0.5), None, None).unwrap();
+        assert!(y.iter().all(|y| y.abs() < 1e-12));
+        // Gamma(xm) = Gamma(0) is infinite, so it is the inverse that drops the zero frequency.
+        let u = fhtcoeff(4, 0.1, -0.5, 0., 0.5, true);
+        assert!(u[0].re.is_infinite());
+        let y = ifht(&(&ones * &w), 0.1, -0.5, None, Some(0.5), None, None).unwrap();
+        assert!(y.iter().all(|y| y.abs() < 1e-12));
+
+        // Both are infinite: 2^q Gamma(0) / Gamma(-2) = 2^q poch(-2, 2) = 2^q * 2.
+        let u = fhtcoeff(4, 0.1, -3., 0., 2., false);
+        assert_abs_diff_eq!(u[0].re, 8., epsilon = 1e-12);
+        let u = fhtcoeff(4, 0.1, -2., 0., 1., false);
+        assert_abs_diff_eq!(u[0].re, -2., epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,376 @@
+use super::init_nd_shape_and_axes;
+use ndarray::{Array, Array1, ArrayBase, Axis, Data, Dimension, Slice};
+use num_traits::Float;
+use sci_rs_core::Result;
+
+/// Return the Discrete Fourier Transform sample frequencies.
+///
+/// The returned float array `f` contains the frequency bin centers in cycles per unit of the
+/// sample spacing (with zero at the start). For instance, if the sample spacing is in seconds,
+/// then the frequency unit is cycles/second.
+///
+/// Given a window length `n` and a sample spacing `d`:
+///
+/// ```text
+/// f = [0, 1, ...,   n/2-1,     -n/2, ..., -1] / (d*n)   if n is even
+/// f = [0, 1, ..., (n-1)/2, -(n-1)/2, ..., -1] / (d*n)   if n is odd
+/// ```
+///
+/// ## Parameters
+/// * `n`: int
+///   Window length.
+/// * `d`: scalar, optional
+///   Sample spacing (inverse of the sampling rate). Defaults to 1.
+///
+/// ## Returns
+/// * `f`: ndarray
+///   Array of length `n` containing the sample frequencies.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import scipy.fft
+/// >>> scipy.fft.fftfreq(8, d=0.1)
+/// array([ 0.  ,  1.25,  2.5 , ..., -3.75, -2.5 , -1.25])
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::fft::fftfreq;
+///
+/// let f = fftfreq(8, Some(0.1));
+/// assert_eq!(f, array![0., 1.25, 2.5, 3.75, -5., -3.75, -2.5, -1.25]);
+/// ```
+pub fn fftfreq<F: Float>(n: usize, d: Option<F>) -> Array1<F> {
+    let val = (d.unwrap_or_else(F::one) * F::from(n).unwrap()).recip();
+    let positive = n.div_ceil(2);
+    Array1::from_shape_fn(n, |i| {
+        if i < positive {
+            F::from(i).unwrap() * val
+        } else {
+            -F::from(n - i).unwrap() * val
+        }
+    })
+}
+
+/// Return the Discrete Fourier Transform sample frequencies (for usage with [rfft](super::rfft),
+/// [irfft](super::irfft)).
+///
+/// The returned float array `f` contains the frequency bin centers in cycles per unit of the
+/// sample spacing (with zero at the start). For instance, if the sample spacing is in seconds,
+/// then the frequency unit is cycles/second.
+///
+/// Given a window length `n` and a sample spacing `d`:
+///
+/// ```text
+/// f = [0, 1, ...,     n/2-1,     n/2] / (d*n)   if n is even
+/// f = [0, 1, ..., (n-1)/2-1, (n-1)/2] / (d*n)   if n is odd
+/// ```
+///
+/// Unlike [fftfreq] the Nyquist frequency component is considered to be positive.
+///
+/// ## Parameters
+/// * `n`: int
+///   Window length.
+/// * `d`: scalar, optional
+///   Sample spacing (inverse of the sampling rate). Defaults to 1.
+///
+/// ## Returns
+/// * `f`: ndarray
+///   Array of length ``n/2 + 1`` containing the sample frequencies.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import scipy.fft
+/// >>> scipy.fft.rfftfreq(10, d=0.1)
+/// array([0., 1., 2., 3., 4., 5.])
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::fft::rfftfreq;
+///
+/// assert_eq!(rfftfreq(10, Some(0.1)), array![0., 1., 2., 3., 4., 5.]);
+/// ```
+pub fn rfftfreq<F: Float>(n: usize, d: Option<F>) -> Array1<F> {
+    let val = (d.unwrap_or_else(F::one) * F::from(n).unwrap()).recip();
+    Array1::from_shape_fn(n / 2 + 1, |i| F::from(i).unwrap() * val)
+}
+
+/// Shift the zero-frequency component to the center of the spectrum.
+///
+/// This function swaps half-spaces for all axes listed (defaults to all). Note that ``y[0]`` is
+/// the Nyquist component only if ``len(x)`` is even.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   Input array.
+/// * `axes`: int or shape tuple, optional
+///   Axes over which to shift. Default is None, which shifts all axes.
+///
+/// ## Returns
+/// * `y`: ndarray
+///   The shifted array.
+///
+/// ## Errors
+/// [Error::InvalidArg](sci_rs_core::Error::InvalidArg) if an element of `axes` is out of range or
+/// repeated.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> freqs = np.fft.fftfreq(10, 0.1)
+/// >>> freqs
+/// array([ 0.,  1.,  2., ..., -3., -2., -1.])
+/// >>> np.fft.fftshift(freqs)
+/// array([-5., -4., -3., -2., -1.,  0.,  1.,  2.,  3.,  4.])
+/// >>> freqs = np.fft.fftfreq(9, d=1./9).reshape(3, 3)
+/// >>> np.fft.fftshift(freqs, axes=(1,))
+/// array([[ 2.,  0.,  1.],
+///        [-4.,  3.,  4.],
+///        [-1., -3., -2.]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::fft::{fftfreq, fftshift};
+///
+/// let freqs = fftfreq(10, Some(0.1));
+/// assert_eq!(
+///     fftshift(&freqs, None).unwrap(),
+///     array![-5., -4., -3., -2., -1., 0., 1., 2., 3., 4.]
+/// );
+///
+/// let freqs = fftfreq(9, Some(1. / 9.)).into_shape_with_order((3, 3)).unwrap();
+/// assert_eq!(
+///     fftshift(&freqs, Some(&[1])).unwrap(),
+///     array![[2., 0., 1.], [-4., 3., 4.], [-1., -3., -2.]]
+/// );
+/// ```
+pub fn fftshift<T, S, D>(x: &ArrayBase<S, D>, axes: Option<&[isize]>) -> Result<Array<T, D>>
+where
+    T: Clone,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    roll_half(x, axes, false)
+}
+
+/// The inverse of [fftshift]. Although identical for even-length `x`, the functions differ by
+/// one sample for odd-length `x`.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   Input array.
+/// * `axes`: int or shape tuple, optional
+///   Axes over which to calculate. Defaults to None, which shifts all axes.
+///
+/// ## Returns
+/// * `y`: ndarray
+///   The shifted array.
+///
+/// ## Errors
+/// [Error::InvalidArg](sci_rs_core::Error::InvalidArg) if an element of `axes` is out of range or
+/// repeated.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> freqs = np.fft.fftfreq(9, d=1./9).reshape(3, 3)
+/// >>> np.fft.ifftshift(np.fft.fftshift(freqs))
+/// array([[ 0.,  1.,  2.],
+///        [ 3.,  4., -4.],
+///        [-3., -2., -1.]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::fft::{fftfreq, fftshift, ifftshift};
+///
+/// let freqs = fftfreq(9, Some(1. / 9.)).into_shape_with_order((3, 3)).unwrap();
+/// let shifted = fftshift(&freqs, None).unwrap();
+/// assert_eq!(ifftshift(&shifted, None).unwrap(), freqs);
+/// ```
+pub fn ifftshift<T, S, D>(x: &ArrayBase<S, D>, axes: Option<&[isize]>) -> Result<Array<T, D>>
+where
+    T: Clone,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    roll_half(x, axes, true)
+}
+
+/// Find the next fast size of input data to `fft`, for zero-padding, etc.
+///
+/// The FFTs of rustfft are fastest for lengths that are products of small primes, and can be
+/// orders of magnitude slower for large prime lengths. This returns the next composite of the
+/// prime factors 2, 3, 5, 7 and 11 greater than or equal to `target` (11-smooth numbers). For
+/// real transforms only the prime factors 2, 3 and 5 are allowed (5-smooth numbers, also known
+/// as regular numbers), as in Scipy.
+///
+/// ## Parameters
+/// * `target`: int
+///   Length to start searching from.
+/// * `real`: bool, optional
+///   True if the FFT involves real input or output (e.g., [rfft](super::rfft) or
+///   [irfft](super::irfft) but not [fft](super::fft)). Defaults to False.
+///
+/// ## Returns
+/// * `out`: int
+///   The smallest fast length greater than or equal to `target`.
+///
+/// ## Notes
+/// Lengths up to 6 are returned as is, since they are all 5-smooth.
+///
+/// ## Examples
+/// On a particular machine, an FFT of prime length takes many times longer than one of the
+/// next fast length after zero-padding:
+/// ```custom,{class=language-python}
+/// >>> from scipy import fft
+/// >>> import numpy as np
+/// >>> rng = np.random.default_rng()
+/// >>> min_len = 93059  # prime length is worst case for speed
+/// >>> a = rng.standard_normal(min_len)
+/// >>> b = fft.fft(a)
+/// >>> fft.next_fast_len(min_len, real=True)
+/// 93312
+/// >>> b = fft.fft(a, 93312)
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::fft::next_fast_len;
+///
+/// assert_eq!(next_fast_len(93059, Some(true)), 93312);
+/// assert_eq!(next_fast_len(93059, None), 93170);
+/// assert_eq!(next_fast_len(10007, Some(true)), 10125);
+/// assert_eq!(next_fast_len(10007, None), 10080);
+/// ```
+pub fn next_fast_len(target: usize, real: Option<bool>) -> usize {
+    if target <= 6 {
+        return target;
+    }
+    let odd_factors: &[usize] = if real.unwrap_or(false) {
+        &[3, 5]
+    } else {
+        &[3, 5, 7, 11]
+    };
+    let mut best = target.checked_next_power_of_two().unwrap_or(usize::MAX);
+    smooth_search(target, 1, odd_factors, &mut best);
+    best
+}
+
+/// Lower `best` to the smallest product of a power of two with `odd` and powers of
+/// `odd_factors` that is at least `target`. Each product of the odd factors is visited once, in
+/// nondecreasing order of the factors.
+fn smooth_search(target: usize, odd: usize, odd_factors: &[usize], best: &mut usize) {
+    let mut n = odd;
+    while n < target {
+        n = n.saturating_mul(2);
+    }
+    *best = (*best).min(n);
+    for (i, &p) in odd_factors.iter().enumerate() {
+        match odd.checked_mul(p) {
+            Some(odd) if odd < *best => smooth_search(target, odd, &odd_factors[i..], best),
+            _ => {}
+        }
+    }
+}
+
+/// Roll each of `axes` by half its length, rounded down, or by minus that for the `inverse`.
+fn roll_half<T, S, D>(
+    x: &ArrayBase<S, D>,
+    axes: Option<&[isize]>,
+    inverse: bool,
+) -> Result<Array<T, D>>
+where
+    T: Clone,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let (_, axes) = init_nd_shape_and_axes(x.shape(), None, axes)?;
+    let mut y = x.to_owned();
+    for d in axes {
+        let axis = Axis(d);
+        let n = y.len_of(axis);
+        // Rolling right by the shift moves element n - shift to the front.
+        let split = if inverse { n / 2 } else { n - n / 2 };
+        let mut rolled = y.clone();
+        rolled
+            .slice_axis_mut(axis, Slice::from(..n - split))
+            .assign(&y.slice_axis(axis, Slice::from(split..)));
+        rolled
+            .slice_axis_mut(axis, Slice::from(n - split..))
+            .assign(&y.slice_axis(axis, Slice::from(..split)));
+        y = rolled;
+    }
+    Ok(y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{array, Array2};
+
+    #[test]
+    fn frequencies() {
+        assert_eq!(fftfreq::<f64>(5, None), array![0., 0.2, 0.4, -0.4, -0.2]);
+        assert_eq!(fftfreq::<f64>(1, None), array![0.]);
+        assert!(fftfreq::<f64>(0, None).is_empty());
+        assert_eq!(rfftfreq(5, Some(0.5)), array![0., 0.4, 0.8]);
+        assert_eq!(rfftfreq::<f64>(4, None), array![0., 0.25, 0.5]);
+    }
+
+    #[test]
+    fn fast_lengths() {
+        let is_smooth = |mut n: usize, primes: &[usize]| {
+            for p in primes {
+                while n.is_multiple_of(*p) {
+                    n /= p;
+                }
+            }
+            n == 1
+        };
+        for (target, real) in [
+            (7, None),
+            (13, Some(false)),
+            (13, Some(true)),
+            (97, Some(true)),
+        ] {
+            let primes: &[usize] = if real == Some(true) {
+                &[2, 3, 5]
+            } else {
+                &[2, 3, 5, 7, 11]
+            };
+            let n = next_fast_len(target, real);
+            assert!(is_smooth(n, primes));
+            assert!((target..n).all(|m| !is_smooth(m, primes)));
+        }
+        assert_eq!(next_fast_len(0, None), 0);
+        assert_eq!(next_fast_len(5, Some(true)), 5);
+        assert_eq!(next_fast_len(97, None), 98);
+        assert_eq!(next_fast_len(97, Some(true)), 100);
+        assert_eq!(next_fast_len(1021, None), 1024);
+        assert_eq!(next_fast_len(2 * 3usize.pow(12) + 1, None), 1063125);
+        assert_eq!(next_fast_len(2 * 3usize.pow(12) + 1, Some(true)), 1080000);
+    }
+
+    #[test]
+    fn shifts() {
+        let x = array![0, 1, 2, 3, 4];
+        assert_eq!(fftshift(&x, None).unwrap(), array![3, 4, 0, 1, 2]);
+        assert_eq!(ifftshift(&x, None).unwrap(), array![2, 3, 4, 0, 1]);
+        let x = Array2::from_shape_fn((2, 3), |(i, j)| i * 3 + j);
+        assert_eq!(fftshift(&x, None).unwrap(), array![[5, 3, 4], [2, 0, 1]]);
+        assert_eq!(
+            fftshift(&x, Some(&[0])).unwrap(),
+            array![[3, 4, 5], [0, 1, 2]]
+        );
+        assert_eq!(
+            ifftshift(&x, Some(&[-1])).unwrap(),
+            array![[1, 2, 0], [4, 5, 3]]
+        );
+        for axes in [None, Some(&[0][..]), Some(&[1, 0][..])] {
+            assert_eq!(ifftshift(&fftshift(&x, axes).unwrap(), axes).unwrap(), x);
+        }
+        assert!(fftshift(&x, Some(&[2])).is_err());
+        assert!(ifftshift(&x, Some(&[-3])).is_err());
+    }
+}
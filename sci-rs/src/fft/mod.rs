@@ -0,0 +1,14 @@
+//! Functions from the [`scipy.fft`](https://docs.scipy.org/doc/scipy/reference/fft.html) module,
+//! computed with [rustfft](https://docs.rs/rustfft).
+
+mod basic;
+mod fftlog;
+mod helper;
+mod planner;
+mod realtransforms;
+
+pub use basic::*;
+pub use fftlog::*;
+pub use helper::*;
+pub use planner::*;
+pub use realtransforms::*;
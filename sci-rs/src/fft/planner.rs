@@ -0,0 +1,79 @@
+use realfft::{ComplexToReal, RealFftPlanner, RealToComplex};
+use rustfft::{Fft, FftDirection, FftNum, FftPlanner};
+use std::sync::Arc;
+
+/// A cache of FFT plans that can be reused across calls of the functions of this module.
+///
+/// Planning an FFT chooses the algorithm for its length and precomputes the twiddle factors,
+/// which can cost as much as the transform itself for small inputs. Every function of this module
+/// accepts an optional `plan`: without it, a new planner is created and discarded on each call,
+/// while a planner passed in keeps the plans of every length and direction it has made, so batch
+/// workloads only plan each distinct transform once. The float type of the transforms is the type
+/// parameter, a planner of `f32` being separate from one of `f64`.
+///
+/// This is the counterpart of the `plan` argument of the `scipy.fft` functions, and of
+/// [FftPlanner] and [RealFftPlanner], which hold the complex and real plans.
+///
+/// ## Examples
+/// ```
+/// use ndarray::Array2;
+/// use sci_rs::fft::{dct, rfft, Planner};
+///
+/// let mut planner = Planner::new();
+/// let x = Array2::from_shape_fn((4, 64), |(i, j)| (i * j) as f64);
+/// for _ in 0..100 {
+///     // The plans of length 64 are made on the first iteration and reused afterwards.
+///     let spectrum = rfft(&x, None, None, None, None, Some(&mut planner)).unwrap();
+///     let cosines = dct(&x, None, None, None, None, None, Some(&mut planner)).unwrap();
+///     assert_eq!(spectrum.dim(), (4, 33));
+///     assert_eq!(cosines.dim(), (4, 64));
+/// }
+/// ```
+pub struct Planner<F: FftNum> {
+    complex: FftPlanner<F>,
+    real: RealFftPlanner<F>,
+}
+
+impl<F: FftNum> Planner<F> {
+    /// Create a planner without any plans.
+    pub fn new() -> Self {
+        Self {
+            complex: FftPlanner::new(),
+            real: RealFftPlanner::new(),
+        }
+    }
+
+    /// Call `f` with the given `planner`, or with a new one.
+    pub(crate) fn with<R>(planner: Option<&mut Self>, f: impl FnOnce(&mut Self) -> R) -> R {
+        match planner {
+            Some(planner) => f(planner),
+            None => f(&mut Self::new()),
+        }
+    }
+
+    /// Complex FFT of length `len`, or its `inverse`.
+    pub(crate) fn plan_fft(&mut self, len: usize, inverse: bool) -> Arc<dyn Fft<F>> {
+        let direction = if inverse {
+            FftDirection::Inverse
+        } else {
+            FftDirection::Forward
+        };
+        self.complex.plan_fft(len, direction)
+    }
+
+    /// Real to complex FFT of length `len`.
+    pub(crate) fn plan_rfft(&mut self, len: usize) -> Arc<dyn RealToComplex<F>> {
+        self.real.plan_fft_forward(len)
+    }
+
+    /// Complex to real inverse FFT of length `len`.
+    pub(crate) fn plan_irfft(&mut self, len: usize) -> Arc<dyn ComplexToReal<F>> {
+        self.real.plan_fft_inverse(len)
+    }
+}
+
+impl<F: FftNum> Default for Planner<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,1125 @@
+use super::{check_points, check_workers, for_each_lane, init_nd_shape_and_axes, FftNorm, Planner};
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use core::f64::consts::{PI, SQRT_2};
+use nalgebra::Complex;
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension};
+use num_traits::{Float, Zero};
+use rustfft::FftNum;
+use sci_rs_core::{Error, Result};
+
+/// Return the Discrete Cosine Transform of arbitrary type sequence x.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   The input array.
+/// * `type_`: {1, 2, 3, 4}, optional
+///   Type of the DCT (see Notes). Default type is 2.
+/// * `n`: int, optional
+///   Length of the transform. If ``n < x.shape[axis]``, `x` is truncated. If
+///   ``n > x.shape[axis]``, `x` is zero-padded. The default results in ``n = x.shape[axis]``.
+/// * `axis`: int, optional
+///   Axis along which the dct is computed; the default is over the last axis (i.e.,
+///   ``axis=-1``).
+/// * `norm`: [FftNorm], optional
+///   Normalization mode (see Notes). Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `y`: ndarray of real
+///   The transformed input array.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `type_` is not 1, 2, 3 or 4, if `axis` is out of range, or if the
+/// length of the transform is zero, or one for the type 1 DCT.
+///
+/// ## Notes
+/// For a single dimension array `x`, ``dct(x, norm='ortho')`` is equal to MATLAB
+/// ``dct(x)``.
+///
+/// There are, theoretically, 8 types of the DCT, only the first 4 types are implemented in
+/// SciPy. 'The' DCT generally refers to DCT type 2, and 'the' Inverse DCT generally refers to DCT
+/// type 3.
+///
+/// **Type I**
+///
+/// There are several definitions of the DCT-I; we use the following (for
+/// ``norm=Backward``)
+///
+/// ``y[k] = x[0] + (-1)**k x[N-1] + 2 * sum(x[n] * cos(pi * k * n / (N-1)), n = 1, ..., N-2)``
+///
+/// If ``norm=Ortho``, ``x[0]`` and ``x[N-1]`` are multiplied by a scaling factor of
+/// ``sqrt(2)``, and ``y[0]`` and ``y[N-1]`` are divided by ``sqrt(2)``, and the result is scaled
+/// by ``sqrt(1/(2(N-1)))``, making the transform orthonormal. The DCT-I is only supported for
+/// input size > 1.
+///
+/// **Type II**
+///
+/// There are several definitions of the DCT-II; we use the following (for
+/// ``norm=Backward``)
+///
+/// ``y[k] = 2 * sum(x[n] * cos(pi * k * (2n+1) / (2N)), n = 0, ..., N-1)``
+///
+/// If ``norm=Ortho``, ``y[k]`` is multiplied by a scaling factor ``f``
+///
+/// ``f = sqrt(1/(4N))`` if ``k = 0``, ``f = sqrt(1/(2N))`` otherwise,
+///
+/// which makes the corresponding matrix of coefficients orthonormal (``O @ O.T = np.eye(N)``).
+///
+/// **Type III**
+///
+/// There are several definitions, we use the following (for ``norm=Backward``)
+///
+/// ``y[k] = x[0] + 2 * sum(x[n] * cos(pi * (2k+1) * n / (2N)), n = 1, ..., N-1)``
+///
+/// or, for ``norm=Ortho``
+///
+/// ``y[k] = x[0] / sqrt(N) + sqrt(2/N) * sum(x[n] * cos(pi * (2k+1) * n / (2N)), n = 1, ..., N-1)``
+///
+/// The (unnormalized) DCT-III is the inverse of the (unnormalized) DCT-II, up to a factor
+/// ``2N``. The orthonormalized DCT-III is exactly the inverse of the orthonormalized DCT-II.
+///
+/// **Type IV**
+///
+/// There are several definitions of the DCT-IV; we use the following (for
+/// ``norm=Backward``)
+///
+/// ``y[k] = 2 * sum(x[n] * cos(pi * (2k+1) * (2n+1) / (4N)), n = 0, ..., N-1)``
+///
+/// If ``norm=Ortho``, ``y[k]`` is multiplied by a scaling factor ``f = sqrt(1/(2N))``.
+///
+/// With ``norm=Forward`` the transforms are instead scaled by ``1/(2(N-1))`` for the DCT-I and
+/// ``1/(2N)`` for the other types, and the inverse transforms are not scaled.
+///
+/// The transforms are computed with a complex FFT of twice the length of the input.
+///
+/// ## References
+/// 1. 'A Fast Cosine Transform in One and Two Dimensions', by J. Makhoul, `IEEE Transactions on
+///    acoustics, speech and signal processing` vol. 28(1), pp. 27-34,
+///    <https://doi.org/10.1109/TASSP.1980.1163351> (1980).
+/// 2. Wikipedia, "Discrete cosine transform",
+///    <https://en.wikipedia.org/wiki/Discrete_cosine_transform>
+///
+/// ## Examples
+/// The Type 1 DCT is equivalent to the FFT (though faster) for real, even-symmetrical inputs.
+/// The output is also real and even-symmetrical. Half of the FFT input is used to generate half
+/// of the FFT output:
+/// ```custom,{class=language-python}
+/// >>> from scipy.fft import fft, dct
+/// >>> import numpy as np
+/// >>> fft(np.array([4., 3., 5., 10., 5., 3.])).real
+/// array([ 30.,  -8.,   6.,  -2.,   6.,  -8.])
+/// >>> dct(np.array([4., 3., 5., 10.]), 1)
+/// array([ 30.,  -8.,   6.,  -2.])
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::fft::dct;
+///
+/// let y = dct(&array![4., 3., 5., 10.], Some(1), None, None, None, None, None).unwrap();
+/// for (y, e) in y.iter().zip([30f64, -8., 6., -2.]) {
+///     assert!((y - e).abs() < 1e-12);
+/// }
+/// ```
+pub fn dct<F, S, D>(
+    x: &ArrayBase<S, D>,
+    type_: Option<usize>,
+    n: Option<usize>,
+    axis: Option<isize>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, x)?;
+    let type_ = check_type(type_)?;
+    let workers = check_workers(workers)?;
+    Planner::with(plan, |planner| {
+        r2r(
+            x,
+            false,
+            type_,
+            n,
+            axis,
+            norm.unwrap_or_default(),
+            false,
+            workers,
+            planner,
+        )
+    })
+}
+
+/// Return the Inverse Discrete Cosine Transform of an arbitrary type sequence.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   The input array.
+/// * `type_`: {1, 2, 3, 4}, optional
+///   Type of the DCT (see Notes). Default type is 2.
+/// * `n`: int, optional
+///   Length of the transform. If ``n < x.shape[axis]``, `x` is truncated. If
+///   ``n > x.shape[axis]``, `x` is zero-padded. The default results in ``n = x.shape[axis]``.
+/// * `axis`: int, optional
+///   Axis along which the idct is computed; the default is over the last axis (i.e.,
+///   ``axis=-1``).
+/// * `norm`: [FftNorm], optional
+///   Normalization mode (see Notes). Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `idct`: ndarray of real
+///   The transformed input array.
+///
+/// ## Errors
+/// As for [dct].
+///
+/// ## Notes
+/// For a single dimension array `x`, ``idct(x, norm=Ortho)`` is equal to MATLAB ``idct(x)``.
+///
+/// 'The' IDCT is the IDCT-II, which is the same as the normalized DCT-III.
+///
+/// The IDCT is equivalent to a normal DCT except for the normalization and type. DCT type 1 and
+/// 4 are their own inverse and DCTs 2 and 3 are each other's inverses.
+///
+/// ## Examples
+/// The Type 1 DCT is equivalent to the DFT for real, even-symmetrical inputs. The output is also
+/// real and even-symmetrical. Half of the IFFT input is used to generate half of the IFFT output:
+/// ```custom,{class=language-python}
+/// >>> from scipy.fft import ifft, idct
+/// >>> import numpy as np
+/// >>> ifft(np.array([ 30.,  -8.,   6.,  -2.,   6.,  -8.])).real
+/// array([  4.,   3.,   5.,  10.,   5.,   3.])
+/// >>> idct(np.array([ 30.,  -8.,   6.,  -2.]), 1)
+/// array([  4.,   3.,   5.,  10.])
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::fft::idct;
+///
+/// let x = idct(&array![30., -8., 6., -2.], Some(1), None, None, None, None, None).unwrap();
+/// for (x, e) in x.iter().zip([4f64, 3., 5., 10.]) {
+///     assert!((x - e).abs() < 1e-12);
+/// }
+/// ```
+pub fn idct<F, S, D>(
+    x: &ArrayBase<S, D>,
+    type_: Option<usize>,
+    n: Option<usize>,
+    axis: Option<isize>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, x)?;
+    let type_ = check_type(type_)?;
+    let workers = check_workers(workers)?;
+    Planner::with(plan, |planner| {
+        r2r(
+            x,
+            false,
+            type_,
+            n,
+            axis,
+            norm.unwrap_or_default(),
+            true,
+            workers,
+            planner,
+        )
+    })
+}
+
+/// Return multidimensional Discrete Cosine Transform along the specified axes.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   The input array.
+/// * `type_`: {1, 2, 3, 4}, optional
+///   Type of the DCT (see Notes of [dct]). Default type is 2.
+/// * `s`: sequence of ints, optional
+///   The shape of the result. If both `s` and `axes` (see below) are None, `s` is
+///   ``x.shape``; if `s` is None but `axes` is not None, then `s` is ``x.shape[axes]``. If
+///   ``s[i] > x.shape[i]``, the ith dimension of the input is padded with zeros. If
+///   ``s[i] < x.shape[i]``, the ith dimension of the input is truncated to length ``s[i]``.
+/// * `axes`: sequence of ints, optional
+///   Axes over which the DCT is computed. If not given, the last ``len(s)`` axes are used, or
+///   all axes if `s` is also not specified.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode (see Notes of [dct]). Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `y`: ndarray of real
+///   The transformed input array.
+///
+/// ## Errors
+/// As for [dct] along each axis, and [Error::InvalidArg] if `s` and `axes` are invalid as for
+/// [fftn](super::fftn).
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy.fft import dctn, idctn
+/// >>> rng = np.random.default_rng()
+/// >>> y = rng.standard_normal((16, 16))
+/// >>> np.allclose(y, idctn(dctn(y)))
+/// True
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::Array2;
+/// use sci_rs::fft::{dctn, idctn};
+///
+/// let y = Array2::from_shape_fn((16, 16), |(i, j)| ((i * 7 + j * 3) % 11) as f64 - 5.);
+/// let z = idctn(&dctn(&y, None, None, None, None, None, None).unwrap(), None, None, None, None, None, None).unwrap();
+/// assert!(y.iter().zip(&z).all(|(y, z)| (y - z).abs() < 1e-10));
+/// ```
+pub fn dctn<F, S, D>(
+    x: &ArrayBase<S, D>,
+    type_: Option<usize>,
+    s: Option<&[usize]>,
+    axes: Option<&[isize]>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    r2rn(x, false, type_, s, axes, norm, false, workers, plan)
+}
+
+/// Return multidimensional Inverse Discrete Cosine Transform along the specified axes.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   The input array.
+/// * `type_`: {1, 2, 3, 4}, optional
+///   Type of the DCT (see Notes of [dct]). Default type is 2.
+/// * `s`: sequence of ints, optional
+///   The shape of the result, see [dctn].
+/// * `axes`: sequence of ints, optional
+///   Axes over which the IDCT is computed. If not given, the last ``len(s)`` axes are used, or
+///   all axes if `s` is also not specified.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode (see Notes of [dct]). Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `y`: ndarray of real
+///   The transformed input array.
+///
+/// ## Errors
+/// As for [dctn].
+///
+/// ## Examples
+/// See [dctn].
+pub fn idctn<F, S, D>(
+    x: &ArrayBase<S, D>,
+    type_: Option<usize>,
+    s: Option<&[usize]>,
+    axes: Option<&[isize]>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    r2rn(x, false, type_, s, axes, norm, true, workers, plan)
+}
+
+/// Return the Discrete Sine Transform of arbitrary type sequence x.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   The input array.
+/// * `type_`: {1, 2, 3, 4}, optional
+///   Type of the DST (see Notes). Default type is 2.
+/// * `n`: int, optional
+///   Length of the transform. If ``n < x.shape[axis]``, `x` is truncated. If
+///   ``n > x.shape[axis]``, `x` is zero-padded. The default results in ``n = x.shape[axis]``.
+/// * `axis`: int, optional
+///   Axis along which the dst is computed; the default is over the last axis (i.e.,
+///   ``axis=-1``).
+/// * `norm`: [FftNorm], optional
+///   Normalization mode (see Notes). Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `dst`: ndarray of reals
+///   The transformed input array.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `type_` is not 1, 2, 3 or 4, if `axis` is out of range, or if the
+/// length of the transform is zero.
+///
+/// ## Notes
+/// There are, theoretically, 8 types of the DST for different combinations of even/odd
+/// boundary conditions and boundary off sets, only the first 4 types are implemented in SciPy.
+///
+/// **Type I**
+///
+/// There are several definitions of the DST-I; we use the following for ``norm=Backward``.
+/// DST-I assumes the input is odd around `n=-1` and `n=N`.
+///
+/// ``y[k] = 2 * sum(x[n] * sin(pi * (k+1) * (n+1) / (N+1)), n = 0, ..., N-1)``
+///
+/// The (unnormalized) DST-I is its own inverse, up to a factor ``2(N+1)``. The orthonormalized DST-I is exactly its own inverse.
+///
+/// Unlike the DCT-I, whose sum has the ``N - 1`` intervals between the end points, the DST-I
+/// leaves out the zeros of the sine at both ends, so the underlying FFT has the length
+/// ``2(N+1)``. This is also the length used by the `norm` scaling of the DST-I, and a single
+/// point transform is allowed.
+///
+/// **Type II**
+///
+/// There are several definitions of the DST-II; we use the following for ``norm=Backward``.
+/// DST-II assumes the input is odd around `n=-1/2` and `n=N-1/2`; the output is odd around
+/// ``k=-1`` and even around `k=N-1`
+///
+/// ``y[k] = 2 * sum(x[n] * sin(pi * (k+1) * (2n+1) / (2N)), n = 0, ..., N-1)``
+///
+/// If ``norm=Ortho``, ``y[k]`` is multiplied by a scaling factor ``f``
+///
+/// ``f = sqrt(1/(4N))`` if ``k = N-1``, ``f = sqrt(1/(2N))`` otherwise.
+///
+/// **Type III**
+///
+/// There are several definitions of the DST-III, we use the following (for
+/// ``norm=Backward``). DST-III assumes the input is odd around `n=-1` and even around `n=N-1`
+///
+/// ``y[k] = (-1)**k x[N-1] + 2 * sum(x[n] * sin(pi * (2k+1) * (n+1) / (2N)), n = 0, ..., N-2)``
+///
+/// The (unnormalized) DST-III is the inverse of the (unnormalized) DST-II, up to a factor
+/// ``2N``. The orthonormalized DST-III is exactly the inverse of the orthonormalized DST-II.
+///
+/// **Type IV**
+///
+/// There are several definitions of the DST-IV, we use the following (for ``norm=Backward``).
+/// DST-IV assumes the input is odd around `n=-0.5` and even around `n=N-0.5`
+///
+/// ``y[k] = 2 * sum(x[n] * sin(pi * (2k+1) * (2n+1) / (4N)), n = 0, ..., N-1)``
+///
+/// The (unnormalized) DST-IV is its own inverse, up to a factor ``2N``. The orthonormalized
+/// DST-IV is exactly its own inverse.
+///
+/// ## References
+/// 1. Wikipedia, "Discrete sine transform", <https://en.wikipedia.org/wiki/Discrete_sine_transform>
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> from scipy.fft import dst
+/// >>> dst([1., 2., 3., 4.], type=2, norm='ortho')
+/// array([ 4.61939766, -2.        ,  1.91341716, -1.        ])
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::fft::{dst, FftNorm};
+///
+/// let y = dst(&array![1., 2., 3., 4.], Some(2), None, None, Some(FftNorm::Ortho), None, None).unwrap();
+/// for (y, e) in y.iter().zip([4.61939766f64, -2., 1.91341716, -1.]) {
+///     assert!((y - e).abs() < 1e-8);
+/// }
+/// ```
+pub fn dst<F, S, D>(
+    x: &ArrayBase<S, D>,
+    type_: Option<usize>,
+    n: Option<usize>,
+    axis: Option<isize>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, x)?;
+    let type_ = check_type(type_)?;
+    let workers = check_workers(workers)?;
+    Planner::with(plan, |planner| {
+        r2r(
+            x,
+            true,
+            type_,
+            n,
+            axis,
+            norm.unwrap_or_default(),
+            false,
+            workers,
+            planner,
+        )
+    })
+}
+
+/// Return the Inverse Discrete Sine Transform of an arbitrary type sequence.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   The input array.
+/// * `type_`: {1, 2, 3, 4}, optional
+///   Type of the DST (see Notes of [dst]). Default type is 2.
+/// * `n`: int, optional
+///   Length of the transform. If ``n < x.shape[axis]``, `x` is truncated. If
+///   ``n > x.shape[axis]``, `x` is zero-padded. The default results in ``n = x.shape[axis]``.
+/// * `axis`: int, optional
+///   Axis along which the idst is computed; the default is over the last axis (i.e.,
+///   ``axis=-1``).
+/// * `norm`: [FftNorm], optional
+///   Normalization mode (see Notes of [dst]). Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `idst`: ndarray of real
+///   The transformed input array.
+///
+/// ## Errors
+/// As for [dst].
+///
+/// ## Notes
+/// 'The' IDST is the IDST-II, which is the same as the normalized DST-III.
+///
+/// The IDST is equivalent to a normal DST except for the normalization and type. DST type 1 and
+/// 4 are their own inverse and DSTs 2 and 3 are each other's inverses.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> from scipy.fft import dst, idst
+/// >>> idst(dst([1., 2., 3., 4.], type=1), type=1)
+/// array([1., 2., 3., 4.])
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::fft::{dst, idst};
+///
+/// let x = array![1., 2., 3., 4.];
+/// let y = idst(&dst(&x, Some(1), None, None, None, None, None).unwrap(), Some(1), None, None, None, None, None).unwrap();
+/// assert!(x.iter().zip(&y).all(|(x, y): (&f64, _)| (x - y).abs() < 1e-12));
+/// ```
+pub fn idst<F, S, D>(
+    x: &ArrayBase<S, D>,
+    type_: Option<usize>,
+    n: Option<usize>,
+    axis: Option<isize>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, x)?;
+    let type_ = check_type(type_)?;
+    let workers = check_workers(workers)?;
+    Planner::with(plan, |planner| {
+        r2r(
+            x,
+            true,
+            type_,
+            n,
+            axis,
+            norm.unwrap_or_default(),
+            true,
+            workers,
+            planner,
+        )
+    })
+}
+
+/// Return multidimensional Discrete Sine Transform along the specified axes.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   The input array.
+/// * `type_`: {1, 2, 3, 4}, optional
+///   Type of the DST (see Notes of [dst]). Default type is 2.
+/// * `s`: sequence of ints, optional
+///   The shape of the result. If both `s` and `axes` (see below) are None, `s` is
+///   ``x.shape``; if `s` is None but `axes` is not None, then `s` is ``x.shape[axes]``. If
+///   ``s[i] > x.shape[i]``, the ith dimension of the input is padded with zeros. If
+///   ``s[i] < x.shape[i]``, the ith dimension of the input is truncated to length ``s[i]``.
+/// * `axes`: sequence of ints, optional
+///   Axes over which the DST is computed. If not given, the last ``len(s)`` axes are used, or
+///   all axes if `s` is also not specified.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode (see Notes of [dst]). Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `y`: ndarray of real
+///   The transformed input array.
+///
+/// ## Errors
+/// As for [dst] along each axis, and [Error::InvalidArg] if `s` and `axes` are invalid as for
+/// [fftn](super::fftn).
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy.fft import dstn, idstn
+/// >>> rng = np.random.default_rng()
+/// >>> y = rng.standard_normal((16, 16))
+/// >>> np.allclose(y, idstn(dstn(y)))
+/// True
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::Array2;
+/// use sci_rs::fft::{dstn, idstn};
+///
+/// let y = Array2::from_shape_fn((16, 16), |(i, j)| ((i * 7 + j * 3) % 11) as f64 - 5.);
+/// let z = idstn(&dstn(&y, None, None, None, None, None, None).unwrap(), None, None, None, None, None, None).unwrap();
+/// assert!(y.iter().zip(&z).all(|(y, z)| (y - z).abs() < 1e-10));
+/// ```
+pub fn dstn<F, S, D>(
+    x: &ArrayBase<S, D>,
+    type_: Option<usize>,
+    s: Option<&[usize]>,
+    axes: Option<&[isize]>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    r2rn(x, true, type_, s, axes, norm, false, workers, plan)
+}
+
+/// Return multidimensional Inverse Discrete Sine Transform along the specified axes.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   The input array.
+/// * `type_`: {1, 2, 3, 4}, optional
+///   Type of the DST (see Notes of [dst]). Default type is 2.
+/// * `s`: sequence of ints, optional
+///   The shape of the result, see [dstn].
+/// * `axes`: sequence of ints, optional
+///   Axes over which the IDST is computed. If not given, the last ``len(s)`` axes are used, or
+///   all axes if `s` is also not specified.
+/// * `norm`: [FftNorm], optional
+///   Normalization mode (see Notes of [dst]). Default is [FftNorm::Backward].
+/// * `workers`: int, optional
+///   Maximum number of threads to use for the independent lanes of the transforms. Defaults to 1,
+///   computing on the calling thread. Without the `parallel` feature, this has no effect and all
+///   lanes are computed on the calling thread.
+/// * `plan`: [Planner], optional
+///   Planner holding the FFT plans, which are reused across calls. A new one is used if not given.
+///
+/// ## Returns
+/// * `y`: ndarray of real
+///   The transformed input array.
+///
+/// ## Errors
+/// As for [dstn].
+///
+/// ## Examples
+/// See [dstn].
+pub fn idstn<F, S, D>(
+    x: &ArrayBase<S, D>,
+    type_: Option<usize>,
+    s: Option<&[usize]>,
+    axes: Option<&[isize]>,
+    norm: Option<FftNorm>,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    r2rn(x, true, type_, s, axes, norm, true, workers, plan)
+}
+
+/// The transform type, 2 if not given.
+fn check_type(type_: Option<usize>) -> Result<usize> {
+    match type_.unwrap_or(2) {
+        type_ @ 1..=4 => Ok(type_),
+        _ => Err(Error::InvalidArg {
+            arg: "type_".into(),
+            reason: "invalid transform type, must be 1, 2, 3 or 4".into(),
+        }),
+    }
+}
+
+/// DCT or DST, or their inverses, along each of `axes`.
+#[allow(clippy::too_many_arguments)]
+fn r2rn<F, S, D>(
+    x: &ArrayBase<S, D>,
+    sine: bool,
+    type_: Option<usize>,
+    s: Option<&[usize]>,
+    axes: Option<&[isize]>,
+    norm: Option<FftNorm>,
+    inverse: bool,
+    workers: Option<usize>,
+    plan: Option<&mut Planner<F>>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let type_ = check_type(type_)?;
+    let workers = check_workers(workers)?;
+    let (shape, axes) = init_nd_shape_and_axes(x.shape(), s, axes)?;
+    let norm = norm.unwrap_or_default();
+    Planner::with(plan, |planner| {
+        let mut y = x.to_owned();
+        for (&axis, &n) in axes.iter().zip(&shape) {
+            y = r2r(
+                &y,
+                sine,
+                type_,
+                Some(n),
+                axis,
+                norm,
+                inverse,
+                workers,
+                planner,
+            )?;
+        }
+        Ok(y)
+    })
+}
+
+/// DCT, or DST if `sine`, of `type_` of `x` along `axis`, cropped or zero-padded to `n` points.
+///
+/// Every type is written as ``y[k] = 2 * sum(w[n] * cos(pi * (k + k0) * (n + n0) / m))``, or with
+/// a sine, with the inputs `w` at the ends of the sum weighted by a half for some types. Expanding
+/// the exponential of the phase, the sum is the FFT of length ``2m`` of
+/// ``w[n] * exp(-i pi k0 n / m)`` rotated by ``exp(-i pi (k + k0) n0 / m)``, of which the cosine
+/// sum is the real part and the sine sum minus the imaginary part.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn r2r<F, S, D>(
+    x: &ArrayBase<S, D>,
+    sine: bool,
+    type_: usize,
+    n: Option<usize>,
+    axis: usize,
+    norm: FftNorm,
+    inverse: bool,
+    workers: usize,
+    planner: &mut Planner<F>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = Axis(axis);
+    let n = check_points(n.unwrap_or(x.len_of(axis)))?;
+    // The inverses of the types 2 and 3 are each other, the types 1 and 4 are their own.
+    let type_ = match (type_, inverse) {
+        (2, true) => 3,
+        (3, true) => 2,
+        (type_, _) => type_,
+    };
+    let half = F::from(0.5).unwrap();
+    let (k0, n0, m) = match (sine, type_) {
+        (false, 1) if n < 2 => {
+            return Err(Error::InvalidArg {
+                arg: "n".into(),
+                reason: "DCT-I is not defined for size < 2".into(),
+            })
+        }
+        (false, 1) => (F::zero(), F::zero(), n - 1),
+        (false, 2) => (F::zero(), half, n),
+        (false, 3) => (half, F::zero(), n),
+        (true, 1) => (F::one(), F::one(), n + 1),
+        (true, 2) => (F::one(), half, n),
+        (true, 3) => (half, F::one(), n),
+        _ => (half, half, n),
+    };
+
+    // Input and output weights of the end points, including the orthonormalization. The end
+    // points of the DST-I are the zeros of the sine, so they are left out of the sum.
+    let ortho = norm == FftNorm::Ortho;
+    let sqrt2 = F::from(SQRT_2).unwrap();
+    let mut w_in = vec![F::one(); n];
+    let mut w_out = vec![F::one(); n];
+    // The end points of the sums of the types 2 and 3 are the first for the DCT and the last for
+    // the DST.
+    let end = if sine { n - 1 } else { 0 };
+    match (sine, type_) {
+        (false, 1) => {
+            let (w, v) = if ortho {
+                (sqrt2 * half, sqrt2.recip())
+            } else {
+                (half, F::one())
+            };
+            w_in[0] = w;
+            w_in[n - 1] = w;
+            w_out[0] = v;
+            w_out[n - 1] = v;
+        }
+        (_, 2) if ortho => w_out[end] = sqrt2.recip(),
+        (_, 3) => w_in[end] = if ortho { sqrt2 * half } else { half },
+        _ => {}
+    }
+
+    let pi = F::from(PI).unwrap();
+    let mf = F::from(m).unwrap();
+    let two = F::from(2).unwrap();
+    let scale = if sine { -two } else { two } * norm.factor::<F>(2 * m, inverse);
+    let pre: Vec<Complex<F>> = (0..n)
+        .map(|i| Complex::from_polar(w_in[i], -pi * k0 * F::from(i).unwrap() / mf))
+        .collect();
+    let post: Vec<Complex<F>> = w_out
+        .iter()
+        .enumerate()
+        .map(|(k, &w)| Complex::from_polar(w * scale, -pi * (F::from(k).unwrap() + k0) * n0 / mf))
+        .collect();
+
+    let fft = planner.plan_fft(2 * m, false);
+    let mut out_dim = x.raw_dim();
+    out_dim[axis.index()] = n;
+    let mut out = Array::zeros(out_dim);
+    for_each_lane(
+        x.view(),
+        out.view_mut(),
+        axis,
+        workers,
+        || {
+            let scratch = vec![Complex::zero(); fft.get_inplace_scratch_len()];
+            (vec![Complex::zero(); 2 * m], scratch)
+        },
+        |(buffer, scratch), lane, mut out| {
+            buffer.fill(Complex::zero());
+            buffer
+                .iter_mut()
+                .zip(lane)
+                .zip(&pre)
+                .for_each(|((b, &x), &p)| *b = p * x);
+            fft.process_with_scratch(buffer, scratch);
+            out.iter_mut()
+                .zip(buffer.iter())
+                .zip(&post)
+                .for_each(|((o, &b), &p)| {
+                    let y = b * p;
+                    *o = if sine { y.im } else { y.re };
+                });
+        },
+    )?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array1, Array2};
+
+    /// Direct evaluation of the unnormalized DCT definitions.
+    fn direct(x: &[f64], type_: usize) -> Vec<f64> {
+        let n = x.len();
+        let nf = n as f64;
+        (0..n)
+            .map(|k| {
+                let kf = k as f64;
+                let sum = |range: core::ops::Range<usize>, arg: &dyn Fn(f64) -> f64| {
+                    range.map(|i| x[i] * arg(i as f64).cos()).sum::<f64>()
+                };
+                match type_ {
+                    1 => {
+                        let sign = if k % 2 == 0 { 1. } else { -1. };
+                        x[0] + sign * x[n - 1] + 2. * sum(1..n - 1, &|i| PI * kf * i / (nf - 1.))
+                    }
+                    2 => 2. * sum(0..n, &|i| PI * kf * (2. * i + 1.) / (2. * nf)),
+                    3 => x[0] + 2. * sum(1..n, &|i| PI * (2. * kf + 1.) * i / (2. * nf)),
+                    _ => 2. * sum(0..n, &|i| PI * (2. * kf + 1.) * (2. * i + 1.) / (4. * nf)),
+                }
+            })
+            .collect()
+    }
+
+    #[track_caller]
+    fn assert_vec_eq(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn definitions() {
+        let x = array![1.0, 2.0, 1.0, -1.0, 1.5];
+        let expected = [
+            [6.5, 3.7426406871192857, 0.5, -4.742640687119286, 2.5],
+            [
+                9.0,
+                2.575654997459685,
+                1.4270509831248426,
+                -6.294124350063393,
+                1.9270509831248401,
+            ],
+            [
+                6.173740532470405,
+                2.208169069885462,
+                2.0,
+                -6.298339013634936,
+                0.9164294112790672,
+            ],
+            [
+                6.514938735958442,
+                1.6069425281361993,
+                -2.121320343559642,
+                -4.61444730317393,
+                4.656198563657519,
+            ],
+        ];
+        for (type_, expected) in (1..=4).zip(expected) {
+            let y = dct(&x, Some(type_), None, None, None, None, None).unwrap();
+            assert_vec_eq(y.as_slice().unwrap(), &expected);
+        }
+
+        for n in [2, 7, 8] {
+            let x: Vec<f64> = (0..n).map(|i| ((i * 5) % 7) as f64 - 2.5).collect();
+            for type_ in 1..=4 {
+                let y = dct(
+                    &Array1::from(x.clone()),
+                    Some(type_),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+                assert_vec_eq(y.as_slice().unwrap(), &direct(&x, type_));
+            }
+        }
+    }
+
+    #[test]
+    fn inverses_and_normalization() {
+        let x = array![0.5, -1., 3., 2., 0., 1.25];
+        for type_ in 1..=4 {
+            for norm in [FftNorm::Backward, FftNorm::Ortho, FftNorm::Forward] {
+                let y = dct(&x, Some(type_), None, None, Some(norm), None, None).unwrap();
+                let z = idct(&y, Some(type_), None, None, Some(norm), None, None).unwrap();
+                assert_vec_eq(z.as_slice().unwrap(), x.as_slice().unwrap());
+            }
+            // The orthonormal transforms preserve the energy.
+            let y = dct(
+                &x,
+                Some(type_),
+                None,
+                None,
+                Some(FftNorm::Ortho),
+                None,
+                None,
+            )
+            .unwrap();
+            assert_abs_diff_eq!(y.dot(&y), x.dot(&x), epsilon = 1e-10);
+            // The forward normalization scales by 1/(2(N-1)) or 1/(2N).
+            let backward = dct(&x, Some(type_), None, None, None, None, None).unwrap();
+            let forward = dct(
+                &x,
+                Some(type_),
+                None,
+                None,
+                Some(FftNorm::Forward),
+                None,
+                None,
+            )
+            .unwrap();
+            let m = if type_ == 1 { 5. } else { 6. };
+            assert_vec_eq(
+                forward.as_slice().unwrap(),
+                backward.mapv(|y| y / (2. * m)).as_slice().unwrap(),
+            );
+        }
+        // MATLAB's dct is the orthonormal DCT-II.
+        let y = dct(
+            &array![1., 1., 1., 1.],
+            None,
+            None,
+            None,
+            Some(FftNorm::Ortho),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_vec_eq(y.as_slice().unwrap(), &[2., 0., 0., 0.]);
+    }
+
+    #[test]
+    fn axes_and_lengths() {
+        let x = Array2::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64 % 5.);
+        let cols = dct(&x, Some(3), Some(5), Some(0), None, None, None).unwrap();
+        assert_eq!(cols.dim(), (5, 4));
+        for j in 0..4 {
+            let mut col = x.column(j).to_vec();
+            col.extend([0., 0.]);
+            assert_vec_eq(&cols.column(j).to_vec(), &direct(&col, 3));
+        }
+
+        let y = dctn(&x, Some(4), None, None, None, None, None).unwrap();
+        let expected = dct(
+            &dct(&x, Some(4), None, Some(0), None, None, None).unwrap(),
+            Some(4),
+            None,
+            Some(1),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_vec_eq(y.as_slice().unwrap(), expected.as_slice().unwrap());
+        let z = idctn(&y, Some(4), None, None, None, None, None).unwrap();
+        assert_vec_eq(z.as_slice().unwrap(), x.as_slice().unwrap());
+        let y = dctn(&x, None, Some(&[2]), None, Some(FftNorm::Ortho), None, None).unwrap();
+        assert_eq!(y.dim(), (3, 2));
+
+        assert!(dct(&x, Some(5), None, None, None, None, None).is_err());
+        assert!(dct(&x, Some(1), Some(1), None, None, None, None).is_err());
+        assert!(idct(&x, None, None, Some(2), None, None, None).is_err());
+        assert!(dctn(&x, Some(0), None, None, None, None, None).is_err());
+        assert!(dct(&array![3.], Some(2), None, None, None, None, None).is_ok());
+    }
+
+    #[test]
+    fn sines() {
+        let x = array![1.0, 2.0, 1.0, -1.0, 1.5];
+        let expected = [
+            [
+                6.232050807568877,
+                4.330127018922193,
+                3.0,
+                -6.062177826491071,
+                2.767949192431125,
+            ],
+            [
+                5.163118960624632,
+                5.1185538454784485,
+                2.6631189606246335,
+                -4.477768030049994,
+                5.0,
+            ],
+            [
+                4.185095954079375,
+                5.71586454726535,
+                1.5,
+                -4.2437285922657715,
+                3.2870400009202045,
+            ],
+            [
+                4.724096464820422,
+                3.9127972817480976,
+                6.363961030678928,
+                -3.5952865545662,
+                1.2028485414096821,
+            ],
+        ];
+        for (type_, expected) in (1..=4).zip(expected) {
+            let y = dst(&x, Some(type_), None, None, None, None, None).unwrap();
+            assert_vec_eq(y.as_slice().unwrap(), &expected);
+            for norm in [FftNorm::Backward, FftNorm::Ortho, FftNorm::Forward] {
+                let y = dst(&x, Some(type_), None, None, Some(norm), None, None).unwrap();
+                let z = idst(&y, Some(type_), None, None, Some(norm), None, None).unwrap();
+                assert_vec_eq(z.as_slice().unwrap(), x.as_slice().unwrap());
+            }
+            let y = dst(
+                &x,
+                Some(type_),
+                None,
+                None,
+                Some(FftNorm::Ortho),
+                None,
+                None,
+            )
+            .unwrap();
+            assert_abs_diff_eq!(y.dot(&y), x.dot(&x), epsilon = 1e-10);
+        }
+
+        // The DST-I is defined on a single point, with the FFT of length 2(N+1) setting the scale.
+        let one = array![2.];
+        for (type_, expected) in (1..=4).zip([4., 4., 2., 2. * SQRT_2]) {
+            let y = dst(&one, Some(type_), None, None, None, None, None).unwrap();
+            assert_vec_eq(y.as_slice().unwrap(), &[expected]);
+        }
+        let y = dst(
+            &one,
+            Some(1),
+            None,
+            None,
+            Some(FftNorm::Forward),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_vec_eq(y.as_slice().unwrap(), &[1.]);
+        assert!(dct(&one, Some(1), None, None, None, None, None).is_err());
+
+        let x = Array2::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64 % 5.);
+        let y = dstn(&x, Some(1), Some(&[6]), Some(&[0]), None, None, None).unwrap();
+        assert_eq!(y.dim(), (6, 4));
+        let expected = dst(&x, Some(1), Some(6), Some(0), None, None, None).unwrap();
+        assert_vec_eq(y.as_slice().unwrap(), expected.as_slice().unwrap());
+        let y = dstn(&x, Some(3), None, None, Some(FftNorm::Ortho), None, None).unwrap();
+        let z = idstn(&y, Some(3), None, None, Some(FftNorm::Ortho), None, None).unwrap();
+        assert_vec_eq(z.as_slice().unwrap(), x.as_slice().unwrap());
+        assert!(dst(&x, Some(0), None, None, None, None, None).is_err());
+        assert!(dst(&x, None, Some(0), None, None, None, None).is_err());
+        assert!(idstn(&x, None, None, Some(&[0, 0]), None, None, None).is_err());
+    }
+}
@@ -0,0 +1,345 @@
+use super::common::{
+    axpy, lu, min_step, newton_tol, rms_norm, select_initial_step, solve_lu, Interpolant,
+    OdeSolver, Rhs,
+};
+use crate::linalg::LuFactor;
+use alloc::vec::Vec;
+use ndarray::{s, Array1, Array2, Zip};
+use num_traits::Float;
+
+/// Maximum order of the formulas.
+const MAX_ORDER: usize = 5;
+/// Maximum number of iterations of the simplified Newton method.
+const NEWTON_MAXITER: usize = 4;
+/// Multiply the step size by at least this factor on a rejected step.
+const MIN_FACTOR: f64 = 0.2;
+/// Multiply the step size by at most this factor on an accepted step.
+const MAX_FACTOR: f64 = 10.;
+/// Coefficients of the numerical differentiation formulas of Klopfenstein and Shampine.
+const KAPPA: [f64; MAX_ORDER + 1] = [0., -0.1850, -1. / 9., -0.0823, -0.0415, 0.];
+
+/// Matrix of the change of the backward differences of `order` when the step size is
+/// multiplied by `factor`.
+fn compute_r<F: Float>(order: usize, factor: F) -> Array2<F> {
+    let mut r = Array2::ones((order + 1, order + 1));
+    for i in 1..=order {
+        r[[i, 0]] = F::zero();
+        for j in 1..=order {
+            let (fi, fj) = (F::from(i).unwrap(), F::from(j).unwrap());
+            r[[i, j]] = r[[i - 1, j]] * (fi - F::one() - factor * fj) / fi;
+        }
+    }
+    r
+}
+
+/// Rescale the backward differences `d` after the step size is multiplied by `factor`.
+fn change_d<F: Float>(d: &mut Array2<F>, order: usize, factor: F) {
+    let r = compute_r(order, factor);
+    let u = compute_r(order, F::one());
+    let ru = Array2::from_shape_fn((order + 1, order + 1), |(i, j)| {
+        (0..=order).fold(F::zero(), |acc, k| acc + r[[i, k]] * u[[k, j]])
+    });
+    let old = d.slice(s![..=order, ..]).to_owned();
+    for i in 0..=order {
+        let mut row = Array1::zeros(d.ncols());
+        for k in 0..=order {
+            axpy(&mut row, ru[[k, i]], old.row(k));
+        }
+        d.row_mut(i).assign(&row);
+    }
+}
+
+/// Implicit multistep method of the backward differentiation formulas of variable order 1 to
+/// 5, in the quasi-constant step size form of the numerical differentiation formulas.
+pub(super) struct Bdf<F> {
+    t: F,
+    y: Array1<F>,
+    t_old: F,
+    t_bound: F,
+    direction: F,
+    h_abs: F,
+    rtol: F,
+    atol: Array1<F>,
+    newton_tol: F,
+    jac: Array2<F>,
+    lu: Option<LuFactor<F>>,
+    nlu: usize,
+    gamma: [F; MAX_ORDER + 1],
+    alpha: [F; MAX_ORDER + 1],
+    error_const: [F; MAX_ORDER + 1],
+    /// Backward differences of the solution, scaled by the powers of the step size.
+    d: Array2<F>,
+    order: usize,
+    n_equal_steps: usize,
+}
+
+impl<F: Float> Bdf<F> {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new(
+        rhs: &mut Rhs<'_, F>,
+        t0: F,
+        y0: Array1<F>,
+        f0: Array1<F>,
+        jac: Array2<F>,
+        t_bound: F,
+        rtol: F,
+        atol: Array1<F>,
+    ) -> Self {
+        let h_abs = select_initial_step(rhs, t0, &y0, t_bound, &f0, 1, rtol, &atol);
+        let direction = (t_bound - t0).signum();
+        let mut gamma = [F::zero(); MAX_ORDER + 1];
+        for k in 1..=MAX_ORDER {
+            gamma[k] = gamma[k - 1] + F::one() / F::from(k).unwrap();
+        }
+        let mut alpha = [F::zero(); MAX_ORDER + 1];
+        let mut error_const = [F::zero(); MAX_ORDER + 1];
+        for k in 0..=MAX_ORDER {
+            let kappa = F::from(KAPPA[k]).unwrap();
+            alpha[k] = (F::one() - kappa) * gamma[k];
+            error_const[k] = kappa * gamma[k] + F::one() / F::from(k + 1).unwrap();
+        }
+        let n = y0.len();
+        let mut d = Array2::zeros((MAX_ORDER + 3, n));
+        d.row_mut(0).assign(&y0);
+        Zip::from(d.row_mut(1))
+            .and(&f0)
+            .for_each(|d, &f| *d = f * h_abs * direction);
+        Bdf {
+            t: t0,
+            y: y0,
+            t_old: t0,
+            t_bound,
+            direction,
+            h_abs,
+            rtol,
+            newton_tol: newton_tol(rtol),
+            atol,
+            jac,
+            lu: None,
+            nlu: 0,
+            gamma,
+            alpha,
+            error_const,
+            d,
+            order: 1,
+            n_equal_steps: 0,
+        }
+    }
+
+    /// Solve the BDF system by simplified Newton iterations from `y_predict`. Returns whether
+    /// it converged, the number of iterations, the solution and its correction.
+    fn solve_bdf_system(
+        &self,
+        rhs: &mut Rhs<'_, F>,
+        t_new: F,
+        y_predict: &Array1<F>,
+        c: F,
+        psi: &Array1<F>,
+        scale: &Array1<F>,
+    ) -> (bool, usize, Array1<F>, Array1<F>) {
+        let lu = self.lu.as_ref().unwrap();
+        let mut y = y_predict.clone();
+        let mut d = Array1::zeros(y.len());
+        let mut dy_norm_old = None;
+        for k in 0..NEWTON_MAXITER {
+            let f = rhs.call(t_new, y.view());
+            if f.iter().any(|f| !f.is_finite()) {
+                return (false, k + 1, y, d);
+            }
+            let b = Zip::from(&f)
+                .and(psi)
+                .and(&d)
+                .map_collect(|&f, &psi, &d| c * f - psi - d);
+            let Some(dy) = solve_lu(lu, &b) else {
+                return (false, k + 1, y, d);
+            };
+            let dy_norm = rms_norm((&dy / scale).view());
+            let rate = dy_norm_old.map(|old| dy_norm / old);
+            let remaining = (NEWTON_MAXITER - k) as i32;
+            if let Some(rate) = rate {
+                if rate >= F::one()
+                    || rate.powi(remaining) / (F::one() - rate) * dy_norm > self.newton_tol
+                {
+                    return (false, k + 1, y, d);
+                }
+            }
+            y = y + &dy;
+            d = d + &dy;
+            if dy_norm.is_zero()
+                || rate.is_some_and(|rate| rate / (F::one() - rate) * dy_norm < self.newton_tol)
+            {
+                return (true, k + 1, y, d);
+            }
+            dy_norm_old = Some(dy_norm);
+        }
+        (false, NEWTON_MAXITER, y, d)
+    }
+}
+
+impl<F: Float> OdeSolver<F> for Bdf<F> {
+    fn t_old(&self) -> F {
+        self.t_old
+    }
+
+    fn t(&self) -> F {
+        self.t
+    }
+
+    fn y(&self) -> &Array1<F> {
+        &self.y
+    }
+
+    fn nlu(&self) -> usize {
+        self.nlu
+    }
+
+    fn step(&mut self, rhs: &mut Rhs<'_, F>) -> core::result::Result<(), &'static str> {
+        let t = self.t;
+        let min_step = min_step(t);
+        let mut h_abs = self.h_abs;
+        if h_abs < min_step {
+            change_d(&mut self.d, self.order, min_step / h_abs);
+            h_abs = min_step;
+            self.n_equal_steps = 0;
+        }
+        let order = self.order;
+        let mut current_jac = false;
+        let (t_new, y_new, d, n_iter, error_norm, scale) = loop {
+            if h_abs < min_step {
+                return Err("Required step size is less than spacing between numbers.");
+            }
+            let mut t_new = t + h_abs * self.direction;
+            if self.direction * (t_new - self.t_bound) > F::zero() {
+                t_new = self.t_bound;
+                change_d(&mut self.d, order, (t_new - t).abs() / h_abs);
+                self.n_equal_steps = 0;
+                self.lu = None;
+            }
+            let h = t_new - t;
+            h_abs = h.abs();
+
+            let mut y_predict = Array1::zeros(self.y.len());
+            for row in self.d.slice(s![..=order, ..]).rows() {
+                axpy(&mut y_predict, F::one(), row);
+            }
+            let scale = Zip::from(&y_predict)
+                .and(&self.atol)
+                .map_collect(|&y, &atol| atol + self.rtol * y.abs());
+            let mut psi = Array1::zeros(self.y.len());
+            for k in 1..=order {
+                axpy(&mut psi, self.gamma[k] / self.alpha[order], self.d.row(k));
+            }
+            let c = h / self.alpha[order];
+            let mut solution = None;
+            loop {
+                if self.lu.is_none() {
+                    let mut a = self.jac.mapv(|j| -c * j);
+                    a.diag_mut().mapv_inplace(|d| d + F::one());
+                    self.nlu += 1;
+                    self.lu = lu(&a);
+                    if self.lu.is_none() {
+                        break;
+                    }
+                }
+                let result = self.solve_bdf_system(rhs, t_new, &y_predict, c, &psi, &scale);
+                if result.0 {
+                    solution = Some(result);
+                    break;
+                }
+                if current_jac {
+                    break;
+                }
+                let f = rhs.call(t_new, y_predict.view());
+                self.jac = rhs.jacobian(t_new, &y_predict, &f, &self.atol);
+                self.lu = None;
+                current_jac = true;
+            }
+            let Some((_, n_iter, y_new, d)) = solution else {
+                let factor = F::from(0.5).unwrap();
+                h_abs = h_abs * factor;
+                change_d(&mut self.d, order, factor);
+                self.n_equal_steps = 0;
+                self.lu = None;
+                continue;
+            };
+
+            let scale = Zip::from(&y_new)
+                .and(&self.atol)
+                .map_collect(|&y, &atol| atol + self.rtol * y.abs());
+            let error_norm = self.error_const[order] * rms_norm((&d / &scale).view());
+            if error_norm > F::one() {
+                let exponent = -F::one() / F::from(order + 1).unwrap();
+                let factor = F::from(MIN_FACTOR)
+                    .unwrap()
+                    .max(safety::<F>(n_iter) * error_norm.powf(exponent));
+                h_abs = h_abs * factor;
+                change_d(&mut self.d, order, factor);
+                self.n_equal_steps = 0;
+                continue;
+            }
+            break (t_new, y_new, d, n_iter, error_norm, scale);
+        };
+
+        self.n_equal_steps += 1;
+        self.t_old = t;
+        self.t = t_new;
+        self.y = y_new;
+        self.h_abs = h_abs;
+        // D^{j + 1} y_n = D^j y_n - D^j y_{n - 1}, where D^j y_n = D^j y_{n - 1} in the update.
+        let next = &d - &self.d.row(order + 1);
+        self.d.row_mut(order + 2).assign(&next);
+        self.d.row_mut(order + 1).assign(&d);
+        for i in (0..=order).rev() {
+            let sum = &self.d.row(i) + &self.d.row(i + 1);
+            self.d.row_mut(i).assign(&sum);
+        }
+        if self.n_equal_steps < order + 1 {
+            return Ok(());
+        }
+
+        let error_m_norm = if order > 1 {
+            self.error_const[order - 1] * rms_norm((&self.d.row(order) / &scale).view())
+        } else {
+            F::infinity()
+        };
+        let error_p_norm = if order < MAX_ORDER {
+            self.error_const[order + 1] * rms_norm((&self.d.row(order + 2) / &scale).view())
+        } else {
+            F::infinity()
+        };
+        let factors: Vec<F> = [error_m_norm, error_norm, error_p_norm]
+            .into_iter()
+            .enumerate()
+            .map(|(i, norm)| norm.powf(-F::one() / F::from(order + i).unwrap()))
+            .collect();
+        let best = (0..3).fold(
+            0,
+            |best, i| if factors[i] > factors[best] { i } else { best },
+        );
+        self.order = order + best - 1;
+        let factor = F::from(MAX_FACTOR)
+            .unwrap()
+            .min(safety::<F>(n_iter) * factors[best]);
+        self.h_abs = self.h_abs * factor;
+        change_d(&mut self.d, self.order, factor);
+        self.n_equal_steps = 0;
+        self.lu = None;
+        Ok(())
+    }
+
+    fn dense_output(&mut self, _rhs: &mut Rhs<'_, F>) -> Interpolant<F> {
+        let h = self.h_abs * self.direction;
+        Interpolant::Bdf {
+            t_shift: (0..self.order)
+                .map(|k| self.t - h * F::from(k).unwrap())
+                .collect(),
+            denom: (1..=self.order).map(|k| h * F::from(k).unwrap()).collect(),
+            d: self.d.slice(s![..=self.order, ..]).to_owned(),
+        }
+    }
+}
+
+/// Safety factor of the step size, smaller when the Newton iterations converge slowly.
+fn safety<F: Float>(n_iter: usize) -> F {
+    F::from(0.9 * (2 * NEWTON_MAXITER + 1) as f64 / (2 * NEWTON_MAXITER + n_iter) as f64).unwrap()
+}
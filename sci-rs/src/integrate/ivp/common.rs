@@ -0,0 +1,256 @@
+use crate::linalg::{lu_factor, lu_solve, LuFactor};
+use alloc::vec::Vec;
+use ndarray::{Array1, Array2, ArrayView1, Zip};
+use num_traits::Float;
+
+/// User-supplied Jacobian of the right-hand side.
+pub(super) type Jacobian<'a, F> = &'a mut dyn FnMut(F, ArrayView1<F>) -> Array2<F>;
+
+/// Right-hand side of the system and its Jacobian, counting their evaluations.
+pub(super) struct Rhs<'a, F> {
+    fun: &'a mut dyn FnMut(F, ArrayView1<F>) -> Array1<F>,
+    jac: Option<Jacobian<'a, F>>,
+    pub(super) nfev: usize,
+    pub(super) njev: usize,
+}
+
+impl<'a, F: Float> Rhs<'a, F> {
+    pub(super) fn new(
+        fun: &'a mut dyn FnMut(F, ArrayView1<F>) -> Array1<F>,
+        jac: Option<Jacobian<'a, F>>,
+    ) -> Self {
+        Rhs {
+            fun,
+            jac,
+            nfev: 0,
+            njev: 0,
+        }
+    }
+
+    pub(super) fn call(&mut self, t: F, y: ArrayView1<F>) -> Array1<F> {
+        self.nfev += 1;
+        (self.fun)(t, y)
+    }
+
+    /// The Jacobian at `(t, y)`, where the right-hand side is `f`. Without a user-supplied
+    /// Jacobian, it is approximated by forward differences of steps of relative size
+    /// `sqrt(eps)`, in the direction of `f`, and of absolute size at least `sqrt(eps) * atol`.
+    pub(super) fn jacobian(
+        &mut self,
+        t: F,
+        y: &Array1<F>,
+        f: &Array1<F>,
+        atol: &Array1<F>,
+    ) -> Array2<F> {
+        self.njev += 1;
+        if let Some(jac) = self.jac.as_mut() {
+            return jac(t, y.view());
+        }
+        let n = y.len();
+        let factor = Float::sqrt(F::epsilon());
+        let mut jac = Array2::zeros((n, n));
+        let mut y_step = y.clone();
+        for j in 0..n {
+            let sign = if f[j] < F::zero() {
+                -F::one()
+            } else {
+                F::one()
+            };
+            let mut h = (y[j] + sign * factor * y[j].abs().max(atol[j])) - y[j];
+            if h.is_zero() {
+                h = sign * factor;
+            }
+            y_step[j] = y[j] + h;
+            let f_step = self.call(t, y_step.view());
+            Zip::from(jac.column_mut(j))
+                .and(&f_step)
+                .and(f)
+                .for_each(|jac, &f_step, &f| *jac = (f_step - f) / h);
+            y_step[j] = y[j];
+        }
+        jac
+    }
+}
+
+/// A solver taking one adaptive step at a time.
+pub(super) trait OdeSolver<F> {
+    /// Time before the last step.
+    fn t_old(&self) -> F;
+    /// Current time.
+    fn t(&self) -> F;
+    /// Current state.
+    fn y(&self) -> &Array1<F>;
+    /// Take a step towards the end of the interval, or give the reason of the failure.
+    fn step(&mut self, rhs: &mut Rhs<'_, F>) -> core::result::Result<(), &'static str>;
+    /// Interpolant of the last step.
+    fn dense_output(&mut self, rhs: &mut Rhs<'_, F>) -> Interpolant<F>;
+    /// Number of LU decompositions.
+    fn nlu(&self) -> usize {
+        0
+    }
+}
+
+/// Root mean square of the values.
+pub(super) fn rms_norm<F: Float>(x: ArrayView1<F>) -> F {
+    let n = F::from(x.len()).unwrap();
+    Float::sqrt(x.fold(F::zero(), |acc, &v| acc + v * v) / n)
+}
+
+/// `y += a * x`.
+pub(super) fn axpy<F: Float>(y: &mut Array1<F>, a: F, x: ArrayView1<F>) {
+    Zip::from(y).and(x).for_each(|y, &x| *y = *y + a * x);
+}
+
+/// Tolerance of the simplified Newton iterations of the implicit solvers.
+pub(super) fn newton_tol<F: Float>(rtol: F) -> F {
+    (F::from(10).unwrap() * F::epsilon() / rtol).max(F::from(0.03).unwrap().min(Float::sqrt(rtol)))
+}
+
+/// LU factorization of `a`, or `None` if it is not finite.
+pub(super) fn lu<F: Float>(a: &Array2<F>) -> Option<LuFactor<F>> {
+    lu_factor(a).ok()
+}
+
+/// Solve `a x = b` from the LU factorization of `a`, or `None` if `a` is singular or `b` is not
+/// finite.
+pub(super) fn solve_lu<F: Float>(lu: &LuFactor<F>, b: &Array1<F>) -> Option<Array1<F>> {
+    lu_solve(lu, b, None).ok()
+}
+
+/// Smallest step size allowed at `t`, ten spacings of the floating point numbers.
+pub(super) fn min_step<F: Float>(t: F) -> F {
+    F::from(10).unwrap() * (F::epsilon() * t.abs()).max(F::min_positive_value())
+}
+
+/// Empirical choice of the initial step size, from Hairer, Norsett and Wanner, Section II.4.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn select_initial_step<F: Float>(
+    rhs: &mut Rhs<'_, F>,
+    t0: F,
+    y0: &Array1<F>,
+    t_bound: F,
+    f0: &Array1<F>,
+    order: i32,
+    rtol: F,
+    atol: &Array1<F>,
+) -> F {
+    let interval_length = (t_bound - t0).abs();
+    let direction = (t_bound - t0).signum();
+    let small = F::from(1e-5).unwrap();
+    let scale = Zip::from(y0)
+        .and(atol)
+        .map_collect(|&y, &atol| atol + y.abs() * rtol);
+    let d0 = rms_norm((y0 / &scale).view());
+    let d1 = rms_norm((f0 / &scale).view());
+    let h0 = if d0 < small || d1 < small {
+        F::from(1e-6).unwrap()
+    } else {
+        F::from(0.01).unwrap() * d0 / d1
+    }
+    .min(interval_length);
+    let mut y1 = y0.clone();
+    axpy(&mut y1, h0 * direction, f0.view());
+    let f1 = rhs.call(t0 + h0 * direction, y1.view());
+    let d2 = rms_norm(((&f1 - f0) / &scale).view()) / h0;
+    let tiny = F::from(1e-15).unwrap();
+    let h1 = if d1 <= tiny && d2 <= tiny {
+        F::from(1e-6).unwrap().max(h0 * F::from(1e-3).unwrap())
+    } else {
+        (F::from(0.01).unwrap() / d1.max(d2)).powf(F::one() / F::from(order + 1).unwrap())
+    };
+    (F::from(100).unwrap() * h0).min(h1).min(interval_length)
+}
+
+/// Interpolant of a single step, valid between `t_old` and `t_old + h`.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Interpolant<F> {
+    /// `y_old + h * Q p(x)`, with `p(x) = (x, x^2, ...)` and `x = (t - t_old) / h`.
+    Polynomial {
+        t_old: F,
+        h: F,
+        y_old: Array1<F>,
+        q: Array2<F>,
+    },
+    /// The nested polynomial of DOP853, in `x` and `1 - x`, of coefficients `f`.
+    Dop853 {
+        t_old: F,
+        h: F,
+        y_old: Array1<F>,
+        f: Array2<F>,
+    },
+    /// The Newton interpolating polynomial of BDF through the backward differences `d` at the
+    /// times `t - k h`, `y = d[0] + sum(d[k + 1] * prod((t - t_shift[i]) / denom[i], i <= k))`.
+    Bdf {
+        t_shift: Vec<F>,
+        denom: Vec<F>,
+        d: Array2<F>,
+    },
+}
+
+impl<F: Float> Interpolant<F> {
+    pub(super) fn eval(&self, t: F) -> Array1<F> {
+        match self {
+            Interpolant::Polynomial { t_old, h, y_old, q } => {
+                let x = (t - *t_old) / *h;
+                let mut y = y_old.clone();
+                let mut p = *h;
+                for column in q.columns() {
+                    p = p * x;
+                    axpy(&mut y, p, column);
+                }
+                y
+            }
+            Interpolant::Dop853 { t_old, h, y_old, f } => {
+                let x = (t - *t_old) / *h;
+                let mut y = Array1::zeros(y_old.len());
+                for (i, row) in f.rows().into_iter().rev().enumerate() {
+                    let factor = if i % 2 == 0 { x } else { F::one() - x };
+                    Zip::from(&mut y)
+                        .and(row)
+                        .for_each(|y, &f| *y = (*y + f) * factor);
+                }
+                y + y_old
+            }
+            Interpolant::Bdf { t_shift, denom, d } => {
+                let mut y = d.row(0).to_owned();
+                let mut p = F::one();
+                for (k, (&t_shift, &denom)) in t_shift.iter().zip(denom).enumerate() {
+                    p = p * (t - t_shift) / denom;
+                    axpy(&mut y, p, d.row(k + 1));
+                }
+                y
+            }
+        }
+    }
+}
+
+/// Continuous solution of [solve_ivp](super::solve_ivp), made of the interpolants of its steps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OdeSolution<F> {
+    ts: Vec<F>,
+    interpolants: Vec<Interpolant<F>>,
+}
+
+impl<F: Float> OdeSolution<F> {
+    pub(super) fn new(ts: Vec<F>, interpolants: Vec<Interpolant<F>>) -> Self {
+        OdeSolution { ts, interpolants }
+    }
+
+    /// The times between the steps, in the direction of the integration.
+    pub fn ts(&self) -> &[F] {
+        &self.ts
+    }
+
+    /// Evaluate the solution at `t`.
+    ///
+    /// Times outside of the interval of integration are extrapolated from the first or the last
+    /// step.
+    pub fn call(&self, t: F) -> Array1<F> {
+        let ascending = self.ts[self.ts.len() - 1] >= self.ts[0];
+        let before = self
+            .ts
+            .partition_point(|&s| if ascending { s < t } else { s > t });
+        let segment = before.saturating_sub(1).min(self.interpolants.len() - 1);
+        self.interpolants[segment].eval(t)
+    }
+}
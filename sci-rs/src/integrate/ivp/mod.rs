@@ -0,0 +1,623 @@
+mod bdf;
+mod common;
+mod radau;
+mod rk;
+
+use crate::optimize::brentq;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use bdf::Bdf;
+pub use common::OdeSolution;
+use common::{Jacobian, OdeSolver, Rhs};
+use ndarray::{Array1, Array2, ArrayBase, ArrayView1, Data, Ix1};
+use num_traits::Float;
+use radau::Radau;
+use rk::RungeKutta;
+use sci_rs_core::{Error, Result};
+
+/// Integration method of [solve_ivp].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OdeMethod {
+    /// Explicit Runge-Kutta method of order 5(4), of Dormand and Prince, with a quartic
+    /// interpolant.
+    #[default]
+    Rk45,
+    /// Explicit Runge-Kutta method of order 3(2), of Bogacki and Shampine, with a cubic Hermite
+    /// interpolant.
+    Rk23,
+    /// Explicit Runge-Kutta method of order 8, of Hairer, with an interpolant of order 7.
+    Dop853,
+    /// Implicit Runge-Kutta method of the Radau IIA family of order 5, with a cubic
+    /// interpolant. For stiff problems.
+    Radau,
+    /// Implicit multistep method of variable order 1 to 5, based on the backward
+    /// differentiation formulas, with a quasi-constant step size. For stiff problems.
+    Bdf,
+}
+
+/// Direction of the zero crossings which trigger an [Event].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EventDirection {
+    /// Both crossings.
+    #[default]
+    Any,
+    /// The function of the event goes from negative to positive.
+    Increasing,
+    /// The function of the event goes from positive to negative.
+    Decreasing,
+}
+
+/// An event to locate during the integration of [solve_ivp], at the zeros of `fun(t, y)`.
+pub struct Event<'a, F> {
+    /// Continuous function of the time and of the state, which is zero at the event.
+    pub fun: &'a mut dyn FnMut(F, ArrayView1<F>) -> F,
+    /// Terminate the integration at the given occurrence of the event. The integration does
+    /// not stop on the event if it is `None`.
+    pub terminal: Option<usize>,
+    /// Direction of the crossings of zero to detect.
+    pub direction: EventDirection,
+}
+
+impl<'a, F> Event<'a, F> {
+    /// A non-terminal event of function `fun`, triggered in both directions.
+    pub fn new(fun: &'a mut dyn FnMut(F, ArrayView1<F>) -> F) -> Self {
+        Event {
+            fun,
+            terminal: None,
+            direction: EventDirection::Any,
+        }
+    }
+
+    fn is_active(&self, g: F, g_new: F) -> bool
+    where
+        F: Float,
+    {
+        let up = g <= F::zero() && g_new >= F::zero();
+        let down = g >= F::zero() && g_new <= F::zero();
+        match self.direction {
+            EventDirection::Any => up || down,
+            EventDirection::Increasing => up,
+            EventDirection::Decreasing => down,
+        }
+    }
+}
+
+/// Result of [solve_ivp].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OdeResult<F> {
+    /// Times of the steps.
+    pub t: Array1<F>,
+    /// Values of the solution at `t`, of shape `(n, n_points)`.
+    pub y: Array2<F>,
+    /// The continuous solution, if `dense_output` was requested.
+    pub sol: Option<OdeSolution<F>>,
+    /// For each event, the times at which it was detected.
+    pub t_events: Vec<Vec<F>>,
+    /// For each event, the values of the solution at `t_events`, of shape `(n_events, n)`.
+    pub y_events: Vec<Array2<F>>,
+    /// Number of evaluations of the right-hand side.
+    pub nfev: usize,
+    /// Number of evaluations of the Jacobian.
+    pub njev: usize,
+    /// Number of LU decompositions.
+    pub nlu: usize,
+    /// Termination status: -1 if the integration step failed, 0 if the end of `t_span` was
+    /// reached, and 1 if a termination event occurred.
+    pub status: i32,
+    /// Description of the cause of the termination.
+    pub message: String,
+    /// Whether `status` is at least 0.
+    pub success: bool,
+}
+
+/// Solve an initial value problem for a system of ODEs.
+///
+/// Numerically integrates the system of ordinary differential equations `dy / dt = f(t, y)`,
+/// given the initial value `y(t0) = y0`.
+///
+/// ## Parameters
+/// * `fun`: Right-hand side of the system, of the time `t` and of the state `y` of shape
+///   `(n,)`, which returns `n` values.
+/// * `t_span`: Interval of integration `(t0, tf)`. The solver starts at `t0` and integrates
+///   until it reaches `tf`, which may be smaller than `t0`.
+/// * `y0`: Initial state, of shape `(n,)`.
+/// * `method`: Integration method, [Rk45](OdeMethod::Rk45) by default. The explicit
+///   Runge-Kutta methods should be used for non-stiff problems, and the implicit
+///   [Radau](OdeMethod::Radau) and [Bdf](OdeMethod::Bdf) for stiff ones.
+/// * `jac`: The Jacobian of `fun` with respect to `y`, of shape `(n, n)`, for the implicit
+///   methods. They approximate it by forward differences if it is not given. The explicit
+///   methods ignore it.
+/// * `rtol`, `atol`: Relative and absolute tolerances. The solver keeps the local error
+///   estimates less than `atol + rtol * abs(y)`. `rtol` is `1e-3` by default, and is raised to
+///   `100 * eps` if smaller. `atol` is given once or per component, `1e-6` by default.
+/// * `dense_output`: Whether to compute a continuous solution. `false` by default.
+/// * `events`: [Event]s to track. The zeros of their functions are located by Brent's method on
+///   the interpolant of the step where they change sign.
+///
+/// ## Returns
+/// The [OdeResult], whose `t` and `y` hold the solution at the steps.
+///
+/// ## Errors
+/// If `t_span` is empty or not finite, `y0` is empty or not finite, `fun` does not return `n`
+/// values, `jac` does not return a matrix of shape `(n, n)`, the tolerances are negative,
+/// `atol` has a wrong length, or an event terminates at its 0th occurrence.
+///
+/// ## Notes
+/// The step size is controlled by the embedded error estimator of the method, as in [1]. On an
+/// accepted step, it grows by at most a factor 10, and on a rejected one it shrinks by at most
+/// a factor 5.
+///
+/// The implicit methods solve their nonlinear systems by simplified Newton iterations, which
+/// reuse the LU factorization of the iteration matrix while the step size does not change, and
+/// the Jacobian until the iterations fail to converge. Radau solves its collocation system in
+/// the eigenbasis of its Butcher matrix, and adapts its step size with the predictive
+/// controller of Gustafsson [4]. BDF changes its order and step size after `order + 1` equal
+/// steps, and is implemented as the numerical differentiation formulas of [5].
+///
+/// ## References
+/// 1. E. Hairer, S. P. Norsett and G. Wanner, "Solving Ordinary Differential Equations I:
+///    Nonstiff Problems", Sec. II.
+/// 2. J. R. Dormand, P. J. Prince, "A family of embedded Runge-Kutta formulae", Journal of
+///    Computational and Applied Mathematics, Vol. 6, No. 1, pp. 19-26, 1980.
+/// 3. P. Bogacki, L.F. Shampine, "A 3(2) Pair of Runge-Kutta Formulas", Appl. Math. Lett.
+///    Vol. 2, No. 4. pp. 321-325, 1989.
+/// 4. E. Hairer, G. Wanner, "Solving Ordinary Differential Equations II: Stiff and
+///    Differential-Algebraic Problems", Sec. IV.8.
+/// 5. L. F. Shampine, M. W. Reichelt, "THE MATLAB ODE SUITE", SIAM J. SCI. COMPUTE.,
+///    Vol. 18, No. 1, pp. 1-22, January 1997.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.integrate import solve_ivp
+/// def upward_cannon(t, y): return [y[1], -0.5]
+/// def hit_ground(t, y): return y[0]
+/// hit_ground.terminal = True
+/// hit_ground.direction = -1
+/// sol = solve_ivp(upward_cannon, [0, 100], [0, 10], events=hit_ground)
+/// sol.t_events
+/// # [array([40.])]
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{array, ArrayView1};
+/// use sci_rs::integrate::{solve_ivp, Event, EventDirection};
+///
+/// let upward_cannon = |_t: f64, y: ArrayView1<f64>| array![y[1], -0.5];
+/// let mut hit_ground = |_t: f64, y: ArrayView1<f64>| y[0];
+/// let mut events = [Event {
+///     terminal: Some(1),
+///     direction: EventDirection::Decreasing,
+///     ..Event::new(&mut hit_ground)
+/// }];
+/// let y0 = array![0., 10.];
+/// let sol = solve_ivp(upward_cannon, (0., 100.), &y0, None, None, None, None, None, &mut events)
+///     .unwrap();
+/// assert_eq!(sol.status, 1);
+/// assert_abs_diff_eq!(sol.t_events[0][0], 40., epsilon = 1e-10);
+/// assert_abs_diff_eq!(sol.t[sol.t.len() - 1], 40., epsilon = 1e-10);
+/// ```
+///
+/// The stiff Robertson chemical kinetics problem, with its Jacobian:
+/// ```custom,{class=language-python}
+/// def rober(t, y):
+///     return [-0.04 * y[0] + 1e4 * y[1] * y[2],
+///             0.04 * y[0] - 1e4 * y[1] * y[2] - 3e7 * y[1]**2,
+///             3e7 * y[1]**2]
+/// def jac(t, y):
+///     return [[-0.04, 1e4 * y[2], 1e4 * y[1]],
+///             [0.04, -1e4 * y[2] - 6e7 * y[1], -1e4 * y[1]],
+///             [0, 6e7 * y[1], 0]]
+/// sol = solve_ivp(rober, [0, 40], [1, 0, 0], method='Radau', jac=jac, rtol=1e-6, atol=1e-10)
+/// sol.y[:, -1]
+/// # array([7.15827069e-01, 9.18553476e-06, 2.84163745e-01])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{array, ArrayView1};
+/// use sci_rs::integrate::{solve_ivp, OdeMethod};
+///
+/// let rober = |_t: f64, y: ArrayView1<f64>| {
+///     array![
+///         -0.04 * y[0] + 1e4 * y[1] * y[2],
+///         0.04 * y[0] - 1e4 * y[1] * y[2] - 3e7 * y[1].powi(2),
+///         3e7 * y[1].powi(2)
+///     ]
+/// };
+/// let mut jac = |_t: f64, y: ArrayView1<f64>| {
+///     array![
+///         [-0.04, 1e4 * y[2], 1e4 * y[1]],
+///         [0.04, -1e4 * y[2] - 6e7 * y[1], -1e4 * y[1]],
+///         [0., 6e7 * y[1], 0.]
+///     ]
+/// };
+/// let y0 = array![1., 0., 0.];
+/// let (rtol, atol) = (Some(1e-6), Some(&[1e-10][..]));
+/// let method = Some(OdeMethod::Radau);
+/// let sol = solve_ivp(rober, (0., 40.), &y0, method, Some(&mut jac), rtol, atol, None, &mut [])
+///     .unwrap();
+/// assert!(sol.success);
+/// let last = sol.t.len() - 1;
+/// assert_abs_diff_eq!(sol.y[[0, last]], 7.15827069e-01, epsilon = 1e-6);
+/// assert_abs_diff_eq!(sol.y[[1, last]], 9.18553476e-06, epsilon = 1e-10);
+/// ```
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn solve_ivp<F, S>(
+    mut fun: impl FnMut(F, ArrayView1<F>) -> Array1<F>,
+    t_span: (F, F),
+    y0: &ArrayBase<S, Ix1>,
+    method: Option<OdeMethod>,
+    jac: Option<&mut dyn FnMut(F, ArrayView1<F>) -> Array2<F>>,
+    rtol: Option<F>,
+    atol: Option<&[F]>,
+    dense_output: Option<bool>,
+    events: &mut [Event<'_, F>],
+) -> Result<OdeResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let (t0, t_bound) = t_span;
+    if !t0.is_finite() || !t_bound.is_finite() || t0 == t_bound {
+        return Err(Error::InvalidArg {
+            arg: "t_span".into(),
+            reason: "t_span must be a non-empty finite interval.".into(),
+        });
+    }
+    let n = y0.len();
+    if n == 0 || y0.iter().any(|y| !y.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "y0".into(),
+            reason: "y0 must be a non-empty array of finite values.".into(),
+        });
+    }
+    let rtol = match rtol {
+        Some(rtol) if rtol < F::zero() || rtol.is_nan() => {
+            return Err(Error::InvalidArg {
+                arg: "rtol".into(),
+                reason: "rtol must be non-negative.".into(),
+            })
+        }
+        rtol => rtol
+            .unwrap_or_else(|| F::from(1e-3).unwrap())
+            .max(F::from(100).unwrap() * F::epsilon()),
+    };
+    let atol = match atol {
+        None => Array1::from_elem(n, F::from(1e-6).unwrap()),
+        Some(&[atol]) => Array1::from_elem(n, atol),
+        Some(atol) if atol.len() == n => Array1::from(atol.to_vec()),
+        Some(_) => {
+            return Err(Error::InvalidArg {
+                arg: "atol".into(),
+                reason: "atol must have one value or one per component.".into(),
+            })
+        }
+    };
+    if atol.iter().any(|&atol| atol < F::zero() || atol.is_nan()) {
+        return Err(Error::InvalidArg {
+            arg: "atol".into(),
+            reason: "atol must be non-negative.".into(),
+        });
+    }
+    if events.iter().any(|event| event.terminal == Some(0)) {
+        return Err(Error::InvalidArg {
+            arg: "events".into(),
+            reason: "A terminal event must terminate at an occurrence >= 1.".into(),
+        });
+    }
+    let dense_output = dense_output.unwrap_or(false);
+    let y0 = y0.to_owned();
+    let mut rhs = Rhs::new(&mut fun, jac.map(|jac| jac as Jacobian<F>));
+    let f0 = rhs.call(t0, y0.view());
+    if f0.len() != n {
+        return Err(Error::InvalidArg {
+            arg: "fun".into(),
+            reason: "The number of values of `fun` is not equal to the number of components."
+                .into(),
+        });
+    }
+    let method = method.unwrap_or_default();
+    let mut solver: Box<dyn OdeSolver<F>> = match method {
+        OdeMethod::Rk23 | OdeMethod::Rk45 | OdeMethod::Dop853 => Box::new(RungeKutta::new(
+            method,
+            &mut rhs,
+            t0,
+            y0.clone(),
+            f0,
+            t_bound,
+            rtol,
+            atol,
+        )),
+        OdeMethod::Radau | OdeMethod::Bdf => {
+            let j0 = rhs.jacobian(t0, &y0, &f0, &atol);
+            if j0.dim() != (n, n) {
+                return Err(Error::InvalidArg {
+                    arg: "jac".into(),
+                    reason: "The return value of `jac` has wrong shape.".into(),
+                });
+            }
+            if method == OdeMethod::Radau {
+                Box::new(Radau::new(
+                    &mut rhs,
+                    t0,
+                    y0.clone(),
+                    f0,
+                    j0,
+                    t_bound,
+                    rtol,
+                    atol,
+                ))
+            } else {
+                Box::new(Bdf::new(
+                    &mut rhs,
+                    t0,
+                    y0.clone(),
+                    f0,
+                    j0,
+                    t_bound,
+                    rtol,
+                    atol,
+                ))
+            }
+        }
+    };
+
+    let direction = (t_bound - t0).signum();
+    let mut ts = vec![t0];
+    let mut ys = y0.to_vec();
+    let mut interpolants = Vec::new();
+    let mut g: Vec<F> = events
+        .iter_mut()
+        .map(|event| (event.fun)(t0, y0.view()))
+        .collect();
+    let mut counts = vec![0; events.len()];
+    let mut t_events = vec![Vec::new(); events.len()];
+    let mut y_events = vec![Vec::new(); events.len()];
+    let (status, message) = loop {
+        if let Err(message) = solver.step(&mut rhs) {
+            break (-1, message);
+        }
+        let (t_old, mut t) = (solver.t_old(), solver.t());
+        let mut y = solver.y().clone();
+        let mut sol = dense_output.then(|| solver.dense_output(&mut rhs));
+        let mut terminate = false;
+        if !events.is_empty() {
+            let g_new: Vec<F> = events
+                .iter_mut()
+                .map(|event| (event.fun)(t, y.view()))
+                .collect();
+            let active: Vec<usize> = (0..events.len())
+                .filter(|&i| events[i].is_active(g[i], g_new[i]))
+                .collect();
+            if !active.is_empty() {
+                let sol = sol.get_or_insert_with(|| solver.dense_output(&mut rhs));
+                let mut roots: Vec<(usize, F)> = active
+                    .into_iter()
+                    .map(|i| {
+                        counts[i] += 1;
+                        let event = &mut events[i].fun;
+                        let root = brentq(
+                            |s| event(s, sol.eval(s).view()),
+                            t_old,
+                            t,
+                            Some(F::from(4).unwrap() * F::epsilon()),
+                            None,
+                            None,
+                        );
+                        (i, root.map_or(t, |root| root.root))
+                    })
+                    .collect();
+                let reached =
+                    |&(i, _): &(usize, F)| events[i].terminal.is_some_and(|max| counts[i] >= max);
+                if roots.iter().any(reached) {
+                    roots
+                        .sort_by(|a, b| (direction * a.1).partial_cmp(&(direction * b.1)).unwrap());
+                    let last = roots.iter().position(reached).unwrap();
+                    roots.truncate(last + 1);
+                    terminate = true;
+                }
+                for &(i, root) in &roots {
+                    t_events[i].push(root);
+                    y_events[i].extend(sol.eval(root));
+                }
+                if terminate {
+                    t = roots[roots.len() - 1].1;
+                    y = sol.eval(t);
+                }
+            }
+            g = g_new;
+        }
+        ts.push(t);
+        ys.extend(y);
+        if let Some(sol) = sol.filter(|_| dense_output) {
+            interpolants.push(sol);
+        }
+        if terminate {
+            break (1, "A termination event occurred.");
+        }
+        if direction * (t - t_bound) >= F::zero() {
+            break (
+                0,
+                "The solver successfully reached the end of the integration interval.",
+            );
+        }
+    };
+
+    let n_points = ts.len();
+    let y = Array2::from_shape_vec((n_points, n), ys).unwrap();
+    let y_events = y_events
+        .into_iter()
+        .map(|y| Array2::from_shape_vec((y.len() / n, n), y).unwrap())
+        .collect();
+    Ok(OdeResult {
+        sol: dense_output.then(|| OdeSolution::new(ts.clone(), interpolants)),
+        t: Array1::from(ts),
+        y: y.reversed_axes(),
+        t_events,
+        y_events,
+        nfev: rhs.nfev,
+        njev: rhs.njev,
+        nlu: solver.nlu(),
+        status,
+        message: message.into(),
+        success: status >= 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn exponential_decay() {
+        let fun = |_t: f64, y: ArrayView1<f64>| y.mapv(|y| -0.5 * y);
+        let y0 = array![2., 4., 8.];
+        for method in [
+            OdeMethod::Rk23,
+            OdeMethod::Rk45,
+            OdeMethod::Dop853,
+            OdeMethod::Radau,
+            OdeMethod::Bdf,
+        ] {
+            let sol = solve_ivp(
+                fun,
+                (0., 10.),
+                &y0,
+                Some(method),
+                None,
+                Some(1e-8),
+                Some(&[1e-10]),
+                Some(true),
+                &mut [],
+            )
+            .unwrap();
+            assert!(sol.success);
+            assert_eq!(sol.status, 0);
+            assert_eq!(sol.t[sol.t.len() - 1], 10.);
+            for (j, &t) in sol.t.iter().enumerate() {
+                for i in 0..3 {
+                    assert_abs_diff_eq!(sol.y[[i, j]], y0[i] * (-0.5 * t).exp(), epsilon = 1e-6);
+                }
+            }
+            let dense = sol.sol.unwrap();
+            for t in [0.3, 2.7, 5.5, 9.9] {
+                let y = dense.call(t);
+                for i in 0..3 {
+                    assert_abs_diff_eq!(y[i], y0[i] * (-0.5 * t).exp(), epsilon = 1e-6);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn harmonic_oscillator_backwards() {
+        let fun = |_t: f64, y: ArrayView1<f64>| array![y[1], -y[0]];
+        let y0 = array![0., 1.];
+        let sol = solve_ivp(
+            fun,
+            (0., -10.),
+            &y0,
+            Some(OdeMethod::Dop853),
+            None,
+            Some(1e-10),
+            Some(&[1e-12]),
+            Some(true),
+            &mut [],
+        )
+        .unwrap();
+        assert!(sol.success);
+        let last = sol.t.len() - 1;
+        assert_abs_diff_eq!(sol.y[[0, last]], (-10f64).sin(), epsilon = 1e-9);
+        assert_abs_diff_eq!(sol.y[[1, last]], (-10f64).cos(), epsilon = 1e-9);
+        let dense = sol.sol.unwrap();
+        for t in [-0.1, -3.3, -7.7] {
+            assert_abs_diff_eq!(dense.call(t)[0], t.sin(), epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn events() {
+        let fun = |_t: f64, y: ArrayView1<f64>| array![y[1], -y[0]];
+        let y0 = array![1f64.sin(), 1f64.cos()];
+        let mut zero = |_t: f64, y: ArrayView1<f64>| y[0];
+        let mut crossing = |_t: f64, y: ArrayView1<f64>| y[0] - 0.5;
+        let mut events = [
+            Event {
+                direction: EventDirection::Increasing,
+                ..Event::new(&mut zero)
+            },
+            Event {
+                terminal: Some(3),
+                ..Event::new(&mut crossing)
+            },
+        ];
+        let sol = solve_ivp(
+            fun,
+            (1., 20.),
+            &y0,
+            None,
+            None,
+            Some(1e-8),
+            Some(&[1e-10]),
+            None,
+            &mut events,
+        )
+        .unwrap();
+        assert_eq!(sol.status, 1);
+        let pi = core::f64::consts::PI;
+        // y = sin(t) crosses 0.5 at 5 pi / 6, 13 pi / 6 and 17 pi / 6, and zero upwards at 2 pi.
+        assert_eq!(sol.t_events[0].len(), 1);
+        assert_abs_diff_eq!(sol.t_events[0][0], 2. * pi, epsilon = 1e-6);
+        let expected = [5. * pi / 6., 13. * pi / 6., 17. * pi / 6.];
+        assert_eq!(sol.t_events[1].len(), 3);
+        for (&t, e) in sol.t_events[1].iter().zip(expected) {
+            assert_abs_diff_eq!(t, e, epsilon = 1e-6);
+        }
+        assert_abs_diff_eq!(sol.y_events[1][[2, 0]], 0.5, epsilon = 1e-6);
+        assert_abs_diff_eq!(sol.t[sol.t.len() - 1], 17. * pi / 6., epsilon = 1e-6);
+    }
+
+    #[test]
+    fn stiff_robertson() {
+        let rober = |_t: f64, y: ArrayView1<f64>| {
+            array![
+                -0.04 * y[0] + 1e4 * y[1] * y[2],
+                0.04 * y[0] - 1e4 * y[1] * y[2] - 3e7 * y[1].powi(2),
+                3e7 * y[1].powi(2)
+            ]
+        };
+        let y0 = array![1., 0., 0.];
+        for method in [OdeMethod::Bdf, OdeMethod::Radau] {
+            let sol = solve_ivp(
+                rober,
+                (0., 1e5),
+                &y0,
+                Some(method),
+                None,
+                Some(1e-6),
+                Some(&[1e-10]),
+                Some(true),
+                &mut [],
+            )
+            .unwrap();
+            assert!(sol.success);
+            assert!(sol.njev > 0 && sol.nlu > 0);
+            // Far fewer steps than an explicit method, which is limited by stability.
+            assert!(sol.t.len() < 500);
+            let last = sol.t.len() - 1;
+            assert_abs_diff_eq!(sol.y[[0, last]], 1.786592e-2, epsilon = 1e-5);
+            assert_abs_diff_eq!(sol.y[[2, last]], 9.821340e-1, epsilon = 1e-5);
+            let y = sol.sol.unwrap().call(40.);
+            assert_abs_diff_eq!(y[0], 7.158271e-1, epsilon = 1e-5);
+            assert_abs_diff_eq!(y[1], 9.185535e-6, epsilon = 1e-9);
+        }
+    }
+}
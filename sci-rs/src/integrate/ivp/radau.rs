@@ -0,0 +1,457 @@
+use super::common::{
+    lu, min_step, newton_tol, rms_norm, select_initial_step, solve_lu, Interpolant, OdeSolver, Rhs,
+};
+use crate::linalg::LuFactor;
+use ndarray::{Array1, Array2, Zip};
+use num_traits::Float;
+
+/// Maximum number of iterations of the simplified Newton method.
+const NEWTON_MAXITER: usize = 6;
+/// Multiply the step size by at least this factor on a rejected step.
+const MIN_FACTOR: f64 = 0.2;
+/// Multiply the step size by at most this factor on an accepted step.
+const MAX_FACTOR: f64 = 10.;
+
+/// Transformation of the collocation system to the real and complex eigenvalues of the inverse
+/// of the Butcher matrix, and its inverse.
+#[allow(clippy::excessive_precision)]
+const T: [[f64; 3]; 3] = [
+    [
+        0.09443876248897524,
+        -0.14125529502095421,
+        0.03002919410514742,
+    ],
+    [
+        0.25021312296533332,
+        0.20412935229379994,
+        -0.38294211275726192,
+    ],
+    [1., 1., 0.],
+];
+#[allow(clippy::excessive_precision)]
+const TI: [[f64; 3]; 3] = [
+    [
+        4.17871859155190428,
+        0.32768282076106237,
+        0.52337644549944951,
+    ],
+    [
+        -4.17871859155190428,
+        -0.32768282076106237,
+        0.47662355450055044,
+    ],
+    [
+        0.50287263494578682,
+        -2.57192694985560522,
+        0.59603920482822492,
+    ],
+];
+
+/// Constants of the Radau IIA method of order 5, which depend on `sqrt(6)` and `3^(1 / 3)`.
+struct Constants<F> {
+    /// Collocation nodes.
+    c: [F; 3],
+    /// Error estimator.
+    e: [F; 3],
+    /// Real eigenvalue of the inverse of the Butcher matrix.
+    mu_real: F,
+    /// Real and imaginary parts of the complex eigenvalue.
+    mu_complex: (F, F),
+    /// Coefficients of the interpolant.
+    p: [[F; 3]; 3],
+}
+
+impl<F: Float> Constants<F> {
+    fn new() -> Self {
+        let f = |x: f64| F::from(x).unwrap();
+        let s6 = 6f64.sqrt();
+        let (c3, c3_2) = (3f64.cbrt(), 3f64.cbrt().powi(2));
+        Constants {
+            c: [f((4. - s6) / 10.), f((4. + s6) / 10.), F::one()],
+            e: [
+                f((-13. - 7. * s6) / 3.),
+                f((-13. + 7. * s6) / 3.),
+                f(-1. / 3.),
+            ],
+            mu_real: f(3. + c3_2 - c3),
+            mu_complex: (
+                f(3. + 0.5 * (c3 - c3_2)),
+                f(-0.5 * (3f64.powf(5. / 6.) + 3f64.powf(7. / 6.))),
+            ),
+            p: [
+                [
+                    f(13. / 3. + 7. * s6 / 3.),
+                    f(-23. / 3. - 22. * s6 / 3.),
+                    f(10. / 3. + 5. * s6),
+                ],
+                [
+                    f(13. / 3. - 7. * s6 / 3.),
+                    f(-23. / 3. + 22. * s6 / 3.),
+                    f(10. / 3. - 5. * s6),
+                ],
+                [f(1. / 3.), f(-8. / 3.), f(10. / 3.)],
+            ],
+        }
+    }
+}
+
+/// `m^T x` for a 3x3 matrix `m` of constants and the rows of `x`.
+fn combine<F: Float>(m: &[[F; 3]; 3], x: &Array2<F>) -> Array2<F> {
+    Array2::from_shape_fn(x.dim(), |(i, k)| {
+        (0..3).fold(F::zero(), |acc, j| acc + m[i][j] * x[[j, k]])
+    })
+}
+
+/// Implicit Runge-Kutta method of the Radau IIA family of order 5.
+pub(super) struct Radau<F> {
+    constants: Constants<F>,
+    t: F,
+    y: Array1<F>,
+    f: Array1<F>,
+    t_old: F,
+    t_bound: F,
+    direction: F,
+    h_abs: F,
+    h_abs_old: Option<F>,
+    error_norm_old: Option<F>,
+    rtol: F,
+    atol: Array1<F>,
+    newton_tol: F,
+    jac: Array2<F>,
+    current_jac: bool,
+    lu_real: Option<LuFactor<F>>,
+    lu_complex: Option<LuFactor<F>>,
+    nlu: usize,
+    sol: Option<Interpolant<F>>,
+}
+
+impl<F: Float> Radau<F> {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new(
+        rhs: &mut Rhs<'_, F>,
+        t0: F,
+        y0: Array1<F>,
+        f0: Array1<F>,
+        jac: Array2<F>,
+        t_bound: F,
+        rtol: F,
+        atol: Array1<F>,
+    ) -> Self {
+        let h_abs = select_initial_step(rhs, t0, &y0, t_bound, &f0, 3, rtol, &atol);
+        Radau {
+            constants: Constants::new(),
+            t: t0,
+            y: y0,
+            f: f0,
+            t_old: t0,
+            t_bound,
+            direction: (t_bound - t0).signum(),
+            h_abs,
+            h_abs_old: None,
+            error_norm_old: None,
+            rtol,
+            atol,
+            newton_tol: newton_tol(rtol),
+            jac,
+            current_jac: true,
+            lu_real: None,
+            lu_complex: None,
+            nlu: 0,
+            sol: None,
+        }
+    }
+
+    /// Factorize `mu_real / h - J`, and the real form of `mu_complex / h - J`.
+    fn factorize(&mut self, h: F) -> Option<()> {
+        let n = self.y.len();
+        let (a, b) = (
+            self.constants.mu_complex.0 / h,
+            self.constants.mu_complex.1 / h,
+        );
+        let mut real = self.jac.mapv(|j| -j);
+        real.diag_mut()
+            .mapv_inplace(|d| d + self.constants.mu_real / h);
+        let mut complex = Array2::zeros((2 * n, 2 * n));
+        for i in 0..n {
+            for j in 0..n {
+                complex[[i, j]] = -self.jac[[i, j]];
+                complex[[n + i, n + j]] = -self.jac[[i, j]];
+            }
+            complex[[i, i]] = complex[[i, i]] + a;
+            complex[[n + i, n + i]] = complex[[n + i, n + i]] + a;
+            complex[[i, n + i]] = -b;
+            complex[[n + i, i]] = b;
+        }
+        self.nlu += 2;
+        self.lu_real = Some(lu(&real)?);
+        self.lu_complex = Some(lu(&complex)?);
+        Some(())
+    }
+
+    /// Solve the collocation system by simplified Newton iterations, from the initial guess
+    /// `z0` of the increments of the stages. Returns whether it converged, the number of
+    /// iterations, the increments and the rate of convergence.
+    fn solve_collocation_system(
+        &self,
+        rhs: &mut Rhs<'_, F>,
+        h: F,
+        z0: Array2<F>,
+        scale: &Array1<F>,
+    ) -> (bool, usize, Array2<F>, Option<F>) {
+        let n = self.y.len();
+        let constants = &self.constants;
+        let ti = TI.map(|row| row.map(|x| F::from(x).unwrap()));
+        let t = T.map(|row| row.map(|x| F::from(x).unwrap()));
+        let (lu_real, lu_complex) = (self.lu_real.as_ref(), self.lu_complex.as_ref());
+        let (lu_real, lu_complex) = (lu_real.unwrap(), lu_complex.unwrap());
+        let m_real = constants.mu_real / h;
+        let (a, b) = (constants.mu_complex.0 / h, constants.mu_complex.1 / h);
+        let mut w = combine(&ti, &z0);
+        let mut z = z0;
+        let mut f = Array2::zeros((3, n));
+        let mut dw_norm_old = None;
+        let mut rate = None;
+        for k in 0..NEWTON_MAXITER {
+            for i in 0..3 {
+                let y = &self.y + &z.row(i);
+                f.row_mut(i)
+                    .assign(&rhs.call(self.t + constants.c[i] * h, y.view()));
+            }
+            if f.iter().any(|f| !f.is_finite()) {
+                break;
+            }
+            let tf = combine(&ti, &f);
+            let f_real = Zip::from(tf.row(0))
+                .and(w.row(0))
+                .map_collect(|&f, &w| f - m_real * w);
+            let mut f_complex = Array1::zeros(2 * n);
+            for i in 0..n {
+                f_complex[i] = tf[[1, i]] - (a * w[[1, i]] - b * w[[2, i]]);
+                f_complex[n + i] = tf[[2, i]] - (a * w[[2, i]] + b * w[[1, i]]);
+            }
+            let (Some(dw_real), Some(dw_complex)) =
+                (solve_lu(lu_real, &f_real), solve_lu(lu_complex, &f_complex))
+            else {
+                break;
+            };
+            let mut dw = Array2::zeros((3, n));
+            dw.row_mut(0).assign(&dw_real);
+            for i in 0..n {
+                dw[[1, i]] = dw_complex[i];
+                dw[[2, i]] = dw_complex[n + i];
+            }
+            let dw_norm = rms_norm(
+                Zip::from(&dw)
+                    .and_broadcast(scale)
+                    .map_collect(|&dw, &s| dw / s)
+                    .into_shape_with_order(3 * n)
+                    .unwrap()
+                    .view(),
+            );
+            if let Some(dw_norm_old) = dw_norm_old {
+                rate = Some(dw_norm / dw_norm_old);
+            }
+            let remaining = (NEWTON_MAXITER - k) as i32;
+            if let Some(rate) = rate {
+                if rate >= F::one()
+                    || rate.powi(remaining) / (F::one() - rate) * dw_norm > self.newton_tol
+                {
+                    return (false, k + 1, z, Some(rate));
+                }
+            }
+            w = w + dw;
+            z = combine(&t, &w);
+            if dw_norm.is_zero()
+                || rate.is_some_and(|rate| rate / (F::one() - rate) * dw_norm < self.newton_tol)
+            {
+                return (true, k + 1, z, rate);
+            }
+            dw_norm_old = Some(dw_norm);
+        }
+        (false, NEWTON_MAXITER, z, rate)
+    }
+}
+
+/// Factor of the step size, with the predictive controller of Gustafsson.
+fn predict_factor<F: Float>(
+    h_abs: F,
+    h_abs_old: Option<F>,
+    error_norm: F,
+    error_norm_old: Option<F>,
+) -> F {
+    let quarter = F::from(0.25).unwrap();
+    let multiplier = match (h_abs_old, error_norm_old) {
+        (Some(h_abs_old), Some(error_norm_old)) if !error_norm.is_zero() => {
+            h_abs / h_abs_old * (error_norm_old / error_norm).powf(quarter)
+        }
+        _ => F::one(),
+    };
+    multiplier.min(F::one()) * error_norm.powf(-quarter)
+}
+
+impl<F: Float> OdeSolver<F> for Radau<F> {
+    fn t_old(&self) -> F {
+        self.t_old
+    }
+
+    fn t(&self) -> F {
+        self.t
+    }
+
+    fn y(&self) -> &Array1<F> {
+        &self.y
+    }
+
+    fn nlu(&self) -> usize {
+        self.nlu
+    }
+
+    fn step(&mut self, rhs: &mut Rhs<'_, F>) -> core::result::Result<(), &'static str> {
+        let t = self.t;
+        let n = self.y.len();
+        let min_step = min_step(t);
+        let (mut h_abs, h_abs_old, error_norm_old) = if self.h_abs < min_step {
+            (min_step, None, None)
+        } else {
+            (self.h_abs, self.h_abs_old, self.error_norm_old)
+        };
+        let mut rejected = false;
+        let (t_new, y_new, z, n_iter, rate, error_norm) = loop {
+            if h_abs < min_step {
+                return Err("Required step size is less than spacing between numbers.");
+            }
+            let mut t_new = t + h_abs * self.direction;
+            if self.direction * (t_new - self.t_bound) > F::zero() {
+                t_new = self.t_bound;
+            }
+            let h = t_new - t;
+            h_abs = h.abs();
+            let z0 = match &self.sol {
+                None => Array2::zeros((3, n)),
+                Some(sol) => {
+                    let mut z0 = Array2::zeros((3, n));
+                    for i in 0..3 {
+                        z0.row_mut(i)
+                            .assign(&(sol.eval(t + h * self.constants.c[i]) - &self.y));
+                    }
+                    z0
+                }
+            };
+            let scale = Zip::from(&self.y)
+                .and(&self.atol)
+                .map_collect(|&y, &atol| atol + y.abs() * self.rtol);
+            let mut solution = None;
+            loop {
+                if (self.lu_real.is_none() || self.lu_complex.is_none())
+                    && self.factorize(h).is_none()
+                {
+                    break;
+                }
+                let result = self.solve_collocation_system(rhs, h, z0.clone(), &scale);
+                if result.0 {
+                    solution = Some(result);
+                    break;
+                }
+                if self.current_jac {
+                    break;
+                }
+                self.jac = rhs.jacobian(t, &self.y, &self.f, &self.atol);
+                self.current_jac = true;
+                self.lu_real = None;
+                self.lu_complex = None;
+            }
+            let Some((_, n_iter, z, rate)) = solution else {
+                h_abs = h_abs * F::from(0.5).unwrap();
+                self.lu_real = None;
+                self.lu_complex = None;
+                continue;
+            };
+
+            let y_new = &self.y + &z.row(2);
+            let ze =
+                Zip::from(z.row(0))
+                    .and(z.row(1))
+                    .and(z.row(2))
+                    .map_collect(|&z0, &z1, &z2| {
+                        (z0 * self.constants.e[0]
+                            + z1 * self.constants.e[1]
+                            + z2 * self.constants.e[2])
+                            / h
+                    });
+            let lu_real = self.lu_real.as_ref().unwrap();
+            let scale = Zip::from(&self.y)
+                .and(&y_new)
+                .and(&self.atol)
+                .map_collect(|&y, &y_new, &atol| atol + y.abs().max(y_new.abs()) * self.rtol);
+            let mut error = solve_lu(lu_real, &(&self.f + &ze));
+            let mut error_norm = error
+                .as_ref()
+                .map_or(F::infinity(), |error| rms_norm((error / &scale).view()));
+            if rejected && error_norm > F::one() {
+                if let Some(e) = error.as_ref() {
+                    let f = rhs.call(t, (&self.y + e).view());
+                    error = solve_lu(lu_real, &(f + &ze));
+                    error_norm = error
+                        .as_ref()
+                        .map_or(F::infinity(), |error| rms_norm((error / &scale).view()));
+                }
+            }
+            if error_norm > F::one() {
+                let safety = safety::<F>(n_iter);
+                let factor = predict_factor(h_abs, h_abs_old, error_norm, error_norm_old);
+                h_abs = h_abs * F::from(MIN_FACTOR).unwrap().max(safety * factor);
+                self.lu_real = None;
+                self.lu_complex = None;
+                rejected = true;
+                continue;
+            }
+            break (t_new, y_new, z, n_iter, rate, error_norm);
+        };
+
+        let recompute_jac = n_iter > 2 && rate.is_some_and(|rate| rate > F::from(1e-3).unwrap());
+        let factor = predict_factor(h_abs, h_abs_old, error_norm, error_norm_old);
+        let mut factor = F::from(MAX_FACTOR)
+            .unwrap()
+            .min(safety::<F>(n_iter) * factor);
+        if !recompute_jac && factor < F::from(1.2).unwrap() {
+            factor = F::one();
+        } else {
+            self.lu_real = None;
+            self.lu_complex = None;
+        }
+        let f_new = rhs.call(t_new, y_new.view());
+        if recompute_jac {
+            self.jac = rhs.jacobian(t_new, &y_new, &f_new, &self.atol);
+            self.current_jac = true;
+        } else {
+            self.current_jac = false;
+        }
+        self.h_abs_old = Some(self.h_abs);
+        self.error_norm_old = Some(error_norm);
+        self.h_abs = h_abs * factor;
+        let h = t_new - t;
+        let p = &self.constants.p;
+        let q = Array2::from_shape_fn((n, 3), |(i, m)| {
+            (0..3).fold(F::zero(), |acc, j| acc + z[[j, i]] * p[j][m]) / h
+        });
+        self.sol = Some(Interpolant::Polynomial {
+            t_old: t,
+            h,
+            y_old: core::mem::replace(&mut self.y, y_new),
+            q,
+        });
+        self.t_old = t;
+        self.t = t_new;
+        self.f = f_new;
+        Ok(())
+    }
+
+    fn dense_output(&mut self, _rhs: &mut Rhs<'_, F>) -> Interpolant<F> {
+        self.sol.clone().unwrap()
+    }
+}
+
+/// Safety factor of the step size, smaller when the Newton iterations converge slowly.
+fn safety<F: Float>(n_iter: usize) -> F {
+    F::from(0.9 * (2 * NEWTON_MAXITER + 1) as f64 / (2 * NEWTON_MAXITER + n_iter) as f64).unwrap()
+}
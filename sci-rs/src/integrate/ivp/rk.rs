@@ -0,0 +1,628 @@
+use super::common::{axpy, min_step, rms_norm, select_initial_step, Interpolant, OdeSolver, Rhs};
+use super::OdeMethod;
+use alloc::vec::Vec;
+use ndarray::{Array1, Array2, Zip};
+use num_traits::Float;
+
+/// Multiply the step size by at least this factor on a rejected step.
+const MIN_FACTOR: f64 = 0.2;
+/// Multiply the step size by at most this factor on an accepted step.
+const MAX_FACTOR: f64 = 10.;
+/// Safety factor of the step size prediction.
+const SAFETY: f64 = 0.9;
+
+/// Butcher tableau of an embedded explicit Runge-Kutta pair.
+///
+/// `a[s]` holds the coefficients of stage `s` on the previous stages, the stage past the last
+/// being the derivative at the new point. The interpolant of the step is given by `p` for
+/// Bogacki-Shampine and Dormand-Prince, and by the extra stages and `D` for DOP853.
+struct Tableau {
+    n_stages: usize,
+    error_estimator_order: i32,
+    c: &'static [f64],
+    a: &'static [&'static [f64]],
+    b: &'static [f64],
+    e: &'static [f64],
+    p: &'static [&'static [f64]],
+}
+
+const RK23: Tableau = Tableau {
+    n_stages: 3,
+    error_estimator_order: 2,
+    c: &[0., 1. / 2., 3. / 4.],
+    a: &[&[], &[1. / 2.], &[0., 3. / 4.]],
+    b: &[2. / 9., 1. / 3., 4. / 9.],
+    e: &[5. / 72., -1. / 12., -1. / 9., 1. / 8.],
+    p: &[
+        &[1., -4. / 3., 5. / 9.],
+        &[0., 1., -2. / 3.],
+        &[0., 4. / 3., -8. / 9.],
+        &[0., -1., 1.],
+    ],
+};
+
+const RK45: Tableau = Tableau {
+    n_stages: 6,
+    error_estimator_order: 4,
+    c: &[0., 1. / 5., 3. / 10., 4. / 5., 8. / 9., 1.],
+    a: &[
+        &[],
+        &[1. / 5.],
+        &[3. / 40., 9. / 40.],
+        &[44. / 45., -56. / 15., 32. / 9.],
+        &[
+            19372. / 6561.,
+            -25360. / 2187.,
+            64448. / 6561.,
+            -212. / 729.,
+        ],
+        &[
+            9017. / 3168.,
+            -355. / 33.,
+            46732. / 5247.,
+            49. / 176.,
+            -5103. / 18656.,
+        ],
+    ],
+    b: &[
+        35. / 384.,
+        0.,
+        500. / 1113.,
+        125. / 192.,
+        -2187. / 6784.,
+        11. / 84.,
+    ],
+    e: &[
+        -71. / 57600.,
+        0.,
+        71. / 16695.,
+        -71. / 1920.,
+        17253. / 339200.,
+        -22. / 525.,
+        1. / 40.,
+    ],
+    p: &[
+        &[
+            1.,
+            -8048581381. / 2820520608.,
+            8663915743. / 2820520608.,
+            -12715105075. / 11282082432.,
+        ],
+        &[0., 0., 0., 0.],
+        &[
+            0.,
+            131558114200. / 32700410799.,
+            -68118460800. / 10900136933.,
+            87487479700. / 32700410799.,
+        ],
+        &[
+            0.,
+            -1754552775. / 470086768.,
+            14199869525. / 1410260304.,
+            -10690763975. / 1880347072.,
+        ],
+        &[
+            0.,
+            127303824393. / 49829197408.,
+            -318862633887. / 49829197408.,
+            701980252875. / 199316789632.,
+        ],
+        &[
+            0.,
+            -282668133. / 205662961.,
+            2019193451. / 616988883.,
+            -1453857185. / 822651844.,
+        ],
+        &[
+            0.,
+            40617522. / 29380423.,
+            -110615467. / 29380423.,
+            69997945. / 29380423.,
+        ],
+    ],
+};
+
+/// Coefficients of DOP853, from the Fortran code of Hairer. Stages 13 to 15 are only evaluated
+/// for the dense output.
+#[allow(clippy::excessive_precision)]
+const DOP853: Tableau = Tableau {
+    n_stages: 12,
+    error_estimator_order: 7,
+    c: &[
+        0.,
+        0.526001519587677318785587544488e-1,
+        0.789002279381515978178381316732e-1,
+        0.118350341907227396726757197510,
+        0.281649658092772603273242802490,
+        0.333333333333333333333333333333,
+        0.25,
+        0.307692307692307692307692307692,
+        0.651282051282051282051282051282,
+        0.6,
+        0.857142857142857142857142857142,
+        1.,
+        1.,
+        0.1,
+        0.2,
+        0.777777777777777777777777777778,
+    ],
+    a: &[
+        &[],
+        &[5.26001519587677318785587544488e-2],
+        &[
+            1.97250569845378994544595329183e-2,
+            5.91751709536136983633785987549e-2,
+        ],
+        &[
+            2.95875854768068491816892993775e-2,
+            0.,
+            8.87627564304205475450678981324e-2,
+        ],
+        &[
+            2.41365134159266685502369798665e-1,
+            0.,
+            -8.84549479328286085344864962717e-1,
+            9.24834003261792003115737966543e-1,
+        ],
+        &[
+            3.7037037037037037037037037037e-2,
+            0.,
+            0.,
+            1.70828608729473871279604482173e-1,
+            1.25467687566822425016691814123e-1,
+        ],
+        &[
+            3.7109375e-2,
+            0.,
+            0.,
+            1.70252211019544039314978060272e-1,
+            6.02165389804559606850219397283e-2,
+            -1.7578125e-2,
+        ],
+        &[
+            3.70920001185047927108779319836e-2,
+            0.,
+            0.,
+            1.70383925712239993810214054705e-1,
+            1.07262030446373284651809199168e-1,
+            -1.53194377486244017527936158236e-2,
+            8.27378916381402288758473766002e-3,
+        ],
+        &[
+            6.24110958716075717114429577812e-1,
+            0.,
+            0.,
+            -3.36089262944694129406857109825,
+            -8.68219346841726006818189891453e-1,
+            2.75920996994467083049415600797e1,
+            2.01540675504778934086186788979e1,
+            -4.34898841810699588477366255144e1,
+        ],
+        &[
+            4.77662536438264365890433908527e-1,
+            0.,
+            0.,
+            -2.48811461997166764192642586468,
+            -5.90290826836842996371446475743e-1,
+            2.12300514481811942347288949897e1,
+            1.52792336328824235832596922938e1,
+            -3.32882109689848629194453265587e1,
+            -2.03312017085086261358222928593e-2,
+        ],
+        &[
+            -9.3714243008598732571704021658e-1,
+            0.,
+            0.,
+            5.18637242884406370830023853209,
+            1.09143734899672957818500254654,
+            -8.14978701074692612513997267357,
+            -1.85200656599969598641566180701e1,
+            2.27394870993505042818970056734e1,
+            2.49360555267965238987089396762,
+            -3.0467644718982195003823669022,
+        ],
+        &[
+            2.27331014751653820792359768449,
+            0.,
+            0.,
+            -1.05344954667372501984066689879e1,
+            -2.00087205822486249909675718444,
+            -1.79589318631187989172765950534e1,
+            2.79488845294199600508499808837e1,
+            -2.85899827713502369474065508674,
+            -8.87285693353062954433549289258,
+            1.23605671757943030647266201528e1,
+            6.43392746015763530355970484046e-1,
+        ],
+        &[],
+        &[
+            5.61675022830479523392909219681e-2,
+            0.,
+            0.,
+            0.,
+            0.,
+            0.,
+            2.53500210216624811088794765333e-1,
+            -2.46239037470802489917441475441e-1,
+            -1.24191423263816360469010140626e-1,
+            1.5329179827876569731206322685e-1,
+            8.20105229563468988491666602057e-3,
+            7.56789766054569976138603589584e-3,
+            -8.298e-3,
+        ],
+        &[
+            3.18346481635021405060768473261e-2,
+            0.,
+            0.,
+            0.,
+            0.,
+            2.83009096723667755288322961402e-2,
+            5.35419883074385676223797384372e-2,
+            -5.49237485713909884646569340306e-2,
+            0.,
+            0.,
+            -1.08347328697249322858509316994e-4,
+            3.82571090835658412954920192323e-4,
+            -3.40465008687404560802977114492e-4,
+            1.41312443674632500278074618366e-1,
+        ],
+        &[
+            -4.28896301583791923408573538692e-1,
+            0.,
+            0.,
+            0.,
+            0.,
+            -4.69762141536116384314449447206,
+            7.68342119606259904184240953878,
+            4.06898981839711007970213554331,
+            3.56727187455281109270669543021e-1,
+            0.,
+            0.,
+            0.,
+            -1.39902416515901462129418009734e-3,
+            2.9475147891527723389556272149,
+            -9.15095847217987001081870187138,
+        ],
+    ],
+    b: &[
+        5.42937341165687622380535766363e-2,
+        0.,
+        0.,
+        0.,
+        0.,
+        4.45031289275240888144113950566,
+        1.89151789931450038304281599044,
+        -5.8012039600105847814672114227,
+        3.1116436695781989440891606237e-1,
+        -1.52160949662516078556178806805e-1,
+        2.01365400804030348374776537501e-1,
+        4.47106157277725905176885569043e-2,
+    ],
+    e: &[
+        0.1312004499419488073250102996e-1,
+        0.,
+        0.,
+        0.,
+        0.,
+        -0.1225156446376204440720569753e1,
+        -0.4957589496572501915214079952,
+        0.1664377182454986536961530415e1,
+        -0.3503288487499736816886487290,
+        0.3341791187130174790297318841,
+        0.8192320648511571246570742613e-1,
+        -0.2235530786388629525884427845e-1,
+        0.,
+    ],
+    p: &[
+        &[
+            -0.84289382761090128651353491142e1,
+            0.,
+            0.,
+            0.,
+            0.,
+            0.56671495351937776962531783590,
+            -0.30689499459498916912797304727e1,
+            0.23846676565120698287728149680e1,
+            0.21170345824450282767155149946e1,
+            -0.87139158377797299206789907490,
+            0.22404374302607882758541771650e1,
+            0.63157877876946881815570249290,
+            -0.88990336451333310820698117400e-1,
+            0.18148505520854727256656404962e2,
+            -0.91946323924783554000451984436e1,
+            -0.44360363875948939664310572000e1,
+        ],
+        &[
+            0.10427508642579134603413151009e2,
+            0.,
+            0.,
+            0.,
+            0.,
+            0.24228349177525818288430175319e3,
+            0.16520045171727028198505394887e3,
+            -0.37454675472269020279518312152e3,
+            -0.22113666853125306036270938578e2,
+            0.77334326684722638389603898808e1,
+            -0.30674084731089398182061213626e2,
+            -0.93321305264302278729567221706e1,
+            0.15697238121770843886131091075e2,
+            -0.31139403219565177677282850411e2,
+            -0.93529243588444783865713862664e1,
+            0.35816841486394083752465898540e2,
+        ],
+        &[
+            0.19985053242002433820987653617e2,
+            0.,
+            0.,
+            0.,
+            0.,
+            -0.38703730874935176555105901742e3,
+            -0.18917813819516756882830838328e3,
+            0.52780815920542364900561016686e3,
+            -0.11573902539959630126141871134e2,
+            0.68812326946963000169666922661e1,
+            -0.10006050966910838403183860980e1,
+            0.77771377980534432092869265740,
+            -0.27782057523535084065932004339e1,
+            -0.60196695231264120758267380846e2,
+            0.84320405506677161018159903784e2,
+            0.11992291136182789328035130030e2,
+        ],
+        &[
+            -0.25693933462703749003312586129e2,
+            0.,
+            0.,
+            0.,
+            0.,
+            -0.15418974869023643374053993627e3,
+            -0.23152937917604549567536039109e3,
+            0.35763911791061412378285349910e3,
+            0.93405324183624310003907691704e2,
+            -0.37458323136451633156875139351e2,
+            0.10409964950896230045147246184e3,
+            0.29840293426660503123344363579e2,
+            -0.43533456590011143754432175058e2,
+            0.96324553959188282948394950600e2,
+            -0.39177261675615439165231486172e2,
+            -0.14972683625798562581422125276e3,
+        ],
+    ],
+};
+
+/// The third order weights of the second error estimator of DOP853, subtracted from `b`.
+#[allow(clippy::excessive_precision)]
+const DOP853_BHH: [(usize, f64); 3] = [
+    (0, 0.244094488188976377952755905512),
+    (8, 0.733846688281611857341361741547),
+    (11, 0.220588235294117647058823529412e-1),
+];
+
+/// Explicit Runge-Kutta method with an embedded error estimator.
+pub(super) struct RungeKutta<F> {
+    tableau: &'static Tableau,
+    dop853: bool,
+    t: F,
+    y: Array1<F>,
+    f: Array1<F>,
+    t_old: F,
+    y_old: Array1<F>,
+    t_bound: F,
+    direction: F,
+    h_abs: F,
+    h_previous: F,
+    rtol: F,
+    atol: Array1<F>,
+    k: Array2<F>,
+}
+
+impl<F: Float> RungeKutta<F> {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new(
+        method: OdeMethod,
+        rhs: &mut Rhs<'_, F>,
+        t0: F,
+        y0: Array1<F>,
+        f0: Array1<F>,
+        t_bound: F,
+        rtol: F,
+        atol: Array1<F>,
+    ) -> Self {
+        let tableau = match method {
+            OdeMethod::Rk23 => &RK23,
+            OdeMethod::Rk45 => &RK45,
+            OdeMethod::Dop853 => &DOP853,
+            OdeMethod::Radau | OdeMethod::Bdf => unreachable!("not an explicit method"),
+        };
+        let h_abs = select_initial_step(
+            rhs,
+            t0,
+            &y0,
+            t_bound,
+            &f0,
+            tableau.error_estimator_order,
+            rtol,
+            &atol,
+        );
+        let n = y0.len();
+        RungeKutta {
+            tableau,
+            dop853: method == OdeMethod::Dop853,
+            t: t0,
+            y_old: y0.clone(),
+            y: y0,
+            f: f0,
+            t_old: t0,
+            t_bound,
+            direction: (t_bound - t0).signum(),
+            h_abs,
+            h_previous: F::zero(),
+            rtol,
+            atol,
+            k: Array2::zeros((tableau.c.len().max(tableau.n_stages + 1), n)),
+        }
+    }
+
+    /// `y + h * sum(a[j] * k[j])`.
+    fn stage_input(&self, y: &Array1<F>, h: F, a: &[f64]) -> Array1<F> {
+        let mut y = y.clone();
+        for (j, &a) in a.iter().enumerate().filter(|(_, &a)| a != 0.) {
+            axpy(&mut y, h * F::from(a).unwrap(), self.k.row(j));
+        }
+        y
+    }
+
+    /// Weighted sum of the stages, `sum(w[j] * k[j])`.
+    fn combine(&self, w: &[f64]) -> Array1<F> {
+        let mut sum = Array1::zeros(self.y.len());
+        for (j, &w) in w.iter().enumerate().filter(|(_, &w)| w != 0.) {
+            axpy(&mut sum, F::from(w).unwrap(), self.k.row(j));
+        }
+        sum
+    }
+
+    fn error_norm(&self, h: F, scale: &Array1<F>) -> F {
+        if !self.dop853 {
+            let error = self.combine(self.tableau.e) / scale;
+            return h.abs() * rms_norm(error.view());
+        }
+        let err5 = self.combine(self.tableau.e) / scale;
+        let mut e3: Vec<f64> = self.tableau.b.to_vec();
+        for (j, bhh) in DOP853_BHH {
+            e3[j] -= bhh;
+        }
+        let err3 = self.combine(&e3) / scale;
+        let err5 = err5.fold(F::zero(), |acc, &e| acc + e * e);
+        let err3 = err3.fold(F::zero(), |acc, &e| acc + e * e);
+        if err5.is_zero() && err3.is_zero() {
+            return F::zero();
+        }
+        let denom = err5 + F::from(0.01).unwrap() * err3;
+        h.abs() * err5 / Float::sqrt(denom * F::from(scale.len()).unwrap())
+    }
+}
+
+impl<F: Float> OdeSolver<F> for RungeKutta<F> {
+    fn t_old(&self) -> F {
+        self.t_old
+    }
+
+    fn t(&self) -> F {
+        self.t
+    }
+
+    fn y(&self) -> &Array1<F> {
+        &self.y
+    }
+
+    fn step(&mut self, rhs: &mut Rhs<'_, F>) -> core::result::Result<(), &'static str> {
+        let tableau = self.tableau;
+        let (safety, min_factor, max_factor) = (
+            F::from(SAFETY).unwrap(),
+            F::from(MIN_FACTOR).unwrap(),
+            F::from(MAX_FACTOR).unwrap(),
+        );
+        let exponent = -F::one() / F::from(tableau.error_estimator_order + 1).unwrap();
+        let t = self.t;
+        let min_step = min_step(t);
+        let mut h_abs = self.h_abs.max(min_step);
+        let mut rejected = false;
+        loop {
+            if h_abs < min_step {
+                return Err("Required step size is less than spacing between numbers.");
+            }
+            let mut t_new = t + h_abs * self.direction;
+            if self.direction * (t_new - self.t_bound) > F::zero() {
+                t_new = self.t_bound;
+            }
+            let h = t_new - t;
+            h_abs = h.abs();
+
+            self.k.row_mut(0).assign(&self.f);
+            for s in 1..tableau.n_stages {
+                let y = self.stage_input(&self.y, h, tableau.a[s]);
+                let k = rhs.call(t + F::from(tableau.c[s]).unwrap() * h, y.view());
+                self.k.row_mut(s).assign(&k);
+            }
+            let y_new = self.stage_input(&self.y, h, tableau.b);
+            let f_new = rhs.call(t_new, y_new.view());
+            self.k.row_mut(tableau.n_stages).assign(&f_new);
+
+            let scale = Zip::from(&self.y)
+                .and(&y_new)
+                .and(&self.atol)
+                .map_collect(|&y, &y_new, &atol| atol + y.abs().max(y_new.abs()) * self.rtol);
+            let error_norm = self.error_norm(h, &scale);
+            if error_norm < F::one() {
+                let mut factor = if error_norm.is_zero() {
+                    max_factor
+                } else {
+                    max_factor.min(safety * error_norm.powf(exponent))
+                };
+                if rejected {
+                    factor = factor.min(F::one());
+                }
+                self.h_previous = h;
+                self.t_old = t;
+                self.t = t_new;
+                self.y_old = core::mem::replace(&mut self.y, y_new);
+                self.f = f_new;
+                self.h_abs = h_abs * factor;
+                return Ok(());
+            }
+            h_abs = h_abs * min_factor.max(safety * error_norm.powf(exponent));
+            rejected = true;
+        }
+    }
+
+    fn dense_output(&mut self, rhs: &mut Rhs<'_, F>) -> Interpolant<F> {
+        let tableau = self.tableau;
+        let h = self.h_previous;
+        if !self.dop853 {
+            let q = Array2::from_shape_fn((self.y.len(), tableau.p[0].len()), |(i, m)| {
+                tableau.p.iter().enumerate().fold(F::zero(), |acc, (j, p)| {
+                    acc + self.k[[j, i]] * F::from(p[m]).unwrap()
+                })
+            });
+            return Interpolant::Polynomial {
+                t_old: self.t_old,
+                h,
+                y_old: self.y_old.clone(),
+                q,
+            };
+        }
+        for s in tableau.n_stages + 1..tableau.c.len() {
+            let y = self.stage_input(&self.y_old, h, tableau.a[s]);
+            let k = rhs.call(self.t_old + F::from(tableau.c[s]).unwrap() * h, y.view());
+            self.k.row_mut(s).assign(&k);
+        }
+        let n = self.y.len();
+        let mut f = Array2::zeros((3 + tableau.p.len(), n));
+        let delta_y = &self.y - &self.y_old;
+        let f_old = self.k.row(0);
+        Zip::from(f.row_mut(0))
+            .and(&delta_y)
+            .for_each(|f, &d| *f = d);
+        Zip::from(f.row_mut(1))
+            .and(&delta_y)
+            .and(f_old)
+            .for_each(|f, &d, &f_old| *f = h * f_old - d);
+        let two = F::from(2).unwrap();
+        Zip::from(f.row_mut(2))
+            .and(&delta_y)
+            .and(f_old)
+            .and(&self.f)
+            .for_each(|f, &d, &f_old, &f_new| *f = two * d - h * (f_new + f_old));
+        for (i, d) in tableau.p.iter().enumerate() {
+            let row = self.combine(d);
+            Zip::from(f.row_mut(3 + i))
+                .and(&row)
+                .for_each(|f, &r| *f = h * r);
+        }
+        Interpolant::Dop853 {
+            t_old: self.t_old,
+            h,
+            y_old: self.y_old.clone(),
+            f,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Functions from the
+//! [`scipy.integrate`](https://docs.scipy.org/doc/scipy/reference/integrate.html) module.
+
+mod ivp;
+mod quadpack;
+mod quadrature;
+
+pub use ivp::*;
+pub use quadpack::*;
+pub use quadrature::*;
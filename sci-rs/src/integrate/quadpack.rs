@@ -0,0 +1,410 @@
+use alloc::vec::Vec;
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Abscissae of the 21-point Kronrod rule on `[-1, 1]`, down to the center.
+#[allow(clippy::excessive_precision)]
+const XGK21: [f64; 11] = [
+    0.995657163025808080735527280689003,
+    0.973906528517171720077964012084452,
+    0.930157491355708226001207180059508,
+    0.865063366688984510732096688423493,
+    0.780817726586416897063717578345042,
+    0.679409568299024406234327365114874,
+    0.562757134668604683339000099272694,
+    0.433395394129247190799265943165784,
+    0.294392862701460198131126603103866,
+    0.148874338981631210884826001129720,
+    0.,
+];
+/// Weights of the 21-point Kronrod rule.
+#[allow(clippy::excessive_precision)]
+const WGK21: [f64; 11] = [
+    0.011694638867371874278064396062192,
+    0.032558162307964727478818972459390,
+    0.054755896574351996031381300244580,
+    0.075039674810919952767043140916190,
+    0.093125454583697605535065465083366,
+    0.109387158802297641899210590325805,
+    0.123491976262065851077208067831718,
+    0.134709217311473325928054001771707,
+    0.142775938577060080797094273138717,
+    0.147739104901338491374841515972068,
+    0.149445554002916905664936468389821,
+];
+/// Weights of the embedded 10-point Gauss rule, 0 at the Kronrod abscissae.
+#[allow(clippy::excessive_precision)]
+const WG10: [f64; 11] = [
+    0.,
+    0.066671344308688137593568809893332,
+    0.,
+    0.149451349150580593145776339657697,
+    0.,
+    0.219086362515982043995534934228163,
+    0.,
+    0.269266719309996355091226921569469,
+    0.,
+    0.295524224714752870173892994651338,
+    0.,
+];
+/// Abscissae of the 15-point Kronrod rule on `[-1, 1]`, down to the center.
+#[allow(clippy::excessive_precision)]
+const XGK15: [f64; 8] = [
+    0.991455371120812639206854697526329,
+    0.949107912342758524526189684047851,
+    0.864864423359769072789712788640926,
+    0.741531185599394439863864773280788,
+    0.586087235467691130294144845693013,
+    0.405845151377397166906606412076961,
+    0.207784955007898467600689403773245,
+    0.,
+];
+/// Weights of the 15-point Kronrod rule.
+#[allow(clippy::excessive_precision)]
+const WGK15: [f64; 8] = [
+    0.022935322010529224963732008058970,
+    0.063092092629978553290700663189204,
+    0.104790010322250183839876322541518,
+    0.140653259715525918745189590510238,
+    0.169004726639267902826583426598550,
+    0.190350578064785409913256402421014,
+    0.204432940075298892414161999234649,
+    0.209482141084727828012999174891714,
+];
+/// Weights of the embedded 7-point Gauss rule, 0 at the Kronrod abscissae.
+#[allow(clippy::excessive_precision)]
+const WG7: [f64; 8] = [
+    0.,
+    0.129484966168869693270611432679082,
+    0.,
+    0.279705391489276667901467771423780,
+    0.,
+    0.381830050505118944950369775488975,
+    0.,
+    0.417959183673469387755102040816327,
+];
+
+/// Cause of termination of [quad].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuadFlag {
+    /// The integral was found within the tolerances.
+    Converged,
+    /// The maximum number of subintervals was reached.
+    MaxSubdivisions,
+    /// Roundoff errors prevent the tolerances from being reached.
+    Roundoff,
+    /// The integrand behaves too badly for a subinterval to be bisected further, as near a
+    /// non-integrable singularity.
+    BadIntegrand,
+}
+
+/// Represents the result of [quad].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadResult<F> {
+    /// The integral of the function.
+    pub integral: F,
+    /// Estimate of the absolute error of the integral.
+    pub abserr: F,
+    /// Number of evaluations of the function.
+    pub neval: usize,
+    /// Number of subintervals of the integration range.
+    pub intervals: usize,
+    /// True if the integral was found within the tolerances.
+    pub converged: bool,
+    /// Description of the cause of termination.
+    pub flag: QuadFlag,
+}
+
+/// Gauss-Kronrod estimate of the integral over a subinterval.
+#[derive(Debug, Clone, Copy)]
+struct Estimate<F> {
+    a: F,
+    b: F,
+    result: F,
+    abserr: F,
+    /// Integral of the absolute value of the function.
+    resabs: F,
+    /// Integral of the absolute deviation of the function from its mean.
+    resasc: F,
+}
+
+/// Integrate `f` over `[a, b]` with the Kronrod rule of abscissae `xgk` and weights `wgk`,
+/// estimating the error from the embedded Gauss rule of weights `wg`.
+fn kronrod<F: Float>(
+    f: &mut impl FnMut(F) -> F,
+    a: F,
+    b: F,
+    xgk: &[f64],
+    wgk: &[f64],
+    wg: &[f64],
+) -> Estimate<F> {
+    let c = |x: f64| F::from(x).unwrap();
+    let half = c(0.5);
+    let center = half * (a + b);
+    let half_length = half * (b - a);
+    let n = xgk.len() - 1;
+    let fc = f(center);
+    let mut resk = c(wgk[n]) * fc;
+    let mut resg = c(wg[n]) * fc;
+    let mut resabs = resk.abs();
+    let mut values = Vec::with_capacity(n);
+    for j in 0..n {
+        let dx = half_length * c(xgk[j]);
+        let (f1, f2) = (f(center - dx), f(center + dx));
+        resk = resk + c(wgk[j]) * (f1 + f2);
+        resg = resg + c(wg[j]) * (f1 + f2);
+        resabs = resabs + c(wgk[j]) * (f1.abs() + f2.abs());
+        values.push((f1, f2));
+    }
+    let mean = resk * half;
+    let resasc = values
+        .iter()
+        .zip(wgk)
+        .fold(c(wgk[n]) * (fc - mean).abs(), |acc, (&(f1, f2), &w)| {
+            acc + c(w) * ((f1 - mean).abs() + (f2 - mean).abs())
+        });
+    let scale = half_length.abs();
+    let (resabs, resasc) = (resabs * scale, resasc * scale);
+    let mut abserr = ((resk - resg) * half_length).abs();
+    if !resasc.is_zero() && !abserr.is_zero() {
+        abserr = resasc * F::one().min((c(200.) * abserr / resasc).powf(c(1.5)));
+    }
+    let roundoff = c(50.) * F::epsilon();
+    if resabs > F::min_positive_value() / roundoff {
+        abserr = abserr.max(roundoff * resabs);
+    }
+    Estimate {
+        a,
+        b,
+        result: resk * half_length,
+        abserr,
+        resabs,
+        resasc,
+    }
+}
+
+/// Compute a definite integral.
+///
+/// Integrate `f` from `a` to `b`, possibly infinite, by adaptive Gauss-Kronrod quadrature.
+///
+/// ## Parameters
+/// * `f`: The function to integrate.
+/// * `a`: Lower limit of integration, which may be negative infinity.
+/// * `b`: Upper limit of integration, which may be infinity.
+/// * `epsabs`: Absolute error tolerance. `1.49e-8` by default.
+/// * `epsrel`: Relative error tolerance. `1.49e-8` by default.
+/// * `limit`: Maximum number of subintervals. 50 by default.
+///
+/// ## Returns
+/// The integral, along with an estimate of its absolute error, the number of evaluations of
+/// `f` and of subintervals. `flag` tells why the tolerances `abserr <= max(epsabs, epsrel *
+/// |integral|)` were not reached when `converged` is false.
+///
+/// ## Errors
+/// If `a` or `b` is NaN, `limit` is 0, or `epsabs` is not positive and `epsrel` is less than
+/// `max(50 * eps, 5e-29)`.
+///
+/// ## Notes
+/// The subinterval with the largest error estimate is bisected until the tolerances are met,
+/// like QUADPACK's QAG, with the 21-point Gauss-Kronrod rule. An infinite range is mapped onto
+/// `(0, 1]` by `x = a + (1 - t) / t`, and integrated with the 15-point Gauss-Kronrod rule.
+/// Without extrapolation, integrable singularities at the limits may need a larger `limit`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import integrate
+/// integrate.quad(lambda x: x**2, 0, 4)
+/// # (21.333333333333332, 2.3684757858670003e-13)
+/// integrate.quad(lambda x: np.exp(-x), 0, np.inf)
+/// # (1.0, 5.842605999138044e-11)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use sci_rs::integrate::quad;
+///
+/// let res = quad(|x: f64| x * x, 0., 4., None, None, None).unwrap();
+/// assert_eq!(res.integral, 21.333333333333332);
+/// assert_abs_diff_eq!(res.abserr, 2.3684757858670003e-13, epsilon = 1e-20);
+/// let res = quad(|x: f64| (-x).exp(), 0., f64::INFINITY, None, None, None).unwrap();
+/// assert!(res.converged);
+/// assert_abs_diff_eq!(res.integral, 1., epsilon = 1e-15);
+/// assert!(res.abserr < 1e-10);
+/// ```
+pub fn quad<F: Float>(
+    mut f: impl FnMut(F) -> F,
+    a: F,
+    b: F,
+    epsabs: Option<F>,
+    epsrel: Option<F>,
+    limit: Option<usize>,
+) -> Result<QuadResult<F>> {
+    let epsabs = epsabs.unwrap_or_else(|| F::from(1.49e-8).unwrap());
+    let epsrel = epsrel.unwrap_or_else(|| F::from(1.49e-8).unwrap());
+    let limit = limit.unwrap_or(50);
+    if a.is_nan() || b.is_nan() {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "limits of integration must not be NaN.".into(),
+        });
+    }
+    if limit == 0 {
+        return Err(Error::InvalidArg {
+            arg: "limit".into(),
+            reason: "must be positive.".into(),
+        });
+    }
+    let min_epsrel = (F::from(50).unwrap() * F::epsilon()).max(F::from(5e-29).unwrap());
+    if epsabs <= F::zero() && epsrel < min_epsrel {
+        return Err(Error::InvalidArg {
+            arg: "epsrel".into(),
+            reason: "must be at least max(50 * eps, 5e-29) if epsabs <= 0.".into(),
+        });
+    }
+    if a == b {
+        return Ok(QuadResult {
+            integral: F::zero(),
+            abserr: F::zero(),
+            neval: 0,
+            intervals: 0,
+            converged: true,
+            flag: QuadFlag::Converged,
+        });
+    }
+    let (a, b, sign) = if a < b {
+        (a, b, F::one())
+    } else {
+        (b, a, -F::one())
+    };
+    let mut neval = 0;
+    let one = F::one();
+    let mut rule = |l: F, r: F| {
+        if a.is_finite() && b.is_finite() {
+            neval += XGK21.len() * 2 - 1;
+            return kronrod(&mut f, l, r, &XGK21, &WGK21, &WG10);
+        }
+        neval += XGK15.len() * 2 - 1;
+        // Map the infinite range onto (0, 1], folding it about 0 when both limits are.
+        let mut g = |t: F| {
+            let u = (one - t) / t;
+            let value = match (a.is_finite(), b.is_finite()) {
+                (true, _) => f(a + u),
+                (_, true) => f(b - u),
+                _ => {
+                    neval += 1;
+                    f(u) + f(-u)
+                }
+            };
+            value / t / t
+        };
+        kronrod(&mut g, l, r, &XGK15, &WGK15, &WG7)
+    };
+    let range = if a.is_finite() && b.is_finite() {
+        (a, b)
+    } else {
+        (F::zero(), one)
+    };
+    let first = rule(range.0, range.1);
+    let mut intervals = alloc::vec![first];
+    let mut integral = first.result;
+    let mut abserr = first.abserr;
+    let (mut roundoff_small, mut roundoff_large) = (0, 0);
+    let flag = loop {
+        let tolerance = epsabs.max(epsrel * integral.abs());
+        if abserr <= tolerance || abserr.is_zero() {
+            break QuadFlag::Converged;
+        }
+        if intervals.len() >= limit {
+            break QuadFlag::MaxSubdivisions;
+        }
+        // Bisect the subinterval with the largest error estimate.
+        let worst = (0..intervals.len())
+            .max_by(|&i, &j| {
+                let (ei, ej) = (intervals[i].abserr, intervals[j].abserr);
+                ei.partial_cmp(&ej).unwrap_or(core::cmp::Ordering::Equal)
+            })
+            .unwrap();
+        let parent = intervals.swap_remove(worst);
+        let mid = F::from(0.5).unwrap() * (parent.a + parent.b);
+        let (left, right) = (rule(parent.a, mid), rule(mid, parent.b));
+        let (area12, error12) = (left.result + right.result, left.abserr + right.abserr);
+        integral = integral + area12 - parent.result;
+        abserr = abserr + error12 - parent.abserr;
+        if left.resasc != left.abserr && right.resasc != right.abserr {
+            let delta = parent.result - area12;
+            if delta.abs() <= F::from(1e-5).unwrap() * area12.abs()
+                && error12 >= F::from(0.99).unwrap() * parent.abserr
+            {
+                roundoff_small += 1;
+            }
+            if intervals.len() >= 10 && error12 > parent.abserr {
+                roundoff_large += 1;
+            }
+        }
+        intervals.push(left);
+        intervals.push(right);
+        let too_small = (one + F::from(100).unwrap() * F::epsilon())
+            * (mid.abs() + F::from(1000).unwrap() * F::min_positive_value());
+        if roundoff_small >= 6 || roundoff_large >= 20 {
+            break QuadFlag::Roundoff;
+        }
+        if parent.a.abs() <= too_small && parent.b.abs() <= too_small {
+            break QuadFlag::BadIntegrand;
+        }
+    };
+    // Sum the subintervals afresh, rather than the running updates.
+    let integral = intervals.iter().fold(F::zero(), |acc, e| acc + e.result);
+    let abserr = intervals.iter().fold(F::zero(), |acc, e| acc + e.abserr);
+    Ok(QuadResult {
+        integral: sign * integral,
+        abserr,
+        neval,
+        intervals: intervals.len(),
+        converged: flag == QuadFlag::Converged,
+        flag,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn finite_ranges() {
+        let res = quad(|x: f64| x.sin().powi(2), 0., 50., None, None, None).unwrap();
+        assert!(res.converged);
+        assert_abs_diff_eq!(res.integral, 25. - 100f64.sin() / 4., epsilon = 1e-10);
+        assert!(res.intervals > 1);
+        assert_eq!(res.neval, 21 * (2 * res.intervals - 1));
+        let res = quad(|x: f64| x.ln(), 1., 0., Some(0.), Some(1e-12), Some(200)).unwrap();
+        assert!(res.converged);
+        assert_abs_diff_eq!(res.integral, 1., epsilon = 1e-11);
+        let res = quad(|x: f64| 1. / x.sqrt(), 0., 1., None, None, None).unwrap();
+        assert_eq!(res.flag, QuadFlag::MaxSubdivisions);
+        assert_abs_diff_eq!(res.integral, 2., epsilon = 1e-8);
+        let res = quad(|x: f32| x * x, -1., 2., None, None, None).unwrap();
+        assert_abs_diff_eq!(res.integral, 3., epsilon = 1e-6);
+        assert_eq!(
+            quad(|x: f64| x, 2., 2., None, None, None).unwrap().integral,
+            0.
+        );
+        assert!(quad(|x: f64| x, 0., f64::NAN, None, None, None).is_err());
+        assert!(quad(|x: f64| x, 0., 1., Some(0.), Some(0.), None).is_err());
+    }
+
+    #[test]
+    fn infinite_ranges() {
+        let inf = f64::INFINITY;
+        let res = quad(|x: f64| (-x * x).exp(), -inf, inf, None, None, None).unwrap();
+        assert!(res.converged);
+        assert_abs_diff_eq!(res.integral, PI.sqrt(), epsilon = 1e-12);
+        let res = quad(|x: f64| 1. / (1. + x * x), -inf, 1., None, None, None).unwrap();
+        assert_abs_diff_eq!(res.integral, 0.75 * PI, epsilon = 1e-10);
+        let res = quad(|x: f64| x.exp(), 0., -inf, None, None, None).unwrap();
+        assert_abs_diff_eq!(res.integral, -1., epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,630 @@
+use crate::linalg::eigh;
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use alloc::vec::Vec;
+use nalgebra::RealField;
+use ndarray::{
+    s, Array, Array1, Array2, ArrayBase, ArrayView1, Axis, Data, Dimension, RemoveAxis, Zip,
+};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Spacings between the samples along an axis of length `n`, from the sample points `x` or
+/// the constant spacing `dx`.
+fn spacings<F: Float>(n: usize, x: Option<&[F]>, dx: Option<F>) -> Result<Vec<F>> {
+    match x {
+        Some(x) if x.len() != n => Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "must have the length of y along axis.".into(),
+        }),
+        Some(x) => Ok(x.windows(2).map(|w| w[1] - w[0]).collect()),
+        None => Ok(alloc::vec![dx.unwrap_or_else(F::one); n.saturating_sub(1)]),
+    }
+}
+
+/// Integrate along the given axis using the composite trapezoidal rule.
+///
+/// ## Parameters
+/// * `y`: The values to integrate.
+/// * `x`: The sample points corresponding to the values of `y` along `axis`. Spaced by `dx`
+///   by default.
+/// * `dx`: The spacing between the samples when `x` is not given. 1 by default.
+/// * `axis`: The axis along which to integrate. -1 by default.
+///
+/// ## Returns
+/// The integral of `y` along `axis`, with one dimension fewer. The integral along a 1-D `y`
+/// is read with `[()]` or [`into_scalar`](ndarray::Array0::into_scalar).
+///
+/// ## Errors
+/// If `axis` is out of range, or `x` does not have the length of `y` along `axis`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.integrate import trapezoid
+/// trapezoid([1, 2, 3])
+/// # 4.0
+/// trapezoid([1, 2, 3], x=[4, 6, 8])
+/// # 8.0
+/// trapezoid([1, 2, 3], dx=2)
+/// # 8.0
+/// a = np.arange(6).reshape(2, 3)
+/// trapezoid(a, axis=0)
+/// # array([1.5, 2.5, 3.5])
+/// trapezoid(a, axis=1)
+/// # array([2.,  8.])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{array, Array};
+/// use sci_rs::integrate::trapezoid;
+///
+/// let y = array![1., 2., 3.];
+/// assert_eq!(trapezoid(&y, None, None, None).unwrap().into_scalar(), 4.);
+/// assert_eq!(trapezoid(&y, Some(&[4., 6., 8.]), None, None).unwrap()[()], 8.);
+/// assert_eq!(trapezoid(&y, None, Some(2.), None).unwrap()[()], 8.);
+/// let a = Array::range(0., 6., 1.).into_shape_with_order((2, 3)).unwrap();
+/// assert_eq!(trapezoid(&a, None, None, Some(0)).unwrap(), array![1.5, 2.5, 3.5]);
+/// assert_eq!(trapezoid(&a, None, None, Some(1)).unwrap(), array![2., 8.]);
+/// ```
+pub fn trapezoid<F, S, D>(
+    y: &ArrayBase<S, D>,
+    x: Option<&[F]>,
+    dx: Option<F>,
+    axis: Option<isize>,
+) -> Result<Array<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let axis = check_and_get_axis_dyn(axis, y)?;
+    let d = spacings(y.len_of(Axis(axis)), x, dx)?;
+    let half = F::from(0.5).unwrap();
+    Ok(y.map_axis(Axis(axis), |y| {
+        d.iter()
+            .zip(y.windows(2))
+            .fold(F::zero(), |acc, (&d, y)| acc + d * (y[0] + y[1]) * half)
+    }))
+}
+
+/// Cumulatively integrate along the given axis using the composite trapezoidal rule.
+///
+/// ## Parameters
+/// * `y`: The values to integrate.
+/// * `x`: The sample points corresponding to the values of `y` along `axis`. Spaced by `dx`
+///   by default.
+/// * `dx`: The spacing between the samples when `x` is not given. 1 by default.
+/// * `axis`: The axis along which to integrate. -1 by default.
+/// * `initial`: If given, it is inserted at the beginning of the result, which then has the
+///   shape of `y`. It must be 0. `None` by default.
+///
+/// ## Returns
+/// The cumulative integrals of `y` along `axis`, one element shorter than `y` along it unless
+/// `initial` is given.
+///
+/// ## Errors
+/// If `axis` is out of range, `x` does not have the length of `y` along `axis`, or `initial`
+/// is not 0.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.integrate import cumulative_trapezoid
+/// cumulative_trapezoid([1, 2, 3])
+/// # array([1.5, 4. ])
+/// x = np.linspace(-2, 2, num=5)
+/// cumulative_trapezoid(x, x, initial=0)
+/// # array([ 0. , -1.5, -2. , -1.5,  0. ])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{array, Array};
+/// use sci_rs::integrate::cumulative_trapezoid;
+///
+/// let y = array![1., 2., 3.];
+/// assert_eq!(cumulative_trapezoid(&y, None, None, None, None).unwrap(), array![1.5, 4.]);
+/// let x = Array::linspace(-2., 2., 5);
+/// let integral = cumulative_trapezoid(&x, x.as_slice(), None, None, Some(0.)).unwrap();
+/// assert_eq!(integral, array![0., -1.5, -2., -1.5, 0.]);
+/// ```
+pub fn cumulative_trapezoid<F, S, D>(
+    y: &ArrayBase<S, D>,
+    x: Option<&[F]>,
+    dx: Option<F>,
+    axis: Option<isize>,
+    initial: Option<F>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, y)?;
+    let n = y.len_of(Axis(axis));
+    let d = spacings(n, x, dx)?;
+    if initial.is_some_and(|initial| !initial.is_zero()) {
+        return Err(Error::InvalidArg {
+            arg: "initial".into(),
+            reason: "must be None or 0.".into(),
+        });
+    }
+    let skip = usize::from(initial.is_some());
+    let mut shape = y.raw_dim();
+    shape[axis] = d.len() + skip;
+    let mut out = Array::zeros(shape);
+    let half = F::from(0.5).unwrap();
+    Zip::from(out.lanes_mut(Axis(axis)))
+        .and(y.lanes(Axis(axis)))
+        .for_each(|mut out, y| {
+            let mut total = F::zero();
+            for (o, (&d, y)) in out.iter_mut().skip(skip).zip(d.iter().zip(y.windows(2))) {
+                total = total + d * (y[0] + y[1]) * half;
+                *o = total;
+            }
+        });
+    Ok(out)
+}
+
+/// How [`simpson`] integrates an even number of samples, whose intervals Simpson's rule does
+/// not cover in pairs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SimpsonEven {
+    /// Simpson's rule on the first intervals, with Cartwright's correction for the last one,
+    /// which is exact for quadratics.
+    #[default]
+    Simpson,
+    /// The average of [`SimpsonEven::First`] and [`SimpsonEven::Last`].
+    Avg,
+    /// Simpson's rule on the first intervals and the trapezoidal rule on the last one.
+    First,
+    /// The trapezoidal rule on the first interval and Simpson's rule on the others.
+    Last,
+}
+
+/// Simpson's rule on the pairs of intervals from the sample `start` to `stop`, spaced by `h`.
+fn basic_simpson<F: Float>(y: ArrayView1<F>, h: &[F], start: usize, stop: usize) -> F {
+    let (two, six) = (F::from(2).unwrap(), F::from(6).unwrap());
+    (start..stop).step_by(2).fold(F::zero(), |acc, i| {
+        let (h0, h1) = (h[i], h[i + 1]);
+        let hsum = h0 + h1;
+        let ratio = h0 / h1;
+        acc + hsum / six
+            * (y[i] * (two - ratio.recip())
+                + y[i + 1] * (hsum * hsum / (h0 * h1))
+                + y[i + 2] * (two - ratio))
+    })
+}
+
+/// Integrate along the given axis using the composite Simpson's rule.
+///
+/// ## Parameters
+/// * `y`: The values to integrate.
+/// * `x`: The sample points corresponding to the values of `y` along `axis`. Spaced by `dx`
+///   by default.
+/// * `dx`: The spacing between the samples when `x` is not given. 1 by default.
+/// * `axis`: The axis along which to integrate. -1 by default.
+/// * `even`: How an even number of samples is integrated, see [`SimpsonEven`].
+///   [`SimpsonEven::Simpson`] by default.
+///
+/// ## Returns
+/// The integral of `y` along `axis`, with one dimension fewer.
+///
+/// ## Errors
+/// If `axis` is out of range, or `x` does not have the length of `y` along `axis`.
+///
+/// ## Notes
+/// For an odd number of equally spaced samples, the result is exact for polynomials up to
+/// the third degree. Two samples are integrated with the trapezoidal rule, and a single one
+/// integrates to 0.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import integrate
+/// x = np.arange(0, 10)
+/// integrate.simpson(x, x=x)
+/// # 40.5
+/// y = np.power(x, 3)
+/// integrate.simpson(y, x=x)
+/// # 1640.5
+/// integrate.simpson(y, x=x, even='first')
+/// # 1644.5
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::Array;
+/// use sci_rs::integrate::{simpson, SimpsonEven};
+///
+/// let x = Array::range(0., 10., 1.);
+/// let y = x.powi(3);
+/// assert_eq!(simpson(&x, x.as_slice(), None, None, None).unwrap()[()], 40.5);
+/// assert_eq!(simpson(&y, x.as_slice(), None, None, None).unwrap()[()], 1640.5);
+/// let first = simpson(&y, x.as_slice(), None, None, Some(SimpsonEven::First));
+/// assert_eq!(first.unwrap()[()], 1644.5);
+/// ```
+pub fn simpson<F, S, D>(
+    y: &ArrayBase<S, D>,
+    x: Option<&[F]>,
+    dx: Option<F>,
+    axis: Option<isize>,
+    even: Option<SimpsonEven>,
+) -> Result<Array<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let axis = check_and_get_axis_dyn(axis, y)?;
+    let n = y.len_of(Axis(axis));
+    let h = spacings(n, x, dx)?;
+    let even = even.unwrap_or_default();
+    let half = F::from(0.5).unwrap();
+    let six = F::from(6).unwrap();
+    Ok(y.map_axis(Axis(axis), |y| {
+        if n < 3 {
+            return h.first().map_or(F::zero(), |&h| half * h * (y[0] + y[1]));
+        }
+        if n % 2 == 1 {
+            return basic_simpson(y, &h, 0, n - 2);
+        }
+        let first = || basic_simpson(y, &h, 0, n - 3) + half * h[n - 2] * (y[n - 2] + y[n - 1]);
+        let last = || half * h[0] * (y[0] + y[1]) + basic_simpson(y, &h, 1, n - 2);
+        match even {
+            SimpsonEven::Simpson => {
+                // Cartwright's correction for the last interval.
+                let (h0, h1) = (h[n - 3], h[n - 2]);
+                let three = F::from(3).unwrap();
+                let alpha = (h1 * h1 * F::from(2).unwrap() + three * h0 * h1) / (six * (h0 + h1));
+                let beta = (h1 * h1 + three * h0 * h1) / (six * h0);
+                let eta = h1.powi(3) / (six * h0 * (h0 + h1));
+                basic_simpson(y, &h, 0, n - 3) + alpha * y[n - 1] + beta * y[n - 2] - eta * y[n - 3]
+            }
+            SimpsonEven::Avg => (first() + last()) * half,
+            SimpsonEven::First => first(),
+            SimpsonEven::Last => last(),
+        }
+    }))
+}
+
+/// Romberg integration using samples of a function.
+///
+/// ## Parameters
+/// * `y`: The values to integrate, of which there are `2^k + 1` along `axis`.
+/// * `dx`: The spacing between the samples. 1 by default.
+/// * `axis`: The axis along which to integrate. -1 by default.
+///
+/// ## Returns
+/// The integral of `y` along `axis`, with one dimension fewer.
+///
+/// ## Errors
+/// If `axis` is out of range, or the number of samples along it is not one plus a power of
+/// 2.
+///
+/// ## Notes
+/// The trapezoidal rule over 1, 2, 4, ... intervals is extrapolated to vanishing intervals by
+/// Richardson extrapolation.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import integrate
+/// integrate.romb(np.arange(3, 12))
+/// # 56.0
+/// x = np.arange(10, 14.25, 0.25)
+/// integrate.romb(np.sin(np.power(x, 2.5)))
+/// # -0.742561336672229
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::Array;
+/// use sci_rs::integrate::romb;
+///
+/// assert_eq!(romb(&Array::range(3., 12., 1.), None, None).unwrap()[()], 56.);
+/// let y = Array::range(10., 14.25, 0.25).mapv(|x: f64| x.powf(2.5).sin());
+/// let integral = romb(&y, None, None).unwrap()[()];
+/// assert_abs_diff_eq!(integral, -0.742561336672229, epsilon = 1e-12);
+/// ```
+pub fn romb<F, S, D>(
+    y: &ArrayBase<S, D>,
+    dx: Option<F>,
+    axis: Option<isize>,
+) -> Result<Array<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let axis = check_and_get_axis_dyn(axis, y)?;
+    let intervals = y.len_of(Axis(axis)).wrapping_sub(1);
+    if intervals == usize::MAX || !intervals.is_power_of_two() {
+        return Err(Error::InvalidArg {
+            arg: "y".into(),
+            reason: "number of samples must be one plus a non-negative power of 2.".into(),
+        });
+    }
+    let k = intervals.trailing_zeros() as usize;
+    let dx = dx.unwrap_or_else(F::one);
+    let half = F::from(0.5).unwrap();
+    Ok(y.map_axis(Axis(axis), |y| {
+        // The last row of the Romberg table, refined in place.
+        let mut h = F::from(intervals).unwrap() * dx;
+        let mut row = alloc::vec![(y[0] + y[intervals]) * half * h];
+        for i in 1..=k {
+            let step = intervals >> (i - 1);
+            let midpoints = (step / 2..intervals)
+                .step_by(step)
+                .fold(F::zero(), |acc, j| acc + y[j]);
+            let mut next = alloc::vec![(row[0] + h * midpoints) * half];
+            for j in 1..=i {
+                let prev = next[j - 1];
+                let factor = F::from((1usize << (2 * j)) - 1).unwrap();
+                next.push(prev + (prev - row[j - 1]) / factor);
+            }
+            row = next;
+            h = h * half;
+        }
+        row[k]
+    }))
+}
+
+/// Gauss-Legendre quadrature.
+///
+/// Compute the sample points and weights for Gauss-Legendre quadrature, which integrate
+/// exactly the polynomials of degree up to `2 * deg - 1` over `[-1, 1]`.
+///
+/// ## Parameters
+/// * `deg`: Number of sample points and weights.
+///
+/// ## Returns
+/// The sample points in ascending order, the roots of the Legendre polynomial of degree
+/// `deg`, and the corresponding weights.
+///
+/// ## Errors
+/// If `deg` is 0.
+///
+/// ## Notes
+/// The points are the eigenvalues of the symmetric tridiagonal Jacobi matrix of the
+/// three-term recurrence of the Legendre polynomials, and the weights are twice the squares
+/// of the first components of its eigenvectors (Golub-Welsch). The points are then improved
+/// by a Newton step on the Legendre polynomial, and both are symmetrized.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// np.polynomial.legendre.leggauss(3)
+/// # (array([-0.77459667,  0.        ,  0.77459667]),
+/// #  array([0.55555556, 0.88888889, 0.55555556]))
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use sci_rs::integrate::leggauss;
+///
+/// let (x, w) = leggauss::<f64>(3).unwrap();
+/// for (x, e) in x.iter().zip([-0.6f64.sqrt(), 0., 0.6f64.sqrt()]) {
+///     assert_abs_diff_eq!(*x, e, epsilon = 1e-15);
+/// }
+/// for (w, e) in w.iter().zip([5. / 9., 8. / 9., 5. / 9.]) {
+///     assert_abs_diff_eq!(*w, e, epsilon = 1e-15);
+/// }
+/// ```
+pub fn leggauss<F>(deg: usize) -> Result<(Array1<F>, Array1<F>)>
+where
+    F: Float + RealField,
+{
+    if deg == 0 {
+        return Err(Error::InvalidArg {
+            arg: "deg".into(),
+            reason: "must be positive.".into(),
+        });
+    }
+    let jacobi = Array2::from_shape_fn((deg, deg), |(i, j)| {
+        if i.abs_diff(j) == 1 {
+            let k = F::from(i.max(j)).unwrap();
+            k / Float::sqrt(F::from(4).unwrap() * k * k - F::one())
+        } else {
+            F::zero()
+        }
+    });
+    let eigen = eigh(&jacobi, None)?;
+    let two = F::from(2).unwrap();
+    let mut x = eigen.eigenvalues;
+    let mut w: Array1<F> = eigen.eigenvectors.row(0).mapv(|v| two * v * v);
+    // Newton step on the Legendre polynomial, evaluated by its recurrence.
+    x.mapv_inplace(|x| {
+        let (mut p, mut prev) = (x, F::one());
+        for k in 1..deg {
+            let k = F::from(k).unwrap();
+            (p, prev) = (
+                ((two * k + F::one()) * x * p - k * prev) / (k + F::one()),
+                p,
+            );
+        }
+        let n = F::from(deg).unwrap();
+        let dp = n * (prev - x * p) / (F::one() - x * x);
+        x - p / dp
+    });
+    let half = F::from(0.5).unwrap();
+    let x = Zip::from(&x)
+        .and(x.slice(s![..;-1]))
+        .map_collect(|&x, &r| (x - r) * half);
+    let w = Zip::from(&w)
+        .and(w.slice(s![..;-1]))
+        .map_collect(|&w, &r| (w + r) * half);
+    let total = w.sum();
+    Ok((x, w.mapv(|w| w * two / total)))
+}
+
+/// Compute a definite integral using fixed-order Gaussian quadrature.
+///
+/// ## Parameters
+/// * `f`: The function to integrate.
+/// * `a`: Lower limit of integration.
+/// * `b`: Upper limit of integration.
+/// * `n`: Order of the quadrature, its number of sample points. 5 by default.
+///
+/// ## Returns
+/// The Gauss-Legendre approximation of the integral, exact for polynomials of degree up to
+/// `2 * n - 1`.
+///
+/// ## Errors
+/// If `n` is 0.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import integrate
+/// f = lambda x: x**8
+/// integrate.fixed_quad(f, 0.0, 1.0, n=4)
+/// # (0.1110884353741496, None)
+/// integrate.fixed_quad(f, 0.0, 1.0, n=5)
+/// # (0.11111111111111102, None)
+/// integrate.fixed_quad(np.cos, 0.0, np.pi/2, n=4)
+/// # (0.9999999771971152, None)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use core::f64::consts::FRAC_PI_2;
+/// use sci_rs::integrate::fixed_quad;
+///
+/// let f = |x: f64| x.powi(8);
+/// let integral = fixed_quad(f, 0., 1., Some(4)).unwrap();
+/// assert_abs_diff_eq!(integral, 0.1110884353741496, epsilon = 1e-15);
+/// let integral = fixed_quad(f, 0., 1., None).unwrap();
+/// assert_abs_diff_eq!(integral, 1. / 9., epsilon = 1e-15);
+/// let integral = fixed_quad(f64::cos, 0., FRAC_PI_2, Some(4)).unwrap();
+/// assert_abs_diff_eq!(integral, 0.9999999771971152, epsilon = 1e-15);
+/// ```
+pub fn fixed_quad<F>(mut f: impl FnMut(F) -> F, a: F, b: F, n: Option<usize>) -> Result<F>
+where
+    F: Float + RealField,
+{
+    let (x, w) = leggauss(n.unwrap_or(5))?;
+    let half = F::from(0.5).unwrap();
+    let (center, half_length) = ((a + b) * half, (b - a) * half);
+    let sum = x.iter().zip(&w).fold(F::zero(), |acc, (&x, &w)| {
+        acc + w * f(center + half_length * x)
+    });
+    Ok(half_length * sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array1, Array3};
+
+    #[test]
+    fn trapezoidal_rule() {
+        // Exact for lines, on uneven samples.
+        let x = [0., 0.5, 2., 2.25, 4.];
+        let y = Array1::from_iter(x.iter().map(|x| 3. * x - 1.));
+        assert_abs_diff_eq!(trapezoid(&y, Some(&x), None, None).unwrap()[()], 20.);
+        let a = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| (i + j * k) as f64);
+        let along = trapezoid(&a, None, Some(0.5), Some(-2)).unwrap();
+        assert_eq!(along.dim(), (2, 4));
+        assert_eq!(along[[1, 3]], 0.5 * (1. + 2. * 4. + 7.) / 2.);
+        assert!(trapezoid(&a, Some(&[0., 1.]), None, None).is_err());
+        assert!(trapezoid(&a, None, None, Some(3)).is_err());
+        assert_eq!(trapezoid(&array![2.], None, None, None).unwrap()[()], 0.);
+    }
+
+    #[test]
+    fn cumulative() {
+        let a = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * k * k) as f64);
+        let integral = cumulative_trapezoid(&a, None, None, Some(2), Some(0.)).unwrap();
+        assert_eq!(integral.dim(), a.dim());
+        let totals = trapezoid(&a, None, None, Some(2)).unwrap();
+        for ((i, j), total) in totals.indexed_iter() {
+            assert_eq!(integral[[i, j, 0]], 0.);
+            assert_abs_diff_eq!(integral[[i, j, 3]], *total, epsilon = 1e-12);
+        }
+        let integral = cumulative_trapezoid(&a, None, None, Some(0), None).unwrap();
+        assert_eq!(integral.dim(), (1, 3, 4));
+        assert!(cumulative_trapezoid(&a, None, None, None, Some(1.)).is_err());
+        let empty = cumulative_trapezoid(&Array1::<f64>::zeros(0), None, None, None, None);
+        assert_eq!(empty.unwrap().len(), 0);
+    }
+
+    #[test]
+    fn simpsons_rule() {
+        // Exact for cubics with an odd number of samples, and quadratics with an even one.
+        let x = Array1::linspace(-1., 3., 9);
+        let cubic = x.mapv(|x| x * x * x - 2. * x);
+        assert_abs_diff_eq!(
+            simpson(&cubic, None, Some(0.5), None, None).unwrap()[()],
+            20. - 8.,
+            epsilon = 1e-12
+        );
+        let x = [0., 0.3, 1.1, 1.5, 2.6, 3.];
+        let square = Array1::from_iter(x.iter().map(|x| x * x));
+        let integral = simpson(&square, Some(&x), None, None, None).unwrap()[()];
+        assert_abs_diff_eq!(integral, 9., epsilon = 1e-12);
+        let cube = Array1::from_iter(x.iter().map(|x| x * x * x));
+        let integral = simpson(&cube, Some(&x), None, None, None).unwrap()[()];
+        assert_abs_diff_eq!(integral, 20.5162, epsilon = 1e-12);
+
+        let a = Array3::from_shape_fn((4, 2, 3), |(i, j, k)| ((i * i * i) * (j + 1) + k) as f64);
+        let integrals = [
+            (SimpsonEven::Simpson, 20.5),
+            (SimpsonEven::First, 21.5),
+            (SimpsonEven::Last, 20.5),
+            (SimpsonEven::Avg, 21.),
+        ];
+        for (even, integral) in integrals {
+            let y = simpson(&a, None, None, Some(0), Some(even)).unwrap();
+            assert_abs_diff_eq!(y[[0, 0]], integral, epsilon = 1e-12);
+            assert_abs_diff_eq!(y[[1, 2]], 2. * integral + 6., epsilon = 1e-12);
+        }
+        assert_eq!(
+            simpson(&array![1., 3.], None, None, None, None).unwrap()[()],
+            2.
+        );
+        assert_eq!(
+            simpson(&array![2.], None, None, None, None).unwrap()[()],
+            0.
+        );
+    }
+
+    #[test]
+    fn romberg() {
+        let y = Array1::linspace(0., 1., 33).mapv(f64::exp);
+        let integral = romb(&y, Some(1. / 32.), None).unwrap()[()];
+        assert_abs_diff_eq!(integral, 1f64.exp() - 1., epsilon = 1e-14);
+        let a = Array3::from_shape_fn((2, 5, 3), |(i, j, _)| (i + j * j) as f64);
+        let integrals = romb(&a, None, Some(1)).unwrap();
+        assert_eq!(integrals.dim(), (2, 3));
+        assert_abs_diff_eq!(integrals[[1, 0]], 4. + 64. / 3., epsilon = 1e-12);
+        assert_eq!(romb(&array![1., 2.], None, None).unwrap()[()], 1.5);
+        assert!(romb(&array![1., 2., 3., 4.], None, None).is_err());
+        assert!(romb(&array![1.], None, None).is_err());
+    }
+
+    #[test]
+    fn gauss_legendre() {
+        for deg in [1, 2, 7, 20, 64] {
+            let (x, w) = leggauss::<f64>(deg).unwrap();
+            assert_abs_diff_eq!(w.sum(), 2., epsilon = 1e-13);
+            assert!(x.windows(2).into_iter().all(|x| x[0] < x[1]));
+            // Exact for the monomials up to degree 2 * deg - 1.
+            for k in 0..2 * deg {
+                let integral: f64 = x.iter().zip(&w).map(|(x, w)| w * x.powi(k as i32)).sum();
+                let exact = if k % 2 == 0 { 2. / (k + 1) as f64 } else { 0. };
+                assert_abs_diff_eq!(integral, exact, epsilon = 1e-13);
+            }
+        }
+        assert_eq!(leggauss::<f64>(1).unwrap(), (array![0.], array![2.]));
+        assert!(leggauss::<f64>(0).is_err());
+        let integral = fixed_quad(|x: f64| x.exp(), 1., -1., Some(10)).unwrap();
+        assert_abs_diff_eq!(integral, 1f64.exp().recip() - 1f64.exp(), epsilon = 1e-14);
+    }
+}
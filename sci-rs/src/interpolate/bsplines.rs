@@ -0,0 +1,556 @@
+use super::ppoly::Extrapolate;
+use crate::linalg::BandedLu;
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use alloc::{format, vec, vec::Vec};
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension, RemoveAxis, Zip};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Univariate spline in the B-spline basis.
+///
+/// `S(x) = sum(c[j] * B[j, k](x) for j in 0..n)`
+///
+/// where `B[j, k]` are B-spline basis functions of degree `k` and knots `t`, and `n` is
+/// `t.len() - k - 1`.
+///
+/// ## Notes
+/// B-spline basis elements are defined via
+///
+/// `B[i, 0](x) = 1 if t[i] <= x < t[i+1] else 0`
+///
+/// `B[i, k](x) = (x - t[i]) / (t[i+k] - t[i]) * B[i, k-1](x)
+///             + (t[i+k+1] - x) / (t[i+k+1] - t[i+1]) * B[i+1, k-1](x)`
+///
+/// The spline is evaluated with the de Boor algorithm. Only the base interval
+/// `t[k] <= x <= t[n]` is covered by all the basis functions; other points are extrapolated from
+/// the first or last polynomial pieces, or are NaN, depending on `extrapolate`.
+///
+/// ## References
+/// [1] Tom Lyche and Knut Morken, Spline methods,
+///     <http://www.uio.no/studier/emner/matnat/ifi/INF-MAT5340/v05/undervisningsmateriale/>
+///
+/// [2] Carl de Boor, A practical guide to splines, Springer, 2001.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.interpolate import BSpline
+/// k = 2
+/// t = [0, 1, 2, 3, 4, 5, 6]
+/// c = [-1, 2, 0, -1]
+/// spl = BSpline(t, c, k)
+/// spl(2.5)
+/// array(1.375)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::interpolate::BSpline;
+///
+/// let t = vec![0., 1., 2., 3., 4., 5., 6.];
+/// let spl = BSpline::new(t, array![-1., 2., 0., -1.], 2, None, None).unwrap();
+/// assert_abs_diff_eq!(spl.call(&[2.5], None, None)[0], 1.375, epsilon = 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BSpline<F, D: Dimension> {
+    t: Vec<F>,
+    c: Array<F, D>,
+    k: usize,
+    axis: usize,
+    extrapolate: Extrapolate,
+}
+
+impl<F: Float, D: Dimension> BSpline<F, D> {
+    /// Build the spline of knots `t`, coefficients `c` and degree `k`.
+    ///
+    /// ## Parameters
+    /// * `t`: Knots, in non-decreasing order, at least `2 * k + 2` of them.
+    /// * `c`: Spline coefficients, at least `t.len() - k - 1` along `axis`. Further coefficients
+    ///   are ignored.
+    /// * `k`: B-spline degree.
+    /// * `extrapolate`: Whether to extrapolate beyond the base interval, `t[k] .. t[n]`, or to
+    ///   return NaNs. If [Extrapolate::Periodic], periodic extrapolation is used. Default is
+    ///   [Extrapolate::Enabled].
+    /// * `axis`: Interpolation axis of the coefficients. Default is zero.
+    pub fn new(
+        t: Vec<F>,
+        c: Array<F, D>,
+        k: usize,
+        extrapolate: Option<Extrapolate>,
+        axis: Option<isize>,
+    ) -> Result<Self> {
+        if c.ndim() == 0 {
+            return Err(Error::InvalidArg {
+                arg: "c".into(),
+                reason: "Coefficients must be at least 1-dimensional.".into(),
+            });
+        }
+        let axis = check_and_get_axis_dyn(Some(axis.unwrap_or(0)), &c)?;
+        if t.len() < 2 * k + 2 {
+            return Err(Error::InvalidArg {
+                arg: "t".into(),
+                reason: format!("Need at least {} knots for k={}.", 2 * k + 2, k),
+            });
+        }
+        let n = t.len() - k - 1;
+        if t.windows(2).any(|w| w[0] > w[1]) || t.iter().any(|t| t.is_nan()) {
+            return Err(Error::InvalidArg {
+                arg: "t".into(),
+                reason: "Knots must be in a non-decreasing order.".into(),
+            });
+        }
+        if t[k] == t[n] {
+            return Err(Error::InvalidArg {
+                arg: "t".into(),
+                reason: "Need at least two internal knots.".into(),
+            });
+        }
+        if c.len_of(Axis(axis)) < n {
+            return Err(Error::InvalidArg {
+                arg: "c".into(),
+                reason: "Knots, coefficients and degree are inconsistent.".into(),
+            });
+        }
+        Ok(Self {
+            t,
+            c,
+            k,
+            axis,
+            extrapolate: extrapolate.unwrap_or_default(),
+        })
+    }
+
+    /// The knots.
+    pub fn t(&self) -> &[F] {
+        &self.t
+    }
+
+    /// The spline coefficients.
+    pub fn c(&self) -> &Array<F, D> {
+        &self.c
+    }
+
+    /// The degree.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// The interpolation axis of the coefficients.
+    pub fn axis(&self) -> usize {
+        self.axis
+    }
+
+    /// Number of basis functions, `t.len() - k - 1`.
+    fn n(&self) -> usize {
+        self.t.len() - self.k - 1
+    }
+
+    /// Evaluate the spline or its derivative.
+    ///
+    /// ## Parameters
+    /// * `x`: Points to evaluate the spline at.
+    /// * `nu`: Order of derivative to evaluate. Default is zero.
+    /// * `extrapolate`: Whether to extrapolate based on the first and last intervals or return
+    ///   NaNs. Defaults to the one of the spline.
+    ///
+    /// ## Returns
+    /// The values, in an array of the shape of the coefficients with the interpolation axis
+    /// replaced by the points.
+    pub fn call(
+        &self,
+        x: &[F],
+        nu: Option<usize>,
+        extrapolate: Option<Extrapolate>,
+    ) -> Array<F, D> {
+        self.eval(x, nu.unwrap_or(0), extrapolate.unwrap_or(self.extrapolate))
+    }
+
+    /// Evaluate the `nu`-th derivative of the spline at every `x`.
+    pub(crate) fn eval(&self, x: &[F], nu: usize, extrapolate: Extrapolate) -> Array<F, D> {
+        let k = self.k;
+        let (start, end) = (self.t[k], self.t[self.n()]);
+        let mut basis = vec![F::zero(); x.len() * (k + 1)];
+        let intervals: Vec<_> = x
+            .iter()
+            .zip(basis.chunks_exact_mut(k + 1))
+            .map(|(&x, h)| {
+                let x = match extrapolate {
+                    Extrapolate::Periodic => {
+                        let r = (x - start) % (end - start);
+                        start + if r < F::zero() { r + end - start } else { r }
+                    }
+                    _ => x,
+                };
+                let l = find_interval(&self.t, k, x, extrapolate == Extrapolate::Enabled)?;
+                eval_basis(&self.t, k, x, l, nu, h);
+                Some(l)
+            })
+            .collect();
+
+        let mut shape = self.c.raw_dim();
+        shape[self.axis] = x.len();
+        let mut out = Array::zeros(shape);
+        Zip::from(self.c.lanes(Axis(self.axis)))
+            .and(out.lanes_mut(Axis(self.axis)))
+            .for_each(|c, mut out| {
+                for ((out, l), h) in out
+                    .iter_mut()
+                    .zip(&intervals)
+                    .zip(basis.chunks_exact(k + 1))
+                {
+                    *out = match l {
+                        Some(l) => h
+                            .iter()
+                            .enumerate()
+                            .fold(F::zero(), |acc, (i, &h)| acc + c[l - k + i] * h),
+                        None => F::nan(),
+                    };
+                }
+            });
+        out
+    }
+
+    /// Return a B-spline representing the derivative.
+    ///
+    /// ## Parameters
+    /// * `nu`: Derivative order. Default is 1.
+    ///
+    /// ## Notes
+    /// The basis functions of degree `k - 1` between repeated knots vanish, and get a zero
+    /// coefficient.
+    ///
+    /// ## Errors
+    /// If `nu` is larger than the degree.
+    pub fn derivative(&self, nu: Option<usize>) -> Result<Self> {
+        let nu = nu.unwrap_or(1);
+        if nu > self.k {
+            return Err(Error::InvalidArg {
+                arg: "nu".into(),
+                reason: format!(
+                    "Order of derivative (nu = {}) must be <= order of spline (k = {}).",
+                    nu, self.k
+                ),
+            });
+        }
+        let mut spline = self.clone();
+        for _ in 0..nu {
+            let (t, k, n) = (&spline.t, spline.k, spline.n());
+            let scale = F::from(k).unwrap();
+            let mut shape = spline.c.raw_dim();
+            shape[spline.axis] = n - 1;
+            let mut c = Array::zeros(shape);
+            Zip::from(c.lanes_mut(Axis(spline.axis)))
+                .and(spline.c.lanes(Axis(spline.axis)))
+                .for_each(|mut dc, c| {
+                    for (i, dc) in dc.iter_mut().enumerate() {
+                        let dt = t[i + k + 1] - t[i + 1];
+                        if dt > F::zero() {
+                            *dc = scale * (c[i + 1] - c[i]) / dt;
+                        }
+                    }
+                });
+            spline = Self {
+                t: spline.t[1..spline.t.len() - 1].to_vec(),
+                c,
+                k: k - 1,
+                ..spline
+            };
+        }
+        Ok(spline)
+    }
+
+    /// Return a B-spline representing the antiderivative.
+    ///
+    /// ## Parameters
+    /// * `nu`: Antiderivative order. Default is 1.
+    ///
+    /// ## Notes
+    /// The antiderivative is zero at `t[0]`, and a knot is added at each end for every order.
+    ///
+    /// If the antiderivative is computed and [Extrapolate::Periodic] is set for the spline, it is
+    /// set to [Extrapolate::Disabled] for the returned instance, since the antiderivative is no
+    /// longer periodic and its correct evaluation outside of the base interval is difficult.
+    pub fn antiderivative(&self, nu: Option<usize>) -> Self {
+        let mut spline = self.clone();
+        for _ in 0..nu.unwrap_or(1) {
+            let (t, k, n) = (&spline.t, spline.k, spline.n());
+            let scale = F::from(k + 1).unwrap();
+            let mut shape = spline.c.raw_dim();
+            shape[spline.axis] = n + 1;
+            let mut c = Array::zeros(shape);
+            Zip::from(c.lanes_mut(Axis(spline.axis)))
+                .and(spline.c.lanes(Axis(spline.axis)))
+                .for_each(|mut ic, c| {
+                    for i in 0..n {
+                        ic[i + 1] = ic[i] + c[i] * (t[i + k + 1] - t[i]) / scale;
+                    }
+                });
+            let t: Vec<F> = [t[0]]
+                .into_iter()
+                .chain(t.iter().copied())
+                .chain([t[t.len() - 1]])
+                .collect();
+            spline = Self {
+                t,
+                c,
+                k: k + 1,
+                ..spline
+            };
+        }
+        if spline.extrapolate == Extrapolate::Periodic {
+            spline.extrapolate = Extrapolate::Disabled;
+        }
+        spline
+    }
+
+    /// Compute a definite integral of the spline.
+    ///
+    /// ## Parameters
+    /// * `a`: Lower limit of integration.
+    /// * `b`: Upper limit of integration.
+    /// * `extrapolate`: Whether to extrapolate beyond the base interval, or to integrate only
+    ///   over it. If [Extrapolate::Periodic], periodic extrapolation is used. Defaults to the one
+    ///   of the spline.
+    ///
+    /// ## Returns
+    /// Definite integral of the spline over `[a, b]`, for every spline.
+    pub fn integrate(&self, a: F, b: F, extrapolate: Option<Extrapolate>) -> Array<F, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        let extrapolate = extrapolate.unwrap_or(self.extrapolate);
+        let (mut a, mut b, sign) = if b < a {
+            (b, a, -F::one())
+        } else {
+            (a, b, F::one())
+        };
+        let (start, end) = (self.t[self.k], self.t[self.n()]);
+        let antiderivative = self.antiderivative(Some(1));
+        let axis = Axis(self.axis);
+        let integral = |a: F, b: F| {
+            let values = antiderivative.eval(&[a, b], 0, Extrapolate::Enabled);
+            &values.index_axis(axis, 1) - &values.index_axis(axis, 0)
+        };
+
+        let total = match extrapolate {
+            Extrapolate::Periodic => {
+                let period = end - start;
+                let n_periods = ((b - a) / period).floor();
+                let left = (b - a) - n_periods * period;
+                let mut total = integral(start, end).mapv(|v| v * n_periods);
+
+                // The remaining part starts in the base interval, and may wrap around its end.
+                let r = (a - start) % period;
+                let a = start + if r < F::zero() { r + period } else { r };
+                let b = a + left;
+                if b <= end {
+                    total = total + integral(a, b);
+                } else {
+                    total = total + integral(a, end) + integral(start, start + b - end);
+                }
+                total
+            }
+            Extrapolate::Enabled => integral(a, b),
+            Extrapolate::Disabled => integral(a.max(start), b.min(end)),
+        };
+        total.mapv(|v| v * sign)
+    }
+}
+
+/// Index `l` of the knot interval `t[l] <= x < t[l + 1]` used to evaluate a spline of degree `k`
+/// at `x`, or `None` if `x` is NaN or outside of the base interval without `extrapolate`.
+///
+/// The right end of the base interval belongs to the last non-empty interval, and points outside of
+/// it are assigned the first or last one.
+pub(crate) fn find_interval<F: Float>(t: &[F], k: usize, x: F, extrapolate: bool) -> Option<usize> {
+    let n = t.len() - k - 1;
+    if x.is_nan() || (!extrapolate && (x < t[k] || x > t[n])) {
+        return None;
+    }
+    let l = t.partition_point(|&t| t <= x).saturating_sub(1);
+    Some(l.clamp(k, n - 1))
+}
+
+/// Values of the `nu`-th derivatives of the `k + 1` B-splines `B[l - k], ..., B[l]` that are
+/// non-zero at `x`, in the interval `l` of the knots `t`, written into `h`.
+///
+/// This is the Cox-de Boor recursion up to degree `k - nu`, followed by the recursion of the
+/// derivatives.
+pub(crate) fn eval_basis<F: Float>(t: &[F], k: usize, x: F, l: usize, nu: usize, h: &mut [F]) {
+    if nu > k {
+        h.fill(F::zero());
+        return;
+    }
+    h[0] = F::one();
+    for j in 1..=k {
+        let scale = F::from(j).unwrap();
+        let mut saved = F::zero();
+        for n in 0..j {
+            let (xa, xb) = (t[l + n + 1 - j], t[l + n + 1]);
+            let w = if xb == xa {
+                F::zero()
+            } else {
+                h[n] / (xb - xa)
+            };
+            if j <= k - nu {
+                h[n] = saved + w * (xb - x);
+                saved = w * (x - xa);
+            } else {
+                h[n] = saved - scale * w;
+                saved = scale * w;
+            }
+        }
+        h[j] = saved;
+    }
+}
+
+/// Interpolating spline of degree `k` through the points `(x, y)`, with `x` along `axis` of `y`.
+///
+/// The knots are those of scipy's `make_interp_spline` without boundary conditions: the points
+/// themselves for `k <= 1`, the midpoints for `k = 2`, and the not-a-knot knots for odd `k`.
+/// `x` must be sorted with at least `k + 1` distinct points.
+pub(crate) fn make_interp_spline<F, S, D>(
+    x: &[F],
+    y: &ArrayBase<S, D>,
+    k: usize,
+    axis: usize,
+) -> Result<BSpline<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let n = x.len();
+    if n < k + 1 {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: format!(
+                "Need at least {} points for a spline of degree {}.",
+                k + 1,
+                k
+            ),
+        });
+    }
+    if x.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "Expect x to be a strictly increasing sequence.".into(),
+        });
+    }
+    let (first, last) = (x[0], x[n - 1]);
+    let two = F::one() + F::one();
+    let t: Vec<F> = match k {
+        0 => x.iter().copied().chain([last]).collect(),
+        1 => [first]
+            .into_iter()
+            .chain(x.iter().copied())
+            .chain([last])
+            .collect(),
+        2 => {
+            // The midpoints of all but the first and last pairs of points.
+            let inner = x[1..n - 1].windows(2).map(|w| (w[0] + w[1]) / two);
+            (0..3)
+                .map(|_| first)
+                .chain(inner)
+                .chain((0..3).map(|_| last))
+                .collect()
+        }
+        _ if k % 2 == 1 => {
+            let m = (k - 1) / 2;
+            (0..=k)
+                .map(|_| first)
+                .chain(x[m + 1..n - m - 1].iter().copied())
+                .chain((0..=k).map(|_| last))
+                .collect()
+        }
+        _ => {
+            return Err(Error::InvalidArg {
+                arg: "k".into(),
+                reason: "Only odd degrees above 2 are supported without knots.".into(),
+            })
+        }
+    };
+
+    let mut c = y.to_owned();
+    if k <= 1 {
+        return BSpline::new(t, c, k, None, Some(axis as isize));
+    }
+
+    // Row i of the collocation matrix holds the basis functions B[l - k], ..., B[l] at x[i].
+    let mut rows = vec![F::zero(); n * (k + 1)];
+    let mut intervals = vec![0; n];
+    let (mut kl, mut ku) = (0, 0);
+    for (i, (h, l)) in rows.chunks_exact_mut(k + 1).zip(&mut intervals).enumerate() {
+        *l = find_interval(&t, k, x[i], true).unwrap_or(k);
+        eval_basis(&t, k, x[i], *l, 0, h);
+        kl = kl.max(i.saturating_sub(*l - k));
+        ku = ku.max(l.saturating_sub(i));
+    }
+    let lu = BandedLu::new(n, kl, ku, |i, j| {
+        let l = intervals[i];
+        if j + k >= l && j <= l {
+            rows[i * (k + 1) + j + k - l]
+        } else {
+            F::zero()
+        }
+    })?;
+
+    let mut b = vec![F::zero(); n];
+    for mut lane in c.lanes_mut(Axis(axis)) {
+        b.iter_mut().zip(&lane).for_each(|(b, &y)| *b = y);
+        lu.solve(&mut b);
+        lane.iter_mut().zip(&b).for_each(|(c, &b)| *c = b);
+    }
+    BSpline::new(t, c, k, None, Some(axis as isize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn basis_partition_of_unity() {
+        let t = [0., 0., 0., 0., 1., 2.5, 3., 3., 3., 3.];
+        let mut h = [0.; 4];
+        for x in [0., 0.3, 1., 2.7, 3.] {
+            let l = find_interval(&t, 3, x, false).unwrap();
+            assert!(t[l] <= x && (x < t[l + 1] || l == 5));
+            eval_basis(&t, 3, x, l, 0, &mut h);
+            assert_abs_diff_eq!(h.iter().sum::<f64>(), 1., epsilon = 1e-14);
+            assert!(h.iter().all(|&h| h >= 0.));
+        }
+        assert_eq!(find_interval(&t, 3, -0.1, false), None);
+        assert_eq!(find_interval(&t, 3, -0.1, true), Some(3));
+        assert_eq!(find_interval(&t, 3, 3.2, true), Some(5));
+    }
+
+    #[test]
+    fn reproduces_polynomials() {
+        // A spline of degree k interpolates polynomials of degree k exactly.
+        let x = [0., 0.5, 1.3, 2., 3.1, 4., 4.2];
+        let p = |x: f64| 1. - 2. * x + 0.5 * x * x - 0.25 * x * x * x;
+        let y = x.map(p);
+        let spline = make_interp_spline(&x, &ndarray::arr1(&y), 3, 0).unwrap();
+        let xs = [-0.5, 0., 0.7, 2.2, 4.2, 5.];
+        let ys = spline.eval(&xs, 0, Extrapolate::Enabled);
+        for (&x, &y) in xs.iter().zip(&ys) {
+            assert_abs_diff_eq!(y, p(x), epsilon = 1e-12);
+        }
+
+        let q = |x: f64| 3. + x - 0.75 * x * x;
+        let y = array![x.map(q), x.map(|x| 2. * q(x))];
+        let spline = make_interp_spline(&x, &y, 2, 1).unwrap();
+        let ys = spline.eval(&xs, 0, Extrapolate::Disabled);
+        for (j, &x) in xs.iter().enumerate() {
+            if (0. ..=4.2).contains(&x) {
+                assert_abs_diff_eq!(ys[[0, j]], q(x), epsilon = 1e-12);
+                assert_abs_diff_eq!(ys[[1, j]], 2. * q(x), epsilon = 1e-12);
+            } else {
+                assert!(ys[[0, j]].is_nan());
+            }
+        }
+    }
+}
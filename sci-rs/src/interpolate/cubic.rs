@@ -0,0 +1,669 @@
+use super::ppoly::{Extrapolate, PPoly};
+use crate::linalg::BandedLu;
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use alloc::{vec, vec::Vec};
+use core::ops::Deref;
+use ndarray::{Array, ArrayBase, ArrayView1, ArrayViewMut1, Axis, Data, Dimension, Zip};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Boundary condition of a [CubicSpline], applied at both ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoundaryCondition {
+    /// The first and second segment at a curve end are the same polynomial. It is a good default
+    /// when there is no information on boundary conditions.
+    #[default]
+    NotAKnot,
+    /// The second derivative at curve ends are zero.
+    Natural,
+    /// The first derivative at curve ends are zero.
+    Clamped,
+    /// The interpolated function is periodic with period `x[n-1] - x[0]`. The first and last value
+    /// of `y` must be identical.
+    Periodic,
+}
+
+/// Cubic spline data interpolator.
+///
+/// Interpolate data with a piecewise cubic polynomial which is twice continuously differentiable.
+/// The result is represented as a [PPoly] instance with breakpoints matching the given data, which
+/// the spline dereferences to for evaluation and calculus.
+///
+/// ## Notes
+/// Parameters `bc_type` and `extrapolate` work independently, i.e. the former controls only
+/// construction of a spline, and the latter only evaluation.
+///
+/// When a boundary condition is 'not-a-knot' and n = 2, it is replaced by a condition that the
+/// first derivative is equal to the linear interpolant slope. When both boundary conditions are
+/// 'not-a-knot' and n = 3, the solution is sought as a parabola passing through given points.
+///
+/// ## References
+/// [1] [Cubic Spline Interpolation](https://en.wikiversity.org/wiki/Cubic_Spline_Interpolation)
+///     on Wikiversity.
+///
+/// [2] Carl de Boor, "A Practical Guide to Splines", Springer-Verlag, 1978.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.interpolate import CubicSpline
+/// theta = 2 * np.pi * np.linspace(0, 1, 5)
+/// y = np.c_[np.cos(theta), np.sin(theta)]
+/// cs = CubicSpline(theta, y, bc_type='periodic')
+/// print("ds/dx={:.1f} ds/dy={:.1f}".format(cs(0, 1)[0], cs(0, 1)[1]))
+/// ds/dx=0.0 ds/dy=1.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::Array2;
+/// use sci_rs::interpolate::{BoundaryCondition, CubicSpline};
+///
+/// let theta: Vec<f64> = (0..5).map(|i| core::f64::consts::PI * i as f64 / 2.).collect();
+/// let y = Array2::from_shape_fn((5, 2), |(i, j)| {
+///     if j == 0 { theta[i].cos() } else { theta[i].sin() }
+/// });
+/// let cs = CubicSpline::new(&theta, &y, None, Some(BoundaryCondition::Periodic), None).unwrap();
+/// let ds = cs.call(&[0.], Some(1), None);
+/// let printed = format!("ds/dx={:.1} ds/dy={:.1}", ds[[0, 0]], ds[[0, 1]]);
+/// assert_eq!(printed, "ds/dx=0.0 ds/dy=1.0");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CubicSpline<F, D: Dimension> {
+    ppoly: PPoly<F, D>,
+}
+
+impl<F: Float, D: Dimension> CubicSpline<F, D> {
+    /// Interpolate the points `(x, y)`.
+    ///
+    /// ## Parameters
+    /// * `x`: 1-D array containing values of the independent variable. Values must be real,
+    ///   finite and in strictly increasing order.
+    /// * `y`: Array containing values of the dependent variable. It can have arbitrary number of
+    ///   dimensions, but the length along `axis` must match the length of `x`. Values must be
+    ///   finite.
+    /// * `axis`: Axis along which `y` is assumed to be varying. Default is zero.
+    /// * `bc_type`: Boundary condition type. Default is [BoundaryCondition::NotAKnot].
+    /// * `extrapolate`: How to evaluate out-of-bounds points. Default is
+    ///   [Extrapolate::Periodic] with [BoundaryCondition::Periodic], and [Extrapolate::Enabled]
+    ///   otherwise.
+    ///
+    /// ## Errors
+    /// If there are less than 2 points, `x` is not strictly increasing, or the first and last
+    /// values of `y` differ with [BoundaryCondition::Periodic].
+    pub fn new<S>(
+        x: &[F],
+        y: &ArrayBase<S, D>,
+        axis: Option<isize>,
+        bc_type: Option<BoundaryCondition>,
+        extrapolate: Option<Extrapolate>,
+    ) -> Result<Self>
+    where
+        S: Data<Elem = F>,
+    {
+        let bc = bc_type.unwrap_or_default();
+        let (axis, dx) = prepare_input(x, y, axis)?;
+        let n = x.len();
+        let tol = F::from(1e-15).unwrap();
+        if bc == BoundaryCondition::Periodic
+            && y.lanes(Axis(axis))
+                .into_iter()
+                .any(|y| (y[0] - y[n - 1]).abs() > tol * (F::one() + y[n - 1].abs()))
+        {
+            return Err(Error::InvalidArg {
+                arg: "y".into(),
+                reason: "The first and last `y` point along axis must be identical for periodic \
+                         boundary conditions."
+                    .into(),
+            });
+        }
+
+        let slopes = Slopes::new(x, &dx, bc)?;
+        let mut dydx = Array::zeros(y.raw_dim());
+        let mut slope = vec![F::zero(); n - 1];
+        Zip::from(y.lanes(Axis(axis)))
+            .and(dydx.lanes_mut(Axis(axis)))
+            .for_each(|y, dydx| {
+                slope
+                    .iter_mut()
+                    .enumerate()
+                    .for_each(|(i, s)| *s = (y[i + 1] - y[i]) / dx[i]);
+                slopes.solve(&dx, y, &slope, dydx);
+            });
+
+        let extrapolate = extrapolate.unwrap_or(match bc {
+            BoundaryCondition::Periodic => Extrapolate::Periodic,
+            _ => Extrapolate::Enabled,
+        });
+        Ok(Self {
+            ppoly: hermite(x, &dx, y, &dydx, axis, extrapolate)?,
+        })
+    }
+}
+
+impl<F, D: Dimension> Deref for CubicSpline<F, D> {
+    type Target = PPoly<F, D>;
+
+    fn deref(&self) -> &PPoly<F, D> {
+        &self.ppoly
+    }
+}
+
+/// Weighting of the slopes of [Akima1DInterpolator].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AkimaMethod {
+    /// The original Akima weights.
+    #[default]
+    Akima,
+    /// The modified Akima weights, which avoid overshoots when the data is flat on more than two
+    /// consecutive intervals, or when the slopes of both sides of a point are opposite.
+    Makima,
+}
+
+/// Akima interpolator.
+///
+/// Fit piecewise cubic polynomials, given vectors x and y. The interpolation method by Akima uses a
+/// continuously differentiable sub-spline built from piecewise cubic polynomials. The resultant
+/// curve passes through the given data points and will appear smooth and natural. Like
+/// [CubicSpline], the interpolator dereferences to its [PPoly].
+///
+/// ## Notes
+/// Use only for precise data, as the fitted curve passes through the given points exactly. This
+/// routine is useful for plotting a pleasingly smooth curve through a few given points for
+/// purposes of plotting.
+///
+/// Let `d_i = (y_{i+1} - y_i) / (x_{i+1} - x_i)` be the slopes of the intervals. Then the
+/// derivative at `x_i` is `(|d_{i+1} - d_i| d_{i-1} + |d_{i-1} - d_{i-2}| d_i) /
+/// (|d_{i+1} - d_i| + |d_{i-1} - d_{i-2}|)`, and the two missing slopes at each end are
+/// extrapolated linearly from the first and last ones. Where the denominator vanishes, the
+/// derivative is the average of the surrounding slopes.
+///
+/// The modified Akima interpolation adds `|d_{i+1} + d_i| / 2` and `|d_{i-1} + d_{i-2}| / 2` to
+/// the respective weights.
+///
+/// ## References
+/// [1] A new method of interpolation and smooth curve fitting based on local procedures. Hiroshi
+///     Akima, J. ACM, October 1970, 17(4), 589-602.
+///
+/// [2] [Makima Piecewise Cubic Interpolation](https://blogs.mathworks.com/cleve/2019/04/29/makima-piecewise-cubic-interpolation/).
+///     Cleve Moler and Cosmin Ionita, 2019.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.interpolate import Akima1DInterpolator
+/// x = np.arange(6.)
+/// y = np.array([0., 0., 0., 1., 1., 1.])
+/// Akima1DInterpolator(x, y, method="makima")([2.5, 4.5])
+/// array([0.5, 1. ])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::interpolate::{Akima1DInterpolator, AkimaMethod};
+///
+/// let x = [0., 1., 2., 3., 4., 5.];
+/// let y = array![0., 0., 0., 1., 1., 1.];
+/// let akima = Akima1DInterpolator::new(&x, &y, None, Some(AkimaMethod::Makima), None).unwrap();
+/// let values = akima.call(&[2.5, 4.5], None, None);
+/// assert_abs_diff_eq!(values[0], 0.5, epsilon = 1e-12);
+/// assert_abs_diff_eq!(values[1], 1., epsilon = 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Akima1DInterpolator<F, D: Dimension> {
+    ppoly: PPoly<F, D>,
+}
+
+impl<F: Float, D: Dimension> Akima1DInterpolator<F, D> {
+    /// Interpolate the points `(x, y)`.
+    ///
+    /// ## Parameters
+    /// * `x`: 1-D array of monotonically increasing real values.
+    /// * `y`: N-D array of real values. The length of `y` along the interpolation axis must be
+    ///   equal to the length of `x`.
+    /// * `axis`: Axis in the `y` array corresponding to the x-coordinate values. Default is zero.
+    /// * `method`: The weighting of the slopes. Default is [AkimaMethod::Akima].
+    /// * `extrapolate`: How to evaluate out-of-bounds points. Default is [Extrapolate::Disabled].
+    ///
+    /// ## Errors
+    /// If there are less than 2 points or `x` is not strictly increasing.
+    pub fn new<S>(
+        x: &[F],
+        y: &ArrayBase<S, D>,
+        axis: Option<isize>,
+        method: Option<AkimaMethod>,
+        extrapolate: Option<Extrapolate>,
+    ) -> Result<Self>
+    where
+        S: Data<Elem = F>,
+    {
+        let makima = method.unwrap_or_default() == AkimaMethod::Makima;
+        let (axis, dx) = prepare_input(x, y, axis)?;
+        let n = x.len();
+        let half = F::from(0.5).unwrap();
+        let two = F::one() + F::one();
+
+        // The slopes of the intervals, with two more extrapolated at each end, and the weights of
+        // the slopes on both sides of every point.
+        let weights = |y: ArrayView1<F>| {
+            let mut m = vec![F::zero(); n + 3];
+            for i in 0..n - 1 {
+                m[i + 2] = (y[i + 1] - y[i]) / dx[i];
+            }
+            if n == 2 {
+                let slope = m[2];
+                m.fill(slope);
+            }
+            m[1] = two * m[2] - m[3];
+            m[0] = two * m[1] - m[2];
+            m[n + 1] = two * m[n] - m[n - 1];
+            m[n + 2] = two * m[n + 1] - m[n];
+            let weight = |a: F, b: F| {
+                if makima {
+                    (b - a).abs() + half * (b + a).abs()
+                } else {
+                    (b - a).abs()
+                }
+            };
+            let f: Vec<(F, F)> = (0..n)
+                .map(|i| (weight(m[i + 2], m[i + 3]), weight(m[i], m[i + 1])))
+                .collect();
+            (m, f)
+        };
+
+        // The slope is only defined where the weights are not negligible for the whole data.
+        let max = y
+            .lanes(Axis(axis))
+            .into_iter()
+            .flat_map(|y| weights(y).1.into_iter().map(|(f1, f2)| f1 + f2))
+            .fold(F::neg_infinity(), F::max);
+        let tol = F::from(1e-9).unwrap() * max;
+        let mut dydx = Array::zeros(y.raw_dim());
+        Zip::from(y.lanes(Axis(axis)))
+            .and(dydx.lanes_mut(Axis(axis)))
+            .for_each(|y, mut dydx| {
+                let (m, f) = weights(y);
+                for (i, (t, (f1, f2))) in dydx.iter_mut().zip(f).enumerate() {
+                    *t = if f1 + f2 > tol {
+                        (f1 * m[i + 1] + f2 * m[i + 2]) / (f1 + f2)
+                    } else {
+                        half * (m[i + 3] + m[i])
+                    };
+                }
+            });
+
+        Ok(Self {
+            ppoly: hermite(
+                x,
+                &dx,
+                y,
+                &dydx,
+                axis,
+                extrapolate.unwrap_or(Extrapolate::Disabled),
+            )?,
+        })
+    }
+}
+
+impl<F, D: Dimension> Deref for Akima1DInterpolator<F, D> {
+    type Target = PPoly<F, D>;
+
+    fn deref(&self) -> &PPoly<F, D> {
+        &self.ppoly
+    }
+}
+
+/// The linear system for the first derivatives of a cubic spline at the points, which only
+/// depends on the points and on the boundary condition.
+enum Slopes<F> {
+    /// The slope of the single interval at both points.
+    Line,
+    /// Tridiagonal system of the derivatives at every point.
+    Tridiagonal(BandedLu<F>, BoundaryCondition),
+    /// Periodic spline through 3 points, with the same derivative everywhere.
+    PeriodicTriple,
+    /// The first n - 2 rows and columns of the cyclic tridiagonal system of the derivatives at the
+    /// first n - 1 points, and the solution for the corner entries on the right-hand side.
+    Cyclic(BandedLu<F>, Vec<F>),
+}
+
+impl<F: Float> Slopes<F> {
+    fn new(x: &[F], dx: &[F], bc: BoundaryCondition) -> Result<Self> {
+        let n = x.len();
+        let two = F::one() + F::one();
+        Ok(match (bc, n) {
+            (BoundaryCondition::NotAKnot | BoundaryCondition::Periodic, 2) => Slopes::Line,
+            (BoundaryCondition::Periodic, 3) => Slopes::PeriodicTriple,
+            (BoundaryCondition::Periodic, _) => {
+                // Row i couples the derivatives at i - 1, i and i + 1 through the lengths of the
+                // intervals i and i - 1.
+                let lu = BandedLu::new(n - 2, 1, 1, |i, j| match (i, j) {
+                    (0, 0) => two * (dx[n - 2] + dx[0]),
+                    (0, 1) => dx[n - 2],
+                    _ if i == j => two * (dx[i - 1] + dx[i]),
+                    _ if j + 1 == i => dx[i],
+                    _ => dx[i - 1],
+                })?;
+                let mut corner = vec![F::zero(); n - 2];
+                corner[0] = -dx[0];
+                corner[n - 3] = -dx[n - 4];
+                lu.solve(&mut corner);
+                Slopes::Cyclic(lu, corner)
+            }
+            _ => {
+                // Through 3 points, the not-a-knot spline is the parabola whose derivative is the
+                // average of the derivatives at both ends of each interval.
+                let parabola = bc == BoundaryCondition::NotAKnot && n == 3;
+                let lu = BandedLu::new(n, 1, 1, |i, j| match (i, j) {
+                    _ if parabola && i != 1 => F::one(),
+                    (0, 0) => match bc {
+                        BoundaryCondition::NotAKnot => dx[1],
+                        BoundaryCondition::Natural => two * dx[0],
+                        _ => F::one(),
+                    },
+                    (0, 1) => match bc {
+                        BoundaryCondition::NotAKnot => x[2] - x[0],
+                        BoundaryCondition::Natural => dx[0],
+                        _ => F::zero(),
+                    },
+                    _ if i == n - 1 && j == n - 1 => match bc {
+                        BoundaryCondition::NotAKnot => dx[n - 3],
+                        BoundaryCondition::Natural => two * dx[n - 2],
+                        _ => F::one(),
+                    },
+                    _ if i == n - 1 => match bc {
+                        BoundaryCondition::NotAKnot => x[n - 1] - x[n - 3],
+                        BoundaryCondition::Natural => dx[n - 2],
+                        _ => F::zero(),
+                    },
+                    _ if i == j => two * (dx[i - 1] + dx[i]),
+                    _ if j + 1 == i => dx[i],
+                    _ => dx[i - 1],
+                })?;
+                Slopes::Tridiagonal(lu, bc)
+            }
+        })
+    }
+
+    /// Derivatives of the spline through the values `y`, whose interval slopes are `slope`.
+    fn solve(&self, dx: &[F], y: ArrayView1<F>, slope: &[F], mut dydx: ArrayViewMut1<F>) {
+        let n = dydx.len();
+        let (two, three) = (F::one() + F::one(), F::from(3).unwrap());
+        // Right-hand side of the rows of the points strictly inside.
+        let inner = |i: usize| three * (dx[i] * slope[i - 1] + dx[i - 1] * slope[i]);
+        match self {
+            Slopes::Line => dydx.fill(slope[0]),
+            Slopes::PeriodicTriple => {
+                let t = (slope[0] / dx[0] + slope[1] / dx[1]) / (dx[0].recip() + dx[1].recip());
+                dydx.fill(t);
+            }
+            Slopes::Tridiagonal(lu, bc) => {
+                let mut b: Vec<F> = (0..n)
+                    .map(|i| {
+                        if i == 0 || i == n - 1 {
+                            F::zero()
+                        } else {
+                            inner(i)
+                        }
+                    })
+                    .collect();
+                match bc {
+                    BoundaryCondition::NotAKnot if n == 3 => {
+                        (b[0], b[2]) = (two * slope[0], two * slope[1]);
+                    }
+                    BoundaryCondition::NotAKnot => {
+                        let d = dx[0] + dx[1];
+                        b[0] =
+                            ((dx[0] + two * d) * dx[1] * slope[0] + dx[0] * dx[0] * slope[1]) / d;
+                        let d = dx[n - 2] + dx[n - 3];
+                        b[n - 1] = (dx[n - 2] * dx[n - 2] * slope[n - 3]
+                            + (two * d + dx[n - 2]) * dx[n - 3] * slope[n - 2])
+                            / d;
+                    }
+                    BoundaryCondition::Natural => {
+                        (b[0], b[n - 1]) = (three * (y[1] - y[0]), three * (y[n - 1] - y[n - 2]));
+                    }
+                    _ => {}
+                }
+                lu.solve(&mut b);
+                dydx.iter_mut().zip(b).for_each(|(d, b)| *d = b);
+            }
+            Slopes::Cyclic(lu, corner) => {
+                let m = n - 1;
+                let mut b: Vec<F> = (0..m - 1)
+                    .map(|i| if i == 0 { F::zero() } else { inner(i) })
+                    .collect();
+                b[0] = three * (dx[0] * slope[m - 1] + dx[m - 1] * slope[0]);
+                lu.solve(&mut b);
+                let last = inner(m - 1);
+                let (a_m1_0, a_m1_m2, a_m1_m1) =
+                    (dx[m - 2], dx[m - 1], two * (dx[m - 1] + dx[m - 2]));
+                let s_m1 = (last - a_m1_0 * b[0] - a_m1_m2 * b[m - 2])
+                    / (a_m1_m1 + a_m1_0 * corner[0] + a_m1_m2 * corner[m - 2]);
+                for i in 0..m - 1 {
+                    dydx[i] = b[i] + s_m1 * corner[i];
+                }
+                dydx[m - 1] = s_m1;
+                dydx[m] = dydx[0];
+            }
+        }
+    }
+}
+
+/// Check the points `(x, y)` of an interpolator along `axis` of `y`, which defaults to zero.
+///
+/// Returns the axis as an index, and the lengths of the intervals.
+fn prepare_input<F, S, D>(
+    x: &[F],
+    y: &ArrayBase<S, D>,
+    axis: Option<isize>,
+) -> Result<(usize, Vec<F>)>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(Some(axis.unwrap_or(0)), y)?;
+    if x.len() < 2 {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "x must contain at least 2 elements.".into(),
+        });
+    }
+    if x.len() != y.len_of(Axis(axis)) {
+        return Err(Error::InvalidArg {
+            arg: "y".into(),
+            reason: "The length of y along axis does not match the length of x.".into(),
+        });
+    }
+    if x.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "x must be strictly increasing sequence.".into(),
+        });
+    }
+    Ok((axis, x.windows(2).map(|w| w[1] - w[0]).collect()))
+}
+
+/// Piecewise cubic polynomial with the values `y` and first derivatives `dydx` at the points `x`,
+/// whose intervals have the lengths `dx`.
+fn hermite<F, S, D>(
+    x: &[F],
+    dx: &[F],
+    y: &ArrayBase<S, D>,
+    dydx: &Array<F, D>,
+    axis: usize,
+    extrapolate: Extrapolate,
+) -> Result<PPoly<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let two = F::one() + F::one();
+    let mut shape = y.raw_dim();
+    shape[axis] = x.len() - 1;
+    let mut c: [Array<F, D>; 4] = core::array::from_fn(|_| Array::zeros(shape.clone()));
+    let [c0, c1, c2, c3] = &mut c;
+    let axis = Axis(axis);
+    Zip::from(y.lanes(axis))
+        .and(dydx.lanes(axis))
+        .and(c0.lanes_mut(axis))
+        .and(c1.lanes_mut(axis))
+        .and(c2.lanes_mut(axis))
+        .and(c3.lanes_mut(axis))
+        .for_each(|y, dydx, mut c0, mut c1, mut c2, mut c3| {
+            for (i, &dx) in dx.iter().enumerate() {
+                let slope = (y[i + 1] - y[i]) / dx;
+                let t = (dydx[i] + dydx[i + 1] - two * slope) / dx;
+                c0[i] = t / dx;
+                c1[i] = (slope - dydx[i]) / dx - t;
+                c2[i] = dydx[i];
+                c3[i] = y[i];
+            }
+        });
+    PPoly::new(
+        c.into(),
+        x.to_vec(),
+        Some(axis.index() as isize),
+        Some(extrapolate),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{arr1, Array2};
+
+    #[track_caller]
+    fn assert_close(a: impl IntoIterator<Item = f64>, b: &[f64], epsilon: f64) {
+        let a: Vec<f64> = a.into_iter().collect();
+        assert_eq!(a.len(), b.len());
+        for (a, &b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = epsilon);
+        }
+    }
+
+    #[track_caller]
+    fn spline(x: &[f64], y: &[f64], bc: BoundaryCondition) -> CubicSpline<f64, ndarray::Ix1> {
+        CubicSpline::new(x, &arr1(y), None, Some(bc), None).unwrap()
+    }
+
+    #[test]
+    fn boundary_conditions() {
+        let x = [0., 1., 2.];
+        let y = [0., 1., 0.];
+        // -x^3 / 2 + 3 x / 2 on [0, 1], mirrored on [1, 2].
+        let natural = spline(&x, &y, BoundaryCondition::Natural);
+        assert_abs_diff_eq!(natural.call(&[0.5], None, None)[0], 0.6875, epsilon = 1e-12);
+        assert_abs_diff_eq!(natural.call(&[0.], Some(2), None)[0], 0., epsilon = 1e-12);
+        assert_abs_diff_eq!(natural.call(&[2.], Some(2), None)[0], 0., epsilon = 1e-12);
+        assert_abs_diff_eq!(natural.call(&[0.], Some(1), None)[0], 1.5, epsilon = 1e-12);
+        // 3 x^2 - 2 x^3 on [0, 1], which is also the periodic spline.
+        for bc in [BoundaryCondition::Clamped, BoundaryCondition::Periodic] {
+            let s = spline(&x, &y, bc);
+            assert_close(s.call(&[0.5, 1.5], None, None), &[0.5, 0.5], 1e-12);
+            assert_abs_diff_eq!(s.call(&[0.], Some(1), None)[0], 0., epsilon = 1e-12);
+        }
+        // The not-a-knot spline through 3 points is the parabola.
+        let s = spline(&x, &y, BoundaryCondition::NotAKnot);
+        assert_close(s.call(&[0.5, 3.], None, None), &[0.75, -3.], 1e-12);
+        let s = spline(&x[..2], &y[..2], BoundaryCondition::NotAKnot);
+        assert_close(s.call(&[0.5, 3.], None, None), &[0.5, 3.], 1e-12);
+
+        // The periodic spline is periodic up to its second derivative.
+        let x = [0., 0.5, 1.5, 2., 3., 4.];
+        let y = [0., 1., 0.5, -1., -0.2, 0.];
+        let s = spline(&x, &y, BoundaryCondition::Periodic);
+        for nu in 0..3 {
+            let ends = s.call(&[0., 4.], Some(nu), Some(Extrapolate::Enabled));
+            assert_abs_diff_eq!(ends[0], ends[1], epsilon = 1e-12);
+        }
+        assert_abs_diff_eq!(s.call(&[4.5], None, None)[0], s.call(&[0.5], None, None)[0]);
+        assert!(CubicSpline::new(
+            &x,
+            &arr1(&[1., 1., 1., 1., 1., 2.]),
+            None,
+            Some(BoundaryCondition::Periodic),
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn cubic_polynomials() {
+        // Not-a-knot splines reproduce cubic polynomials, for every column.
+        let x = [0., 1., 2.5, 3., 4.];
+        let p = |x: f64| x * x * x - 2. * x + 1.;
+        let y = Array2::from_shape_fn((5, 2), |(i, j)| (j + 1) as f64 * p(x[i]));
+        let s = CubicSpline::new(&x, &y, None, None, None).unwrap();
+        let xs = [-1., 0.3, 2.7, 5.];
+        let values = s.call(&xs, None, None);
+        let derivatives = s.derivative(None).call(&xs, None, None);
+        let primitives = s.antiderivative(None).call(&xs, None, None);
+        for (i, &x) in xs.iter().enumerate() {
+            for j in 0..2 {
+                let scale = (j + 1) as f64;
+                assert_abs_diff_eq!(values[[i, j]], scale * p(x), epsilon = 1e-10);
+                assert_abs_diff_eq!(
+                    derivatives[[i, j]],
+                    scale * (3. * x * x - 2.),
+                    epsilon = 1e-10
+                );
+                let primitive = x * x * x * x / 4. - x * x + x;
+                assert_abs_diff_eq!(primitives[[i, j]], scale * primitive, epsilon = 1e-10);
+            }
+        }
+        let integral = s.integrate(0.5, 3.5, None);
+        assert_close(integral, &[28.5, 57.], 1e-10);
+
+        // The roots of -x^3 / 2 + 3 x / 2 and its mirror image.
+        let s = spline(&[0., 1., 2.], &[0., 1., 0.], BoundaryCondition::Natural);
+        let sqrt3 = 3f64.sqrt();
+        let roots = s.roots(None, None).into_scalar();
+        assert_close(roots, &[-sqrt3, 0., 2., 2. + sqrt3], 1e-12);
+        let roots = s.roots(None, Some(Extrapolate::Disabled)).into_scalar();
+        assert_close(roots, &[0., 2.], 1e-12);
+        let roots = s.solve(0.5, None, None).into_scalar();
+        let root = 2. * (80f64.to_radians()).cos();
+        assert_close(
+            roots,
+            &[
+                -2. * (20f64.to_radians()).cos(),
+                root,
+                2. - root,
+                2. + 2. * (20f64.to_radians()).cos(),
+            ],
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn akima() {
+        let x = [0., 1., 2., 3.];
+        let y = arr1(&[0., 1., 1., 2.]);
+        for (method, slopes) in [
+            (AkimaMethod::Akima, [1.5, 0.5, 0.5, 1.5]),
+            (AkimaMethod::Makima, [1.3, 0.375, 0.375, 1.3]),
+        ] {
+            let akima = Akima1DInterpolator::new(&x, &y, None, Some(method), None).unwrap();
+            assert_close(akima.call(&x, None, None), &[0., 1., 1., 2.], 1e-12);
+            assert_close(akima.call(&x, Some(1), None), &slopes, 1e-12);
+            assert!(akima.call(&[-0.5], None, None)[0].is_nan());
+        }
+
+        // No overshoot next to flat data, along the last axis.
+        let y = ndarray::array![[0., 0., 0., 1., 1., 1.]];
+        let x = [0., 1., 2., 3., 4., 5.];
+        let akima = Akima1DInterpolator::new(&x, &y, Some(-1), None, None).unwrap();
+        let values = akima.call(&[1.5, 2.25, 2.5, 4.5], None, None);
+        assert_close(values, &[0., 0.15625, 0.5, 1.], 1e-12);
+
+        let akima =
+            Akima1DInterpolator::new(&x[..2], &y.slice(ndarray::s![.., ..2]), Some(1), None, None);
+        assert_close(akima.unwrap().call(&[0.5], Some(1), None), &[0.], 1e-12);
+    }
+}
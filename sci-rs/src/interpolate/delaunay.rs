@@ -0,0 +1,238 @@
+use alloc::{collections::BTreeSet, vec, vec::Vec};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Delaunay triangulation of points in the plane.
+///
+/// The points are inserted one at a time by the Bowyer-Watson algorithm, inside a large triangle
+/// that is removed at the end. The triangles that this misses along the convex hull, whose
+/// circumcircles reach a vertex of that triangle, are added back by filling the concave corners of
+/// the boundary. Coincident points are only triangulated once.
+#[derive(Debug, Clone)]
+pub(crate) struct Triangulation<F> {
+    /// The points, scaled to the unit square.
+    points: Vec<[F; 2]>,
+    /// Shift and scale of the points.
+    offset: [F; 2],
+    scale: F,
+    /// Indices of the vertices of every triangle, counterclockwise.
+    triangles: Vec<[usize; 3]>,
+}
+
+impl<F: Float> Triangulation<F> {
+    /// Triangulate `points`, failing if they are fewer than 3 or all collinear.
+    pub(crate) fn new(points: &[[F; 2]]) -> Result<Self> {
+        let collinear = || Error::InvalidArg {
+            arg: "points".into(),
+            reason: "The points are collinear and cannot be triangulated.".into(),
+        };
+        if points.iter().flatten().any(|p| !p.is_finite()) {
+            return Err(Error::InvalidArg {
+                arg: "points".into(),
+                reason: "The points must be finite.".into(),
+            });
+        }
+        let n = points.len();
+        let mut offset = [F::infinity(); 2];
+        let mut upper = [F::neg_infinity(); 2];
+        for p in points {
+            for d in 0..2 {
+                offset[d] = offset[d].min(p[d]);
+                upper[d] = upper[d].max(p[d]);
+            }
+        }
+        let scale = (upper[0] - offset[0]).max(upper[1] - offset[1]);
+        if n < 3 || scale <= F::zero() {
+            return Err(collinear());
+        }
+        let mut scaled: Vec<[F; 2]> = points
+            .iter()
+            .map(|p| [(p[0] - offset[0]) / scale, (p[1] - offset[1]) / scale])
+            .collect();
+
+        // Vertices `n..n + 3` enclose the unit square.
+        let m = F::from(100).unwrap();
+        let three = F::from(3).unwrap();
+        scaled.extend([[-m, -m], [three * m, -m], [-m, three * m]]);
+        let mut triangles = vec![[n, n + 1, n + 2]];
+        let mut bad = Vec::new();
+        let mut edges = BTreeSet::new();
+        for i in 0..n {
+            let d = scaled[i];
+            bad.clear();
+            bad.extend(
+                (0..triangles.len())
+                    .filter(|&t| in_circle(triangles[t].map(|v| scaled[v]), d) > F::zero()),
+            );
+            // The boundary of the cavity is made of the edges of only one bad triangle.
+            edges.clear();
+            for &t in &bad {
+                let [a, b, c] = triangles[t];
+                for (u, v) in [(a, b), (b, c), (c, a)] {
+                    if !edges.remove(&(v, u)) {
+                        edges.insert((u, v));
+                    }
+                }
+            }
+            for &t in bad.iter().rev() {
+                triangles.swap_remove(t);
+            }
+            triangles.extend(edges.iter().map(|&(u, v)| [u, v, i]));
+        }
+        triangles.retain(|t| t.iter().all(|&v| v < n));
+        scaled.truncate(n);
+        if triangles.is_empty() {
+            return Err(collinear());
+        }
+
+        let mut triangulation = Triangulation {
+            points: scaled,
+            offset,
+            scale,
+            triangles,
+        };
+        triangulation.fill_hull();
+        Ok(triangulation)
+    }
+
+    /// Add triangles at the concave corners of the boundary until it is convex.
+    fn fill_hull(&mut self) {
+        let n = self.points.len();
+        let directed: BTreeSet<_> = self
+            .triangles
+            .iter()
+            .flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)])
+            .collect();
+        let (mut next, mut prev) = (vec![None; n], vec![None; n]);
+        for &(u, v) in &directed {
+            if !directed.contains(&(v, u)) {
+                next[u] = Some(v);
+                prev[v] = Some(u);
+            }
+        }
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for b in 0..n {
+                let (Some(a), Some(c)) = (prev[b], next[b]) else {
+                    continue;
+                };
+                let [pa, pb, pc] = [a, b, c].map(|v| self.points[v]);
+                if a != c && orientation(pa, pb, pc) < F::zero() {
+                    self.triangles.push([a, c, b]);
+                    next[a] = Some(c);
+                    prev[c] = Some(a);
+                    (prev[b], next[b]) = (None, None);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    /// Indices of the vertices of the triangles.
+    pub(crate) fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    /// A triangle containing `p` and the barycentric coordinates of `p` in it, if any.
+    pub(crate) fn find_simplex(&self, p: [F; 2]) -> Option<(usize, [F; 3])> {
+        let p = [
+            (p[0] - self.offset[0]) / self.scale,
+            (p[1] - self.offset[1]) / self.scale,
+        ];
+        let eps = F::from(100).unwrap() * F::epsilon();
+        self.triangles.iter().enumerate().find_map(|(t, vertices)| {
+            let [a, b, c] = vertices.map(|v| self.points[v]);
+            let area = orientation(a, b, c);
+            if area <= F::zero() {
+                return None;
+            }
+            let lb = orientation(a, p, c) / area;
+            let lc = orientation(a, b, p) / area;
+            let la = F::one() - lb - lc;
+            (la >= -eps && lb >= -eps && lc >= -eps).then_some((t, [la, lb, lc]))
+        })
+    }
+}
+
+/// Twice the signed area of the triangle `a, b, c`, positive if it is counterclockwise.
+fn orientation<F: Float>(a: [F; 2], b: [F; 2], c: [F; 2]) -> F {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+/// Positive if `d` is inside the circumcircle of the counterclockwise triangle `t`.
+fn in_circle<F: Float>(t: [[F; 2]; 3], d: [F; 2]) -> F {
+    let [a, b, c] = t.map(|p| [p[0] - d[0], p[1] - d[1]]);
+    let lift = |p: [F; 2]| p[0] * p[0] + p[1] * p[1];
+    lift(a) * (b[0] * c[1] - c[0] * b[1])
+        + lift(b) * (c[0] * a[1] - a[0] * c[1])
+        + lift(c) * (a[0] * b[1] - b[0] * a[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn empty_circles() {
+        // Corners of a rectangle and a low-discrepancy sequence inside it.
+        let mut points = vec![[0., 0.], [2., 0.], [0., 1.], [2., 1.], [1., 0.]];
+        points.extend((1..60).map(|i| {
+            let i = i as f64;
+            [
+                2. * (i * 0.618_033_988_7).fract(),
+                (i * 0.754_877_666_2).fract(),
+            ]
+        }));
+        points.push(points[7]);
+        let tri = Triangulation::new(&points).unwrap();
+        let area: f64 = tri
+            .triangles()
+            .iter()
+            .map(|t| {
+                let [a, b, c] = t.map(|v| points[v]);
+                let area = orientation(a, b, c);
+                assert!(area > 0.);
+                for (i, &d) in points.iter().enumerate() {
+                    if !t.contains(&i) {
+                        assert!(in_circle([a, b, c], d) <= 1e-12);
+                    }
+                }
+                area / 2.
+            })
+            .sum();
+        assert_abs_diff_eq!(area, 2., epsilon = 1e-12);
+        // Euler's formula, with the duplicate and the 4 corners and 1 point of the boundary.
+        assert_eq!(tri.triangles().len(), 2 * (points.len() - 1) - 5 - 2);
+
+        let (t, bary) = tri.find_simplex([0.3, 0.4]).unwrap();
+        let p = bary
+            .iter()
+            .zip(tri.triangles()[t])
+            .fold([0.; 2], |p, (l, v)| {
+                [p[0] + l * points[v][0], p[1] + l * points[v][1]]
+            });
+        assert_abs_diff_eq!(p[0], 0.3, epsilon = 1e-12);
+        assert_abs_diff_eq!(p[1], 0.4, epsilon = 1e-12);
+        assert!(tri.find_simplex([2.1, 0.5]).is_none());
+    }
+
+    #[test]
+    fn hull_slivers() {
+        // Points on a flat arc, whose thin triangles have huge circumcircles.
+        let points: Vec<[f64; 2]> = (0..9)
+            .map(|i| {
+                let x = i as f64 / 8.;
+                [x, -1e-3 * x * (1. - x)]
+            })
+            .chain([[0.5, 1.]])
+            .collect();
+        let tri = Triangulation::new(&points).unwrap();
+        assert_eq!(tri.triangles().len(), 8);
+        assert!(tri.find_simplex([0.49, 1e-4]).is_some());
+
+        assert!(Triangulation::new(&[[0., 0.], [1., 1.], [2., 2.]]).is_err());
+        assert!(Triangulation::new(&[[0., 0.], [1., 1.]]).is_err());
+    }
+}
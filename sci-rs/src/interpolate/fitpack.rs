@@ -0,0 +1,369 @@
+use super::bsplines::{eval_basis, find_interval, BSpline};
+use super::ppoly::Extrapolate;
+use alloc::{vec, vec::Vec};
+use ndarray::{Array, Array1, ArrayBase, Axis, Data, Dimension, Ix1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Find the B-spline representation of a 1-D curve.
+///
+/// Given the set of data points `(x[i], y[i])` determine a smooth spline approximation of degree
+/// `k` on the interval `xb <= x <= xe`.
+///
+/// ## Parameters
+/// * `x`, `y`: The data points defining a curve `y = f(x)`. `x` must be non-decreasing.
+/// * `w`: Strictly positive weights of the points, applied to the residuals. Default is ones.
+/// * `xb`, `xe`: The interval to fit. Defaults to `x[0]` and `x[x.len() - 1]`.
+/// * `k`: The degree of the spline fit. It is recommended to use cubic splines. Even values of
+///   `k` should be avoided especially with small `s` values. `1 <= k <= 5`, default is 3.
+/// * `t`: The interior knots of the spline, for a weighted least-squares fit. By default, the
+///   spline interpolates the points, with the knots at the points for odd `k` and between them
+///   for even `k`.
+///
+/// ## Returns
+/// The spline, with `k + 1` knots at `xb` and `xe` around the interior ones.
+///
+/// ## Errors
+/// If there are not more than `k` points, or the knots do not satisfy the Schoenberg-Whitney
+/// conditions, i.e. there is no subset of the points that can be interpolated by the spline.
+///
+/// ## Notes
+/// This covers the interpolation (`s = 0`) and the least-squares (`task = -1`) modes of FITPACK's
+/// `curfit`, whose weighted least-squares problem is solved with Givens rotations.
+///
+/// ## References
+/// [1] P. Dierckx, "An algorithm for smoothing, differentiation and integration of experimental
+///     data using spline functions", J.Comp.Appl.Maths 1 (1975) 165-184.
+///
+/// [2] P. Dierckx, "Curve and surface fitting with splines", Monographs on Numerical Analysis,
+///     Oxford University Press, 1993.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.interpolate import splev, splrep
+/// x = np.linspace(0, 10, 10)
+/// y = np.sin(x)
+/// spl = splrep(x, y)
+/// x2 = np.linspace(0, 10, 200)
+/// y2 = splev(x2, spl)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::Array1;
+/// use sci_rs::interpolate::{splev, splrep};
+///
+/// let x = Array1::linspace(0., 10., 10);
+/// let y = x.mapv(f64::sin);
+/// let spl = splrep(x.as_slice().unwrap(), &y, None, None, None, None, None).unwrap();
+/// let x2 = Array1::linspace(0., 10., 200);
+/// let y2 = splev(x2.as_slice().unwrap(), &spl, None, None).unwrap();
+/// for (x, y) in x2.iter().zip(&y2) {
+///     assert_abs_diff_eq!(*y, x.sin(), epsilon = 0.1);
+/// }
+/// ```
+pub fn splrep<F, S>(
+    x: &[F],
+    y: &ArrayBase<S, Ix1>,
+    w: Option<&[F]>,
+    xb: Option<F>,
+    xe: Option<F>,
+    k: Option<usize>,
+    t: Option<&[F]>,
+) -> Result<BSpline<F, Ix1>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let k = k.unwrap_or(3);
+    let m = x.len();
+    if !(1..=5).contains(&k) {
+        return Err(Error::InvalidArg {
+            arg: "k".into(),
+            reason: "1 <= k <= 5 must hold.".into(),
+        });
+    }
+    if m <= k {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "m > k must hold.".into(),
+        });
+    }
+    if y.len() != m || w.is_some_and(|w| w.len() != m) {
+        return Err(Error::InvalidArg {
+            arg: "y".into(),
+            reason: "Lengths of the first three arguments (x, y, w) must be equal.".into(),
+        });
+    }
+    if w.is_some_and(|w| w.iter().any(|&w| w.is_nan() || w <= F::zero())) {
+        return Err(Error::InvalidArg {
+            arg: "w".into(),
+            reason: "The weights must be strictly positive.".into(),
+        });
+    }
+    let (xb, xe) = (xb.unwrap_or(x[0]), xe.unwrap_or(x[m - 1]));
+    if x.windows(2).any(|w| w[0] > w[1]) || !(xb <= x[0] && x[m - 1] <= xe) {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "x must be non-decreasing within [xb, xe].".into(),
+        });
+    }
+
+    let interior: Vec<F> = match t {
+        Some(t) => t.to_vec(),
+        None if k % 2 == 1 => x[k / 2 + 1..m - k / 2 - 1].to_vec(),
+        None => {
+            let half = F::from(0.5).unwrap();
+            x[k / 2..m - k / 2]
+                .windows(2)
+                .map(|w| (w[0] + w[1]) * half)
+                .collect()
+        }
+    };
+    if interior.iter().any(|&t| !(xb < t && t < xe)) {
+        return Err(Error::InvalidArg {
+            arg: "t".into(),
+            reason: "The interior knots must lie strictly within [xb, xe].".into(),
+        });
+    }
+    let knots: Vec<F> = (0..=k)
+        .map(|_| xb)
+        .chain(interior)
+        .chain((0..=k).map(|_| xe))
+        .collect();
+    let c = lsq_coefficients(x, y, w, &knots, k)?;
+    BSpline::new(knots, c, k, None, None)
+}
+
+/// Coefficients of the spline of degree `k` on the knots `t` that fits the points `(x, y)` with
+/// the weights `w` in the least-squares sense.
+///
+/// The observation matrix is reduced to an upper triangular band matrix by Givens rotations, one
+/// point at a time, like FITPACK's `fpcurf`.
+fn lsq_coefficients<F, S>(
+    x: &[F],
+    y: &ArrayBase<S, Ix1>,
+    w: Option<&[F]>,
+    t: &[F],
+    k: usize,
+) -> Result<Array1<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let n = t.len() - k - 1;
+    // Row i of the triangular matrix holds the entries (i, i), ..., (i, i + k).
+    let mut r = vec![F::zero(); n * (k + 1)];
+    let mut z = vec![F::zero(); n];
+    let mut h = vec![F::zero(); k + 1];
+    for (i, &xi) in x.iter().enumerate() {
+        let wi = w.map_or(F::one(), |w| w[i]);
+        let Some(l) = find_interval(t, k, xi, false) else {
+            continue;
+        };
+        eval_basis(t, k, xi, l, 0, &mut h);
+        h.iter_mut().for_each(|h| *h = *h * wi);
+        let mut yi = y[i] * wi;
+        for j in 0..=k {
+            let piv = h[j];
+            if piv.is_zero() {
+                continue;
+            }
+            let row = &mut r[(l - k + j) * (k + 1)..(l - k + j + 1) * (k + 1)];
+            let dd = piv.hypot(row[0]);
+            let (cos, sin) = (row[0] / dd, piv / dd);
+            row[0] = dd;
+            let rotate = |a: &mut F, b: &mut F| {
+                (*a, *b) = (cos * *a - sin * *b, cos * *b + sin * *a);
+            };
+            rotate(&mut yi, &mut z[l - k + j]);
+            for i2 in j + 1..=k {
+                rotate(&mut h[i2], &mut row[i2 - j]);
+            }
+        }
+    }
+
+    let mut c = Array1::zeros(n);
+    for i in (0..n).rev() {
+        let row = &r[i * (k + 1)..(i + 1) * (k + 1)];
+        if row[0].is_zero() {
+            return Err(Error::InvalidArg {
+                arg: "t".into(),
+                reason: "The knots do not satisfy the Schoenberg-Whitney conditions.".into(),
+            });
+        }
+        let sum = (1..=k.min(n - 1 - i)).fold(z[i], |acc, j| acc - row[j] * c[i + j]);
+        c[i] = sum / row[0];
+    }
+    Ok(c)
+}
+
+/// Behaviour of [splev] outside of the base interval of the spline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplevExt {
+    /// Return the extrapolated value.
+    #[default]
+    Extrapolate,
+    /// Return 0.
+    Zeros,
+    /// Return an error.
+    Raise,
+    /// Return the boundary value.
+    Const,
+}
+
+/// Evaluate a B-spline or its derivatives.
+///
+/// ## Parameters
+/// * `x`: Points at which to return the value of the smoothed spline or its derivatives.
+/// * `tck`: The spline, e.g. from [splrep].
+/// * `der`: The order of derivative of the spline to compute, which must be less than or equal to
+///   the degree of the spline. Default is zero.
+/// * `ext`: Controls the value returned for elements of `x` not in the base interval of the
+///   spline. Default is [SplevExt::Extrapolate].
+///
+/// ## Returns
+/// The values, in an array of the shape of the coefficients with the interpolation axis replaced
+/// by the points.
+///
+/// ## Errors
+/// If `der` is larger than the degree, or a point is out of the base interval with
+/// [SplevExt::Raise].
+pub fn splev<F: Float, D: Dimension>(
+    x: &[F],
+    tck: &BSpline<F, D>,
+    der: Option<usize>,
+    ext: Option<SplevExt>,
+) -> Result<Array<F, D>> {
+    let der = der.unwrap_or(0);
+    let k = tck.k();
+    if der > k {
+        return Err(Error::InvalidArg {
+            arg: "der".into(),
+            reason: "0 <= der <= k must hold.".into(),
+        });
+    }
+    let t = tck.t();
+    let (start, end) = (t[k], t[t.len() - k - 1]);
+    let outside = |x: F| x < start || x > end;
+    match ext.unwrap_or_default() {
+        SplevExt::Extrapolate => Ok(tck.eval(x, der, Extrapolate::Enabled)),
+        SplevExt::Raise if x.iter().any(|&x| outside(x)) => Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "Error code returned by splev: x out of the base interval.".into(),
+        }),
+        SplevExt::Raise => Ok(tck.eval(x, der, Extrapolate::Enabled)),
+        SplevExt::Zeros => {
+            let mut out = tck.eval(x, der, Extrapolate::Enabled);
+            for mut lane in out.lanes_mut(Axis(tck.axis())) {
+                for (out, &x) in lane.iter_mut().zip(x) {
+                    if outside(x) {
+                        *out = F::zero();
+                    }
+                }
+            }
+            Ok(out)
+        }
+        SplevExt::Const => {
+            let clipped: Vec<F> = x.iter().map(|&x| x.max(start).min(end)).collect();
+            Ok(tck.eval(&clipped, der, Extrapolate::Enabled))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::arr1;
+
+    #[track_caller]
+    fn assert_close(a: impl IntoIterator<Item = f64>, b: &[f64], epsilon: f64) {
+        let a: Vec<f64> = a.into_iter().collect();
+        assert_eq!(a.len(), b.len());
+        for (a, &b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = epsilon);
+        }
+    }
+
+    #[test]
+    fn interpolation() {
+        let x = [0., 0.4, 1., 1.5, 2.2, 3., 3.1, 4.];
+        let y = arr1(&[1., -1., 0.5, 2., 0., 1., 1.2, -0.5]);
+        for k in 1..=5 {
+            let spl = splrep(&x, &y, None, None, None, Some(k), None).unwrap();
+            assert_eq!(spl.t().len(), x.len() + k + 1);
+            let values = splev(&x, &spl, None, None).unwrap();
+            assert_close(values, y.as_slice().unwrap(), 1e-10);
+        }
+        // The cubic interpolating spline is the not-a-knot one.
+        let cubic = splrep(&x, &y, None, None, None, None, None).unwrap();
+        let interp = super::super::bsplines::make_interp_spline(&x, &y, 3, 0).unwrap();
+        let xs = [0.2, 1.7, 3.05, 3.9];
+        let expected = interp.call(&xs, None, None);
+        assert_close(
+            splev(&xs, &cubic, None, None).unwrap(),
+            expected.as_slice().unwrap(),
+            1e-10,
+        );
+
+        assert!(splrep(
+            &x[..3],
+            &y.slice(ndarray::s![..3]),
+            None,
+            None,
+            None,
+            None,
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn least_squares() {
+        let x = [0., 0.5, 1.5, 1.8, 2.];
+        let w = [1., 1., 1., 2., 1.];
+        // A broken line with a knot at the break.
+        let kink = arr1(&x.map(|x: f64| (x - 1.).abs()));
+        let spl = splrep(&x, &kink, Some(&w), None, None, Some(1), Some(&[1.])).unwrap();
+        assert_close(spl.c().iter().copied(), &[1., 0., 1.], 1e-12);
+        // A line through a line.
+        let line = arr1(&x.map(|x| 2. * x - 1.));
+        let spl = splrep(&x, &line, Some(&w), None, None, Some(2), Some(&[1.])).unwrap();
+        assert_close(spl.c().iter().copied(), &[-1., 0., 2., 3.], 1e-12);
+        assert_close(splev(&[1.2], &spl, Some(1), None).unwrap(), &[2.], 1e-12);
+
+        // No point in the support of the hat function at 1.6.
+        let knots = [1.55, 1.6, 1.65];
+        assert!(splrep(&x, &kink, None, None, None, Some(1), Some(&knots)).is_err());
+    }
+
+    #[test]
+    fn extension() {
+        let x = [0., 1., 2., 3.];
+        let spl = splrep(
+            &x,
+            &arr1(&[0., 1., 4., 9.]),
+            None,
+            None,
+            None,
+            Some(2),
+            None,
+        )
+        .unwrap();
+        let xs = [-1., 1.5, 4.];
+        assert_close(
+            splev(&xs, &spl, None, None).unwrap(),
+            &[1., 2.25, 16.],
+            1e-12,
+        );
+        let zeros = splev(&xs, &spl, None, Some(SplevExt::Zeros)).unwrap();
+        assert_close(zeros, &[0., 2.25, 0.], 1e-12);
+        let bounds = splev(&xs, &spl, Some(1), Some(SplevExt::Const)).unwrap();
+        assert_close(bounds, &[0., 3., 6.], 1e-12);
+        assert!(splev(&xs, &spl, None, Some(SplevExt::Raise)).is_err());
+        assert!(splev(&xs, &spl, Some(3), None).is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use super::delaunay::Triangulation;
+use super::interp1d::{FillValue, Interp1d, Interp1dKind};
+use alloc::{vec, vec::Vec};
+use ndarray::{Array1, ArrayBase, Data, Ix1, Ix2};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Method of interpolation of [griddata].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GriddataMethod {
+    /// Return the value at the data point closest to the point of interpolation.
+    Nearest,
+    /// Tessellate the input point set to N-D simplices, and interpolate linearly on each simplex.
+    #[default]
+    Linear,
+}
+
+/// Interpolate unstructured D-D data.
+///
+/// ## Parameters
+/// * `points`: Data point coordinates, of shape `(n, D)`.
+/// * `values`: Data values, of shape `(n,)`.
+/// * `xi`: Points at which to interpolate data, of shape `(m, D)`.
+/// * `method`: Method of interpolation, [GriddataMethod::Linear] by default.
+///   * [Nearest](GriddataMethod::Nearest): return the value at the data point closest to the
+///     point of interpolation.
+///   * [Linear](GriddataMethod::Linear): tessellate the input point set to triangles, and
+///     interpolate linearly on each triangle. Only 1-D and 2-D data are supported.
+/// * `fill_value`: Value used to fill in for requested points outside of the convex hull of the
+///   input points. If not provided, then the default is NaN. This option has no effect for the
+///   nearest method, except in 1-D.
+///
+/// ## Returns
+/// Array of interpolated values, of shape `(m,)`.
+///
+/// ## Errors
+/// If `values` or `xi` do not match `points`, or linear interpolation is requested in more than 2
+/// dimensions or for points that cannot be triangulated.
+///
+/// ## Notes
+/// In 1-D, this is [Interp1d] with `fill_value` out of bounds. In 2-D, the points are
+/// triangulated by the Bowyer-Watson algorithm, and located by a search over the triangles. The
+/// nearest neighbours are found by brute force.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.interpolate import griddata
+/// points = np.array([[0, 0], [1, 0], [0, 1], [1, 1]])
+/// values = np.array([0., 1., 2., 3.])
+/// griddata(points, values, [[0.25, 0.25], [0.9, 0.1], [2., 2.]])
+/// # array([0.75, 1.1 ,  nan])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::interpolate::griddata;
+///
+/// let points = array![[0., 0.], [1., 0.], [0., 1.], [1., 1.]];
+/// let values = array![0f64, 1., 2., 3.];
+/// let xi = array![[0.25, 0.25], [0.9, 0.1], [2., 2.]];
+/// let interpolated = griddata(&points, &values, &xi, None, None).unwrap();
+/// assert_abs_diff_eq!(interpolated[0], 0.75, epsilon = 1e-12);
+/// assert_abs_diff_eq!(interpolated[1], 1.1, epsilon = 1e-12);
+/// assert!(interpolated[2].is_nan());
+/// ```
+pub fn griddata<F, S1, S2, S3>(
+    points: &ArrayBase<S1, Ix2>,
+    values: &ArrayBase<S2, Ix1>,
+    xi: &ArrayBase<S3, Ix2>,
+    method: Option<GriddataMethod>,
+    fill_value: Option<F>,
+) -> Result<Array1<F>>
+where
+    F: Float,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = F>,
+    S3: Data<Elem = F>,
+{
+    let method = method.unwrap_or_default();
+    let fill_value = fill_value.unwrap_or_else(F::nan);
+    let (n, ndim) = points.dim();
+    if values.len() != n {
+        return Err(Error::InvalidArg {
+            arg: "values".into(),
+            reason: "Different number of values and points.".into(),
+        });
+    }
+    if n == 0 {
+        return Err(Error::InvalidArg {
+            arg: "points".into(),
+            reason: "At least one point is required.".into(),
+        });
+    }
+    if xi.ncols() != ndim {
+        return Err(Error::InvalidArg {
+            arg: "xi".into(),
+            reason: "Number of dimensions in xi does not match points.".into(),
+        });
+    }
+
+    match (method, ndim) {
+        (_, 1) => {
+            let kind = match method {
+                GriddataMethod::Nearest => Interp1dKind::Nearest,
+                GriddataMethod::Linear => Interp1dKind::Linear,
+            };
+            let x = points.column(0).to_vec();
+            let interp = Interp1d::new(
+                &x,
+                values,
+                Some(kind),
+                None,
+                Some(false),
+                Some(FillValue::Value(fill_value)),
+                None,
+            )?;
+            interp.call(&xi.column(0).to_vec())
+        }
+        (GriddataMethod::Nearest, _) => Ok(xi
+            .rows()
+            .into_iter()
+            .map(|p| {
+                let distance = |q: usize| {
+                    p.iter()
+                        .zip(points.row(q))
+                        .fold(F::zero(), |acc, (&a, &b)| acc + (a - b) * (a - b))
+                };
+                let nearest = (1..n).fold(0, |best, q| {
+                    if distance(q) < distance(best) {
+                        q
+                    } else {
+                        best
+                    }
+                });
+                values[nearest]
+            })
+            .collect()),
+        (GriddataMethod::Linear, 2) => {
+            let vertices: Vec<[F; 2]> = points.rows().into_iter().map(|p| [p[0], p[1]]).collect();
+            let triangulation = Triangulation::new(&vertices)?;
+            Ok(xi
+                .rows()
+                .into_iter()
+                .map(|p| match triangulation.find_simplex([p[0], p[1]]) {
+                    Some((t, weights)) => triangulation.triangles()[t]
+                        .iter()
+                        .zip(weights)
+                        .fold(F::zero(), |acc, (&v, w)| acc + w * values[v]),
+                    None => fill_value,
+                })
+                .collect())
+        }
+        (GriddataMethod::Linear, _) => Err(Error::InvalidArg {
+            arg: "method".into(),
+            reason: "Linear interpolation is only implemented for 1-D and 2-D points.".into(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array2};
+
+    #[test]
+    fn linear() {
+        let points = Array2::from_shape_fn((40, 2), |(i, d)| {
+            let i = i as f64;
+            if d == 0 {
+                3. * (i * 0.618_033_988_7).fract() - 1.
+            } else {
+                (i * 0.754_877_666_2).fract()
+            }
+        });
+        let plane = |p: [f64; 2]| 2. * p[0] - 0.5 * p[1] + 1.;
+        let values: Array1<f64> = points
+            .rows()
+            .into_iter()
+            .map(|p| plane([p[0], p[1]]))
+            .collect();
+        let xi = array![[0., 0.5], [1.2, 0.3], [-0.5, 0.7], [5., 5.]];
+        let interpolated = griddata(&points, &values, &xi, None, Some(-1.)).unwrap();
+        for (p, v) in xi.rows().into_iter().zip(&interpolated).take(3) {
+            assert_abs_diff_eq!(*v, plane([p[0], p[1]]), epsilon = 1e-12);
+        }
+        assert_eq!(interpolated[3], -1.);
+
+        // Data points are reproduced.
+        let interpolated = griddata(&points, &values, &points, None, None).unwrap();
+        for (a, b) in interpolated.iter().zip(&values) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn nearest_and_1d() {
+        let points = array![[0., 0., 0.], [1., 0., 0.], [0., 2., 0.], [0., 0., 3.]];
+        let values = array![1., 2., 3., 4.];
+        let xi = array![
+            [0.1, 0.1, 0.1],
+            [0.9, 0.2, 0.],
+            [0., 1.2, 1.],
+            [10., 0., 0.]
+        ];
+        let nearest = griddata(&points, &values, &xi, Some(GriddataMethod::Nearest), None).unwrap();
+        assert_eq!(nearest.to_vec(), vec![1., 2., 3., 2.]);
+        assert!(griddata(&points, &values, &xi, None, None).is_err());
+
+        let points = array![[2.], [0.], [1.]];
+        let values = array![4., 0., 1.];
+        let xi = array![[0.5], [1.75], [3.]];
+        let linear = griddata(&points, &values, &xi, None, Some(0.)).unwrap();
+        assert_eq!(linear.to_vec(), vec![0.5, 3.25, 0.]);
+        let nearest = griddata(&points, &values, &xi, Some(GriddataMethod::Nearest), None).unwrap();
+        assert_eq!(nearest[1], 4.);
+        assert!(nearest[2].is_nan());
+
+        assert!(griddata(&points, &array![1., 2.], &xi, None, None).is_err());
+        assert!(griddata(&points, &values, &array![[1., 2.]], None, None).is_err());
+    }
+}
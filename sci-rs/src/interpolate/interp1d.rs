@@ -0,0 +1,447 @@
+use super::bsplines::{make_interp_spline, BSpline};
+use super::ppoly::Extrapolate;
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use alloc::{format, vec, vec::Vec};
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension, Zip};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Kind of interpolation of [Interp1d].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interp1dKind {
+    /// Straight lines between the points.
+    #[default]
+    Linear,
+    /// Value of the nearest point, rounding down at the midpoints.
+    Nearest,
+    /// Value of the nearest point, rounding up at the midpoints.
+    NearestUp,
+    /// Spline of degree 0.
+    Zero,
+    /// Spline of degree 1.
+    SLinear,
+    /// Spline of degree 2.
+    Quadratic,
+    /// Spline of degree 3.
+    Cubic,
+    /// Value of the previous point.
+    Previous,
+    /// Value of the next point.
+    Next,
+    /// Spline of the given degree, which must be 2 or odd above it.
+    Spline(usize),
+}
+
+impl Interp1dKind {
+    /// Degree of the spline kinds.
+    fn order(self) -> Option<usize> {
+        match self {
+            Interp1dKind::Zero => Some(0),
+            Interp1dKind::SLinear => Some(1),
+            Interp1dKind::Quadratic => Some(2),
+            Interp1dKind::Cubic => Some(3),
+            Interp1dKind::Spline(k) => Some(k),
+            _ => None,
+        }
+    }
+}
+
+/// Values of [Interp1d] outside of the range of its points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FillValue<F> {
+    /// The same value below and above the range.
+    Value(F),
+    /// The values `(below, above)` the range.
+    Bounds(F, F),
+    /// Extrapolate from the interpolant.
+    Extrapolate,
+}
+
+impl<F: Float> Default for FillValue<F> {
+    fn default() -> Self {
+        FillValue::Value(F::nan())
+    }
+}
+
+/// Interpolate a 1-D function.
+///
+/// `x` and `y` are arrays of values used to approximate some function f: `y = f(x)`. This type
+/// returns an interpolant whose [call](Interp1d::call) method uses interpolation to find the value
+/// of new points.
+///
+/// ## Notes
+/// Calling `Interp1d` with NaNs present in the input values results in unspecified values.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import interpolate
+/// x = np.arange(0, 10)
+/// y = np.exp(-x/3.0)
+/// f = interpolate.interp1d(x, y)
+/// xnew = np.arange(0, 9, 0.1)
+/// ynew = f(xnew)   # use interpolation function returned by `interp1d`
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::Array1;
+/// use sci_rs::interpolate::Interp1d;
+///
+/// let x: Vec<f64> = (0..10).map(|x| x as f64).collect();
+/// let y = Array1::from_iter(x.iter().map(|x| (-x / 3.).exp()));
+/// let f = Interp1d::new(&x, &y, None, None, None, None, None).unwrap();
+/// let xnew: Vec<f64> = (0..90).map(|i| i as f64 * 0.1).collect();
+/// let ynew = f.call(&xnew).unwrap();
+/// assert_abs_diff_eq!(ynew[15], (y[1] + y[2]) / 2., epsilon = 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Interp1d<F, D: Dimension> {
+    x: Vec<F>,
+    y: Array<F, D>,
+    axis: usize,
+    kind: Interp1dKind,
+    bounds_error: bool,
+    fill_value: FillValue<F>,
+    spline: Option<BSpline<F, D>>,
+}
+
+impl<F: Float, D: Dimension> Interp1d<F, D> {
+    /// Build the interpolant of the points `(x, y)`.
+    ///
+    /// ## Parameters
+    /// * `x`: A 1-D array of real values.
+    /// * `y`: A N-D array of real values. The length of `y` along the interpolation axis must be
+    ///   equal to the length of `x`.
+    /// * `kind`: Specifies the kind of interpolation. Default is [Interp1dKind::Linear].
+    /// * `axis`: Axis in the `y` array corresponding to the x-coordinate values. Defaults to -1.
+    /// * `bounds_error`: If true, an error is returned any time interpolation is attempted on a
+    ///   value outside of the range of x (where extrapolation is necessary). If false, out of
+    ///   bounds values are assigned `fill_value`. By default, an error is returned unless
+    ///   `fill_value` is [FillValue::Extrapolate].
+    /// * `fill_value`: Value of the points outside of the data range, NaN by default. With
+    ///   [FillValue::Extrapolate], the points outside of the data range are extrapolated, except
+    ///   for the [Previous](Interp1dKind::Previous) and [Next](Interp1dKind::Next) kinds which
+    ///   then take the last or first value of `y` on the side they extend, and NaN on the other.
+    /// * `assume_sorted`: If false (default), values of `x` can be in any order and they are sorted
+    ///   first. If true, `x` has to be an array of monotonically increasing values.
+    ///
+    /// ## Errors
+    /// If `x` and `y` have different lengths along the axis, if there are too few points for the
+    /// kind of interpolation, or if `bounds_error` is requested along with extrapolation.
+    pub fn new<S>(
+        x: &[F],
+        y: &ArrayBase<S, D>,
+        kind: Option<Interp1dKind>,
+        axis: Option<isize>,
+        bounds_error: Option<bool>,
+        fill_value: Option<FillValue<F>>,
+        assume_sorted: Option<bool>,
+    ) -> Result<Self>
+    where
+        S: Data<Elem = F>,
+    {
+        let kind = kind.unwrap_or_default();
+        let fill_value = fill_value.unwrap_or_default();
+        let extrapolate = fill_value == FillValue::Extrapolate;
+        let bounds_error = bounds_error.unwrap_or(!extrapolate);
+        if bounds_error && extrapolate {
+            return Err(Error::ConflictArg {
+                reason: "Cannot extrapolate and raise at the same time.".into(),
+            });
+        }
+        if y.ndim() == 0 {
+            return Err(Error::InvalidArg {
+                arg: "y".into(),
+                reason: "the y array must have at least one dimension.".into(),
+            });
+        }
+        let axis = check_and_get_axis_dyn(axis, y)?;
+        if x.len() != y.len_of(Axis(axis)) {
+            return Err(Error::InvalidArg {
+                arg: "x".into(),
+                reason: "x and y arrays must be equal in length along interpolation axis.".into(),
+            });
+        }
+        let minval = match kind {
+            Interp1dKind::Linear => 2,
+            Interp1dKind::Nearest
+            | Interp1dKind::NearestUp
+            | Interp1dKind::Previous
+            | Interp1dKind::Next => 1,
+            _ => kind.order().unwrap_or_default() + 1,
+        };
+        if x.len() < minval {
+            return Err(Error::InvalidArg {
+                arg: "x".into(),
+                reason: format!("x and y arrays must have at least {} entries", minval),
+            });
+        }
+
+        let (x, y) = if assume_sorted.unwrap_or(false) {
+            (x.to_vec(), y.to_owned())
+        } else {
+            let mut ind: Vec<usize> = (0..x.len()).collect();
+            ind.sort_by(|&i, &j| {
+                x[i].partial_cmp(&x[j])
+                    .unwrap_or(core::cmp::Ordering::Equal)
+            });
+            let mut sorted = y.to_owned();
+            Zip::from(sorted.lanes_mut(Axis(axis)))
+                .and(y.lanes(Axis(axis)))
+                .for_each(|mut sorted, y| {
+                    for (sorted, &i) in sorted.iter_mut().zip(&ind) {
+                        *sorted = y[i];
+                    }
+                });
+            (ind.iter().map(|&i| x[i]).collect(), sorted)
+        };
+        let spline = match kind.order() {
+            Some(k) => Some(make_interp_spline(&x, &y, k, axis)?),
+            None => None,
+        };
+
+        Ok(Interp1d {
+            x,
+            y,
+            axis,
+            kind,
+            bounds_error,
+            fill_value,
+            spline,
+        })
+    }
+
+    /// The sorted points `x`.
+    pub fn x(&self) -> &[F] {
+        &self.x
+    }
+
+    /// The values `y` in the order of the sorted `x`.
+    pub fn y(&self) -> &Array<F, D> {
+        &self.y
+    }
+
+    /// Evaluate the interpolant at the points `x_new`.
+    ///
+    /// The result has the shape of `y`, with the interpolation axis replaced by the points.
+    ///
+    /// ## Errors
+    /// If a point is outside of the range of `x` when the interpolant was built with
+    /// `bounds_error`.
+    pub fn call(&self, x_new: &[F]) -> Result<Array<F, D>> {
+        let n = self.x.len();
+        let (first, last) = (self.x[0], self.x[n - 1]);
+        if self.bounds_error {
+            if x_new.iter().any(|&x| x < first) {
+                return Err(Error::InvalidArg {
+                    arg: "x_new".into(),
+                    reason: "A value in x_new is below the interpolation range.".into(),
+                });
+            }
+            if x_new.iter().any(|&x| x > last) {
+                return Err(Error::InvalidArg {
+                    arg: "x_new".into(),
+                    reason: "A value in x_new is above the interpolation range.".into(),
+                });
+            }
+        }
+
+        let extrapolate = self.fill_value == FillValue::Extrapolate;
+        let mut out = match &self.spline {
+            Some(spline) => {
+                let extrapolate = if extrapolate {
+                    Extrapolate::Enabled
+                } else {
+                    Extrapolate::Disabled
+                };
+                spline.eval(x_new, 0, extrapolate)
+            }
+            None => self.eval_local(x_new),
+        };
+
+        let axis = Axis(self.axis);
+        Zip::from(out.lanes_mut(axis))
+            .and(self.y.lanes(axis))
+            .for_each(|mut out, y| {
+                let (below, above) = match (self.fill_value, self.kind) {
+                    (FillValue::Value(v), _) => (v, v),
+                    (FillValue::Bounds(below, above), _) => (below, above),
+                    (FillValue::Extrapolate, Interp1dKind::Previous) => (F::nan(), y[n - 1]),
+                    (FillValue::Extrapolate, Interp1dKind::Next) => (y[0], F::nan()),
+                    (FillValue::Extrapolate, _) => return,
+                };
+                for (out, &x) in out.iter_mut().zip(x_new) {
+                    if x < first {
+                        *out = below;
+                    } else if x > last {
+                        *out = above;
+                    }
+                }
+            });
+        Ok(out)
+    }
+
+    /// Evaluate the kinds that only use one or two neighbouring points.
+    fn eval_local(&self, x_new: &[F]) -> Array<F, D> {
+        let x = &self.x;
+        let n = x.len();
+        let two = F::one() + F::one();
+        // Every point is `y[lo] + t * (y[hi] - y[lo])`, with `lo == hi` for the single point kinds.
+        let stencil: Vec<(usize, usize, F)> = x_new
+            .iter()
+            .map(|&xn| match self.kind {
+                Interp1dKind::Nearest | Interp1dKind::NearestUp => {
+                    let up = self.kind == Interp1dKind::NearestUp;
+                    let i = x
+                        .windows(2)
+                        .map(|w| (w[0] + w[1]) / two)
+                        .take_while(|&m| if up { m <= xn } else { m < xn })
+                        .count();
+                    (i, i, F::zero())
+                }
+                Interp1dKind::Previous => {
+                    let i = x.partition_point(|&x| x <= xn).max(1) - 1;
+                    (i, i, F::zero())
+                }
+                Interp1dKind::Next => {
+                    let i = x.partition_point(|&x| x < xn).min(n - 1);
+                    (i, i, F::zero())
+                }
+                _ => {
+                    let hi = x.partition_point(|&x| x < xn).clamp(1, n - 1);
+                    let lo = hi - 1;
+                    (lo, hi, (xn - x[lo]) / (x[hi] - x[lo]))
+                }
+            })
+            .collect();
+
+        let mut shape = self.y.raw_dim();
+        shape[self.axis] = x_new.len();
+        let mut out = Array::zeros(shape);
+        Zip::from(out.lanes_mut(Axis(self.axis)))
+            .and(self.y.lanes(Axis(self.axis)))
+            .for_each(|mut out, y| {
+                for (out, &(lo, hi, t)) in out.iter_mut().zip(&stencil) {
+                    *out = if lo == hi {
+                        y[lo]
+                    } else {
+                        y[lo] + t * (y[hi] - y[lo])
+                    };
+                }
+            });
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array2};
+
+    #[track_caller]
+    fn assert_vec_eq(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (&a, &b) in a.iter().zip(b) {
+            if b.is_nan() {
+                assert!(a.is_nan(), "{a} is not NaN");
+            } else {
+                assert_abs_diff_eq!(a, b, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn neighbours() {
+        let x = [0., 1., 2., 4.];
+        let y = array![10., 20., 15., 5.];
+        let x_new = [-1., 0., 0.5, 0.7, 3., 3.5, 4., 5.];
+        let eval = |kind, fill_value| {
+            let f = Interp1d::new(&x, &y, Some(kind), None, Some(false), fill_value, None).unwrap();
+            f.call(&x_new).unwrap().to_vec()
+        };
+        let nan = f64::NAN;
+        assert_vec_eq(
+            &eval(Interp1dKind::Nearest, None),
+            &[nan, 10., 10., 20., 15., 5., 5., nan],
+        );
+        assert_vec_eq(
+            &eval(Interp1dKind::NearestUp, Some(FillValue::Value(0.))),
+            &[0., 10., 20., 20., 5., 5., 5., 0.],
+        );
+        assert_vec_eq(
+            &eval(Interp1dKind::Previous, Some(FillValue::Bounds(-1., 1.))),
+            &[-1., 10., 10., 10., 15., 15., 5., 1.],
+        );
+        assert_vec_eq(
+            &eval(Interp1dKind::Next, None),
+            &[nan, 10., 20., 20., 5., 5., 5., nan],
+        );
+        assert_vec_eq(
+            &eval(Interp1dKind::Linear, None),
+            &[nan, 10., 15., 17., 10., 7.5, 5., nan],
+        );
+        assert_vec_eq(
+            &eval(Interp1dKind::Zero, None),
+            &[nan, 10., 10., 10., 15., 15., 5., nan],
+        );
+        assert_vec_eq(
+            &eval(Interp1dKind::SLinear, None),
+            &[nan, 10., 15., 17., 10., 7.5, 5., nan],
+        );
+    }
+
+    #[test]
+    fn extrapolation_and_bounds() {
+        let x = [3., 1., 2.];
+        let y = array![9., 1., 4.];
+        let x_new = [0., 4.];
+        let extrapolate = Some(FillValue::Extrapolate);
+        let eval = |kind| {
+            let f = Interp1d::new(&x, &y, Some(kind), None, None, extrapolate, None).unwrap();
+            f.call(&x_new).unwrap().to_vec()
+        };
+        assert_vec_eq(&eval(Interp1dKind::Linear), &[-2., 14.]);
+        assert_vec_eq(&eval(Interp1dKind::Nearest), &[1., 9.]);
+        assert_vec_eq(&eval(Interp1dKind::Quadratic), &[0., 16.]);
+        assert_vec_eq(&eval(Interp1dKind::Previous), &[f64::NAN, 9.]);
+        assert_vec_eq(&eval(Interp1dKind::Next), &[1., f64::NAN]);
+
+        let f = Interp1d::new(&x, &y, None, None, None, None, None).unwrap();
+        assert_eq!(f.x(), [1., 2., 3.]);
+        assert!(f.call(&[1., 3.]).is_ok());
+        assert!(f.call(&[0.5]).is_err());
+        assert!(f.call(&[3.5]).is_err());
+        assert!(Interp1d::new(&x, &y, None, None, Some(true), extrapolate, None).is_err());
+        assert!(Interp1d::new(&x, &y, Some(Interp1dKind::Cubic), None, None, None, None).is_err());
+        assert!(Interp1d::new(&x[..2], &y, None, None, None, None, None).is_err());
+    }
+
+    #[test]
+    fn splines_along_axis() {
+        // A cubic through 5 points of a cubic polynomial, for every row and along axis 0.
+        let x = [0., 1., 2.5, 3., 4.];
+        let p = |x: f64| x * x * x - 2. * x + 1.;
+        let y = Array2::from_shape_fn((5, 2), |(i, j)| (j + 1) as f64 * p(x[i]));
+        let f =
+            Interp1d::new(&x, &y, Some(Interp1dKind::Cubic), Some(0), None, None, None).unwrap();
+        let x_new = [0.2, 1.7, 3.9];
+        let out = f.call(&x_new).unwrap();
+        assert_eq!(out.dim(), (3, 2));
+        for (i, &x) in x_new.iter().enumerate() {
+            assert_abs_diff_eq!(out[[i, 0]], p(x), epsilon = 1e-12);
+            assert_abs_diff_eq!(out[[i, 1]], 2. * p(x), epsilon = 1e-12);
+        }
+
+        // Not-a-knot cubic spline of data that is not a cubic.
+        let y = array![0., 1., 0., 1., 0.];
+        let x = [0., 1., 2., 3., 4.];
+        let f = Interp1d::new(&x, &y, Some(Interp1dKind::Cubic), None, None, None, None).unwrap();
+        assert_vec_eq(
+            &f.call(&[0.5, 1.5, 2.5, 3.5]).unwrap().to_vec(),
+            &[1.125, 0.375, 0.375, 1.125],
+        );
+    }
+}
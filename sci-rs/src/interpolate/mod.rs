@@ -0,0 +1,21 @@
+//! Functions from the
+//! [`scipy.interpolate`](https://docs.scipy.org/doc/scipy/reference/interpolate.html) module.
+
+mod bsplines;
+mod cubic;
+mod delaunay;
+mod fitpack;
+mod griddata;
+mod interp1d;
+mod ppoly;
+mod rbf;
+mod smoothing;
+
+pub use bsplines::*;
+pub use cubic::*;
+pub use fitpack::*;
+pub use griddata::*;
+pub use interp1d::*;
+pub use ppoly::*;
+pub use rbf::*;
+pub use smoothing::*;
@@ -0,0 +1,553 @@
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use crate::signal::lti::conversions::roots;
+use alloc::{vec, vec::Vec};
+use nalgebra::RealField;
+use ndarray::{Array, ArrayView1, Axis, Dimension, RemoveAxis, Slice, Zip};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// How a piecewise polynomial is evaluated outside of its breakpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Extrapolate {
+    /// Extrapolate from the first and last polynomial pieces.
+    #[default]
+    Enabled,
+    /// Return NaN.
+    Disabled,
+    /// Wrap the points into the range of the breakpoints.
+    Periodic,
+}
+
+/// Piecewise polynomial in terms of coefficients and breakpoints.
+///
+/// The polynomial between `x[i]` and `x[i + 1]` is written in the local power basis:
+///
+/// `S = sum(c[m][i] * (xp - x[i])**(k - m) for m in 0..=k)`
+///
+/// where `k` is the degree of the polynomial. Each `c[m]` holds the coefficients of one power for
+/// every interval along `axis`, the other axes of the arrays describing as many polynomials.
+///
+/// ## Notes
+/// High-order polynomials in the power basis can be numerically unstable. Precision problems can
+/// start to appear for orders larger than 20-30.
+///
+/// The breakpoints have to be strictly increasing, where scipy also accepts decreasing ones.
+#[derive(Debug, Clone)]
+pub struct PPoly<F, D: Dimension> {
+    c: Vec<Array<F, D>>,
+    x: Vec<F>,
+    axis: usize,
+    extrapolate: Extrapolate,
+}
+
+impl<F: Float, D: Dimension> PPoly<F, D> {
+    /// Build the piecewise polynomial of the coefficients `c` on the breakpoints `x`.
+    ///
+    /// ## Parameters
+    /// * `c`: Polynomial coefficients, from the highest power to the constant. All the arrays must
+    ///   have the same shape, with `x.len() - 1` intervals along `axis`.
+    /// * `x`: Strictly increasing breakpoints.
+    /// * `axis`: Interpolation axis of the coefficients. Default is zero.
+    /// * `extrapolate`: Default way to evaluate points outside of the breakpoints. Default is
+    ///   [Extrapolate::Enabled].
+    pub fn new(
+        c: Vec<Array<F, D>>,
+        x: Vec<F>,
+        axis: Option<isize>,
+        extrapolate: Option<Extrapolate>,
+    ) -> Result<Self> {
+        let Some(first) = c.first() else {
+            return Err(Error::InvalidArg {
+                arg: "c".into(),
+                reason: "Polynomial must be at least of order 0.".into(),
+            });
+        };
+        let axis = check_and_get_axis_dyn(Some(axis.unwrap_or(0)), first)?;
+        if c.iter().any(|c| c.shape() != first.shape()) {
+            return Err(Error::InvalidArg {
+                arg: "c".into(),
+                reason: "All the coefficient arrays must have the same shape.".into(),
+            });
+        }
+        if x.len() < 2 {
+            return Err(Error::InvalidArg {
+                arg: "x".into(),
+                reason: "At least 2 breakpoints are needed.".into(),
+            });
+        }
+        if first.len_of(Axis(axis)) != x.len() - 1 {
+            return Err(Error::InvalidArg {
+                arg: "c".into(),
+                reason: "Shape of c along axis must be x.len() - 1.".into(),
+            });
+        }
+        if x.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(Error::InvalidArg {
+                arg: "x".into(),
+                reason: "x must be strictly increasing.".into(),
+            });
+        }
+        Ok(Self {
+            c,
+            x,
+            axis,
+            extrapolate: extrapolate.unwrap_or_default(),
+        })
+    }
+
+    /// The coefficients, from the highest power to the constant.
+    pub fn c(&self) -> &[Array<F, D>] {
+        &self.c
+    }
+
+    /// The breakpoints.
+    pub fn x(&self) -> &[F] {
+        &self.x
+    }
+
+    /// Evaluate the piecewise polynomial or its derivative.
+    ///
+    /// ## Parameters
+    /// * `x`: Points to evaluate the interpolant at.
+    /// * `nu`: Order of derivative to evaluate. Default is zero.
+    /// * `extrapolate`: Whether to extrapolate to out-of-bounds points based on first and last
+    ///   intervals, or to return NaNs. Defaults to the one of the polynomial.
+    ///
+    /// ## Returns
+    /// The values, in an array of the shape of the coefficients with the interpolation axis
+    /// replaced by the points.
+    pub fn call(
+        &self,
+        x: &[F],
+        nu: Option<usize>,
+        extrapolate: Option<Extrapolate>,
+    ) -> Array<F, D> {
+        let extrapolate = extrapolate.unwrap_or(self.extrapolate);
+        match nu.unwrap_or(0) {
+            0 => self.eval(x, extrapolate),
+            nu => self.derivative(Some(nu)).eval(x, extrapolate),
+        }
+    }
+
+    fn eval(&self, x: &[F], extrapolate: Extrapolate) -> Array<F, D> {
+        let n = self.x.len();
+        let (first, last) = (self.x[0], self.x[n - 1]);
+        // Interval and local coordinate of every point.
+        let points: Vec<Option<(usize, F)>> = x
+            .iter()
+            .map(|&x| {
+                let x = match extrapolate {
+                    Extrapolate::Periodic => {
+                        let r = (x - first) % (last - first);
+                        first + if r < F::zero() { r + last - first } else { r }
+                    }
+                    _ => x,
+                };
+                if x.is_nan() || (extrapolate == Extrapolate::Disabled && (x < first || x > last)) {
+                    return None;
+                }
+                let i = self.x.partition_point(|&b| b <= x).clamp(1, n - 1) - 1;
+                Some((i, x - self.x[i]))
+            })
+            .collect();
+
+        let mut shape = self.c[0].raw_dim();
+        shape[self.axis] = x.len();
+        let mut out = Array::zeros(shape);
+        for c in &self.c {
+            Zip::from(out.lanes_mut(Axis(self.axis)))
+                .and(c.lanes(Axis(self.axis)))
+                .for_each(|mut out, c| {
+                    for (out, point) in out.iter_mut().zip(&points) {
+                        *out = match *point {
+                            Some((i, s)) => *out * s + c[i],
+                            None => F::nan(),
+                        };
+                    }
+                });
+        }
+        out
+    }
+
+    /// Construct a new piecewise polynomial representing the derivative.
+    ///
+    /// ## Parameters
+    /// * `nu`: Order of derivative to evaluate. Default is 1.
+    ///
+    /// ## Returns
+    /// Piecewise polynomial of order `k - nu` representing the derivative of this polynomial. If
+    /// `nu` is larger than the order, the derivative is a zero polynomial of order 0.
+    pub fn derivative(&self, nu: Option<usize>) -> Self {
+        let nu = nu.unwrap_or(1);
+        let k = self.c.len() - 1;
+        let c = if nu > k {
+            vec![Array::zeros(self.c[0].raw_dim())]
+        } else {
+            // The power m = k - p becomes m - nu, scaled by m! / (m - nu)!.
+            self.c[..=k - nu]
+                .iter()
+                .enumerate()
+                .map(|(p, c)| {
+                    let factor = rising_factorial::<F>(k - nu - p + 1, nu);
+                    c.mapv(|c| c * factor)
+                })
+                .collect()
+        };
+        Self {
+            c,
+            x: self.x.clone(),
+            axis: self.axis,
+            extrapolate: self.extrapolate,
+        }
+    }
+
+    /// Construct a new piecewise polynomial representing the antiderivative.
+    ///
+    /// The antiderivative is also the indefinite integral of the function, and derivative is its
+    /// inverse operation.
+    ///
+    /// ## Parameters
+    /// * `nu`: Order of antiderivative to evaluate. Default is 1.
+    ///
+    /// ## Returns
+    /// Piecewise polynomial of order `k + nu` representing the antiderivative of this polynomial.
+    ///
+    /// ## Notes
+    /// The antiderivative returned by this function is continuous and continuously differentiable
+    /// to order `nu - 1`, up to floating point rounding error. It is zero at the first breakpoint.
+    ///
+    /// If the antiderivative is computed and [Extrapolate::Periodic] is set for the polynomial, it
+    /// is set to [Extrapolate::Disabled] for the returned instance, since the antiderivative is no
+    /// longer periodic and its correct evaluation outside of the breakpoints is difficult.
+    pub fn antiderivative(&self, nu: Option<usize>) -> Self {
+        let nu = nu.unwrap_or(1);
+        let k = self.c.len() - 1;
+        // The power m = k - p becomes m + nu, divided by (m + 1) ... (m + nu).
+        let mut c: Vec<Array<F, D>> = self
+            .c
+            .iter()
+            .enumerate()
+            .map(|(p, c)| {
+                let factor = rising_factorial::<F>(k - p + 1, nu);
+                c.mapv(|c| c / factor)
+            })
+            .collect();
+        c.extend((0..nu).map(|_| Array::zeros(self.c[0].raw_dim())));
+
+        // The integration constants make the derivatives of orders below nu continuous, the
+        // coefficient of power q being the q-th derivative at the end of the previous interval
+        // divided by q!.
+        let axis = Axis(self.axis);
+        let order = c.len() - 1;
+        for i in 1..self.x.len() - 1 {
+            let s = self.x[i] - self.x[i - 1];
+            for q in 0..nu {
+                let mut value = Array::zeros(c[0].slice_axis(axis, Slice::from(i..=i)).raw_dim());
+                for (p, c) in c[..=order - q].iter().enumerate() {
+                    let m = order - p;
+                    let factor = binomial::<F>(m, q) * s.powi((m - q) as i32);
+                    Zip::from(&mut value)
+                        .and(c.slice_axis(axis, Slice::from(i - 1..i)))
+                        .for_each(|v, &c| *v = *v + factor * c);
+                }
+                c[order - q]
+                    .slice_axis_mut(axis, Slice::from(i..=i))
+                    .assign(&value);
+            }
+        }
+
+        Self {
+            c,
+            x: self.x.clone(),
+            axis: self.axis,
+            extrapolate: match self.extrapolate {
+                Extrapolate::Periodic => Extrapolate::Disabled,
+                extrapolate => extrapolate,
+            },
+        }
+    }
+
+    /// Compute a definite integral over a piecewise polynomial.
+    ///
+    /// ## Parameters
+    /// * `a`: Lower integration bound.
+    /// * `b`: Upper integration bound.
+    /// * `extrapolate`: Whether to extrapolate to out-of-bounds points based on first and last
+    ///   intervals, or to return NaNs. Defaults to the one of the polynomial.
+    ///
+    /// ## Returns
+    /// Definite integral of the piecewise polynomial over `[a, b]`, for every polynomial.
+    pub fn integrate(&self, a: F, b: F, extrapolate: Option<Extrapolate>) -> Array<F, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        let extrapolate = extrapolate.unwrap_or(self.extrapolate);
+        let (a, b, sign) = if b < a {
+            (b, a, -F::one())
+        } else {
+            (a, b, F::one())
+        };
+        let antiderivative = self.antiderivative(Some(1));
+        let axis = Axis(self.axis);
+        let integral = |a: F, b: F, extrapolate| {
+            let values = antiderivative.eval(&[a, b], extrapolate);
+            &values.index_axis(axis, 1) - &values.index_axis(axis, 0)
+        };
+
+        let total = match extrapolate {
+            Extrapolate::Periodic => {
+                let (xs, xe) = (self.x[0], self.x[self.x.len() - 1]);
+                let period = xe - xs;
+                let n_periods = ((b - a) / period).floor();
+                let left = (b - a) - n_periods * period;
+                let mut total = integral(xs, xe, Extrapolate::Enabled).mapv(|v| v * n_periods);
+
+                // The remaining part starts in the base period, and may wrap around its end.
+                let r = (a - xs) % period;
+                let a = xs + if r < F::zero() { r + period } else { r };
+                let b = a + left;
+                if b <= xe {
+                    total = total + integral(a, b, Extrapolate::Enabled);
+                } else {
+                    total = total
+                        + integral(a, xe, Extrapolate::Enabled)
+                        + integral(xs, xs + b - xe, Extrapolate::Enabled);
+                }
+                total
+            }
+            extrapolate => integral(a, b, extrapolate),
+        };
+        total.mapv(|v| v * sign)
+    }
+}
+
+impl<F: Float + RealField, D: RemoveAxis> PPoly<F, D> {
+    /// Find real solutions of the equation `pp(x) == y`.
+    ///
+    /// ## Parameters
+    /// * `y`: Right-hand side.
+    /// * `discontinuity`: Whether to report sign changes across discontinuities at breakpoints as
+    ///   roots. Default is true.
+    /// * `extrapolate`: Whether to return roots from the polynomial extrapolated based on first and
+    ///   last intervals. [Extrapolate::Periodic] works the same as [Extrapolate::Enabled].
+    ///   Defaults to the one of the polynomial.
+    ///
+    /// ## Returns
+    /// The sorted roots of every polynomial.
+    ///
+    /// ## Notes
+    /// If the piecewise polynomial is identically equal to `y` on an interval `[x[i], x[i + 1]]`,
+    /// the roots contain the start of the interval followed by a NaN.
+    pub fn solve(
+        &self,
+        y: F,
+        discontinuity: Option<bool>,
+        extrapolate: Option<Extrapolate>,
+    ) -> Array<Vec<F>, D::Smaller> {
+        let discontinuity = discontinuity.unwrap_or(true);
+        let extrapolate = extrapolate.unwrap_or(self.extrapolate) != Extrapolate::Disabled;
+        let axis = Axis(self.axis);
+        let mut out = Array::from_elem(self.c[0].index_axis(axis, 0).raw_dim(), Vec::new());
+        let mut lanes: Vec<_> = self.c.iter().map(|c| c.lanes(axis).into_iter()).collect();
+        for out in out.iter_mut() {
+            let c: Vec<ArrayView1<F>> = lanes.iter_mut().filter_map(|lane| lane.next()).collect();
+            *out = real_roots(&c, &self.x, y, discontinuity, extrapolate);
+        }
+        out
+    }
+
+    /// Find real roots of the piecewise polynomial.
+    ///
+    /// This is [solve](PPoly::solve) with `y = 0`.
+    pub fn roots(
+        &self,
+        discontinuity: Option<bool>,
+        extrapolate: Option<Extrapolate>,
+    ) -> Array<Vec<F>, D::Smaller> {
+        self.solve(F::zero(), discontinuity, extrapolate)
+    }
+}
+
+/// Roots of `pp(x) == y` for the piecewise polynomial of coefficients `c` on the breakpoints `x`.
+fn real_roots<F: Float + RealField>(
+    c: &[ArrayView1<F>],
+    x: &[F],
+    y: F,
+    discontinuity: bool,
+    extrapolate: bool,
+) -> Vec<F> {
+    let m = x.len() - 1;
+    // Value and first derivative of the polynomial of interval i.
+    let eval = |i: usize, s: F| {
+        c.iter().fold((F::zero(), F::zero()), |(v, dv), c| {
+            (v * s + c[i], dv * s + v)
+        })
+    };
+    // Roots are merged with the previous one within this tolerance, the error of double roots.
+    let tol = Float::sqrt(F::epsilon());
+    let mut out: Vec<F> = Vec::new();
+    let mut last = F::nan();
+    for i in 0..m {
+        if i > 0 && discontinuity {
+            let va = eval(i - 1, x[i] - x[i - 1]).0 - y;
+            let vb = eval(i, F::zero()).0 - y;
+            if (va < F::zero() && vb > F::zero()) || (va > F::zero() && vb < F::zero()) {
+                last = x[i];
+                out.push(last);
+            }
+        }
+
+        let mut p: Vec<F> = c.iter().map(|c| c[i]).collect();
+        let constant = p.len() - 1;
+        p[constant] -= y;
+        let Some(lead) = p.iter().position(|p| !p.is_zero()) else {
+            // Equal to y on the whole interval.
+            out.push(x[i]);
+            out.push(F::nan());
+            last = F::nan();
+            continue;
+        };
+        let p = &p[lead..];
+        let candidates: Vec<F> = match p.len() {
+            1 => Vec::new(),
+            2 => vec![-p[1] / p[0]],
+            _ => roots(p)
+                .into_iter()
+                .filter(|r| r.im.is_zero())
+                .map(|r| r.re)
+                .collect(),
+        };
+        let mut found: Vec<F> = candidates
+            .into_iter()
+            .map(|r| {
+                // Refine by one Newton iteration.
+                let (f, df) = eval(i, r);
+                let (f, df) = (f - y, df);
+                let step = if df.is_zero() { F::zero() } else { f / df };
+                let r = if Float::abs(step) < Float::abs(r) {
+                    r - step
+                } else {
+                    r
+                };
+                x[i] + r
+            })
+            .filter(|&r| {
+                let after_start = r >= x[i] || (extrapolate && i == 0);
+                let before_end = r <= x[i + 1] || (extrapolate && i == m - 1);
+                after_start && before_end
+            })
+            .collect();
+        found.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        for r in found {
+            if last.is_nan() || Float::abs(r - last) > tol * (F::one() + Float::abs(r)) {
+                last = r;
+                out.push(r);
+            }
+        }
+    }
+    out
+}
+
+/// `a * (a + 1) * ... * (a + n - 1)`.
+fn rising_factorial<F: Float>(a: usize, n: usize) -> F {
+    (a..a + n).fold(F::one(), |acc, j| acc * F::from(j).unwrap())
+}
+
+/// The binomial coefficient `n` choose `k`.
+fn binomial<F: Float>(n: usize, k: usize) -> F {
+    (0..k).fold(F::one(), |acc, j| {
+        acc * F::from(n - j).unwrap() / F::from(j + 1).unwrap()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{arr1, array};
+
+    #[track_caller]
+    fn assert_close(a: impl IntoIterator<Item = f64>, b: &[f64], epsilon: f64) {
+        let a: Vec<f64> = a.into_iter().collect();
+        assert_eq!(a.len(), b.len());
+        for (a, &b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = epsilon);
+        }
+    }
+
+    #[test]
+    fn calculus() {
+        // 1 + x on [0, 1], then 2 - 2 (x - 1) + 3 (x - 1)^2 on [1, 3].
+        let c = vec![array![0., 3.], array![1., -2.], array![1., 2.]];
+        let pp = PPoly::new(c, vec![0., 1., 3.], None, None).unwrap();
+        let f = |x: f64| {
+            if x < 1. {
+                1. + x
+            } else {
+                2. - 2. * (x - 1.) + 3. * (x - 1.) * (x - 1.)
+            }
+        };
+        let xs = [-1., 0., 0.5, 1., 2., 3., 4.];
+        let values = pp.call(&xs, None, None);
+        for (&x, &v) in xs.iter().zip(&values) {
+            assert_abs_diff_eq!(v, f(x), epsilon = 1e-12);
+        }
+        let values = pp.call(&xs, None, Some(Extrapolate::Disabled));
+        assert!(values[0].is_nan() && values[6].is_nan());
+
+        let d = pp.call(&[0.5, 2.], Some(1), None);
+        assert_close(d, &[1., 4.], 1e-12);
+        assert_eq!(pp.derivative(Some(3)).c().len(), 1);
+
+        // The antiderivative is continuous and zero at the start.
+        let ad = pp.antiderivative(None);
+        let values = ad.call(&[0., 1., 3.], None, None);
+        assert_close(values, &[0., 1.5, 9.5], 1e-12);
+        assert_close(ad.call(&[2.], Some(1), None), &[f(2.)], 1e-12);
+        assert_close(
+            pp.antiderivative(Some(2)).call(&[1.], Some(1), None),
+            &[1.5],
+            1e-12,
+        );
+
+        assert_abs_diff_eq!(
+            pp.integrate(0., 3., None).into_scalar(),
+            9.5,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            pp.integrate(3., 0.5, None).into_scalar(),
+            -8.875,
+            epsilon = 1e-12
+        );
+        assert!(pp
+            .integrate(-1., 1., Some(Extrapolate::Disabled))
+            .into_scalar()
+            .is_nan());
+        // 2 full periods, and [2.8, 3] + [0, 0.3] for the remainder.
+        let periodic = pp.integrate(2.8, 9.3, Some(Extrapolate::Periodic));
+        assert_abs_diff_eq!(periodic.into_scalar(), 21.153, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn solutions() {
+        // (x - 1)(x - 2) extrapolated from [0, 1.5], then a jump to 1 on [1.5, 3].
+        let c = vec![array![1., 0.], array![-3., 0.], array![2., 1.]];
+        let pp = PPoly::new(c, vec![0., 1.5, 3.], None, None).unwrap();
+        assert_eq!(pp.roots(None, None).into_scalar(), [1., 1.5]);
+        assert_eq!(pp.roots(Some(false), None).into_scalar(), [1.]);
+        let r = pp.solve(1., Some(false), None).into_scalar();
+        assert_eq!(r.len(), 3);
+        assert_abs_diff_eq!(r[0], (3. - 5f64.sqrt()) / 2., epsilon = 1e-12);
+        assert!(r[2].is_nan());
+        let r = pp.solve(0.5, None, None).into_scalar();
+        assert_eq!(r.len(), 2);
+        assert_abs_diff_eq!(r[0], (3. - 3f64.sqrt()) / 2., epsilon = 1e-12);
+        assert_eq!(r[1], 1.5);
+
+        let pp = PPoly::new(vec![arr1(&[2., 0.])], vec![0., 1., 2.], None, None).unwrap();
+        let r = pp.roots(None, None).into_scalar();
+        assert_eq!(r[0], 1.);
+        assert!(r[1].is_nan());
+    }
+}
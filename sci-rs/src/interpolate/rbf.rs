@@ -0,0 +1,573 @@
+use alloc::{collections::BTreeMap, format, vec, vec::Vec};
+use nalgebra::{DMatrix, RealField};
+use ndarray::{Array, Array2, ArrayBase, ArrayViewMut1, Axis, Data, Dimension, Ix1, Ix2};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Radial basis function of [RBFInterpolator], evaluated at `r = epsilon * |x - y|`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RbfKernel {
+    /// `-r`
+    Linear,
+    /// `r^2 * log(r)`
+    #[default]
+    ThinPlateSpline,
+    /// `r^3`
+    Cubic,
+    /// `-r^5`
+    Quintic,
+    /// `-sqrt(1 + r^2)`
+    Multiquadric,
+    /// `1 / sqrt(1 + r^2)`
+    InverseMultiquadric,
+    /// `1 / (1 + r^2)`
+    InverseQuadratic,
+    /// `exp(-r^2)`
+    Gaussian,
+}
+
+impl RbfKernel {
+    fn eval<F: Float>(self, r: F) -> F {
+        match self {
+            RbfKernel::Linear => -r,
+            RbfKernel::ThinPlateSpline if r.is_zero() => F::zero(),
+            RbfKernel::ThinPlateSpline => r * r * r.ln(),
+            RbfKernel::Cubic => r.powi(3),
+            RbfKernel::Quintic => -r.powi(5),
+            RbfKernel::Multiquadric => -(F::one() + r * r).sqrt(),
+            RbfKernel::InverseMultiquadric => (F::one() + r * r).sqrt().recip(),
+            RbfKernel::InverseQuadratic => (F::one() + r * r).recip(),
+            RbfKernel::Gaussian => (-r * r).exp(),
+        }
+    }
+
+    /// Smallest degree of the polynomial for which the interpolation problem is well-posed, if
+    /// the kernel is only conditionally positive definite.
+    fn min_degree(self) -> Option<usize> {
+        match self {
+            RbfKernel::Linear | RbfKernel::Multiquadric => Some(0),
+            RbfKernel::ThinPlateSpline | RbfKernel::Cubic => Some(1),
+            RbfKernel::Quintic => Some(2),
+            _ => None,
+        }
+    }
+
+    /// Whether the interpolant does not depend on `epsilon`.
+    fn scale_invariant(self) -> bool {
+        matches!(
+            self,
+            RbfKernel::Linear | RbfKernel::ThinPlateSpline | RbfKernel::Cubic | RbfKernel::Quintic
+        )
+    }
+}
+
+/// Radial basis function (RBF) interpolation in N dimensions.
+///
+/// The interpolant is a linear combination of the kernel centered at the data points, plus a
+/// polynomial of the given degree:
+///
+/// `f(x) = sum(a[i] * phi(epsilon * |x - y[i]|)) + sum(b[j] * p[j](x))`
+///
+/// The coefficients are the solution of
+///
+/// `(K(y, y) + smoothing * I) a + P(y) b = d` and `P(y)^T a = 0`
+///
+/// where `K(y, y)` is the kernel matrix of the data points and `P(y)` holds the monomials at
+/// them. With `smoothing = 0` the data are interpolated, otherwise the interpolant fits them in the
+/// least-squares sense of the regularized problem. The polynomial is evaluated at the points
+/// scaled to `[-1, 1]` in every dimension, which only changes `b`.
+///
+/// ## Notes
+/// With `neighbors`, the interpolant at every point only involves its nearest data points: the
+/// system is solved for every distinct set of neighbors of the evaluation points. The neighbors
+/// are found by brute force.
+///
+/// ## References
+/// [1] Fasshauer, G., 2007. Meshfree Approximation Methods with Matlab. World Scientific
+///     Publishing Co.
+///
+/// [2] Wahba, G., 1990. Spline Models for Observational Data. SIAM.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.interpolate import RBFInterpolator
+/// xobs = np.array([[0., 0.], [1., 0.], [0., 1.], [1., 1.], [0.5, 0.5]])
+/// yobs = np.hypot(xobs[:, 0], xobs[:, 1])
+/// RBFInterpolator(xobs, yobs)(np.array([[0.5, 0.5], [0.25, 0.75]]))
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{array, Array1, Array2};
+/// use sci_rs::interpolate::RBFInterpolator;
+///
+/// let xobs: Array2<f64> = array![[0., 0.], [1., 0.], [0., 1.], [1., 1.], [0.5, 0.5]];
+/// let yobs: Array1<f64> = xobs.rows().into_iter().map(|p| p[0].hypot(p[1])).collect();
+/// let rbf = RBFInterpolator::new(&xobs, &yobs, None, None, None, None, None).unwrap();
+/// let values = rbf.call(&array![[0.5, 0.5], [0.25, 0.75]]).unwrap();
+/// assert_abs_diff_eq!(values[0], 0.5f64.hypot(0.5), epsilon = 1e-12);
+/// assert_abs_diff_eq!(values[1], 0.25f64.hypot(0.75), epsilon = 0.1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RBFInterpolator<F, D: Dimension> {
+    y: Array2<F>,
+    /// The data, with the trailing dimensions flattened.
+    d: Array2<F>,
+    shape: D,
+    neighbors: Option<usize>,
+    smoothing: F,
+    kernel: RbfKernel,
+    epsilon: F,
+    /// Exponents of the monomials in every dimension.
+    powers: Vec<Vec<usize>>,
+    /// Solution of the global system, without `neighbors`.
+    global: Option<Rbf<F>>,
+}
+
+/// Solution of the interpolation problem on some data points.
+#[derive(Debug, Clone)]
+struct Rbf<F> {
+    /// Coefficients of the kernels, then of the monomials.
+    coeffs: DMatrix<F>,
+    shift: Vec<F>,
+    scale: Vec<F>,
+}
+
+impl<F: Float + RealField, D: Dimension> RBFInterpolator<F, D> {
+    /// Build the interpolant of the data `d` at the points `y`.
+    ///
+    /// ## Parameters
+    /// * `y`: Data point coordinates, of shape `(p, n)`.
+    /// * `d`: Data values, of shape `(p, ...)`.
+    /// * `neighbors`: If specified, the value of the interpolant at each evaluation point will be
+    ///   computed using only this many nearest data points. All the data points are used by
+    ///   default.
+    /// * `smoothing`: Smoothing parameter. The interpolant perfectly fits the data when this is
+    ///   set to 0 (default). For large values, the interpolant approaches a least squares fit of a
+    ///   polynomial with the specified degree.
+    /// * `kernel`: Type of RBF, [RbfKernel::ThinPlateSpline] by default.
+    /// * `epsilon`: Shape parameter that scales the input to the RBF. It defaults to 1 for the
+    ///   linear, thin plate spline, cubic and quintic kernels, on which it has no effect, and must
+    ///   be specified for the other kernels.
+    /// * `degree`: Degree of the added polynomial, or -1 for none. The default is the minimum
+    ///   degree for the kernel, or 0 if there is none. For some RBFs the interpolant may not be
+    ///   well-posed if the polynomial degree is less than that minimum.
+    ///
+    /// ## Errors
+    /// If the shapes of `y` and `d` do not match, `epsilon` is missing, there are fewer data
+    /// points (or neighbors) than monomials, or the system is singular.
+    pub fn new<S1, S2>(
+        y: &ArrayBase<S1, Ix2>,
+        d: &ArrayBase<S2, D>,
+        neighbors: Option<usize>,
+        smoothing: Option<F>,
+        kernel: Option<RbfKernel>,
+        epsilon: Option<F>,
+        degree: Option<isize>,
+    ) -> Result<Self>
+    where
+        S1: Data<Elem = F>,
+        S2: Data<Elem = F>,
+    {
+        let (p, ndim) = y.dim();
+        if d.ndim() == 0 || d.shape()[0] != p {
+            return Err(Error::InvalidArg {
+                arg: "d".into(),
+                reason: format!("Expected the first axis of `d` to have length {}.", p),
+            });
+        }
+        let kernel = kernel.unwrap_or_default();
+        let epsilon = match epsilon {
+            Some(epsilon) => epsilon,
+            None if kernel.scale_invariant() => F::one(),
+            None => {
+                return Err(Error::InvalidArg {
+                    arg: "epsilon".into(),
+                    reason: "`epsilon` must be specified if `kernel` is not linear, thin plate \
+                             spline, cubic or quintic."
+                        .into(),
+                })
+            }
+        };
+        let degree = match degree {
+            None => kernel.min_degree().unwrap_or(0) as isize,
+            Some(degree) if degree < -1 => {
+                return Err(Error::InvalidArg {
+                    arg: "degree".into(),
+                    reason: "`degree` must be at least -1.".into(),
+                })
+            }
+            Some(degree) => degree,
+        };
+        let powers = monomial_powers(ndim, degree);
+        let neighbors = neighbors.map(|k| k.min(p));
+        if neighbors.unwrap_or(p) < powers.len() {
+            return Err(Error::InvalidArg {
+                arg: "y".into(),
+                reason: format!(
+                    "At least {} data points are required when `degree` is {} and the number of \
+                     dimensions is {}.",
+                    powers.len(),
+                    degree,
+                    ndim
+                ),
+            });
+        }
+
+        let m = d.len() / p.max(1);
+        let flat = d
+            .to_shape((p, m))
+            .map_err(|e| Error::InvalidArg {
+                arg: "d".into(),
+                reason: format!("{}", e),
+            })?
+            .to_owned();
+        let mut rbf = RBFInterpolator {
+            y: y.to_owned(),
+            d: flat,
+            shape: d.raw_dim(),
+            neighbors,
+            smoothing: smoothing.unwrap_or_else(F::zero),
+            kernel,
+            epsilon,
+            powers,
+            global: None,
+        };
+        if neighbors.is_none() {
+            let indices: Vec<usize> = (0..p).collect();
+            rbf.global = Some(rbf.solve(&indices)?);
+        }
+        Ok(rbf)
+    }
+
+    /// Solve the interpolation problem on the data points `indices`.
+    fn solve(&self, indices: &[usize]) -> Result<Rbf<F>> {
+        let (p, r, ndim) = (indices.len(), self.powers.len(), self.y.ncols());
+        let points = self.y.select(Axis(0), indices);
+        let (mut shift, mut scale) = (vec![F::zero(); ndim], vec![F::one(); ndim]);
+        let half = F::from(0.5).unwrap();
+        for (dim, column) in points.columns().into_iter().enumerate() {
+            let min = column.fold(F::infinity(), |a, &b| Float::min(a, b));
+            let max = column.fold(F::neg_infinity(), |a, &b| Float::max(a, b));
+            shift[dim] = (max + min) * half;
+            if max > min {
+                scale[dim] = (max - min) * half;
+            }
+        }
+
+        let mut lhs = DMatrix::zeros(p + r, p + r);
+        for i in 0..p {
+            for j in 0..p {
+                lhs[(i, j)] = self.kernel_at(points.row(i), points.row(j));
+            }
+            lhs[(i, i)] += self.smoothing;
+            let monomials = self.monomials(points.row(i), &shift, &scale);
+            for (j, value) in monomials.into_iter().enumerate() {
+                lhs[(i, p + j)] = value;
+                lhs[(p + j, i)] = value;
+            }
+        }
+        let mut rhs = DMatrix::zeros(p + r, self.d.ncols());
+        for (i, &index) in indices.iter().enumerate() {
+            for (j, &value) in self.d.row(index).iter().enumerate() {
+                rhs[(i, j)] = value;
+            }
+        }
+        let coeffs = lhs.lu().solve(&rhs).ok_or_else(|| Error::InvalidArg {
+            arg: "y".into(),
+            reason: "Singular matrix. The matrix of monomials evaluated at the data point \
+                     coordinates does not have full column rank."
+                .into(),
+        })?;
+        Ok(Rbf {
+            coeffs,
+            shift,
+            scale,
+        })
+    }
+
+    /// Kernel between two points.
+    fn kernel_at<S1, S2>(&self, a: ArrayBase<S1, Ix1>, b: ArrayBase<S2, Ix1>) -> F
+    where
+        S1: Data<Elem = F>,
+        S2: Data<Elem = F>,
+    {
+        let r2 = a
+            .iter()
+            .zip(&b)
+            .fold(F::zero(), |acc, (&a, &b)| acc + (a - b) * (a - b));
+        self.kernel.eval(self.epsilon * Float::sqrt(r2))
+    }
+
+    /// Monomials at `x`, shifted and scaled.
+    fn monomials<S: Data<Elem = F>>(
+        &self,
+        x: ArrayBase<S, Ix1>,
+        shift: &[F],
+        scale: &[F],
+    ) -> Vec<F> {
+        self.powers
+            .iter()
+            .map(|powers| {
+                powers
+                    .iter()
+                    .enumerate()
+                    .fold(F::one(), |acc, (dim, &power)| {
+                        acc * Float::powi((x[dim] - shift[dim]) / scale[dim], power as i32)
+                    })
+            })
+            .collect()
+    }
+
+    /// Evaluate the interpolant at `x`, of shape `(q, n)`.
+    ///
+    /// ## Returns
+    /// Values of the interpolant, of shape `(q, ...)` following the trailing dimensions of `d`.
+    ///
+    /// ## Errors
+    /// If `x` does not have the dimensions of the data points, or a local system is singular.
+    pub fn call<S: Data<Elem = F>>(&self, x: &ArrayBase<S, Ix2>) -> Result<Array<F, D>> {
+        let (q, ndim) = x.dim();
+        if ndim != self.y.ncols() {
+            return Err(Error::InvalidArg {
+                arg: "x".into(),
+                reason: format!(
+                    "Expected the second axis of `x` to have length {}.",
+                    self.y.ncols()
+                ),
+            });
+        }
+        let mut out = Array2::zeros((q, self.d.ncols()));
+        match &self.global {
+            Some(rbf) => {
+                let indices: Vec<usize> = (0..self.y.nrows()).collect();
+                for (i, point) in x.rows().into_iter().enumerate() {
+                    self.evaluate(rbf, &indices, point, out.row_mut(i));
+                }
+            }
+            None => {
+                let k = self.neighbors.unwrap_or(self.y.nrows());
+                let mut groups: BTreeMap<Vec<usize>, Vec<usize>> = BTreeMap::new();
+                for (i, point) in x.rows().into_iter().enumerate() {
+                    groups.entry(self.nearest(point, k)).or_default().push(i);
+                }
+                for (indices, points) in groups {
+                    let rbf = self.solve(&indices)?;
+                    for i in points {
+                        self.evaluate(&rbf, &indices, x.row(i), out.row_mut(i));
+                    }
+                }
+            }
+        }
+        let mut shape = self.shape.clone();
+        shape[0] = q;
+        Ok(out
+            .into_shape_with_order(shape)
+            .expect("the trailing dimensions are those of `d`"))
+    }
+
+    /// Value at `x` of the interpolant on the data points `indices`, written into `out`.
+    fn evaluate<S: Data<Elem = F>>(
+        &self,
+        rbf: &Rbf<F>,
+        indices: &[usize],
+        x: ArrayBase<S, Ix1>,
+        mut out: ArrayViewMut1<F>,
+    ) {
+        let kernels = indices
+            .iter()
+            .map(|&j| self.kernel_at(x.view(), self.y.row(j)));
+        let monomials = self.monomials(x.view(), &rbf.shift, &rbf.scale);
+        for (row, weight) in kernels.chain(monomials).enumerate() {
+            for (m, out) in out.iter_mut().enumerate() {
+                *out += weight * rbf.coeffs[(row, m)];
+            }
+        }
+    }
+
+    /// Indices of the `k` data points nearest to `x`, sorted.
+    fn nearest<S: Data<Elem = F>>(&self, x: ArrayBase<S, Ix1>, k: usize) -> Vec<usize> {
+        let distances: Vec<F> = self
+            .y
+            .rows()
+            .into_iter()
+            .map(|y| {
+                y.iter()
+                    .zip(&x)
+                    .fold(F::zero(), |acc, (&a, &b)| acc + (a - b) * (a - b))
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..distances.len()).collect();
+        order.sort_by(|&a, &b| {
+            distances[a]
+                .partial_cmp(&distances[b])
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+        order.truncate(k);
+        order.sort_unstable();
+        order
+    }
+}
+
+/// Exponents of the monomials of degree at most `degree` in `ndim` variables, by increasing
+/// degree.
+fn monomial_powers(ndim: usize, degree: isize) -> Vec<Vec<usize>> {
+    let mut powers = Vec::new();
+    for total in 0..=degree.max(-1) {
+        // Non-increasing sequences of variables, like `combinations_with_replacement`.
+        let mut combination = vec![0; total as usize];
+        loop {
+            let mut power = vec![0; ndim];
+            combination.iter().for_each(|&v| power[v] += 1);
+            if ndim > 0 {
+                powers.push(power);
+            }
+            let Some(i) = combination.iter().rposition(|&v| v + 1 < ndim) else {
+                break;
+            };
+            let v = combination[i] + 1;
+            combination[i..].iter_mut().for_each(|c| *c = v);
+        }
+    }
+    powers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array1};
+
+    fn points() -> Array2<f64> {
+        Array2::from_shape_fn((30, 2), |(i, d)| {
+            let i = (i + 1) as f64;
+            if d == 0 {
+                (i * 0.618_033_988_7).fract()
+            } else {
+                2. * (i * 0.754_877_666_2).fract()
+            }
+        })
+    }
+
+    #[test]
+    fn interpolation() {
+        let y = points();
+        let d: Array1<f64> = y
+            .rows()
+            .into_iter()
+            .map(|p| (3. * p[0]).sin() * p[1])
+            .collect();
+        let kernels = [
+            RbfKernel::Linear,
+            RbfKernel::ThinPlateSpline,
+            RbfKernel::Cubic,
+            RbfKernel::Quintic,
+            RbfKernel::Multiquadric,
+            RbfKernel::InverseMultiquadric,
+            RbfKernel::InverseQuadratic,
+            RbfKernel::Gaussian,
+        ];
+        for kernel in kernels {
+            let rbf =
+                RBFInterpolator::new(&y, &d, None, None, Some(kernel), Some(2.), None).unwrap();
+            for (a, b) in rbf.call(&y).unwrap().iter().zip(&d) {
+                assert_abs_diff_eq!(a, b, epsilon = 1e-8);
+            }
+        }
+        assert!(
+            RBFInterpolator::new(&y, &d, None, None, Some(RbfKernel::Gaussian), None, None)
+                .is_err()
+        );
+        assert!(RBFInterpolator::new(
+            &y.slice(ndarray::s![..2, ..]),
+            &d.slice(ndarray::s![..2]),
+            None,
+            None,
+            None,
+            None,
+            None
+        )
+        .is_err());
+        assert!(RBFInterpolator::new(&y, &d, None, None, None, None, Some(-2)).is_err());
+        assert!(RBFInterpolator::new(&y, &d, None, None, None, None, None)
+            .unwrap()
+            .call(&array![[0., 1., 2.]])
+            .is_err());
+    }
+
+    #[test]
+    fn polynomials() {
+        // The polynomial part reproduces data of its degree, with or without smoothing.
+        let y = points();
+        let quadratic = |p: [f64; 2]| 1. + p[0] - 2. * p[1] + p[0] * p[1] - 0.5 * p[1] * p[1];
+        let d: Array1<f64> = y
+            .rows()
+            .into_iter()
+            .map(|p| quadratic([p[0], p[1]]))
+            .collect();
+        let x = array![[0.3, 1.7], [0.9, 0.1], [1.5, 2.5]];
+        for smoothing in [0., 10.] {
+            let quintic = RBFInterpolator::new(
+                &y,
+                &d,
+                None,
+                Some(smoothing),
+                Some(RbfKernel::Quintic),
+                None,
+                None,
+            )
+            .unwrap();
+            for (value, p) in quintic.call(&x).unwrap().iter().zip(x.rows()) {
+                assert_abs_diff_eq!(*value, quadratic([p[0], p[1]]), epsilon = 1e-8);
+            }
+        }
+
+        // Heavy smoothing tends to the least-squares line.
+        let x1 = array![[0.], [1.], [2.], [3.], [4.]];
+        let d1 = array![0., 2., 1., 3., 5.];
+        let rbf = RBFInterpolator::new(&x1, &d1, None, Some(1e12), None, None, None).unwrap();
+        let fit = rbf.call(&array![[0.], [4.]]).unwrap();
+        assert_abs_diff_eq!(fit[0], 0., epsilon = 1e-6);
+        assert_abs_diff_eq!(fit[1], 4.4, epsilon = 1e-6);
+        assert_eq!(monomial_powers(2, 2).len(), 6);
+        assert_eq!(monomial_powers(3, -1).len(), 0);
+    }
+
+    #[test]
+    fn neighbors_and_vector_data() {
+        let y = points();
+        let d = Array2::from_shape_fn((30, 2), |(i, m)| {
+            let p = y.row(i);
+            if m == 0 {
+                p[0] * p[1]
+            } else {
+                (p[0] - p[1]).cos()
+            }
+        });
+        let x = array![[0.2, 0.3], [0.7, 1.1], [0.45, 1.9]];
+        let global = RBFInterpolator::new(&y, &d, None, None, None, None, None).unwrap();
+        let all = RBFInterpolator::new(&y, &d, Some(100), None, None, None, None).unwrap();
+        let (a, b) = (global.call(&x).unwrap(), all.call(&x).unwrap());
+        assert_eq!(a.dim(), (3, 2));
+        for (a, b) in a.iter().zip(&b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-10);
+        }
+        for m in 0..2 {
+            let column = d.column(m).to_owned();
+            let single = RBFInterpolator::new(&y, &column, None, None, None, None, None).unwrap();
+            for (a, b) in single.call(&x).unwrap().iter().zip(a.column(m)) {
+                assert_abs_diff_eq!(a, b, epsilon = 1e-10);
+            }
+        }
+
+        let local = RBFInterpolator::new(&y, &d, Some(8), None, None, None, None).unwrap();
+        for (a, b) in local.call(&y).unwrap().iter().zip(&d) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-10);
+        }
+        for (a, b) in local.call(&x).unwrap().iter().zip(&a) {
+            assert_abs_diff_eq!(a, b, epsilon = 0.05);
+        }
+    }
+}
@@ -0,0 +1,397 @@
+use super::bsplines::{eval_basis, find_interval, BSpline};
+use crate::linalg::BandedLu;
+use crate::optimize::minimize_scalar;
+use alloc::{vec, vec::Vec};
+use ndarray::{ArrayBase, Data, Ix1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Compute the (coefficients of) smoothing cubic spline function using `lam` to control the
+/// tradeoff between the amount of smoothness of the curve and its proximity to the data. In case
+/// `lam` is `None`, using the GCV criteria [1] to find it.
+///
+/// A smoothing spline is found as a solution to the regularized weighted linear regression
+/// problem:
+///
+/// `sum(w[i] * |y[i] - f(x[i])|^2) + lam * integral(f''(u)^2, x[0], x[n - 1])`
+///
+/// where `f` is a spline function, `w` is a vector of weights and `lam` is a regularization
+/// parameter.
+///
+/// If `lam` is `None`, it is found by minimizing the generalized cross-validation criterion [1]
+/// over `[0, n]`. Otherwise, the given non-negative `lam` is used: `lam = 0` gives the natural
+/// cubic spline through the points.
+///
+/// ## Parameters
+/// * `x`: Abscissas, strictly increasing with at least 5 points.
+/// * `y`: Ordinates.
+/// * `w`: Vector of weights. Default is ones.
+/// * `lam`: Regularization parameter.
+///
+/// ## Returns
+/// A cubic spline on the knots `x`, with triple knots added at both ends.
+///
+/// ## Errors
+/// If the lengths of `x`, `y` and `w` differ, there are fewer than 5 points, `x` is not strictly
+/// increasing, `lam` is negative or the problem is ill-posed.
+///
+/// ## References
+/// [1] G. Wahba, "Estimating the smoothing parameter" in Spline models for observational data,
+///     Philadelphia, Pennsylvania: Society for Industrial and Applied Mathematics, 1990,
+///     pp. 45-65. <https://doi.org/10.1137/1.9781611970128>
+///
+/// [2] H. J. Woltring, A Fortran package for generalized, cross-validatory spline smoothing and
+///     differentiation, Advances in Engineering Software, vol. 8, no. 2, pp. 104-113, 1986.
+///     <https://doi.org/10.1016/0141-1195(86)90098-7>
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.interpolate import make_smoothing_spline
+/// x = np.arange(0, 2*np.pi+np.pi/4, 2*np.pi/16)
+/// y = np.sin(x) + 0.1*np.cos(7*x)
+/// spl = make_smoothing_spline(x, y)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use core::f64::consts::PI;
+/// use ndarray::Array1;
+/// use sci_rs::interpolate::make_smoothing_spline;
+///
+/// let x: Vec<f64> = (0..17).map(|i| i as f64 * PI / 8.).collect();
+/// let y: Array1<f64> = x.iter().map(|x| x.sin() + 0.1 * (7. * x).cos()).collect();
+/// let spl = make_smoothing_spline(&x, &y, None, None).unwrap();
+/// for (&x, s) in x.iter().zip(spl.call(&x, None, None)) {
+///     assert_abs_diff_eq!(s, x.sin(), epsilon = 0.1);
+/// }
+/// ```
+pub fn make_smoothing_spline<F, S>(
+    x: &[F],
+    y: &ArrayBase<S, Ix1>,
+    w: Option<&[F]>,
+    lam: Option<F>,
+) -> Result<BSpline<F, Ix1>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let n = x.len();
+    if y.len() != n || w.is_some_and(|w| w.len() != n) {
+        return Err(Error::InvalidArg {
+            arg: "y".into(),
+            reason: "`x`, `y` and `w` should have the same length.".into(),
+        });
+    }
+    if n < 5 {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "`x` and `y` length must be at least 5.".into(),
+        });
+    }
+    if x.iter().any(|x| x.is_nan()) || x.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "`x` should be an ascending array.".into(),
+        });
+    }
+    if lam.is_some_and(|lam| lam.is_nan() || lam < F::zero()) {
+        return Err(Error::InvalidArg {
+            arg: "lam".into(),
+            reason: "Regularization parameter should be non-negative.".into(),
+        });
+    }
+
+    let fit = PenalizedFit::new(x, y, w);
+    let c = match lam {
+        Some(lam) if lam.is_zero() => fit.interpolate()?,
+        Some(lam) => fit.solve(lam)?.1,
+        None => {
+            let bounds = (F::zero(), F::from(n).unwrap());
+            let gcv = minimize_scalar(|lam| fit.gcv(lam), None, Some(bounds), None, None, None)?;
+            if !gcv.success {
+                return Err(Error::InvalidArg {
+                    arg: "y".into(),
+                    reason: "Unable to find minimum of the GCV function.".into(),
+                });
+            }
+            fit.solve(gcv.x)
+                .map_err(|_| Error::InvalidArg {
+                    arg: "y".into(),
+                    reason: "Seems like the problem is ill-posed.".into(),
+                })?
+                .1
+        }
+    };
+    BSpline::new(fit.t, c.into(), 3, None, None)
+}
+
+/// Weighted least-squares fit of cubic splines on the knots at the points, penalized by the
+/// integral of the squared second derivative.
+///
+/// The symmetric matrices of the normal equations are kept as their diagonal and the three
+/// super-diagonals, `band[i][d]` being the entry `(i, i + d)`.
+struct PenalizedFit<F> {
+    t: Vec<F>,
+    y: Vec<F>,
+    w: Vec<F>,
+    /// Interval and values of the non-zero B-splines at every point.
+    rows: Vec<(usize, [F; 4])>,
+    /// `B^T W B` for the design matrix `B`.
+    gram: Vec<[F; 4]>,
+    /// Integrals of the products of the second derivatives of the B-splines.
+    penalty: Vec<[F; 4]>,
+}
+
+impl<F: Float> PenalizedFit<F> {
+    fn new<S: Data<Elem = F>>(x: &[F], y: &ArrayBase<S, Ix1>, w: Option<&[F]>) -> Self {
+        let n = x.len();
+        let (first, last) = (x[0], x[n - 1]);
+        let t: Vec<F> = [first; 3]
+            .into_iter()
+            .chain(x.iter().copied())
+            .chain([last; 3])
+            .collect();
+        let w = w.map_or_else(|| vec![F::one(); n], |w| w.to_vec());
+
+        let mut gram = vec![[F::zero(); 4]; n + 2];
+        let rows: Vec<_> = x
+            .iter()
+            .zip(&w)
+            .map(|(&x, &w)| {
+                let l = find_interval(&t, 3, x, false).unwrap();
+                let mut h = [F::zero(); 4];
+                eval_basis(&t, 3, x, l, 0, &mut h);
+                for a in 0..4 {
+                    for b in a..4 {
+                        gram[l - 3 + a][b - a] = gram[l - 3 + a][b - a] + w * h[a] * h[b];
+                    }
+                }
+                (l, h)
+            })
+            .collect();
+
+        // The second derivatives are linear on every interval, so Simpson's rule is exact.
+        let mut penalty = vec![[F::zero(); 4]; n + 2];
+        let (half, sixth, four) = (
+            F::from(0.5).unwrap(),
+            F::from(6).unwrap().recip(),
+            F::from(4).unwrap(),
+        );
+        for (i, x) in x.windows(2).enumerate() {
+            let l = i + 3;
+            let mut d = [[F::zero(); 4]; 3];
+            for (d, at) in d.iter_mut().zip([x[0], (x[0] + x[1]) * half, x[1]]) {
+                eval_basis(&t, 3, at, l, 2, d);
+            }
+            let scale = (x[1] - x[0]) * sixth;
+            for a in 0..4 {
+                for b in a..4 {
+                    let simpson = d[0][a] * d[0][b] + four * d[1][a] * d[1][b] + d[2][a] * d[2][b];
+                    penalty[l - 3 + a][b - a] = penalty[l - 3 + a][b - a] + scale * simpson;
+                }
+            }
+        }
+
+        PenalizedFit {
+            t,
+            y: y.to_vec(),
+            w,
+            rows,
+            gram,
+            penalty,
+        }
+    }
+
+    /// Entry `(i, j)`, within the band, of the symmetric matrix `band`.
+    fn entry(band: &[[F; 4]], i: usize, j: usize) -> F {
+        let (i, j) = (i.min(j), i.max(j));
+        band[i][j - i]
+    }
+
+    /// Factorization of the normal equations with the regularization parameter `lam > 0`, and
+    /// their solution.
+    fn solve(&self, lam: F) -> Result<(BandedLu<F>, Vec<F>)> {
+        let m = self.gram.len();
+        let lu = BandedLu::new(m, 3, 3, |i, j| {
+            Self::entry(&self.gram, i, j) + lam * Self::entry(&self.penalty, i, j)
+        })?;
+        let mut c = vec![F::zero(); m];
+        for ((&(l, h), &y), &w) in self.rows.iter().zip(&self.y).zip(&self.w) {
+            for (a, h) in h.iter().enumerate() {
+                c[l - 3 + a] = c[l - 3 + a] + w * *h * y;
+            }
+        }
+        lu.solve(&mut c);
+        Ok((lu, c))
+    }
+
+    /// Coefficients of the natural cubic spline through the points, the limit of `lam -> 0`.
+    fn interpolate(&self) -> Result<Vec<F>> {
+        let m = self.gram.len();
+        let (first, last) = (self.t[3], self.t[m]);
+        let mut rows = vec![(0, [F::zero(); 4]); m];
+        eval_basis(&self.t, 3, first, 3, 2, &mut rows[0].1);
+        rows[0].0 = 3;
+        rows[1..m - 1].copy_from_slice(&self.rows);
+        eval_basis(&self.t, 3, last, m - 1, 2, &mut rows[m - 1].1);
+        rows[m - 1].0 = m - 1;
+        let lu = BandedLu::new(m, 3, 3, |i, j| {
+            let (l, h) = rows[i];
+            if l - 3 <= j && j <= l {
+                h[j + 3 - l]
+            } else {
+                F::zero()
+            }
+        })?;
+        let mut c: Vec<F> = [F::zero()]
+            .into_iter()
+            .chain(self.y.iter().copied())
+            .chain([F::zero()])
+            .collect();
+        lu.solve(&mut c);
+        Ok(c)
+    }
+
+    /// Generalized cross-validation criterion of the fit with `lam`: the mean squared residual
+    /// divided by `(1 - tr(A) / n)^2` for the influence matrix `A`.
+    ///
+    /// The trace of `A` is that of `(B^T W B + lam * P)^{-1} B^T W B`, of which only the band is
+    /// needed.
+    fn gcv(&self, lam: F) -> F {
+        let Ok((lu, c)) = self.solve(lam) else {
+            return F::infinity();
+        };
+        let n = F::from(self.y.len()).unwrap();
+        let residuals = self
+            .rows
+            .iter()
+            .zip(&self.y)
+            .map(|(&(l, h), &y)| {
+                let fit = h
+                    .iter()
+                    .zip(&c[l - 3..=l])
+                    .fold(F::zero(), |acc, (&h, &c)| acc + h * c);
+                (y - fit).powi(2)
+            })
+            .fold(F::zero(), |acc, r| acc + r);
+
+        let m = c.len();
+        let mut trace = F::zero();
+        let mut column = vec![F::zero(); m];
+        for j in 0..m {
+            column.fill(F::zero());
+            column[j] = F::one();
+            lu.solve(&mut column);
+            for (i, &z) in column
+                .iter()
+                .enumerate()
+                .take((j + 4).min(m))
+                .skip(j.saturating_sub(3))
+            {
+                trace = trace + z * Self::entry(&self.gram, i, j);
+            }
+        }
+        residuals / n / (F::one() - trace / n).powi(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{arr1, Array1};
+
+    fn data() -> (Vec<f64>, Array1<f64>) {
+        let x: Vec<f64> = (0..12)
+            .map(|i| i as f64 * 0.5 + 0.01 * (i * i) as f64)
+            .collect();
+        let y = x
+            .iter()
+            .enumerate()
+            .map(|(i, x)| x.sin() + 0.2 * (2.5 * i as f64).cos())
+            .collect();
+        (x, y)
+    }
+
+    #[test]
+    fn limits() {
+        let (x, y) = data();
+        // Natural interpolating spline.
+        let spl = make_smoothing_spline(&x, &y, None, Some(0.)).unwrap();
+        for (s, y) in spl.call(&x, None, None).iter().zip(&y) {
+            assert_abs_diff_eq!(s, y, epsilon = 1e-10);
+        }
+        for s in spl.call(&[x[0], x[11]], Some(2), None) {
+            assert_abs_diff_eq!(s, 0., epsilon = 1e-9);
+        }
+
+        // Weighted regression line.
+        let w: Vec<f64> = (0..12).map(|i| 1. + (i % 3) as f64).collect();
+        let spl = make_smoothing_spline(&x, &y, Some(&w), Some(1e8)).unwrap();
+        let sum = |f: &dyn Fn(usize) -> f64| (0..12).map(f).sum::<f64>();
+        let (sw, sx, sy) = (sum(&|i| w[i]), sum(&|i| w[i] * x[i]), sum(&|i| w[i] * y[i]));
+        let (sxx, sxy) = (sum(&|i| w[i] * x[i] * x[i]), sum(&|i| w[i] * x[i] * y[i]));
+        let slope = (sw * sxy - sx * sy) / (sw * sxx - sx * sx);
+        let intercept = (sy - slope * sx) / sw;
+        for (s, x) in spl.call(&x, None, None).iter().zip(&x) {
+            assert_abs_diff_eq!(*s, intercept + slope * x, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn jump_conditions() {
+        // The third derivative jumps by `w[i] * (y[i] - f(x[i])) / lam` at the interior points.
+        let (x, y) = data();
+        let w: Vec<f64> = (0..12).map(|i| 1. + (i % 4) as f64 * 0.5).collect();
+        let lam = 0.3;
+        let spl = make_smoothing_spline(&x, &y, Some(&w), Some(lam)).unwrap();
+        let values = spl.call(&x, None, None);
+        for i in 1..11 {
+            let after = spl.call(&[x[i]], Some(3), None)[0];
+            let before = spl.call(&[x[i] - 1e-9], Some(3), None)[0];
+            assert_abs_diff_eq!(
+                lam * (after - before),
+                w[i] * (y[i] - values[i]),
+                epsilon = 1e-5
+            );
+        }
+    }
+
+    #[test]
+    fn generalized_cross_validation() {
+        let (x, y) = data();
+        let fit = PenalizedFit::new(&x, &y, None);
+        let lam = minimize_scalar(|lam| fit.gcv(lam), None, Some((0., 12.)), None, None, None)
+            .unwrap()
+            .x;
+        assert!(lam > 1e-3);
+        for scale in [0.9, 1.1] {
+            assert!(fit.gcv(lam) <= fit.gcv(lam * scale));
+        }
+        let chosen = make_smoothing_spline(&x, &y, None, None).unwrap();
+        let fixed = make_smoothing_spline(&x, &y, None, Some(lam)).unwrap();
+        for (a, b) in chosen.c().iter().zip(fixed.c()) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let (x, y) = data();
+        let spline = |x: &[f64], y: &Array1<f64>, lam| make_smoothing_spline(x, y, None, lam);
+        assert!(spline(&x[..4], &y.slice(ndarray::s![..4]).to_owned(), None).is_err());
+        assert!(spline(&x[..11], &y, None).is_err());
+        assert!(spline(&x, &y, Some(-1.)).is_err());
+        let mut unsorted = x.clone();
+        unsorted.swap(3, 4);
+        assert!(spline(&unsorted, &y, None).is_err());
+        assert!(spline(
+            &[0., 1., 2., 3., 4.],
+            &arr1(&[1., 2., 0., 1., 3.]),
+            Some(1.)
+        )
+        .is_ok());
+    }
+}
@@ -38,6 +38,37 @@ pub mod stats;
 /// Special math functions
 pub mod special;
 
+/// Integration
+#[cfg(feature = "alloc")]
+pub mod integrate;
+
+/// Interpolation
+#[cfg(feature = "alloc")]
+pub mod interpolate;
+
+/// Multidimensional image processing
+#[cfg(feature = "alloc")]
+pub mod ndimage;
+
+/// Optimization and root finding
+#[cfg(feature = "alloc")]
+pub mod optimize;
+
+/// Sparse matrices
+#[cfg(feature = "alloc")]
+pub mod sparse;
+
+/// Spatial data structures
+#[cfg(feature = "alloc")]
+pub mod spatial;
+
+/// Seedable random number generation
+pub mod random;
+
+/// Discrete Fourier transforms
+#[cfg(feature = "std")]
+pub mod fft;
+
 /// Debug plotting
 #[cfg(feature = "plot")]
 pub mod plot;
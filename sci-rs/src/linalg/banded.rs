@@ -0,0 +1,438 @@
+use alloc::{format, vec, vec::Vec};
+use ndarray::{Array, Array2, ArrayBase, Data, Dimension, Ix2};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// LU factorization with partial pivoting of a square banded matrix.
+///
+/// The factors are stored in the band layout of LAPACK's `gbtrf`: entry `(i, j)` of the matrix
+/// is kept in row `kl + ku + i - j` of a `(2 * kl + ku + 1) x n` array, the extra `kl` rows
+/// holding the fill-in of the row interchanges.
+#[derive(Debug, Clone)]
+pub(crate) struct BandedLu<F> {
+    n: usize,
+    kl: usize,
+    ku: usize,
+    ab: Vec<F>,
+    ipiv: Vec<usize>,
+}
+
+impl<F: Float> BandedLu<F> {
+    /// Factor the `n x n` matrix with `kl` sub-diagonals and `ku` super-diagonals whose entries
+    /// within the band are given by `a(i, j)`.
+    ///
+    /// Fails if the matrix is singular.
+    pub(crate) fn new(
+        n: usize,
+        kl: usize,
+        ku: usize,
+        a: impl Fn(usize, usize) -> F,
+    ) -> Result<Self> {
+        let kv = kl + ku;
+        let mut lu = BandedLu {
+            n,
+            kl,
+            ku,
+            ab: vec![F::zero(); (2 * kl + ku + 1) * n],
+            ipiv: vec![0; n],
+        };
+        for j in 0..n {
+            for i in j.saturating_sub(ku)..(j + kl + 1).min(n) {
+                *lu.at(i, j) = a(i, j);
+            }
+        }
+
+        // Index of the last column touched by the interchanges so far.
+        let mut ju = 0;
+        for j in 0..n {
+            let km = kl.min(n - 1 - j);
+            let p = (0..=km)
+                .max_by(|&p, &q| {
+                    let (vp, vq) = (lu.ab[(kv + p) * n + j].abs(), lu.ab[(kv + q) * n + j].abs());
+                    // Keep the first of equal entries, like `i_amax`.
+                    vp.partial_cmp(&vq)
+                        .unwrap_or(core::cmp::Ordering::Equal)
+                        .then(q.cmp(&p))
+                })
+                .unwrap_or(0);
+            lu.ipiv[j] = j + p;
+            let pivot = lu.ab[(kv + p) * n + j];
+            if pivot == F::zero() || pivot.is_nan() {
+                return Err(Error::InvalidArg {
+                    arg: "a".into(),
+                    reason: "Matrix is singular.".into(),
+                });
+            }
+            ju = ju.max((j + ku + p).min(n - 1));
+            if p != 0 {
+                for c in j..=ju {
+                    let (r, s) = ((kv + j - c) * n + c, (kv + j + p - c) * n + c);
+                    lu.ab.swap(r, s);
+                }
+            }
+            for i in 1..=km {
+                lu.ab[(kv + i) * n + j] = lu.ab[(kv + i) * n + j] / pivot;
+            }
+            for c in j + 1..=ju {
+                let u = lu.ab[(kv + j - c) * n + c];
+                if u != F::zero() {
+                    for i in 1..=km {
+                        let l = lu.ab[(kv + i) * n + j];
+                        let e = &mut lu.ab[(kv + j + i - c) * n + c];
+                        *e = *e - l * u;
+                    }
+                }
+            }
+        }
+        Ok(lu)
+    }
+
+    /// Entry `(i, j)` of the band.
+    fn at(&mut self, i: usize, j: usize) -> &mut F {
+        &mut self.ab[(self.kl + self.ku + i - j) * self.n + j]
+    }
+
+    /// Solve `A x = b` in place.
+    pub(crate) fn solve(&self, b: &mut [F]) {
+        let (n, kl) = (self.n, self.kl);
+        let kv = self.kl + self.ku;
+        for j in 0..n.saturating_sub(1) {
+            let l = self.ipiv[j];
+            b.swap(j, l);
+            for i in 1..=kl.min(n - 1 - j) {
+                b[j + i] = b[j + i] - self.ab[(kv + i) * n + j] * b[j];
+            }
+        }
+        for j in (0..n).rev() {
+            b[j] = b[j] / self.ab[kv * n + j];
+            for i in j.saturating_sub(kv)..j {
+                b[i] = b[i] - self.ab[(kv + i - j) * n + j] * b[j];
+            }
+        }
+    }
+}
+
+/// Check the band storage `ab` of `rows` rows and the right-hand side `b`, returning `b` as a
+/// matrix of its columns.
+fn check_band<F, S, T, D>(
+    ab: &ArrayBase<S, Ix2>,
+    rows: usize,
+    b: &ArrayBase<T, D>,
+) -> Result<Array2<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+    D: Dimension,
+{
+    if ab.nrows() != rows {
+        return Err(Error::InvalidArg {
+            arg: "ab".into(),
+            reason: format!(
+                "invalid values for the number of lower and upper diagonals: expected {rows} rows."
+            ),
+        });
+    }
+    let n = ab.ncols();
+    if b.ndim() == 0 || b.ndim() > 2 || b.shape()[0] != n {
+        return Err(Error::InvalidArg {
+            arg: "b".into(),
+            reason: "must be a vector or matrix with as many rows as ab has columns.".into(),
+        });
+    }
+    if ab.iter().chain(b.iter()).any(|v| !v.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "ab".into(),
+            reason: "array must not contain infs or NaNs.".into(),
+        });
+    }
+    let k = if b.ndim() == 2 { b.shape()[1] } else { 1 };
+    Ok(Array2::from_shape_vec((n, k), b.iter().copied().collect()).unwrap())
+}
+
+/// Solve the equation `a x = b` for `x`, assuming `a` is banded matrix.
+///
+/// The matrix `a` is stored in `ab` using the matrix diagonal ordered form:
+/// `ab[u + i - j, j] == a[i, j]`.
+///
+/// ## Parameters
+/// * `l_and_u`: Number of non-zero lower and upper diagonals.
+/// * `ab`: Banded matrix, of shape `(l + u + 1, m)`.
+/// * `b`: Right-hand side, a vector of length `m` or a matrix of several right hand sides as
+///   columns.
+///
+/// ## Returns
+/// The solution to the system `a x = b`, of the shape of `b`.
+///
+/// ## Errors
+/// If `ab` does not have `l + u + 1` rows, `b` does not have as many rows as `ab` columns,
+/// either contains infs or NaNs, or if `a` is singular.
+///
+/// ## Examples
+/// Solve the banded system `a x = b`, where
+/// ```text
+///     [5  2 -1  0  0]       [0]
+///     [1  4  2 -1  0]       [1]
+/// a = [0  1  3  2 -1]   b = [2]
+///     [0  0  1  2  2]       [2]
+///     [0  0  0  1  1]       [3]
+/// ```
+/// There is one nonzero diagonal below the main diagonal (`l = 1`), and two above (`u = 2`).
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import solve_banded
+/// ab = np.array([[0,  0, -1, -1, -1],
+///                [0,  2,  2,  2,  2],
+///                [5,  4,  3,  2,  1],
+///                [1,  1,  1,  1,  0]])
+/// b = np.array([0, 1, 2, 2, 3])
+/// x = solve_banded((1, 2), ab, b)
+/// x
+/// # array([-2.37288136,  3.93220339, -4.        ,  4.3559322 , -1.3559322 ])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::solve_banded;
+///
+/// let ab = array![
+///     [0., 0., -1., -1., -1.],
+///     [0., 2., 2., 2., 2.],
+///     [5., 4., 3., 2., 1.],
+///     [1., 1., 1., 1., 0.]
+/// ];
+/// let b = array![0., 1., 2., 2., 3.];
+/// let x = solve_banded((1, 2), &ab, &b).unwrap();
+/// for (x, e) in x.iter().zip([-2.37288136, 3.93220339, -4., 4.3559322, -1.3559322]) {
+///     assert_abs_diff_eq!(*x, e, epsilon = 1e-8);
+/// }
+/// ```
+pub fn solve_banded<F, S, T, D>(
+    l_and_u: (usize, usize),
+    ab: &ArrayBase<S, Ix2>,
+    b: &ArrayBase<T, D>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+    D: Dimension,
+{
+    let (l, u) = l_and_u;
+    let mut x = check_band(ab, l + u + 1, b)?;
+    let lu = BandedLu::new(ab.ncols(), l, u, |i, j| ab[[u + i - j, j]])?;
+    for mut column in x.columns_mut() {
+        let mut c = column.to_vec();
+        lu.solve(&mut c);
+        column.assign(&ndarray::ArrayView1::from(&c));
+    }
+    Ok(x.into_shape_with_order(b.raw_dim()).unwrap())
+}
+
+/// Solve equation `a x = b`, where `a` is a Hermitian positive-definite banded matrix.
+///
+/// The matrix `a` is stored in `ab` either in lower diagonal or upper diagonal ordered form:
+/// `ab[u + i - j, j] == a[i, j]` if upper form (`i <= j`), and `ab[i - j, j] == a[i, j]` if
+/// lower form (`i >= j`).
+///
+/// ## Parameters
+/// * `ab`: Banded matrix, of shape `(u + 1, m)`.
+/// * `b`: Right-hand side, a vector of length `m` or a matrix of several right hand sides as
+///   columns.
+/// * `lower`: Whether the matrix is in the lower form. `false` by default, for the upper
+///   form.
+///
+/// ## Returns
+/// The solution to the system `a x = b`, of the shape of `b`.
+///
+/// ## Errors
+/// If `b` does not have as many rows as `ab` columns, either contains infs or NaNs, or if
+/// `a` is not positive definite.
+///
+/// ## Notes
+/// The solution comes from the banded Cholesky factorization `a = L L^T`, in `O(m u^2)`
+/// operations.
+///
+/// ## Examples
+/// Solve the banded system `a x = b`, where
+/// ```text
+///     [ 4  2 -1  0  0  0]       [1]
+///     [ 2  5  2 -1  0  0]       [2]
+/// a = [-1  2  6  2 -1  0]   b = [2]
+///     [ 0 -1  2  7  2 -1]       [3]
+///     [ 0  0 -1  2  8  2]       [3]
+///     [ 0  0  0 -1  2  9]       [3]
+/// ```
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import solveh_banded
+/// ab = np.array([[ 0,  0, -1, -1, -1, -1],
+///                [ 0,  2,  2,  2,  2,  2],
+///                [ 4,  5,  6,  7, 8, 9]])
+/// b = np.array([1, 2, 2, 3, 3, 3])
+/// x = solveh_banded(ab, b)
+/// x
+/// # array([ 0.03431373,  0.45938375,  0.05602241,  0.47759104,  0.17577031,
+/// #         0.34733894])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::solveh_banded;
+///
+/// let ab = array![
+///     [0., 0., -1., -1., -1., -1.],
+///     [0., 2., 2., 2., 2., 2.],
+///     [4., 5., 6., 7., 8., 9.]
+/// ];
+/// let b = array![1., 2., 2., 3., 3., 3.];
+/// let x = solveh_banded(&ab, &b, None).unwrap();
+/// let expected = [0.03431373, 0.45938375, 0.05602241, 0.47759104, 0.17577031, 0.34733894];
+/// for (x, e) in x.iter().zip(expected) {
+///     assert_abs_diff_eq!(*x, e, epsilon = 1e-8);
+/// }
+/// ```
+pub fn solveh_banded<F, S, T, D>(
+    ab: &ArrayBase<S, Ix2>,
+    b: &ArrayBase<T, D>,
+    lower: Option<bool>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+    D: Dimension,
+{
+    if ab.nrows() == 0 {
+        return Err(Error::InvalidArg {
+            arg: "ab".into(),
+            reason: "must have at least one row.".into(),
+        });
+    }
+    let u = ab.nrows() - 1;
+    let mut x = check_band(ab, u + 1, b)?;
+    let n = ab.ncols();
+    // Entry (i, j) of a, for j <= i <= j + u.
+    let a = |i: usize, j: usize| {
+        if lower.unwrap_or(false) {
+            ab[[i - j, j]]
+        } else {
+            ab[[u + j - i, i]]
+        }
+    };
+
+    // Lower triangular Cholesky factor, with entry (i, j) in l[(i - j) * n + j].
+    let mut l = vec![F::zero(); (u + 1) * n];
+    for j in 0..n {
+        for i in j..(j + u + 1).min(n) {
+            let sum = (i.saturating_sub(u)..j).fold(a(i, j), |acc, k| {
+                acc - l[(i - k) * n + k] * l[(j - k) * n + k]
+            });
+            if i == j {
+                if sum <= F::zero() {
+                    return Err(Error::InvalidArg {
+                        arg: "ab".into(),
+                        reason: format!(
+                            "leading minor of order {} is not positive definite.",
+                            j + 1
+                        ),
+                    });
+                }
+                l[j] = sum.sqrt();
+            } else {
+                l[(i - j) * n + j] = sum / l[j];
+            }
+        }
+    }
+
+    for mut column in x.columns_mut() {
+        // Forward substitution with L, then back substitution with L^T.
+        for i in 0..n {
+            let sum = (i.saturating_sub(u)..i)
+                .fold(column[i], |acc, k| acc - l[(i - k) * n + k] * column[k]);
+            column[i] = sum / l[i];
+        }
+        for i in (0..n).rev() {
+            let sum = (i + 1..(i + u + 1).min(n))
+                .fold(column[i], |acc, k| acc - l[(k - i) * n + i] * column[k]);
+            column[i] = sum / l[i];
+        }
+    }
+    Ok(x.into_shape_with_order(b.raw_dim()).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn pivoting() {
+        // Tridiagonal-plus-one matrix whose first diagonal entry forces an interchange.
+        let a = [
+            [0.0, 2.0, 1.0, 0.0, 0.0],
+            [3.0, 1.0, -1.0, 2.0, 0.0],
+            [0.0, 4.0, 2.0, 1.0, 5.0],
+            [0.0, 0.0, 1.0, -3.0, 2.0],
+            [0.0, 0.0, 0.0, 2.0, 1.0],
+        ];
+        let lu = BandedLu::new(5, 1, 2, |i, j| a[i][j]).unwrap();
+        let x = [1.0, -2.0, 0.5, 3.0, -1.0];
+        let mut b: Vec<f64> = a
+            .iter()
+            .map(|row| row.iter().zip(x).map(|(a, x)| a * x).sum())
+            .collect();
+        lu.solve(&mut b);
+        for (b, x) in b.iter().zip(x) {
+            assert_abs_diff_eq!(*b, x, epsilon = 1e-12);
+        }
+
+        assert!(BandedLu::new(2, 1, 1, |_, _| 1.0).is_err());
+    }
+
+    #[test]
+    fn band_storage() {
+        // Pentadiagonal symmetric positive definite matrix.
+        let n = 6;
+        let a = Array2::from_shape_fn((n, n), |(i, j)| match i.abs_diff(j) {
+            0 => 6.0 + i as f64,
+            1 => -1.5,
+            2 => 0.5,
+            _ => 0.0,
+        });
+        let b = Array2::from_shape_fn((n, 2), |(i, j)| (i + 3 * j) as f64 - 2.0);
+        let upper = Array2::from_shape_fn((3, n), |(r, j)| match (j + r).checked_sub(2) {
+            Some(i) => a[[i, j]],
+            None => 0.0,
+        });
+        let lower =
+            Array2::from_shape_fn((3, n), |(r, j)| if r + j < n { a[[r + j, j]] } else { 0.0 });
+        let general = Array2::from_shape_fn((5, n), |(r, j)| match (j + r).checked_sub(2) {
+            Some(i) if i < n => a[[i, j]],
+            _ => 0.0,
+        });
+        for x in [
+            solveh_banded(&upper, &b, None).unwrap(),
+            solveh_banded(&lower, &b, Some(true)).unwrap(),
+            solve_banded((2, 2), &general, &b).unwrap(),
+        ] {
+            for (ax, b) in a.dot(&x).iter().zip(&b) {
+                assert_abs_diff_eq!(*ax, *b, epsilon = 1e-12);
+            }
+        }
+
+        let indefinite = ndarray::array![[0.0, 3.0, 3.0], [1.0, 1.0, 1.0]];
+        assert_eq!(
+            solveh_banded(&indefinite, &ndarray::array![1.0, 1.0, 1.0], None),
+            Err(Error::InvalidArg {
+                arg: "ab".into(),
+                reason: "leading minor of order 2 is not positive definite.".into(),
+            })
+        );
+        assert!(solve_banded((1, 1), &general, &b).is_err());
+    }
+}
@@ -0,0 +1,933 @@
+use alloc::{format, vec::Vec};
+use ndarray::{Array, Array1, Array2, ArrayBase, ArrayView2, Axis, Data, Dimension, Ix2};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Check that `a` is square and finite, and that `b` has as many rows as `a`, returning `b`
+/// as a matrix of its columns.
+fn check_system<F, S, T, D>(a: &ArrayBase<S, Ix2>, b: &ArrayBase<T, D>) -> Result<Array2<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+    D: Dimension,
+{
+    let n = a.nrows();
+    if a.ncols() != n {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "expected square matrix.".into(),
+        });
+    }
+    if b.ndim() == 0 || b.ndim() > 2 || b.shape()[0] != n {
+        return Err(Error::InvalidArg {
+            arg: "b".into(),
+            reason: "must be a vector or matrix with as many rows as a.".into(),
+        });
+    }
+    if a.iter().chain(b.iter()).any(|v| !v.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "array must not contain infs or NaNs.".into(),
+        });
+    }
+    let k = if b.ndim() == 2 { b.shape()[1] } else { 1 };
+    Ok(Array2::from_shape_vec((n, k), b.iter().copied().collect()).unwrap())
+}
+
+/// Solve in place the triangular systems `A x = b` of the columns of `b`, or `A^T x = b` if
+/// `trans`, where `A` is the lower or upper triangle of `a`.
+fn substitute<F: Float>(
+    a: ArrayView2<F>,
+    b: &mut Array2<F>,
+    lower: bool,
+    trans: bool,
+    unit_diagonal: bool,
+) -> Result<()> {
+    let n = a.nrows();
+    let coef = |i: usize, j: usize| if trans { a[[j, i]] } else { a[[i, j]] };
+    // The transpose of a lower triangular matrix is upper triangular.
+    let forward = lower != trans;
+    for step in 0..n {
+        let i = if forward { step } else { n - 1 - step };
+        let diagonal = if unit_diagonal { F::one() } else { coef(i, i) };
+        if diagonal == F::zero() {
+            return Err(Error::InvalidArg {
+                arg: "a".into(),
+                reason: format!("singular matrix: resolution failed at diagonal {i}."),
+            });
+        }
+        let known = if forward { 0..i } else { i + 1..n };
+        for c in 0..b.ncols() {
+            let sum = known
+                .clone()
+                .fold(b[[i, c]], |acc, j| acc - coef(i, j) * b[[j, c]]);
+            b[[i, c]] = sum / diagonal;
+        }
+    }
+    Ok(())
+}
+
+/// LU factorization with partial pivoting of a matrix, as computed by [`lu_factor`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LuFactor<F> {
+    /// Matrix containing `U` in its upper triangle, and `L` in its lower triangle, whose unit
+    /// diagonal is not stored.
+    pub lu: Array2<F>,
+    /// Pivot indices: row `i` of the matrix was interchanged with row `piv[i]`.
+    pub piv: Vec<usize>,
+}
+
+impl<F: Float> LuFactor<F> {
+    /// Factorize `P A = L U`, with `L` unit lower triangular and `U` upper triangular. A zero
+    /// pivot leaves its column as is, giving a singular `U`.
+    fn new(a: ArrayView2<F>) -> Self {
+        let (m, n) = a.dim();
+        let mut lu = a.to_owned();
+        let mut piv = Vec::with_capacity(m.min(n));
+        for k in 0..m.min(n) {
+            // The first of the largest entries of the column, like `i_amax`.
+            let p = (k..m).fold(k, |p, i| {
+                if lu[[i, k]].abs() > lu[[p, k]].abs() {
+                    i
+                } else {
+                    p
+                }
+            });
+            piv.push(p);
+            if p != k {
+                for j in 0..n {
+                    lu.swap([k, j], [p, j]);
+                }
+            }
+            let pivot = lu[[k, k]];
+            if pivot.is_zero() {
+                continue;
+            }
+            for i in k + 1..m {
+                let l = lu[[i, k]] / pivot;
+                lu[[i, k]] = l;
+                for j in k + 1..n {
+                    lu[[i, j]] = lu[[i, j]] - l * lu[[k, j]];
+                }
+            }
+        }
+        LuFactor { lu, piv }
+    }
+
+    /// Row `i` of `P A` is row `perm[i]` of `A`.
+    fn permutation(&self) -> Vec<usize> {
+        let mut perm: Vec<usize> = (0..self.lu.nrows()).collect();
+        for (i, &p) in self.piv.iter().enumerate() {
+            perm.swap(i, p);
+        }
+        perm
+    }
+
+    /// Solve in place `A x = b`, or `A^T x = b` if `trans`, for the columns of `b`.
+    fn solve(&self, b: &mut Array2<F>, trans: bool) -> Result<()> {
+        let lu = self.lu.view();
+        if trans {
+            // A^T = U^T L^T P.
+            substitute(lu, b, false, true, false)?;
+            substitute(lu, b, true, true, true)?;
+            for (i, &p) in self.piv.iter().enumerate().rev() {
+                for c in 0..b.ncols() {
+                    b.swap([i, c], [p, c]);
+                }
+            }
+        } else {
+            for (i, &p) in self.piv.iter().enumerate() {
+                for c in 0..b.ncols() {
+                    b.swap([i, c], [p, c]);
+                }
+            }
+            substitute(lu, b, true, false, true)?;
+            substitute(lu, b, false, false, false)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check that `a` is square and finite.
+fn check_square<F: Float, S: Data<Elem = F>>(a: &ArrayBase<S, Ix2>) -> Result<()> {
+    if a.ncols() != a.nrows() {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "expected square matrix.".into(),
+        });
+    }
+    if a.iter().any(|v| !v.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "array must not contain infs or NaNs.".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Solves the linear equation set `a @ x == b` for the unknown `x` for square `a` matrix.
+///
+/// ## Parameters
+/// * `a`: Square input data.
+/// * `b`: Input data for the right hand side, a vector or a matrix of several right hand
+///   sides as columns.
+/// * `transposed`: If `true`, solve `a.T @ x == b` instead. `false` by default.
+///
+/// ## Returns
+/// The solution `x`, of the shape of `b`.
+///
+/// ## Errors
+/// If `a` is not square, `b` does not have as many rows as `a`, either contains infs or NaNs,
+/// or if `a` is singular.
+///
+/// ## Notes
+/// The solution comes from the LU factorization with partial pivoting of `a`, which fails if
+/// a pivot is exactly zero, see [`lu_factor`]. A nearly singular `a` gives an inaccurate
+/// solution without failing.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import linalg
+/// a = np.array([[3, 2, 0], [1, -1, 0], [0, 5, 1]])
+/// b = np.array([2, 4, -1])
+/// linalg.solve(a, b)
+/// # array([ 2., -2.,  9.])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::solve;
+///
+/// let a = array![[3., 2., 0.], [1., -1., 0.], [0., 5., 1.]];
+/// let b = array![2., 4., -1.];
+/// let x = solve(&a, &b, None).unwrap();
+/// for (x, e) in x.iter().zip([2., -2., 9.]) {
+///     assert_abs_diff_eq!(*x, e, epsilon = 1e-14);
+/// }
+/// assert!(solve(&array![[1., 2.], [2., 4.]], &array![1., 1.], None).is_err());
+/// ```
+pub fn solve<F, S, T, D>(
+    a: &ArrayBase<S, Ix2>,
+    b: &ArrayBase<T, D>,
+    transposed: Option<bool>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+    D: Dimension,
+{
+    let mut x = check_system(a, b)?;
+    LuFactor::new(a.view()).solve(&mut x, transposed.unwrap_or(false))?;
+    Ok(x.into_shape_with_order(b.raw_dim()).unwrap())
+}
+
+/// Solve the equation `a x = b` for `x`, assuming `a` is a triangular matrix.
+///
+/// ## Parameters
+/// * `a`: A triangular matrix.
+/// * `b`: Right-hand side matrix in `a x = b`, a vector or a matrix of several right hand
+///   sides as columns.
+/// * `trans`: If `true`, solve `a^T x = b` instead. `false` by default.
+/// * `lower`: Use only data contained in the lower triangle of `a`. `false` by default, using
+///   only the upper triangle.
+/// * `unit_diagonal`: If `true`, the diagonal elements of `a` are assumed to be 1 and will
+///   not be referenced. `false` by default.
+///
+/// ## Returns
+/// The solution `x`, of the shape of `b`.
+///
+/// ## Errors
+/// If `a` is not square, `b` does not have as many rows as `a`, either contains infs or NaNs,
+/// or if `a` is singular, with a zero on its diagonal.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import solve_triangular
+/// a = np.array([[3, 0, 0, 0], [2, 1, 0, 0], [1, 0, 1, 0], [1, 1, 1, 1]])
+/// b = np.array([4, 2, 4, 2])
+/// solve_triangular(a, b, lower=True)
+/// # array([ 1.33333333, -0.66666667,  2.66666667, -1.33333333])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::solve_triangular;
+///
+/// let a = array![[3., 0., 0., 0.], [2., 1., 0., 0.], [1., 0., 1., 0.], [1., 1., 1., 1.]];
+/// let b = array![4., 2., 4., 2.];
+/// let x = solve_triangular(&a, &b, None, Some(true), None).unwrap();
+/// for (x, e) in x.iter().zip([4. / 3., -2. / 3., 8. / 3., -4. / 3.]) {
+///     assert_abs_diff_eq!(*x, e, epsilon = 1e-14);
+/// }
+/// ```
+pub fn solve_triangular<F, S, T, D>(
+    a: &ArrayBase<S, Ix2>,
+    b: &ArrayBase<T, D>,
+    trans: Option<bool>,
+    lower: Option<bool>,
+    unit_diagonal: Option<bool>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+    D: Dimension,
+{
+    let mut x = check_system(a, b)?;
+    substitute(
+        a.view(),
+        &mut x,
+        lower.unwrap_or(false),
+        trans.unwrap_or(false),
+        unit_diagonal.unwrap_or(false),
+    )?;
+    Ok(x.into_shape_with_order(b.raw_dim()).unwrap())
+}
+
+/// Result of [`lu`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LuResult<F> {
+    /// Permutation matrix, of shape `(m, m)`.
+    pub p: Array2<F>,
+    /// Lower triangular matrix with unit diagonal, of shape `(m, k)` with `k = min(m, n)`.
+    pub l: Array2<F>,
+    /// Upper triangular matrix, of shape `(k, n)`.
+    pub u: Array2<F>,
+}
+
+/// Compute LU decomposition of a matrix with partial pivoting.
+///
+/// The decomposition is `a = p @ l @ u`, where `p` is a permutation matrix, `l` lower
+/// triangular with unit diagonal elements, and `u` upper triangular.
+///
+/// ## Parameters
+/// * `a`: Array to decompose, of shape `(m, n)`.
+///
+/// ## Returns
+/// The [`LuResult`] with the matrices `p`, `l` and `u`.
+///
+/// ## Errors
+/// If `a` contains infs or NaNs.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import lu
+/// A = np.array([[2, 5, 8, 7], [5, 2, 2, 8], [7, 5, 6, 6], [5, 4, 4, 8]])
+/// p, l, u = lu(A)
+/// np.allclose(A, p @ l @ u)
+/// # True
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::lu;
+///
+/// let a = array![[2., 5., 8., 7.], [5., 2., 2., 8.], [7., 5., 6., 6.], [5., 4., 4., 8.]];
+/// let lu = lu(&a).unwrap();
+/// for (plu, a) in lu.p.dot(&lu.l).dot(&lu.u).iter().zip(&a) {
+///     assert_abs_diff_eq!(*plu, *a, epsilon = 1e-14);
+/// }
+/// ```
+pub fn lu<F, S>(a: &ArrayBase<S, Ix2>) -> Result<LuResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    if a.iter().any(|v| !v.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "array must not contain infs or NaNs.".into(),
+        });
+    }
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let factor = LuFactor::new(a.view());
+    let mut p = Array2::zeros((m, m));
+    for (i, &r) in factor.permutation().iter().enumerate() {
+        p[[r, i]] = F::one();
+    }
+    let lu = &factor.lu;
+    let l = Array2::from_shape_fn((m, k), |(i, j)| match i.cmp(&j) {
+        core::cmp::Ordering::Greater => lu[[i, j]],
+        core::cmp::Ordering::Equal => F::one(),
+        core::cmp::Ordering::Less => F::zero(),
+    });
+    let u = Array2::from_shape_fn((k, n), |(i, j)| if i <= j { lu[[i, j]] } else { F::zero() });
+    Ok(LuResult { p, l, u })
+}
+
+/// Compute pivoted LU decomposition of a matrix.
+///
+/// The decomposition is `A = P L U`, where `P` is a permutation matrix, `L` lower triangular
+/// with unit diagonal elements, and `U` upper triangular.
+///
+/// ## Parameters
+/// * `a`: Square matrix to decompose.
+///
+/// ## Returns
+/// The [`LuFactor`], for [`lu_solve`].
+///
+/// ## Errors
+/// If `a` is not square or contains infs or NaNs.
+///
+/// ## Notes
+/// A singular matrix is factorized without error, with a zero on the diagonal of `U`, and
+/// fails when solving with [`lu_solve`].
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import lu_factor
+/// A = np.array([[2, 5, 8, 7], [5, 2, 2, 8], [7, 5, 6, 6], [5, 4, 4, 8]])
+/// lu, piv = lu_factor(A)
+/// piv
+/// # array([2, 2, 3, 3], dtype=int32)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::linalg::lu_factor;
+///
+/// let a = array![[2., 5., 8., 7.], [5., 2., 2., 8.], [7., 5., 6., 6.], [5., 4., 4., 8.]];
+/// let lu = lu_factor(&a).unwrap();
+/// assert_eq!(lu.piv, vec![2, 2, 3, 3]);
+/// ```
+pub fn lu_factor<F, S>(a: &ArrayBase<S, Ix2>) -> Result<LuFactor<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    check_square(a)?;
+    Ok(LuFactor::new(a.view()))
+}
+
+/// Solve an equation system, `a x = b`, given the LU factorization of `a`.
+///
+/// ## Parameters
+/// * `lu_and_piv`: Factorization of the coefficient matrix `a`, as given by [`lu_factor`].
+/// * `b`: Right-hand side, a vector or a matrix of several right hand sides as columns.
+/// * `trans`: If `true`, solve `a^T x = b` instead. `false` by default.
+///
+/// ## Returns
+/// The solution `x`, of the shape of `b`.
+///
+/// ## Errors
+/// If `b` does not have as many rows as `a`, contains infs or NaNs, or if `a` is singular.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import lu_factor, lu_solve
+/// A = np.array([[2, 5, 8, 7], [5, 2, 2, 8], [7, 5, 6, 6], [5, 4, 4, 8]])
+/// b = np.array([1, 1, 1, 1])
+/// lu, piv = lu_factor(A)
+/// x = lu_solve((lu, piv), b)
+/// np.allclose(A @ x - b, np.zeros((4,)))
+/// # True
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::{lu_factor, lu_solve};
+///
+/// let a = array![[2., 5., 8., 7.], [5., 2., 2., 8.], [7., 5., 6., 6.], [5., 4., 4., 8.]];
+/// let b = array![1., 1., 1., 1.];
+/// let lu = lu_factor(&a).unwrap();
+/// let x = lu_solve(&lu, &b, None).unwrap();
+/// for ax in a.dot(&x) {
+///     assert_abs_diff_eq!(ax, 1., epsilon = 1e-14);
+/// }
+/// ```
+pub fn lu_solve<F, T, D>(
+    lu_and_piv: &LuFactor<F>,
+    b: &ArrayBase<T, D>,
+    trans: Option<bool>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    T: Data<Elem = F>,
+    D: Dimension,
+{
+    let mut x = check_system(&lu_and_piv.lu, b)?;
+    lu_and_piv.solve(&mut x, trans.unwrap_or(false))?;
+    Ok(x.into_shape_with_order(b.raw_dim()).unwrap())
+}
+
+/// Compute the determinant of a matrix.
+///
+/// ## Parameters
+/// * `a`: Square matrix.
+///
+/// ## Returns
+/// The determinant of `a`, from its LU factorization.
+///
+/// ## Errors
+/// If `a` is not square or contains infs or NaNs.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import linalg
+/// a = np.array([[0,2,3], [4,5,6], [7,8,9]])
+/// linalg.det(a)
+/// # 3.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::det;
+///
+/// let a = array![[0., 2., 3.], [4., 5., 6.], [7., 8., 9.]];
+/// assert_abs_diff_eq!(det(&a).unwrap(), 3., epsilon = 1e-14);
+/// ```
+pub fn det<F, S>(a: &ArrayBase<S, Ix2>) -> Result<F>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    check_square(a)?;
+    let factor = LuFactor::new(a.view());
+    let sign = factor
+        .piv
+        .iter()
+        .enumerate()
+        .fold(F::one(), |sign, (i, &p)| if p == i { sign } else { -sign });
+    Ok(factor.lu.diag().fold(sign, |det, &u| det * u))
+}
+
+/// Compute the inverse of a matrix.
+///
+/// ## Parameters
+/// * `a`: Square matrix to be inverted.
+///
+/// ## Returns
+/// The inverse of the matrix `a`.
+///
+/// ## Errors
+/// If `a` is not square, contains infs or NaNs, or if it is singular.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import linalg
+/// a = np.array([[1., 2.], [3., 4.]])
+/// linalg.inv(a)
+/// # array([[-2. ,  1. ],
+/// #        [ 1.5, -0.5]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::inv;
+///
+/// let a = array![[1., 2.], [3., 4.]];
+/// let ainv = inv(&a).unwrap();
+/// for (x, e) in ainv.iter().zip([-2., 1., 1.5, -0.5]) {
+///     assert_abs_diff_eq!(*x, e, epsilon = 1e-14);
+/// }
+/// ```
+pub fn inv<F, S>(a: &ArrayBase<S, Ix2>) -> Result<Array2<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    check_square(a)?;
+    let mut x = Array2::eye(a.nrows());
+    LuFactor::new(a.view()).solve(&mut x, false)?;
+    Ok(x)
+}
+
+fn inner<'a, F: Float + 'a>(
+    x: impl IntoIterator<Item = &'a F>,
+    y: impl IntoIterator<Item = &'a F>,
+) -> F {
+    x.into_iter()
+        .zip(y)
+        .fold(F::zero(), |acc, (&x, &y)| acc + x * y)
+}
+
+/// Singular value decomposition `M = W V^T` of a matrix with at least as many rows as columns
+/// by the one-sided Jacobi method, which orthogonalizes the columns of `M` by plane rotations
+/// until they are orthogonal to machine precision. The columns of `W` are the left singular
+/// vectors scaled by the singular values, and both factors are ordered by decreasing singular
+/// values.
+pub(super) fn jacobi_svd<F: Float>(m: ArrayView2<F>) -> (Array2<F>, Array2<F>, Array1<F>) {
+    let n = m.ncols();
+    let mut w = m.to_owned();
+    let mut v = Array2::<F>::eye(n);
+    let eps = F::epsilon();
+    let rotate = |m: &mut Array2<F>, p: usize, q: usize, c: F, s: F| {
+        for mut row in m.rows_mut() {
+            let (mp, mq) = (row[p], row[q]);
+            row[p] = c * mp - s * mq;
+            row[q] = s * mp + c * mq;
+        }
+    };
+    for _ in 0..64 {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (cp, cq) = (w.column(p), w.column(q));
+                let alpha = inner(cp, cp);
+                let beta = inner(cq, cq);
+                let gamma = inner(cp, cq);
+                if gamma.is_zero() || gamma.abs() <= eps * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let t = zeta.signum() / (zeta.abs() + F::one().hypot(zeta));
+                let c = F::one() / F::one().hypot(t);
+                rotate(&mut w, p, q, c, c * t);
+                rotate(&mut v, p, q, c, c * t);
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let norms: Vec<F> = w
+        .columns()
+        .into_iter()
+        .map(|c| inner(c, c).sqrt())
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| {
+        norms[j]
+            .partial_cmp(&norms[i])
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+    (
+        w.select(Axis(1), &order),
+        v.select(Axis(1), &order),
+        order.iter().map(|&j| norms[j]).collect(),
+    )
+}
+
+/// Result of [`lstsq`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LstsqResult<F, D: Dimension> {
+    /// Least-squares solution, with a row per column of `a` and the columns of `b`.
+    pub solution: Array<F, D>,
+    /// Squared 2-norm of the residual of each column of `b`, only computed if `a` has more
+    /// rows than columns and full rank, and empty otherwise.
+    pub residuals: Array1<F>,
+    /// Effective rank of `a`.
+    pub rank: usize,
+    /// Singular values of `a`, in decreasing order.
+    pub singular_values: Array1<F>,
+}
+
+/// Compute least-squares solution to equation `a @ x = b`.
+///
+/// Compute a vector `x` such that the 2-norm `|b - a x|` is minimized.
+///
+/// ## Parameters
+/// * `a`: Left-hand side matrix, of shape `(m, n)`.
+/// * `b`: Right-hand side, a vector of length `m` or a matrix of several right hand sides as
+///   `m` rows.
+/// * `rcond`: Cutoff for the small singular values of `a`, relative to the largest one.
+///   Singular values below are treated as zero. Machine epsilon times `max(m, n)` by default.
+///
+/// ## Returns
+/// The [`LstsqResult`] with the solution, of `n` rows, the residuals, rank and singular values
+/// of `a`.
+///
+/// ## Errors
+/// If `a` is empty, `b` does not have as many rows as `a`, either contains infs or NaNs, or if
+/// `rcond` is negative.
+///
+/// ## Notes
+/// Among the minimizers of `|b - a x|`, the solution is the one of minimal norm, from the
+/// singular value decomposition of `a` by the one-sided Jacobi method.
+///
+/// ## Examples
+/// Fit a quadratic polynomial of the form `y = a + b x^2` to data.
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import lstsq
+/// x = np.array([1, 2.5, 3.5, 4, 5, 7, 8.5])
+/// y = np.array([0.3, 1.1, 1.5, 2.0, 3.2, 6.6, 8.6])
+/// M = x[:, np.newaxis]**[0, 2]
+/// p, res, rnk, s = lstsq(M, y)
+/// p
+/// # array([ 0.20925829,  0.12013861])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{array, Array2};
+/// use sci_rs::linalg::lstsq;
+///
+/// let x: [f64; 7] = [1., 2.5, 3.5, 4., 5., 7., 8.5];
+/// let y = array![0.3, 1.1, 1.5, 2.0, 3.2, 6.6, 8.6];
+/// let m = Array2::from_shape_fn((7, 2), |(i, j)| x[i].powi(2 * j as i32));
+/// let result = lstsq(&m, &y, None).unwrap();
+/// assert_abs_diff_eq!(result.solution[0], 0.20925829, epsilon = 1e-8);
+/// assert_abs_diff_eq!(result.solution[1], 0.12013861, epsilon = 1e-8);
+/// assert_eq!(result.rank, 2);
+/// ```
+pub fn lstsq<F, S, T, D>(
+    a: &ArrayBase<S, Ix2>,
+    b: &ArrayBase<T, D>,
+    rcond: Option<F>,
+) -> Result<LstsqResult<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+    D: Dimension,
+{
+    let (m, n) = a.dim();
+    if m == 0 || n == 0 {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "must not be empty.".into(),
+        });
+    }
+    if b.ndim() == 0 || b.ndim() > 2 || b.shape()[0] != m {
+        return Err(Error::InvalidArg {
+            arg: "b".into(),
+            reason: "must be a vector or matrix with as many rows as a.".into(),
+        });
+    }
+    if a.iter().chain(b.iter()).any(|v| !v.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "array must not contain infs or NaNs.".into(),
+        });
+    }
+    let rcond = rcond.unwrap_or(F::epsilon() * F::from(m.max(n)).unwrap());
+    if rcond < F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "rcond".into(),
+            reason: "must be non-negative.".into(),
+        });
+    }
+    let k = if b.ndim() == 2 { b.shape()[1] } else { 1 };
+    let bm = Array2::from_shape_vec((m, k), b.iter().copied().collect()).unwrap();
+
+    // With `a = U S V^T` and `W = U S`, the minimal norm solution is
+    // `V S^+ U^T b = sum_j v_j (w_j . b) / s_j^2` over the singular values above the cutoff,
+    // and decomposing `a^T` instead swaps the roles of the factors.
+    let (w, v, s) = jacobi_svd(if m >= n { a.view() } else { a.t() });
+    let cutoff = rcond * s[0];
+    let rank = s.iter().take_while(|&&s| s > cutoff).count();
+    let mut x = Array2::<F>::zeros((n, k));
+    for j in 0..rank {
+        let (into, onto) = if m >= n {
+            (v.column(j), w.column(j))
+        } else {
+            (w.column(j), v.column(j))
+        };
+        let s2 = s[j] * s[j];
+        for c in 0..k {
+            let scale = inner(onto, bm.column(c)) / s2;
+            for (xi, &vi) in x.column_mut(c).iter_mut().zip(&into) {
+                *xi = *xi + vi * scale;
+            }
+        }
+    }
+
+    let residuals = if rank == n && m > n {
+        (0..k)
+            .map(|c| {
+                a.rows()
+                    .into_iter()
+                    .zip(bm.column(c))
+                    .map(|(row, &bi)| bi - inner(row, x.column(c)))
+                    .fold(F::zero(), |acc, r| acc + r * r)
+            })
+            .collect()
+    } else {
+        Array1::zeros(0)
+    };
+    let mut shape = b.raw_dim();
+    shape[0] = n;
+    Ok(LstsqResult {
+        solution: x.into_shape_with_order(shape).unwrap(),
+        residuals,
+        rank,
+        singular_values: s,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, s};
+
+    fn assert_solves(a: &Array2<f64>, x: &Array2<f64>, b: &Array2<f64>) {
+        for (ax, b) in a.dot(x).iter().zip(b) {
+            assert_abs_diff_eq!(*ax, *b, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn general() {
+        // The first pivot is zero without the interchanges.
+        let a = array![
+            [0., 2., 1., -1.],
+            [3., 1., -1., 2.],
+            [1., 4., 2., 1.],
+            [-2., 0., 1., 3.]
+        ];
+        let b = array![[1., 0.], [2., -1.], [0., 3.], [4., 1.]];
+        let x = solve(&a, &b, None).unwrap();
+        assert_eq!(x.dim(), (4, 2));
+        assert_solves(&a, &x, &b);
+        let x = solve(&a, &b, Some(true)).unwrap();
+        assert_solves(&a.t().to_owned(), &x, &b);
+
+        // A vector right hand side keeps its shape.
+        let x = solve(&a, &b.column(1), None).unwrap();
+        assert_eq!(x.dim(), 4);
+        for (ax, b) in a.dot(&x).iter().zip(b.column(1)) {
+            assert_abs_diff_eq!(*ax, *b, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn triangular() {
+        let a = array![[2., 1., -1.], [4., 3., 2.], [-1., 5., 0.5]];
+        let b = array![[1.], [2.], [3.]];
+        for (lower, trans, unit) in [
+            (false, false, false),
+            (true, false, false),
+            (false, true, false),
+            (true, true, true),
+        ] {
+            // The triangle of a which is used, as a full matrix.
+            let t = Array2::from_shape_fn((3, 3), |(i, j)| match (i, j) {
+                _ if i == j && unit => 1.,
+                _ if (i >= j) == lower || i == j => a[[i, j]],
+                _ => 0.,
+            });
+            let x = solve_triangular(&a, &b, Some(trans), Some(lower), Some(unit)).unwrap();
+            let t = if trans { t.t().to_owned() } else { t };
+            assert_solves(&t, &x, &b);
+        }
+    }
+
+    #[test]
+    fn invalid_systems() {
+        let a = array![[1., 2.], [2., 4.]];
+        let b = array![1., 1.];
+        assert!(solve(&a, &b, None).is_err());
+        assert!(solve(&array![[1., 2., 3.]], &b, None).is_err());
+        assert!(solve(&array![[1., 0.], [0., 1.]], &array![1., 2., 3.], None).is_err());
+        assert!(solve(&array![[1., 0.], [0., f64::NAN]], &b, None).is_err());
+        let upper = array![[1., 2.], [0., 0.]];
+        assert_eq!(
+            solve_triangular(&upper, &b, None, None, None),
+            Err(Error::InvalidArg {
+                arg: "a".into(),
+                reason: "singular matrix: resolution failed at diagonal 1.".into(),
+            })
+        );
+        assert!(solve_triangular(&upper, &b, None, None, Some(true)).is_ok());
+    }
+
+    #[test]
+    fn factorizations() {
+        let a = array![
+            [0., 2., 1., -1.],
+            [3., 1., -1., 2.],
+            [1., 4., 2., 1.],
+            [-2., 0., 1., 3.]
+        ];
+        let lu_a = lu_factor(&a).unwrap();
+        let b = array![[1., 0.], [2., -1.], [0., 3.], [4., 1.]];
+        assert_solves(&a, &lu_solve(&lu_a, &b, None).unwrap(), &b);
+        let x = lu_solve(&lu_a, &b, Some(true)).unwrap();
+        assert_solves(&a.t().to_owned(), &x, &b);
+        assert_solves(&a, &inv(&a).unwrap(), &Array2::eye(4));
+        assert_abs_diff_eq!(det(&a).unwrap(), -14., epsilon = 1e-12);
+
+        // Rectangular matrices, in both orientations.
+        for a in [a.slice(s![.., ..3]), a.slice(s![..3, ..])] {
+            let LuResult { p, l, u } = lu(&a).unwrap();
+            for (plu, a) in p.dot(&l).dot(&u).iter().zip(&a) {
+                assert_abs_diff_eq!(*plu, *a, epsilon = 1e-14);
+            }
+            assert_eq!(l.dim(), (a.nrows(), 3));
+            assert_eq!(u.dim(), (3, a.ncols()));
+        }
+
+        // Singular matrices factorize, but do not solve.
+        let singular = array![[1., 2., 3.], [2., 4., 6.], [1., 0., 1.]];
+        assert_eq!(det(&singular).unwrap(), 0.);
+        assert!(inv(&singular).is_err());
+        assert!(lu_solve(&lu_factor(&singular).unwrap(), &array![1., 1., 1.], None).is_err());
+        assert!(det(&array![[1., 2.]]).is_err());
+    }
+
+    #[test]
+    fn least_squares() {
+        // Overdetermined with full rank: the residual is orthogonal to the columns of a.
+        let a = array![[1., 1.], [1., 2.], [1., 3.], [1., 4.]];
+        let b = array![[6., 1.], [5., 0.], [7., 2.], [10., 1.]];
+        let result = lstsq(&a, &b, None).unwrap();
+        assert_eq!(result.rank, 2);
+        for (x, e) in result.solution.iter().zip([3.5, 0.5, 1.4, 0.2]) {
+            assert_abs_diff_eq!(*x, e, epsilon = 1e-12);
+        }
+        for (r, e) in result.residuals.iter().zip([4.2, 1.8]) {
+            assert_abs_diff_eq!(*r, e, epsilon = 1e-12);
+        }
+        let r = &b - &a.dot(&result.solution);
+        for v in a.t().dot(&r) {
+            assert_abs_diff_eq!(v, 0., epsilon = 1e-12);
+        }
+        let s = &result.singular_values;
+        assert!(s[0] >= s[1]);
+        assert_abs_diff_eq!(s[0] * s[0] + s[1] * s[1], 34., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn minimal_norm() {
+        // Underdetermined: the solution of minimal norm.
+        let a = array![[1., 1., 1.]];
+        let result = lstsq(&a, &array![3.], None).unwrap();
+        assert_eq!(result.rank, 1);
+        assert_eq!(result.residuals.len(), 0);
+        for x in &result.solution {
+            assert_abs_diff_eq!(*x, 1., epsilon = 1e-14);
+        }
+
+        // Rank deficient: the duplicated column shares the coefficient.
+        let a = array![[1., 1., 0.], [1., 1., 1.], [1., 1., 2.]];
+        let result = lstsq(&a, &array![1., 2., 3.], None).unwrap();
+        assert_eq!(result.rank, 2);
+        assert_eq!(result.residuals.len(), 0);
+        assert_abs_diff_eq!(result.singular_values[2], 0., epsilon = 1e-14);
+        for (x, e) in result.solution.iter().zip([0.5, 0.5, 1.]) {
+            assert_abs_diff_eq!(*x, e, epsilon = 1e-12);
+        }
+
+        assert!(lstsq(&a, &array![1., 2.], None).is_err());
+        assert!(lstsq(&a, &array![1., 2., 3.], Some(-1.)).is_err());
+    }
+}
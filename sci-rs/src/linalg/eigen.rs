@@ -0,0 +1,364 @@
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+use nalgebra::{Complex, DMatrix, RealField, Schur, SymmetricEigen};
+use ndarray::{Array1, Array2, ArrayBase, Data, Ix2};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Check that `a` is square and finite, and copy it into a nalgebra matrix.
+fn to_dmatrix<F, S>(a: &ArrayBase<S, Ix2>) -> Result<DMatrix<F>>
+where
+    F: Float + RealField,
+    S: Data<Elem = F>,
+{
+    let n = a.nrows();
+    if a.ncols() != n {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "expected square matrix.".into(),
+        });
+    }
+    if a.iter().any(|v| !Float::is_finite(*v)) {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "array must not contain infs or NaNs.".into(),
+        });
+    }
+    Ok(DMatrix::from_fn(n, n, |i, j| a[[i, j]]))
+}
+
+/// Result of [`eig`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigResult<F> {
+    /// The eigenvalues, each repeated according to its multiplicity, with the complex
+    /// conjugate pairs next to each other, the one of positive imaginary part first.
+    pub eigenvalues: Array1<Complex<F>>,
+    /// The normalized eigenvectors, the column `eigenvectors[:, i]` corresponding to the
+    /// eigenvalue `eigenvalues[i]`.
+    pub eigenvectors: Array2<Complex<F>>,
+}
+
+/// Solve an ordinary eigenvalue problem of a square matrix.
+///
+/// Find eigenvalues `w` and right eigenvectors `v` of the matrix `a`, such that
+/// `a @ v[:, i] = w[i] * v[:, i]`.
+///
+/// ## Parameters
+/// * `a`: A real square matrix.
+///
+/// ## Returns
+/// The [`EigResult`] with the eigenvalues and eigenvectors.
+///
+/// ## Errors
+/// If `a` is not square, contains infs or NaNs, or if the QR iterations do not converge.
+///
+/// ## Notes
+/// The matrix is reduced to Hessenberg form, then to its real Schur form `a = Q T Q^T` by the
+/// implicit double-shift QR algorithm, with the eigenvalues on the `1x1` and `2x2` diagonal
+/// blocks of `T`. The eigenvectors are found by back substitution in `T`, and normalized to
+/// unit Euclidean norm with their largest component real. Nearly singular back substitutions,
+/// for repeated eigenvalues, are perturbed like LAPACK's `trevc`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import linalg
+/// a = np.array([[0., -1.], [1., 0.]])
+/// linalg.eigvals(a)
+/// # array([0.+1.j, 0.-1.j])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::eig;
+///
+/// let a = array![[0., -1.], [1., 0.]];
+/// let result = eig(&a).unwrap();
+/// for (w, e) in result.eigenvalues.iter().zip([1., -1.]) {
+///     assert_abs_diff_eq!(w.re, 0., epsilon = 1e-15);
+///     assert_abs_diff_eq!(w.im, e, epsilon = 1e-15);
+/// }
+/// ```
+pub fn eig<F, S>(a: &ArrayBase<S, Ix2>) -> Result<EigResult<F>>
+where
+    F: Float + RealField,
+    S: Data<Elem = F>,
+{
+    let m = to_dmatrix(a)?;
+    let n = m.nrows();
+    let norm = m.iter().fold(F::zero(), |acc, &x| acc + Float::abs(x));
+    let Some(schur) = Schur::try_new(m, Float::epsilon(), 100 * n.max(1)) else {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "the QR iterations did not converge.".into(),
+        });
+    };
+    let (q, t) = schur.unpack();
+    let zero = Complex::new(F::zero(), F::zero());
+    // Smallest perturbation of the diagonal of the back substitution.
+    let smin = Float::max(
+        <F as Float>::epsilon() * norm,
+        <F as Float>::min_positive_value(),
+    );
+
+    // The diagonal blocks of T, as their first row and size.
+    let mut blocks: Vec<(usize, usize)> = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let size = if i + 1 < n && !t[(i + 1, i)].is_zero() {
+            2
+        } else {
+            1
+        };
+        blocks.push((i, size));
+        i += size;
+    }
+
+    let mut eigenvalues = Array1::from_elem(n, zero);
+    let mut eigenvectors = Array2::from_elem((n, n), zero);
+    for (b, &(start, size)) in blocks.iter().enumerate() {
+        let lambdas: Vec<Complex<F>> = if size == 1 {
+            vec![Complex::new(t[(start, start)], F::zero())]
+        } else {
+            let (hmm, hmn) = (t[(start, start)], t[(start, start + 1)]);
+            let (hnm, hnn) = (t[(start + 1, start)], t[(start + 1, start + 1)]);
+            let half_diff = (hmm - hnn) / (F::one() + F::one());
+            let half_trace = (hmm + hnn) / (F::one() + F::one());
+            let discr = hnm * hmn + half_diff * half_diff;
+            let sqrt = if discr < F::zero() {
+                Complex::new(F::zero(), Float::sqrt(-discr))
+            } else {
+                Complex::new(Float::sqrt(discr), F::zero())
+            };
+            vec![
+                Complex::new(half_trace, F::zero()) + sqrt,
+                Complex::new(half_trace, F::zero()) - sqrt,
+            ]
+        };
+
+        for (offset, &lambda) in lambdas.iter().enumerate() {
+            let column = start + offset;
+            eigenvalues[column] = lambda;
+            // Eigenvector y of T, zero below the block.
+            let mut y = vec![zero; n];
+            if size == 1 {
+                y[start] = Complex::new(F::one(), F::zero());
+            } else {
+                let (hmm, hmn) = (t[(start, start)], t[(start, start + 1)]);
+                let (hnm, hnn) = (t[(start + 1, start)], t[(start + 1, start + 1)]);
+                let first = (Complex::new(hmn, F::zero()), lambda - hmm);
+                (y[start], y[start + 1]) = if first.0.norm() + first.1.norm() > F::zero() {
+                    first
+                } else {
+                    (lambda - hnn, Complex::new(hnm, F::zero()))
+                };
+            }
+            let end = start + size;
+            for &(row, rows) in blocks[..b].iter().rev() {
+                let rhs = |i: usize| (row + rows..end).fold(zero, |acc, j| acc - y[j] * t[(i, j)]);
+                let shifted = |i: usize, j: usize| {
+                    let d = Complex::new(t[(i, j)], F::zero());
+                    if i == j {
+                        d - lambda
+                    } else {
+                        d
+                    }
+                };
+                let perturb = |d: Complex<F>| {
+                    if d.norm() < smin {
+                        Complex::new(smin, F::zero())
+                    } else {
+                        d
+                    }
+                };
+                if rows == 1 {
+                    y[row] = rhs(row) / perturb(shifted(row, row));
+                } else {
+                    let (r0, r1) = (rhs(row), rhs(row + 1));
+                    let (m00, m01) = (shifted(row, row), shifted(row, row + 1));
+                    let (m10, m11) = (shifted(row + 1, row), shifted(row + 1, row + 1));
+                    let det = perturb(m00 * m11 - m01 * m10);
+                    y[row] = (r0 * m11 - m01 * r1) / det;
+                    y[row + 1] = (m00 * r1 - m10 * r0) / det;
+                }
+            }
+
+            // Eigenvector Q y of a, of unit norm and largest component real.
+            let x: Vec<Complex<F>> = (0..n)
+                .map(|i| (0..end).fold(zero, |acc, j| acc + y[j] * q[(i, j)]))
+                .collect();
+            let largest = (0..n).fold(0, |p, i| if x[i].norm() > x[p].norm() { i } else { p });
+            let length = Float::sqrt(x.iter().fold(F::zero(), |acc, x| acc + x.norm_sqr()));
+            let phase = x[largest].conj() / (x[largest].norm() * length);
+            for (v, x) in eigenvectors.column_mut(column).iter_mut().zip(x) {
+                *v = x * phase;
+            }
+            eigenvectors[[largest, column]].im = F::zero();
+        }
+    }
+    Ok(EigResult {
+        eigenvalues,
+        eigenvectors,
+    })
+}
+
+/// Result of [`eigh`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EighResult<F> {
+    /// The eigenvalues in ascending order, each repeated according to its multiplicity.
+    pub eigenvalues: Array1<F>,
+    /// The orthonormal eigenvectors, the column `eigenvectors[:, i]` corresponding to the
+    /// eigenvalue `eigenvalues[i]`.
+    pub eigenvectors: Array2<F>,
+}
+
+/// Solve a standard eigenvalue problem for a real symmetric matrix.
+///
+/// Find eigenvalues `w` and eigenvectors `v` of the symmetric matrix `a`, such that
+/// `a @ v[:, i] = w[i] * v[:, i]`.
+///
+/// ## Parameters
+/// * `a`: A real symmetric matrix, of which only one triangle is used.
+/// * `lower`: Whether the relevant array data is taken from the lower or upper triangle of
+///   `a`. `true` by default, for the lower triangle.
+///
+/// ## Returns
+/// The [`EighResult`] with the eigenvalues and eigenvectors.
+///
+/// ## Errors
+/// If `a` is not square, contains infs or NaNs, or if the QL iterations do not converge.
+///
+/// ## Notes
+/// The matrix is reduced to symmetric tridiagonal form by Householder reflections, then
+/// diagonalized by the implicit QL/QR algorithm with Wilkinson shifts.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import eigh
+/// A = np.array([[6, 3, 1, 5], [3, 0, 5, 1], [1, 5, 6, 2], [5, 1, 2, 2]])
+/// w, v = eigh(A)
+/// np.allclose(A @ v - v @ np.diag(w), np.zeros((4, 4)))
+/// # True
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{array, Array2};
+/// use sci_rs::linalg::eigh;
+///
+/// let a = array![[6., 3., 1., 5.], [3., 0., 5., 1.], [1., 5., 6., 2.], [5., 1., 2., 2.]];
+/// let result = eigh(&a, None).unwrap();
+/// let (w, v) = (&result.eigenvalues, &result.eigenvectors);
+/// let residual = a.dot(v) - v.dot(&Array2::from_diag(w));
+/// for r in residual {
+///     assert_abs_diff_eq!(r, 0., epsilon = 1e-13);
+/// }
+/// ```
+pub fn eigh<F, S>(a: &ArrayBase<S, Ix2>, lower: Option<bool>) -> Result<EighResult<F>>
+where
+    F: Float + RealField,
+    S: Data<Elem = F>,
+{
+    let m = to_dmatrix(a)?;
+    let n = m.nrows();
+    let lower = lower.unwrap_or(true);
+    let m = DMatrix::from_fn(n, n, |i, j| {
+        if (i >= j) == lower {
+            m[(i, j)]
+        } else {
+            m[(j, i)]
+        }
+    });
+    let Some(eigen) = SymmetricEigen::try_new(m, Float::epsilon(), 100 * n.max(1)) else {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "the QL iterations did not converge.".into(),
+        });
+    };
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| {
+        eigen.eigenvalues[i]
+            .partial_cmp(&eigen.eigenvalues[j])
+            .unwrap_or(Ordering::Equal)
+    });
+    Ok(EighResult {
+        eigenvalues: order.iter().map(|&i| eigen.eigenvalues[i]).collect(),
+        eigenvectors: Array2::from_shape_fn((n, n), |(i, k)| eigen.eigenvectors[(i, order[k])]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    fn assert_eigenpairs(a: &Array2<f64>, result: &EigResult<f64>) {
+        let n = a.nrows();
+        for (k, &w) in result.eigenvalues.iter().enumerate() {
+            let v = result.eigenvectors.column(k);
+            let length: f64 = v.iter().map(|v| v.norm_sqr()).sum();
+            assert_abs_diff_eq!(length, 1., epsilon = 1e-14);
+            for i in 0..n {
+                let av = (0..n).fold(Complex::new(0., 0.), |acc, j| acc + v[j] * a[[i, j]]);
+                let residual = av - v[i] * w;
+                assert!(residual.norm() < 1e-12, "{k}: {residual}");
+            }
+        }
+    }
+
+    #[test]
+    fn real_and_complex_eigenvalues() {
+        let a = array![
+            [4., -2., 1., 0., 3.],
+            [1., 1., 0., 2., -1.],
+            [0., 3., -2., 1., 1.],
+            [2., 0., 1., 5., 0.],
+            [-1., 1., 2., 0., 1.]
+        ];
+        let result = eig(&a).unwrap();
+        assert_eigenpairs(&a, &result);
+        let w = &result.eigenvalues;
+        let trace: f64 = w.iter().map(|w| w.re).sum();
+        assert_abs_diff_eq!(trace, 9., epsilon = 1e-12);
+        assert_abs_diff_eq!(w.iter().map(|w| w.im).sum::<f64>(), 0., epsilon = 1e-12);
+        assert!(w.iter().any(|w| w.im > 0.));
+    }
+
+    #[test]
+    fn repeated_eigenvalues() {
+        // Defective, with a single eigenvector.
+        let a = array![[2., 1., 0.], [0., 2., 1.], [0., 0., 2.]];
+        let result = eig(&a).unwrap();
+        assert_eigenpairs(&a, &result);
+        for w in &result.eigenvalues {
+            assert_abs_diff_eq!(w.re, 2., epsilon = 1e-14);
+        }
+        assert_eigenpairs(&Array2::eye(3), &eig(&Array2::eye(3)).unwrap());
+        assert!(eig(&array![[1., 2.]]).is_err());
+    }
+
+    #[test]
+    fn symmetric() {
+        let a = array![[2., 1., 0.], [1., 2., 1.], [0., 1., 2.]];
+        let result = eigh(&a, None).unwrap();
+        let sqrt2 = 2f64.sqrt();
+        for (w, e) in result.eigenvalues.iter().zip([2. - sqrt2, 2., 2. + sqrt2]) {
+            assert_abs_diff_eq!(*w, e, epsilon = 1e-14);
+        }
+        let v = &result.eigenvectors;
+        for (vtv, e) in v.t().dot(v).iter().zip(&Array2::<f64>::eye(3)) {
+            assert_abs_diff_eq!(*vtv, *e, epsilon = 1e-14);
+        }
+        // Only one triangle is read.
+        let upper = array![[2., 1., 0.], [9., 2., 1.], [9., 9., 2.]];
+        assert_eq!(
+            eigh(&upper, Some(false)).unwrap().eigenvalues,
+            result.eigenvalues
+        );
+    }
+}
@@ -0,0 +1,619 @@
+use super::solve;
+use core::ops::Mul;
+use nalgebra::{Complex, DMatrix, RealField, Schur};
+use ndarray::{Array2, ArrayBase, Data, Ix2, LinalgScalar};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+fn check_square<F: Float, S: Data<Elem = F>>(a: &ArrayBase<S, Ix2>) -> Result<()> {
+    if a.ncols() != a.nrows() {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "expected square matrix.".into(),
+        });
+    }
+    if a.iter().any(|v| !v.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "array must not contain infs or NaNs.".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Coefficients of the numerators of the `[m/m]` Padé approximants of the exponential.
+const PADE_3: [f64; 4] = [120., 60., 12., 1.];
+const PADE_5: [f64; 6] = [30240., 15120., 3360., 420., 30., 1.];
+const PADE_7: [f64; 8] = [
+    17297280., 8648640., 1995840., 277200., 25200., 1512., 56., 1.,
+];
+const PADE_9: [f64; 10] = [
+    17643225600.,
+    8821612800.,
+    2075673600.,
+    302702400.,
+    30270240.,
+    2162160.,
+    110880.,
+    3960.,
+    90.,
+    1.,
+];
+const PADE_13: [f64; 14] = [
+    64764752532480000.,
+    32382376266240000.,
+    7771770303897600.,
+    1187353796428800.,
+    129060195264000.,
+    10559470521600.,
+    670442572800.,
+    33522128640.,
+    1323241920.,
+    40840800.,
+    960960.,
+    16380.,
+    182.,
+    1.,
+];
+/// Largest 1-norms for which the approximants of degrees 3 to 9 are accurate to double
+/// precision, and the one to which the degree 13 is scaled.
+const THETA: [f64; 4] = [
+    1.495585217958292e-2,
+    2.53939833006323e-1,
+    9.504178996162932e-1,
+    2.097847961257068,
+];
+const THETA_13: f64 = 5.371920351148152;
+
+/// Exponential of `a`, of 1-norm `norm1`, by scaling and squaring of the Padé approximants,
+/// where `solve(q, p)` solves `q x = p`.
+fn exp_pade<T, F>(
+    a: &Array2<T>,
+    norm1: F,
+    solve: impl Fn(&Array2<T>, &Array2<T>) -> Result<Array2<T>>,
+) -> Result<Array2<T>>
+where
+    F: Float,
+    T: LinalgScalar + Mul<F, Output = T>,
+{
+    let n = a.nrows();
+    let ident = Array2::<T>::eye(n);
+    let scaled = |m: &Array2<T>, c: f64| m.mapv(|x| x * F::from(c).unwrap());
+    let a2 = a.dot(a);
+    let coefficients: [&[f64]; 4] = [&PADE_3, &PADE_5, &PADE_7, &PADE_9];
+    for (b, theta) in coefficients.into_iter().zip(THETA) {
+        if norm1 <= F::from(theta).unwrap() {
+            // Odd powers in u and even ones in v, for the approximant (v - u)^-1 (v + u).
+            let mut u = scaled(&ident, b[1]);
+            let mut v = scaled(&ident, b[0]);
+            let mut power = ident.clone();
+            for k in 1..b.len() / 2 {
+                power = power.dot(&a2);
+                u = u + scaled(&power, b[2 * k + 1]);
+                v = v + scaled(&power, b[2 * k]);
+            }
+            let u = a.dot(&u);
+            return solve(&(&v - &u), &(&v + &u));
+        }
+    }
+
+    let s = Float::max(
+        (norm1 / F::from(THETA_13).unwrap()).log2().ceil(),
+        F::zero(),
+    );
+    let s = s.to_i32().unwrap();
+    let scale = F::from(2).unwrap().powi(-s);
+    let a = a.mapv(|x| x * scale);
+    let scale2 = scale * scale;
+    let a2 = a2.mapv(|x| x * scale2);
+    let a4 = a2.dot(&a2);
+    let a6 = a4.dot(&a2);
+    let b = PADE_13;
+    let u = a.dot(
+        &(a6.dot(&(scaled(&a6, b[13]) + scaled(&a4, b[11]) + scaled(&a2, b[9])))
+            + scaled(&a6, b[7])
+            + scaled(&a4, b[5])
+            + scaled(&a2, b[3])
+            + scaled(&ident, b[1])),
+    );
+    let v = a6.dot(&(scaled(&a6, b[12]) + scaled(&a4, b[10]) + scaled(&a2, b[8])))
+        + scaled(&a6, b[6])
+        + scaled(&a4, b[4])
+        + scaled(&a2, b[2])
+        + scaled(&ident, b[0]);
+    let mut r = solve(&(&v - &u), &(&v + &u))?;
+    for _ in 0..s {
+        r = r.dot(&r);
+    }
+    Ok(r)
+}
+
+/// Compute the matrix exponential of an array.
+///
+/// ## Parameters
+/// * `a`: Square matrix.
+///
+/// ## Returns
+/// The matrix exponential of `a`, `sum(a^k / k!)`.
+///
+/// ## Errors
+/// If `a` is not square or contains infs or NaNs.
+///
+/// ## Notes
+/// Implements the scaling and squaring algorithm of Higham: the Padé approximant of the
+/// lowest degree accurate to double precision at the 1-norm of `a` is used, and above the
+/// range of the degree 13, `a` is scaled by a power of 2 and the approximant squared back.
+///
+/// ## References
+/// Awad H. Al-Mohy and Nicholas J. Higham (2009) "A New Scaling and Squaring Algorithm for
+/// the Matrix Exponential." SIAM Journal on Matrix Analysis and Applications. 31 (3).
+/// pp. 970-989. ISSN 1095-7162
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import expm
+/// expm(np.array([[1.0, 2.0], [-1.0, 3.0]]))
+/// # array([[-2.22535226, 12.43535262],
+/// #        [-6.21767631, 10.21000036]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::expm;
+///
+/// let e = expm(&array![[1.0, 2.0], [-1.0, 3.0]]).unwrap();
+/// for (e, x) in e.iter().zip([-2.22535226, 12.43535262, -6.21767631, 10.21000036]) {
+///     assert_abs_diff_eq!(*e, x, epsilon = 1e-7);
+/// }
+/// ```
+pub fn expm<F, S>(a: &ArrayBase<S, Ix2>) -> Result<Array2<F>>
+where
+    F: Float + 'static,
+    S: Data<Elem = F>,
+{
+    check_square(a)?;
+    let norm1 = a
+        .columns()
+        .into_iter()
+        .map(|c| c.fold(F::zero(), |acc, &x| acc + x.abs()))
+        .fold(F::zero(), F::max);
+    exp_pade(&a.to_owned(), norm1, |q, p| solve(q, p, None))
+}
+
+/// Result of the matrix functions [`sqrtm`], [`logm`] and [`funm`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatfuncResult<F> {
+    /// Value of the matrix function.
+    pub value: Array2<Complex<F>>,
+    /// Estimate of the relative error of the value. Large estimates signal ill-conditioned
+    /// problems, for which the value may be inaccurate.
+    pub errest: F,
+}
+
+impl<F: Float> MatfuncResult<F> {
+    /// The real part of the value, if its imaginary parts are all negligible, as the
+    /// function of a real matrix often is.
+    pub fn real(&self) -> Option<Array2<F>> {
+        let tol = F::epsilon() * F::from(1e6).unwrap();
+        self.value
+            .iter()
+            .all(|z| z.im.abs() <= tol)
+            .then(|| self.value.mapv(|z| z.re))
+    }
+}
+
+/// Factors `Q` and `T` of a complex Schur decomposition.
+type ComplexSchur<F> = (Array2<Complex<F>>, Array2<Complex<F>>);
+
+/// Complex Schur decomposition `a = Q T Q^H`, with `T` upper triangular.
+fn complex_schur<F, S>(a: &ArrayBase<S, Ix2>) -> Result<ComplexSchur<F>>
+where
+    F: Float + RealField,
+    S: Data<Elem = F>,
+{
+    check_square(a)?;
+    let n = a.nrows();
+    let m = DMatrix::from_fn(n, n, |i, j| Complex::new(a[[i, j]], F::zero()));
+    let Some(schur) = Schur::try_new(m, Float::epsilon(), 100 * n.max(1)) else {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "the QR iterations did not converge.".into(),
+        });
+    };
+    let (q, t) = schur.unpack();
+    Ok((
+        Array2::from_shape_fn((n, n), |(i, j)| q[(i, j)]),
+        Array2::from_shape_fn((n, n), |(i, j)| {
+            if i <= j {
+                t[(i, j)]
+            } else {
+                Complex::new(F::zero(), F::zero())
+            }
+        }),
+    ))
+}
+
+/// `q x q^H`.
+fn unitary_similarity<F: Float + 'static>(
+    q: &Array2<Complex<F>>,
+    x: &Array2<Complex<F>>,
+) -> Array2<Complex<F>> {
+    q.dot(x).dot(&q.t().mapv(|z| z.conj()))
+}
+
+/// Solve `u x = b` for upper triangular `u`.
+fn solve_upper<F: Float>(
+    u: &Array2<Complex<F>>,
+    b: &Array2<Complex<F>>,
+) -> Result<Array2<Complex<F>>> {
+    let n = u.nrows();
+    let mut x = b.clone();
+    for i in (0..n).rev() {
+        if u[[i, i]].norm().is_zero() {
+            return Err(Error::InvalidArg {
+                arg: "a".into(),
+                reason: "singular matrix.".into(),
+            });
+        }
+        for c in 0..x.ncols() {
+            let sum = (i + 1..n).fold(x[[i, c]], |acc, j| acc - u[[i, j]] * x[[j, c]]);
+            x[[i, c]] = sum / u[[i, i]];
+        }
+    }
+    Ok(x)
+}
+
+/// Principal square root of the upper triangular `t`, by the recurrence of Björck and
+/// Hammarling.
+fn sqrt_upper<F: Float>(t: &Array2<Complex<F>>) -> Array2<Complex<F>> {
+    let n = t.nrows();
+    let mut r = Array2::from_elem((n, n), Complex::new(F::zero(), F::zero()));
+    for j in 0..n {
+        r[[j, j]] = t[[j, j]].sqrt();
+        for i in (0..j).rev() {
+            let num = (i + 1..j).fold(t[[i, j]], |acc, k| acc - r[[i, k]] * r[[k, j]]);
+            let den = r[[i, i]] + r[[j, j]];
+            r[[i, j]] = if num.norm().is_zero() { num } else { num / den };
+        }
+    }
+    r
+}
+
+fn norm1<F: Float>(a: &Array2<Complex<F>>) -> F {
+    a.columns()
+        .into_iter()
+        .map(|c| c.fold(F::zero(), |acc, z| acc + z.norm()))
+        .fold(F::zero(), F::max)
+}
+
+/// Matrix square root.
+///
+/// ## Parameters
+/// * `a`: Square matrix whose square root to evaluate.
+///
+/// ## Returns
+/// The [`MatfuncResult`] with the principal square root `x` of `a`, such that `x @ x = a`,
+/// and the relative error estimate `||x @ x - a||_F / ||a||_F`.
+///
+/// ## Errors
+/// If `a` is not square, contains infs or NaNs, or its Schur decomposition fails.
+///
+/// ## Notes
+/// The square root is computed on the complex Schur form of `a`. A singular `a` may not
+/// have a square root, signaled by an infinite or NaN error estimate.
+///
+/// ## References
+/// Edvin Deadman, Nicholas J. Higham, Rui Ralha (2013) "Blocked Schur Algorithms for
+/// Computing the Matrix Square Root, Lecture Notes in Computer Science, 7782. pp. 171-182.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import sqrtm
+/// a = np.array([[1.0, 3.0], [1.0, 4.0]])
+/// r = sqrtm(a)
+/// r
+/// # array([[ 0.75592895,  1.13389342],
+/// #        [ 0.37796447,  1.88982237]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::sqrtm;
+///
+/// let a = array![[1.0, 3.0], [1.0, 4.0]];
+/// let result = sqrtm(&a).unwrap();
+/// assert!(result.errest < 1e-14);
+/// let r = result.real().unwrap();
+/// for (r, e) in r.iter().zip([0.75592895, 1.13389342, 0.37796447, 1.88982237]) {
+///     assert_abs_diff_eq!(*r, e, epsilon = 1e-8);
+/// }
+/// ```
+pub fn sqrtm<F, S>(a: &ArrayBase<S, Ix2>) -> Result<MatfuncResult<F>>
+where
+    F: Float + RealField,
+    S: Data<Elem = F>,
+{
+    let (q, t) = complex_schur(a)?;
+    let value = unitary_similarity(&q, &sqrt_upper(&t));
+    let frobenius =
+        |m: &Array2<Complex<F>>| Float::sqrt(m.iter().fold(F::zero(), |acc, z| acc + z.norm_sqr()));
+    let a = a.mapv(|x| Complex::new(x, F::zero()));
+    let residual = frobenius(&(value.dot(&value) - &a));
+    let norm = frobenius(&a);
+    let errest = if norm.is_zero() {
+        residual
+    } else {
+        residual / norm
+    };
+    Ok(MatfuncResult { value, errest })
+}
+
+/// Compute matrix logarithm.
+///
+/// The matrix logarithm is the inverse of [`expm`]: `expm(logm(a)) == a`.
+///
+/// ## Parameters
+/// * `a`: Square matrix whose logarithm to evaluate.
+///
+/// ## Returns
+/// The [`MatfuncResult`] with the principal logarithm of `a`, and the relative error
+/// estimate `||expm(logm(a)) - a||_1 / ||a||_1`.
+///
+/// ## Errors
+/// If `a` is not square, contains infs or NaNs, its Schur decomposition fails, or if `a` is
+/// singular, without logarithm.
+///
+/// ## Notes
+/// The logarithm is computed by inverse scaling and squaring on the complex Schur form of
+/// `a`: square roots are taken until it is close to the identity, where the logarithm is
+/// evaluated by a Padé approximant, as the Gauss-Legendre quadrature of
+/// `log(I + X) = integral_0^1 X (I + t X)^-1 dt`.
+///
+/// ## References
+/// Awad H. Al-Mohy and Nicholas J. Higham (2012) "Improved Inverse Scaling and Squaring
+/// Algorithms for the Matrix Logarithm." SIAM Journal on Scientific Computing, 34 (4).
+/// C152-C169. ISSN 1095-7197
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import logm, expm
+/// a = np.array([[1.0, 3.0], [1.0, 4.0]])
+/// b = logm(a)
+/// b
+/// # array([[-1.02571087,  2.05142174],
+/// #        [ 0.68380725,  1.02571087]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::logm;
+///
+/// let a = array![[1.0, 3.0], [1.0, 4.0]];
+/// let b = logm(&a).unwrap().real().unwrap();
+/// for (b, e) in b.iter().zip([-1.02571087, 2.05142174, 0.68380725, 1.02571087]) {
+///     assert_abs_diff_eq!(*b, e, epsilon = 1e-8);
+/// }
+/// ```
+pub fn logm<F, S>(a: &ArrayBase<S, Ix2>) -> Result<MatfuncResult<F>>
+where
+    F: Float + RealField,
+    S: Data<Elem = F>,
+{
+    let (q, mut t) = complex_schur(a)?;
+    let n = t.nrows();
+    if t.diag().iter().any(|z| z.norm().is_zero()) {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "singular matrix, without logarithm.".into(),
+        });
+    }
+    let ident = Array2::<Complex<F>>::eye(n);
+    let mut roots = 0;
+    while norm1(&(&t - &ident)) > F::from(0.25).unwrap() && roots < 64 {
+        t = sqrt_upper(&t);
+        roots += 1;
+    }
+
+    // Nodes and weights of the 8 points Gauss-Legendre quadrature on [-1, 1].
+    const GAUSS_LEGENDRE: [(f64, f64); 4] = [
+        (0.1834346424956498, 0.362683783378362),
+        (0.525532409916329, 0.3137066458778873),
+        (0.7966664774136267, 0.2223810344533745),
+        (0.9602898564975363, 0.1012285362903763),
+    ];
+    let x = &t - &ident;
+    let half = F::from(0.5).unwrap();
+    let mut log = Array2::from_elem((n, n), Complex::new(F::zero(), F::zero()));
+    for (node, weight) in GAUSS_LEGENDRE {
+        for node in [-node, node] {
+            let node = (F::one() + F::from(node).unwrap()) * half;
+            let weight = F::from(weight).unwrap() * half;
+            let term = solve_upper(&(&ident + &x.mapv(|z| z * node)), &x)?;
+            log = log + term.mapv(|z| z * weight);
+        }
+    }
+    let log = log.mapv(|z| z * Float::powi(F::from(2).unwrap(), roots));
+
+    // The exponential of the triangular logarithm is triangular.
+    let exp = exp_pade(&log, norm1(&log), solve_upper)?;
+    let ca = a.mapv(|x| Complex::new(x, F::zero()));
+    let errest = norm1(&(unitary_similarity(&q, &exp) - &ca)) / norm1(&ca);
+    Ok(MatfuncResult {
+        value: unitary_similarity(&q, &log),
+        errest,
+    })
+}
+
+/// Evaluate a matrix function specified by a callable.
+///
+/// Returns the value of matrix-valued function `f` at `a`. The function `f` is an extension
+/// of the scalar-valued function `func` to matrices.
+///
+/// ## Parameters
+/// * `a`: Square matrix at which to evaluate the function.
+/// * `func`: Callable object that evaluates a scalar function `f`.
+///
+/// ## Returns
+/// The [`MatfuncResult`] with the value of the matrix function specified by `func` evaluated
+/// at `a`, and the estimate of its relative error, `1` at most.
+///
+/// ## Errors
+/// If `a` is not square, contains infs or NaNs, or its Schur decomposition fails.
+///
+/// ## Notes
+/// This function implements the general algorithm based on Schur decomposition (Algorithm
+/// 9.1.1. in Golub and Van Loan). The Parlett recurrence used requires distinct eigenvalues:
+/// close eigenvalues give a large error estimate, and repeated ones an inaccurate value.
+///
+/// ## References
+/// Gene H. Golub, Charles F. van Loan, Matrix Computations 4th ed.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import funm
+/// a = np.array([[1.0, 3.0], [1.0, 4.0]])
+/// funm(a, lambda x: x*x)
+/// # array([[  4.,  15.],
+/// #        [  5.,  19.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::funm;
+///
+/// let a = array![[1.0, 3.0], [1.0, 4.0]];
+/// let result = funm(&a, |x| x * x).unwrap();
+/// for (f, e) in result.real().unwrap().iter().zip([4., 15., 5., 19.]) {
+///     assert_abs_diff_eq!(*f, e, epsilon = 1e-12);
+/// }
+/// ```
+pub fn funm<F, S, Func>(a: &ArrayBase<S, Ix2>, func: Func) -> Result<MatfuncResult<F>>
+where
+    F: Float + RealField,
+    S: Data<Elem = F>,
+    Func: Fn(Complex<F>) -> Complex<F>,
+{
+    let (q, t) = complex_schur(a)?;
+    let n = t.nrows();
+    let mut f = Array2::from_elem((n, n), Complex::new(F::zero(), F::zero()));
+    for i in 0..n {
+        f[[i, i]] = func(t[[i, i]]);
+    }
+    let mut minden = if n > 0 { t[[0, 0]].norm() } else { F::zero() };
+    for p in 1..n {
+        for i in 0..n - p {
+            let j = i + p;
+            let s = (i + 1..j).fold(t[[i, j]] * (f[[j, j]] - f[[i, i]]), |acc, k| {
+                acc + t[[i, k]] * f[[k, j]] - f[[i, k]] * t[[k, j]]
+            });
+            let den = t[[j, j]] - t[[i, i]];
+            f[[i, j]] = if den.norm().is_zero() { s } else { s / den };
+            minden = Float::min(minden, den.norm());
+        }
+    }
+    let value = unitary_similarity(&q, &f);
+
+    let tol = <F as Float>::epsilon();
+    let minden = if minden.is_zero() { tol } else { minden };
+    let strict_upper = Array2::from_shape_fn((n, n), |(i, j)| {
+        if i < j {
+            t[[i, j]]
+        } else {
+            Complex::new(F::zero(), F::zero())
+        }
+    });
+    let errest = if value
+        .iter()
+        .any(|z| !Float::is_finite(z.re) || !Float::is_finite(z.im))
+    {
+        <F as Float>::infinity()
+    } else {
+        Float::min(
+            F::one(),
+            Float::max(tol, tol / minden * norm1(&strict_upper)),
+        )
+    };
+    Ok(MatfuncResult { value, errest })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn exponentials() {
+        // Each degree of the approximants, and scaling and squaring.
+        for t in [0.01, 0.2, 0.9, 2., 5., 30.] {
+            let e = expm(&array![[0., t], [-t, 0.]]).unwrap();
+            let rotation = [t.cos(), t.sin(), -t.sin(), t.cos()];
+            for (e, r) in e.iter().zip(rotation) {
+                assert_abs_diff_eq!(*e, r, epsilon = 1e-13);
+            }
+        }
+        let (a, b, c) = (1., 10., 3.);
+        let e = expm(&array![[a, b], [0., c]]).unwrap();
+        let expected = [a.exp(), b * (c.exp() - a.exp()) / (c - a), 0., c.exp()];
+        for (e, x) in e.iter().zip(expected) {
+            assert_abs_diff_eq!(*e, x, epsilon = 1e-12 * f64::max(x.abs(), 1.));
+        }
+        assert_eq!(
+            expm(&Array2::<f64>::zeros((3, 3))).unwrap(),
+            Array2::<f64>::eye(3)
+        );
+        assert!(expm(&array![[1., 2.]]).is_err());
+    }
+
+    #[test]
+    fn roots_and_logarithms() {
+        let a = array![[4., 1., 0.], [1., 3., -1.], [0., 2., 5.]];
+        let r = sqrtm(&a).unwrap();
+        assert!(r.errest < 1e-14);
+        let r = r.real().unwrap();
+        for (rr, a) in r.dot(&r).iter().zip(&a) {
+            assert_abs_diff_eq!(*rr, *a, epsilon = 1e-12);
+        }
+        let log = logm(&a).unwrap();
+        assert!(log.errest < 1e-13);
+        for (e, a) in expm(&log.real().unwrap()).unwrap().iter().zip(&a) {
+            assert_abs_diff_eq!(*e, *a, epsilon = 1e-12);
+        }
+
+        // Negative eigenvalues give complex principal values.
+        let negative = array![[-4., 0.], [0., 1.]];
+        let r = sqrtm(&negative).unwrap();
+        assert!(r.real().is_none());
+        assert_abs_diff_eq!(r.value[[0, 0]].im, 2., epsilon = 1e-15);
+        let log = logm(&negative).unwrap();
+        assert_abs_diff_eq!(log.value[[0, 0]].im, core::f64::consts::PI, epsilon = 1e-14);
+        assert!(logm(&array![[1., 0.], [0., 0.]]).is_err());
+    }
+
+    #[test]
+    fn functions() {
+        let a = array![[1., 2., 0.], [-1., 3., 1.], [0., 1., -2.]];
+        let result = funm(&a, |z| z.exp()).unwrap();
+        assert!(result.errest < 1e-13);
+        let expected = expm(&a).unwrap();
+        for (f, e) in result.real().unwrap().iter().zip(&expected) {
+            assert_abs_diff_eq!(*f, *e, epsilon = 1e-12);
+        }
+        // Repeated eigenvalues are not supported by the recurrence.
+        let result = funm(&array![[1., 1.], [0., 1.]], |z| z.exp()).unwrap();
+        assert_eq!(result.errest, 1.);
+    }
+}
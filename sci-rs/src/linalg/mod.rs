@@ -1,3 +1,56 @@
+#[cfg(feature = "alloc")]
+mod banded;
+#[cfg(feature = "alloc")]
+mod basic;
 mod companion;
+#[cfg(feature = "alloc")]
+mod eigen;
+#[cfg(feature = "alloc")]
+mod matfuncs;
+#[cfg(feature = "alloc")]
+mod norm;
+#[cfg(feature = "alloc")]
+mod qr;
+#[cfg(feature = "alloc")]
+mod special_matrices;
+#[cfg(feature = "alloc")]
+mod toeplitz;
 
+#[cfg(feature = "alloc")]
+pub use banded::*;
+#[cfg(feature = "alloc")]
+pub use basic::*;
 pub use companion::*;
+#[cfg(feature = "alloc")]
+pub use eigen::*;
+#[cfg(feature = "alloc")]
+pub use matfuncs::*;
+#[cfg(feature = "alloc")]
+pub use norm::*;
+#[cfg(feature = "alloc")]
+pub use qr::*;
+#[cfg(feature = "alloc")]
+pub use special_matrices::*;
+#[cfg(feature = "alloc")]
+pub use toeplitz::*;
+
+use num_traits::Float;
+
+/// Inner product of `a` and `b`.
+pub(crate) fn dot<'a, F: Float + 'a>(
+    a: impl IntoIterator<Item = &'a F>,
+    b: impl IntoIterator<Item = &'a F>,
+) -> F {
+    a.into_iter()
+        .zip(b)
+        .fold(F::zero(), |acc, (&a, &b)| acc + a * b)
+}
+
+/// Cross product of `a` and `b`.
+pub(crate) fn cross<F: Float>(a: [F; 3], b: [F; 3]) -> [F; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
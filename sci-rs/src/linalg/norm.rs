@@ -0,0 +1,343 @@
+use super::basic::jacobi_svd;
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use alloc::vec::Vec;
+use ndarray::{Array1, ArrayBase, ArrayD, ArrayView2, Axis, Data, Dimension, IxDyn};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Order of the norm computed by [`norm`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NormOrder<F> {
+    /// Frobenius norm, of matrices only.
+    Fro,
+    /// Nuclear norm, the sum of the singular values, of matrices only.
+    Nuc,
+    /// Order `p`, including `0` and the infinities, with the meanings of
+    /// `numpy.linalg.norm`.
+    P(F),
+}
+
+/// Axes along which [`norm`] computes the norms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormAxis {
+    /// Vector norms along the axis.
+    Vector(isize),
+    /// Matrix norms of the matrices with rows along the first axis and columns along the
+    /// second one.
+    Matrix(isize, isize),
+}
+
+/// Norm of the vector `x`.
+fn vector_norm<'a, F: Float + 'a>(
+    x: impl Iterator<Item = &'a F>,
+    ord: Option<NormOrder<F>>,
+) -> Result<F> {
+    let ord = match ord {
+        None => F::from(2).unwrap(),
+        Some(NormOrder::P(p)) => p,
+        Some(_) => {
+            return Err(Error::InvalidArg {
+                arg: "ord".into(),
+                reason: "invalid norm order for vectors.".into(),
+            })
+        }
+    };
+    let mut abs = x.map(|x| x.abs());
+    Ok(if ord == F::infinity() {
+        abs.fold(F::zero(), F::max)
+    } else if ord == F::neg_infinity() {
+        abs.fold(F::infinity(), F::min)
+    } else if ord.is_zero() {
+        F::from(abs.filter(|x| !x.is_zero()).count()).unwrap()
+    } else if ord == F::one() {
+        abs.fold(F::zero(), |acc, x| acc + x)
+    } else if ord == F::from(2).unwrap() {
+        abs.fold(F::zero(), |acc, x| acc + x * x).sqrt()
+    } else {
+        abs.fold(F::zero(), |acc, x| acc + x.powf(ord))
+            .powf(ord.recip())
+    })
+}
+
+/// Norm of the matrix `a`.
+fn matrix_norm<F: Float>(a: ArrayView2<F>, ord: Option<NormOrder<F>>) -> Result<F> {
+    let singular_values = || -> Array1<F> {
+        if a.nrows() >= a.ncols() {
+            jacobi_svd(a).2
+        } else {
+            jacobi_svd(a.t()).2
+        }
+    };
+    let sums = |axis: usize| -> Vec<F> {
+        a.axis_iter(Axis(axis))
+            .map(|x| x.fold(F::zero(), |acc, x| acc + x.abs()))
+            .collect()
+    };
+    let ord = match ord {
+        None | Some(NormOrder::Fro) => {
+            return Ok(a.fold(F::zero(), |acc, x| acc + *x * *x).sqrt());
+        }
+        Some(NormOrder::Nuc) => return Ok(singular_values().sum()),
+        Some(NormOrder::P(p)) => p,
+    };
+    let two = F::from(2).unwrap();
+    Ok(if ord == F::infinity() {
+        sums(0).into_iter().fold(F::zero(), F::max)
+    } else if ord == F::neg_infinity() {
+        sums(0).into_iter().fold(F::infinity(), F::min)
+    } else if ord == F::one() {
+        sums(1).into_iter().fold(F::zero(), F::max)
+    } else if ord == -F::one() {
+        sums(1).into_iter().fold(F::infinity(), F::min)
+    } else if ord == two {
+        singular_values().fold(F::zero(), |acc, &s| acc.max(s))
+    } else if ord == -two {
+        singular_values().fold(F::infinity(), |acc, &s| acc.min(s))
+    } else {
+        return Err(Error::InvalidArg {
+            arg: "ord".into(),
+            reason: "invalid norm order for matrices.".into(),
+        });
+    })
+}
+
+/// Matrix or vector norm.
+///
+/// This function is able to return one of eight different matrix norms, or one of an
+/// infinite number of vector norms, depending on the value of the `ord` parameter.
+///
+/// ## Parameters
+/// * `a`: Input array. If `axis` is `None`, `a` must be 1-D or 2-D, unless `ord` is `None`.
+/// * `ord`: Order of the norm, see the notes. `None` by default, for the 2-norm of vectors
+///   and the Frobenius norm of matrices.
+/// * `axis`: Axis of `a` along which to compute the vector norms, or pair of axes holding
+///   the 2-D matrices whose norms are computed. `None` by default, for the vector norm if
+///   `a` is 1-D or the matrix norm if it is 2-D, and the 2-norm of `a` flattened if `ord`
+///   is also `None`.
+/// * `keepdims`: If `true`, the axes which are normed over are left in the result as
+///   dimensions with size one. `false` by default.
+///
+/// ## Returns
+/// Norm of the matrices or vectors, with the shape of `a` without the normed axes: a 0-D
+/// array for a single norm, read with `result[[]]`.
+///
+/// ## Errors
+/// If `ord` is invalid for vectors or matrices, the axes are out of range or repeated, or if
+/// `a` is neither 1-D nor 2-D when `ord` is given without `axis`.
+///
+/// ## Notes
+/// The following norms can be calculated:
+///
+/// | ord      | norm for matrices            | norm for vectors             |
+/// |----------|------------------------------|------------------------------|
+/// | `None`   | Frobenius norm               | 2-norm                       |
+/// | `Fro`    | Frobenius norm               | --                           |
+/// | `Nuc`    | nuclear norm                 | --                           |
+/// | `P(inf)` | `max(sum(abs(a), axis=1))`   | `max(abs(a))`                |
+/// | `P(-inf)`| `min(sum(abs(a), axis=1))`   | `min(abs(a))`                |
+/// | `P(0)`   | --                           | `sum(a != 0)`                |
+/// | `P(1)`   | `max(sum(abs(a), axis=0))`   | as below                     |
+/// | `P(-1)`  | `min(sum(abs(a), axis=0))`   | as below                     |
+/// | `P(2)`   | 2-norm (largest sing. value) | as below                     |
+/// | `P(-2)`  | smallest singular value      | as below                     |
+/// | other    | --                           | `sum(abs(a)**ord)**(1./ord)` |
+///
+/// Like numpy, the values of `ord <= 0` give results which are not norms, but may still be
+/// useful, and the matrix `P(1)` and `P(inf)` are along the columns and rows respectively.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import norm
+/// a = np.arange(9) - 4.0
+/// b = a.reshape((3, 3))
+/// norm(a), norm(b), norm(b, 'fro'), norm(a, np.inf), norm(b, np.inf), norm(b, 1), norm(a, -1)
+/// # (7.745966692414834, 7.745966692414834, 7.745966692414834, 4.0, 9.0, 7.0, 0.0)
+/// norm(b, 2)
+/// # 7.3484692283495345
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::Array;
+/// use sci_rs::linalg::{norm, NormOrder};
+///
+/// let a = Array::range(-4., 5., 1.);
+/// let b = a.clone().into_shape_with_order((3, 3)).unwrap();
+/// assert_abs_diff_eq!(norm(&a, None, None, None).unwrap()[[]], 7.745966692414834);
+/// assert_abs_diff_eq!(norm(&b, None, None, None).unwrap()[[]], 7.745966692414834);
+/// assert_abs_diff_eq!(norm(&b, Some(NormOrder::Fro), None, None).unwrap()[[]], 7.745966692414834);
+/// let inf = Some(NormOrder::P(f64::INFINITY));
+/// assert_eq!(norm(&a, inf, None, None).unwrap()[[]], 4.);
+/// assert_eq!(norm(&b, inf, None, None).unwrap()[[]], 9.);
+/// assert_eq!(norm(&b, Some(NormOrder::P(1.)), None, None).unwrap()[[]], 7.);
+/// assert_eq!(norm(&a, Some(NormOrder::P(-1.)), None, None).unwrap()[[]], 0.);
+/// let two = norm(&b, Some(NormOrder::P(2.)), None, None).unwrap()[[]];
+/// assert_abs_diff_eq!(two, 7.3484692283495345, epsilon = 1e-14);
+/// ```
+pub fn norm<F, S, D>(
+    a: &ArrayBase<S, D>,
+    ord: Option<NormOrder<F>>,
+    axis: Option<NormAxis>,
+    keepdims: Option<bool>,
+) -> Result<ArrayD<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let a = a.view().into_dyn();
+    let keepdims = keepdims.unwrap_or(false);
+    let axis = match (axis, ord) {
+        (Some(axis), _) => axis,
+        (None, None) => {
+            let value = a.fold(F::zero(), |acc, x| acc + *x * *x).sqrt();
+            let shape = if keepdims {
+                alloc::vec![1; a.ndim()]
+            } else {
+                Vec::new()
+            };
+            return Ok(ArrayD::from_elem(IxDyn(&shape), value));
+        }
+        (None, Some(_)) => match a.ndim() {
+            1 => NormAxis::Vector(0),
+            2 => NormAxis::Matrix(0, 1),
+            _ => {
+                return Err(Error::InvalidArg {
+                    arg: "a".into(),
+                    reason: "improper number of dimensions to norm.".into(),
+                })
+            }
+        },
+    };
+
+    let (values, normed) = match axis {
+        NormAxis::Vector(axis) => {
+            let axis = check_and_get_axis_dyn(Some(axis), &a)?;
+            let values = a
+                .lanes(Axis(axis))
+                .into_iter()
+                .map(|lane| vector_norm(lane.iter(), ord))
+                .collect::<Result<Vec<F>>>()?;
+            (values, alloc::vec![axis])
+        }
+        NormAxis::Matrix(rows, columns) => {
+            let (rows, columns) = (
+                check_and_get_axis_dyn(Some(rows), &a)?,
+                check_and_get_axis_dyn(Some(columns), &a)?,
+            );
+            if rows == columns {
+                return Err(Error::InvalidArg {
+                    arg: "axis".into(),
+                    reason: "duplicate axes given.".into(),
+                });
+            }
+            // The normed axes last, so the matrices are consecutive in logical order.
+            let mut order: Vec<usize> = (0..a.ndim())
+                .filter(|&i| i != rows && i != columns)
+                .collect();
+            order.extend([rows, columns]);
+            let permuted = a.view().permuted_axes(order);
+            let (m, n) = (a.len_of(Axis(rows)), a.len_of(Axis(columns)));
+            let flat = permuted.as_standard_layout();
+            let count = flat.len().checked_div(m * n).unwrap_or(0);
+            let matrices = flat
+                .to_shape((count, m, n))
+                .map_err(|_| Error::InvalidArg {
+                    arg: "a".into(),
+                    reason: "cannot split into matrices.".into(),
+                })?
+                .to_owned();
+            let values = matrices
+                .outer_iter()
+                .map(|matrix| matrix_norm(matrix, ord))
+                .collect::<Result<Vec<F>>>()?;
+            (values, alloc::vec![rows, columns])
+        }
+    };
+    let shape: Vec<usize> = (0..a.ndim())
+        .filter_map(|i| match normed.contains(&i) {
+            true => keepdims.then_some(1),
+            false => Some(a.len_of(Axis(i))),
+        })
+        .collect();
+    Ok(ArrayD::from_shape_vec(IxDyn(&shape), values).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array};
+
+    #[test]
+    fn vector_norms() {
+        let x = array![[3., -4., 0.], [1., 2., -2.]];
+        let along = |ord, axis| norm(&x, ord, Some(NormAxis::Vector(axis)), None).unwrap();
+        assert_eq!(along(None, 1), array![5., 3.].into_dyn());
+        assert_eq!(along(Some(NormOrder::P(0.)), 1), array![2., 3.].into_dyn());
+        assert_eq!(
+            along(Some(NormOrder::P(1.)), -2),
+            array![4., 6., 2.].into_dyn()
+        );
+        let cubic = along(Some(NormOrder::P(3.)), 0);
+        assert_abs_diff_eq!(cubic[[1]], 72f64.powf(1. / 3.), epsilon = 1e-14);
+        assert_eq!(
+            along(Some(NormOrder::P(f64::NEG_INFINITY)), 0),
+            array![1., 2., 0.].into_dyn()
+        );
+        let kept = norm(&x, None, Some(NormAxis::Vector(1)), Some(true)).unwrap();
+        assert_eq!(kept.shape(), &[2, 1]);
+        assert!(norm(&x, Some(NormOrder::Fro), Some(NormAxis::Vector(0)), None).is_err());
+        assert!(norm(&x, None, Some(NormAxis::Vector(2)), None).is_err());
+    }
+
+    #[test]
+    fn matrix_norms() {
+        // A stack of two matrices along the last axis.
+        let a = Array::from_shape_fn((3, 2, 2), |(i, j, k)| match k {
+            0 => [[2., 0.], [0., -3.], [0., 0.]][i][j],
+            _ => [[1., 1.], [1., 1.], [1., 1.]][i][j],
+        });
+        let norms = |ord| norm(&a, Some(ord), Some(NormAxis::Matrix(0, 1)), None).unwrap();
+        assert_eq!(norms(NormOrder::P(1.)), array![3., 3.].into_dyn());
+        assert_eq!(
+            norms(NormOrder::P(f64::INFINITY)),
+            array![3., 2.].into_dyn()
+        );
+        let two = norms(NormOrder::P(2.));
+        assert_abs_diff_eq!(two[[0]], 3., epsilon = 1e-14);
+        assert_abs_diff_eq!(two[[1]], 6f64.sqrt(), epsilon = 1e-14);
+        let smallest = norms(NormOrder::P(-2.));
+        assert_abs_diff_eq!(smallest[[0]], 2., epsilon = 1e-14);
+        assert_abs_diff_eq!(smallest[[1]], 0., epsilon = 1e-14);
+        let nuclear = norms(NormOrder::Nuc);
+        assert_abs_diff_eq!(nuclear[[0]], 5., epsilon = 1e-14);
+        // The transposed matrices have swapped 1 and inf norms.
+        let swapped = norm(
+            &a,
+            Some(NormOrder::P(1.)),
+            Some(NormAxis::Matrix(1, 0)),
+            Some(true),
+        )
+        .unwrap();
+        assert_eq!(swapped.shape(), &[1, 1, 2]);
+        assert_eq!(swapped.iter().copied().collect::<Vec<_>>(), [3., 2.]);
+
+        assert!(norm(
+            &a,
+            Some(NormOrder::P(3.)),
+            Some(NormAxis::Matrix(0, 1)),
+            None
+        )
+        .is_err());
+        assert!(norm(&a, None, Some(NormAxis::Matrix(1, -2)), None).is_err());
+        assert!(norm(&a, Some(NormOrder::P(2.)), None, None).is_err());
+        assert_eq!(
+            norm(&a, None, None, Some(true)).unwrap().shape(),
+            &[1, 1, 1]
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use alloc::vec::Vec;
+use ndarray::{s, Array1, Array2, ArrayBase, Axis, Data, Ix2};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Shape of the factors computed by [`qr`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QrMode {
+    /// `Q` of shape `(m, m)` and `R` of shape `(m, n)`.
+    #[default]
+    Full,
+    /// Only `R`, of shape `(m, n)`.
+    R,
+    /// `Q` of shape `(m, k)` and `R` of shape `(k, n)`, with `k = min(m, n)`.
+    Economic,
+}
+
+/// Result of [`qr`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrResult<F> {
+    /// Orthogonal factor, unless the mode is [`QrMode::R`].
+    pub q: Option<Array2<F>>,
+    /// Upper triangular factor.
+    pub r: Array2<F>,
+    /// Column permutation, with `a[:, p] = q @ r`, if pivoting.
+    pub p: Option<Vec<usize>>,
+}
+
+/// Compute QR decomposition of a matrix.
+///
+/// Calculate the decomposition `a = q @ r` where `q` is orthogonal and `r` upper triangular,
+/// or `a[:, p] = q @ r` with column pivoting.
+///
+/// ## Parameters
+/// * `a`: Matrix to be decomposed, of shape `(m, n)`.
+/// * `mode`: Which factors to compute and their shape, see [`QrMode`]. [`QrMode::Full`] by
+///   default.
+/// * `pivoting`: Whether or not factorization should include pivoting for rank-revealing QR
+///   decomposition, in which case the diagonal of `r` is non-increasing in absolute value.
+///   `false` by default.
+///
+/// ## Returns
+/// The [`QrResult`] with the factors and permutation.
+///
+/// ## Errors
+/// If `a` contains infs or NaNs.
+///
+/// ## Notes
+/// The factorization uses Householder reflections, with the conventions of LAPACK's `geqrf`
+/// and `geqp3`: each reflection maps its column onto the opposite sign of its leading
+/// element, and is skipped if the column is already triangular.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import linalg
+/// a = np.array([[1., 2.], [3., 4.]])
+/// q, r = linalg.qr(a)
+/// r
+/// # array([[-3.16227766, -4.42718872],
+/// #        [ 0.        , -0.63245553]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::qr;
+///
+/// let a = array![[1., 2.], [3., 4.]];
+/// let result = qr(&a, None, None).unwrap();
+/// for (r, e) in result.r.iter().zip([-3.16227766, -4.42718872, 0., -0.63245553]) {
+///     assert_abs_diff_eq!(*r, e, epsilon = 1e-8);
+/// }
+/// for (qr, a) in result.q.unwrap().dot(&result.r).iter().zip(&a) {
+///     assert_abs_diff_eq!(*qr, *a, epsilon = 1e-14);
+/// }
+/// ```
+pub fn qr<F, S>(
+    a: &ArrayBase<S, Ix2>,
+    mode: Option<QrMode>,
+    pivoting: Option<bool>,
+) -> Result<QrResult<F>>
+where
+    F: Float + 'static,
+    S: Data<Elem = F>,
+{
+    if a.iter().any(|v| !v.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "array must not contain infs or NaNs.".into(),
+        });
+    }
+    let mode = mode.unwrap_or_default();
+    let pivoting = pivoting.unwrap_or(false);
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let mut r = a.to_owned();
+    let mut perm: Vec<usize> = (0..n).collect();
+    let two = F::one() + F::one();
+    // Unit Householder vectors, zero for the skipped reflections.
+    let mut reflectors: Vec<Array1<F>> = Vec::with_capacity(k);
+    for j in 0..k {
+        if pivoting {
+            // The first of the columns of largest remaining norm.
+            let norms: Vec<F> = (j..n)
+                .map(|c| r.slice(s![j.., c]).fold(F::zero(), |acc, &x| acc + x * x))
+                .collect();
+            let p = (0..norms.len()).fold(0, |p, c| if norms[c] > norms[p] { c } else { p });
+            if p != 0 {
+                for i in 0..m {
+                    r.swap([i, j], [i, j + p]);
+                }
+                perm.swap(j, j + p);
+            }
+        }
+        let x = r.slice(s![j.., j]);
+        let tail = x.iter().skip(1).fold(F::zero(), |acc, &x| acc + x * x);
+        if tail.is_zero() {
+            reflectors.push(Array1::zeros(m - j));
+            continue;
+        }
+        let norm = (x[0] * x[0] + tail).sqrt();
+        let beta = if x[0] >= F::zero() { -norm } else { norm };
+        let mut v = x.to_owned();
+        v[0] = v[0] - beta;
+        let vnorm = v.fold(F::zero(), |acc, &x| acc + x * x).sqrt();
+        v.mapv_inplace(|x| x / vnorm);
+        let mut block = r.slice_mut(s![j.., j..]);
+        let w = v.dot(&block);
+        for (mut row, &vi) in block.axis_iter_mut(Axis(0)).zip(&v) {
+            row.scaled_add(-two * vi, &w);
+        }
+        r.slice_mut(s![j + 1.., j]).fill(F::zero());
+        r[[j, j]] = beta;
+        reflectors.push(v);
+    }
+
+    let q = match mode {
+        QrMode::R => None,
+        QrMode::Full | QrMode::Economic => {
+            let cols = if mode == QrMode::Full { m } else { k };
+            let mut q = Array2::eye(m).slice_move(s![.., ..cols]);
+            for (j, v) in reflectors.iter().enumerate().rev() {
+                let mut block = q.slice_mut(s![j.., ..]);
+                let w = v.dot(&block);
+                for (mut row, &vi) in block.axis_iter_mut(Axis(0)).zip(v) {
+                    row.scaled_add(-two * vi, &w);
+                }
+            }
+            Some(q)
+        }
+    };
+    if mode == QrMode::Economic {
+        r = r.slice_move(s![..k, ..]);
+    }
+    Ok(QrResult {
+        q,
+        r,
+        p: pivoting.then_some(perm),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    fn assert_factorizes(a: &Array2<f64>, result: &QrResult<f64>) {
+        let q = result.q.as_ref().unwrap();
+        for (qtq, e) in q.t().dot(q).iter().zip(&Array2::<f64>::eye(q.ncols())) {
+            assert_abs_diff_eq!(*qtq, *e, epsilon = 1e-14);
+        }
+        let a = match &result.p {
+            Some(p) => a.select(Axis(1), p),
+            None => a.clone(),
+        };
+        for (qr, a) in q.dot(&result.r).iter().zip(&a) {
+            assert_abs_diff_eq!(*qr, *a, epsilon = 1e-13);
+        }
+        for ((i, j), r) in result.r.indexed_iter() {
+            if i > j {
+                assert_eq!(*r, 0.);
+            }
+        }
+    }
+
+    #[test]
+    fn modes() {
+        let a = array![
+            [1., 2., 0.],
+            [-1., 4., 3.],
+            [2., 0., 1.],
+            [0., 1., -2.],
+            [3., 3., 3.]
+        ];
+        let full = qr(&a, None, None).unwrap();
+        assert_eq!(full.r.dim(), (5, 3));
+        assert_factorizes(&a, &full);
+        let economic = qr(&a, Some(QrMode::Economic), None).unwrap();
+        assert_eq!(economic.q.as_ref().unwrap().dim(), (5, 3));
+        assert_eq!(economic.r, full.r.slice(s![..3, ..]));
+        assert_factorizes(&a, &economic);
+        let r = qr(&a, Some(QrMode::R), None).unwrap();
+        assert_eq!((r.q, r.r), (None, full.r));
+
+        let wide = a.t().to_owned();
+        assert_factorizes(&wide, &qr(&wide, Some(QrMode::Economic), None).unwrap());
+    }
+
+    #[test]
+    fn pivoting_reveals_rank() {
+        // The last column is the sum of the first two.
+        let a = array![[1., 0., 1.], [0., 1., 1.], [1., 1., 2.], [2., -1., 1.]];
+        let result = qr(&a, None, Some(true)).unwrap();
+        assert_factorizes(&a, &result);
+        assert_eq!(result.p.as_ref().unwrap()[0], 2);
+        let diagonal = result.r.diag().mapv(f64::abs);
+        assert!(diagonal[0] >= diagonal[1]);
+        assert_abs_diff_eq!(diagonal[2], 0., epsilon = 1e-14);
+        assert!(qr(&array![[f64::NAN]], None, None).is_err());
+    }
+}
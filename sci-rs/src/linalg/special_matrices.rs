@@ -0,0 +1,299 @@
+use alloc::vec::Vec;
+use ndarray::{s, Array2, ArrayBase, ArrayView2, Data, Ix1};
+use num_traits::{Float, Zero};
+use sci_rs_core::{Error, Result};
+
+/// Construct a Toeplitz matrix.
+///
+/// The Toeplitz matrix has constant diagonals, with `c` as its first column and `r` as its
+/// first row.
+///
+/// ## Parameters
+/// * `c`: First column of the matrix.
+/// * `r`: First row of the matrix. Its first element is ignored, the first row being
+///   `[c[0], r[1..]]`. `c` by default, giving a symmetric matrix.
+///
+/// ## Returns
+/// The Toeplitz matrix, of shape `(c.len(), r.len())`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.linalg import toeplitz
+/// toeplitz([1, 2, 3], [1, 4, 5, 6])
+/// # array([[1, 4, 5, 6],
+/// #        [2, 1, 4, 5],
+/// #        [3, 2, 1, 4]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::linalg::toeplitz;
+///
+/// let t = toeplitz(&array![1, 2, 3], Some(&array![1, 4, 5, 6]));
+/// assert_eq!(t, array![[1, 4, 5, 6], [2, 1, 4, 5], [3, 2, 1, 4]]);
+/// assert_eq!(toeplitz(&array![1, 2], None), array![[1, 2], [2, 1]]);
+/// ```
+pub fn toeplitz<T, S>(c: &ArrayBase<S, Ix1>, r: Option<&ArrayBase<S, Ix1>>) -> Array2<T>
+where
+    T: Clone,
+    S: Data<Elem = T>,
+{
+    let r = r.unwrap_or(c);
+    Array2::from_shape_fn((c.len(), r.len()), |(i, j)| {
+        if i >= j {
+            c[i - j].clone()
+        } else {
+            r[j - i].clone()
+        }
+    })
+}
+
+/// Construct a Hankel matrix.
+///
+/// The Hankel matrix has constant anti-diagonals, with `c` as its first column and `r` as its
+/// last row.
+///
+/// ## Parameters
+/// * `c`: First column of the matrix.
+/// * `r`: Last row of the matrix. Its first element is ignored, the last row being
+///   `[c[c.len() - 1], r[1..]]`. Zeros of the length of `c` by default.
+///
+/// ## Returns
+/// The Hankel matrix, of shape `(c.len(), r.len())`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.linalg import hankel
+/// hankel([1, 17, 99], [99, 2, 3, 4])
+/// # array([[ 1, 17, 99,  2],
+/// #        [17, 99,  2,  3],
+/// #        [99,  2,  3,  4]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::linalg::hankel;
+///
+/// let h = hankel(&array![1, 17, 99], Some(&array![99, 2, 3, 4]));
+/// assert_eq!(h, array![[1, 17, 99, 2], [17, 99, 2, 3], [99, 2, 3, 4]]);
+/// assert_eq!(hankel(&array![1, 2, 3], None), array![[1, 2, 3], [2, 3, 0], [3, 0, 0]]);
+/// ```
+pub fn hankel<T, S>(c: &ArrayBase<S, Ix1>, r: Option<&ArrayBase<S, Ix1>>) -> Array2<T>
+where
+    T: Clone + Zero,
+    S: Data<Elem = T>,
+{
+    let rows = c.len();
+    let cols = r.map_or(rows, |r| r.len());
+    Array2::from_shape_fn((rows, cols), |(i, j)| {
+        if i + j < rows {
+            c[i + j].clone()
+        } else {
+            r.map_or(T::zero(), |r| r[i + j + 1 - rows].clone())
+        }
+    })
+}
+
+/// Construct a circulant matrix.
+///
+/// Each column of the circulant matrix is the previous one rotated down by one element.
+///
+/// ## Parameters
+/// * `c`: First column of the matrix.
+///
+/// ## Returns
+/// The circulant matrix, of shape `(c.len(), c.len())`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.linalg import circulant
+/// circulant([1, 2, 3])
+/// # array([[1, 3, 2],
+/// #        [2, 1, 3],
+/// #        [3, 2, 1]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::linalg::circulant;
+///
+/// let c = circulant(&array![1, 2, 3]);
+/// assert_eq!(c, array![[1, 3, 2], [2, 1, 3], [3, 2, 1]]);
+/// ```
+pub fn circulant<T, S>(c: &ArrayBase<S, Ix1>) -> Array2<T>
+where
+    T: Clone,
+    S: Data<Elem = T>,
+{
+    let n = c.len();
+    Array2::from_shape_fn((n, n), |(i, j)| c[(n + i - j) % n].clone())
+}
+
+/// Create a companion matrix.
+///
+/// The companion matrix of the polynomial with coefficients `a` has the roots of the
+/// polynomial as eigenvalues.
+///
+/// ## Parameters
+/// * `a`: Polynomial coefficients, from the highest degree.
+///
+/// ## Returns
+/// The companion matrix, of shape `(a.len() - 1, a.len() - 1)`, with first row
+/// `-a[1..] / a[0]` and ones on the first sub-diagonal.
+///
+/// ## Errors
+/// If `a` has fewer than two coefficients, or if its leading coefficient is zero.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.linalg import companion
+/// companion([1, -10, 31, -30])
+/// # array([[ 10., -31.,  30.],
+/// #        [  1.,   0.,   0.],
+/// #        [  0.,   1.,   0.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::linalg::companion;
+///
+/// let c = companion(&array![1., -10., 31., -30.]).unwrap();
+/// assert_eq!(c, array![[10., -31., 30.], [1., 0., 0.], [0., 1., 0.]]);
+/// assert!(companion(&array![0., 1.]).is_err());
+/// ```
+pub fn companion<F, S>(a: &ArrayBase<S, Ix1>) -> Result<Array2<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    if a.len() < 2 {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "the length of a must be at least 2.".into(),
+        });
+    }
+    if a[0].is_zero() {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "the first coefficient in a must not be zero.".into(),
+        });
+    }
+    let n = a.len() - 1;
+    Ok(Array2::from_shape_fn((n, n), |(i, j)| match i {
+        0 => -a[j + 1] / a[0],
+        _ if i == j + 1 => F::one(),
+        _ => F::zero(),
+    }))
+}
+
+/// Create a block diagonal matrix from provided arrays.
+///
+/// ## Parameters
+/// * `blocks`: Matrices to lay out along the diagonal, in order.
+///
+/// ## Returns
+/// The block diagonal matrix, whose shape is the sum of the shapes of the blocks, with zeros
+/// outside of the blocks.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.linalg import block_diag
+/// block_diag([[1, 0], [0, 1]], [[3, 4, 5], [6, 7, 8]], [[7]])
+/// # array([[1, 0, 0, 0, 0, 0],
+/// #        [0, 1, 0, 0, 0, 0],
+/// #        [0, 0, 3, 4, 5, 0],
+/// #        [0, 0, 6, 7, 8, 0],
+/// #        [0, 0, 0, 0, 0, 7]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::linalg::block_diag;
+///
+/// let (a, b, c) = (array![[1, 0], [0, 1]], array![[3, 4, 5], [6, 7, 8]], array![[7]]);
+/// let d = block_diag(&[a.view(), b.view(), c.view()]);
+/// assert_eq!(
+///     d,
+///     array![
+///         [1, 0, 0, 0, 0, 0],
+///         [0, 1, 0, 0, 0, 0],
+///         [0, 0, 3, 4, 5, 0],
+///         [0, 0, 6, 7, 8, 0],
+///         [0, 0, 0, 0, 0, 7]
+///     ]
+/// );
+/// ```
+pub fn block_diag<T>(blocks: &[ArrayView2<T>]) -> Array2<T>
+where
+    T: Clone + Zero,
+{
+    let (rows, cols) = blocks
+        .iter()
+        .fold((0, 0), |(r, c), b| (r + b.nrows(), c + b.ncols()));
+    let mut out = Array2::zeros((rows, cols));
+    let corners: Vec<(usize, usize)> = blocks
+        .iter()
+        .scan((0, 0), |(r, c), b| {
+            let corner = (*r, *c);
+            (*r, *c) = (*r + b.nrows(), *c + b.ncols());
+            Some(corner)
+        })
+        .collect();
+    for (b, (r, c)) in blocks.iter().zip(corners) {
+        out.slice_mut(s![r..r + b.nrows(), c..c + b.ncols()])
+            .assign(b);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use ndarray::{array, Array1};
+
+    #[test]
+    fn rectangular_toeplitz_and_hankel() {
+        let c = array![1., 2., 3., 4.];
+        let r = array![9., 5.];
+        // The first element of r is ignored for both.
+        assert_eq!(
+            toeplitz(&c, Some(&r)),
+            array![[1., 5.], [2., 1.], [3., 2.], [4., 3.]]
+        );
+        assert_eq!(
+            hankel(&c, Some(&r)),
+            array![[1., 2.], [2., 3.], [3., 4.], [4., 5.]]
+        );
+        let t = toeplitz(&r, Some(&c));
+        assert_eq!(t, array![[9., 2., 3., 4.], [5., 9., 2., 3.]]);
+    }
+
+    #[test]
+    fn circulant_products_are_circular_convolutions() {
+        let c = array![1., 2., 3., 4.];
+        let x = array![1., 0., -1., 2.];
+        let expected: Array1<f64> = (0..4)
+            .map(|i| (0..4).map(|j| c[(4 + i - j) % 4] * x[j]).sum())
+            .collect();
+        assert_eq!(circulant(&c).dot(&x), expected);
+    }
+
+    #[test]
+    fn empty_and_degenerate() {
+        assert_eq!(block_diag::<f64>(&[]).dim(), (0, 0));
+        let empty = Array2::<f64>::zeros((0, 2));
+        let one = array![[1.]];
+        assert_eq!(
+            block_diag(&[one.view(), empty.view(), one.view()]),
+            array![[1., 0., 0., 0.], [0., 0., 0., 1.]]
+        );
+        assert_eq!(companion(&array![2., 4.]).unwrap(), array![[-2.]]);
+        assert!(companion(&array![1.]).is_err());
+    }
+}
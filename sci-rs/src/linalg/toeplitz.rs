@@ -0,0 +1,328 @@
+use alloc::{vec, vec::Vec};
+use ndarray::{Array, Array1, Array2, ArrayBase, Data, Dimension, Ix1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Levinson recursion solving `T x = b` for the Toeplitz matrix `T` whose diagonals are
+/// `a = [r[n-1], ..., r[1], c[0], ..., c[n-1]]`, from the lower left to the upper right corner.
+fn levinson<F: Float>(a: &[F], b: &[F]) -> Result<Vec<F>> {
+    let n = b.len();
+    let singular = || {
+        Err(Error::InvalidArg {
+            arg: "c".into(),
+            reason: "singular principal minor.".into(),
+        })
+    };
+    if a[n - 1].is_zero() {
+        return singular();
+    }
+    let mut x = vec![F::zero(); n];
+    x[0] = b[0] / a[n - 1];
+    if n == 1 {
+        return Ok(x);
+    }
+    // Solutions of the forward and backward systems of the leading principal minors.
+    let mut g = vec![F::zero(); n];
+    let mut h = vec![F::zero(); n];
+    g[0] = a[n - 2] / a[n - 1];
+    h[0] = a[n] / a[n - 1];
+    for m in 1..n {
+        let (mut x_num, mut x_den) = (-b[m], -a[n - 1]);
+        for j in 0..m {
+            let nmj = n + m - (j + 1);
+            x_num = x_num + a[nmj] * x[j];
+            x_den = x_den + a[nmj] * g[m - j - 1];
+        }
+        if x_den.is_zero() {
+            return singular();
+        }
+        x[m] = x_num / x_den;
+        for j in 0..m {
+            x[j] = x[j] - x[m] * g[m - j - 1];
+        }
+        if m == n - 1 {
+            break;
+        }
+
+        let (mut g_num, mut h_num, mut g_den) = (-a[n - m - 2], -a[n + m], -a[n - 1]);
+        for j in 0..m {
+            g_num = g_num + a[n + j - m - 1] * g[j];
+            h_num = h_num + a[n + m - j - 1] * h[j];
+            g_den = g_den + a[n + j - m - 1] * h[m - j - 1];
+        }
+        if g_den.is_zero() {
+            return singular();
+        }
+        g[m] = g_num / g_den;
+        h[m] = h_num / x_den;
+        let (c1, c2) = (g[m], h[m]);
+        for j in 0..m.div_ceil(2) {
+            let k = m - 1 - j;
+            let (gj, gk, hj, hk) = (g[j], g[k], h[j], h[k]);
+            g[j] = gj - c1 * hk;
+            g[k] = gk - c1 * hj;
+            h[j] = hj - c2 * gk;
+            h[k] = hk - c2 * gj;
+        }
+    }
+    Ok(x)
+}
+
+/// Solve a Toeplitz system using Levinson Recursion.
+///
+/// The Toeplitz matrix has constant diagonals, with `c` as its first column and `r` as its
+/// first row. If `r` is not given, `r == c` is assumed.
+///
+/// ## Parameters
+/// * `c`: First column of the matrix.
+/// * `r`: First row of the matrix, whose first element is ignored. `c` by default, for a
+///   symmetric matrix.
+/// * `b`: Right-hand side in `T x = b`, a vector or a matrix of several right hand sides as
+///   columns.
+///
+/// ## Returns
+/// The solution `x` of the system `T x = b`, of the shape of `b`.
+///
+/// ## Errors
+/// If `c`, `r` and the rows of `b` have different lengths, if any of them contains infs or
+/// NaNs, or if a leading principal minor of the matrix is singular.
+///
+/// ## Notes
+/// The solution is computed using Levinson-Durbin recursion, which is faster than generic
+/// least-squares methods, in `O(n^2)` operations instead of `O(n^3)`, but can be less
+/// numerically stable, as it requires every leading principal minor to be nonsingular.
+///
+/// ## References
+/// Wikipedia, "Levinson recursion", <https://en.wikipedia.org/wiki/Levinson_recursion>
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.linalg import solve_toeplitz
+/// c = np.array([1, 3, 6, 10])    # First column of T
+/// r = np.array([1, -1, -2, -3])  # First row of T
+/// b = np.array([1, 2, 2, 5])
+/// solve_toeplitz((c, r), b)
+/// # array([ 1.66666667, -1.        , -2.66666667,  2.33333333])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::solve_toeplitz;
+///
+/// let c = array![1., 3., 6., 10.];
+/// let r = array![1., -1., -2., -3.];
+/// let b = array![1., 2., 2., 5.];
+/// let x = solve_toeplitz(&c, Some(&r), &b).unwrap();
+/// for (x, e) in x.iter().zip([5. / 3., -1., -8. / 3., 7. / 3.]) {
+///     assert_abs_diff_eq!(*x, e, epsilon = 1e-12);
+/// }
+/// ```
+pub fn solve_toeplitz<F, S, T, D>(
+    c: &ArrayBase<S, Ix1>,
+    r: Option<&ArrayBase<S, Ix1>>,
+    b: &ArrayBase<T, D>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+    D: Dimension,
+{
+    let r = r.unwrap_or(c);
+    let n = c.len();
+    if n == 0 || r.len() != n {
+        return Err(Error::InvalidArg {
+            arg: "r".into(),
+            reason: "must not be empty and of the length of c.".into(),
+        });
+    }
+    if b.ndim() == 0 || b.ndim() > 2 || b.shape()[0] != n {
+        return Err(Error::InvalidArg {
+            arg: "b".into(),
+            reason: "must be a vector or matrix with as many rows as c.".into(),
+        });
+    }
+    if c.iter().chain(r).chain(b.iter()).any(|v| !v.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "c".into(),
+            reason: "array must not contain infs or NaNs.".into(),
+        });
+    }
+    let a: Vec<F> = r.iter().skip(1).rev().chain(c).copied().collect();
+    let k = if b.ndim() == 2 { b.shape()[1] } else { 1 };
+    let bm = Array2::from_shape_vec((n, k), b.iter().copied().collect()).unwrap();
+    let mut x = Array2::zeros((n, k));
+    for (mut xc, bc) in x.columns_mut().into_iter().zip(bm.columns()) {
+        let bc: Vec<F> = bc.to_vec();
+        xc.assign(&Array1::from(levinson(&a, &bc)?));
+    }
+    Ok(x.into_shape_with_order(b.raw_dim()).unwrap())
+}
+
+/// Result of [`levinson_durbin`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevinsonDurbinResult<F> {
+    /// Autoregressive coefficients `phi`, with `x[t] = phi[0] x[t-1] + ... + phi[p-1] x[t-p]`
+    /// plus the innovation.
+    pub ar_coefficients: Array1<F>,
+    /// Reflection coefficients, the partial autocorrelations of the lags `1..=p`.
+    pub reflection_coefficients: Array1<F>,
+    /// Variance of the innovation, the error of the linear prediction of order `p`.
+    pub error: F,
+}
+
+/// Solve the Yule-Walker equations of an autoregressive process by the Levinson-Durbin
+/// recursion.
+///
+/// ## Parameters
+/// * `r`: Autocovariances of the process at the lags `0, 1, ...`.
+/// * `order`: Order `p` of the autoregressive model, at most `r.len() - 1`. `r.len() - 1` by
+///   default.
+///
+/// ## Returns
+/// The [`LevinsonDurbinResult`] with the autoregressive and reflection coefficients of the
+/// model, and the variance of its innovation.
+///
+/// ## Errors
+/// If `r` contains infs or NaNs, `order` is larger than `r.len() - 1`, or if the symmetric
+/// Toeplitz matrix of the autocovariances is not positive definite.
+///
+/// ## Notes
+/// The autoregressive coefficients solve the symmetric Toeplitz system of the autocovariances
+/// `r[0..p]` with right hand side `r[1..=p]`, in `O(p^2)` operations. The reflection
+/// coefficients are the last autoregressive coefficient of the models of increasing orders,
+/// all of absolute value less than 1 for a positive definite matrix.
+///
+/// ## Examples
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::linalg::levinson_durbin;
+///
+/// // Autocovariances of x[t] = 0.5 x[t-1] + e[t] with unit innovation variance.
+/// let r = array![4. / 3., 2. / 3., 1. / 3.];
+/// let result = levinson_durbin(&r, None).unwrap();
+/// assert_abs_diff_eq!(result.ar_coefficients[0], 0.5, epsilon = 1e-12);
+/// assert_abs_diff_eq!(result.ar_coefficients[1], 0., epsilon = 1e-12);
+/// assert_abs_diff_eq!(result.reflection_coefficients[0], 0.5, epsilon = 1e-12);
+/// assert_abs_diff_eq!(result.error, 1., epsilon = 1e-12);
+/// ```
+pub fn levinson_durbin<F, S>(
+    r: &ArrayBase<S, Ix1>,
+    order: Option<usize>,
+) -> Result<LevinsonDurbinResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    if r.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "r".into(),
+            reason: "must not be empty.".into(),
+        });
+    }
+    let order = order.unwrap_or(r.len() - 1);
+    if order >= r.len() {
+        return Err(Error::InvalidArg {
+            arg: "order".into(),
+            reason: "must be less than the length of r.".into(),
+        });
+    }
+    if r.iter().any(|v| !v.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "r".into(),
+            reason: "array must not contain infs or NaNs.".into(),
+        });
+    }
+    let mut phi = Array1::zeros(order);
+    let mut reflection = Array1::zeros(order);
+    let mut error = r[0];
+    for k in 0..order {
+        if error <= F::zero() {
+            return Err(Error::InvalidArg {
+                arg: "r".into(),
+                reason: "autocovariance matrix is not positive definite.".into(),
+            });
+        }
+        let acc = (0..k).fold(r[k + 1], |acc, j| acc - phi[j] * r[k - j]);
+        let kappa = acc / error;
+        let previous = phi.clone();
+        for j in 0..k {
+            phi[j] = previous[j] - kappa * previous[k - 1 - j];
+        }
+        phi[k] = kappa;
+        reflection[k] = kappa;
+        error = error * (F::one() - kappa * kappa);
+    }
+    if error <= F::zero() && order > 0 {
+        return Err(Error::InvalidArg {
+            arg: "r".into(),
+            reason: "autocovariance matrix is not positive definite.".into(),
+        });
+    }
+    Ok(LevinsonDurbinResult {
+        ar_coefficients: phi,
+        reflection_coefficients: reflection,
+        error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::{solve, toeplitz};
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, s};
+
+    #[test]
+    fn matches_dense_solve() {
+        let c = array![4., -1., 0.5, 2., -3.];
+        let r = array![4., 2., -1., 0.25, 1.];
+        let b = array![[1., 0.], [2., 1.], [-1., 0.], [0.5, 3.], [3., -2.]];
+        for r in [Some(&r), None] {
+            let x = solve_toeplitz(&c, r, &b).unwrap();
+            let expected = solve(&toeplitz(&c, r), &b, None).unwrap();
+            for (x, e) in x.iter().zip(&expected) {
+                assert_abs_diff_eq!(*x, *e, epsilon = 1e-12);
+            }
+        }
+        assert_eq!(
+            solve_toeplitz(&array![2.], None, &array![3.]).unwrap(),
+            array![1.5]
+        );
+    }
+
+    #[test]
+    fn singular_minors() {
+        // Nonsingular, but its leading principal minor is.
+        let c = array![0., 1.];
+        assert!(solve_toeplitz(&c, None, &array![1., 1.]).is_err());
+        assert!(solve_toeplitz(&c, Some(&array![0.]), &array![1., 1.]).is_err());
+        assert!(levinson_durbin(&array![1., 1., 1.], None).is_err());
+    }
+
+    #[test]
+    fn yule_walker() {
+        let r = array![3., 2., 1.5, 1., 0.2];
+        let result = levinson_durbin(&r, None).unwrap();
+        // The coefficients solve the Yule-Walker equations.
+        let expected = solve_toeplitz(&r.slice(s![..4]), None, &r.slice(s![1..])).unwrap();
+        for (phi, e) in result.ar_coefficients.iter().zip(&expected) {
+            assert_abs_diff_eq!(*phi, *e, epsilon = 1e-12);
+        }
+        let error = r[0] - result.ar_coefficients.dot(&r.slice(s![1..]));
+        assert_abs_diff_eq!(result.error, error, epsilon = 1e-12);
+        // The reflection coefficients are the last coefficients of the lower orders.
+        for p in 1..=4 {
+            let lower = levinson_durbin(&r, Some(p)).unwrap();
+            assert_abs_diff_eq!(
+                lower.ar_coefficients[p - 1],
+                result.reflection_coefficients[p - 1],
+                epsilon = 1e-12
+            );
+        }
+    }
+}
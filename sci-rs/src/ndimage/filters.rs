@@ -0,0 +1,1208 @@
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use alloc::vec::Vec;
+use ndarray::{
+    Array, Array1, ArrayBase, ArrayView1, ArrayViewD, ArrayViewMut1, Axis, Data, Dimension, Zip,
+};
+use num_traits::{Float, Zero};
+use sci_rs_core::{Error, Result};
+
+/// How the filters extend the input beyond its boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoundaryMode {
+    /// Reflection about the edge of the last pixel, `(d c b a | a b c d | d c b a)`.
+    #[default]
+    Reflect,
+    /// Filled with the constant value `cval`, `(k k k k | a b c d | k k k k)`.
+    Constant,
+    /// Replication of the last pixel, `(a a a a | a b c d | d d d d)`.
+    Nearest,
+    /// Reflection about the center of the last pixel, `(d c b | a b c d | c b a)`.
+    Mirror,
+    /// Wrapping around to the opposite edge, `(a b c d | a b c d | a b c d)`.
+    Wrap,
+}
+
+impl BoundaryMode {
+    /// Index into a line of length `n` of the extended line at `i`, or `None` for `cval`.
+    pub(crate) fn index(self, i: isize, n: usize) -> Option<usize> {
+        let n = n as isize;
+        if (0..n).contains(&i) {
+            return Some(i as usize);
+        }
+        let i = match self {
+            BoundaryMode::Constant => return None,
+            BoundaryMode::Nearest => i.clamp(0, n - 1),
+            BoundaryMode::Wrap => i.rem_euclid(n),
+            BoundaryMode::Reflect => {
+                let i = i.rem_euclid(2 * n);
+                if i < n {
+                    i
+                } else {
+                    2 * n - 1 - i
+                }
+            }
+            BoundaryMode::Mirror if n == 1 => 0,
+            BoundaryMode::Mirror => {
+                let i = i.rem_euclid(2 * n - 2);
+                if i < n {
+                    i
+                } else {
+                    2 * n - 2 - i
+                }
+            }
+        };
+        Some(i as usize)
+    }
+
+    /// Value of the extended `input` at `position + offset`, using `index` as scratch space.
+    pub(crate) fn get<T: Copy>(
+        self,
+        input: &ArrayViewD<T>,
+        position: &[usize],
+        offset: &[isize],
+        cval: T,
+        index: &mut [usize],
+    ) -> T {
+        for (a, i) in index.iter_mut().enumerate() {
+            match self.index(position[a] as isize + offset[a], input.len_of(Axis(a))) {
+                Some(j) => *i = j,
+                None => return cval,
+            }
+        }
+        input[&*index]
+    }
+
+    /// Fill `buffer` with `line` extended by `before` samples before and the rest after it.
+    pub(crate) fn extend<T: Copy>(
+        self,
+        line: ArrayView1<T>,
+        before: isize,
+        cval: T,
+        buffer: &mut [T],
+    ) {
+        let n = line.len();
+        for (k, b) in buffer.iter_mut().enumerate() {
+            *b = match self.index(k as isize - before, n) {
+                Some(i) => line[i],
+                None => cval,
+            };
+        }
+    }
+}
+
+/// Broadcast a per-axis parameter given either once or once for each of the `ndim` axes.
+pub(crate) fn per_axis<T: Copy>(values: &[T], ndim: usize, arg: &str) -> Result<Vec<T>> {
+    match values.len() {
+        1 => Ok(alloc::vec![values[0]; ndim]),
+        len if len == ndim => Ok(values.to_vec()),
+        _ => Err(Error::InvalidArg {
+            arg: arg.into(),
+            reason: "must have one value, or one for each axis of the input.".into(),
+        }),
+    }
+}
+
+/// Elements of the neighborhood of each output position, for the filters taking either a
+/// `size` or a boolean `footprint`.
+pub(crate) struct Footprint {
+    /// Shape of the footprint.
+    pub shape: Vec<usize>,
+    /// Shift of the footprint center along each axis.
+    pub origin: Vec<isize>,
+    /// Offsets of the selected elements from each output position, in logical order.
+    pub offsets: Vec<Vec<isize>>,
+}
+
+impl Footprint {
+    /// The footprint of `footprint`, or else of the box of shape `size`, with its center
+    /// shifted by `origin`.
+    pub fn new<D: Dimension>(
+        ndim: usize,
+        size: Option<&[usize]>,
+        footprint: Option<&Array<bool, D>>,
+        origin: Option<&[isize]>,
+    ) -> Result<Self> {
+        let footprint = match (footprint, size) {
+            (Some(footprint), _) => footprint.view().into_dyn(),
+            (None, Some(size)) => {
+                let shape = per_axis(size, ndim, "size")?;
+                return Self::new(ndim, None, Some(&Array::from_elem(shape, true)), origin);
+            }
+            (None, None) => {
+                return Err(Error::InvalidArg {
+                    arg: "size".into(),
+                    reason: "no footprint provided.".into(),
+                })
+            }
+        };
+        if footprint.ndim() != ndim {
+            return Err(Error::InvalidArg {
+                arg: "footprint".into(),
+                reason: "must have the dimensions of the input.".into(),
+            });
+        }
+        let shape = footprint.shape().to_vec();
+        if shape.contains(&0) {
+            return Err(Error::InvalidArg {
+                arg: "size".into(),
+                reason: "must be at least 1.".into(),
+            });
+        }
+        let origin = per_axis(origin.unwrap_or(&[0]), ndim, "origin")?;
+        let in_range = |(&size, &origin): (&usize, &isize)| {
+            let size1 = (size / 2) as isize;
+            -size1 <= origin && origin <= (size as isize - 1) / 2
+        };
+        if !shape.iter().zip(&origin).all(in_range) {
+            return Err(Error::InvalidArg {
+                arg: "origin".into(),
+                reason: "invalid origin for the filter size.".into(),
+            });
+        }
+        let offsets = footprint
+            .indexed_iter()
+            .filter(|(_, &selected)| selected)
+            .map(|(index, _)| {
+                (0..ndim)
+                    .map(|a| index[a] as isize - (shape[a] / 2) as isize - origin[a])
+                    .collect()
+            })
+            .collect();
+        Ok(Footprint {
+            shape,
+            origin,
+            offsets,
+        })
+    }
+
+    /// Whether all the elements of the footprint are selected.
+    pub fn is_box(&self) -> bool {
+        self.offsets.len() == self.shape.iter().product::<usize>()
+    }
+}
+
+/// Apply `filter` to each lane of `input` along `axis`, extended for a filter of `size`.
+///
+/// The output at `i` of a lane is computed from `buffer[i..i + size]`, which holds the input
+/// from `i - size / 2 - origin` on.
+pub(crate) fn filter_lanes<T, S, D>(
+    input: &ArrayBase<S, D>,
+    axis: usize,
+    size: usize,
+    origin: isize,
+    mode: BoundaryMode,
+    cval: T,
+    mut filter: impl FnMut(&[T], ArrayViewMut1<T>),
+) -> Result<Array<T, D>>
+where
+    T: Copy,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let size1 = (size / 2) as isize;
+    if size == 0 {
+        return Err(Error::InvalidArg {
+            arg: "size".into(),
+            reason: "must be at least 1.".into(),
+        });
+    }
+    if origin < -size1 || origin > (size as isize - 1) / 2 {
+        return Err(Error::InvalidArg {
+            arg: "origin".into(),
+            reason: "invalid origin for the filter size.".into(),
+        });
+    }
+    let mut output = Array::from_elem(input.raw_dim(), cval);
+    let n = input.len_of(Axis(axis));
+    if n == 0 {
+        return Ok(output);
+    }
+    let mut buffer = alloc::vec![cval; n + size - 1];
+    Zip::from(output.lanes_mut(Axis(axis)))
+        .and(input.lanes(Axis(axis)))
+        .for_each(|out, line| {
+            mode.extend(line, size1 + origin, cval, &mut buffer);
+            filter(&buffer, out);
+        });
+    Ok(output)
+}
+
+/// Correlate the lanes of `input` along `axis` with `weights`.
+///
+/// The output at `i` is the sum of `weights[j] * input[i + j - weights.len() / 2 - origin]`.
+pub(crate) fn correlate1d<F, S, D>(
+    input: &ArrayBase<S, D>,
+    weights: &[F],
+    axis: usize,
+    mode: BoundaryMode,
+    cval: F,
+    origin: isize,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    filter_lanes(
+        input,
+        axis,
+        weights.len(),
+        origin,
+        mode,
+        cval,
+        |buffer, mut out| {
+            for (i, o) in out.iter_mut().enumerate() {
+                *o = weights
+                    .iter()
+                    .zip(&buffer[i..])
+                    .fold(F::zero(), |acc, (&w, &x)| acc + w * x);
+            }
+        },
+    )
+}
+
+/// Samples of the `order`-th derivative of the Gaussian kernel on `-radius..=radius`.
+fn gaussian_kernel1d<F: Float>(sigma: F, order: usize, radius: usize) -> Array1<F> {
+    let sigma2 = sigma * sigma;
+    let x = Array1::from_iter(
+        (0..2 * radius + 1).map(|i| F::from(i as isize - radius as isize).unwrap()),
+    );
+    let mut phi = x.mapv(|x| (-x * x / (sigma2 + sigma2)).exp());
+    let sum = phi.sum();
+    phi.mapv_inplace(|p| p / sum);
+    if order == 0 {
+        return phi;
+    }
+    // The derivatives are the products of phi with polynomials q, with
+    // q' = dq/dx - x / sigma2 * q.
+    let mut q = alloc::vec![F::zero(); order + 1];
+    q[0] = F::one();
+    for _ in 0..order {
+        q = (0..=order)
+            .map(|i| {
+                let derivative = match q.get(i + 1) {
+                    Some(&c) => F::from(i + 1).unwrap() * c,
+                    None => F::zero(),
+                };
+                match i {
+                    0 => derivative,
+                    _ => derivative - q[i - 1] / sigma2,
+                }
+            })
+            .collect();
+    }
+    Zip::from(&mut phi).and(&x).for_each(|p, &x| {
+        *p = *p * q.iter().rev().fold(F::zero(), |acc, &c| acc * x + c);
+    });
+    phi
+}
+
+/// 1-D Gaussian filter.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `sigma`: Standard deviation for the Gaussian kernel.
+/// * `axis`: The axis of `input` along which to calculate. -1 by default.
+/// * `order`: An order of 0 corresponds to convolution with a Gaussian kernel. A positive
+///   order corresponds to convolution with that derivative of a Gaussian. 0 by default.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Reflect`] by default.
+/// * `cval`: Value to fill past the edges of the input with [`BoundaryMode::Constant`]. 0 by
+///   default.
+/// * `truncate`: Truncate the filter at this many standard deviations. 4 by default.
+/// * `radius`: Radius of the Gaussian kernel, overriding `truncate`. `None` by default.
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// If `sigma` or `truncate` is negative or not finite, or `axis` is out of range.
+///
+/// ## Notes
+/// The Gaussian kernel has size `2 * radius + 1`, with `radius = round(truncate * sigma)`
+/// unless given. A `sigma` of 0 leaves the input unchanged.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.ndimage import gaussian_filter1d
+/// gaussian_filter1d([1.0, 2.0, 3.0, 4.0, 5.0], 1)
+/// # array([1.42704095, 2.06782203, 3.        , 3.93217797, 4.57295905])
+/// gaussian_filter1d([1.0, 2.0, 3.0, 4.0, 5.0], 4)
+/// # array([2.91948343, 2.95023502, 3.        , 3.04976498, 3.08051657])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::ndimage::gaussian_filter1d;
+///
+/// let x = array![1., 2., 3., 4., 5.];
+/// let y = gaussian_filter1d(&x, 1., None, None, None, None, None, None).unwrap();
+/// for (y, e) in y.iter().zip([1.42704095, 2.06782203, 3., 3.93217797, 4.57295905]) {
+///     assert_abs_diff_eq!(*y, e, epsilon = 1e-8);
+/// }
+/// let y = gaussian_filter1d(&x, 4., None, None, None, None, None, None).unwrap();
+/// for (y, e) in y.iter().zip([2.91948343, 2.95023502, 3., 3.04976498, 3.08051657]) {
+///     assert_abs_diff_eq!(*y, e, epsilon = 1e-8);
+/// }
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn gaussian_filter1d<F, S, D>(
+    input: &ArrayBase<S, D>,
+    sigma: F,
+    axis: Option<isize>,
+    order: Option<usize>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    truncate: Option<F>,
+    radius: Option<usize>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, input)?;
+    let truncate = truncate.unwrap_or_else(|| F::from(4).unwrap());
+    if !sigma.is_finite() || sigma < F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "sigma".into(),
+            reason: "must be non-negative and finite.".into(),
+        });
+    }
+    if !truncate.is_finite() || truncate < F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "truncate".into(),
+            reason: "must be non-negative and finite.".into(),
+        });
+    }
+    if sigma.is_zero() {
+        return Ok(input.to_owned());
+    }
+    let radius = radius.unwrap_or_else(|| {
+        (truncate * sigma + F::from(0.5).unwrap())
+            .to_usize()
+            .unwrap()
+    });
+    // Reversed to correlate, which only matters for the odd derivatives.
+    let mut weights = gaussian_kernel1d(sigma, order.unwrap_or(0), radius).to_vec();
+    weights.reverse();
+    correlate1d(
+        input,
+        &weights,
+        axis,
+        mode.unwrap_or_default(),
+        cval.unwrap_or_else(F::zero),
+        0,
+    )
+}
+
+/// Multidimensional Gaussian filter.
+///
+/// The multidimensional filter is implemented as a sequence of 1-D Gaussian filters along
+/// each axis.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `sigma`: Standard deviation for the Gaussian kernel, given once for all axes or once for
+///   each axis.
+/// * `order`: The order of the filter along each axis, given once or once for each axis. An
+///   order of 0 corresponds to convolution with a Gaussian kernel, a positive order to
+///   convolution with that derivative of a Gaussian. 0 by default.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Reflect`] by default.
+/// * `cval`: Value to fill past the edges of the input with [`BoundaryMode::Constant`]. 0 by
+///   default.
+/// * `truncate`: Truncate the filter at this many standard deviations. 4 by default.
+/// * `radius`: Radius of the Gaussian kernel along each axis, given once or once for each
+///   axis, overriding `truncate`. `None` by default.
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// If `sigma`, `order` or `radius` is neither given once nor once for each axis, or `sigma`
+/// or `truncate` is negative or not finite.
+///
+/// ## Notes
+/// Axes with a `sigma` of 0 are not filtered.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.ndimage import gaussian_filter
+/// a = np.arange(0.0, 50.0, 2.0).reshape((5, 5))
+/// gaussian_filter(a, sigma=1)[0]
+/// # array([ 5.1244914 ,  6.40605357,  8.2704095 , 10.13476543, 11.4163276 ])
+/// gaussian_filter(a, sigma=(1, 0))[0]
+/// # array([ 4.2704095,  6.2704095,  8.2704095, 10.2704095, 12.2704095])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::Array;
+/// use sci_rs::ndimage::gaussian_filter;
+///
+/// let a = Array::range(0., 50., 2.).into_shape_with_order((5, 5)).unwrap();
+/// let b = gaussian_filter(&a, &[1.], None, None, None, None, None).unwrap();
+/// for (b, e) in b.row(0).iter().zip([5.1244914, 6.40605357, 8.2704095, 10.13476543, 11.4163276]) {
+///     assert_abs_diff_eq!(*b, e, epsilon = 1e-8);
+/// }
+/// let b = gaussian_filter(&a, &[1., 0.], None, None, None, None, None).unwrap();
+/// for (b, e) in b.row(0).iter().zip([4.2704095, 6.2704095, 8.2704095, 10.2704095, 12.2704095]) {
+///     assert_abs_diff_eq!(*b, e, epsilon = 1e-8);
+/// }
+/// ```
+pub fn gaussian_filter<F, S, D>(
+    input: &ArrayBase<S, D>,
+    sigma: &[F],
+    order: Option<&[usize]>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    truncate: Option<F>,
+    radius: Option<&[usize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let ndim = input.ndim();
+    let sigma = per_axis(sigma, ndim, "sigma")?;
+    let order = per_axis(order.unwrap_or(&[0]), ndim, "order")?;
+    let radius = match radius {
+        Some(radius) => per_axis(radius, ndim, "radius")?
+            .into_iter()
+            .map(Some)
+            .collect(),
+        None => alloc::vec![None; ndim],
+    };
+    let mut output = input.to_owned();
+    for axis in 0..ndim {
+        // The axes with a negligible sigma are left as they are, like in scipy.
+        if sigma[axis] > F::from(1e-15).unwrap() || sigma[axis] < F::zero() {
+            output = gaussian_filter1d(
+                &output,
+                sigma[axis],
+                Some(axis as isize),
+                Some(order[axis]),
+                mode,
+                cval,
+                truncate,
+                radius[axis],
+            )?;
+        }
+    }
+    Ok(output)
+}
+
+/// 1-D uniform filter.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `size`: Length of the uniform filter.
+/// * `axis`: The axis of `input` along which to calculate. -1 by default.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Reflect`] by default.
+/// * `cval`: Value to fill past the edges of the input with [`BoundaryMode::Constant`]. 0 by
+///   default.
+/// * `origin`: Controls the placement of the filter, shifted to the left by positive values.
+///   0 by default.
+///
+/// ## Returns
+/// The moving averages, of the same shape as `input`.
+///
+/// ## Errors
+/// If `size` is 0, `origin` is outside of `-(size / 2)..=(size - 1) / 2`, or `axis` is out of
+/// range.
+///
+/// ## Notes
+/// The averages are computed with running sums, in linear time independently of `size`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.ndimage import uniform_filter1d
+/// uniform_filter1d([2.0, 8, 0, 4, 1, 9, 9, 0], size=3)
+/// # array([4.        , 3.33333333, 4.        , 1.66666667, 4.66666667,
+/// #        6.33333333, 6.        , 3.        ])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::ndimage::uniform_filter1d;
+///
+/// let x = array![2., 8., 0., 4., 1., 9., 9., 0.];
+/// let y = uniform_filter1d(&x, 3, None, None, None, None).unwrap();
+/// let expected = [4., 3.33333333, 4., 1.66666667, 4.66666667, 6.33333333, 6., 3.];
+/// for (y, e) in y.iter().zip(expected) {
+///     assert_abs_diff_eq!(*y, e, epsilon = 1e-8);
+/// }
+/// ```
+pub fn uniform_filter1d<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: usize,
+    axis: Option<isize>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<isize>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, input)?;
+    let scale = F::from(size).unwrap().recip();
+    filter_lanes(
+        input,
+        axis,
+        size,
+        origin.unwrap_or(0),
+        mode.unwrap_or_default(),
+        cval.unwrap_or_else(F::zero),
+        |buffer, mut out| {
+            let mut sum = buffer[..size].iter().fold(F::zero(), |acc, &x| acc + x);
+            for (i, o) in out.iter_mut().enumerate() {
+                if i > 0 {
+                    sum = sum + buffer[i + size - 1] - buffer[i - 1];
+                }
+                *o = sum * scale;
+            }
+        },
+    )
+}
+
+/// Multidimensional uniform filter.
+///
+/// The multidimensional filter is implemented as a sequence of 1-D uniform filters along
+/// each axis.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `size`: The sizes of the uniform filter along the axes, given once for all axes or once
+///   for each axis.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Reflect`] by default.
+/// * `cval`: Value to fill past the edges of the input with [`BoundaryMode::Constant`]. 0 by
+///   default.
+/// * `origin`: Controls the placement of the filter along each axis, given once or once for
+///   each axis. 0 by default.
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// If `size` or `origin` is neither given once nor once for each axis, a size is 0, or an
+/// origin is out of range for its size.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.ndimage import uniform_filter
+/// a = np.arange(0.0, 50.0, 2.0).reshape((5, 5))
+/// uniform_filter(a, size=3)[0]
+/// # array([ 4.        ,  5.33333333,  7.33333333,  9.33333333, 10.66666667])
+/// uniform_filter(a, size=(3, 1), mode='constant')[0]
+/// # array([3.33333333, 4.66666667, 6.        , 7.33333333, 8.66666667])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::Array;
+/// use sci_rs::ndimage::{uniform_filter, BoundaryMode};
+///
+/// let a = Array::range(0., 50., 2.).into_shape_with_order((5, 5)).unwrap();
+/// let b = uniform_filter(&a, &[3], None, None, None).unwrap();
+/// for (b, e) in b.row(0).iter().zip([4., 5.33333333, 7.33333333, 9.33333333, 10.66666667]) {
+///     assert_abs_diff_eq!(*b, e, epsilon = 1e-8);
+/// }
+/// let constant = Some(BoundaryMode::Constant);
+/// let b = uniform_filter(&a, &[3, 1], constant, None, None).unwrap();
+/// for (b, e) in b.row(0).iter().zip([3.33333333, 4.66666667, 6., 7.33333333, 8.66666667]) {
+///     assert_abs_diff_eq!(*b, e, epsilon = 1e-8);
+/// }
+/// ```
+pub fn uniform_filter<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: &[usize],
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let ndim = input.ndim();
+    let size = per_axis(size, ndim, "size")?;
+    let origin = per_axis(origin.unwrap_or(&[0]), ndim, "origin")?;
+    if size.contains(&0) {
+        return Err(Error::InvalidArg {
+            arg: "size".into(),
+            reason: "must be at least 1.".into(),
+        });
+    }
+    let mut output = input.to_owned();
+    for axis in 0..ndim {
+        if size[axis] > 1 {
+            output = uniform_filter1d(
+                &output,
+                size[axis],
+                Some(axis as isize),
+                mode,
+                cval,
+                Some(origin[axis]),
+            )?;
+        }
+    }
+    Ok(output)
+}
+
+/// Calculate a multidimensional filter using the given function.
+///
+/// At each element, the values of the extended input in the footprint are passed to
+/// `function`, in logical order, and the value it returns is the output.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `function`: Function computing the output from the neighborhood values.
+/// * `size`: Shape of the box taken from the input at every element position, given once for
+///   all axes or once for each axis. Ignored if `footprint` is given.
+/// * `footprint`: Boolean array selecting the elements of the neighborhood.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Reflect`] by default.
+/// * `cval`: Value to fill past the edges of the input with [`BoundaryMode::Constant`]. 0 by
+///   default.
+/// * `origin`: Controls the placement of the filter along each axis, given once or once for
+///   each axis. 0 by default.
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// If neither `size` nor `footprint` is given, the footprint has the wrong number of
+/// dimensions, or `origin` is out of range.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// ndimage.generic_filter([2, 8, 0, 4, 1, 9, 9, 0], np.ptp, size=3)
+/// # array([6, 8, 8, 4, 8, 8, 9, 9])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::generic_filter;
+///
+/// let x = array![2, 8, 0, 4, 1, 9, 9, 0];
+/// let ptp = |values: &[i32]| values.iter().max().unwrap() - values.iter().min().unwrap();
+/// let y = generic_filter(&x, ptp, Some(&[3]), None, None, None, None).unwrap();
+/// assert_eq!(y, array![6, 8, 8, 4, 8, 8, 9, 9]);
+/// ```
+pub fn generic_filter<T, S, D>(
+    input: &ArrayBase<S, D>,
+    mut function: impl FnMut(&[T]) -> T,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<T>,
+    origin: Option<&[isize]>,
+) -> Result<Array<T, D>>
+where
+    T: Copy + Zero,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let footprint = Footprint::new(input.ndim(), size, footprint, origin)?;
+    let mode = mode.unwrap_or_default();
+    let cval = cval.unwrap_or_else(T::zero);
+    let view = input.view().into_dyn();
+    let mut values = Vec::with_capacity(footprint.offsets.len());
+    let mut index = alloc::vec![0; input.ndim()];
+    let mut output = Array::from_elem(view.raw_dim(), cval);
+    for (position, out) in output.indexed_iter_mut() {
+        values.clear();
+        values.extend(
+            footprint
+                .offsets
+                .iter()
+                .map(|o| mode.get(&view, position.slice(), o, cval, &mut index)),
+        );
+        *out = function(&values);
+    }
+    Ok(output.into_dimensionality().unwrap())
+}
+
+/// Derivative along `axis` by `derivative`, smoothed along the other axes by `smooth`.
+fn derivative_filter<F, S, D>(
+    input: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    derivative: [F; 3],
+    smooth: [F; 3],
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, input)?;
+    let mode = mode.unwrap_or_default();
+    let cval = cval.unwrap_or_else(F::zero);
+    let mut output = correlate1d(input, &derivative, axis, mode, cval, 0)?;
+    for other in (0..input.ndim()).filter(|&other| other != axis) {
+        output = correlate1d(&output, &smooth, other, mode, cval, 0)?;
+    }
+    Ok(output)
+}
+
+/// Calculate a Sobel filter.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `axis`: The axis of `input` along which to calculate the derivative. -1 by default.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Reflect`] by default.
+/// * `cval`: Value to fill past the edges of the input with [`BoundaryMode::Constant`]. 0 by
+///   default.
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// If `axis` is out of range.
+///
+/// ## Notes
+/// The derivative is the correlation with `[-1, 0, 1]` along `axis`, smoothed by the
+/// correlation with `[1, 2, 1]` along the other axes.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.array([[0.0, 2, 1, 0], [3, 1, 0, 2], [1, 4, 2, 1], [0, 1, 3, 5]])
+/// ndimage.sobel(a, axis=0)
+/// # array([[ 8.,  0., -1.,  5.],
+/// #        [ 5.,  6.,  5.,  4.],
+/// #        [-9.,  0.,  9., 12.],
+/// #        [-6., -6.,  3., 13.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::sobel;
+///
+/// let a = array![[0., 2., 1., 0.], [3., 1., 0., 2.], [1., 4., 2., 1.], [0., 1., 3., 5.]];
+/// let expected = array![
+///     [8., 0., -1., 5.],
+///     [5., 6., 5., 4.],
+///     [-9., 0., 9., 12.],
+///     [-6., -6., 3., 13.]
+/// ];
+/// assert_eq!(sobel(&a, Some(0), None, None).unwrap(), expected);
+/// ```
+pub fn sobel<F, S, D>(
+    input: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let (zero, one, two) = (F::zero(), F::one(), F::from(2).unwrap());
+    derivative_filter(input, axis, [-one, zero, one], [one, two, one], mode, cval)
+}
+
+/// Calculate a Prewitt filter.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `axis`, `mode`, `cval`: As for [`sobel`].
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// If `axis` is out of range.
+///
+/// ## Notes
+/// The derivative is the correlation with `[-1, 0, 1]` along `axis`, smoothed by the
+/// correlation with `[1, 1, 1]` along the other axes.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.array([[0.0, 2, 1, 0], [3, 1, 0, 2], [1, 4, 2, 1], [0, 1, 3, 5]])
+/// ndimage.prewitt(a)
+/// # array([[ 2., -1., -3.,  0.],
+/// #        [ 3., -1., -4.,  0.],
+/// #        [ 2.,  1.,  2.,  3.],
+/// #        [ 5.,  7.,  5.,  3.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::prewitt;
+///
+/// let a = array![[0., 2., 1., 0.], [3., 1., 0., 2.], [1., 4., 2., 1.], [0., 1., 3., 5.]];
+/// let expected = array![
+///     [2., -1., -3., 0.],
+///     [3., -1., -4., 0.],
+///     [2., 1., 2., 3.],
+///     [5., 7., 5., 3.]
+/// ];
+/// assert_eq!(prewitt(&a, None, None, None).unwrap(), expected);
+/// ```
+pub fn prewitt<F, S, D>(
+    input: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let (zero, one) = (F::zero(), F::one());
+    derivative_filter(input, axis, [-one, zero, one], [one, one, one], mode, cval)
+}
+
+/// Sum over the axes of the second derivatives computed by `derivative2` along each axis.
+fn generic_laplace<F, S, D>(
+    input: &ArrayBase<S, D>,
+    mut derivative2: impl FnMut(usize) -> Result<Array<F, D>>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let mut output = Array::zeros(input.raw_dim());
+    for axis in 0..input.ndim() {
+        output = output + derivative2(axis)?;
+    }
+    Ok(output)
+}
+
+/// N-D Laplace filter based on approximate second derivatives.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Reflect`] by default.
+/// * `cval`: Value to fill past the edges of the input with [`BoundaryMode::Constant`]. 0 by
+///   default.
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Notes
+/// The second derivatives are the correlations with `[1, -2, 1]` along each axis.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.array([[0.0, 2, 1, 0], [3, 1, 0, 2], [1, 4, 2, 1], [0, 1, 3, 5]])
+/// ndimage.laplace(a)
+/// # array([[  5.,  -4.,  -1.,   3.],
+/// #        [ -7.,   5.,   6.,  -5.],
+/// #        [  4., -11.,   0.,   6.],
+/// #        [  2.,   4.,  -1.,  -6.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::laplace;
+///
+/// let a = array![[0., 2., 1., 0.], [3., 1., 0., 2.], [1., 4., 2., 1.], [0., 1., 3., 5.]];
+/// let expected = array![
+///     [5., -4., -1., 3.],
+///     [-7., 5., 6., -5.],
+///     [4., -11., 0., 6.],
+///     [2., 4., -1., -6.]
+/// ];
+/// assert_eq!(laplace(&a, None, None).unwrap(), expected);
+/// ```
+pub fn laplace<F, S, D>(
+    input: &ArrayBase<S, D>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let weights = [F::one(), -F::from(2).unwrap(), F::one()];
+    let (mode, cval) = (mode.unwrap_or_default(), cval.unwrap_or_else(F::zero));
+    generic_laplace(input, |axis| {
+        correlate1d(input, &weights, axis, mode, cval, 0)
+    })
+}
+
+/// Multidimensional Laplace filter using Gaussian second derivatives.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `sigma`: Standard deviation for the Gaussian kernel, given once for all axes or once for
+///   each axis.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Reflect`] by default.
+/// * `cval`: Value to fill past the edges of the input with [`BoundaryMode::Constant`]. 0 by
+///   default.
+/// * `truncate`: Truncate the filter at this many standard deviations. 4 by default.
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// As for [`gaussian_filter`].
+///
+/// ## Notes
+/// The result is the sum over the axes of the [`gaussian_filter`] with an order of 2 along
+/// the axis.
+pub fn gaussian_laplace<F, S, D>(
+    input: &ArrayBase<S, D>,
+    sigma: &[F],
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    truncate: Option<F>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let mut order = alloc::vec![0; input.ndim()];
+    generic_laplace(input, |axis| {
+        order[axis] = 2;
+        let derivative = gaussian_filter(input, sigma, Some(&order), mode, cval, truncate, None);
+        order[axis] = 0;
+        derivative
+    })
+}
+
+/// Multidimensional gradient magnitude using Gaussian derivatives.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `sigma`, `mode`, `cval`, `truncate`: As for [`gaussian_laplace`].
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// As for [`gaussian_filter`].
+///
+/// ## Notes
+/// The result is the square root of the sum over the axes of the squared
+/// [`gaussian_filter`] with an order of 1 along the axis.
+pub fn gaussian_gradient_magnitude<F, S, D>(
+    input: &ArrayBase<S, D>,
+    sigma: &[F],
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    truncate: Option<F>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let mut order = alloc::vec![0; input.ndim()];
+    let mut output = Array::zeros(input.raw_dim());
+    for axis in 0..input.ndim() {
+        order[axis] = 1;
+        let derivative = gaussian_filter(input, sigma, Some(&order), mode, cval, truncate, None)?;
+        order[axis] = 0;
+        Zip::from(&mut output)
+            .and(&derivative)
+            .for_each(|o, &d| *o = *o + d * d);
+    }
+    Ok(output.mapv_into(F::sqrt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array};
+
+    #[test]
+    fn boundary_modes() {
+        let x = array![1., 2., 3., 4.];
+        let extended = |mode| {
+            let mut buffer = [0.; 10];
+            BoundaryMode::extend(mode, x.view(), 3, -1., &mut buffer);
+            buffer
+        };
+        assert_eq!(
+            extended(BoundaryMode::Reflect),
+            [3., 2., 1., 1., 2., 3., 4., 4., 3., 2.]
+        );
+        assert_eq!(
+            extended(BoundaryMode::Mirror),
+            [4., 3., 2., 1., 2., 3., 4., 3., 2., 1.]
+        );
+        assert_eq!(
+            extended(BoundaryMode::Nearest),
+            [1., 1., 1., 1., 2., 3., 4., 4., 4., 4.]
+        );
+        assert_eq!(
+            extended(BoundaryMode::Wrap),
+            [2., 3., 4., 1., 2., 3., 4., 1., 2., 3.]
+        );
+        assert_eq!(
+            extended(BoundaryMode::Constant),
+            [-1., -1., -1., 1., 2., 3., 4., -1., -1., -1.]
+        );
+
+        let y = correlate1d(&x, &[1., 1., 1.], 0, BoundaryMode::Constant, 0., 0).unwrap();
+        assert_eq!(y, array![3., 6., 9., 7.]);
+        let y = correlate1d(&x, &[1., 1., 1.], 0, BoundaryMode::Wrap, 0., 1).unwrap();
+        assert_eq!(y, array![8., 7., 6., 9.]);
+        assert!(correlate1d(&x, &[1., 1., 1.], 0, BoundaryMode::Wrap, 0., 2).is_err());
+    }
+
+    #[test]
+    fn gaussian_derivatives() {
+        let x = array![0., 1., 4., 9., 16., 25., 36.];
+        // Close to the derivatives of a parabola, 6 and 2, away from the boundaries.
+        let first = gaussian_filter1d(&x, 1., None, Some(1), None, None, None, None).unwrap();
+        let second = gaussian_filter1d(&x, 1., None, Some(2), None, None, None, None).unwrap();
+        assert_abs_diff_eq!(first[3], 5.99314412898092, epsilon = 1e-12);
+        assert_abs_diff_eq!(second[3], 1.969461398471029, epsilon = 1e-12);
+        let kernel = gaussian_kernel1d(2., 0, 8);
+        assert_abs_diff_eq!(kernel.sum(), 1., epsilon = 1e-15);
+        assert_abs_diff_eq!(kernel[8] / kernel[6], 0.5f64.exp(), epsilon = 1e-12);
+
+        // Filtering is separable and constants are preserved.
+        let image = Array::from_elem((3, 4, 5), 2.);
+        let smoothed = gaussian_filter(&image, &[1., 2., 0.5], None, None, None, None, None);
+        for (s, i) in smoothed.unwrap().iter().zip(&image) {
+            assert_abs_diff_eq!(*s, *i, epsilon = 1e-12);
+        }
+        let smoothed = gaussian_filter(&image, &[1., 2.], None, None, None, None, None);
+        assert!(smoothed.is_err());
+        let radius = gaussian_filter1d(&x, 1., None, None, None, None, None, Some(1)).unwrap();
+        let side = (-0.5f64).exp();
+        assert_abs_diff_eq!(radius[1], (x[1] + (x[0] + x[2]) * side) / (1. + 2. * side));
+    }
+
+    #[test]
+    fn uniform_box() {
+        let a = Array::from_shape_fn((4, 6), |(i, j)| ((3 * i + 5 * j) % 7) as f64);
+        for (mode, origin) in [
+            (BoundaryMode::Reflect, [0, 0]),
+            (BoundaryMode::Mirror, [1, -1]),
+            (BoundaryMode::Wrap, [-1, 0]),
+            (BoundaryMode::Constant, [0, 1]),
+        ] {
+            let filtered = uniform_filter(&a, &[3, 4], Some(mode), Some(-2.), Some(&origin));
+            let filtered = filtered.unwrap();
+            // The direct average over the box of each output.
+            for ((i, j), y) in filtered.indexed_iter() {
+                let mut sum = 0.;
+                for di in 0..3 {
+                    for dj in 0..4 {
+                        let ii = i as isize + di - 1 - origin[0];
+                        let jj = j as isize + dj - 2 - origin[1];
+                        sum += match (mode.index(ii, 4), mode.index(jj, 6)) {
+                            (Some(ii), Some(jj)) => a[[ii, jj]],
+                            _ => -2.,
+                        };
+                    }
+                }
+                assert_abs_diff_eq!(*y, sum / 12., epsilon = 1e-12);
+            }
+        }
+        assert!(uniform_filter(&a, &[3, 0], None, None, None).is_err());
+        assert!(uniform_filter(&a, &[3], None, None, Some(&[2])).is_err());
+        assert!(uniform_filter1d(&a, 4, None, None, None, Some(-2)).is_ok());
+    }
+
+    #[test]
+    fn derivative_filters() {
+        // Quadratic along each axis, so the exact Laplacian is 2 + 6 = 8.
+        let a = Array::from_shape_fn((6, 7), |(i, j)| (i * i + 3 * j * j + i * j) as f64);
+        let exact = laplace(&a, None, None).unwrap();
+        assert_eq!(
+            exact.slice(ndarray::s![1..5, 1..6]),
+            Array::from_elem((4, 5), 8.)
+        );
+
+        let sigma = [1., 0.7];
+        let log = gaussian_laplace(&a, &sigma, None, None, None).unwrap();
+        let dxx = gaussian_filter(&a, &sigma, Some(&[2, 0]), None, None, None, None).unwrap();
+        let dyy = gaussian_filter(&a, &sigma, Some(&[0, 2]), None, None, None, None).unwrap();
+        for ((l, x), y) in log.iter().zip(&dxx).zip(&dyy) {
+            assert_abs_diff_eq!(*l, x + y, epsilon = 1e-12);
+        }
+        let magnitude = gaussian_gradient_magnitude(&a, &sigma, None, None, None).unwrap();
+        let dx = gaussian_filter(&a, &sigma, Some(&[1, 0]), None, None, None, None).unwrap();
+        let dy = gaussian_filter(&a, &sigma, Some(&[0, 1]), None, None, None, None).unwrap();
+        for ((m, x), y) in magnitude.iter().zip(&dx).zip(&dy) {
+            assert_abs_diff_eq!(*m, x.hypot(*y), epsilon = 1e-12);
+        }
+
+        // The gradient of a plane, with the weights of the smoothing filters.
+        let plane = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i + 2 * j + 3 * k) as f64);
+        let nearest = Some(BoundaryMode::Nearest);
+        let gradient = sobel(&plane, Some(1), nearest, None).unwrap();
+        assert_eq!(gradient[[1, 2, 2]], 2. * 2. * 4. * 4.);
+        let gradient = prewitt(&plane, Some(-1), nearest, None).unwrap();
+        assert_eq!(gradient[[1, 2, 2]], 3. * 2. * 3. * 3.);
+        assert!(prewitt(&plane, Some(3), None, None).is_err());
+    }
+
+    #[test]
+    fn custom_functions() {
+        let a = Array::from_shape_fn((5, 6), |(i, j)| ((4 * i + 7 * j) % 9) as f64);
+        let footprint = array![[true, false, true], [false, true, true]];
+        let median = |values: &[f64]| {
+            let mut values = values.to_vec();
+            values.sort_by(f64::total_cmp);
+            values[values.len() / 2]
+        };
+        let origin = Some(&[0, -1][..]);
+        let mirror = Some(BoundaryMode::Mirror);
+        let generic = generic_filter(&a, median, None, Some(&footprint), mirror, None, origin);
+        let expected =
+            crate::ndimage::median_filter(&a, None, Some(&footprint), mirror, None, origin);
+        assert_eq!(generic.unwrap(), expected.unwrap());
+
+        // The values are passed in the logical order of the footprint.
+        let x = array![1, 2, 3];
+        let first = |values: &[i32]| values[0] * 10 + values[1];
+        let y = generic_filter(
+            &x,
+            first,
+            Some(&[2]),
+            None,
+            Some(BoundaryMode::Constant),
+            Some(-1),
+            None,
+        );
+        assert_eq!(y.unwrap(), array![-9, 12, 23]);
+        assert!(generic_filter(&x, first, None, None, None, None, None).is_err());
+    }
+}
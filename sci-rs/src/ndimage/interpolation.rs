@@ -0,0 +1,936 @@
+use super::filters::{per_axis, BoundaryMode};
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use alloc::vec::Vec;
+use ndarray::{
+    Array, Array2, ArrayBase, ArrayD, ArrayView2, ArrayViewD, Axis, Data, Dimension, Ix2, IxDyn,
+    RemoveAxis,
+};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Poles of the recursive filter computing the B-spline coefficients of `order`.
+fn spline_poles<F: Float>(order: usize) -> Vec<F> {
+    let c = |x: f64| F::from(x).unwrap();
+    match order {
+        2 => alloc::vec![c(8.).sqrt() - c(3.)],
+        3 => alloc::vec![c(3.).sqrt() - c(2.)],
+        4 => alloc::vec![
+            (c(664.) - c(438976.).sqrt()).sqrt() + c(304.).sqrt() - c(19.),
+            (c(664.) + c(438976.).sqrt()).sqrt() - c(304.).sqrt() - c(19.),
+        ],
+        5 => alloc::vec![
+            (c(67.5) - c(4436.25).sqrt()).sqrt() + c(26.25).sqrt() - c(6.5),
+            (c(67.5) + c(4436.25).sqrt()).sqrt() - c(26.25).sqrt() - c(6.5),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+/// Replace the samples of `line` by the coefficients of the interpolating B-spline, whose
+/// boundary conditions follow `mode`.
+fn spline_filter_line<F: Float>(line: &mut [F], poles: &[F], mode: BoundaryMode) {
+    let n = line.len();
+    if n < 2 || poles.is_empty() {
+        return;
+    }
+    let one = F::one();
+    let gain = poles
+        .iter()
+        .fold(one, |gain, &z| gain * (one - z) * (one - one / z));
+    line.iter_mut().for_each(|x| *x = *x * gain);
+    for &z in poles {
+        // Initial causal coefficient, summing the extended line backwards from the start.
+        let c0 = line[0];
+        line[0] = match mode {
+            BoundaryMode::Wrap => {
+                let mut zi = one;
+                let mut sum = c0;
+                for &c in line[1..].iter().rev() {
+                    zi = zi * z;
+                    sum = sum + zi * c;
+                }
+                sum / (one - zi * z)
+            }
+            BoundaryMode::Reflect | BoundaryMode::Nearest => {
+                let zn = z.powi(n as i32);
+                let mut zi = one;
+                let mut sum = F::zero();
+                for i in 0..n {
+                    sum = sum + zi * (line[i] + zn * line[n - 1 - i]);
+                    zi = zi * z;
+                }
+                c0 + sum * z / (one - zn * zn)
+            }
+            _ => {
+                let zn = z.powi(n as i32 - 1);
+                let mut zi = z;
+                let mut sum = c0 + zn * line[n - 1];
+                for i in 1..n - 1 {
+                    sum = sum + zi * (line[i] + zn * line[n - 1 - i]);
+                    zi = zi * z;
+                }
+                sum / (one - zn * zn)
+            }
+        };
+        for i in 1..n {
+            line[i] = line[i] + z * line[i - 1];
+        }
+        // Initial anticausal coefficient, from the symmetry or periodicity of the result.
+        line[n - 1] = match mode {
+            BoundaryMode::Wrap => {
+                let mut zi = one;
+                let mut sum = line[n - 1];
+                for &c in &line[..n - 1] {
+                    zi = zi * z;
+                    sum = sum + zi * c;
+                }
+                sum * z / (zi * z - one)
+            }
+            BoundaryMode::Reflect | BoundaryMode::Nearest => line[n - 1] * z / (z - one),
+            _ => (z * line[n - 2] + line[n - 1]) * z / (z * z - one),
+        };
+        for i in (0..n - 1).rev() {
+            line[i] = z * (line[i + 1] - line[i]);
+        }
+    }
+}
+
+/// Centered B-spline of `order` at `t`, as a sum of truncated powers.
+fn bspline<F: Float>(order: usize, t: F) -> F {
+    let half = F::from(order + 1).unwrap() / F::from(2).unwrap();
+    let mut binomial = F::one();
+    let mut sum = F::zero();
+    for j in 0..=order + 1 {
+        let x = t + half - F::from(j).unwrap();
+        if x > F::zero() {
+            let term = binomial * x.powi(order as i32);
+            sum = if j % 2 == 0 { sum + term } else { sum - term };
+        }
+        binomial = binomial * F::from(order + 1 - j).unwrap() / F::from(j + 1).unwrap();
+    }
+    (1..=order).fold(sum, |sum, k| sum / F::from(k).unwrap())
+}
+
+/// B-spline interpolation of an array at arbitrary coordinates.
+struct Spline<F> {
+    coefficients: ArrayD<F>,
+    shape: Vec<usize>,
+    order: usize,
+    mode: BoundaryMode,
+    cval: F,
+    /// Whether the input is padded with `cval` rather than only sampled within its bounds.
+    grid_constant: bool,
+    /// Samples padded on each side of the input before computing the coefficients.
+    pad: usize,
+    indices: Vec<Vec<usize>>,
+    weights: Vec<Vec<F>>,
+    taps: Vec<usize>,
+    index: Vec<usize>,
+}
+
+impl<F: Float> Spline<F> {
+    fn new(
+        input: ArrayViewD<F>,
+        order: Option<usize>,
+        mode: Option<BoundaryMode>,
+        cval: Option<F>,
+        prefilter: Option<bool>,
+        grid_constant: bool,
+    ) -> Result<Self> {
+        let order = order.unwrap_or(3);
+        if order > 5 {
+            return Err(Error::InvalidArg {
+                arg: "order".into(),
+                reason: "must be between 0 and 5.".into(),
+            });
+        }
+        let mode = mode.unwrap_or(BoundaryMode::Constant);
+        let cval = cval.unwrap_or_else(F::zero);
+        let grid_constant = grid_constant && mode == BoundaryMode::Constant;
+        let shape = input.shape().to_vec();
+        let ndim = shape.len();
+        let prefilter = prefilter.unwrap_or(true) && order > 1;
+        // The coefficients of the edge and constant extensions are those of an input padded
+        // far enough for the boundary conditions not to matter.
+        let pad = if grid_constant || (prefilter && mode == BoundaryMode::Nearest) {
+            12
+        } else {
+            0
+        };
+        let mut coefficients = if pad > 0 {
+            let padded: Vec<usize> = shape.iter().map(|&s| s + 2 * pad).collect();
+            let extension = if grid_constant {
+                BoundaryMode::Constant
+            } else {
+                BoundaryMode::Nearest
+            };
+            let offset = alloc::vec![-(pad as isize); ndim];
+            let mut index = alloc::vec![0; ndim];
+            ArrayD::from_shape_fn(padded, |i| {
+                extension.get(&input, i.slice(), &offset, cval, &mut index)
+            })
+        } else {
+            input.to_owned()
+        };
+        if prefilter {
+            let poles = spline_poles(order);
+            for axis in 0..ndim {
+                for mut lane in coefficients.lanes_mut(Axis(axis)) {
+                    let mut line = lane.to_vec();
+                    spline_filter_line(&mut line, &poles, mode);
+                    lane.iter_mut().zip(line).for_each(|(c, x)| *c = x);
+                }
+            }
+        }
+        Ok(Self {
+            coefficients,
+            shape,
+            order,
+            mode,
+            cval,
+            grid_constant,
+            pad,
+            indices: alloc::vec![alloc::vec![0; order + 1]; ndim],
+            weights: alloc::vec![alloc::vec![F::zero(); order + 1]; ndim],
+            taps: alloc::vec![0; ndim],
+            index: alloc::vec![0; ndim],
+        })
+    }
+
+    /// Value of the spline at the input coordinates `x`.
+    fn at(&mut self, x: &[F]) -> F {
+        let order = self.order;
+        let tolerance = F::epsilon().sqrt();
+        for (a, &x) in x.iter().enumerate() {
+            let n = self.shape[a];
+            let last = F::from(n.max(1) - 1).unwrap();
+            let mut x = x;
+            if x.is_nan() {
+                return self.cval;
+            }
+            match self.mode {
+                BoundaryMode::Constant if self.grid_constant => {
+                    let margin = F::from(order + 1).unwrap();
+                    if x < -margin || x > last + margin {
+                        return self.cval;
+                    }
+                }
+                BoundaryMode::Constant => {
+                    if x < -tolerance || x > last + tolerance {
+                        return self.cval;
+                    }
+                    x = x.max(F::zero()).min(last);
+                }
+                BoundaryMode::Nearest => x = x.max(F::zero()).min(last),
+                _ if !x.is_finite() => return self.cval,
+                _ if n == 1 => x = F::zero(),
+                mode => {
+                    // The extended spline is periodic, so keep the coordinates small.
+                    let period = F::from(match mode {
+                        BoundaryMode::Wrap => n,
+                        BoundaryMode::Reflect => 2 * n,
+                        _ => 2 * n - 2,
+                    })
+                    .unwrap();
+                    x = x - (x / period).floor() * period;
+                }
+            }
+            let x = x + F::from(self.pad).unwrap();
+            let nearest = if order % 2 == 1 {
+                x.floor()
+            } else {
+                (x + F::from(0.5).unwrap()).floor()
+            };
+            let start = nearest.to_isize().unwrap() - (order / 2) as isize;
+            let len = self.coefficients.len_of(Axis(a));
+            // The padding extends past the support of the spline at the points within the
+            // margin of a constant extension.
+            let extension = match self.mode {
+                BoundaryMode::Constant if self.grid_constant => BoundaryMode::Nearest,
+                BoundaryMode::Constant => BoundaryMode::Mirror,
+                mode => mode,
+            };
+            for k in 0..=order {
+                let i = start + k as isize;
+                self.indices[a][k] = extension.index(i, len).unwrap();
+                self.weights[a][k] = if order == 0 {
+                    F::one()
+                } else {
+                    bspline(order, x - F::from(i).unwrap())
+                };
+            }
+        }
+        // Sum over all the combinations of the coefficients along each axis.
+        let ndim = self.shape.len();
+        self.taps.iter_mut().for_each(|t| *t = 0);
+        let mut value = F::zero();
+        loop {
+            let mut weight = F::one();
+            for a in 0..ndim {
+                self.index[a] = self.indices[a][self.taps[a]];
+                weight = weight * self.weights[a][self.taps[a]];
+            }
+            value = value + weight * self.coefficients[&*self.index];
+            let mut a = ndim;
+            loop {
+                if a == 0 {
+                    return value;
+                }
+                a -= 1;
+                self.taps[a] += 1;
+                if self.taps[a] <= order {
+                    break;
+                }
+                self.taps[a] = 0;
+            }
+        }
+    }
+
+    /// Array of `shape` whose values are those of the spline at the input coordinates
+    /// computed by `mapping` from the output coordinates.
+    fn transform(
+        &mut self,
+        shape: &[usize],
+        mut mapping: impl FnMut(&[usize], &mut [F]),
+    ) -> ArrayD<F> {
+        let mut x = alloc::vec![F::zero(); self.shape.len()];
+        ArrayD::from_shape_fn(IxDyn(shape), |o| {
+            mapping(o.slice(), &mut x);
+            self.at(&x)
+        })
+    }
+
+    /// Affine transformation of the output coordinates into the input coordinates.
+    fn affine(&mut self, matrix: ArrayView2<F>, offset: &[F], shape: &[usize]) -> ArrayD<F> {
+        self.transform(shape, |o, x| {
+            for (a, x) in x.iter_mut().enumerate() {
+                *x = o
+                    .iter()
+                    .zip(matrix.row(a))
+                    .fold(offset[a], |acc, (&o, &m)| acc + m * F::from(o).unwrap());
+            }
+        })
+    }
+}
+
+/// Map the input array to new coordinates by interpolation.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `coordinates`: The coordinates at which `input` is evaluated, along the first axis.
+/// * `order`: The order of the spline interpolation, from 0 to 5. 3 by default.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Constant`] by default.
+/// * `cval`: Value of the points outside the boundaries of the input with
+///   [`BoundaryMode::Constant`]. 0 by default.
+/// * `prefilter`: Whether `input` is prefiltered into the coefficients of the interpolating
+///   spline when `order` is greater than 1. Otherwise it is taken as the coefficients, which
+///   smooths the result. `true` by default.
+///
+/// ## Returns
+/// The interpolated values, of the shape of `coordinates` without its first axis.
+///
+/// ## Errors
+/// If the length of the first axis of `coordinates` differs from the number of dimensions of
+/// `input`, or `order` is greater than 5.
+///
+/// ## Notes
+/// With [`BoundaryMode::Constant`], the points outside the boundaries of the input take the
+/// value `cval` and no interpolation is performed beyond its edges. [`BoundaryMode::Wrap`]
+/// is periodic with the length of the input, like scipy's `grid-wrap`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.arange(12.).reshape((4, 3))
+/// ndimage.map_coordinates(a, [[0.5, 2], [0.5, 1]], order=1)
+/// # array([2., 7.])
+/// inds = np.array([[0.5, 2], [0.5, 4]])
+/// ndimage.map_coordinates(a, inds, order=1, cval=-33.3)
+/// # array([  2. , -33.3])
+/// ndimage.map_coordinates(a, inds, order=1, mode='nearest')
+/// # array([2., 8.])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{array, Array};
+/// use sci_rs::ndimage::{map_coordinates, BoundaryMode};
+///
+/// let a = Array::range(0., 12., 1.).into_shape_with_order((4, 3)).unwrap();
+/// let y = map_coordinates(&a, &array![[0.5, 2.], [0.5, 1.]], Some(1), None, None, None);
+/// assert_eq!(y.unwrap(), array![2., 7.]);
+/// let inds = array![[0.5, 2.], [0.5, 4.]];
+/// let y = map_coordinates(&a, &inds, Some(1), None, Some(-33.3), None);
+/// assert_eq!(y.unwrap(), array![2., -33.3]);
+/// let y = map_coordinates(&a, &inds, Some(1), Some(BoundaryMode::Nearest), None, None);
+/// assert_eq!(y.unwrap(), array![2., 8.]);
+/// ```
+pub fn map_coordinates<F, S, S2, D, E>(
+    input: &ArrayBase<S, D>,
+    coordinates: &ArrayBase<S2, E>,
+    order: Option<usize>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    prefilter: Option<bool>,
+) -> Result<Array<F, E::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    S2: Data<Elem = F>,
+    D: Dimension,
+    E: RemoveAxis,
+{
+    let ndim = input.ndim();
+    if coordinates.len_of(Axis(0)) != ndim {
+        return Err(Error::InvalidArg {
+            arg: "coordinates".into(),
+            reason: "first axis must have the length of the number of dimensions of input.".into(),
+        });
+    }
+    let mut spline = Spline::new(input.view().into_dyn(), order, mode, cval, prefilter, false)?;
+    let coordinates = coordinates.view().into_dyn();
+    let mut index = alloc::vec![0; coordinates.ndim()];
+    let output = spline.transform(&coordinates.shape()[1..], |o, x| {
+        index[1..].copy_from_slice(o);
+        for (a, x) in x.iter_mut().enumerate() {
+            index[0] = a;
+            *x = coordinates[&*index];
+        }
+    });
+    Ok(output.into_dimensionality().unwrap())
+}
+
+/// Apply an affine transformation.
+///
+/// The value of the output at the coordinates `o` is that of the input at
+/// `matrix · o + offset`, found by spline interpolation.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `matrix`: The `(ndim, ndim)` matrix of the transformation, or the `(ndim, ndim + 1)` or
+///   `(ndim + 1, ndim + 1)` matrix in homogeneous coordinates, whose last column is the
+///   offset.
+/// * `offset`: The offset into the input, given once or once for each axis. 0 by default, and
+///   ignored for a matrix in homogeneous coordinates.
+/// * `output_shape`: The shape of the output. The shape of `input` by default.
+/// * `order`: The order of the spline interpolation, from 0 to 5. 3 by default.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Constant`] by default.
+/// * `cval`: Value of the points outside the boundaries of the input with
+///   [`BoundaryMode::Constant`]. 0 by default.
+/// * `prefilter`: Whether `input` is prefiltered into the coefficients of the interpolating
+///   spline when `order` is greater than 1. `true` by default.
+///
+/// ## Returns
+/// The transformed array.
+///
+/// ## Errors
+/// If `matrix` or `offset` does not match the number of dimensions of `input`,
+/// `output_shape` has a different number of dimensions, or `order` is greater than 5.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.arange(12.).reshape((4, 3))
+/// ndimage.affine_transform(a, [[0, 1], [1, 0]], output_shape=(3, 4), order=1)
+/// # array([[ 0.,  3.,  6.,  9.],
+/// #        [ 1.,  4.,  7., 10.],
+/// #        [ 2.,  5.,  8., 11.]])
+/// ndimage.affine_transform(a, [[0.5, 0], [0, 1]], offset=[1, 0], order=1)
+/// # array([[3. , 4. , 5. ],
+/// #        [4.5, 5.5, 6.5],
+/// #        [6. , 7. , 8. ],
+/// #        [7.5, 8.5, 9.5]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{array, Array};
+/// use sci_rs::ndimage::affine_transform;
+///
+/// let a = Array::range(0., 12., 1.).into_shape_with_order((4, 3)).unwrap();
+/// let swap = array![[0., 1.], [1., 0.]];
+/// let y = affine_transform(&a, &swap, None, Some(&[3, 4]), Some(1), None, None, None);
+/// assert_eq!(y.unwrap(), a.t());
+/// let half = array![[0.5, 0., 1.], [0., 1., 0.]];
+/// let y = affine_transform(&a, &half, None, None, Some(1), None, None, None).unwrap();
+/// assert_eq!(y.column(0), array![3., 4.5, 6., 7.5]);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn affine_transform<F, S, S2, D>(
+    input: &ArrayBase<S, D>,
+    matrix: &ArrayBase<S2, Ix2>,
+    offset: Option<&[F]>,
+    output_shape: Option<&[usize]>,
+    order: Option<usize>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    prefilter: Option<bool>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    S2: Data<Elem = F>,
+    D: Dimension,
+{
+    let ndim = input.ndim();
+    let (rows, cols) = matrix.dim();
+    let offset = if rows >= ndim && rows <= ndim + 1 && cols == ndim + 1 {
+        matrix.column(ndim).iter().take(ndim).copied().collect()
+    } else if (rows, cols) == (ndim, ndim) {
+        per_axis(offset.unwrap_or(&[F::zero()]), ndim, "offset")?
+    } else {
+        return Err(Error::InvalidArg {
+            arg: "matrix".into(),
+            reason: "shape must match the number of dimensions of input.".into(),
+        });
+    };
+    let output_shape = output_shape.unwrap_or(input.shape());
+    if output_shape.len() != ndim {
+        return Err(Error::InvalidArg {
+            arg: "output_shape".into(),
+            reason: "must have the number of dimensions of input.".into(),
+        });
+    }
+    let mut spline = Spline::new(input.view().into_dyn(), order, mode, cval, prefilter, false)?;
+    let matrix = matrix.slice(ndarray::s![..ndim, ..ndim]);
+    let output = spline.affine(matrix, &offset, output_shape);
+    Ok(output.into_dimensionality().unwrap())
+}
+
+/// Shift an array.
+///
+/// The value of the output at the coordinates `o` is that of the input at `o - shift`, found
+/// by spline interpolation.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `shift`: The shift along each axis, given once for all axes or once for each axis.
+/// * `order`: The order of the spline interpolation, from 0 to 5. 3 by default.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Constant`] by default.
+/// * `cval`: Value of the points outside the boundaries of the input with
+///   [`BoundaryMode::Constant`]. 0 by default.
+/// * `prefilter`: Whether `input` is prefiltered into the coefficients of the interpolating
+///   spline when `order` is greater than 1. `true` by default.
+///
+/// ## Returns
+/// The shifted array, of the same shape as `input`.
+///
+/// ## Errors
+/// If `shift` is neither given once nor once for each axis, or `order` is greater than 5.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.ndimage import shift
+/// shift([0.0, 0.0, 1.0, 0.0, 0.0, 0.0], 1.5)
+/// # array([ 0.        ,  0.        , -0.09330144,  0.59150718,  0.60227273,
+/// #        -0.12559809])
+/// shift([1.0, 2.0, 3.0, 4.0], -1, order=1, mode='nearest')
+/// # array([2., 3., 4., 4.])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::ndimage::{shift, BoundaryMode};
+///
+/// let x = array![0., 0., 1., 0., 0., 0.];
+/// let y = shift(&x, &[1.5], None, None, None, None).unwrap();
+/// let expected = [0., 0., -0.09330144, 0.59150718, 0.60227273, -0.12559809];
+/// for (y, e) in y.iter().zip(expected) {
+///     assert_abs_diff_eq!(*y, e, epsilon = 1e-8);
+/// }
+/// let x = array![1., 2., 3., 4.];
+/// let y = shift(&x, &[-1.], Some(1), Some(BoundaryMode::Nearest), None, None);
+/// assert_eq!(y.unwrap(), array![2., 3., 4., 4.]);
+/// ```
+pub fn shift<F, S, D>(
+    input: &ArrayBase<S, D>,
+    shift: &[F],
+    order: Option<usize>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    prefilter: Option<bool>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let ndim = input.ndim();
+    let offset: Vec<F> = per_axis(shift, ndim, "shift")?
+        .into_iter()
+        .map(|s| -s)
+        .collect();
+    let mut spline = Spline::new(input.view().into_dyn(), order, mode, cval, prefilter, false)?;
+    let output = spline.affine(Array2::eye(ndim).view(), &offset, input.shape());
+    Ok(output.into_dimensionality().unwrap())
+}
+
+/// Zoom an array.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `zoom`: The zoom factor along each axis, given once for all axes or once for each axis.
+/// * `order`: The order of the spline interpolation, from 0 to 5. 3 by default.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Constant`] by default.
+/// * `cval`: Value of the points outside the boundaries of the input with
+///   [`BoundaryMode::Constant`]. 0 by default.
+/// * `prefilter`: Whether `input` is prefiltered into the coefficients of the interpolating
+///   spline when `order` is greater than 1. `true` by default.
+/// * `grid_mode`: Whether the zoom applies to the extent of the pixels rather than to the
+///   distance between the centers of the first and last pixels. `false` by default.
+///
+/// ## Returns
+/// The zoomed array, whose length along each axis is that of `input` times the zoom,
+/// rounded to the nearest integer.
+///
+/// ## Errors
+/// If `zoom` is neither given once nor once for each axis or is not positive and finite, or
+/// `order` is greater than 5.
+///
+/// ## Notes
+/// Without `grid_mode`, the centers of the corner pixels of the input and output are aligned.
+/// With `grid_mode`, their outer edges are, and [`BoundaryMode::Constant`] interpolates the
+/// input padded with `cval` instead of only sampling within its bounds, like scipy's
+/// `grid-constant`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.ndimage import zoom
+/// zoom([0.0, 1.0, 2.0, 3.0], 2, order=1)
+/// # array([0.        , 0.42857143, 0.85714286, 1.28571429, 1.71428571,
+/// #        2.14285714, 2.57142857, 3.        ])
+/// zoom([0.0, 1.0, 2.0, 3.0], 2, order=1, mode='nearest', grid_mode=True)
+/// # array([0.  , 0.25, 0.75, 1.25, 1.75, 2.25, 2.75, 3.  ])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::ndimage::{zoom, BoundaryMode};
+///
+/// let x = array![0., 1., 2., 3.];
+/// let y = zoom(&x, &[2.], Some(1), None, None, None, None).unwrap();
+/// for (k, y) in y.iter().enumerate() {
+///     assert_abs_diff_eq!(*y, k as f64 * 3. / 7., epsilon = 1e-12);
+/// }
+/// let nearest = Some(BoundaryMode::Nearest);
+/// let y = zoom(&x, &[2.], Some(1), nearest, None, None, Some(true)).unwrap();
+/// assert_eq!(y, array![0., 0.25, 0.75, 1.25, 1.75, 2.25, 2.75, 3.]);
+/// ```
+pub fn zoom<F, S, D>(
+    input: &ArrayBase<S, D>,
+    zoom: &[F],
+    order: Option<usize>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    prefilter: Option<bool>,
+    grid_mode: Option<bool>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let ndim = input.ndim();
+    let zoom = per_axis(zoom, ndim, "zoom")?;
+    if zoom.iter().any(|z| !z.is_finite() || *z <= F::zero()) {
+        return Err(Error::InvalidArg {
+            arg: "zoom".into(),
+            reason: "must be positive and finite.".into(),
+        });
+    }
+    let grid_mode = grid_mode.unwrap_or(false);
+    let output_shape: Vec<usize> = input
+        .shape()
+        .iter()
+        .zip(&zoom)
+        .map(|(&n, &z)| {
+            let len = (F::from(n).unwrap() * z).to_f64().unwrap();
+            len.round_ties_even() as usize
+        })
+        .collect();
+    let half = F::from(0.5).unwrap();
+    let (scale, offset): (Vec<F>, Vec<F>) = input
+        .shape()
+        .iter()
+        .zip(&output_shape)
+        .map(|(&n, &m)| {
+            if grid_mode {
+                let scale = F::from(n).unwrap() / F::from(m).unwrap();
+                (scale, half * scale - half)
+            } else if m > 1 {
+                (F::from(n - 1).unwrap() / F::from(m - 1).unwrap(), F::zero())
+            } else {
+                (F::one(), F::zero())
+            }
+        })
+        .unzip();
+    let mut spline = Spline::new(
+        input.view().into_dyn(),
+        order,
+        mode,
+        cval,
+        prefilter,
+        grid_mode,
+    )?;
+    let matrix = Array2::from_diag(&ndarray::Array1::from(scale));
+    let output = spline.affine(matrix.view(), &offset, &output_shape);
+    Ok(output.into_dimensionality().unwrap())
+}
+
+/// Rotate an array.
+///
+/// The array is rotated in the plane of the two `axes` about its center.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `angle`: The rotation angle in degrees.
+/// * `axes`: The two axes defining the plane of rotation. `(1, 0)` by default.
+/// * `reshape`: Whether the output is enlarged to contain the whole rotated input. `true` by
+///   default.
+/// * `order`: The order of the spline interpolation, from 0 to 5. 3 by default.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Constant`] by default.
+/// * `cval`: Value of the points outside the boundaries of the input with
+///   [`BoundaryMode::Constant`]. 0 by default.
+/// * `prefilter`: Whether `input` is prefiltered into the coefficients of the interpolating
+///   spline when `order` is greater than 1. `true` by default.
+///
+/// ## Returns
+/// The rotated array.
+///
+/// ## Errors
+/// If `input` has fewer than 2 dimensions, `axes` are out of range or equal, or `order` is
+/// greater than 5.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.arange(6.).reshape((2, 3))
+/// ndimage.rotate(a, 90)
+/// # array([[2., 5.],
+/// #        [1., 4.],
+/// #        [0., 3.]])
+/// ndimage.rotate(a, 180, reshape=False)
+/// # array([[5., 4., 3.],
+/// #        [2., 1., 0.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{array, Array};
+/// use sci_rs::ndimage::rotate;
+///
+/// let a = Array::range(0., 6., 1.).into_shape_with_order((2, 3)).unwrap();
+/// let y = rotate(&a, 90., None, None, None, None, None, None).unwrap();
+/// let expected = array![[2., 5.], [1., 4.], [0., 3.]];
+/// assert_eq!(y.dim(), (3, 2));
+/// for (y, e) in y.iter().zip(&expected) {
+///     assert_abs_diff_eq!(*y, *e, epsilon = 1e-12);
+/// }
+/// let y = rotate(&a, 180., None, Some(false), None, None, None, None).unwrap();
+/// for (y, e) in y.iter().zip(&array![[5., 4., 3.], [2., 1., 0.]]) {
+///     assert_abs_diff_eq!(*y, *e, epsilon = 1e-12);
+/// }
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn rotate<F, S, D>(
+    input: &ArrayBase<S, D>,
+    angle: F,
+    axes: Option<(isize, isize)>,
+    reshape: Option<bool>,
+    order: Option<usize>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    prefilter: Option<bool>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let ndim = input.ndim();
+    if ndim < 2 {
+        return Err(Error::InvalidArg {
+            arg: "input".into(),
+            reason: "must have at least 2 dimensions.".into(),
+        });
+    }
+    let (first, second) = axes.unwrap_or((1, 0));
+    let first = check_and_get_axis_dyn(Some(first), input)?;
+    let second = check_and_get_axis_dyn(Some(second), input)?;
+    if first == second {
+        return Err(Error::InvalidArg {
+            arg: "axes".into(),
+            reason: "must be different.".into(),
+        });
+    }
+    let (first, second) = (first.min(second), first.max(second));
+    // Exact for multiples of a right angle, so that they only permute the input.
+    let quarters = angle / F::from(90).unwrap();
+    let (c, s) = if quarters.fract().is_zero() {
+        match quarters.to_i64().map(|q| q.rem_euclid(4)) {
+            Some(0) => (F::one(), F::zero()),
+            Some(1) => (F::zero(), F::one()),
+            Some(2) => (-F::one(), F::zero()),
+            _ => (F::zero(), -F::one()),
+        }
+    } else {
+        let angle = angle.to_radians();
+        (angle.cos(), angle.sin())
+    };
+    let shape = input.shape();
+    let (iy, ix) = (
+        F::from(shape[first]).unwrap(),
+        F::from(shape[second]).unwrap(),
+    );
+    let mut output_shape = shape.to_vec();
+    if reshape.unwrap_or(true) {
+        // Bounds of the rotated corners of the plane.
+        let ptp = |corners: [F; 4]| {
+            let (min, max) = corners
+                .iter()
+                .fold((F::zero(), F::zero()), |(min, max), &x| {
+                    (min.min(x), max.max(x))
+                });
+            (max - min + F::from(0.5).unwrap()).to_usize().unwrap()
+        };
+        output_shape[first] = ptp([F::zero(), s * ix, c * iy, c * iy + s * ix]);
+        output_shape[second] = ptp([F::zero(), c * ix, -s * iy, c * ix - s * iy]);
+    }
+    // Rotate about the centers of the input and output planes.
+    let center = |n: usize| (F::from(n).unwrap() - F::one()) / F::from(2).unwrap();
+    let (oy, ox) = (center(output_shape[first]), center(output_shape[second]));
+    let mut matrix = Array2::eye(ndim);
+    matrix[[first, first]] = c;
+    matrix[[first, second]] = s;
+    matrix[[second, first]] = -s;
+    matrix[[second, second]] = c;
+    let mut offset = alloc::vec![F::zero(); ndim];
+    offset[first] = center(shape[first]) - (c * oy + s * ox);
+    offset[second] = center(shape[second]) - (c * ox - s * oy);
+    let mut spline = Spline::new(input.view().into_dyn(), order, mode, cval, prefilter, false)?;
+    let output = spline.affine(matrix.view(), &offset, &output_shape);
+    Ok(output.into_dimensionality().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array1, Array3};
+
+    #[test]
+    fn spline_interpolation() {
+        // The interpolating splines go through the samples whatever the boundaries.
+        let x = array![0., 1., 4., 2., -1., 3.];
+        let nodes = Array1::range(0., 6., 1.).insert_axis(Axis(0));
+        for order in 0..=5 {
+            for mode in [
+                BoundaryMode::Constant,
+                BoundaryMode::Nearest,
+                BoundaryMode::Mirror,
+                BoundaryMode::Reflect,
+                BoundaryMode::Wrap,
+            ] {
+                let y = map_coordinates(&x, &nodes, Some(order), Some(mode), None, None).unwrap();
+                for (y, e) in y.iter().zip(&x) {
+                    assert_abs_diff_eq!(y, e, epsilon = 1e-10);
+                }
+            }
+        }
+        let at = array![[0.5, 2.25, 4.75]];
+        let cases = [
+            (
+                5,
+                BoundaryMode::Reflect,
+                [0.11877263, 4.13980383, 1.82045886],
+            ),
+            (
+                2,
+                BoundaryMode::Mirror,
+                [0.18460892, 3.99989487, 2.54005467],
+            ),
+            (
+                4,
+                BoundaryMode::Nearest,
+                [0.14301794, 4.12670189, 2.02519678],
+            ),
+        ];
+        for (order, mode, expected) in cases {
+            let y = map_coordinates(&x, &at, Some(order), Some(mode), None, None).unwrap();
+            for (y, e) in y.iter().zip(expected) {
+                assert_abs_diff_eq!(*y, e, epsilon = 1e-8);
+            }
+        }
+        // Outside of the input and periodically.
+        let impulse = array![0., 0., 1., 0., 0., 0.];
+        let y = shift(&impulse, &[1.5], None, Some(BoundaryMode::Wrap), None, None).unwrap();
+        for (y, e) in y.iter().zip([0.025, 0.025, -0.125, 0.6, 0.6, -0.125]) {
+            assert_abs_diff_eq!(*y, e, epsilon = 1e-12);
+        }
+        let y = shift(
+            &impulse,
+            &[12.],
+            Some(3),
+            Some(BoundaryMode::Wrap),
+            None,
+            None,
+        )
+        .unwrap();
+        for (y, e) in y.iter().zip(&impulse) {
+            assert_abs_diff_eq!(y, e, epsilon = 1e-12);
+        }
+        assert!(map_coordinates(&x, &at, Some(6), None, None, None).is_err());
+        assert!(map_coordinates(&x, &array![[0.], [1.]], None, None, None, None).is_err());
+    }
+
+    #[test]
+    fn geometric_transforms() {
+        let a = Array3::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 20 + j * 5 + k * k) as f64);
+        // Right angles only permute the samples, in the plane of the given axes.
+        let y = rotate(&a, -90., Some((0, 2)), None, None, None, None, None).unwrap();
+        assert_eq!(y.dim(), (5, 4, 3));
+        for ((i, j, k), y) in y.indexed_iter() {
+            assert_abs_diff_eq!(*y, a[[2 - k, j, i]], epsilon = 1e-10);
+        }
+        let y = rotate(&a, 45., None, None, Some(1), None, None, None).unwrap();
+        assert_eq!(y.dim(), (5, 5, 5));
+
+        let identity = Array2::eye(3);
+        let y = affine_transform(&a, &identity, None, None, Some(5), None, None, None).unwrap();
+        for (y, e) in y.iter().zip(&a) {
+            assert_abs_diff_eq!(y, e, epsilon = 1e-10);
+        }
+        let y = shift(&a, &[0., -1., 2.], Some(1), None, Some(-1.), None).unwrap();
+        assert_eq!(y[[1, 0, 2]], a[[1, 1, 0]]);
+        assert_eq!(y[[1, 3, 2]], -1.);
+        assert_eq!(y[[1, 0, 1]], -1.);
+        assert!(shift(&a, &[1., 2.], None, None, None, None).is_err());
+        assert!(affine_transform(&a, &Array2::eye(2), None, None, None, None, None, None).is_err());
+
+        // Zooming out and in again keeps the samples aligned with the corners.
+        let y = zoom(&a, &[1., 0.5, 1.4], Some(1), None, None, None, None).unwrap();
+        assert_eq!(y.dim(), (3, 2, 7));
+        assert_abs_diff_eq!(y[[2, 1, 6]], a[[2, 3, 4]], epsilon = 1e-12);
+        let x = array![1., 2.];
+        let y = zoom(&x, &[2.], Some(0), None, Some(9.), None, Some(true)).unwrap();
+        assert_eq!(y, array![1., 1., 2., 2.]);
+        let y = zoom(&x, &[1.5], Some(3), None, None, None, Some(true)).unwrap();
+        assert_eq!(y.len(), 3);
+        assert!(zoom(&x, &[0.], None, None, None, None, None).is_err());
+        assert!(rotate(&x, 10., None, None, None, None, None, None).is_err());
+    }
+}
@@ -0,0 +1,889 @@
+use super::filters::Footprint;
+use super::morphology::generate_binary_structure;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::ops::Range;
+use ndarray::{Array, ArrayBase, ArrayD, Data, Dimension, Slice};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Label features in an array.
+///
+/// ## Parameters
+/// * `input`: Array of the features to be labeled, the set elements.
+/// * `structure`: Structuring element of shape `(3, ..., 3)` defining the connected
+///   neighbors. The structure generated by [`generate_binary_structure`] with a connectivity of
+///   1 by default, which excludes the diagonal neighbors.
+///
+/// ## Returns
+/// The array of the labels of the features, from 1 in the order in which they are first
+/// met, with 0 for the background, and the number of features.
+///
+/// ## Errors
+/// If `structure` does not have the shape `(3, ..., 3)` or is not symmetric.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.array([[0, 0, 1, 1, 0, 0],
+///               [0, 0, 0, 1, 0, 0],
+///               [1, 1, 0, 0, 1, 0],
+///               [0, 0, 0, 1, 0, 0]])
+/// ndimage.label(a)
+/// # (array([[0, 0, 1, 1, 0, 0],
+/// #         [0, 0, 0, 1, 0, 0],
+/// #         [2, 2, 0, 0, 3, 0],
+/// #         [0, 0, 0, 4, 0, 0]], dtype=int32), 4)
+/// ndimage.label(a, structure=np.ones((3, 3)))[1]
+/// # 2
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{array, Array2};
+/// use sci_rs::ndimage::label;
+///
+/// let a = array![
+///     [0, 0, 1, 1, 0, 0],
+///     [0, 0, 0, 1, 0, 0],
+///     [1, 1, 0, 0, 1, 0],
+///     [0, 0, 0, 1, 0, 0]
+/// ]
+/// .mapv(|x| x == 1);
+/// let (labels, count) = label(&a, None).unwrap();
+/// let expected = array![
+///     [0, 0, 1, 1, 0, 0],
+///     [0, 0, 0, 1, 0, 0],
+///     [2, 2, 0, 0, 3, 0],
+///     [0, 0, 0, 4, 0, 0]
+/// ];
+/// assert_eq!((labels, count), (expected, 4));
+/// let (_, count) = label(&a, Some(&Array2::from_elem((3, 3), true))).unwrap();
+/// assert_eq!(count, 2);
+/// ```
+pub fn label<S, D>(
+    input: &ArrayBase<S, D>,
+    structure: Option<&Array<bool, D>>,
+) -> Result<(Array<usize, D>, usize)>
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    let ndim = input.ndim();
+    let footprint = match structure {
+        Some(structure) => {
+            if structure.shape().iter().any(|&s| s != 3) || structure.ndim() != ndim {
+                return Err(Error::InvalidArg {
+                    arg: "structure".into(),
+                    reason: "dimensions must be equal to 3.".into(),
+                });
+            }
+            if structure.slice_each_axis(|_| Slice::new(0, None, -1)) != structure {
+                return Err(Error::InvalidArg {
+                    arg: "structure".into(),
+                    reason: "must be symmetric.".into(),
+                });
+            }
+            Footprint::new(ndim, None, Some(structure), None)?
+        }
+        None => {
+            let structure = generate_binary_structure(ndim, 1);
+            Footprint::new(ndim, None, Some(&structure), None)?
+        }
+    };
+    let input = input.view().into_dyn();
+    let shape = input.shape();
+    let mut labels = ArrayD::zeros(input.raw_dim());
+    let mut count = 0;
+    let mut queue = VecDeque::new();
+    for (seed, &value) in input.indexed_iter() {
+        if !value || labels[&seed] != 0 {
+            continue;
+        }
+        // Flood the feature from its first element.
+        count += 1;
+        labels[&seed] = count;
+        queue.push_back(seed.slice().to_vec());
+        while let Some(position) = queue.pop_front() {
+            for offset in &footprint.offsets {
+                let next: Option<Vec<usize>> = (0..ndim)
+                    .map(|a| {
+                        let i = position[a] as isize + offset[a];
+                        (0..shape[a] as isize).contains(&i).then_some(i as usize)
+                    })
+                    .collect();
+                if let Some(next) = next {
+                    if input[&*next] && labels[&*next] == 0 {
+                        labels[&*next] = count;
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+    }
+    Ok((labels.into_dimensionality().unwrap(), count))
+}
+
+/// Find objects in a labeled array.
+///
+/// ## Parameters
+/// * `input`: Array of labels, with 0 for the background.
+/// * `max_label`: Maximum label to be searched for in `input`. The largest label by default.
+///
+/// ## Returns
+/// For each label from 1 to `max_label`, the ranges of indices along each axis of the
+/// smallest box containing the object, or `None` if the label does not occur.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.zeros((6, 6), dtype=int)
+/// a[2:4, 2:4] = 1
+/// a[4, 4] = 1
+/// a[:2, :3] = 2
+/// a[0, 5] = 3
+/// ndimage.find_objects(a)
+/// # [(slice(2, 5, None), slice(2, 5, None)),
+/// #  (slice(0, 2, None), slice(0, 3, None)),
+/// #  (slice(0, 1, None), slice(5, 6, None))]
+/// ndimage.find_objects(a, max_label=2)
+/// # [(slice(2, 5, None), slice(2, 5, None)), (slice(0, 2, None), slice(0, 3, None))]
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{s, Array2};
+/// use sci_rs::ndimage::find_objects;
+///
+/// let mut a = Array2::zeros((6, 6));
+/// a.slice_mut(s![2..4, 2..4]).fill(1);
+/// a[[4, 4]] = 1;
+/// a.slice_mut(s![..2, ..3]).fill(2);
+/// a[[0, 5]] = 3;
+/// let objects = find_objects(&a, None);
+/// assert_eq!(objects, [Some(vec![2..5, 2..5]), Some(vec![0..2, 0..3]), Some(vec![0..1, 5..6])]);
+/// let first = objects[0].clone().unwrap();
+/// assert_eq!(a.slice(s![first[0].clone(), first[1].clone()]).sum(), 5);
+/// assert_eq!(find_objects(&a, Some(2)).len(), 2);
+/// ```
+pub fn find_objects<S, D>(
+    input: &ArrayBase<S, D>,
+    max_label: Option<usize>,
+) -> Vec<Option<Vec<Range<usize>>>>
+where
+    S: Data<Elem = usize>,
+    D: Dimension,
+{
+    let max_label = max_label.unwrap_or_else(|| input.iter().copied().max().unwrap_or(0));
+    let mut objects: Vec<Option<Vec<Range<usize>>>> = alloc::vec![None; max_label];
+    for (index, &label) in input.view().into_dyn().indexed_iter() {
+        if label == 0 || label > max_label {
+            continue;
+        }
+        let index = index.slice();
+        match &mut objects[label - 1] {
+            Some(ranges) => {
+                for (range, &i) in ranges.iter_mut().zip(index) {
+                    range.start = range.start.min(i);
+                    range.end = range.end.max(i + 1);
+                }
+            }
+            object => *object = Some(index.iter().map(|&i| i..i + 1).collect()),
+        }
+    }
+    objects
+}
+
+/// The regions over which the measurements are made, binning the elements by their labels in
+/// the manner of `bincount`.
+struct Regions {
+    /// Bin of each element, in logical order.
+    bins: Vec<usize>,
+    /// Number of bins.
+    len: usize,
+    /// Bins of the measured regions.
+    selected: Vec<usize>,
+}
+
+impl Regions {
+    fn new<D: Dimension>(
+        shape: &[usize],
+        labels: Option<&Array<usize, D>>,
+        index: Option<&[usize]>,
+    ) -> Result<Self> {
+        let size = shape.iter().product();
+        let Some(labels) = labels else {
+            return Ok(Self {
+                bins: alloc::vec![0; size],
+                len: 1,
+                selected: alloc::vec![0],
+            });
+        };
+        if labels.shape() != shape {
+            return Err(Error::InvalidArg {
+                arg: "labels".into(),
+                reason: "must have the shape of input.".into(),
+            });
+        }
+        Ok(match index {
+            Some(index) => {
+                let max_label = labels.iter().copied().max().unwrap_or(0);
+                let max_index = index.iter().copied().max().unwrap_or(0);
+                Self {
+                    bins: labels.iter().copied().collect(),
+                    len: max_label.max(max_index) + 1,
+                    selected: index.to_vec(),
+                }
+            }
+            // All the labeled elements form a single region.
+            None => Self {
+                bins: labels.iter().map(|&l| l.min(1)).collect(),
+                len: 2,
+                selected: alloc::vec![1],
+            },
+        })
+    }
+
+    /// Call `f` with the bin, value and index of each element of `input`.
+    fn for_each<F, S, D>(&self, input: &ArrayBase<S, D>, mut f: impl FnMut(usize, F, &[usize]))
+    where
+        F: Copy,
+        S: Data<Elem = F>,
+        D: Dimension,
+    {
+        for ((index, &x), &bin) in input.view().into_dyn().indexed_iter().zip(&self.bins) {
+            f(bin, x, index.slice());
+        }
+    }
+
+    /// The values of the measured regions, from those of all the bins.
+    fn select<T: Clone>(&self, values: &[T]) -> Vec<T> {
+        self.selected.iter().map(|&b| values[b].clone()).collect()
+    }
+}
+
+/// Sums and counts of the elements of each bin.
+fn sums_and_counts<F, S, D>(input: &ArrayBase<S, D>, regions: &Regions) -> (Vec<F>, Vec<usize>)
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let mut sums = alloc::vec![F::zero(); regions.len];
+    let mut counts = alloc::vec![0; regions.len];
+    regions.for_each(input, |bin, x, _| {
+        sums[bin] = sums[bin] + x;
+        counts[bin] += 1;
+    });
+    (sums, counts)
+}
+
+/// Calculate the sum of the values of the array over labeled regions.
+///
+/// ## Parameters
+/// * `input`: The values to be summed.
+/// * `labels`: Labels of the elements of `input`, of the same shape. All of `input` forms a
+///   single region by default.
+/// * `index`: The labels of the regions to be measured. All the elements with a nonzero label
+///   form a single region by default. Ignored without `labels`.
+///
+/// ## Returns
+/// The sum over each region, 0 for a label which does not occur.
+///
+/// ## Errors
+/// If `labels` does not have the shape of `input`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import ndimage
+/// input = [0, 1, 2, 3]
+/// labels = [1, 1, 2, 2]
+/// ndimage.sum_labels(input, labels, index=[1, 2])
+/// # [1.0, 5.0]
+/// ndimage.sum_labels(input, labels)
+/// # 6
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::sum_labels;
+///
+/// let input = array![0., 1., 2., 3.];
+/// let labels = array![1, 1, 2, 2];
+/// assert_eq!(sum_labels(&input, Some(&labels), Some(&[1, 2])).unwrap(), [1., 5.]);
+/// assert_eq!(sum_labels(&input, Some(&labels), None).unwrap(), [6.]);
+/// ```
+pub fn sum_labels<F, S, D>(
+    input: &ArrayBase<S, D>,
+    labels: Option<&Array<usize, D>>,
+    index: Option<&[usize]>,
+) -> Result<Vec<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let regions = Regions::new(input.shape(), labels, index)?;
+    let (sums, _) = sums_and_counts(input, &regions);
+    Ok(regions.select(&sums))
+}
+
+/// Calculate the mean of the values of the array over labeled regions.
+///
+/// ## Parameters
+/// * `input`: The values to be averaged.
+/// * `labels`: Labels of the elements of `input`, of the same shape. All of `input` forms a
+///   single region by default.
+/// * `index`: The labels of the regions to be measured. All the elements with a nonzero label
+///   form a single region by default. Ignored without `labels`.
+///
+/// ## Returns
+/// The mean over each region, NaN for a label which does not occur.
+///
+/// ## Errors
+/// If `labels` does not have the shape of `input`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.arange(25).reshape((5, 5))
+/// labels = np.zeros_like(a)
+/// labels[3:5, 3:5] = 1
+/// ndimage.mean(a, labels=labels, index=[0, 1])
+/// # [10.285714285714286, 21.0]
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{s, Array, Array2};
+/// use sci_rs::ndimage::mean;
+///
+/// let a = Array::range(0., 25., 1.).into_shape_with_order((5, 5)).unwrap();
+/// let mut labels = Array2::zeros((5, 5));
+/// labels.slice_mut(s![3..5, 3..5]).fill(1);
+/// assert_eq!(mean(&a, Some(&labels), Some(&[0, 1])).unwrap(), [72. / 7., 21.]);
+/// ```
+pub fn mean<F, S, D>(
+    input: &ArrayBase<S, D>,
+    labels: Option<&Array<usize, D>>,
+    index: Option<&[usize]>,
+) -> Result<Vec<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let regions = Regions::new(input.shape(), labels, index)?;
+    let (sums, counts) = sums_and_counts(input, &regions);
+    let means: Vec<F> = sums
+        .iter()
+        .zip(counts)
+        .map(|(&s, c)| s / F::from(c).unwrap())
+        .collect();
+    Ok(regions.select(&means))
+}
+
+/// Calculate the variance of the values of the array over labeled regions.
+///
+/// ## Parameters
+/// * `input`: The values to be processed.
+/// * `labels`: Labels of the elements of `input`, of the same shape. All of `input` forms a
+///   single region by default.
+/// * `index`: The labels of the regions to be measured. All the elements with a nonzero label
+///   form a single region by default. Ignored without `labels`.
+///
+/// ## Returns
+/// The population variance over each region, NaN for a label which does not occur.
+///
+/// ## Errors
+/// If `labels` does not have the shape of `input`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.array([[1, 2, 0, 0],
+///               [5, 3, 0, 4],
+///               [0, 0, 0, 7],
+///               [9, 3, 0, 0]])
+/// ndimage.variance(a)
+/// # 7.609375
+/// lbl, nlbl = ndimage.label(a)
+/// ndimage.variance(a, lbl, index=np.arange(1, nlbl + 1))
+/// # array([2.1875, 2.25  , 9.    ])
+/// ndimage.variance(a, lbl)
+/// # 6.1875
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::{label, variance};
+///
+/// let a = array![[1., 2., 0., 0.], [5., 3., 0., 4.], [0., 0., 0., 7.], [9., 3., 0., 0.]];
+/// assert_eq!(variance(&a, None, None).unwrap(), [7.609375]);
+/// let (lbl, _) = label(&a.mapv(|x| x != 0.), None).unwrap();
+/// assert_eq!(variance(&a, Some(&lbl), Some(&[1, 2, 3])).unwrap(), [2.1875, 2.25, 9.]);
+/// assert_eq!(variance(&a, Some(&lbl), None).unwrap(), [6.1875]);
+/// ```
+pub fn variance<F, S, D>(
+    input: &ArrayBase<S, D>,
+    labels: Option<&Array<usize, D>>,
+    index: Option<&[usize]>,
+) -> Result<Vec<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let regions = Regions::new(input.shape(), labels, index)?;
+    let (sums, counts) = sums_and_counts(input, &regions);
+    let means: Vec<F> = sums
+        .iter()
+        .zip(&counts)
+        .map(|(&s, &c)| s / F::from(c).unwrap())
+        .collect();
+    let mut squares = alloc::vec![F::zero(); regions.len];
+    regions.for_each(input, |bin, x, _| {
+        squares[bin] = squares[bin] + (x - means[bin]).powi(2);
+    });
+    let variances: Vec<F> = squares
+        .iter()
+        .zip(counts)
+        .map(|(&s, c)| s / F::from(c).unwrap())
+        .collect();
+    Ok(regions.select(&variances))
+}
+
+/// Calculate the standard deviation of the values of the array over labeled regions.
+///
+/// ## Parameters
+/// * `input`: The values to be processed.
+/// * `labels`: Labels of the elements of `input`, of the same shape. All of `input` forms a
+///   single region by default.
+/// * `index`: The labels of the regions to be measured. All the elements with a nonzero label
+///   form a single region by default. Ignored without `labels`.
+///
+/// ## Returns
+/// The population standard deviation over each region, NaN for a label which does not occur.
+///
+/// ## Errors
+/// If `labels` does not have the shape of `input`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.array([[1, 2, 0, 0],
+///               [5, 3, 0, 4],
+///               [0, 0, 0, 7],
+///               [9, 3, 0, 0]])
+/// lbl, nlbl = ndimage.label(a)
+/// ndimage.standard_deviation(a, lbl, index=np.arange(1, nlbl + 1))
+/// # array([1.479, 1.5  , 3.   ])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::{label, standard_deviation};
+///
+/// let a = array![[1., 2., 0., 0.], [5., 3., 0., 4.], [0., 0., 0., 7.], [9., 3., 0., 0.]];
+/// let (lbl, _) = label(&a.mapv(|x| x != 0.), None).unwrap();
+/// let std = standard_deviation(&a, Some(&lbl), Some(&[1, 2, 3])).unwrap();
+/// assert_eq!(std, [2.1875f64.sqrt(), 1.5, 3.]);
+/// ```
+pub fn standard_deviation<F, S, D>(
+    input: &ArrayBase<S, D>,
+    labels: Option<&Array<usize, D>>,
+    index: Option<&[usize]>,
+) -> Result<Vec<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    Ok(variance(input, labels, index)?
+        .into_iter()
+        .map(F::sqrt)
+        .collect())
+}
+
+/// Calculate the minima and maxima of the values of an array over labeled regions, along with
+/// their positions.
+///
+/// ## Parameters
+/// * `input`: The values to be processed.
+/// * `labels`: Labels of the elements of `input`, of the same shape. All of `input` forms a
+///   single region by default.
+/// * `index`: The labels of the regions to be measured. All the elements with a nonzero label
+///   form a single region by default. Ignored without `labels`.
+///
+/// ## Returns
+/// The minimum, the maximum, the index of the first minimum and the index of the first
+/// maximum of each region. The extrema are NaN and their positions `None` for a label which
+/// does not occur.
+///
+/// ## Errors
+/// If `labels` does not have the shape of `input`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.array([[1, 2, 0, 0],
+///               [5, 3, 0, 4],
+///               [0, 0, 0, 7],
+///               [9, 3, 0, 0]])
+/// ndimage.extrema(a)
+/// # (0, 9, (0, 2), (3, 0))
+/// lbl, nlbl = ndimage.label(a)
+/// ndimage.extrema(a, lbl, index=np.arange(1, nlbl + 1))
+/// # (array([1, 4, 3]),
+/// #  array([5, 7, 9]),
+/// #  [(0, 0), (1, 3), (3, 1)],
+/// #  [(1, 0), (2, 3), (3, 0)])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::{extrema, label};
+///
+/// let a = array![[1., 2., 0., 0.], [5., 3., 0., 4.], [0., 0., 0., 7.], [9., 3., 0., 0.]];
+/// let (min, max, argmin, argmax) = extrema(&a, None, None).unwrap();
+/// assert_eq!((min, max), (vec![0.], vec![9.]));
+/// assert_eq!((argmin, argmax), (vec![Some(vec![0, 2])], vec![Some(vec![3, 0])]));
+/// let (lbl, _) = label(&a.mapv(|x| x != 0.), None).unwrap();
+/// let (min, max, argmin, argmax) = extrema(&a, Some(&lbl), Some(&[1, 2, 3])).unwrap();
+/// assert_eq!((min, max), (vec![1., 4., 3.], vec![5., 7., 9.]));
+/// assert_eq!(argmin[1], Some(vec![1, 3]));
+/// assert_eq!(argmax[2], Some(vec![3, 0]));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn extrema<F, S, D>(
+    input: &ArrayBase<S, D>,
+    labels: Option<&Array<usize, D>>,
+    index: Option<&[usize]>,
+) -> Result<(
+    Vec<F>,
+    Vec<F>,
+    Vec<Option<Vec<usize>>>,
+    Vec<Option<Vec<usize>>>,
+)>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let regions = Regions::new(input.shape(), labels, index)?;
+    let mut minima: Vec<Option<(F, Vec<usize>)>> = alloc::vec![None; regions.len];
+    let mut maxima: Vec<Option<(F, Vec<usize>)>> = alloc::vec![None; regions.len];
+    regions.for_each(input, |bin, x, position| {
+        if minima[bin].as_ref().is_none_or(|(min, _)| x < *min) {
+            minima[bin] = Some((x, position.to_vec()));
+        }
+        if maxima[bin].as_ref().is_none_or(|(max, _)| x > *max) {
+            maxima[bin] = Some((x, position.to_vec()));
+        }
+    });
+    let (min, argmin) = regions
+        .select(&minima)
+        .into_iter()
+        .map(|e| e.map_or((F::nan(), None), |(x, i)| (x, Some(i))))
+        .unzip();
+    let (max, argmax) = regions
+        .select(&maxima)
+        .into_iter()
+        .map(|e| e.map_or((F::nan(), None), |(x, i)| (x, Some(i))))
+        .unzip();
+    Ok((min, max, argmin, argmax))
+}
+
+/// Calculate the minimum of the values of an array over labeled regions.
+///
+/// ## Parameters
+/// * `input`: The values to be processed.
+/// * `labels`: Labels of the elements of `input`, of the same shape. All of `input` forms a
+///   single region by default.
+/// * `index`: The labels of the regions to be measured. All the elements with a nonzero label
+///   form a single region by default. Ignored without `labels`.
+///
+/// ## Returns
+/// The minimum of each region, NaN for a label which does not occur.
+///
+/// ## Errors
+/// If `labels` does not have the shape of `input`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import ndimage
+/// ndimage.minimum([1, 2, 0, 5, 3], [1, 1, 0, 2, 2], index=[1, 2])
+/// # [1.0, 3.0]
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::minimum;
+///
+/// let labels = array![1, 1, 0, 2, 2];
+/// let min = minimum(&array![1., 2., 0., 5., 3.], Some(&labels), Some(&[1, 2]));
+/// assert_eq!(min.unwrap(), [1., 3.]);
+/// ```
+pub fn minimum<F, S, D>(
+    input: &ArrayBase<S, D>,
+    labels: Option<&Array<usize, D>>,
+    index: Option<&[usize]>,
+) -> Result<Vec<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    Ok(extrema(input, labels, index)?.0)
+}
+
+/// Calculate the maximum of the values of an array over labeled regions.
+///
+/// ## Parameters
+/// * `input`: The values to be processed.
+/// * `labels`: Labels of the elements of `input`, of the same shape. All of `input` forms a
+///   single region by default.
+/// * `index`: The labels of the regions to be measured. All the elements with a nonzero label
+///   form a single region by default. Ignored without `labels`.
+///
+/// ## Returns
+/// The maximum of each region, NaN for a label which does not occur.
+///
+/// ## Errors
+/// If `labels` does not have the shape of `input`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import ndimage
+/// ndimage.maximum([1, 2, 0, 5, 3], [1, 1, 0, 2, 2], index=[1, 2])
+/// # [2.0, 5.0]
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::maximum;
+///
+/// let labels = array![1, 1, 0, 2, 2];
+/// let max = maximum(&array![1., 2., 0., 5., 3.], Some(&labels), Some(&[1, 2]));
+/// assert_eq!(max.unwrap(), [2., 5.]);
+/// ```
+pub fn maximum<F, S, D>(
+    input: &ArrayBase<S, D>,
+    labels: Option<&Array<usize, D>>,
+    index: Option<&[usize]>,
+) -> Result<Vec<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    Ok(extrema(input, labels, index)?.1)
+}
+
+/// Calculate the center of mass of the values of an array over labeled regions.
+///
+/// ## Parameters
+/// * `input`: The masses.
+/// * `labels`: Labels of the elements of `input`, of the same shape. All of `input` forms a
+///   single region by default.
+/// * `index`: The labels of the regions to be measured. All the elements with a nonzero label
+///   form a single region by default. Ignored without `labels`.
+///
+/// ## Returns
+/// The coordinates of the center of mass of each region, NaN for a label which does not
+/// occur or whose mass is 0.
+///
+/// ## Errors
+/// If `labels` does not have the shape of `input`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.array(([0, 0, 0, 0],
+///               [0, 1, 1, 0],
+///               [0, 1, 1, 0],
+///               [0, 1, 1, 0]))
+/// ndimage.center_of_mass(a)
+/// # (2.0, 1.5)
+/// b = np.array(([0, 1, 1, 0],
+///               [0, 1, 0, 0],
+///               [0, 0, 0, 0],
+///               [0, 0, 1, 1],
+///               [0, 0, 1, 1]))
+/// lbl = ndimage.label(b)[0]
+/// ndimage.center_of_mass(b, lbl, [1, 2])
+/// # [(0.3333333333333333, 1.3333333333333333), (3.5, 2.5)]
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::{center_of_mass, label};
+///
+/// let a = array![[0., 0., 0., 0.], [0., 1., 1., 0.], [0., 1., 1., 0.], [0., 1., 1., 0.]];
+/// assert_eq!(center_of_mass(&a, None, None).unwrap(), [[2., 1.5]]);
+/// let b = array![
+///     [0., 1., 1., 0.],
+///     [0., 1., 0., 0.],
+///     [0., 0., 0., 0.],
+///     [0., 0., 1., 1.],
+///     [0., 0., 1., 1.]
+/// ];
+/// let (lbl, _) = label(&b.mapv(|x| x != 0.), None).unwrap();
+/// let centers = center_of_mass(&b, Some(&lbl), Some(&[1, 2])).unwrap();
+/// assert_eq!(centers, [[1. / 3., 4. / 3.], [3.5, 2.5]]);
+/// ```
+pub fn center_of_mass<F, S, D>(
+    input: &ArrayBase<S, D>,
+    labels: Option<&Array<usize, D>>,
+    index: Option<&[usize]>,
+) -> Result<Vec<Vec<F>>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let regions = Regions::new(input.shape(), labels, index)?;
+    let ndim = input.ndim();
+    let mut masses = alloc::vec![F::zero(); regions.len];
+    let mut moments = alloc::vec![alloc::vec![F::zero(); ndim]; regions.len];
+    regions.for_each(input, |bin, x, position| {
+        masses[bin] = masses[bin] + x;
+        for (m, &i) in moments[bin].iter_mut().zip(position) {
+            *m = *m + x * F::from(i).unwrap();
+        }
+    });
+    let centers: Vec<Vec<F>> = moments
+        .into_iter()
+        .zip(masses)
+        .map(|(m, mass)| m.into_iter().map(|m| m / mass).collect())
+        .collect();
+    Ok(regions.select(&centers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array2, Array3};
+
+    #[test]
+    fn labeling() {
+        // A checkerboard standing on a plane, whose squares only touch through the edges
+        // and corners, except for those resting on the plane.
+        let a = Array3::from_shape_fn((3, 4, 4), |(i, j, k)| (i + j + k) % 2 == 0 || k == 0);
+        let (labels, count) = label(&a, None).unwrap();
+        assert_eq!(
+            count,
+            1 + a
+                .slice(ndarray::s![.., .., 2..])
+                .iter()
+                .filter(|&&x| x)
+                .count()
+        );
+        assert_eq!(labels[[0, 0, 0]], 1);
+        assert_eq!(labels[[2, 3, 0]], 1);
+        assert_eq!(labels[[0, 0, 2]], 2);
+        let full = generate_binary_structure(3, 3)
+            .into_dimensionality()
+            .unwrap();
+        assert_eq!(label(&a, Some(&full)).unwrap().1, 1);
+
+        let asymmetric = array![
+            [true, true, false],
+            [false, true, false],
+            [false, false, false]
+        ];
+        let flat = array![[true, false, true], [false, false, true]];
+        assert!(label(&flat, Some(&asymmetric)).is_err());
+        assert!(label(&flat, Some(&Array2::from_elem((3, 2), true))).is_err());
+        let (labels, count) = label(&flat, None).unwrap();
+        assert_eq!((labels, count), (array![[1, 0, 2], [0, 0, 2]], 2));
+    }
+
+    #[test]
+    fn objects() {
+        let labels = array![[0, 4, 4, 0], [1, 0, 4, 0], [0, 0, 0, 1]];
+        let objects = find_objects(&labels, None);
+        assert_eq!(
+            objects,
+            [Some(vec![1..3, 0..4]), None, None, Some(vec![0..2, 1..3])]
+        );
+        assert_eq!(find_objects(&labels, Some(1)), [Some(vec![1..3, 0..4])]);
+        assert_eq!(find_objects(&labels, Some(5)).len(), 5);
+        assert!(find_objects(&Array2::<usize>::zeros((2, 2)), None).is_empty());
+    }
+
+    #[test]
+    fn statistics() {
+        let a = array![
+            [1., 2., 0., 0.],
+            [5., 3., 0., 4.],
+            [0., 0., 0., 7.],
+            [9., 3., 0., 0.]
+        ];
+        let lbl = array![[1, 1, 0, 0], [1, 1, 0, 2], [0, 0, 0, 2], [3, 3, 0, 0]];
+        // Regions may be repeated, absent or the background.
+        let index = [3, 0, 5, 3];
+        assert_eq!(
+            sum_labels(&a, Some(&lbl), Some(&index)).unwrap(),
+            [12., 0., 0., 12.]
+        );
+        let means = mean(&a, Some(&lbl), Some(&index)).unwrap();
+        assert_eq!((means[0], means[1]), (6., 0.));
+        assert!(means[2].is_nan());
+        let variances = variance(&a, Some(&lbl), Some(&index)).unwrap();
+        assert_eq!((variances[0], variances[1]), (9., 0.));
+        assert!(variances[2].is_nan());
+        assert_eq!(sum_labels(&a, None, Some(&[1])).unwrap(), [34.]);
+        assert_eq!(mean(&a, Some(&lbl), None).unwrap(), [34. / 8.]);
+        assert!(mean(&a, Some(&Array2::zeros((4, 3))), None).is_err());
+
+        let (min, max, argmin, argmax) = extrema(&a, Some(&lbl), Some(&[0, 4])).unwrap();
+        assert_eq!((min[0], max[0]), (0., 0.));
+        assert_eq!(
+            (argmin[0].clone(), argmax[0].clone()),
+            (Some(vec![0, 2]), Some(vec![0, 2]))
+        );
+        assert!(min[1].is_nan() && max[1].is_nan());
+        assert_eq!((argmin[1].clone(), argmax[1].clone()), (None, None));
+    }
+
+    #[test]
+    fn centers() {
+        let a = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| (i + j * k) as f64);
+        let lbl = Array3::from_shape_fn((2, 3, 4), |(_, _, k)| k / 2 + 1);
+        let centers = center_of_mass(&a, Some(&lbl), Some(&[2, 1])).unwrap();
+        for (center, ks) in centers.iter().zip([2..4, 0..2]) {
+            let mut mass = 0.;
+            let mut moment = [0.; 3];
+            for ((i, j, k), &x) in a.indexed_iter() {
+                if ks.contains(&k) {
+                    mass += x;
+                    moment[0] += x * i as f64;
+                    moment[1] += x * j as f64;
+                    moment[2] += x * k as f64;
+                }
+            }
+            for (c, m) in center.iter().zip(moment) {
+                assert_abs_diff_eq!(*c, m / mass, epsilon = 1e-12);
+            }
+        }
+        let empty = center_of_mass(&a, Some(&lbl), Some(&[7])).unwrap();
+        assert!(empty[0].iter().all(|c| c.is_nan()));
+    }
+}
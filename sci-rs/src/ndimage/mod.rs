@@ -0,0 +1,14 @@
+//! Functions from the
+//! [`scipy.ndimage`](https://docs.scipy.org/doc/scipy/reference/ndimage.html) module.
+
+mod filters;
+mod interpolation;
+mod measurements;
+mod morphology;
+mod rank;
+
+pub use filters::*;
+pub use interpolation::*;
+pub use measurements::*;
+pub use morphology::*;
+pub use rank::*;
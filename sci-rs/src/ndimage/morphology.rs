@@ -0,0 +1,1054 @@
+use super::filters::{per_axis, BoundaryMode, Footprint};
+use super::rank::{maximum_filter, minimum_filter};
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use ndarray::{Array, ArrayBase, ArrayD, Data, Dimension, IxDyn, Slice};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Generate a binary structure for binary morphological operations.
+///
+/// ## Parameters
+/// * `rank`: Number of dimensions of the structure.
+/// * `connectivity`: Elements up to a squared distance of `connectivity` from the center are
+///   considered neighbors. Values below 1 are treated as 1.
+///
+/// ## Returns
+/// Structuring element of shape `(3, ..., 3)`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import ndimage
+/// ndimage.generate_binary_structure(2, 1)
+/// # array([[False,  True, False],
+/// #        [ True,  True,  True],
+/// #        [False,  True, False]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::generate_binary_structure;
+///
+/// let cross = generate_binary_structure(2, 1);
+/// let expected = array![[false, true, false], [true, true, true], [false, true, false]];
+/// assert_eq!(cross, expected.into_dyn());
+/// assert!(generate_binary_structure(3, 3).iter().all(|&s| s));
+/// ```
+pub fn generate_binary_structure(rank: usize, connectivity: usize) -> ArrayD<bool> {
+    let connectivity = connectivity.max(1);
+    ArrayD::from_shape_fn(IxDyn(&alloc::vec![3; rank]), |index| {
+        index.slice().iter().filter(|&&i| i != 1).count() <= connectivity
+    })
+}
+
+/// The footprint of `structure`, or of the structure with connectivity 1 by default.
+fn structure_footprint<D: Dimension>(
+    ndim: usize,
+    structure: Option<&Array<bool, D>>,
+    origin: Option<&[isize]>,
+) -> Result<Footprint> {
+    match structure {
+        Some(structure) => Footprint::new(ndim, None, Some(structure), origin),
+        None => {
+            let structure = generate_binary_structure(ndim, 1);
+            Footprint::new(ndim, None, Some(&structure), origin)
+        }
+    }
+}
+
+/// Erode `input` by the `offsets`, with the elements beyond the boundaries of `border_value`,
+/// or dilate it if `dilate`, `iterations` times or until it is unchanged if 0.
+fn erode(
+    input: ArrayD<bool>,
+    offsets: &[Vec<isize>],
+    iterations: usize,
+    border_value: bool,
+    dilate: bool,
+) -> ArrayD<bool> {
+    let mode = BoundaryMode::Constant;
+    let mut index = alloc::vec![0; input.ndim()];
+    let mut output = input;
+    let mut iteration = 0;
+    while iterations == 0 || iteration < iterations {
+        let input = output.view();
+        let next = ArrayD::from_shape_fn(input.raw_dim(), |position| {
+            let mut values = offsets
+                .iter()
+                .map(|offset| mode.get(&input, position.slice(), offset, border_value, &mut index));
+            match dilate {
+                true => values.any(|v| v),
+                false => values.all(|v| v),
+            }
+        });
+        let unchanged = next == output;
+        output = next;
+        iteration += 1;
+        if unchanged {
+            break;
+        }
+    }
+    output
+}
+
+/// Binary morphology of `input` by erosion or dilation.
+fn binary_morphology<S, D>(
+    input: &ArrayBase<S, D>,
+    structure: Option<&Array<bool, D>>,
+    iterations: Option<usize>,
+    border_value: Option<bool>,
+    origin: Option<&[isize]>,
+    dilate: bool,
+) -> Result<Array<bool, D>>
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    let footprint = structure_footprint(input.ndim(), structure, origin)?;
+    let mut offsets = footprint.offsets;
+    if dilate {
+        // The dilation takes the elements of the reflected structure.
+        offsets.iter_mut().flatten().for_each(|o| *o = -*o);
+    }
+    let output = erode(
+        input.to_owned().into_dyn(),
+        &offsets,
+        iterations.unwrap_or(1),
+        border_value.unwrap_or(false),
+        dilate,
+    );
+    Ok(output.into_dimensionality().unwrap())
+}
+
+/// Multidimensional binary erosion with a given structuring element.
+///
+/// The output is set where the structuring element, centered on the element, only covers set
+/// elements of the input.
+///
+/// ## Parameters
+/// * `input`: Binary image to be eroded.
+/// * `structure`: Structuring element used for the erosion. The structure generated by
+///   [`generate_binary_structure`] with a connectivity of 1 by default.
+/// * `iterations`: The erosion is repeated `iterations` times, or until the result does not
+///   change anymore if 0. 1 by default.
+/// * `border_value`: Value of the input beyond its boundaries. `false` by default.
+/// * `origin`: Placement of the structuring element along each axis, given once or once for
+///   each axis. 0 by default.
+///
+/// ## Returns
+/// Erosion of the input by the structuring element.
+///
+/// ## Errors
+/// If `structure` does not have the dimensions of `input`, or `origin` is out of range.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.zeros((7, 7), dtype=bool)
+/// a[1:6, 2:5] = True
+/// ndimage.binary_erosion(a).astype(int)
+/// # array([[0, 0, 0, 0, 0, 0, 0],
+/// #        [0, 0, 0, 0, 0, 0, 0],
+/// #        [0, 0, 0, 1, 0, 0, 0],
+/// #        [0, 0, 0, 1, 0, 0, 0],
+/// #        [0, 0, 0, 1, 0, 0, 0],
+/// #        [0, 0, 0, 0, 0, 0, 0],
+/// #        [0, 0, 0, 0, 0, 0, 0]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{s, Array2};
+/// use sci_rs::ndimage::binary_erosion;
+///
+/// let mut a = Array2::from_elem((7, 7), false);
+/// a.slice_mut(s![1..6, 2..5]).fill(true);
+/// let eroded = binary_erosion(&a, None, None, None, None).unwrap();
+/// let mut expected = Array2::from_elem((7, 7), false);
+/// expected.slice_mut(s![2..5, 3]).fill(true);
+/// assert_eq!(eroded, expected);
+/// ```
+pub fn binary_erosion<S, D>(
+    input: &ArrayBase<S, D>,
+    structure: Option<&Array<bool, D>>,
+    iterations: Option<usize>,
+    border_value: Option<bool>,
+    origin: Option<&[isize]>,
+) -> Result<Array<bool, D>>
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    binary_morphology(input, structure, iterations, border_value, origin, false)
+}
+
+/// Multidimensional binary dilation with the given structuring element.
+///
+/// The output is set where the reflected structuring element, centered on the element,
+/// covers a set element of the input.
+///
+/// ## Parameters
+/// * `input`: Binary image to be dilated.
+/// * `structure`, `iterations`, `border_value`, `origin`: As for [`binary_erosion`].
+///
+/// ## Returns
+/// Dilation of the input by the structuring element.
+///
+/// ## Errors
+/// If `structure` does not have the dimensions of `input`, or `origin` is out of range.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.zeros((5, 5), dtype=bool)
+/// a[2, 2] = True
+/// ndimage.binary_dilation(a, iterations=2).astype(int)
+/// # array([[0, 0, 1, 0, 0],
+/// #        [0, 1, 1, 1, 0],
+/// #        [1, 1, 1, 1, 1],
+/// #        [0, 1, 1, 1, 0],
+/// #        [0, 0, 1, 0, 0]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{array, Array2};
+/// use sci_rs::ndimage::binary_dilation;
+///
+/// let mut a = Array2::from_elem((5, 5), false);
+/// a[[2, 2]] = true;
+/// let dilated = binary_dilation(&a, None, Some(2), None, None).unwrap();
+/// let expected = array![
+///     [0, 0, 1, 0, 0],
+///     [0, 1, 1, 1, 0],
+///     [1, 1, 1, 1, 1],
+///     [0, 1, 1, 1, 0],
+///     [0, 0, 1, 0, 0]
+/// ];
+/// assert_eq!(dilated, expected.mapv(|x| x == 1));
+/// ```
+pub fn binary_dilation<S, D>(
+    input: &ArrayBase<S, D>,
+    structure: Option<&Array<bool, D>>,
+    iterations: Option<usize>,
+    border_value: Option<bool>,
+    origin: Option<&[isize]>,
+) -> Result<Array<bool, D>>
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    binary_morphology(input, structure, iterations, border_value, origin, true)
+}
+
+/// Multidimensional binary opening with the given structuring element.
+///
+/// The opening is the dilation of the erosion of the input, which removes the objects
+/// smaller than the structuring element.
+///
+/// ## Parameters
+/// * `input`: Binary image to be opened.
+/// * `structure`, `iterations`, `border_value`, `origin`: As for [`binary_erosion`], used by
+///   both the erosion and the dilation.
+///
+/// ## Returns
+/// Opening of the input by the structuring element.
+///
+/// ## Errors
+/// If `structure` does not have the dimensions of `input`, or `origin` is out of range.
+pub fn binary_opening<S, D>(
+    input: &ArrayBase<S, D>,
+    structure: Option<&Array<bool, D>>,
+    iterations: Option<usize>,
+    border_value: Option<bool>,
+    origin: Option<&[isize]>,
+) -> Result<Array<bool, D>>
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    let eroded = binary_erosion(input, structure, iterations, border_value, origin)?;
+    binary_dilation(&eroded, structure, iterations, border_value, origin)
+}
+
+/// Multidimensional binary closing with the given structuring element.
+///
+/// The closing is the erosion of the dilation of the input, which fills the holes smaller
+/// than the structuring element.
+///
+/// ## Parameters
+/// * `input`: Binary image to be closed.
+/// * `structure`, `iterations`, `border_value`, `origin`: As for [`binary_erosion`], used by
+///   both the dilation and the erosion.
+///
+/// ## Returns
+/// Closing of the input by the structuring element.
+///
+/// ## Errors
+/// If `structure` does not have the dimensions of `input`, or `origin` is out of range.
+pub fn binary_closing<S, D>(
+    input: &ArrayBase<S, D>,
+    structure: Option<&Array<bool, D>>,
+    iterations: Option<usize>,
+    border_value: Option<bool>,
+    origin: Option<&[isize]>,
+) -> Result<Array<bool, D>>
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    let dilated = binary_dilation(input, structure, iterations, border_value, origin)?;
+    binary_erosion(&dilated, structure, iterations, border_value, origin)
+}
+
+/// Fill the holes in binary objects.
+///
+/// ## Parameters
+/// * `input`: Binary image with holes to be filled.
+/// * `structure`: Structuring element defining the connectivity of the background. The
+///   structure generated by [`generate_binary_structure`] with a connectivity of 1 by default.
+/// * `origin`: Placement of the structuring element along each axis, given once or once for
+///   each axis. 0 by default.
+///
+/// ## Returns
+/// The input with the holes filled, where the holes are the background elements which cannot
+/// be reached from beyond the boundaries.
+///
+/// ## Errors
+/// If `structure` does not have the dimensions of `input`, or `origin` is out of range.
+///
+/// ## Notes
+/// The background reachable from the boundaries is flooded with a breadth-first search, the
+/// equivalent of iterating a dilation of the boundaries constrained to the background until
+/// it is stable.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.zeros((5, 5), dtype=bool)
+/// a[1:4, 1:4] = True
+/// a[2, 2] = False
+/// ndimage.binary_fill_holes(a).astype(int)
+/// # array([[0, 0, 0, 0, 0],
+/// #        [0, 1, 1, 1, 0],
+/// #        [0, 1, 1, 1, 0],
+/// #        [0, 1, 1, 1, 0],
+/// #        [0, 0, 0, 0, 0]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{s, Array2};
+/// use sci_rs::ndimage::binary_fill_holes;
+///
+/// let mut a = Array2::from_elem((5, 5), false);
+/// a.slice_mut(s![1..4, 1..4]).fill(true);
+/// a[[2, 2]] = false;
+/// let filled = binary_fill_holes(&a, None, None).unwrap();
+/// a[[2, 2]] = true;
+/// assert_eq!(filled, a);
+/// ```
+pub fn binary_fill_holes<S, D>(
+    input: &ArrayBase<S, D>,
+    structure: Option<&Array<bool, D>>,
+    origin: Option<&[isize]>,
+) -> Result<Array<bool, D>>
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    let footprint = structure_footprint(input.ndim(), structure, origin)?;
+    let input = input.view().into_dyn();
+    let shape = input.shape();
+    let ndim = input.ndim();
+    // The element at `position - offset`, if it is inside of the input.
+    let neighbor = |position: &[usize], offset: &[isize], sign: isize| -> Option<Vec<usize>> {
+        (0..ndim)
+            .map(|a| {
+                let i = position[a] as isize - sign * offset[a];
+                (0..shape[a] as isize).contains(&i).then_some(i as usize)
+            })
+            .collect()
+    };
+    // The background reachable from beyond the boundaries.
+    let mut outside = ArrayD::from_elem(input.raw_dim(), false);
+    let mut queue = VecDeque::new();
+    for (position, &value) in input.indexed_iter() {
+        let position = position.slice();
+        let seed = footprint
+            .offsets
+            .iter()
+            .any(|o| neighbor(position, o, 1).is_none());
+        if !value && seed {
+            outside[position] = true;
+            queue.push_back(position.to_vec());
+        }
+    }
+    while let Some(position) = queue.pop_front() {
+        for offset in &footprint.offsets {
+            if let Some(next) = neighbor(&position, offset, -1) {
+                if !input[&*next] && !outside[&*next] {
+                    outside[&*next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    Ok(outside.mapv(|o| !o).into_dimensionality().unwrap())
+}
+
+/// Multidimensional binary hit-or-miss transform.
+///
+/// The hit-or-miss transform finds the locations of a given pattern, where `structure1` fits
+/// in the foreground and `structure2` fits in the background.
+///
+/// ## Parameters
+/// * `input`: Binary image where a pattern is to be detected.
+/// * `structure1`: Part of the structuring element to be fitted to the foreground. The
+///   structure generated by [`generate_binary_structure`] with a connectivity of 1 by default.
+/// * `structure2`: Part of the structuring element that has to miss completely the
+///   foreground. The complement of `structure1` by default.
+/// * `origin1`: Placement of `structure1` along each axis. 0 by default.
+/// * `origin2`: Placement of `structure2` along each axis. `origin1` by default.
+///
+/// ## Returns
+/// Hit-or-miss transform of the input.
+///
+/// ## Errors
+/// If a structure does not have the dimensions of `input`, or an origin is out of range.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.zeros((7, 7), dtype=bool)
+/// a[1, 1] = True
+/// a[2:4, 2:4] = True
+/// a[4:6, 4:6] = True
+/// structure1 = np.array([[True, False, False], [False, True, True], [False, True, True]])
+/// ndimage.binary_hit_or_miss(a, structure1=structure1).astype(int)
+/// # array([[0, 0, 0, 0, 0, 0, 0],
+/// #        [0, 0, 0, 0, 0, 0, 0],
+/// #        [0, 0, 1, 0, 0, 0, 0],
+/// #        [0, 0, 0, 0, 0, 0, 0],
+/// #        [0, 0, 0, 0, 1, 0, 0],
+/// #        [0, 0, 0, 0, 0, 0, 0],
+/// #        [0, 0, 0, 0, 0, 0, 0]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{array, s, Array2};
+/// use sci_rs::ndimage::binary_hit_or_miss;
+///
+/// let mut a = Array2::from_elem((7, 7), false);
+/// a[[1, 1]] = true;
+/// a.slice_mut(s![2..4, 2..4]).fill(true);
+/// a.slice_mut(s![4..6, 4..6]).fill(true);
+/// let structure1 = array![[true, false, false], [false, true, true], [false, true, true]];
+/// let hits = binary_hit_or_miss(&a, Some(&structure1), None, None, None).unwrap();
+/// let mut expected = Array2::from_elem((7, 7), false);
+/// expected[[2, 2]] = true;
+/// expected[[4, 4]] = true;
+/// assert_eq!(hits, expected);
+/// ```
+pub fn binary_hit_or_miss<S, D>(
+    input: &ArrayBase<S, D>,
+    structure1: Option<&Array<bool, D>>,
+    structure2: Option<&Array<bool, D>>,
+    origin1: Option<&[isize]>,
+    origin2: Option<&[isize]>,
+) -> Result<Array<bool, D>>
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    let default;
+    let structure1 = match structure1 {
+        Some(structure1) => structure1,
+        None => {
+            default = generate_binary_structure(input.ndim(), 1)
+                .into_dimensionality::<D>()
+                .unwrap();
+            &default
+        }
+    };
+    let complement;
+    let structure2 = match structure2 {
+        Some(structure2) => structure2,
+        None => {
+            complement = structure1.mapv(|s| !s);
+            &complement
+        }
+    };
+    let hit = binary_erosion(input, Some(structure1), None, None, origin1)?;
+    let background = input.mapv(|x| !x);
+    let miss = binary_erosion(
+        &background,
+        Some(structure2),
+        None,
+        None,
+        origin2.or(origin1),
+    )?;
+    Ok(hit & miss)
+}
+
+/// Greyscale erosion, or dilation if `dilate`, by a flat or non-flat structuring element.
+#[allow(clippy::too_many_arguments)]
+fn grey_morphology<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    structure: Option<&Array<F, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+    dilate: bool,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let Some(structure) = structure else {
+        if !dilate {
+            return minimum_filter(input, size, footprint, mode, cval, origin);
+        }
+        // The dilation takes the maximum over the reflected footprint, with its center moved
+        // accordingly for the even sizes.
+        let reflected = footprint.map(|f| f.slice_each_axis(|_| Slice::new(0, None, -1)));
+        let shape = match (&reflected, size) {
+            (Some(reflected), _) => reflected.shape().to_vec(),
+            (None, Some(size)) => per_axis(size, input.ndim(), "size")?,
+            (None, None) => {
+                return Err(Error::InvalidArg {
+                    arg: "size".into(),
+                    reason: "no footprint provided.".into(),
+                })
+            }
+        };
+        let origin = per_axis(origin.unwrap_or(&[0]), input.ndim(), "origin")?;
+        let origin: Vec<isize> = origin
+            .iter()
+            .zip(&shape)
+            .map(|(&o, &s)| -o - (s % 2 == 0) as isize)
+            .collect();
+        let reflected = reflected.map(|r| r.to_owned());
+        return maximum_filter(input, size, reflected.as_ref(), mode, cval, Some(&origin));
+    };
+    let ones;
+    let footprint = match footprint {
+        Some(footprint) => footprint,
+        None => {
+            ones = Array::from_elem(structure.raw_dim(), true);
+            &ones
+        }
+    };
+    if footprint.shape() != structure.shape() {
+        return Err(Error::InvalidArg {
+            arg: "structure".into(),
+            reason: "must have the shape of the footprint.".into(),
+        });
+    }
+    let offsets = Footprint::new(input.ndim(), None, Some(footprint), origin)?.offsets;
+    let heights: Vec<F> = footprint
+        .iter()
+        .zip(structure)
+        .filter(|(&selected, _)| selected)
+        .map(|(_, &h)| h)
+        .collect();
+    let mode = mode.unwrap_or_default();
+    let cval = cval.unwrap_or_else(F::zero);
+    let view = input.view().into_dyn();
+    let mut index = alloc::vec![0; input.ndim()];
+    let mut scratch: Vec<isize> = alloc::vec![0; input.ndim()];
+    let output = ArrayD::from_shape_fn(view.raw_dim(), |position| {
+        let position = position.slice();
+        let values = offsets
+            .iter()
+            .zip(&heights)
+            .map(|(offset, &h)| match dilate {
+                true => {
+                    // The reflected structure is taken at the opposite offsets.
+                    scratch.iter_mut().zip(offset).for_each(|(s, &o)| *s = -o);
+                    mode.get(&view, position, &scratch, cval, &mut index) + h
+                }
+                false => mode.get(&view, position, offset, cval, &mut index) - h,
+            });
+        match dilate {
+            true => values.fold(F::neg_infinity(), F::max),
+            false => values.fold(F::infinity(), F::min),
+        }
+    });
+    Ok(output.into_dimensionality().unwrap())
+}
+
+/// Calculate a greyscale erosion, using either a structuring element, or a footprint
+/// corresponding to a flat structuring element.
+///
+/// The output at each element is the minimum over the footprint of the input minus the
+/// structuring element.
+///
+/// ## Parameters
+/// * `input`: Array over which the greyscale erosion is to be computed.
+/// * `size`: Shape of a flat and full structuring element, given once for all axes or once for
+///   each axis. Ignored if `footprint` or `structure` is given.
+/// * `footprint`: Positions of the elements of a flat structuring element, or of the elements
+///   of `structure` used.
+/// * `structure`: Structuring element used for the greyscale erosion, which may be non-flat.
+///   Flat by default.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Reflect`] by default.
+/// * `cval`: Value to fill past the edges of the input with [`BoundaryMode::Constant`]. 0 by
+///   default.
+/// * `origin`: Placement of the structuring element along each axis, given once or once for
+///   each axis. 0 by default.
+///
+/// ## Returns
+/// Greyscale erosion of the input.
+///
+/// ## Errors
+/// If none of `size`, `footprint` and `structure` is given, they have the wrong dimensions or
+/// shapes, or `origin` is out of range.
+///
+/// ## Notes
+/// With a flat structuring element, the erosion is the [`minimum_filter`].
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.zeros((7, 7))
+/// a[1:6, 1:6] = 3
+/// a[4, 4] = 2
+/// a[2, 3] = 1
+/// ndimage.grey_erosion(a, size=(3, 3))
+/// # array([[0., 0., 0., 0., 0., 0., 0.],
+/// #        [0., 0., 0., 0., 0., 0., 0.],
+/// #        [0., 0., 1., 1., 1., 0., 0.],
+/// #        [0., 0., 1., 1., 1., 0., 0.],
+/// #        [0., 0., 3., 2., 2., 0., 0.],
+/// #        [0., 0., 0., 0., 0., 0., 0.],
+/// #        [0., 0., 0., 0., 0., 0., 0.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{s, Array2};
+/// use sci_rs::ndimage::grey_erosion;
+///
+/// let mut a = Array2::zeros((7, 7));
+/// a.slice_mut(s![1..6, 1..6]).fill(3.);
+/// a[[4, 4]] = 2.;
+/// a[[2, 3]] = 1.;
+/// let eroded = grey_erosion(&a, Some(&[3, 3]), None, None, None, None, None).unwrap();
+/// let mut expected = Array2::zeros((7, 7));
+/// expected.slice_mut(s![2..4, 2..5]).fill(1.);
+/// expected.slice_mut(s![4, 2..5]).assign(&ndarray::array![3., 2., 2.]);
+/// assert_eq!(eroded, expected);
+/// ```
+pub fn grey_erosion<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    structure: Option<&Array<F, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    grey_morphology(input, size, footprint, structure, mode, cval, origin, false)
+}
+
+/// Calculate a greyscale dilation, using either a structuring element, or a footprint
+/// corresponding to a flat structuring element.
+///
+/// The output at each element is the maximum over the reflected footprint of the input plus
+/// the reflected structuring element.
+///
+/// ## Parameters
+/// * `input`: Array over which the greyscale dilation is to be computed.
+/// * `size`, `footprint`, `structure`, `mode`, `cval`, `origin`: As for [`grey_erosion`].
+///
+/// ## Returns
+/// Greyscale dilation of the input.
+///
+/// ## Errors
+/// As for [`grey_erosion`].
+///
+/// ## Notes
+/// With a flat structuring element, the dilation is the [`maximum_filter`] over the reflected
+/// footprint.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.zeros((7, 7))
+/// a[2:5, 2:5] = 1
+/// a[4, 4] = 2
+/// a[2, 3] = 3
+/// ndimage.grey_dilation(a, size=(3, 3))
+/// # array([[0., 0., 0., 0., 0., 0., 0.],
+/// #        [0., 1., 3., 3., 3., 1., 0.],
+/// #        [0., 1., 3., 3., 3., 1., 0.],
+/// #        [0., 1., 3., 3., 3., 2., 0.],
+/// #        [0., 1., 1., 2., 2., 2., 0.],
+/// #        [0., 1., 1., 2., 2., 2., 0.],
+/// #        [0., 0., 0., 0., 0., 0., 0.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{array, s, Array2};
+/// use sci_rs::ndimage::grey_dilation;
+///
+/// let mut a = Array2::zeros((7, 7));
+/// a.slice_mut(s![2..5, 2..5]).fill(1.);
+/// a[[4, 4]] = 2.;
+/// a[[2, 3]] = 3.;
+/// let dilated = grey_dilation(&a, Some(&[3, 3]), None, None, None, None, None).unwrap();
+/// let expected = array![
+///     [0., 0., 0., 0., 0., 0., 0.],
+///     [0., 1., 3., 3., 3., 1., 0.],
+///     [0., 1., 3., 3., 3., 1., 0.],
+///     [0., 1., 3., 3., 3., 2., 0.],
+///     [0., 1., 1., 2., 2., 2., 0.],
+///     [0., 1., 1., 2., 2., 2., 0.],
+///     [0., 0., 0., 0., 0., 0., 0.]
+/// ];
+/// assert_eq!(dilated, expected);
+/// ```
+pub fn grey_dilation<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    structure: Option<&Array<F, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    grey_morphology(input, size, footprint, structure, mode, cval, origin, true)
+}
+
+/// Multidimensional greyscale opening, the [`grey_dilation`] of the [`grey_erosion`].
+///
+/// ## Parameters
+/// * `input`: Array over which the greyscale opening is to be computed.
+/// * `size`, `footprint`, `structure`, `mode`, `cval`, `origin`: As for [`grey_erosion`], used
+///   by both the erosion and the dilation.
+///
+/// ## Returns
+/// Greyscale opening of the input.
+///
+/// ## Errors
+/// As for [`grey_erosion`].
+pub fn grey_opening<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    structure: Option<&Array<F, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let eroded = grey_erosion(input, size, footprint, structure, mode, cval, origin)?;
+    grey_dilation(&eroded, size, footprint, structure, mode, cval, origin)
+}
+
+/// Multidimensional greyscale closing, the [`grey_erosion`] of the [`grey_dilation`].
+///
+/// ## Parameters
+/// * `input`: Array over which the greyscale closing is to be computed.
+/// * `size`, `footprint`, `structure`, `mode`, `cval`, `origin`: As for [`grey_erosion`], used
+///   by both the dilation and the erosion.
+///
+/// ## Returns
+/// Greyscale closing of the input.
+///
+/// ## Errors
+/// As for [`grey_erosion`].
+pub fn grey_closing<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    structure: Option<&Array<F, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let dilated = grey_dilation(input, size, footprint, structure, mode, cval, origin)?;
+    grey_erosion(&dilated, size, footprint, structure, mode, cval, origin)
+}
+
+/// Multidimensional white tophat filter, the input minus its [`grey_opening`].
+///
+/// It extracts the bright details smaller than the structuring element.
+///
+/// ## Parameters
+/// * `input`: Array over which the white tophat is to be computed.
+/// * `size`, `footprint`, `structure`, `mode`, `cval`, `origin`: As for [`grey_erosion`].
+///
+/// ## Returns
+/// White tophat of the input.
+///
+/// ## Errors
+/// As for [`grey_erosion`].
+pub fn white_tophat<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    structure: Option<&Array<F, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let opened = grey_opening(input, size, footprint, structure, mode, cval, origin)?;
+    Ok(input.to_owned() - opened)
+}
+
+/// Multidimensional black tophat filter, the [`grey_closing`] minus the input.
+///
+/// It extracts the dark details smaller than the structuring element.
+///
+/// ## Parameters
+/// * `input`: Array over which the black tophat is to be computed.
+/// * `size`, `footprint`, `structure`, `mode`, `cval`, `origin`: As for [`grey_erosion`].
+///
+/// ## Returns
+/// Black tophat of the input.
+///
+/// ## Errors
+/// As for [`grey_erosion`].
+pub fn black_tophat<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    structure: Option<&Array<F, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let closed = grey_closing(input, size, footprint, structure, mode, cval, origin)?;
+    Ok(closed - input)
+}
+
+/// Multidimensional morphological gradient, the [`grey_dilation`] minus the
+/// [`grey_erosion`].
+///
+/// ## Parameters
+/// * `input`: Array over which the morphological gradient is to be computed.
+/// * `size`, `footprint`, `structure`, `mode`, `cval`, `origin`: As for [`grey_erosion`].
+///
+/// ## Returns
+/// Morphological gradient of the input.
+///
+/// ## Errors
+/// As for [`grey_erosion`].
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.zeros((7, 7))
+/// a[2:5, 2:5] = 1
+/// ndimage.morphological_gradient(a, size=(3, 3))[3]
+/// # array([0., 1., 1., 0., 1., 1., 0.])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{array, s, Array2};
+/// use sci_rs::ndimage::morphological_gradient;
+///
+/// let mut a = Array2::zeros((7, 7));
+/// a.slice_mut(s![2..5, 2..5]).fill(1.);
+/// let gradient = morphological_gradient(&a, Some(&[3]), None, None, None, None, None).unwrap();
+/// assert_eq!(gradient.row(3), array![0., 1., 1., 0., 1., 1., 0.]);
+/// ```
+pub fn morphological_gradient<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    structure: Option<&Array<F, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let dilated = grey_dilation(input, size, footprint, structure, mode, cval, origin)?;
+    let eroded = grey_erosion(input, size, footprint, structure, mode, cval, origin)?;
+    Ok(dilated - eroded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use ndarray::{array, Array2};
+
+    fn mask(rows: &[&str]) -> Array2<bool> {
+        let values = rows.iter().flat_map(|row| row.chars().map(|c| c == '#'));
+        Array2::from_shape_vec((rows.len(), rows[0].len()), values.collect()).unwrap()
+    }
+
+    #[test]
+    fn erosion_and_dilation() {
+        let a = mask(&["......", ".####.", ".####.", ".###..", "......"]);
+        let square = Array2::from_elem((2, 2), true);
+        let eroded = binary_erosion(&a, Some(&square), None, None, None).unwrap();
+        assert_eq!(
+            eroded,
+            mask(&["......", "......", "..###.", "..##..", "......"])
+        );
+        let shifted = binary_erosion(&a, Some(&square), None, None, Some(&[-1, 0])).unwrap();
+        assert_eq!(
+            shifted,
+            mask(&["......", "..###.", "..##..", "......", "......"])
+        );
+        // Dilation by an even structure reflects it about the center, which restores the input.
+        let dilated = binary_dilation(&eroded, Some(&square), None, None, None).unwrap();
+        assert_eq!(dilated, a);
+
+        // Repeated until nothing is left, or until the border is reached.
+        let all = binary_erosion(&a, None, Some(0), None, None).unwrap();
+        assert!(all.iter().all(|&x| !x));
+        let all = binary_dilation(&a, None, Some(0), None, None).unwrap();
+        assert!(all.iter().all(|&x| x));
+        let border = binary_dilation(
+            &Array2::from_elem((3, 3), false),
+            None,
+            None,
+            Some(true),
+            None,
+        );
+        assert_eq!(border.unwrap(), mask(&["###", "#.#", "###"]));
+        let full = Array2::from_elem((3, 3), true);
+        let kept = binary_erosion(&full, None, None, Some(true), None).unwrap();
+        assert_eq!(kept, full);
+        let too_big = binary_erosion(&a, Some(&square), None, None, Some(&[1, 0]));
+        assert!(too_big.is_err());
+    }
+
+    #[test]
+    fn opening_closing_and_holes() {
+        let a = mask(&["#.....", ".####.", ".#..#.", ".####.", "......"]);
+        let opened = binary_opening(&a, None, None, None, None).unwrap();
+        assert!(opened.iter().all(|&x| !x));
+        let closed = binary_closing(&a, None, None, None, None).unwrap();
+        assert_eq!(
+            closed,
+            mask(&["......", ".####.", ".####.", ".####.", "......"])
+        );
+
+        let filled = binary_fill_holes(&a, None, None).unwrap();
+        assert_eq!(
+            filled,
+            mask(&["#.....", ".####.", ".####.", ".####.", "......"])
+        );
+        // The hole leaks through diagonals with a connectivity of 2.
+        let leaky = mask(&["......", ".###..", ".#.#..", ".##...", "......"]);
+        let eight = generate_binary_structure(2, 2)
+            .into_dimensionality()
+            .unwrap();
+        assert_eq!(
+            binary_fill_holes(&leaky, Some(&eight), None).unwrap(),
+            leaky
+        );
+        let filled = binary_fill_holes(&leaky, None, None).unwrap();
+        assert!(filled[[2, 2]]);
+
+        // Isolated foreground elements, away from the border which is not background.
+        let isolated = mask(&["....", ".#..", "...#", "..##"]);
+        let structure = array![
+            [false, false, false],
+            [false, true, false],
+            [false, false, false]
+        ];
+        let hits = binary_hit_or_miss(&isolated, Some(&structure), None, None, None).unwrap();
+        assert_eq!(hits, mask(&["....", ".#..", "....", "...."]));
+    }
+
+    #[test]
+    fn greyscale() {
+        // Even flat structuring elements, reflected by the dilation.
+        let x = ndarray::array![0., 0., 1., 0., 3., 3.];
+        let dilated = grey_dilation(&x, Some(&[2]), None, None, None, None, None).unwrap();
+        assert_eq!(dilated, ndarray::array![0., 1., 1., 3., 3., 3.]);
+        let eroded = grey_erosion(&dilated, Some(&[2]), None, None, None, None, None).unwrap();
+        assert_eq!(eroded, ndarray::array![0., 0., 1., 1., 3., 3.]);
+        let peaks = white_tophat(&x, Some(&[2]), None, None, None, None, None).unwrap();
+        assert_eq!(peaks, ndarray::array![0., 0., 1., 0., 0., 0.]);
+        let pits = black_tophat(&-&x, Some(&[2]), None, None, None, None, None).unwrap();
+        assert_eq!(pits, peaks);
+
+        // A non-flat structuring element, directly.
+        let a = Array2::from_shape_fn((4, 5), |(i, j)| ((3 * i + 2 * j) % 7) as f64);
+        let structure = ndarray::array![[0.5, 1., 0.], [2., 0., -1.]];
+        let footprint = ndarray::array![[true, true, false], [true, true, true]];
+        let erosion = |dilate: bool| {
+            grey_morphology(
+                &a,
+                None,
+                Some(&footprint),
+                Some(&structure),
+                Some(BoundaryMode::Nearest),
+                None,
+                None,
+                dilate,
+            )
+            .unwrap()
+        };
+        let at = |i: isize, j: isize| a[[i.clamp(0, 3) as usize, j.clamp(0, 4) as usize]];
+        for (((i, j), &eroded), &dilated) in erosion(false).indexed_iter().zip(&erosion(true)) {
+            let (i, j) = (i as isize, j as isize);
+            let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+            for ((k, l), &h) in structure.indexed_iter() {
+                if footprint[[k, l]] {
+                    let (di, dj) = (k as isize - 1, l as isize - 1);
+                    min = min.min(at(i + di, j + dj) - h);
+                    max = max.max(at(i - di, j - dj) + h);
+                }
+            }
+            assert_eq!((eroded, dilated), (min, max));
+        }
+        let wrong = Array2::from_elem((2, 2), true);
+        assert!(grey_erosion(&a, None, Some(&wrong), Some(&structure), None, None, None).is_err());
+    }
+}
@@ -0,0 +1,625 @@
+use super::filters::{filter_lanes, BoundaryMode, Footprint};
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use ndarray::{indices, Array, ArrayBase, ArrayD, Axis, Data, Dimension, IxDyn};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Sliding minimum, or maximum if `max`, of the lanes of `input` along `axis`.
+fn extremum_filter1d<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: usize,
+    axis: usize,
+    max: bool,
+    mode: BoundaryMode,
+    cval: F,
+    origin: isize,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    filter_lanes(input, axis, size, origin, mode, cval, |buffer, mut out| {
+        // Indices of the candidates for the extremum of the windows, with monotonic values.
+        let mut candidates = VecDeque::with_capacity(size);
+        for (k, &x) in buffer.iter().enumerate() {
+            while candidates.back().is_some_and(|&c: &usize| match max {
+                true => buffer[c] <= x,
+                false => buffer[c] >= x,
+            }) {
+                candidates.pop_back();
+            }
+            candidates.push_back(k);
+            if k + 1 >= size {
+                let i = k + 1 - size;
+                while candidates[0] < i {
+                    candidates.pop_front();
+                }
+                out[i] = buffer[candidates[0]];
+            }
+        }
+    })
+}
+
+/// Element of rank `rank` of the neighborhoods in `footprint`, selected from a sorted window
+/// updated as it slides along the last axis.
+fn sliding_rank<F, S, D>(
+    input: &ArrayBase<S, D>,
+    rank: usize,
+    footprint: &Footprint,
+    mode: BoundaryMode,
+    cval: F,
+) -> Array<F, D>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let view = input.view().into_dyn();
+    let ndim = view.ndim();
+    if ndim == 0 || view.is_empty() {
+        return input.to_owned();
+    }
+    let last = ndim - 1;
+    let offsets = &footprint.offsets;
+    // Whether the element at each offset leaves the window as it slides by one, and whether
+    // the one after it enters.
+    let steps: Vec<(bool, bool)> = offsets
+        .iter()
+        .map(|o| {
+            let selected = |d: isize| {
+                offsets
+                    .iter()
+                    .any(|p| p[..last] == o[..last] && p[last] == o[last] + d)
+            };
+            (!selected(-1), !selected(1))
+        })
+        .collect();
+    let cmp = |a: &F, b: &F| a.partial_cmp(b).unwrap_or(Ordering::Equal);
+    let mut output = ArrayD::from_elem(view.raw_dim(), cval);
+    let mut window = Vec::with_capacity(offsets.len());
+    let mut position = alloc::vec![0; ndim];
+    let mut index = alloc::vec![0; ndim];
+    for outer in indices(IxDyn(&view.shape()[..last])) {
+        position[..last].copy_from_slice(outer.slice());
+        position[last] = 0;
+        window.clear();
+        window.extend(
+            offsets
+                .iter()
+                .map(|o| mode.get(&view, &position, o, cval, &mut index)),
+        );
+        window.sort_by(cmp);
+        output[&*position] = window[rank];
+        for j in 1..view.len_of(Axis(last)) {
+            position[last] = j - 1;
+            for (o, _) in offsets.iter().zip(&steps).filter(|(_, s)| s.0) {
+                let x = mode.get(&view, &position, o, cval, &mut index);
+                let i = window.partition_point(|y| cmp(y, &x) == Ordering::Less);
+                window.remove(i.min(window.len() - 1));
+            }
+            position[last] = j;
+            for (o, _) in offsets.iter().zip(&steps).filter(|(_, s)| s.1) {
+                let x = mode.get(&view, &position, o, cval, &mut index);
+                let i = window.partition_point(|y| cmp(y, &x) == Ordering::Less);
+                window.insert(i, x);
+            }
+            output[&*position] = window[rank];
+        }
+    }
+    output.into_dimensionality().unwrap()
+}
+
+/// Rank filter with the rank given by `rank` from the size of the footprint.
+fn generic_rank_filter<F, S, D>(
+    input: &ArrayBase<S, D>,
+    rank: impl FnOnce(usize) -> Result<usize>,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let footprint = Footprint::new(input.ndim(), size, footprint, origin)?;
+    let filter_size = footprint.offsets.len();
+    if filter_size == 0 {
+        return Err(Error::InvalidArg {
+            arg: "footprint".into(),
+            reason: "must select at least one element.".into(),
+        });
+    }
+    let rank = rank(filter_size)?;
+    let mode = mode.unwrap_or_default();
+    let cval = cval.unwrap_or_else(F::zero);
+    let extremum = rank == 0 || rank == filter_size - 1;
+    if !(extremum && footprint.is_box()) {
+        return Ok(sliding_rank(input, rank, &footprint, mode, cval));
+    }
+    // The minimum and maximum over boxes are separable.
+    let mut output = input.to_owned();
+    for (axis, (&size, &origin)) in footprint.shape.iter().zip(&footprint.origin).enumerate() {
+        if size > 1 {
+            let max = rank > 0;
+            output = extremum_filter1d(&output, size, axis, max, mode, cval, origin)?;
+        }
+    }
+    Ok(output)
+}
+
+/// Calculate a multidimensional rank filter.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `rank`: The rank of the element to select from the sorted neighborhood, counted from its
+///   end if negative, such that -1 indicates the largest element.
+/// * `size`: Shape of the box taken from the input at every element position, given once for
+///   all axes or once for each axis. Ignored if `footprint` is given.
+/// * `footprint`: Boolean array selecting the elements of the neighborhood.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Reflect`] by default.
+/// * `cval`: Value to fill past the edges of the input with [`BoundaryMode::Constant`]. 0 by
+///   default.
+/// * `origin`: Controls the placement of the filter along each axis, given once or once for
+///   each axis. 0 by default.
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// If neither `size` nor `footprint` is given, the footprint is empty or has the wrong number of
+/// dimensions, `rank` is out of the footprint, or `origin` is out of range.
+///
+/// ## Notes
+/// The neighborhoods are kept sorted as they slide along the last axis, so that each step
+/// only updates the elements which enter and leave the footprint. The minimum and maximum over
+/// a box are computed separably with monotonic queues, in linear time.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.array([[1.0, 2, 0, 0], [5, 3, 0, 4], [0, 0, 0, 7], [9, 3, 0, 0]])
+/// ndimage.rank_filter(a, rank=-1, size=3)
+/// # array([[5., 5., 4., 4.],
+/// #        [5., 5., 7., 7.],
+/// #        [9., 9., 7., 7.],
+/// #        [9., 9., 7., 7.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::rank_filter;
+///
+/// let a = array![[1., 2., 0., 0.], [5., 3., 0., 4.], [0., 0., 0., 7.], [9., 3., 0., 0.]];
+/// let b = rank_filter(&a, -1, Some(&[3]), None, None, None, None).unwrap();
+/// let expected = array![[5., 5., 4., 4.], [5., 5., 7., 7.], [9., 9., 7., 7.], [9., 9., 7., 7.]];
+/// assert_eq!(b, expected);
+/// ```
+pub fn rank_filter<F, S, D>(
+    input: &ArrayBase<S, D>,
+    rank: isize,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let rank = |filter_size: usize| {
+        let n = filter_size as isize;
+        let rank = if rank < 0 { rank + n } else { rank };
+        match rank {
+            0.. if rank < n => Ok(rank as usize),
+            _ => Err(Error::InvalidArg {
+                arg: "rank".into(),
+                reason: "not within the filter footprint size.".into(),
+            }),
+        }
+    };
+    generic_rank_filter(input, rank, size, footprint, mode, cval, origin)
+}
+
+/// Calculate a multidimensional percentile filter.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `percentile`: The percentile, between -100 and 100, counted from 100 if negative, such
+///   that -20 equals 80.
+/// * `size`, `footprint`, `mode`, `cval`, `origin`: As for [`rank_filter`].
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// As for [`rank_filter`], or if `percentile` is out of range.
+///
+/// ## Notes
+/// The percentile selects the element of rank `floor(n * percentile / 100)` of the `n`
+/// elements of each neighborhood, without interpolating.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.array([[1.0, 2, 0, 0], [5, 3, 0, 4], [0, 0, 0, 7], [9, 3, 0, 0]])
+/// ndimage.percentile_filter(a, percentile=20, size=2)
+/// # array([[1., 1., 0., 0.],
+/// #        [1., 1., 0., 0.],
+/// #        [0., 0., 0., 0.],
+/// #        [0., 0., 0., 0.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::percentile_filter;
+///
+/// let a = array![[1., 2., 0., 0.], [5., 3., 0., 4.], [0., 0., 0., 7.], [9., 3., 0., 0.]];
+/// let b = percentile_filter(&a, 20., Some(&[2]), None, None, None, None).unwrap();
+/// let expected = array![[1., 1., 0., 0.], [1., 1., 0., 0.], [0., 0., 0., 0.], [0., 0., 0., 0.]];
+/// assert_eq!(b, expected);
+/// ```
+pub fn percentile_filter<F, S, D>(
+    input: &ArrayBase<S, D>,
+    percentile: F,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let hundred = F::from(100).unwrap();
+    let percentile = match percentile < F::zero() {
+        true => percentile + hundred,
+        false => percentile,
+    };
+    if !(F::zero() <= percentile && percentile <= hundred) {
+        return Err(Error::InvalidArg {
+            arg: "percentile".into(),
+            reason: "must be between -100 and 100.".into(),
+        });
+    }
+    let rank = |filter_size: usize| {
+        Ok(match percentile == hundred {
+            true => filter_size - 1,
+            false => (F::from(filter_size).unwrap() * percentile / hundred)
+                .to_usize()
+                .unwrap(),
+        })
+    };
+    generic_rank_filter(input, rank, size, footprint, mode, cval, origin)
+}
+
+/// Calculate a multidimensional median filter.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `size`, `footprint`, `mode`, `cval`, `origin`: As for [`rank_filter`].
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// As for [`rank_filter`].
+///
+/// ## Notes
+/// For an even number `n` of elements in the footprint, the element of rank `n / 2` is
+/// selected rather than an average.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import ndimage
+/// a = np.array([[1.0, 2, 0, 0], [5, 3, 0, 4], [0, 0, 0, 7], [9, 3, 0, 0]])
+/// ndimage.median_filter(a, footprint=[[0, 1, 0], [1, 1, 1], [0, 1, 0]])
+/// # array([[1., 2., 0., 0.],
+/// #        [3., 2., 0., 4.],
+/// #        [0., 0., 0., 4.],
+/// #        [9., 3., 0., 0.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::median_filter;
+///
+/// let a = array![[1., 2., 0., 0.], [5., 3., 0., 4.], [0., 0., 0., 7.], [9., 3., 0., 0.]];
+/// let cross = array![[false, true, false], [true, true, true], [false, true, false]];
+/// let b = median_filter(&a, None, Some(&cross), None, None, None).unwrap();
+/// let expected = array![[1., 2., 0., 0.], [3., 2., 0., 4.], [0., 0., 0., 4.], [9., 3., 0., 0.]];
+/// assert_eq!(b, expected);
+/// ```
+pub fn median_filter<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let rank = |filter_size: usize| Ok(filter_size / 2);
+    generic_rank_filter(input, rank, size, footprint, mode, cval, origin)
+}
+
+/// Calculate a multidimensional minimum filter.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `size`, `footprint`, `mode`, `cval`, `origin`: As for [`rank_filter`].
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// As for [`rank_filter`].
+pub fn minimum_filter<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let rank = |_| Ok(0);
+    generic_rank_filter(input, rank, size, footprint, mode, cval, origin)
+}
+
+/// Calculate a multidimensional maximum filter.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `size`, `footprint`, `mode`, `cval`, `origin`: As for [`rank_filter`].
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// As for [`rank_filter`].
+pub fn maximum_filter<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: Option<&[usize]>,
+    footprint: Option<&Array<bool, D>>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let rank = |filter_size: usize| Ok(filter_size - 1);
+    generic_rank_filter(input, rank, size, footprint, mode, cval, origin)
+}
+
+/// Calculate a 1-D minimum filter along the given axis.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `size`: Length along which to calculate the 1-D minimum.
+/// * `axis`: The axis of `input` along which to calculate. -1 by default.
+/// * `mode`: How the input is extended beyond its boundaries, see [`BoundaryMode`].
+///   [`BoundaryMode::Reflect`] by default.
+/// * `cval`: Value to fill past the edges of the input with [`BoundaryMode::Constant`]. 0 by
+///   default.
+/// * `origin`: Controls the placement of the filter. 0 by default.
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// If `size` is 0, `origin` is out of range, or `axis` is out of range.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.ndimage import minimum_filter1d
+/// minimum_filter1d([2.0, 8, 0, 4, 1, 9, 9, 0], size=3)
+/// # array([2., 0., 0., 0., 1., 1., 0., 0.])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::minimum_filter1d;
+///
+/// let x = array![2., 8., 0., 4., 1., 9., 9., 0.];
+/// let y = minimum_filter1d(&x, 3, None, None, None, None).unwrap();
+/// assert_eq!(y, array![2., 0., 0., 0., 1., 1., 0., 0.]);
+/// ```
+pub fn minimum_filter1d<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: usize,
+    axis: Option<isize>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<isize>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, input)?;
+    extremum_filter1d(
+        input,
+        size,
+        axis,
+        false,
+        mode.unwrap_or_default(),
+        cval.unwrap_or_else(F::zero),
+        origin.unwrap_or(0),
+    )
+}
+
+/// Calculate a 1-D maximum filter along the given axis.
+///
+/// ## Parameters
+/// * `input`: The input array.
+/// * `size`: Length along which to calculate the 1-D maximum.
+/// * `axis`, `mode`, `cval`, `origin`: As for [`minimum_filter1d`].
+///
+/// ## Returns
+/// The filtered array, of the same shape as `input`.
+///
+/// ## Errors
+/// If `size` is 0, `origin` is out of range, or `axis` is out of range.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.ndimage import maximum_filter1d
+/// maximum_filter1d([2.0, 8, 0, 4, 1, 9, 9, 0], size=3)
+/// # array([8., 8., 8., 4., 9., 9., 9., 9.])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::ndimage::maximum_filter1d;
+///
+/// let x = array![2., 8., 0., 4., 1., 9., 9., 0.];
+/// let y = maximum_filter1d(&x, 3, None, None, None, None).unwrap();
+/// assert_eq!(y, array![8., 8., 8., 4., 9., 9., 9., 9.]);
+/// ```
+pub fn maximum_filter1d<F, S, D>(
+    input: &ArrayBase<S, D>,
+    size: usize,
+    axis: Option<isize>,
+    mode: Option<BoundaryMode>,
+    cval: Option<F>,
+    origin: Option<isize>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let axis = check_and_get_axis_dyn(axis, input)?;
+    extremum_filter1d(
+        input,
+        size,
+        axis,
+        true,
+        mode.unwrap_or_default(),
+        cval.unwrap_or_else(F::zero),
+        origin.unwrap_or(0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+    use ndarray::{array, Array3};
+
+    /// Element of rank `rank` of each neighborhood, sorted directly.
+    fn direct_rank(
+        a: &Array3<f64>,
+        rank: usize,
+        footprint: &Footprint,
+        mode: BoundaryMode,
+    ) -> Array3<f64> {
+        let view = a.view().into_dyn();
+        let mut index = [0; 3];
+        Array3::from_shape_fn(a.dim(), |(i, j, k)| {
+            let mut window: Vec<f64> = footprint
+                .offsets
+                .iter()
+                .map(|o| mode.get(&view, &[i, j, k], o, -1., &mut index))
+                .collect();
+            window.sort_by(f64::total_cmp);
+            window[rank]
+        })
+    }
+
+    #[test]
+    fn sliding_windows() {
+        let a = Array3::from_shape_fn((3, 4, 7), |(i, j, k)| ((5 * i + 3 * j + 7 * k) % 11) as f64);
+        let footprint = array![
+            [[true, false, true], [false, true, true]],
+            [[true, true, false], [false, false, true]]
+        ];
+        for mode in [
+            BoundaryMode::Reflect,
+            BoundaryMode::Constant,
+            BoundaryMode::Wrap,
+        ] {
+            let origin = [0, -1, 1];
+            let expected = Footprint::new(3, None, Some(&footprint), Some(&origin)).unwrap();
+            for rank in 0..7 {
+                let filtered = rank_filter(
+                    &a,
+                    rank as isize,
+                    None,
+                    Some(&footprint),
+                    Some(mode),
+                    Some(-1.),
+                    Some(&origin),
+                );
+                assert_eq!(filtered.unwrap(), direct_rank(&a, rank, &expected, mode));
+            }
+        }
+        let median = median_filter(&a, Some(&[2, 3, 4]), None, None, None, None).unwrap();
+        let expected = Footprint::new::<ndarray::Ix3>(3, Some(&[2, 3, 4]), None, None).unwrap();
+        assert_eq!(
+            median,
+            direct_rank(&a, 12, &expected, BoundaryMode::Reflect)
+        );
+        assert!(rank_filter(&a, 7, None, Some(&footprint), None, None, None).is_err());
+        assert!(median_filter(&a, None, None, None, None, None).is_err());
+        assert!(percentile_filter(&a, 101., Some(&[3]), None, None, None, None).is_err());
+    }
+
+    #[test]
+    fn separable_extrema() {
+        let a = Array3::from_shape_fn((4, 5, 6), |(i, j, k)| ((7 * i + 2 * j + 5 * k) % 13) as f64);
+        let size = [3, 2, 4];
+        let origin = [1, 0, -2];
+        let expected = Footprint::new::<ndarray::Ix3>(3, Some(&size), None, Some(&origin)).unwrap();
+        for mode in [
+            BoundaryMode::Nearest,
+            BoundaryMode::Mirror,
+            BoundaryMode::Constant,
+        ] {
+            let minimum =
+                minimum_filter(&a, Some(&size), None, Some(mode), Some(-1.), Some(&origin));
+            let maximum =
+                maximum_filter(&a, Some(&size), None, Some(mode), Some(-1.), Some(&origin));
+            assert_eq!(minimum.unwrap(), direct_rank(&a, 0, &expected, mode));
+            assert_eq!(maximum.unwrap(), direct_rank(&a, 23, &expected, mode));
+        }
+        let maximum = percentile_filter(&a, 100., Some(&size), None, None, None, None).unwrap();
+        assert_eq!(
+            maximum,
+            maximum_filter(&a, Some(&size), None, None, None, None).unwrap()
+        );
+        assert!(minimum_filter1d(&a, 0, None, None, None, None).is_err());
+    }
+}
@@ -0,0 +1,208 @@
+use super::line_search::{dot, line_search_wolfe2, Jacobian, ScalarFunction};
+use super::trust_region::matvec;
+use super::{MinimizeOptions, OptimizeResult};
+use alloc::string::String;
+use ndarray::{Array1, Array2, ArrayView1};
+use num_traits::Float;
+use sci_rs_core::Result;
+
+/// Minimization by the BFGS algorithm, as [super::minimize] with [super::Method::Bfgs].
+pub(super) fn bfgs<F: Float>(
+    fun: impl FnMut(ArrayView1<F>) -> F,
+    jac: Option<Jacobian<'_, F>>,
+    x0: Array1<F>,
+    options: &MinimizeOptions<F>,
+) -> Result<OptimizeResult<F, Array1<F>>> {
+    let n = x0.len();
+    let maxiter = options.maxiter.unwrap_or(200 * n);
+    let gtol = options.gtol.unwrap_or_else(|| F::from(1e-5).unwrap());
+    let eps = options.eps.unwrap_or_else(|| F::epsilon().sqrt());
+    let (c1, c2) = (F::from(1e-4).unwrap(), F::from(0.9).unwrap());
+    let norm = |v: &Array1<F>| v.fold(F::zero(), |acc, x| acc.max(x.abs()));
+
+    let mut sf = ScalarFunction::new(fun, jac, eps, None);
+    let mut xk = x0;
+    let mut old_fval = sf.fun(&xk);
+    let mut gfk = sf.grad(&xk);
+    let mut hk = Array2::<F>::eye(n);
+    // Sets the initial step guess to dx ~ 1.
+    let mut old_old_fval = old_fval + dot(&gfk, &gfk).sqrt() / F::from(2).unwrap();
+
+    let mut k = 0;
+    let mut precision_loss = false;
+    let mut gnorm = norm(&gfk);
+    while gnorm > gtol && k < maxiter {
+        let pk = -matvec(&hk, &gfk);
+        let derphi0 = dot(&gfk, &pk);
+        let alpha1 = if derphi0.is_zero() {
+            F::one()
+        } else {
+            let alpha1 = F::one().min(F::from(2.02).unwrap() * (old_fval - old_old_fval) / derphi0);
+            if alpha1 < F::zero() {
+                F::one()
+            } else {
+                alpha1
+            }
+        };
+        let Some(step) = line_search_wolfe2(
+            &mut sf,
+            &xk,
+            &pk,
+            old_fval,
+            derphi0,
+            alpha1,
+            c1,
+            c2,
+            F::max_value(),
+            10,
+        ) else {
+            // The line search failed to find a better solution.
+            precision_loss = true;
+            break;
+        };
+
+        let sk = pk.mapv(|p| step.alpha * p);
+        xk = &xk + &sk;
+        (old_old_fval, old_fval) = (old_fval, step.phi);
+        let gfkp1 = step.grad.unwrap_or_else(|| sf.grad(&xk));
+        let yk = &gfkp1 - &gfk;
+        gfk = gfkp1;
+        k += 1;
+        gnorm = norm(&gfk);
+        if gnorm <= gtol {
+            break;
+        }
+        if !old_fval.is_finite() {
+            precision_loss = true;
+            break;
+        }
+
+        let rhok_inv = dot(&yk, &sk);
+        let rhok = if rhok_inv.is_zero() {
+            F::from(1000).unwrap()
+        } else {
+            rhok_inv.recip()
+        };
+        // H = (I - rho s y^T) H (I - rho y s^T) + rho s s^T, expanded for the symmetric H.
+        let hy = matvec(&hk, &yk);
+        let scale = rhok * rhok * dot(&yk, &hy) + rhok;
+        hk = Array2::from_shape_fn((n, n), |(i, j)| {
+            hk[(i, j)] - rhok * (sk[i] * hy[j] + hy[i] * sk[j]) + scale * sk[i] * sk[j]
+        });
+    }
+
+    let (status, message) = if precision_loss {
+        (
+            2,
+            "Desired error not necessarily achieved due to precision loss.",
+        )
+    } else if k >= maxiter {
+        (1, "Maximum number of iterations has been exceeded.")
+    } else if gnorm.is_nan() || old_fval.is_nan() || xk.iter().any(|x| x.is_nan()) {
+        (3, "NaN result encountered.")
+    } else {
+        (0, "Optimization terminated successfully.")
+    };
+    Ok(OptimizeResult {
+        x: xk,
+        fun: old_fval,
+        nit: k,
+        nfev: sf.nfev,
+        jac: Some(gfk),
+        njev: sf.njev,
+        success: status == 0,
+        status,
+        message: String::from(message),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{minimize, Method};
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    fn rosen(x: ArrayView1<f64>) -> f64 {
+        x.windows(2)
+            .into_iter()
+            .map(|w| 100. * (w[1] - w[0] * w[0]).powi(2) + (1. - w[0]).powi(2))
+            .sum()
+    }
+
+    fn rosen_der(x: ArrayView1<f64>) -> Array1<f64> {
+        let n = x.len();
+        Array1::from_shape_fn(n, |i| {
+            let mut d = 0.;
+            if i > 0 {
+                d += 200. * (x[i] - x[i - 1] * x[i - 1]);
+            }
+            if i + 1 < n {
+                d += -400. * x[i] * (x[i + 1] - x[i] * x[i]) - 2. * (1. - x[i]);
+            }
+            d
+        })
+    }
+
+    #[test]
+    fn rosenbrock() {
+        let x0 = array![1.3, 0.7, 0.8, 1.9, 1.2];
+        let options = MinimizeOptions {
+            gtol: Some(1e-6),
+            ..Default::default()
+        };
+        let mut jac = rosen_der;
+        let res = minimize(rosen, &x0, Method::Bfgs, Some(&mut jac), options).unwrap();
+        assert!(res.success);
+        for x in &res.x {
+            assert_abs_diff_eq!(*x, 1., epsilon = 1e-6);
+        }
+        assert!(res.jac.unwrap().iter().all(|g| g.abs() <= 1e-6));
+
+        // Finite differences.
+        let res = minimize(
+            rosen,
+            &array![-1.2, 1.],
+            Method::Bfgs,
+            None,
+            Default::default(),
+        )
+        .unwrap();
+        assert_eq!(res.status, 0);
+        assert_abs_diff_eq!(res.x[0], 1., epsilon = 1e-5);
+        assert_abs_diff_eq!(res.x[1], 1., epsilon = 1e-5);
+    }
+
+    #[test]
+    fn quadratic() {
+        let f = |x: ArrayView1<f64>| (x[0] - 1.).powi(2) + 10. * (x[1] + 2.).powi(2) + x[0] * x[1];
+        let mut jac =
+            |x: ArrayView1<f64>| array![2. * (x[0] - 1.) + x[1], 20. * (x[1] + 2.) + x[0]];
+        let res = minimize(
+            f,
+            &array![0., 0.],
+            Method::Bfgs,
+            Some(&mut jac),
+            Default::default(),
+        )
+        .unwrap();
+        assert!(res.success);
+        assert_abs_diff_eq!(res.x[0], 80. / 39., epsilon = 1e-6);
+        assert_abs_diff_eq!(res.x[1], -82. / 39., epsilon = 1e-6);
+
+        let options = MinimizeOptions {
+            maxiter: Some(2),
+            ..Default::default()
+        };
+        let res = minimize(rosen, &array![-1.2, 1.], Method::Bfgs, None, options).unwrap();
+        assert_eq!((res.nit, res.status), (2, 1));
+        assert!(!res.success);
+
+        let options = MinimizeOptions {
+            bounds: Some(vec![(0., 1.); 2]),
+            ..Default::default()
+        };
+        assert!(minimize(rosen, &array![-1.2, 1.], Method::Bfgs, None, options).is_err());
+    }
+}
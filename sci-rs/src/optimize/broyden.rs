@@ -0,0 +1,264 @@
+use super::line_search::dot;
+use super::root::{MultiRootResult, RootOptions};
+use super::trust_region::{matvec, norm, rmatvec};
+use crate::linalg::BandedLu;
+use ndarray::{Array1, Array2, ArrayView1};
+use num_traits::Float;
+
+/// Maximum norm of `v`.
+fn max_norm<F: Float>(v: &Array1<F>) -> F {
+    v.fold(F::zero(), |acc, &v| acc.max(v.abs()))
+}
+
+/// Inverse of the Jacobian `j0`, if it is not singular.
+fn inverse<F: Float>(j0: &Array2<F>) -> Option<Array2<F>> {
+    let n = j0.nrows();
+    let lu = BandedLu::new(n, n - 1, n - 1, |i, j| j0[(i, j)]).ok()?;
+    let mut inv = Array2::zeros((n, n));
+    for (j, mut col) in inv.columns_mut().into_iter().enumerate() {
+        let mut e = Array1::zeros(n);
+        e[j] = F::one();
+        lu.solve(e.as_slice_mut().unwrap());
+        col.assign(&e);
+    }
+    inv.iter().all(|v| v.is_finite()).then_some(inv)
+}
+
+/// Minimize `phi(alpha)` along a descent direction by backtracking with quadratic and cubic
+/// interpolations until the Armijo condition `phi(alpha) <= phi0 + c1 alpha derphi0` holds,
+/// as scipy's `scalar_search_armijo`.
+///
+/// Returns `None` if the step would become smaller than `amin`.
+fn scalar_search_armijo<F: Float>(
+    mut phi: impl FnMut(F) -> F,
+    phi0: F,
+    derphi0: F,
+    amin: F,
+) -> Option<F> {
+    let c1 = F::from(1e-4).unwrap();
+    let two = F::from(2).unwrap();
+    let three = F::from(3).unwrap();
+    let armijo = |alpha: F, phi: F| phi <= phi0 + c1 * alpha * derphi0;
+    let mut alpha0 = F::one();
+    let mut phi_a0 = phi(alpha0);
+    if armijo(alpha0, phi_a0) {
+        return Some(alpha0);
+    }
+    // Minimizer of the quadratic interpolation.
+    let mut alpha1 = -derphi0 * alpha0 * alpha0 / two / (phi_a0 - phi0 - derphi0 * alpha0);
+    let mut phi_a1 = phi(alpha1);
+    if armijo(alpha1, phi_a1) {
+        return Some(alpha1);
+    }
+    // Minimizers of the cubic interpolations of the last two points.
+    while alpha1 > amin {
+        let factor = alpha0 * alpha0 * alpha1 * alpha1 * (alpha1 - alpha0);
+        let (r1, r0) = (
+            phi_a1 - phi0 - derphi0 * alpha1,
+            phi_a0 - phi0 - derphi0 * alpha0,
+        );
+        let a = (alpha0 * alpha0 * r1 - alpha1 * alpha1 * r0) / factor;
+        let b = (alpha1.powi(3) * r0 - alpha0.powi(3) * r1) / factor;
+        let mut alpha2 = (-b + (b * b - three * a * derphi0).abs().sqrt()) / (three * a);
+        let phi_a2 = phi(alpha2);
+        if armijo(alpha2, phi_a2) {
+            return Some(alpha2);
+        }
+        if alpha1 - alpha2 > alpha1 / two || F::one() - alpha2 / alpha1 < F::from(0.96).unwrap() {
+            alpha2 = alpha1 / two;
+        }
+        (alpha0, alpha1, phi_a0, phi_a1) = (alpha1, alpha2, phi_a1, phi_a2);
+    }
+    None
+}
+
+/// Solution by Broyden's methods, as [super::root] with [super::RootMethod::Broyden1] or
+/// [super::RootMethod::Broyden2] if `second` is `true`.
+///
+/// The approximation of the inverse Jacobian is kept as a dense matrix, and the steps are taken
+/// by the line search of scipy's `nonlin_solve`.
+pub(super) fn broyden<F: Float, G: FnMut(ArrayView1<F>) -> Array1<F>>(
+    mut fun: G,
+    x0: Array1<F>,
+    f0: Array1<F>,
+    j0: Option<Array2<F>>,
+    second: bool,
+    options: &RootOptions<F>,
+) -> MultiRootResult<F> {
+    let n = x0.len();
+    let fatol = options.fatol.unwrap_or(F::epsilon().cbrt());
+    let ftol = options.ftol.unwrap_or(F::infinity());
+    let xatol = options.xatol.unwrap_or(F::infinity());
+    let xtol = options.xtol.unwrap_or(F::infinity());
+    let maxiter = options.maxiter.unwrap_or(100 * (n + 1));
+    let f0_norm = max_norm(&f0);
+
+    // Initial approximation of the inverse Jacobian.
+    let njev = usize::from(j0.is_some());
+    let mut gm = j0.as_ref().and_then(inverse).unwrap_or_else(|| {
+        let alpha = F::from(0.5).unwrap() * norm(&x0).max(F::one()) / norm(&f0);
+        let mut gm = Array2::zeros((n, n));
+        gm.diag_mut().fill(-alpha);
+        gm
+    });
+
+    let mut x = x0;
+    let mut f = f0;
+    let mut dx_norm = F::infinity();
+    let mut nfev = 1;
+    let mut nit = 0;
+    let status = loop {
+        let f_norm = max_norm(&f);
+        if f_norm <= fatol
+            && f_norm / ftol <= f0_norm
+            && dx_norm <= xatol
+            && dx_norm / xtol <= max_norm(&x)
+        {
+            break 1;
+        }
+        if nit == maxiter {
+            break 2;
+        }
+        let dx = matvec(&gm, &f).mapv(|v| -v);
+        if norm(&dx).is_zero() || dx.iter().any(|v| !v.is_finite()) {
+            break 3;
+        }
+
+        // Line search on the squared norm of the residuals, whose slope is estimated as
+        // -||f||^2 for the Newton direction.
+        let phi0 = dot(&f, &f);
+        let mut last = None;
+        let s = scalar_search_armijo(
+            |s| {
+                let ft = fun((&x + &dx.mapv(|v| v * s)).view());
+                nfev += 1;
+                let p = dot(&ft, &ft);
+                let p = if p.is_finite() { p } else { F::infinity() };
+                last = Some((s, ft));
+                p
+            },
+            phi0,
+            -phi0,
+            F::from(1e-2).unwrap(),
+        )
+        .unwrap_or(F::one());
+        let step = dx.mapv(|v| v * s);
+        let f_new = match last {
+            Some((last_s, ft)) if last_s == s => ft,
+            _ => {
+                nfev += 1;
+                fun((&x + &step).view())
+            }
+        };
+        x = &x + &step;
+        let df = &f_new - &f;
+        f = f_new;
+        dx_norm = max_norm(&step);
+        nit += 1;
+
+        // Rank-1 update G += c d^T of the inverse Jacobian, satisfying the secant condition
+        // G df = dx.
+        let c = &step - &matvec(&gm, &df);
+        let d = if second {
+            let scale = dot(&df, &df);
+            df.mapv(|v| v / scale)
+        } else {
+            let v = rmatvec(&gm, &step);
+            let scale = dot(&df, &v);
+            v.mapv(|v| v / scale)
+        };
+        if d.iter().all(|v| v.is_finite()) {
+            for i in 0..n {
+                for j in 0..n {
+                    gm[(i, j)] = gm[(i, j)] + c[i] * d[j];
+                }
+            }
+        }
+    };
+
+    let message = match status {
+        1 => "A solution was found at the specified tolerance.",
+        2 => "The maximum number of iterations allowed has been reached.",
+        _ => "Jacobian inversion yielded zero vector.",
+    };
+    MultiRootResult {
+        x,
+        fun: f,
+        jac: None,
+        nit,
+        nfev,
+        njev,
+        success: status == 1,
+        status,
+        message: message.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    fn fun(x: ArrayView1<f64>) -> Array1<f64> {
+        array![
+            x[0] + 0.5 * (x[0] - x[1]).powi(3) - 1.,
+            0.5 * (x[1] - x[0]).powi(3) + x[1]
+        ]
+    }
+
+    #[test]
+    fn broyden() {
+        for second in [false, true] {
+            let x0 = array![0., 0.];
+            let f0 = fun(x0.view());
+            let res = super::broyden(fun, x0, f0, None, second, &Default::default());
+            assert!(res.success, "{}", res.message);
+            assert!(res.fun.iter().all(|f| f.abs() <= f64::EPSILON.cbrt()));
+            assert_abs_diff_eq!(
+                res.x.as_slice().unwrap(),
+                [0.8411639, 0.1588361].as_slice(),
+                epsilon = 1e-4
+            );
+        }
+
+        // Starting from the exact inverse Jacobian.
+        let x0 = array![0., 0.];
+        let f0 = fun(x0.view());
+        let options = RootOptions {
+            fatol: Some(1e-10),
+            ..Default::default()
+        };
+        let res = super::broyden(fun, x0, f0, Some(Array2::eye(2)), false, &options);
+        assert!(res.success, "{}", res.message);
+        assert_eq!(res.njev, 1);
+        assert_abs_diff_eq!(
+            res.x.as_slice().unwrap(),
+            [0.8411639, 0.1588361].as_slice(),
+            epsilon = 1e-7
+        );
+    }
+
+    #[test]
+    fn maxiter() {
+        let x0 = array![0., 0.];
+        let f0 = fun(x0.view());
+        let options = RootOptions {
+            maxiter: Some(2),
+            fatol: Some(1e-14),
+            ..Default::default()
+        };
+        let res = super::broyden(fun, x0, f0, None, false, &options);
+        assert!(!res.success);
+        assert_eq!((res.status, res.nit), (2, 2));
+    }
+
+    #[test]
+    fn armijo() {
+        // The full step of the quadratic overshoots, and the interpolation finds its minimum.
+        let phi = |a: f64| (a - 0.25).powi(2);
+        let alpha = scalar_search_armijo(phi, 0.0625, -0.5, 1e-2).unwrap();
+        assert_abs_diff_eq!(alpha, 0.25, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,288 @@
+use super::trust_region::Svd;
+use super::{least_squares, LeastSquaresMethod, LeastSquaresOptions};
+use alloc::format;
+use ndarray::{Array1, Array2, ArrayBase, ArrayView1, Data, Ix1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Use non-linear least squares to fit a function, `model`, to data.
+///
+/// Assumes `ydata = model(xdata, params) + eps`.
+///
+/// ## Parameters
+/// * `model`: The model function, of the independent variables and of the 1-D array of the `n`
+///   parameters to fit, which returns the `m` values of the dependent data.
+/// * `xdata`: The independent variables where the data is measured.
+/// * `ydata`: The dependent data, of shape `(m,)`.
+/// * `p0`: Initial guess for the parameters, of shape `(n,)`.
+/// * `sigma`: Uncertainty in `ydata`, of shape `(m,)`. The residuals are scaled as
+///   `r = (ydata - model(xdata, params)) / sigma`, so that the fit minimizes `sum(r^2)`. Unit
+///   uncertainties by default.
+/// * `absolute_sigma`: If `true`, `sigma` is used in an absolute sense and the estimated
+///   parameter covariance reflects these absolute values. If `false` (default), only the
+///   relative magnitudes of the `sigma` values matter, and the covariance is scaled by the
+///   reduced chi-square `sum(r^2) / (m - n)`.
+/// * `bounds`: Lower and upper bounds on the parameters, which may be infinite. Unbounded by
+///   default.
+///
+/// ## Returns
+/// * `popt`: Optimal values for the parameters so that the sum of the squared residuals is
+///   minimized.
+/// * `pcov`: The estimated approximate covariance of `popt`, of shape `(n, n)`. The diagonals
+///   provide the variance of the parameter estimates. If the Jacobian matrix at the solution
+///   doesn't have a full rank, the covariance is computed from its pseudo-inverse. It is filled
+///   with infinities if it cannot be estimated, which happens when `absolute_sigma` is `false`
+///   and there are no more data points than parameters.
+///
+/// ## Errors
+/// If the data or `p0` are not finite, or `sigma` or the values of `model` do not match `ydata`,
+/// or there are fewer data points than parameters without bounds. If [least_squares] does, or
+/// if the least-squares minimization fails.
+///
+/// ## Notes
+/// Without bounds, the fit uses the [Lm](LeastSquaresMethod::Lm) method of [least_squares], and
+/// the [Trf](LeastSquaresMethod::Trf) method otherwise. The Jacobian is estimated by finite
+/// differences.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.optimize import curve_fit
+/// def func(x, a, b, c):
+///     return a * np.exp(-b * x) + c
+/// xdata = np.linspace(0, 4, 50)
+/// ydata = func(xdata, 2.5, 1.3, 0.5)
+/// popt, pcov = curve_fit(func, xdata, ydata, p0=[1, 1, 1])
+/// popt
+/// # array([2.5, 1.3, 0.5])
+/// popt, pcov = curve_fit(func, xdata, ydata, p0=[1, 0.5, 0.25], bounds=(0, [3., 1., 0.5]))
+/// popt[1]
+/// # 1.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{Array1, ArrayView1, array};
+/// use sci_rs::optimize::curve_fit;
+///
+/// let func = |x: ArrayView1<f64>, p: ArrayView1<f64>| x.mapv(|x| p[0] * (-p[1] * x).exp() + p[2]);
+/// let xdata = Array1::linspace(0., 4., 50);
+/// let ydata = func(xdata.view(), array![2.5, 1.3, 0.5].view());
+/// let p0 = array![1., 1., 1.];
+/// let (popt, pcov) = curve_fit(func, &xdata, &ydata, &p0, None, None, None).unwrap();
+/// assert_abs_diff_eq!(popt[0], 2.5, epsilon = 1e-8);
+/// assert_abs_diff_eq!(popt[1], 1.3, epsilon = 1e-8);
+/// assert_abs_diff_eq!(popt[2], 0.5, epsilon = 1e-8);
+/// assert_eq!(pcov.dim(), (3, 3));
+///
+/// let bounds = [(0., 3.), (0., 1.), (0., 0.5)];
+/// let p0 = array![1., 0.5, 0.25];
+/// let (popt, _) = curve_fit(func, &xdata, &ydata, &p0, None, None, Some(&bounds)).unwrap();
+/// assert_abs_diff_eq!(popt[1], 1., epsilon = 1e-6);
+/// ```
+pub fn curve_fit<F, M, S1, S2, S3>(
+    mut model: M,
+    xdata: &ArrayBase<S1, Ix1>,
+    ydata: &ArrayBase<S2, Ix1>,
+    p0: &ArrayBase<S3, Ix1>,
+    sigma: Option<ArrayView1<F>>,
+    absolute_sigma: Option<bool>,
+    bounds: Option<&[(F, F)]>,
+) -> Result<(Array1<F>, Array2<F>)>
+where
+    F: Float,
+    M: FnMut(ArrayView1<F>, ArrayView1<F>) -> Array1<F>,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = F>,
+    S3: Data<Elem = F>,
+{
+    let (m, n) = (ydata.len(), p0.len());
+    if xdata.iter().chain(ydata).any(|v| !v.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "ydata".into(),
+            reason: "array must not contain infs or NaNs".into(),
+        });
+    }
+    if m == 0 {
+        return Err(Error::InvalidArg {
+            arg: "ydata".into(),
+            reason: "`ydata` must not be empty!".into(),
+        });
+    }
+    let transform = match sigma {
+        Some(sigma) if sigma.len() != m => {
+            return Err(Error::InvalidArg {
+                arg: "sigma".into(),
+                reason: "`sigma` has incorrect shape.".into(),
+            });
+        }
+        Some(sigma) => sigma.mapv(F::recip),
+        None => Array1::from_elem(m, F::one()),
+    };
+    let bounded = bounds.is_some_and(|b| b.iter().any(|(lb, ub)| lb.is_finite() || ub.is_finite()));
+    if !bounded && n > m {
+        return Err(Error::InvalidArg {
+            arg: "p0".into(),
+            reason: format!(
+                "The number of func parameters={n} must not exceed the number of data points={m}"
+            ),
+        });
+    }
+    if n > 0 && model(xdata.view(), p0.view()).len() != m {
+        return Err(Error::InvalidArg {
+            arg: "model".into(),
+            reason: "The values of `model` do not match the shape of `ydata`.".into(),
+        });
+    }
+
+    let residuals = |p: ArrayView1<F>| {
+        let f = model(xdata.view(), p);
+        Array1::from_shape_fn(f.len().min(m), |i| (f[i] - ydata[i]) * transform[i])
+    };
+    let method = if bounded {
+        LeastSquaresMethod::Trf
+    } else {
+        LeastSquaresMethod::Lm
+    };
+    let res = least_squares(
+        residuals,
+        p0,
+        None,
+        bounds.filter(|_| bounded),
+        Some(method),
+        None,
+        LeastSquaresOptions {
+            ftol: None,
+            xtol: None,
+            gtol: None,
+            max_nfev: None,
+            f_scale: None,
+            diff_step: None,
+        },
+    )?;
+    if !res.success {
+        return Err(Error::InvalidArg {
+            arg: "p0".into(),
+            reason: format!("Optimal parameters not found: {}", res.message),
+        });
+    }
+
+    // Moore-Penrose inverse of J^T J, discarding the zero singular values.
+    let Svd { s, v, .. } = Svd::new(&res.jac, &Array1::zeros(m));
+    let threshold = F::epsilon() * F::from(m.max(n)).unwrap() * s[0];
+    let mut pcov = Array2::from_shape_fn((n, n), |(i, j)| {
+        s.iter()
+            .enumerate()
+            .filter(|(_, &s)| s > threshold)
+            .fold(F::zero(), |acc, (k, &s)| {
+                acc + v[(i, k)] * v[(j, k)] / (s * s)
+            })
+    });
+    if pcov.iter().any(|c| c.is_nan()) {
+        pcov.fill(F::infinity());
+    } else if !absolute_sigma.unwrap_or(false) {
+        if m > n {
+            let s_sq = (res.cost + res.cost) / F::from(m - n).unwrap();
+            pcov.mapv_inplace(|c| c * s_sq);
+        } else {
+            pcov.fill(F::infinity());
+        }
+    }
+    Ok((res.x, pcov))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    fn line(x: ArrayView1<f64>, p: ArrayView1<f64>) -> Array1<f64> {
+        x.mapv(|x| p[0] * x + p[1])
+    }
+
+    #[test]
+    fn linear_covariance() {
+        // The covariance of a linear fit is s^2 (X^T X)^-1, with the design matrix X = [x, 1].
+        let x = array![0., 1., 2., 3., 4.];
+        let y = array![0.1, 0.9, 2.2, 2.8, 4.1];
+        let (popt, pcov) = curve_fit(line, &x, &y, &array![0., 0.], None, None, None).unwrap();
+        let (sx, sxx, sy, sxy) = (10., 30., 10.1, 30.1);
+        let det = 5. * sxx - sx * sx;
+        let (a, b) = ((5. * sxy - sx * sy) / det, (sxx * sy - sx * sxy) / det);
+        assert_abs_diff_eq!(popt[0], a, epsilon = 1e-8);
+        assert_abs_diff_eq!(popt[1], b, epsilon = 1e-8);
+        let ss: f64 = (0..5).map(|i| (y[i] - a * x[i] - b).powi(2)).sum();
+        let s2 = ss / 3.;
+        assert_abs_diff_eq!(pcov[(0, 0)], s2 * 5. / det, epsilon = 1e-8);
+        assert_abs_diff_eq!(pcov[(0, 1)], -s2 * sx / det, epsilon = 1e-8);
+        assert_abs_diff_eq!(pcov[(1, 0)], -s2 * sx / det, epsilon = 1e-8);
+        assert_abs_diff_eq!(pcov[(1, 1)], s2 * sxx / det, epsilon = 1e-8);
+
+        // With absolute uncertainties, the covariance is (X^T W X)^-1, with W = 1 / sigma^2.
+        let sigma = Array1::from_elem(5, 0.5);
+        let (_, pcov) = curve_fit(
+            line,
+            &x,
+            &y,
+            &array![0., 0.],
+            Some(sigma.view()),
+            Some(true),
+            None,
+        )
+        .unwrap();
+        assert_abs_diff_eq!(pcov[(0, 0)], 0.25 * 5. / det, epsilon = 1e-8);
+        assert_abs_diff_eq!(pcov[(1, 1)], 0.25 * sxx / det, epsilon = 1e-8);
+        // Only the relative uncertainties matter otherwise.
+        let (_, relative) = curve_fit(
+            line,
+            &x,
+            &y,
+            &array![0., 0.],
+            Some(sigma.view()),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_abs_diff_eq!(relative[(0, 0)], s2 * 5. / det, epsilon = 1e-8);
+
+        // There are as many parameters as data points.
+        let (_, pcov) = curve_fit(
+            line,
+            &array![0., 1.],
+            &array![1., 3.],
+            &array![0., 0.],
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert!(pcov.iter().all(|c| c.is_infinite()));
+    }
+
+    #[test]
+    fn bounded() {
+        let x = array![0., 1., 2., 3., 4.];
+        let y = array![0.1, 0.9, 2.2, 2.8, 4.1];
+        let bounds = [(0., 0.5), (-1., 1.)];
+        let (popt, pcov) =
+            curve_fit(line, &x, &y, &array![0.1, 0.], None, None, Some(&bounds)).unwrap();
+        assert_abs_diff_eq!(popt[0], 0.5, epsilon = 1e-6);
+        assert_abs_diff_eq!(popt[1], 1., epsilon = 1e-6);
+        assert!(pcov.iter().all(|c| c.is_finite()));
+    }
+
+    #[test]
+    fn invalid_args() {
+        let x = array![0., 1., 2.];
+        let y = array![0., 1., 2.];
+        let p0 = array![0., 0.];
+        assert!(curve_fit(line, &x, &array![0., f64::NAN, 2.], &p0, None, None, None).is_err());
+        assert!(curve_fit(line, &x, &y, &p0, Some(array![1., 1.].view()), None, None).is_err());
+        assert!(curve_fit(line, &x, &y, &array![0., 0., 0., 0.], None, None, None).is_err());
+        let short = |_: ArrayView1<f64>, p: ArrayView1<f64>| array![p[0]];
+        assert!(curve_fit(short, &x, &y, &p0, None, None, None).is_err());
+    }
+}
@@ -0,0 +1,638 @@
+use super::{minimize, Method, MinimizeOptions, OptimizeResult};
+use crate::random::{default_rng, Rng};
+use alloc::vec::Vec;
+use ndarray::{Array1, Array2, ArrayView1, Axis};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Mutation strategy of [differential_evolution].
+///
+/// The mutant is built from the best member of the population, a random member or the
+/// candidate itself, and the scaled differences of one or two pairs of other random members.
+/// It is then crossed over with the candidate, either binomially, taking each parameter from the
+/// mutant with the probability `recombination`, or exponentially, taking a run of consecutive
+/// parameters whose length is determined by `recombination`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MutationStrategy {
+    /// `b' = b_0 + F (x_r0 - x_r1)`, with binomial crossover.
+    #[default]
+    Best1Bin,
+    /// `b' = b_0 + F (x_r0 - x_r1)`, with exponential crossover.
+    Best1Exp,
+    /// `b' = x_r0 + F (x_r1 - x_r2)`, with binomial crossover.
+    Rand1Bin,
+    /// `b' = x_r0 + F (x_r1 - x_r2)`, with exponential crossover.
+    Rand1Exp,
+    /// `b' = x_r0 + F (b_0 - x_r0) + F (x_r1 - x_r2)`, with binomial crossover.
+    RandToBest1Bin,
+    /// `b' = x_r0 + F (b_0 - x_r0) + F (x_r1 - x_r2)`, with exponential crossover.
+    RandToBest1Exp,
+    /// `b' = x_i + F (b_0 - x_i + x_r0 - x_r1)`, with binomial crossover.
+    CurrentToBest1Bin,
+    /// `b' = x_i + F (b_0 - x_i + x_r0 - x_r1)`, with exponential crossover.
+    CurrentToBest1Exp,
+    /// `b' = b_0 + F (x_r0 + x_r1 - x_r2 - x_r3)`, with binomial crossover.
+    Best2Bin,
+    /// `b' = b_0 + F (x_r0 + x_r1 - x_r2 - x_r3)`, with exponential crossover.
+    Best2Exp,
+    /// `b' = x_r0 + F (x_r1 + x_r2 - x_r3 - x_r4)`, with binomial crossover.
+    Rand2Bin,
+    /// `b' = x_r0 + F (x_r1 + x_r2 - x_r3 - x_r4)`, with exponential crossover.
+    Rand2Exp,
+}
+
+impl MutationStrategy {
+    fn is_exponential(self) -> bool {
+        use MutationStrategy::*;
+        matches!(
+            self,
+            Best1Exp | Rand1Exp | RandToBest1Exp | CurrentToBest1Exp | Best2Exp | Rand2Exp
+        )
+    }
+
+    fn is_rand2(self) -> bool {
+        matches!(
+            self,
+            MutationStrategy::Rand2Bin | MutationStrategy::Rand2Exp
+        )
+    }
+}
+
+/// Initialization of the population of [differential_evolution].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PopulationInit {
+    /// Latin hypercube sampling, which splits the range of every parameter into as many
+    /// segments as members and puts one member in each, covering the parameter space evenly.
+    #[default]
+    LatinHypercube,
+    /// Uniform random sampling, which may leave parts of the parameter space uncovered.
+    Random,
+}
+
+/// When the best solution of [differential_evolution] is updated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Updating {
+    /// The best solution is updated after each candidate, so that the following mutants benefit
+    /// from it within the same generation, which may converge faster.
+    #[default]
+    Immediate,
+    /// The best solution is updated once per generation, which allows the evaluation of the
+    /// trial population to be parallelized.
+    Deferred,
+}
+
+/// Solver options of [differential_evolution].
+///
+/// Every field left as `None` takes the default of the solver, which is also what [Default]
+/// gives.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DifferentialEvolutionOptions<F> {
+    /// The [MutationStrategy], [Best1Bin](MutationStrategy::Best1Bin) by default.
+    pub strategy: Option<MutationStrategy>,
+    /// Maximum number of generations, 1000 by default. The maximum number of function
+    /// evaluations, without polishing, is `(maxiter + 1) * popsize * n`.
+    pub maxiter: Option<usize>,
+    /// Multiplier of the number of parameters `n` giving the number of members of the
+    /// population, which is at least 5. It is 15 by default.
+    pub popsize: Option<usize>,
+    /// Relative tolerance for convergence, which is reached when the standard deviation of the
+    /// energies of the population is at most `atol + tol * |mean(energies)|`. It is `0.01` by
+    /// default.
+    pub tol: Option<F>,
+    /// Absolute tolerance for convergence, 0 by default.
+    pub atol: Option<F>,
+    /// Range `[min, max)` of the differential weight `F` of the mutation, within `[0, 2]`.
+    /// It is drawn anew every generation, which is known as dithering and can speed up the
+    /// convergence. It is `(0.5, 1)` by default, and a constant weight is given by equal bounds.
+    pub mutation: Option<(F, F)>,
+    /// The crossover probability, within `[0, 1]`, `0.7` by default. Increasing it allows a
+    /// larger number of mutants to progress into the next generation, but at the risk of
+    /// population stability.
+    pub recombination: Option<F>,
+    /// Polish the best member of the population with the [LBfgsB](Method::LBfgsB) method of
+    /// [minimize] at the end, which usually improves the minimization slightly. `true` by
+    /// default.
+    pub polish: Option<bool>,
+    /// The [PopulationInit], [LatinHypercube](PopulationInit::LatinHypercube) by default.
+    pub init: Option<PopulationInit>,
+    /// Initial guess, of shape `(n,)`, which replaces the first member of the population.
+    pub x0: Option<Array1<F>>,
+    /// The [Updating] of the best solution, [Immediate](Updating::Immediate) by default. It is
+    /// [Deferred](Updating::Deferred) if `workers` is greater than 1.
+    pub updating: Option<Updating>,
+    /// Maximum number of threads to use for the evaluations of the population, which needs the
+    /// `parallel` feature. Defaults to 1, computing on the calling thread.
+    pub workers: Option<usize>,
+}
+
+/// Values of `func` at the members of `population`, mapped from the unit hypercube to the
+/// bounds, computed on a pool of `workers` threads with the `parallel` feature. The NaNs are
+/// replaced by infinities, so that they are never selected.
+fn energies<F, G>(
+    func: &G,
+    population: &Array2<F>,
+    bounds: &[(F, F)],
+    workers: usize,
+) -> Result<Array1<F>>
+where
+    F: Float + Send + Sync,
+    G: Fn(ArrayView1<F>) -> F + Sync,
+{
+    let energy = |member: ArrayView1<F>| {
+        let e = func(scale_parameters(member, bounds).view());
+        if e.is_nan() {
+            F::infinity()
+        } else {
+            e
+        }
+    };
+    #[cfg(feature = "parallel")]
+    if workers > 1 {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(workers)
+            .build()
+            .map_err(|e| Error::InvalidArg {
+                arg: "workers".into(),
+                reason: e.to_string(),
+            })?;
+        let energies: Vec<F> = pool.install(|| {
+            population
+                .axis_iter(Axis(0))
+                .into_par_iter()
+                .map(energy)
+                .collect()
+        });
+        return Ok(Array1::from(energies));
+    }
+    Ok(population.axis_iter(Axis(0)).map(energy).collect())
+}
+
+/// Map `member` from the unit hypercube to the bounds.
+fn scale_parameters<F: Float>(member: ArrayView1<F>, bounds: &[(F, F)]) -> Array1<F> {
+    member
+        .iter()
+        .zip(bounds)
+        .map(|(&x, &(lb, ub))| lb + x * (ub - lb))
+        .collect()
+}
+
+/// Swap the member of lowest energy with the first one.
+fn promote_lowest_energy<F: Float>(population: &mut Array2<F>, energies: &mut Array1<F>) {
+    let best = energies
+        .iter()
+        .enumerate()
+        .fold(0, |best, (i, &e)| if e < energies[best] { i } else { best });
+    if best != 0 {
+        energies.swap(0, best);
+        for mut col in population.columns_mut() {
+            col.swap(0, best);
+        }
+    }
+}
+
+/// Trial vector of `candidate`, in the unit hypercube, made by the crossover of the candidate
+/// with its mutant.
+fn mutate<F: Float>(
+    population: &Array2<F>,
+    candidate: usize,
+    strategy: MutationStrategy,
+    scale: F,
+    recombination: F,
+    rng: &mut Rng,
+) -> Array1<F> {
+    use MutationStrategy::*;
+    let (np, n) = population.dim();
+    let mut fill_point = rng.integers(0, n);
+    let mut others: Vec<usize> = (0..np).filter(|&i| i != candidate).collect();
+    rng.shuffle(&mut others);
+    let p = |i: usize| population.row(i);
+    let r = |k: usize| p(others[k]);
+    let bprime: Array1<F> = match strategy {
+        Best1Bin | Best1Exp => Array1::from_shape_fn(n, |j| p(0)[j] + scale * (r(0)[j] - r(1)[j])),
+        Rand1Bin | Rand1Exp => Array1::from_shape_fn(n, |j| r(0)[j] + scale * (r(1)[j] - r(2)[j])),
+        RandToBest1Bin | RandToBest1Exp => Array1::from_shape_fn(n, |j| {
+            r(0)[j] + scale * (p(0)[j] - r(0)[j]) + scale * (r(1)[j] - r(2)[j])
+        }),
+        CurrentToBest1Bin | CurrentToBest1Exp => Array1::from_shape_fn(n, |j| {
+            let x = p(candidate)[j];
+            x + scale * (p(0)[j] - x + r(0)[j] - r(1)[j])
+        }),
+        Best2Bin | Best2Exp => Array1::from_shape_fn(n, |j| {
+            p(0)[j] + scale * (r(0)[j] + r(1)[j] - r(2)[j] - r(3)[j])
+        }),
+        Rand2Bin | Rand2Exp => Array1::from_shape_fn(n, |j| {
+            r(0)[j] + scale * (r(1)[j] + r(2)[j] - r(3)[j] - r(4)[j])
+        }),
+    };
+
+    let mut trial = p(candidate).to_owned();
+    let crossovers: Vec<bool> = (0..n).map(|_| rng.random::<F>() < recombination).collect();
+    if strategy.is_exponential() {
+        // A run of parameters starting at the fill point, at least one long.
+        let mut i = 0;
+        while i < n && (i == 0 || crossovers[i]) {
+            trial[fill_point] = bprime[fill_point];
+            fill_point = (fill_point + 1) % n;
+            i += 1;
+        }
+    } else {
+        for (j, &crossover) in crossovers.iter().enumerate() {
+            if crossover || j == fill_point {
+                trial[j] = bprime[j];
+            }
+        }
+    }
+    // Parameters mutated out of the hypercube are drawn again.
+    trial.mapv_inplace(|x| {
+        if x < F::zero() || x > F::one() {
+            rng.random()
+        } else {
+            x
+        }
+    });
+    trial
+}
+
+/// Finds the global minimum of a multivariate function.
+///
+/// The differential evolution method [1] is stochastic in nature. It does not use gradient
+/// methods to find the minimum, and can search large areas of candidate space, but often requires
+/// larger numbers of function evaluations than conventional gradient-based techniques.
+///
+/// ## Parameters
+/// * `func`: The objective function to be minimized, of the 1-D array of the `n` parameters.
+/// * `bounds`: Finite lower and upper bounds of every parameter.
+/// * `rng`: The random number generator, which makes the minimization repeatable. A generator
+///   seeded from the operating system is used if not given.
+/// * `options`: [DifferentialEvolutionOptions] of the solver.
+///
+/// ## Returns
+/// The optimization result, with `status` 0 if the population converged, and 1 if the maximum
+/// number of generations was reached. `nit` is the number of generations, and `jac` the gradient
+/// at the solution if it was polished.
+///
+/// ## Errors
+/// If `bounds` is empty or not finite, a lower bound is greater than an upper bound, or `x0`
+/// does not match them. If the mutation, recombination, population size or number of workers
+/// are invalid.
+///
+/// ## Notes
+/// Each member of the population is mutated in turn, by mixing it with the differences of
+/// other members according to `strategy`. If the resulting trial vector has a lower or equal
+/// energy, it replaces the member in the next generation. The members are kept in the unit
+/// hypercube, mapped to the bounds for the evaluations of `func`.
+///
+/// With [Updating::Deferred], the whole trial population is evaluated at once, which is done
+/// on a pool of `workers` threads with the `parallel` feature, so `func` must be [Sync]. The
+/// random numbers only depend on the function values, so that the result for a given seed
+/// does not depend on the number of workers.
+///
+/// ## References
+/// 1. Storn, R and Price, K, Differential Evolution - a Simple and Efficient Heuristic for Global
+///    Optimization over Continuous Spaces, Journal of Global Optimization, 1997, 11, 341 - 359.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.optimize import rosen, differential_evolution
+/// bounds = [(0, 2), (0, 2), (0, 2), (0, 2), (0, 2)]
+/// result = differential_evolution(rosen, bounds, rng=1)
+/// result.x, result.fun
+/// # (array([1., 1., 1., 1., 1.]), 1.9216496320061384e-19)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::ArrayView1;
+/// use sci_rs::optimize::differential_evolution;
+/// use sci_rs::random::Rng;
+///
+/// let rosen = |x: ArrayView1<f64>| {
+///     x.windows(2)
+///         .into_iter()
+///         .map(|w| 100. * (w[1] - w[0] * w[0]).powi(2) + (1. - w[0]).powi(2))
+///         .sum()
+/// };
+/// let bounds = [(0., 2.); 5];
+/// let mut rng = Rng::new(1);
+/// let res = differential_evolution(rosen, &bounds, Some(&mut rng), Default::default()).unwrap();
+/// assert!(res.success);
+/// for x in res.x {
+///     assert_abs_diff_eq!(x, 1., epsilon = 1e-6);
+/// }
+/// assert!(res.fun < 1e-12);
+/// ```
+pub fn differential_evolution<F, G>(
+    func: G,
+    bounds: &[(F, F)],
+    rng: Option<&mut Rng>,
+    options: DifferentialEvolutionOptions<F>,
+) -> Result<OptimizeResult<F, Array1<F>>>
+where
+    F: Float + Send + Sync,
+    G: Fn(ArrayView1<F>) -> F + Sync,
+{
+    let n = bounds.len();
+    if n == 0
+        || bounds
+            .iter()
+            .any(|(lb, ub)| !lb.is_finite() || !ub.is_finite() || lb > ub)
+    {
+        return Err(Error::InvalidArg { arg: "bounds".into(), reason: "bounds should be a sequence containing finite real valued (min, max) pairs for each \
+             value in x".into() });
+    }
+    let strategy = options.strategy.unwrap_or_default();
+    let maxiter = options.maxiter.unwrap_or(1000);
+    let np = (options.popsize.unwrap_or(15) * n).max(5);
+    let tol = options.tol.unwrap_or(F::from(0.01).unwrap());
+    let atol = options.atol.unwrap_or(F::zero());
+    let (mut_low, mut_high) = options
+        .mutation
+        .unwrap_or((F::from(0.5).unwrap(), F::one()));
+    let recombination = options.recombination.unwrap_or(F::from(0.7).unwrap());
+    let workers = options.workers.unwrap_or(1);
+    let two = F::from(2).unwrap();
+    if mut_low.is_nan() || mut_low < F::zero() || mut_high.is_nan() || mut_high > two {
+        return Err(Error::InvalidArg { arg: "mutation".into(), reason: "The mutation constant must be a float in U[0, 2), or specified as a tuple(min, max) \
+             where min < max and min, max are in U[0, 2).".into() });
+    }
+    if recombination.is_nan() || recombination < F::zero() || recombination > F::one() {
+        return Err(Error::InvalidArg {
+            arg: "recombination".into(),
+            reason: "recombination must be within [0, 1].".into(),
+        });
+    }
+    if strategy.is_rand2() && np < 6 {
+        return Err(Error::InvalidArg {
+            arg: "popsize".into(),
+            reason: "The rand2 strategies need a population of at least 6 members.".into(),
+        });
+    }
+    if workers == 0 {
+        return Err(Error::InvalidArg {
+            arg: "workers".into(),
+            reason: "workers must not be zero".into(),
+        });
+    }
+    let updating = if workers > 1 {
+        Updating::Deferred
+    } else {
+        options.updating.unwrap_or_default()
+    };
+    let mut default = None;
+    let rng = rng.unwrap_or_else(|| default.insert(default_rng()));
+
+    // Initial population in the unit hypercube.
+    let mut population = match options.init.unwrap_or_default() {
+        PopulationInit::LatinHypercube => {
+            let segment = F::one() / F::from(np).unwrap();
+            let mut population = Array2::zeros((np, n));
+            for mut col in population.columns_mut() {
+                let mut order: Vec<usize> = (0..np).collect();
+                rng.shuffle(&mut order);
+                for (x, i) in col.iter_mut().zip(order) {
+                    *x = segment * (F::from(i).unwrap() + rng.random());
+                }
+            }
+            population
+        }
+        PopulationInit::Random => Array2::from_shape_simple_fn((np, n), || rng.random()),
+    };
+    if let Some(x0) = &options.x0 {
+        if x0.len() != n
+            || x0
+                .iter()
+                .zip(bounds)
+                .any(|(&x, &(lb, ub))| x.is_nan() || x < lb || x > ub)
+        {
+            return Err(Error::InvalidArg {
+                arg: "x0".into(),
+                reason: "Some entries in x0 lay outside the specified bounds".into(),
+            });
+        }
+        for (j, (&x, &(lb, ub))) in x0.iter().zip(bounds).enumerate() {
+            population[(0, j)] = if ub > lb {
+                (x - lb) / (ub - lb)
+            } else {
+                F::zero()
+            };
+        }
+    }
+    let mut population_energies = energies(&func, &population, bounds, workers)?;
+    let mut nfev = np;
+    promote_lowest_energy(&mut population, &mut population_energies);
+
+    let converged = |energies: &Array1<F>| {
+        if energies.iter().any(|e| e.is_infinite()) {
+            return false;
+        }
+        let count = F::from(np).unwrap();
+        let mean = energies.sum() / count;
+        let std =
+            (energies.fold(F::zero(), |acc, &e| acc + (e - mean) * (e - mean)) / count).sqrt();
+        std <= atol + tol * mean.abs()
+    };
+    let mut nit = 0;
+    let mut success = false;
+    while nit < maxiter {
+        nit += 1;
+        let scale = rng.uniform(mut_low, mut_high);
+        match updating {
+            Updating::Immediate => {
+                for candidate in 0..np {
+                    let trial = mutate(&population, candidate, strategy, scale, recombination, rng);
+                    let energy = func(scale_parameters(trial.view(), bounds).view());
+                    let energy = if energy.is_nan() {
+                        F::infinity()
+                    } else {
+                        energy
+                    };
+                    nfev += 1;
+                    if energy <= population_energies[candidate] {
+                        population.row_mut(candidate).assign(&trial);
+                        population_energies[candidate] = energy;
+                        if energy < population_energies[0] {
+                            promote_lowest_energy(&mut population, &mut population_energies);
+                        }
+                    }
+                }
+            }
+            Updating::Deferred => {
+                let mut trials = Array2::zeros((np, n));
+                for candidate in 0..np {
+                    trials.row_mut(candidate).assign(&mutate(
+                        &population,
+                        candidate,
+                        strategy,
+                        scale,
+                        recombination,
+                        rng,
+                    ));
+                }
+                let trial_energies = energies(&func, &trials, bounds, workers)?;
+                nfev += np;
+                for candidate in 0..np {
+                    if trial_energies[candidate] <= population_energies[candidate] {
+                        population.row_mut(candidate).assign(&trials.row(candidate));
+                        population_energies[candidate] = trial_energies[candidate];
+                    }
+                }
+                promote_lowest_energy(&mut population, &mut population_energies);
+            }
+        }
+        if converged(&population_energies) {
+            success = true;
+            break;
+        }
+    }
+
+    let mut res = OptimizeResult {
+        x: scale_parameters(population.row(0), bounds),
+        fun: population_energies[0],
+        nit,
+        nfev,
+        jac: None,
+        njev: 0,
+        success,
+        status: if success { 0 } else { 1 },
+        message: if success {
+            "Optimization terminated successfully."
+        } else {
+            "Maximum number of iterations has been exceeded."
+        }
+        .into(),
+    };
+    if options.polish.unwrap_or(true) {
+        if let Ok(polished) = minimize(
+            &func,
+            &res.x,
+            Method::LBfgsB,
+            None,
+            MinimizeOptions::lbfgsb(bounds, None),
+        ) {
+            res.nfev += polished.nfev;
+            if polished.fun < res.fun {
+                res.fun = polished.fun;
+                res.x = polished.x;
+                res.jac = polished.jac;
+            }
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use core::f64::consts::PI;
+
+    fn rastrigin(x: ArrayView1<f64>) -> f64 {
+        10. * x.len() as f64
+            + x.iter()
+                .map(|x| x * x - 10. * (2. * PI * x).cos())
+                .sum::<f64>()
+    }
+
+    #[test]
+    fn strategies() {
+        use MutationStrategy::*;
+        let bounds = [(-5.12, 5.12); 2];
+        for strategy in [
+            Best1Bin,
+            Best1Exp,
+            Rand1Bin,
+            Rand1Exp,
+            RandToBest1Bin,
+            RandToBest1Exp,
+            CurrentToBest1Bin,
+            CurrentToBest1Exp,
+            Best2Bin,
+            Best2Exp,
+            Rand2Bin,
+            Rand2Exp,
+        ] {
+            let options = DifferentialEvolutionOptions {
+                strategy: Some(strategy),
+                tol: Some(1e-8),
+                ..Default::default()
+            };
+            let res = differential_evolution(rastrigin, &bounds, Some(&mut Rng::new(3)), options)
+                .unwrap();
+            assert!(res.success, "{strategy:?}");
+            assert_abs_diff_eq!(res.fun, 0., epsilon = 1e-10);
+            assert_abs_diff_eq!(
+                res.x.as_slice().unwrap(),
+                [0., 0.].as_slice(),
+                epsilon = 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn reproducible() {
+        let bounds = [(-5.12, 5.12); 3];
+        let run = |updating, workers| {
+            let options = DifferentialEvolutionOptions {
+                updating: Some(updating),
+                workers: Some(workers),
+                init: Some(PopulationInit::Random),
+                polish: Some(false),
+                x0: Some(ndarray::array![1., 1., 1.]),
+                ..Default::default()
+            };
+            differential_evolution(rastrigin, &bounds, Some(&mut Rng::new(11)), options).unwrap()
+        };
+        let immediate = run(Updating::Immediate, 1);
+        assert_eq!(immediate, run(Updating::Immediate, 1));
+        assert!(immediate.jac.is_none());
+        // The deferred updating gives the same result on any number of threads.
+        let deferred = run(Updating::Deferred, 1);
+        assert_eq!(deferred, run(Updating::Deferred, 4));
+        assert_eq!(deferred.nfev, 45 * (deferred.nit + 1));
+    }
+
+    #[test]
+    fn maxiter() {
+        let options = DifferentialEvolutionOptions {
+            maxiter: Some(2),
+            polish: Some(false),
+            ..Default::default()
+        };
+        let bounds = [(-5.12, 5.12); 4];
+        let res =
+            differential_evolution(rastrigin, &bounds, Some(&mut Rng::new(0)), options).unwrap();
+        assert!(!res.success);
+        assert_eq!((res.status, res.nit, res.nfev), (1, 2, 180));
+    }
+
+    #[test]
+    fn invalid_args() {
+        let de = |bounds: &[(f64, f64)], options| {
+            differential_evolution(rastrigin, bounds, None, options).is_err()
+        };
+        assert!(de(&[], Default::default()));
+        assert!(de(&[(0., f64::INFINITY)], Default::default()));
+        assert!(de(&[(1., 0.)], Default::default()));
+        let bounds = [(0., 1.)];
+        let options = |mutation, recombination, x0| DifferentialEvolutionOptions {
+            mutation,
+            recombination,
+            x0,
+            ..Default::default()
+        };
+        assert!(de(&bounds, options(Some((0.5, 2.5)), None, None)));
+        assert!(de(&bounds, options(None, Some(1.5), None)));
+        assert!(de(&bounds, options(None, None, Some(ndarray::array![2.]))));
+        let options = DifferentialEvolutionOptions {
+            strategy: Some(MutationStrategy::Rand2Bin),
+            popsize: Some(5),
+            ..Default::default()
+        };
+        assert!(de(&bounds, options));
+    }
+}
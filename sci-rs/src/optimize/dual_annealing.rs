@@ -0,0 +1,590 @@
+use super::{minimize, Method, MinimizeOptions, OptimizeResult};
+use crate::random::{default_rng, Rng};
+use crate::special::xsf::gamma;
+use ndarray::{Array1, ArrayView1};
+use num_traits::{Float, FloatConst};
+use sci_rs_core::{Error, Result};
+
+/// Solver options of [dual_annealing].
+///
+/// Every field left as `None` takes the default of the solver, which is also what [Default]
+/// gives.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DualAnnealingOptions<F> {
+    /// The maximum number of global search iterations, 1000 by default.
+    pub maxiter: Option<usize>,
+    /// The initial temperature, within `(0.01, 5e4]`. Higher values facilitate a wider search
+    /// of the energy landscape, allowing to escape local minima that it is trapped in. It is
+    /// 5230 by default.
+    pub initial_temp: Option<F>,
+    /// During the annealing process, the temperature is decreasing, and the annealing restarts
+    /// from a new random point when it reaches `initial_temp * restart_temp_ratio`. Within
+    /// `(0, 1)`, `2e-5` by default.
+    pub restart_temp_ratio: Option<F>,
+    /// Parameter of the visiting distribution, within `(1, 3)`. Higher values give the
+    /// distribution a heavier tail, which makes the algorithm jump to a more distant region.
+    /// It is `2.62` by default.
+    pub visit: Option<F>,
+    /// Parameter of the acceptance distribution, within `(-1e4, -5]`. The lower it is, the
+    /// smaller the probability of acceptance. It is `-5` by default.
+    pub accept: Option<F>,
+    /// Soft limit for the number of evaluations of the objective function, which may be exceeded
+    /// by a local search. It is `1e7` by default.
+    pub maxfun: Option<usize>,
+    /// Skip the local searches, which gives a generalized simulated annealing. `false` by
+    /// default.
+    pub no_local_search: Option<bool>,
+    /// Initial guess, of shape `(n,)`. A random point within the bounds is used if not given.
+    pub x0: Option<Array1<F>>,
+}
+
+/// The distorted Cauchy-Lorentz visiting distribution of Tsallis and Stariolo, from which the
+/// trial points are drawn, wrapped around the bounds.
+struct VisitingDistribution<'a, F> {
+    bounds: &'a [(F, F)],
+    visit: F,
+    factor4_p: F,
+    factor6: F,
+}
+
+impl<'a, F: Float + FloatConst> VisitingDistribution<'a, F> {
+    const TAIL_LIMIT: f64 = 1e8;
+    const MIN_VISIT_BOUND: f64 = 1e-10;
+
+    fn new(bounds: &'a [(F, F)], visit: F) -> Self {
+        let qv = visit.to_f64().unwrap();
+        let factor2 = ((4. - qv) * (qv - 1.).ln()).exp();
+        let factor3 = ((2. - qv) * 2f64.ln() / (qv - 1.)).exp();
+        let factor4_p = core::f64::consts::PI.sqrt() * factor2 / (factor3 * (3. - qv));
+        let factor5 = 1. / (qv - 1.) - 0.5;
+        let d1 = 2. - factor5;
+        let angle = core::f64::consts::PI * (1. - factor5);
+        let factor6 = angle / angle.sin() / gamma(d1).abs();
+        VisitingDistribution {
+            bounds,
+            visit,
+            factor4_p: F::from(factor4_p).unwrap(),
+            factor6: F::from(factor6).unwrap(),
+        }
+    }
+
+    /// A visiting step at `temperature`, clipped to the tail limit.
+    fn visit_fn(&self, temperature: F, rng: &mut Rng) -> F {
+        let qv = self.visit;
+        let one = F::one();
+        let three = F::from(3).unwrap();
+        let x: F = rng.standard_normal();
+        let y: F = rng.standard_normal();
+        let factor1 = (temperature.ln() / (qv - one)).exp();
+        let factor4 = self.factor4_p * factor1;
+        let x = x * (-(qv - one) * (self.factor6 / factor4).ln() / (three - qv)).exp();
+        let den = ((qv - one) * y.abs().ln() / (three - qv)).exp();
+        let visit = x / den;
+        let tail = F::from(Self::TAIL_LIMIT).unwrap();
+        if visit > tail {
+            tail * rng.random()
+        } else if visit < -tail {
+            -tail * rng.random()
+        } else {
+            visit
+        }
+    }
+
+    /// Move the `index` coordinate of `x` by a visiting step, wrapped around its bounds.
+    fn move_coordinate(&self, x: &mut Array1<F>, index: usize, step: F) {
+        let (lb, ub) = self.bounds[index];
+        let range = ub - lb;
+        let a = x[index] + step - lb;
+        let mut v = ((a % range) + range) % range + lb;
+        let min_visit = F::from(Self::MIN_VISIT_BOUND).unwrap();
+        if (v - lb).abs() < min_visit {
+            v = v + min_visit;
+        }
+        x[index] = v;
+    }
+
+    /// A trial point from `x`. The first `n` steps of a chain move all the coordinates, and the
+    /// next `n` steps move one coordinate at a time.
+    fn visiting(&self, x: &Array1<F>, step: usize, temperature: F, rng: &mut Rng) -> Array1<F> {
+        let n = x.len();
+        let mut x_visit = x.clone();
+        if step < n {
+            for i in 0..n {
+                let visit = self.visit_fn(temperature, rng);
+                self.move_coordinate(&mut x_visit, i, visit);
+            }
+        } else {
+            let visit = self.visit_fn(temperature, rng);
+            self.move_coordinate(&mut x_visit, step - n, visit);
+        }
+        x_visit
+    }
+}
+
+/// The objective function, counting its evaluations.
+struct Objective<G> {
+    func: G,
+    nfev: usize,
+    njev: usize,
+}
+
+impl<G> Objective<G> {
+    fn fun<F: Float>(&mut self, x: &Array1<F>) -> F
+    where
+        G: FnMut(ArrayView1<F>) -> F,
+    {
+        self.nfev += 1;
+        (self.func)(x.view())
+    }
+
+    /// Local minimization from `x` by [Method::LBfgsB], whose result is kept if it is within the
+    /// bounds and lower than `e`.
+    fn local_search<F: Float>(&mut self, x: &Array1<F>, e: F, bounds: &[(F, F)]) -> (F, Array1<F>)
+    where
+        G: FnMut(ArrayView1<F>) -> F,
+    {
+        let n = x.len();
+        let maxiter = (6 * n).clamp(100, 1000);
+        let func = &mut self.func;
+        let res = minimize(
+            |x| func(x),
+            x,
+            Method::LBfgsB,
+            None,
+            MinimizeOptions::lbfgsb(bounds, Some(maxiter)),
+        );
+        match res {
+            Ok(res) => {
+                self.nfev += res.nfev;
+                self.njev += res.njev;
+                let valid = res.fun.is_finite()
+                    && res
+                        .x
+                        .iter()
+                        .zip(bounds)
+                        .all(|(&x, &(lb, ub))| x.is_finite() && x >= lb && x <= ub);
+                if valid && res.fun < e {
+                    (res.fun, res.x)
+                } else {
+                    (e, x.clone())
+                }
+            }
+            Err(_) => (e, x.clone()),
+        }
+    }
+}
+
+/// Current and best states of the annealing.
+struct EnergyState<F> {
+    current_energy: F,
+    current_location: Array1<F>,
+    ebest: F,
+    xbest: Array1<F>,
+}
+
+impl<F: Float> EnergyState<F> {
+    /// Start from `x0`, or a random point at which the function is finite.
+    fn new<G: FnMut(ArrayView1<F>) -> F>(
+        objective: &mut Objective<G>,
+        bounds: &[(F, F)],
+        rng: &mut Rng,
+        x0: Option<&Array1<F>>,
+    ) -> Result<Self> {
+        let (current_energy, current_location) = Self::reset(objective, bounds, rng, x0)?;
+        Ok(EnergyState {
+            current_energy,
+            current_location: current_location.clone(),
+            ebest: current_energy,
+            xbest: current_location,
+        })
+    }
+
+    fn reset<G: FnMut(ArrayView1<F>) -> F>(
+        objective: &mut Objective<G>,
+        bounds: &[(F, F)],
+        rng: &mut Rng,
+        x0: Option<&Array1<F>>,
+    ) -> Result<(F, Array1<F>)> {
+        let random = |rng: &mut Rng| -> Array1<F> {
+            bounds.iter().map(|&(lb, ub)| rng.uniform(lb, ub)).collect()
+        };
+        let mut location = x0.cloned().unwrap_or_else(|| random(rng));
+        for _ in 0..1000 {
+            let energy = objective.fun(&location);
+            if energy.is_finite() {
+                return Ok((energy, location));
+            }
+            location = random(rng);
+        }
+        Err(Error::InvalidArg {
+            arg: "func".into(),
+            reason:
+                "Stopping algorithm because function create NaN or (+/-) infinity values even with \
+             trying new random parameters"
+                    .into(),
+        })
+    }
+}
+
+/// Find the global minimum of a function using Dual Annealing.
+///
+/// ## Parameters
+/// * `func`: The objective function to be minimized, of the 1-D array of the `n` parameters.
+/// * `bounds`: Finite lower and upper bounds of every parameter.
+/// * `rng`: The random number generator, which makes the minimization repeatable. A generator
+///   seeded from the operating system is used if not given.
+/// * `options`: [DualAnnealingOptions] of the solver.
+///
+/// ## Returns
+/// The optimization result, with `status` 0 if the maximum number of iterations was reached,
+/// and 1 if the maximum number of function evaluations was, which is not a success. `njev`
+/// counts the gradient evaluations of the local searches.
+///
+/// ## Errors
+/// If `bounds` is empty or not finite, a lower bound is not less than the upper bound, `x0`
+/// does not match them, or the parameters of the annealing are out of their ranges. If `func`
+/// is not finite at a thousand random points in a row.
+///
+/// ## Notes
+/// This function implements the Dual Annealing optimization [1], which combines the
+/// generalized simulated annealing of Tsallis and Stariolo [2] with a local search, here the
+/// [LBfgsB](Method::LBfgsB) method of [minimize], applied to the accepted locations.
+///
+/// The trial points are drawn from a distorted Cauchy-Lorentz distribution, whose width
+/// depends on the artificial temperature `T(t) = T0 (2^(q_v - 1) - 1) / ((1 + t)^(q_v - 1) - 1)`.
+/// A trial point of higher energy is accepted with the probability
+/// `(1 - (1 - q_a) dE / T_q(t))^(1 / (1 - q_a))`, with the temperature of the acceptance
+/// `T_q(t) = T(t) / (t + 1)`.
+///
+/// ## References
+/// 1. Xiang Y, Sun DY, Fan W, Gong XG. Generalized Simulated Annealing Algorithm and Its
+///    Application to the Thomson Model. Physics Letters A, 233, 216-220 (1997).
+/// 2. Tsallis C, Stariolo DA. Generalized Simulated Annealing. Physica A, 233, 395-406 (1996).
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.optimize import dual_annealing
+/// func = lambda x: np.sum(x*x - 10*np.cos(2*np.pi*x)) + 10*np.size(x)
+/// lw = [-5.12] * 10
+/// up = [5.12] * 10
+/// ret = dual_annealing(func, bounds=list(zip(lw, up)))
+/// ret.x, ret.fun
+/// # (array([-4.26437714e-09, ...]), 0.000000)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use core::f64::consts::PI;
+/// use ndarray::ArrayView1;
+/// use sci_rs::optimize::dual_annealing;
+/// use sci_rs::random::Rng;
+///
+/// let func = |x: ArrayView1<f64>| {
+///     x.iter().map(|x| x * x - 10. * (2. * PI * x).cos()).sum::<f64>() + 10. * x.len() as f64
+/// };
+/// let bounds = [(-5.12, 5.12); 10];
+/// let mut rng = Rng::new(42);
+/// let ret = dual_annealing(func, &bounds, Some(&mut rng), Default::default()).unwrap();
+/// assert_abs_diff_eq!(ret.fun, 0., epsilon = 1e-10);
+/// for x in ret.x {
+///     assert_abs_diff_eq!(x, 0., epsilon = 1e-6);
+/// }
+/// ```
+pub fn dual_annealing<F, G>(
+    func: G,
+    bounds: &[(F, F)],
+    rng: Option<&mut Rng>,
+    options: DualAnnealingOptions<F>,
+) -> Result<OptimizeResult<F, Array1<F>>>
+where
+    F: Float + FloatConst,
+    G: FnMut(ArrayView1<F>) -> F,
+{
+    let n = bounds.len();
+    if n == 0
+        || bounds
+            .iter()
+            .any(|(lb, ub)| !lb.is_finite() || !ub.is_finite())
+    {
+        return Err(Error::InvalidArg {
+            arg: "bounds".into(),
+            reason: "Some bounds values are inf values or nan values".into(),
+        });
+    }
+    if bounds.iter().any(|(lb, ub)| lb >= ub) {
+        return Err(Error::InvalidArg {
+            arg: "bounds".into(),
+            reason: "Bounds are not consistent min < max".into(),
+        });
+    }
+    let maxiter = options.maxiter.unwrap_or(1000);
+    let initial_temp = options.initial_temp.unwrap_or(F::from(5230).unwrap());
+    let restart_temp_ratio = options.restart_temp_ratio.unwrap_or(F::from(2e-5).unwrap());
+    let visit = options.visit.unwrap_or(F::from(2.62).unwrap());
+    let accept = options.accept.unwrap_or(F::from(-5).unwrap());
+    let maxfun = options.maxfun.unwrap_or(10_000_000);
+    let one = F::one();
+    let in_range =
+        |v: F, low: f64, high: f64| v > F::from(low).unwrap() && v <= F::from(high).unwrap();
+    if !in_range(initial_temp, 0.01, 5e4) {
+        return Err(Error::InvalidArg {
+            arg: "initial_temp".into(),
+            reason: "initial_temp must be within (0.01, 5e4].".into(),
+        });
+    }
+    if !in_range(restart_temp_ratio, 0., 1.) || restart_temp_ratio >= one {
+        return Err(Error::InvalidArg {
+            arg: "restart_temp_ratio".into(),
+            reason: "restart_temp_ratio must be within (0, 1).".into(),
+        });
+    }
+    if !in_range(visit, 1., 3.) || visit >= F::from(3).unwrap() {
+        return Err(Error::InvalidArg {
+            arg: "visit".into(),
+            reason: "visit must be within (1, 3).".into(),
+        });
+    }
+    if !in_range(accept, -1e4, -5.) {
+        return Err(Error::InvalidArg {
+            arg: "accept".into(),
+            reason: "accept must be within (-1e4, -5].".into(),
+        });
+    }
+    if let Some(x0) = &options.x0 {
+        if x0.len() != n
+            || x0
+                .iter()
+                .zip(bounds)
+                .any(|(&x, &(lb, ub))| x.is_nan() || x < lb || x > ub)
+        {
+            return Err(Error::InvalidArg {
+                arg: "x0".into(),
+                reason: "Some entries in x0 lay outside the specified bounds".into(),
+            });
+        }
+    }
+    let mut default = None;
+    let rng = rng.unwrap_or_else(|| default.insert(default_rng()));
+    let local_search_enabled = !options.no_local_search.unwrap_or(false);
+
+    let distribution = VisitingDistribution::new(bounds, visit);
+    let mut objective = Objective {
+        func,
+        nfev: 0,
+        njev: 0,
+    };
+    let mut state = EnergyState::new(&mut objective, bounds, rng, options.x0.as_ref())?;
+
+    // State of the strategy chain, whose minimum is the start of the local searches.
+    let mut emin = state.current_energy;
+    let mut xmin = state.current_location.clone();
+    let mut not_improved_idx = 0;
+    let mut not_improved_max_idx = 1000;
+    let k = 100 * n;
+
+    let temperature_restart = initial_temp * restart_temp_ratio;
+    let t1 = ((visit - one) * F::from(2).unwrap().ln()).exp() - one;
+    let mut iteration = 0;
+    let maxfun_message = "Maximum number of function call reached during annealing";
+    let status = 'annealing: loop {
+        for i in 0..maxiter {
+            let s = F::from(i + 2).unwrap();
+            let t2 = ((visit - one) * s.ln()).exp() - one;
+            let temperature = initial_temp * t1 / t2;
+            if iteration >= maxiter {
+                break 'annealing 0;
+            }
+            if temperature < temperature_restart {
+                (state.current_energy, state.current_location) =
+                    EnergyState::reset(&mut objective, bounds, rng, None)?;
+                continue 'annealing;
+            }
+
+            // Markov chain of 2n trial points at this temperature.
+            let temperature_step = temperature / F::from(i + 1).unwrap();
+            not_improved_idx += 1;
+            let mut improved = i == 0;
+            for j in 0..2 * n {
+                let x_visit = distribution.visiting(&state.current_location, j, temperature, rng);
+                let e = objective.fun(&x_visit);
+                if e < state.current_energy {
+                    state.current_energy = e;
+                    state.current_location = x_visit.clone();
+                    if e < state.ebest {
+                        state.ebest = e;
+                        state.xbest = x_visit;
+                        improved = true;
+                        not_improved_idx = 0;
+                    }
+                } else {
+                    // Acceptance of a higher energy with the generalized Metropolis probability.
+                    let r: F = rng.random();
+                    let pqv_temp =
+                        one - (one - accept) * (e - state.current_energy) / temperature_step;
+                    let pqv = if pqv_temp.is_nan() || pqv_temp <= F::zero() {
+                        F::zero()
+                    } else {
+                        (pqv_temp.ln() / (one - accept)).exp()
+                    };
+                    if r <= pqv {
+                        state.current_energy = e;
+                        state.current_location = x_visit;
+                        xmin = state.current_location.clone();
+                    }
+                    if not_improved_idx >= not_improved_max_idx
+                        && (j == 0 || state.current_energy < emin)
+                    {
+                        emin = state.current_energy;
+                        xmin = state.current_location.clone();
+                    }
+                }
+                if objective.nfev >= maxfun {
+                    break 'annealing 1;
+                }
+            }
+
+            if local_search_enabled {
+                if improved {
+                    let (e, x) = objective.local_search(&state.xbest, state.ebest, bounds);
+                    if e < state.ebest {
+                        not_improved_idx = 0;
+                        state.ebest = e;
+                        state.xbest = x.clone();
+                        state.current_energy = e;
+                        state.current_location = x;
+                    }
+                    if objective.nfev >= maxfun {
+                        break 'annealing 1;
+                    }
+                }
+                // A local search may also be done without improvement, with a probability that
+                // decreases with the temperature.
+                let mut do_ls = false;
+                if k < 90 * n {
+                    let pls = (F::from(k).unwrap() * (state.ebest - state.current_energy)
+                        / temperature_step)
+                        .exp();
+                    if pls >= rng.random() {
+                        do_ls = true;
+                    }
+                }
+                if not_improved_idx >= not_improved_max_idx {
+                    do_ls = true;
+                }
+                if do_ls {
+                    let (e, x) = objective.local_search(&xmin, emin, bounds);
+                    xmin = x.clone();
+                    emin = e;
+                    not_improved_idx = 0;
+                    not_improved_max_idx = n;
+                    if e < state.ebest {
+                        state.ebest = e;
+                        state.xbest = x.clone();
+                        state.current_energy = e;
+                        state.current_location = x;
+                    }
+                    if objective.nfev >= maxfun {
+                        break 'annealing 1;
+                    }
+                }
+            }
+            iteration += 1;
+        }
+        // The loop only ends by reaching the maximum number of iterations.
+        break 0;
+    };
+
+    Ok(OptimizeResult {
+        x: state.xbest,
+        fun: state.ebest,
+        nit: iteration,
+        nfev: objective.nfev,
+        jac: None,
+        njev: objective.njev,
+        success: status == 0,
+        status,
+        message: if status == 0 {
+            "Maximum number of iteration reached"
+        } else {
+            maxfun_message
+        }
+        .into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    fn rastrigin(x: ArrayView1<f64>) -> f64 {
+        x.iter()
+            .map(|x| x * x - 10. * (2. * core::f64::consts::PI * x).cos())
+            .sum::<f64>()
+            + 10. * x.len() as f64
+    }
+
+    #[test]
+    fn annealing() {
+        let bounds = [(-5.12, 5.12); 2];
+        let options = DualAnnealingOptions {
+            x0: Some(array![3., -4.]),
+            ..Default::default()
+        };
+        let run = |options| dual_annealing(rastrigin, &bounds, Some(&mut Rng::new(5)), options);
+        let res = run(options.clone()).unwrap();
+        assert!(res.success);
+        assert_eq!(res.nit, 1000);
+        assert_abs_diff_eq!(res.fun, 0., epsilon = 1e-12);
+        assert_eq!(res, run(options).unwrap());
+
+        // Without the local searches, the annealing alone gets close to the minimum.
+        let options = DualAnnealingOptions {
+            no_local_search: Some(true),
+            ..Default::default()
+        };
+        let res = run(options).unwrap();
+        assert_eq!(res.njev, 0);
+        assert!(res.fun < 1e-2);
+
+        let options = DualAnnealingOptions {
+            maxfun: Some(100),
+            ..Default::default()
+        };
+        let res = run(options).unwrap();
+        assert!(!res.success);
+        assert_eq!(res.status, 1);
+    }
+
+    #[test]
+    fn invalid_args() {
+        let da = |bounds: &[(f64, f64)], options| {
+            dual_annealing(rastrigin, bounds, None, options).is_err()
+        };
+        assert!(da(&[], Default::default()));
+        assert!(da(&[(0., f64::NAN)], Default::default()));
+        assert!(da(&[(1., 1.)], Default::default()));
+        let bounds = [(0., 1.)];
+        let options = DualAnnealingOptions {
+            visit: Some(3.),
+            ..Default::default()
+        };
+        assert!(da(&bounds, options));
+        let options = DualAnnealingOptions {
+            accept: Some(0.),
+            ..Default::default()
+        };
+        assert!(da(&bounds, options));
+        let options = DualAnnealingOptions {
+            x0: Some(array![2.]),
+            ..Default::default()
+        };
+        assert!(da(&bounds, options));
+        let fun = |_: ArrayView1<f64>| f64::NAN;
+        assert!(dual_annealing(fun, &bounds, None, Default::default()).is_err());
+    }
+}
@@ -0,0 +1,330 @@
+use super::root::{MultiRootResult, RootOptions};
+use super::trust_region::{matvec, norm, rmatvec};
+use crate::linalg::BandedLu;
+use alloc::format;
+use ndarray::{Array1, Array2, ArrayView1};
+use num_traits::Float;
+
+/// Forward-difference approximation of the Jacobian of `fun` at `x`, where its value is `f`, as
+/// MINPACK's `fdjac1` with the relative step `eps`.
+fn fd_jacobian<F: Float, G: FnMut(ArrayView1<F>) -> Array1<F>>(
+    fun: &mut G,
+    x: &Array1<F>,
+    f: &Array1<F>,
+    eps: F,
+) -> Array2<F> {
+    let n = x.len();
+    let mut jac = Array2::zeros((n, n));
+    let mut xh = x.clone();
+    for j in 0..n {
+        let h = if x[j].is_zero() {
+            eps
+        } else {
+            eps * x[j].abs()
+        };
+        xh[j] = x[j] + h;
+        let fh = fun(xh.view());
+        for i in 0..n {
+            jac[(i, j)] = (fh[i] - f[i]) / h;
+        }
+        xh[j] = x[j];
+    }
+    jac
+}
+
+/// Dogleg step of MINPACK's `dogleg`, which minimizes `||J p + f||` subject to
+/// `||D p|| <= delta`, between the Gauss-Newton step and the scaled steepest descent step.
+///
+/// The Gauss-Newton step is skipped if `J` is singular.
+fn dogleg<F: Float>(jac: &Array2<F>, diag: &Array1<F>, f: &Array1<F>, delta: F) -> Array1<F> {
+    let n = f.len();
+    let gauss_newton = BandedLu::new(n, n - 1, n - 1, |i, j| jac[(i, j)])
+        .ok()
+        .map(|lu| {
+            let mut p = f.mapv(|f| -f);
+            lu.solve(p.as_slice_mut().unwrap());
+            p
+        })
+        .filter(|p| p.iter().all(|p| p.is_finite()));
+    let qnorm = gauss_newton
+        .as_ref()
+        .map_or(F::infinity(), |p| norm(&(p * diag)));
+    if let Some(p) = gauss_newton.as_ref().filter(|_| qnorm <= delta) {
+        return p.clone();
+    }
+
+    // The Gauss-Newton step is too long, so find the scaled gradient direction and the point
+    // minimizing the model along it.
+    let g = rmatvec(jac, f) / diag;
+    let gnorm = norm(&g);
+    let mut alpha = delta / qnorm;
+    let mut sgnorm = F::zero();
+    let mut direction = Array1::zeros(n);
+    if !gnorm.is_zero() {
+        direction = Array1::from_shape_fn(n, |j| -g[j] / gnorm / diag[j]);
+        let jd = norm(&matvec(jac, &direction));
+        sgnorm = gnorm / jd / jd;
+        alpha = F::zero();
+        if sgnorm < delta && gauss_newton.is_some() {
+            // The dogleg point on the segment between the two steps, where it crosses the
+            // trust region boundary.
+            let bnorm = norm(f);
+            let dq = delta / qnorm;
+            let sd = sgnorm / delta;
+            let temp = bnorm / gnorm * (bnorm / qnorm) * sd;
+            let temp = temp - dq * sd * sd
+                + ((temp - dq).powi(2) + (F::one() - dq * dq) * (F::one() - sd * sd)).sqrt();
+            alpha = dq * (F::one() - sd * sd) / temp;
+        }
+    }
+    let scale = (F::one() - alpha) * sgnorm.min(delta);
+    match gauss_newton {
+        Some(p) => Array1::from_shape_fn(n, |j| scale * direction[j] + alpha * p[j]),
+        None => direction.mapv(|d| scale * d),
+    }
+}
+
+/// Solution by the Powell hybrid method, as [super::root] with [super::RootMethod::Hybr].
+///
+/// This follows MINPACK's `hybrd` and `hybrj` with the scaling of the variables by the norms of
+/// the columns of the Jacobian.
+#[allow(clippy::type_complexity)]
+pub(super) fn hybr<F: Float, G: FnMut(ArrayView1<F>) -> Array1<F>>(
+    mut fun: G,
+    mut jac: Option<&mut dyn FnMut(ArrayView1<F>) -> Array2<F>>,
+    x0: Array1<F>,
+    f0: Array1<F>,
+    j0: Option<Array2<F>>,
+    options: &RootOptions<F>,
+) -> MultiRootResult<F> {
+    let n = x0.len();
+    let (tenth, half) = (F::from(0.1).unwrap(), F::from(0.5).unwrap());
+    let xtol = options.xtol.unwrap_or(F::from(1.49012e-8).unwrap());
+    let factor = options.factor.unwrap_or(F::from(100).unwrap());
+    let maxfev = options
+        .maxfev
+        .unwrap_or(if jac.is_some() { 100 } else { 200 } * (n + 1));
+    let eps = options
+        .epsfcn
+        .unwrap_or(F::epsilon())
+        .max(F::epsilon())
+        .sqrt();
+
+    let mut x = x0;
+    let mut f = f0;
+    let mut fnorm = norm(&f);
+    let (mut nfev, mut njev, mut nit) = (1, 0, 0);
+    let mut j0 = j0;
+    let mut diag = Array1::zeros(n);
+    let (mut delta, mut xnorm) = (F::zero(), F::zero());
+    let (mut ncsuc, mut ncfail, mut nslow1, mut nslow2) = (0, 0, 0, 0);
+    let mut first = true;
+    let (status, jacobian) = 'outer: loop {
+        let mut jacobian = match (j0.take(), jac.as_mut()) {
+            (Some(j0), _) => {
+                njev += 1;
+                j0
+            }
+            (None, Some(jac)) => {
+                njev += 1;
+                jac(x.view())
+            }
+            (None, None) => {
+                nfev += n;
+                fd_jacobian(&mut fun, &x, &f, eps)
+            }
+        };
+        let col_norms: Array1<F> = jacobian
+            .columns()
+            .into_iter()
+            .map(|c| norm(&c.to_owned()))
+            .collect();
+        if first {
+            diag = col_norms.mapv(|c| if c.is_zero() { F::one() } else { c });
+            xnorm = norm(&(&diag * &x));
+            delta = if xnorm.is_zero() {
+                factor
+            } else {
+                factor * xnorm
+            };
+        }
+        diag.zip_mut_with(&col_norms, |d, &c| *d = d.max(c));
+
+        let mut jeval = true;
+        loop {
+            let p = dogleg(&jacobian, &diag, &f, delta);
+            let x_new = &x + &p;
+            let pnorm = norm(&(&diag * &p));
+            if first {
+                delta = delta.min(pnorm);
+                first = false;
+            }
+            let f_new = fun(x_new.view());
+            nfev += 1;
+            nit += 1;
+            let fnorm1 = norm(&f_new);
+
+            // Ratio of the actual to the predicted reduction of the sum of squares.
+            let actred = if fnorm1 < fnorm {
+                F::one() - (fnorm1 / fnorm).powi(2)
+            } else {
+                -F::one()
+            };
+            let jp = matvec(&jacobian, &p);
+            let predicted = norm(&(&jp + &f));
+            let prered = if predicted < fnorm {
+                F::one() - (predicted / fnorm).powi(2)
+            } else {
+                F::zero()
+            };
+            let ratio = if prered > F::zero() {
+                actred / prered
+            } else {
+                F::zero()
+            };
+
+            if ratio < tenth {
+                ncsuc = 0;
+                ncfail += 1;
+                delta = half * delta;
+            } else {
+                ncfail = 0;
+                ncsuc += 1;
+                if ratio >= half || ncsuc > 1 {
+                    delta = delta.max(pnorm / half);
+                }
+                if (ratio - F::one()).abs() <= tenth {
+                    delta = pnorm / half;
+                }
+            }
+
+            let df = &f_new - &f;
+            if ratio >= F::from(1e-4).unwrap() {
+                x = x_new;
+                f = f_new;
+                xnorm = norm(&(&diag * &x));
+                fnorm = fnorm1;
+            }
+            nslow1 += 1;
+            if actred >= F::from(1e-3).unwrap() {
+                nslow1 = 0;
+            }
+            if jeval {
+                nslow2 += 1;
+            }
+            if actred >= tenth {
+                nslow2 = 0;
+            }
+
+            if delta <= xtol * xnorm || fnorm.is_zero() {
+                break 'outer (1, jacobian);
+            }
+            if nfev >= maxfev {
+                break 'outer (2, jacobian);
+            }
+            if tenth * (tenth * delta).max(pnorm) <= F::epsilon() * xnorm {
+                break 'outer (3, jacobian);
+            }
+            if nslow2 == 5 {
+                break 'outer (4, jacobian);
+            }
+            if nslow1 == 10 {
+                break 'outer (5, jacobian);
+            }
+            if ncfail == 2 {
+                break;
+            }
+
+            // Broyden's rank-1 update of the Jacobian.
+            let dp = &diag * &diag * &p;
+            let scale = pnorm * pnorm;
+            for i in 0..n {
+                let r = (df[i] - jp[i]) / scale;
+                for j in 0..n {
+                    jacobian[(i, j)] = jacobian[(i, j)] + r * dp[j];
+                }
+            }
+            jeval = false;
+        }
+    };
+
+    let message = match status {
+        1 => "The solution converged.".into(),
+        2 => format!("The number of calls to function has reached maxfev = {maxfev}."),
+        3 => format!(
+            "xtol={} is too small, no further improvement in the approximate solution is possible.",
+            xtol.to_f64().unwrap()
+        ),
+        4 => "The iteration is not making good progress, as measured by the improvement from the last five Jacobian evaluations.".into(),
+        _ => "The iteration is not making good progress, as measured by the improvement from the last ten iterations.".into(),
+    };
+    MultiRootResult {
+        x,
+        fun: f,
+        jac: Some(jacobian),
+        nit,
+        nfev,
+        njev,
+        success: status == 1,
+        status,
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    fn rosenbrock(x: ArrayView1<f64>) -> Array1<f64> {
+        array![10. * (x[1] - x[0] * x[0]), 1. - x[0]]
+    }
+
+    #[test]
+    fn finite_differences() {
+        let x0 = array![-1.2, 1.];
+        let f0 = rosenbrock(x0.view());
+        let res = hybr(rosenbrock, None, x0, f0, None, &Default::default());
+        assert!(res.success, "{}", res.message);
+        assert_abs_diff_eq!(
+            res.x.as_slice().unwrap(),
+            [1., 1.].as_slice(),
+            epsilon = 1e-8
+        );
+        assert!(res.nfev > res.nit);
+        assert_eq!(res.njev, 0);
+        // The Jacobian is updated along the way and is close to the exact one at the solution.
+        let jac = res.jac.unwrap();
+        assert_abs_diff_eq!(jac[(1, 0)], -1., epsilon = 1e-6);
+        assert_abs_diff_eq!(jac[(0, 1)], 10., epsilon = 1e-6);
+    }
+
+    #[test]
+    fn singular_jacobian() {
+        // The Jacobian is singular at the initial point, so the first step is along the gradient.
+        let fun = |x: ArrayView1<f64>| array![x[0] * x[0] + x[1] - 2., x[0] - x[1]];
+        let mut jac = |x: ArrayView1<f64>| array![[2. * x[0], 1.], [1., -1.]];
+        let x0 = array![-0.5, 0.];
+        let f0 = fun(x0.view());
+        let j0 = jac(x0.view());
+        let res = hybr(fun, Some(&mut jac), x0, f0, Some(j0), &Default::default());
+        assert!(res.success, "{}", res.message);
+        assert!(res.fun.iter().all(|f| f.abs() < 1e-8));
+    }
+
+    #[test]
+    fn maxfev() {
+        let x0 = array![-1.2, 1.];
+        let f0 = rosenbrock(x0.view());
+        let options = RootOptions {
+            maxfev: Some(5),
+            ..Default::default()
+        };
+        let res = hybr(rosenbrock, None, x0, f0, None, &options);
+        assert!(!res.success);
+        assert_eq!(res.status, 2);
+        assert_eq!(res.nfev, 5);
+    }
+}
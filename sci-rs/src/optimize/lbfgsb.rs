@@ -0,0 +1,463 @@
+use super::line_search::{dot, line_search_wolfe2, Jacobian, ScalarFunction};
+use super::{MinimizeOptions, OptimizeResult};
+use crate::linalg::BandedLu;
+use alloc::{string::String, vec, vec::Vec};
+use ndarray::{Array1, ArrayView1, Zip};
+use num_traits::Float;
+use sci_rs_core::Result;
+
+/// Limited-memory BFGS approximation of the Hessian in the compact form
+/// `B = theta * I - W * M * W^T`, with `W = [Y, theta * S]` for the stored corrections.
+struct Memory<F> {
+    s: Vec<Array1<F>>,
+    y: Vec<Array1<F>>,
+    theta: F,
+    /// LU factorization of `M^-1 = [[-D, L^T], [L, theta * S^T S]]`, where `D` is the diagonal
+    /// and `L` the strictly lower triangle of `S^T Y`.
+    middle: Option<BandedLu<F>>,
+}
+
+impl<F: Float> Memory<F> {
+    fn new() -> Self {
+        Memory {
+            s: Vec::new(),
+            y: Vec::new(),
+            theta: F::one(),
+            middle: None,
+        }
+    }
+
+    fn clear(&mut self) {
+        *self = Memory::new();
+    }
+
+    fn is_empty(&self) -> bool {
+        self.s.is_empty()
+    }
+
+    /// Store the correction pair, dropping the oldest ones beyond `m`.
+    fn push(&mut self, s: Array1<F>, y: Array1<F>, m: usize) {
+        self.theta = dot(&y, &y) / dot(&s, &y);
+        self.s.push(s);
+        self.y.push(y);
+        if self.s.len() > m {
+            self.s.remove(0);
+            self.y.remove(0);
+        }
+        let k = self.s.len();
+        let (s, y, theta) = (&self.s, &self.y, self.theta);
+        let entry = |i: usize, j: usize| match (i < k, j < k) {
+            (true, true) if i == j => -dot(&s[i], &y[i]),
+            (true, false) if j - k > i => dot(&s[j - k], &y[i]),
+            (false, true) if i - k > j => dot(&s[i - k], &y[j]),
+            (false, false) => theta * dot(&s[i - k], &s[j - k]),
+            _ => F::zero(),
+        };
+        self.middle = BandedLu::new(2 * k, 2 * k - 1, 2 * k - 1, entry).ok();
+        if self.middle.is_none() {
+            self.clear();
+        }
+    }
+
+    /// Row `i` of `W`.
+    fn w_row(&self, i: usize) -> Vec<F> {
+        let y = self.y.iter().map(|y| y[i]);
+        y.chain(self.s.iter().map(|s| self.theta * s[i])).collect()
+    }
+
+    /// `M * v`.
+    fn m_times(&self, v: &[F]) -> Vec<F> {
+        let mut v = v.to_vec();
+        if let Some(middle) = &self.middle {
+            middle.solve(&mut v);
+        }
+        v
+    }
+}
+
+fn inner<F: Float>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b).fold(F::zero(), |acc, (&a, &b)| acc + a * b)
+}
+
+/// Infinity norm of the projected gradient.
+fn projected_gradient_norm<F: Float>(x: &Array1<F>, g: &Array1<F>, bounds: &[(F, F)]) -> F {
+    Zip::from(x)
+        .and(g)
+        .and(bounds)
+        .fold(F::zero(), |acc, &x, &g, &(l, u)| {
+            let g = if g < F::zero() {
+                g.max(x - u)
+            } else {
+                g.min(x - l)
+            };
+            acc.max(g.abs())
+        })
+}
+
+/// The generalized Cauchy point, the first local minimizer of the quadratic model along the path
+/// of the steepest descent direction projected on the bounds.
+///
+/// Returns the point, `M * c` where `c = W^T (xcp - x)`, and whether every variable is free or
+/// fixed at a bound.
+fn cauchy<F: Float>(
+    x: &Array1<F>,
+    g: &Array1<F>,
+    bounds: &[(F, F)],
+    memory: &Memory<F>,
+) -> (Array1<F>, Vec<F>, Vec<bool>) {
+    let n = x.len();
+    let theta = memory.theta;
+    let zero = F::zero();
+    let axpy = |y: &mut [F], a: F, x: &[F]| y.iter_mut().zip(x).for_each(|(y, &x)| *y = *y + a * x);
+
+    // Breakpoints of the variables along the projected path.
+    let mut free = vec![true; n];
+    let mut d = Array1::zeros(n);
+    let mut breakpoints = Vec::new();
+    for i in 0..n {
+        let (l, u) = bounds[i];
+        let t = if g[i] < zero {
+            (x[i] - u) / g[i]
+        } else if g[i] > zero {
+            (x[i] - l) / g[i]
+        } else {
+            F::infinity()
+        };
+        if t.is_zero() {
+            free[i] = false;
+        } else {
+            d[i] = -g[i];
+            if t.is_finite() {
+                breakpoints.push((t, i));
+            }
+        }
+    }
+    breakpoints.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(core::cmp::Ordering::Equal));
+
+    let mut xcp = x.clone();
+    let mut p: Vec<F> = memory.y.iter().map(|y| dot(y, &d)).collect();
+    p.extend(memory.s.iter().map(|s| theta * dot(s, &d)));
+    let mut mp = memory.m_times(&p);
+    let mut mc = vec![zero; p.len()];
+    let mut f1 = -dot(&d, &d);
+    let f2_org = -theta * f1 - inner(&p, &mp);
+    let mut f2 = f2_org;
+    let mut dtm = -f1 / f2;
+    let mut t_old = zero;
+    let mut remaining = d.iter().filter(|d| !d.is_zero()).count();
+    for (t, b) in breakpoints {
+        if dtm < t - t_old {
+            break;
+        }
+        let dt = t - t_old;
+        xcp[b] = if d[b] > zero {
+            bounds[b].1
+        } else {
+            bounds[b].0
+        };
+        let (gb, zb) = (g[b], xcp[b] - x[b]);
+        axpy(&mut mc, dt, &mp);
+        let wb = memory.w_row(b);
+        let mwb = memory.m_times(&wb);
+        f1 = f1 + dt * f2 + gb * gb + theta * gb * zb - gb * inner(&wb, &mc);
+        f2 = f2
+            - theta * gb * gb
+            - F::from(2).unwrap() * gb * inner(&wb, &mp)
+            - gb * gb * inner(&wb, &mwb);
+        f2 = f2.max(F::epsilon() * f2_org);
+        axpy(&mut p, gb, &wb);
+        axpy(&mut mp, gb, &mwb);
+        d[b] = zero;
+        free[b] = false;
+        remaining -= 1;
+        dtm = -f1 / f2;
+        t_old = t;
+    }
+    // The minimizer is between the last breakpoint passed and the next one.
+    let dtm = if remaining == 0 { zero } else { dtm.max(zero) };
+    t_old = t_old + dtm;
+    Zip::from(&mut xcp).and(x).and(&d).for_each(|xcp, &x, &d| {
+        if !d.is_zero() {
+            *xcp = x + t_old * d;
+        }
+    });
+    axpy(&mut mc, dtm, &mp);
+    (xcp, mc, free)
+}
+
+/// Minimize the quadratic model over the free variables from the Cauchy point, by the direct
+/// primal method, and backtrack into the bounds.
+fn subspace_minimization<F: Float>(
+    x: &Array1<F>,
+    g: &Array1<F>,
+    bounds: &[(F, F)],
+    memory: &Memory<F>,
+    (mut xcp, mc, free): (Array1<F>, Vec<F>, Vec<bool>),
+) -> Array1<F> {
+    let theta = memory.theta;
+    let free: Vec<usize> = (0..x.len()).filter(|&i| free[i]).collect();
+    if memory.is_empty() || free.is_empty() {
+        return xcp;
+    }
+    let rows: Vec<Vec<F>> = free.iter().map(|&i| memory.w_row(i)).collect();
+    let k2 = mc.len();
+    // Reduced gradient of the model at the Cauchy point.
+    let r: Vec<F> = free
+        .iter()
+        .zip(&rows)
+        .map(|(&i, w)| g[i] + theta * (xcp[i] - x[i]) - inner(w, &mc))
+        .collect();
+    let wtr: Vec<F> = (0..k2)
+        .map(|a| {
+            rows.iter()
+                .zip(&r)
+                .fold(F::zero(), |acc, (w, &r)| acc + w[a] * r)
+        })
+        .collect();
+    let mut v = memory.m_times(&wtr);
+    // N = I - M W^T Z Z^T W / theta.
+    let columns: Vec<Vec<F>> = (0..k2)
+        .map(|b| {
+            let column: Vec<F> = (0..k2)
+                .map(|a| rows.iter().fold(F::zero(), |acc, w| acc + w[a] * w[b]))
+                .collect();
+            memory.m_times(&column)
+        })
+        .collect();
+    let identity = |a: usize, b: usize| if a == b { F::one() } else { F::zero() };
+    let Ok(lu) = BandedLu::new(k2, k2 - 1, k2 - 1, |a, b| {
+        identity(a, b) - columns[b][a] / theta
+    }) else {
+        return xcp;
+    };
+    lu.solve(&mut v);
+    let du: Vec<F> = rows
+        .iter()
+        .zip(&r)
+        .map(|(w, &r)| -r / theta - inner(w, &v) / (theta * theta))
+        .collect();
+
+    let mut alpha = F::one();
+    for (&i, &du) in free.iter().zip(&du) {
+        let (l, u) = bounds[i];
+        if du > F::zero() {
+            alpha = alpha.min((u - xcp[i]) / du);
+        } else if du < F::zero() {
+            alpha = alpha.min((l - xcp[i]) / du);
+        }
+    }
+    for (&i, &du) in free.iter().zip(&du) {
+        xcp[i] = xcp[i] + alpha * du;
+    }
+    xcp
+}
+
+/// Minimization by the L-BFGS-B algorithm, as [super::minimize] with [super::Method::LBfgsB].
+pub(super) fn lbfgsb<F: Float>(
+    fun: impl FnMut(ArrayView1<F>) -> F,
+    jac: Option<Jacobian<'_, F>>,
+    x0: Array1<F>,
+    options: &MinimizeOptions<F>,
+) -> Result<OptimizeResult<F, Array1<F>>> {
+    let n = x0.len();
+    let m = options.maxcor.unwrap_or(10).max(1);
+    let ftol = options
+        .ftol
+        .unwrap_or_else(|| F::from(2.220446049250313e-9).unwrap());
+    let gtol = options.gtol.unwrap_or_else(|| F::from(1e-5).unwrap());
+    let eps = options.eps.unwrap_or_else(|| F::from(1e-8).unwrap());
+    let maxfun = options.maxfev.unwrap_or(15000);
+    let maxiter = options.maxiter.unwrap_or(15000);
+    let maxls = options.maxls.unwrap_or(20);
+    let (c1, c2) = (F::from(1e-3).unwrap(), F::from(0.9).unwrap());
+    let bounds = options
+        .bounds
+        .clone()
+        .unwrap_or_else(|| vec![(F::neg_infinity(), F::infinity()); n]);
+    let constrained = bounds.iter().any(|(l, u)| l.is_finite() || u.is_finite());
+    let boxed = bounds.iter().all(|(l, u)| l.is_finite() && u.is_finite());
+
+    let mut x = x0;
+    Zip::from(&mut x)
+        .and(&bounds)
+        .for_each(|x, &(l, u)| *x = x.max(l).min(u));
+    let mut sf = ScalarFunction::new(fun, jac, eps, Some(bounds.clone()));
+    let mut f = sf.fun(&x);
+    let mut g = sf.grad(&x);
+    let mut memory = Memory::new();
+    let mut iterations = 0;
+    let (status, message) = loop {
+        if projected_gradient_norm(&x, &g, &bounds) <= gtol {
+            break (0, "CONVERGENCE: NORM OF PROJECTED GRADIENT <= PGTOL");
+        }
+        let cauchy = cauchy(&x, &g, &bounds, &memory);
+        let xbar = subspace_minimization(&x, &g, &bounds, &memory, cauchy);
+        let d = &xbar - &x;
+
+        // The largest step that stays within the bounds.
+        let stpmax = if constrained && iterations == 0 {
+            F::one()
+        } else {
+            Zip::from(&x).and(&d).and(&bounds).fold(
+                F::from(1e10).unwrap(),
+                |stpmax, &x, &d, &(l, u)| {
+                    if d < F::zero() && l.is_finite() {
+                        stpmax.min((l - x) / d).max(F::zero())
+                    } else if d > F::zero() && u.is_finite() {
+                        stpmax.min((u - x) / d).max(F::zero())
+                    } else {
+                        stpmax
+                    }
+                },
+            )
+        };
+        let alpha1 = if iterations == 0 && !boxed {
+            dot(&d, &d).sqrt().recip().min(stpmax)
+        } else {
+            F::one()
+        };
+        let derphi0 = dot(&g, &d);
+        let step = (derphi0 < F::zero())
+            .then(|| line_search_wolfe2(&mut sf, &x, &d, f, derphi0, alpha1, c1, c2, stpmax, maxls))
+            .flatten();
+        let Some(step) = step else {
+            if memory.is_empty() {
+                break (2, "ABNORMAL_TERMINATION_IN_LNSRCH");
+            }
+            // Restart from the steepest descent direction.
+            memory.clear();
+            continue;
+        };
+
+        let mut x_new = if step.alpha == F::one() {
+            xbar
+        } else {
+            &x + &d.mapv(|d| step.alpha * d)
+        };
+        Zip::from(&mut x_new)
+            .and(&bounds)
+            .for_each(|x, &(l, u)| *x = x.max(l).min(u));
+        let g_new = step.grad.unwrap_or_else(|| sf.grad(&x_new));
+        let f_old = f;
+        f = step.phi;
+        let s = &x_new - &x;
+        let y = &g_new - &g;
+        let curvature = dot(&s, &y);
+        let descent = -dot(&g, &s);
+        (x, g) = (x_new, g_new);
+        iterations += 1;
+
+        if projected_gradient_norm(&x, &g, &bounds) <= gtol {
+            break (0, "CONVERGENCE: NORM OF PROJECTED GRADIENT <= PGTOL");
+        }
+        let scale = f_old.abs().max(f.abs()).max(F::one());
+        if f_old - f <= ftol * scale {
+            break (0, "CONVERGENCE: RELATIVE REDUCTION OF F <= FACTR*EPSMCH");
+        }
+        // Skip the update if the curvature condition does not hold.
+        if curvature > F::epsilon() * descent {
+            memory.push(s, y, m);
+        }
+        if iterations >= maxiter {
+            break (1, "STOP: TOTAL NO. OF ITERATIONS REACHED LIMIT");
+        }
+        if sf.nfev > maxfun {
+            break (1, "STOP: TOTAL NO. OF F,G EVALUATIONS EXCEEDS LIMIT");
+        }
+    };
+
+    Ok(OptimizeResult {
+        x,
+        fun: f,
+        nit: iterations,
+        nfev: sf.nfev,
+        jac: Some(g),
+        njev: sf.njev,
+        success: status == 0,
+        status,
+        message: String::from(message),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{minimize, Method};
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    fn rosen(x: ArrayView1<f64>) -> f64 {
+        x.windows(2)
+            .into_iter()
+            .map(|w| 100. * (w[1] - w[0] * w[0]).powi(2) + (1. - w[0]).powi(2))
+            .sum()
+    }
+
+    #[test]
+    fn rosenbrock() {
+        let x0 = array![1.3, 0.7, 0.8, 1.9, 1.2];
+        for maxcor in [1, 10] {
+            let options = MinimizeOptions {
+                maxcor: Some(maxcor),
+                ..Default::default()
+            };
+            let res = minimize(rosen, &x0, Method::LBfgsB, None, options).unwrap();
+            assert!(res.success);
+            for x in &res.x {
+                assert_abs_diff_eq!(*x, 1., epsilon = 1e-3);
+            }
+        }
+
+        // The upper bound of x is active, and the finite differences are taken below it.
+        let options = MinimizeOptions {
+            bounds: Some(vec![(-2., 0.5), (-1., 2.)]),
+            ..Default::default()
+        };
+        let res = minimize(rosen, &array![-1.2, 1.], Method::LBfgsB, None, options).unwrap();
+        assert_eq!(res.status, 0);
+        assert_eq!(res.x[0], 0.5);
+        assert_abs_diff_eq!(res.x[1], 0.25, epsilon = 1e-5);
+        let jac = res.jac.unwrap();
+        assert_abs_diff_eq!(jac[0], -1., epsilon = 1e-3);
+        assert_abs_diff_eq!(jac[1], 0., epsilon = 1e-3);
+
+        let options = MinimizeOptions {
+            maxiter: Some(3),
+            ..Default::default()
+        };
+        let res = minimize(rosen, &x0, Method::LBfgsB, None, options).unwrap();
+        assert_eq!((res.nit, res.status), (3, 1));
+    }
+
+    #[test]
+    fn bounded_quadratic() {
+        let c = array![-1., 2., 0.5, 3., -0.25];
+        let f = |x: ArrayView1<f64>| {
+            x.iter()
+                .zip(&c)
+                .enumerate()
+                .map(|(i, (x, c))| (i + 1) as f64 * (x - c).powi(2))
+                .sum::<f64>()
+        };
+        let mut jac =
+            |x: ArrayView1<f64>| Array1::from_shape_fn(5, |i| 2. * (i + 1) as f64 * (x[i] - c[i]));
+        let options = MinimizeOptions {
+            bounds: Some(vec![
+                (0., 1.),
+                (0., 1.),
+                (0., 1.),
+                (f64::NEG_INFINITY, 1.),
+                (-1., f64::INFINITY),
+            ]),
+            ..Default::default()
+        };
+        // The initial point is clipped to the bounds.
+        let x0 = array![5., -5., 0., 0., 0.];
+        let res = minimize(f, &x0, Method::LBfgsB, Some(&mut jac), options).unwrap();
+        assert!(res.success);
+        for (x, expected) in res.x.iter().zip([0., 1., 0.5, 1., -0.25]) {
+            assert_abs_diff_eq!(*x, expected, epsilon = 1e-8);
+        }
+        assert_eq!(res.nfev, res.njev);
+    }
+}
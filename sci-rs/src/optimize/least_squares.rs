@@ -0,0 +1,718 @@
+use super::lm::lm;
+use super::trf::trf;
+use super::trust_region::rmatvec;
+use alloc::string::String;
+use alloc::vec::Vec;
+use ndarray::{Array1, Array2, ArrayBase, ArrayView1, Data, Ix1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Algorithm of [least_squares].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeastSquaresMethod {
+    /// Trust Region Reflective algorithm, which supports bounds.
+    #[default]
+    Trf,
+    /// Levenberg-Marquardt algorithm, for unbounded problems with at least as many residuals as
+    /// variables.
+    Lm,
+}
+
+/// Loss function `rho(z)` of [least_squares], applied to the squared residuals `z = f^2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Loss {
+    /// `rho(z) = z`, the standard least-squares problem.
+    #[default]
+    Linear,
+    /// `rho(z) = 2 * ((1 + z)^0.5 - 1)`, a smooth approximation of the l1 loss.
+    SoftL1,
+    /// `rho(z) = z` if `z <= 1` else `2 * z^0.5 - 1`, which works similarly to
+    /// [SoftL1](Loss::SoftL1).
+    Huber,
+    /// `rho(z) = ln(1 + z)`, which severely weakens the influence of outliers.
+    Cauchy,
+    /// `rho(z) = arctan(z)`, which limits the maximum loss of a single residual.
+    Arctan,
+}
+
+impl Loss {
+    /// Values of `rho(z)` and of its first and second derivatives.
+    fn rho<F: Float>(self, z: F) -> (F, F, F) {
+        let one = F::one();
+        let half = F::from(0.5).unwrap();
+        match self {
+            Loss::Linear => (z, one, F::zero()),
+            Loss::SoftL1 => {
+                let t = one + z;
+                (
+                    (t.sqrt() - one) * F::from(2).unwrap(),
+                    t.sqrt().recip(),
+                    -half * t.powf(F::from(-1.5).unwrap()),
+                )
+            }
+            Loss::Huber if z <= one => (z, one, F::zero()),
+            Loss::Huber => (
+                F::from(2).unwrap() * z.sqrt() - one,
+                z.sqrt().recip(),
+                -half * z.powf(F::from(-1.5).unwrap()),
+            ),
+            Loss::Cauchy => {
+                let t = one + z;
+                (z.ln_1p(), t.recip(), -(t * t).recip())
+            }
+            Loss::Arctan => {
+                let t = one + z * z;
+                (z.atan(), t.recip(), -F::from(2).unwrap() * z / (t * t))
+            }
+        }
+    }
+}
+
+/// Loss function with the soft margin `f_scale` between inlier and outlier residuals.
+pub(super) struct LossFunction<F> {
+    loss: Loss,
+    f_scale: F,
+}
+
+impl<F: Float> LossFunction<F> {
+    /// Cost `0.5 * sum(f_scale^2 * rho((f / f_scale)^2))` of the residuals `f`.
+    pub(super) fn cost(&self, f: &Array1<F>) -> F {
+        let c2 = self.f_scale * self.f_scale;
+        let half = F::from(0.5).unwrap();
+        match self.loss {
+            Loss::Linear => half * f.fold(F::zero(), |acc, &f| acc + f * f),
+            loss => {
+                half * c2
+                    * f.fold(F::zero(), |acc, &f| {
+                        acc + loss.rho((f / self.f_scale).powi(2)).0
+                    })
+            }
+        }
+    }
+
+    /// Scale the residuals and the Jacobian so that the gradient and the Gauss-Newton
+    /// approximation of the Hessian of the cost are `J^T f` and `J^T J`, as in the standard
+    /// least-squares problem.
+    pub(super) fn scale(&self, jac: &Array2<F>, f: &Array1<F>) -> (Array2<F>, Array1<F>) {
+        if self.loss == Loss::Linear {
+            return (jac.clone(), f.clone());
+        }
+        let two = F::from(2).unwrap();
+        let (rho1, j_scale): (Vec<F>, Vec<F>) = f
+            .iter()
+            .map(|&f| {
+                let z = (f / self.f_scale).powi(2);
+                let (_, rho1, rho2) = self.loss.rho(z);
+                let j_scale =
+                    (rho1 + two * rho2 / (self.f_scale * self.f_scale) * f * f).max(F::epsilon());
+                (rho1, j_scale.sqrt())
+            })
+            .unzip();
+        let f = Array1::from_shape_fn(f.len(), |i| f[i] * rho1[i] / j_scale[i]);
+        let jac = Array2::from_shape_fn(jac.dim(), |(i, j)| jac[(i, j)] * j_scale[i]);
+        (jac, f)
+    }
+}
+
+/// Signature of the Jacobian of the residuals.
+type Jacobian<'a, F> = &'a mut dyn FnMut(ArrayView1<F>) -> Array2<F>;
+
+/// Residuals of a least-squares problem and their Jacobian, which is approximated by forward
+/// differences if it is not given. The steps are reversed where they would cross an upper
+/// bound.
+pub(super) struct Residuals<'a, F, G> {
+    fun: G,
+    jac: Option<Jacobian<'a, F>>,
+    diff_step: F,
+    ub: Option<Array1<F>>,
+    /// Number of evaluations of the residuals, excluding those of the finite differences.
+    pub(super) nfev: usize,
+    /// Number of evaluations of the residuals by the finite differences.
+    pub(super) fd_nfev: usize,
+    /// Number of evaluations of the Jacobian.
+    pub(super) njev: usize,
+}
+
+impl<F: Float, G: FnMut(ArrayView1<F>) -> Array1<F>> Residuals<'_, F, G> {
+    pub(super) fn has_jac(&self) -> bool {
+        self.jac.is_some()
+    }
+
+    pub(super) fn fun(&mut self, x: &Array1<F>) -> Array1<F> {
+        self.nfev += 1;
+        (self.fun)(x.view())
+    }
+
+    pub(super) fn jac(&mut self, x: &Array1<F>, f: &Array1<F>) -> Array2<F> {
+        self.njev += 1;
+        if let Some(jac) = &mut self.jac {
+            return jac(x.view());
+        }
+        let mut jac = Array2::zeros((f.len(), x.len()));
+        let mut xh = x.clone();
+        for i in 0..x.len() {
+            let sign = if x[i] >= F::zero() {
+                F::one()
+            } else {
+                -F::one()
+            };
+            let mut h = self.diff_step * sign * x[i].abs().max(F::one());
+            if self.ub.as_ref().is_some_and(|ub| x[i] + h > ub[i]) {
+                h = -h;
+            }
+            xh[i] = x[i] + h;
+            let dx = xh[i] - x[i];
+            self.fd_nfev += 1;
+            let fh = (self.fun)(xh.view());
+            xh[i] = x[i];
+            for (j, (&fh, &f)) in fh.iter().zip(f).enumerate() {
+                jac[(j, i)] = (fh - f) / dx;
+            }
+        }
+        jac
+    }
+}
+
+/// Tolerances and limits of [least_squares].
+///
+/// Every field left as `None` takes its default, which is also what [Default] gives.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LeastSquaresOptions<F> {
+    /// Tolerance for termination by the change of the cost function, `1e-8` by default.
+    pub ftol: Option<F>,
+    /// Tolerance for termination by the change of the independent variables, `1e-8` by default.
+    pub xtol: Option<F>,
+    /// Tolerance for termination by the norm of the gradient, `1e-8` by default.
+    pub gtol: Option<F>,
+    /// Maximum number of function evaluations before the termination. By default, it is
+    /// `100 * n` for [Trf](LeastSquaresMethod::Trf), and for [Lm](LeastSquaresMethod::Lm)
+    /// `100 * n` if `jac` is given and `100 * n * (n + 1)` otherwise, since it counts the
+    /// evaluations of the finite differences.
+    pub max_nfev: Option<usize>,
+    /// Value of soft margin between inlier and outlier residuals, `1.0` by default. The loss is
+    /// evaluated as `rho(f^2) = C^2 * rho(f^2 / C^2)`, with `C = f_scale`.
+    pub f_scale: Option<F>,
+    /// Relative step size of the finite differences of the Jacobian, if `jac` is not given. The
+    /// step is `diff_step * max(1, |x|)`, with `diff_step` the square root of the machine
+    /// epsilon by default.
+    pub diff_step: Option<F>,
+}
+
+/// Result of [least_squares].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeastSquaresResult<F> {
+    /// Solution found.
+    pub x: Array1<F>,
+    /// Value of the cost function at the solution.
+    pub cost: F,
+    /// Vector of residuals at the solution.
+    pub fun: Array1<F>,
+    /// Jacobian matrix of the residuals at the solution, of shape `(m, n)`.
+    pub jac: Array2<F>,
+    /// Gradient of the cost function at the solution.
+    pub grad: Array1<F>,
+    /// First-order optimality measure. For [Trf](LeastSquaresMethod::Trf), it is the infinity
+    /// norm of the gradient scaled to account for the presence of the bounds. For
+    /// [Lm](LeastSquaresMethod::Lm), it is the infinity norm of the gradient.
+    pub optimality: F,
+    /// Whether each variable is at its lower bound (-1), its upper bound (1), or not at a bound
+    /// (0).
+    pub active_mask: Array1<i8>,
+    /// Number of evaluations of the residuals.
+    pub nfev: usize,
+    /// Number of evaluations of the Jacobian.
+    pub njev: usize,
+    /// Reason for the termination: 0 if the maximum number of function evaluations is exceeded,
+    /// 1 if the `gtol` condition is satisfied, 2 if the `ftol` condition is satisfied, 3 if the
+    /// `xtol` condition is satisfied, and 4 if both the `ftol` and `xtol` conditions are.
+    pub status: usize,
+    /// Verbal description of the termination reason.
+    pub message: String,
+    /// True if one of the convergence criteria is satisfied (`status > 0`).
+    pub success: bool,
+}
+
+impl<F: Float> LeastSquaresResult<F> {
+    /// Result of the termination `status`.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new<G>(
+        x: Array1<F>,
+        cost: F,
+        fun: Array1<F>,
+        jac: Array2<F>,
+        grad: Array1<F>,
+        optimality: F,
+        active_mask: Array1<i8>,
+        residuals: &Residuals<'_, F, G>,
+        nfev: usize,
+        status: usize,
+    ) -> Self {
+        let message = match status {
+            0 => "The maximum number of function evaluations is exceeded.",
+            1 => "`gtol` termination condition is satisfied.",
+            2 => "`ftol` termination condition is satisfied.",
+            3 => "`xtol` termination condition is satisfied.",
+            _ => "Both `ftol` and `xtol` termination conditions are satisfied.",
+        };
+        LeastSquaresResult {
+            x,
+            cost,
+            fun,
+            jac,
+            grad,
+            optimality,
+            active_mask,
+            nfev,
+            njev: residuals.njev,
+            status,
+            message: String::from(message),
+            success: status > 0,
+        }
+    }
+}
+
+/// Solve a nonlinear least-squares problem with bounds on the variables.
+///
+/// Given the residuals `f(x)` (an m-D real function of n real variables) and the loss function
+/// `rho(s)` (a scalar function), `least_squares` finds a local minimum of the cost function
+/// `F(x) = 0.5 * sum(rho(f_i(x)^2), i = 0, ..., m - 1)` subject to `lb <= x <= ub`.
+///
+/// ## Parameters
+/// * `fun`: Function which computes the vector of residuals, of shape `(m,)`, of the 1-D array
+///   of the `n` variables.
+/// * `x0`: Initial guess on the independent variables, of shape `(n,)`.
+/// * `jac`: Method of computing the Jacobian matrix, of shape `(m, n)`, whose element `(i, j)`
+///   is the partial derivative of `f[i]` with respect to `x[j]`. If it is `None`, the Jacobian
+///   is estimated by forward differences with the relative step `diff_step`.
+/// * `bounds`: Lower and upper bounds on every variable, which may be infinite. Unbounded by
+///   default.
+/// * `method`: Algorithm to perform the minimization, [Trf](LeastSquaresMethod::Trf) by
+///   default.
+/// * `loss`: Determines the loss function, [Linear](Loss::Linear) by default. The robust loss
+///   functions reduce the influence of outliers on the solution.
+/// * `options`: [LeastSquaresOptions] of the tolerances and limits.
+///
+/// ## Returns
+/// The [LeastSquaresResult], with the solution `x`.
+///
+/// ## Errors
+/// If `x0` is empty or not finite, or the residuals at `x0` are empty or not finite, or the
+/// Jacobian does not have the shape `(m, n)`, or the bounds do not match `x0`, or a lower bound
+/// is not less than an upper bound, or `x0` is not within the bounds. If `f_scale` is not
+/// positive or every tolerance is below the machine epsilon. If the
+/// [Lm](LeastSquaresMethod::Lm) method is given bounds, a robust loss or fewer residuals than
+/// variables.
+///
+/// ## Notes
+/// Method [Trf](LeastSquaresMethod::Trf) (Trust Region Reflective) is motivated by the process
+/// of solving a system of equations, which constitute the first-order optimality condition for
+/// a bound-constrained minimization problem as formulated in [1]. The algorithm iteratively
+/// solves trust-region subproblems augmented by a special diagonal quadratic term and with
+/// trust-region shape determined by the distance from the bounds and the direction of the
+/// gradient. The subproblems are solved exactly by a singular value decomposition. The iterates
+/// stay strictly feasible, and a step that would cross a bound is reflected from it [2].
+///
+/// Method [Lm](LeastSquaresMethod::Lm) is the Levenberg-Marquardt algorithm as implemented in
+/// MINPACK [3], with the exact solution of the trust-region subproblem of the Trf method in place
+/// of the QR factorization of MINPACK. It does not handle bounds and sparse Jacobians, but it is
+/// usually the most efficient method for small unconstrained problems.
+///
+/// A robust loss is handled by scaling the residuals and the Jacobian so that the subproblems
+/// are those of the standard least-squares problem, as in [4]. `fun` and `jac` of the result are
+/// the values of the residuals and of their Jacobian, not the scaled ones.
+///
+/// ## References
+/// 1. M. A. Branch, T. F. Coleman, and Y. Li, "A Subspace, Interior, and Conjugate Gradient
+///    Method for Large-Scale Bound-Constrained Minimization Problems," SIAM Journal on
+///    Scientific Computing, Vol. 21, Number 1, pp 1-23, 1999.
+/// 2. T. F. Coleman and Y. Li, "An Interior Trust Region Approach for Nonlinear Minimization
+///    Subject to Bounds," SIAM Journal on Optimization, 6 (2), pp. 418-445, 1996.
+/// 3. J. J. More, "The Levenberg-Marquardt Algorithm: Implementation and Theory," Numerical
+///    Analysis, ed. G. A. Watson, Lecture Notes in Mathematics 630, Springer Verlag, pp.
+///    105-116, 1977.
+/// 4. B. Triggs et. al., "Bundle Adjustment - A Modern Synthesis", Proceedings of the
+///    International Workshop on Vision Algorithms: Theory and Practice, pp. 298-372, 1999.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.optimize import least_squares
+/// def fun_rosenbrock(x):
+///     return np.array([10 * (x[1] - x[0]**2), (1 - x[0])])
+/// res = least_squares(fun_rosenbrock, [2, 2])
+/// res.x, res.cost
+/// # (array([1., 1.]), 9.866924291084687e-30)
+/// res = least_squares(fun_rosenbrock, [2, 2], bounds=([-np.inf, 1.5], np.inf))
+/// res.x, res.cost
+/// # (array([1.22437075, 1.5       ]), 0.025213093946805685)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{array, ArrayView1};
+/// use sci_rs::optimize::least_squares;
+///
+/// let fun_rosenbrock = |x: ArrayView1<f64>| array![10. * (x[1] - x[0] * x[0]), 1. - x[0]];
+/// let x0 = array![2., 2.];
+/// let res = least_squares(fun_rosenbrock, &x0, None, None, None, None, Default::default())
+///     .unwrap();
+/// assert_abs_diff_eq!(res.x.as_slice().unwrap(), [1., 1.].as_slice(), epsilon = 1e-8);
+/// assert_abs_diff_eq!(res.cost, 0., epsilon = 1e-16);
+///
+/// let bounds = [(f64::NEG_INFINITY, f64::INFINITY), (1.5, f64::INFINITY)];
+/// let res = least_squares(fun_rosenbrock, &x0, None, Some(&bounds), None, None, Default::default())
+///     .unwrap();
+/// assert_abs_diff_eq!(res.x.as_slice().unwrap(), [1.22437075, 1.5].as_slice(), epsilon = 1e-6);
+/// assert_abs_diff_eq!(res.cost, 0.025213093946805685, epsilon = 1e-10);
+/// assert_eq!(res.active_mask, array![0, -1]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn least_squares<F, S>(
+    fun: impl FnMut(ArrayView1<F>) -> Array1<F>,
+    x0: &ArrayBase<S, Ix1>,
+    jac: Option<&mut dyn FnMut(ArrayView1<F>) -> Array2<F>>,
+    bounds: Option<&[(F, F)]>,
+    method: Option<LeastSquaresMethod>,
+    loss: Option<Loss>,
+    options: LeastSquaresOptions<F>,
+) -> Result<LeastSquaresResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let n = x0.len();
+    if n == 0 || x0.iter().any(|x| !x.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "x0".into(),
+            reason: "x0 must be a non-empty array of finite values.".into(),
+        });
+    }
+    let method = method.unwrap_or_default();
+    let loss = loss.unwrap_or_default();
+    let f_scale = options.f_scale.unwrap_or_else(F::one);
+    if f_scale.is_nan() || f_scale <= F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "f_scale".into(),
+            reason: "`f_scale` must be positive.".into(),
+        });
+    }
+    let eps = F::epsilon();
+    let tol = |tol: Option<F>| tol.unwrap_or_else(|| F::from(1e-8).unwrap());
+    let (ftol, xtol, gtol) = (tol(options.ftol), tol(options.xtol), tol(options.gtol));
+    if ftol < eps && xtol < eps && gtol < eps {
+        return Err(Error::InvalidArg {
+            arg: "ftol".into(),
+            reason: "At least one of the tolerances must be higher than machine epsilon.".into(),
+        });
+    }
+    let (lb, ub): (Array1<F>, Array1<F>) = match bounds {
+        Some(bounds) => {
+            if bounds.len() != n {
+                return Err(Error::InvalidArg {
+                    arg: "bounds".into(),
+                    reason: "The number of bounds is not compatible with the length of `x0`."
+                        .into(),
+                });
+            }
+            if bounds
+                .iter()
+                .any(|(lb, ub)| lb.is_nan() || ub.is_nan() || lb >= ub)
+            {
+                return Err(Error::InvalidArg {
+                    arg: "bounds".into(),
+                    reason: "Each lower bound must be strictly less than each upper bound.".into(),
+                });
+            }
+            let (lb, ub): (Vec<F>, Vec<F>) = bounds.iter().copied().unzip();
+            (Array1::from(lb), Array1::from(ub))
+        }
+        None => (
+            Array1::from_elem(n, F::neg_infinity()),
+            Array1::from_elem(n, F::infinity()),
+        ),
+    };
+    if x0
+        .iter()
+        .zip(&lb)
+        .zip(&ub)
+        .any(|((x, l), u)| x < l || x > u)
+    {
+        return Err(Error::InvalidArg {
+            arg: "x0".into(),
+            reason: "`x0` is infeasible.".into(),
+        });
+    }
+    let bounded = lb.iter().chain(&ub).any(|b| b.is_finite());
+    if method == LeastSquaresMethod::Lm {
+        if bounded {
+            return Err(Error::InvalidArg {
+                arg: "bounds".into(),
+                reason: "Method 'lm' doesn't support bounds.".into(),
+            });
+        }
+        if loss != Loss::Linear {
+            return Err(Error::InvalidArg {
+                arg: "loss".into(),
+                reason: "Method 'lm' supports only 'linear' loss function.".into(),
+            });
+        }
+    }
+
+    let mut residuals = Residuals {
+        fun,
+        jac,
+        diff_step: options.diff_step.unwrap_or_else(|| eps.sqrt()),
+        ub: bounded.then(|| ub.clone()),
+        nfev: 0,
+        fd_nfev: 0,
+        njev: 0,
+    };
+    let x0 = match method {
+        LeastSquaresMethod::Trf => make_strictly_feasible(x0.to_owned(), &lb, &ub, true),
+        LeastSquaresMethod::Lm => x0.to_owned(),
+    };
+    let f0 = residuals.fun(&x0);
+    let m = f0.len();
+    if m == 0 || f0.iter().any(|f| !f.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "fun".into(),
+            reason: "Residuals are not finite in the initial point.".into(),
+        });
+    }
+    if method == LeastSquaresMethod::Lm && m < n {
+        return Err(Error::InvalidArg {
+            arg: "fun".into(),
+            reason:
+                "Method 'lm' doesn't work when the number of residuals is less than the number of \
+             variables."
+                    .into(),
+        });
+    }
+    let j0 = residuals.jac(&x0, &f0);
+    if j0.dim() != (m, n) {
+        return Err(Error::InvalidArg {
+            arg: "jac".into(),
+            reason: "The return value of `jac` has wrong shape.".into(),
+        });
+    }
+    let loss = LossFunction { loss, f_scale };
+    let tols = (ftol, xtol, gtol);
+    Ok(match method {
+        LeastSquaresMethod::Trf => {
+            let max_nfev = options.max_nfev.unwrap_or(100 * n);
+            trf(&mut residuals, x0, f0, j0, &lb, &ub, &loss, tols, max_nfev)
+        }
+        LeastSquaresMethod::Lm => {
+            let max_nfev = options.max_nfev.unwrap_or(if residuals.has_jac() {
+                100 * n
+            } else {
+                100 * n * (n + 1)
+            });
+            lm(&mut residuals, x0, f0, j0, tols, max_nfev)
+        }
+    })
+}
+
+/// Gradient `J^T f` of the cost.
+pub(super) fn compute_grad<F: Float>(jac: &Array2<F>, f: &Array1<F>) -> Array1<F> {
+    rmatvec(jac, f)
+}
+
+/// Move the variables on or beyond the bounds inside them, by the relative step `1e-10` if
+/// `rstep` is set and by one ulp otherwise.
+pub(super) fn make_strictly_feasible<F: Float>(
+    mut x: Array1<F>,
+    lb: &Array1<F>,
+    ub: &Array1<F>,
+    rstep: bool,
+) -> Array1<F> {
+    let active = find_active_constraints(&x, lb, ub, rstep.then(|| F::from(1e-10).unwrap()));
+    let step = |b: F| {
+        if rstep {
+            F::from(1e-10).unwrap() * b.abs().max(F::one())
+        } else {
+            (b.abs() * F::epsilon()).max(F::min_positive_value())
+        }
+    };
+    for i in 0..x.len() {
+        match active[i] {
+            -1 => x[i] = lb[i] + step(lb[i]),
+            1 => x[i] = ub[i] - step(ub[i]),
+            _ => {}
+        }
+        if x[i] < lb[i] || x[i] > ub[i] {
+            x[i] = (lb[i] + ub[i]) / F::from(2).unwrap();
+        }
+    }
+    x
+}
+
+/// Determine which constraints are active at `x`: -1 for a lower bound, 1 for an upper bound
+/// and 0 otherwise, either exactly or within the relative tolerance `rtol`.
+pub(super) fn find_active_constraints<F: Float>(
+    x: &Array1<F>,
+    lb: &Array1<F>,
+    ub: &Array1<F>,
+    rtol: Option<F>,
+) -> Array1<i8> {
+    Array1::from_shape_fn(x.len(), |i| {
+        let (x, lb, ub) = (x[i], lb[i], ub[i]);
+        let Some(rtol) = rtol else {
+            return if x <= lb {
+                -1
+            } else if x >= ub {
+                1
+            } else {
+                0
+            };
+        };
+        let lower_dist = x - lb;
+        let upper_dist = ub - x;
+        let lower_threshold = rtol * lb.abs().max(F::one());
+        let upper_threshold = rtol * ub.abs().max(F::one());
+        if lb.is_finite() && lower_dist <= upper_dist.min(lower_threshold) {
+            -1
+        } else if ub.is_finite() && upper_dist <= lower_dist.min(upper_threshold) {
+            1
+        } else {
+            0
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn losses() {
+        let f = array![0.5, 2.];
+        let linear = LossFunction {
+            loss: Loss::Linear,
+            f_scale: 1.,
+        };
+        assert_abs_diff_eq!(linear.cost(&f), 2.125);
+        let expected = [
+            (
+                Loss::SoftL1,
+                0.5 * (2. * (1.25f64.sqrt() - 1.) + 2. * (5f64.sqrt() - 1.)),
+            ),
+            (Loss::Huber, 0.5 * (0.25 + 3.)),
+            (Loss::Cauchy, 0.5 * (1.25f64.ln() + 5f64.ln())),
+            (Loss::Arctan, 0.5 * (0.25f64.atan() + 4f64.atan())),
+        ];
+        for (loss, cost) in expected {
+            let lf = LossFunction { loss, f_scale: 1. };
+            assert_abs_diff_eq!(lf.cost(&f), cost, epsilon = 1e-12);
+            // The scaled residuals give the gradient of the cost.
+            let jac = Array2::eye(2);
+            let (j, fs) = lf.scale(&jac, &f);
+            let grad = compute_grad(&j, &fs);
+            for i in 0..2 {
+                let h = 1e-7;
+                let mut fh = f.clone();
+                fh[i] += h;
+                assert_abs_diff_eq!(grad[i], (lf.cost(&fh) - cost) / h, epsilon = 1e-5);
+            }
+        }
+        // f_scale
+        let lf = LossFunction {
+            loss: Loss::Cauchy,
+            f_scale: 2.,
+        };
+        assert_abs_diff_eq!(
+            lf.cost(&f),
+            0.5 * 4. * ((1. + 0.0625f64).ln() + 2f64.ln()),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn robust_fit() {
+        // A line with one outlier, which the soft l1 loss mostly ignores.
+        let t = Array1::linspace(0., 9., 10);
+        let mut y = t.mapv(|t| 2. * t + 1.);
+        y[4] += 30.;
+        let fun = |x: ArrayView1<f64>| Array1::from_shape_fn(10, |i| x[0] * t[i] + x[1] - y[i]);
+        let x0 = array![1., 0.];
+        let linear = least_squares(fun, &x0, None, None, None, None, Default::default()).unwrap();
+        assert!(linear.success);
+        assert!((linear.x[1] - 1.).abs() > 1.);
+
+        let options = LeastSquaresOptions {
+            f_scale: Some(0.1),
+            ..Default::default()
+        };
+        for loss in [Loss::SoftL1, Loss::Huber, Loss::Cauchy, Loss::Arctan] {
+            let res =
+                least_squares(fun, &x0, None, None, None, Some(loss), options.clone()).unwrap();
+            assert!(res.success);
+            assert_abs_diff_eq!(res.x[0], 2., epsilon = 0.05);
+            assert_abs_diff_eq!(res.x[1], 1., epsilon = 0.2);
+            assert_abs_diff_eq!(res.fun[4], -30., epsilon = 1.);
+        }
+    }
+
+    #[test]
+    fn invalid_args() {
+        let fun = |x: ArrayView1<f64>| array![x[0] - 1., x[1] - 2.];
+        let x0 = array![0., 0.];
+        let run = |x0: &Array1<f64>, bounds: Option<&[(f64, f64)]>, method, loss, options| {
+            least_squares(fun, x0, None, bounds, method, loss, options)
+        };
+        assert!(run(&array![], None, None, None, Default::default()).is_err());
+        assert!(run(&array![f64::NAN, 0.], None, None, None, Default::default()).is_err());
+        assert!(run(&x0, Some(&[(0., 1.)]), None, None, Default::default()).is_err());
+        assert!(run(
+            &x0,
+            Some(&[(0., 1.), (1., 1.)]),
+            None,
+            None,
+            Default::default()
+        )
+        .is_err());
+        assert!(run(
+            &x0,
+            Some(&[(0., 1.), (1., 2.)]),
+            None,
+            None,
+            Default::default()
+        )
+        .is_err());
+        let lm = Some(LeastSquaresMethod::Lm);
+        assert!(run(&x0, Some(&[(-1., 1.); 2]), lm, None, Default::default()).is_err());
+        assert!(run(&x0, None, lm, Some(Loss::Huber), Default::default()).is_err());
+        let options = LeastSquaresOptions {
+            f_scale: Some(0.),
+            ..Default::default()
+        };
+        assert!(run(&x0, None, None, None, options).is_err());
+        let options = LeastSquaresOptions {
+            ftol: Some(0.),
+            xtol: Some(0.),
+            gtol: Some(0.),
+            ..Default::default()
+        };
+        assert!(run(&x0, None, None, None, options).is_err());
+
+        let mut jac = |_: ArrayView1<f64>| Array2::eye(3);
+        assert!(least_squares(
+            fun,
+            &x0,
+            Some(&mut jac),
+            None,
+            None,
+            None,
+            Default::default()
+        )
+        .is_err());
+        let fun = |x: ArrayView1<f64>| array![x[0] + x[1]];
+        assert!(least_squares(fun, &x0, None, None, lm, None, Default::default()).is_err());
+        let fun = |_: ArrayView1<f64>| array![f64::INFINITY];
+        assert!(least_squares(fun, &x0, None, None, None, None, Default::default()).is_err());
+    }
+}
@@ -0,0 +1,267 @@
+use alloc::vec::Vec;
+use ndarray::{Array1, ArrayView1};
+use num_traits::Float;
+
+pub(super) use crate::linalg::dot;
+
+/// Signature of the gradient of an objective function.
+pub(super) type Jacobian<'a, F> = &'a mut dyn FnMut(ArrayView1<F>) -> Array1<F>;
+
+/// An objective function and its gradient, which is approximated by forward differences with the
+/// absolute step `eps` if it is not given. The steps are reversed where they would cross an
+/// upper bound.
+///
+/// The last value of the function is kept, so that it is not evaluated again at the same point.
+pub(super) struct ScalarFunction<'a, F, G> {
+    fun: G,
+    jac: Option<Jacobian<'a, F>>,
+    eps: F,
+    bounds: Option<Vec<(F, F)>>,
+    /// Number of evaluations of the function, including those of the finite differences.
+    pub(super) nfev: usize,
+    /// Number of evaluations of the gradient.
+    pub(super) njev: usize,
+    last: Option<(Array1<F>, F)>,
+}
+
+impl<'a, F: Float, G: FnMut(ArrayView1<F>) -> F> ScalarFunction<'a, F, G> {
+    pub(super) fn new(
+        fun: G,
+        jac: Option<Jacobian<'a, F>>,
+        eps: F,
+        bounds: Option<Vec<(F, F)>>,
+    ) -> Self {
+        ScalarFunction {
+            fun,
+            jac,
+            eps,
+            bounds,
+            nfev: 0,
+            njev: 0,
+            last: None,
+        }
+    }
+
+    pub(super) fn fun(&mut self, x: &Array1<F>) -> F {
+        match &self.last {
+            Some((last, f)) if last == x => *f,
+            _ => {
+                self.nfev += 1;
+                let f = (self.fun)(x.view());
+                self.last = Some((x.clone(), f));
+                f
+            }
+        }
+    }
+
+    pub(super) fn grad(&mut self, x: &Array1<F>) -> Array1<F> {
+        self.njev += 1;
+        if let Some(jac) = &mut self.jac {
+            return jac(x.view());
+        }
+        let f0 = self.fun(x);
+        let mut xh = x.clone();
+        Array1::from_shape_fn(x.len(), |i| {
+            let mut h = self.eps;
+            if self.bounds.as_ref().is_some_and(|b| x[i] + h > b[i].1) {
+                h = -h;
+            }
+            xh[i] = x[i] + h;
+            let dx = xh[i] - x[i];
+            self.nfev += 1;
+            let df = (self.fun)(xh.view()) - f0;
+            xh[i] = x[i];
+            df / dx
+        })
+    }
+}
+
+/// Step of a line search, with the function value there, and the gradient if it was evaluated.
+pub(super) struct Step<F> {
+    pub(super) alpha: F,
+    pub(super) phi: F,
+    pub(super) grad: Option<Array1<F>>,
+}
+
+/// The objective function on the line `x + alpha * p`.
+struct Line<'s, 'a, F, G> {
+    sf: &'s mut ScalarFunction<'a, F, G>,
+    x: &'s Array1<F>,
+    p: &'s Array1<F>,
+    /// Gradient at the last point where the derivative was evaluated.
+    grad: Option<Array1<F>>,
+}
+
+impl<F: Float, G: FnMut(ArrayView1<F>) -> F> Line<'_, '_, F, G> {
+    fn point(&self, alpha: F) -> Array1<F> {
+        self.x + &self.p.mapv(|p| alpha * p)
+    }
+
+    fn phi(&mut self, alpha: F) -> F {
+        let x = self.point(alpha);
+        self.sf.fun(&x)
+    }
+
+    fn derphi(&mut self, alpha: F) -> F {
+        let x = self.point(alpha);
+        let grad = self.sf.grad(&x);
+        let derphi = dot(&grad, self.p);
+        self.grad = Some(grad);
+        derphi
+    }
+
+    fn step(self, alpha: F, phi: F, derphi: Option<F>) -> Step<F> {
+        Step {
+            alpha,
+            phi,
+            grad: derphi.and(self.grad),
+        }
+    }
+}
+
+/// Find a step `alpha` along the descent direction `p` from `x` that satisfies the strong Wolfe
+/// conditions, starting from `alpha1` and doubling it up to `amax`.
+///
+/// `phi0` and `derphi0` are the function value and the directional derivative at `x`. Returns
+/// `None` if no suitable step is found, or the last trial step if `maxiter` is reached.
+///
+/// This is algorithm 3.5 of Nocedal and Wright, Numerical Optimization, with the zoom by cubic
+/// and quadratic interpolation of scipy's `line_search_wolfe2`.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn line_search_wolfe2<F: Float, G: FnMut(ArrayView1<F>) -> F>(
+    sf: &mut ScalarFunction<'_, F, G>,
+    x: &Array1<F>,
+    p: &Array1<F>,
+    phi0: F,
+    derphi0: F,
+    alpha1: F,
+    c1: F,
+    c2: F,
+    amax: F,
+    maxiter: usize,
+) -> Option<Step<F>> {
+    let mut line = Line {
+        sf,
+        x,
+        p,
+        grad: None,
+    };
+    let two = F::from(2).unwrap();
+    let mut alpha0 = F::zero();
+    let mut alpha1 = alpha1.min(amax);
+    let mut phi_a0 = phi0;
+    let mut phi_a1 = line.phi(alpha1);
+    let mut derphi_a0 = derphi0;
+    for i in 0..maxiter {
+        if alpha1.is_zero() || alpha0 > amax {
+            return None;
+        }
+        if phi_a1 > phi0 + c1 * alpha1 * derphi0 || (phi_a1 >= phi_a0 && i > 0) {
+            let (alpha, phi, derphi) = zoom(
+                &mut line, alpha0, alpha1, phi_a0, phi_a1, derphi_a0, phi0, derphi0, c1, c2,
+            )?;
+            return Some(line.step(alpha, phi, Some(derphi)));
+        }
+        let derphi_a1 = line.derphi(alpha1);
+        if derphi_a1.abs() <= -c2 * derphi0 {
+            return Some(line.step(alpha1, phi_a1, Some(derphi_a1)));
+        }
+        if derphi_a1 >= F::zero() {
+            let (alpha, phi, derphi) = zoom(
+                &mut line, alpha1, alpha0, phi_a1, phi_a0, derphi_a1, phi0, derphi0, c1, c2,
+            )?;
+            return Some(line.step(alpha, phi, Some(derphi)));
+        }
+        alpha0 = alpha1;
+        alpha1 = (two * alpha1).min(amax);
+        phi_a0 = phi_a1;
+        phi_a1 = line.phi(alpha1);
+        derphi_a0 = derphi_a1;
+    }
+    Some(line.step(alpha1, phi_a1, None))
+}
+
+/// Zoom into the interval between `a_lo` and `a_hi`, which contains steps that satisfy the
+/// strong Wolfe conditions.
+#[allow(clippy::too_many_arguments)]
+fn zoom<F: Float, G: FnMut(ArrayView1<F>) -> F>(
+    line: &mut Line<'_, '_, F, G>,
+    mut a_lo: F,
+    mut a_hi: F,
+    mut phi_lo: F,
+    mut phi_hi: F,
+    mut derphi_lo: F,
+    phi0: F,
+    derphi0: F,
+    c1: F,
+    c2: F,
+) -> Option<(F, F, F)> {
+    let delta1 = F::from(0.2).unwrap();
+    let delta2 = F::from(0.1).unwrap();
+    let half = F::from(0.5).unwrap();
+    let mut phi_rec = phi0;
+    let mut a_rec = F::zero();
+    for i in 0..=10 {
+        let dalpha = a_hi - a_lo;
+        let (a, b) = if dalpha < F::zero() {
+            (a_hi, a_lo)
+        } else {
+            (a_lo, a_hi)
+        };
+        // Minimize the cubic interpolant, falling back to the quadratic one and to bisection
+        // when the minimizer is too close to the ends of the interval.
+        let cchk = delta1 * dalpha;
+        let cubic = (i > 0)
+            .then(|| cubicmin(a_lo, phi_lo, derphi_lo, a_hi, phi_hi, a_rec, phi_rec))
+            .flatten()
+            .filter(|&a_j| a_j <= b - cchk && a_j >= a + cchk);
+        let a_j = cubic.unwrap_or_else(|| {
+            let qchk = delta2 * dalpha;
+            quadmin(a_lo, phi_lo, derphi_lo, a_hi, phi_hi)
+                .filter(|&a_j| a_j <= b - qchk && a_j >= a + qchk)
+                .unwrap_or(a_lo + half * dalpha)
+        });
+
+        let phi_aj = line.phi(a_j);
+        if phi_aj > phi0 + c1 * a_j * derphi0 || phi_aj >= phi_lo {
+            (phi_rec, a_rec) = (phi_hi, a_hi);
+            (a_hi, phi_hi) = (a_j, phi_aj);
+        } else {
+            let derphi_aj = line.derphi(a_j);
+            if derphi_aj.abs() <= -c2 * derphi0 {
+                return Some((a_j, phi_aj, derphi_aj));
+            }
+            if derphi_aj * (a_hi - a_lo) >= F::zero() {
+                (phi_rec, a_rec) = (phi_hi, a_hi);
+                (a_hi, phi_hi) = (a_lo, phi_lo);
+            } else {
+                (phi_rec, a_rec) = (phi_lo, a_lo);
+            }
+            (a_lo, phi_lo, derphi_lo) = (a_j, phi_aj, derphi_aj);
+        }
+    }
+    None
+}
+
+/// Minimizer of the cubic through `(a, fa)`, `(b, fb)` and `(c, fc)` with derivative `fpa` at
+/// `a`, if it exists.
+fn cubicmin<F: Float>(a: F, fa: F, fpa: F, b: F, fb: F, c: F, fc: F) -> Option<F> {
+    let db = b - a;
+    let dc = c - a;
+    let denom = (db * dc).powi(2) * (db - dc);
+    let (rb, rc) = (fb - fa - fpa * db, fc - fa - fpa * dc);
+    let ca = (dc * dc * rb - db * db * rc) / denom;
+    let cb = (-dc.powi(3) * rb + db.powi(3) * rc) / denom;
+    let radical = cb * cb - F::from(3).unwrap() * ca * fpa;
+    let xmin = a + (-cb + radical.sqrt()) / (F::from(3).unwrap() * ca);
+    xmin.is_finite().then_some(xmin)
+}
+
+/// Minimizer of the quadratic through `(a, fa)` and `(b, fb)` with derivative `fpa` at `a`, if
+/// it exists.
+fn quadmin<F: Float>(a: F, fa: F, fpa: F, b: F, fb: F) -> Option<F> {
+    let db = b - a;
+    let cb = (fb - fa - fpa * db) / (db * db);
+    let xmin = a - fpa / (F::from(2).unwrap() * cb);
+    xmin.is_finite().then_some(xmin)
+}
@@ -0,0 +1,724 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ndarray::{Array1, Array2, ArrayBase, ArrayView1, ArrayView2, Axis, Data, Ix1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Result of [linprog].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinprogResult<F> {
+    /// The values of the decision variables that minimize the objective function while
+    /// satisfying the constraints, or the last iterate if the optimization failed.
+    pub x: Array1<F>,
+    /// The optimal value of the objective function `c @ x`.
+    pub fun: F,
+    /// The values of the slack variables `b_ub - A_ub @ x`, which are nonnegative for the
+    /// satisfied inequality constraints.
+    pub slack: Array1<F>,
+    /// The residuals of the equality constraints, `b_eq - A_eq @ x`.
+    pub con: Array1<F>,
+    /// True when the algorithm succeeds in finding an optimal solution.
+    pub success: bool,
+    /// An integer representing the exit status of the algorithm: 0 if the optimization
+    /// terminated successfully, 1 if the iteration limit was reached, 2 if the problem appears
+    /// to be infeasible, 3 if the problem appears to be unbounded, and 4 if numerical
+    /// difficulties were encountered.
+    pub status: usize,
+    /// The total number of iterations performed in all phases.
+    pub nit: usize,
+    /// A string descriptor of the exit status of the algorithm.
+    pub message: String,
+}
+
+/// Linear programming: minimize a linear objective function subject to linear equality and
+/// inequality constraints.
+///
+/// Linear programming solves problems of the following form:
+///
+/// ```text
+/// min_x c @ x
+/// such that A_ub @ x <= b_ub
+///           A_eq @ x == b_eq
+///           l <= x <= u
+/// ```
+///
+/// where `x` is a vector of decision variables, `c`, `b_ub`, `b_eq`, `l` and `u` are vectors,
+/// and `A_ub` and `A_eq` are matrices.
+///
+/// ## Parameters
+/// * `c`: The coefficients of the linear objective function to be minimized, of shape `(n,)`.
+/// * `a_ub`: The inequality constraint matrix, of shape `(m_ub, n)`. Each row specifies the
+///   coefficients of a linear inequality constraint on `x`.
+/// * `b_ub`: The inequality constraint vector, of shape `(m_ub,)`. Each element represents an
+///   upper bound on the corresponding value of `A_ub @ x`.
+/// * `a_eq`: The equality constraint matrix, of shape `(m_eq, n)`.
+/// * `b_eq`: The equality constraint vector, of shape `(m_eq,)`. Each element of `A_eq @ x`
+///   must equal the corresponding element of `b_eq`.
+/// * `bounds`: The `(min, max)` pairs of each element of `x`, which may be infinite. By
+///   default, every variable is nonnegative, with the bounds `(0, inf)`.
+/// * `maxiter`: The maximum number of iterations to perform in all phases, 5000 by default.
+///
+/// ## Returns
+/// The [LinprogResult], with `status` 0 on success.
+///
+/// ## Errors
+/// If `c` is empty or not finite, or the constraints are not finite or do not match `c`, or
+/// only one of a matrix and a vector of constraints is given, or a lower bound is greater than
+/// an upper bound, or a lower bound is `inf` or an upper bound is `-inf`.
+///
+/// ## Notes
+/// The bounds are removed by shifting and mirroring the variables, and splitting the free ones
+/// into their positive and negative parts, so that the problem is in the standard form
+/// `min c @ x` such that `A @ x == b`, `x >= 0`, with the slack variables of the inequalities
+/// and of the finite upper bounds. It is solved by the two-phase simplex method [1] on a dense
+/// tableau: the first phase minimizes the sum of artificial variables to find a basic feasible
+/// solution, and the second one minimizes the objective from there. The entering and leaving
+/// variables follow Bland's rule [2], which prevents cycling on degenerate problems.
+///
+/// The method is best suited to small and dense problems, and it does not implement the HiGHS
+/// solvers that are the default of scipy, so the solution may be another vertex when the
+/// optimum is not unique, and the number of iterations differs.
+///
+/// ## References
+/// 1. Dantzig, George B., Linear programming and extensions. Rand Corporation Research Study
+///    Princeton Univ. Press, Princeton, NJ, 1963
+/// 2. Bland, Robert G. New finite pivoting rules for the simplex method. Mathematics of
+///    Operations Research (2), 1977: pp. 103-107.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.optimize import linprog
+/// c = [-1, 4]
+/// A = [[-3, 1], [1, 2]]
+/// b = [6, 4]
+/// x0_bounds = (None, None)
+/// x1_bounds = (-3, None)
+/// res = linprog(c, A_ub=A, b_ub=b, bounds=[x0_bounds, x1_bounds])
+/// res.fun, res.x, res.slack
+/// # (-22.0, array([10., -3.]), array([39.,  0.]))
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::optimize::linprog;
+///
+/// let c = array![-1., 4.];
+/// let a = array![[-3., 1.], [1., 2.]];
+/// let b = array![6., 4.];
+/// let bounds = [(f64::NEG_INFINITY, f64::INFINITY), (-3., f64::INFINITY)];
+/// let res = linprog(&c, Some(a.view()), Some(b.view()), None, None, Some(&bounds), None).unwrap();
+/// assert_eq!(res.status, 0);
+/// assert_abs_diff_eq!(res.fun, -22.);
+/// assert_abs_diff_eq!(res.x[0], 10.);
+/// assert_abs_diff_eq!(res.x[1], -3.);
+/// assert_abs_diff_eq!(res.slack[0], 39.);
+/// assert_abs_diff_eq!(res.slack[1], 0.);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn linprog<F, S>(
+    c: &ArrayBase<S, Ix1>,
+    a_ub: Option<ArrayView2<F>>,
+    b_ub: Option<ArrayView1<F>>,
+    a_eq: Option<ArrayView2<F>>,
+    b_eq: Option<ArrayView1<F>>,
+    bounds: Option<&[(F, F)]>,
+    maxiter: Option<usize>,
+) -> Result<LinprogResult<F>>
+where
+    F: Float + 'static,
+    S: Data<Elem = F>,
+{
+    let n = c.len();
+    if n == 0 || c.iter().any(|c| !c.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "c".into(),
+            reason: "c must be a non-empty array of finite values.".into(),
+        });
+    }
+    let constraints = |a: Option<ArrayView2<F>>, b: Option<ArrayView1<F>>, name: &str| {
+        let (a, b) = match (a, b) {
+            (Some(a), Some(b)) => (a.to_owned(), b.to_owned()),
+            (None, None) => (Array2::zeros((0, n)), Array1::zeros(0)),
+            _ => {
+                return Err(Error::InvalidArg {
+                    arg: name.into(),
+                    reason: "The constraint matrix and vector must be given together.".into(),
+                })
+            }
+        };
+        if a.ncols() != n || a.nrows() != b.len() {
+            return Err(Error::InvalidArg {
+                arg: name.into(),
+                reason: "The constraints do not match the shape of `c`.".into(),
+            });
+        }
+        if a.iter().chain(&b).any(|v| !v.is_finite()) {
+            return Err(Error::InvalidArg {
+                arg: name.into(),
+                reason: "The constraints must not contain infs or NaNs.".into(),
+            });
+        }
+        Ok((a, b))
+    };
+    let (a_ub, b_ub) = constraints(a_ub, b_ub, "a_ub")?;
+    let (a_eq, b_eq) = constraints(a_eq, b_eq, "a_eq")?;
+    let bounds = match bounds {
+        Some(bounds) if bounds.len() != n => {
+            return Err(Error::InvalidArg {
+                arg: "bounds".into(),
+                reason: "The number of bounds is not compatible with the length of `c`.".into(),
+            });
+        }
+        Some(bounds) => bounds.to_vec(),
+        None => alloc::vec![(F::zero(), F::infinity()); n],
+    };
+    if bounds.iter().any(|&(lb, ub)| {
+        lb.is_nan() || ub.is_nan() || lb > ub || lb == F::infinity() || ub == F::neg_infinity()
+    }) {
+        return Err(Error::InvalidArg {
+            arg: "bounds".into(),
+            reason:
+                "Each lower bound must be less than its upper bound, and finite or -inf, and each \
+             upper bound finite or inf."
+                    .into(),
+        });
+    }
+
+    let standard = StandardForm::new(c.view(), &a_ub, &b_ub, &a_eq, &b_eq, &bounds);
+    let mut tableau = Tableau::new(&standard);
+    let maxiter = maxiter.unwrap_or(5000);
+    let mut nit = 0;
+    let mut status = match tableau.solve(&standard.c, &mut nit, maxiter) {
+        Outcome::Optimal => 0,
+        Outcome::IterationLimit => 1,
+        Outcome::Infeasible => 2,
+        Outcome::Unbounded => 3,
+    };
+
+    let x = standard.variables(&tableau.solution());
+    let slack = &b_ub - &a_ub.dot(&x);
+    let con = &b_eq - &a_eq.dot(&x);
+    let fun = c
+        .iter()
+        .zip(&x)
+        .fold(F::zero(), |acc, (&c, &x)| acc + c * x);
+    if status == 0 {
+        // The tolerance is relative to the magnitude of the constraints.
+        let scale = a_ub
+            .iter()
+            .chain(&b_ub)
+            .chain(&a_eq)
+            .chain(&b_eq)
+            .fold(F::one(), |acc, v| acc.max(v.abs()));
+        let tol = F::from(1e-9).unwrap().max(F::epsilon().sqrt()) * scale;
+        let violated = slack.iter().any(|&s| s < -tol)
+            || con.iter().any(|c| c.abs() > tol)
+            || x.iter()
+                .zip(&bounds)
+                .any(|(&x, &(lb, ub))| x < lb - tol || x > ub + tol);
+        if violated || !fun.is_finite() {
+            status = 4;
+        }
+    }
+    let message = match status {
+        0 => "Optimization terminated successfully.",
+        1 => "The iteration limit was reached before the algorithm converged.",
+        2 => "The algorithm terminated successfully and determined that the problem is infeasible.",
+        3 => "The algorithm terminated successfully and determined that the problem is unbounded.",
+        _ => "Numerical difficulties were encountered.",
+    };
+    Ok(LinprogResult {
+        x,
+        fun,
+        slack,
+        con,
+        success: status == 0,
+        status,
+        nit,
+        message: String::from(message),
+    })
+}
+
+/// Substitution of an original variable by nonnegative ones.
+#[derive(Clone, Copy)]
+enum Substitution<F> {
+    /// `x = lb + y`, with the finite upper bound `y <= ub - lb` if any.
+    Shift(F, Option<F>),
+    /// `x = ub - y`, for a variable with only an upper bound.
+    Mirror(F),
+    /// `x = y+ - y-`, for a free variable.
+    Free,
+}
+
+/// Problem in the standard form `min c @ y` such that `A @ y == b`, `y >= 0`, with `b >= 0`.
+struct StandardForm<F> {
+    a: Array2<F>,
+    b: Array1<F>,
+    c: Array1<F>,
+    /// Index of the first column of each original variable, and its substitution.
+    columns: Vec<(usize, Substitution<F>)>,
+    /// Rows whose slack variable can start in the basis, with its column.
+    slack_basis: Vec<Option<usize>>,
+}
+
+impl<F: Float> StandardForm<F> {
+    fn new(
+        c: ArrayView1<F>,
+        a_ub: &Array2<F>,
+        b_ub: &Array1<F>,
+        a_eq: &Array2<F>,
+        b_eq: &Array1<F>,
+        bounds: &[(F, F)],
+    ) -> Self {
+        let mut columns = Vec::with_capacity(bounds.len());
+        let mut ny = 0;
+        for &(lb, ub) in bounds {
+            let substitution = match (lb.is_finite(), ub.is_finite()) {
+                (true, true) => Substitution::Shift(lb, Some(ub - lb)),
+                (true, false) => Substitution::Shift(lb, None),
+                (false, true) => Substitution::Mirror(ub),
+                (false, false) => Substitution::Free,
+            };
+            columns.push((ny, substitution));
+            ny += if let Substitution::Free = substitution {
+                2
+            } else {
+                1
+            };
+        }
+        let upper: Vec<(usize, F)> = columns
+            .iter()
+            .filter_map(|&(col, s)| match s {
+                Substitution::Shift(_, Some(width)) => Some((col, width)),
+                _ => None,
+            })
+            .collect();
+        let (m_ub, m_eq) = (a_ub.nrows(), a_eq.nrows());
+        let n_slack = m_ub + upper.len();
+        let m = n_slack + m_eq;
+        let mut a = Array2::zeros((m, ny + n_slack));
+        let mut b = Array1::zeros(m);
+
+        // Substitute the variables in the rows of the original constraints.
+        let rows = a_ub
+            .rows()
+            .into_iter()
+            .zip(b_ub)
+            .chain(a_eq.rows().into_iter().zip(b_eq));
+        let row_index = (0..m_ub).chain(n_slack..m);
+        for (i, (row, &bi)) in row_index.zip(rows) {
+            let mut rhs = bi;
+            for (&aij, &(col, s)) in row.iter().zip(&columns) {
+                match s {
+                    Substitution::Shift(lb, _) => {
+                        a[(i, col)] = aij;
+                        rhs = rhs - aij * lb;
+                    }
+                    Substitution::Mirror(ub) => {
+                        a[(i, col)] = -aij;
+                        rhs = rhs - aij * ub;
+                    }
+                    Substitution::Free => {
+                        a[(i, col)] = aij;
+                        a[(i, col + 1)] = -aij;
+                    }
+                }
+            }
+            b[i] = rhs;
+        }
+        for (k, &(col, width)) in upper.iter().enumerate() {
+            a[(m_ub + k, col)] = F::one();
+            b[m_ub + k] = width;
+        }
+        for k in 0..n_slack {
+            a[(k, ny + k)] = F::one();
+        }
+        // Make the right-hand side nonnegative, in which case the slack variable of an
+        // inequality is a basic feasible variable.
+        let mut slack_basis = alloc::vec![None; m];
+        for i in 0..m {
+            if b[i] < F::zero() {
+                a.row_mut(i).mapv_inplace(|v| -v);
+                b[i] = -b[i];
+            } else if i < n_slack {
+                slack_basis[i] = Some(ny + i);
+            }
+        }
+
+        let mut cost = Array1::zeros(ny + n_slack);
+        for (&cj, &(col, s)) in c.iter().zip(&columns) {
+            match s {
+                Substitution::Shift(..) => cost[col] = cj,
+                Substitution::Mirror(_) => cost[col] = -cj,
+                Substitution::Free => {
+                    cost[col] = cj;
+                    cost[col + 1] = -cj;
+                }
+            }
+        }
+        StandardForm {
+            a,
+            b,
+            c: cost,
+            columns,
+            slack_basis,
+        }
+    }
+
+    /// Original variables of the solution `y` in the standard form.
+    fn variables(&self, y: &Array1<F>) -> Array1<F> {
+        self.columns
+            .iter()
+            .map(|&(col, s)| match s {
+                Substitution::Shift(lb, _) => lb + y[col],
+                Substitution::Mirror(ub) => ub - y[col],
+                Substitution::Free => y[col] - y[col + 1],
+            })
+            .collect()
+    }
+}
+
+/// Outcome of the simplex method.
+enum Outcome {
+    Optimal,
+    IterationLimit,
+    Infeasible,
+    Unbounded,
+}
+
+/// Dense simplex tableau of a problem in the standard form, with a column of artificial
+/// variable for each row that has no feasible slack variable.
+struct Tableau<F> {
+    /// Constraints, with the right-hand side in the last column.
+    a: Array2<F>,
+    /// Reduced costs, with the opposite of the objective value in the last column.
+    obj: Array1<F>,
+    /// Basic variable of each row.
+    basis: Vec<usize>,
+    /// Number of columns of the variables of the standard form, which precede the artificial
+    /// ones.
+    ny: usize,
+    tol: F,
+}
+
+impl<F: Float> Tableau<F> {
+    fn new(standard: &StandardForm<F>) -> Self {
+        let (m, ny) = standard.a.dim();
+        let artificial: Vec<usize> = (0..m)
+            .filter(|&i| standard.slack_basis[i].is_none())
+            .collect();
+        let ncols = ny + artificial.len();
+        let mut a = Array2::zeros((m, ncols + 1));
+        a.slice_mut(ndarray::s![.., ..ny]).assign(&standard.a);
+        a.column_mut(ncols).assign(&standard.b);
+        let mut basis: Vec<usize> = standard
+            .slack_basis
+            .iter()
+            .map(|b| b.unwrap_or(0))
+            .collect();
+        for (k, &i) in artificial.iter().enumerate() {
+            a[(i, ny + k)] = F::one();
+            basis[i] = ny + k;
+        }
+        Tableau {
+            a,
+            obj: Array1::zeros(ncols + 1),
+            basis,
+            ny,
+            tol: F::from(1e-9).unwrap().max(F::epsilon().sqrt()),
+        }
+    }
+
+    /// Set the costs of the columns, and the reduced costs for the current basis.
+    fn set_objective(&mut self, cost: impl Fn(usize) -> F) {
+        let ncols = self.a.ncols() - 1;
+        self.obj =
+            Array1::from_shape_fn(ncols + 1, |j| if j < ncols { cost(j) } else { F::zero() });
+        for (row, &b) in self.a.rows().into_iter().zip(&self.basis) {
+            let cb = cost(b);
+            if !cb.is_zero() {
+                self.obj.zip_mut_with(&row, |o, &r| *o = *o - cb * r);
+            }
+        }
+    }
+
+    fn pivot(&mut self, r: usize, c: usize) {
+        let p = self.a[(r, c)];
+        self.a.row_mut(r).mapv_inplace(|v| v / p);
+        let pivot_row = self.a.row(r).to_owned();
+        for (i, mut row) in self.a.rows_mut().into_iter().enumerate() {
+            let f = row[c];
+            if i != r && !f.is_zero() {
+                row.zip_mut_with(&pivot_row, |v, &p| *v = *v - f * p);
+            }
+        }
+        let f = self.obj[c];
+        self.obj.zip_mut_with(&pivot_row, |v, &p| *v = *v - f * p);
+        self.basis[r] = c;
+    }
+
+    /// Minimize the objective over the first `ncols` columns with Bland's rule: the entering
+    /// variable is the first one with a negative reduced cost, and the leaving one the first
+    /// basic variable among the rows that are tied in the ratio test.
+    fn minimize(&mut self, ncols: usize, nit: &mut usize, maxiter: usize) -> Outcome {
+        let rhs = self.a.ncols() - 1;
+        loop {
+            let Some(c) = (0..ncols).find(|&j| self.obj[j] < -self.tol) else {
+                return Outcome::Optimal;
+            };
+            let mut leaving: Option<(usize, F)> = None;
+            for (i, row) in self.a.rows().into_iter().enumerate() {
+                if row[c] > self.tol {
+                    let ratio = row[rhs] / row[c];
+                    let better = match leaving {
+                        None => true,
+                        Some((l, best)) => {
+                            ratio < best - self.tol
+                                || (ratio <= best + self.tol && self.basis[i] < self.basis[l])
+                        }
+                    };
+                    if better {
+                        leaving = Some((i, ratio));
+                    }
+                }
+            }
+            let Some((r, _)) = leaving else {
+                return Outcome::Unbounded;
+            };
+            if *nit >= maxiter {
+                return Outcome::IterationLimit;
+            }
+            self.pivot(r, c);
+            *nit += 1;
+        }
+    }
+
+    /// Solve the problem with the costs `c` of the standard form by the two-phase simplex
+    /// method.
+    fn solve(&mut self, c: &Array1<F>, nit: &mut usize, maxiter: usize) -> Outcome {
+        let ncols = self.a.ncols() - 1;
+        let ny = self.ny;
+
+        // Phase 1: minimize the sum of the artificial variables.
+        if ncols > ny {
+            self.set_objective(|j| if j < ny { F::zero() } else { F::one() });
+            match self.minimize(ncols, nit, maxiter) {
+                Outcome::Optimal => {}
+                outcome => return outcome,
+            }
+            let scale = self
+                .a
+                .column(ncols)
+                .fold(F::one(), |acc, b| acc.max(b.abs()));
+            if -self.obj[ncols] > self.tol * scale {
+                return Outcome::Infeasible;
+            }
+            // Drive the artificial variables out of the basis, and drop the redundant
+            // constraints where they cannot be.
+            let mut keep = Vec::with_capacity(self.basis.len());
+            for r in 0..self.basis.len() {
+                if self.basis[r] >= ny {
+                    match (0..ny).find(|&j| self.a[(r, j)].abs() > self.tol) {
+                        Some(j) => self.pivot(r, j),
+                        None => continue,
+                    }
+                }
+                keep.push(r);
+            }
+            let cols: Vec<usize> = (0..ny).chain([ncols]).collect();
+            self.a = self.a.select(Axis(0), &keep).select(Axis(1), &cols);
+            self.basis = keep.iter().map(|&r| self.basis[r]).collect();
+        }
+
+        // Phase 2: minimize the objective from the basic feasible solution.
+        self.set_objective(|j| c[j]);
+        self.minimize(ny, nit, maxiter)
+    }
+
+    /// Values of the variables of the standard form at the current basic solution.
+    fn solution(&self) -> Array1<F> {
+        let rhs = self.a.ncols() - 1;
+        let mut y = Array1::zeros(self.ny);
+        for (row, &b) in self.a.rows().into_iter().zip(&self.basis) {
+            if b < self.ny {
+                y[b] = row[rhs];
+            }
+        }
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn standard() {
+        // max 3x + 2y such that x + y <= 4, x + 3y <= 9, x <= 3.
+        let c = array![-3., -2.];
+        let a = array![[1., 1.], [1., 3.]];
+        let b = array![4., 9.];
+        let bounds = [(0., 3.), (0., f64::INFINITY)];
+        let res = linprog(
+            &c,
+            Some(a.view()),
+            Some(b.view()),
+            None,
+            None,
+            Some(&bounds),
+            None,
+        )
+        .unwrap();
+        assert!(res.success);
+        assert_abs_diff_eq!(res.x[0], 3.);
+        assert_abs_diff_eq!(res.x[1], 1.);
+        assert_abs_diff_eq!(res.fun, -11.);
+        assert_abs_diff_eq!(res.slack[0], 0.);
+        assert_abs_diff_eq!(res.slack[1], 3.);
+        assert!(res.nit > 0);
+    }
+
+    #[test]
+    fn equality() {
+        // min x + 2y + 3z such that x + y + z == 1, x - y == 0.5 with the default bounds.
+        let c = array![1., 2., 3.];
+        let a_eq = array![[1., 1., 1.], [1., -1., 0.]];
+        let b_eq = array![1., 0.5];
+        let res = linprog(
+            &c,
+            None,
+            None,
+            Some(a_eq.view()),
+            Some(b_eq.view()),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(res.status, 0);
+        assert_abs_diff_eq!(res.x[0], 0.75, epsilon = 1e-12);
+        assert_abs_diff_eq!(res.x[1], 0.25, epsilon = 1e-12);
+        assert_abs_diff_eq!(res.x[2], 0., epsilon = 1e-12);
+        assert_abs_diff_eq!(res.fun, 1.25, epsilon = 1e-12);
+        assert_eq!(res.slack.len(), 0);
+        assert!(res.con.iter().all(|c| c.abs() < 1e-12));
+
+        // A redundant equality and a negative right-hand side.
+        let a_eq = array![[1., 1., 1.], [2., 2., 2.], [-1., 1., 0.]];
+        let b_eq = array![1., 2., -0.5];
+        let res = linprog(
+            &c,
+            None,
+            None,
+            Some(a_eq.view()),
+            Some(b_eq.view()),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(res.status, 0);
+        assert_abs_diff_eq!(res.fun, 1.25, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn mirrored_and_free() {
+        // min -x - y such that x + y <= 2, x - y >= -10 with x <= 1 and y free.
+        let c = array![-1., -1.];
+        let a = array![[1., 1.], [-1., 1.]];
+        let b = array![2., 10.];
+        let bounds = [(f64::NEG_INFINITY, 1.), (f64::NEG_INFINITY, f64::INFINITY)];
+        let res = linprog(
+            &c,
+            Some(a.view()),
+            Some(b.view()),
+            None,
+            None,
+            Some(&bounds),
+            None,
+        )
+        .unwrap();
+        assert_eq!(res.status, 0);
+        assert_abs_diff_eq!(res.fun, -2., epsilon = 1e-12);
+        assert!(res.x[0] <= 1. + 1e-12);
+        assert_abs_diff_eq!(res.x[0] + res.x[1], 2., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn failures() {
+        let c = array![1., 1.];
+        // x + y <= -1 with nonnegative variables.
+        let a = array![[1., 1.]];
+        let b = array![-1.];
+        let res = linprog(&c, Some(a.view()), Some(b.view()), None, None, None, None).unwrap();
+        assert_eq!(res.status, 2);
+        assert!(!res.success);
+
+        // min -x - y such that x - y <= 1.
+        let c = array![-1., -1.];
+        let b = array![1.];
+        let a = array![[1., -1.]];
+        let res = linprog(&c, Some(a.view()), Some(b.view()), None, None, None, None).unwrap();
+        assert_eq!(res.status, 3);
+
+        // Iteration limit.
+        let c = array![-3., -2.];
+        let a = array![[1., 1.], [1., 3.]];
+        let b = array![4., 9.];
+        let bounds = [(0., 3.), (0., f64::INFINITY)];
+        let res = linprog(
+            &c,
+            Some(a.view()),
+            Some(b.view()),
+            None,
+            None,
+            Some(&bounds),
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!((res.status, res.nit), (1, 1));
+    }
+
+    #[test]
+    fn degenerate() {
+        // Beale's example, which cycles with the largest coefficient rule.
+        let c = array![-0.75, 150., -0.02, 6.];
+        let a = array![
+            [0.25, -60., -0.04, 9.],
+            [0.5, -90., -0.02, 3.],
+            [0., 0., 1., 0.]
+        ];
+        let b = array![0., 0., 1.];
+        let res = linprog(&c, Some(a.view()), Some(b.view()), None, None, None, None).unwrap();
+        assert_eq!(res.status, 0);
+        assert_abs_diff_eq!(res.fun, -0.05, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn invalid_args() {
+        let c = array![1., 1.];
+        let a = array![[1., 1.]];
+        let b = array![1.];
+        let run = |c: &Array1<f64>,
+                   a: Option<ArrayView2<f64>>,
+                   b: Option<ArrayView1<f64>>,
+                   bounds| { linprog(c, a, b, None, None, bounds, None) };
+        assert!(run(&array![], None, None, None).is_err());
+        assert!(run(&array![f64::NAN], None, None, None).is_err());
+        assert!(run(&c, Some(a.view()), None, None).is_err());
+        assert!(run(&c, Some(a.view()), Some(array![1., 2.].view()), None).is_err());
+        assert!(run(&array![1.], Some(a.view()), Some(b.view()), None).is_err());
+        assert!(run(&c, None, None, Some(&[(0., 1.)])).is_err());
+        assert!(run(&c, None, None, Some(&[(0., 1.), (2., 1.)])).is_err());
+        assert!(run(
+            &c,
+            None,
+            None,
+            Some(&[(0., 1.), (f64::INFINITY, f64::INFINITY)])
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,237 @@
+use super::least_squares::{compute_grad, LeastSquaresResult, Residuals};
+use super::trust_region::{matvec, norm, solve_lsq_trust_region, Svd};
+use ndarray::{Array1, Array2, ArrayView1};
+use num_traits::Float;
+
+/// Minimization by the Levenberg-Marquardt algorithm, as [super::least_squares] with
+/// [super::LeastSquaresMethod::Lm].
+///
+/// The trust region and the Levenberg-Marquardt parameter are updated as in MINPACK's `lmder`,
+/// with the unit scaling of the variables.
+pub(super) fn lm<F: Float, G: FnMut(ArrayView1<F>) -> Array1<F>>(
+    residuals: &mut Residuals<'_, F, G>,
+    x0: Array1<F>,
+    f0: Array1<F>,
+    j0: Array2<F>,
+    (ftol, xtol, gtol): (F, F, F),
+    max_nfev: usize,
+) -> LeastSquaresResult<F> {
+    let (m, n) = j0.dim();
+    let (half, tenth, quarter) = (
+        F::from(0.5).unwrap(),
+        F::from(0.1).unwrap(),
+        F::from(0.25).unwrap(),
+    );
+    let factor = F::from(100).unwrap();
+    let nfev = |residuals: &Residuals<'_, F, G>| residuals.nfev + residuals.fd_nfev;
+
+    let mut x = x0;
+    let mut f = f0;
+    let mut jac = j0;
+    let mut fnorm = norm(&f);
+    let mut xnorm = norm(&x);
+    let mut delta = if xnorm.is_zero() {
+        factor
+    } else {
+        factor * xnorm
+    };
+    let mut par = F::zero();
+    let mut first = true;
+    let status = 'outer: loop {
+        // Cosine of the angle between the residuals and the columns of the Jacobian.
+        let g = compute_grad(&jac, &f);
+        let gnorm = if fnorm.is_zero() {
+            F::zero()
+        } else {
+            jac.columns()
+                .into_iter()
+                .zip(&g)
+                .fold(F::zero(), |acc, (col, &g)| {
+                    let col_norm = col.fold(F::zero(), |acc, &c| acc + c * c).sqrt();
+                    if col_norm.is_zero() {
+                        acc
+                    } else {
+                        acc.max((g / (col_norm * fnorm)).abs())
+                    }
+                })
+        };
+        if gnorm <= gtol {
+            break 1;
+        }
+
+        let svd = Svd::new(&jac, &f);
+        loop {
+            let (p, alpha) = solve_lsq_trust_region(m, &svd, delta, par);
+            par = alpha;
+            let pnorm = norm(&p);
+            if first {
+                delta = delta.min(pnorm);
+                first = false;
+            }
+            let x_new = &x + &p;
+            let f_new = residuals.fun(&x_new);
+            let fnorm1 = norm(&f_new);
+
+            // Ratio of the actual to the predicted reduction.
+            let actred = if tenth * fnorm1 < fnorm {
+                F::one() - (fnorm1 / fnorm).powi(2)
+            } else {
+                -F::one()
+            };
+            let temp1 = norm(&matvec(&jac, &p)) / fnorm;
+            let temp2 = par.sqrt() * pnorm / fnorm;
+            let prered = temp1 * temp1 + temp2 * temp2 / half;
+            let dirder = -(temp1 * temp1 + temp2 * temp2);
+            let ratio = if prered.is_zero() {
+                F::zero()
+            } else {
+                actred / prered
+            };
+
+            if ratio <= quarter {
+                let mut temp = if actred >= F::zero() {
+                    half
+                } else {
+                    half * dirder / (dirder + half * actred)
+                };
+                if tenth * fnorm1 >= fnorm || temp < tenth {
+                    temp = tenth;
+                }
+                delta = temp * delta.min(pnorm / tenth);
+                par = par / temp;
+            } else if par.is_zero() || ratio >= F::from(0.75).unwrap() {
+                delta = pnorm / half;
+                par = half * par;
+            }
+
+            let successful = ratio >= F::from(1e-4).unwrap();
+            if successful {
+                x = x_new;
+                f = f_new;
+                xnorm = norm(&x);
+                fnorm = fnorm1;
+            }
+
+            let ftol_satisfied = actred.abs() <= ftol && prered <= ftol && half * ratio <= F::one();
+            let xtol_satisfied = delta <= xtol * xnorm;
+            match (ftol_satisfied, xtol_satisfied) {
+                (true, true) => break 'outer 4,
+                (true, false) => break 'outer 2,
+                (false, true) => break 'outer 3,
+                _ if nfev(residuals) >= max_nfev => break 'outer 0,
+                _ => {}
+            }
+            if successful {
+                break;
+            }
+        }
+        jac = residuals.jac(&x, &f);
+    };
+
+    if status != 1 {
+        jac = residuals.jac(&x, &f);
+    }
+    let cost = half * fnorm * fnorm;
+    let grad = compute_grad(&jac, &f);
+    let optimality = grad.fold(F::zero(), |acc, g| acc.max(g.abs()));
+    let nfev = nfev(residuals);
+    LeastSquaresResult::new(
+        x,
+        cost,
+        f,
+        jac,
+        grad,
+        optimality,
+        Array1::zeros(n),
+        residuals,
+        nfev,
+        status,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{least_squares, LeastSquaresMethod, LeastSquaresOptions};
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    const LM: Option<LeastSquaresMethod> = Some(LeastSquaresMethod::Lm);
+
+    #[test]
+    fn rosenbrock() {
+        let fun = |x: ArrayView1<f64>| array![10. * (x[1] - x[0] * x[0]), 1. - x[0]];
+        let mut jac = |x: ArrayView1<f64>| array![[-20. * x[0], 10.], [-1., 0.]];
+        let res = least_squares(
+            fun,
+            &array![-1.2, 1.],
+            Some(&mut jac),
+            None,
+            LM,
+            None,
+            Default::default(),
+        )
+        .unwrap();
+        assert!(res.success);
+        assert_abs_diff_eq!(
+            res.x.as_slice().unwrap(),
+            [1., 1.].as_slice(),
+            epsilon = 1e-10
+        );
+        assert_abs_diff_eq!(res.cost, 0., epsilon = 1e-20);
+        assert_eq!(res.active_mask, array![0, 0]);
+
+        // Finite differences, whose evaluations are counted.
+        let fd = least_squares(
+            fun,
+            &array![-1.2, 1.],
+            None,
+            None,
+            LM,
+            None,
+            Default::default(),
+        )
+        .unwrap();
+        assert!(fd.success);
+        assert_abs_diff_eq!(
+            fd.x.as_slice().unwrap(),
+            [1., 1.].as_slice(),
+            epsilon = 1e-8
+        );
+        assert!(fd.nfev >= 3 * fd.njev);
+    }
+
+    #[test]
+    fn overdetermined() {
+        // Fit y = a * t / (b + t) to noisy data.
+        let t = array![0.038, 0.194, 0.425, 0.626, 1.253, 2.5, 3.74];
+        let y = array![0.05, 0.127, 0.094, 0.2122, 0.2729, 0.2665, 0.3317];
+        let fun =
+            |x: ArrayView1<f64>| Array1::from_shape_fn(7, |i| x[0] * t[i] / (x[1] + t[i]) - y[i]);
+        let x0 = array![0.9, 0.2];
+        let lm = least_squares(fun, &x0, None, None, LM, None, Default::default()).unwrap();
+        let trf = least_squares(fun, &x0, None, None, None, None, Default::default()).unwrap();
+        assert!(lm.success && trf.success);
+        assert_abs_diff_eq!(
+            lm.x.as_slice().unwrap(),
+            trf.x.as_slice().unwrap(),
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            lm.x.as_slice().unwrap(),
+            [0.362, 0.556].as_slice(),
+            epsilon = 1e-3
+        );
+        assert_abs_diff_eq!(lm.cost, trf.cost, epsilon = 1e-12);
+        assert!(lm.optimality < 1e-6);
+
+        let options = LeastSquaresOptions {
+            max_nfev: Some(5),
+            ..Default::default()
+        };
+        let res = least_squares(fun, &x0, None, None, LM, None, options).unwrap();
+        assert_eq!(res.status, 0);
+        assert!(!res.success);
+    }
+}
@@ -0,0 +1,236 @@
+use super::bfgs::bfgs;
+use super::lbfgsb::lbfgsb;
+use super::nelder_mead::nelder_mead;
+use super::OptimizeResult;
+use alloc::vec::Vec;
+use ndarray::{Array1, Array2, ArrayBase, ArrayView1, Data, Ix1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Type of solver of [minimize].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// The downhill simplex algorithm of Nelder and Mead, which only evaluates the function.
+    NelderMead,
+    /// The quasi-Newton method of Broyden, Fletcher, Goldfarb, and Shanno, which builds an
+    /// approximation of the inverse Hessian from the gradients.
+    Bfgs,
+    /// The limited-memory BFGS method for bound-constrained problems, which only keeps the last
+    /// `maxcor` corrections of the Hessian approximation.
+    LBfgsB,
+}
+
+/// Solver options of [minimize].
+///
+/// Every field left as `None` takes the default of the solver, which is also what [Default]
+/// gives. Options that do not apply to the chosen [Method] are ignored.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MinimizeOptions<F> {
+    /// Maximum allowed number of iterations. For [Method::NelderMead], it is `200 * n` by default
+    /// if `maxfev` is not given either, and unlimited otherwise. It is `200 * n` for
+    /// [Method::Bfgs] and 15000 for [Method::LBfgsB] by default.
+    pub maxiter: Option<usize>,
+    /// Maximum allowed number of function evaluations. For [Method::NelderMead], it is `200 * n`
+    /// by default if `maxiter` is not given either, and unlimited otherwise. It is 15000 for
+    /// [Method::LBfgsB] by default, and not used by [Method::Bfgs].
+    pub maxfev: Option<usize>,
+    /// Absolute error in `xopt` between iterations that is acceptable for convergence, `1e-4` by
+    /// default.
+    pub xatol: Option<F>,
+    /// Absolute error in `fun(xopt)` between iterations that is acceptable for convergence, `1e-4`
+    /// by default.
+    pub fatol: Option<F>,
+    /// Adapt the algorithm parameters of [Method::NelderMead] to the dimensionality of the
+    /// problem, which is useful in high dimensions. `false` by default.
+    pub adaptive: Option<bool>,
+    /// Initial simplex of [Method::NelderMead], of shape `(n + 1, n)`, whose rows are the
+    /// vertices. If not given, it is made of `x0` and `x0` with each coordinate in turn increased
+    /// by 5%, or set to `0.00025` if it is zero.
+    pub initial_simplex: Option<Array2<F>>,
+    /// Lower and upper bounds on every variable, which may be infinite. The trial points of
+    /// [Method::NelderMead] are clipped to the bounds, and [Method::LBfgsB] keeps every iterate
+    /// within them. Not supported by [Method::Bfgs].
+    pub bounds: Option<Vec<(F, F)>>,
+    /// Terminate when the infinity norm of the gradient, projected on the bounds for
+    /// [Method::LBfgsB], is at most `gtol`, `1e-5` by default.
+    pub gtol: Option<F>,
+    /// Terminate [Method::LBfgsB] when the relative reduction of the function value
+    /// `(f_k - f_k+1) / max(|f_k|, |f_k+1|, 1)` is at most `ftol`, `2.22e-9` by default.
+    pub ftol: Option<F>,
+    /// Absolute step size of the finite differences of the gradient, if `jac` is not given. It is
+    /// the square root of the machine epsilon for [Method::Bfgs] and `1e-8` for [Method::LBfgsB]
+    /// by default.
+    pub eps: Option<F>,
+    /// Maximum number of corrections of [Method::LBfgsB] used to approximate the Hessian, 10 by
+    /// default.
+    pub maxcor: Option<usize>,
+    /// Maximum number of line search steps of [Method::LBfgsB] per iteration, 20 by default.
+    pub maxls: Option<usize>,
+}
+
+impl<F> MinimizeOptions<F> {
+    /// Options of [Method::LBfgsB] with the given bounds and maximum number of iterations, and
+    /// the defaults otherwise, for the local searches of the global optimizers.
+    pub(super) fn lbfgsb(bounds: &[(F, F)], maxiter: Option<usize>) -> Self
+    where
+        F: Copy,
+    {
+        MinimizeOptions {
+            maxiter,
+            maxfev: None,
+            xatol: None,
+            fatol: None,
+            adaptive: None,
+            initial_simplex: None,
+            bounds: Some(bounds.to_vec()),
+            gtol: None,
+            ftol: None,
+            eps: None,
+            maxcor: None,
+            maxls: None,
+        }
+    }
+}
+
+/// Minimization of scalar function of one or more variables.
+///
+/// ## Parameters
+/// * `fun`: The objective function to be minimized, of the 1-D array of the `n` variables.
+/// * `x0`: Initial guess, of shape `(n,)`.
+/// * `method`: Type of solver.
+///   * [NelderMead](Method::NelderMead): the simplex is reflected, expanded, contracted or
+///     shrunk until both its size and the spread of the function values on it are below `xatol`
+///     and `fatol`.
+///   * [Bfgs](Method::Bfgs): the iterates follow the quasi-Newton directions, with steps found
+///     by a line search satisfying the strong Wolfe conditions, until the gradient is below
+///     `gtol`.
+///   * [LBfgsB](Method::LBfgsB): the iterates stay within the bounds, and move towards the
+///     minimizer of the quadratic model on the face of the bounds reached by the projected
+///     steepest descent, until the projected gradient is below `gtol` or the reduction of the
+///     function value is below `ftol`.
+/// * `jac`: Method for computing the gradient vector of the methods that use it. If it is `None`,
+///   the gradient is estimated by forward differences with the step `eps`.
+/// * `options`: [MinimizeOptions] of the solver.
+///
+/// ## Returns
+/// The optimization result, with `status` 0 on success. Otherwise, for
+/// [NelderMead](Method::NelderMead), `status` is 1 if the maximum number of function evaluations
+/// and 2 if the maximum number of iterations was reached. For [Bfgs](Method::Bfgs), it is 1 if
+/// the maximum number of iterations was reached, 2 if the line search failed and 3 if a NaN was
+/// encountered. For [LBfgsB](Method::LBfgsB), it is 1 if the maximum number of iterations or
+/// function evaluations was reached and 2 if the line search failed.
+///
+/// ## Errors
+/// If `x0` is empty or not finite, or the bounds or initial simplex do not match it, or a lower
+/// bound is greater than an upper bound, or bounds are given to [Bfgs](Method::Bfgs).
+///
+/// ## Notes
+/// The Nelder-Mead method only uses function values, so it is robust to noise and to
+/// non-differentiable functions, but it may take a large number of iterations to converge and
+/// can stall away from a minimum. The adaptive parameters of [2] help it in more than a few
+/// dimensions.
+///
+/// The BFGS method [3] has good performance even for non-smooth optimizations. The L-BFGS-B
+/// method [4] finds the generalized Cauchy point along the projected gradient, then minimizes the
+/// compact representation of the limited-memory Hessian over the free variables. Both use the
+/// line search of Nocedal and Wright (algorithm 3.5 with cubic interpolation) rather than the
+/// MINPACK line search of scipy, so the iterates and number of evaluations may differ.
+///
+/// ## References
+/// 1. Nelder, J A, and R Mead. 1965. A Simplex Method for Function Minimization. The Computer
+///    Journal 7: 308-13.
+/// 2. Gao, F. and Han, L. Implementing the Nelder-Mead simplex algorithm with adaptive
+///    parameters. 2012. Computational Optimization and Applications. 51:1, pp. 259-277
+/// 3. Nocedal, J, and S J Wright. 2006. Numerical Optimization. Springer New York.
+/// 4. Byrd, R H and P Lu and J. Nocedal. 1995. A Limited Memory Algorithm for Bound Constrained
+///    Optimization. SIAM Journal on Scientific and Statistical Computing 16 (5): 1190-1208.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.optimize import minimize, rosen
+/// x0 = [1.3, 0.7, 0.8, 1.9, 1.2]
+/// res = minimize(rosen, x0, method='Nelder-Mead', options={'xatol': 1e-8})
+/// res.x, res.nit, res.nfev
+/// # (array([1., 1., 1., 1., 1.]), 339, 571)
+/// res = minimize(rosen, [1.3, 0.7], method='L-BFGS-B', bounds=[(-2, 0.5), (-1, 2)])
+/// res.x
+/// # array([0.5 , 0.25])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{array, ArrayView1};
+/// use sci_rs::optimize::{minimize, Method, MinimizeOptions};
+///
+/// let rosen = |x: ArrayView1<f64>| {
+///     x.windows(2)
+///         .into_iter()
+///         .map(|w| 100. * (w[1] - w[0] * w[0]).powi(2) + (1. - w[0]).powi(2))
+///         .sum()
+/// };
+/// let x0 = array![1.3, 0.7, 0.8, 1.9, 1.2];
+/// let options = MinimizeOptions {
+///     xatol: Some(1e-8),
+///     ..Default::default()
+/// };
+/// let res = minimize(rosen, &x0, Method::NelderMead, None, options).unwrap();
+/// for x in res.x {
+///     assert_abs_diff_eq!(x, 1., epsilon = 1e-8);
+/// }
+/// assert_eq!((res.nit, res.nfev), (339, 571));
+///
+/// let options = MinimizeOptions {
+///     bounds: Some(vec![(-2., 0.5), (-1., 2.)]),
+///     ..Default::default()
+/// };
+/// let res = minimize(rosen, &array![1.3, 0.7], Method::LBfgsB, None, options).unwrap();
+/// assert_abs_diff_eq!(res.x[0], 0.5);
+/// assert_abs_diff_eq!(res.x[1], 0.25, epsilon = 1e-5);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn minimize<F, S>(
+    fun: impl FnMut(ArrayView1<F>) -> F,
+    x0: &ArrayBase<S, Ix1>,
+    method: Method,
+    jac: Option<&mut dyn FnMut(ArrayView1<F>) -> Array1<F>>,
+    options: MinimizeOptions<F>,
+) -> Result<OptimizeResult<F, Array1<F>>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let n = x0.len();
+    if n == 0 || x0.iter().any(|x| !x.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "x0".into(),
+            reason: "x0 must be a non-empty array of finite values.".into(),
+        });
+    }
+    if let Some(bounds) = &options.bounds {
+        if bounds.len() != n {
+            return Err(Error::InvalidArg {
+                arg: "bounds".into(),
+                reason: "The number of bounds is not compatible with the length of `x0`.".into(),
+            });
+        }
+        if bounds
+            .iter()
+            .any(|(lb, ub)| lb.is_nan() || ub.is_nan() || lb > ub)
+        {
+            return Err(Error::InvalidArg {
+                arg: "bounds".into(),
+                reason: "One of the lower bounds is greater than an upper bound.".into(),
+            });
+        }
+    }
+    match method {
+        Method::NelderMead => nelder_mead(fun, x0.to_owned(), &options),
+        Method::Bfgs if options.bounds.is_some() => Err(Error::InvalidArg {
+            arg: "bounds".into(),
+            reason: "Method BFGS cannot handle bounds.".into(),
+        }),
+        Method::Bfgs => bfgs(fun, jac, x0.to_owned(), &options),
+        Method::LBfgsB => lbfgsb(fun, jac, x0.to_owned(), &options),
+    }
+}
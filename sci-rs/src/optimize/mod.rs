@@ -0,0 +1,33 @@
+//! Functions from the
+//! [`scipy.optimize`](https://docs.scipy.org/doc/scipy/reference/optimize.html) module.
+
+mod bfgs;
+mod broyden;
+mod curve_fit;
+mod differential_evolution;
+mod dual_annealing;
+mod hybr;
+mod lbfgsb;
+mod least_squares;
+mod line_search;
+mod linprog;
+mod lm;
+mod minimize;
+mod nelder_mead;
+mod result;
+mod root;
+mod scalar;
+mod trf;
+mod trust_region;
+mod zeros;
+
+pub use curve_fit::*;
+pub use differential_evolution::*;
+pub use dual_annealing::*;
+pub use least_squares::*;
+pub use linprog::*;
+pub use minimize::*;
+pub use result::*;
+pub use root::*;
+pub use scalar::*;
+pub use zeros::*;
@@ -0,0 +1,367 @@
+use super::{MinimizeOptions, OptimizeResult};
+use alloc::{string::String, vec, vec::Vec};
+use ndarray::{Array1, ArrayView1, Zip};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// The objective function, evaluated at most `maxfev` times.
+struct Objective<G> {
+    fun: G,
+    nfev: usize,
+    maxfev: usize,
+}
+
+impl<G> Objective<G> {
+    fn call<F>(&mut self, x: &Array1<F>) -> Option<F>
+    where
+        G: FnMut(ArrayView1<F>) -> F,
+    {
+        if self.nfev >= self.maxfev {
+            return None;
+        }
+        self.nfev += 1;
+        Some((self.fun)(x.view()))
+    }
+}
+
+/// Vertices of the simplex and the function values at them.
+struct Simplex<F> {
+    sim: Vec<Array1<F>>,
+    fsim: Vec<F>,
+}
+
+impl<F: Float> Simplex<F> {
+    /// Sort the vertices by increasing function value, NaN last, so that `sim[0]` is the best.
+    fn sort(&mut self) {
+        let mut order: Vec<usize> = (0..self.fsim.len()).collect();
+        let fsim = &self.fsim;
+        order.sort_by(|&i, &j| {
+            fsim[i]
+                .partial_cmp(&fsim[j])
+                .unwrap_or_else(|| fsim[i].is_nan().cmp(&fsim[j].is_nan()))
+        });
+        self.sim = order.iter().map(|&i| self.sim[i].clone()).collect();
+        self.fsim = order.iter().map(|&i| self.fsim[i]).collect();
+    }
+}
+
+/// Coefficients of reflection, expansion, contraction and shrinkage.
+struct Coefficients<F> {
+    rho: F,
+    chi: F,
+    psi: F,
+    sigma: F,
+}
+
+/// Clip `x` to the bounds, if any.
+fn clip<F: Float>(mut x: Array1<F>, bounds: Option<&[(F, F)]>) -> Array1<F> {
+    if let Some(bounds) = bounds {
+        Zip::from(&mut x)
+            .and(bounds)
+            .for_each(|x, &(lb, ub)| *x = x.max(lb).min(ub));
+    }
+    x
+}
+
+/// Minimization by the Nelder-Mead algorithm, as [super::minimize] with [super::Method::NelderMead].
+pub(super) fn nelder_mead<F: Float>(
+    fun: impl FnMut(ArrayView1<F>) -> F,
+    x0: Array1<F>,
+    options: &MinimizeOptions<F>,
+) -> Result<OptimizeResult<F, Array1<F>>> {
+    let n = x0.len();
+    let bounds = options.bounds.as_deref();
+    let x0 = clip(x0, bounds);
+    let one = F::one();
+    let coefficients = if options.adaptive.unwrap_or(false) {
+        let dim = F::from(n).unwrap();
+        let two = F::from(2).unwrap();
+        Coefficients {
+            rho: one,
+            chi: one + two / dim,
+            psi: F::from(0.75).unwrap() - one / (two * dim),
+            sigma: one - one / dim,
+        }
+    } else {
+        let half = F::from(0.5).unwrap();
+        Coefficients {
+            rho: one,
+            chi: F::from(2).unwrap(),
+            psi: half,
+            sigma: half,
+        }
+    };
+    let xatol = options.xatol.unwrap_or_else(|| F::from(1e-4).unwrap());
+    let fatol = options.fatol.unwrap_or_else(|| F::from(1e-4).unwrap());
+
+    let mut sim: Vec<Array1<F>> = match &options.initial_simplex {
+        None => {
+            let (nonzdelt, zdelt) = (F::from(0.05).unwrap(), F::from(0.00025).unwrap());
+            let mut sim = vec![x0.clone()];
+            sim.extend((0..n).map(|k| {
+                let mut y = x0.clone();
+                y[k] = if y[k].is_zero() {
+                    zdelt
+                } else {
+                    (one + nonzdelt) * y[k]
+                };
+                y
+            }));
+            sim
+        }
+        Some(initial) => {
+            if initial.dim() != (n + 1, n) {
+                return Err(Error::InvalidArg {
+                    arg: "initial_simplex".into(),
+                    reason: "`initial_simplex` should be an array of shape (N+1,N) for `x0` of \
+                             length N."
+                        .into(),
+                });
+            }
+            initial.rows().into_iter().map(|v| v.to_owned()).collect()
+        }
+    };
+    if let Some(bounds) = bounds {
+        // Reflect the vertices above the upper bounds into the interior rather than clipping
+        // them, which could make the simplex degenerate.
+        for v in &mut sim {
+            Zip::from(&mut *v).and(bounds).for_each(|x, &(_, ub)| {
+                if *x > ub {
+                    *x = ub + ub - *x;
+                }
+            });
+            *v = clip(v.clone(), Some(bounds));
+        }
+    }
+
+    let (maxiter, maxfev) = match (options.maxiter, options.maxfev) {
+        (None, None) => (200 * n, 200 * n),
+        (maxiter, maxfev) => (maxiter.unwrap_or(usize::MAX), maxfev.unwrap_or(usize::MAX)),
+    };
+    let mut objective = Objective {
+        fun,
+        nfev: 0,
+        maxfev,
+    };
+    let mut simplex = Simplex {
+        fsim: vec![F::infinity(); n + 1],
+        sim,
+    };
+    for (v, f) in simplex.sim.iter().zip(&mut simplex.fsim) {
+        match objective.call(v) {
+            Some(fv) => *f = fv,
+            None => break,
+        }
+    }
+    simplex.sort();
+
+    let mut iterations = 1;
+    while objective.nfev < maxfev && iterations < maxiter {
+        let Simplex { sim, fsim } = &simplex;
+        let size = sim[1..]
+            .iter()
+            .flat_map(|v| (v - &sim[0]).into_iter())
+            .fold(F::zero(), |acc, d| acc.max(d.abs()));
+        let spread = fsim[1..]
+            .iter()
+            .fold(F::zero(), |acc, &f| acc.max((fsim[0] - f).abs()));
+        if size <= xatol && spread <= fatol {
+            break;
+        }
+        // The evaluations stop midway through the step when `maxfev` is reached.
+        if step(&mut simplex, &mut objective, &coefficients, bounds).is_some() {
+            iterations += 1;
+        }
+        simplex.sort();
+    }
+
+    let (status, message) = if objective.nfev >= maxfev {
+        (
+            1,
+            "Maximum number of function evaluations has been exceeded.",
+        )
+    } else if iterations >= maxiter {
+        (2, "Maximum number of iterations has been exceeded.")
+    } else {
+        (0, "Optimization terminated successfully.")
+    };
+    let Simplex { mut sim, fsim } = simplex;
+    Ok(OptimizeResult {
+        x: sim.swap_remove(0),
+        fun: fsim[0],
+        nit: iterations,
+        nfev: objective.nfev,
+        jac: None,
+        njev: 0,
+        success: status == 0,
+        status,
+        message: String::from(message),
+    })
+}
+
+/// Replace the worst vertex of the sorted simplex by a reflection, expansion or contraction of it
+/// through the centroid of the others, or shrink the simplex towards the best vertex.
+fn step<F: Float, G: FnMut(ArrayView1<F>) -> F>(
+    simplex: &mut Simplex<F>,
+    objective: &mut Objective<G>,
+    coefficients: &Coefficients<F>,
+    bounds: Option<&[(F, F)]>,
+) -> Option<()> {
+    let Coefficients {
+        rho,
+        chi,
+        psi,
+        sigma,
+    } = *coefficients;
+    let one = F::one();
+    let Simplex { sim, fsim } = simplex;
+    let n = sim.len() - 1;
+    let count = F::from(n).unwrap();
+    let xbar = sim[..n]
+        .iter()
+        .fold(Array1::<F>::zeros(n), |acc, v| acc + v)
+        .mapv(|x| x / count);
+    // The point `(1 + t) * xbar - t * sim[n]` on the line through the worst vertex.
+    let along = |t: F| {
+        clip(
+            xbar.mapv(|x| (one + t) * x) - sim[n].mapv(|x| t * x),
+            bounds,
+        )
+    };
+
+    let xr = along(rho);
+    let fxr = objective.call(&xr)?;
+    if fxr < fsim[0] {
+        let xe = along(rho * chi);
+        let fxe = objective.call(&xe)?;
+        (sim[n], fsim[n]) = if fxe < fxr { (xe, fxe) } else { (xr, fxr) };
+        return Some(());
+    }
+    if fxr < fsim[n - 1] {
+        (sim[n], fsim[n]) = (xr, fxr);
+        return Some(());
+    }
+    let contracted = if fxr < fsim[n] {
+        // Outside contraction.
+        let xc = along(psi * rho);
+        let fxc = objective.call(&xc)?;
+        (fxc <= fxr).then_some((xc, fxc))
+    } else {
+        // Inside contraction.
+        let xcc = along(-psi);
+        let fxcc = objective.call(&xcc)?;
+        (fxcc < fsim[n]).then_some((xcc, fxcc))
+    };
+    match contracted {
+        Some(vertex) => (sim[n], fsim[n]) = vertex,
+        None => {
+            for j in 1..=n {
+                let v = &sim[0] + &(&sim[j] - &sim[0]).mapv(|d| sigma * d);
+                sim[j] = clip(v, bounds);
+                fsim[j] = objective.call(&sim[j])?;
+            }
+        }
+    }
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{minimize, Method};
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    fn rosen(x: ArrayView1<f64>) -> f64 {
+        x.windows(2)
+            .into_iter()
+            .map(|w| 100. * (w[1] - w[0] * w[0]).powi(2) + (1. - w[0]).powi(2))
+            .sum()
+    }
+
+    fn nelder_mead(
+        x0: Array1<f64>,
+        options: MinimizeOptions<f64>,
+    ) -> OptimizeResult<f64, Array1<f64>> {
+        minimize(rosen, &x0, Method::NelderMead, None, options).unwrap()
+    }
+
+    #[test]
+    fn rosenbrock() {
+        let res = nelder_mead(array![-1.2, 1.], Default::default());
+        assert_abs_diff_eq!(res.x[0], 1.0000220217835696, epsilon = 1e-12);
+        assert_abs_diff_eq!(res.x[1], 1.0000422197517715, epsilon = 1e-12);
+        assert_eq!((res.nit, res.nfev, res.status), (85, 159, 0));
+        assert!(res.success);
+
+        let options = MinimizeOptions {
+            xatol: Some(1e-8),
+            adaptive: Some(true),
+            ..Default::default()
+        };
+        let res = nelder_mead(array![1.3, 0.7, 0.8, 1.9, 1.2], options);
+        assert_abs_diff_eq!(res.fun, 2.890749886613622e-17, epsilon = 1e-22);
+        assert_eq!((res.nit, res.nfev), (487, 838));
+
+        let options = MinimizeOptions {
+            initial_simplex: Some(array![[0., 0.], [1., 0.], [0., 1.]]),
+            ..Default::default()
+        };
+        let res = nelder_mead(array![-1.2, 1.], options);
+        assert_abs_diff_eq!(res.x[0], 0.999986962958794, epsilon = 1e-12);
+        assert_eq!((res.nit, res.nfev), (72, 137));
+    }
+
+    #[test]
+    fn bounds_and_limits() {
+        let options = MinimizeOptions {
+            bounds: Some(vec![(-2., 0.5), (-1., 2.)]),
+            ..Default::default()
+        };
+        let res = nelder_mead(array![-1.2, 1.], options);
+        assert_eq!(res.x[0], 0.5);
+        assert_abs_diff_eq!(res.x[1], 0.2500195980022476, epsilon = 1e-12);
+        assert_eq!((res.nit, res.nfev), (65, 119));
+
+        let options = MinimizeOptions {
+            maxfev: Some(30),
+            ..Default::default()
+        };
+        let res = nelder_mead(array![-1.2, 1.], options);
+        assert_abs_diff_eq!(res.fun, 3.466221131832191, epsilon = 1e-12);
+        assert_eq!((res.nit, res.nfev, res.status), (15, 30, 1));
+        assert!(!res.success);
+
+        let options = MinimizeOptions {
+            maxiter: Some(10),
+            ..Default::default()
+        };
+        let res = nelder_mead(array![-1.2, 1.], options);
+        assert_abs_diff_eq!(res.fun, 4.135559808808324, epsilon = 1e-12);
+        assert_eq!((res.nit, res.nfev, res.status), (10, 21, 2));
+    }
+
+    #[test]
+    fn invalid() {
+        let x0 = array![-1.2, 1.];
+        let minimize =
+            |x0: &Array1<f64>, options| minimize(rosen, x0, Method::NelderMead, None, options);
+        assert!(minimize(&array![], Default::default()).is_err());
+        assert!(minimize(&array![f64::NAN, 1.], Default::default()).is_err());
+        let options = MinimizeOptions {
+            initial_simplex: Some(array![[0., 0.], [1., 0.]]),
+            ..Default::default()
+        };
+        assert!(minimize(&x0, options).is_err());
+        let options = MinimizeOptions {
+            bounds: Some(vec![(0., 1.)]),
+            ..Default::default()
+        };
+        assert!(minimize(&x0, options).is_err());
+        let options = MinimizeOptions {
+            bounds: Some(vec![(0., 1.), (1., 0.)]),
+            ..Default::default()
+        };
+        assert!(minimize(&x0, options).is_err());
+    }
+}
@@ -0,0 +1,29 @@
+use alloc::string::String;
+
+/// Represents the optimization result.
+///
+/// `X` is the type of the solution, the scalar `F` for the minimizers of functions of one
+/// variable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptimizeResult<F, X = F> {
+    /// The solution of the optimization.
+    pub x: X,
+    /// Value of the objective function at the solution.
+    pub fun: F,
+    /// Number of iterations performed by the optimizer.
+    pub nit: usize,
+    /// Number of evaluations of the objective function.
+    pub nfev: usize,
+    /// Values of the gradient of the objective function at the solution, for the solvers that
+    /// use it.
+    pub jac: Option<X>,
+    /// Number of evaluations of the gradient of the objective function.
+    pub njev: usize,
+    /// Whether or not the optimizer exited successfully.
+    pub success: bool,
+    /// Termination status of the optimizer. Its value depends on the underlying solver, 0 meaning
+    /// success.
+    pub status: usize,
+    /// Description of the cause of the termination.
+    pub message: String,
+}
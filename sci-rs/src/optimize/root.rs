@@ -0,0 +1,230 @@
+use super::broyden::broyden;
+use super::hybr::hybr;
+use alloc::string::String;
+use ndarray::{Array1, Array2, ArrayBase, ArrayView1, Data, Ix1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Type of solver of [root].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RootMethod {
+    /// Modification of the Powell hybrid method, as implemented in MINPACK's `hybrd` and
+    /// `hybrj`.
+    #[default]
+    Hybr,
+    /// Broyden's first Jacobian approximation, known as Broyden's good method.
+    Broyden1,
+    /// Broyden's second Jacobian approximation, known as Broyden's bad method.
+    Broyden2,
+}
+
+/// Solver options of [root].
+///
+/// Every field left as `None` takes the default of the solver, which is also what [Default]
+/// gives. Options that do not apply to the chosen [RootMethod] are ignored.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RootOptions<F> {
+    /// The calculation terminates if the relative error between two consecutive iterates is at
+    /// most `xtol`. It is `1.49012e-8` for [RootMethod::Hybr], and unlimited for the Broyden
+    /// methods by default.
+    pub xtol: Option<F>,
+    /// Absolute tolerance of the Broyden methods on the maximum norm of the step, unlimited by
+    /// default.
+    pub xatol: Option<F>,
+    /// Absolute tolerance of the Broyden methods on the maximum norm of the residual, the cube
+    /// root of the machine epsilon by default.
+    pub fatol: Option<F>,
+    /// Relative tolerance of the Broyden methods on the maximum norm of the residual, unlimited
+    /// by default.
+    pub ftol: Option<F>,
+    /// The maximum number of calls to the function of [RootMethod::Hybr]. It is
+    /// `100 * (n + 1)` if `jac` is given and `200 * (n + 1)` otherwise by default.
+    pub maxfev: Option<usize>,
+    /// Maximum number of iterations of the Broyden methods, `100 * (n + 1)` by default.
+    pub maxiter: Option<usize>,
+    /// A suitable step length for the forward-difference approximation of the Jacobian of
+    /// [RootMethod::Hybr], if `jac` is not given. The relative errors of the function are
+    /// assumed to be of the order of `epsfcn`, which is the machine epsilon by default.
+    pub epsfcn: Option<F>,
+    /// Bound of the initial step of [RootMethod::Hybr], `factor * ||x0||`, 100 by default.
+    pub factor: Option<F>,
+}
+
+/// Result of [root].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiRootResult<F> {
+    /// The solution of the system of equations.
+    pub x: Array1<F>,
+    /// Values of the function at the solution.
+    pub fun: Array1<F>,
+    /// Approximation of the Jacobian at the solution, for [RootMethod::Hybr].
+    pub jac: Option<Array2<F>>,
+    /// Number of iterations.
+    pub nit: usize,
+    /// Number of evaluations of the function, including those of the finite differences.
+    pub nfev: usize,
+    /// Number of evaluations of the Jacobian.
+    pub njev: usize,
+    /// Whether or not the solver exited successfully.
+    pub success: bool,
+    /// Termination status of the solver, 1 meaning success. For [RootMethod::Hybr], it is 2 if
+    /// the maximum number of function evaluations was reached, 3 if `xtol` is too small for any
+    /// further improvement, 4 if the last five Jacobian evaluations and 5 if the last ten
+    /// iterations did not make good progress. For the Broyden methods, it is 2 if the maximum
+    /// number of iterations was reached.
+    pub status: usize,
+    /// Description of the cause of the termination.
+    pub message: String,
+}
+
+/// Find a root of a vector function.
+///
+/// ## Parameters
+/// * `fun`: A vector function to find a root of, of the 1-D array of the `n` variables, which
+///   returns `n` values.
+/// * `x0`: Initial guess, of shape `(n,)`.
+/// * `method`: Type of solver, [Hybr](RootMethod::Hybr) by default.
+/// * `jac`: The Jacobian of `fun`, of shape `(n, n)`. [Hybr](RootMethod::Hybr) approximates it
+///   by forward differences if it is not given, and the Broyden methods use it for their
+///   initial approximation.
+/// * `options`: [RootOptions] of the solver.
+///
+/// ## Returns
+/// The [MultiRootResult], whose `status` is 1 on success.
+///
+/// ## Errors
+/// If `x0` is empty or not finite, or `fun` does not return `n` finite values at `x0`, or `jac`
+/// does not return a matrix of shape `(n, n)`.
+///
+/// ## Notes
+/// Method [Hybr](RootMethod::Hybr) uses a modification of the Powell hybrid method: the step
+/// is the dogleg combination of the Newton step and of the steepest descent step of the sum of
+/// squares within a trust region. The Jacobian is updated by rank-1 Broyden updates between
+/// its evaluations, which only happen when the updates fail to make progress [1]. The linear
+/// systems are solved by an LU factorization of the Jacobian, rather than the QR factorization
+/// of MINPACK.
+///
+/// Methods [Broyden1](RootMethod::Broyden1) and [Broyden2](RootMethod::Broyden2) update an
+/// approximation of the inverse Jacobian by Broyden's rank-1 updates [2], and take the steps
+/// with an Armijo backtracking line search on the sum of squares.
+///
+/// ## References
+/// 1. More, Jorge J., Burton S. Garbow, and Kenneth E. Hillstrom. 1980. User Guide for
+///    MINPACK-1.
+/// 2. C. G. Broyden, "A Class of Methods for Solving Nonlinear Simultaneous Equations".
+///    Mathematics of Computation. American Mathematical Society 19 (92): 577-593, 1965.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import optimize
+/// def fun(x):
+///     return [x[0]  + 0.5 * (x[0] - x[1])**3 - 1.0,
+///             0.5 * (x[1] - x[0])**3 + x[1]]
+/// def jac(x):
+///     return np.array([[1 + 1.5 * (x[0] - x[1])**2, -1.5 * (x[0] - x[1])**2],
+///                      [-1.5 * (x[1] - x[0])**2, 1 + 1.5 * (x[1] - x[0])**2]])
+/// sol = optimize.root(fun, [0, 0], jac=jac, method='hybr')
+/// sol.x
+/// # array([ 0.8411639,  0.1588361])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{array, Array1, Array2, ArrayView1};
+/// use sci_rs::optimize::{root, RootMethod};
+///
+/// let fun = |x: ArrayView1<f64>| {
+///     array![
+///         x[0] + 0.5 * (x[0] - x[1]).powi(3) - 1.,
+///         0.5 * (x[1] - x[0]).powi(3) + x[1]
+///     ]
+/// };
+/// let mut jac = |x: ArrayView1<f64>| {
+///     let d = 1.5 * (x[0] - x[1]).powi(2);
+///     array![[1. + d, -d], [-d, 1. + d]]
+/// };
+/// let x0 = array![0., 0.];
+/// let sol = root(fun, &x0, Some(RootMethod::Hybr), Some(&mut jac), Default::default()).unwrap();
+/// assert!(sol.success);
+/// assert_abs_diff_eq!(sol.x[0], 0.8411639, epsilon = 1e-7);
+/// assert_abs_diff_eq!(sol.x[1], 0.1588361, epsilon = 1e-7);
+///
+/// let sol = root(fun, &x0, Some(RootMethod::Broyden1), None, Default::default()).unwrap();
+/// assert!(sol.success);
+/// assert_abs_diff_eq!(sol.x[0], 0.8411639, epsilon = 1e-5);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn root<F, S>(
+    mut fun: impl FnMut(ArrayView1<F>) -> Array1<F>,
+    x0: &ArrayBase<S, Ix1>,
+    method: Option<RootMethod>,
+    mut jac: Option<&mut dyn FnMut(ArrayView1<F>) -> Array2<F>>,
+    options: RootOptions<F>,
+) -> Result<MultiRootResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let n = x0.len();
+    if n == 0 || x0.iter().any(|x| !x.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "x0".into(),
+            reason: "x0 must be a non-empty array of finite values.".into(),
+        });
+    }
+    let x0 = x0.to_owned();
+    let f0 = fun(x0.view());
+    if f0.len() != n {
+        return Err(Error::InvalidArg {
+            arg: "fun".into(),
+            reason: "The number of values of `fun` is not equal to the number of variables.".into(),
+        });
+    }
+    if f0.iter().any(|f| !f.is_finite()) {
+        return Err(Error::InvalidArg {
+            arg: "fun".into(),
+            reason: "The values of `fun` are not finite at `x0`.".into(),
+        });
+    }
+    let j0 = jac.as_mut().map(|jac| jac(x0.view()));
+    if j0.as_ref().is_some_and(|j0| j0.dim() != (n, n)) {
+        return Err(Error::InvalidArg {
+            arg: "jac".into(),
+            reason: "The return value of `jac` has wrong shape.".into(),
+        });
+    }
+    Ok(match method.unwrap_or_default() {
+        RootMethod::Hybr => hybr(fun, jac, x0, f0, j0, &options),
+        RootMethod::Broyden1 => broyden(fun, x0, f0, j0, false, &options),
+        RootMethod::Broyden2 => broyden(fun, x0, f0, j0, true, &options),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use ndarray::array;
+
+    #[test]
+    fn invalid_args() {
+        let fun = |x: ArrayView1<f64>| array![x[0] - 1., x[1] - 2.];
+        assert!(root(fun, &array![], None, None, Default::default()).is_err());
+        assert!(root(fun, &array![f64::NAN, 0.], None, None, Default::default()).is_err());
+        assert!(root(fun, &array![0., 0., 0.], None, None, Default::default()).is_err());
+        let fun = |x: ArrayView1<f64>| array![x[0].ln(), x[1]];
+        assert!(root(fun, &array![-1., 0.], None, None, Default::default()).is_err());
+        let fun = |x: ArrayView1<f64>| array![x[0] - 1., x[1] - 2.];
+        let mut jac = |_: ArrayView1<f64>| Array2::eye(3);
+        assert!(root(
+            fun,
+            &array![0., 0.],
+            None,
+            Some(&mut jac),
+            Default::default()
+        )
+        .is_err());
+    }
+}
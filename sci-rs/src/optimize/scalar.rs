@@ -0,0 +1,708 @@
+use super::OptimizeResult;
+use alloc::string::String;
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Method of minimization of [minimize_scalar].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MinimizeScalarMethod {
+    /// Brent's method, interpolating by parabolas and falling back to golden-section steps.
+    #[default]
+    Brent,
+    /// The golden-section search.
+    Golden,
+    /// Brent's method restricted to the interval given by the bounds.
+    Bounded,
+}
+
+/// A bracket of a minimum: `xb` is strictly between `xa` and `xc`, and `fb` is no greater than
+/// `fa` and `fc`, one of them strictly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bracket<F> {
+    /// Left end of the bracket, which may be greater than `xc`.
+    pub xa: F,
+    /// Interior point of the bracket.
+    pub xb: F,
+    /// Right end of the bracket.
+    pub xc: F,
+    /// Objective function value at `xa`.
+    pub fa: F,
+    /// Objective function value at `xb`.
+    pub fb: F,
+    /// Objective function value at `xc`.
+    pub fc: F,
+    /// Number of function evaluations made.
+    pub funcalls: usize,
+}
+
+/// Bracket the minimum of a function.
+///
+/// Given a function and distinct initial points, search in the downhill direction (as defined by
+/// the initial points) and return three points that bracket the minimum of the function.
+///
+/// ## Parameters
+/// * `func`: Objective function to minimize.
+/// * `xa`, `xb`: Initial points, 0 and 1 by default. Note that `xa` and `xb` may be swapped, and
+///   the search is in the direction of the lower of the values at them.
+/// * `grow_limit`: Maximum grow limit, 110 by default.
+/// * `maxiter`: Maximum number of iterations to perform, 1000 by default.
+///
+/// ## Returns
+/// The [Bracket], such that `xa < xb < xc` or `xc < xb < xa`.
+///
+/// ## Errors
+/// If no valid bracket is found before the iteration limit, or the search ends on points that do
+/// not bracket a minimum, e.g. when the function is unbounded below or its minimum is at the
+/// initial points.
+///
+/// ## Notes
+/// The points are expanded geometrically by the golden ratio, and by parabolic extrapolation up
+/// to `grow_limit` times the last step.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import optimize
+/// def f(x):
+///     return 10*x**2 + 3*x + 5
+/// xa, xb = 0.1, 1
+/// xa, xb, xc, fa, fb, fc, funcalls = optimize.bracket(f, xa, xb)
+/// (xa < xb < xc) or (xc < xb < xa), fb < fa and fb < fc
+/// # (True, True)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use sci_rs::optimize::bracket;
+///
+/// let f = |x: f64| 10. * x * x + 3. * x + 5.;
+/// let b = bracket(f, Some(0.1), Some(1.), None, None).unwrap();
+/// assert!((b.xa < b.xb && b.xb < b.xc) || (b.xc < b.xb && b.xb < b.xa));
+/// assert!(b.fb < b.fa && b.fb < b.fc);
+/// ```
+pub fn bracket<F: Float>(
+    mut func: impl FnMut(F) -> F,
+    xa: Option<F>,
+    xb: Option<F>,
+    grow_limit: Option<F>,
+    maxiter: Option<usize>,
+) -> Result<Bracket<F>> {
+    let gold = F::from(1.618034).unwrap();
+    let very_small = F::from(1e-21).unwrap();
+    let two = F::from(2).unwrap();
+    let grow_limit = grow_limit.unwrap_or_else(|| F::from(110).unwrap());
+    let maxiter = maxiter.unwrap_or(1000);
+
+    let (mut xa, mut xb) = (xa.unwrap_or_else(F::zero), xb.unwrap_or_else(F::one));
+    let (mut fa, mut fb) = (func(xa), func(xb));
+    // Switch so that fa >= fb.
+    if fa < fb {
+        (xa, xb) = (xb, xa);
+        (fa, fb) = (fb, fa);
+    }
+    let mut xc = xb + gold * (xb - xa);
+    let mut fc = func(xc);
+    let mut funcalls = 3;
+    let mut iter = 0;
+    while fc < fb {
+        let tmp1 = (xb - xa) * (fb - fc);
+        let tmp2 = (xb - xc) * (fb - fa);
+        let val = tmp2 - tmp1;
+        let denom = if val.abs() < very_small {
+            two * very_small
+        } else {
+            two * val
+        };
+        // Parabolic extrapolation, limited to `wlim`.
+        let mut w = xb - ((xb - xc) * tmp2 - (xb - xa) * tmp1) / denom;
+        let wlim = xb + grow_limit * (xc - xb);
+        if iter > maxiter {
+            return Err(Error::InvalidArg {
+                arg: "maxiter".into(),
+                reason: "No valid bracket was found before the iteration limit was reached. \
+                         Consider trying different initial points or increasing `maxiter`."
+                    .into(),
+            });
+        }
+        iter += 1;
+        let mut fw;
+        if (w - xc) * (xb - w) > F::zero() {
+            fw = func(w);
+            funcalls += 1;
+            if fw < fc {
+                (xa, xb) = (xb, w);
+                (fa, fb) = (fb, fw);
+                break;
+            } else if fw > fb {
+                (xc, fc) = (w, fw);
+                break;
+            }
+            w = xc + gold * (xc - xb);
+            fw = func(w);
+            funcalls += 1;
+        } else if (w - wlim) * (wlim - xc) >= F::zero() {
+            w = wlim;
+            fw = func(w);
+            funcalls += 1;
+        } else if (w - wlim) * (xc - w) > F::zero() {
+            fw = func(w);
+            funcalls += 1;
+            if fw < fc {
+                (xb, xc) = (xc, w);
+                w = xc + gold * (xc - xb);
+                (fb, fc) = (fc, fw);
+                fw = func(w);
+                funcalls += 1;
+            }
+        } else {
+            w = xc + gold * (xc - xb);
+            fw = func(w);
+            funcalls += 1;
+        }
+        (xa, xb, xc) = (xb, xc, w);
+        (fa, fb, fc) = (fb, fc, fw);
+    }
+
+    let decreasing = (fb < fc && fb <= fa) || (fb < fa && fb <= fc);
+    let ordered = (xa < xb && xb < xc) || (xc < xb && xb < xa);
+    let finite = xa.is_finite() && xb.is_finite() && xc.is_finite();
+    if !(decreasing && ordered && finite) {
+        return Err(Error::InvalidArg {
+            arg: "func".into(),
+            reason: "The algorithm terminated without finding a valid bracket. \
+                     Consider trying different initial points."
+                .into(),
+        });
+    }
+    Ok(Bracket {
+        xa,
+        xb,
+        xc,
+        fa,
+        fb,
+        fc,
+        funcalls,
+    })
+}
+
+/// Local minimization of scalar function of one variable.
+///
+/// ## Parameters
+/// * `fun`: Objective function.
+/// * `bracket`: For methods [Brent](MinimizeScalarMethod::Brent) and
+///   [Golden](MinimizeScalarMethod::Golden), defines the bracketing interval and is required by
+///   either method. It can be `[xa, xb, xc]`, satisfying `xa < xb < xc` and `fun(xb) < fun(xa)`
+///   and `fun(xb) < fun(xc)`, or `[xa, xb]`, the starting points of a downhill [bracket] search.
+///   The search starts from `[0, 1]` if it is `None`. Ignored by the bounded method.
+/// * `bounds`: For method [Bounded](MinimizeScalarMethod::Bounded), `bounds` is mandatory and
+///   must have two finite items corresponding to the optimization bounds.
+/// * `method`: Type of solver, [Bounded](MinimizeScalarMethod::Bounded) if `bounds` are given and
+///   [Brent](MinimizeScalarMethod::Brent) otherwise.
+///   * [Brent](MinimizeScalarMethod::Brent): relative tolerance `tol`, `1.48e-8` by default, and
+///     at most `maxiter` iterations, 500 by default.
+///   * [Golden](MinimizeScalarMethod::Golden): relative tolerance `tol`, the square root of the
+///     machine epsilon by default, and at most `maxiter` iterations, 5000 by default.
+///   * [Bounded](MinimizeScalarMethod::Bounded): absolute tolerance `tol`, `1e-5` by default, and
+///     at most `maxiter` function evaluations, 500 by default.
+/// * `tol`: Tolerance for termination.
+/// * `maxiter`: Maximum number of iterations, or function evaluations for the bounded method.
+///
+/// ## Returns
+/// The optimization result, with `status` 0 on success, 1 if the iteration limit was reached and
+/// 2 if a NaN was encountered.
+///
+/// ## Errors
+/// If `bounds` are given for the Brent or golden methods, or missing for the bounded method, if
+/// the bounds are not finite and increasing, or the bracket is invalid or cannot be found.
+///
+/// ## Notes
+/// Brent's method uses inverse parabolic interpolation when possible to speed up convergence of
+/// the golden section method. The golden section search is guaranteed to converge in linear time,
+/// but is usually slower. The bounded method is Brent's method confined to the bounds, which
+/// never evaluates the function at the bounds themselves.
+///
+/// ## References
+/// 1. Press, W., S.A. Teukolsky, W.T. Vetterling, and B.P. Flannery. Numerical Recipes in C.
+///    Cambridge University Press.
+/// 2. Brent, R. P. Algorithms for Minimization Without Derivatives. Englewood Cliffs, NJ:
+///    Prentice-Hall, 1973.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.optimize import minimize_scalar
+/// def f(x):
+///     return (x - 2) * x * (x + 2)**2
+/// res = minimize_scalar(f)
+/// res.x
+/// # 1.28077640403
+/// res = minimize_scalar(f, bounds=(-3, -1), method='bounded')
+/// res.x
+/// # -2.0000002026
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use sci_rs::optimize::minimize_scalar;
+///
+/// let f = |x: f64| (x - 2.) * x * (x + 2.).powi(2);
+/// let res = minimize_scalar(f, None, None, None, None, None).unwrap();
+/// assert_abs_diff_eq!(res.x, 1.28077640403, epsilon = 1e-8);
+/// let res = minimize_scalar(f, None, Some((-3., -1.)), None, None, None).unwrap();
+/// assert_abs_diff_eq!(res.x, -2.0000002026, epsilon = 1e-8);
+/// ```
+pub fn minimize_scalar<F: Float>(
+    mut fun: impl FnMut(F) -> F,
+    bracket: Option<&[F]>,
+    bounds: Option<(F, F)>,
+    method: Option<MinimizeScalarMethod>,
+    tol: Option<F>,
+    maxiter: Option<usize>,
+) -> Result<OptimizeResult<F>> {
+    let method = method.unwrap_or(if bounds.is_some() {
+        MinimizeScalarMethod::Bounded
+    } else {
+        MinimizeScalarMethod::Brent
+    });
+    if tol.is_some_and(|tol| tol.is_nan() || tol < F::zero()) {
+        return Err(Error::InvalidArg {
+            arg: "tol".into(),
+            reason: "tol must be non-negative.".into(),
+        });
+    }
+    match (method, bounds) {
+        (MinimizeScalarMethod::Bounded, Some((lower, upper))) => {
+            if !lower.is_finite() || !upper.is_finite() {
+                return Err(Error::InvalidArg {
+                    arg: "bounds".into(),
+                    reason: "Optimization bounds must be finite scalars.".into(),
+                });
+            }
+            if lower > upper {
+                return Err(Error::InvalidArg {
+                    arg: "bounds".into(),
+                    reason: "The lower bound exceeds the upper bound.".into(),
+                });
+            }
+            let xatol = tol.unwrap_or_else(|| F::from(1e-5).unwrap());
+            Ok(bounded(fun, lower, upper, xatol, maxiter.unwrap_or(500)))
+        }
+        (MinimizeScalarMethod::Bounded, None) => Err(Error::InvalidArg {
+            arg: "bounds".into(),
+            reason: "The `bounds` parameter is mandatory for method `bounded`.".into(),
+        }),
+        (_, Some(_)) => Err(Error::InvalidArg {
+            arg: "bounds".into(),
+            reason: "Use of `bounds` is incompatible with methods `brent` and `golden`.".into(),
+        }),
+        (MinimizeScalarMethod::Brent, None) => {
+            let brack = bracket_info(&mut fun, bracket)?;
+            let xtol = tol.unwrap_or_else(|| F::from(1.48e-8).unwrap());
+            Ok(brent(fun, brack, xtol, maxiter.unwrap_or(500)))
+        }
+        (MinimizeScalarMethod::Golden, None) => {
+            let brack = bracket_info(&mut fun, bracket)?;
+            let xtol = tol.unwrap_or_else(|| F::epsilon().sqrt());
+            Ok(golden(fun, brack, xtol, maxiter.unwrap_or(5000)))
+        }
+    }
+}
+
+/// Find, or check, the bracket of the Brent and golden methods.
+fn bracket_info<F: Float>(mut fun: impl FnMut(F) -> F, brack: Option<&[F]>) -> Result<Bracket<F>> {
+    match brack {
+        None => bracket(fun, None, None, None, None),
+        Some(&[xa, xb]) => bracket(fun, Some(xa), Some(xb), None, None),
+        Some(&[xa, xb, xc]) => {
+            let (xa, xc) = if xa > xc { (xc, xa) } else { (xa, xc) };
+            if !(xa < xb && xb < xc) {
+                return Err(Error::InvalidArg {
+                    arg: "bracket".into(),
+                    reason: "Bracketing values (xa, xb, xc) do not fulfill this requirement: \
+                             (xa < xb) and (xb < xc)"
+                        .into(),
+                });
+            }
+            let (fa, fb, fc) = (fun(xa), fun(xb), fun(xc));
+            if !(fb < fa && fb < fc) {
+                return Err(Error::InvalidArg {
+                    arg: "bracket".into(),
+                    reason: "Bracketing values (xa, xb, xc) do not fulfill this requirement: \
+                             (f(xb) < f(xa)) and (f(xb) < f(xc))"
+                        .into(),
+                });
+            }
+            Ok(Bracket {
+                xa,
+                xb,
+                xc,
+                fa,
+                fb,
+                fc,
+                funcalls: 3,
+            })
+        }
+        Some(_) => Err(Error::InvalidArg {
+            arg: "bracket".into(),
+            reason: "Bracketing interval must be length 2 or 3 sequence.".into(),
+        }),
+    }
+}
+
+/// Result of the Brent and golden methods, which fail on reaching `maxiter` or a NaN.
+fn scalar_result<F: Float>(
+    x: F,
+    fun: F,
+    nit: usize,
+    nfev: usize,
+    maxiter: usize,
+) -> OptimizeResult<F> {
+    let (status, message) = if x.is_nan() || fun.is_nan() {
+        (2, "NaN result encountered.")
+    } else if nit >= maxiter {
+        (1, "Maximum number of iterations exceeded.")
+    } else {
+        (0, "Optimization terminated successfully.")
+    };
+    OptimizeResult {
+        x,
+        fun,
+        nit,
+        nfev,
+        jac: None,
+        njev: 0,
+        success: status == 0,
+        status,
+        message: String::from(message),
+    }
+}
+
+/// Brent's method from the bracket, with relative tolerance `tol`.
+fn brent<F: Float>(
+    mut fun: impl FnMut(F) -> F,
+    brack: Bracket<F>,
+    tol: F,
+    maxiter: usize,
+) -> OptimizeResult<F> {
+    let mintol = F::from(1e-11).unwrap();
+    let cg = F::from(0.381966).unwrap();
+    let (half, two) = (F::from(0.5).unwrap(), F::from(2).unwrap());
+
+    let Bracket { xa, xc, fb, .. } = brack;
+    let (mut x, mut w, mut v) = (brack.xb, brack.xb, brack.xb);
+    let (mut fx, mut fw, mut fv) = (fb, fb, fb);
+    let (mut a, mut b) = if xa < xc { (xa, xc) } else { (xc, xa) };
+    let (mut deltax, mut rat) = (F::zero(), F::zero());
+    let mut funcalls = brack.funcalls;
+    let mut iter = 0;
+    while iter < maxiter {
+        let tol1 = tol * x.abs() + mintol;
+        let tol2 = two * tol1;
+        let xmid = half * (a + b);
+        if (x - xmid).abs() < tol2 - half * (b - a) {
+            break;
+        }
+        let mut golden = true;
+        if deltax.abs() > tol1 {
+            // Parabolic step through x, w and v.
+            let tmp1 = (x - w) * (fx - fv);
+            let tmp2 = (x - v) * (fx - fw);
+            let mut p = (x - v) * tmp2 - (x - w) * tmp1;
+            let mut tmp2 = two * (tmp2 - tmp1);
+            if tmp2 > F::zero() {
+                p = -p;
+            }
+            tmp2 = tmp2.abs();
+            let dx_temp = deltax;
+            deltax = rat;
+            if p > tmp2 * (a - x) && p < tmp2 * (b - x) && p.abs() < (half * tmp2 * dx_temp).abs() {
+                golden = false;
+                rat = p / tmp2;
+                let u = x + rat;
+                if u - a < tol2 || b - u < tol2 {
+                    rat = if xmid - x >= F::zero() { tol1 } else { -tol1 };
+                }
+            }
+        }
+        if golden {
+            deltax = if x >= xmid { a - x } else { b - x };
+            rat = cg * deltax;
+        }
+
+        // Move by at least tol1.
+        let u = if rat.abs() >= tol1 {
+            x + rat
+        } else if rat >= F::zero() {
+            x + tol1
+        } else {
+            x - tol1
+        };
+        let fu = fun(u);
+        funcalls += 1;
+        if fu > fx {
+            if u < x {
+                a = u;
+            } else {
+                b = u;
+            }
+            if fu <= fw || w == x {
+                (v, w) = (w, u);
+                (fv, fw) = (fw, fu);
+            } else if fu <= fv || v == x || v == w {
+                (v, fv) = (u, fu);
+            }
+        } else {
+            if u >= x {
+                a = x;
+            } else {
+                b = x;
+            }
+            (v, w, x) = (w, x, u);
+            (fv, fw, fx) = (fw, fx, fu);
+        }
+        iter += 1;
+    }
+    scalar_result(x, fx, iter, funcalls, maxiter)
+}
+
+/// The golden-section search from the bracket, with relative tolerance `tol`.
+fn golden<F: Float>(
+    mut fun: impl FnMut(F) -> F,
+    brack: Bracket<F>,
+    tol: F,
+    maxiter: usize,
+) -> OptimizeResult<F> {
+    let gr = F::from(0.61803399).unwrap();
+    let gc = F::one() - gr;
+    let Bracket { xa, xb, xc, .. } = brack;
+    let (mut x0, mut x3) = (xa, xc);
+    let (mut x1, mut x2) = if (xc - xb).abs() > (xb - xa).abs() {
+        (xb, xb + gc * (xc - xb))
+    } else {
+        (xb - gc * (xb - xa), xb)
+    };
+    let (mut f1, mut f2) = (fun(x1), fun(x2));
+    let mut funcalls = brack.funcalls + 2;
+    let mut nit = 0;
+    while nit < maxiter {
+        if (x3 - x0).abs() <= tol * (x1.abs() + x2.abs()) {
+            break;
+        }
+        if f2 < f1 {
+            (x0, x1) = (x1, x2);
+            x2 = gr * x1 + gc * x3;
+            f1 = f2;
+            f2 = fun(x2);
+        } else {
+            (x3, x2) = (x2, x1);
+            x1 = gr * x2 + gc * x0;
+            f2 = f1;
+            f1 = fun(x1);
+        }
+        funcalls += 1;
+        nit += 1;
+    }
+    let (x, f) = if f1 < f2 { (x1, f1) } else { (x2, f2) };
+    scalar_result(x, f, nit, funcalls, maxiter)
+}
+
+/// Brent's method in `[a, b]`, with absolute tolerance `xatol` and at most `maxfun` evaluations.
+fn bounded<F: Float>(
+    mut fun: impl FnMut(F) -> F,
+    mut a: F,
+    mut b: F,
+    xatol: F,
+    maxfun: usize,
+) -> OptimizeResult<F> {
+    let (half, two, three) = (
+        F::from(0.5).unwrap(),
+        F::from(2).unwrap(),
+        F::from(3).unwrap(),
+    );
+    let sqrt_eps = F::from(2.2e-16).unwrap().sqrt();
+    let golden_mean = half * (three - F::from(5).unwrap().sqrt());
+    let sign = |x: F| if x < F::zero() { -F::one() } else { F::one() };
+
+    let mut fulc = a + golden_mean * (b - a);
+    let (mut nfc, mut xf) = (fulc, fulc);
+    let (mut rat, mut e) = (F::zero(), F::zero());
+    let mut fx = fun(xf);
+    let mut num = 1;
+    let mut fu = F::infinity();
+    let (mut ffulc, mut fnfc) = (fx, fx);
+    let mut xm = half * (a + b);
+    let mut tol1 = sqrt_eps * xf.abs() + xatol / three;
+    let mut tol2 = two * tol1;
+    let mut status = 0;
+    while (xf - xm).abs() > tol2 - half * (b - a) {
+        let mut golden = true;
+        // Check for a parabolic fit.
+        if e.abs() > tol1 {
+            let r = (xf - nfc) * (fx - ffulc);
+            let q = (xf - fulc) * (fx - fnfc);
+            let mut p = (xf - fulc) * q - (xf - nfc) * r;
+            let mut q = two * (q - r);
+            if q > F::zero() {
+                p = -p;
+            }
+            q = q.abs();
+            let r = e;
+            e = rat;
+            if p.abs() < (half * q * r).abs() && p > q * (a - xf) && p < q * (b - xf) {
+                golden = false;
+                rat = p / q;
+                let x = xf + rat;
+                if x - a < tol2 || b - x < tol2 {
+                    rat = tol1 * sign(xm - xf);
+                }
+            }
+        }
+        if golden {
+            e = if xf >= xm { a - xf } else { b - xf };
+            rat = golden_mean * e;
+        }
+        let x = xf + sign(rat) * rat.abs().max(tol1);
+        fu = fun(x);
+        num += 1;
+        if fu <= fx {
+            if x >= xf {
+                a = xf;
+            } else {
+                b = xf;
+            }
+            (fulc, ffulc) = (nfc, fnfc);
+            (nfc, fnfc) = (xf, fx);
+            (xf, fx) = (x, fu);
+        } else {
+            if x < xf {
+                a = x;
+            } else {
+                b = x;
+            }
+            if fu <= fnfc || nfc == xf {
+                (fulc, ffulc) = (nfc, fnfc);
+                (nfc, fnfc) = (x, fu);
+            } else if fu <= ffulc || fulc == xf || fulc == nfc {
+                (fulc, ffulc) = (x, fu);
+            }
+        }
+        xm = half * (a + b);
+        tol1 = sqrt_eps * xf.abs() + xatol / three;
+        tol2 = two * tol1;
+        if num >= maxfun {
+            status = 1;
+            break;
+        }
+    }
+    if xf.is_nan() || fx.is_nan() || fu.is_nan() {
+        status = 2;
+    }
+    let message = match status {
+        0 => "Solution found.",
+        1 => "Maximum number of function calls reached.",
+        _ => "NaN result encountered.",
+    };
+    OptimizeResult {
+        x: xf,
+        fun: fx,
+        nit: num,
+        nfev: num,
+        jac: None,
+        njev: 0,
+        success: status == 0,
+        status,
+        message: String::from(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    fn f(x: f64) -> f64 {
+        (x - 2.) * x * (x + 2.).powi(2)
+    }
+
+    #[test]
+    fn bracket_expansion() {
+        let b = bracket(f, None, None, None, None).unwrap();
+        assert_eq!((b.xa, b.xb, b.funcalls), (0., 1., 3));
+        assert_abs_diff_eq!(b.xc, 2.618034, epsilon = 1e-12);
+        // Downhill from 0 towards -1, past the local maximum at -2.
+        let b = bracket(f, Some(0.), Some(-1.), None, None).unwrap();
+        assert_eq!((b.xa, b.xb, b.funcalls), (0., 1.618034, 4));
+        assert_abs_diff_eq!(b.xc, 4.236068025156, epsilon = 1e-9);
+
+        assert!(bracket(|_: f64| 1., None, None, None, None).is_err());
+        assert!(bracket(|x: f64| x, None, None, None, Some(10)).is_err());
+    }
+
+    #[test]
+    fn methods() {
+        let minimize = |bracket: Option<&[f64]>, method| {
+            minimize_scalar(f, bracket, None, Some(method), None, None).unwrap()
+        };
+        let res = minimize(None, MinimizeScalarMethod::Brent);
+        assert_abs_diff_eq!(res.x, 1.2807764040333458, epsilon = 1e-12);
+        assert_abs_diff_eq!(res.fun, -9.914949590828147, epsilon = 1e-12);
+        assert_eq!((res.nit, res.nfev, res.status), (11, 14, 0));
+        assert!(res.success);
+
+        let res = minimize(None, MinimizeScalarMethod::Golden);
+        assert_abs_diff_eq!(res.x, 1.280776401465682, epsilon = 1e-12);
+        assert_eq!((res.nit, res.nfev), (38, 43));
+
+        let res = minimize(Some(&[-3., -1.]), MinimizeScalarMethod::Brent);
+        assert_abs_diff_eq!(res.x, 1.2807764079128436, epsilon = 1e-12);
+        assert_eq!((res.nit, res.nfev), (11, 14));
+
+        let res = minimize(Some(&[-1., -2.5, -3.]), MinimizeScalarMethod::Golden);
+        assert_abs_diff_eq!(res.x, -2.0000000034047725, epsilon = 1e-12);
+        assert_eq!((res.nit, res.nfev), (37, 42));
+
+        let res = minimize_scalar(f, None, None, None, None, Some(5)).unwrap();
+        assert_eq!((res.nit, res.status), (5, 1));
+        assert!(!res.success);
+    }
+
+    #[test]
+    fn bounded() {
+        let res = minimize_scalar(f, None, Some((-3., -1.)), None, None, None).unwrap();
+        assert_abs_diff_eq!(res.x, -2.0000002026, epsilon = 1e-9);
+        assert!(res.success);
+        assert_eq!(res.nit, res.nfev);
+
+        // Minima at the bounds are approached within the tolerance.
+        let res = minimize_scalar(|x: f64| x, None, Some((0., 5.)), None, None, None).unwrap();
+        assert_abs_diff_eq!(res.x, 0., epsilon = 1e-4);
+        let res = minimize_scalar(
+            |x: f64| (x - 1.).powi(2),
+            None,
+            Some((0., 5.)),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_abs_diff_eq!(res.x, 1., epsilon = 1e-5);
+
+        let res =
+            minimize_scalar(|x: f64| x.cos(), None, Some((0., 5.)), None, None, Some(4)).unwrap();
+        assert_eq!((res.nfev, res.status), (4, 1));
+    }
+
+    #[test]
+    fn invalid() {
+        let bounded = Some(MinimizeScalarMethod::Bounded);
+        assert!(minimize_scalar(f, None, None, bounded, None, None).is_err());
+        assert!(minimize_scalar(f, None, Some((1., 0.)), None, None, None).is_err());
+        assert!(minimize_scalar(f, None, Some((0., f64::INFINITY)), None, None, None).is_err());
+        let golden = Some(MinimizeScalarMethod::Golden);
+        assert!(minimize_scalar(f, None, Some((0., 1.)), golden, None, None).is_err());
+        // Not ordered, not bracketing a minimum, and of the wrong length.
+        assert!(minimize_scalar(f, Some(&[0., -1., 1.]), None, None, None, None).is_err());
+        assert!(minimize_scalar(f, Some(&[-3., -1., 0.]), None, None, None, None).is_err());
+        assert!(minimize_scalar(f, Some(&[0.]), None, None, None, None).is_err());
+        assert!(minimize_scalar(f, None, None, None, Some(-1.), None).is_err());
+    }
+}
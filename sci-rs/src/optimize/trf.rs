@@ -0,0 +1,499 @@
+use super::least_squares::{
+    compute_grad, find_active_constraints, make_strictly_feasible, LeastSquaresResult,
+    LossFunction, Residuals,
+};
+use super::line_search::dot;
+use super::trust_region::{matvec, norm, solve_lsq_trust_region, update_tr_radius, Svd};
+use ndarray::{Array1, Array2, ArrayView1};
+use num_traits::Float;
+
+/// Minimization by the Trust Region Reflective algorithm, as [super::least_squares] with
+/// [super::LeastSquaresMethod::Trf].
+///
+/// The variables are scaled by the Coleman-Li vector `v`, which is the distance to the bound
+/// that the gradient points to, and the trust-region subproblems in these scaled variables are
+/// solved exactly. A step that would leave the feasible region is compared to its reflection
+/// from the first bound it crosses and to the scaled steepest descent step.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn trf<F: Float, G: FnMut(ArrayView1<F>) -> Array1<F>>(
+    residuals: &mut Residuals<'_, F, G>,
+    x0: Array1<F>,
+    f0: Array1<F>,
+    j0: Array2<F>,
+    lb: &Array1<F>,
+    ub: &Array1<F>,
+    loss: &LossFunction<F>,
+    (ftol, xtol, gtol): (F, F, F),
+    max_nfev: usize,
+) -> LeastSquaresResult<F> {
+    let (m, n) = j0.dim();
+    let mut x = x0;
+    let mut f_true = f0;
+    let mut j_true = j0;
+    let mut cost = loss.cost(&f_true);
+    let (mut jac, mut f) = loss.scale(&j_true, &f_true);
+    let mut g = compute_grad(&jac, &f);
+
+    let (v, _) = cl_scaling_vector(&x, &g, lb, ub);
+    let mut delta = norm(&(&x / &v.mapv(F::sqrt)));
+    if delta.is_zero() {
+        delta = F::one();
+    }
+
+    let mut alpha = F::zero();
+    let mut status = None;
+    let mut g_norm;
+    loop {
+        let (v, dv) = cl_scaling_vector(&x, &g, lb, ub);
+        g_norm = (&g * &v).fold(F::zero(), |acc, g| acc.max(g.abs()));
+        if g_norm < gtol {
+            status = Some(1);
+        }
+        if status.is_some() || residuals.nfev == max_nfev {
+            break;
+        }
+
+        // The problem in the variables scaled by d = v^0.5 has the additional diagonal term
+        // diag(g * dv) in its Hessian.
+        let d = v.mapv(F::sqrt);
+        let diag_h = &g * &dv;
+        let g_h = &d * &g;
+        let j_h = Array2::from_shape_fn((m, n), |(i, j)| jac[(i, j)] * d[j]);
+        let j_augmented = Array2::from_shape_fn((m + n, n), |(i, j)| match i.checked_sub(m) {
+            None => j_h[(i, j)],
+            Some(i) if i == j => diag_h[j].sqrt(),
+            Some(_) => F::zero(),
+        });
+        let f_augmented = Array1::from_shape_fn(m + n, |i| if i < m { f[i] } else { F::zero() });
+        let svd = Svd::new(&j_augmented, &f_augmented);
+        let theta = F::from(0.995).unwrap().max(F::one() - g_norm);
+        let tr = TrustRegion {
+            x: &x,
+            j_h: &j_h,
+            diag_h: &diag_h,
+            g_h: &g_h,
+            d: &d,
+            lb,
+            ub,
+            theta,
+        };
+
+        let mut actual_reduction = -F::one();
+        let mut accepted = None;
+        while actual_reduction <= F::zero() && residuals.nfev < max_nfev {
+            let (p_h, a) = solve_lsq_trust_region(m, &svd, delta, alpha);
+            alpha = a;
+            let (step, step_h, predicted_reduction) = tr.select_step(p_h, delta);
+
+            let x_new = make_strictly_feasible(&x + &step, lb, ub, false);
+            let f_new = residuals.fun(&x_new);
+            let step_h_norm = norm(&step_h);
+            if f_new.iter().any(|f| !f.is_finite()) {
+                delta = F::from(0.25).unwrap() * step_h_norm;
+                continue;
+            }
+
+            let cost_new = loss.cost(&f_new);
+            actual_reduction = cost - cost_new;
+            let (delta_new, ratio) = update_tr_radius(
+                delta,
+                actual_reduction,
+                predicted_reduction,
+                step_h_norm,
+                step_h_norm > F::from(0.95).unwrap() * delta,
+            );
+            if actual_reduction > F::zero() {
+                accepted = Some((x_new, f_new, cost_new));
+            }
+            status = check_termination(
+                actual_reduction,
+                cost,
+                norm(&step),
+                norm(&x),
+                ratio,
+                ftol,
+                xtol,
+            );
+            if status.is_some() {
+                break;
+            }
+            alpha = alpha * delta / delta_new;
+            delta = delta_new;
+        }
+
+        if let Some((x_new, f_new, cost_new)) = accepted {
+            x = x_new;
+            f_true = f_new;
+            cost = cost_new;
+            j_true = residuals.jac(&x, &f_true);
+            (jac, f) = loss.scale(&j_true, &f_true);
+            g = compute_grad(&jac, &f);
+        }
+    }
+
+    let active_mask = find_active_constraints(&x, lb, ub, Some(xtol));
+    let nfev = residuals.nfev;
+    LeastSquaresResult::new(
+        x,
+        cost,
+        f_true,
+        j_true,
+        g,
+        g_norm,
+        active_mask,
+        residuals,
+        nfev,
+        status.unwrap_or(0),
+    )
+}
+
+/// Coleman-Li scaling vector `v` and its derivative `dv`. Where the gradient points to a finite
+/// bound, `v` is the distance to that bound, and it is 1 elsewhere.
+fn cl_scaling_vector<F: Float>(
+    x: &Array1<F>,
+    g: &Array1<F>,
+    lb: &Array1<F>,
+    ub: &Array1<F>,
+) -> (Array1<F>, Array1<F>) {
+    let mut v = Array1::from_elem(x.len(), F::one());
+    let mut dv = Array1::zeros(x.len());
+    for i in 0..x.len() {
+        if g[i] < F::zero() && ub[i].is_finite() {
+            v[i] = ub[i] - x[i];
+            dv[i] = -F::one();
+        }
+        if g[i] > F::zero() && lb[i].is_finite() {
+            v[i] = x[i] - lb[i];
+            dv[i] = F::one();
+        }
+    }
+    (v, dv)
+}
+
+/// Status of the `ftol` and `xtol` termination conditions, if any is satisfied.
+fn check_termination<F: Float>(
+    d_cost: F,
+    cost: F,
+    dx_norm: F,
+    x_norm: F,
+    ratio: F,
+    ftol: F,
+    xtol: F,
+) -> Option<usize> {
+    let ftol_satisfied = d_cost < ftol * cost && ratio > F::from(0.25).unwrap();
+    let xtol_satisfied = dx_norm < xtol * (xtol + x_norm);
+    match (ftol_satisfied, xtol_satisfied) {
+        (true, true) => Some(4),
+        (true, false) => Some(2),
+        (false, true) => Some(3),
+        (false, false) => None,
+    }
+}
+
+/// Trust-region subproblem of an iteration, in the variables scaled by `d`.
+struct TrustRegion<'a, F> {
+    x: &'a Array1<F>,
+    j_h: &'a Array2<F>,
+    diag_h: &'a Array1<F>,
+    g_h: &'a Array1<F>,
+    d: &'a Array1<F>,
+    lb: &'a Array1<F>,
+    ub: &'a Array1<F>,
+    /// Fraction of the step to a bound that is taken, to stay strictly feasible.
+    theta: F,
+}
+
+impl<F: Float> TrustRegion<'_, F> {
+    /// Value of the quadratic model `0.5 * s^T (J_h^T J_h + diag_h) s + g_h^T s`.
+    fn evaluate_quadratic(&self, s: &Array1<F>) -> F {
+        let js = matvec(self.j_h, s);
+        let q = dot(&js, &js) + dot(&(s * self.diag_h), s);
+        F::from(0.5).unwrap() * q + dot(s, self.g_h)
+    }
+
+    /// Coefficients `a`, `b` and `c` of the quadratic model `a * t^2 + b * t + c` along the line
+    /// `s0 + t * s`.
+    fn build_quadratic_1d(&self, s: &Array1<F>, s0: &Array1<F>) -> (F, F, F) {
+        let half = F::from(0.5).unwrap();
+        let v = matvec(self.j_h, s);
+        let a = half * (dot(&v, &v) + dot(&(s * self.diag_h), s));
+        let u = matvec(self.j_h, s0);
+        let b = dot(self.g_h, s) + dot(&u, &v) + dot(&(s0 * self.diag_h), s);
+        let c = half * (dot(&u, &u) + dot(&(s0 * self.diag_h), s0)) + dot(self.g_h, s0);
+        (a, b, c)
+    }
+
+    /// Choose the best step among the trust-region step `p_h` in the scaled variables, truncated
+    /// to stay strictly feasible, its reflection from the first bound that it crosses, and the
+    /// scaled steepest descent step. Returns the step in the original and the scaled variables,
+    /// and the reduction of the quadratic model.
+    fn select_step(&self, mut p_h: Array1<F>, delta: F) -> (Array1<F>, Array1<F>, F) {
+        let (x, d, theta) = (self.x, self.d, self.theta);
+        let mut p = d * &p_h;
+        let x_p = x + &p;
+        if x_p
+            .iter()
+            .zip(self.lb.iter().zip(self.ub))
+            .all(|(x, (lb, ub))| x >= lb && x <= ub)
+        {
+            let p_value = self.evaluate_quadratic(&p_h);
+            return (p, p_h, -p_value);
+        }
+
+        let (p_stride, hits) = step_size_to_bound(x, &p, self.lb, self.ub);
+        // Reflected direction.
+        let mut r_h = p_h.clone();
+        for (r, &hit) in r_h.iter_mut().zip(&hits) {
+            if hit != 0 {
+                *r = -*r;
+            }
+        }
+        let r = d * &r_h;
+
+        // Restrict the trust-region step, such that it hits the bound.
+        p.mapv_inplace(|p| p * p_stride);
+        p_h.mapv_inplace(|p| p * p_stride);
+        let x_on_bound = x + &p;
+
+        // The reflected direction crosses first either the feasible region or the trust region
+        // boundary. The bounds of its step size account for the strict feasibility.
+        let (_, to_tr) = intersect_trust_region(&p_h, &r_h, delta);
+        let (to_bound, _) = step_size_to_bound(&x_on_bound, &r, self.lb, self.ub);
+        let r_stride = to_bound.min(to_tr);
+        let (r_stride_l, r_stride_u) = if r_stride > F::zero() {
+            let r_stride_u = if r_stride == to_bound {
+                theta * to_bound
+            } else {
+                to_tr
+            };
+            ((F::one() - theta) * p_stride / r_stride, r_stride_u)
+        } else {
+            (F::zero(), -F::one())
+        };
+        let (r_h, r_value) = if r_stride_l <= r_stride_u {
+            let (a, b, c) = self.build_quadratic_1d(&r_h, &p_h);
+            let (r_stride, r_value) = minimize_quadratic_1d(a, b, r_stride_l, r_stride_u, c);
+            (r_h.mapv(|r| r * r_stride) + &p_h, r_value)
+        } else {
+            (r_h, F::infinity())
+        };
+
+        // Make the trust-region step strictly interior.
+        p.mapv_inplace(|p| p * theta);
+        p_h.mapv_inplace(|p| p * theta);
+        let p_value = self.evaluate_quadratic(&p_h);
+
+        let ag_h = self.g_h.mapv(|g| -g);
+        let ag = d * &ag_h;
+        let to_tr = delta / norm(&ag_h);
+        let (to_bound, _) = step_size_to_bound(x, &ag, self.lb, self.ub);
+        let ag_stride = if to_bound < to_tr {
+            theta * to_bound
+        } else {
+            to_tr
+        };
+        let (a, b, _) = self.build_quadratic_1d(&ag_h, &Array1::zeros(ag_h.len()));
+        let (ag_stride, ag_value) = minimize_quadratic_1d(a, b, F::zero(), ag_stride, F::zero());
+
+        if p_value < r_value && p_value < ag_value {
+            (p, p_h, -p_value)
+        } else if r_value < p_value && r_value < ag_value {
+            let r = d * &r_h;
+            (r, r_h, -r_value)
+        } else {
+            (
+                ag.mapv(|a| a * ag_stride),
+                ag_h.mapv(|a| a * ag_stride),
+                -ag_value,
+            )
+        }
+    }
+}
+
+/// Minimize the quadratic `a * t^2 + b * t + c` on `[lb, ub]`, returning the minimizer and the
+/// minimum.
+fn minimize_quadratic_1d<F: Float>(a: F, b: F, lb: F, ub: F, c: F) -> (F, F) {
+    let y = |t: F| t * (a * t + b) + c;
+    let mut best = (lb, y(lb));
+    let mut candidates = [Some(ub), None];
+    if !a.is_zero() {
+        let extremum = -F::from(0.5).unwrap() * b / a;
+        if lb < extremum && extremum < ub {
+            candidates[1] = Some(extremum);
+        }
+    }
+    for t in candidates.into_iter().flatten() {
+        if y(t) < best.1 {
+            best = (t, y(t));
+        }
+    }
+    best
+}
+
+/// Smallest step size `t` such that `x + t * s` reaches a bound, and for every variable whether
+/// it reaches its lower bound (-1), its upper bound (1) or none (0) at that step.
+fn step_size_to_bound<F: Float>(
+    x: &Array1<F>,
+    s: &Array1<F>,
+    lb: &Array1<F>,
+    ub: &Array1<F>,
+) -> (F, Array1<i8>) {
+    let steps = Array1::from_shape_fn(x.len(), |i| {
+        if s[i].is_zero() {
+            F::infinity()
+        } else {
+            ((lb[i] - x[i]) / s[i]).max((ub[i] - x[i]) / s[i])
+        }
+    });
+    let min_step = steps.fold(F::infinity(), |acc, &t| acc.min(t));
+    let hits = Array1::from_shape_fn(x.len(), |i| match steps[i] == min_step {
+        true if s[i] > F::zero() => 1,
+        true if s[i] < F::zero() => -1,
+        _ => 0,
+    });
+    (min_step, hits)
+}
+
+/// Step sizes `t1 <= t2` at which the line `x + t * s` intersects the boundary of the trust
+/// region `||x|| <= delta`, where `x` lies.
+fn intersect_trust_region<F: Float>(x: &Array1<F>, s: &Array1<F>, delta: F) -> (F, F) {
+    let a = dot(s, s);
+    if a.is_zero() {
+        return (F::zero(), F::zero());
+    }
+    let b = dot(x, s);
+    let c = (dot(x, x) - delta * delta).min(F::zero());
+    let d = (b * b - a * c).sqrt();
+    // Avoid the loss of significance.
+    let q = -(b + d.copysign(b));
+    let (t1, t2) = (q / a, c / q);
+    if t1 < t2 {
+        (t1, t2)
+    } else {
+        (t2, t1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{least_squares, LeastSquaresMethod, LeastSquaresOptions};
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn bounded_exponential() {
+        // Fit y = a * exp(-b * t) with a <= 2, whose unbounded solution is a = 3, b = 0.5.
+        let t = Array1::linspace(0., 4., 9);
+        let y = t.mapv(|t| 3. * (-0.5 * t).exp());
+        let fun =
+            |x: ArrayView1<f64>| Array1::from_shape_fn(9, |i| x[0] * (-x[1] * t[i]).exp() - y[i]);
+        let mut jac = |x: ArrayView1<f64>| {
+            Array2::from_shape_fn((9, 2), |(i, j)| {
+                let e = (-x[1] * t[i]).exp();
+                if j == 0 {
+                    e
+                } else {
+                    -x[0] * t[i] * e
+                }
+            })
+        };
+        let x0 = array![1., 1.];
+        let res = least_squares(
+            fun,
+            &x0,
+            Some(&mut jac),
+            None,
+            None,
+            None,
+            Default::default(),
+        )
+        .unwrap();
+        assert!(res.success);
+        assert_abs_diff_eq!(
+            res.x.as_slice().unwrap(),
+            [3., 0.5].as_slice(),
+            epsilon = 1e-8
+        );
+        assert_abs_diff_eq!(res.cost, 0., epsilon = 1e-16);
+        assert_eq!(res.active_mask, array![0, 0]);
+
+        let bounds = [(0., 2.), (0., f64::INFINITY)];
+        let res = least_squares(
+            fun,
+            &x0,
+            Some(&mut jac),
+            Some(&bounds),
+            Some(LeastSquaresMethod::Trf),
+            None,
+            Default::default(),
+        )
+        .unwrap();
+        assert!(res.success);
+        assert_abs_diff_eq!(res.x[0], 2., epsilon = 1e-8);
+        assert_eq!(res.active_mask, array![1, 0]);
+        // The gradient vanishes along the free variable and points outwards at the bound.
+        assert_abs_diff_eq!(res.grad[1], 0., epsilon = 1e-5);
+        assert!(res.grad[0] < 0.);
+        assert!(res.optimality < 1e-5);
+        assert!(res
+            .x
+            .iter()
+            .zip(&bounds)
+            .all(|(x, b)| *x >= b.0 && *x <= b.1));
+
+        // Finite differences.
+        let fd = least_squares(
+            fun,
+            &x0,
+            None,
+            Some(&bounds),
+            None,
+            None,
+            Default::default(),
+        )
+        .unwrap();
+        assert_abs_diff_eq!(
+            fd.x.as_slice().unwrap(),
+            res.x.as_slice().unwrap(),
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            fd.jac.as_slice().unwrap(),
+            res.jac.as_slice().unwrap(),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn max_nfev() {
+        let fun = |x: ArrayView1<f64>| array![10. * (x[1] - x[0] * x[0]), 1. - x[0]];
+        let options = LeastSquaresOptions {
+            max_nfev: Some(3),
+            ..Default::default()
+        };
+        let res = least_squares(fun, &array![-1.2, 1.], None, None, None, None, options).unwrap();
+        assert_eq!((res.status, res.nfev), (0, 3));
+        assert!(!res.success);
+    }
+
+    #[test]
+    fn helpers() {
+        assert_eq!(minimize_quadratic_1d(1., -2., -5., 5., 1.), (1., 0.));
+        assert_eq!(minimize_quadratic_1d(1., -2., 2., 5., 0.), (2., 0.));
+        assert_eq!(minimize_quadratic_1d(-1., 0., -1., 2., 0.), (2., -4.));
+
+        let (t, hits) = step_size_to_bound(
+            &array![0., 0.5],
+            &array![1., -1.],
+            &array![-1., 0.],
+            &array![2., 1.],
+        );
+        assert_eq!((t, hits), (0.5, array![0, -1]));
+
+        let (t1, t2) = intersect_trust_region(&array![0., 0.], &array![3., 4.], 10.);
+        assert_abs_diff_eq!(t1, -2.);
+        assert_abs_diff_eq!(t2, 2.);
+    }
+}
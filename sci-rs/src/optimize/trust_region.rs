@@ -0,0 +1,271 @@
+use super::line_search::dot;
+use alloc::vec::Vec;
+use ndarray::{Array1, Array2};
+use num_traits::Float;
+
+/// Euclidean norm of `v`.
+pub(super) fn norm<F: Float>(v: &Array1<F>) -> F {
+    dot(v, v).sqrt()
+}
+
+/// Product of the matrix `a` and the vector `v`.
+pub(super) fn matvec<F: Float>(a: &Array2<F>, v: &Array1<F>) -> Array1<F> {
+    a.rows()
+        .into_iter()
+        .map(|row| {
+            row.iter()
+                .zip(v)
+                .fold(F::zero(), |acc, (&a, &v)| acc + a * v)
+        })
+        .collect()
+}
+
+/// Product of the transpose of the matrix `a` and the vector `v`.
+pub(super) fn rmatvec<F: Float>(a: &Array2<F>, v: &Array1<F>) -> Array1<F> {
+    a.columns()
+        .into_iter()
+        .map(|col| {
+            col.iter()
+                .zip(v)
+                .fold(F::zero(), |acc, (&a, &v)| acc + a * v)
+        })
+        .collect()
+}
+
+/// Thin singular value decomposition `A = U diag(s) V^T` of a matrix with at least as many rows
+/// as columns, of which only `U^T f` is kept.
+pub(super) struct Svd<F> {
+    /// Projection `U^T f` of the right-hand side on the left singular vectors.
+    pub(super) uf: Array1<F>,
+    /// Singular values, in decreasing order.
+    pub(super) s: Array1<F>,
+    /// Right singular vectors, as columns.
+    pub(super) v: Array2<F>,
+}
+
+impl<F: Float> Svd<F> {
+    /// Decompose `a` by the one-sided Jacobi method, which orthogonalizes its columns by plane
+    /// rotations until they are orthogonal to machine precision.
+    pub(super) fn new(a: &Array2<F>, f: &Array1<F>) -> Self {
+        let n = a.ncols();
+        let mut u = a.clone();
+        let mut v = Array2::<F>::eye(n);
+        let eps = F::epsilon();
+        let rotate = |m: &mut Array2<F>, p: usize, q: usize, c: F, s: F| {
+            for mut row in m.rows_mut() {
+                let (mp, mq) = (row[p], row[q]);
+                row[p] = c * mp - s * mq;
+                row[q] = s * mp + c * mq;
+            }
+        };
+        for _ in 0..64 {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (cp, cq) = (u.column(p), u.column(q));
+                    let alpha = cp.fold(F::zero(), |acc, &x| acc + x * x);
+                    let beta = cq.fold(F::zero(), |acc, &x| acc + x * x);
+                    let gamma = cp
+                        .iter()
+                        .zip(cq)
+                        .fold(F::zero(), |acc, (&x, &y)| acc + x * y);
+                    if gamma.is_zero() || gamma.abs() <= eps * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    let zeta = (beta - alpha) / (gamma + gamma);
+                    let t = zeta.signum() / (zeta.abs() + F::one().hypot(zeta));
+                    let c = F::one() / F::one().hypot(t);
+                    rotate(&mut u, p, q, c, c * t);
+                    rotate(&mut v, p, q, c, c * t);
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut s: Vec<(F, usize)> = u
+            .columns()
+            .into_iter()
+            .enumerate()
+            .map(|(j, col)| (col.fold(F::zero(), |acc, &x| acc + x * x).sqrt(), j))
+            .collect();
+        s.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(core::cmp::Ordering::Equal));
+        let uf = s
+            .iter()
+            .map(|&(s, j)| {
+                if s.is_zero() {
+                    F::zero()
+                } else {
+                    u.column(j)
+                        .iter()
+                        .zip(f)
+                        .fold(F::zero(), |acc, (&u, &f)| acc + u * f)
+                        / s
+                }
+            })
+            .collect();
+        Svd {
+            uf,
+            s: s.iter().map(|&(s, _)| s).collect(),
+            v: Array2::from_shape_fn((n, n), |(i, k)| v[(i, s[k].1)]),
+        }
+    }
+}
+
+/// Solve the trust-region subproblem `min ||A p + f||` subject to `||p|| <= delta` from the
+/// decomposition of `A`, which has `m` rows before any augmentation.
+///
+/// The solution is `p = -(A^T A + alpha I)^-1 A^T f`, with the Levenberg-Marquardt parameter
+/// `alpha` found by Newton iterations on `1 / ||p(alpha)|| - 1 / delta`, starting from
+/// `initial_alpha`. Returns the step and `alpha`, which is zero for the Gauss-Newton step.
+///
+/// This is the algorithm 4.3 of More, J. J. 1978. The Levenberg-Marquardt Algorithm:
+/// Implementation and Theory, as in scipy's `solve_lsq_trust_region`.
+pub(super) fn solve_lsq_trust_region<F: Float>(
+    m: usize,
+    svd: &Svd<F>,
+    delta: F,
+    initial_alpha: F,
+) -> (Array1<F>, F) {
+    let Svd { uf, s, v } = svd;
+    let n = v.nrows();
+    let suf = s * uf;
+    let step = |alpha: F| {
+        -matvec(
+            v,
+            &Array1::from_shape_fn(n, |i| suf[i] / (s[i] * s[i] + alpha)),
+        )
+    };
+    let phi_and_derivative = |alpha: F| {
+        let denom = s.mapv(|s| s * s + alpha);
+        let p_norm = norm(&(&suf / &denom));
+        let phi = p_norm - delta;
+        let phi_prime = -suf
+            .iter()
+            .zip(&denom)
+            .fold(F::zero(), |acc, (&suf, &d)| acc + suf * suf / (d * d * d))
+            / p_norm;
+        (phi, phi_prime)
+    };
+
+    // Try the Gauss-Newton step if A has full rank.
+    let full_rank = m >= n && s[n - 1] > F::epsilon() * F::from(m).unwrap() * s[0];
+    if full_rank {
+        let p = -matvec(v, &(uf / s));
+        if norm(&p) <= delta {
+            return (p, F::zero());
+        }
+    }
+
+    let mut alpha_upper = norm(&suf) / delta;
+    let mut alpha_lower = if full_rank {
+        let (phi, phi_prime) = phi_and_derivative(F::zero());
+        -phi / phi_prime
+    } else {
+        F::zero()
+    };
+    let safeguard =
+        |lower: F, upper: F| (F::from(0.001).unwrap() * upper).max((lower * upper).sqrt());
+    let mut alpha = if !full_rank && initial_alpha.is_zero() {
+        safeguard(alpha_lower, alpha_upper)
+    } else {
+        initial_alpha
+    };
+    for _ in 0..10 {
+        if alpha < alpha_lower || alpha > alpha_upper {
+            alpha = safeguard(alpha_lower, alpha_upper);
+        }
+        let (phi, phi_prime) = phi_and_derivative(alpha);
+        if phi < F::zero() {
+            alpha_upper = alpha;
+        }
+        let ratio = phi / phi_prime;
+        alpha_lower = alpha_lower.max(alpha - ratio);
+        alpha = alpha - (phi + delta) * ratio / delta;
+        if phi.abs() < F::from(0.01).unwrap() * delta {
+            break;
+        }
+    }
+
+    // Make the norm of p equal to delta, to keep it within the trust region.
+    let p = step(alpha);
+    let scale = delta / norm(&p);
+    (p.mapv(|p| p * scale), alpha)
+}
+
+/// Update the radius of the trust region from the ratio of the actual and predicted reductions
+/// of the cost, which is also returned.
+pub(super) fn update_tr_radius<F: Float>(
+    delta: F,
+    actual_reduction: F,
+    predicted_reduction: F,
+    step_norm: F,
+    bound_hit: bool,
+) -> (F, F) {
+    let ratio = if predicted_reduction > F::zero() {
+        actual_reduction / predicted_reduction
+    } else if predicted_reduction.is_zero() && actual_reduction.is_zero() {
+        F::one()
+    } else {
+        F::zero()
+    };
+    let delta = if ratio < F::from(0.25).unwrap() {
+        F::from(0.25).unwrap() * step_norm
+    } else if ratio > F::from(0.75).unwrap() && bound_hit {
+        delta + delta
+    } else {
+        delta
+    };
+    (delta, ratio)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn svd() {
+        let a = array![[1., 2.], [3., 4.], [5., 6.]];
+        let f = array![1., 0., -1.];
+        let svd = Svd::new(&a, &f);
+        assert_abs_diff_eq!(svd.s[0], 9.525518091565107, epsilon = 1e-12);
+        assert_abs_diff_eq!(svd.s[1], 0.514300580658644, epsilon = 1e-12);
+        // A^T f = V diag(s) U^T f
+        let atf = matvec(&svd.v, &(&svd.s * &svd.uf));
+        assert_abs_diff_eq!(
+            atf.as_slice().unwrap(),
+            rmatvec(&a, &f).as_slice().unwrap(),
+            epsilon = 1e-12
+        );
+        // V is orthogonal.
+        let vtv = svd.v.t().to_owned();
+        for i in 0..2 {
+            for j in 0..2 {
+                let d = vtv.row(i).dot(&vtv.row(j));
+                assert_abs_diff_eq!(d, if i == j { 1. } else { 0. }, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn trust_region_step() {
+        let a = array![[2., 0.], [0., 1.], [0., 0.]];
+        let f = array![-2., -3., 1.];
+        let svd = Svd::new(&a, &f);
+        // The Gauss-Newton step (1, 3) is within the trust region.
+        let (p, alpha) = solve_lsq_trust_region(3, &svd, 10., 0.);
+        assert_abs_diff_eq!(p.as_slice().unwrap(), [1., 3.].as_slice(), epsilon = 1e-12);
+        assert_eq!(alpha, 0.);
+        // Otherwise, the step is on the boundary and solves the damped normal equations.
+        let (p, alpha) = solve_lsq_trust_region(3, &svd, 1., 0.);
+        assert_abs_diff_eq!(norm(&p), 1., epsilon = 1e-12);
+        assert!(alpha > 0.);
+        assert_abs_diff_eq!(p[0], 4. / (4. + alpha), epsilon = 1e-2);
+        assert_abs_diff_eq!(p[1], 3. / (1. + alpha), epsilon = 1e-2);
+    }
+}
@@ -0,0 +1,1171 @@
+use alloc::format;
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Default absolute tolerance of the bracketing root finders.
+const XTOL: f64 = 2e-12;
+/// Default maximum number of iterations of the bracketing root finders.
+const MAXITER: usize = 100;
+
+/// Cause of termination of a root finder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootFlag {
+    /// The root was found within the tolerances.
+    Converged,
+    /// The maximum number of iterations was reached.
+    ConvergenceError,
+    /// The derivative, or the slope of the secant, was zero before convergence.
+    ZeroDerivative,
+}
+
+/// Represents the root finding result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootResults<F> {
+    /// Estimated root location.
+    pub root: F,
+    /// Number of iterations needed to find the root.
+    pub iterations: usize,
+    /// Number of times the function was called.
+    pub function_calls: usize,
+    /// True if the routine converged.
+    pub converged: bool,
+    /// Description of the cause of termination.
+    pub flag: RootFlag,
+}
+
+impl<F> RootResults<F> {
+    fn new(root: F, iterations: usize, function_calls: usize, flag: RootFlag) -> Self {
+        RootResults {
+            root,
+            iterations,
+            function_calls,
+            converged: flag == RootFlag::Converged,
+            flag,
+        }
+    }
+}
+
+/// Check the tolerances and fill in their defaults.
+fn tolerances<F: Float>(xtol: Option<F>, rtol: Option<F>) -> Result<(F, F)> {
+    let xtol = xtol.unwrap_or_else(|| F::from(XTOL).unwrap());
+    let min_rtol = F::from(4).unwrap() * F::epsilon();
+    let rtol = rtol.unwrap_or(min_rtol);
+    if xtol.is_nan() || xtol <= F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "xtol".into(),
+            reason: format!("xtol too small ({:?} <= 0)", xtol.to_f64()),
+        });
+    }
+    if rtol.is_nan() || rtol < min_rtol {
+        return Err(Error::InvalidArg {
+            arg: "rtol".into(),
+            reason: format!(
+                "rtol too small ({:?} < {:?})",
+                rtol.to_f64(),
+                min_rtol.to_f64()
+            ),
+        });
+    }
+    Ok((xtol, rtol))
+}
+
+fn sign_error<F: Float>(a: F, b: F, fa: F, fb: F) -> Error {
+    Error::InvalidArg {
+        arg: "f".into(),
+        reason: format!(
+            "f(a) and f(b) must have different signs, but f({:?})={:?}, f({:?})={:?}",
+            a.to_f64(),
+            fa.to_f64(),
+            b.to_f64(),
+            fb.to_f64()
+        ),
+    }
+}
+
+/// Find a root of a real function using the Newton-Raphson (or secant or Halley's) method.
+///
+/// Find a root of the scalar-valued function `func` given a nearby scalar starting point `x0`.
+/// The Newton-Raphson method is used if the derivative `fprime` of `func` is provided, otherwise
+/// the secant method is used. If the second order derivative `fprime2` of `func` is also provided,
+/// then Halley's method is used.
+///
+/// ## Parameters
+/// * `func`: The function whose root is wanted.
+/// * `x0`: An initial estimate of the root that should be somewhere near the actual root.
+/// * `fprime`: The derivative of the function when available and convenient. If it is `None`
+///   (default), then the secant method is used.
+/// * `fprime2`: The second order derivative of the function when available and convenient. If it
+///   is `None` (default), then the normal Newton-Raphson or the secant method is used. If it is
+///   given along with `fprime`, then Halley's method is used.
+/// * `x1`: Another estimate of the root for the secant method. Default is `x0 * (1 + 1e-4)`
+///   moved away from zero by `1e-4`.
+/// * `tol`: The allowable error of the zero value, `1.48e-8` by default.
+/// * `rtol`: Tolerance (relative) for termination, 0 by default.
+/// * `maxiter`: Maximum number of iterations, 50 by default.
+///
+/// ## Returns
+/// The estimated root, along with the number of iterations and function calls, which count the
+/// evaluations of the derivatives. `flag` is [RootFlag::ZeroDerivative] if the derivative or the
+/// slope of the secant vanished, in which case the root is the last estimate, or the midpoint of
+/// the secant, and [RootFlag::ConvergenceError] if `maxiter` was reached.
+///
+/// ## Errors
+/// If `tol` is not positive, `maxiter` is 0, or `x1` equals `x0`.
+///
+/// ## Notes
+/// The convergence rate of the Newton-Raphson method is quadratic, the Halley method is cubic,
+/// and the secant method is sub-quadratic. This means that if the function is well-behaved the
+/// actual error in the estimated root after the nth iteration is approximately the square (cube
+/// for Halley) of the error after the (n-1)th step. However, the stopping criterion used here is
+/// the step size and there is no guarantee that a root has been found. Consequently, the result
+/// should be verified. Safer algorithms are [brentq], [brenth], [ridder], and [bisect], but they
+/// all require that the root first be bracketed in an interval where the function changes sign.
+///
+/// The Halley correction of the Newton step is only applied when it does not reverse the
+/// direction of the step, i.e. when the second derivative changes the step by a factor
+/// `1 / (1 - adj)` with `|adj| < 1`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import optimize
+/// def f(x):
+///     return (x**3 - 1)  # only one real root at x = 1
+/// root = optimize.newton(f, 1.5)
+/// root
+/// # 1.0000000000000016
+/// root = optimize.newton(f, 1.5, fprime=lambda x: 3 * x**2)
+/// root
+/// # 1.0
+/// root = optimize.newton(f, 1.5, fprime=lambda x: 3 * x**2, fprime2=lambda x: 6 * x)
+/// root
+/// # 1.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use sci_rs::optimize::newton;
+///
+/// let f = |x: f64| x.powi(3) - 1.;
+/// let secant = newton(f, 1.5, None, None, None, None, None, None).unwrap();
+/// assert_eq!(secant.root, 1.0000000000000016);
+/// let fprime = &mut |x: f64| 3. * x * x;
+/// let newton_raphson = newton(f, 1.5, Some(fprime), None, None, None, None, None).unwrap();
+/// assert_eq!(newton_raphson.root, 1.);
+/// let fprime2 = &mut |x: f64| 6. * x;
+/// let halley = newton(f, 1.5, Some(fprime), Some(fprime2), None, None, None, None).unwrap();
+/// assert_eq!(halley.root, 1.);
+/// assert!(halley.iterations < newton_raphson.iterations);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn newton<F: Float>(
+    mut func: impl FnMut(F) -> F,
+    x0: F,
+    fprime: Option<&mut dyn FnMut(F) -> F>,
+    fprime2: Option<&mut dyn FnMut(F) -> F>,
+    x1: Option<F>,
+    tol: Option<F>,
+    rtol: Option<F>,
+    maxiter: Option<usize>,
+) -> Result<RootResults<F>> {
+    let tol = tol.unwrap_or_else(|| F::from(1.48e-8).unwrap());
+    let rtol = rtol.unwrap_or_else(F::zero);
+    let maxiter = maxiter.unwrap_or(50);
+    if tol.is_nan() || tol <= F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "tol".into(),
+            reason: format!("tol too small ({:?} <= 0)", tol.to_f64()),
+        });
+    }
+    if maxiter < 1 {
+        return Err(Error::InvalidArg {
+            arg: "maxiter".into(),
+            reason: "maxiter must be greater than 0".into(),
+        });
+    }
+    let close = |p: F, p0: F| (p - p0).abs() <= tol + rtol * p0.abs();
+    let two = F::from(2).unwrap();
+    let mut calls = 0;
+    let mut p = x0;
+
+    if let Some(fprime) = fprime {
+        // Newton-Raphson method, or Halley's with the second derivative.
+        let mut fprime2 = fprime2;
+        let mut p0 = x0;
+        for iteration in 0..maxiter {
+            let fval = func(p0);
+            calls += 1;
+            if fval.is_zero() {
+                return Ok(RootResults::new(p0, iteration, calls, RootFlag::Converged));
+            }
+            let fder = fprime(p0);
+            calls += 1;
+            if fder.is_zero() {
+                let flag = RootFlag::ZeroDerivative;
+                return Ok(RootResults::new(p0, iteration + 1, calls, flag));
+            }
+            let mut step = fval / fder;
+            if let Some(fprime2) = fprime2.as_mut() {
+                let fder2 = fprime2(p0);
+                calls += 1;
+                // Only apply the correction while it keeps the direction of the Newton step.
+                let adj = step * fder2 / fder / two;
+                if adj.abs() < F::one() {
+                    step = step / (F::one() - adj);
+                }
+            }
+            p = p0 - step;
+            if close(p, p0) {
+                return Ok(RootResults::new(
+                    p,
+                    iteration + 1,
+                    calls,
+                    RootFlag::Converged,
+                ));
+            }
+            p0 = p;
+        }
+    } else {
+        // Secant method.
+        let mut p0 = x0;
+        let mut p1 = match x1 {
+            Some(x1) if x1 == x0 => {
+                return Err(Error::InvalidArg {
+                    arg: "x1".into(),
+                    reason: "x1 and x0 must be different".into(),
+                })
+            }
+            Some(x1) => x1,
+            None => {
+                let eps = F::from(1e-4).unwrap();
+                let p1 = x0 * (F::one() + eps);
+                if p1 >= F::zero() {
+                    p1 + eps
+                } else {
+                    p1 - eps
+                }
+            }
+        };
+        let mut q0 = func(p0);
+        let mut q1 = func(p1);
+        calls += 2;
+        if q1.abs() < q0.abs() {
+            (p0, p1, q0, q1) = (p1, p0, q1, q0);
+        }
+        for iteration in 0..maxiter {
+            if q1 == q0 {
+                let midpoint = (p1 + p0) / two;
+                let flag = RootFlag::ZeroDerivative;
+                return Ok(RootResults::new(midpoint, iteration + 1, calls, flag));
+            }
+            p = if q1.abs() > q0.abs() {
+                (-q0 / q1 * p1 + p0) / (F::one() - q0 / q1)
+            } else {
+                (-q1 / q0 * p0 + p1) / (F::one() - q1 / q0)
+            };
+            if close(p, p1) {
+                return Ok(RootResults::new(
+                    p,
+                    iteration + 1,
+                    calls,
+                    RootFlag::Converged,
+                ));
+            }
+            (p0, q0) = (p1, q1);
+            p1 = p;
+            q1 = func(p1);
+            calls += 1;
+        }
+    }
+    Ok(RootResults::new(
+        p,
+        maxiter,
+        calls,
+        RootFlag::ConvergenceError,
+    ))
+}
+
+/// Find a root of a function in a bracketing interval using Brent's method.
+///
+/// Uses the classic Brent's method to find a root of the function `f` on the sign changing
+/// interval `[a, b]`. Generally considered the best of the rootfinding routines here. It is a safe
+/// version of the secant method that uses inverse quadratic extrapolation. Brent's method combines
+/// root bracketing, interval bisection, and inverse quadratic interpolation.
+///
+/// ## Parameters
+/// * `f`: Function returning a number. `f` must be continuous, and `f(a)` and `f(b)` must have
+///   opposite signs.
+/// * `a`, `b`: The ends of the bracketing interval.
+/// * `xtol`, `rtol`: The computed root `x0` will satisfy
+///   `|x - x0| <= xtol + rtol * |x0|`, where `x` is the exact root. `xtol` must be positive
+///   and defaults to `2e-12`. `rtol` cannot be smaller than its default value of `4 * eps`.
+/// * `maxiter`: The maximum number of iterations, 100 by default.
+///
+/// ## Returns
+/// The root, along with the number of iterations and function calls. `converged` is false if the
+/// root was not found within `maxiter` iterations.
+///
+/// ## Errors
+/// If the tolerances are too small, or `f(a)` and `f(b)` have the same sign.
+///
+/// ## References
+/// [1] Brent, R. P., Algorithms for Minimization Without Derivatives. Englewood Cliffs, NJ:
+///     Prentice-Hall, 1973. Ch. 3-4.
+///
+/// [2] Press, W. H.; Flannery, B. P.; Teukolsky, S. A.; and Vetterling, W. T. Numerical Recipes
+///     in FORTRAN: The Art of Scientific Computing, 2nd ed. Cambridge, England: Cambridge
+///     University Press, pp. 352-355, 1992. Section 9.3: "Van Wijngaarden-Dekker-Brent Method."
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import optimize
+/// def f(x):
+///     return (x**2 - 1)
+/// root = optimize.brentq(f, -2, 0)
+/// root
+/// # -1.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use sci_rs::optimize::brentq;
+///
+/// let result = brentq(|x: f64| x * x - 1., -2., 0., None, None, None).unwrap();
+/// assert!(result.converged);
+/// assert_abs_diff_eq!(result.root, -1., epsilon = 1e-12);
+/// ```
+pub fn brentq<F: Float>(
+    f: impl FnMut(F) -> F,
+    a: F,
+    b: F,
+    xtol: Option<F>,
+    rtol: Option<F>,
+    maxiter: Option<usize>,
+) -> Result<RootResults<F>> {
+    brent(f, a, b, xtol, rtol, maxiter, false)
+}
+
+/// Find a root of a function in a bracketing interval using Brent's method with hyperbolic
+/// extrapolation.
+///
+/// A variation on the classic Brent routine to find a root of the function `f` between the
+/// arguments `a` and `b` that uses hyperbolic extrapolation instead of inverse quadratic
+/// extrapolation. Bus & Dekker (1975) guarantee convergence for this method, claiming that the
+/// upper bound of function evaluations here is 4 or 5 times that of bisection.
+///
+/// ## Parameters
+/// * `f`: Function returning a number. `f` must be continuous, and `f(a)` and `f(b)` must have
+///   opposite signs.
+/// * `a`, `b`: The ends of the bracketing interval.
+/// * `xtol`, `rtol`: The computed root `x0` will satisfy
+///   `|x - x0| <= xtol + rtol * |x0|`, where `x` is the exact root. `xtol` must be positive
+///   and defaults to `2e-12`. `rtol` cannot be smaller than its default value of `4 * eps`.
+/// * `maxiter`: The maximum number of iterations, 100 by default.
+///
+/// ## Returns
+/// The root, along with the number of iterations and function calls. `converged` is false if the
+/// root was not found within `maxiter` iterations.
+///
+/// ## Errors
+/// If the tolerances are too small, or `f(a)` and `f(b)` have the same sign.
+///
+/// ## References
+/// [1] Bus, J. C. P., Dekker, T. J., "Two Efficient Algorithms with Guaranteed Convergence for
+///     Finding a Zero of a Function", ACM Transactions on Mathematical Software, Vol. 1, Issue 4,
+///     Dec. 1975, pp. 330-345. Section 3: "Algorithm M".
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import optimize
+/// def f(x):
+///     return (x**2 - 1)
+/// root = optimize.brenth(f, 0, 2)
+/// root
+/// # 1.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use sci_rs::optimize::brenth;
+///
+/// let result = brenth(|x: f64| x * x - 1., 0., 2., None, None, None).unwrap();
+/// assert_abs_diff_eq!(result.root, 1., epsilon = 1e-12);
+/// ```
+pub fn brenth<F: Float>(
+    f: impl FnMut(F) -> F,
+    a: F,
+    b: F,
+    xtol: Option<F>,
+    rtol: Option<F>,
+    maxiter: Option<usize>,
+) -> Result<RootResults<F>> {
+    brent(f, a, b, xtol, rtol, maxiter, true)
+}
+
+/// Brent's method, with hyperbolic instead of inverse quadratic extrapolation for
+/// [brenth].
+fn brent<F: Float>(
+    mut f: impl FnMut(F) -> F,
+    a: F,
+    b: F,
+    xtol: Option<F>,
+    rtol: Option<F>,
+    maxiter: Option<usize>,
+    hyperbolic: bool,
+) -> Result<RootResults<F>> {
+    let (xtol, rtol) = tolerances(xtol, rtol)?;
+    let (two, three) = (F::from(2).unwrap(), F::from(3).unwrap());
+    let (mut xpre, mut xcur) = (a, b);
+    let (mut xblk, mut fblk, mut spre, mut scur) = (F::zero(), F::zero(), F::zero(), F::zero());
+    let mut fpre = f(xpre);
+    let mut fcur = f(xcur);
+    let mut calls = 2;
+    if fpre.is_zero() {
+        return Ok(RootResults::new(xpre, 0, calls, RootFlag::Converged));
+    }
+    if fcur.is_zero() {
+        return Ok(RootResults::new(xcur, 0, calls, RootFlag::Converged));
+    }
+    if fpre.is_sign_negative() == fcur.is_sign_negative() {
+        return Err(sign_error(a, b, fpre, fcur));
+    }
+
+    for iterations in 1..=maxiter.unwrap_or(MAXITER) {
+        if !fpre.is_zero() && !fcur.is_zero() && fpre.is_sign_negative() != fcur.is_sign_negative()
+        {
+            xblk = xpre;
+            fblk = fpre;
+            spre = xcur - xpre;
+            scur = spre;
+        }
+        if fblk.abs() < fcur.abs() {
+            (xpre, xcur, xblk) = (xcur, xblk, xcur);
+            (fpre, fcur, fblk) = (fcur, fblk, fcur);
+        }
+
+        let delta = (xtol + rtol * xcur.abs()) / two;
+        let sbis = (xblk - xcur) / two;
+        if fcur.is_zero() || sbis.abs() < delta {
+            return Ok(RootResults::new(
+                xcur,
+                iterations,
+                calls,
+                RootFlag::Converged,
+            ));
+        }
+
+        if spre.abs() > delta && fcur.abs() < fpre.abs() {
+            let stry = if xpre == xblk {
+                // Interpolate.
+                -fcur * (xcur - xpre) / (fcur - fpre)
+            } else {
+                // Extrapolate.
+                let dpre = (fpre - fcur) / (xpre - xcur);
+                let dblk = (fblk - fcur) / (xblk - xcur);
+                if hyperbolic {
+                    -fcur * (fblk - fpre) / (fblk * dpre - fpre * dblk)
+                } else {
+                    -fcur * (fblk * dblk - fpre * dpre) / (dblk * dpre * (fblk - fpre))
+                }
+            };
+            if two * stry.abs() < spre.abs().min(three * sbis.abs() - delta) {
+                // Good short step.
+                spre = scur;
+                scur = stry;
+            } else {
+                spre = sbis;
+                scur = sbis;
+            }
+        } else {
+            spre = sbis;
+            scur = sbis;
+        }
+
+        xpre = xcur;
+        fpre = fcur;
+        if scur.abs() > delta {
+            xcur = xcur + scur;
+        } else if sbis > F::zero() {
+            xcur = xcur + delta;
+        } else {
+            xcur = xcur - delta;
+        }
+        fcur = f(xcur);
+        calls += 1;
+    }
+    let iterations = maxiter.unwrap_or(MAXITER);
+    Ok(RootResults::new(
+        xcur,
+        iterations,
+        calls,
+        RootFlag::ConvergenceError,
+    ))
+}
+
+/// Find root of a function within an interval using bisection.
+///
+/// Basic bisection routine to find a root of the function `f` between the arguments `a` and `b`.
+/// `f(a)` and `f(b)` cannot have the same signs. Slow but sure.
+///
+/// ## Parameters
+/// * `f`: Function returning a number. `f` must be continuous, and `f(a)` and `f(b)` must have
+///   opposite signs.
+/// * `a`, `b`: The ends of the bracketing interval.
+/// * `xtol`, `rtol`: The computed root `x0` will satisfy
+///   `|x - x0| <= xtol + rtol * |x0|`, where `x` is the exact root. `xtol` must be positive
+///   and defaults to `2e-12`. `rtol` cannot be smaller than its default value of `4 * eps`.
+/// * `maxiter`: The maximum number of iterations, 100 by default.
+///
+/// ## Returns
+/// The root, along with the number of iterations and function calls. `converged` is false if the
+/// root was not found within `maxiter` iterations.
+///
+/// ## Errors
+/// If the tolerances are too small, or `f(a)` and `f(b)` have the same sign.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import optimize
+/// def f(x):
+///     return (x**2 - 1)
+/// root = optimize.bisect(f, 0, 2)
+/// root
+/// # 1.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use sci_rs::optimize::bisect;
+///
+/// let result = bisect(|x: f64| x * x - 1., 0., 2., None, None, None).unwrap();
+/// assert_abs_diff_eq!(result.root, 1., epsilon = 1e-12);
+/// ```
+pub fn bisect<F: Float>(
+    mut f: impl FnMut(F) -> F,
+    a: F,
+    b: F,
+    xtol: Option<F>,
+    rtol: Option<F>,
+    maxiter: Option<usize>,
+) -> Result<RootResults<F>> {
+    let (xtol, rtol) = tolerances(xtol, rtol)?;
+    let mut xa = a;
+    let (fa, fb) = (f(a), f(b));
+    let mut calls = 2;
+    if fa * fb > F::zero() {
+        return Err(sign_error(a, b, fa, fb));
+    }
+    if fa.is_zero() {
+        return Ok(RootResults::new(a, 0, calls, RootFlag::Converged));
+    }
+    if fb.is_zero() {
+        return Ok(RootResults::new(b, 0, calls, RootFlag::Converged));
+    }
+    let half = F::from(0.5).unwrap();
+    let mut dm = b - a;
+    for iterations in 1..=maxiter.unwrap_or(MAXITER) {
+        dm = dm * half;
+        let xm = xa + dm;
+        let fm = f(xm);
+        calls += 1;
+        if fm * fa >= F::zero() {
+            xa = xm;
+        }
+        if fm.is_zero() || dm.abs() < xtol + rtol * xm.abs() {
+            return Ok(RootResults::new(xm, iterations, calls, RootFlag::Converged));
+        }
+    }
+    let iterations = maxiter.unwrap_or(MAXITER);
+    Ok(RootResults::new(
+        xa,
+        iterations,
+        calls,
+        RootFlag::ConvergenceError,
+    ))
+}
+
+/// Find a root of a function in an interval using Ridder's method.
+///
+/// Uses Ridder's method to find a root of the function `f` between the arguments `a` and `b`.
+/// Ridder's method is faster than bisection, but not generally as fast as the Brent routines.
+/// [1] provides the classic description and source of the algorithm.
+///
+/// ## Parameters
+/// * `f`: Function returning a number. `f` must be continuous, and `f(a)` and `f(b)` must have
+///   opposite signs.
+/// * `a`, `b`: The ends of the bracketing interval.
+/// * `xtol`, `rtol`: The computed root `x0` will satisfy
+///   `|x - x0| <= xtol + rtol * |x0|`, where `x` is the exact root. `xtol` must be positive
+///   and defaults to `2e-12`. `rtol` cannot be smaller than its default value of `4 * eps`.
+/// * `maxiter`: The maximum number of iterations, 100 by default.
+///
+/// ## Returns
+/// The root, along with the number of iterations and function calls. `converged` is false if the
+/// root was not found within `maxiter` iterations.
+///
+/// ## Errors
+/// If the tolerances are too small, or `f(a)` and `f(b)` have the same sign.
+///
+/// ## References
+/// [1] Ridders, C. F. J. "A New Algorithm for Computing a Single Root of a Real Continuous
+///     Function." IEEE Trans. Circuits Systems 26, 979-980, 1979.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import optimize
+/// def f(x):
+///     return (x**2 - 1)
+/// root = optimize.ridder(f, 0, 2)
+/// root
+/// # 1.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use sci_rs::optimize::ridder;
+///
+/// let result = ridder(|x: f64| x * x - 1., 0., 2., None, None, None).unwrap();
+/// assert_abs_diff_eq!(result.root, 1., epsilon = 1e-12);
+/// ```
+pub fn ridder<F: Float>(
+    mut f: impl FnMut(F) -> F,
+    a: F,
+    b: F,
+    xtol: Option<F>,
+    rtol: Option<F>,
+    maxiter: Option<usize>,
+) -> Result<RootResults<F>> {
+    let (xtol, rtol) = tolerances(xtol, rtol)?;
+    let (mut xa, mut xb) = (a, b);
+    let (mut fa, mut fb) = (f(a), f(b));
+    let mut calls = 2;
+    if fa * fb > F::zero() {
+        return Err(sign_error(a, b, fa, fb));
+    }
+    if fa.is_zero() {
+        return Ok(RootResults::new(a, 0, calls, RootFlag::Converged));
+    }
+    if fb.is_zero() {
+        return Ok(RootResults::new(b, 0, calls, RootFlag::Converged));
+    }
+    let half = F::from(0.5).unwrap();
+    let sign = |x: F| if x > F::zero() { F::one() } else { -F::one() };
+    let mut tol = xtol;
+    let mut xn = F::zero();
+    for iterations in 1..=maxiter.unwrap_or(MAXITER) {
+        let dm = half * (xb - xa);
+        let xm = xa + dm;
+        let fm = f(xm);
+        let dn = sign(fb - fa) * dm * fm / (fm * fm - fa * fb).sqrt();
+        xn = xm - sign(dn) * dn.abs().min(dm.abs() - half * tol);
+        let fn_ = f(xn);
+        calls += 2;
+        if fn_ * fm < F::zero() {
+            (xa, fa, xb, fb) = (xn, fn_, xm, fm);
+        } else if fn_ * fa < F::zero() {
+            (xb, fb) = (xn, fn_);
+        } else {
+            (xa, fa) = (xn, fn_);
+        }
+        // Like scipy, the relative tolerance uses the signed estimate.
+        tol = xtol + rtol * xn;
+        if fn_.is_zero() || (xb - xa).abs() < tol {
+            return Ok(RootResults::new(xn, iterations, calls, RootFlag::Converged));
+        }
+    }
+    let iterations = maxiter.unwrap_or(MAXITER);
+    Ok(RootResults::new(
+        xn,
+        iterations,
+        calls,
+        RootFlag::ConvergenceError,
+    ))
+}
+
+/// Find a root using TOMS Algorithm 748 method.
+///
+/// Implements the Algorithm 748 method of Alefeld, Potro and Shi to find a root of the function
+/// `f` on the interval `[a, b]`, where `f(a)` and `f(b)` must have opposite signs.
+///
+/// It uses a mixture of inverse cubic interpolation and "Newton-quadratic" steps. [APS1995].
+///
+/// ## Parameters
+/// * `f`: Function returning a number. `f` must be continuous, and `f(a)` and `f(b)` must have
+///   opposite signs.
+/// * `a`, `b`: The ends of the bracketing interval, with `a < b`.
+/// * `k`: The number of Newton quadratic steps to perform each irreducible iteration, 1 by
+///   default. It is at least 1 and at most 100.
+/// * `xtol`, `rtol`: The computed root `x0` will satisfy
+///   `|x - x0| <= xtol + rtol * |x0|`, where `x` is the exact root. `xtol` must be positive
+///   and defaults to `2e-12`. `rtol` cannot be smaller than its default value of `4 * eps`.
+/// * `maxiter`: The maximum number of iterations, 100 by default.
+///
+/// ## Returns
+/// The root, along with the number of iterations and function calls. `converged` is false if the
+/// root was not found within `maxiter` iterations.
+///
+/// ## Errors
+/// If `[a, b]` is not an interval, the tolerances are too small, `f(a)` and `f(b)` have the same
+/// sign, or `f` returns a non-finite value.
+///
+/// ## Notes
+/// `f` must be continuous. Algorithm 748 with `k=2` is asymptotically the most efficient algorithm
+/// known for finding roots of a four times continuously differentiable function. In contrast with
+/// Brent's algorithm, which may only decrease the length of the enclosing bracket on the last
+/// step, Algorithm 748 decreases it each iteration with the same asymptotic efficiency as it
+/// finds the root.
+///
+/// ## References
+/// [APS1995] Alefeld, G. E. and Potra, F. A. and Shi, Yixun, *Algorithm 748: Enclosing Zeros of
+/// Continuous Functions*, ACM Trans. Math. Softw. Volume 221(1995) doi = {10.1145/210089.210111}
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import optimize
+/// def f(x):
+///     return (x**3 - 1)  # only one real root at x = 1
+/// root, results = optimize.toms748(f, 0, 2, full_output=True)
+/// root
+/// # 1.0
+/// results.converged
+/// # True
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use sci_rs::optimize::toms748;
+///
+/// let result = toms748(|x: f64| x.powi(3) - 1., 0., 2., None, None, None, None).unwrap();
+/// assert!(result.converged);
+/// assert_abs_diff_eq!(result.root, 1., epsilon = 1e-12);
+/// ```
+pub fn toms748<F: Float>(
+    f: impl FnMut(F) -> F,
+    a: F,
+    b: F,
+    k: Option<usize>,
+    xtol: Option<F>,
+    rtol: Option<F>,
+    maxiter: Option<usize>,
+) -> Result<RootResults<F>> {
+    let (xtol, rtol) = tolerances(xtol, rtol)?;
+    if a.is_nan() || b.is_nan() || a >= b {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: format!(
+                "a and b are not an interval [{:?}, {:?}]",
+                a.to_f64(),
+                b.to_f64()
+            ),
+        });
+    }
+    let mut solver = Toms748 {
+        f,
+        calls: 0,
+        iterations: 0,
+        k: k.unwrap_or(1).clamp(1, 100),
+        xtol,
+        rtol,
+        maxiter: maxiter.unwrap_or(MAXITER),
+        ab: [a, b],
+        fab: [F::zero(); 2],
+    };
+    solver.solve()
+}
+
+/// State of Algorithm 748: the bracket `ab`, with the function values `fab`.
+struct Toms748<F, Func> {
+    f: Func,
+    calls: usize,
+    iterations: usize,
+    k: usize,
+    xtol: F,
+    rtol: F,
+    maxiter: usize,
+    ab: [F; 2],
+    fab: [F; 2],
+}
+
+impl<F: Float, Func: FnMut(F) -> F> Toms748<F, Func> {
+    fn call(&mut self, x: F) -> Result<F> {
+        let fx = (self.f)(x);
+        self.calls += 1;
+        if !fx.is_finite() {
+            return Err(Error::InvalidArg {
+                arg: "f".into(),
+                reason: format!(
+                    "Invalid function value: f({:?}) -> {:?}",
+                    x.to_f64(),
+                    fx.to_f64()
+                ),
+            });
+        }
+        Ok(fx)
+    }
+
+    fn result(&self, x: F, flag: RootFlag) -> Result<RootResults<F>> {
+        Ok(RootResults::new(x, self.iterations, self.calls, flag))
+    }
+
+    fn midpoint(&self) -> F {
+        (self.ab[0] + self.ab[1]) / F::from(2).unwrap()
+    }
+
+    /// Replace the end of the bracket with the same sign as `fc` by `c`, returning the old end.
+    fn update_bracket(&mut self, c: F, fc: F) -> (F, F) {
+        let same = sign(self.fab[0]) * sign(fc) > F::zero();
+        let i = if same { 0 } else { 1 };
+        let old = (self.ab[i], self.fab[i]);
+        self.ab[i] = c;
+        self.fab[i] = fc;
+        old
+    }
+
+    fn solve(&mut self) -> Result<RootResults<F>> {
+        let [a, b] = self.ab;
+        if !a.is_finite() || !b.is_finite() {
+            return Err(Error::InvalidArg {
+                arg: "a".into(),
+                reason: "Invalid x value.".into(),
+            });
+        }
+        let fa = self.call(a)?;
+        if fa.is_zero() {
+            return self.result(a, RootFlag::Converged);
+        }
+        let fb = self.call(b)?;
+        if fb.is_zero() {
+            return self.result(b, RootFlag::Converged);
+        }
+        if sign(fa) * sign(fb) > F::zero() {
+            return Err(sign_error(a, b, fa, fb));
+        }
+        self.fab = [fa, fb];
+
+        // The first step only has two x-values.
+        let mut c = secant(self.ab, self.fab);
+        if !(self.ab[0] < c && c < self.ab[1]) {
+            c = self.midpoint();
+        }
+        let fc = self.call(c)?;
+        if fc.is_zero() {
+            return self.result(c, RootFlag::Converged);
+        }
+        let mut d = self.update_bracket(c, fc);
+        let mut e = None;
+        self.iterations += 1;
+
+        loop {
+            match self.iterate(&mut d, &mut e)? {
+                Some(root) => return self.result(root, RootFlag::Converged),
+                None => {
+                    let [a, b] = self.ab;
+                    if (a - b).abs() <= self.xtol + self.rtol * b.abs() {
+                        return self.result(self.midpoint(), RootFlag::Converged);
+                    }
+                    if self.iterations >= self.maxiter {
+                        return self.result(self.midpoint(), RootFlag::ConvergenceError);
+                    }
+                }
+            }
+        }
+    }
+
+    /// One step of the algorithm, Algorithm 4.1 (k=1) or 4.2 (k=2) in [APS1995], returning the
+    /// root if it was hit exactly. `d` and `e` are the previous ends of the bracket.
+    fn iterate(&mut self, d: &mut (F, F), e: &mut Option<(F, F)>) -> Result<Option<F>> {
+        self.iterations += 1;
+        let eps = F::epsilon();
+        let two = F::from(2).unwrap();
+        let width = self.ab[1] - self.ab[0];
+
+        for steps in 2..self.k + 2 {
+            let mut c = None;
+            // If the f-values are sufficiently separated, perform an inverse polynomial
+            // interpolation step. Otherwise, repeat an approximate Newton-Raphson step.
+            if let Some((xe, fe)) = *e {
+                let fs = [self.fab[0], self.fab[1], d.1, fe];
+                let atol = F::from(32).unwrap() * eps;
+                let separated = fs.iter().all(|f| !f.is_zero() && f.is_finite())
+                    && (0..4).all(|i| (i + 1..4).all(|j| (fs[i] - fs[j]).abs() > atol));
+                if separated {
+                    let c0 = neville(fs, [self.ab[0], self.ab[1], d.0, xe], F::zero());
+                    if self.ab[0] < c0 && c0 < self.ab[1] {
+                        c = Some(c0);
+                    }
+                }
+            }
+            let c = c.unwrap_or_else(|| newton_quadratic(self.ab, self.fab, *d, steps));
+            let fc = self.call(c)?;
+            if fc.is_zero() {
+                return Ok(Some(c));
+            }
+            *e = Some(*d);
+            *d = self.update_bracket(c, fc);
+        }
+
+        // `u` is the end with the smallest function value.
+        let ui = if self.fab[0].abs() < self.fab[1].abs() {
+            0
+        } else {
+            1
+        };
+        let (u, fu) = (self.ab[ui], self.fab[ui]);
+        let slope = (self.fab[1] - self.fab[0]) / (self.ab[1] - self.ab[0]);
+        let mut c = u - two * fu / slope;
+        if (c - u).abs() > (self.ab[1] - self.ab[0]) / two {
+            c = self.midpoint();
+        } else if (c - u).abs() <= eps * u.abs() {
+            // `c` didn't change much, either because the function values at the ends have vastly
+            // differing magnitudes, or because the root is very close to that end.
+            let exponent = |x: F| x.abs().log2().floor();
+            if exponent(self.fab[ui]) < exponent(self.fab[1 - ui]) - F::from(50).unwrap() {
+                c = (F::from(31).unwrap() * self.ab[ui] + self.ab[1 - ui]) / F::from(32).unwrap();
+            } else {
+                // Make a bigger adjustment, about the size of the requested tolerance.
+                let mm = if ui == 0 { F::one() } else { -F::one() };
+                c = u + mm * c.abs() * self.rtol + mm * self.xtol;
+            }
+            if !(self.ab[0] < c && c < self.ab[1]) {
+                c = self.midpoint();
+            }
+        }
+        let fc = self.call(c)?;
+        if fc.is_zero() {
+            return Ok(Some(c));
+        }
+        *e = Some(*d);
+        *d = self.update_bracket(c, fc);
+
+        // If the width of the new interval did not decrease enough, bisect.
+        if self.ab[1] - self.ab[0] > width / two {
+            *e = Some(*d);
+            let z = self.midpoint();
+            let fz = self.call(z)?;
+            if fz.is_zero() {
+                return Ok(Some(z));
+            }
+            *d = self.update_bracket(z, fz);
+        }
+        Ok(None)
+    }
+}
+
+fn sign<F: Float>(x: F) -> F {
+    if x > F::zero() {
+        F::one()
+    } else if x < F::zero() {
+        -F::one()
+    } else {
+        F::zero()
+    }
+}
+
+/// Root of the secant through the ends of a bracket.
+fn secant<F: Float>([x0, x1]: [F; 2], [f0, f1]: [F; 2]) -> F {
+    if f0 == f1 {
+        return F::nan();
+    }
+    if f1.abs() > f0.abs() {
+        (-f0 / f1 * x1 + x0) / (F::one() - f0 / f1)
+    } else {
+        (-f1 / f0 * x0 + x1) / (F::one() - f1 / f0)
+    }
+}
+
+/// Value at `x` of the cubic through the points `(xs, ys)`, by Neville's algorithm.
+fn neville<F: Float>(xs: [F; 4], ys: [F; 4], x: F) -> F {
+    let (mut q, mut d) = ([[F::zero(); 4]; 4], [[F::zero(); 4]; 4]);
+    for i in 0..4 {
+        q[i][0] = ys[i];
+        d[i][0] = ys[i];
+    }
+    for k in 1..4 {
+        for i in k..4 {
+            let alpha = d[i][k - 1] - q[i - 1][k - 1];
+            let diff = xs[i - k] - xs[i];
+            q[i][k] = (xs[i] - x) / diff * alpha;
+            d[i][k] = (xs[i - k] - x) / diff * alpha;
+        }
+    }
+    q[3][1] + q[3][2] + q[3][3] + q[3][0]
+}
+
+/// `k` Newton-Raphson steps on the quadratic through the ends of the bracket and the point `d`
+/// outside of it.
+fn newton_quadratic<F: Float>(ab: [F; 2], fab: [F; 2], (d, fd): (F, F), k: usize) -> F {
+    let ([a, b], [fa, fb]) = (ab, fab);
+    let slope = (fb - fa) / (b - a);
+    let curvature = ((fd - fb) / (d - b) - slope) / (d - a);
+    let p = |x: F| (curvature * (x - b) + slope) * (x - a) + fa;
+    if curvature.is_zero() {
+        return a - fa / slope;
+    }
+    let mut r = if sign(curvature) * sign(fa) > F::zero() {
+        a
+    } else {
+        b
+    };
+    for _ in 0..k {
+        let r1 = r - p(r) / (slope + curvature * (F::from(2).unwrap() * r - a - b));
+        if !(a < r1 && r1 < b) {
+            if a < r && r < b {
+                return r;
+            }
+            return (a + b) / F::from(2).unwrap();
+        }
+        r = r1;
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use core::f64::consts::FRAC_PI_2;
+
+    type Solver = fn(&mut dyn FnMut(f64) -> f64, f64, f64) -> Result<RootResults<f64>>;
+    /// A function with a bracket of its root.
+    type Problem = (fn(f64) -> f64, f64, f64, f64);
+
+    fn problems() -> [Problem; 5] {
+        [
+            (|x| x.powi(3) - 2. * x - 5., 2., 3., 2.094_551_481_542_326_5),
+            (|x| x.cos() - x, 0., 1., 0.739_085_133_215_160_7),
+            (|x| x.exp() - 2., -1., 4., core::f64::consts::LN_2),
+            (|x| x.sin(), 3., 4., core::f64::consts::PI),
+            (|x| x * (-x).exp() - 0.1, 0., 1., 0.111_832_559_158_962_9),
+        ]
+    }
+
+    fn solvers() -> [(&'static str, Solver); 5] {
+        [
+            ("brentq", |f, a, b| brentq(f, a, b, None, None, None)),
+            ("brenth", |f, a, b| brenth(f, a, b, None, None, None)),
+            ("bisect", |f, a, b| bisect(f, a, b, None, None, None)),
+            ("ridder", |f, a, b| ridder(f, a, b, None, None, None)),
+            ("toms748", |f, a, b| {
+                toms748(f, a, b, None, None, None, None)
+            }),
+        ]
+    }
+
+    #[test]
+    fn roots() {
+        let problems = problems();
+        for (name, solver) in solvers() {
+            for &(f, a, b, root) in &problems {
+                let mut calls = 0;
+                let result = solver(
+                    &mut |x| {
+                        calls += 1;
+                        f(x)
+                    },
+                    a,
+                    b,
+                )
+                .unwrap();
+                assert!(result.converged, "{name}");
+                assert_eq!(result.flag, RootFlag::Converged);
+                assert_eq!(result.function_calls, calls, "{name}");
+                assert_abs_diff_eq!(result.root, root, epsilon = 1e-11);
+            }
+            // Roots at the ends and sign errors.
+            let result = solver(&mut |x| x - 2., 2., 3.).unwrap();
+            assert_eq!((result.root, result.iterations), (2., 0));
+            assert!(solver(&mut |x| x * x + 1., -1., 1.).is_err(), "{name}");
+        }
+    }
+
+    #[test]
+    fn brent_steps() {
+        let expected = [(7, 8), (7, 8), (11, 12), (6, 7), (8, 9)];
+        for ((f, a, b, _), expected) in problems().into_iter().zip(expected) {
+            let result = brentq(f, a, b, None, None, None).unwrap();
+            assert_eq!((result.iterations, result.function_calls), expected);
+        }
+    }
+
+    #[test]
+    fn newton_methods() {
+        let f = |x: f64| x.powi(3) - 1.;
+        let (fprime, fprime2) = (&mut |x: f64| 3. * x * x, &mut |x: f64| 6. * x);
+        let runs = [
+            newton(f, 1.5, None, None, None, None, None, None),
+            newton(f, 1.5, Some(fprime), None, None, None, None, None),
+            newton(f, 1.5, Some(fprime), Some(fprime2), None, None, None, None),
+            newton(f64::cos, 1., None, None, Some(1.2), None, None, None),
+        ];
+        let expected = [(1., 7, 8), (1., 6, 12), (1., 4, 12), (FRAC_PI_2, 5, 6)];
+        for (result, (root, iterations, calls)) in runs.into_iter().zip(expected) {
+            let result = result.unwrap();
+            assert!(result.converged);
+            assert_abs_diff_eq!(result.root, root, epsilon = 1e-12);
+            assert_eq!(
+                (result.iterations, result.function_calls),
+                (iterations, calls)
+            );
+        }
+
+        // Vanishing derivative and secant.
+        let fprime = &mut |x: f64| 2. * x;
+        let result = newton(
+            |x| x * x + 1.,
+            0.,
+            Some(fprime),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(result.flag, RootFlag::ZeroDerivative);
+        assert_eq!(
+            (result.root, result.iterations, result.function_calls),
+            (0., 1, 2)
+        );
+        let result = newton(|_| 1., 0.5, None, None, None, None, None, None).unwrap();
+        assert_eq!(result.flag, RootFlag::ZeroDerivative);
+        assert_abs_diff_eq!(result.root, 0.500075, epsilon = 1e-15);
+
+        let result = newton(
+            |x| x * x + 1.,
+            0.5,
+            Some(fprime),
+            None,
+            None,
+            None,
+            None,
+            Some(5),
+        );
+        let result = result.unwrap();
+        assert_eq!(result.flag, RootFlag::ConvergenceError);
+        assert_eq!((result.iterations, result.function_calls), (5, 10));
+        assert_abs_diff_eq!(result.root, 0.841530602630985, epsilon = 1e-12);
+
+        assert!(newton(f, 1.5, None, None, Some(1.5), None, None, None).is_err());
+        assert!(newton(f, 1.5, None, None, None, Some(0.), None, None).is_err());
+        assert!(newton(f, 1.5, None, None, None, None, None, Some(0)).is_err());
+    }
+
+    #[test]
+    fn convergence_error() {
+        let f = |x: f64| x.powi(3) - 2. * x - 5.;
+        let result = bisect(f, 2., 3., None, None, Some(5)).unwrap();
+        assert!(!result.converged);
+        assert_eq!(result.flag, RootFlag::ConvergenceError);
+        assert_eq!((result.iterations, result.function_calls), (5, 7));
+        assert!(toms748(f, 2., 3., None, None, None, Some(1)).is_ok_and(|r| !r.converged));
+        assert!(brentq(f, 2., 3., Some(0.), None, None).is_err());
+        assert!(brentq(f, 2., 3., None, Some(1e-17), None).is_err());
+        assert!(toms748(f, 3., 2., None, None, None, None).is_err());
+        let invalid = |x: f64| if x > 0.5 { f64::NAN } else { x };
+        assert!(toms748(invalid, -1., 1., None, None, None, None).is_err());
+    }
+}
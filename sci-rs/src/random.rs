@@ -0,0 +1,173 @@
+//! Seedable pseudo-random number generation for the stochastic algorithms of the crate.
+//!
+//! [Rng] plays the role of numpy's `Generator`: the algorithms that draw random numbers take an
+//! optional `&mut Rng`, so that their results are reproducible from a seed.
+
+use num_traits::Float;
+
+/// A seedable pseudo-random number generator.
+///
+/// This is the `xoshiro256++` generator of Blackman and Vigna, whose state is expanded from the
+/// seed by `SplitMix64`. It is small and fast, but not cryptographically secure.
+///
+/// ## Examples
+/// ```
+/// use sci_rs::random::Rng;
+///
+/// let mut rng = Rng::new(42);
+/// let u: f64 = rng.random();
+/// assert!((0. ..1.).contains(&u));
+/// assert_eq!(Rng::new(42).random::<f64>(), u);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    s: [u64; 4],
+}
+
+impl Rng {
+    /// Create a generator from a seed. The same seed always gives the same sequence.
+    pub fn new(seed: u64) -> Self {
+        let mut z = seed;
+        let mut splitmix = || {
+            z = z.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut x = z;
+            x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            x ^ (x >> 31)
+        };
+        Rng {
+            s: [splitmix(), splitmix(), splitmix(), splitmix()],
+        }
+    }
+
+    /// Create a generator seeded from the randomness of the operating system, which differs
+    /// between calls.
+    #[cfg(feature = "std")]
+    pub fn from_entropy() -> Self {
+        use std::collections::hash_map::RandomState;
+        use std::hash::{BuildHasher, Hasher};
+
+        let mut hasher = RandomState::new().build_hasher();
+        if let Ok(time) = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+            hasher.write_u128(time.as_nanos());
+        }
+        Rng::new(hasher.finish())
+    }
+
+    /// Next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Sample from the uniform distribution over `[0, 1)`.
+    pub fn random<F: Float>(&mut self) -> F {
+        // The 53 high bits fill the mantissa of a f64.
+        let u = (self.next_u64() >> 11) as f64 * (1. / (1u64 << 53) as f64);
+        F::from(u).unwrap()
+    }
+
+    /// Sample from the uniform distribution over `[low, high)`.
+    pub fn uniform<F: Float>(&mut self, low: F, high: F) -> F {
+        low + (high - low) * self.random()
+    }
+
+    /// Sample an integer uniformly from `[low, high)`.
+    ///
+    /// ## Panics
+    /// If `low >= high`.
+    pub fn integers(&mut self, low: usize, high: usize) -> usize {
+        assert!(low < high, "low must be less than high");
+        let range = (high - low) as u64;
+        // Lemire's multiply and reject method, which avoids the bias of the modulo.
+        let threshold = range.wrapping_neg() % range;
+        loop {
+            let m = u128::from(self.next_u64()) * u128::from(range);
+            if (m as u64) >= threshold {
+                return low + (m >> 64) as usize;
+            }
+        }
+    }
+
+    /// Sample from the standard normal distribution, by the polar method of Marsaglia.
+    pub fn standard_normal<F: Float>(&mut self) -> F {
+        loop {
+            let u: f64 = self.uniform(-1., 1.);
+            let v: f64 = self.uniform(-1., 1.);
+            let s = u * u + v * v;
+            if s > 0. && s < 1. {
+                return F::from(u * (-2. * s.ln() / s).sqrt()).unwrap();
+            }
+        }
+    }
+
+    /// Shuffle `x` in place, with all the permutations equally likely.
+    pub fn shuffle<T>(&mut self, x: &mut [T]) {
+        for i in (1..x.len()).rev() {
+            x.swap(i, self.integers(0, i + 1));
+        }
+    }
+}
+
+/// Generator used when none is given: seeded from the operating system with the `std` feature,
+/// and from a fixed seed otherwise.
+pub(crate) fn default_rng() -> Rng {
+    #[cfg(feature = "std")]
+    return Rng::from_entropy();
+    #[cfg(not(feature = "std"))]
+    return Rng::new(0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn reproducible() {
+        let mut a = Rng::new(1234);
+        let mut b = Rng::new(1234);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn distributions() {
+        let mut rng = Rng::new(7);
+        let n = 100_000;
+        let u: Vec<f64> = (0..n).map(|_| rng.uniform(2., 4.)).collect();
+        assert!(u.iter().all(|u| (2. ..4.).contains(u)));
+        assert_abs_diff_eq!(u.iter().sum::<f64>() / n as f64, 3., epsilon = 1e-2);
+
+        let z: Vec<f64> = (0..n).map(|_| rng.standard_normal()).collect();
+        let mean = z.iter().sum::<f64>() / n as f64;
+        let var = z.iter().map(|z| (z - mean).powi(2)).sum::<f64>() / n as f64;
+        assert_abs_diff_eq!(mean, 0., epsilon = 2e-2);
+        assert_abs_diff_eq!(var, 1., epsilon = 2e-2);
+
+        let mut counts = [0; 5];
+        for _ in 0..n {
+            counts[rng.integers(3, 8) - 3] += 1;
+        }
+        assert!(counts
+            .iter()
+            .all(|&c| (c as f64 / n as f64 - 0.2).abs() < 1e-2));
+
+        let mut x: Vec<usize> = (0..10).collect();
+        rng.shuffle(&mut x);
+        let mut sorted = x.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,564 @@
+use alloc::vec::Vec;
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Gaussian approximation to B-spline basis function of order n.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   a knot vector
+/// * `n`: int
+///   The order of the spline. Must be non-negative, i.e., n >= 0
+///
+/// ## Returns
+/// * `res`: ndarray
+///   B-spline basis function values approximated by a zero-mean Gaussian function.
+///
+/// ## Notes
+/// The B-spline basis function can be approximated well by a zero-mean Gaussian function with
+/// standard-deviation equal to `sqrt((n + 1) / 12)` for large `n`:
+///
+/// ``f(x; sigma) = exp(-x**2 / (2 * sigma**2)) / sqrt(2 * pi * sigma**2)``
+///
+/// ## References
+/// 1. Bouma H., Vilanova A., Bescos J.O., ter Haar Romeny B.M., Gerritsen F.A. (2007) Fast and
+///    Accurate Gaussian Derivatives Based on B-Splines. In: Sgallari F., Murli A., Paragios N.
+///    (eds) Scale Space and Variational Methods in Computer Vision. SSVM 2007. Lecture Notes in
+///    Computer Science, vol 4485. Springer, Berlin, Heidelberg
+/// 2. <http://folk.uio.no/inf3330/scripting/doc/python/SciPy/tutorial/old/node24.html>
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy.signal import gauss_spline
+/// >>> knots = np.array([-1.0, 0.0, -1.0])
+/// >>> gauss_spline(knots, 3)
+/// array([0.15418033, 0.6909883, 0.15418033])  # may vary
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::signal::bsplines::gauss_spline;
+///
+/// let res = gauss_spline(&[-1.0f64, 0.0, -1.0], 3);
+/// assert!((res[0] - 0.15418033).abs() < 1e-8);
+/// assert!((res[1] - 0.6909883).abs() < 1e-7);
+/// ```
+pub fn gauss_spline<F: Float>(x: &[F], n: usize) -> Vec<F> {
+    let signsq = F::from(n + 1).unwrap() / F::from(12).unwrap();
+    let two = F::from(2).unwrap();
+    let scale = (two * F::from(core::f64::consts::PI).unwrap() * signsq)
+        .sqrt()
+        .recip();
+    x.iter()
+        .map(|&x| scale * (-x * x / two / signsq).exp())
+        .collect()
+}
+
+/// Compute cubic spline coefficients for rank-1 array.
+///
+/// Find the cubic spline coefficients for a 1-D signal assuming mirror-symmetric boundary
+/// conditions. To obtain the signal back from the spline representation mirror-symmetric-convolve
+/// these coefficients with a length 3 FIR window ``[1.0, 4.0, 1.0] / 6.0``.
+///
+/// ## Parameters
+/// * `signal`: ndarray
+///   A rank-1 array representing samples of a signal.
+/// * `lamb`: float, optional
+///   Smoothing coefficient, default is 0.0.
+///
+/// ## Returns
+/// * `c`: ndarray
+///   Cubic spline coefficients.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `signal` is empty, or if `lamb` is negative or lies in
+/// ``(0, 1/144)`` where the smoothing filter has no complex pole pair. A smoothing spline also
+/// needs at least two samples.
+///
+/// ## Notes
+/// With `lamb = 0` the coefficients interpolate the signal and are computed by a cascade of a
+/// causal and an anti-causal first-order IIR filter with a pole at ``-2 + sqrt(3)``. Otherwise a
+/// pair of second-order sections is used to compute the smoothing spline.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy.signal import cspline1d, cspline1d_eval
+/// >>> sig = np.array([1.0, 3.0, 2.0, 5.0, 4.0, 0.5, 2.0])
+/// >>> cspline1d_eval(cspline1d(sig), [1.0, 2.5])
+/// array([3.        , 3.34708676])
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::signal::bsplines::{cspline1d, cspline1d_eval};
+///
+/// let sig = [1.0, 3.0, 2.0, 5.0, 4.0, 0.5, 2.0];
+/// let cj = cspline1d::<f64>(&sig, None).unwrap();
+/// let y = cspline1d_eval(&cj, &[1.0, 2.5], None, None);
+/// assert!((y[0] - 3.0).abs() < 1e-12);
+/// assert!((y[1] - 3.34708676).abs() < 1e-8);
+/// ```
+pub fn cspline1d<F: Float>(signal: &[F], lamb: Option<F>) -> Result<Vec<F>> {
+    check_signal(signal)?;
+    let lamb = lamb.unwrap_or_else(F::zero);
+    if lamb.is_zero() {
+        let zi = F::from(-2).unwrap() + F::from(3).unwrap().sqrt();
+        return Ok(first_order_coeff(signal, zi, F::from(6).unwrap()));
+    }
+    if lamb < F::from(1. / 144.).unwrap() || lamb.is_nan() {
+        return Err(Error::InvalidArg {
+            arg: "lamb".into(),
+            reason: "lamb must be zero or at least 1/144.".into(),
+        });
+    }
+    if signal.len() < 2 {
+        return Err(Error::InvalidArg {
+            arg: "signal".into(),
+            reason: "A smoothing spline needs at least two samples.".into(),
+        });
+    }
+    Ok(cubic_smooth_coeff(signal, lamb))
+}
+
+/// Compute quadratic spline coefficients for rank-1 array.
+///
+/// Find the quadratic spline coefficients for a 1-D signal assuming mirror-symmetric boundary
+/// conditions. To obtain the signal back from the spline representation mirror-symmetric-convolve
+/// these coefficients with a length 3 FIR window ``[1.0, 6.0, 1.0] / 8.0``.
+///
+/// ## Parameters
+/// * `signal`: ndarray
+///   A rank-1 array representing samples of a signal.
+/// * `lamb`: float, optional
+///   Smoothing coefficient (must be zero for now).
+///
+/// ## Returns
+/// * `c`: ndarray
+///   Quadratic spline coefficients.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `signal` is empty or `lamb` is not zero, smoothing quadratic splines
+/// are not supported.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy.signal import qspline1d, qspline1d_eval
+/// >>> sig = np.array([1.0, 3.0, 2.0, 5.0, 4.0, 0.5, 2.0])
+/// >>> qspline1d_eval(qspline1d(sig), [1.0, 2.5])
+/// array([3.        , 3.41639224])
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::signal::bsplines::{qspline1d, qspline1d_eval};
+///
+/// let sig = [1.0, 3.0, 2.0, 5.0, 4.0, 0.5, 2.0];
+/// let cj = qspline1d::<f64>(&sig, None).unwrap();
+/// let y = qspline1d_eval(&cj, &[1.0, 2.5], None, None);
+/// assert!((y[0] - 3.0).abs() < 1e-12);
+/// assert!((y[1] - 3.41639224).abs() < 1e-8);
+/// ```
+pub fn qspline1d<F: Float>(signal: &[F], lamb: Option<F>) -> Result<Vec<F>> {
+    check_signal(signal)?;
+    if !lamb.unwrap_or_else(F::zero).is_zero() {
+        return Err(Error::InvalidArg {
+            arg: "lamb".into(),
+            reason: "Smoothing quadratic splines not supported yet.".into(),
+        });
+    }
+    let zi = F::from(-3).unwrap() + F::from(2).unwrap() * F::from(2).unwrap().sqrt();
+    Ok(first_order_coeff(signal, zi, F::from(8).unwrap()))
+}
+
+/// Evaluate a cubic spline at the new set of points.
+///
+/// `dx` is the old sample-spacing while `x0` was the old origin. In other-words the old-sample
+/// points (knot-points) for which the `cj` represent spline coefficients were at equally-spaced
+/// points of:
+///
+/// ``oldx = x0 + j*dx  j=0...N-1``, with ``N=len(cj)``
+///
+/// Edges are handled using mirror-symmetric boundary conditions.
+///
+/// ## Parameters
+/// * `cj`: ndarray
+///   cublic spline coefficients
+/// * `newx`: ndarray
+///   New set of points.
+/// * `dx`: float, optional
+///   Old sample-spacing, the default value is 1.0.
+/// * `x0`: int, optional
+///   Old origin, the default value is 0.
+///
+/// ## Returns
+/// * `res`: ndarray
+///   Evaluated a cubic spline points.
+///
+/// ## Examples
+/// See [cspline1d].
+pub fn cspline1d_eval<F: Float>(cj: &[F], newx: &[F], dx: Option<F>, x0: Option<F>) -> Vec<F> {
+    spline1d_eval(cj, newx, dx, x0, F::from(2).unwrap(), 4, cubic)
+}
+
+/// Evaluate a quadratic spline at the new set of points.
+///
+/// `dx` is the old sample-spacing while `x0` was the old origin. In other-words the old-sample
+/// points (knot-points) for which the `cj` represent spline coefficients were at equally-spaced
+/// points of:
+///
+/// ``oldx = x0 + j*dx  j=0...N-1``, with ``N=len(cj)``
+///
+/// Edges are handled using mirror-symmetric boundary conditions.
+///
+/// ## Parameters
+/// * `cj`: ndarray
+///   Quadratic spline coefficients
+/// * `newx`: ndarray
+///   New set of points.
+/// * `dx`: float, optional
+///   Old sample-spacing, the default value is 1.0.
+/// * `x0`: int, optional
+///   Old origin, the default value is 0.
+///
+/// ## Returns
+/// * `res`: ndarray
+///   Evaluated a quadratic spline points.
+///
+/// ## Examples
+/// See [qspline1d].
+pub fn qspline1d_eval<F: Float>(cj: &[F], newx: &[F], dx: Option<F>, x0: Option<F>) -> Vec<F> {
+    spline1d_eval(cj, newx, dx, x0, F::from(1.5).unwrap(), 3, quadratic)
+}
+
+fn check_signal<F>(signal: &[F]) -> Result<()> {
+    if signal.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "signal".into(),
+            reason: "signal must not be empty.".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Interpolating spline coefficients from the symmetric first-order IIR filter
+/// ``gain * (-zi) / ((1 - zi/z) (1 - zi z))``, run as a causal pass followed by an anti-causal
+/// pass over the output.
+fn first_order_coeff<F: Float>(signal: &[F], zi: F, gain: F) -> Vec<F> {
+    let k = signal.len();
+    // Initial condition of the causal pass from the mirror-symmetric extension of the signal.
+    let mut power = F::one();
+    let mut sum = F::zero();
+    for &s in signal {
+        sum = sum + power * s;
+        power = power * zi;
+    }
+    let first = signal[0] + zi * sum;
+    if k == 1 {
+        return alloc::vec![gain * zi / (zi - F::one()) * first];
+    }
+
+    let mut yplus = Vec::with_capacity(k);
+    yplus.push(first);
+    for &s in &signal[1..] {
+        let prev = *yplus.last().unwrap();
+        yplus.push(s + zi * prev);
+    }
+
+    let mut output = alloc::vec![F::zero(); k];
+    output[k - 1] = zi / (zi - F::one()) * yplus[k - 1];
+    for i in (0..k - 1).rev() {
+        output[i] = zi * (output[i + 1] - yplus[i]);
+    }
+    output.into_iter().map(|o| o * gain).collect()
+}
+
+/// Pole radius and angle of the smoothing cubic spline filter.
+fn coeff_smooth<F: Float>(lamb: F) -> (F, F) {
+    let c = |v: f64| F::from(v).unwrap();
+    let root = (c(3.) + c(144.) * lamb).sqrt();
+    let xi = F::one() - c(96.) * lamb + c(24.) * lamb * root;
+    let omega = (c(144.) * lamb - F::one()).sqrt().atan2(xi.sqrt());
+    let rho = (c(24.) * lamb - F::one() - xi.sqrt()) / (c(24.) * lamb);
+    let rho = rho * ((c(48.) * lamb + c(24.) * lamb * root) / xi).sqrt();
+    (rho, omega)
+}
+
+/// Impulse response of the causal second-order section.
+fn hc<F: Float>(k: usize, cs: F, rho: F, omega: F) -> F {
+    let k = F::from(k).unwrap();
+    cs / omega.sin() * rho.powf(k) * (omega * (k + F::one())).sin()
+}
+
+/// Impulse response of the symmetric cascade of both second-order sections.
+fn hs<F: Float>(k: isize, cs: F, rho: F, omega: F) -> F {
+    let rho2 = rho * rho;
+    let c0 = cs * cs * (F::one() + rho2)
+        / (F::one() - rho2)
+        / (F::one() - F::from(2).unwrap() * rho2 * (F::from(2).unwrap() * omega).cos()
+            + rho2 * rho2);
+    let gamma = (F::one() - rho2) / (F::one() + rho2) / omega.tan();
+    let ak = F::from(k.unsigned_abs()).unwrap();
+    c0 * rho.powf(ak) * ((omega * ak).cos() + gamma * (omega * ak).sin())
+}
+
+fn cubic_smooth_coeff<F: Float>(signal: &[F], lamb: F) -> Vec<F> {
+    let (rho, omega) = coeff_smooth(lamb);
+    let a1 = F::from(2).unwrap() * rho * omega.cos();
+    let a2 = rho * rho;
+    let cs = F::one() - a1 + a2;
+    let k = signal.len();
+
+    // Forward filter:
+    // yp[n] = cs * signal[n] + 2 * rho * cos(omega) * yp[n - 1] - rho * rho * yp[n - 2]
+    let h0 = hc(0, cs, rho, omega) * signal[0];
+    let zi_2 = h0
+        + signal.iter().enumerate().fold(F::zero(), |acc, (i, &s)| {
+            acc + hc(i + 1, cs, rho, omega) * s
+        });
+    let zi_1 = h0
+        + hc(1, cs, rho, omega) * signal[1]
+        + signal.iter().enumerate().fold(F::zero(), |acc, (i, &s)| {
+            acc + hc(i + 2, cs, rho, omega) * s
+        });
+    let mut yp = alloc::vec![F::zero(); k];
+    yp[0] = zi_2;
+    yp[1] = zi_1;
+    for n in 2..k {
+        yp[n] = cs * signal[n] + a1 * yp[n - 1] - a2 * yp[n - 2];
+    }
+
+    // Reverse filter:
+    // y[n] = cs * yp[n] + 2 * rho * cos(omega) * y[n + 1] - rho * rho * y[n + 2]
+    let (zi_2, zi_3) =
+        signal
+            .iter()
+            .rev()
+            .enumerate()
+            .fold((F::zero(), F::zero()), |(z2, z3), (i, &s)| {
+                let i = i as isize;
+                (
+                    z2 + (hs(i, cs, rho, omega) + hs(i + 1, cs, rho, omega)) * s,
+                    z3 + (hs(i - 1, cs, rho, omega) + hs(i + 2, cs, rho, omega)) * s,
+                )
+            });
+    let mut y = alloc::vec![F::zero(); k];
+    y[k - 1] = zi_2;
+    y[k - 2] = zi_3;
+    for n in (0..k.saturating_sub(2)).rev() {
+        y[n] = cs * yp[n] + a1 * y[n + 1] - a2 * y[n + 2];
+    }
+    y
+}
+
+/// Cubic B-spline basis function.
+fn cubic<F: Float>(x: F) -> F {
+    let x = x.abs();
+    let c = |v: f64| F::from(v).unwrap();
+    if x < F::one() {
+        c(2. / 3.) - x * x + x * x * x / c(2.)
+    } else if x < c(2.) {
+        let t = c(2.) - x;
+        t * t * t / c(6.)
+    } else {
+        F::zero()
+    }
+}
+
+/// Quadratic B-spline basis function.
+fn quadratic<F: Float>(x: F) -> F {
+    let x = x.abs();
+    let c = |v: f64| F::from(v).unwrap();
+    if x < c(0.5) {
+        c(0.75) - x * x
+    } else if x < c(1.5) {
+        let t = x - c(1.5);
+        t * t / c(2.)
+    } else {
+        F::zero()
+    }
+}
+
+/// Evaluate the spline with coefficients `cj` and a basis function of support `2 * half_width`
+/// covering `terms` knots, reflecting points outside of ``[0, N-1]`` back into it.
+fn spline1d_eval<F: Float>(
+    cj: &[F],
+    newx: &[F],
+    dx: Option<F>,
+    x0: Option<F>,
+    half_width: F,
+    terms: usize,
+    basis: fn(F) -> F,
+) -> Vec<F> {
+    let dx = dx.unwrap_or_else(F::one);
+    let x0 = x0.unwrap_or_else(F::zero);
+    let n = cj.len();
+    if n == 0 {
+        return alloc::vec![F::zero(); newx.len()];
+    }
+    let last = F::from(n - 1).unwrap();
+    newx.iter()
+        .map(|&x| {
+            let mut x = (x - x0) / dx;
+            // Mirror-symmetric boundary conditions, with period 2 * (N - 1).
+            if n == 1 {
+                x = F::zero();
+            }
+            while x < F::zero() || x > last {
+                x = if x < F::zero() {
+                    -x
+                } else {
+                    F::from(2).unwrap() * last - x
+                };
+            }
+            let jlower = (x - half_width).floor().to_isize().unwrap() + 1;
+            (0..terms as isize)
+                .map(|i| {
+                    let j = jlower + i;
+                    let idx = j.clamp(0, n as isize - 1) as usize;
+                    cj[idx] * basis(x - F::from(j).unwrap())
+                })
+                .fold(F::zero(), |acc, v| acc + v)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    const SIGNAL: [f64; 7] = [1., 3., 2., 5., 4., 0.5, 2.];
+    const NEWX: [f64; 6] = [-1.5, 0.25, 2.5, 5.75, 7., -0.5];
+
+    #[track_caller]
+    fn assert_vec_eq(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn cubic_interpolation() {
+        let cj = cspline1d(&SIGNAL, None).unwrap();
+        assert_vec_eq(
+            &cj,
+            &[
+                0.3346697145416824,
+                4.326202019051283,
+                0.3605222092531947,
+                6.231709143935938,
+                4.71264121500306,
+                -1.0822740039481744,
+                2.6164548007896355,
+            ],
+        );
+        let knots: Vec<f64> = (0..7).map(f64::from).collect();
+        let y = cspline1d_eval(&cj, &knots, None, None);
+        for (y, s) in y[1..].iter().zip(&SIGNAL[1..]) {
+            assert_abs_diff_eq!(y, s, epsilon = 1e-10);
+        }
+        assert_abs_diff_eq!(y[0], 1., epsilon = 1e-4);
+        assert_vec_eq(
+            &cspline1d_eval(&cj, &NEWX, None, None),
+            &[
+                2.382521585614179,
+                1.5924854992822375,
+                3.3470867574459247,
+                1.4564287618337457,
+                0.4999999999999997,
+                2.2478175374256892,
+            ],
+        );
+        // Rescaled sample points.
+        let newx: Vec<f64> = NEWX.iter().map(|x| 10. + 0.5 * x).collect();
+        assert_vec_eq(
+            &cspline1d_eval(&cj, &newx, Some(0.5), Some(10.)),
+            &cspline1d_eval(&cj, &NEWX, None, None),
+        );
+    }
+
+    #[test]
+    fn cubic_smoothing() {
+        let cj = cspline1d(&SIGNAL, Some(0.5)).unwrap();
+        assert_vec_eq(
+            &cj,
+            &[
+                1.176386578195604,
+                1.8739282876154164,
+                2.8514118106890867,
+                3.987619281173626,
+                3.405601228977583,
+                1.8259114625557809,
+                1.490458570483127,
+            ],
+        );
+        assert_vec_eq(
+            &cspline1d_eval(&cj, &NEWX, None, None),
+            &[
+                2.371808919174433,
+                1.4005469221338611,
+                3.3870259297799037,
+                1.6011485251687663,
+                2.0332842749473055,
+                1.545521672969545,
+            ],
+        );
+    }
+
+    #[test]
+    fn quadratic_interpolation() {
+        let cj = qspline1d(&SIGNAL, None).unwrap();
+        assert_vec_eq(
+            &cj,
+            &[
+                0.6119535012644364,
+                3.7163109300994033,
+                1.0901809181391215,
+                5.74260356106586,
+                4.454197715465684,
+                -0.46778985385999416,
+                2.352541407694283,
+            ],
+        );
+        assert_vec_eq(
+            &qspline1d_eval(&cj, &NEWX, None, None),
+            &[
+                2.4032459241192625,
+                1.4850540281242708,
+                3.416392239602491,
+                1.5593232403821427,
+                0.5000000000000002,
+                2.16413221568192,
+            ],
+        );
+    }
+
+    #[test]
+    fn gauss() {
+        let res = gauss_spline(&[0., 1., 2.], 3);
+        let sigma2: f64 = 4. / 12.;
+        for (x, r) in [0f64, 1., 2.].iter().zip(res) {
+            let expected =
+                (-x * x / (2. * sigma2)).exp() / (2. * core::f64::consts::PI * sigma2).sqrt();
+            assert_abs_diff_eq!(r, expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        // A single sample is a constant spline.
+        let cj = cspline1d(&[2.], None).unwrap();
+        assert_vec_eq(
+            &cspline1d_eval(&cj, &[-3., 0., 4.5], None, None),
+            &[cj[0]; 3],
+        );
+        assert!(cspline1d::<f64>(&[], None).is_err());
+        assert!(cspline1d(&SIGNAL, Some(-1.)).is_err());
+        assert!(cspline1d(&SIGNAL, Some(1e-3)).is_err());
+        assert!(cspline1d(&[1.], Some(1.)).is_err());
+        assert!(qspline1d(&SIGNAL, Some(1.)).is_err());
+        assert!(qspline1d::<f64>(&[], None).is_err());
+        assert!(cspline1d_eval::<f64>(&[], &[1.], None, None) == [0.]);
+    }
+}
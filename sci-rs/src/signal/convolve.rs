@@ -1,82 +1,617 @@
+use crate::fft::next_fast_len;
 use nalgebra::Complex;
-use num_traits::{Float, FromPrimitive, Signed, Zero};
+use ndarray::{
+    Array, Array2, ArrayBase, ArrayView, ArrayView1, ArrayView2, Axis, Data, Dimension, Ix2, Slice,
+    Zip,
+};
+use num_traits::{Float, Zero};
+use realfft::RealFftPlanner;
 use rustfft::{FftNum, FftPlanner};
+use sci_rs_core::{Error, Result};
 
 pub use sci_rs_core::num_rs::ConvolveMode;
 
-/// Performs FFT-based convolution on two slices of floating point values.
+/// Convolve two N-dimensional arrays using FFT.
 ///
-/// According to Python docs, this is generally much faster than direct convolution
-/// for large arrays (n > ~500), but can be slower when only a few output values are needed.
-/// We only implement the FFT version in Rust for now.
+/// Convolve `in1` and `in2` using the fast Fourier transform method, with
+/// the output size determined by the `mode` argument.
 ///
-/// # Arguments
-/// - `in1`: First input signal
-/// - `in2`: Second input signal
-/// - `mode`: Convolution mode (currently only Full is supported)
+/// This is generally much faster than direct convolution for large arrays (n > ~500), but can be
+/// slower when only a few output values are needed. Since the inputs are real, the transform along
+/// the last convolved axis only computes the non-negative frequencies.
 ///
-/// # Returns
-/// A Vec containing the discrete linear convolution of `in1` with `in2`.
-/// For Full mode, the output length will be `in1.len() + in2.len() - 1`.
-pub fn fftconvolve<F: Float + FftNum>(in1: &[F], in2: &[F], mode: ConvolveMode) -> Vec<F> {
-    // Determine the size of the FFT (next power of 2 for zero-padding)
-    let n1 = in1.len();
-    let n2 = in2.len();
-    let n = n1 + n2 - 1;
-    let fft_size = n.next_power_of_two();
-
-    // Prepare input buffers as Complex<F> with zero-padding to fft_size
-    let mut padded_in1 = vec![Complex::zero(); fft_size];
-    let mut padded_in2 = vec![Complex::zero(); fft_size];
-
-    // Copy input data into zero-padded buffers
-    padded_in1.iter_mut().zip(in1.iter()).for_each(|(p, &v)| {
-        *p = Complex::new(v, F::zero());
-    });
-    padded_in2.iter_mut().zip(in2.iter()).for_each(|(p, &v)| {
-        *p = Complex::new(v, F::zero());
-    });
+/// ## Parameters
+/// * `in1`: array_like
+///   First input.
+/// * `in2`: array_like
+///   Second input. Should have the same number of dimensions as `in1`.
+/// * `mode`: [ConvolveMode]
+///   A string indicating the size of the output:
+///
+///   [ConvolveMode::Full]:
+///   The output is the full discrete linear convolution of the inputs.
+///
+///   [ConvolveMode::Valid]:
+///   The output consists only of those elements that do not rely on the zero-padding. In 'valid'
+///   mode, either `in1` or `in2` must be at least as large as the other in every dimension.
+///
+///   [ConvolveMode::Same]:
+///   The output is the same size as `in1`, centered with respect to the 'full' output.
+/// * `axes`: `Option<&[isize]>`
+///   Axes over which to compute the convolution. The default is over all axes. Negative axes count
+///   from the last axis. The remaining axes must have equal lengths in both inputs or a length of
+///   one in either, and are broadcast.
+///
+/// ## Returns
+/// * `out`: array
+///   An N-dimensional array containing a subset of the discrete linear convolution of `in1` with
+///   `in2`.
+///
+/// ## Errors
+/// [Error::InvalidArg] if the inputs have a different number of dimensions, if `axes` holds an
+/// axis out of range or the same axis twice, or if the shapes of `in1` and `in2` cannot be
+/// broadcast along the remaining axes. [Error::ConflictArg] if neither input is at least as large
+/// as the other along every convolved axis in [ConvolveMode::Valid].
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy import signal
+/// >>> face = np.array([[1., 2., 3., 4.], [5., 6., 7., 8.], [9., 10., 11., 12.]])
+/// >>> kernel = np.outer(signal.windows.gaussian(3, 1), signal.windows.gaussian(3, 1))
+/// >>> blurred = signal.fftconvolve(face, kernel, mode='same')
+/// >>> blurred[1, 1]
+/// 29.385842421217816
+/// ```
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{array, Array2};
+/// use sci_rs::signal::convolve::{fftconvolve, ConvolveMode};
+///
+/// let face = array![[1., 2., 3., 4.], [5., 6., 7., 8.], [9., 10., 11., 12.]];
+/// let g = [(-0.5f64).exp(), 1., (-0.5f64).exp()];
+/// let kernel = Array2::from_shape_fn((3, 3), |(i, j)| g[i] * g[j]);
+/// let blurred = fftconvolve(&face, &kernel, ConvolveMode::Same, None).unwrap();
+/// assert_eq!(blurred.dim(), (3, 4));
+/// assert_abs_diff_eq!(blurred[[1, 1]], 29.385842421217816, epsilon = 1e-10);
+/// ```
+pub fn fftconvolve<F, S1, S2, D>(
+    in1: &ArrayBase<S1, D>,
+    in2: &ArrayBase<S2, D>,
+    mode: ConvolveMode,
+    axes: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = F>,
+    D: Dimension,
+{
+    let ndim = in1.ndim();
+    if in2.ndim() != ndim {
+        return Err(Error::InvalidArg {
+            arg: "in2".into(),
+            reason: "in1 and in2 should have the same dimensionality".into(),
+        });
+    }
+    if in1.is_empty() || in2.is_empty() {
+        return Ok(Array::zeros(D::zeros(ndim)));
+    }
 
-    // Perform the FFT
-    let mut planner = FftPlanner::new();
-    let fft = planner.plan_fft_forward(fft_size);
-    fft.process(&mut padded_in1);
-    fft.process(&mut padded_in2);
+    let (mut in1, mut in2) = (in1.view(), in2.view());
+    let axes = init_freq_conv_axes(in1.shape(), in2.shape(), mode, axes)?;
+    if mode == ConvolveMode::Valid && inputs_swap_needed(in1.shape(), in2.shape(), &axes)? {
+        core::mem::swap(&mut in1, &mut in2);
+    }
 
-    // Multiply element-wise in the frequency domain
-    let mut result_freq: Vec<Complex<F>> = padded_in1
-        .iter()
-        .zip(&padded_in2)
-        .map(|(a, b)| a * b)
-        .collect();
+    let shape = full_shape(&in1, &in2, &axes);
+    let ret = conv_full(in1.view(), in2.view(), &axes, &shape);
+    Ok(apply_conv_mode(ret, in1.shape(), in2.shape(), mode, &axes))
+}
 
-    // Perform the inverse FFT
-    let ifft = planner.plan_fft_inverse(fft_size);
-    ifft.process(&mut result_freq);
+/// Convolve two N-dimensional arrays using the overlap-add method.
+///
+/// Convolve `in1` and `in2` using the overlap-add method, with the output size determined by the
+/// `mode` argument.
+///
+/// This is generally much faster than [convolve] for large arrays (n > ~500), and generally much
+/// faster than [fftconvolve] when one array is much larger than the other, but can be slower when
+/// only a few output values are needed or when the arrays are very similar in shape, and can only
+/// output float arrays (int or object array inputs will be cast to float).
+///
+/// ## Parameters
+/// * `in1`: array_like
+///   First input.
+/// * `in2`: array_like
+///   Second input. Should have the same number of dimensions as `in1`.
+/// * `mode`: [ConvolveMode]
+///   A string indicating the size of the output, as in [fftconvolve].
+/// * `axes`: `Option<&[isize]>`
+///   Axes over which to compute the convolution. The default is over all axes. The remaining axes
+///   are broadcast as in [fftconvolve], so multichannel data can be convolved along time only.
+///
+/// ## Returns
+/// * `out`: array
+///   An N-dimensional array containing a subset of the discrete linear convolution of `in1` with
+///   `in2`.
+///
+/// ## Errors
+/// As for [fftconvolve].
+///
+/// ## Notes
+/// The larger input is split into blocks along each convolved axis, each block is convolved with
+/// the smaller input using the FFT, and the overlapping results are summed. The block size is
+/// chosen to minimize the cost per output sample as in scipy, rounded up to a fast FFT length. When
+/// no axis benefits from splitting, this is the same as [fftconvolve].
+///
+/// ## References
+/// 1. Wikipedia, "Overlap-add_method". <https://en.wikipedia.org/wiki/Overlap-add_method>
+/// 2. Richard G. Lyons. Understanding Digital Signal Processing, Third Edition, 2011. Chapter 13.10.
+///    ISBN 13: 978-0137-02741-5
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> from scipy import signal
+/// >>> signal.oaconvolve([1., 2., 3., 4., 5., 6., 7., 8., 9., 10.], [1., 1.])
+/// array([ 1.,  3.,  5.,  7.,  9., 11., 13., 15., 17., 19., 10.])
+/// ```
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{array, Array2};
+/// use sci_rs::signal::convolve::{oaconvolve, ConvolveMode};
+///
+/// let sig = array![1., 2., 3., 4., 5., 6., 7., 8., 9., 10.];
+/// let out = oaconvolve(&sig, &array![1., 1.], ConvolveMode::Full, None).unwrap();
+/// for (a, b) in out.iter().zip([1., 3., 5., 7., 9., 11., 13., 15., 17., 19., 10.]) {
+///     assert_abs_diff_eq!(*a, b, epsilon = 1e-10);
+/// }
+///
+/// // Smooth each of 4 channels along time with the same kernel.
+/// let channels = Array2::from_shape_fn((4, 1000), |(c, t)| (c * t) as f64);
+/// let kernel = array![[0.25, 0.5, 0.25]];
+/// let smoothed = oaconvolve(&channels, &kernel, ConvolveMode::Same, Some(&[-1])).unwrap();
+/// assert_eq!(smoothed.dim(), (4, 1000));
+/// assert_abs_diff_eq!(smoothed[[3, 500]], 1500., epsilon = 1e-8);
+/// ```
+pub fn oaconvolve<F, S1, S2, D>(
+    in1: &ArrayBase<S1, D>,
+    in2: &ArrayBase<S2, D>,
+    mode: ConvolveMode,
+    axes: Option<&[isize]>,
+) -> Result<Array<F, D>>
+where
+    F: Float + FftNum,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = F>,
+    D: Dimension,
+{
+    let ndim = in1.ndim();
+    if in2.ndim() != ndim {
+        return Err(Error::InvalidArg {
+            arg: "in2".into(),
+            reason: "in1 and in2 should have the same dimensionality".into(),
+        });
+    }
+    if in1.is_empty() || in2.is_empty() {
+        return Ok(Array::zeros(D::zeros(ndim)));
+    }
 
-    // Take only the real part, normalize, and truncate to the original output size (n)
-    let fft_size = F::from(fft_size).unwrap();
-    let full_convolution = result_freq
+    let (mut in1, mut in2) = (in1.view(), in2.view());
+    let axes = init_freq_conv_axes(in1.shape(), in2.shape(), mode, axes)?;
+    if mode == ConvolveMode::Valid && inputs_swap_needed(in1.shape(), in2.shape(), &axes)? {
+        core::mem::swap(&mut in1, &mut in2);
+    }
+
+    let shape = full_shape(&in1, &in2, &axes);
+    let lens: Vec<_> = axes
         .iter()
-        .take(n)
-        .map(|x| x.re / fft_size)
+        .map(|&d| oa_lens(in1.len_of(Axis(d)), in2.len_of(Axis(d))))
         .collect();
+    let split = axes.iter().zip(&lens).any(|(&d, &(_, step1, step2))| {
+        step1 != in1.len_of(Axis(d)) || step2 != in2.len_of(Axis(d))
+    });
+    let ret = if split {
+        overlap_add_conv(in1.view(), in2.view(), &axes, &lens, &shape)
+    } else {
+        conv_full(in1.view(), in2.view(), &axes, &shape)
+    };
+    Ok(apply_conv_mode(ret, in1.shape(), in2.shape(), mode, &axes))
+}
 
-    // Extract the appropriate slice based on the mode
-    match mode {
-        ConvolveMode::Full => full_convolution,
-        ConvolveMode::Valid => {
-            if n1 >= n2 {
-                full_convolution[(n2 - 1)..(n1)].to_vec()
-            } else {
-                Vec::new()
+/// Shape of the full convolution, with the axes that are not convolved broadcast.
+fn full_shape<F, D: Dimension>(in1: &ArrayView<F, D>, in2: &ArrayView<F, D>, axes: &[usize]) -> D {
+    let mut shape = in1.raw_dim();
+    for d in 0..shape.ndim() {
+        shape[d] = if axes.contains(&d) {
+            in1.len_of(Axis(d)) + in2.len_of(Axis(d)) - 1
+        } else {
+            in1.len_of(Axis(d)).max(in2.len_of(Axis(d)))
+        };
+    }
+    shape
+}
+
+/// Full convolution of `in1` and `in2` along `axes`, of the given `shape`.
+fn conv_full<F: Float + FftNum, D: Dimension>(
+    in1: ArrayView<F, D>,
+    in2: ArrayView<F, D>,
+    axes: &[usize],
+    shape: &D,
+) -> Array<F, D> {
+    if axes.is_empty() {
+        // Without any convolved axes the convolution is a broadcast product.
+        let mut ret = Array::zeros(shape.clone());
+        Zip::from(&mut ret)
+            .and_broadcast(&in1)
+            .and_broadcast(&in2)
+            .for_each(|r, &a, &b| *r = a * b);
+        ret
+    } else {
+        freq_domain_conv(in1, in2, axes, shape)
+    }
+}
+
+/// Crop the full convolution `ret` of inputs of shapes `s1` and `s2` according to the mode, like
+/// scipy's `_apply_conv_mode`.
+fn apply_conv_mode<F: Clone, D: Dimension>(
+    ret: Array<F, D>,
+    s1: &[usize],
+    s2: &[usize],
+    mode: ConvolveMode,
+    axes: &[usize],
+) -> Array<F, D> {
+    let mut cropped = ret.raw_dim();
+    for d in 0..cropped.ndim() {
+        cropped[d] = match mode {
+            ConvolveMode::Full => ret.len_of(Axis(d)),
+            ConvolveMode::Same => s1[d],
+            ConvolveMode::Valid if axes.contains(&d) => s1[d] - s2[d] + 1,
+            ConvolveMode::Valid => ret.len_of(Axis(d)),
+        };
+    }
+    centered(ret.view(), cropped)
+}
+
+/// FFT length and the block lengths of both inputs for overlap-add convolution along an axis of
+/// lengths `s1` and `s2`, like scipy's `_calc_oa_lens`. The block lengths are the whole inputs if
+/// splitting does not pay off.
+fn oa_lens(s1: usize, s2: usize) -> (usize, usize, usize) {
+    let fallback = (next_fast_len(s1 + s2 - 1, Some(true)), s1, s2);
+    let (small, large) = (s1.min(s2), s1.max(s2));
+    if small == large || small == 1 || 2 * small >= large {
+        return fallback;
+    }
+
+    // The block size minimizing the cost per output sample of the FFTs.
+    let overlap = (small - 1) as f64;
+    let opt_size = -overlap * lambertw_m1(-1. / (2. * core::f64::consts::E * overlap));
+    let block_size = next_fast_len(opt_size.ceil() as usize, Some(true));
+    if block_size >= large {
+        return fallback;
+    }
+    let step = block_size - small + 1;
+    if s1 > s2 {
+        (block_size, step, s2)
+    } else {
+        (block_size, s1, step)
+    }
+}
+
+/// The lower branch ``W_{-1}`` of the Lambert W function for `x` in ``[-1/e, 0)``.
+fn lambertw_m1(x: f64) -> f64 {
+    let l1 = (-x).ln();
+    let l2 = (-l1).ln();
+    let mut w = l1 - l2 + l2 / l1;
+    for _ in 0..50 {
+        // Halley's iteration on w exp(w) - x.
+        let ew = w.exp();
+        let f = w * ew - x;
+        let step = f / (ew * (w + 1.) - (w + 2.) * f / (2. * w + 2.));
+        w -= step;
+        if step.abs() <= 1e-12 * w.abs() {
+            break;
+        }
+    }
+    w
+}
+
+/// Full convolution of `in1` and `in2` along `axes` of the given `shape`, by summing the
+/// convolutions of all pairs of blocks of the inputs. `lens` holds the FFT length and the block
+/// lengths of both inputs along each axis.
+fn overlap_add_conv<F: Float + FftNum, D: Dimension>(
+    in1: ArrayView<F, D>,
+    in2: ArrayView<F, D>,
+    axes: &[usize],
+    lens: &[(usize, usize, usize)],
+    shape: &D,
+) -> Array<F, D> {
+    let fshape: Vec<usize> = lens.iter().map(|l| l.0).collect();
+    let steps1: Vec<usize> = lens.iter().map(|l| l.1).collect();
+    let steps2: Vec<usize> = lens.iter().map(|l| l.2).collect();
+    let mut planner = FftPlanner::new();
+    let mut real_planner = RealFftPlanner::new();
+    let spectra = |x: &ArrayView<F, D>,
+                   steps: &[usize],
+                   planner: &mut FftPlanner<F>,
+                   real_planner: &mut RealFftPlanner<F>| {
+        block_starts(x.shape(), axes, steps)
+            .into_iter()
+            .map(|starts| {
+                let block =
+                    x.slice_each_axis(|ad| match axes.iter().position(|&d| d == ad.axis.index()) {
+                        Some(k) => Slice::from(starts[k]..(starts[k] + steps[k]).min(ad.len)),
+                        None => Slice::from(..),
+                    });
+                let sp = rfftn(block, axes, &fshape, planner, real_planner);
+                (starts, sp)
+            })
+            .collect::<Vec<_>>()
+    };
+    let spectra1 = spectra(&in1, &steps1, &mut planner, &mut real_planner);
+    let spectra2 = spectra(&in2, &steps2, &mut planner, &mut real_planner);
+
+    let mut ret = Array::zeros(shape.clone());
+    for (starts1, sp1) in &spectra1 {
+        for (starts2, sp2) in &spectra2 {
+            let mut sp_shape = sp1.raw_dim();
+            for d in 0..sp_shape.ndim() {
+                sp_shape[d] = sp1.len_of(Axis(d)).max(sp2.len_of(Axis(d)));
             }
+            let mut sp = Array::zeros(sp_shape);
+            Zip::from(&mut sp)
+                .and_broadcast(sp1)
+                .and_broadcast(sp2)
+                .for_each(|s, &a, &b| *s = a * b);
+            let block = irfftn(sp, axes, &fshape, &mut planner, &mut real_planner);
+
+            // Add the block convolution at the sum of the block offsets, cut at the output edge.
+            let ranges: Vec<(usize, usize)> = axes
+                .iter()
+                .enumerate()
+                .map(|(k, &d)| {
+                    let start = starts1[k] + starts2[k];
+                    (start, (start + fshape[k]).min(shape[d]))
+                })
+                .collect();
+            let position = |axis: Axis| axes.iter().position(|&d| d == axis.index());
+            let mut dst = ret.slice_each_axis_mut(|ad| match position(ad.axis) {
+                Some(k) => Slice::from(ranges[k].0..ranges[k].1),
+                None => Slice::from(..),
+            });
+            let src = block.slice_each_axis(|ad| match position(ad.axis) {
+                Some(k) => Slice::from(0..ranges[k].1 - ranges[k].0),
+                None => Slice::from(..),
+            });
+            Zip::from(&mut dst).and(&src).for_each(|d, &s| *d = *d + s);
         }
-        ConvolveMode::Same => {
-            let start = (n2 - 1) / 2;
-            let end = start + n1;
-            full_convolution[start..end].to_vec()
+    }
+    ret
+}
+
+/// Start indices along `axes` of all blocks of length `steps` covering an array of `shape`.
+fn block_starts(shape: &[usize], axes: &[usize], steps: &[usize]) -> Vec<Vec<usize>> {
+    let mut starts = vec![vec![]];
+    for (&d, &step) in axes.iter().zip(steps) {
+        starts = starts
+            .into_iter()
+            .flat_map(|s: Vec<usize>| {
+                (0..shape[d]).step_by(step).map(move |start| {
+                    let mut s = s.clone();
+                    s.push(start);
+                    s
+                })
+            })
+            .collect();
+    }
+    starts
+}
+
+/// Full convolution of `in1` and `in2` along the non-empty `axes`, cropped to `shape`.
+fn freq_domain_conv<F: Float + FftNum, D: Dimension>(
+    in1: ArrayView<F, D>,
+    in2: ArrayView<F, D>,
+    axes: &[usize],
+    shape: &D,
+) -> Array<F, D> {
+    let fshape: Vec<usize> = axes
+        .iter()
+        .map(|&d| next_fast_len(shape[d], Some(true)))
+        .collect();
+    let mut planner = FftPlanner::new();
+    let mut real_planner = RealFftPlanner::new();
+    let sp1 = rfftn(in1, axes, &fshape, &mut planner, &mut real_planner);
+    let sp2 = rfftn(in2, axes, &fshape, &mut planner, &mut real_planner);
+
+    let mut sp_shape = sp1.raw_dim();
+    for d in 0..sp_shape.ndim() {
+        sp_shape[d] = sp1.len_of(Axis(d)).max(sp2.len_of(Axis(d)));
+    }
+    let mut sp = Array::zeros(sp_shape);
+    Zip::from(&mut sp)
+        .and_broadcast(&sp1)
+        .and_broadcast(&sp2)
+        .for_each(|s, &a, &b| *s = a * b);
+    let ret = irfftn(sp, axes, &fshape, &mut planner, &mut real_planner);
+    ret.slice_each_axis(|ad| Slice::from(0..shape[ad.axis.index()]))
+        .to_owned()
+}
+
+/// Normalize `axes` and remove the axes that are broadcast rather than convolved, like scipy's
+/// `_init_freq_conv_axes`.
+fn init_freq_conv_axes(
+    s1: &[usize],
+    s2: &[usize],
+    mode: ConvolveMode,
+    axes: Option<&[isize]>,
+) -> Result<Vec<usize>> {
+    let ndim = s1.len();
+    let mut axes: Vec<usize> = match axes {
+        None => (0..ndim).collect(),
+        Some(axes) => axes
+            .iter()
+            .map(|&a| {
+                let d = if a < 0 { a + ndim as isize } else { a };
+                if d < 0 || d >= ndim as isize {
+                    return Err(Error::InvalidArg {
+                        arg: "axes".into(),
+                        reason: "axes exceeds dimensionality of input".into(),
+                    });
+                }
+                Ok(d as usize)
+            })
+            .collect::<Result<_>>()?,
+    };
+    axes.sort_unstable();
+    if axes.windows(2).any(|w| w[0] == w[1]) {
+        return Err(Error::InvalidArg {
+            arg: "axes".into(),
+            reason: "all axes must be unique".into(),
+        });
+    }
+
+    // Axes of length one in either input are handled by broadcasting.
+    axes.retain(|&d| s1[d] != 1 && s2[d] != 1);
+    if (0..ndim).any(|d| !axes.contains(&d) && s1[d] != s2[d] && s1[d] != 1 && s2[d] != 1) {
+        return Err(Error::InvalidArg {
+            arg: "in2".into(),
+            reason: "incompatible shapes for in1 and in2".into(),
+        });
+    }
+    Ok(axes)
+}
+
+/// True if `in2` is larger than `in1` along the convolved axes in 'valid' mode.
+fn inputs_swap_needed(s1: &[usize], s2: &[usize], axes: &[usize]) -> Result<bool> {
+    let ok1 = axes.iter().all(|&d| s1[d] >= s2[d]);
+    let ok2 = axes.iter().all(|&d| s2[d] >= s1[d]);
+    if !(ok1 || ok2) {
+        return Err(Error::ConflictArg {
+            reason: "For 'valid' mode, one must be at least as large as the other in every \
+                     dimension"
+                .into(),
+        });
+    }
+    Ok(!ok1)
+}
+
+/// Center crop `x` to `shape`.
+fn centered<F: Clone, D: Dimension>(x: ArrayView<F, D>, shape: D) -> Array<F, D> {
+    x.slice_each_axis(|ad| {
+        let (len, new) = (ad.len, shape[ad.axis.index()]);
+        let start = (len - new) / 2;
+        Slice::from(start..start + new)
+    })
+    .to_owned()
+}
+
+/// Real N-D FFT of `x` zero-padded to `fshape` along `axes`. The last axis in `axes` only keeps the
+/// non-negative frequencies.
+fn rfftn<F: Float + FftNum, D: Dimension>(
+    x: ArrayView<F, D>,
+    axes: &[usize],
+    fshape: &[usize],
+    planner: &mut FftPlanner<F>,
+    real_planner: &mut RealFftPlanner<F>,
+) -> Array<Complex<F>, D> {
+    let (&last, &n) = axes.last().zip(fshape.last()).unwrap();
+    let mut padded_dim = x.raw_dim();
+    for (&d, &n) in axes.iter().zip(fshape) {
+        padded_dim[d] = n;
+    }
+    let mut padded = Array::zeros(padded_dim.clone());
+    padded
+        .slice_each_axis_mut(|ad| Slice::from(0..x.len_of(ad.axis)))
+        .assign(&x);
+
+    let r2c = real_planner.plan_fft_forward(n);
+    let mut input = r2c.make_input_vec();
+    let mut output = r2c.make_output_vec();
+    let mut scratch = r2c.make_scratch_vec();
+    let mut sp_dim = padded_dim;
+    sp_dim[last] = n / 2 + 1;
+    let mut sp = Array::zeros(sp_dim);
+    for (lane, mut sp_lane) in padded
+        .lanes(Axis(last))
+        .into_iter()
+        .zip(sp.lanes_mut(Axis(last)))
+    {
+        input.iter_mut().zip(lane).for_each(|(i, &l)| *i = l);
+        r2c.process_with_scratch(&mut input, &mut output, &mut scratch)
+            .expect("buffers are sized by the plan");
+        sp_lane.assign(&ArrayView1::from(&output));
+    }
+
+    for &d in &axes[..axes.len() - 1] {
+        fft_axis(&mut sp, Axis(d), planner, false);
+    }
+    sp
+}
+
+/// Inverse of [rfftn], normalized and of length `fshape` along `axes`.
+fn irfftn<F: Float + FftNum, D: Dimension>(
+    mut sp: Array<Complex<F>, D>,
+    axes: &[usize],
+    fshape: &[usize],
+    planner: &mut FftPlanner<F>,
+    real_planner: &mut RealFftPlanner<F>,
+) -> Array<F, D> {
+    for &d in &axes[..axes.len() - 1] {
+        fft_axis(&mut sp, Axis(d), planner, true);
+    }
+
+    let (&last, &n) = axes.last().zip(fshape.last()).unwrap();
+    let c2r = real_planner.plan_fft_inverse(n);
+    let mut input = c2r.make_input_vec();
+    let mut output = c2r.make_output_vec();
+    let mut scratch = c2r.make_scratch_vec();
+    let mut ret_dim = sp.raw_dim();
+    ret_dim[last] = n;
+    let mut ret = Array::zeros(ret_dim);
+    let scale = F::from(fshape.iter().product::<usize>()).unwrap();
+    for (sp_lane, mut lane) in sp
+        .lanes(Axis(last))
+        .into_iter()
+        .zip(ret.lanes_mut(Axis(last)))
+    {
+        input.iter_mut().zip(sp_lane).for_each(|(i, &s)| *i = s);
+        // The spectrum of a real signal is real at DC and Nyquist, drop the rounding errors there.
+        input[0].im = F::zero();
+        if n.is_multiple_of(2) {
+            input[n / 2].im = F::zero();
         }
+        c2r.process_with_scratch(&mut input, &mut output, &mut scratch)
+            .expect("buffers are sized by the plan");
+        lane.iter_mut()
+            .zip(&output)
+            .for_each(|(l, &o)| *l = o / scale);
+    }
+    ret
+}
+
+/// Unnormalized forward or inverse complex FFT of `x` along `axis`.
+fn fft_axis<F: FftNum, D: Dimension>(
+    x: &mut Array<Complex<F>, D>,
+    axis: Axis,
+    planner: &mut FftPlanner<F>,
+    inverse: bool,
+) {
+    let len = x.len_of(axis);
+    let fft = if inverse {
+        planner.plan_fft_inverse(len)
+    } else {
+        planner.plan_fft_forward(len)
+    };
+    let mut buffer = vec![Complex::zero(); len];
+    let mut scratch = vec![Complex::zero(); fft.get_inplace_scratch_len()];
+    for mut lane in x.lanes_mut(axis) {
+        buffer
+            .iter_mut()
+            .zip(lane.iter())
+            .for_each(|(b, &l)| *b = l);
+        fft.process_with_scratch(&mut buffer, &mut scratch);
+        lane.assign(&ArrayView1::from(&buffer));
     }
 }
 
@@ -90,26 +625,401 @@ pub fn fftconvolve<F: Float + FftNum>(in1: &[F], in2: &[F], mode: ConvolveMode)
 /// A Vec containing the convolution of `in1` with `in2`.
 /// With Full mode, the output length will be `in1.len() + in2.len() - 1`.
 pub fn convolve<F: Float + FftNum>(in1: &[F], in2: &[F], mode: ConvolveMode) -> Vec<F> {
-    fftconvolve(in1, in2, mode)
+    fftconvolve1(in1, in2, mode)
+}
+
+/// [fftconvolve] of two slices.
+fn fftconvolve1<F: Float + FftNum>(in1: &[F], in2: &[F], mode: ConvolveMode) -> Vec<F> {
+    fftconvolve(&ArrayView1::from(in1), &ArrayView1::from(in2), mode, None)
+        .expect("1-D inputs can always be convolved")
+        .into_raw_vec_and_offset()
+        .0
 }
 
-/// Compute the cross-correlation of two signals using FFT.
+/// Algorithm used by [correlate].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConvolveMethod {
+    /// Chooses between [ConvolveMethod::Direct] and [ConvolveMethod::Fft] based on an estimate of
+    /// which is faster.
+    #[default]
+    Auto,
+    /// The correlation is determined directly from sums, the definition of correlation.
+    Direct,
+    /// The Fast Fourier Transform is used to perform the correlation by calling [fftconvolve].
+    Fft,
+}
+
+/// Cross-correlate two signals.
+///
+/// Cross-correlate `in1` and `in2`, with the output size determined by the `mode` argument.
+/// The correlation `z` of the two signals is defined as
 ///
-/// Cross-correlation is similar to convolution but with flipping one of the signals.
-/// This function uses FFT to compute the correlation efficiently.
+/// ``z[k] = sum_l in1[l + k] * in2[l]``
+///
+/// for the lags `k` returned by [correlation_lags].
 ///
 /// # Arguments
 /// * `in1` - First input array
 /// * `in2` - Second input array
+/// * `mode` - [ConvolveMode::Full] returns every lag at which the signals overlap.
+///   [ConvolveMode::Valid] returns only the lags at which one signal completely overlaps the
+///   other. [ConvolveMode::Same] returns `in1.len()` lags centered with respect to the full
+///   output.
+/// * `method` - Whether to compute the correlation with sums or FFTs. Defaults to
+///   [ConvolveMethod::Auto].
 ///
 /// # Returns
 /// A Vec containing the cross-correlation of `in1` with `in2`.
 /// With Full mode, the output length will be `in1.len() + in2.len() - 1`.
-pub fn correlate<F: Float + FftNum>(in1: &[F], in2: &[F], mode: ConvolveMode) -> Vec<F> {
-    // For correlation, we need to reverse in2
-    let mut in2_rev = in2.to_vec();
-    in2_rev.reverse();
-    fftconvolve(in1, &in2_rev, mode)
+///
+/// # Examples
+/// Use cross-correlation to find the delay of a shifted signal:
+/// ```
+/// use sci_rs::signal::convolve::{correlate, correlation_lags, ConvolveMode};
+///
+/// let sig = [0., 1., 3., 2., 0., -1., 0., 0.];
+/// let delayed = [0., 0., 0., 1., 3., 2., 0., -1.];
+/// let corr = correlate(&delayed, &sig, ConvolveMode::Full, None);
+/// let lags = correlation_lags(delayed.len(), sig.len(), ConvolveMode::Full);
+/// let peak = corr
+///     .iter()
+///     .enumerate()
+///     .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+///     .unwrap()
+///     .0;
+/// assert_eq!(lags[peak], 2);
+/// ```
+pub fn correlate<F: Float + FftNum>(
+    in1: &[F],
+    in2: &[F],
+    mode: ConvolveMode,
+    method: Option<ConvolveMethod>,
+) -> Vec<F> {
+    let (n1, n2) = (in1.len(), in2.len());
+    let lags = correlation_lags(n1, n2, mode);
+    let method = match method.unwrap_or_default() {
+        ConvolveMethod::Auto => {
+            // Compare the number of multiplications for the direct sums with a rough FFT cost.
+            let n = (n1 + n2 - 1) as f64;
+            if (n1.min(n2) * lags.len()) as f64 <= 3. * n * n.log2() {
+                ConvolveMethod::Direct
+            } else {
+                ConvolveMethod::Fft
+            }
+        }
+        method => method,
+    };
+    match method {
+        ConvolveMethod::Direct => lags
+            .into_iter()
+            .map(|k| {
+                // Overlapping indices l of in2 satisfy 0 <= l + k < n1.
+                let lo = (-k).max(0) as usize;
+                let hi = (n1 as isize - k).min(n2 as isize).max(0) as usize;
+                (lo..hi).fold(F::zero(), |acc, l| {
+                    acc + in1[(l as isize + k) as usize] * in2[l]
+                })
+            })
+            .collect(),
+        _ => {
+            // For correlation, we need to reverse in2
+            let mut in2_rev = in2.to_vec();
+            in2_rev.reverse();
+            let full = fftconvolve1(in1, &in2_rev, ConvolveMode::Full);
+            lags.into_iter()
+                .map(|k| full[(k + n2 as isize - 1) as usize])
+                .collect()
+        }
+    }
+}
+
+/// Calculates the lag / displacement indices array for 1D cross-correlation.
+///
+/// # Arguments
+/// * `in1_len` - First input size.
+/// * `in2_len` - Second input size.
+/// * `mode` - Indicates the size of the output, as in [correlate].
+///
+/// # Returns
+/// Returns a Vec containing cross-correlation lag/displacement indices, where the `i`-th lag
+/// corresponds to the `i`-th sample returned by [correlate] with the same `mode`.
+///
+/// # Notes
+/// Cross-correlation for continuous functions `f` and `g` is defined as:
+///
+/// ``(f * g)(τ) = ∫ f(t) g(t - τ) dt``
+///
+/// Where `τ` is defined as the displacement, also known as the lag. Cross correlation for
+/// discrete functions `f` and `g` is defined as:
+///
+/// ``(f * g)[n] = Σ_m f[m] g[m - n]``
+///
+/// Where `n` is the lag.
+///
+/// # Examples
+/// ```custom,{class=language-python}
+/// >>> from scipy import signal
+/// >>> signal.correlation_lags(5, 3, mode='valid')
+/// array([0, 1, 2])
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::signal::convolve::{correlation_lags, ConvolveMode};
+///
+/// assert_eq!(correlation_lags(5, 3, ConvolveMode::Valid), vec![0, 1, 2]);
+/// assert_eq!(correlation_lags(3, 5, ConvolveMode::Valid), vec![-2, -1, 0]);
+/// assert_eq!(correlation_lags(3, 2, ConvolveMode::Full), vec![-1, 0, 1, 2]);
+/// ```
+pub fn correlation_lags(in1_len: usize, in2_len: usize, mode: ConvolveMode) -> Vec<isize> {
+    let (n1, n2) = (in1_len as isize, in2_len as isize);
+    match mode {
+        ConvolveMode::Full => (-n2 + 1..n1).collect(),
+        // The output is the same size as `in1`, centered with respect to the 'full' output.
+        ConvolveMode::Same => (-(n2 / 2)..n1 - n2 / 2).collect(),
+        ConvolveMode::Valid => {
+            let lag_bound = n1 - n2;
+            if lag_bound >= 0 {
+                (0..lag_bound + 1).collect()
+            } else {
+                (lag_bound..1).collect()
+            }
+        }
+    }
+}
+
+/// How [convolve2d] and [correlate2d] handle the boundaries of `in1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConvolveBoundary {
+    /// Pad input arrays with `fillvalue`.
+    #[default]
+    Fill,
+    /// Circular boundary conditions.
+    Wrap,
+    /// Symmetrical boundary conditions.
+    Symm,
+}
+
+/// Convolve two 2-dimensional arrays.
+///
+/// Convolve `in1` and `in2` with output size determined by `mode`, and
+/// boundary conditions determined by `boundary` and `fillvalue`.
+///
+/// ## Parameters
+/// * `in1`: array_like
+///   First input.
+/// * `in2`: array_like
+///   Second input. Should have the same number of dimensions as `in1`.
+/// * `mode`: [ConvolveMode]
+///   A string indicating the size of the output:
+///
+///   [ConvolveMode::Full]:
+///   The output is the full discrete linear convolution of the inputs.
+///
+///   [ConvolveMode::Valid]:
+///   The output consists only of those elements that do not rely on the zero-padding. In 'valid'
+///   mode, either `in1` or `in2` must be at least as large as the other in every dimension.
+///
+///   [ConvolveMode::Same]:
+///   The output is the same size as `in1`, centered with respect to the 'full' output.
+/// * `boundary`: [ConvolveBoundary], optional
+///   A flag indicating how to handle boundaries. Default is [ConvolveBoundary::Fill].
+/// * `fillvalue`: scalar, optional
+///   Value to fill pad input arrays with. Default is 0.
+///
+/// ## Returns
+/// * `out`: ndarray
+///   A 2-dimensional array containing a subset of the discrete linear convolution of `in1` with
+///   `in2`.
+///
+/// ## Errors
+/// [Error::ConflictArg] if neither input is at least as large as the other in every dimension in
+/// [ConvolveMode::Valid].
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy import signal
+/// >>> a = np.array([[1., -2., 3., 0.5], [4., 0., -1., 2.], [2.5, 1., -3., 1.5]])
+/// >>> b = np.array([[2., 1., 0.], [-1., 0.5, 3.]])
+/// >>> signal.convolve2d(a, b, boundary='symm', mode='same')
+/// array([[ 2.5,  3. , -1. , 10.25],
+///        [ 9.5, -3. , -2. , 14.75],
+///        [18.5,  8. , -2.5,  0.5 ]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::signal::convolve::{convolve2d, ConvolveBoundary, ConvolveMode};
+///
+/// let a = array![[1., -2., 3., 0.5], [4., 0., -1., 2.], [2.5, 1., -3., 1.5]];
+/// let b = array![[2., 1., 0.], [-1., 0.5, 3.]];
+/// let out = convolve2d(&a, &b, ConvolveMode::Same, Some(ConvolveBoundary::Symm), None).unwrap();
+/// assert_eq!(
+///     out,
+///     array![[2.5, 3., -1., 10.25], [9.5, -3., -2., 14.75], [18.5, 8., -2.5, 0.5]]
+/// );
+/// ```
+pub fn convolve2d<F, S1, S2>(
+    in1: &ArrayBase<S1, Ix2>,
+    in2: &ArrayBase<S2, Ix2>,
+    mode: ConvolveMode,
+    boundary: Option<ConvolveBoundary>,
+    fillvalue: Option<F>,
+) -> Result<Array2<F>>
+where
+    F: Float,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = F>,
+{
+    let (mut in1, mut in2) = (in1.view(), in2.view());
+    if mode == ConvolveMode::Valid && inputs_swap_needed(in1.shape(), in2.shape(), &[0, 1])? {
+        core::mem::swap(&mut in1, &mut in2);
+    }
+    Ok(convolve2d_impl(
+        in1,
+        in2,
+        true,
+        mode,
+        boundary.unwrap_or_default(),
+        fillvalue.unwrap_or(F::zero()),
+    ))
+}
+
+/// Cross-correlate two 2-dimensional arrays.
+///
+/// Cross correlate `in1` and `in2` with output size determined by `mode`, and
+/// boundary conditions determined by `boundary` and `fillvalue`.
+///
+/// ## Parameters
+/// * `in1`: array_like
+///   First input.
+/// * `in2`: array_like
+///   Second input. Should have the same number of dimensions as `in1`.
+/// * `mode`: [ConvolveMode]
+///   A string indicating the size of the output, as in [convolve2d].
+/// * `boundary`: [ConvolveBoundary], optional
+///   A flag indicating how to handle boundaries. Default is [ConvolveBoundary::Fill].
+/// * `fillvalue`: scalar, optional
+///   Value to fill pad input arrays with. Default is 0.
+///
+/// ## Returns
+/// * `correlate2d`: ndarray
+///   A 2-dimensional array containing a subset of the discrete linear cross-correlation of `in1`
+///   with `in2`.
+///
+/// ## Errors
+/// [Error::ConflictArg] if neither input is at least as large as the other in every dimension in
+/// [ConvolveMode::Valid].
+///
+/// ## Examples
+/// Use 2D cross-correlation to find the location of a template in an image:
+/// ```
+/// use ndarray::{array, Array2};
+/// use sci_rs::signal::convolve::{correlate2d, ConvolveMode};
+///
+/// let mut image = Array2::<f64>::zeros((6, 7));
+/// let template = array![[1., -1.], [-1., 2.]];
+/// image.slice_mut(ndarray::s![3..5, 1..3]).assign(&template);
+/// let corr = correlate2d(&image, &template, ConvolveMode::Valid, None, None).unwrap();
+/// let (peak, _) = corr
+///     .indexed_iter()
+///     .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+///     .unwrap();
+/// assert_eq!(peak, (3, 1));
+/// ```
+pub fn correlate2d<F, S1, S2>(
+    in1: &ArrayBase<S1, Ix2>,
+    in2: &ArrayBase<S2, Ix2>,
+    mode: ConvolveMode,
+    boundary: Option<ConvolveBoundary>,
+    fillvalue: Option<F>,
+) -> Result<Array2<F>>
+where
+    F: Float,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = F>,
+{
+    let (mut in1, mut in2) = (in1.view(), in2.view());
+    let swapped_inputs =
+        mode == ConvolveMode::Valid && inputs_swap_needed(in1.shape(), in2.shape(), &[0, 1])?;
+    if swapped_inputs {
+        core::mem::swap(&mut in1, &mut in2);
+    }
+    let out = convolve2d_impl(
+        in1,
+        in2,
+        false,
+        mode,
+        boundary.unwrap_or_default(),
+        fillvalue.unwrap_or(F::zero()),
+    );
+    if swapped_inputs {
+        return Ok(out.slice(ndarray::s![..;-1, ..;-1]).to_owned());
+    }
+    Ok(out)
+}
+
+/// Direct 2-D convolution (`convolve == true`) or correlation of `in1` with `in2`, following
+/// scipy's `_convolve2d`.
+fn convolve2d_impl<F: Float>(
+    in1: ArrayView2<F>,
+    in2: ArrayView2<F>,
+    convolve: bool,
+    mode: ConvolveMode,
+    boundary: ConvolveBoundary,
+    fillvalue: F,
+) -> Array2<F> {
+    let (ns, nwin) = (in1.dim(), in2.dim());
+    let os = match mode {
+        ConvolveMode::Full => (ns.0 + nwin.0 - 1, ns.1 + nwin.1 - 1),
+        ConvolveMode::Same => ns,
+        ConvolveMode::Valid => (ns.0 + 1 - nwin.0, ns.1 + 1 - nwin.1),
+    };
+    // Position in in1 of the first window sample for output index m.
+    let start = |m: usize, nwin: usize| -> isize {
+        let (m, nwin) = (m as isize, nwin as isize);
+        match (mode, convolve) {
+            (ConvolveMode::Full, true) => m,
+            (ConvolveMode::Full, false) => m - nwin + 1,
+            (ConvolveMode::Same, true) => m + (nwin - 1) / 2,
+            (ConvolveMode::Same, false) => m - (nwin - 1) / 2,
+            (ConvolveMode::Valid, true) => m + nwin - 1,
+            (ConvolveMode::Valid, false) => m,
+        }
+    };
+    // Index into in1 after applying the boundary conditions, None for fill values.
+    let index = |i: isize, n: usize| -> Option<usize> {
+        let n = n as isize;
+        match boundary {
+            _ if (0..n).contains(&i) => Some(i as usize),
+            ConvolveBoundary::Fill => None,
+            ConvolveBoundary::Wrap => Some(i.rem_euclid(n) as usize),
+            ConvolveBoundary::Symm => {
+                let i = i.rem_euclid(2 * n);
+                Some(if i < n { i } else { 2 * n - 1 - i } as usize)
+            }
+        }
+    };
+    let step = |start: isize, j: usize| -> isize {
+        if convolve {
+            start - j as isize
+        } else {
+            start + j as isize
+        }
+    };
+
+    Array2::from_shape_fn(os, |(m, n)| {
+        let (new_m, new_n) = (start(m, nwin.0), start(n, nwin.1));
+        let mut sum = F::zero();
+        for ((j, k), &w) in in2.indexed_iter() {
+            let x = match (index(step(new_m, j), ns.0), index(step(new_n, k), ns.1)) {
+                (Some(i0), Some(i1)) => in1[[i0, i1]],
+                _ => fillvalue,
+            };
+            sum = sum + x * w;
+        }
+        sum
+    })
 }
 
 #[cfg(test)]
@@ -133,7 +1043,7 @@ mod tests {
     fn test_correlate() {
         let in1 = vec![1.0, 2.0, 3.0];
         let in2 = vec![4.0, 5.0, 6.0];
-        let result = correlate(&in1, &in2, ConvolveMode::Full);
+        let result = correlate(&in1, &in2, ConvolveMode::Full, None);
         let expected = vec![6.0, 17.0, 32.0, 23.0, 12.0];
         for (a, b) in result.iter().zip(expected.iter()) {
             assert_relative_eq!(a, b, epsilon = 1e-10);
@@ -173,7 +1083,7 @@ mod tests {
         let sig: Vec<f64> = StandardUniform.sample_iter(&mut rng).take(1000).collect();
 
         // Compute autocorrelation using correlate directly
-        let autocorr = correlate(&sig, &sig, ConvolveMode::Full);
+        let autocorr = correlate(&sig, &sig, ConvolveMode::Full, None);
 
         // Basic sanity checks
         assert_eq!(autocorr.len(), 1999); // Full convolution length should be 2N-1
@@ -192,4 +1102,332 @@ mod tests {
         let autocorr: Vec<f32> = autocorr.iter().map(|x| *x as f32).collect();
         crate::plot::python_plot(vec![&sig, &autocorr]);
     }
+
+    #[test]
+    fn test_correlate_modes() {
+        let in1 = [1.0, -2.0, 3.0, 0.5, 4.0];
+        let in2 = [2.0, 1.0, -1.0];
+        let cases = [
+            (
+                ConvolveMode::Full,
+                vec![-1.0, 3.0, -3.0, -1.5, 2.5, 5.0, 8.0],
+            ),
+            (ConvolveMode::Same, vec![3.0, -3.0, -1.5, 2.5, 5.0]),
+            (ConvolveMode::Valid, vec![-3.0, -1.5, 2.5]),
+        ];
+        for (mode, expected) in cases {
+            for method in [ConvolveMethod::Direct, ConvolveMethod::Fft] {
+                let result = correlate(&in1, &in2, mode, Some(method));
+                assert_eq!(result.len(), expected.len());
+                assert_eq!(correlation_lags(5, 3, mode).len(), expected.len());
+                for (a, b) in result.iter().zip(expected.iter()) {
+                    assert_relative_eq!(a, b, epsilon = 1e-10);
+                }
+            }
+        }
+
+        // in2 longer than in1 flips the valid lags to be non-positive.
+        let expected = [2.5, -1.5, -3.0];
+        assert_eq!(correlation_lags(3, 5, ConvolveMode::Valid), vec![-2, -1, 0]);
+        for method in [ConvolveMethod::Direct, ConvolveMethod::Fft] {
+            let result = correlate(&in2, &in1, ConvolveMode::Valid, Some(method));
+            for (a, b) in result.iter().zip(expected.iter()) {
+                assert_relative_eq!(a, b, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_correlation_lags() {
+        assert_eq!(
+            correlation_lags(5, 3, ConvolveMode::Full),
+            vec![-2, -1, 0, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            correlation_lags(5, 3, ConvolveMode::Same),
+            vec![-1, 0, 1, 2, 3]
+        );
+        assert_eq!(
+            correlation_lags(4, 4, ConvolveMode::Same),
+            vec![-2, -1, 0, 1]
+        );
+        assert_eq!(correlation_lags(5, 5, ConvolveMode::Valid), vec![0]);
+    }
+
+    #[track_caller]
+    fn assert_array_eq<D: Dimension>(a: Array<f64, D>, b: Array<f64, D>) {
+        assert_eq!(a.shape(), b.shape());
+        for (a, b) in a.iter().zip(b.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_fftconvolve_2d() {
+        let a = ndarray::arr2(&[[1., -2., 3., 0.5], [4., 0., -1., 2.], [2.5, 1., -3., 1.5]]);
+        let b = ndarray::arr2(&[[2., 1.], [-1., 0.5]]);
+        let full = ndarray::arr2(&[
+            [2.0, -3.0, 4.0, 4.0, 0.5],
+            [7.0, 6.5, -6.0, 4.0, 2.25],
+            [1.0, 6.5, -4.0, -2.5, 2.5],
+            [-2.5, 0.25, 3.5, -3.0, 0.75],
+        ]);
+        assert_array_eq(
+            fftconvolve(&a, &b, ConvolveMode::Full, None).unwrap(),
+            full.clone(),
+        );
+        assert_array_eq(
+            fftconvolve(&a, &b, ConvolveMode::Same, None).unwrap(),
+            full.slice(ndarray::s![..3, ..4]).to_owned(),
+        );
+        let valid = full.slice(ndarray::s![1..3, 1..4]).to_owned();
+        assert_array_eq(
+            fftconvolve(&a, &b, ConvolveMode::Valid, None).unwrap(),
+            valid.clone(),
+        );
+        // Convolution commutes, so 'valid' swaps the inputs when in2 is larger.
+        assert_array_eq(
+            fftconvolve(&b, &a, ConvolveMode::Valid, None).unwrap(),
+            valid,
+        );
+    }
+
+    #[test]
+    fn test_fftconvolve_axes() {
+        let a = ndarray::arr2(&[[1., 2., 3., 4.], [4., 0., -1., 2.]]);
+        let b = ndarray::arr2(&[[1., -1.], [0.5, 2.]]);
+        let rows = fftconvolve(&a, &b, ConvolveMode::Full, Some(&[-1])).unwrap();
+        for i in 0..2 {
+            let row = convolve(
+                a.row(i).as_slice().unwrap(),
+                b.row(i).as_slice().unwrap(),
+                ConvolveMode::Full,
+            );
+            assert_array_eq(rows.row(i).to_owned(), ndarray::Array1::from(row));
+        }
+
+        // A single kernel is broadcast across the channels.
+        let kernel = ndarray::arr2(&[[1., 2., 1.]]);
+        let smoothed = fftconvolve(&a, &kernel, ConvolveMode::Same, Some(&[1])).unwrap();
+        assert_array_eq(
+            smoothed,
+            ndarray::arr2(&[[4., 8., 12., 11.], [8., 3., 0., 3.]]),
+        );
+
+        // Length one axes are not convolved, so scaling each channel is a broadcast product.
+        let scaled = fftconvolve(
+            &a,
+            &ndarray::arr2(&[[2.], [-1.]]),
+            ConvolveMode::Full,
+            Some(&[1]),
+        );
+        assert_array_eq(
+            scaled.unwrap(),
+            ndarray::arr2(&[[2., 4., 6., 8.], [-4., 0., 1., -2.]]),
+        );
+    }
+
+    #[test]
+    fn test_fftconvolve_errors() {
+        let a = ndarray::Array2::<f64>::ones((3, 2));
+        let b = ndarray::Array2::<f64>::ones((2, 3));
+        assert!(fftconvolve(&a, &b, ConvolveMode::Valid, None).is_err());
+        assert!(fftconvolve(&a, &b, ConvolveMode::Full, Some(&[0])).is_err());
+        assert!(fftconvolve(&a, &b, ConvolveMode::Full, Some(&[2])).is_err());
+        assert!(fftconvolve(&a, &b, ConvolveMode::Full, Some(&[0, -2])).is_err());
+        let empty = ndarray::Array2::<f64>::zeros((0, 3));
+        assert!(fftconvolve(&a, &empty, ConvolveMode::Full, None)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_oaconvolve() {
+        // Split into blocks along the long axis of either input.
+        let long = ndarray::Array1::from_shape_fn(300, |i| ((i * 7) % 13) as f64 - 6.);
+        let short = ndarray::Array1::from_shape_fn(11, |i| 1. / (i + 1) as f64);
+        for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+            for (a, b) in [(&long, &short), (&short, &long)] {
+                assert_array_eq(
+                    oaconvolve(a, b, mode, None).unwrap(),
+                    fftconvolve(a, b, mode, None).unwrap(),
+                );
+            }
+        }
+
+        // The larger input differs between the axes.
+        let a = ndarray::Array2::from_shape_fn((50, 4), |(i, j)| (i as f64).sin() + j as f64);
+        let b = ndarray::Array2::from_shape_fn((3, 40), |(i, j)| (j as f64).cos() - i as f64);
+        for mode in [ConvolveMode::Full, ConvolveMode::Same] {
+            assert_array_eq(
+                oaconvolve(&a, &b, mode, None).unwrap(),
+                fftconvolve(&a, &b, mode, None).unwrap(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_oaconvolve_axes() {
+        let channels = ndarray::Array2::from_shape_fn((3, 200), |(c, t)| ((c + 1) * t % 17) as f64);
+        let kernel = ndarray::arr2(&[[1., -2., 0.5, 3., 1.]]);
+        for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+            let out = oaconvolve(&channels, &kernel, mode, Some(&[-1])).unwrap();
+            for (c, row) in out.rows().into_iter().enumerate() {
+                let expected = convolve(
+                    channels.row(c).as_slice().unwrap(),
+                    kernel.row(0).as_slice().unwrap(),
+                    mode,
+                );
+                assert_array_eq(row.to_owned(), ndarray::Array1::from(expected));
+            }
+        }
+        // One kernel per channel.
+        let kernels = ndarray::Array2::from_shape_fn((3, 4), |(c, i)| (c + i) as f64);
+        assert_array_eq(
+            oaconvolve(&channels, &kernels, ConvolveMode::Full, Some(&[1])).unwrap(),
+            fftconvolve(&channels, &kernels, ConvolveMode::Full, Some(&[1])).unwrap(),
+        );
+        assert!(oaconvolve(&channels, &kernels, ConvolveMode::Full, Some(&[0])).is_err());
+    }
+
+    #[test]
+    fn test_oa_lens() {
+        // W_{-1}(-1 / (2e)) = -2.678...
+        assert_relative_eq!(
+            lambertw_m1(-1. / (2. * core::f64::consts::E)),
+            -2.678346990016661,
+            epsilon = 1e-10
+        );
+        assert_eq!(oa_lens(100, 100), (200, 100, 100));
+        assert_eq!(oa_lens(100, 60), (160, 100, 60));
+        let (block, step1, step2) = oa_lens(1000, 11);
+        assert!(block < 1000 && step2 == 11 && step1 + step2 - 1 == block);
+        let (block, step1, step2) = oa_lens(11, 1000);
+        assert!(block < 1000 && step1 == 11 && step1 + step2 - 1 == block);
+    }
+
+    #[test]
+    fn test_convolve2d_boundaries() {
+        let a = ndarray::arr2(&[[1., -2., 3., 0.5], [4., 0., -1., 2.], [2.5, 1., -3., 1.5]]);
+        let b = ndarray::arr2(&[[2., 1., 0.], [-1., 0.5, 3.]]);
+        let cases = [
+            (
+                ConvolveMode::Full,
+                ConvolveBoundary::Fill,
+                ndarray::arr2(&[
+                    [3.75, -1.75, 5.25, 5.25, 2.75, 2.75],
+                    [9.25, 8.0, -3.0, -2.0, 11.75, 2.75],
+                    [3.25, 8.0, 8.0, -2.5, 0.0, 7.25],
+                    [0.75, 3.25, 12.5, 1.5, -7.25, 5.75],
+                ]),
+            ),
+            (
+                ConvolveMode::Full,
+                ConvolveBoundary::Wrap,
+                ndarray::arr2(&[
+                    [-8.25, 1.75, 15.0, 4.0, -8.25, 1.75],
+                    [18.25, 8.0, -3.0, -2.0, 18.25, 8.0],
+                    [0.5, 12.5, 8.0, -2.5, 0.5, 12.5],
+                    [-8.25, 1.75, 15.0, 4.0, -8.25, 1.75],
+                ]),
+            ),
+            (
+                ConvolveMode::Full,
+                ConvolveBoundary::Symm,
+                ndarray::arr2(&[
+                    [-3.5, 2.5, 3.0, -1.0, 10.25, 5.25],
+                    [5.5, 9.5, -3.0, -2.0, 14.75, -1.25],
+                    [5.5, 18.5, 8.0, -2.5, 0.5, 3.5],
+                    [9.25, 12.25, 6.0, 0.0, -5.25, 3.75],
+                ]),
+            ),
+            (
+                ConvolveMode::Same,
+                ConvolveBoundary::Fill,
+                ndarray::arr2(&[
+                    [-1.75, 5.25, 5.25, 2.75],
+                    [8.0, -3.0, -2.0, 11.75],
+                    [8.0, 8.0, -2.5, 0.0],
+                ]),
+            ),
+            (
+                ConvolveMode::Same,
+                ConvolveBoundary::Wrap,
+                ndarray::arr2(&[
+                    [1.75, 15.0, 4.0, -8.25],
+                    [8.0, -3.0, -2.0, 18.25],
+                    [12.5, 8.0, -2.5, 0.5],
+                ]),
+            ),
+            (
+                ConvolveMode::Valid,
+                ConvolveBoundary::Fill,
+                ndarray::arr2(&[[-3.0, -2.0], [8.0, -2.5]]),
+            ),
+        ];
+        for (mode, boundary, expected) in cases {
+            let out = convolve2d(&a, &b, mode, Some(boundary), Some(0.5)).unwrap();
+            assert_array_eq(out, expected);
+        }
+        // The larger input is used as in1 in 'valid' mode.
+        let out = convolve2d(&b, &a, ConvolveMode::Valid, None, None).unwrap();
+        assert_array_eq(out, ndarray::arr2(&[[-3.0, -2.0], [8.0, -2.5]]));
+        // Without padding, the 2-D convolution agrees with the FFT convolution.
+        assert_array_eq(
+            convolve2d(&a, &b, ConvolveMode::Full, None, None).unwrap(),
+            fftconvolve(&a, &b, ConvolveMode::Full, None).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_correlate2d_boundaries() {
+        let a = ndarray::arr2(&[[1., -2., 3., 0.5], [4., 0., -1., 2.], [2.5, 1., -3., 1.5]]);
+        let b = ndarray::arr2(&[[2., 1., 0.], [-1., 0.5, 3.]]);
+        let cases = [
+            (
+                ConvolveMode::Full,
+                ConvolveBoundary::Fill,
+                ndarray::arr2(&[
+                    [4.25, -4.5, 8.5, 6.5, 0.25, 2.75],
+                    [13.25, 3.5, -7.0, 4.5, 10.0, 1.25],
+                    [8.75, 8.75, -3.0, 1.0, 5.25, 4.75],
+                    [2.75, 4.75, 7.25, 0.25, -3.25, 4.75],
+                ]),
+            ),
+            (
+                ConvolveMode::Same,
+                ConvolveBoundary::Wrap,
+                ndarray::arr2(&[
+                    [2.0, -7.0, 4.5, 20.5],
+                    [10.75, -3.0, 1.0, 11.25],
+                    [-0.5, 13.0, 4.0, -4.25],
+                ]),
+            ),
+            (
+                ConvolveMode::Same,
+                ConvolveBoundary::Symm,
+                ndarray::arr2(&[
+                    [1.0, -7.0, 4.5, 14.5],
+                    [13.75, -3.0, 1.0, 8.25],
+                    [9.25, -5.0, 1.0, 3.75],
+                ]),
+            ),
+            (
+                ConvolveMode::Valid,
+                ConvolveBoundary::Symm,
+                ndarray::arr2(&[[-7.0, 4.5], [-3.0, 1.0]]),
+            ),
+        ];
+        for (mode, boundary, expected) in cases {
+            let out = correlate2d(&a, &b, mode, Some(boundary), Some(0.5)).unwrap();
+            assert_array_eq(out, expected);
+        }
+        // Swapped inputs in 'valid' mode reverse the output.
+        let out = correlate2d(&b, &a, ConvolveMode::Valid, None, None).unwrap();
+        assert_array_eq(out, ndarray::arr2(&[[1.0, -3.0], [4.5, -7.0]]));
+
+        let c = ndarray::Array2::<f64>::ones((2, 5));
+        assert!(correlate2d(&a, &c, ConvolveMode::Valid, None, None).is_err());
+        assert!(convolve2d(&a, &c, ConvolveMode::Valid, None, None).is_err());
+    }
 }
@@ -0,0 +1,780 @@
+use crate::fft::next_fast_len;
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use core::f64::consts::PI;
+use nalgebra::Complex;
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension};
+use num_traits::{Float, Zero};
+use rustfft::{Fft, FftNum, FftPlanner};
+use sci_rs_core::{Error, Result};
+use std::sync::Arc;
+
+/// Create a callable chirp z-transform function.
+///
+/// Transform to compute the frequency response around a spiral.
+/// Objects of this class are callables which can compute the
+/// chirp z-transform on their inputs with [Czt::transform]. This object precalculates the
+/// constant chirps used in the given transform.
+///
+/// ## Notes
+/// The defaults are chosen such that ``czt.transform(x)`` is equivalent to
+/// ``fft.fft(x)`` and, if ``m > len(x)``, that ``czt.transform(x)`` is equivalent to
+/// ``fft.fft(x, m)``.
+///
+/// If `w` does not lie on the unit circle, then the transform will be
+/// around a spiral with exponentially-increasing radius. Regardless,
+/// angle will increase linearly.
+///
+/// For transforms that do lie on the unit circle, accuracy is better when
+/// using [ZoomFft], since any numerical error in `w` is
+/// accumulated for long data lengths, drifting away from the unit circle.
+///
+/// The chirp z-transform can be faster than an equivalent FFT with
+/// zero padding. Try it with your own array sizes to see.
+///
+/// However, the chirp z-transform is considerably less precise than the
+/// equivalent zero-padded FFT.
+///
+/// As this CZT is implemented using the Bluestein algorithm, it can compute
+/// large prime-length Fourier transforms in O(N log N) time, rather than the
+/// O(N**2) time required by the direct DFT calculation.
+///
+/// ## References
+/// \[1\] Leo I. Bluestein, "A linear filtering approach to the computation
+///     of the discrete Fourier transform," Northeast Electronics Research
+///     and Engineering Meeting Record 10, 218-219 (1968).
+///
+/// \[2\] Rabiner, Schafer, and Rader, "The chirp z-transform algorithm and
+///     its application," Bell Syst. Tech. J. 48, 1249-1292 (1969).
+#[derive(Clone)]
+pub struct Czt<F> {
+    n: usize,
+    m: usize,
+    w: Complex<F>,
+    a: Complex<F>,
+    awk2: Vec<Complex<F>>,
+    fwk2: Vec<Complex<F>>,
+    wk2: Vec<Complex<F>>,
+    fft: Arc<dyn Fft<F>>,
+    ifft: Arc<dyn Fft<F>>,
+}
+
+impl<F: Float + FftNum> Czt<F> {
+    /// Create a chirp z-transform.
+    ///
+    /// ## Parameters
+    /// * `n`: int
+    ///   The size of the signal.
+    /// * `m`: int, optional
+    ///   The number of output points desired. Default is `n`.
+    /// * `w`: complex, optional
+    ///   The ratio between points in each step. This must be precise or the
+    ///   accumulated error will degrade the tail of the output sequence.
+    ///   Defaults to equally spaced points around the entire unit circle.
+    /// * `a`: complex, optional
+    ///   The starting point in the complex plane. Default is 1+0j.
+    ///
+    /// ## Errors
+    /// [Error::InvalidArg] if `n` or `m` is zero.
+    ///
+    /// ## Examples
+    /// Compute multiple prime-length FFTs:
+    /// ```custom,{class=language-python}
+    /// >>> from scipy.signal import CZT
+    /// >>> import numpy as np
+    /// >>> a = np.random.rand(7)
+    /// >>> b = np.random.rand(7)
+    /// >>> c = np.random.rand(7)
+    /// >>> czt_7 = CZT(n=7)
+    /// >>> A = czt_7(a)
+    /// >>> B = czt_7(b)
+    /// >>> C = czt_7(c)
+    /// ```
+    /// Sci-rs:
+    /// ```
+    /// use ndarray::array;
+    /// use sci_rs::signal::czt::Czt;
+    ///
+    /// let czt_7 = Czt::<f64>::new(7, None, None, None).unwrap();
+    /// let a = czt_7.transform(&array![1., 0., 0., 0., 0., 0., 0.], None).unwrap();
+    /// assert!(a.iter().all(|a| (a.re - 1.).abs() < 1e-12 && a.im.abs() < 1e-12));
+    /// ```
+    pub fn new(
+        n: usize,
+        m: Option<usize>,
+        w: Option<Complex<F>>,
+        a: Option<Complex<F>>,
+    ) -> Result<Self> {
+        let m = validate_sizes(n, m)?;
+        let a = a.unwrap_or(Complex::new(F::one(), F::zero()));
+        let pi = F::from(PI).unwrap();
+        let k_max = m.max(n);
+        let (w, wk2): (_, Vec<_>) = match w {
+            None => {
+                // Nothing specified, default to FFT-like, where k^2 is reduced modulo 2 m for
+                // accuracy.
+                let w =
+                    Complex::new(F::zero(), -F::from(2).unwrap() * pi / F::from(m).unwrap()).exp();
+                let wk2 = (0..k_max)
+                    .map(|k| {
+                        let k2 = (k as u128 * k as u128) % (2 * m as u128);
+                        Complex::new(F::zero(), -pi * F::from(k2).unwrap() / F::from(m).unwrap())
+                            .exp()
+                    })
+                    .collect();
+                (w, wk2)
+            }
+            Some(w) => {
+                let wk2 = (0..k_max)
+                    .map(|k| w.powf(F::from(k * k).unwrap() / F::from(2).unwrap()))
+                    .collect();
+                (w, wk2)
+            }
+        };
+        let awk2 = (0..n)
+            .map(|k| a.powf(-F::from(k).unwrap()) * wk2[k])
+            .collect();
+        Ok(Self::from_chirps(n, m, w, a, awk2, wk2))
+    }
+
+    /// Precalculate the FFT of the chirp filter.
+    fn from_chirps(
+        n: usize,
+        m: usize,
+        w: Complex<F>,
+        a: Complex<F>,
+        awk2: Vec<Complex<F>>,
+        wk2: Vec<Complex<F>>,
+    ) -> Self {
+        let nfft = next_fast_len(n + m - 1, None);
+        let mut planner = FftPlanner::new();
+        let fft = planner.plan_fft_forward(nfft);
+        let ifft = planner.plan_fft_inverse(nfft);
+        let mut fwk2: Vec<Complex<F>> = wk2[1..n]
+            .iter()
+            .rev()
+            .chain(&wk2[..m])
+            .map(|w| w.inv())
+            .collect();
+        fwk2.resize(nfft, Complex::zero());
+        fft.process(&mut fwk2);
+        let mut wk2 = wk2;
+        wk2.truncate(m);
+        Self {
+            n,
+            m,
+            w,
+            a,
+            awk2,
+            fwk2,
+            wk2,
+            fft,
+            ifft,
+        }
+    }
+
+    /// The size of the signal.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// The number of output points.
+    pub fn m(&self) -> usize {
+        self.m
+    }
+
+    /// The ratio between points in each step.
+    pub fn w(&self) -> Complex<F> {
+        self.w
+    }
+
+    /// The starting point in the complex plane.
+    pub fn a(&self) -> Complex<F> {
+        self.a
+    }
+
+    /// Calculate the chirp z-transform of a signal.
+    ///
+    /// ## Parameters
+    /// * `x`: array
+    ///   The signal to transform, real or complex.
+    /// * `axis`: int, optional
+    ///   Axis over which to compute the FFT. If not given, the last axis is
+    ///   used.
+    ///
+    /// ## Returns
+    /// * `out`: ndarray
+    ///   An array of the same dimensions as `x`, but with the length of the
+    ///   transformed axis set to `m`.
+    ///
+    /// ## Errors
+    /// [Error::InvalidArg] if `axis` is out of range or `x` does not have length `n` along `axis`.
+    pub fn transform<T, S, D>(
+        &self,
+        x: &ArrayBase<S, D>,
+        axis: Option<isize>,
+    ) -> Result<Array<Complex<F>, D>>
+    where
+        T: Copy + Into<Complex<F>>,
+        S: Data<Elem = T>,
+        D: Dimension,
+    {
+        let axis = Axis(check_and_get_axis_dyn(axis, x)?);
+        if x.len_of(axis) != self.n {
+            return Err(Error::InvalidArg {
+                arg: "x".into(),
+                reason: format!("CZT defined for length {}, not {}", self.n, x.len_of(axis)),
+            });
+        }
+        let mut out_dim = x.raw_dim();
+        out_dim[axis.index()] = self.m;
+        let mut y = Array::zeros(out_dim);
+
+        let nfft = self.fwk2.len();
+        let scale = F::from(nfft).unwrap();
+        let mut buffer = vec![Complex::zero(); nfft];
+        let mut scratch = vec![
+            Complex::zero();
+            self.fft
+                .get_inplace_scratch_len()
+                .max(self.ifft.get_inplace_scratch_len())
+        ];
+        for (lane, mut y) in x.lanes(axis).into_iter().zip(y.lanes_mut(axis)) {
+            buffer.fill(Complex::zero());
+            buffer
+                .iter_mut()
+                .zip(lane)
+                .zip(&self.awk2)
+                .for_each(|((b, &x), &awk2)| *b = x.into() * awk2);
+            self.fft.process_with_scratch(&mut buffer, &mut scratch);
+            buffer
+                .iter_mut()
+                .zip(&self.fwk2)
+                .for_each(|(b, &f)| *b = *b * f);
+            self.ifft.process_with_scratch(&mut buffer, &mut scratch);
+            y.iter_mut()
+                .zip(&buffer[self.n - 1..])
+                .zip(&self.wk2)
+                .for_each(|((y, &b), &wk2)| *y = b * wk2 / scale);
+        }
+        Ok(y)
+    }
+
+    /// Return the points at which the chirp z-transform is computed.
+    pub fn points(&self) -> Vec<Complex<F>> {
+        (0..self.m)
+            .map(|k| self.a * self.w.powf(-F::from(k).unwrap()))
+            .collect()
+    }
+}
+
+/// Create a callable zoom FFT transform function.
+///
+/// This is a specialization of the chirp z-transform ([Czt]) for a set of
+/// equally-spaced frequencies around the unit circle, used to calculate a
+/// section of the FFT more efficiently than calculating the entire FFT and
+/// truncating.
+///
+/// ## Notes
+/// The defaults are chosen such that ``ZoomFft::new(n, &[fs], None, Some(fs), None)`` computes
+/// the same result as ``fft.fft(x)``, and ``ZoomFft::new(n, &[fs], Some(m), Some(fs), None)`` is
+/// equivalent to ``fft.fft(x, m)`` for `m` greater than `n`.
+///
+/// Sampling frequency is 1/dt, the time step between samples in the
+/// signal `x`. The unit circle corresponds to frequencies from 0 up
+/// to the sampling frequency. The default sampling frequency of 2
+/// means that `f1`, `f2` values up to the Nyquist frequency are in the
+/// range \[0, 1). For `f1`, `f2` values expressed in radians, a sampling
+/// frequency of 2*pi should be used.
+///
+/// Remember that a zoom FFT can only interpolate the points of the existing
+/// FFT. It cannot help to resolve two separate nearby frequencies.
+/// Frequency resolution can only be increased by increasing acquisition
+/// time.
+///
+/// These functions are implemented using Bluestein's algorithm (as is
+/// [Czt]). \[2\]
+///
+/// ## References
+/// \[1\] Steve Alan Shilling, "A study of the chirp z-transform and its
+///     applications", pg 29 (1970)
+///     <https://krex.k-state.edu/bitstream/handle/2097/7844/LD2668R41972S43.pdf>
+///
+/// \[2\] Leo I. Bluestein, "A linear filtering approach to the computation
+///     of the discrete Fourier transform," Northeast Electronics Research
+///     and Engineering Meeting Record 10, 218-219 (1968).
+#[derive(Clone)]
+pub struct ZoomFft<F> {
+    czt: Czt<F>,
+    f1: F,
+    f2: F,
+    fs: F,
+}
+
+impl<F: Float + FftNum> ZoomFft<F> {
+    /// Create a zoom FFT.
+    ///
+    /// ## Parameters
+    /// * `n`: int
+    ///   The size of the signal.
+    /// * `fn`: array_like
+    ///   A length-2 sequence \[`f1`, `f2`\] giving the frequency range, or a
+    ///   scalar, for which the range \[0, `fn`\] is assumed.
+    /// * `m`: int, optional
+    ///   The number of points to evaluate. Default is `n`.
+    /// * `fs`: float, optional
+    ///   The sampling frequency. If ``fs=10`` represented 10 kHz, for example,
+    ///   then `f1` and `f2` would also be given in kHz.
+    ///   The default sampling frequency is 2, so `f1` and `f2` should be
+    ///   in the range \[0, 1\] to keep the transform below the Nyquist
+    ///   frequency.
+    /// * `endpoint`: bool, optional
+    ///   If true, `f2` is the last sample. Otherwise, it is not included.
+    ///   Default is false.
+    ///
+    /// ## Errors
+    /// [Error::InvalidArg] if `n` or `m` is zero, or if `fn` does not have one or two elements.
+    ///
+    /// ## Examples
+    /// To plot the transform results use something like the following:
+    /// ```custom,{class=language-python}
+    /// >>> import numpy as np
+    /// >>> from scipy.signal import ZoomFFT
+    /// >>> t = np.linspace(0, 1, 1021)
+    /// >>> x = np.cos(2*np.pi*15*t) + np.sin(2*np.pi*17*t)
+    /// >>> f1, f2 = 5, 27
+    /// >>> transform = ZoomFFT(len(x), [f1, f2], len(x), fs=1021)
+    /// >>> X = transform(x)
+    /// >>> f = np.linspace(f1, f2, len(x))
+    /// ```
+    /// Sci-rs:
+    /// ```
+    /// use ndarray::Array1;
+    /// use sci_rs::signal::czt::ZoomFft;
+    ///
+    /// let t = Array1::linspace(0., 1., 1021);
+    /// let pi = core::f64::consts::PI;
+    /// let x = t.mapv(|t| (2. * pi * 15. * t).cos() + (2. * pi * 17. * t).sin());
+    /// let transform = ZoomFft::new(x.len(), &[5., 27.], None, Some(1021.), None).unwrap();
+    /// let xf = transform.transform(&x, None).unwrap();
+    /// // The spectrum peaks at the tones at 15 Hz and 17 Hz.
+    /// assert!(xf[465].norm() > 500. && xf[557].norm() > 500.);
+    /// assert!(xf[300].norm() < 60.);
+    /// ```
+    pub fn new(
+        n: usize,
+        fn_: &[F],
+        m: Option<usize>,
+        fs: Option<F>,
+        endpoint: Option<bool>,
+    ) -> Result<Self> {
+        let m = validate_sizes(n, m)?;
+        let (f1, f2) = match *fn_ {
+            [f1, f2] => (f1, f2),
+            [f2] => (F::zero(), f2),
+            _ => {
+                return Err(Error::InvalidArg {
+                    arg: "fn".into(),
+                    reason: "fn must be a scalar or 2-length sequence".into(),
+                })
+            }
+        };
+        let fs = fs.unwrap_or_else(|| F::from(2).unwrap());
+        let pi = F::from(PI).unwrap();
+        let two_pi = F::from(2).unwrap() * pi;
+        let m_f = F::from(m).unwrap();
+        let scale = if endpoint.unwrap_or(false) {
+            ((f2 - f1) * m_f) / (fs * (m_f - F::one()))
+        } else {
+            (f2 - f1) / fs
+        };
+        let a = Complex::new(F::zero(), two_pi * f1 / fs).exp();
+        let w = Complex::new(F::zero(), -two_pi / m_f * scale).exp();
+        let wk2: Vec<_> = (0..m.max(n))
+            .map(|k| {
+                let k = F::from(k).unwrap();
+                Complex::new(F::zero(), -(pi * scale * k * k) / m_f).exp()
+            })
+            .collect();
+        let awk2 = (0..n)
+            .map(|k| {
+                Complex::new(F::zero(), -two_pi * f1 / fs * F::from(k).unwrap()).exp() * wk2[k]
+            })
+            .collect();
+        Ok(Self {
+            czt: Czt::from_chirps(n, m, w, a, awk2, wk2),
+            f1,
+            f2,
+            fs,
+        })
+    }
+
+    /// The frequency range \[`f1`, `f2`\] of the transform.
+    pub fn frequency_range(&self) -> (F, F) {
+        (self.f1, self.f2)
+    }
+
+    /// The sampling frequency.
+    pub fn fs(&self) -> F {
+        self.fs
+    }
+
+    /// The size of the signal.
+    pub fn n(&self) -> usize {
+        self.czt.n
+    }
+
+    /// The number of output points.
+    pub fn m(&self) -> usize {
+        self.czt.m
+    }
+
+    /// Calculate the zoom FFT of a signal along `axis`, see [Czt::transform].
+    pub fn transform<T, S, D>(
+        &self,
+        x: &ArrayBase<S, D>,
+        axis: Option<isize>,
+    ) -> Result<Array<Complex<F>, D>>
+    where
+        T: Copy + Into<Complex<F>>,
+        S: Data<Elem = T>,
+        D: Dimension,
+    {
+        self.czt.transform(x, axis)
+    }
+
+    /// Return the points on the unit circle at which the zoom FFT is computed.
+    pub fn points(&self) -> Vec<Complex<F>> {
+        self.czt.points()
+    }
+}
+
+/// Return the points at which the chirp z-transform is computed.
+///
+/// ## Parameters
+/// * `m`: int
+///   The number of points desired.
+/// * `w`: complex, optional
+///   The ratio between points in each step.
+///   Defaults to equally spaced points around the entire unit circle.
+/// * `a`: complex, optional
+///   The starting point in the complex plane. Default is 1+0j.
+///
+/// ## Returns
+/// * `out`: ndarray
+///   The points in the Z plane at which [czt] samples the z-transform,
+///   when called with arguments `m`, `w`, and `a`, as complex numbers.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `m` is zero.
+///
+/// ## Examples
+/// Plot the points of a 16-point FFT:
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy.signal import czt_points
+/// >>> points = czt_points(16)
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::signal::czt::czt_points;
+///
+/// let points = czt_points::<f64>(16, None, None).unwrap();
+/// assert!(points.iter().all(|p| (p.norm() - 1.).abs() < 1e-12));
+/// assert!((points[4].im - 1.).abs() < 1e-12);
+/// ```
+pub fn czt_points<F: Float + FftNum>(
+    m: usize,
+    w: Option<Complex<F>>,
+    a: Option<Complex<F>>,
+) -> Result<Vec<Complex<F>>> {
+    let m = validate_sizes(1, Some(m))?;
+    let a = a.unwrap_or(Complex::new(F::one(), F::zero()));
+    let two_pi = F::from(2. * PI).unwrap();
+    Ok(match w {
+        // Nothing specified, default to FFT
+        None => (0..m)
+            .map(|k| {
+                a * Complex::new(
+                    F::zero(),
+                    two_pi * F::from(k).unwrap() / F::from(m).unwrap(),
+                )
+                .exp()
+            })
+            .collect(),
+        Some(w) => (0..m).map(|k| a * w.powf(-F::from(k).unwrap())).collect(),
+    })
+}
+
+/// Compute the frequency response around a spiral in the Z plane.
+///
+/// ## Parameters
+/// * `x`: array
+///   The signal to transform.
+/// * `m`: int, optional
+///   The number of output points desired. Default is the length of the
+///   input data.
+/// * `w`: complex, optional
+///   The ratio between points in each step. This must be precise or the
+///   accumulated error will degrade the tail of the output sequence.
+///   Defaults to equally spaced points around the entire unit circle.
+/// * `a`: complex, optional
+///   The starting point in the complex plane. Default is 1+0j.
+/// * `axis`: int, optional
+///   Axis over which to compute the FFT. If not given, the last axis is
+///   used.
+///
+/// ## Returns
+/// * `out`: ndarray
+///   An array of the same dimensions as `x`, but with the length of the
+///   transformed axis set to `m`.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `axis` is out of range, or if `x` is empty along `axis` or `m` is zero.
+///
+/// ## Notes
+/// The values represent the response of the system ``sum_k x[k] z^-k`` at the points
+/// ``z = a * w^-k`` for ``k`` in ``0..m``. See [Czt] for details on the algorithm.
+///
+/// ## Examples
+/// The default parameters compute the FFT:
+/// ```custom,{class=language-python}
+/// >>> from scipy.signal import czt
+/// >>> czt([1., 2., 3., 4.])
+/// array([10.+0.j, -2.+2.j, -2.+0.j, -2.-2.j])
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::Complex;
+/// use ndarray::array;
+/// use sci_rs::signal::czt::czt;
+///
+/// let xf = czt(&array![1., 2., 3., 4.], None, None, None, None).unwrap();
+/// let expected = [(10., 0.), (-2., 2.), (-2., 0.), (-2., -2.)];
+/// for (xf, (re, im)) in xf.iter().zip(expected) {
+///     assert!((xf - Complex::new(re, im)).norm() < 1e-12);
+/// }
+/// ```
+pub fn czt<F, T, S, D>(
+    x: &ArrayBase<S, D>,
+    m: Option<usize>,
+    w: Option<Complex<F>>,
+    a: Option<Complex<F>>,
+    axis: Option<isize>,
+) -> Result<Array<Complex<F>, D>>
+where
+    F: Float + FftNum,
+    T: Copy + Into<Complex<F>>,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let n = x.len_of(Axis(check_and_get_axis_dyn(axis, x)?));
+    Czt::new(n, m, w, a)?.transform(x, axis)
+}
+
+/// Compute the DFT of `x` only for frequencies in range `fn`.
+///
+/// ## Parameters
+/// * `x`: array
+///   The signal to transform.
+/// * `fn`: array_like
+///   A length-2 sequence \[`f1`, `f2`\] giving the frequency range, or a scalar,
+///   for which the range \[0, `fn`\] is assumed.
+/// * `m`: int, optional
+///   The number of points to evaluate. The default is the length of `x`.
+/// * `fs`: float, optional
+///   The sampling frequency. If ``fs=10`` represented 10 kHz, for example,
+///   then `f1` and `f2` would also be given in kHz.
+///   The default sampling frequency is 2, so `f1` and `f2` should be
+///   in the range \[0, 1\] to keep the transform below the Nyquist
+///   frequency.
+/// * `endpoint`: bool, optional
+///   If true, `f2` is the last sample. Otherwise, it is not included.
+///   Default is false.
+/// * `axis`: int, optional
+///   Axis over which to compute the FFT. If not given, the last axis is
+///   used.
+///
+/// ## Returns
+/// * `out`: ndarray
+///   The transformed signal. The Fourier transform will be calculated
+///   at the points f1, f1+df, f1+2df, ..., f2, where df=(f2-f1)/m.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `axis` is out of range, if `x` is empty along `axis` or `m` is zero, or
+/// if `fn` does not have one or two elements.
+///
+/// ## Notes
+/// See [ZoomFft] for details on the algorithm.
+///
+/// ## Examples
+/// Evaluate the DFT of a short signal at two frequencies, with the default sampling frequency of
+/// 2:
+/// ```custom,{class=language-python}
+/// >>> from scipy.signal import zoom_fft
+/// >>> zoom_fft([1., 2., 3., 4.], [0, 0.5], m=2)
+/// array([10.        +0.j        , -0.41421356-7.24264069j])
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::Complex;
+/// use ndarray::array;
+/// use sci_rs::signal::czt::zoom_fft;
+///
+/// let x = array![1., 2., 3., 4.];
+/// let xf = zoom_fft(&x, &[0., 0.5], Some(2), None, None, None).unwrap();
+/// assert!((xf[0] - Complex::new(10., 0.)).norm() < 1e-12);
+/// assert!((xf[1] - Complex::new(-0.4142135623730949, -7.242640687119286)).norm() < 1e-12);
+/// ```
+pub fn zoom_fft<F, T, S, D>(
+    x: &ArrayBase<S, D>,
+    fn_: &[F],
+    m: Option<usize>,
+    fs: Option<F>,
+    endpoint: Option<bool>,
+    axis: Option<isize>,
+) -> Result<Array<Complex<F>, D>>
+where
+    F: Float + FftNum,
+    T: Copy + Into<Complex<F>>,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let n = x.len_of(Axis(check_and_get_axis_dyn(axis, x)?));
+    ZoomFft::new(n, fn_, m, fs, endpoint)?.transform(x, axis)
+}
+
+/// The signal length must be positive, and so must the number of output points, which defaults
+/// to the signal length.
+fn validate_sizes(n: usize, m: Option<usize>) -> Result<usize> {
+    if n < 1 {
+        return Err(Error::InvalidArg {
+            arg: "n".into(),
+            reason: "Invalid number of CZT data points (0) specified. n must be positive.".into(),
+        });
+    }
+    match m {
+        Some(0) => Err(Error::InvalidArg {
+            arg: "m".into(),
+            reason: "Invalid number of CZT output points (0) specified. m must be positive.".into(),
+        }),
+        Some(m) => Ok(m),
+        None => Ok(n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{Array1, Array2};
+
+    /// Direct evaluation of ``sum_k x[k] z^-k`` at each point.
+    fn direct(x: &[f64], points: &[Complex<f64>]) -> Vec<Complex<f64>> {
+        points
+            .iter()
+            .map(|&z| {
+                x.iter()
+                    .enumerate()
+                    .map(|(k, &x)| z.powf(-(k as f64)) * x)
+                    .sum()
+            })
+            .collect()
+    }
+
+    #[track_caller]
+    fn assert_complex_eq(a: &[Complex<f64>], b: &[Complex<f64>], epsilon: f64) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert_abs_diff_eq!((a - b).norm(), 0., epsilon = epsilon);
+        }
+    }
+
+    fn signal(n: usize) -> Vec<f64> {
+        (0..n).map(|i| ((i * 7 + 3) % 11) as f64 - 4.5).collect()
+    }
+
+    #[test]
+    fn fft_equivalence() {
+        for (n, m) in [(1, 1), (7, 7), (8, 8), (13, 20), (16, 5)] {
+            let x = signal(n);
+            let xf = czt(&Array1::from_vec(x.clone()), Some(m), None, None, None).unwrap();
+            let points = czt_points(m, None, None).unwrap();
+            assert_complex_eq(xf.as_slice().unwrap(), &direct(&x, &points), 1e-9);
+        }
+    }
+
+    #[test]
+    fn spiral() {
+        let x = signal(10);
+        let w = Complex::new(0., -0.3).exp() * 0.98;
+        let a = Complex::new(0.8, 0.6) * 1.1;
+        let czt = Czt::new(10, Some(12), Some(w), Some(a)).unwrap();
+        let xf = czt.transform(&Array1::from_vec(x.clone()), None).unwrap();
+        let points = czt.points();
+        assert_complex_eq(&points, &czt_points(12, Some(w), Some(a)).unwrap(), 1e-12);
+        assert_complex_eq(xf.as_slice().unwrap(), &direct(&x, &points), 1e-9);
+    }
+
+    #[test]
+    fn complex_input() {
+        let x: Array1<Complex<f64>> = (0..6)
+            .map(|i| Complex::new(i as f64, 1. - i as f64))
+            .collect();
+        let xf: Array1<Complex<f64>> = czt(&x, None, None, None, None).unwrap();
+        let re: Array1<Complex<f64>> = czt(&x.mapv(|x| x.re), None, None, None, None).unwrap();
+        let im: Array1<Complex<f64>> = czt(&x.mapv(|x| x.im), None, None, None, None).unwrap();
+        for ((xf, re), im) in xf.iter().zip(&re).zip(&im) {
+            assert_abs_diff_eq!((xf - (re + im * Complex::i())).norm(), 0., epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn zoom() {
+        let x = signal(16);
+        let fs = 10.;
+        for endpoint in [false, true] {
+            let zoom = ZoomFft::new(16, &[1., 3.], Some(9), Some(fs), Some(endpoint)).unwrap();
+            let df = if endpoint { 2. / 8. } else { 2. / 9. };
+            let points: Vec<_> = (0..9)
+                .map(|k| {
+                    let f = 1. + df * k as f64;
+                    Complex::new(0., 2. * core::f64::consts::PI * f / fs).exp()
+                })
+                .collect();
+            assert_complex_eq(&zoom.points(), &points, 1e-12);
+            let xf = zoom.transform(&Array1::from_vec(x.clone()), None).unwrap();
+            assert_complex_eq(xf.as_slice().unwrap(), &direct(&x, &points), 1e-9);
+        }
+
+        // The full range is the FFT.
+        let xa = Array1::from_vec(x.clone());
+        let xf = zoom_fft(&xa, &[fs], None, Some(fs), None, None).unwrap();
+        let fft = czt(&xa, None, None, None, None).unwrap();
+        assert_complex_eq(xf.as_slice().unwrap(), fft.as_slice().unwrap(), 1e-9);
+    }
+
+    #[test]
+    fn axis() {
+        let x = Array2::from_shape_fn((5, 3), |(i, j)| (i * (j + 1)) as f64);
+        let xf = czt(&x, Some(4), None, None, Some(0)).unwrap();
+        assert_eq!(xf.dim(), (4, 3));
+        for j in 0..3 {
+            let col = czt(&x.column(j), Some(4), None, None, None).unwrap();
+            for i in 0..4 {
+                assert_abs_diff_eq!((xf[[i, j]] - col[i]).norm(), 0., epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_args() {
+        let x = Array1::<f64>::zeros(4);
+        assert!(czt(&x, Some(0), None, None, None).is_err());
+        assert!(czt(&Array1::<f64>::zeros(0), None, None, None, None).is_err());
+        assert!(czt(&x, None, None, None, Some(1)).is_err());
+        assert!(zoom_fft(&x, &[0., 0.5, 1.], None, None, None, None).is_err());
+        let czt = Czt::<f64>::new(5, None, None, None).unwrap();
+        assert!(czt.transform(&x, None).is_err());
+        assert!(czt_points::<f64>(0, None, None).is_err());
+    }
+}
@@ -0,0 +1,270 @@
+use super::{
+    check_and_get_axis_dyn,
+    design::{cheby1_dyn, firwin_dyn, DigitalFilter, FilterBandType, FilterOutputType, Sos},
+    resample_poly, sosfilt_dyn, sosfiltfilt_dyn, upfirdn_lane, ResamplePolyWindow, UpFirDnMode,
+};
+use crate::{signal::windows::Hamming, special::Bessel};
+use alloc::{vec, vec::Vec};
+use core::{iter::Sum, ops::SubAssign};
+use nalgebra::RealField;
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension};
+use num_traits::{Float, MulAdd, Pow};
+use sci_rs_core::{Error, Result};
+
+/// Anti-aliasing filter used by [decimate].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecimateFilterType {
+    /// Chebyshev type I filter of order 8 with 0.05 dB of passband ripple and a cutoff of
+    /// ``0.8 / q``.
+    #[default]
+    Iir,
+    /// Hamming windowed FIR filter of order ``20 * q`` with a cutoff of ``1 / q``.
+    Fir,
+}
+
+/// Downsample the signal after applying an anti-aliasing filter.
+///
+/// By default, an order 8 Chebyshev type I filter is used. A FIR filter of
+/// order ``20 * q`` with Hamming window is used if `ftype` is 'fir'.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   The input signal made up of equidistant samples. If `x` is a multidimensional
+///   array, the parameter `axis` specifies the time axis.
+/// * `q`: int
+///   The downsampling factor. When using IIR downsampling, it is recommended
+///   to call `decimate` multiple times for downsampling factors higher than
+///   13.
+/// * `n`: int, optional
+///   The order of the filter (1 less than the length for 'fir'). Defaults to
+///   8 for 'iir' and 20 times the downsampling factor for 'fir'.
+/// * `ftype`: [DecimateFilterType], optional
+///   If [DecimateFilterType::Iir] or [DecimateFilterType::Fir], specifies the type of lowpass
+///   filter. Default is [DecimateFilterType::Iir].
+/// * `axis`: int, optional
+///   The axis along which to decimate. Default is -1.
+/// * `zero_phase`: bool, optional
+///   Prevent phase shift by filtering with `sosfiltfilt` instead of `sosfilt` when using an IIR
+///   filter, and shifting the outputs back by the filter's group delay when using an FIR filter
+///   (see [resample_poly]). The default value of true is recommended, since a phase shift is
+///   generally not desired.
+///
+/// ## Returns
+/// * `y`: ndarray
+///   The down-sampled signal. The length along `axis` is ``ceil(x.len_of(axis) / q)``.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `q` is zero or `axis` is out of range, or if the FIR filter cannot be
+/// designed for `q`.
+///
+/// ## Notes
+/// The ``zero_phase`` keyword was added in scipy 0.18.0.
+/// The possibility to use instances of ``dlti`` as ``ftype`` is not supported.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy import signal
+/// >>> wave_duration = 3
+/// >>> sample_rate = 100
+/// >>> freq = 2
+/// >>> q = 5
+/// >>> samples = wave_duration*sample_rate
+/// >>> samples_decimated = int(samples/q)
+/// >>> x = np.linspace(0, wave_duration, samples, endpoint=False)
+/// >>> y = np.cos(x*np.pi*freq*2)
+/// >>> ydem = signal.decimate(y, q)
+/// >>> ydem.shape
+/// (60,)
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::Array1;
+/// use sci_rs::signal::filter::decimate;
+///
+/// let (wave_duration, sample_rate, freq, q) = (3., 100, 2., 5);
+/// let samples = 3 * sample_rate;
+/// let x = Array1::range(0., samples as f64, 1.) * wave_duration / samples as f64;
+/// let y = x.mapv(|x| (x * core::f64::consts::PI * freq * 2.).cos());
+/// let ydem = decimate(&y, q, None, None, None, None).unwrap();
+/// assert_eq!(ydem.len(), 60);
+/// ```
+pub fn decimate<F, S, D>(
+    x: &ArrayBase<S, D>,
+    q: usize,
+    n: Option<usize>,
+    ftype: Option<DecimateFilterType>,
+    axis: Option<isize>,
+    zero_phase: Option<bool>,
+) -> Result<Array<F, D>>
+where
+    F: Float + RealField + Sum + SubAssign + Bessel + MulAdd<Output = F> + Pow<F, Output = F>,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    if q == 0 {
+        return Err(Error::InvalidArg {
+            arg: "q".into(),
+            reason: "q must be a positive integer.".into(),
+        });
+    }
+    let axis = Axis(check_and_get_axis_dyn(axis, x)?);
+    let zero_phase = zero_phase.unwrap_or(true);
+    let q_f = F::from_usize(q).unwrap();
+
+    let mut out_dim = x.raw_dim();
+    out_dim[axis.index()] = x.len_of(axis).div_ceil(q);
+    let mut y = Array::zeros(out_dim);
+
+    match ftype.unwrap_or_default() {
+        DecimateFilterType::Fir => {
+            let n = n.unwrap_or(20 * q);
+            let b: Vec<F> = firwin_dyn(
+                n + 1,
+                &[F::one() / q_f],
+                None,
+                None::<&Hamming>,
+                &FilterBandType::Lowpass,
+                None,
+                None,
+            )
+            .map_err(crate::error::Error::into_core)?;
+            if zero_phase {
+                return resample_poly(
+                    x,
+                    1,
+                    q,
+                    Some(axis.index() as isize),
+                    Some(ResamplePolyWindow::Coefficients(&b)),
+                    None,
+                    None,
+                );
+            }
+            // upfirdn only calculates the needed outputs, unlike filtering before downsampling.
+            for (lane, y) in x.lanes(axis).into_iter().zip(y.lanes_mut(axis)) {
+                upfirdn_lane(&b, lane, 1, q, UpFirDnMode::Constant, F::zero(), 0, y);
+            }
+        }
+        DecimateFilterType::Iir => {
+            let n = n.unwrap_or(8);
+            let DigitalFilter::Sos(filter) = cheby1_dyn(
+                n,
+                F::from_f64(0.05).unwrap(),
+                vec![F::from_f64(0.8).unwrap() / q_f],
+                Some(FilterBandType::Lowpass),
+                Some(false),
+                Some(FilterOutputType::Sos),
+                None,
+            ) else {
+                unreachable!("cheby1_dyn returns the requested output type");
+            };
+            let sos: Vec<Sos<F>> = filter.sos;
+            for (lane, mut y) in x.lanes(axis).into_iter().zip(y.lanes_mut(axis)) {
+                let filtered = if zero_phase {
+                    sosfiltfilt_dyn(lane.iter(), &sos)
+                } else {
+                    sosfilt_dyn(lane.iter(), &mut sos.clone())
+                };
+                y.iter_mut()
+                    .zip(filtered.into_iter().step_by(q))
+                    .for_each(|(y, f)| *y = f);
+            }
+        }
+    }
+    Ok(y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{Array1, Array2};
+
+    fn cosine(samples: usize, freq: f64, rate: f64) -> Array1<f64> {
+        Array1::from_iter(
+            (0..samples).map(|i| (2. * core::f64::consts::PI * freq * i as f64 / rate).cos()),
+        )
+    }
+
+    #[test]
+    fn iir_zero_phase() {
+        // A tone well inside the passband passes through without phase shift.
+        let x = cosine(300, 2., 100.);
+        let y = decimate(&x, 5, None, None, None, None).unwrap();
+        let expected = cosine(60, 2., 20.);
+        assert_eq!(y.len(), 60);
+        for (y, e) in y.iter().zip(&expected).skip(5).take(50) {
+            assert_abs_diff_eq!(y, e, epsilon = 1e-2);
+        }
+    }
+
+    #[test]
+    fn fir_zero_phase() {
+        let x = cosine(301, 2., 100.);
+        let y = decimate(&x, 5, None, Some(DecimateFilterType::Fir), None, None).unwrap();
+        let expected = cosine(61, 2., 20.);
+        assert_eq!(y.len(), 61);
+        for (y, e) in y.iter().zip(&expected).skip(25).take(10) {
+            assert_abs_diff_eq!(y, e, epsilon = 1e-2);
+        }
+    }
+
+    #[test]
+    fn fir_causal() {
+        // Without phase compensation, decimation matches filtering and then downsampling.
+        let x = Array1::from_iter((0..23).map(|i| ((i * 7) % 11) as f64 - 5.));
+        let b: Vec<f64> = firwin_dyn(
+            7,
+            &[0.5],
+            None,
+            None::<&Hamming>,
+            &FilterBandType::Lowpass,
+            None,
+            None,
+        )
+        .unwrap();
+        let y = decimate(
+            &x,
+            2,
+            Some(6),
+            Some(DecimateFilterType::Fir),
+            None,
+            Some(false),
+        )
+        .unwrap();
+        assert_eq!(y.len(), 12);
+        for (k, y) in y.iter().enumerate() {
+            let i = 2 * k;
+            let expected: f64 = (0..b.len().min(i + 1)).map(|j| b[j] * x[i - j]).sum();
+            assert_abs_diff_eq!(*y, expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn removes_aliasing_tone() {
+        // A tone above the new Nyquist frequency is strongly attenuated.
+        let x = cosine(400, 30., 100.);
+        for ftype in [DecimateFilterType::Iir, DecimateFilterType::Fir] {
+            for zero_phase in [true, false] {
+                let y = decimate(&x, 4, None, Some(ftype), None, Some(zero_phase)).unwrap();
+                let tail = y.slice(ndarray::s![30..70]);
+                assert!(tail.iter().all(|y| y.abs() < 2e-2), "{ftype:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn axis() {
+        let x = cosine(200, 1., 100.);
+        let x2 = Array2::from_shape_fn((200, 2), |(i, j)| x[i] * (j + 1) as f64);
+        let y = decimate(&x2, 3, None, None, Some(0), None).unwrap();
+        assert_eq!(y.dim(), (67, 2));
+        let y1 = decimate(&x, 3, None, None, None, None).unwrap();
+        for (k, y1) in y1.iter().enumerate() {
+            assert_abs_diff_eq!(y[[k, 0]], *y1, epsilon = 1e-12);
+            assert_abs_diff_eq!(y[[k, 1]], 2. * y1, epsilon = 1e-12);
+        }
+        assert!(decimate(&x2, 3, None, None, Some(2), None).is_err());
+        assert!(decimate(&x, 0, None, None, None, None).is_err());
+    }
+}
@@ -0,0 +1,193 @@
+use nalgebra::Complex;
+use ndarray::{Array1, Array2, ArrayBase, ArrayView1, Axis, Data, Ix2};
+use num_traits::{Float, Zero};
+use rustfft::{Fft, FftNum, FftPlanner};
+use sci_rs_core::{Error, Result};
+use std::sync::Arc;
+
+/// Compute the '2-D' analytic signal of `x`
+///
+/// ## Parameters
+/// * `x`: 2-D array_like
+///   2-D signal data.
+/// * `n`: `Option<(usize, usize)>`
+///   Number of Fourier components along each axis. Default is `x.dim()`. The input is zero-padded
+///   or truncated to this shape before the transform.
+///
+/// ## Returns
+/// * `xa`: ndarray
+///   Analytic signal of `x` taken along axes (0,1).
+///
+/// ## Errors
+/// [Error::InvalidArg] if either entry of `n` is zero.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy.signal import hilbert2
+/// >>> x = np.array([[1., 2., 0.5, -1.], [0., 3., 1., 2.], [-2., 1., 4., 0.5]])
+/// >>> hilbert2(x)[0, 0]
+/// (0.8556624327025937-2.6547005383792515j)
+/// ```
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::signal::filter::hilbert2;
+///
+/// let x = array![[1., 2., 0.5, -1.], [0., 3., 1., 2.], [-2., 1., 4., 0.5]];
+/// let xa = hilbert2(&x, None).unwrap();
+/// assert_abs_diff_eq!(xa[[0, 0]].re, 0.8556624327025937, epsilon = 1e-12);
+/// assert_abs_diff_eq!(xa[[0, 0]].im, -2.6547005383792515, epsilon = 1e-12);
+/// ```
+pub fn hilbert2<F, S>(
+    x: &ArrayBase<S, Ix2>,
+    n: Option<(usize, usize)>,
+) -> Result<Array2<Complex<F>>>
+where
+    F: Float + FftNum,
+    S: Data<Elem = F>,
+{
+    let (n0, n1) = n.unwrap_or(x.dim());
+    if n0 == 0 || n1 == 0 {
+        return Err(Error::InvalidArg {
+            arg: "n".into(),
+            reason: "N must be positive.".into(),
+        });
+    }
+
+    // Zero-pad or truncate x to the requested shape.
+    let mut xf = Array2::from_elem((n0, n1), Complex::zero());
+    let rows = n0.min(x.nrows());
+    let cols = n1.min(x.ncols());
+    xf.slice_mut(ndarray::s![..rows, ..cols])
+        .zip_mut_with(&x.slice(ndarray::s![..rows, ..cols]), |xf, &x| {
+            *xf = Complex::new(x, F::zero())
+        });
+
+    let mut planner = FftPlanner::new();
+    fft_axes(&mut xf, &mut planner, false);
+
+    let h0 = hilbert_multiplier::<F>(n0);
+    let h1 = hilbert_multiplier::<F>(n1);
+    for ((i, j), xf) in xf.indexed_iter_mut() {
+        *xf = *xf * (h0[i] * h1[j]);
+    }
+
+    fft_axes(&mut xf, &mut planner, true);
+    let scale = F::from(n0 * n1).unwrap();
+    xf.mapv_inplace(|x| x / scale);
+    Ok(xf)
+}
+
+/// Frequency domain multiplier that zeroes the negative frequencies and doubles the positive ones
+/// of an `n` point spectrum.
+fn hilbert_multiplier<F: Float>(n: usize) -> Array1<F> {
+    let mut h = Array1::zeros(n);
+    let two = F::one() + F::one();
+    h[0] = F::one();
+    if n.is_multiple_of(2) {
+        h[n / 2] = F::one();
+        h.slice_mut(ndarray::s![1..n / 2]).fill(two);
+    } else {
+        h.slice_mut(ndarray::s![1..n.div_ceil(2)]).fill(two);
+    }
+    h
+}
+
+/// Unnormalized forward or inverse FFT of `x` along both of its axes.
+fn fft_axes<F: FftNum>(x: &mut Array2<Complex<F>>, planner: &mut FftPlanner<F>, inverse: bool) {
+    let mut plan = |len| -> Arc<dyn Fft<F>> {
+        if inverse {
+            planner.plan_fft_inverse(len)
+        } else {
+            planner.plan_fft_forward(len)
+        }
+    };
+    for axis in [Axis(0), Axis(1)] {
+        let fft = plan(x.len_of(axis));
+        let mut buffer = vec![Complex::zero(); x.len_of(axis)];
+        let mut scratch = vec![Complex::zero(); fft.get_inplace_scratch_len()];
+        for mut lane in x.lanes_mut(axis) {
+            buffer
+                .iter_mut()
+                .zip(lane.iter())
+                .for_each(|(b, &l)| *b = l);
+            fft.process_with_scratch(&mut buffer, &mut scratch);
+            lane.assign(&ArrayView1::from(&buffer));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::arr2;
+
+    #[track_caller]
+    fn assert_vec_eq(a: Array2<Complex<f64>>, b: Array2<(f64, f64)>) {
+        assert_eq!(a.shape(), b.shape());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a.re, b.0, epsilon = 1e-10);
+            assert_abs_diff_eq!(a.im, b.1, epsilon = 1e-10);
+        }
+    }
+
+    fn x() -> Array2<f64> {
+        arr2(&[[1., 2., 0.5, -1.], [0., 3., 1., 2.], [-2., 1., 4., 0.5]])
+    }
+
+    #[test]
+    fn analytic_signal() {
+        let expected = arr2(&[
+            [
+                (0.855662432703, -2.654700538379),
+                (3.443375672974, -0.904700538379),
+                (0.644337567297, 3.232050807569),
+                (-2.443375672974, -1.116025403784),
+            ],
+            [
+                (0.721687836487, 1.232050807569),
+                (1.123611625134, 0.07735026919),
+                (0.278312163513, -1.520725942164),
+                (3.876388374866, -0.366025403784),
+            ],
+            [
+                (-2.57735026919, -0.82735026919),
+                (1.433012701892, -2.42264973081),
+                (4.57735026919, 0.538675134595),
+                (0.066987298108, 4.732050807569),
+            ],
+        ]);
+        assert_vec_eq(hilbert2(&x(), None).unwrap(), expected);
+    }
+
+    #[test]
+    fn padded_and_truncated() {
+        let expected = arr2(&[
+            [
+                (0.42264973081, -0.866025403784),
+                (1.711324865405, -1.211324865405),
+                (1.366025403784, 0.07735026919),
+            ],
+            [
+                (1.299038105677, 0.345299461621),
+                (1.123611625134, -0.07735026919),
+                (1.57735026919, -0.017949192431),
+            ],
+            [
+                (-1.42264973081, 1.732050807569),
+                (1.288675134595, -1.964101615138),
+                (3.133974596216, 2.232050807569),
+            ],
+            [
+                (-1.299038105677, -1.5),
+                (1.876388374866, -0.5),
+                (-0.57735026919, 1.75),
+            ],
+        ]);
+        assert_vec_eq(hilbert2(&x(), Some((4, 3))).unwrap(), expected);
+        assert!(hilbert2(&x(), Some((0, 3))).is_err());
+    }
+}
@@ -25,32 +25,52 @@ pub use ext::*;
 pub use sosfilt::*;
 
 #[cfg(feature = "alloc")]
-mod arraytools;
+pub(crate) mod arraytools;
 #[cfg(feature = "alloc")]
 use arraytools::*;
 
+#[cfg(feature = "alloc")]
+mod decimate;
 #[cfg(feature = "alloc")]
 mod filtfilt;
+#[cfg(feature = "std")]
+mod hilbert;
 #[cfg(feature = "alloc")]
 mod lfilter;
 #[cfg(feature = "alloc")]
 mod lfilter_zi;
 #[cfg(feature = "alloc")]
+mod order_filter;
+#[cfg(feature = "alloc")]
+mod resample_poly;
+#[cfg(feature = "alloc")]
 mod savgol_filter;
 #[cfg(feature = "alloc")]
 mod sosfilt_zi;
 #[cfg(feature = "alloc")]
 mod sosfiltfilt;
+#[cfg(feature = "alloc")]
+mod upfirdn;
 
+#[cfg(feature = "alloc")]
+pub use decimate::*;
 #[cfg(feature = "alloc")]
 pub use filtfilt::*;
+#[cfg(feature = "std")]
+pub use hilbert::*;
 #[cfg(feature = "alloc")]
 pub use lfilter::*;
 #[cfg(feature = "alloc")]
 pub use lfilter_zi::*;
 #[cfg(feature = "alloc")]
+pub use order_filter::*;
+#[cfg(feature = "alloc")]
+pub use resample_poly::*;
+#[cfg(feature = "alloc")]
 pub use savgol_filter::*;
 #[cfg(feature = "alloc")]
 pub use sosfilt_zi::*;
 #[cfg(feature = "alloc")]
 pub use sosfiltfilt::*;
+#[cfg(feature = "alloc")]
+pub use upfirdn::*;
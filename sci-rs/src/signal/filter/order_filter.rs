@@ -0,0 +1,218 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use ndarray::{Array, ArrayBase, Data, Dimension};
+use num_traits::Zero;
+use sci_rs_core::{Error, Result};
+
+/// Perform an order filter on an N-D array.
+///
+/// Perform an order filter on the array in. The domain argument acts as a mask centered over each
+/// pixel. The non-zero elements of domain are used to select elements surrounding each input
+/// pixel which are placed in a list. The list is sorted, and the output for that pixel is the
+/// element corresponding to rank in the sorted list.
+///
+/// ## Parameters
+/// * `a`: ndarray
+///   The N-dimensional input array.
+/// * `domain`: array_like
+///   A mask array with the same number of dimensions as `a`. Each dimension should have an odd
+///   number of elements.
+/// * `rank`: int
+///   A non-negative integer which selects the element from the sorted list (0 corresponds to the
+///   smallest element, 1 is the next smallest element, etc.).
+///
+/// ## Returns
+/// * `out`: ndarray
+///   The results of the order filter in an array with the same shape as `a`.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `domain` does not have the same number of dimensions as `a`, if any of
+/// its dimensions has an even length, or if `rank` is not less than the number of elements
+/// selected by `domain`.
+///
+/// ## Notes
+/// Elements outside of `a` are taken to be zero. The median filter is the order filter with
+/// `rank` equal to half the number of selected elements.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy import signal
+/// >>> x = np.arange(25).reshape(5, 5)
+/// >>> domain = np.identity(3)
+/// >>> signal.order_filter(x, domain, 0)
+/// array([[  0.,   0.,   0.,   0.,   0.],
+///        [  0.,   0.,   1.,   2.,   0.],
+///        [  0.,   5.,   6.,   7.,   0.],
+///        [  0.,  10.,  11.,  12.,   0.],
+///        [  0.,   0.,   0.,   0.,   0.]])
+/// >>> signal.order_filter(x, domain, 2)
+/// array([[  6.,   7.,   8.,   9.,   4.],
+///        [ 11.,  12.,  13.,  14.,   9.],
+///        [ 16.,  17.,  18.,  19.,  14.],
+///        [ 21.,  22.,  23.,  24.,  19.],
+///        [ 20.,  21.,  22.,  23.,  24.]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::{array, Array};
+/// use sci_rs::signal::filter::order_filter;
+///
+/// let x = Array::from_iter(0..25).into_shape_with_order((5, 5)).unwrap();
+/// let domain = Array::from_shape_fn((3, 3), |(i, j)| i == j);
+/// assert_eq!(
+///     order_filter(&x, &domain, 0).unwrap(),
+///     array![
+///         [0, 0, 0, 0, 0],
+///         [0, 0, 1, 2, 0],
+///         [0, 5, 6, 7, 0],
+///         [0, 10, 11, 12, 0],
+///         [0, 0, 0, 0, 0]
+///     ]
+/// );
+/// assert_eq!(
+///     order_filter(&x, &domain, 2).unwrap(),
+///     array![
+///         [6, 7, 8, 9, 4],
+///         [11, 12, 13, 14, 9],
+///         [16, 17, 18, 19, 14],
+///         [21, 22, 23, 24, 19],
+///         [20, 21, 22, 23, 24]
+///     ]
+/// );
+/// ```
+pub fn order_filter<F, S1, S2, D>(
+    a: &ArrayBase<S1, D>,
+    domain: &ArrayBase<S2, D>,
+    rank: usize,
+) -> Result<Array<F, D>>
+where
+    F: PartialOrd + Copy + Zero,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = bool>,
+    D: Dimension,
+{
+    if domain.ndim() != a.ndim() {
+        return Err(Error::InvalidArg {
+            arg: "domain".into(),
+            reason: "domain must have the same number of dimensions as the input.".into(),
+        });
+    }
+    if domain.shape().iter().any(|n| n.is_multiple_of(2)) {
+        return Err(Error::InvalidArg {
+            arg: "domain".into(),
+            reason: "Each dimension of domain argument should have an odd number of elements."
+                .into(),
+        });
+    }
+
+    // Offsets of the selected elements relative to the center of the domain.
+    let offsets: Vec<Vec<isize>> = domain
+        .view()
+        .into_dyn()
+        .indexed_iter()
+        .filter(|(_, &selected)| selected)
+        .map(|(idx, _)| {
+            idx.slice()
+                .iter()
+                .zip(domain.shape())
+                .map(|(&i, &n)| i as isize - (n / 2) as isize)
+                .collect()
+        })
+        .collect();
+    if rank >= offsets.len() {
+        return Err(Error::InvalidArg {
+            arg: "rank".into(),
+            reason: "rank must be less than the number of selected elements of domain.".into(),
+        });
+    }
+
+    let a_dyn = a.view().into_dyn();
+    let shape = a.shape();
+    let mut window = Vec::with_capacity(offsets.len());
+    let mut index = alloc::vec![0; a.ndim()];
+    let out: Vec<F> = a_dyn
+        .indexed_iter()
+        .map(|(idx, _)| {
+            window.clear();
+            window.extend(offsets.iter().map(|offset| {
+                let inside = idx
+                    .slice()
+                    .iter()
+                    .zip(offset)
+                    .zip(shape)
+                    .zip(index.iter_mut())
+                    .all(|(((&i, &o), &n), index)| {
+                        let j = i as isize + o;
+                        *index = j as usize;
+                        j >= 0 && (j as usize) < n
+                    });
+                if inside {
+                    a_dyn[index.as_slice()]
+                } else {
+                    F::zero()
+                }
+            }));
+            let (_, &mut value, _) = window
+                .select_nth_unstable_by(rank, |x, y| x.partial_cmp(y).unwrap_or(Ordering::Equal));
+            value
+        })
+        .collect();
+    Ok(Array::from_shape_vec(a.raw_dim(), out).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use ndarray::{array, Array1, Array2, Array3};
+
+    #[test]
+    fn median_1d() {
+        let x = array![2., 8., -1., 5., 3., 9., 4.];
+        let domain = Array1::from_elem(3, true);
+        assert_eq!(
+            order_filter(&x, &domain, 1).unwrap(),
+            array![2., 2., 5., 3., 5., 4., 4.]
+        );
+        // Only the left neighbour and the element itself.
+        let domain = array![true, true, false];
+        assert_eq!(
+            order_filter(&x, &domain, 1).unwrap(),
+            array![2., 8., 8., 5., 5., 9., 9.]
+        );
+    }
+
+    #[test]
+    fn three_dimensions() {
+        let x = Array3::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 20 + j * 5 + k) as i32);
+        let domain = Array3::from_elem((3, 3, 3), true);
+        let max = order_filter(&x, &domain, 26).unwrap();
+        let min = order_filter(&x, &domain, 0).unwrap();
+        for ((i, j, k), &v) in max.indexed_iter() {
+            let expected = ((i + 1).min(2) * 20 + (j + 1).min(3) * 5 + (k + 1).min(4)) as i32;
+            assert_eq!(v, expected);
+        }
+        // Only the interior is away from the zero padding.
+        for ((i, j, k), &v) in min.indexed_iter() {
+            let interior = i == 1 && (1..3).contains(&j) && (1..4).contains(&k);
+            let expected = if interior {
+                x[[i - 1, j - 1, k - 1]]
+            } else {
+                0
+            };
+            assert_eq!(v, expected);
+        }
+    }
+
+    #[test]
+    fn invalid_args() {
+        let x = array![[1., 2.], [3., 4.]];
+        assert!(order_filter(&x, &Array2::from_elem((2, 3), true), 0).is_err());
+        assert!(order_filter(&x, &Array2::from_elem((3, 3), true), 9).is_err());
+        assert!(order_filter(&x, &Array2::from_elem((3, 3), false), 0).is_err());
+        let dyn_x = x.into_dyn();
+        let dyn_domain = Array1::from_elem(3, true).into_dyn();
+        assert!(order_filter(&dyn_x, &dyn_domain, 0).is_err());
+    }
+}
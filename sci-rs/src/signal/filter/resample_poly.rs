@@ -0,0 +1,539 @@
+use super::{
+    check_and_get_axis_dyn,
+    design::{firwin_dyn, FilterBandType},
+    upfirdn_lane, upfirdn_output_len, UpFirDnMode,
+};
+use crate::{
+    signal::windows::{get_window, GetWindowBuilder},
+    special::Bessel,
+};
+use alloc::vec::Vec;
+use nalgebra::RealField;
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension};
+use num_traits::{Float, MulAdd, Pow};
+use sci_rs_core::{Error, Result};
+
+/// Lowpass FIR filter applied by [resample_poly].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResamplePolyWindow<'a, F>
+where
+    F: Float,
+{
+    /// Design the filter with [firwin_dyn] using this window.
+    Window(GetWindowBuilder<'a, F>),
+    /// Use these FIR filter coefficients directly.
+    Coefficients(&'a [F]),
+}
+
+/// Signal extension used by [resample_poly] beyond the boundaries of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResamplePolyPadType {
+    /// Pads with `cval`, which defaults to zero.
+    #[default]
+    Constant,
+    /// See [UpFirDnMode::Edge].
+    Edge,
+    /// See [UpFirDnMode::Symmetric].
+    Symmetric,
+    /// See [UpFirDnMode::Reflect].
+    Reflect,
+    /// See [UpFirDnMode::Wrap].
+    Wrap,
+    /// See [UpFirDnMode::Smooth].
+    Smooth,
+    /// See [UpFirDnMode::Antisymmetric].
+    Antisymmetric,
+    /// See [UpFirDnMode::Antireflect].
+    Antireflect,
+    /// See [UpFirDnMode::Line].
+    Line,
+    /// Removes the mean of the signal before zero padding and adds it back afterwards.
+    Mean,
+    /// Removes the median of the signal before zero padding and adds it back afterwards.
+    Median,
+    /// Removes the minimum of the signal before zero padding and adds it back afterwards.
+    Minimum,
+    /// Removes the maximum of the signal before zero padding and adds it back afterwards.
+    Maximum,
+}
+
+impl ResamplePolyPadType {
+    /// The equivalent extension mode, or `None` for the background removal options.
+    fn mode(self) -> Option<UpFirDnMode> {
+        Some(match self {
+            ResamplePolyPadType::Constant => UpFirDnMode::Constant,
+            ResamplePolyPadType::Edge => UpFirDnMode::Edge,
+            ResamplePolyPadType::Symmetric => UpFirDnMode::Symmetric,
+            ResamplePolyPadType::Reflect => UpFirDnMode::Reflect,
+            ResamplePolyPadType::Wrap => UpFirDnMode::Wrap,
+            ResamplePolyPadType::Smooth => UpFirDnMode::Smooth,
+            ResamplePolyPadType::Antisymmetric => UpFirDnMode::Antisymmetric,
+            ResamplePolyPadType::Antireflect => UpFirDnMode::Antireflect,
+            ResamplePolyPadType::Line => UpFirDnMode::Line,
+            _ => return None,
+        })
+    }
+
+    /// Background value of a lane that is removed before filtering.
+    fn background<F: Float>(self, lane: &[F]) -> F {
+        let fold = |f: fn(F, F) -> F| lane.iter().copied().reduce(f).unwrap_or_else(F::zero);
+        match self {
+            ResamplePolyPadType::Mean => fold(|a, b| a + b) / F::from(lane.len().max(1)).unwrap(),
+            ResamplePolyPadType::Median => {
+                let mut sorted = lane.to_vec();
+                sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+                let n = sorted.len();
+                if n == 0 {
+                    F::zero()
+                } else {
+                    (sorted[(n - 1) / 2] + sorted[n / 2]) / F::from(2).unwrap()
+                }
+            }
+            ResamplePolyPadType::Minimum => fold(F::min),
+            ResamplePolyPadType::Maximum => fold(F::max),
+            _ => F::zero(),
+        }
+    }
+}
+
+/// Resample `x` along the given axis using polyphase filtering.
+///
+/// The signal `x` is upsampled by the factor `up`, a zero-phase low-pass
+/// FIR filter is applied, and then it is downsampled by the factor `down`.
+/// The resulting sample rate is ``up / down`` times the original sample
+/// rate. By default, values beyond the boundary of the signal are assumed
+/// to be zero during the filtering step.
+///
+/// ## Parameters
+/// * `x`: array_like
+///   The data to be resampled.
+/// * `up`: int
+///   The upsampling factor.
+/// * `down`: int
+///   The downsampling factor.
+/// * `axis`: int, optional
+///   The axis of `x` that is resampled. Default is 0.
+/// * `window`: [ResamplePolyWindow], optional
+///   Desired window to use to design the low-pass filter, or the FIR filter
+///   coefficients to employ. Default is a Kaiser window with a `beta` of 5. See below for details.
+/// * `padtype`: [ResamplePolyPadType], optional
+///   `constant`, `line`, `mean`, `median`, `maximum`, `minimum` or any of
+///   the other signal extension modes supported by [upfirdn](super::upfirdn). Changes assumptions
+///   on values beyond the boundary. If `constant`, assumed to be `cval` (default zero).
+///   If `line` assumed to continue a linear trend defined by the first and last
+///   points. `mean`, `median`, `maximum` and `minimum` work as in `np.pad` and assume
+///   that the values beyond the boundary are the mean, median, maximum or minimum
+///   respectively of the array along the axis.
+/// * `cval`: float, optional
+///   Value to use if `padtype='constant'`. Default is zero.
+///
+/// ## Returns
+/// * `resampled_x`: array
+///   The resampled array.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `up` or `down` is zero, `axis` is out of range, the filter cannot be
+/// designed, or `x` is too short for `padtype`. [Error::ConflictArg] if `cval` is given with a
+/// `padtype` other than [ResamplePolyPadType::Constant].
+///
+/// ## Notes
+/// This polyphase method will likely be faster than the Fourier method
+/// in `scipy.signal.resample` when the number of samples is large and
+/// prime, or when the number of samples is large and `up` and `down`
+/// share a large greatest common denominator. The length of the FIR
+/// filter used will depend on ``max(up, down) // gcd(up, down)``, and
+/// the number of operations during polyphase filtering will depend on
+/// the filter length and `down` (see [upfirdn](super::upfirdn) for details).
+///
+/// The argument `window` specifies the FIR low-pass filter design.
+///
+/// If `window` is a [ResamplePolyWindow::Window], it is passed to [get_window] and
+/// [firwin_dyn] to generate the appropriate filter coefficients.
+///
+/// If `window` is [ResamplePolyWindow::Coefficients], it is assumed to be the FIR filter
+/// coefficients. Note that the FIR filter is applied after the upsampling
+/// step, so it should be designed to operate on a signal at a sampling
+/// frequency higher than the original by a factor of `up//gcd(up, down)`.
+/// This function's output will be centered with respect to this array, so it
+/// is best to pass a symmetric filter with an odd number of samples if, as
+/// is usually the case, a zero-phase filter is desired.
+///
+/// For any other type of `window`, the functions `scipy.signal.get_window`
+/// and `scipy.signal.firwin` are called to generate the appropriate filter
+/// coefficients.
+///
+/// The first sample of the returned vector is the same as the first
+/// sample of the input vector. The spacing between samples is changed
+/// from ``dx`` to ``dx * down / float(up)``.
+///
+/// ## Examples
+/// By default, the end of the resampled data rises to meet the first
+/// sample of the next cycle for the FFT method, and gets closer to zero
+/// for the polyphase method:
+///
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy import signal
+/// >>> x = np.linspace(0, 10, 20, endpoint=False)
+/// >>> y = np.cos(-x**2/6.0)
+/// >>> f_poly = signal.resample_poly(y, 100, 20)
+/// >>> f_poly.shape
+/// (100,)
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::Array1;
+/// use sci_rs::signal::filter::resample_poly;
+///
+/// let x = Array1::linspace(0., 9.5, 20);
+/// let y = x.mapv(|x: f64| (-x * x / 6.).cos());
+/// let f_poly = resample_poly(&y, 100, 20, None, None, None, None).unwrap();
+/// assert_eq!(f_poly.len(), 100);
+/// assert!((f_poly[0] - y[0]).abs() < 0.02);
+/// ```
+pub fn resample_poly<F, S, D>(
+    x: &ArrayBase<S, D>,
+    up: usize,
+    down: usize,
+    axis: Option<isize>,
+    window: Option<ResamplePolyWindow<F>>,
+    padtype: Option<ResamplePolyPadType>,
+    cval: Option<F>,
+) -> Result<Array<F, D>>
+where
+    F: Float + RealField + Bessel + MulAdd<Output = F> + Pow<F, Output = F>,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    if up < 1 || down < 1 {
+        return Err(Error::InvalidArg {
+            arg: "up".into(),
+            reason: "up and down must be >= 1".into(),
+        });
+    }
+    let padtype = padtype.unwrap_or_default();
+    if cval.is_some() && padtype != ResamplePolyPadType::Constant {
+        return Err(Error::ConflictArg {
+            reason: "cval has no effect when padtype is not Constant".into(),
+        });
+    }
+    let axis = Axis(check_and_get_axis_dyn(Some(axis.unwrap_or(0)), x)?);
+
+    // Determine our up and down factors.
+    let g = gcd(up, down);
+    let (up, down) = (up / g, down / g);
+    if up == 1 && down == 1 {
+        return Ok(x.to_owned());
+    }
+    let n_in = x.len_of(axis);
+    let n_out = (n_in * up).div_ceil(down);
+
+    let (mut h, half_len) = match window {
+        Some(ResamplePolyWindow::Coefficients(h)) => {
+            if h.is_empty() {
+                return Err(Error::InvalidArg {
+                    arg: "window".into(),
+                    reason: "window must not be empty".into(),
+                });
+            }
+            (h.to_vec(), (h.len() - 1) / 2)
+        }
+        window => {
+            let window = match window {
+                Some(ResamplePolyWindow::Window(w)) => w,
+                _ => GetWindowBuilder::Kaiser {
+                    beta: F::from_f64(5.).unwrap(),
+                },
+            };
+            // Design a linear-phase low-pass FIR filter.
+            let max_rate = up.max(down);
+            let f_c = F::one() / F::from_usize(max_rate).unwrap();
+            // Reasonable cutoff for our sinc-like function.
+            let half_len = 10 * max_rate;
+            let numtaps = 2 * half_len + 1;
+            let window = get_window(window, numtaps, Some(false));
+            let h: Vec<F> = firwin_dyn(
+                numtaps,
+                &[f_c],
+                None,
+                Some(&window),
+                &FilterBandType::Lowpass,
+                None,
+                None,
+            )
+            .map_err(crate::error::Error::into_core)?;
+            (h, half_len)
+        }
+    };
+    let up_f = F::from_usize(up).unwrap();
+    h.iter_mut().for_each(|h| *h *= up_f);
+
+    // Zero-pad our filter to put the output samples at the center.
+    let n_pre_pad = down - half_len % down;
+    let n_pre_remove = (half_len + n_pre_pad) / down;
+    let mut n_post_pad = 0;
+    // We should rarely need to do this given our filter lengths...
+    while n_in > 0
+        && upfirdn_output_len(h.len() + n_pre_pad + n_post_pad, n_in, up, down)
+            < n_out + n_pre_remove
+    {
+        n_post_pad += 1;
+    }
+    let h: Vec<F> = core::iter::repeat_n(F::zero(), n_pre_pad)
+        .chain(h)
+        .chain(core::iter::repeat_n(F::zero(), n_post_pad))
+        .collect();
+
+    let mode = padtype.mode().unwrap_or_default();
+    if mode.needs_two_samples() && n_in < 2 {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "padtype requires at least two samples along axis".into(),
+        });
+    }
+    let cval = cval.unwrap_or_else(F::zero);
+
+    let mut out_dim = x.raw_dim();
+    out_dim[axis.index()] = n_out;
+    let mut y = Array::zeros(out_dim);
+    for (lane, mut out) in x.lanes(axis).into_iter().zip(y.lanes_mut(axis)) {
+        // Remove background depending on the padtype option.
+        let mut lane = lane.to_vec();
+        let background = padtype.background(&lane);
+        lane.iter_mut().for_each(|x| *x -= background);
+        // Filter, computing only the outputs that are kept.
+        upfirdn_lane(
+            &h,
+            (&lane).into(),
+            up,
+            down,
+            mode,
+            cval,
+            n_pre_remove,
+            out.view_mut(),
+        );
+        // Add background back.
+        out.mapv_inplace(|y| y + background);
+    }
+    Ok(y)
+}
+
+/// Greatest common divisor of `a` and `b`.
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{arr1, Array1, Array2};
+
+    fn x() -> Array1<f64> {
+        arr1(&[
+            1.0,
+            1.299132070024,
+            1.586143231563,
+            1.830507621912,
+            1.985887260777,
+            2.004782214436,
+            1.870737201668,
+            1.646337918818,
+            1.510673431787,
+            1.727116054021,
+            2.480964374615,
+            3.623359604559,
+            4.56017028665,
+            4.625881398522,
+            3.892385419504,
+            3.501238666889,
+            4.476990601625,
+            5.965472288966,
+            5.99492066331,
+            4.913883955782,
+        ])
+    }
+
+    #[track_caller]
+    fn assert_vec_eq<D: Dimension>(a: Array<f64, D>, b: Array<f64, D>) {
+        assert_eq!(a.shape(), b.shape());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn default_window() {
+        let expected = arr1(&[
+            1.000606173554,
+            1.279561541249,
+            1.343535076959,
+            1.587104709642,
+            1.782227882537,
+            1.874106087569,
+            1.987091053115,
+            2.026473996826,
+            1.967616893167,
+            1.871871193085,
+            1.724291650057,
+            1.575193094375,
+            1.51158916207,
+            1.599324252957,
+            1.915736052787,
+            2.482468269607,
+            3.237346545757,
+            3.986232778404,
+            4.562934541279,
+            4.750023922844,
+            4.394699147459,
+            3.894744880607,
+            3.578740976416,
+            3.571863749619,
+            4.479704434928,
+            5.72709039837,
+            5.953327996055,
+            5.998554625673,
+            5.860583942891,
+            3.287027642909,
+        ]);
+        assert_vec_eq(
+            resample_poly(&x(), 3, 2, None, None, None, None).unwrap(),
+            expected,
+        );
+
+        let expected = arr1(&[
+            0.782573405831,
+            1.760959178737,
+            2.000871995267,
+            1.530200214934,
+            2.542506987978,
+            4.603035001486,
+            3.716150519603,
+            6.102222756713,
+        ]);
+        // Common factors of up and down are removed.
+        assert_vec_eq(
+            resample_poly(&x(), 4, 10, None, None, None, None).unwrap(),
+            expected,
+        );
+    }
+
+    #[test]
+    fn padtypes() {
+        let window = ResamplePolyWindow::Coefficients(&[0.1, 0.25, 0.3, 0.25, 0.1]);
+        let interior = [
+            1.442637650793,
+            1.812710671615,
+            1.995334737606,
+            1.852666347651,
+            1.578505675302,
+            1.834597193693,
+            3.052161989587,
+            4.385950372606,
+            4.259133409013,
+            3.774618404359,
+            5.221231445295,
+            5.772823646936,
+        ];
+        let cases = [
+            (
+                ResamplePolyPadType::Constant,
+                None,
+                0.859826414005,
+                2.456941977891,
+            ),
+            (
+                ResamplePolyPadType::Constant,
+                Some(1.5),
+                1.159826414005,
+                3.206941977891,
+            ),
+            (
+                ResamplePolyPadType::Line,
+                None,
+                1.018627635523,
+                5.016880901987,
+            ),
+            (
+                ResamplePolyPadType::Edge,
+                None,
+                1.059826414005,
+                4.913883955782,
+            ),
+            (
+                ResamplePolyPadType::Mean,
+                None,
+                1.464792256659,
+                3.969356584527,
+            ),
+            (
+                ResamplePolyPadType::Median,
+                None,
+                1.30840107291,
+                3.578378625154,
+            ),
+            (
+                ResamplePolyPadType::Maximum,
+                None,
+                2.058810546667,
+                5.454402309546,
+            ),
+            (
+                ResamplePolyPadType::Minimum,
+                None,
+                1.059826414005,
+                2.956941977891,
+            ),
+        ];
+        for (padtype, cval, first, last) in cases {
+            let y =
+                resample_poly(&x(), 2, 3, None, Some(window.clone()), Some(padtype), cval).unwrap();
+            let mut expected = vec![first];
+            expected.extend(interior);
+            expected.push(last);
+            assert_vec_eq(y, Array1::from_vec(expected));
+        }
+    }
+
+    #[test]
+    fn axis() {
+        let x2 = Array2::from_shape_fn((2, 20), |(i, j)| x()[j] * (i + 1) as f64);
+        let y = resample_poly(&x2, 3, 2, Some(-1), None, None, None).unwrap();
+        let y1 = resample_poly(&x(), 3, 2, None, None, None, None).unwrap();
+        assert_eq!(y.dim(), (2, 30));
+        for (j, y1) in y1.iter().enumerate() {
+            assert_abs_diff_eq!(y[[0, j]], y1, epsilon = 1e-12);
+            assert_abs_diff_eq!(y[[1, j]], 2. * y1, epsilon = 1e-12);
+        }
+        // The default axis is 0.
+        assert_eq!(
+            resample_poly(&x2, 3, 2, None, None, None, None)
+                .unwrap()
+                .dim(),
+            (3, 20)
+        );
+    }
+
+    #[test]
+    fn identity_and_errors() {
+        assert_vec_eq(
+            resample_poly(&x(), 3, 3, None, None, None, None).unwrap(),
+            x(),
+        );
+        assert!(resample_poly(&x(), 0, 3, None, None, None, None).is_err());
+        assert!(resample_poly(
+            &x(),
+            2,
+            3,
+            None,
+            None,
+            Some(ResamplePolyPadType::Mean),
+            Some(1.)
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,507 @@
+use super::check_and_get_axis_dyn;
+use ndarray::{Array, ArrayBase, ArrayView1, ArrayViewMut1, Axis, Data, Dimension};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Signal extension mode used by [upfirdn] beyond the boundaries of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UpFirDnMode {
+    /// Pads with a constant value, `cval`.
+    #[default]
+    Constant,
+    /// Pads by repeating the edge values.
+    Edge,
+    /// Pads with the reflection of the signal mirrored about the edge, including the edge sample.
+    Symmetric,
+    /// Pads with the reflection of the signal mirrored about the edge sample.
+    Reflect,
+    /// Pads by wrapping around to the other end of the signal.
+    Wrap,
+    /// Pads with a linear extrapolation of the two samples nearest each edge.
+    Smooth,
+    /// Pads with the negated [UpFirDnMode::Symmetric] reflection.
+    Antisymmetric,
+    /// Pads with the point reflection of the signal about each edge sample.
+    Antireflect,
+    /// Pads with the line joining the first and last samples.
+    Line,
+}
+
+impl UpFirDnMode {
+    /// Whether this mode needs at least two samples to extend a signal.
+    pub(crate) fn needs_two_samples(self) -> bool {
+        matches!(
+            self,
+            UpFirDnMode::Reflect
+                | UpFirDnMode::Smooth
+                | UpFirDnMode::Antireflect
+                | UpFirDnMode::Line
+        )
+    }
+
+    /// Value of the extended signal at `idx`, which lies outside of `x`.
+    fn extend<F: Float>(self, x: &ArrayView1<F>, idx: isize, cval: F) -> F {
+        let n = x.len() as isize;
+        let at = |i: isize| x[i as usize];
+        let cast = |i: isize| F::from(i).unwrap();
+        if idx < 0 {
+            match self {
+                UpFirDnMode::Constant => cval,
+                UpFirDnMode::Edge => at(0),
+                UpFirDnMode::Symmetric | UpFirDnMode::Antisymmetric => {
+                    let sign = if self == UpFirDnMode::Symmetric {
+                        F::one()
+                    } else {
+                        -F::one()
+                    };
+                    if -idx < n {
+                        return sign * at(-idx - 1);
+                    }
+                    // The pattern repeats with a period of 2 * n.
+                    let idx = (-idx - 1) % (2 * n);
+                    if idx < n {
+                        sign * at(idx)
+                    } else {
+                        at(n - 1 - (idx - n))
+                    }
+                }
+                UpFirDnMode::Reflect => {
+                    if -idx < n - 1 {
+                        return at(-idx);
+                    }
+                    // The pattern repeats with a period of 2 * (n - 1).
+                    let idx = (-idx - 1) % (2 * (n - 1));
+                    if idx < n - 1 {
+                        at(idx + 1)
+                    } else {
+                        at(n - 2 - (idx - (n - 1)))
+                    }
+                }
+                UpFirDnMode::Wrap => at(n - (-idx - 1) % n - 1),
+                UpFirDnMode::Smooth => at(0) + cast(idx) * (at(1) - at(0)),
+                UpFirDnMode::Line => at(0) + cast(idx) * (at(n - 1) - at(0)) / cast(n - 1),
+                UpFirDnMode::Antireflect => {
+                    if -idx < n {
+                        return at(0) - (at(-idx) - at(0));
+                    }
+                    let le = at(0) + (at(0) - at(n - 1)) * cast((-idx - 1) / (n - 1));
+                    let idx = (-idx - 1) % (2 * (n - 1));
+                    if idx < n - 1 {
+                        le - (at(idx + 1) - at(0))
+                    } else {
+                        le - (at(n - 1) - at(n - 2 - (idx - (n - 1))))
+                    }
+                }
+            }
+        } else {
+            match self {
+                UpFirDnMode::Constant => cval,
+                UpFirDnMode::Edge => at(n - 1),
+                UpFirDnMode::Symmetric | UpFirDnMode::Antisymmetric => {
+                    let sign = if self == UpFirDnMode::Symmetric {
+                        F::one()
+                    } else {
+                        -F::one()
+                    };
+                    if idx < 2 * n {
+                        return sign * at(2 * n - idx - 1);
+                    }
+                    let idx = idx % (2 * n);
+                    if idx < n {
+                        at(idx)
+                    } else {
+                        sign * at(n - 1 - (idx - n))
+                    }
+                }
+                UpFirDnMode::Reflect => {
+                    if idx < 2 * n - 1 {
+                        return at(2 * n - idx - 2);
+                    }
+                    let idx = idx % (2 * (n - 1));
+                    if idx < n - 1 {
+                        at(idx)
+                    } else {
+                        at(n - 1 - (idx - (n - 1)))
+                    }
+                }
+                UpFirDnMode::Wrap => at(idx % n),
+                UpFirDnMode::Smooth => at(n - 1) + cast(idx - n + 1) * (at(n - 1) - at(n - 2)),
+                UpFirDnMode::Line => {
+                    at(n - 1) + cast(idx - n + 1) * (at(n - 1) - at(0)) / cast(n - 1)
+                }
+                UpFirDnMode::Antireflect => {
+                    if idx < 2 * n - 1 {
+                        return at(n - 1) - (at(2 * n - idx - 2) - at(n - 1));
+                    }
+                    let re = at(n - 1) + (at(n - 1) - at(0)) * cast(idx / (n - 1) - 1);
+                    let idx = idx % (2 * (n - 1));
+                    if idx < n - 1 {
+                        re + (at(idx) - at(0))
+                    } else {
+                        re + (at(n - 1) - at(n - 1 - (idx - (n - 1))))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Upsample, FIR filter, and downsample.
+///
+/// ## Parameters
+/// * `h`: array_like
+///   1-D FIR (finite-impulse response) filter coefficients.
+/// * `x`: array_like
+///   Input signal array.
+/// * `up`: int, optional
+///   Upsampling rate. Default is 1.
+/// * `down`: int, optional
+///   Downsampling rate. Default is 1.
+/// * `axis`: int, optional
+///   The axis of the input data array along which to apply the
+///   linear filter. The filter is applied to each subarray along
+///   this axis. Default is -1.
+/// * `mode`: [UpFirDnMode], optional
+///   The signal extension mode to use. The set
+///   ``{"constant", "symmetric", "reflect", "edge", "wrap"}`` correspond to
+///   modes provided by `numpy.pad`. ``"smooth"`` implements a smooth
+///   extension by extending based on the slope of the last 2 points at each
+///   end of the array. ``"antireflect"`` and ``"antisymmetric"`` are
+///   anti-symmetric versions of ``"reflect"`` and ``"symmetric"``. The mode
+///   `"line"` extends the signal based on a linear trend defined by the
+///   first and last points along the ``axis``. Default is
+///   [UpFirDnMode::Constant].
+/// * `cval`: float, optional
+///   The constant value to use when ``mode == "constant"``. Default is 0.
+///
+/// ## Returns
+/// * `y`: ndarray
+///   The output signal array. Dimensions will be the same as `x` except
+///   for along `axis`, which will change size according to the `h`,
+///   `up`,  and `down` parameters.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `h` is empty, `up` or `down` is zero, `axis` is out of range, or `x`
+/// has too few samples along `axis` to be extended with `mode`.
+///
+/// ## Notes
+/// The algorithm is an implementation of the block diagram shown on page 129
+/// of the Vaidyanathan text \[1\] (Figure 4.3-8d).
+///
+/// The direct approach of upsampling by factor of P with zero insertion,
+/// FIR filtering of length ``N``, and downsampling by factor of Q is
+/// O(N*Q) per output sample. The polyphase implementation used here is
+/// O(N/P).
+///
+/// ## References
+/// \[1\] P. P. Vaidyanathan, Multirate Systems and Filter Banks,
+///     Prentice Hall, 1993.
+///
+/// ## Examples
+/// Simple operations:
+///
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy.signal import upfirdn
+/// >>> upfirdn([1, 1, 1], [1, 1, 1])   # FIR filter
+/// array([ 1.,  2.,  3.,  2.,  1.])
+/// >>> upfirdn([1], [1, 2, 3], 3)  # upsampling with zeros insertion
+/// array([ 1.,  0.,  0.,  2.,  0.,  0.,  3.])
+/// >>> upfirdn([1, 1, 1], [1, 2, 3], 3)  # upsampling with sample-and-hold
+/// array([ 1.,  1.,  1.,  2.,  2.,  2.,  3.,  3.,  3.])
+/// >>> upfirdn([.5, 1, .5], [1, 1, 1], 2)  # linear interpolation
+/// array([ 0.5,  1. ,  1. ,  1. ,  1. ,  1. ,  0.5])
+/// >>> upfirdn([1], np.arange(10), 1, 3)  # decimation by 3
+/// array([ 0.,  3.,  6.,  9.])
+/// >>> upfirdn([.5, 1, .5], np.arange(10), 2, 3)  # linear interp, rate 2/3
+/// array([ 0. ,  1. ,  2.5,  4. ,  5.5,  7. ,  8.5])
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::{array, Array1};
+/// use sci_rs::signal::filter::upfirdn;
+///
+/// let ones = array![1., 1., 1.];
+/// let y = upfirdn(&[1., 1., 1.], &ones, None, None, None, None, None).unwrap();
+/// assert_eq!(y, array![1., 2., 3., 2., 1.]);
+/// let y = upfirdn(&[1.], &array![1., 2., 3.], Some(3), None, None, None, None).unwrap();
+/// assert_eq!(y, array![1., 0., 0., 2., 0., 0., 3.]);
+/// let y = upfirdn(&[1., 1., 1.], &array![1., 2., 3.], Some(3), None, None, None, None).unwrap();
+/// assert_eq!(y, array![1., 1., 1., 2., 2., 2., 3., 3., 3.]);
+/// let y = upfirdn(&[0.5, 1., 0.5], &ones, Some(2), None, None, None, None).unwrap();
+/// assert_eq!(y, array![0.5, 1., 1., 1., 1., 1., 0.5]);
+///
+/// let x = Array1::range(0., 10., 1.);
+/// let y = upfirdn(&[1.], &x, None, Some(3), None, None, None).unwrap();
+/// assert_eq!(y, array![0., 3., 6., 9.]);
+/// let y = upfirdn(&[0.5, 1., 0.5], &x, Some(2), Some(3), None, None, None).unwrap();
+/// assert_eq!(y, array![0., 1., 2.5, 4., 5.5, 7., 8.5]);
+/// ```
+pub fn upfirdn<F, S, D>(
+    h: &[F],
+    x: &ArrayBase<S, D>,
+    up: Option<usize>,
+    down: Option<usize>,
+    axis: Option<isize>,
+    mode: Option<UpFirDnMode>,
+    cval: Option<F>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let up = up.unwrap_or(1);
+    let down = down.unwrap_or(1);
+    if h.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "h".into(),
+            reason: "h must be 1-D with non-zero length".into(),
+        });
+    }
+    if up < 1 || down < 1 {
+        return Err(Error::InvalidArg {
+            arg: "up".into(),
+            reason: "Both up and down must be >= 1".into(),
+        });
+    }
+    let axis = Axis(check_and_get_axis_dyn(axis, x)?);
+    let mode = mode.unwrap_or_default();
+    let n_in = x.len_of(axis);
+    if (mode != UpFirDnMode::Constant && n_in < 1) || (mode.needs_two_samples() && n_in < 2) {
+        return Err(Error::InvalidArg {
+            arg: "mode".into(),
+            reason: "x has too few samples along axis to be extended with mode".into(),
+        });
+    }
+
+    let mut out_dim = x.raw_dim();
+    out_dim[axis.index()] = upfirdn_output_len(h.len(), n_in, up, down);
+    let mut y = Array::zeros(out_dim);
+    let cval = cval.unwrap_or_else(F::zero);
+    for (lane, out) in x.lanes(axis).into_iter().zip(y.lanes_mut(axis)) {
+        upfirdn_lane(h, lane, up, down, mode, cval, 0, out);
+    }
+    Ok(y)
+}
+
+/// Length of the full output of upsampling `in_len` samples by `up`, filtering with `len_h` taps
+/// and downsampling by `down`.
+#[inline]
+pub(crate) fn upfirdn_output_len(len_h: usize, in_len: usize, up: usize, down: usize) -> usize {
+    (((in_len as isize - 1) * up as isize + len_h as isize - 1).div_euclid(down as isize) + 1)
+        .max(0) as usize
+}
+
+/// Polyphase upsample, FIR filter and downsample of a single lane.
+///
+/// Writes samples `offset..offset + out.len()` of the full output into `out`. Only the taps of
+/// the polyphase component that lines up with the input are evaluated for each output sample,
+/// so neither the zero-stuffed signal nor the discarded outputs are ever computed.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn upfirdn_lane<F: Float>(
+    h: &[F],
+    x: ArrayView1<F>,
+    up: usize,
+    down: usize,
+    mode: UpFirDnMode,
+    cval: F,
+    offset: usize,
+    mut out: ArrayViewMut1<F>,
+) {
+    let len_x = x.len() as isize;
+    let len_h = h.len() as isize;
+    let up_i = up as isize;
+    let zero_pad = mode == UpFirDnMode::Constant && cval.is_zero();
+    for (i, y) in out.iter_mut().enumerate() {
+        let n = ((offset + i) * down) as isize;
+        // Input samples m contribute through tap n - m * up, which must lie in [0, len_h).
+        let m_hi = n / up_i;
+        let m_lo = (n - len_h).div_euclid(up_i) + 1;
+        let (m_lo, m_hi) = if zero_pad {
+            (m_lo.max(0), m_hi.min(len_x - 1))
+        } else {
+            (m_lo, m_hi)
+        };
+        *y = (m_lo..=m_hi).fold(F::zero(), |acc, m| {
+            let xm = if (0..len_x).contains(&m) {
+                x[m as usize]
+            } else {
+                mode.extend(&x, m, cval)
+            };
+            acc + h[(n - m * up_i) as usize] * xm
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{arr1, Array1, Array2};
+
+    #[track_caller]
+    fn assert_vec_eq<D: Dimension>(a: Array<f64, D>, b: Array<f64, D>) {
+        assert_eq!(a.shape(), b.shape());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn modes() {
+        let h = [0.25, 0.5, 1., 0.5, 0.25, 0.125, 0.3];
+        let x = arr1(&[1., 3., 2., 5., 4., 0.5]);
+        let interior = [2.625, 4.3, 3.875, 7.4, 4.75, 5.975, 2.875];
+        let cases = [
+            (
+                UpFirDnMode::Constant,
+                [0.25, 0.5, 1.75, 2.0, 3.75],
+                [3.0, 0.75, 1.325, 0.0625, 0.15],
+            ),
+            (
+                UpFirDnMode::Symmetric,
+                [2.6, 1.375, 2.9, 2.125, 4.05],
+                [3.125, 1.0, 2.825, 2.3125, 5.525],
+            ),
+            (
+                UpFirDnMode::Reflect,
+                [5.25, 2.25, 3.1, 2.375, 4.65],
+                [4.0, 2.75, 6.575, 4.5625, 6.65],
+            ),
+            (
+                UpFirDnMode::Edge,
+                [1.8, 1.125, 2.3, 2.125, 4.05],
+                [3.125, 1.0, 1.95, 0.5625, 0.9],
+            ),
+            (
+                UpFirDnMode::Wrap,
+                [3.25, 1.25, 3.075, 2.0625, 3.9],
+                [3.25, 1.25, 3.075, 2.0625, 3.9],
+            ),
+            (
+                UpFirDnMode::Smooth,
+                [-3.0, -0.375, 0.6, 1.875, 3.45],
+                [2.25, -0.75, -3.3, -4.6875, -9.6],
+            ),
+            (
+                UpFirDnMode::Antisymmetric,
+                [-2.1, -0.375, 0.6, 1.875, 3.45],
+                [2.875, 0.5, -0.175, -2.1875, -5.225],
+            ),
+            (
+                UpFirDnMode::Antireflect,
+                [-1.65, 0.0, 1.5, 1.875, 3.45],
+                [2.25, -0.75, -2.675, -3.4375, -4.85],
+            ),
+            (
+                UpFirDnMode::Line,
+                [2.04, 1.2, 2.385, 2.1375, 4.08],
+                [3.1, 0.95, 1.8, 0.4125, 0.6],
+            ),
+        ];
+        for (mode, head, tail) in cases {
+            let y = upfirdn(&h, &x, Some(2), None, None, Some(mode), None).unwrap();
+            let expected: Vec<f64> = head.into_iter().chain(interior).chain(tail).collect();
+            assert_vec_eq(y, Array1::from_vec(expected));
+        }
+    }
+
+    #[test]
+    fn repeated_extension() {
+        // Filters much longer than the signal extend it across several periods.
+        let h = [0.5, 0.25, 1., 0.5, 0.25, 0.125, 0.3, 0.2, 0.1, 0.7, 0.6];
+        let x = arr1(&[1., 3., 2.]);
+        let cases = [
+            (
+                UpFirDnMode::Symmetric,
+                [
+                    9.025, 9.5, 8.05, 8.225, 10.075, 9.425, 9.025, 9.5, 8.05, 8.225, 10.075, 9.425,
+                    9.025,
+                ],
+            ),
+            (
+                UpFirDnMode::Reflect,
+                [
+                    9.975, 10.725, 8.925, 11.1, 9.975, 10.725, 8.925, 11.1, 9.975, 10.725, 8.925,
+                    11.1, 9.975,
+                ],
+            ),
+            (
+                UpFirDnMode::Wrap,
+                [
+                    8.275, 9.75, 9.125, 8.275, 9.75, 9.125, 8.275, 9.75, 9.125, 8.275, 9.75, 9.125,
+                    8.275,
+                ],
+            ),
+            (
+                UpFirDnMode::Antisymmetric,
+                [
+                    -3.275, -3.3, 0.05, 2.575, 3.225, 0.725, -3.275, -3.3, 0.05, 2.575, 3.225,
+                    0.725, -3.275,
+                ],
+            ),
+            (
+                UpFirDnMode::Antireflect,
+                [
+                    -6.875, -5.625, -1.225, 2.05, 2.175, 3.425, 7.825, 11.1, 11.225, 12.475,
+                    16.875, 20.15, 20.275,
+                ],
+            ),
+            (
+                UpFirDnMode::Smooth,
+                [
+                    -38.825, -29.775, -22.225, -15.425, -11.625, -9.325, -7.775, -6.6, -6.325,
+                    -6.65, -7.275, -10.0, -14.525,
+                ],
+            ),
+            (
+                UpFirDnMode::Line,
+                [
+                    -6.3125, -3.3, -1.4125, 1.975, 3.4875, 5.375, 7.45, 9.975, 12.0875, 14.2,
+                    17.3625, 19.475, 20.8375,
+                ],
+            ),
+            (
+                UpFirDnMode::Edge,
+                [
+                    4.525, 5.525, 5.525, 7.275, 7.275, 7.275, 7.275, 7.75, 7.85, 7.85, 9.15, 9.65,
+                    9.05,
+                ],
+            ),
+        ];
+        for (mode, expected) in cases {
+            let y = upfirdn(&h, &x, None, None, None, Some(mode), None).unwrap();
+            assert_vec_eq(y, arr1(&expected));
+        }
+        let y = upfirdn(&h, &x, None, None, None, None, Some(2.)).unwrap();
+        assert_vec_eq(
+            y,
+            arr1(&[
+                8.55, 9.3, 8.3, 9.55, 9.3, 9.175, 8.875, 9.15, 9.15, 8.45, 9.15, 9.65, 9.05,
+            ]),
+        );
+    }
+
+    #[test]
+    fn axis() {
+        let x = Array2::from_shape_fn((10, 2), |(i, j)| (i * (j + 1)) as f64);
+        let y = upfirdn(&[0.5, 1., 0.5], &x, Some(2), Some(3), Some(0), None, None).unwrap();
+        assert_eq!(y.dim(), (7, 2));
+        let expected = arr1(&[0., 1., 2.5, 4., 5.5, 7., 8.5]);
+        assert_vec_eq(y.column(0).to_owned(), expected.clone());
+        assert_vec_eq(y.column(1).to_owned(), expected * 2.);
+    }
+
+    #[test]
+    fn invalid_args() {
+        let x = arr1(&[1., 2., 3.]);
+        assert!(upfirdn(&[], &x, None, None, None, None, None).is_err());
+        assert!(upfirdn(&[1.], &x, Some(0), None, None, None, None).is_err());
+        assert!(upfirdn(&[1.], &x, None, Some(0), None, None, None).is_err());
+        assert!(upfirdn(&[1.], &x, None, None, Some(1), None, None).is_err());
+        let x = arr1(&[1.]);
+        assert!(upfirdn(&[1.], &x, None, None, None, Some(UpFirDnMode::Line), None).is_err());
+    }
+}
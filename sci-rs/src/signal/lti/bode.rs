@@ -0,0 +1,286 @@
+use super::Lti;
+use alloc::vec::Vec;
+use nalgebra::{Complex, ComplexField, RealField};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Calculate the frequency response of a continuous-time system.
+///
+/// ## Parameters
+/// * `system`: [Lti]
+///   A continuous-time, single-input, single-output system.
+/// * `w`: array_like, optional
+///   Array of frequencies (in rad/s). Magnitude and phase data is calculated for every value in
+///   this array. If not given, a reasonable set will be calculated.
+/// * `n`: int, optional
+///   Number of frequency points to compute if `w` is not given. The `n` frequencies are
+///   logarithmically spaced in an interval chosen to include the influence of the poles and zeros
+///   of the system. Defaults to 10000.
+///
+/// ## Returns
+/// * `w`: 1D ndarray
+///   Frequency array [rad/s]
+/// * `H`: 1D ndarray
+///   Array of complex magnitude values
+///
+/// ## Errors
+/// [Error::InvalidArg] if `system` is discrete-time or not single-input, single-output.
+///
+/// ## Examples
+/// Generating the Nyquist plot of a transfer function
+/// ``H(s) = 5 / (s-1)^3``:
+/// ```custom,{class=language-python}
+/// >>> from scipy import signal
+/// >>> s1 = signal.ZerosPolesGain([], [1, 1, 1], [5])
+/// >>> w, H = signal.freqresp(s1, w=[1.0])
+/// >>> H
+/// array([1.25-1.25j])
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::Complex;
+/// use sci_rs::signal::lti::{freqresp, ZerosPolesGain};
+///
+/// let one = Complex::new(1.0, 0.0);
+/// let s1 = ZerosPolesGain::new(vec![], vec![one; 3], 5.0, None).unwrap();
+/// let (w, h) = freqresp(&s1, Some(&[1.0]), None).unwrap();
+/// assert!((h[0] - Complex::new(1.25, -1.25)).norm() < 1e-12);
+/// ```
+pub fn freqresp<F, L>(
+    system: &L,
+    w: Option<&[F]>,
+    n: Option<usize>,
+) -> Result<(Vec<F>, Vec<Complex<F>>)>
+where
+    F: Float + RealField,
+    L: Lti<F>,
+{
+    if system.dt().is_some() {
+        return Err(Error::InvalidArg {
+            arg: "system".into(),
+            reason: "freqresp can only be used with continuous-time systems.".into(),
+        });
+    }
+    // Only single-input, single-output systems have a zeros, poles, gain representation.
+    let zpk = system.to_zpk()?;
+    let w = match w {
+        Some(w) => w.to_vec(),
+        None => findfreqs(&zpk.zeros, &zpk.poles, n.unwrap_or(10000)),
+    };
+    let h = w
+        .iter()
+        .map(|&w| {
+            let s = Complex::new(F::zero(), w);
+            let num = zpk
+                .zeros
+                .iter()
+                .fold(Complex::new(zpk.gain, F::zero()), |acc, &z| acc * (s - z));
+            let den = zpk
+                .poles
+                .iter()
+                .fold(Complex::new(F::one(), F::zero()), |acc, &p| acc * (s - p));
+            num / den
+        })
+        .collect();
+    Ok((w, h))
+}
+
+/// Calculate Bode magnitude and phase data of a continuous-time system.
+///
+/// ## Parameters
+/// * `system`: [Lti]
+///   A continuous-time, single-input, single-output system.
+/// * `w`: array_like, optional
+///   Array of frequencies (in rad/s). Magnitude and phase data is calculated for every value in
+///   this array. If not given a reasonable set will be calculated.
+/// * `n`: int, optional
+///   Number of frequency points to compute if `w` is not given. The `n` frequencies are
+///   logarithmically spaced in an interval chosen to include the influence of the poles and zeros
+///   of the system. Defaults to 100.
+///
+/// ## Returns
+/// * `w`: 1D ndarray
+///   Frequency array [rad/s]
+/// * `mag`: 1D ndarray
+///   Magnitude array [dB]
+/// * `phase`: 1D ndarray
+///   Phase array [deg], unwrapped so that it has no jumps of more than 180 degrees.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `system` is discrete-time or not single-input, single-output.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> from scipy import signal
+/// >>> sys = signal.TransferFunction([1], [1, 1])
+/// >>> w, mag, phase = signal.bode(sys, w=[1.0])
+/// >>> mag, phase
+/// (array([-3.01029996]), array([-45.]))
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::signal::lti::{bode, TransferFunction};
+///
+/// let sys = TransferFunction::<f64>::new(&[1.], &[1., 1.], None).unwrap();
+/// let (w, mag, phase) = bode(&sys, Some(&[1.0]), None).unwrap();
+/// assert!((mag[0] + 3.01029996).abs() < 1e-8);
+/// assert!((phase[0] + 45.).abs() < 1e-12);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn bode<F, L>(system: &L, w: Option<&[F]>, n: Option<usize>) -> Result<(Vec<F>, Vec<F>, Vec<F>)>
+where
+    F: Float + RealField,
+    L: Lti<F>,
+{
+    let (w, y) = freqresp(system, w, Some(n.unwrap_or(100)))?;
+    let twenty = F::from(20).unwrap();
+    let mag = y.iter().map(|y| twenty * Float::log10(y.norm())).collect();
+    let mut phase: Vec<F> = y.iter().map(|y| Float::atan2(y.im, y.re)).collect();
+    unwrap_phase(&mut phase);
+    let to_deg = F::from(180).unwrap() / F::pi();
+    phase.iter_mut().for_each(|p| *p *= to_deg);
+    Ok((w, mag, phase))
+}
+
+/// Find an array of `n` logarithmically spaced frequencies that are useful for analog filter
+/// response plots of the zeros `z` and poles `p`.
+fn findfreqs<F: Float + RealField>(z: &[Complex<F>], p: &[Complex<F>], n: usize) -> Vec<F> {
+    let fallback = [Complex::new(F::from(-1000).unwrap(), F::zero())];
+    let ep = if p.is_empty() { &fallback[..] } else { p };
+    let limit = F::from(1e5).unwrap();
+    let ez: Vec<Complex<F>> = ep
+        .iter()
+        .filter(|p| p.im >= F::zero())
+        .chain(z.iter().filter(|z| z.norm() < limit && z.im >= F::zero()))
+        .copied()
+        .collect();
+    let integ = |e: &Complex<F>| -> F {
+        if e.norm() < F::from(1e-10).unwrap() {
+            F::one()
+        } else {
+            F::zero()
+        }
+    };
+    let (three, half, tenth, two) = (
+        F::from(3).unwrap(),
+        F::from(0.5).unwrap(),
+        F::from(0.1).unwrap(),
+        F::from(2).unwrap(),
+    );
+    let hmax = ez
+        .iter()
+        .map(|e| three * Float::abs(e.re + integ(e)) + F::from(1.5).unwrap() * e.im)
+        .fold(F::neg_infinity(), Float::max);
+    let lmin = ez
+        .iter()
+        .map(|e| Float::abs(e.re + integ(e)) + two * e.im)
+        .fold(F::infinity(), Float::min);
+    let hfreq = round_half_even(Float::log10(hmax) + half);
+    let lfreq = round_half_even(Float::log10(tenth * lmin) - half);
+
+    let ten = F::from(10).unwrap();
+    match n {
+        0 => Vec::new(),
+        1 => alloc::vec![Float::powf(ten, lfreq)],
+        n => (0..n)
+            .map(|i| {
+                let e = lfreq + (hfreq - lfreq) * F::from(i).unwrap() / F::from(n - 1).unwrap();
+                Float::powf(ten, e)
+            })
+            .collect(),
+    }
+}
+
+/// Round to the nearest integer, with halves rounded to even like `numpy.round`.
+fn round_half_even<F: Float>(x: F) -> F {
+    let r = x.round();
+    let half = F::from(0.5).unwrap();
+    if Float::abs(x - x.trunc()) == half {
+        let two = F::from(2).unwrap();
+        (x / two).round() * two
+    } else {
+        r
+    }
+}
+
+/// Unwrap radian phase in place by changing absolute jumps greater than pi to their 2*pi
+/// complement, like `numpy.unwrap`.
+fn unwrap_phase<F: Float + RealField>(p: &mut [F]) {
+    let (pi, two_pi) = (F::pi(), F::two_pi());
+    let mut correction = F::zero();
+    for i in 1..p.len() {
+        let d = p[i] - (p[i - 1] - correction);
+        let shifted = d + pi;
+        let mut dd = shifted - Float::floor(shifted / two_pi) * two_pi - pi;
+        if dd == -pi && d > F::zero() {
+            dd = pi;
+        }
+        if Float::abs(d) >= pi {
+            correction += dd - d;
+        }
+        p[i] += correction;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::lti::{TransferFunction, ZerosPolesGain};
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn default_frequencies() {
+        let sys = TransferFunction::new(&[1.], &[1., 1.], None).unwrap();
+        let (w, mag, phase) = bode(&sys, None, None).unwrap();
+        // Frequencies span 10^-2 to 10^1 around the pole at -1.
+        assert_eq!(w.len(), 100);
+        assert_abs_diff_eq!(w[0], 0.01, epsilon = 1e-12);
+        assert_abs_diff_eq!(w[99], 10., epsilon = 1e-10);
+        for ((w, mag), phase) in w.iter().zip(&mag).zip(&phase) {
+            assert_abs_diff_eq!(*mag, -10. * f64::log10(1. + w * w), epsilon = 1e-10);
+            assert_abs_diff_eq!(*phase, -f64::atan(*w).to_degrees(), epsilon = 1e-10);
+        }
+
+        let (w, h) = freqresp(&sys, None, None).unwrap();
+        assert_eq!(w.len(), 10000);
+        assert_eq!(h.len(), 10000);
+
+        // Poles near 100 rad/s with a zero in the origin.
+        let sys = ZerosPolesGain::new(
+            vec![Complex::new(0., 0.)],
+            vec![Complex::new(-20., 100.), Complex::new(-20., -100.)],
+            1.,
+            None,
+        )
+        .unwrap();
+        let (w, _) = freqresp(&sys, None, Some(3)).unwrap();
+        assert_abs_diff_eq!(w[0], 0.01, epsilon = 1e-12);
+        assert_abs_diff_eq!(w[2], 1000., epsilon = 1e-8);
+    }
+
+    #[test]
+    fn unwrapped_phase() {
+        // Three poles lag the phase by up to 270 degrees.
+        let sys = TransferFunction::new(&[1.], &[1., 3., 3., 1.], None).unwrap();
+        let w = [0.1, 1., 10., 100.];
+        let (_, mag, phase) = bode(&sys, Some(&w), None).unwrap();
+        for ((w, mag), phase) in w.iter().zip(&mag).zip(&phase) {
+            assert_abs_diff_eq!(*mag, -30. * f64::log10(1. + w * w), epsilon = 1e-8);
+            assert_abs_diff_eq!(*phase, -3. * f64::atan(*w).to_degrees(), epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn numpy_rounding() {
+        assert_eq!(round_half_even(2.5), 2.);
+        assert_eq!(round_half_even(-1.5), -2.);
+        assert_eq!(round_half_even(0.977), 1.);
+    }
+
+    #[test]
+    fn invalid_systems() {
+        let discrete = TransferFunction::new(&[1.], &[1., 1.], Some(0.1)).unwrap();
+        assert!(bode(&discrete, None, None).is_err());
+    }
+}
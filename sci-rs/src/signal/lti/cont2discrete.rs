@@ -0,0 +1,346 @@
+use super::{expm, Lti, StateSpace};
+use nalgebra::{DMatrix, RealField};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Discretization method used by [cont2discrete].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiscretizationMethod {
+    /// Generalized bilinear transformation, with the weighting parameter `alpha`.
+    Gbt,
+    /// Tustin's approximation, the generalized bilinear transformation with ``alpha = 0.5``.
+    Bilinear,
+    /// Euler (or forward differencing) method, the generalized bilinear transformation with
+    /// ``alpha = 0``.
+    Euler,
+    /// Backwards differencing, the generalized bilinear transformation with ``alpha = 1``.
+    BackwardDiff,
+    /// Zero-order hold.
+    #[default]
+    Zoh,
+    /// Linear interpolation, or first-order hold.
+    Foh,
+    /// Equivalent impulse response.
+    Impulse,
+}
+
+/// Transform a continuous to a discrete state-space system.
+///
+/// ## Parameters
+/// * `system`: [Lti]
+///   The continuous-time system to be converted, in any of the
+///   [TransferFunction](super::TransferFunction), [ZerosPolesGain](super::ZerosPolesGain) or
+///   [StateSpace] representations.
+/// * `dt`: float
+///   The discretization time step.
+/// * `method`: [DiscretizationMethod], optional
+///   Which method to use:
+///   * [DiscretizationMethod::Gbt]: generalized bilinear transformation
+///   * [DiscretizationMethod::Bilinear]: Tustin's approximation ("gbt" with alpha=0.5)
+///   * [DiscretizationMethod::Euler]: Euler (or forward differencing) method ("gbt" with
+///     alpha=0)
+///   * [DiscretizationMethod::BackwardDiff]: Backwards differencing ("gbt" with alpha=1.0)
+///   * [DiscretizationMethod::Zoh]: zero-order hold (default)
+///   * [DiscretizationMethod::Foh]: first-order hold
+///   * [DiscretizationMethod::Impulse]: equivalent impulse response
+/// * `alpha`: float within \[0, 1\], optional
+///   The generalized bilinear transformation weighting parameter, which should only be
+///   specified with method="gbt", and is ignored otherwise.
+///
+/// ## Returns
+/// * `sysd`: [StateSpace]
+///   The discretized system with sampling time `dt`. Use [Lti::to_tf] or [Lti::to_zpk] to get
+///   the same representation as a continuous-time `system` given in those forms.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `system` is already discrete-time, if `dt` is not positive, if `alpha`
+/// is not within \[0, 1\] for [DiscretizationMethod::Gbt], if the system has a direct
+/// feedthrough with [DiscretizationMethod::Impulse], or if ``I - alpha * dt * A`` is singular.
+///
+/// ## Notes
+/// By default, the zero-order hold method is used.
+///
+/// The generalized bilinear transformation approximates ``s`` with
+/// ``(z - 1) / (dt * (alpha * z + 1 - alpha))``, so that ``alpha = 0`` is the forward Euler
+/// method, ``alpha = 0.5`` is Tustin's method and ``alpha = 1`` is backward differencing.
+///
+/// The hold methods discretize the system exactly for inputs that are piecewise constant
+/// (zero-order hold) or piecewise linear (first-order hold) between samples, using the matrix
+/// exponential of an augmented state matrix. The first-order hold is non-causal in the sense
+/// that it needs the next input sample, which is why it introduces a direct feedthrough.
+///
+/// ## References
+/// \[1\] <https://en.wikipedia.org/wiki/Discretization#Discretization_of_linear_state_space_models>
+///
+/// \[2\] <http://techteach.no/publications/discretetime_signals_systems/discrete.pdf>
+///
+/// \[3\] G. Zhang, X. Chen, and T. Chen, Digital redesign via the generalized
+///     bilinear transformation, Int. J. Control, vol. 82, no. 4, pp. 741-754,
+///     2009. (<https://www.mypolyuweb.hk/~magzhang/Research/ZCC09_IJC.pdf>)
+///
+/// \[4\] G. F. Franklin, J. D. Powell, and M. L. Workman, Digital control
+///     of dynamic systems, 3rd ed. Menlo Park, Calif: Addison-Wesley,
+///     pp. 204-206, 1998.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> from scipy.signal import cont2discrete
+/// >>> cont2discrete(([1.], [1., 1.]), 0.1, method='zoh')
+/// (array([[0.        , 0.09516258]]), array([ 1.        , -0.90483742]), 0.1)
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::signal::lti::{cont2discrete, DiscretizationMethod, Lti, TransferFunction};
+///
+/// let system = TransferFunction::<f64>::new(&[1.], &[1., 1.], None).unwrap();
+/// let sysd = cont2discrete(&system, 0.1, Some(DiscretizationMethod::Zoh), None).unwrap();
+/// assert_eq!(sysd.dt, Some(0.1));
+/// let tf = sysd.to_tf().unwrap();
+/// assert!((tf.num[0] - 0.09516258).abs() < 1e-8);
+/// assert!((tf.den[1] + 0.90483742).abs() < 1e-8);
+/// ```
+pub fn cont2discrete<F, L>(
+    system: &L,
+    dt: F,
+    method: Option<DiscretizationMethod>,
+    alpha: Option<F>,
+) -> Result<StateSpace<F>>
+where
+    F: Float + RealField,
+    L: Lti<F>,
+{
+    if system.dt().is_some() {
+        return Err(Error::InvalidArg {
+            arg: "system".into(),
+            reason: "cont2discrete can only be used with continuous-time systems.".into(),
+        });
+    }
+    let sys = system.to_ss()?;
+    let (a, b, c, d) = (&sys.a, &sys.b, &sys.c, &sys.d);
+    let (n, m) = (a.nrows(), b.ncols());
+
+    let gbt = |alpha: F| -> Result<StateSpace<F>> {
+        // Solve the linear systems with I - alpha * dt * A instead of inverting it.
+        let singular = || Error::InvalidArg {
+            arg: "system".into(),
+            reason: "I - alpha * dt * A is singular.".into(),
+        };
+        let ident = DMatrix::identity(n, n);
+        let ima = &ident - a * (alpha * dt);
+        let lu = ima.clone().lu();
+        let ad = lu
+            .solve(&(&ident + a * ((F::one() - alpha) * dt)))
+            .ok_or_else(singular)?;
+        let bd = lu.solve(&(b * dt)).ok_or_else(singular)?;
+        // Similarly solve for the output equation matrices.
+        let cd = ima
+            .transpose()
+            .lu()
+            .solve(&c.transpose())
+            .ok_or_else(singular)?
+            .transpose();
+        let dd = d + c * &bd * alpha;
+        StateSpace::new(ad, bd, cd, dd, Some(dt))
+    };
+
+    match method.unwrap_or_default() {
+        DiscretizationMethod::Gbt => match alpha {
+            Some(alpha) if alpha >= F::zero() && alpha <= F::one() => gbt(alpha),
+            _ => Err(Error::InvalidArg {
+                arg: "alpha".into(),
+                reason: "Alpha parameter must be within the interval [0,1] for the gbt method"
+                    .into(),
+            }),
+        },
+        DiscretizationMethod::Bilinear => gbt(F::from_f64(0.5).unwrap()),
+        DiscretizationMethod::Euler => gbt(F::zero()),
+        DiscretizationMethod::BackwardDiff => gbt(F::one()),
+        DiscretizationMethod::Zoh => {
+            // Build an exponential matrix
+            //   [ A*dt  B*dt ]
+            //   [  0     0   ]
+            let mut em = DMatrix::zeros(n + m, n + m);
+            em.view_mut((0, 0), (n, n)).copy_from(&(a * dt));
+            em.view_mut((0, n), (n, m)).copy_from(&(b * dt));
+            let ms = expm(&em)?;
+
+            // Dispose of the lower rows
+            let ad = ms.view((0, 0), (n, n)).into_owned();
+            let bd = ms.view((0, n), (n, m)).into_owned();
+            StateSpace::new(ad, bd, c.clone(), d.clone(), Some(dt))
+        }
+        DiscretizationMethod::Foh => {
+            // Build an exponential matrix similar to the zero-order hold, with an additional
+            // integrator for the slope of the input
+            //   [ A*dt  B*dt  0 ]
+            //   [  0     0    I ]
+            //   [  0     0    0 ]
+            let mut em = DMatrix::zeros(n + 2 * m, n + 2 * m);
+            em.view_mut((0, 0), (n, n)).copy_from(&(a * dt));
+            em.view_mut((0, n), (n, m)).copy_from(&(b * dt));
+            em.view_mut((n, n + m), (m, m))
+                .copy_from(&DMatrix::identity(m, m));
+            let ms = expm(&em)?;
+
+            let ms11 = ms.view((0, 0), (n, n));
+            let ms12 = ms.view((0, n), (n, m));
+            let ms13 = ms.view((0, n + m), (n, m));
+
+            let ad = ms11.into_owned();
+            let bd = ms12 - ms13 + ms11 * ms13;
+            let cd = c.clone();
+            let dd = d + c * ms13;
+            StateSpace::new(ad, bd, cd, dd, Some(dt))
+        }
+        DiscretizationMethod::Impulse => {
+            if d.iter().any(|d| !d.is_zero()) {
+                return Err(Error::InvalidArg {
+                    arg: "system".into(),
+                    reason: "Impulse method is only applicable to strictly proper systems".into(),
+                });
+            }
+            let ad = expm(&(a * dt))?;
+            let bd = &ad * b * dt;
+            let dd = c * &bd;
+            StateSpace::new(ad, bd, c.clone(), dd, Some(dt))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::lti::TransferFunction;
+    use approx::assert_abs_diff_eq;
+
+    fn first_order() -> StateSpace<f64> {
+        // 1 / (s + 1)
+        StateSpace::new(
+            DMatrix::from_row_slice(1, 1, &[-1.]),
+            DMatrix::from_row_slice(1, 1, &[1.]),
+            DMatrix::from_row_slice(1, 1, &[1.]),
+            DMatrix::from_row_slice(1, 1, &[0.]),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[track_caller]
+    fn assert_abcd_eq(sys: &StateSpace<f64>, abcd: [f64; 4]) {
+        assert_eq!(sys.dt, Some(0.1));
+        for (m, e) in [&sys.a, &sys.b, &sys.c, &sys.d].into_iter().zip(abcd) {
+            assert_eq!(m.shape(), (1, 1));
+            assert_abs_diff_eq!(m[(0, 0)], e, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn gbt() {
+        let sys = first_order();
+        let cases = [
+            (DiscretizationMethod::Euler, [0.9, 0.1, 1., 0.]),
+            (
+                DiscretizationMethod::Bilinear,
+                [0.95 / 1.05, 0.1 / 1.05, 1. / 1.05, 0.05 / 1.05],
+            ),
+            (
+                DiscretizationMethod::BackwardDiff,
+                [1. / 1.1, 0.1 / 1.1, 1. / 1.1, 0.1 / 1.1],
+            ),
+        ];
+        for (method, abcd) in cases {
+            let sysd = cont2discrete(&sys, 0.1, Some(method), None).unwrap();
+            assert_abcd_eq(&sysd, abcd);
+        }
+        let sysd = cont2discrete(&sys, 0.1, Some(DiscretizationMethod::Gbt), Some(0.5)).unwrap();
+        assert_abcd_eq(&sysd, [0.95 / 1.05, 0.1 / 1.05, 1. / 1.05, 0.05 / 1.05]);
+    }
+
+    #[test]
+    fn hold() {
+        let sys = first_order();
+        let e = (-0.1f64).exp();
+        let sysd = cont2discrete(&sys, 0.1, None, None).unwrap();
+        assert_abcd_eq(&sysd, [e, 1. - e, 1., 0.]);
+        let sysd = cont2discrete(&sys, 0.1, Some(DiscretizationMethod::Foh), None).unwrap();
+        assert_abcd_eq(&sysd, [e, 0.09055917006062712, 1., 0.04837418035959575]);
+        let sysd = cont2discrete(&sys, 0.1, Some(DiscretizationMethod::Impulse), None).unwrap();
+        assert_abcd_eq(&sysd, [e, 0.1 * e, 1., 0.1 * e]);
+    }
+
+    #[test]
+    fn mimo() {
+        // Double integrator with two inputs, the zero-order hold is exact for constant inputs.
+        let sys = StateSpace::new(
+            DMatrix::from_row_slice(2, 2, &[0., 1., 0., 0.]),
+            DMatrix::from_row_slice(2, 2, &[0., 1., 1., 0.]),
+            DMatrix::from_row_slice(1, 2, &[1., 0.]),
+            DMatrix::from_row_slice(1, 2, &[0., 0.]),
+            None,
+        )
+        .unwrap();
+        let sysd = cont2discrete(&sys, 0.5, None, None).unwrap();
+        let expected_a = DMatrix::from_row_slice(2, 2, &[1., 0.5, 0., 1.]);
+        let expected_b = DMatrix::from_row_slice(2, 2, &[0.125, 0.5, 0.5, 0.]);
+        for (a, e) in sysd.a.iter().zip(expected_a.iter()) {
+            assert_abs_diff_eq!(a, e, epsilon = 1e-12);
+        }
+        for (b, e) in sysd.b.iter().zip(expected_b.iter()) {
+            assert_abs_diff_eq!(b, e, epsilon = 1e-12);
+        }
+        let sysd = cont2discrete(&sys, 0.5, Some(DiscretizationMethod::Foh), None).unwrap();
+        assert_eq!(sysd.d.shape(), (1, 2));
+        assert_abs_diff_eq!(sysd.d[(0, 0)], 0.5 * 0.5 / 6., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn transfer_function() {
+        let tf = TransferFunction::new(&[1.], &[1., 1.], None).unwrap();
+        let tfd = cont2discrete(&tf, 0.1, None, None)
+            .unwrap()
+            .to_tf()
+            .unwrap();
+        let e = (-0.1f64).exp();
+        assert_eq!(tfd.dt, Some(0.1));
+        assert_abs_diff_eq!(tfd.num[0], 1. - e, epsilon = 1e-12);
+        assert_abs_diff_eq!(tfd.den[0], 1., epsilon = 1e-12);
+        assert_abs_diff_eq!(tfd.den[1], -e, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn invalid_args() {
+        let sys = first_order();
+        let gbt = Some(DiscretizationMethod::Gbt);
+        assert!(cont2discrete(&sys, 0.1, gbt, None).is_err());
+        assert!(cont2discrete(&sys, 0.1, gbt, Some(1.5)).is_err());
+        assert!(cont2discrete(&sys, -0.1, None, None).is_err());
+        let sysd = cont2discrete(&sys, 0.1, None, None).unwrap();
+        assert!(cont2discrete(&sysd, 0.1, None, None).is_err());
+        // Singular I - dt * A
+        assert!(cont2discrete(&sys, 1., Some(DiscretizationMethod::BackwardDiff), None).is_ok());
+        let unstable = StateSpace::new(
+            DMatrix::from_row_slice(1, 1, &[1.]),
+            DMatrix::from_row_slice(1, 1, &[1.]),
+            DMatrix::from_row_slice(1, 1, &[1.]),
+            DMatrix::from_row_slice(1, 1, &[0.]),
+            None,
+        )
+        .unwrap();
+        assert!(cont2discrete(
+            &unstable,
+            1.,
+            Some(DiscretizationMethod::BackwardDiff),
+            None
+        )
+        .is_err());
+        let proper = StateSpace::new(
+            DMatrix::from_row_slice(1, 1, &[-1.]),
+            DMatrix::from_row_slice(1, 1, &[1.]),
+            DMatrix::from_row_slice(1, 1, &[1.]),
+            DMatrix::from_row_slice(1, 1, &[1.]),
+            None,
+        )
+        .unwrap();
+        assert!(cont2discrete(&proper, 0.1, Some(DiscretizationMethod::Impulse), None).is_err());
+    }
+}
@@ -0,0 +1,544 @@
+use crate::{linalg::companion_dyn, signal::filter::design::poly_dyn};
+use alloc::{vec, vec::Vec};
+use nalgebra::{Complex, DMatrix, RealField};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Normalize numerator/denominator of a continuous-time transfer function.
+///
+/// Leading zeros of `den` are removed and both polynomials are divided by the leading coefficient
+/// of `den`. Leading (close to) zero coefficients of `num` are removed, keeping at least one.
+pub(crate) fn normalize<F: Float + RealField>(num: &[F], den: &[F]) -> Result<(Vec<F>, Vec<F>)> {
+    if num.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "num".into(),
+            reason: "Numerator must have at least one element.".into(),
+        });
+    }
+    let lead = den
+        .iter()
+        .position(|d| !d.is_zero())
+        .ok_or(Error::InvalidArg {
+            arg: "den".into(),
+            reason: "Denominator must have at least on nonzero element.".into(),
+        })?;
+    let d0 = den[lead];
+    let den: Vec<F> = den[lead..].iter().map(|&d| d / d0).collect();
+    let mut num: Vec<F> = num.iter().map(|&n| n / d0).collect();
+
+    let tol = F::from_f64(1e-14).unwrap();
+    let leading_zeros = num
+        .iter()
+        .take_while(|&&n| Float::abs(n) <= tol)
+        .count()
+        .min(num.len() - 1);
+    num.drain(..leading_zeros);
+    Ok((num, den))
+}
+
+/// Return the roots of a polynomial with coefficients given in `p`, like `numpy.roots`.
+pub(crate) fn roots<F: Float + RealField>(p: &[F]) -> Vec<Complex<F>> {
+    let Some(first) = p.iter().position(|c| !c.is_zero()) else {
+        return Vec::new();
+    };
+    let last = p.iter().rposition(|c| !c.is_zero()).unwrap();
+    let trimmed = &p[first..=last];
+
+    // The roots are the eigenvalues of the companion matrix.
+    let mut roots: Vec<Complex<F>> = if trimmed.len() > 1 {
+        companion_dyn::<F, _, _>(trimmed.iter(), trimmed.len())
+            .complex_eigenvalues()
+            .iter()
+            .copied()
+            .collect()
+    } else {
+        Vec::new()
+    };
+    // Trailing zero coefficients are roots at the origin.
+    roots.resize(
+        roots.len() + p.len() - 1 - last,
+        Complex::new(F::zero(), F::zero()),
+    );
+    roots
+}
+
+/// Real polynomial coefficients from a sequence of roots that come in conjugate pairs.
+pub(crate) fn poly<F: Float + RealField>(roots: &[Complex<F>]) -> Vec<F> {
+    poly_dyn(&roots.to_vec())
+        .into_iter()
+        .map(|c| c.re)
+        .collect()
+}
+
+/// Characteristic polynomial of the square matrix `a`.
+pub(crate) fn poly_matrix<F: Float + RealField>(a: &DMatrix<F>) -> Vec<F> {
+    if a.is_empty() {
+        return vec![F::one()];
+    }
+    let eig: Vec<Complex<F>> = a.complex_eigenvalues().iter().copied().collect();
+    poly(&eig)
+}
+
+/// Check that the state-space matrices have consistent shapes.
+pub(crate) fn check_abcd<F>(
+    a: &DMatrix<F>,
+    b: &DMatrix<F>,
+    c: &DMatrix<F>,
+    d: &DMatrix<F>,
+) -> Result<()> {
+    let n = a.nrows();
+    if a.ncols() != n {
+        return Err(Error::InvalidArg {
+            arg: "A".into(),
+            reason: "A must be square.".into(),
+        });
+    }
+    if b.nrows() != n {
+        return Err(Error::InvalidArg {
+            arg: "B".into(),
+            reason: "A and B must have the same number of rows.".into(),
+        });
+    }
+    if c.ncols() != n {
+        return Err(Error::InvalidArg {
+            arg: "C".into(),
+            reason: "A and C must have the same number of columns.".into(),
+        });
+    }
+    if d.nrows() != c.nrows() {
+        return Err(Error::InvalidArg {
+            arg: "D".into(),
+            reason: "C and D must have the same number of rows.".into(),
+        });
+    }
+    if d.ncols() != b.ncols() {
+        return Err(Error::InvalidArg {
+            arg: "D".into(),
+            reason: "B and D must have the same number of columns.".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Transfer function to zero-pole-gain representation, like scipy's `tf2zpk`.
+#[allow(clippy::type_complexity)]
+pub(crate) fn tf2zpk<F: Float + RealField>(
+    num: &[F],
+    den: &[F],
+) -> Result<(Vec<Complex<F>>, Vec<Complex<F>>, F)> {
+    let (num, den) = normalize(num, den)?;
+    Ok((roots(&num), roots(&den), num[0]))
+}
+
+/// Zero-pole-gain to transfer function representation, like scipy's `zpk2tf`.
+pub(crate) fn zpk2tf<F: Float + RealField>(
+    z: &[Complex<F>],
+    p: &[Complex<F>],
+    k: F,
+) -> (Vec<F>, Vec<F>) {
+    let num = poly(z).into_iter().map(|b| b * k).collect();
+    (num, poly(p))
+}
+
+/// Transfer function to state-space representation.
+///
+/// ## Parameters
+/// * `num`, `den`: array_like
+///   Sequences representing the coefficients of the numerator and
+///   denominator polynomials, in order of descending degree. The
+///   denominator needs to be at least as long as the numerator.
+///
+/// ## Returns
+/// * `A`, `B`, `C`, `D`: ndarray
+///   State space representation of the system, in controller canonical
+///   form.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `num` is empty, if `den` is all zeros, or if the transfer function is
+/// improper, with a numerator of higher degree than the denominator.
+///
+/// ## Examples
+/// Convert the transfer function:
+///
+/// ``H(s) = (s^2 + 3s + 3) / (s^2 + 2s + 1)``
+/// ```custom,{class=language-python}
+/// >>> num = [1, 3, 3]
+/// >>> den = [1, 2, 1]
+/// ```
+/// to the state-space representation:
+/// ```custom,{class=language-python}
+/// >>> from scipy.signal import tf2ss
+/// >>> A, B, C, D = tf2ss(num, den)
+/// >>> A
+/// array([[-2., -1.],
+///        [ 1.,  0.]])
+/// >>> B
+/// array([[ 1.],
+///        [ 0.]])
+/// >>> C
+/// array([[ 1.,  2.]])
+/// >>> D
+/// array([[ 1.]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::DMatrix;
+/// use sci_rs::signal::lti::tf2ss;
+///
+/// let (a, b, c, d) = tf2ss(&[1., 3., 3.], &[1., 2., 1.]).unwrap();
+/// assert_eq!(a, DMatrix::from_row_slice(2, 2, &[-2., -1., 1., 0.]));
+/// assert_eq!(b, DMatrix::from_row_slice(2, 1, &[1., 0.]));
+/// assert_eq!(c, DMatrix::from_row_slice(1, 2, &[1., 2.]));
+/// assert_eq!(d, DMatrix::from_row_slice(1, 1, &[1.]));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn tf2ss<F: Float + RealField>(
+    num: &[F],
+    den: &[F],
+) -> Result<(DMatrix<F>, DMatrix<F>, DMatrix<F>, DMatrix<F>)> {
+    let (num, den) = normalize(num, den)?;
+    let (m, k) = (num.len(), den.len());
+    if m > k {
+        return Err(Error::InvalidArg {
+            arg: "num".into(),
+            reason: "Improper transfer function. `num` is longer than `den`.".into(),
+        });
+    }
+
+    // Pad the numerator to the length of the denominator.
+    let mut padded = vec![F::zero(); k - m];
+    padded.extend(num);
+    let d = DMatrix::from_element(1, 1, padded[0]);
+    if k == 1 {
+        return Ok((
+            DMatrix::zeros(1, 1),
+            DMatrix::zeros(1, 1),
+            DMatrix::zeros(1, 1),
+            d,
+        ));
+    }
+
+    let n = k - 1;
+    let mut a = DMatrix::zeros(n, n);
+    for j in 0..n {
+        a[(0, j)] = -den[j + 1];
+    }
+    for i in 1..n {
+        a[(i, i - 1)] = F::one();
+    }
+    let mut b = DMatrix::zeros(n, 1);
+    b[(0, 0)] = F::one();
+    let c = DMatrix::from_fn(1, n, |_, j| padded[j + 1] - padded[0] * den[j + 1]);
+    Ok((a, b, c, d))
+}
+
+/// State-space to transfer function.
+///
+/// A, B, C, D defines a linear state-space system with `p` inputs,
+/// `q` outputs, and `n` state variables.
+///
+/// ## Parameters
+/// * `A`: array_like
+///   State (or system) matrix of shape ``(n, n)``
+/// * `B`: array_like
+///   Input matrix of shape ``(n, p)``
+/// * `C`: array_like
+///   Output matrix of shape ``(q, n)``
+/// * `D`: array_like
+///   Feedthrough (or feedforward) matrix of shape ``(q, p)``
+/// * `input`: int
+///   For multiple-input systems, the index of the input to use.
+///
+/// ## Returns
+/// * `num`: 2-D ndarray
+///   Numerator(s) of the resulting transfer function(s). `num` has one row
+///   for each of the system's outputs. Each row is a sequence representation
+///   of the numerator polynomial.
+/// * `den`: 1-D ndarray
+///   Denominator of the resulting transfer function(s). `den` is a sequence
+///   representation of the denominator polynomial.
+///
+/// ## Errors
+/// [Error::InvalidArg] if the shapes of the matrices are inconsistent, or if the system does not
+/// have the input specified.
+///
+/// ## Examples
+/// Convert the state-space representation:
+///
+/// ``x' = [[-2, -1], [1, 0]] x + [[1], [0]] u``
+///
+/// ``y = [[1, 2]] x + [[1]] u``
+/// ```custom,{class=language-python}
+/// >>> A = [[-2, -1], [1, 0]]
+/// >>> B = [[1], [0]]  # 2-D column vector
+/// >>> C = [[1, 2]]    # 2-D row vector
+/// >>> D = 1
+/// ```
+/// to the transfer function:
+///
+/// ``H(s) = (s^2 + 3s + 3) / (s^2 + 2s + 1)``
+/// ```custom,{class=language-python}
+/// >>> from scipy.signal import ss2tf
+/// >>> ss2tf(A, B, C, D)
+/// (array([[1., 3., 3.]]), array([ 1.,  2.,  1.]))
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::DMatrix;
+/// use sci_rs::signal::lti::ss2tf;
+///
+/// let a: DMatrix<f64> = DMatrix::from_row_slice(2, 2, &[-2., -1., 1., 0.]);
+/// let b = DMatrix::from_row_slice(2, 1, &[1., 0.]);
+/// let c = DMatrix::from_row_slice(1, 2, &[1., 2.]);
+/// let d = DMatrix::from_row_slice(1, 1, &[1.]);
+/// let (num, den) = ss2tf(&a, &b, &c, &d, 0).unwrap();
+/// for (n, e) in num[0].iter().zip([1., 3., 3.]) {
+///     assert!((n - e).abs() < 1e-12);
+/// }
+/// for (d, e) in den.iter().zip([1., 2., 1.]) {
+///     assert!((d - e).abs() < 1e-12);
+/// }
+/// ```
+pub fn ss2tf<F: Float + RealField>(
+    a: &DMatrix<F>,
+    b: &DMatrix<F>,
+    c: &DMatrix<F>,
+    d: &DMatrix<F>,
+    input: usize,
+) -> Result<(Vec<Vec<F>>, Vec<F>)> {
+    check_abcd(a, b, c, d)?;
+    if input >= d.ncols() {
+        return Err(Error::InvalidArg {
+            arg: "input".into(),
+            reason: "System does not have the input specified.".into(),
+        });
+    }
+    let b = b.column(input);
+    let den = poly_matrix(a);
+    let num = (0..d.nrows())
+        .map(|k| {
+            let ak = a - b * c.row(k);
+            poly_matrix(&ak)
+                .into_iter()
+                .zip(&den)
+                .map(|(p, &den)| p + (d[(k, input)] - F::one()) * den)
+                .collect()
+        })
+        .collect();
+    Ok((num, den))
+}
+
+/// Zero-pole-gain representation to state-space representation
+///
+/// ## Parameters
+/// * `z`, `p`: sequence
+///   Zeros and poles. Complex zeros and poles must come in conjugate pairs.
+/// * `k`: float
+///   System gain.
+///
+/// ## Returns
+/// * `A`, `B`, `C`, `D`: ndarray
+///   State space representation of the system, in controller canonical
+///   form.
+///
+/// ## Errors
+/// [Error::InvalidArg] if there are more zeros than poles.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> from scipy.signal import zpk2ss
+/// >>> zpk2ss([-1], [-2, -3], 2)
+/// (array([[-5., -6.],
+///        [ 1.,  0.]]), array([[1.],
+///        [0.]]), array([[2., 2.]]), array([[0.]]))
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::{Complex, DMatrix};
+/// use sci_rs::signal::lti::zpk2ss;
+///
+/// let c = |re| Complex::new(re, 0.);
+/// let (a, b, c, d) = zpk2ss(&[c(-1.)], &[c(-2.), c(-3.)], 2.).unwrap();
+/// assert_eq!(a, DMatrix::from_row_slice(2, 2, &[-5., -6., 1., 0.]));
+/// assert_eq!(b, DMatrix::from_row_slice(2, 1, &[1., 0.]));
+/// assert_eq!(c, DMatrix::from_row_slice(1, 2, &[2., 2.]));
+/// assert_eq!(d, DMatrix::from_row_slice(1, 1, &[0.]));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn zpk2ss<F: Float + RealField>(
+    z: &[Complex<F>],
+    p: &[Complex<F>],
+    k: F,
+) -> Result<(DMatrix<F>, DMatrix<F>, DMatrix<F>, DMatrix<F>)> {
+    let (num, den) = zpk2tf(z, p, k);
+    tf2ss(&num, &den)
+}
+
+/// State-space representation to zero-pole-gain representation.
+///
+/// A, B, C, D defines a linear state-space system with `p` inputs,
+/// `q` outputs, and `n` state variables.
+///
+/// ## Parameters
+/// * `A`: array_like
+///   State (or system) matrix of shape ``(n, n)``
+/// * `B`: array_like
+///   Input matrix of shape ``(n, p)``
+/// * `C`: array_like
+///   Output matrix of shape ``(q, n)``
+/// * `D`: array_like
+///   Feedthrough (or feedforward) matrix of shape ``(q, p)``
+/// * `input`: int
+///   For multiple-input systems, the index of the input to use.
+///
+/// ## Returns
+/// * `z`: sequence
+///   Zeros
+/// * `p`: sequence
+///   Poles
+/// * `k`: float
+///   System gain.
+///
+/// ## Errors
+/// [Error::InvalidArg] if the shapes of the matrices are inconsistent, if the system does not
+/// have the input specified, or if it has more than one output.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> from scipy.signal import ss2zpk
+/// >>> ss2zpk([[-5, -6], [1, 0]], [[1], [0]], [[2, 2]], [[0]])
+/// (array([-1.]), array([-3., -2.]), 2.0)
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::{Complex, DMatrix};
+/// use sci_rs::signal::lti::ss2zpk;
+///
+/// let a: DMatrix<f64> = DMatrix::from_row_slice(2, 2, &[-5., -6., 1., 0.]);
+/// let (z, p, k) = ss2zpk(&a, &DMatrix::from_row_slice(2, 1, &[1., 0.]), &DMatrix::from_row_slice(1, 2, &[2., 2.]), &DMatrix::from_row_slice(1, 1, &[0.]), 0).unwrap();
+/// assert!((z[0] - Complex::new(-1., 0.)).norm() < 1e-12);
+/// assert_eq!(p.len(), 2);
+/// assert!((k - 2.).abs() < 1e-12);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn ss2zpk<F: Float + RealField>(
+    a: &DMatrix<F>,
+    b: &DMatrix<F>,
+    c: &DMatrix<F>,
+    d: &DMatrix<F>,
+    input: usize,
+) -> Result<(Vec<Complex<F>>, Vec<Complex<F>>, F)> {
+    let (num, den) = ss2tf(a, b, c, d, input)?;
+    let [num] = num.as_slice() else {
+        return Err(Error::InvalidArg {
+            arg: "C".into(),
+            reason: "Only single-output systems can be converted to zeros, poles and gain.".into(),
+        });
+    };
+    tf2zpk(num, &den)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[track_caller]
+    fn assert_vec_eq(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn polynomial_roots() {
+        let mut r = roots(&[0., 1., -3., 2., 0.]);
+        r.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+        assert_eq!(r.len(), 3);
+        for (r, e) in r.iter().zip([0., 1., 2.]) {
+            assert_abs_diff_eq!(r.re, e, epsilon = 1e-12);
+            assert_abs_diff_eq!(r.im, 0., epsilon = 1e-12);
+        }
+        assert!(roots(&[0., 0.]).is_empty());
+        assert!(roots(&[3.]).is_empty());
+        assert_vec_eq(&poly(&r), &[1., -3., 2., 0.]);
+    }
+
+    #[test]
+    fn normalized() {
+        let (num, den) = normalize(&[0., 0., 2., 4.], &[0., 2., 1.]).unwrap();
+        assert_vec_eq(&num, &[1., 2.]);
+        assert_vec_eq(&den, &[1., 0.5]);
+        assert!(normalize(&[1.], &[0., 0.]).is_err());
+    }
+
+    #[test]
+    fn state_space_round_trip() {
+        let (a, b, c, d) = tf2ss(&[1., 3., 3.], &[1., 2., 1.]).unwrap();
+        assert_eq!(a, DMatrix::from_row_slice(2, 2, &[-2., -1., 1., 0.]));
+        assert_eq!(b, DMatrix::from_row_slice(2, 1, &[1., 0.]));
+        assert_eq!(c, DMatrix::from_row_slice(1, 2, &[1., 2.]));
+        assert_eq!(d, DMatrix::from_row_slice(1, 1, &[1.]));
+        let (num, den) = ss2tf(&a, &b, &c, &d, 0).unwrap();
+        assert_vec_eq(&num[0], &[1., 3., 3.]);
+        assert_vec_eq(&den, &[1., 2., 1.]);
+        assert!(ss2tf(&a, &b, &c, &d, 1).is_err());
+        assert!(tf2ss(&[1., 2., 3.], &[1., 2.]).is_err());
+
+        // Static gain
+        let (a, b, c, d) = tf2ss(&[2.], &[4.]).unwrap();
+        assert_eq!(a, DMatrix::zeros(1, 1));
+        assert_eq!(b, DMatrix::zeros(1, 1));
+        assert_eq!(c, DMatrix::zeros(1, 1));
+        assert_eq!(d, DMatrix::from_element(1, 1, 0.5));
+    }
+
+    #[test]
+    fn multiple_inputs_and_outputs() {
+        // x' = -x + u1 + 2 u2, y1 = x, y2 = 3 x + u2
+        let a = DMatrix::from_element(1, 1, -1.);
+        let b = DMatrix::from_row_slice(1, 2, &[1., 2.]);
+        let c = DMatrix::from_row_slice(2, 1, &[1., 3.]);
+        let d = DMatrix::from_row_slice(2, 2, &[0., 0., 0., 1.]);
+        let (num, den) = ss2tf(&a, &b, &c, &d, 0).unwrap();
+        assert_vec_eq(&num[0], &[0., 1.]);
+        assert_vec_eq(&num[1], &[0., 3.]);
+        assert_vec_eq(&den, &[1., 1.]);
+        let (num, _) = ss2tf(&a, &b, &c, &d, 1).unwrap();
+        assert_vec_eq(&num[0], &[0., 2.]);
+        assert_vec_eq(&num[1], &[1., 7.]);
+        assert!(ss2zpk(&a, &b, &c, &d, 0).is_err());
+
+        let (z, p, k) = ss2zpk(
+            &a,
+            &b,
+            &c.rows(1, 1).into_owned(),
+            &d.rows(1, 1).into_owned(),
+            1,
+        )
+        .unwrap();
+        assert_eq!(z.len(), 1);
+        assert_abs_diff_eq!(z[0].re, -7., epsilon = 1e-12);
+        assert_abs_diff_eq!(p[0].re, -1., epsilon = 1e-12);
+        assert_abs_diff_eq!(k, 1., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn zeros_poles_gain_round_trip() {
+        let z = [Complex::new(-1., 0.)];
+        let p = [Complex::new(-2., 1.), Complex::new(-2., -1.)];
+        let (a, b, c, d) = zpk2ss(&z, &p, 3.).unwrap();
+        // 3 (s + 1) / (s^2 + 4 s + 5)
+        assert_eq!(a, DMatrix::from_row_slice(2, 2, &[-4., -5., 1., 0.]));
+        assert_eq!(c, DMatrix::from_row_slice(1, 2, &[3., 3.]));
+        let (z2, mut p2, k2) = ss2zpk(&a, &b, &c, &d, 0).unwrap();
+        assert_abs_diff_eq!(z2[0].re, -1., epsilon = 1e-12);
+        p2.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+        for (p2, p) in p2.iter().zip([p[1], p[0]]) {
+            assert_abs_diff_eq!((p2 - p).norm(), 0., epsilon = 1e-12);
+        }
+        assert_abs_diff_eq!(k2, 3., epsilon = 1e-12);
+        assert!(zpk2ss(&p, &z, 1.).is_err());
+    }
+}
@@ -0,0 +1,364 @@
+use super::Lti;
+use alloc::{vec, vec::Vec};
+use nalgebra::{DMatrix, DVector, RealField};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Simulate output of a discrete-time linear system.
+///
+/// ## Parameters
+/// * `system`: [Lti]
+///   A discrete-time system, in any of the [TransferFunction](super::TransferFunction),
+///   [ZerosPolesGain](super::ZerosPolesGain) or [StateSpace](super::StateSpace) representations.
+/// * `u`: array_like
+///   An input array describing the input at each time `t` (interpolation is assumed between given
+///   times). It has one row per time and one column per input.
+/// * `t`: array_like, optional
+///   The time steps at which the input is defined. If `t` is given, it must be the same length as
+///   `u`, and the final value in `t` determines the number of steps returned in the output.
+/// * `x0`: array_like, optional
+///   The initial conditions on the state vector (zero by default).
+///
+/// ## Returns
+/// * `tout`: ndarray
+///   Time values for the output, as a 1-D array.
+/// * `yout`: ndarray
+///   System response, with one row per time and one column per output.
+/// * `xout`: ndarray
+///   Time-evolution of the state-vector, with one row per time and one column per state.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `system` is continuous-time, or if `u`, `t` or `x0` do not match the
+/// system.
+///
+/// ## Examples
+/// A simple integrator transfer function with a discrete time step of 1.0 could be implemented
+/// as:
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy import signal
+/// >>> tf = ([1.0,], [1.0, -1.0], 1.0)
+/// >>> t_in = [0.0, 1.0, 2.0, 3.0]
+/// >>> u = np.asarray([0.0, 0.0, 1.0, 1.0])
+/// >>> t_out, y = signal.dlsim(tf, u, t=t_in)
+/// >>> y.T
+/// array([[ 0.,  0.,  0.,  1.]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::DMatrix;
+/// use sci_rs::signal::lti::{dlsim, TransferFunction};
+///
+/// let tf = TransferFunction::new(&[1.0], &[1.0, -1.0], Some(1.0)).unwrap();
+/// let u = DMatrix::from_column_slice(4, 1, &[0.0, 0.0, 1.0, 1.0]);
+/// let (t_out, y, _) = dlsim(&tf, &u, Some(&[0.0, 1.0, 2.0, 3.0]), None).unwrap();
+/// assert_eq!(t_out, vec![0.0, 1.0, 2.0, 3.0]);
+/// assert_eq!(y.as_slice(), &[0.0, 0.0, 0.0, 1.0]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn dlsim<F, L>(
+    system: &L,
+    u: &DMatrix<F>,
+    t: Option<&[F]>,
+    x0: Option<&[F]>,
+) -> Result<(Vec<F>, DMatrix<F>, DMatrix<F>)>
+where
+    F: Float + RealField,
+    L: Lti<F>,
+{
+    let Some(dt) = system.dt() else {
+        return Err(Error::InvalidArg {
+            arg: "system".into(),
+            reason: "dlsim can only be used with discrete-time dlti systems.".into(),
+        });
+    };
+    let sys = system.to_ss()?;
+    let (a, b, c, d) = (&sys.a, &sys.b, &sys.c, &sys.d);
+    if u.ncols() != b.ncols() {
+        return Err(Error::InvalidArg {
+            arg: "u".into(),
+            reason: "System does not define that many inputs.".into(),
+        });
+    }
+
+    let (stoptime, out_samples) = match t {
+        None => {
+            if u.nrows() == 0 {
+                return Err(Error::InvalidArg {
+                    arg: "u".into(),
+                    reason: "u must have at least one row.".into(),
+                });
+            }
+            (F::from(u.nrows() - 1).unwrap() * dt, u.nrows())
+        }
+        Some(t) => {
+            if t.len() != u.nrows() || t.is_empty() {
+                return Err(Error::InvalidArg {
+                    arg: "t".into(),
+                    reason: "t must have the same length as the number of rows of u.".into(),
+                });
+            }
+            if t.windows(2).any(|w| w[1] <= w[0]) {
+                return Err(Error::InvalidArg {
+                    arg: "t".into(),
+                    reason: "t must be strictly increasing.".into(),
+                });
+            }
+            let stoptime = t[t.len() - 1];
+            let samples = Float::floor(stoptime / dt).to_usize().unwrap_or(0) + 1;
+            (stoptime, samples)
+        }
+    };
+    let tout: Vec<F> = if out_samples == 1 {
+        vec![F::zero()]
+    } else {
+        let step = stoptime / F::from(out_samples - 1).unwrap();
+        (0..out_samples)
+            .map(|i| F::from(i).unwrap() * step)
+            .collect()
+    };
+
+    let mut x = match x0 {
+        Some(x0) if x0.len() != a.nrows() => {
+            return Err(Error::InvalidArg {
+                arg: "x0".into(),
+                reason: "x0 must have one element per state.".into(),
+            })
+        }
+        Some(x0) => DVector::from_column_slice(x0),
+        None => DVector::zeros(a.nrows()),
+    };
+
+    // Pre-interpolate inputs into the desired time steps.
+    let u_dt = match t {
+        None => u.clone(),
+        Some(t) => interp_rows(t, u, &tout),
+    };
+
+    // Simulate the system
+    let mut xout = DMatrix::zeros(out_samples, a.nrows());
+    let mut yout = DMatrix::zeros(out_samples, c.nrows());
+    for i in 0..out_samples {
+        let ui = u_dt.row(i).transpose();
+        xout.set_row(i, &x.transpose());
+        yout.set_row(i, &(c * &x + d * &ui).transpose());
+        x = a * &x + b * &ui;
+    }
+    Ok((tout, yout, xout))
+}
+
+/// Step response of discrete-time system.
+///
+/// ## Parameters
+/// * `system`: [Lti]
+///   A discrete-time system.
+/// * `x0`: array_like, optional
+///   Initial state-vector. Defaults to zero.
+/// * `t`: array_like, optional
+///   Time points. Computed if not given.
+/// * `n`: int, optional
+///   The number of time points to compute (if `t` is not given). Defaults to 100.
+///
+/// ## Returns
+/// * `tout`: ndarray
+///   Output time points, as a 1-D array.
+/// * `yout`: Vec of ndarray
+///   Step response of system. Each element of the Vec represents the output of the system based
+///   on a step response to each input.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `system` is continuous-time or `x0` does not match the system.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> from scipy import signal
+/// >>> t, y = signal.dstep(([1.0], [1.0, -0.5], 1.0), n=4)
+/// >>> y[0].T
+/// array([[0.  , 1.  , 1.5 , 1.75]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::signal::lti::{dstep, TransferFunction};
+///
+/// let tf = TransferFunction::new(&[1.0], &[1.0, -0.5], Some(1.0)).unwrap();
+/// let (t, y) = dstep(&tf, None, None, Some(4)).unwrap();
+/// assert_eq!(t, vec![0.0, 1.0, 2.0, 3.0]);
+/// assert_eq!(y[0].as_slice(), &[0.0, 1.0, 1.5, 1.75]);
+/// ```
+pub fn dstep<F, L>(
+    system: &L,
+    x0: Option<&[F]>,
+    t: Option<&[F]>,
+    n: Option<usize>,
+) -> Result<(Vec<F>, Vec<DMatrix<F>>)>
+where
+    F: Float + RealField,
+    L: Lti<F>,
+{
+    dresponse(system, x0, t, n, |u, i| u.column_mut(i).fill(F::one()))
+}
+
+/// Impulse response of discrete-time system.
+///
+/// ## Parameters
+/// * `system`: [Lti]
+///   A discrete-time system.
+/// * `x0`: array_like, optional
+///   Initial state-vector. Defaults to zero.
+/// * `t`: array_like, optional
+///   Time points. Computed if not given.
+/// * `n`: int, optional
+///   The number of time points to compute (if `t` is not given). Defaults to 100.
+///
+/// ## Returns
+/// * `tout`: ndarray
+///   Output time points, as a 1-D array.
+/// * `yout`: Vec of ndarray
+///   Impulse response of system. Each element of the Vec represents the output of the system
+///   based on an impulse in each input.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `system` is continuous-time or `x0` does not match the system.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> from scipy import signal
+/// >>> t, y = signal.dimpulse(([1.0], [1.0, -0.5], 1.0), n=4)
+/// >>> y[0].T
+/// array([[0.  , 1.  , 0.5 , 0.25]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::signal::lti::{dimpulse, TransferFunction};
+///
+/// let tf = TransferFunction::new(&[1.0], &[1.0, -0.5], Some(1.0)).unwrap();
+/// let (t, y) = dimpulse(&tf, None, None, Some(4)).unwrap();
+/// assert_eq!(t, vec![0.0, 1.0, 2.0, 3.0]);
+/// assert_eq!(y[0].as_slice(), &[0.0, 1.0, 0.5, 0.25]);
+/// ```
+pub fn dimpulse<F, L>(
+    system: &L,
+    x0: Option<&[F]>,
+    t: Option<&[F]>,
+    n: Option<usize>,
+) -> Result<(Vec<F>, Vec<DMatrix<F>>)>
+where
+    F: Float + RealField,
+    L: Lti<F>,
+{
+    dresponse(system, x0, t, n, |u, i| u[(0, i)] = F::one())
+}
+
+/// Simulate the response to the input set by `excite` for each input in turn.
+fn dresponse<F, L>(
+    system: &L,
+    x0: Option<&[F]>,
+    t: Option<&[F]>,
+    n: Option<usize>,
+    excite: impl Fn(&mut DMatrix<F>, usize),
+) -> Result<(Vec<F>, Vec<DMatrix<F>>)>
+where
+    F: Float + RealField,
+    L: Lti<F>,
+{
+    let Some(dt) = system.dt() else {
+        return Err(Error::InvalidArg {
+            arg: "system".into(),
+            reason: "The system must be a discrete-time dlti system.".into(),
+        });
+    };
+    let inputs = system.to_ss()?.inputs();
+    let t: Vec<F> = match t {
+        Some(t) => t.to_vec(),
+        None => (0..n.unwrap_or(100))
+            .map(|i| F::from(i).unwrap() * dt)
+            .collect(),
+    };
+
+    let mut tout = Vec::new();
+    let mut yout = Vec::with_capacity(inputs);
+    for i in 0..inputs {
+        let mut u = DMatrix::zeros(t.len(), inputs);
+        excite(&mut u, i);
+        let (t, y, _) = dlsim(system, &u, Some(&t), x0)?;
+        tout = t;
+        yout.push(y);
+    }
+    Ok((tout, yout))
+}
+
+/// Linearly interpolate the rows of `u`, sampled at `t`, at the times `tout`. Values outside of
+/// `t` are extrapolated from the first or last segment.
+fn interp_rows<F: Float + RealField>(t: &[F], u: &DMatrix<F>, tout: &[F]) -> DMatrix<F> {
+    if t.len() == 1 {
+        return DMatrix::from_fn(tout.len(), u.ncols(), |_, j| u[(0, j)]);
+    }
+    let mut out = DMatrix::zeros(tout.len(), u.ncols());
+    for (i, &ti) in tout.iter().enumerate() {
+        let k = t[1..t.len() - 1].partition_point(|&tk| tk <= ti);
+        let w = (ti - t[k]) / (t[k + 1] - t[k]);
+        for j in 0..u.ncols() {
+            out[(i, j)] = u[(k, j)] + w * (u[(k + 1, j)] - u[(k, j)]);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::lti::{StateSpace, TransferFunction};
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn interpolated_input() {
+        let tf = TransferFunction::new(&[1.0], &[1.0, -1.0], Some(1.0)).unwrap();
+        // A ramp given every other sample is interpolated to every sample.
+        let u = DMatrix::from_column_slice(3, 1, &[0.0, 2.0, 4.0]);
+        let (t, y, x) = dlsim(&tf, &u, Some(&[0.0, 2.0, 4.0]), Some(&[1.0])).unwrap();
+        assert_eq!(t, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(y.as_slice(), &[1.0, 1.0, 2.0, 4.0, 7.0]);
+        assert_eq!(x.as_slice(), y.as_slice());
+
+        // Without t, each row of u is one sample.
+        let (t, y, _) = dlsim(&tf, &u, None, None).unwrap();
+        assert_eq!(t, vec![0.0, 1.0, 2.0]);
+        assert_eq!(y.as_slice(), &[0.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn multiple_inputs() {
+        let sys = StateSpace::new(
+            DMatrix::from_row_slice(2, 2, &[0.5, 0., 0., -0.5]),
+            DMatrix::from_row_slice(2, 2, &[1., 0., 0., 1.]),
+            DMatrix::from_row_slice(1, 2, &[1., 1.]),
+            DMatrix::from_row_slice(1, 2, &[0., 2.]),
+            Some(0.1),
+        )
+        .unwrap();
+        let (t, y) = dstep(&sys, None, None, Some(3)).unwrap();
+        assert_eq!(t.len(), 3);
+        assert_abs_diff_eq!(t[2], 0.2);
+        assert_eq!(y.len(), 2);
+        assert_eq!(y[0].as_slice(), &[0.0, 1.0, 1.5]);
+        assert_eq!(y[1].as_slice(), &[2.0, 3.0, 2.5]);
+
+        let (_, y) = dimpulse(&sys, None, None, Some(3)).unwrap();
+        assert_eq!(y[0].as_slice(), &[0.0, 1.0, 0.5]);
+        assert_eq!(y[1].as_slice(), &[2.0, 1.0, -0.5]);
+    }
+
+    #[test]
+    fn invalid_args() {
+        let continuous = TransferFunction::new(&[1.0], &[1.0, -1.0], None).unwrap();
+        let u = DMatrix::from_element(3, 1, 1.0);
+        assert!(dlsim(&continuous, &u, None, None).is_err());
+        assert!(dstep(&continuous, None, None, None).is_err());
+        let tf = TransferFunction::new(&[1.0], &[1.0, -1.0], Some(1.0)).unwrap();
+        assert!(dlsim(&tf, &u, Some(&[0.0, 1.0]), None).is_err());
+        assert!(dlsim(&tf, &u, Some(&[0.0, 2.0, 1.0]), None).is_err());
+        assert!(dlsim(&tf, &u, None, Some(&[1.0, 2.0])).is_err());
+        assert!(dlsim(&tf, &DMatrix::from_element(3, 2, 1.0), None, None).is_err());
+    }
+}
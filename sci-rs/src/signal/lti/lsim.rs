@@ -0,0 +1,311 @@
+use super::{expm, Lti};
+use alloc::vec::Vec;
+use nalgebra::{DMatrix, RealField, RowDVector};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Simulate output of a continuous-time linear system.
+///
+/// ## Parameters
+/// * `system`: [Lti]
+///   A continuous-time system, in any of the [TransferFunction](super::TransferFunction),
+///   [ZerosPolesGain](super::ZerosPolesGain) or [StateSpace](super::StateSpace) representations.
+/// * `u`: array_like, optional
+///   An input array describing the input at each time `t` (interpolation is assumed between given
+///   times). It has one row per time and one column per input. If `u` is None, or is all zeros,
+///   a zero input is used.
+/// * `t`: array_like
+///   The time steps at which the input is defined and at which the output is desired. Must be
+///   nonnegative, increasing, and equally spaced.
+/// * `x0`: array_like, optional
+///   The initial conditions on the state vector (zero by default).
+/// * `interp`: bool, optional
+///   Whether to use linear (true, the default) or zero-order-hold (false) interpolation for the
+///   input array.
+///
+/// ## Returns
+/// * `t`: 1D ndarray
+///   Time values for the output.
+/// * `yout`: 2D ndarray
+///   System response, with one row per time and one column per output.
+/// * `xout`: 2D ndarray
+///   Time evolution of the state vector, with one row per time and one column per state.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `system` is discrete-time, if `t` is empty, not equally spaced or starts
+/// before zero, or if `u` or `x0` do not match the system.
+///
+/// ## Notes
+/// The system is discretized over each time step with a matrix exponential, so that zero-order
+/// hold and linearly interpolated inputs are simulated exactly.
+///
+/// ## Examples
+/// Simulate a double integrator ``y'' = u`` with a constant input ``u = 1``. The exact solution
+/// is ``y = 1/2 t^2``.
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy.signal import lti, lsim
+/// >>> system = lti([[0., 1.], [0., 0.]], [[0.], [1.]], [[1., 0.]], 0.)
+/// >>> t = np.linspace(0, 5, num=50)
+/// >>> u = np.ones_like(t)
+/// >>> tout, y, x = lsim(system, u, t)
+/// >>> np.allclose(y, 0.5*t**2)
+/// True
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::DMatrix;
+/// use sci_rs::signal::lti::{lsim, StateSpace};
+///
+/// let system = StateSpace::new(
+///     DMatrix::from_row_slice(2, 2, &[0., 1., 0., 0.]),
+///     DMatrix::from_row_slice(2, 1, &[0., 1.]),
+///     DMatrix::from_row_slice(1, 2, &[1., 0.]),
+///     DMatrix::from_row_slice(1, 1, &[0.]),
+///     None,
+/// )
+/// .unwrap();
+/// let t: Vec<f64> = (0..50).map(|i| 5. * i as f64 / 49.).collect();
+/// let u = DMatrix::from_element(50, 1, 1.);
+/// let (tout, y, x) = lsim(&system, Some(&u), &t, None, None).unwrap();
+/// for (t, y) in t.iter().zip(y.column(0).iter()) {
+///     assert!((y - 0.5 * t * t).abs() < 1e-10);
+/// }
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn lsim<F, L>(
+    system: &L,
+    u: Option<&DMatrix<F>>,
+    t: &[F],
+    x0: Option<&[F]>,
+    interp: Option<bool>,
+) -> Result<(Vec<F>, DMatrix<F>, DMatrix<F>)>
+where
+    F: Float + RealField,
+    L: Lti<F>,
+{
+    if system.dt().is_some() {
+        return Err(Error::InvalidArg {
+            arg: "system".into(),
+            reason: "lsim can only be used with continuous-time systems, use dlsim instead.".into(),
+        });
+    }
+    let sys = system.to_ss()?;
+    let (a, b, c, d) = (&sys.a, &sys.b, &sys.c, &sys.d);
+    let (n_states, n_inputs) = (a.nrows(), b.ncols());
+    let n_steps = t.len();
+    if n_steps == 0 {
+        return Err(Error::InvalidArg {
+            arg: "t".into(),
+            reason: "T must have at least one element.".into(),
+        });
+    }
+
+    let x0 = match x0 {
+        Some(x0) if x0.len() != n_states => {
+            return Err(Error::InvalidArg {
+                arg: "x0".into(),
+                reason: "X0 must have one element per state.".into(),
+            })
+        }
+        Some(x0) => RowDVector::from_row_slice(x0),
+        None => RowDVector::zeros(n_states),
+    };
+
+    // States are row vectors, so everything is transposed.
+    let mut xout = DMatrix::zeros(n_steps, n_states);
+    if t[0] < F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "t".into(),
+            reason: "Initial time must be nonnegative".into(),
+        });
+    } else if t[0] > F::zero() {
+        // Step forward to the initial time, with zero input.
+        xout.set_row(0, &(&x0 * expm(&(a.transpose() * t[0]))?));
+    } else {
+        xout.set_row(0, &x0);
+    }
+
+    let u = u.filter(|u| u.iter().any(|u| !u.is_zero()));
+    if let Some(u) = u {
+        if u.nrows() != n_steps {
+            return Err(Error::InvalidArg {
+                arg: "u".into(),
+                reason: "U must have the same number of rows as elements in T.".into(),
+            });
+        }
+        if u.ncols() != n_inputs {
+            return Err(Error::InvalidArg {
+                arg: "u".into(),
+                reason: "System does not define that many inputs.".into(),
+            });
+        }
+    }
+    if n_steps == 1 {
+        return Ok((t.to_vec(), output(&xout, c, d, u), xout));
+    }
+
+    let dt = t[1] - t[0];
+    let rtol = F::from_f64(1e-5).unwrap();
+    let atol = F::from_f64(1e-8).unwrap();
+    if t.windows(2)
+        .any(|w| Float::abs(w[1] - w[0] - dt) > atol + rtol * Float::abs(dt))
+    {
+        return Err(Error::InvalidArg {
+            arg: "t".into(),
+            reason: "Time steps are not equally spaced.".into(),
+        });
+    }
+
+    let Some(u) = u else {
+        // Zero input: just use the matrix exponential.
+        let exp_at_dt = expm(&(a.transpose() * dt))?;
+        for i in 1..n_steps {
+            let x = xout.row(i - 1) * &exp_at_dt;
+            xout.set_row(i, &x);
+        }
+        return Ok((t.to_vec(), output(&xout, c, d, None), xout));
+    };
+
+    if !interp.unwrap_or(true) {
+        // Zero-order hold
+        // Algorithm: to integrate from time 0 to time dt, we solve
+        //   xdot = A x + B u,  x(0) = x0
+        //   udot = 0,          u(0) = u0.
+        //
+        // Solution is
+        //   [ x(dt) ]       [ A*dt   B*dt ] [ x0 ]
+        //   [ u(dt) ] = exp [  0     0    ] [ u0 ]
+        let n = n_states + n_inputs;
+        let mut m = DMatrix::zeros(n, n);
+        m.view_mut((0, 0), (n_states, n_states))
+            .copy_from(&(a * dt));
+        m.view_mut((0, n_states), (n_states, n_inputs))
+            .copy_from(&(b * dt));
+        let exp_mt = expm(&m.transpose())?;
+        let ad = exp_mt.view((0, 0), (n_states, n_states));
+        let bd = exp_mt.view((n_states, 0), (n_inputs, n_states));
+        for i in 1..n_steps {
+            let x = xout.row(i - 1) * ad + u.row(i - 1) * bd;
+            xout.set_row(i, &x);
+        }
+    } else {
+        // Linear interpolation between steps
+        // Algorithm: to integrate from time 0 to time dt, with linear
+        // interpolation between inputs u(0) = u0 and u(dt) = u1, we solve
+        //   xdot = A x + B u,        x(0) = x0
+        //   udot = (u1 - u0) / dt,   u(0) = u0.
+        //
+        // Solution is
+        //   [ x(dt) ]       [ A*dt  B*dt  0 ] [  x0   ]
+        //   [ u(dt) ] = exp [  0     0    I ] [  u0   ]
+        //   [u1 - u0]       [  0     0    0 ] [u1 - u0]
+        let n = n_states + 2 * n_inputs;
+        let mut m = DMatrix::zeros(n, n);
+        m.view_mut((0, 0), (n_states, n_states))
+            .copy_from(&(a * dt));
+        m.view_mut((0, n_states), (n_states, n_inputs))
+            .copy_from(&(b * dt));
+        m.view_mut((n_states, n_states + n_inputs), (n_inputs, n_inputs))
+            .fill_with_identity();
+        let exp_mt = expm(&m.transpose())?;
+        let ad = exp_mt.view((0, 0), (n_states, n_states));
+        let bd1 = exp_mt.view((n_states + n_inputs, 0), (n_inputs, n_states));
+        let bd0 = exp_mt.view((n_states, 0), (n_inputs, n_states)) - bd1;
+        for i in 1..n_steps {
+            let x = xout.row(i - 1) * ad + u.row(i - 1) * &bd0 + u.row(i) * bd1;
+            xout.set_row(i, &x);
+        }
+    }
+    Ok((t.to_vec(), output(&xout, c, d, Some(u)), xout))
+}
+
+/// System output ``y = x C^T + u D^T`` for states and inputs stored as rows.
+fn output<F: Float + RealField>(
+    xout: &DMatrix<F>,
+    c: &DMatrix<F>,
+    d: &DMatrix<F>,
+    u: Option<&DMatrix<F>>,
+) -> DMatrix<F> {
+    let y = xout * c.transpose();
+    match u {
+        Some(u) => y + u * d.transpose(),
+        None => y,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::lti::{StateSpace, TransferFunction};
+    use alloc::vec::Vec;
+    use approx::assert_abs_diff_eq;
+
+    fn linspace(stop: f64, n: usize) -> Vec<f64> {
+        (0..n).map(|i| stop * i as f64 / (n - 1) as f64).collect()
+    }
+
+    #[test]
+    fn first_order_inputs() {
+        // H(s) = 1 / (s + 1)
+        let system = TransferFunction::new(&[1.], &[1., 1.], None).unwrap();
+        let t = linspace(4., 41);
+
+        // A step is exact with either interpolation.
+        let u = DMatrix::from_element(t.len(), 1, 1.);
+        for interp in [true, false] {
+            let (_, y, _) = lsim(&system, Some(&u), &t, None, Some(interp)).unwrap();
+            for (&t, &y) in t.iter().zip(y.iter()) {
+                assert_abs_diff_eq!(y, 1. - (-t).exp(), epsilon = 1e-10);
+            }
+        }
+
+        // A ramp is exact with linear interpolation.
+        let u = DMatrix::from_column_slice(t.len(), 1, &t);
+        let (_, y, _) = lsim(&system, Some(&u), &t, None, None).unwrap();
+        for (&t, &y) in t.iter().zip(y.iter()) {
+            assert_abs_diff_eq!(y, t - 1. + (-t).exp(), epsilon = 1e-10);
+        }
+
+        // With zero-order hold, the ramp is held for each step and lags behind.
+        let (_, y_zoh, _) = lsim(&system, Some(&u), &t, None, Some(false)).unwrap();
+        assert!(y_zoh[40] < y[40]);
+    }
+
+    #[test]
+    fn initial_conditions() {
+        // Undamped oscillator x'' = -x.
+        let system = StateSpace::new(
+            DMatrix::from_row_slice(2, 2, &[0., 1., -1., 0.]),
+            DMatrix::from_row_slice(2, 1, &[0., 1.]),
+            DMatrix::from_row_slice(1, 2, &[1., 0.]),
+            DMatrix::from_row_slice(1, 1, &[0.]),
+            None,
+        )
+        .unwrap();
+        let t: Vec<f64> = (0..20).map(|i| 0.5 + 0.1 * i as f64).collect();
+        let (tout, y, x) = lsim(&system, None, &t, Some(&[1., 0.]), None).unwrap();
+        assert_eq!(tout, t);
+        assert_eq!(x.shape(), (20, 2));
+        for (&t, &y) in t.iter().zip(y.iter()) {
+            assert_abs_diff_eq!(y, t.cos(), epsilon = 1e-10);
+        }
+
+        let zeros = DMatrix::zeros(20, 1);
+        let (_, y0, _) = lsim(&system, Some(&zeros), &t, Some(&[1., 0.]), None).unwrap();
+        assert_eq!(y, y0);
+    }
+
+    #[test]
+    fn invalid_args() {
+        let system = TransferFunction::new(&[1.], &[1., 1.], None).unwrap();
+        let t = linspace(1., 5);
+        let u = DMatrix::from_element(4, 1, 1.);
+        assert!(lsim(&system, Some(&u), &t, None, None).is_err());
+        assert!(lsim(&system, None, &[0., 1., 3.], None, None).is_err());
+        assert!(lsim(&system, None, &[-1., 0.], None, None).is_err());
+        assert!(lsim(&system, None, &t, Some(&[1., 2.]), None).is_err());
+        let discrete = TransferFunction::new(&[1.], &[1., 1.], Some(0.1)).unwrap();
+        assert!(lsim(&discrete, None, &t, None, None).is_err());
+    }
+}
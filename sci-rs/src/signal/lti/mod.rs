@@ -0,0 +1,85 @@
+mod bode;
+mod cont2discrete;
+pub(crate) mod conversions;
+mod dlsim;
+mod lsim;
+mod place_poles;
+mod response;
+mod state_space;
+mod transfer_function;
+mod zeros_poles_gain;
+
+pub use bode::*;
+pub use cont2discrete::*;
+pub use conversions::{ss2tf, ss2zpk, tf2ss, zpk2ss};
+pub use dlsim::*;
+pub use lsim::*;
+pub use place_poles::*;
+pub use response::*;
+pub use state_space::*;
+pub use transfer_function::*;
+pub use zeros_poles_gain::*;
+
+use alloc::vec::Vec;
+use nalgebra::{Complex, DMatrix, RealField};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Common interface of the linear time invariant system representations [TransferFunction],
+/// [ZerosPolesGain] and [StateSpace].
+///
+/// A system is continuous-time (scipy's `lti`) when [Lti::dt] is `None` and discrete-time
+/// (scipy's `dlti`) with the given sampling time otherwise.
+pub trait Lti<F: Float + RealField> {
+    /// Sampling time of a discrete-time system, `None` for continuous-time systems.
+    fn dt(&self) -> Option<F>;
+
+    /// Convert the system to [TransferFunction] representation.
+    fn to_tf(&self) -> Result<TransferFunction<F>>;
+
+    /// Convert the system to [ZerosPolesGain] representation.
+    fn to_zpk(&self) -> Result<ZerosPolesGain<F>>;
+
+    /// Convert the system to [StateSpace] representation.
+    fn to_ss(&self) -> Result<StateSpace<F>>;
+
+    /// Poles of the system.
+    fn poles(&self) -> Result<Vec<Complex<F>>> {
+        Ok(self.to_zpk()?.poles)
+    }
+
+    /// Zeros of the system.
+    fn zeros(&self) -> Result<Vec<Complex<F>>> {
+        Ok(self.to_zpk()?.zeros)
+    }
+}
+
+/// Matrix exponential of `m`, by [crate::linalg::expm].
+fn expm<F: Float + RealField>(m: &DMatrix<F>) -> Result<DMatrix<F>> {
+    let a = ndarray::Array2::from_shape_fn(m.shape(), |(i, j)| m[(i, j)]);
+    let e = crate::linalg::expm(&a)?;
+    Ok(DMatrix::from_fn(m.nrows(), m.ncols(), |i, j| e[[i, j]]))
+}
+
+/// Sampling times must be positive if given.
+fn check_dt<F: Float>(dt: Option<F>) -> Result<Option<F>> {
+    match dt {
+        Some(dt) if dt <= F::zero() || !dt.is_finite() => Err(Error::InvalidArg {
+            arg: "dt".into(),
+            reason:
+                "dt must be a positive, finite sampling time or None for continuous-time systems."
+                    .into(),
+        }),
+        dt => Ok(dt),
+    }
+}
+
+/// Systems can only be interconnected if they share the same sampling time.
+fn check_same_dt<F: Float>(dt1: Option<F>, dt2: Option<F>) -> Result<()> {
+    if dt1 != dt2 {
+        return Err(Error::ConflictArg {
+            reason: "Cannot interconnect systems with different sampling times.".into(),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,941 @@
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+use nalgebra::{Complex, ComplexField, DMatrix, DVector, RealField, SymmetricEigen};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Algorithm used by [place_poles] to choose a well-conditioned eigenvector matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlacePolesMethod {
+    /// Tits and Yang's method, which supports complex poles and updates two eigenvectors at a
+    /// time.
+    #[default]
+    Yt,
+    /// Kautsky, Nichols and Van Dooren's method 0, which only supports real poles and updates
+    /// one eigenvector at a time.
+    Knv0,
+}
+
+/// Result of [place_poles].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlacePolesResult<F: RealField> {
+    /// The closed loop matrix ``K`` such as the eigenvalues of ``A - B K`` are as close as
+    /// possible to the requested poles.
+    pub gain_matrix: DMatrix<F>,
+    /// The poles corresponding to ``A - B K`` sorted as first the real poles in increasing order,
+    /// then the complex conjugates in lexicographic order.
+    pub computed_poles: Vec<Complex<F>>,
+    /// The poles the algorithm was asked to place, sorted as above. They may differ from what
+    /// was achieved.
+    pub requested_poles: Vec<Complex<F>>,
+    /// The transfer matrix such as ``X diag(poles) = (A - B K) X``.
+    pub x: DMatrix<Complex<F>>,
+    /// The relative tolerance achieved on ``det(X)``, or `None` if `B` has full row rank and
+    /// there is nothing to optimize.
+    pub rtol: Option<F>,
+    /// The number of iterations performed before converging, or `None` if `B` has full row rank
+    /// and there is nothing to optimize.
+    pub nb_iter: Option<usize>,
+}
+
+/// Compute K such that eigenvalues (A - dot(B, K))=poles.
+///
+/// K is the gain matrix such as the plant described by the linear system
+/// ``AX+BU`` will have its closed-loop poles, i.e the eigenvalues ``A - B*K``,
+/// as close as possible to those asked for in poles.
+///
+/// SISO, MISO and MIMO systems are supported.
+///
+/// ## Parameters
+/// * `a`, `b`: ndarray
+///   State-space representation of linear system ``AX + BU``.
+/// * `poles`: array_like
+///   Desired real poles and/or complex conjugates poles.
+///   Complex poles are only supported with ``method="YT"`` (default).
+/// * `method`: [PlacePolesMethod], optional
+///   Which method to choose to find the gain matrix K. One of:
+///   * [PlacePolesMethod::Yt]: Yang Tits (default)
+///   * [PlacePolesMethod::Knv0]: Kautsky, Nichols, Van Dooren update method 0
+///
+///   See References and Notes for details on the algorithms.
+/// * `rtol`: float, optional
+///   After each iteration the determinant of the eigenvectors of
+///   ``A - B*K`` is compared to its previous value, when the relative
+///   error between these two values becomes lower than `rtol` the algorithm
+///   stops. Default is 1e-3.
+/// * `maxiter`: int, optional
+///   Maximum number of iterations to compute the gain matrix.
+///   Default is 30.
+///
+/// ## Returns
+/// * `full_state_feedback`: [PlacePolesResult]
+///
+/// ## Errors
+/// [Error::InvalidArg] if `a` is not square, if `b` does not have as many rows as `a`, if the
+/// number of poles differs from the number of states, if complex poles do not come with their
+/// conjugates or are used with [PlacePolesMethod::Knv0], if a pole is repeated more than
+/// ``rank(B)`` times, if `maxiter` is zero, if `rtol` is greater than 1, or if the poles can't be
+/// placed.
+///
+/// ## Notes
+/// The Tits and Yang (YT), \[2\] paper is an update of the original Kautsky et
+/// al. (KNV) paper \[1\]. KNV relies on rank-1 updates to find the transfer
+/// matrix X such that ``X * diag(poles) = (A - B*K)*X``, whereas YT uses
+/// rank-2 updates. This yields on average more robust solutions (see \[2\]
+/// pp 21-22), furthermore the YT algorithm supports complex poles whereas KNV
+/// does not in its original version. Only update method 0 proposed by KNV has
+/// been implemented here, hence the name ``'KNV0'``.
+///
+/// KNV extended to complex poles is used in Matlab's ``place`` function, YT is
+/// distributed under a non-free licence by Slicot under the name ``robpole``.
+/// It is unclear and undocumented how KNV0 has been extended to complex poles
+/// (Tits and Yang claim on page 14 of their paper that their method can not be
+/// used to extend KNV to complex poles), therefore only YT supports them in
+/// this implementation.
+///
+/// As the solution to the problem of pole placement is not unique for MIMO
+/// systems, both methods start with a tentative transfer matrix which is
+/// altered in various way to increase its determinant. Both methods have been
+/// proven to converge to a stable solution, however depending on the way the
+/// initial transfer matrix is chosen they will converge to different
+/// solutions and therefore there is absolutely no guarantee that using
+/// ``'KNV0'`` will yield results similar to Matlab's or any other
+/// implementation of these algorithms.
+///
+/// Using the default method ``'YT'`` should be fine in most cases; ``'KNV0'``
+/// is only provided because it is needed by ``'YT'`` in some specific cases.
+/// Furthermore ``'YT'`` gives on average more robust results than ``'KNV0'``
+/// when ``abs(det(X))`` is used as a robustness indicator.
+///
+/// Unlike scipy, no warning is emitted if the algorithm does not converge within `maxiter`
+/// iterations. Compare [PlacePolesResult::rtol] to the requested tolerance to detect it.
+///
+/// ## References
+/// \[1\] J. Kautsky, N.K. Nichols and P. van Dooren, "Robust pole assignment
+///     in linear state feedback", International Journal of Control, Vol. 41
+///     pp. 1129-1155, 1985.
+///
+/// \[2\] A.L. Tits and Y. Yang, "Globally convergent algorithms for robust
+///     pole assignment by state feedback", IEEE Transactions on Automatic
+///     Control, Vol. 41, pp. 1432-1452, 1996.
+///
+/// ## Examples
+/// A simple example demonstrating real pole placement using both KNV and YT
+/// algorithms. This is example number 1 from section 4 of the reference KNV
+/// publication (\[1\]):
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy import signal
+/// >>> A = np.array([[ 1.380,  -0.2077,  6.715, -5.676  ],
+/// ...               [-0.5814, -4.290,   0,      0.6750 ],
+/// ...               [ 1.067,   4.273,  -6.654,  5.893  ],
+/// ...               [ 0.0480,  4.273,   1.343, -2.104  ]])
+/// >>> B = np.array([[ 0,      5.679 ],
+/// ...               [ 1.136,  1.136 ],
+/// ...               [ 0,      0,    ],
+/// ...               [-3.146,  0     ]])
+/// >>> P = np.array([-0.2, -0.5, -5.0566, -8.6659])
+/// >>> fsf2 = signal.place_poles(A, B, P)  # uses YT method
+/// >>> fsf2.computed_poles
+/// array([-8.6659, -5.0566, -0.5   , -0.2   ])
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::{Complex, DMatrix};
+/// use sci_rs::signal::lti::place_poles;
+///
+/// let a = DMatrix::from_row_slice(4, 4, &[
+///     1.380, -0.2077, 6.715, -5.676,
+///     -0.5814, -4.290, 0., 0.6750,
+///     1.067, 4.273, -6.654, 5.893,
+///     0.0480, 4.273, 1.343, -2.104,
+/// ]);
+/// let b = DMatrix::from_row_slice(4, 2, &[0., 5.679, 1.136, 1.136, 0., 0., -3.146, 0.]);
+/// let p = [-0.2, -0.5, -5.0566, -8.6659].map(|p| Complex::new(p, 0.));
+/// let fsf2 = place_poles(&a, &b, &p, None, None, None).unwrap();
+/// for (c, e) in fsf2.computed_poles.iter().zip([-8.6659, -5.0566, -0.5, -0.2]) {
+///     assert!((c - Complex::new(e, 0.)).norm() < 1e-8);
+/// }
+/// ```
+pub fn place_poles<F>(
+    a: &DMatrix<F>,
+    b: &DMatrix<F>,
+    poles: &[Complex<F>],
+    method: Option<PlacePolesMethod>,
+    rtol: Option<F>,
+    maxiter: Option<usize>,
+) -> Result<PlacePolesResult<F>>
+where
+    F: Float + RealField,
+{
+    let method = method.unwrap_or_default();
+    let rtol = rtol.unwrap_or_else(|| F::from_f64(1e-3).unwrap());
+    let maxiter = maxiter.unwrap_or(30);
+    let poles = order_complex_poles(poles)?;
+    let n = a.nrows();
+    if !a.is_square() {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "A must be square".into(),
+        });
+    }
+    if b.nrows() != n {
+        return Err(Error::InvalidArg {
+            arg: "b".into(),
+            reason: "B must have as many rows as A".into(),
+        });
+    }
+    if poles.len() != n {
+        return Err(Error::InvalidArg {
+            arg: "poles".into(),
+            reason: "The number of poles must equal the number of states".into(),
+        });
+    }
+    let rank_b = matrix_rank(b);
+    if poles
+        .iter()
+        .any(|p| poles.iter().filter(|q| *q == p).count() > rank_b)
+    {
+        return Err(Error::InvalidArg {
+            arg: "poles".into(),
+            reason: "at least one of the requested pole is repeated more than rank(B) times".into(),
+        });
+    }
+    if method == PlacePolesMethod::Knv0 && poles.iter().any(|p| !p.im.is_zero()) {
+        return Err(Error::InvalidArg {
+            arg: "method".into(),
+            reason: "Complex poles are not supported by KNV0".into(),
+        });
+    }
+    if maxiter < 1 {
+        return Err(Error::InvalidArg {
+            arg: "maxiter".into(),
+            reason: "maxiter must be at least equal to 1".into(),
+        });
+    }
+    // Negative tolerances are allowed, to force maxiter iterations.
+    if rtol > F::one() {
+        return Err(Error::InvalidArg {
+            arg: "rtol".into(),
+            reason: "rtol can not be greater than 1".into(),
+        });
+    }
+    let to_complex = |m: &DMatrix<F>| m.map(|v| Complex::new(v, F::zero()));
+
+    // Step A: QR decomposition of B page 1132 KN
+    let u = full_q(b);
+    let u0 = u.columns(0, rank_b);
+    let u1 = u.columns(rank_b, n - rank_b);
+    let z = u0.transpose() * b;
+
+    let (gain_matrix, transfer_matrix, cur_rtol, nb_iter) = if rank_b == n {
+        // If B has as many rows as its rank, the identity is the best transfer matrix and
+        // ``K = B^+ (diag(P) - A)``, where complex conjugate poles ``a +/- bi`` are placed by the
+        // real block ``[[a, -b], [b, a]]``.
+        let mut diag_poles = DMatrix::zeros(n, n);
+        let mut idx = 0;
+        while idx < n {
+            let p = poles[idx];
+            diag_poles[(idx, idx)] = p.re;
+            if !p.im.is_zero() {
+                diag_poles[(idx, idx + 1)] = -p.im;
+                diag_poles[(idx + 1, idx + 1)] = p.re;
+                diag_poles[(idx + 1, idx)] = p.im;
+                idx += 1;
+            }
+            idx += 1;
+        }
+        let svd = b.clone().svd(true, true);
+        let eps = svd.singular_values.max() * F::default_epsilon();
+        let gain_matrix = svd.solve(&(diag_poles - a), eps).unwrap();
+        (gain_matrix, DMatrix::identity(n, n), None, None)
+    } else {
+        // Step A (p1144 KNV) and beginning of step F: decompose (U1^T (A - P[i] I))^T and
+        // build the transfer matrix vectors in the same loop.
+        let mut ker_pole = Vec::with_capacity(n);
+        let mut transfer_matrix = DMatrix::zeros(n, n);
+        let mut j = 0;
+        while j < n {
+            let p = poles[j];
+            let ker_pole_j = if p.im.is_zero() {
+                let pole_space_j =
+                    (u1.transpose() * (a - DMatrix::identity(n, n) * p.re)).transpose();
+                to_complex(
+                    &full_q(&pole_space_j)
+                        .columns(n - rank_b, rank_b)
+                        .into_owned(),
+                )
+            } else {
+                let ident = DMatrix::<Complex<F>>::identity(n, n);
+                let pole_space_j =
+                    (to_complex(&u1.transpose()) * (to_complex(a) - ident * p)).transpose();
+                full_q(&pole_space_j)
+                    .columns(n - rank_b, rank_b)
+                    .into_owned()
+            };
+
+            // Summing all the vectors of the kernel avoids transfer matrices with rows full of
+            // zeros and guarantees a non zero imaginary part for complex poles.
+            let transfer_matrix_j: DVector<Complex<F>> = ker_pole_j.column_sum();
+            let transfer_matrix_j = transfer_matrix_j.unscale(transfer_matrix_j.norm());
+            transfer_matrix.set_column(j, &transfer_matrix_j.map(|v| v.re));
+            if p.im.is_zero() {
+                ker_pole.push(ker_pole_j);
+                j += 1;
+            } else {
+                // Split complex vectors into their real and imaginary parts, and skip the
+                // conjugate pole.
+                transfer_matrix.set_column(j + 1, &transfer_matrix_j.map(|v| v.im));
+                ker_pole.push(ker_pole_j.clone());
+                ker_pole.push(ker_pole_j);
+                j += 2;
+            }
+        }
+
+        let (cur_rtol, nb_iter) = if rank_b > 1 {
+            // Otherwise there is nothing we can optimize.
+            match method {
+                PlacePolesMethod::Yt => {
+                    yt_loop(&ker_pole, &mut transfer_matrix, &poles, maxiter, rtol)
+                }
+                PlacePolesMethod::Knv0 => knv0_loop(&ker_pole, &mut transfer_matrix, maxiter, rtol),
+            }
+        } else {
+            (F::zero(), 0)
+        };
+
+        // Reconstruct the transfer matrix to match complex conjugate pairs, the real and
+        // imaginary parts become Re - Im i and Re + Im i.
+        let mut transfer_matrix = to_complex(&transfer_matrix);
+        let mut idx = 0;
+        while idx + 1 < n {
+            if !poles[idx].im.is_zero() {
+                let rel = transfer_matrix.column(idx).clone_owned();
+                let img = transfer_matrix.column(idx + 1) * Complex::i();
+                transfer_matrix.set_column(idx, &(&rel - &img));
+                transfer_matrix.set_column(idx + 1, &(rel + img));
+                idx += 1;
+            }
+            idx += 1;
+        }
+
+        let cant_place = || Error::InvalidArg {
+            arg: "poles".into(),
+            reason: "The poles you've chosen can't be placed. Check the controllability matrix \
+                and try another set of poles"
+                .into(),
+        };
+        if z.nrows() != z.ncols() {
+            return Err(cant_place());
+        }
+        let diag_poles = DMatrix::from_diagonal(&DVector::from_column_slice(&poles));
+        let m = transfer_matrix
+            .transpose()
+            .lu()
+            .solve(&(diag_poles * transfer_matrix.transpose()))
+            .ok_or_else(cant_place)?
+            .transpose();
+        let gain_matrix = to_complex(&z)
+            .lu()
+            .solve(&(to_complex(&u0.transpose()) * (m - to_complex(a))))
+            .ok_or_else(cant_place)?;
+        // The imaginary parts are only rounding errors.
+        (
+            gain_matrix.map(|v| v.re),
+            transfer_matrix,
+            Some(cur_rtol),
+            Some(nb_iter),
+        )
+    };
+
+    // Kautsky solves A + B K but the usual form is A - B K.
+    let gain_matrix = -gain_matrix;
+    let computed_poles: Vec<_> = (a - b * &gain_matrix)
+        .complex_eigenvalues()
+        .iter()
+        .copied()
+        .collect();
+    // Conjugate eigenvalues are computed as exact pairs, so the ordering cannot fail.
+    let computed_poles = order_complex_poles(&computed_poles).unwrap_or(computed_poles);
+    Ok(PlacePolesResult {
+        gain_matrix,
+        computed_poles,
+        requested_poles: poles,
+        x: transfer_matrix,
+        rtol: cur_rtol,
+        nb_iter,
+    })
+}
+
+/// Sort the real poles in increasing order, followed by the complex conjugate pairs in
+/// lexicographic order of the pole with the negative imaginary part.
+fn order_complex_poles<F: Float + RealField>(poles: &[Complex<F>]) -> Result<Vec<Complex<F>>> {
+    let cmp = |a: &Complex<F>, b: &Complex<F>| {
+        a.re.partial_cmp(&b.re)
+            .unwrap_or(Ordering::Equal)
+            .then(a.im.partial_cmp(&b.im).unwrap_or(Ordering::Equal))
+    };
+    let mut ordered: Vec<_> = poles.iter().copied().filter(|p| p.im.is_zero()).collect();
+    ordered.sort_by(cmp);
+    let mut im_poles: Vec<_> = poles.iter().copied().filter(|p| p.im < F::zero()).collect();
+    im_poles.sort_by(cmp);
+    for p in im_poles {
+        if poles.contains(&p.conj()) {
+            ordered.extend([p, p.conj()]);
+        }
+    }
+    if ordered.len() != poles.len() {
+        return Err(Error::InvalidArg {
+            arg: "poles".into(),
+            reason: "Complex poles must come with their conjugates".into(),
+        });
+    }
+    Ok(ordered)
+}
+
+/// Rank of `m`, counting the singular values above the tolerance used by numpy.
+fn matrix_rank<F: Float + RealField>(m: &DMatrix<F>) -> usize {
+    if m.is_empty() {
+        return 0;
+    }
+    let s = m.clone().svd(false, false).singular_values;
+    let tol = s.max() * F::from_usize(m.nrows().max(m.ncols())).unwrap() * F::default_epsilon();
+    s.iter().filter(|&&s| s > tol).count()
+}
+
+/// Square orthogonal matrix ``Q`` of the complete QR decomposition of `m`, whose trailing
+/// columns are an orthonormal basis of the orthogonal complement of the columns of `m`.
+fn full_q<T: ComplexField>(m: &DMatrix<T>) -> DMatrix<T> {
+    let n = m.nrows();
+    // The Householder reflections of the leading columns are those of `m` alone, while the
+    // identity completes ``Q`` to a square matrix.
+    let mut augmented = DMatrix::zeros(n, m.ncols() + n);
+    augmented.columns_mut(0, m.ncols()).copy_from(m);
+    for i in 0..n {
+        augmented[(i, m.ncols() + i)] = T::one();
+    }
+    augmented.qr().q()
+}
+
+/// Whether all elements are close to zero, as ``numpy.allclose(v, 0)``.
+fn all_close_zero<T: ComplexField>(v: &DMatrix<T>) -> bool {
+    let atol: T::RealField = nalgebra::convert(1e-8);
+    v.iter().all(|v| v.clone().modulus() <= atol)
+}
+
+/// Whether `a` and `b` are close, as ``numpy.allclose(a, b)``.
+fn is_close<F: Float>(a: F, b: F) -> bool {
+    Float::abs(a - b) <= F::from(1e-8).unwrap() + F::from(1e-5).unwrap() * Float::abs(b)
+}
+
+/// Real part of a real kernel stored with complex elements.
+fn real_part<F: Float + RealField>(m: &DMatrix<Complex<F>>) -> DMatrix<F> {
+    m.map(|v| v.re)
+}
+
+/// Algorithm "KNV0" Kautsky et Al. Robust pole assignment in linear state feedback, Int J
+/// Control 1985, vol 41 p 1129->1155
+fn knv0<F: Float + RealField>(
+    ker_pole: &[DMatrix<Complex<F>>],
+    transfer_matrix: &mut DMatrix<F>,
+    j: usize,
+) {
+    let n = transfer_matrix.nrows();
+    // The last column of the complete QR of the other vectors is orthogonal to all of them.
+    let q = full_q(&transfer_matrix.clone().remove_column(j));
+    let ker_pole_j = real_part(&ker_pole[j]);
+    let yj = &ker_pole_j * (ker_pole_j.transpose() * q.columns(n - 1, 1));
+
+    // If the last column of Q is almost orthogonal to the kernel its projection is close to 0,
+    // so stick with the current vector.
+    if !all_close_zero(&yj) {
+        let xj = yj.unscale(yj.norm());
+        transfer_matrix.set_column(j, &xj.column(0));
+    }
+}
+
+/// Applies algorithm from YT section 6.1 page 19 related to real pairs.
+fn yt_real<F: Float + RealField>(
+    ker_pole: &[DMatrix<Complex<F>>],
+    q: &DMatrix<F>,
+    transfer_matrix: &mut DMatrix<F>,
+    i: usize,
+    j: usize,
+) {
+    let n = transfer_matrix.nrows();
+    // step 1 page 19
+    let u = q.column(n - 2);
+    let v = q.column(n - 1);
+
+    // step 2 page 19
+    let ker_pole_i = real_part(&ker_pole[i]);
+    let ker_pole_j = real_part(&ker_pole[j]);
+    let m = ker_pole_i.transpose() * (u * v.transpose() - v * u.transpose()) * &ker_pole_j;
+
+    // step 3 page 19
+    let svd = m.svd(true, true);
+    let (um, sm, vm) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+    let (mu1, mu2) = (um.column(0), um.column(1));
+    let (nu1, nu2) = (vm.row(0).transpose(), vm.row(1).transpose());
+
+    // step 4, section 6.2 page 20
+    let mut transfer_matrix_ij = DMatrix::zeros(2 * n, 1);
+    transfer_matrix_ij
+        .rows_mut(0, n)
+        .copy_from(&transfer_matrix.column(i));
+    transfer_matrix_ij
+        .rows_mut(n, n)
+        .copy_from(&transfer_matrix.column(j));
+
+    let ker_pole_mu_nu = if !is_close(sm[0], sm[1]) {
+        let mut ker_pole_mu_nu = DMatrix::zeros(2 * n, 1);
+        ker_pole_mu_nu
+            .rows_mut(0, n)
+            .copy_from(&(&ker_pole_i * mu1));
+        ker_pole_mu_nu
+            .rows_mut(n, n)
+            .copy_from(&(&ker_pole_j * &nu1));
+        ker_pole_mu_nu
+    } else {
+        let mut ker_pole_mu_nu = DMatrix::zeros(2 * n, 2);
+        ker_pole_mu_nu
+            .view_mut((0, 0), (n, 1))
+            .copy_from(&(&ker_pole_i * mu1));
+        ker_pole_mu_nu
+            .view_mut((0, 1), (n, 1))
+            .copy_from(&(&ker_pole_i * mu2));
+        ker_pole_mu_nu
+            .view_mut((n, 0), (n, 1))
+            .copy_from(&(&ker_pole_j * &nu1));
+        ker_pole_mu_nu
+            .view_mut((n, 1), (n, 1))
+            .copy_from(&(&ker_pole_j * &nu2));
+        ker_pole_mu_nu
+    };
+    let transfer_matrix_ij = &ker_pole_mu_nu * (ker_pole_mu_nu.transpose() * transfer_matrix_ij);
+    let update = if !all_close_zero(&transfer_matrix_ij) {
+        transfer_matrix_ij.scale(Float::sqrt(F::from(2.).unwrap()) / transfer_matrix_ij.norm())
+    } else {
+        // As in KNV0, if the current vectors are orthogonal to the span of ker_pole_mu_nu, use
+        // its first vector and iterate.
+        ker_pole_mu_nu.columns(0, 1).into_owned()
+    };
+    transfer_matrix.set_column(i, &update.column(0).rows(0, n));
+    transfer_matrix.set_column(j, &update.column(0).rows(n, n));
+}
+
+/// Applies algorithm from YT section 6.2 page 20 related to complex pairs.
+fn yt_complex<F: Float + RealField>(
+    ker_pole: &[DMatrix<Complex<F>>],
+    q: &DMatrix<F>,
+    transfer_matrix: &mut DMatrix<F>,
+    i: usize,
+    j: usize,
+) {
+    let n = transfer_matrix.nrows();
+    let sqrt2 = Float::sqrt(F::from(2.).unwrap());
+    // step 1 page 20
+    let u = DVector::from_iterator(
+        n,
+        q.column(n - 2)
+            .iter()
+            .zip(q.column(n - 1).iter())
+            .map(|(&ur, &ui)| Complex::new(ur * sqrt2, ui * sqrt2)),
+    );
+
+    // step 2 page 20
+    let ker_pole_ij = &ker_pole[i];
+    let m =
+        ker_pole_ij.adjoint() * (&u * u.adjoint() - u.conjugate() * u.transpose()) * ker_pole_ij;
+
+    // step 3 page 20, m is Hermitian so its eigenvalues are real
+    let eig = SymmetricEigen::new(m);
+    let mut e_val_idx: Vec<usize> = (0..eig.eigenvalues.len()).collect();
+    e_val_idx.sort_by(|&a, &b| {
+        Float::abs(eig.eigenvalues[a])
+            .partial_cmp(&Float::abs(eig.eigenvalues[b]))
+            .unwrap_or(Ordering::Equal)
+    });
+    let (last, second) = (
+        e_val_idx[e_val_idx.len() - 1],
+        e_val_idx[e_val_idx.len() - 2],
+    );
+
+    // step 4, remember that the complex vector was split as its real part in column i and its
+    // imaginary part in column j
+    let transfer_matrix_ij = DMatrix::from_iterator(
+        n,
+        1,
+        transfer_matrix
+            .column(i)
+            .iter()
+            .zip(transfer_matrix.column(j).iter())
+            .map(|(&re, &im)| Complex::new(re, im)),
+    );
+    let ker_pole_mu = if !is_close(
+        Float::abs(eig.eigenvalues[last]),
+        Float::abs(eig.eigenvalues[second]),
+    ) {
+        ker_pole_ij * eig.eigenvectors.columns(last, 1)
+    } else {
+        ker_pole_ij * eig.eigenvectors.select_columns(&[last, second])
+    };
+    let transfer_matrix_ij = &ker_pole_mu * (ker_pole_mu.adjoint() * transfer_matrix_ij);
+    let update = if !all_close_zero(&transfer_matrix_ij) {
+        transfer_matrix_ij.unscale(transfer_matrix_ij.norm())
+    } else {
+        // Same idea as in yt_real
+        ker_pole_mu.columns(0, 1).into_owned()
+    };
+    transfer_matrix.set_column(i, &update.column(0).map(|v| v.re));
+    transfer_matrix.set_column(j, &update.column(0).map(|v| v.im));
+}
+
+/// Order of the pairs of transfer matrix columns updated by the YT algorithm, where a pair
+/// ``(i, i)`` is a KNV0 update of a single real pole.
+///
+/// The IEEE edition of the YT paper gives the optimal update order for the real poles, in order
+/// to minimize the number of times we have to loop over all poles, see page 1442. The indices
+/// follow the paper and start at 1.
+fn yt_update_order<F: Float + RealField>(poles: &[Complex<F>]) -> Vec<(usize, usize)> {
+    let nb_real = poles.iter().filter(|p| p.im.is_zero()).count();
+    // hnb => Half Nb Real
+    let hnb = nb_real / 2;
+    let odd = nb_real % 2;
+    // The paper is unclear about what should be done when there is only one real pole, use
+    // KNV0 on it.
+    let knv0_first = hnb == 0 && poles[0].im.is_zero();
+
+    let mut order = vec![];
+    if nb_real > 0 {
+        // Update the biggest real pole with the smallest one
+        order.push((nb_real, 1));
+    }
+    let r_comp: Vec<(usize, usize)> = (nb_real + 1..=poles.len())
+        .step_by(2)
+        .map(|r| (r, r + 1))
+        .collect();
+    let knv0_and_complex = |order: &mut Vec<(usize, usize)>| {
+        if knv0_first {
+            order.push((1, 1));
+        }
+        order.extend(&r_comp);
+    };
+    // step 1.a
+    order.extend((1..hnb + odd).map(|r| (2 * r, 2 * r + 1)));
+    // step 1.b
+    order.extend(&r_comp);
+    // step 1.c
+    order.extend((1..=hnb).map(|r| (2 * r - 1, 2 * r)));
+    // step 1.d
+    knv0_and_complex(&mut order);
+    // step 2.a
+    for j in 2..hnb + odd {
+        order.extend((1..=hnb).map(|i| (i, i + j)));
+    }
+    // step 2.b
+    knv0_and_complex(&mut order);
+    // step 2.c
+    for j in 2..hnb + odd {
+        for i in hnb + 1..=nb_real {
+            let idx_1 = if i + j > nb_real {
+                i + j - nb_real
+            } else {
+                i + j
+            };
+            order.push((i, idx_1));
+        }
+    }
+    // step 2.d
+    knv0_and_complex(&mut order);
+    // step 3.a
+    order.extend((1..=hnb).map(|i| (i, i + hnb)));
+    // step 3.b
+    knv0_and_complex(&mut order);
+
+    order.into_iter().map(|(i, j)| (i - 1, j - 1)).collect()
+}
+
+/// Algorithm "YT" Tits, Yang. Globally Convergent Algorithms for Robust Pole Assignment by
+/// State Feedback https://hdl.handle.net/1903/5598
+///
+/// Returns the achieved relative tolerance and the number of iterations.
+fn yt_loop<F: Float + RealField>(
+    ker_pole: &[DMatrix<Complex<F>>],
+    transfer_matrix: &mut DMatrix<F>,
+    poles: &[Complex<F>],
+    maxiter: usize,
+    rtol: F,
+) -> (F, usize) {
+    let update_order = yt_update_order(poles);
+    iterate(transfer_matrix, maxiter, rtol, |transfer_matrix| {
+        for &(i, j) in &update_order {
+            if i == j {
+                knv0(ker_pole, transfer_matrix, i);
+            } else {
+                let q = full_q(&transfer_matrix.clone().remove_columns_at(&[i, j]));
+                if poles[i].im.is_zero() {
+                    yt_real(ker_pole, &q, transfer_matrix, i, j);
+                } else {
+                    yt_complex(ker_pole, &q, transfer_matrix, i, j);
+                }
+            }
+        }
+    })
+}
+
+/// Loop over all poles one by one and apply KNV method 0 algorithm.
+///
+/// Returns the achieved relative tolerance and the number of iterations.
+fn knv0_loop<F: Float + RealField>(
+    ker_pole: &[DMatrix<Complex<F>>],
+    transfer_matrix: &mut DMatrix<F>,
+    maxiter: usize,
+    rtol: F,
+) -> (F, usize) {
+    iterate(transfer_matrix, maxiter, rtol, |transfer_matrix| {
+        for j in 0..transfer_matrix.ncols() {
+            knv0(ker_pole, transfer_matrix, j);
+        }
+    })
+}
+
+/// Apply `update` until the relative change of ``det(X)`` is below `rtol`, as in the
+/// convergence test from YT page 21, or for `maxiter` iterations.
+fn iterate<F: Float + RealField>(
+    transfer_matrix: &mut DMatrix<F>,
+    maxiter: usize,
+    rtol: F,
+    mut update: impl FnMut(&mut DMatrix<F>),
+) -> (F, usize) {
+    let sqrt_eps = Float::sqrt(F::epsilon());
+    let mut cur_rtol = F::zero();
+    for nb_try in 1..=maxiter {
+        let det_transfer_matrixb = Float::abs(transfer_matrix.determinant());
+        update(transfer_matrix);
+        let det_transfer_matrix = Float::max(sqrt_eps, Float::abs(transfer_matrix.determinant()));
+        cur_rtol = Float::abs((det_transfer_matrix - det_transfer_matrixb) / det_transfer_matrix);
+        if cur_rtol < rtol && det_transfer_matrix > sqrt_eps {
+            return (cur_rtol, nb_try);
+        }
+    }
+    (cur_rtol, maxiter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use approx::assert_abs_diff_eq;
+
+    fn real_poles(p: &[f64]) -> Vec<Complex<f64>> {
+        p.iter().map(|&p| Complex::new(p, 0.)).collect()
+    }
+
+    /// Poles match up to a permutation, since repeated poles may be computed as close complex
+    /// conjugates.
+    #[track_caller]
+    fn assert_poles_close(actual: &[Complex<f64>], expected: &[Complex<f64>]) {
+        assert_eq!(actual.len(), expected.len());
+        let mut actual = actual.to_vec();
+        for e in expected {
+            let (idx, d) = actual
+                .iter()
+                .map(|c| (c - e).norm())
+                .enumerate()
+                .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+                .unwrap();
+            assert!(d < 1e-5 * (1. + e.norm()), "{e} not placed: {actual:?}");
+            actual.remove(idx);
+        }
+    }
+
+    #[track_caller]
+    fn check(
+        a: &DMatrix<f64>,
+        b: &DMatrix<f64>,
+        p: &[Complex<f64>],
+        method: Option<PlacePolesMethod>,
+        maxiter: Option<usize>,
+    ) -> PlacePolesResult<f64> {
+        let fsf = place_poles(a, b, p, method, None, maxiter).unwrap();
+        let closed_loop = a - b * &fsf.gain_matrix;
+        let eig: Vec<_> = closed_loop.complex_eigenvalues().iter().copied().collect();
+        assert_eq!(fsf.requested_poles, order_complex_poles(p).unwrap());
+        assert_poles_close(&eig, p);
+        assert_poles_close(&fsf.computed_poles, p);
+        // X diag(P) = (A - B K) X
+        let diag = DMatrix::from_diagonal(&DVector::from_column_slice(&fsf.requested_poles));
+        let lhs = &fsf.x * diag;
+        let rhs = closed_loop.map(|v| Complex::new(v, 0.)) * &fsf.x;
+        assert!((lhs - rhs).norm() < 1e-6 * (1. + fsf.x.norm()));
+        fsf
+    }
+
+    fn knv_example() -> (DMatrix<f64>, DMatrix<f64>) {
+        let a = DMatrix::from_row_slice(
+            4,
+            4,
+            &[
+                1.380, -0.2077, 6.715, -5.676, -0.5814, -4.290, 0., 0.6750, 1.067, 4.273, -6.654,
+                5.893, 0.0480, 4.273, 1.343, -2.104,
+            ],
+        );
+        let b = DMatrix::from_row_slice(4, 2, &[0., 5.679, 1.136, 1.136, 0., 0., -3.146, 0.]);
+        (a, b)
+    }
+
+    #[test]
+    fn real() {
+        let (a, b) = knv_example();
+        let p = real_poles(&[-0.2, -0.5, -5.0566, -8.6659]);
+        let knv = check(&a, &b, &p, Some(PlacePolesMethod::Knv0), None);
+        let yt = check(&a, &b, &p, None, None);
+        // Both optimize the conditioning of the eigenvectors.
+        for fsf in [&knv, &yt] {
+            assert!(fsf.nb_iter.unwrap() >= 1);
+            let det = fsf.x.map(|v| v.re).determinant().abs();
+            assert!(det > 0.5, "{det}");
+        }
+
+        // Repeated poles and nearly equal singular values in yt_real
+        check(&a, &b, &real_poles(&[2., 2., 3., 3.]), None, None);
+        // Odd number of real poles
+        check(&a, &b, &real_poles(&[-1., -2., -3., -4.]), None, Some(1));
+    }
+
+    #[test]
+    fn complex() {
+        // Linearized car model, L. Jaulin, Automatique pour la robotique, p 184/185
+        let a = DMatrix::from_row_slice(
+            4,
+            4,
+            &[
+                0.,
+                7.,
+                0.,
+                0.,
+                0.,
+                0.,
+                0.,
+                7. / 3.,
+                0.,
+                0.,
+                0.,
+                0.,
+                0.,
+                0.,
+                0.,
+                0.,
+            ],
+        );
+        let b = DMatrix::from_row_slice(4, 2, &[0., 0., 0., 0., 1., 0., 0., 1.]);
+        let p = [
+            Complex::new(-3., 0.),
+            Complex::new(-1., 0.),
+            Complex::new(-2., -1.),
+            Complex::new(-2., 1.),
+        ];
+        check(&a, &b, &p, None, None);
+
+        let p = [
+            Complex::new(0., -1e-6),
+            Complex::new(0., 1e-6),
+            Complex::new(-10., 0.),
+            Complex::new(10., 0.),
+        ];
+        check(&a, &b, &p, None, Some(1000));
+
+        // Only complex poles
+        let p = [
+            Complex::new(-1., -1.),
+            Complex::new(-1., 1.),
+            Complex::new(-2., -3.),
+            Complex::new(-2., 3.),
+        ];
+        check(&a, &b, &p, None, None);
+        assert!(place_poles(&a, &b, &p, Some(PlacePolesMethod::Knv0), None, None).is_err());
+
+        // Rank two updates in yt_complex yielding null vectors, found via Monte Carlo by scipy.
+        let a = DMatrix::from_row_slice(
+            6,
+            6,
+            &[
+                -2148., -2902., -2267., -598., -1722., -1829., -165., -283., -2546., -167., -754.,
+                -2285., -543., -1700., -584., -2978., -925., -1300., -1583., -984., -386., -2650.,
+                -764., -897., -517., -1598., 2., -1709., -291., -338., -153., -1804., -1106.,
+                -1168., -867., -2297.,
+            ],
+        );
+        let b = DMatrix::from_row_slice(
+            6,
+            5,
+            &[
+                -108., -374., -524., -1285., -1232., -161., -1204., -672., -637., -15., -483.,
+                -23., -931., -780., -1245., -1129., -1290., -1502., -952., -1374., -62., -964.,
+                -930., -939., -792., -756., -1437., -491., -1543., -686.,
+            ],
+        );
+        let p = [
+            Complex::new(-25., -29.),
+            Complex::new(-25., 29.),
+            Complex::new(31., -42.),
+            Complex::new(31., 42.),
+            Complex::new(33., -41.),
+            Complex::new(33., 41.),
+        ];
+        check(&a, &b, &p, None, None);
+    }
+
+    #[test]
+    fn single_input() {
+        // The gain is unique, the characteristic polynomial of A - B K is s^2 + 3 s + 2.
+        let a = DMatrix::from_row_slice(2, 2, &[0., 1., 0., 0.]);
+        let b = DMatrix::from_row_slice(2, 1, &[0., 1.]);
+        let fsf = check(&a, &b, &real_poles(&[-1., -2.]), None, None);
+        assert_abs_diff_eq!(fsf.gain_matrix[(0, 0)], 2., epsilon = 1e-10);
+        assert_abs_diff_eq!(fsf.gain_matrix[(0, 1)], 3., epsilon = 1e-10);
+        assert_eq!(fsf.rtol, Some(0.));
+        assert_eq!(fsf.nb_iter, Some(0));
+    }
+
+    #[test]
+    fn full_rank() {
+        // B is invertible, so A - B K is the real block diagonal form of the poles.
+        let a = DMatrix::from_row_slice(2, 2, &[1., 2., 3., 4.]);
+        let b = DMatrix::from_row_slice(2, 2, &[2., 0., 0., 1.]);
+        let p = [Complex::new(-1., 2.), Complex::new(-1., -2.)];
+        let fsf = place_poles(&a, &b, &p, None, None, None).unwrap();
+        let closed_loop = &a - &b * &fsf.gain_matrix;
+        let expected = DMatrix::from_row_slice(2, 2, &[-1., 2., -2., -1.]);
+        for (c, e) in closed_loop.iter().zip(expected.iter()) {
+            assert_abs_diff_eq!(c, e, epsilon = 1e-12);
+        }
+        assert_eq!(fsf.x, DMatrix::identity(2, 2));
+        assert_eq!(fsf.rtol, None);
+        assert_eq!(fsf.nb_iter, None);
+        for (c, e) in fsf.computed_poles.iter().zip([p[1], p[0]]) {
+            assert_abs_diff_eq!((c - e).norm(), 0., epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn invalid_args() {
+        let (a, b) = knv_example();
+        let p = real_poles(&[-1., -2., -3., -4.]);
+        let place = |a: &DMatrix<f64>, b: &DMatrix<f64>, p: &[Complex<f64>]| {
+            place_poles(a, b, p, None, None, None)
+        };
+        assert!(place(&a, &b, &p[..3]).is_err());
+        assert!(place(&a.columns(0, 3).into_owned(), &b, &p[..3]).is_err());
+        assert!(place(&a, &b.rows(0, 3).into_owned(), &p).is_err());
+        assert!(place(&a, &b, &real_poles(&[-1., -1., -1., -2.])).is_err());
+        let unpaired = [p[0], p[1], Complex::new(-1., 1.), Complex::new(-2., -1.)];
+        assert!(place(&a, &b, &unpaired).is_err());
+        assert!(place_poles(&a, &b, &p, None, Some(2.), None).is_err());
+        assert!(place_poles(&a, &b, &p, None, None, Some(0)).is_err());
+        // Negative tolerances force maxiter iterations.
+        let fsf = place_poles(&a, &b, &p, None, Some(-1.), Some(5)).unwrap();
+        assert_eq!(fsf.nb_iter, Some(5));
+    }
+}
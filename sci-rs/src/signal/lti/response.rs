@@ -0,0 +1,264 @@
+use super::{lsim, Lti};
+use alloc::vec::Vec;
+use nalgebra::{DMatrix, RealField};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Impulse response of continuous-time system.
+///
+/// ## Parameters
+/// * `system`: [Lti]
+///   A continuous-time system with a single input.
+/// * `x0`: array_like, optional
+///   Initial state-vector. Defaults to zero.
+/// * `t`: array_like, optional
+///   Time points. Computed if not given.
+/// * `n`: int, optional
+///   The number of time points to compute (if `t` is not given). Defaults to 100.
+///
+/// ## Returns
+/// * `t`: ndarray
+///   A 1-D array of time points.
+/// * `yout`: ndarray
+///   The impulse response of the system, with one row per time and one column per output. The
+///   direct feedthrough ``D`` does not contribute to the response.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `system` is discrete-time or does not have exactly one input, or if
+/// `x0` or `t` are invalid for [lsim].
+///
+/// ## Notes
+/// If `t` is not given, the `n` time points are spaced evenly over seven time constants of the
+/// slowest pole, where the time constant of a pole is the inverse of the magnitude of its real
+/// part.
+///
+/// ## Examples
+/// Compute the impulse response of a second order system with a repeated root:
+/// ``x''(t) + 2*x'(t) + x(t) = u(t)``
+/// ```custom,{class=language-python}
+/// >>> from scipy import signal
+/// >>> system = ([1.0], [1.0, 2.0, 1.0])
+/// >>> t, y = signal.impulse(system)
+/// >>> t[-1], y[-1]
+/// (7.0, 0.006383...)
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::signal::lti::{impulse, TransferFunction};
+///
+/// let system = TransferFunction::<f64>::new(&[1.0], &[1.0, 2.0, 1.0], None).unwrap();
+/// let (t, y) = impulse(&system, None, None, None).unwrap();
+/// assert_eq!(t.len(), 100);
+/// assert!((t[99] - 7.0).abs() < 1e-6);
+/// // The impulse response is t * exp(-t).
+/// assert!((y[99] - 7.0 * (-7.0f64).exp()).abs() < 1e-6);
+/// ```
+pub fn impulse<F, L>(
+    system: &L,
+    x0: Option<&[F]>,
+    t: Option<&[F]>,
+    n: Option<usize>,
+) -> Result<(Vec<F>, DMatrix<F>)>
+where
+    F: Float + RealField,
+    L: Lti<F>,
+{
+    let sys = continuous_ss(system)?;
+    // An impulse sets the initial state to B, after which the input is zero.
+    let mut x: Vec<F> = sys.b.column(0).iter().copied().collect();
+    if let Some(x0) = x0 {
+        if x0.len() != x.len() {
+            return Err(Error::InvalidArg {
+                arg: "x0".into(),
+                reason: "X0 must have one element per state.".into(),
+            });
+        }
+        x.iter_mut().zip(x0).for_each(|(x, &x0)| *x += x0);
+    }
+    let t = match t {
+        Some(t) => t.to_vec(),
+        None => default_response_times(&sys.a, n.unwrap_or(100)),
+    };
+    let (t, y, _) = lsim(&sys, None, &t, Some(&x), Some(false))?;
+    Ok((t, y))
+}
+
+/// Step response of continuous-time system.
+///
+/// ## Parameters
+/// * `system`: [Lti]
+///   A continuous-time system with a single input.
+/// * `x0`: array_like, optional
+///   Initial state-vector (default is zero).
+/// * `t`: array_like, optional
+///   Time points (computed if not given).
+/// * `n`: int, optional
+///   Number of time points to compute if `t` is not given. Defaults to 100.
+///
+/// ## Returns
+/// * `t`: 1D ndarray
+///   Output time points.
+/// * `yout`: ndarray
+///   Step response of system, with one row per time and one column per output.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `system` is discrete-time or does not have exactly one input, or if
+/// `x0` or `t` are invalid for [lsim].
+///
+/// ## Notes
+/// If `t` is not given, the time points are chosen as in [impulse].
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> from scipy import signal
+/// >>> lti = signal.lti([1.0], [1.0, 1.0])
+/// >>> t, y = signal.step(lti)
+/// >>> t[-1], y[-1]
+/// (7.0, 0.999088...)
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::signal::lti::{step, TransferFunction};
+///
+/// let lti = TransferFunction::<f64>::new(&[1.0], &[1.0, 1.0], None).unwrap();
+/// let (t, y) = step(&lti, None, None, None).unwrap();
+/// assert!((t[99] - 7.0).abs() < 1e-6);
+/// assert!((y[99] - (1.0 - (-7.0f64).exp())).abs() < 1e-6);
+/// ```
+pub fn step<F, L>(
+    system: &L,
+    x0: Option<&[F]>,
+    t: Option<&[F]>,
+    n: Option<usize>,
+) -> Result<(Vec<F>, DMatrix<F>)>
+where
+    F: Float + RealField,
+    L: Lti<F>,
+{
+    let sys = continuous_ss(system)?;
+    let t = match t {
+        Some(t) => t.to_vec(),
+        None => default_response_times(&sys.a, n.unwrap_or(100)),
+    };
+    let u = DMatrix::from_element(t.len(), 1, F::one());
+    let (t, y, _) = lsim(&sys, Some(&u), &t, x0, Some(false))?;
+    Ok((t, y))
+}
+
+/// State-space form of a continuous-time system with a single input.
+fn continuous_ss<F, L>(system: &L) -> Result<super::StateSpace<F>>
+where
+    F: Float + RealField,
+    L: Lti<F>,
+{
+    if system.dt().is_some() {
+        return Err(Error::InvalidArg {
+            arg: "system".into(),
+            reason: "The system must be a continuous-time lti system.".into(),
+        });
+    }
+    let sys = system.to_ss()?;
+    if sys.inputs() != 1 {
+        return Err(Error::InvalidArg {
+            arg: "system".into(),
+            reason: "The system must have a single input.".into(),
+        });
+    }
+    Ok(sys)
+}
+
+/// A reasonable time interval of `n` points, spanning seven time constants of the slowest pole
+/// of the state matrix `a`.
+pub(crate) fn default_response_times<F: Float + RealField>(a: &DMatrix<F>, n: usize) -> Vec<F> {
+    let r = a
+        .complex_eigenvalues()
+        .iter()
+        .map(|v| Float::abs(v.re))
+        .fold(F::infinity(), Float::min);
+    let r = if r.is_zero() || r.is_infinite() {
+        F::one()
+    } else {
+        r
+    };
+    let stop = F::from(7).unwrap() / r;
+    match n {
+        0 => Vec::new(),
+        1 => alloc::vec![F::zero()],
+        n => (0..n)
+            .map(|i| stop * F::from(i).unwrap() / F::from(n - 1).unwrap())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::lti::{StateSpace, TransferFunction, ZerosPolesGain};
+    use alloc::{vec, vec::Vec};
+    use approx::assert_abs_diff_eq;
+    use nalgebra::Complex;
+
+    #[test]
+    fn default_times() {
+        // The slowest pole at -0.5 has a time constant of 2.
+        let sys = ZerosPolesGain::new(
+            vec![],
+            vec![
+                Complex::new(-0.5, 3.),
+                Complex::new(-0.5, -3.),
+                Complex::new(-4., 0.),
+            ],
+            1.,
+            None,
+        )
+        .unwrap();
+        let (t, y) = step(&sys, None, None, Some(15)).unwrap();
+        assert_eq!(t.len(), 15);
+        assert_abs_diff_eq!(t[14], 14., epsilon = 1e-10);
+        assert_eq!(y.shape(), (15, 1));
+
+        // Integrators fall back to a time constant of 1.
+        let integrator = TransferFunction::new(&[1.], &[1., 0.], None).unwrap();
+        let (t, y) = step(&integrator, None, None, None).unwrap();
+        assert_abs_diff_eq!(t[99], 7., epsilon = 1e-10);
+        for (&t, &y) in t.iter().zip(y.iter()) {
+            assert_abs_diff_eq!(y, t, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn first_order() {
+        let sys = TransferFunction::new(&[2.], &[1., 1.], None).unwrap();
+        let t: Vec<f64> = (0..30).map(|i| 0.1 * i as f64).collect();
+        let (_, y) = step(&sys, None, Some(&t), None).unwrap();
+        for (&t, &y) in t.iter().zip(y.iter()) {
+            assert_abs_diff_eq!(y, 2. * (1. - (-t).exp()), epsilon = 1e-10);
+        }
+        let (_, y) = impulse(&sys, None, Some(&t), None).unwrap();
+        for (&t, &y) in t.iter().zip(y.iter()) {
+            assert_abs_diff_eq!(y, 2. * (-t).exp(), epsilon = 1e-10);
+        }
+        // The initial state adds to the state set by the impulse.
+        let (_, y) = impulse(&sys, Some(&[1.]), Some(&t), None).unwrap();
+        for (&t, &y) in t.iter().zip(y.iter()) {
+            assert_abs_diff_eq!(y, 4. * (-t).exp(), epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn invalid_systems() {
+        let discrete = TransferFunction::new(&[1.], &[1., 1.], Some(1.)).unwrap();
+        assert!(step(&discrete, None, None, None).is_err());
+        assert!(impulse(&discrete, None, None, None).is_err());
+        let mimo = StateSpace::new(
+            DMatrix::from_row_slice(1, 1, &[-1.]),
+            DMatrix::from_row_slice(1, 2, &[1., 1.]),
+            DMatrix::from_row_slice(1, 1, &[1.]),
+            DMatrix::from_row_slice(1, 2, &[0., 0.]),
+            None,
+        )
+        .unwrap();
+        assert!(step(&mimo, None, None, None).is_err());
+        assert!(impulse(&mimo, None, None, None).is_err());
+    }
+}
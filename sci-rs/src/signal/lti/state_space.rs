@@ -0,0 +1,274 @@
+use super::{
+    check_dt, check_same_dt,
+    conversions::{check_abcd, ss2tf, ss2zpk},
+    Lti, TransferFunction, ZerosPolesGain,
+};
+use nalgebra::{DMatrix, RealField};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Linear Time Invariant system in state-space form.
+///
+/// Represents the system as the continuous-time, first order differential
+/// equation ``x' = A x + B u`` and ``y = C x + D u``, or the discrete-time difference equation
+/// ``x[k+1] = A x[k] + B u[k]`` and ``y[k] = C x[k] + D u[k]``, with `n` states, `m` inputs
+/// and `p` outputs.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// >>> from scipy import signal
+/// >>> import numpy as np
+/// >>> a = np.array([[0, 1], [0, 0]])
+/// >>> b = np.array([[0], [1]])
+/// >>> c = np.array([[1, 0]])
+/// >>> d = np.array([[0]])
+/// >>> sys = signal.StateSpace(a, b, c, d)
+/// >>> sys.to_tf()
+/// TransferFunctionContinuous(
+/// array([1.]),
+/// array([1., 0., 0.]),
+/// dt: None
+/// )
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::DMatrix;
+/// use sci_rs::signal::lti::{Lti, StateSpace};
+///
+/// let a = DMatrix::from_row_slice(2, 2, &[0., 1., 0., 0.]);
+/// let b = DMatrix::from_row_slice(2, 1, &[0., 1.]);
+/// let c = DMatrix::from_row_slice(1, 2, &[1., 0.]);
+/// let d = DMatrix::zeros(1, 1);
+/// let sys = StateSpace::new(a, b, c, d, None).unwrap();
+/// let tf = sys.to_tf().unwrap();
+/// assert_eq!(tf.num, vec![1.]);
+/// assert_eq!(tf.den, vec![1., 0., 0.]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateSpace<F: RealField> {
+    /// State matrix of shape `(n, n)`.
+    pub a: DMatrix<F>,
+    /// Input matrix of shape `(n, m)`.
+    pub b: DMatrix<F>,
+    /// Output matrix of shape `(p, n)`.
+    pub c: DMatrix<F>,
+    /// Feedthrough matrix of shape `(p, m)`.
+    pub d: DMatrix<F>,
+    /// Sampling time of a discrete-time system, `None` for continuous-time systems.
+    pub dt: Option<F>,
+}
+
+impl<F: Float + RealField> StateSpace<F> {
+    /// Create a system from its state-space matrices.
+    ///
+    /// ## Errors
+    /// [Error::InvalidArg] if the shapes of the matrices are inconsistent or `dt` is not positive.
+    pub fn new(
+        a: DMatrix<F>,
+        b: DMatrix<F>,
+        c: DMatrix<F>,
+        d: DMatrix<F>,
+        dt: Option<F>,
+    ) -> Result<Self> {
+        check_abcd(&a, &b, &c, &d)?;
+        Ok(Self {
+            a,
+            b,
+            c,
+            d,
+            dt: check_dt(dt)?,
+        })
+    }
+
+    /// Number of inputs of the system.
+    pub fn inputs(&self) -> usize {
+        self.d.ncols()
+    }
+
+    /// Number of outputs of the system.
+    pub fn outputs(&self) -> usize {
+        self.d.nrows()
+    }
+
+    /// Series interconnection, the output of `other` is the input of `self`. This is scipy's
+    /// ``self * other``.
+    ///
+    /// ## Errors
+    /// [Error::InvalidArg] if the number of inputs of `self` differs from the number of outputs of
+    /// `other`. [Error::ConflictArg] if the systems have different sampling times.
+    pub fn series(&self, other: &Self) -> Result<Self> {
+        check_same_dt(self.dt, other.dt)?;
+        if self.inputs() != other.outputs() {
+            return Err(Error::InvalidArg {
+                arg: "other".into(),
+                reason: "Cannot multiply systems with incompatible dimensions.".into(),
+            });
+        }
+        let (n1, n2) = (self.a.nrows(), other.a.nrows());
+
+        // [x1']   [A1 B1*C2 ] [x1]   [B1*D2]
+        // [x2'] = [0  A2    ] [x2] + [B2   ] u2
+        //                    [x1]
+        //  y2   = [C1 D1*C2] [x2] + D1*D2 u2
+        let mut a = DMatrix::zeros(n1 + n2, n1 + n2);
+        a.view_mut((0, 0), (n1, n1)).copy_from(&self.a);
+        a.view_mut((0, n1), (n1, n2))
+            .copy_from(&(&self.b * &other.c));
+        a.view_mut((n1, n1), (n2, n2)).copy_from(&other.a);
+        let mut b = DMatrix::zeros(n1 + n2, other.inputs());
+        b.view_mut((0, 0), (n1, other.inputs()))
+            .copy_from(&(&self.b * &other.d));
+        b.view_mut((n1, 0), (n2, other.inputs()))
+            .copy_from(&other.b);
+        let mut c = DMatrix::zeros(self.outputs(), n1 + n2);
+        c.view_mut((0, 0), (self.outputs(), n1)).copy_from(&self.c);
+        c.view_mut((0, n1), (self.outputs(), n2))
+            .copy_from(&(&self.d * &other.c));
+        Self::new(a, b, c, &self.d * &other.d, self.dt)
+    }
+
+    /// Parallel interconnection, the outputs of both systems driven by the same input are summed.
+    /// This is scipy's ``self + other``.
+    ///
+    /// ## Errors
+    /// [Error::InvalidArg] if the systems have a different number of inputs or outputs.
+    /// [Error::ConflictArg] if the systems have different sampling times.
+    pub fn parallel(&self, other: &Self) -> Result<Self> {
+        check_same_dt(self.dt, other.dt)?;
+        if self.d.shape() != other.d.shape() {
+            return Err(Error::InvalidArg {
+                arg: "other".into(),
+                reason: "Cannot add systems with incompatible dimensions.".into(),
+            });
+        }
+        let (n1, n2) = (self.a.nrows(), other.a.nrows());
+        let mut a = DMatrix::zeros(n1 + n2, n1 + n2);
+        a.view_mut((0, 0), (n1, n1)).copy_from(&self.a);
+        a.view_mut((n1, n1), (n2, n2)).copy_from(&other.a);
+        let b = DMatrix::from_fn(n1 + n2, self.inputs(), |i, j| {
+            if i < n1 {
+                self.b[(i, j)]
+            } else {
+                other.b[(i - n1, j)]
+            }
+        });
+        let c = DMatrix::from_fn(self.outputs(), n1 + n2, |i, j| {
+            if j < n1 {
+                self.c[(i, j)]
+            } else {
+                other.c[(i, j - n1)]
+            }
+        });
+        Self::new(a, b, c, &self.d + &other.d, self.dt)
+    }
+
+    /// Only single-input, single-output systems have transfer function and zeros, poles, gain
+    /// representations.
+    fn check_siso(&self) -> Result<()> {
+        if self.inputs() != 1 || self.outputs() != 1 {
+            return Err(Error::InvalidArg {
+                arg: "self".into(),
+                reason: "Only single-input, single-output systems can be converted.".into(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<F: Float + RealField> Lti<F> for StateSpace<F> {
+    fn dt(&self) -> Option<F> {
+        self.dt
+    }
+
+    /// Convert a single-input, single-output system to [TransferFunction] representation.
+    fn to_tf(&self) -> Result<TransferFunction<F>> {
+        self.check_siso()?;
+        let (mut num, den) = ss2tf(&self.a, &self.b, &self.c, &self.d, 0)?;
+        TransferFunction::new(&num.swap_remove(0), &den, self.dt)
+    }
+
+    /// Convert a single-input, single-output system to [ZerosPolesGain] representation.
+    fn to_zpk(&self) -> Result<ZerosPolesGain<F>> {
+        self.check_siso()?;
+        let (z, p, k) = ss2zpk(&self.a, &self.b, &self.c, &self.d, 0)?;
+        ZerosPolesGain::new(z, p, k, self.dt)
+    }
+
+    fn to_ss(&self) -> Result<StateSpace<F>> {
+        Ok(self.clone())
+    }
+
+    /// Poles of the system, the eigenvalues of `A`.
+    fn poles(&self) -> Result<alloc::vec::Vec<nalgebra::Complex<F>>> {
+        if self.a.is_empty() {
+            return Ok(alloc::vec::Vec::new());
+        }
+        Ok(self.a.complex_eigenvalues().iter().copied().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    fn first_order(pole: f64, gain: f64) -> StateSpace<f64> {
+        StateSpace::new(
+            DMatrix::from_row_slice(1, 1, &[-pole]),
+            DMatrix::from_row_slice(1, 1, &[1.]),
+            DMatrix::from_row_slice(1, 1, &[gain]),
+            DMatrix::from_row_slice(1, 1, &[0.]),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn shapes() {
+        let a = DMatrix::<f64>::zeros(2, 2);
+        let b = DMatrix::zeros(2, 1);
+        let c = DMatrix::zeros(1, 2);
+        let d = DMatrix::zeros(1, 1);
+        assert!(StateSpace::new(a.clone(), b.clone(), c.clone(), d.clone(), None).is_ok());
+        assert!(StateSpace::new(a.clone(), c.clone(), c.clone(), d.clone(), None).is_err());
+        assert!(StateSpace::new(a.clone(), b.clone(), b.clone(), d.clone(), None).is_err());
+        assert!(StateSpace::new(a, b, c, DMatrix::zeros(2, 1), None).is_err());
+    }
+
+    #[test]
+    fn interconnections() {
+        let g1 = first_order(2., 1.);
+        let g2 = first_order(3., 2.);
+
+        // 2 / ((s + 2)(s + 3))
+        let series = g1.series(&g2).unwrap().to_tf().unwrap();
+        for (a, b) in series.num.iter().zip([2.]) {
+            assert_abs_diff_eq!(*a, b, epsilon = 1e-10);
+        }
+        for (a, b) in series.den.iter().zip([1., 5., 6.]) {
+            assert_abs_diff_eq!(*a, b, epsilon = 1e-10);
+        }
+
+        // 1 / (s + 2) + 2 / (s + 3) = (3s + 7) / ((s + 2)(s + 3))
+        let parallel = g1.parallel(&g2).unwrap().to_tf().unwrap();
+        for (a, b) in parallel.num.iter().zip([3., 7.]) {
+            assert_abs_diff_eq!(*a, b, epsilon = 1e-10);
+        }
+        for (a, b) in parallel.den.iter().zip([1., 5., 6.]) {
+            assert_abs_diff_eq!(*a, b, epsilon = 1e-10);
+        }
+
+        let mimo = StateSpace::new(
+            DMatrix::from_row_slice(1, 1, &[-1.]),
+            DMatrix::from_row_slice(1, 2, &[1., 1.]),
+            DMatrix::from_row_slice(1, 1, &[1.]),
+            DMatrix::from_row_slice(1, 2, &[0., 0.]),
+            None,
+        )
+        .unwrap();
+        assert!(mimo.series(&g1).is_err());
+        assert!(g1.parallel(&mimo).is_err());
+        assert!(mimo.to_tf().is_err());
+        assert_eq!(mimo.poles().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,197 @@
+use super::{
+    check_dt, check_same_dt,
+    conversions::{normalize, tf2ss, tf2zpk},
+    Lti, StateSpace, ZerosPolesGain,
+};
+use alloc::{vec, vec::Vec};
+use nalgebra::RealField;
+use num_traits::Float;
+use sci_rs_core::Result;
+
+/// Linear Time Invariant system class in transfer function form.
+///
+/// Represents the system as the continuous-time transfer function
+/// ``H(s)=sum_{i=0}^N b[N-i] s^i / sum_{j=0}^M a[M-j] s^j`` or the
+/// discrete-time transfer function
+/// ``H(z)=sum_{i=0}^N b[N-i] z^i / sum_{j=0}^M a[M-j] z^j``, where
+/// ``b`` are elements of the numerator `num`, ``a`` are elements of
+/// the denominator `den`, and ``N == len(b) - 1``, ``M == len(a) - 1``.
+///
+/// ## Examples
+/// Construct the transfer function ``H(s) = (s^2 + 3s + 3) / (s^2 + 2s + 1)``:
+/// ```custom,{class=language-python}
+/// >>> from scipy import signal
+/// >>> num = [1, 3, 3]
+/// >>> den = [1, 2, 1]
+/// >>> signal.TransferFunction(num, den)
+/// TransferFunctionContinuous(
+/// array([1., 3., 3.]),
+/// array([1., 2., 1.]),
+/// dt: None
+/// )
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::signal::lti::{Lti, TransferFunction};
+///
+/// let sys = TransferFunction::new(&[1., 3., 3.], &[1., 2., 1.], None).unwrap();
+/// assert_eq!(sys.num, vec![1., 3., 3.]);
+/// assert_eq!(sys.den, vec![1., 2., 1.]);
+/// assert_eq!(sys.dt(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferFunction<F> {
+    /// Numerator of the transfer function, highest power first.
+    pub num: Vec<F>,
+    /// Denominator of the transfer function, highest power first.
+    pub den: Vec<F>,
+    /// Sampling time of a discrete-time system, `None` for continuous-time systems.
+    pub dt: Option<F>,
+}
+
+impl<F: Float + RealField> TransferFunction<F> {
+    /// Create a transfer function from its numerator and denominator polynomials.
+    ///
+    /// Both polynomials are normalized so that the leading coefficient of `den` is one.
+    ///
+    /// ## Errors
+    /// [sci_rs_core::Error::InvalidArg] if `num` is empty, `den` has no nonzero element or `dt`
+    /// is not positive.
+    pub fn new(num: &[F], den: &[F], dt: Option<F>) -> Result<Self> {
+        let (num, den) = normalize(num, den)?;
+        Ok(Self {
+            num,
+            den,
+            dt: check_dt(dt)?,
+        })
+    }
+
+    /// Series interconnection, the output of `other` is the input of `self`.
+    ///
+    /// ## Errors
+    /// [sci_rs_core::Error::ConflictArg] if the systems have different sampling times.
+    pub fn series(&self, other: &Self) -> Result<Self> {
+        check_same_dt(self.dt, other.dt)?;
+        Self::new(
+            &polymul(&self.num, &other.num),
+            &polymul(&self.den, &other.den),
+            self.dt,
+        )
+    }
+
+    /// Parallel interconnection, the outputs of both systems driven by the same input are summed.
+    ///
+    /// ## Errors
+    /// [sci_rs_core::Error::ConflictArg] if the systems have different sampling times.
+    pub fn parallel(&self, other: &Self) -> Result<Self> {
+        check_same_dt(self.dt, other.dt)?;
+        let num = polyadd(
+            &polymul(&self.num, &other.den),
+            &polymul(&other.num, &self.den),
+        );
+        Self::new(&num, &polymul(&self.den, &other.den), self.dt)
+    }
+}
+
+impl<F: Float + RealField> Lti<F> for TransferFunction<F> {
+    fn dt(&self) -> Option<F> {
+        self.dt
+    }
+
+    fn to_tf(&self) -> Result<TransferFunction<F>> {
+        Ok(self.clone())
+    }
+
+    fn to_zpk(&self) -> Result<ZerosPolesGain<F>> {
+        let (z, p, k) = tf2zpk(&self.num, &self.den)?;
+        ZerosPolesGain::new(z, p, k, self.dt)
+    }
+
+    fn to_ss(&self) -> Result<StateSpace<F>> {
+        let (a, b, c, d) = tf2ss(&self.num, &self.den)?;
+        StateSpace::new(a, b, c, d, self.dt)
+    }
+}
+
+/// Product of two polynomials.
+fn polymul<F: Float>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut out = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, &a) in a.iter().enumerate() {
+        for (j, &b) in b.iter().enumerate() {
+            out[i + j] = out[i + j] + a * b;
+        }
+    }
+    out
+}
+
+/// Sum of two polynomials, aligned at the lowest power.
+fn polyadd<F: Float>(a: &[F], b: &[F]) -> Vec<F> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let offset = long.len() - short.len();
+    let mut out = long.to_vec();
+    for (o, &s) in out[offset..].iter_mut().zip(short) {
+        *o = *o + s;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[track_caller]
+    fn assert_vec_eq(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn normalizes() {
+        let sys = TransferFunction::new(&[0., 2., 4.], &[2., 4., 2.], Some(0.1)).unwrap();
+        assert_vec_eq(&sys.num, &[1., 2.]);
+        assert_vec_eq(&sys.den, &[1., 2., 1.]);
+        assert_eq!(sys.dt(), Some(0.1));
+        assert!(TransferFunction::new(&[1.], &[1.], Some(0.)).is_err());
+        assert!(TransferFunction::new(&[1.], &[0.], None).is_err());
+    }
+
+    #[test]
+    fn interconnections() {
+        let g1 = TransferFunction::new(&[1., 1.], &[1., 2.], None).unwrap();
+        let g2 = TransferFunction::new(&[1.], &[1., 3.], None).unwrap();
+        let series = g1.series(&g2).unwrap();
+        assert_vec_eq(&series.num, &[1., 1.]);
+        assert_vec_eq(&series.den, &[1., 5., 6.]);
+        let parallel = g1.parallel(&g2).unwrap();
+        assert_vec_eq(&parallel.num, &[1., 5., 5.]);
+        assert_vec_eq(&parallel.den, &[1., 5., 6.]);
+
+        let discrete = TransferFunction::new(&[1.], &[1., 3.], Some(1.)).unwrap();
+        assert!(g1.series(&discrete).is_err());
+    }
+
+    #[test]
+    fn conversions() {
+        let sys = TransferFunction::new(&[1., 3., 3.], &[1., 2., 1.], None).unwrap();
+        let zpk = sys.to_zpk().unwrap();
+        assert_abs_diff_eq!(zpk.gain, 1.);
+        let mut zeros = zpk.zeros.clone();
+        zeros.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+        assert_abs_diff_eq!(zeros[0].re, -1.5, epsilon = 1e-10);
+        assert_abs_diff_eq!(zeros[0].im, -(0.75f64).sqrt(), epsilon = 1e-10);
+        for p in &zpk.poles {
+            assert_abs_diff_eq!(p.re, -1., epsilon = 1e-6);
+        }
+        let tf = zpk.to_tf().unwrap();
+        assert_vec_eq(&tf.num, &sys.num);
+        assert_vec_eq(&tf.den, &sys.den);
+
+        let ss = sys.to_ss().unwrap();
+        let tf = ss.to_tf().unwrap();
+        assert_vec_eq(&tf.num, &sys.num);
+        assert_vec_eq(&tf.den, &sys.den);
+    }
+}
@@ -0,0 +1,162 @@
+use super::{
+    check_dt, check_same_dt,
+    conversions::{zpk2ss, zpk2tf},
+    Lti, StateSpace, TransferFunction,
+};
+use alloc::vec::Vec;
+use nalgebra::{Complex, RealField};
+use num_traits::Float;
+use sci_rs_core::Result;
+
+/// Linear Time Invariant system class in zeros, poles, gain form.
+///
+/// Represents the system as the continuous- or discrete-time transfer function
+/// ``H(s)=k \prod_i (s - z[i]) / \prod_j (s - p[j])``, where ``k`` is the `gain`,
+/// ``z`` are the `zeros` and ``p`` are the `poles`.
+///
+/// ## Examples
+/// Construct the transfer function ``H(s) = 5(s - 1)(s - 2) / (s - 3)(s - 4)``:
+/// ```custom,{class=language-python}
+/// >>> from scipy import signal
+/// >>> signal.ZerosPolesGain([1, 2], [3, 4], 5).to_tf()
+/// TransferFunctionContinuous(
+/// array([  5., -15.,  10.]),
+/// array([ 1., -7., 12.]),
+/// dt: None
+/// )
+/// ```
+/// Sci-rs:
+/// ```
+/// use nalgebra::Complex;
+/// use sci_rs::signal::lti::{Lti, ZerosPolesGain};
+///
+/// let c = |re| Complex::new(re, 0.);
+/// let sys = ZerosPolesGain::new(vec![c(1.), c(2.)], vec![c(3.), c(4.)], 5., None).unwrap();
+/// let tf = sys.to_tf().unwrap();
+/// assert_eq!(tf.num, vec![5., -15., 10.]);
+/// assert_eq!(tf.den, vec![1., -7., 12.]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZerosPolesGain<F> {
+    /// Zeros of the system.
+    pub zeros: Vec<Complex<F>>,
+    /// Poles of the system.
+    pub poles: Vec<Complex<F>>,
+    /// Gain of the system.
+    pub gain: F,
+    /// Sampling time of a discrete-time system, `None` for continuous-time systems.
+    pub dt: Option<F>,
+}
+
+impl<F: Float + RealField> ZerosPolesGain<F> {
+    /// Create a system from its zeros, poles and gain.
+    ///
+    /// Complex zeros and poles are expected to come in conjugate pairs.
+    ///
+    /// ## Errors
+    /// [sci_rs_core::Error::InvalidArg] if `dt` is not positive.
+    pub fn new(
+        zeros: Vec<Complex<F>>,
+        poles: Vec<Complex<F>>,
+        gain: F,
+        dt: Option<F>,
+    ) -> Result<Self> {
+        Ok(Self {
+            zeros,
+            poles,
+            gain,
+            dt: check_dt(dt)?,
+        })
+    }
+
+    /// Series interconnection, the output of `other` is the input of `self`.
+    ///
+    /// ## Errors
+    /// [sci_rs_core::Error::ConflictArg] if the systems have different sampling times.
+    pub fn series(&self, other: &Self) -> Result<Self> {
+        check_same_dt(self.dt, other.dt)?;
+        let zeros = self.zeros.iter().chain(&other.zeros).copied().collect();
+        let poles = self.poles.iter().chain(&other.poles).copied().collect();
+        Self::new(zeros, poles, self.gain * other.gain, self.dt)
+    }
+
+    /// Parallel interconnection, the outputs of both systems driven by the same input are summed.
+    ///
+    /// The zeros of the sum are found from its transfer function.
+    ///
+    /// ## Errors
+    /// [sci_rs_core::Error::ConflictArg] if the systems have different sampling times.
+    pub fn parallel(&self, other: &Self) -> Result<Self> {
+        self.to_tf()?.parallel(&other.to_tf()?)?.to_zpk()
+    }
+}
+
+impl<F: Float + RealField> Lti<F> for ZerosPolesGain<F> {
+    fn dt(&self) -> Option<F> {
+        self.dt
+    }
+
+    fn to_tf(&self) -> Result<TransferFunction<F>> {
+        let (num, den) = zpk2tf(&self.zeros, &self.poles, self.gain);
+        TransferFunction::new(&num, &den, self.dt)
+    }
+
+    fn to_zpk(&self) -> Result<ZerosPolesGain<F>> {
+        Ok(self.clone())
+    }
+
+    fn to_ss(&self) -> Result<StateSpace<F>> {
+        let (a, b, c, d) = zpk2ss(&self.zeros, &self.poles, self.gain)?;
+        StateSpace::new(a, b, c, d, self.dt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn interconnections() {
+        let g1 = ZerosPolesGain::new(vec![c(-1., 0.)], vec![c(-2., 0.)], 2., None).unwrap();
+        let g2 = ZerosPolesGain::new(vec![], vec![c(-3., 1.), c(-3., -1.)], 4., None).unwrap();
+        let series = g1.series(&g2).unwrap();
+        assert_eq!(series.zeros, vec![c(-1., 0.)]);
+        assert_eq!(series.poles.len(), 3);
+        assert_eq!(series.gain, 8.);
+
+        // 2(s + 1)/(s + 2) + 4/(s^2 + 6s + 10) has the numerator 2s^3 + 14s^2 + 36s + 28.
+        let parallel = g1.parallel(&g2).unwrap().to_tf().unwrap();
+        for (a, b) in parallel.num.iter().zip([2., 14., 36., 28.]) {
+            assert_abs_diff_eq!(*a, b, epsilon = 1e-10);
+        }
+        for (a, b) in parallel.den.iter().zip([1., 8., 22., 20.]) {
+            assert_abs_diff_eq!(*a, b, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn state_space() {
+        let sys = ZerosPolesGain::new(
+            vec![c(1., 0.)],
+            vec![c(-1., 2.), c(-1., -2.)],
+            3.,
+            Some(0.5),
+        )
+        .unwrap();
+        let ss = sys.to_ss().unwrap();
+        assert_eq!(ss.dt, Some(0.5));
+        let mut poles = ss.poles().unwrap();
+        poles.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+        assert_abs_diff_eq!(poles[0].re, -1., epsilon = 1e-10);
+        assert_abs_diff_eq!(poles[0].im, -2., epsilon = 1e-10);
+        let zpk = ss.to_zpk().unwrap();
+        assert_abs_diff_eq!(zpk.gain, 3., epsilon = 1e-10);
+        assert_abs_diff_eq!(zpk.zeros[0].re, 1., epsilon = 1e-10);
+    }
+}
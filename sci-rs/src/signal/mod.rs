@@ -14,6 +14,30 @@ pub mod wave;
 #[cfg(feature = "std")]
 pub mod convolve;
 
+/// Chirp Z-transform and Zoom FFT  
+/// Contains functions from the [Chirp Z-transform and Zoom FFT section of
+/// `scipy.signal`](<https://docs.scipy.org/doc/scipy/reference/signal.html#chirp-z-transform-and-zoom-fft>).
+#[cfg(feature = "std")]
+pub mod czt;
+
+/// B-splines  
+/// Contains functions from the [B-splines section of
+/// `scipy.signal`](<https://docs.scipy.org/doc/scipy/reference/signal.html#b-splines>).
+#[cfg(feature = "alloc")]
+pub mod bsplines;
+
+/// Linear Time Invariant systems  
+/// Contains the system representations of the [LTI Representations section of
+/// `scipy.signal`](<https://docs.scipy.org/doc/scipy/reference/signal.html#lti-representations>).
+#[cfg(feature = "alloc")]
+pub mod lti;
+
+/// Peak finding  
+/// Contains functions from the [Peak finding section of
+/// `scipy.signal`](<https://docs.scipy.org/doc/scipy/reference/signal.html#peak-finding>).
+#[cfg(feature = "alloc")]
+pub mod peak_finding;
+
 /// Window functions  
 /// This contains all window functions in the
 /// [`scipy.signal.windows`](https://docs.scipy.org/doc/scipy/reference/signal.windows.html#module-scipy.signal.windows)
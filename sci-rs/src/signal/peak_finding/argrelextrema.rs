@@ -0,0 +1,266 @@
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use alloc::{vec, vec::Vec};
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension, IntoDimension, Zip};
+use sci_rs_core::{Error, Result};
+
+/// How the edges of the vector are treated by [argrelextrema].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExtremaMode {
+    /// Out of bounds indices are clipped to the first or last sample along the axis.
+    #[default]
+    Clip,
+    /// Out of bounds indices wrap around, treating the axis as periodic.
+    Wrap,
+}
+
+/// Calculate the relative extrema of `data`.
+///
+/// Relative extrema are calculated by finding locations where
+/// ``comparator(data[n], data[n+1:n+order+1])`` is true.
+///
+/// ## Parameters
+/// * `data`: ndarray
+///   Array in which to find the relative extrema.
+/// * `comparator`: callable
+///   Function to use to compare two data points.
+///   Should take two arrays as arguments.
+/// * `axis`: `Option<isize>`
+///   Axis over which to select from `data`. Default is 0.
+/// * `order`: `Option<usize>`
+///   How many points on each side to use for the comparison
+///   to consider ``comparator(n, n+x)`` to be true. Default is 1.
+/// * `mode`: `Option<ExtremaMode>`
+///   How the edges of the vector are treated. Default is [ExtremaMode::Clip].
+///
+/// ## Returns
+/// * `extrema`: `Vec<Vec<usize>>`
+///   Indices of the extrema, with one vector of indices per dimension of `data`, in the same
+///   layout as numpy's `nonzero`.
+///
+/// ## Notes
+/// Note that [ExtremaMode::Clip] compares edge samples against themselves, so plateaus and the
+/// boundaries are never reported as strict extrema.
+///
+/// ## Examples
+/// ```
+/// use ndarray::{array, Array1};
+/// use sci_rs::signal::peak_finding::argrelextrema;
+///
+/// let x: Array1<f64> = array![2., 1., 2., 3., 2., 0., 1., 0.];
+/// let extrema = argrelextrema(&x, |a, b| a > b, None, None, None).unwrap();
+/// assert_eq!(extrema, vec![vec![3, 6]]);
+/// let extrema = argrelextrema(&x, |a, b| a < b, None, None, None).unwrap();
+/// assert_eq!(extrema, vec![vec![1, 5]]);
+/// ```
+///
+/// ## Errors
+/// [Error::InvalidArg] if `order` is 0 or `axis` is out of range.
+pub fn argrelextrema<T, S, D, C>(
+    data: &ArrayBase<S, D>,
+    comparator: C,
+    axis: Option<isize>,
+    order: Option<usize>,
+    mode: Option<ExtremaMode>,
+) -> Result<Vec<Vec<usize>>>
+where
+    S: Data<Elem = T>,
+    D: Dimension,
+    C: Fn(&T, &T) -> bool,
+{
+    let results = boolrelextrema(data, comparator, axis, order, mode)?;
+    let mut extrema = vec![Vec::new(); results.ndim()];
+    for (idx, _) in results.indexed_iter().filter(|(_, &r)| r) {
+        let idx = idx.into_dimension();
+        for (dim, &i) in extrema.iter_mut().zip(idx.slice()) {
+            dim.push(i);
+        }
+    }
+    Ok(extrema)
+}
+
+/// Calculate the relative maxima of `data`.
+///
+/// This is [argrelextrema] with a `greater than` comparator.
+///
+/// ## Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs::signal::peak_finding::argrelmax;
+///
+/// let x = array![2., 1., 2., 3., 2., 0., 1., 0.];
+/// assert_eq!(argrelmax(&x, None, None, None).unwrap(), vec![vec![3, 6]]);
+///
+/// let y = array![[1., 2., 1., 2.], [2., 2., 0., 0.], [5., 3., 4., 4.]];
+/// assert_eq!(argrelmax(&y, Some(1), None, None).unwrap(), vec![vec![0], vec![1]]);
+/// ```
+pub fn argrelmax<T, S, D>(
+    data: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    order: Option<usize>,
+    mode: Option<ExtremaMode>,
+) -> Result<Vec<Vec<usize>>>
+where
+    T: PartialOrd,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    argrelextrema(data, |a, b| a > b, axis, order, mode)
+}
+
+/// Calculate the relative minima of `data`.
+///
+/// This is [argrelextrema] with a `less than` comparator.
+///
+/// ## Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs::signal::peak_finding::argrelmin;
+///
+/// let x = array![2., 1., 2., 3., 2., 0., 1., 0.];
+/// assert_eq!(argrelmin(&x, None, None, None).unwrap(), vec![vec![1, 5]]);
+///
+/// let y = array![[1., 2., 1., 2.], [2., 2., 0., 0.], [5., 3., 4., 4.]];
+/// assert_eq!(argrelmin(&y, Some(1), None, None).unwrap(), vec![vec![0, 2], vec![2, 1]]);
+/// ```
+pub fn argrelmin<T, S, D>(
+    data: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    order: Option<usize>,
+    mode: Option<ExtremaMode>,
+) -> Result<Vec<Vec<usize>>>
+where
+    T: PartialOrd,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    argrelextrema(data, |a, b| a < b, axis, order, mode)
+}
+
+/// Calculate the relative extrema of `data`, returning a boolean array of the same shape.
+fn boolrelextrema<T, S, D, C>(
+    data: &ArrayBase<S, D>,
+    comparator: C,
+    axis: Option<isize>,
+    order: Option<usize>,
+    mode: Option<ExtremaMode>,
+) -> Result<Array<bool, D>>
+where
+    S: Data<Elem = T>,
+    D: Dimension,
+    C: Fn(&T, &T) -> bool,
+{
+    let order = order.unwrap_or(1);
+    if order < 1 {
+        return Err(Error::InvalidArg {
+            arg: "order".into(),
+            reason: "Order must be an int >= 1".into(),
+        });
+    }
+    let axis = Axis(check_and_get_axis_dyn(Some(axis.unwrap_or(0)), data)?);
+    let mode = mode.unwrap_or_default();
+
+    let datalen = data.len_of(axis);
+    let take = |i: isize| -> usize {
+        match mode {
+            ExtremaMode::Clip => i.clamp(0, datalen as isize - 1) as usize,
+            ExtremaMode::Wrap => i.rem_euclid(datalen as isize) as usize,
+        }
+    };
+
+    let mut results = Array::from_elem(data.raw_dim(), true);
+    if datalen == 0 {
+        return Ok(results);
+    }
+    Zip::from(data.lanes(axis))
+        .and(results.lanes_mut(axis))
+        .for_each(|lane, mut result| {
+            for (loc, r) in result.iter_mut().enumerate() {
+                let main = &lane[loc];
+                for shift in 1..=order as isize {
+                    let plus = &lane[take(loc as isize + shift)];
+                    let minus = &lane[take(loc as isize - shift)];
+                    if !(comparator(main, plus) && comparator(main, minus)) {
+                        *r = false;
+                        break;
+                    }
+                }
+            }
+        });
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use ndarray::{arr1, arr2, Array3};
+
+    #[test]
+    fn order() {
+        let x = arr1(&[0., 3., 1., 2., 5., 4., 1., 2., 1., 0.]);
+        assert_eq!(
+            argrelmax(&x, None, Some(1), None).unwrap(),
+            vec![vec![1, 4, 7]]
+        );
+        assert_eq!(
+            argrelmax(&x, None, Some(2), None).unwrap(),
+            vec![vec![1, 4]]
+        );
+        assert_eq!(
+            argrelmin(&x, None, Some(1), None).unwrap(),
+            vec![vec![2, 6]]
+        );
+        assert_eq!(
+            argrelmin(&x, None, Some(3), None).unwrap(),
+            vec![Vec::<usize>::new()]
+        );
+        assert!(argrelmax(&x, None, Some(0), None).is_err());
+    }
+
+    #[test]
+    fn modes() {
+        let x = arr1(&[3., 1., 2., 0.]);
+        // Clipping compares the edges against themselves.
+        assert_eq!(
+            argrelmax(&x, None, None, Some(ExtremaMode::Clip)).unwrap(),
+            vec![vec![2]]
+        );
+        assert_eq!(
+            argrelmax(&x, None, None, Some(ExtremaMode::Wrap)).unwrap(),
+            vec![vec![0, 2]]
+        );
+        assert_eq!(
+            argrelmin(&x, None, None, Some(ExtremaMode::Wrap)).unwrap(),
+            vec![vec![1, 3]]
+        );
+    }
+
+    #[test]
+    fn axes() {
+        let x = arr2(&[[1., 2., 1.], [3., 0., 4.], [2., 5., 2.], [1., 1., 1.]]);
+        assert_eq!(
+            argrelmax(&x, Some(0), None, None).unwrap(),
+            vec![vec![1, 1, 2], vec![0, 2, 1]]
+        );
+        assert_eq!(
+            argrelmax(&x, Some(-1), None, None).unwrap(),
+            vec![vec![0, 2], vec![1, 1]]
+        );
+        assert!(argrelmax(&x, Some(2), None, None).is_err());
+    }
+
+    #[test]
+    fn three_dimensional() {
+        let mut x = Array3::<f64>::zeros((2, 3, 4));
+        x[[1, 1, 2]] = 1.;
+        x[[0, 2, 1]] = -1.;
+        assert_eq!(
+            argrelmax(&x, Some(1), None, None).unwrap(),
+            vec![vec![1], vec![1], vec![2]]
+        );
+        assert_eq!(
+            argrelmin(&x, Some(2), None, None).unwrap(),
+            vec![vec![0], vec![2], vec![1]]
+        );
+    }
+}
@@ -0,0 +1,1052 @@
+use alloc::{vec, vec::Vec};
+use ndarray::{ArrayBase, Data, Ix1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// One side of a [PeakCondition].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PeakBound<F> {
+    /// The same bound is applied to every peak.
+    Scalar(F),
+    /// A bound for every sample of `x`, from which the entries at the peak positions are used.
+    /// Must have the same length as `x`.
+    Array(Vec<F>),
+}
+
+/// Interval a peak property has to lie within for the peak to be kept by [find_peaks].
+///
+/// This mirrors scipy's `number or ndarray or sequence` arguments: a plain number (see the
+/// [From] implementation) is interpreted as the minimal required value, while a tuple is
+/// interpreted as `(min, max)` where either side can be `None`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeakCondition<F> {
+    /// Minimal required value, inclusive.
+    pub min: Option<PeakBound<F>>,
+    /// Maximal required value, inclusive.
+    pub max: Option<PeakBound<F>>,
+}
+
+impl<F> PeakCondition<F> {
+    /// Require the property to be at least `min`.
+    pub fn at_least(min: F) -> Self {
+        PeakCondition {
+            min: Some(PeakBound::Scalar(min)),
+            max: None,
+        }
+    }
+
+    /// Require the property to be at most `max`.
+    pub fn at_most(max: F) -> Self {
+        PeakCondition {
+            min: None,
+            max: Some(PeakBound::Scalar(max)),
+        }
+    }
+
+    /// Require the property to lie within `[min, max]`.
+    pub fn between(min: F, max: F) -> Self {
+        PeakCondition {
+            min: Some(PeakBound::Scalar(min)),
+            max: Some(PeakBound::Scalar(max)),
+        }
+    }
+}
+
+impl<F> From<F> for PeakCondition<F> {
+    fn from(min: F) -> Self {
+        PeakCondition::at_least(min)
+    }
+}
+
+/// Arguments for [find_peaks].
+///
+/// Every field left as `None` disables the corresponding condition, which is also what
+/// [Default] gives.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FindPeaksOptions<F> {
+    /// Required height of peaks.
+    pub height: Option<PeakCondition<F>>,
+    /// Required threshold of peaks, the vertical distance to its neighboring samples.
+    pub threshold: Option<PeakCondition<F>>,
+    /// Required minimal horizontal distance (>= 1) in samples between neighbouring peaks.
+    /// Smaller peaks are removed first until the condition is fulfilled for all remaining peaks.
+    pub distance: Option<F>,
+    /// Required prominence of peaks.
+    pub prominence: Option<PeakCondition<F>>,
+    /// Required width of peaks in samples.
+    pub width: Option<PeakCondition<F>>,
+    /// Used for calculation of the peaks prominences, thus it is only used if one of the
+    /// arguments `prominence` or `width` is given. See [peak_prominences].
+    pub wlen: Option<F>,
+    /// Used for calculation of the peaks width, thus it is only used if `width` is given.
+    /// Defaults to 0.5. See [peak_widths].
+    pub rel_height: Option<F>,
+    /// Required size of the flat top of peaks in samples.
+    pub plateau_size: Option<PeakCondition<F>>,
+}
+
+/// Properties of the peaks returned by [find_peaks].
+///
+/// A property is only calculated, and thus `Some`, if the condition it is evaluated by was
+/// requested in [FindPeaksOptions].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeakProperties<F> {
+    /// Size of the flat top of each peak. Present if `plateau_size` was given.
+    pub plateau_sizes: Option<Vec<usize>>,
+    /// Index of the first sample of each plateau. Present if `plateau_size` was given.
+    pub left_edges: Option<Vec<usize>>,
+    /// Index of the last sample of each plateau. Present if `plateau_size` was given.
+    pub right_edges: Option<Vec<usize>>,
+    /// Height of each peak. Present if `height` was given.
+    pub peak_heights: Option<Vec<F>>,
+    /// Vertical distance of each peak to its left neighbour. Present if `threshold` was given.
+    pub left_thresholds: Option<Vec<F>>,
+    /// Vertical distance of each peak to its right neighbour. Present if `threshold` was given.
+    pub right_thresholds: Option<Vec<F>>,
+    /// Prominence of each peak. Present if `prominence` or `width` was given.
+    pub prominences: Option<Vec<F>>,
+    /// Left base of each peak. Present if `prominence` or `width` was given.
+    pub left_bases: Option<Vec<usize>>,
+    /// Right base of each peak. Present if `prominence` or `width` was given.
+    pub right_bases: Option<Vec<usize>>,
+    /// Width of each peak. Present if `width` was given.
+    pub widths: Option<Vec<F>>,
+    /// Height of the contour lines at which the `widths` were evaluated. Present if `width` was
+    /// given.
+    pub width_heights: Option<Vec<F>>,
+    /// Interpolated position of the left intersection point of the width line. Present if
+    /// `width` was given.
+    pub left_ips: Option<Vec<F>>,
+    /// Interpolated position of the right intersection point of the width line. Present if
+    /// `width` was given.
+    pub right_ips: Option<Vec<F>>,
+}
+
+impl<F> Default for PeakProperties<F> {
+    fn default() -> Self {
+        PeakProperties {
+            plateau_sizes: None,
+            left_edges: None,
+            right_edges: None,
+            peak_heights: None,
+            left_thresholds: None,
+            right_thresholds: None,
+            prominences: None,
+            left_bases: None,
+            right_bases: None,
+            widths: None,
+            width_heights: None,
+            left_ips: None,
+            right_ips: None,
+        }
+    }
+}
+
+impl<F: Copy> PeakProperties<F> {
+    /// Drop the entries of all present properties whose peak is not kept.
+    fn retain(&mut self, keep: &[bool]) {
+        fn select<T: Copy>(v: &mut Option<Vec<T>>, keep: &[bool]) {
+            if let Some(v) = v {
+                *v = select_by_mask(v, keep);
+            }
+        }
+        select(&mut self.plateau_sizes, keep);
+        select(&mut self.left_edges, keep);
+        select(&mut self.right_edges, keep);
+        select(&mut self.peak_heights, keep);
+        select(&mut self.left_thresholds, keep);
+        select(&mut self.right_thresholds, keep);
+        select(&mut self.prominences, keep);
+        select(&mut self.left_bases, keep);
+        select(&mut self.right_bases, keep);
+        select(&mut self.widths, keep);
+        select(&mut self.width_heights, keep);
+        select(&mut self.left_ips, keep);
+        select(&mut self.right_ips, keep);
+    }
+}
+
+/// Prominence data returned by [peak_prominences], which can be reused by [peak_widths].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PeakProminences<F> {
+    /// The calculated prominences for each peak.
+    pub prominences: Vec<F>,
+    /// The peaks' bases as indices in `x` to the left of each peak.
+    pub left_bases: Vec<usize>,
+    /// The peaks' bases as indices in `x` to the right of each peak.
+    pub right_bases: Vec<usize>,
+}
+
+/// Width data returned by [peak_widths].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PeakWidths<F> {
+    /// The widths for each peak in samples.
+    pub widths: Vec<F>,
+    /// The height of the contour lines at which the `widths` were evaluated.
+    pub width_heights: Vec<F>,
+    /// Interpolated positions of left intersection points of a horizontal line at the
+    /// respective evaluation height.
+    pub left_ips: Vec<F>,
+    /// Interpolated positions of right intersection points of a horizontal line at the
+    /// respective evaluation height.
+    pub right_ips: Vec<F>,
+}
+
+/// Find peaks inside a signal based on peak properties.
+///
+/// This function takes a 1-D array and finds all local maxima by
+/// simple comparison of neighboring values. Optionally, a subset of these
+/// peaks can be selected by specifying conditions for a peak's properties.
+///
+/// ## Parameters
+/// * `x`: 1-D array
+///   A signal with peaks.
+/// * `options`: [FindPeaksOptions]
+///   Conditions on `height`, `threshold`, `distance`, `prominence`, `width` and
+///   `plateau_size` of the peaks, together with `wlen` and `rel_height` used to evaluate them.
+///
+/// ## Returns
+/// * `peaks`: `Vec<usize>`
+///   Indices of peaks in `x` that satisfy all given conditions.
+/// * `properties`: [PeakProperties]
+///   Properties of the returned peaks which were calculated as intermediate results during
+///   evaluation of the specified conditions.
+///
+/// ## Notes
+/// In the context of this function, a peak or local maximum is defined as any
+/// sample whose two direct neighbours have a smaller amplitude. For flat peaks
+/// (more than one sample of equal amplitude wide) the index of the middle
+/// sample is returned (rounded down in case the number of samples is even).
+/// For noisy signals the peak locations can be off because the noise might
+/// change the position of local maxima.
+///
+/// The conditions are evaluated in the following order: `plateau_size`, `height`,
+/// `threshold`, `distance`, `prominence`, `width`. In most cases this order is
+/// the fastest one because faster operations are applied first to reduce the
+/// number of peaks that need to be evaluated later.
+///
+/// While indices in `peaks` are guaranteed to be at least `distance` samples
+/// apart, edges of flat peaks may be closer than the allowed `distance`.
+///
+/// Use `wlen` to reduce the time it takes to evaluate the conditions for
+/// `prominence` or `width` if `x` is large or has many local maxima.
+///
+/// ## Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs::signal::peak_finding::{find_peaks, FindPeaksOptions, PeakCondition};
+///
+/// let x = array![0., 2., 1., 3., 3., 3., 0.5, 4., 1.];
+/// let (peaks, _) = find_peaks(&x, FindPeaksOptions::default()).unwrap();
+/// assert_eq!(peaks, vec![1, 4, 7]);
+///
+/// let options = FindPeaksOptions {
+///     height: Some(PeakCondition::at_least(2.5)),
+///     ..Default::default()
+/// };
+/// let (peaks, properties) = find_peaks(&x, options).unwrap();
+/// assert_eq!(peaks, vec![4, 7]);
+/// assert_eq!(properties.peak_heights, Some(vec![3., 4.]));
+/// ```
+///
+/// ## Errors
+/// [Error::InvalidArg] if `distance < 1`, `wlen <= 1`, `rel_height < 0` or an array bound does not
+/// match the length of `x`.
+pub fn find_peaks<F, S>(
+    x: &ArrayBase<S, Ix1>,
+    options: FindPeaksOptions<F>,
+) -> Result<(Vec<usize>, PeakProperties<F>)>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let x: Vec<F> = x.iter().copied().collect();
+    if let Some(distance) = options.distance {
+        if distance < F::one() {
+            return Err(Error::InvalidArg {
+                arg: "distance".into(),
+                reason: "`distance` must be greater or equal to 1".into(),
+            });
+        }
+    }
+
+    let (mut peaks, left_edges, right_edges) = local_maxima_1d(&x);
+    let mut properties = PeakProperties::default();
+
+    if let Some(plateau_size) = &options.plateau_size {
+        // Evaluate plateau size
+        let plateau_sizes: Vec<usize> = left_edges
+            .iter()
+            .zip(&right_edges)
+            .map(|(l, r)| r - l + 1)
+            .collect();
+        let sizes: Vec<F> = plateau_sizes.iter().map(|&s| F::from(s).unwrap()).collect();
+        let keep = select_by_property(&sizes, plateau_size, &x, &peaks)?;
+        properties.plateau_sizes = Some(plateau_sizes);
+        properties.left_edges = Some(left_edges);
+        properties.right_edges = Some(right_edges);
+        peaks = select_by_mask(&peaks, &keep);
+        properties.retain(&keep);
+    }
+
+    if let Some(height) = &options.height {
+        // Evaluate height condition
+        let peak_heights: Vec<F> = peaks.iter().map(|&p| x[p]).collect();
+        let keep = select_by_property(&peak_heights, height, &x, &peaks)?;
+        properties.peak_heights = Some(peak_heights);
+        peaks = select_by_mask(&peaks, &keep);
+        properties.retain(&keep);
+    }
+
+    if let Some(threshold) = &options.threshold {
+        // Evaluate threshold condition
+        let left_thresholds: Vec<F> = peaks.iter().map(|&p| x[p] - x[p - 1]).collect();
+        let right_thresholds: Vec<F> = peaks.iter().map(|&p| x[p] - x[p + 1]).collect();
+        let (tmin, tmax) = unpack_condition_args(threshold, &x, &peaks)?;
+        let keep: Vec<bool> = (0..peaks.len())
+            .map(|i| {
+                let (l, r) = (left_thresholds[i], right_thresholds[i]);
+                tmin.as_ref().is_none_or(|tmin| tmin[i] <= l.min(r))
+                    && tmax.as_ref().is_none_or(|tmax| l.max(r) <= tmax[i])
+            })
+            .collect();
+        properties.left_thresholds = Some(left_thresholds);
+        properties.right_thresholds = Some(right_thresholds);
+        peaks = select_by_mask(&peaks, &keep);
+        properties.retain(&keep);
+    }
+
+    if let Some(distance) = options.distance {
+        // Evaluate distance condition
+        let priority: Vec<F> = peaks.iter().map(|&p| x[p]).collect();
+        let keep = select_by_peak_distance(&peaks, &priority, distance);
+        peaks = select_by_mask(&peaks, &keep);
+        properties.retain(&keep);
+    }
+
+    if options.prominence.is_some() || options.width.is_some() {
+        // Calculate prominence (required for both conditions)
+        let wlen = arg_wlen_as_expected(options.wlen)?;
+        let prominences = peak_prominences_impl(&x, &peaks, wlen)?;
+        properties.prominences = Some(prominences.prominences);
+        properties.left_bases = Some(prominences.left_bases);
+        properties.right_bases = Some(prominences.right_bases);
+    }
+
+    if let Some(prominence) = &options.prominence {
+        // Evaluate prominence condition
+        let keep = select_by_property(
+            properties.prominences.as_ref().unwrap(),
+            prominence,
+            &x,
+            &peaks,
+        )?;
+        peaks = select_by_mask(&peaks, &keep);
+        properties.retain(&keep);
+    }
+
+    if let Some(width) = &options.width {
+        // Calculate widths
+        let widths = peak_widths_impl(
+            &x,
+            &peaks,
+            options.rel_height.unwrap_or_else(|| F::from(0.5).unwrap()),
+            properties.prominences.as_ref().unwrap(),
+            properties.left_bases.as_ref().unwrap(),
+            properties.right_bases.as_ref().unwrap(),
+        )?;
+        // Evaluate width condition
+        let keep = select_by_property(&widths.widths, width, &x, &peaks)?;
+        properties.widths = Some(widths.widths);
+        properties.width_heights = Some(widths.width_heights);
+        properties.left_ips = Some(widths.left_ips);
+        properties.right_ips = Some(widths.right_ips);
+        peaks = select_by_mask(&peaks, &keep);
+        properties.retain(&keep);
+    }
+
+    Ok((peaks, properties))
+}
+
+/// Calculate the prominence of each peak in a signal.
+///
+/// The prominence of a peak measures how much a peak stands out from the
+/// surrounding baseline of the signal and is defined as the vertical distance
+/// between the peak and its lowest contour line.
+///
+/// ## Parameters
+/// * `x`: 1-D array
+///   A signal with peaks.
+/// * `peaks`: `&[usize]`
+///   Indices of peaks in `x`.
+/// * `wlen`: `Option<F>`
+///   A window length in samples that optionally limits the evaluated area for
+///   each peak to a subset of `x`. The peak is always placed in the middle of
+///   the window therefore the given length is rounded up to the next odd
+///   integer. This parameter can speed up the calculation.
+///
+/// ## Returns
+/// [PeakProminences] with the prominences and the left and right bases of each peak.
+///
+/// ## Notes
+/// Strategy to compute a peak's prominence:
+///
+/// 1. Extend a horizontal line from the current peak to the left and right
+///    until the line either reaches the window border (see `wlen`) or
+///    intersects the signal again at the slope of a higher peak. An
+///    intersection with a peak of the same height is ignored.
+/// 2. On each side find the minimal signal value within the interval defined
+///    above. These points are the peak's bases.
+/// 3. The higher one of the two bases marks the peak's lowest contour line. The
+///    prominence can then be calculated as the vertical difference between the
+///    peaks height itself and its lowest contour line.
+///
+/// ## Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs::signal::peak_finding::peak_prominences;
+///
+/// let x = array![0., 2., 1., 3., 3., 3., 0.5, 4., 1.];
+/// let prominences = peak_prominences(&x, &[1, 4, 7], None).unwrap();
+/// assert_eq!(prominences.prominences, vec![1., 2.5, 3.]);
+/// assert_eq!(prominences.left_bases, vec![0, 0, 0]);
+/// assert_eq!(prominences.right_bases, vec![2, 6, 8]);
+/// ```
+///
+/// ## Errors
+/// [Error::InvalidArg] if a peak is not a valid index into `x` or `wlen <= 1`.
+pub fn peak_prominences<F, S>(
+    x: &ArrayBase<S, Ix1>,
+    peaks: &[usize],
+    wlen: Option<F>,
+) -> Result<PeakProminences<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let x: Vec<F> = x.iter().copied().collect();
+    let wlen = arg_wlen_as_expected(wlen)?;
+    peak_prominences_impl(&x, peaks, wlen)
+}
+
+/// Calculate the width of each peak in a signal.
+///
+/// This function calculates the width of a peak in samples at a relative
+/// distance to the peak's height and prominence.
+///
+/// ## Parameters
+/// * `x`: 1-D array
+///   A signal with peaks.
+/// * `peaks`: `&[usize]`
+///   Indices of peaks in `x`.
+/// * `rel_height`: `Option<F>`
+///   Chooses the relative height at which the peak width is measured as a
+///   percentage of its prominence. 1.0 calculates the width of the peak at
+///   its lowest contour line while 0.5 evaluates at half the prominence
+///   height. Must be at least 0. Defaults to 0.5.
+/// * `prominence_data`: `Option<&PeakProminences>`
+///   The output of [peak_prominences] when called with the same arguments `x` and `peaks`.
+///   This data are calculated internally if not provided.
+/// * `wlen`: `Option<F>`
+///   A window length in samples passed to [peak_prominences] as an optional
+///   argument for internal calculation of `prominence_data`. This argument
+///   is ignored if `prominence_data` is given.
+///
+/// ## Returns
+/// [PeakWidths] with the widths, evaluation heights and interpolated intersection points.
+///
+/// ## Notes
+/// The basic algorithm to calculate a peak's width is as follows:
+///
+/// * Calculate the evaluation height ``h_eval = h_peak - P * R`` with the peak's height
+///   `h_peak`, its prominence `P` and the relative height `R`.
+/// * Draw a horizontal line at the evaluation height to both sides, starting at
+///   the peak's current vertical position until the lines either intersect a
+///   slope, the signal border or cross the vertical position of the peak's
+///   base. For the first case, intersection with the signal, the true
+///   intersection point is estimated with linear interpolation.
+/// * Calculate the width as the horizontal distance between the chosen
+///   endpoints on both sides. As a consequence of this the maximal possible
+///   width for each peak is the horizontal distance between its bases.
+///
+/// ## Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs::signal::peak_finding::peak_widths;
+///
+/// let x = array![0., 2., 1., 3., 3., 3., 0.5, 4., 1.];
+/// let widths = peak_widths(&x, &[1, 4], None, None, None).unwrap();
+/// assert_eq!(widths.widths, vec![0.75, 3.125]);
+/// assert_eq!(widths.width_heights, vec![1.5, 1.75]);
+/// ```
+///
+/// ## Errors
+/// [Error::InvalidArg] if `rel_height < 0`, the prominence data does not match `peaks` or a
+/// peak lies outside its bases.
+pub fn peak_widths<F, S>(
+    x: &ArrayBase<S, Ix1>,
+    peaks: &[usize],
+    rel_height: Option<F>,
+    prominence_data: Option<&PeakProminences<F>>,
+    wlen: Option<F>,
+) -> Result<PeakWidths<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let x: Vec<F> = x.iter().copied().collect();
+    let computed;
+    let prominence_data = match prominence_data {
+        Some(data) => data,
+        None => {
+            let wlen = arg_wlen_as_expected(wlen)?;
+            computed = peak_prominences_impl(&x, peaks, wlen)?;
+            &computed
+        }
+    };
+    peak_widths_impl(
+        &x,
+        peaks,
+        rel_height.unwrap_or_else(|| F::from(0.5).unwrap()),
+        &prominence_data.prominences,
+        &prominence_data.left_bases,
+        &prominence_data.right_bases,
+    )
+}
+
+/// Find local maxima in a 1D array.
+///
+/// This function finds all local maxima in a 1D array and returns the indices
+/// for their edges and midpoints (rounded down for even plateau sizes).
+fn local_maxima_1d<F: Float>(x: &[F]) -> (Vec<usize>, Vec<usize>, Vec<usize>) {
+    let mut midpoints = Vec::new();
+    let mut left_edges = Vec::new();
+    let mut right_edges = Vec::new();
+
+    // Pointer to current sample, first one can't be maxima
+    let mut i = 1;
+    // Last sample can't be maxima
+    let i_max = x.len().saturating_sub(1);
+    while i < i_max {
+        // Test if previous sample is smaller
+        if x[i - 1] < x[i] {
+            // Index to look ahead of current sample
+            let mut i_ahead = i + 1;
+
+            // Find next sample that is unequal to x[i]
+            while i_ahead < i_max && x[i_ahead] == x[i] {
+                i_ahead += 1;
+            }
+
+            // Maxima is found if next unequal sample is smaller than x[i]
+            if x[i_ahead] < x[i] {
+                left_edges.push(i);
+                right_edges.push(i_ahead - 1);
+                midpoints.push((i + i_ahead - 1) / 2);
+                // Skip samples that can't be maximum
+                i = i_ahead;
+            }
+        }
+        i += 1;
+    }
+    (midpoints, left_edges, right_edges)
+}
+
+/// Keep the entries of `v` whose `keep` flag is set.
+fn select_by_mask<T: Copy>(v: &[T], keep: &[bool]) -> Vec<T> {
+    v.iter()
+        .zip(keep)
+        .filter_map(|(&v, &k)| k.then_some(v))
+        .collect()
+}
+
+/// Resolve a [PeakBound] into a bound for each peak.
+fn unpack_bound<F: Float>(bound: &PeakBound<F>, x: &[F], peaks: &[usize]) -> Result<Vec<F>> {
+    match bound {
+        PeakBound::Scalar(b) => Ok(vec![*b; peaks.len()]),
+        PeakBound::Array(b) => {
+            if b.len() != x.len() {
+                return Err(Error::InvalidArg {
+                    arg: "interval".into(),
+                    reason: "array size of lower interval border must match x".into(),
+                });
+            }
+            Ok(peaks.iter().map(|&p| b[p]).collect())
+        }
+    }
+}
+
+type UnpackedCondition<F> = (Option<Vec<F>>, Option<Vec<F>>);
+
+/// Parse condition arguments for [find_peaks].
+fn unpack_condition_args<F: Float>(
+    condition: &PeakCondition<F>,
+    x: &[F],
+    peaks: &[usize],
+) -> Result<UnpackedCondition<F>> {
+    let imin = condition
+        .min
+        .as_ref()
+        .map(|b| unpack_bound(b, x, peaks))
+        .transpose()?;
+    let imax = condition
+        .max
+        .as_ref()
+        .map(|b| unpack_bound(b, x, peaks))
+        .transpose()?;
+    Ok((imin, imax))
+}
+
+/// Evaluate where the generic property of peaks confirms to an interval.
+fn select_by_property<F: Float>(
+    peak_properties: &[F],
+    condition: &PeakCondition<F>,
+    x: &[F],
+    peaks: &[usize],
+) -> Result<Vec<bool>> {
+    let (pmin, pmax) = unpack_condition_args(condition, x, peaks)?;
+    Ok(peak_properties
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| {
+            pmin.as_ref().is_none_or(|pmin| pmin[i] <= p)
+                && pmax.as_ref().is_none_or(|pmax| p <= pmax[i])
+        })
+        .collect())
+}
+
+/// Evaluate which peaks fulfill the distance condition.
+///
+/// Peaks are visited in order of decreasing `priority`; all lower-priority peaks closer than
+/// `distance` to a kept peak are removed.
+fn select_by_peak_distance<F: Float>(peaks: &[usize], priority: &[F], distance: F) -> Vec<bool> {
+    let peaks_size = peaks.len();
+    // Round up because actual peak distance can only be natural number
+    let distance = distance.ceil().to_usize().unwrap_or(usize::MAX);
+    let mut keep = vec![true; peaks_size];
+
+    // Create map from `i` (index for `peaks` sorted by `priority`) to `j` (index for `peaks`
+    // sorted by position). This allows to iterate `peaks` and `keep` with `j` by order of
+    // `priority` while still maintaining the ability to step to neighbouring peaks with (`j` +
+    // 1) or (`j` - 1).
+    let mut priority_to_position: Vec<usize> = (0..peaks_size).collect();
+    // NaN ranks lowest, so that the order stays total.
+    priority_to_position.sort_by(|&a, &b| {
+        let (a, b) = (priority[a], priority[b]);
+        match (a.is_nan(), b.is_nan()) {
+            (false, false) => a.partial_cmp(&b).unwrap(),
+            (nan_a, nan_b) => nan_b.cmp(&nan_a),
+        }
+    });
+
+    // Highest priority first -> iterate in reverse order (decreasing)
+    for &j in priority_to_position.iter().rev() {
+        // Skip if peak is already marked for removal
+        if !keep[j] {
+            continue;
+        }
+
+        // Flag "earlier" peaks for removal until minimal distance is exceeded
+        let mut k = j;
+        while k > 0 && peaks[j] - peaks[k - 1] < distance {
+            keep[k - 1] = false;
+            k -= 1;
+        }
+
+        // Flag "later" peaks for removal until minimal distance is exceeded
+        let mut k = j + 1;
+        while k < peaks_size && peaks[k] - peaks[j] < distance {
+            keep[k] = false;
+            k += 1;
+        }
+    }
+    keep
+}
+
+/// Ensure argument `wlen` is of type `usize` and larger than 1.
+///
+/// Returns `None` if `wlen` is `None` which disables the window.
+fn arg_wlen_as_expected<F: Float>(wlen: Option<F>) -> Result<Option<usize>> {
+    match wlen {
+        None => Ok(None),
+        Some(wlen) if wlen > F::one() => Ok(Some(wlen.ceil().to_usize().unwrap_or(usize::MAX))),
+        Some(_) => Err(Error::InvalidArg {
+            arg: "wlen".into(),
+            reason: "`wlen` must be larger than 1".into(),
+        }),
+    }
+}
+
+/// Calculate the prominence of each peak in a signal, see [peak_prominences].
+fn peak_prominences_impl<F: Float>(
+    x: &[F],
+    peaks: &[usize],
+    wlen: Option<usize>,
+) -> Result<PeakProminences<F>> {
+    let mut prominences = Vec::with_capacity(peaks.len());
+    let mut left_bases = Vec::with_capacity(peaks.len());
+    let mut right_bases = Vec::with_capacity(peaks.len());
+
+    for &peak in peaks {
+        let mut i_min = 0;
+        let mut i_max = x.len().saturating_sub(1);
+        if peak > i_max || x.is_empty() {
+            return Err(Error::InvalidArg {
+                arg: "peaks".into(),
+                reason: "peak is not a valid index for `x`".into(),
+            });
+        }
+
+        if let Some(wlen) = wlen {
+            // Adjust window around the evaluated peak (within bounds);
+            // if wlen is even the resulting window length is implicitly
+            // rounded to next odd integer
+            i_min = peak.saturating_sub(wlen / 2);
+            i_max = usize::min(peak + wlen / 2, i_max);
+        }
+
+        // Find the left base in interval [i_min, peak]
+        let mut left_base = peak;
+        let mut left_min = x[peak];
+        let mut i = peak as isize;
+        while i_min as isize <= i && x[i as usize] <= x[peak] {
+            if x[i as usize] < left_min {
+                left_min = x[i as usize];
+                left_base = i as usize;
+            }
+            i -= 1;
+        }
+
+        // Find the right base in interval [peak, i_max]
+        let mut right_base = peak;
+        let mut right_min = x[peak];
+        let mut i = peak;
+        while i <= i_max && x[i] <= x[peak] {
+            if x[i] < right_min {
+                right_min = x[i];
+                right_base = i;
+            }
+            i += 1;
+        }
+
+        prominences.push(x[peak] - left_min.max(right_min));
+        left_bases.push(left_base);
+        right_bases.push(right_base);
+    }
+
+    Ok(PeakProminences {
+        prominences,
+        left_bases,
+        right_bases,
+    })
+}
+
+/// Calculate the width of each peak in a signal, see [peak_widths].
+fn peak_widths_impl<F: Float>(
+    x: &[F],
+    peaks: &[usize],
+    rel_height: F,
+    prominences: &[F],
+    left_bases: &[usize],
+    right_bases: &[usize],
+) -> Result<PeakWidths<F>> {
+    if rel_height < F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "rel_height".into(),
+            reason: "`rel_height` must be greater or equal to 0.0".into(),
+        });
+    }
+    if peaks.len() != prominences.len()
+        || peaks.len() != left_bases.len()
+        || peaks.len() != right_bases.len()
+    {
+        return Err(Error::InvalidArg {
+            arg: "prominence_data".into(),
+            reason: "arrays in `prominence_data` must have the same shape as `peaks`".into(),
+        });
+    }
+
+    let mut widths = Vec::with_capacity(peaks.len());
+    let mut width_heights = Vec::with_capacity(peaks.len());
+    let mut left_ips = Vec::with_capacity(peaks.len());
+    let mut right_ips = Vec::with_capacity(peaks.len());
+
+    for (p, &peak) in peaks.iter().enumerate() {
+        let i_min = left_bases[p];
+        let i_max = right_bases[p];
+        if !(i_min <= peak && peak <= i_max && i_max < x.len()) {
+            return Err(Error::InvalidArg {
+                arg: "prominence_data".into(),
+                reason: "prominence data is invalid for a peak".into(),
+            });
+        }
+        let height = x[peak] - prominences[p] * rel_height;
+
+        // Find intersection point on left side
+        let mut i = peak;
+        while i_min < i && height < x[i] {
+            i -= 1;
+        }
+        let mut left_ip = F::from(i).unwrap();
+        if x[i] < height {
+            // Interpolate if true intersection height is between samples
+            left_ip = left_ip + (height - x[i]) / (x[i + 1] - x[i]);
+        }
+
+        // Find intersection point on right side
+        let mut i = peak;
+        while i < i_max && height < x[i] {
+            i += 1;
+        }
+        let mut right_ip = F::from(i).unwrap();
+        if x[i] < height {
+            // Interpolate if true intersection height is between samples
+            right_ip = right_ip - (height - x[i]) / (x[i - 1] - x[i]);
+        }
+
+        widths.push(right_ip - left_ip);
+        width_heights.push(height);
+        left_ips.push(left_ip);
+        right_ips.push(right_ip);
+    }
+
+    Ok(PeakWidths {
+        widths,
+        width_heights,
+        left_ips,
+        right_ips,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{arr1, Array1};
+
+    fn x() -> Array1<f64> {
+        arr1(&[
+            0., 2., 1., 3., 3., 3., 0.5, 4., 1., 1., 2.5, 0., 5., 4., 4.5, -1.,
+        ])
+    }
+
+    #[track_caller]
+    fn assert_vec_eq(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn local_maxima() {
+        let (peaks, left, right) = local_maxima_1d(x().as_slice().unwrap());
+        assert_eq!(peaks, vec![1, 4, 7, 10, 12, 14]);
+        assert_eq!(left, vec![1, 3, 7, 10, 12, 14]);
+        assert_eq!(right, vec![1, 5, 7, 10, 12, 14]);
+    }
+
+    #[test]
+    fn local_maxima_edge_cases() {
+        assert!(local_maxima_1d::<f64>(&[]).0.is_empty());
+        assert!(local_maxima_1d(&[1.]).0.is_empty());
+        assert!(local_maxima_1d(&[1., 2.]).0.is_empty());
+        // Plateaus touching the border are not peaks.
+        assert!(local_maxima_1d(&[0., 1., 1.]).0.is_empty());
+        assert_eq!(local_maxima_1d(&[0., 1., 1., 0.]).0, vec![1]);
+    }
+
+    #[test]
+    fn no_conditions() {
+        let (peaks, properties) = find_peaks(&x(), FindPeaksOptions::default()).unwrap();
+        assert_eq!(peaks, vec![1, 4, 7, 10, 12, 14]);
+        assert_eq!(properties, PeakProperties::default());
+    }
+
+    #[test]
+    fn plateau_size() {
+        let options = FindPeaksOptions {
+            plateau_size: Some(PeakCondition::at_least(2.)),
+            ..Default::default()
+        };
+        let (peaks, properties) = find_peaks(&x(), options).unwrap();
+        assert_eq!(peaks, vec![4]);
+        assert_eq!(properties.plateau_sizes, Some(vec![3]));
+        assert_eq!(properties.left_edges, Some(vec![3]));
+        assert_eq!(properties.right_edges, Some(vec![5]));
+    }
+
+    #[test]
+    fn height() {
+        let options = FindPeaksOptions {
+            height: Some(PeakCondition::between(2.5, 4.5)),
+            ..Default::default()
+        };
+        let (peaks, properties) = find_peaks(&x(), options).unwrap();
+        assert_eq!(peaks, vec![4, 7, 10, 14]);
+        assert_vec_eq(&properties.peak_heights.unwrap(), &[3., 4., 2.5, 4.5]);
+    }
+
+    #[test]
+    fn height_array_bound() {
+        let mut bound = vec![0.; 16];
+        bound[1] = 3.;
+        bound[12] = 6.;
+        let options = FindPeaksOptions {
+            height: Some(PeakCondition {
+                min: Some(PeakBound::Array(bound)),
+                max: None,
+            }),
+            ..Default::default()
+        };
+        let (peaks, _) = find_peaks(&x(), options).unwrap();
+        assert_eq!(peaks, vec![4, 7, 10, 14]);
+
+        let options = FindPeaksOptions {
+            height: Some(PeakCondition {
+                min: Some(PeakBound::Array(vec![0.; 3])),
+                max: None,
+            }),
+            ..Default::default()
+        };
+        assert!(find_peaks(&x(), options).is_err());
+    }
+
+    #[test]
+    fn threshold() {
+        let options = FindPeaksOptions {
+            threshold: Some(PeakCondition::at_least(1.)),
+            ..Default::default()
+        };
+        let (peaks, properties) = find_peaks(&x(), options).unwrap();
+        assert_eq!(peaks, vec![1, 7, 10, 12]);
+        assert_vec_eq(&properties.left_thresholds.unwrap(), &[2., 3.5, 1.5, 5.]);
+        assert_vec_eq(&properties.right_thresholds.unwrap(), &[1., 3., 2.5, 1.]);
+
+        let options = FindPeaksOptions {
+            threshold: Some(PeakCondition::at_most(3.)),
+            ..Default::default()
+        };
+        let (peaks, _) = find_peaks(&x(), options).unwrap();
+        assert_eq!(peaks, vec![1, 4, 10]);
+    }
+
+    #[test]
+    fn distance() {
+        let options = FindPeaksOptions {
+            distance: Some(3.),
+            ..Default::default()
+        };
+        let (peaks, _) = find_peaks(&x(), options).unwrap();
+        assert_eq!(peaks, vec![1, 4, 7, 12]);
+
+        // NaN samples are not compared, and do not panic.
+        let nan = arr1(&[0., 1., f64::NAN, 2., 0., f64::NAN, 0., 3., 0.]);
+        let options = FindPeaksOptions {
+            distance: Some(2.),
+            ..Default::default()
+        };
+        assert!(find_peaks(&nan, options).is_ok());
+        assert_eq!(
+            select_by_peak_distance(&[1, 2, 3], &[1., f64::NAN, 2.], 2.),
+            vec![true, false, true]
+        );
+
+        let options = FindPeaksOptions {
+            distance: Some(0.5),
+            ..Default::default()
+        };
+        assert!(find_peaks(&x(), options).is_err());
+    }
+
+    #[test]
+    fn prominence() {
+        let options = FindPeaksOptions {
+            prominence: Some(1.5.into()),
+            ..Default::default()
+        };
+        let (peaks, properties) = find_peaks(&x(), options).unwrap();
+        assert_eq!(peaks, vec![4, 7, 10, 12]);
+        assert_vec_eq(&properties.prominences.unwrap(), &[2.5, 4., 1.5, 5.]);
+        assert_eq!(properties.left_bases, Some(vec![0, 0, 9, 11]));
+        assert_eq!(properties.right_bases, Some(vec![6, 11, 11, 15]));
+    }
+
+    #[test]
+    fn prominence_wlen() {
+        let prominences = peak_prominences(&x(), &[1, 4, 7, 10, 12, 14], Some(4.)).unwrap();
+        assert_vec_eq(&prominences.prominences, &[1., 2., 3., 1.5, 1., 0.5]);
+        assert_eq!(prominences.left_bases, vec![0, 2, 6, 9, 11, 13]);
+        assert_eq!(prominences.right_bases, vec![2, 6, 8, 11, 13, 15]);
+
+        assert!(peak_prominences(&x(), &[1], Some(1.)).is_err());
+        assert!(peak_prominences(&x(), &[16], None).is_err());
+    }
+
+    #[test]
+    fn width() {
+        let options = FindPeaksOptions {
+            width: Some(PeakCondition::between(0.75, 3.)),
+            ..Default::default()
+        };
+        let (peaks, properties) = find_peaks(&x(), options).unwrap();
+        assert_eq!(peaks, vec![1, 7, 10, 12]);
+        assert_vec_eq(
+            &properties.widths.unwrap(),
+            &[
+                0.75,
+                1.2380952380952381,
+                0.8000000000000007,
+                2.8636363636363633,
+            ],
+        );
+        assert_vec_eq(&properties.width_heights.unwrap(), &[1.5, 2.0, 1.75, 2.5]);
+        assert_vec_eq(
+            &properties.left_ips.unwrap(),
+            &[0.75, 6.428571428571429, 9.5, 11.5],
+        );
+        assert_vec_eq(
+            &properties.right_ips.unwrap(),
+            &[1.5, 7.666666666666667, 10.3, 14.363636363636363],
+        );
+        assert_vec_eq(&properties.prominences.unwrap(), &[1., 4., 1.5, 5.]);
+    }
+
+    #[test]
+    fn widths_rel_height() {
+        let peaks = [1, 4, 7, 10, 12, 14];
+        let widths = peak_widths(&x(), &peaks, Some(1.), None, None).unwrap();
+        assert_vec_eq(
+            &widths.widths,
+            &[
+                1.5,
+                5.75,
+                11.0,
+                1.5999999999999996,
+                3.8181818181818183,
+                1.0909090909090917,
+            ],
+        );
+        assert_vec_eq(&widths.width_heights, &[1.0, 0.5, 0.0, 1.0, 0.0, 4.0]);
+
+        let prominences = peak_prominences(&x(), &peaks, None).unwrap();
+        let reused = peak_widths(&x(), &peaks, Some(1.), Some(&prominences), None).unwrap();
+        assert_eq!(widths, reused);
+
+        assert!(peak_widths(&x(), &peaks, Some(-1.), None, None).is_err());
+    }
+
+    #[test]
+    fn combined_conditions() {
+        let options = FindPeaksOptions {
+            height: Some(2.0.into()),
+            distance: Some(3.),
+            prominence: Some(2.0.into()),
+            ..Default::default()
+        };
+        let (peaks, properties) = find_peaks(&x(), options).unwrap();
+        assert_eq!(peaks, vec![4, 7, 12]);
+        assert_vec_eq(&properties.peak_heights.unwrap(), &[3., 4., 5.]);
+        assert_vec_eq(&properties.prominences.unwrap(), &[2.5, 4., 5.]);
+    }
+}
@@ -0,0 +1,5 @@
+mod argrelextrema;
+mod find_peaks;
+
+pub use argrelextrema::*;
+pub use find_peaks::*;
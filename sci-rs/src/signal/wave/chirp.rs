@@ -0,0 +1,316 @@
+use nalgebra::RealField;
+use ndarray::{Array, ArrayBase, Data, Dimension};
+use sci_rs_core::{Error, Result};
+
+/// Kind of frequency sweep performed by [chirp].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChirpMethod {
+    /// `f(t) = f0 + (f1 - f0) * t / t1`
+    #[default]
+    Linear,
+    /// `f(t) = f0 + (f1 - f0) * t**2 / t1**2`, or the parabola mirrored about `t1` when
+    /// `vertex_zero` is false.
+    Quadratic,
+    /// `f(t) = f0 * (f1/f0)**(t/t1)`
+    ///
+    /// `f0` and `f1` must be nonzero and have the same sign.
+    Logarithmic,
+    /// `f(t) = f0*f1*t1 / ((f0 - f1)*t + f1*t1)`
+    ///
+    /// `f0` and `f1` must be nonzero.
+    Hyperbolic,
+}
+
+/// Frequency-swept cosine generator.
+///
+/// In the following, 'Hz' should be interpreted as 'cycles per unit';
+/// there is no requirement here that the unit is one second.  The
+/// important distinction is that the units of rotation are cycles, not
+/// radians. Likewise, `t` could be a measurement of space instead of time.
+///
+/// Parameters
+/// ----------
+/// t : array_like
+///   Times at which to evaluate the waveform.
+/// f0 : float
+///   Frequency (e.g. Hz) at time t=0.
+/// t1 : float
+///   Time at which `f1` is specified.
+/// f1 : float
+///   Frequency (e.g. Hz) of the waveform at time `t1`.
+/// method : {Linear, Quadratic, Logarithmic, Hyperbolic}, optional
+///   Kind of frequency sweep.  If not given, [ChirpMethod::Linear] is assumed.
+/// phi : float, optional
+///   Phase offset, in degrees. Default is 0.
+/// vertex_zero : bool, optional
+///   This parameter is only used when `method` is [ChirpMethod::Quadratic].
+///   It determines whether the vertex of the parabola that is the graph
+///   of the frequency is at t=0 or t=t1. Default is true.
+///
+/// Returns
+/// -------
+/// y : ndarray
+///   A numpy array containing the signal evaluated at `t` with the
+///   requested time-varying frequency.  More precisely, the function
+///   returns ``cos(phase + (pi/180)*phi)`` where `phase` is the integral
+///   (from 0 to `t`) of ``2*pi*f(t)``.
+///
+/// Errors
+/// ------
+/// [Error::InvalidArg] if `f0` and `f1` do not satisfy the constraints of a
+/// [ChirpMethod::Logarithmic] or [ChirpMethod::Hyperbolic] sweep.
+///
+/// Examples
+/// --------
+/// A linear chirp from 6 Hz to 1 Hz over 10 seconds:
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::Array1;
+/// use sci_rs::signal::wave::{chirp, ChirpMethod};
+///
+/// let t = Array1::linspace(0., 10., 1500);
+/// let w = chirp(&t, 6., 10., 1., Some(ChirpMethod::Linear), None, None).unwrap();
+/// assert_eq!(w.len(), 1500);
+/// assert_abs_diff_eq!(w[0], 1.);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn chirp<F, S, D>(
+    t: &ArrayBase<S, D>,
+    f0: F,
+    t1: F,
+    f1: F,
+    method: Option<ChirpMethod>,
+    phi: Option<F>,
+    vertex_zero: Option<bool>,
+) -> Result<Array<F, D>>
+where
+    F: RealField + Copy,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let phase = chirp_phase(
+        t,
+        f0,
+        t1,
+        f1,
+        method.unwrap_or_default(),
+        vertex_zero.unwrap_or(true),
+    )?;
+    let phi = phi.unwrap_or_else(F::zero) * F::pi() / F::from_f64(180.).unwrap();
+    Ok(phase.mapv_into(|p| (p + phi).cos()))
+}
+
+/// Calculate the phase used by [chirp] to generate its output.
+#[cfg(feature = "alloc")]
+fn chirp_phase<F, S, D>(
+    t: &ArrayBase<S, D>,
+    f0: F,
+    t1: F,
+    f1: F,
+    method: ChirpMethod,
+    vertex_zero: bool,
+) -> Result<Array<F, D>>
+where
+    F: RealField + Copy,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let two_pi = F::two_pi();
+    let phase = match method {
+        ChirpMethod::Linear => {
+            let beta = (f1 - f0) / t1;
+            let half = F::from_f64(0.5).unwrap();
+            t.mapv(|t| two_pi * (f0 * t + half * beta * t * t))
+        }
+        ChirpMethod::Quadratic => {
+            let beta = (f1 - f0) / (t1 * t1);
+            let three = F::from_f64(3.).unwrap();
+            if vertex_zero {
+                t.mapv(|t| two_pi * (f0 * t + beta * t.powi(3) / three))
+            } else {
+                t.mapv(|t| two_pi * (f1 * t + beta * ((t1 - t).powi(3) - t1.powi(3)) / three))
+            }
+        }
+        ChirpMethod::Logarithmic => {
+            if f0 * f1 <= F::zero() {
+                return Err(Error::InvalidArg {
+                    arg: "f0".into(),
+                    reason:
+                        "For a logarithmic chirp, f0 and f1 must be nonzero and have the same sign."
+                            .into(),
+                });
+            }
+            if f0 == f1 {
+                t.mapv(|t| two_pi * f0 * t)
+            } else {
+                let beta = t1 / (f1 / f0).ln();
+                t.mapv(|t| two_pi * beta * f0 * ((f1 / f0).powf(t / t1) - F::one()))
+            }
+        }
+        ChirpMethod::Hyperbolic => {
+            if f0.is_zero() || f1.is_zero() {
+                return Err(Error::InvalidArg {
+                    arg: "f0".into(),
+                    reason: "For a hyperbolic chirp, f0 and f1 must be nonzero.".into(),
+                });
+            }
+            if f0 == f1 {
+                t.mapv(|t| two_pi * f0 * t)
+            } else {
+                // Singular point: the analytic frequency is infinite at t = sing.
+                let sing = -f1 * t1 / (f0 - f1);
+                t.mapv(|t| two_pi * (-sing * f0) * (F::one() - t / sing).abs().ln())
+            }
+        }
+    };
+    Ok(phase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{arr1, arr2, Array1};
+
+    fn t() -> Array1<f64> {
+        arr1(&[0.0, 0.13, 0.37, 0.5, 0.81, 1.0, 1.42])
+    }
+
+    #[track_caller]
+    fn assert_vec_eq<D: Dimension>(a: Array<f64, D>, b: Array<f64, D>) {
+        assert_eq!(a.shape(), b.shape());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn linear() {
+        let expected = arr1(&[
+            1.0,
+            -0.02387383573,
+            0.943408024602,
+            -1.0,
+            -0.955237064178,
+            1.0,
+            -0.996767670858,
+        ]);
+        let result = chirp(&t(), 6., 1., 2., None, None, None).unwrap();
+        assert_vec_eq(result, expected);
+    }
+
+    #[test]
+    fn linear_phi() {
+        let expected = arr1(&[
+            0.0,
+            0.999714979365,
+            0.331634285195,
+            0.0,
+            0.295841429183,
+            0.0,
+            -0.080338100133,
+        ]);
+        let result = chirp(&t(), 6., 1., 2., None, Some(90.), None).unwrap();
+        assert_vec_eq(result, expected);
+    }
+
+    #[test]
+    fn quadratic() {
+        let expected = arr1(&[
+            1.0,
+            0.16700312691,
+            0.617758329849,
+            0.382683432365,
+            0.923063530123,
+            -1.0,
+            0.156012454412,
+        ]);
+        let result = chirp(&t(), 6., 1., 1.5, Some(ChirpMethod::Quadratic), None, None).unwrap();
+        assert_vec_eq(result, expected);
+
+        let expected = arr1(&[
+            1.0,
+            -0.265415107411,
+            -0.4261800561,
+            0.923879532511,
+            -0.280731370087,
+            1.0,
+            -0.055690460401,
+        ]);
+        let result = chirp(
+            &t(),
+            6.,
+            1.,
+            1.5,
+            Some(ChirpMethod::Quadratic),
+            None,
+            Some(false),
+        )
+        .unwrap();
+        assert_vec_eq(result, expected);
+    }
+
+    #[test]
+    fn logarithmic() {
+        let expected = arr1(&[
+            1.0,
+            -0.225779706084,
+            -0.083530248879,
+            0.514210194972,
+            0.876323453816,
+            0.024729088996,
+            -0.164986989128,
+        ]);
+        let result = chirp(
+            &t(),
+            6.,
+            1.,
+            1.5,
+            Some(ChirpMethod::Logarithmic),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_vec_eq(result, expected);
+
+        assert!(chirp(
+            &t(),
+            -6.,
+            1.,
+            1.5,
+            Some(ChirpMethod::Logarithmic),
+            None,
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn hyperbolic() {
+        let expected = arr1(&[
+            1.0,
+            -0.543193530329,
+            -0.999133992873,
+            0.495903214137,
+            -0.976081674191,
+            0.141453106895,
+            -0.427268539251,
+        ]);
+        let result = chirp(&t(), 6., 1., 1.5, Some(ChirpMethod::Hyperbolic), None, None).unwrap();
+        assert_vec_eq(result, expected);
+
+        assert!(chirp(&t(), 0., 1., 1.5, Some(ChirpMethod::Hyperbolic), None, None).is_err());
+    }
+
+    #[test]
+    fn constant_frequency() {
+        // Degenerate sweeps collapse to a plain cosine.
+        let t = arr2(&[[0.0, 0.1], [0.2, 0.3]]);
+        let expected = t.mapv(|t: f64| (2. * core::f64::consts::PI * 3. * t).cos());
+        for method in [ChirpMethod::Logarithmic, ChirpMethod::Hyperbolic] {
+            let result = chirp(&t, 3., 1., 3., Some(method), None, None).unwrap();
+            assert_vec_eq(result, expected.clone());
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use nalgebra::RealField;
+use ndarray::{Array, ArrayBase, Data, Dimension};
+use sci_rs_core::{Error, Result};
+
+/// Outputs of [gausspulse].
+///
+/// The quadrature and envelope components are only populated when requested through `retquad`
+/// and `retenv` respectively.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaussPulse<F, D>
+where
+    D: Dimension,
+{
+    /// Real part of the signal.
+    pub yi: Array<F, D>,
+    /// Imaginary part of the signal. Only present if `retquad` is true.
+    pub yq: Option<Array<F, D>>,
+    /// Envelope of the signal. Only present if `retenv` is true.
+    pub yenv: Option<Array<F, D>>,
+}
+
+/// Return a Gaussian modulated sinusoid:
+///
+/// ``exp(-a t^2) exp(1j*2*pi*fc*t).``
+///
+/// If `retquad` is true, then return the real and imaginary parts
+/// (in-phase and quadrature).
+/// If `retenv` is true, then return the envelope (unmodulated signal).
+/// Otherwise, return the real part of the modulated sinusoid.
+///
+/// Parameters
+/// ----------
+/// t : ndarray
+///   Input array.
+/// fc : float, optional
+///   Center frequency (e.g. Hz).  Default is 1000.
+/// bw : float, optional
+///   Fractional bandwidth in frequency domain of pulse (e.g. Hz).
+///   Default is 0.5.
+/// bwr : float, optional
+///   Reference level at which fractional bandwidth is calculated (dB).
+///   Default is -6.
+/// retquad : bool, optional
+///   If true, return the quadrature (imaginary) as well as the real part
+///   of the signal.  Default is false.
+/// retenv : bool, optional
+///   If true, return the envelope of the signal.  Default is false.
+///
+/// Returns
+/// -------
+/// [GaussPulse] holding
+/// * yI : ndarray
+///   Real part of signal.  Always returned.
+/// * yQ : ndarray
+///   Imaginary part of signal.  Only returned if `retquad` is true.
+/// * yenv : ndarray
+///   Envelope of signal.  Only returned if `retenv` is true.
+///
+/// See Also
+/// --------
+/// [gausspulse_cutoff] for the ``t='cutoff'`` mode of scipy's `gausspulse`, which takes the
+/// `tpr` argument.
+///
+/// Examples
+/// --------
+/// Plot real component, imaginary component, and envelope for a 5 Hz pulse,
+/// sampled at 100 Hz for 2 seconds:
+///
+/// ```
+/// use ndarray::Array1;
+/// use sci_rs::signal::wave::gausspulse;
+///
+/// let t = Array1::linspace(-1., 1., 200);
+/// let pulse = gausspulse(&t, Some(5.), None, None, Some(true), Some(true)).unwrap();
+/// assert!(pulse.yq.is_some());
+/// assert!(pulse.yenv.is_some());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn gausspulse<F, S, D>(
+    t: &ArrayBase<S, D>,
+    fc: Option<F>,
+    bw: Option<F>,
+    bwr: Option<F>,
+    retquad: Option<bool>,
+    retenv: Option<bool>,
+) -> Result<GaussPulse<F, D>>
+where
+    F: RealField + Copy,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let fc = fc.unwrap_or_else(|| F::from_f64(1000.).unwrap());
+    let a = gausspulse_exponent(fc, bw, bwr)?;
+    let two_pi_fc = F::two_pi() * fc;
+
+    let yenv = t.mapv(|t| (-a * t * t).exp());
+    let yi = ndarray::Zip::from(&yenv)
+        .and(t)
+        .map_collect(|&env, &t| env * (two_pi_fc * t).cos());
+    let yq = retquad.unwrap_or(false).then(|| {
+        ndarray::Zip::from(&yenv)
+            .and(t)
+            .map_collect(|&env, &t| env * (two_pi_fc * t).sin())
+    });
+    let yenv = retenv.unwrap_or(false).then_some(yenv);
+
+    Ok(GaussPulse { yi, yq, yenv })
+}
+
+/// Time at which the trailing envelope of a [gausspulse] falls below `tpr`.
+///
+/// This corresponds to calling scipy's `gausspulse` with ``t='cutoff'``.
+///
+/// Parameters
+/// ----------
+/// fc, bw, bwr : float, optional
+///   As in [gausspulse].
+/// tpr : float, optional
+///   The cutoff time is the point at which the pulse amplitude falls
+///   below `tpr` (in dB).  Default is -60.
+///
+/// Examples
+/// --------
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::signal::wave::gausspulse_cutoff;
+///
+/// let cutoff: f64 = gausspulse_cutoff(Some(5.), None, None, None).unwrap();
+/// assert_relative_eq!(cutoff, 0.5562590089628512, max_relative = 1e-12);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn gausspulse_cutoff<F>(
+    fc: Option<F>,
+    bw: Option<F>,
+    bwr: Option<F>,
+    tpr: Option<F>,
+) -> Result<F>
+where
+    F: RealField + Copy,
+{
+    let fc = fc.unwrap_or_else(|| F::from_f64(1000.).unwrap());
+    let tpr = tpr.unwrap_or_else(|| F::from_f64(-60.).unwrap());
+    if tpr >= F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "tpr".into(),
+            reason: "Reference level for time cutoff must be < 0 dB".into(),
+        });
+    }
+    let a = gausspulse_exponent(fc, bw, bwr)?;
+    let tref = F::from_f64(10.)
+        .unwrap()
+        .powf(tpr / F::from_f64(20.).unwrap());
+    Ok((-tref.ln() / a).sqrt())
+}
+
+/// Validate the pulse parameters and return the Gaussian exponent `a` in ``exp(-a t^2)``.
+#[cfg(feature = "alloc")]
+fn gausspulse_exponent<F>(fc: F, bw: Option<F>, bwr: Option<F>) -> Result<F>
+where
+    F: RealField + Copy,
+{
+    let bw = bw.unwrap_or_else(|| F::from_f64(0.5).unwrap());
+    let bwr = bwr.unwrap_or_else(|| F::from_f64(-6.).unwrap());
+    if fc < F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "fc".into(),
+            reason: "Center frequency must be >= 0.".into(),
+        });
+    }
+    if bw <= F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "bw".into(),
+            reason: "Fractional bandwidth must be > 0.".into(),
+        });
+    }
+    if bwr >= F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "bwr".into(),
+            reason: "Reference level for bandwidth must be < 0 dB".into(),
+        });
+    }
+
+    // exp(-a t^2) <->  sqrt(pi/a) exp(-pi^2/a * f^2)  = g(f)
+    let ref_level = F::from_f64(10.)
+        .unwrap()
+        .powf(bwr / F::from_f64(20.).unwrap());
+    // fdel = fc*bw/2:  g(fdel) = ref --- solve this for a
+    //
+    // pi^2/a * fc^2 * bw^2 /4=-log(ref)
+    let x = F::pi() * fc * bw;
+    Ok(-(x * x) / (F::from_f64(4.).unwrap() * ref_level.ln()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::arr1;
+
+    #[track_caller]
+    fn assert_vec_eq<D: Dimension>(a: Array<f64, D>, b: Array<f64, D>) {
+        assert_eq!(a.shape(), b.shape());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn components() {
+        let t = arr1(&[-0.3, -0.1, 0.0, 0.05, 0.2]);
+        let pulse = gausspulse(&t, Some(5.), None, None, Some(true), Some(true)).unwrap();
+        assert_vec_eq(
+            pulse.yi,
+            arr1(&[
+                -0.1340945635654809,
+                -0.7999183981317266,
+                1.0,
+                5.790849491973185e-17,
+                0.40943290494207846,
+            ]),
+        );
+        assert_vec_eq(
+            pulse.yq.unwrap(),
+            arr1(&[0., 0., 0.0, 0.9457174911174389, 0.]),
+        );
+        assert_vec_eq(
+            pulse.yenv.unwrap(),
+            arr1(&[
+                0.1340945635654809,
+                0.7999183981317266,
+                1.0,
+                0.9457174911174389,
+                0.40943290494207846,
+            ]),
+        );
+    }
+
+    #[test]
+    fn only_requested_outputs() {
+        let t = arr1(&[0.0, 1e-4]);
+        let pulse = gausspulse(&t, None, None, None, None, None).unwrap();
+        assert!(pulse.yq.is_none());
+        assert!(pulse.yenv.is_none());
+    }
+
+    #[test]
+    fn cutoff() {
+        let cutoff = gausspulse_cutoff::<f64>(None, None, None, None).unwrap();
+        assert_abs_diff_eq!(cutoff, 0.0027812950448142565, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn invalid_args() {
+        let t = arr1(&[0.0]);
+        assert!(gausspulse(&t, Some(-1.), None, None, None, None).is_err());
+        assert!(gausspulse(&t, None, Some(0.), None, None, None).is_err());
+        assert!(gausspulse(&t, None, None, Some(1.), None, None).is_err());
+        assert!(gausspulse_cutoff(None, None, None, Some(0.)).is_err());
+    }
+}
@@ -0,0 +1,249 @@
+use alloc::vec::Vec;
+use ndarray::Array1;
+use sci_rs_core::{Error, Result};
+
+/// Default feedback taps for each supported `nbits`, taken from scipy.
+const MLS_TAPS: [&[usize]; 31] = [
+    &[1],
+    &[2],
+    &[3],
+    &[3],
+    &[5],
+    &[6],
+    &[7, 6, 1],
+    &[5],
+    &[7],
+    &[9],
+    &[11, 10, 4],
+    &[12, 11, 8],
+    &[13, 12, 2],
+    &[14],
+    &[15, 13, 4],
+    &[14],
+    &[11],
+    &[18, 17, 14],
+    &[17],
+    &[19],
+    &[21],
+    &[18],
+    &[23, 22, 17],
+    &[22],
+    &[25, 24, 20],
+    &[26, 25, 22],
+    &[25],
+    &[27],
+    &[29, 28, 7],
+    &[28],
+    &[31, 30, 10],
+];
+
+/// Maximum length sequence (MLS) generator.
+///
+/// Parameters
+/// ----------
+/// nbits : int
+///   Number of bits to use. Length of the resulting sequence will
+///   be ``(2**nbits) - 1``. Note that generating long sequences
+///   (e.g., greater than ``nbits == 16``) can take a long time.
+/// state : array_like, optional
+///   If array, must be of length ``nbits``, and will be cast to binary
+///   (bool) representation. If None, a seed of ones will be used,
+///   producing a repeatable representation. If ``state`` is all
+///   zeros, an error is raised as this is invalid. Default: None.
+/// length : int, optional
+///   Number of samples to compute. If None, the entire length
+///   ``(2**nbits) - 1`` is computed.
+/// taps : array_like, optional
+///   Polynomial taps to use (e.g., ``[7, 6, 1]`` for an 8-bit sequence).
+///   If None, taps will be automatically selected (for up to
+///   ``nbits == 32``).
+///
+/// Returns
+/// -------
+/// seq : array
+///   Resulting MLS sequence of 0's and 1's.
+/// state : array
+///   The final state of the shift register, which can be passed back as `state` to continue the
+///   sequence.
+///
+/// Notes
+/// -----
+/// The algorithm for MLS generation is generically described in:
+///
+///   <https://en.wikipedia.org/wiki/Maximum_length_sequence>
+///
+/// The default values for taps are specifically taken from the first
+/// option listed for each value of ``nbits`` in:
+///
+///   <https://web.archive.org/web/20181001062252/http://www.newwaveinstruments.com/resources/articles/m_sequence_linear_feedback_shift_register_lfsr.htm>
+///
+/// Errors
+/// ------
+/// [Error::InvalidArg] if `taps` is not given and `nbits` is outside \[2, 32\], if `taps` is
+/// empty or holds values greater than `nbits`, or if `state` is not of length `nbits` or is all
+/// zeros.
+///
+/// Examples
+/// --------
+/// MLS uses binary convention:
+///
+/// ```custom,{class=language-python}
+/// >>> from scipy.signal import max_len_seq
+/// >>> max_len_seq(4)[0]
+/// array([1, 1, 1, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 0, 0], dtype=int8)
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::signal::wave::max_len_seq;
+///
+/// let (seq, state) = max_len_seq(4, None, None, None).unwrap();
+/// assert_eq!(seq, array![1, 1, 1, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 0, 0]);
+/// assert_eq!(state, array![1, 1, 1, 1]);
+/// ```
+///
+/// MLS has a white spectrum (except for DC), which makes its circular autocorrelation an impulse.
+/// This is what makes it useful for measuring impulse responses by cross-correlation.
+pub fn max_len_seq(
+    nbits: usize,
+    state: Option<&[u8]>,
+    length: Option<usize>,
+    taps: Option<&[usize]>,
+) -> Result<(Array1<u8>, Array1<u8>)> {
+    let taps: Vec<usize> = match taps {
+        None => {
+            if !(2..=MLS_TAPS.len() + 1).contains(&nbits) {
+                return Err(Error::InvalidArg {
+                    arg: "nbits".into(),
+                    reason: "nbits must be between 2 and 32 if taps is None".into(),
+                });
+            }
+            MLS_TAPS[nbits - 2].to_vec()
+        }
+        Some(taps) => {
+            let mut taps = taps.to_vec();
+            taps.sort_unstable_by(|a, b| b.cmp(a));
+            taps.dedup();
+            if taps.is_empty() || taps[0] > nbits {
+                return Err(Error::InvalidArg {
+                    arg: "taps".into(),
+                    reason: "taps must be non-empty with values between zero and nbits (inclusive)"
+                        .into(),
+                });
+            }
+            taps
+        }
+    };
+
+    let length = match length {
+        Some(length) => length,
+        None => 1usize
+            .checked_shl(nbits as u32)
+            .filter(|_| nbits < usize::BITS as usize)
+            .map(|n| n - 1)
+            .ok_or(Error::InvalidArg {
+                arg: "nbits".into(),
+                reason: "The full sequence length does not fit in usize, specify length".into(),
+            })?,
+    };
+
+    let mut state: Vec<u8> = match state {
+        None => alloc::vec![1; nbits],
+        Some(state) => state.iter().map(|&s| u8::from(s != 0)).collect(),
+    };
+    if state.len() != nbits {
+        return Err(Error::InvalidArg {
+            arg: "state".into(),
+            reason: "state must be a 1-D array of size nbits".into(),
+        });
+    }
+    if state.iter().all(|&s| s == 0) {
+        return Err(Error::InvalidArg {
+            arg: "state".into(),
+            reason: "state must not be all zeros".into(),
+        });
+    }
+
+    let mut seq = Vec::with_capacity(length);
+    let mut idx = 0;
+    for _ in 0..length {
+        let mut feedback = state[idx];
+        seq.push(feedback);
+        for &tap in &taps {
+            feedback ^= state[(tap + idx) % nbits];
+        }
+        state[idx] = feedback;
+        idx = (idx + 1) % nbits;
+    }
+    // Roll the state so that the next run, which starts with idx == 0, continues the sequence.
+    state.rotate_left(idx);
+
+    Ok((Array1::from_vec(seq), Array1::from_vec(state)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{arr1, concatenate, Axis};
+
+    #[test]
+    fn default_taps() {
+        for nbits in 2..=16 {
+            let (seq, state) = max_len_seq(nbits, None, None, None).unwrap();
+            let n = (1 << nbits) - 1;
+            assert_eq!(seq.len(), n);
+            // A maximum length sequence returns to its initial state after a full period and has
+            // exactly one more 1 than 0.
+            assert_eq!(state, Array1::<u8>::ones(nbits));
+            assert_eq!(seq.iter().filter(|&&s| s == 1).count(), n / 2 + 1);
+
+            // The circular autocorrelation of the +-1 mapped sequence is an impulse.
+            if nbits > 10 {
+                continue;
+            }
+            let bipolar = seq.mapv(|s| 2 * s as i64 - 1);
+            for lag in 0..n {
+                let corr: i64 = (0..n).map(|i| bipolar[i] * bipolar[(i + lag) % n]).sum();
+                assert_eq!(corr, if lag == 0 { n as i64 } else { -1 });
+            }
+        }
+    }
+
+    #[test]
+    fn continued_state() {
+        let (full, _) = max_len_seq(3, None, None, None).unwrap();
+        let (first, state) = max_len_seq(3, None, Some(5), None).unwrap();
+        assert_eq!(first, arr1(&[1, 1, 1, 0, 1]));
+        assert_eq!(state, arr1(&[0, 0, 1]));
+        let (second, state) =
+            max_len_seq(3, Some(state.as_slice().unwrap()), Some(4), None).unwrap();
+        assert_eq!(second, arr1(&[0, 0, 1, 1]));
+        assert_eq!(state, arr1(&[1, 0, 1]));
+        let joined = concatenate![Axis(0), first, second];
+        assert_eq!(joined.slice(ndarray::s![..7]), full);
+    }
+
+    #[test]
+    fn custom_taps_and_state() {
+        let (seq, state) = max_len_seq(5, Some(&[1, 0, 0, 3, 0]), None, Some(&[2])).unwrap();
+        assert_eq!(
+            seq,
+            arr1(&[
+                1, 0, 0, 1, 0, 1, 1, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 1, 0, 1, 0, 1, 0,
+                0, 0, 0
+            ])
+        );
+        assert_eq!(state, arr1(&[1, 0, 0, 1, 0]));
+    }
+
+    #[test]
+    fn invalid_args() {
+        assert!(max_len_seq(1, None, None, None).is_err());
+        assert!(max_len_seq(33, None, None, None).is_err());
+        assert!(max_len_seq(4, None, None, Some(&[])).is_err());
+        assert!(max_len_seq(4, None, None, Some(&[5])).is_err());
+        assert!(max_len_seq(4, Some(&[1, 0, 1]), None, None).is_err());
+        assert!(max_len_seq(4, Some(&[0, 0, 0, 0]), None, None).is_err());
+        assert_eq!(max_len_seq(4, None, Some(0), None).unwrap().0.len(), 0);
+    }
+}
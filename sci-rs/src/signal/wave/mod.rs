@@ -1,5 +1,20 @@
 use nalgebra::RealField;
 use ndarray::{Array, ArrayBase, Data, Dimension, RawData};
+use num_traits::Float;
+
+#[cfg(feature = "alloc")]
+use sci_rs_core::{Error, Result};
+
+mod chirp;
+mod gausspulse;
+#[cfg(feature = "alloc")]
+mod max_len_seq;
+mod sawtooth;
+pub use chirp::*;
+pub use gausspulse::*;
+#[cfg(feature = "alloc")]
+pub use max_len_seq::*;
+pub use sawtooth::*;
 
 /// Return a periodic square-wave waveform.
 ///
@@ -15,10 +30,9 @@ use ndarray::{Array, ArrayBase, Data, Dimension, RawData};
 /// ----------
 /// t : array_like  
 ///   The input time array.
-/// duty : array_like, optional  
-///   Duty cycle.  Default is 0.5 (50% duty cycle).
-///   If an array, causes wave shape to change over time, and must be the
-///   same length as t.
+/// duty : float  
+///   Duty cycle.  Scipy defaults to 0.5 (50% duty cycle).
+///   See [square_modulated] for a duty cycle that changes over time.
 ///
 /// Returns
 /// -------
@@ -59,11 +73,7 @@ where
     assert!(F::zero() <= duty && duty <= F::one());
     let duty_threshold = F::two_pi() * duty;
     t.mapv(|t| {
-        let x = t % F::two_pi();
-        // Because % is the reminder and not the modulo operator, x can be negative.
-        let x = if x < F::zero() { x + F::two_pi() } else { x };
-        debug_assert!(F::zero() <= x && x <= F::two_pi());
-        if x < duty_threshold {
+        if wrap_two_pi(t) < duty_threshold {
             F::one()
         } else {
             -F::one()
@@ -71,6 +81,72 @@ where
     })
 }
 
+/// Return a periodic square-wave waveform whose duty cycle varies over time.
+///
+/// This is [square] with an array of duty cycles, for example to produce a pulse-width
+/// modulated signal. `duty` is broadcast against `t`. Samples where `duty` lies outside \[0, 1\]
+/// are set to NaN, as in scipy.
+///
+/// Errors
+/// ------
+/// [Error::InvalidArg] if `duty` cannot be broadcast to the shape of `t`.
+///
+/// Examples
+/// --------
+/// A pulse-width modulated sine wave:
+///
+/// ```custom,{class=language-python}
+/// >>> sig = np.sin(2 * np.pi * t)
+/// >>> pwm = signal.square(2 * np.pi * 30 * t, duty=(sig + 1)/2)
+/// ```
+/// Sci-rs:
+/// ```
+/// use core::f64::consts::PI;
+/// use ndarray::Array1;
+/// use sci_rs::signal::wave::square_modulated;
+///
+/// let t = Array1::linspace(0., 1., 500);
+/// let sig = t.mapv(|t: f64| (2. * PI * t).sin());
+/// let pwm = square_modulated(&t.mapv(|t| 2. * PI * 30. * t), &((sig + 1.) / 2.)).unwrap();
+/// assert!(pwm.iter().all(|&y| y == 1. || y == -1.));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn square_modulated<F, S, D, SD, DD>(
+    t: &ArrayBase<S, D>,
+    duty: &ArrayBase<SD, DD>,
+) -> Result<Array<F, D>>
+where
+    F: RealField + Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+    SD: Data<Elem = F>,
+    DD: Dimension,
+{
+    let duty = duty.broadcast(t.raw_dim()).ok_or(Error::InvalidArg {
+        arg: "duty".into(),
+        reason: "duty could not be broadcast to the shape of t.".into(),
+    })?;
+    Ok(ndarray::Zip::from(t).and(&duty).map_collect(|&t, &w| {
+        if w < F::zero() || w > F::one() {
+            <F as Float>::nan()
+        } else if wrap_two_pi(t) < F::two_pi() * w {
+            F::one()
+        } else {
+            -F::one()
+        }
+    }))
+}
+
+/// Map `t` onto the period \[0, 2*pi) of the periodic waveforms.
+#[inline]
+fn wrap_two_pi<F: RealField>(t: F) -> F {
+    let x = t % F::two_pi();
+    // Because % is the reminder and not the modulo operator, x can be negative.
+    let x = if x < F::zero() { x + F::two_pi() } else { x };
+    debug_assert!(F::zero() <= x && x <= F::two_pi());
+    x
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,6 +228,41 @@ mod tests {
         assert_vec_eq(result, expected);
     }
 
+    #[test]
+    fn test_square_modulated() {
+        let t = arr1(&[
+            -4.991, -4.973, -3.988, -3.084, -2.562, -2.378, -1.618, -1.449, -0.8056, -0.6883,
+        ]);
+        let duty = arr1(&[0.3, 0.5, 0.7, 0.3, 0.5, 0.7, 0.3, 0.5, 0.7, 1.2]);
+        let expected = arr1(&[1.0, 1.0, 1.0, -1.0, -1.0, 1.0, -1.0, -1.0, -1.0, f32::NAN]);
+        let result = square_modulated(&t, &duty).unwrap();
+        assert_eq!(result.shape(), expected.shape());
+        for (a, b) in result.into_iter().zip(expected) {
+            if b.is_nan() {
+                assert!(a.is_nan());
+            } else {
+                assert_abs_diff_eq!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_square_modulated_scalar_duty() {
+        let t = arr3(&[
+            [
+                [-4.452, -4.182, -3.663, -3.307, -2.995],
+                [-2.482, -2.46, -1.929, -1.823, -1.44],
+            ],
+            [
+                [-0.8743, 0.5359, 0.9073, 2.101, 2.161],
+                [2.582, 2.977, 3.966, 4.298, 4.659],
+            ],
+        ]);
+        let result = square_modulated(&t, &ndarray::arr0(0.67)).unwrap();
+        assert_vec_eq(result, square(&t, 0.67));
+        assert!(square_modulated(&t, &arr1(&[0.5, 0.5])).is_err());
+    }
+
     #[track_caller]
     fn assert_vec_eq<D: Dimension>(a: Array<f32, D>, b: Array<f32, D>) {
         assert_eq!(a.shape(), b.shape());
@@ -0,0 +1,212 @@
+use nalgebra::RealField;
+use ndarray::{Array, ArrayBase, Data, Dimension};
+use num_traits::Float;
+
+#[cfg(feature = "alloc")]
+use sci_rs_core::{Error, Result};
+
+/// Return a periodic sawtooth or triangle waveform.
+///
+/// The sawtooth waveform has a period ``2*pi``, rises from -1 to 1 on the
+/// interval 0 to ``width*2*pi``, then drops from 1 to -1 on the interval
+/// ``width*2*pi`` to ``2*pi``. `width` must be in the interval \[0, 1\].
+///
+/// Note that this is not band-limited.  It produces an infinite number
+/// of harmonics, which are aliased back and forth across the frequency
+/// spectrum.
+///
+/// Parameters
+/// ----------
+/// t : array_like
+///   Time.
+/// width : float
+///   Width of the rising ramp as a proportion of the total cycle.
+///   Scipy defaults to 1, producing a rising ramp, while 0 produces a falling
+///   ramp.  `width` = 0.5 produces a triangle wave.
+///   See [sawtooth_modulated] for a width that changes over time.
+///
+/// Returns
+/// -------
+/// y : ndarray
+///   Output array containing the sawtooth waveform.
+///
+/// Examples
+/// --------
+/// A 5 Hz waveform sampled at 500 Hz for 1 second:
+///
+/// ```custom,{class=language-python}
+/// >>> import numpy as np
+/// >>> from scipy import signal
+/// >>> import matplotlib.pyplot as plt
+/// >>> t = np.linspace(0, 1, 500)
+/// >>> plt.plot(t, signal.sawtooth(2 * np.pi * 5 * t))
+/// ```
+/// Sci-rs:
+/// ```
+/// use ndarray::Array1;
+/// use sci_rs::signal::wave::sawtooth;
+///
+/// let t = Array1::linspace(0., 1., 500).mapv(|t: f64| 2. * core::f64::consts::PI * 5. * t);
+/// let y = sawtooth(&t, 1.);
+/// assert_eq!(y[0], -1.);
+/// ```
+pub fn sawtooth<F, S, D>(t: &ArrayBase<S, D>, width: F) -> Array<F, D>
+where
+    F: RealField + Copy,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    assert!(F::zero() <= width && width <= F::one());
+    t.mapv(|t| sawtooth_sample(t, width))
+}
+
+/// Return a periodic sawtooth or triangle waveform whose `width` varies over time.
+///
+/// This is [sawtooth] with an array of widths, for example to produce a modulated triangle
+/// wave. `width` is broadcast against `t`. Samples where `width` lies outside \[0, 1\] are set to
+/// NaN, as in scipy.
+///
+/// Errors
+/// ------
+/// [Error::InvalidArg] if `width` cannot be broadcast to the shape of `t`.
+///
+/// Examples
+/// --------
+/// ```
+/// use ndarray::array;
+/// use sci_rs::signal::wave::sawtooth_modulated;
+///
+/// let t = array![0f64, 1., 2.];
+/// let width = array![0., 0.5, 2.];
+/// let y = sawtooth_modulated(&t, &width).unwrap();
+/// assert_eq!(y[0], 1.);
+/// assert!(y[2].is_nan());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn sawtooth_modulated<F, S, D, SW, DW>(
+    t: &ArrayBase<S, D>,
+    width: &ArrayBase<SW, DW>,
+) -> Result<Array<F, D>>
+where
+    F: RealField + Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+    SW: Data<Elem = F>,
+    DW: Dimension,
+{
+    let width = width.broadcast(t.raw_dim()).ok_or(Error::InvalidArg {
+        arg: "width".into(),
+        reason: "width could not be broadcast to the shape of t.".into(),
+    })?;
+    Ok(ndarray::Zip::from(t).and(&width).map_collect(|&t, &w| {
+        if w < F::zero() || w > F::one() {
+            <F as Float>::nan()
+        } else {
+            sawtooth_sample(t, w)
+        }
+    }))
+}
+
+/// Evaluate a single sample of the sawtooth with a valid `width`.
+#[inline]
+fn sawtooth_sample<F: RealField + Copy>(t: F, width: F) -> F {
+    let tmod = super::wrap_two_pi(t);
+    if tmod < width * F::two_pi() {
+        tmod / (F::pi() * width) - F::one()
+    } else {
+        (F::pi() * (width + F::one()) - tmod) / (F::pi() * (F::one() - width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{arr1, arr2, Array1};
+
+    fn t() -> Array1<f64> {
+        arr1(&[-4.3, -1.2, 0.0, 0.7, 2.5, 3.9, 6.1, 7.0])
+    }
+
+    #[track_caller]
+    fn assert_vec_eq<D: Dimension>(a: Array<f64, D>, b: Array<f64, D>) {
+        assert_eq!(a.shape(), b.shape());
+        for (a, b) in a.into_iter().zip(b) {
+            if b.is_nan() {
+                assert!(a.is_nan());
+            } else {
+                assert_abs_diff_eq!(a, b, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn rising_ramp() {
+        let expected = arr1(&[
+            -0.3687325105903,
+            0.6180281365795,
+            -1.0,
+            -0.7771830796713,
+            -0.2042252845405,
+            0.2414085561168,
+            0.9416903057211,
+            -0.7718307967135,
+        ]);
+        assert_vec_eq(sawtooth(&t(), 1.), expected);
+    }
+
+    #[test]
+    fn falling_ramp() {
+        let expected = arr1(&[
+            0.3687325105903,
+            -0.6180281365795,
+            1.0,
+            0.7771830796713,
+            0.2042252845405,
+            -0.2414085561168,
+            -0.9416903057211,
+            0.7718307967135,
+        ]);
+        assert_vec_eq(sawtooth(&t(), 0.), expected);
+    }
+
+    #[test]
+    fn triangle() {
+        let expected = arr1(&[
+            0.2625349788194,
+            -0.2360562731589,
+            -1.0,
+            -0.5543661593427,
+            0.591549430919,
+            0.5171828877664,
+            -0.8833806114422,
+            -0.5436615934269,
+        ]);
+        assert_vec_eq(sawtooth(&t(), 0.5), expected);
+    }
+
+    #[test]
+    fn modulated() {
+        let t = arr2(&[[-4.3, -1.2, 0.0, 0.7], [2.5, 3.9, 6.1, 7.0]]);
+        let width = arr2(&[[1.0, 0.5, 0.25, -0.1], [0.0, 0.25, 1.5, 0.5]]);
+        let expected = arr2(&[
+            [-0.3687325105903, -0.2360562731589, -1.0, f64::NAN],
+            [0.2042252845405, 0.011455258511, f64::NAN, -0.5436615934269],
+        ]);
+        assert_vec_eq(sawtooth_modulated(&t, &width).unwrap(), expected);
+    }
+
+    #[test]
+    fn modulated_broadcast() {
+        let t = arr2(&[[-4.3, -1.2], [0.0, 0.7]]);
+        let width = arr1(&[1.0, 0.25]);
+        let expected = arr2(&[
+            [-0.3687325105903, -0.4907041821059],
+            [-1.0, -0.1087323186854],
+        ]);
+        assert_vec_eq(sawtooth_modulated(&t, &width).unwrap(), expected);
+
+        let width = arr1(&[1.0, 0.25, 0.5]);
+        assert!(sawtooth_modulated(&t, &width).is_err());
+    }
+}
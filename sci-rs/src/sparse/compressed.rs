@@ -0,0 +1,631 @@
+use super::CooMatrix;
+use alloc::{format, vec, vec::Vec};
+use core::ops::Range;
+use ndarray::{Array2, ArrayBase, Data, Ix2};
+use num_traits::Num;
+use sci_rs_core::{Error, Result};
+
+/// Storage shared by the compressed formats: the entries of each lane along the major axis (the
+/// rows of CSR, the columns of CSC) are `indices[indptr[i]..indptr[i + 1]]` along the minor axis,
+/// with values `data[indptr[i]..indptr[i + 1]]`.
+#[derive(Debug, Clone, PartialEq)]
+struct Compressed<F> {
+    major: usize,
+    minor: usize,
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    data: Vec<F>,
+}
+
+impl<F: Num + Copy> Compressed<F> {
+    fn new(
+        (major, minor): (usize, usize),
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: Vec<F>,
+    ) -> Result<Self> {
+        if indptr.len() != major + 1 {
+            return Err(Error::InvalidArg {
+                arg: "indptr".into(),
+                reason: format!(
+                    "Expected indptr of length {}, got {}.",
+                    major + 1,
+                    indptr.len()
+                ),
+            });
+        }
+        if indptr[0] != 0 || indptr.windows(2).any(|w| w[0] > w[1]) {
+            return Err(Error::InvalidArg {
+                arg: "indptr".into(),
+                reason: "indptr must start at 0 and be non-decreasing.".into(),
+            });
+        }
+        if indices.len() != data.len() || indptr[major] != data.len() {
+            return Err(Error::InvalidArg {
+                arg: "indices".into(),
+                reason: "indices and data must have the same length, the last value of indptr."
+                    .into(),
+            });
+        }
+        if indices.iter().any(|&i| i >= minor) {
+            return Err(Error::InvalidArg {
+                arg: "indices".into(),
+                reason: "Index exceeds matrix dimensions.".into(),
+            });
+        }
+        Ok(Compressed {
+            major,
+            minor,
+            indptr,
+            indices,
+            data,
+        })
+    }
+
+    /// Compress triplets, with the minor indices of each lane sorted and duplicates summed.
+    fn from_triplets(
+        (major, minor): (usize, usize),
+        majors: &[usize],
+        minors: &[usize],
+        data: &[F],
+    ) -> Self {
+        let mut indptr = vec![0; major + 1];
+        for &i in majors {
+            indptr[i + 1] += 1;
+        }
+        for i in 0..major {
+            indptr[i + 1] += indptr[i];
+        }
+        let mut next = indptr.clone();
+        let mut entries = vec![(0, F::zero()); data.len()];
+        for ((&i, &j), &x) in majors.iter().zip(minors).zip(data) {
+            entries[next[i]] = (j, x);
+            next[i] += 1;
+        }
+        let (mut indices, mut values) = (Vec::with_capacity(data.len()), Vec::new());
+        let mut compressed = vec![0; major + 1];
+        for i in 0..major {
+            let lane = &mut entries[indptr[i]..indptr[i + 1]];
+            lane.sort_by_key(|&(j, _)| j);
+            for &(j, x) in lane.iter() {
+                if indices.len() > compressed[i] && indices.last() == Some(&j) {
+                    let last = values.len() - 1;
+                    values[last] = values[last] + x;
+                } else {
+                    indices.push(j);
+                    values.push(x);
+                }
+            }
+            compressed[i + 1] = indices.len();
+        }
+        Compressed {
+            major,
+            minor,
+            indptr: compressed,
+            indices,
+            data: values,
+        }
+    }
+
+    fn from_dense<S: Data<Elem = F>>(dense: &ArrayBase<S, Ix2>) -> Self {
+        let (major, minor) = dense.dim();
+        let (mut indptr, mut indices, mut data) = (vec![0], Vec::new(), Vec::new());
+        for lane in dense.rows() {
+            for (j, &x) in lane.iter().enumerate() {
+                if x != F::zero() {
+                    indices.push(j);
+                    data.push(x);
+                }
+            }
+            indptr.push(data.len());
+        }
+        Compressed {
+            major,
+            minor,
+            indptr,
+            indices,
+            data,
+        }
+    }
+
+    /// The major and minor indices, and the values, of the entries.
+    fn triplets(&self) -> (Vec<usize>, Vec<usize>, Vec<F>) {
+        let majors = (0..self.major)
+            .flat_map(|i| core::iter::repeat_n(i, self.indptr[i + 1] - self.indptr[i]))
+            .collect();
+        (majors, self.indices.clone(), self.data.clone())
+    }
+
+    fn get(&self, i: usize, j: usize) -> F {
+        assert!(i < self.major && j < self.minor, "index out of bounds");
+        let lane = self.indptr[i]..self.indptr[i + 1];
+        self.indices[lane.clone()]
+            .iter()
+            .zip(&self.data[lane])
+            .filter(|&(&k, _)| k == j)
+            .fold(F::zero(), |acc, (_, &x)| acc + x)
+    }
+
+    /// The same matrix, compressed along the other axis. The new lanes are sorted, as a counting
+    /// sort of the entries by minor index.
+    fn swap_axes(&self) -> Self {
+        let mut indptr = vec![0; self.minor + 1];
+        for &j in &self.indices {
+            indptr[j + 1] += 1;
+        }
+        for j in 0..self.minor {
+            indptr[j + 1] += indptr[j];
+        }
+        let mut next = indptr.clone();
+        let (mut indices, mut data) = (vec![0; self.nnz()], vec![F::zero(); self.nnz()]);
+        for i in 0..self.major {
+            for k in self.indptr[i]..self.indptr[i + 1] {
+                let j = self.indices[k];
+                indices[next[j]] = i;
+                data[next[j]] = self.data[k];
+                next[j] += 1;
+            }
+        }
+        Compressed {
+            major: self.minor,
+            minor: self.major,
+            indptr,
+            indices,
+            data,
+        }
+    }
+
+    fn slice(&self, major: Range<usize>, minor: Range<usize>) -> Self {
+        let (mut indptr, mut indices, mut data) = (vec![0], Vec::new(), Vec::new());
+        for i in major.clone() {
+            for k in self.indptr[i]..self.indptr[i + 1] {
+                if minor.contains(&self.indices[k]) {
+                    indices.push(self.indices[k] - minor.start);
+                    data.push(self.data[k]);
+                }
+            }
+            indptr.push(data.len());
+        }
+        Compressed {
+            major: major.len(),
+            minor: minor.len(),
+            indptr,
+            indices,
+            data,
+        }
+    }
+
+    /// The dense array of shape `(major, minor)`.
+    fn to_dense(&self) -> Array2<F> {
+        let mut dense = Array2::zeros((self.major, self.minor));
+        for i in 0..self.major {
+            for k in self.indptr[i]..self.indptr[i + 1] {
+                let j = self.indices[k];
+                dense[[i, j]] = dense[[i, j]] + self.data[k];
+            }
+        }
+        dense
+    }
+
+    fn nnz(&self) -> usize {
+        self.data.len()
+    }
+}
+
+/// Sparse matrix in compressed sparse row format.
+///
+/// The columns of the entries of row `i` are `indices[indptr[i]..indptr[i + 1]]`, and their
+/// values `data[indptr[i]..indptr[i + 1]]`. The matrices built by Sci-rs have sorted column
+/// indices in each row and no duplicates.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.sparse import csr_array
+/// a = csr_array(np.array([[1., 0., 2.], [0., 0., 3.], [4., 5., 6.]]))
+/// a.indptr, a.indices, a.data
+/// # (array([0, 2, 3, 6]), array([0, 2, 2, 0, 1, 2]), array([1., 2., 3., 4., 5., 6.]))
+/// a[1:, :2].toarray()
+/// # array([[0., 0.],
+/// #        [4., 5.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::sparse::CsrMatrix;
+///
+/// let a = CsrMatrix::from_dense(&array![[1., 0., 2.], [0., 0., 3.], [4., 5., 6.]]);
+/// assert_eq!(a.indptr(), &[0, 2, 3, 6]);
+/// assert_eq!(a.indices(), &[0, 2, 2, 0, 1, 2]);
+/// assert_eq!(a.data(), &[1., 2., 3., 4., 5., 6.]);
+/// assert_eq!(a.slice(1..3, 0..2).unwrap().to_dense(), array![[0., 0.], [4., 5.]]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<F> {
+    storage: Compressed<F>,
+}
+
+impl<F: Num + Copy> CsrMatrix<F> {
+    /// Build a matrix from its compressed rows.
+    ///
+    /// ## Parameters
+    /// * `shape`: The shape `(rows, columns)` of the matrix.
+    /// * `indptr`: The offsets of the rows in `indices` and `data`, of length `rows + 1`.
+    /// * `indices`: The column of each entry.
+    /// * `data`: The value of each entry.
+    ///
+    /// ## Errors
+    /// If `indptr` is not non-decreasing from 0 to the number of entries, or a column is out of
+    /// `shape`.
+    pub fn new(
+        shape: (usize, usize),
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: Vec<F>,
+    ) -> Result<Self> {
+        Ok(CsrMatrix {
+            storage: Compressed::new(shape, indptr, indices, data)?,
+        })
+    }
+
+    /// Build a matrix from the triplets `(row[k], col[k], data[k])` of its entries, summing
+    /// duplicates.
+    ///
+    /// ## Errors
+    /// If the lengths of `row`, `col` and `data` differ, or an index is out of `shape`.
+    pub fn from_triplets(
+        shape: (usize, usize),
+        row: &[usize],
+        col: &[usize],
+        data: &[F],
+    ) -> Result<Self> {
+        check_triplets(shape, row, col, data)?;
+        Ok(CsrMatrix {
+            storage: Compressed::from_triplets(shape, row, col, data),
+        })
+    }
+
+    /// Build a matrix from the nonzero elements of a dense array.
+    pub fn from_dense<S: Data<Elem = F>>(dense: &ArrayBase<S, Ix2>) -> Self {
+        CsrMatrix {
+            storage: Compressed::from_dense(dense),
+        }
+    }
+
+    /// The shape `(rows, columns)` of the matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.storage.major, self.storage.minor)
+    }
+
+    /// The number of stored entries, including explicit zeros.
+    pub fn nnz(&self) -> usize {
+        self.storage.nnz()
+    }
+
+    /// The offsets of the rows in [indices](CsrMatrix::indices) and [data](CsrMatrix::data).
+    pub fn indptr(&self) -> &[usize] {
+        &self.storage.indptr
+    }
+
+    /// The columns of the entries.
+    pub fn indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+
+    /// The values of the entries.
+    pub fn data(&self) -> &[F] {
+        &self.storage.data
+    }
+
+    /// The element at `(row, col)`.
+    ///
+    /// ## Panics
+    /// If the index is out of the shape of the matrix.
+    pub fn get(&self, row: usize, col: usize) -> F {
+        self.storage.get(row, col)
+    }
+
+    /// The submatrix of the given rows and columns.
+    ///
+    /// ## Errors
+    /// If a range is out of the shape of the matrix.
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Self> {
+        let (nrows, ncols) = self.shape();
+        check_range("rows", &rows, nrows)?;
+        check_range("cols", &cols, ncols)?;
+        Ok(CsrMatrix {
+            storage: self.storage.slice(rows, cols),
+        })
+    }
+
+    /// The transposed matrix, in compressed sparse column format with the same arrays.
+    pub fn transpose(&self) -> CscMatrix<F> {
+        CscMatrix {
+            storage: self.storage.clone(),
+        }
+    }
+
+    /// Convert to coordinate format.
+    pub fn to_coo(&self) -> CooMatrix<F> {
+        let (row, col, data) = self.storage.triplets();
+        CooMatrix::new(self.shape(), row, col, data).unwrap()
+    }
+
+    /// Convert to compressed sparse columns.
+    pub fn to_csc(&self) -> CscMatrix<F> {
+        CscMatrix {
+            storage: self.storage.swap_axes(),
+        }
+    }
+
+    /// Convert to a dense array.
+    pub fn to_dense(&self) -> Array2<F> {
+        self.storage.to_dense()
+    }
+}
+
+/// Sparse matrix in compressed sparse column format.
+///
+/// The rows of the entries of column `j` are `indices[indptr[j]..indptr[j + 1]]`, and their
+/// values `data[indptr[j]..indptr[j + 1]]`. The matrices built by Sci-rs have sorted row indices
+/// in each column and no duplicates.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.sparse import csc_array
+/// a = csc_array(([1., 2., 3.], ([0, 2, 2], [0, 0, 1])), shape=(3, 2))
+/// a.indptr, a.indices, a.data
+/// # (array([0, 2, 3]), array([0, 2, 2]), array([1., 2., 3.]))
+/// a.T.toarray()
+/// # array([[1., 0., 2.],
+/// #        [0., 0., 3.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::sparse::CscMatrix;
+///
+/// let a = CscMatrix::from_triplets((3, 2), &[0, 2, 2], &[0, 0, 1], &[1., 2., 3.]).unwrap();
+/// assert_eq!(a.indptr(), &[0, 2, 3]);
+/// assert_eq!(a.indices(), &[0, 2, 2]);
+/// assert_eq!(a.transpose().to_dense(), array![[1., 0., 2.], [0., 0., 3.]]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<F> {
+    storage: Compressed<F>,
+}
+
+impl<F: Num + Copy> CscMatrix<F> {
+    /// Build a matrix from its compressed columns.
+    ///
+    /// ## Parameters
+    /// * `shape`: The shape `(rows, columns)` of the matrix.
+    /// * `indptr`: The offsets of the columns in `indices` and `data`, of length `columns + 1`.
+    /// * `indices`: The row of each entry.
+    /// * `data`: The value of each entry.
+    ///
+    /// ## Errors
+    /// If `indptr` is not non-decreasing from 0 to the number of entries, or a row is out of
+    /// `shape`.
+    pub fn new(
+        shape: (usize, usize),
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: Vec<F>,
+    ) -> Result<Self> {
+        Ok(CscMatrix {
+            storage: Compressed::new((shape.1, shape.0), indptr, indices, data)?,
+        })
+    }
+
+    /// Build a matrix from the triplets `(row[k], col[k], data[k])` of its entries, summing
+    /// duplicates.
+    ///
+    /// ## Errors
+    /// If the lengths of `row`, `col` and `data` differ, or an index is out of `shape`.
+    pub fn from_triplets(
+        shape: (usize, usize),
+        row: &[usize],
+        col: &[usize],
+        data: &[F],
+    ) -> Result<Self> {
+        check_triplets(shape, row, col, data)?;
+        Ok(CscMatrix {
+            storage: Compressed::from_triplets((shape.1, shape.0), col, row, data),
+        })
+    }
+
+    /// Build a matrix from the nonzero elements of a dense array.
+    pub fn from_dense<S: Data<Elem = F>>(dense: &ArrayBase<S, Ix2>) -> Self {
+        CscMatrix {
+            storage: Compressed::from_dense(&dense.t()),
+        }
+    }
+
+    /// The shape `(rows, columns)` of the matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.storage.minor, self.storage.major)
+    }
+
+    /// The number of stored entries, including explicit zeros.
+    pub fn nnz(&self) -> usize {
+        self.storage.nnz()
+    }
+
+    /// The offsets of the columns in [indices](CscMatrix::indices) and [data](CscMatrix::data).
+    pub fn indptr(&self) -> &[usize] {
+        &self.storage.indptr
+    }
+
+    /// The rows of the entries.
+    pub fn indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+
+    /// The values of the entries.
+    pub fn data(&self) -> &[F] {
+        &self.storage.data
+    }
+
+    /// The element at `(row, col)`.
+    ///
+    /// ## Panics
+    /// If the index is out of the shape of the matrix.
+    pub fn get(&self, row: usize, col: usize) -> F {
+        self.storage.get(col, row)
+    }
+
+    /// The submatrix of the given rows and columns.
+    ///
+    /// ## Errors
+    /// If a range is out of the shape of the matrix.
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Self> {
+        let (nrows, ncols) = self.shape();
+        check_range("rows", &rows, nrows)?;
+        check_range("cols", &cols, ncols)?;
+        Ok(CscMatrix {
+            storage: self.storage.slice(cols, rows),
+        })
+    }
+
+    /// The transposed matrix, in compressed sparse row format with the same arrays.
+    pub fn transpose(&self) -> CsrMatrix<F> {
+        CsrMatrix {
+            storage: self.storage.clone(),
+        }
+    }
+
+    /// Convert to coordinate format.
+    pub fn to_coo(&self) -> CooMatrix<F> {
+        let (col, row, data) = self.storage.triplets();
+        CooMatrix::new(self.shape(), row, col, data).unwrap()
+    }
+
+    /// Convert to compressed sparse rows.
+    pub fn to_csr(&self) -> CsrMatrix<F> {
+        CsrMatrix {
+            storage: self.storage.swap_axes(),
+        }
+    }
+
+    /// Convert to a dense array.
+    pub fn to_dense(&self) -> Array2<F> {
+        self.storage.to_dense().reversed_axes()
+    }
+}
+
+fn check_range(arg: &str, range: &Range<usize>, len: usize) -> Result<()> {
+    if range.start > range.end || range.end > len {
+        return Err(Error::InvalidArg {
+            arg: arg.into(),
+            reason: format!("Slice {:?} is out of bounds for length {}.", range, len),
+        });
+    }
+    Ok(())
+}
+
+fn check_triplets<F>(
+    shape: (usize, usize),
+    row: &[usize],
+    col: &[usize],
+    data: &[F],
+) -> Result<()> {
+    if row.len() != data.len() || col.len() != data.len() {
+        return Err(Error::InvalidArg {
+            arg: "data".into(),
+            reason: "Expected row, column and data arrays of the same length.".into(),
+        });
+    }
+    if row.iter().any(|&i| i >= shape.0) {
+        return Err(Error::InvalidArg {
+            arg: "row".into(),
+            reason: "Row index exceeds matrix dimensions.".into(),
+        });
+    }
+    if col.iter().any(|&j| j >= shape.1) {
+        return Err(Error::InvalidArg {
+            arg: "col".into(),
+            reason: "Index exceeds matrix dimensions.".into(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+    use ndarray::{array, s};
+
+    fn random_sparse(rng: &mut Rng, shape: (usize, usize)) -> Array2<f64> {
+        Array2::from_shape_simple_fn(shape, || {
+            if rng.random::<f64>() < 0.3 {
+                rng.integers(1, 10) as f64
+            } else {
+                0.
+            }
+        })
+    }
+
+    #[test]
+    fn conversions() {
+        let mut rng = Rng::new(3);
+        for shape in [(5, 7), (8, 3), (1, 1), (0, 4), (4, 0)] {
+            let dense = random_sparse(&mut rng, shape);
+            let csr = CsrMatrix::from_dense(&dense);
+            let csc = CscMatrix::from_dense(&dense);
+            assert_eq!(csr.shape(), shape);
+            assert_eq!(csc.shape(), shape);
+            assert_eq!(csr.to_dense(), dense);
+            assert_eq!(csc.to_dense(), dense);
+            assert_eq!(csr.to_csc(), csc);
+            assert_eq!(csc.to_csr(), csr);
+            assert_eq!(csr.to_coo().to_csr(), csr);
+            assert_eq!(csc.to_coo().to_csc(), csc);
+            assert_eq!(csr.transpose().to_dense(), dense.t());
+            assert_eq!(csc.transpose().to_dense(), dense.t());
+            for ((i, j), &x) in dense.indexed_iter() {
+                assert_eq!(csr.get(i, j), x);
+                assert_eq!(csc.get(i, j), x);
+            }
+        }
+
+        // Unsorted indices with duplicates, from the raw arrays.
+        let csr =
+            CsrMatrix::new((2, 3), vec![0, 3, 4], vec![2, 0, 2, 1], vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(csr.get(0, 2), 4);
+        assert_eq!(csr.to_dense(), array![[2, 0, 4], [0, 4, 0]]);
+        let csc = csr.to_csc();
+        assert_eq!(csc.indptr(), &[0, 1, 2, 4]);
+        assert_eq!(csc.to_dense(), csr.to_dense());
+        let canonical = csr.to_coo().to_csr();
+        assert_eq!(canonical.indices(), &[0, 2, 1]);
+        assert_eq!(canonical.data(), &[2, 4, 4]);
+
+        assert!(CsrMatrix::new((2, 3), vec![0, 3], vec![0, 1, 2], vec![1., 2., 3.]).is_err());
+        assert!(CsrMatrix::new((2, 3), vec![1, 3, 3], vec![0, 1, 2], vec![1., 2., 3.]).is_err());
+        assert!(CsrMatrix::new((2, 3), vec![0, 2, 1], vec![0], vec![1.]).is_err());
+        assert!(CsrMatrix::new((2, 3), vec![0, 1, 2], vec![0, 3], vec![1., 2.]).is_err());
+        assert!(CscMatrix::new((2, 3), vec![0, 1, 2, 2], vec![0, 2], vec![1., 2.]).is_err());
+        assert!(CscMatrix::from_triplets((2, 3), &[0, 2], &[0, 0], &[1., 2.]).is_err());
+        assert!(CsrMatrix::from_triplets((2, 3), &[0, 1], &[0, 3], &[1., 2.]).is_err());
+    }
+
+    #[test]
+    fn slicing() {
+        let mut rng = Rng::new(5);
+        let dense = random_sparse(&mut rng, (9, 6));
+        let (csr, csc) = (CsrMatrix::from_dense(&dense), CscMatrix::from_dense(&dense));
+        for (rows, cols) in [(2..7, 1..4), (0..9, 0..6), (3..3, 0..6), (8..9, 5..6)] {
+            let expected = dense.slice(s![rows.clone(), cols.clone()]);
+            let sub = csr.slice(rows.clone(), cols.clone()).unwrap();
+            assert_eq!(sub.shape(), expected.dim());
+            assert_eq!(sub.to_dense(), expected);
+            assert_eq!(csc.slice(rows, cols).unwrap().to_dense(), expected);
+        }
+        assert!(csr.slice(0..10, 0..6).is_err());
+        assert!(csc.slice(0..9, 2..7).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+use super::{CscMatrix, CsrMatrix};
+use alloc::{format, vec::Vec};
+use ndarray::{Array2, ArrayBase, Data, Ix2};
+use num_traits::Num;
+use sci_rs_core::{Error, Result};
+
+/// Sparse matrix in coordinate format.
+///
+/// The matrix stores the row, the column and the value of each of its entries, in any order.
+/// Duplicate entries are summed when converting to other formats.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.sparse import coo_array
+/// a = coo_array(([1., 2., 3., 4.], ([0, 0, 1, 0], [0, 2, 1, 0])), shape=(2, 3))
+/// a.toarray()
+/// # array([[5., 0., 2.],
+/// #        [0., 3., 0.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::sparse::CooMatrix;
+///
+/// let a = CooMatrix::new((2, 3), vec![0, 0, 1, 0], vec![0, 2, 1, 0], vec![1., 2., 3., 4.]).unwrap();
+/// assert_eq!(a.to_dense(), array![[5., 0., 2.], [0., 3., 0.]]);
+/// let csr = a.to_csr();
+/// assert_eq!(csr.nnz(), 3);
+/// assert_eq!(csr.get(0, 0), 5.);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<F> {
+    shape: (usize, usize),
+    row: Vec<usize>,
+    col: Vec<usize>,
+    data: Vec<F>,
+}
+
+impl<F: Num + Copy> CooMatrix<F> {
+    /// Build a matrix from the triplets `(row[k], col[k], data[k])` of its entries.
+    ///
+    /// ## Errors
+    /// If the lengths of `row`, `col` and `data` differ, or an index is out of `shape`.
+    pub fn new(
+        shape: (usize, usize),
+        row: Vec<usize>,
+        col: Vec<usize>,
+        data: Vec<F>,
+    ) -> Result<Self> {
+        if row.len() != data.len() || col.len() != data.len() {
+            return Err(Error::InvalidArg {
+                arg: "data".into(),
+                reason: format!(
+                    "Expected row, column and data arrays of the same length, got {}, {} and {}.",
+                    row.len(),
+                    col.len(),
+                    data.len()
+                ),
+            });
+        }
+        for (arg, indices, bound) in [("row", &row, shape.0), ("col", &col, shape.1)] {
+            if let Some(&index) = indices.iter().find(|&&i| i >= bound) {
+                return Err(Error::InvalidArg {
+                    arg: arg.into(),
+                    reason: format!("Index {} exceeds matrix dimension {}.", index, bound),
+                });
+            }
+        }
+        Ok(CooMatrix {
+            shape,
+            row,
+            col,
+            data,
+        })
+    }
+
+    /// Build a matrix from the nonzero elements of a dense array, in row-major order.
+    pub fn from_dense<S: Data<Elem = F>>(dense: &ArrayBase<S, Ix2>) -> Self {
+        let (mut row, mut col, mut data) = (Vec::new(), Vec::new(), Vec::new());
+        for ((i, j), &x) in dense.indexed_iter() {
+            if x != F::zero() {
+                row.push(i);
+                col.push(j);
+                data.push(x);
+            }
+        }
+        CooMatrix {
+            shape: dense.dim(),
+            row,
+            col,
+            data,
+        }
+    }
+
+    /// The shape `(rows, columns)` of the matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    /// The number of stored entries, including duplicates and explicit zeros.
+    pub fn nnz(&self) -> usize {
+        self.data.len()
+    }
+
+    /// The rows of the entries.
+    pub fn row(&self) -> &[usize] {
+        &self.row
+    }
+
+    /// The columns of the entries.
+    pub fn col(&self) -> &[usize] {
+        &self.col
+    }
+
+    /// The values of the entries.
+    pub fn data(&self) -> &[F] {
+        &self.data
+    }
+
+    /// The transposed matrix.
+    pub fn transpose(&self) -> Self {
+        CooMatrix {
+            shape: (self.shape.1, self.shape.0),
+            row: self.col.clone(),
+            col: self.row.clone(),
+            data: self.data.clone(),
+        }
+    }
+
+    /// Convert to compressed sparse rows, with sorted column indices and duplicates summed.
+    pub fn to_csr(&self) -> CsrMatrix<F> {
+        CsrMatrix::from_triplets(self.shape, &self.row, &self.col, &self.data).unwrap()
+    }
+
+    /// Convert to compressed sparse columns, with sorted row indices and duplicates summed.
+    pub fn to_csc(&self) -> CscMatrix<F> {
+        CscMatrix::from_triplets(self.shape, &self.row, &self.col, &self.data).unwrap()
+    }
+
+    /// Convert to a dense array.
+    pub fn to_dense(&self) -> Array2<F> {
+        let mut dense = Array2::zeros(self.shape);
+        for ((&i, &j), &x) in self.row.iter().zip(&self.col).zip(&self.data) {
+            dense[[i, j]] = dense[[i, j]] + x;
+        }
+        dense
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use ndarray::array;
+
+    #[test]
+    fn triplets() {
+        let dense = array![[0., 1., 0., 2.], [0., 0., 0., 0.], [3., 0., 4., 0.]];
+        let a = CooMatrix::from_dense(&dense);
+        assert_eq!(a.shape(), (3, 4));
+        assert_eq!(a.nnz(), 4);
+        assert_eq!(a.row(), &[0, 0, 2, 2]);
+        assert_eq!(a.col(), &[1, 3, 0, 2]);
+        assert_eq!(a.data(), &[1., 2., 3., 4.]);
+        assert_eq!(a.to_dense(), dense);
+        assert_eq!(a.transpose().to_dense(), dense.t());
+        assert_eq!(a.to_csr().to_dense(), dense);
+        assert_eq!(a.to_csc().to_dense(), dense);
+
+        // Duplicates are summed, and explicit zeros are kept until conversion.
+        let b =
+            CooMatrix::new((2, 2), vec![1, 0, 1, 1], vec![0, 1, 0, 1], vec![1, 0, 2, 5]).unwrap();
+        assert_eq!(b.nnz(), 4);
+        assert_eq!(b.to_dense(), array![[0, 0], [3, 5]]);
+        let csr = b.to_csr();
+        assert_eq!(csr.indptr(), &[0, 1, 3]);
+        assert_eq!(csr.indices(), &[1, 0, 1]);
+        assert_eq!(csr.data(), &[0, 3, 5]);
+
+        assert!(CooMatrix::new((2, 2), vec![0, 2], vec![0, 0], vec![1., 2.]).is_err());
+        assert!(CooMatrix::new((2, 2), vec![0, 1], vec![0, 2], vec![1., 2.]).is_err());
+        assert!(CooMatrix::new((2, 2), vec![0], vec![0, 1], vec![1., 2.]).is_err());
+    }
+}
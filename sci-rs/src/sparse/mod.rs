@@ -0,0 +1,15 @@
+//! Sparse matrices, from the
+//! [`scipy.sparse`](https://docs.scipy.org/doc/scipy/reference/sparse.html) module.
+//!
+//! * [CooMatrix]: coordinate format, the `(row, col, value)` triplets of the entries. Simple to
+//!   build, and converted to one of the compressed formats for computation.
+//! * [CsrMatrix]: compressed sparse rows, for fast access to rows.
+//! * [CscMatrix]: compressed sparse columns, for fast access to columns.
+//!
+//! Duplicate entries are allowed and stand for their sum, as in Scipy.
+
+mod compressed;
+mod coo;
+
+pub use compressed::*;
+pub use coo::*;
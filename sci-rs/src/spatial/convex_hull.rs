@@ -0,0 +1,504 @@
+use crate::linalg::{cross, dot};
+use alloc::{collections::BTreeSet, vec, vec::Vec};
+use ndarray::{Array1, Array2, ArrayBase, Data, Ix2};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Convex hull of points in 2 or 3 dimensions.
+///
+/// The hull is computed by the quickhull algorithm: starting from a simplex of extreme points,
+/// the point farthest outside a facet is added to the hull, replacing the facets it sees by new
+/// ones joining it to their horizon, until no point is left outside. Points within a small
+/// multiple of the machine precision of a facet are considered on it, and are not vertices.
+///
+/// ## References
+/// [1] Barber, C.B., Dobkin, D.P. and Huhdanpaa, H., 1996. The quickhull algorithm for convex
+///     hulls. ACM Transactions on Mathematical Software, 22(4), pp. 469-483.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.spatial import ConvexHull
+/// points = np.array([[0., 0.], [2., 0.], [1., 1.], [2., 2.], [0., 2.], [0.5, 1.5]])
+/// hull = ConvexHull(points)
+/// hull.vertices, hull.area, hull.volume
+/// # (array([0, 1, 3, 4], dtype=int32), 8.0, 4.0)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::spatial::ConvexHull;
+///
+/// let points = array![[0., 0.], [2., 0.], [1., 1.], [2., 2.], [0., 2.], [0.5, 1.5]];
+/// let hull = ConvexHull::new(&points).unwrap();
+/// assert_eq!(hull.vertices, array![0, 1, 3, 4]);
+/// assert_eq!((hull.area, hull.volume), (8., 4.));
+/// // The points satisfy the plane equations of all the facets.
+/// for p in points.rows() {
+///     for e in hull.equations.rows() {
+///         assert!(e[0] * p[0] + e[1] * p[1] + e[2] <= 0.);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexHull<F> {
+    /// The input points, of shape `(n, ndim)`.
+    pub points: Array2<F>,
+    /// Indices of the points forming the vertices of the hull, in counterclockwise order in 2-D
+    /// and in ascending order in 3-D.
+    pub vertices: Array1<usize>,
+    /// Indices of the points forming the facets of the hull, of shape `(nfacet, ndim)`: the edges
+    /// in 2-D, counterclockwise, and the triangles in 3-D, counterclockwise seen from outside.
+    pub simplices: Array2<usize>,
+    /// The hyperplanes of the facets, of shape `(nfacet, ndim + 1)`: the outward unit normal and
+    /// the offset, with `normal . x + offset <= 0` for the points of the hull.
+    pub equations: Array2<F>,
+    /// Perimeter in 2-D, or surface area in 3-D.
+    pub area: F,
+    /// Area in 2-D, or volume in 3-D.
+    pub volume: F,
+}
+
+impl<F: Float> ConvexHull<F> {
+    /// Compute the convex hull of some points.
+    ///
+    /// ## Parameters
+    /// * `points`: Coordinates of the points, of shape `(n, ndim)` with `ndim` 2 or 3.
+    ///
+    /// ## Errors
+    /// If the points are not in 2 or 3 dimensions, are not finite, or are fewer than `ndim + 1`
+    /// or all on a line (2-D) or a plane (3-D).
+    pub fn new<S: Data<Elem = F>>(points: &ArrayBase<S, Ix2>) -> Result<Self> {
+        let (n, ndim) = points.dim();
+        if ndim != 2 && ndim != 3 {
+            return Err(Error::InvalidArg {
+                arg: "points".into(),
+                reason: "Only points in 2 or 3 dimensions are supported.".into(),
+            });
+        }
+        if points.iter().any(|x| !x.is_finite()) {
+            return Err(Error::InvalidArg {
+                arg: "points".into(),
+                reason: "The points must be finite.".into(),
+            });
+        }
+        if n < ndim + 1 {
+            return Err(Error::InvalidArg {
+                arg: "points".into(),
+                reason: "Not enough points to construct an initial simplex.".into(),
+            });
+        }
+        let scale = points.fold(F::min_positive_value(), |m, x| m.max(x.abs()));
+        let eps = F::from(10 * ndim).unwrap() * F::epsilon() * scale;
+        let hull = if ndim == 2 {
+            let p: Vec<[F; 2]> = points.rows().into_iter().map(|r| [r[0], r[1]]).collect();
+            hull_2d(&p, eps)?
+        } else {
+            let p: Vec<[F; 3]> = points
+                .rows()
+                .into_iter()
+                .map(|r| [r[0], r[1], r[2]])
+                .collect();
+            hull_3d(&p, eps)?
+        };
+        let (vertices, simplices, equations, area, volume) = hull;
+        Ok(ConvexHull {
+            points: points.to_owned(),
+            vertices,
+            simplices,
+            equations,
+            area,
+            volume,
+        })
+    }
+}
+
+type Hull<F> = (Array1<usize>, Array2<usize>, Array2<F>, F, F);
+
+fn sub<F: Float, const N: usize>(a: [F; N], b: [F; N]) -> [F; N] {
+    core::array::from_fn(|i| a[i] - b[i])
+}
+
+/// Quickhull in the plane, the chains of vertices to the right of lines between vertices.
+fn hull_2d<F: Float>(p: &[[F; 2]], eps: F) -> Result<Hull<F>> {
+    let key = |i: &usize| (p[*i][0], p[*i][1]);
+    let cmp = |a: &usize, b: &usize| key(a).partial_cmp(&key(b)).unwrap();
+    let all: Vec<usize> = (0..p.len()).collect();
+    let left = *all.iter().min_by(|a, b| cmp(a, b)).unwrap();
+    let right = *all.iter().max_by(|a, b| cmp(a, b)).unwrap();
+
+    /// Signed distance of `c` to the right of the line from `a` to `b`.
+    fn right_of<F: Float>(a: [F; 2], b: [F; 2], c: [F; 2]) -> F {
+        let (ab, ac) = (sub(b, a), sub(c, a));
+        (ab[1] * ac[0] - ab[0] * ac[1]) / dot(&ab, &ab).sqrt()
+    }
+
+    /// Append the vertices strictly between `a` and `b` of the hull of `candidates`, to the
+    /// right of the line from `a` to `b`.
+    fn chain<F: Float>(
+        p: &[[F; 2]],
+        a: usize,
+        b: usize,
+        candidates: &[usize],
+        eps: F,
+    ) -> Vec<usize> {
+        let outside: Vec<(usize, F)> = candidates
+            .iter()
+            .map(|&i| (i, right_of(p[a], p[b], p[i])))
+            .filter(|&(_, d)| d > eps)
+            .collect();
+        let Some(&(far, _)) = outside.iter().max_by(|x, y| x.1.partial_cmp(&y.1).unwrap()) else {
+            return Vec::new();
+        };
+        let outside: Vec<usize> = outside.iter().map(|&(i, _)| i).collect();
+        let mut vertices = chain(p, a, far, &outside, eps);
+        vertices.push(far);
+        vertices.extend(chain(p, far, b, &outside, eps));
+        vertices
+    }
+
+    let lower = chain(p, left, right, &all, eps);
+    let upper = chain(p, right, left, &all, eps);
+    if lower.is_empty() && upper.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "points".into(),
+            reason: "The points are collinear, the initial simplex is flat.".into(),
+        });
+    }
+    let mut vertices = vec![left];
+    vertices.extend(lower);
+    vertices.push(right);
+    vertices.extend(upper);
+    // Drop the vertices in the middle of edges, chosen when the farthest points are tied.
+    while let Some(k) = (0..vertices.len()).find(|&k| {
+        let m = vertices.len();
+        let (a, b) = (vertices[(k + m - 1) % m], vertices[(k + 1) % m]);
+        right_of(p[a], p[b], p[vertices[k]]).abs() <= eps
+    }) {
+        vertices.remove(k);
+    }
+
+    let m = vertices.len();
+    let mut simplices = Array2::zeros((m, 2));
+    let mut equations = Array2::zeros((m, 3));
+    let (mut area, mut volume) = (F::zero(), F::zero());
+    for k in 0..m {
+        let (i, j) = (vertices[k], vertices[(k + 1) % m]);
+        let d = sub(p[j], p[i]);
+        let length = dot(&d, &d).sqrt();
+        let normal = [d[1] / length, -d[0] / length];
+        simplices[[k, 0]] = i;
+        simplices[[k, 1]] = j;
+        equations[[k, 0]] = normal[0];
+        equations[[k, 1]] = normal[1];
+        equations[[k, 2]] = -dot(&normal, &p[i]);
+        area = area + length;
+        // Shoelace formula, relative to the first vertex.
+        let (a, b) = (sub(p[i], p[left]), sub(p[j], p[left]));
+        volume = volume + (a[0] * b[1] - a[1] * b[0]) / F::from(2).unwrap();
+    }
+    Ok((
+        Array1::from_vec(vertices),
+        simplices,
+        equations,
+        area,
+        volume,
+    ))
+}
+
+/// Triangular facet of the hull in space, with its outward unit normal and the points above it.
+struct Facet<F> {
+    vertices: [usize; 3],
+    normal: [F; 3],
+    offset: F,
+    outside: Vec<usize>,
+    alive: bool,
+}
+
+impl<F: Float> Facet<F> {
+    fn new(p: &[[F; 3]], vertices: [usize; 3]) -> Self {
+        let [a, b, c] = vertices.map(|i| p[i]);
+        let normal = cross(sub(b, a), sub(c, a));
+        let length = dot(&normal, &normal).sqrt();
+        let normal = normal.map(|x| x / length);
+        Facet {
+            vertices,
+            normal,
+            offset: -dot(&normal, &a),
+            outside: Vec::new(),
+            alive: true,
+        }
+    }
+
+    fn distance(&self, x: [F; 3]) -> F {
+        dot(&self.normal, &x) + self.offset
+    }
+
+    fn area(&self, p: &[[F; 3]]) -> F {
+        let [a, b, c] = self.vertices.map(|i| p[i]);
+        let n = cross(sub(b, a), sub(c, a));
+        dot(&n, &n).sqrt() / F::from(2).unwrap()
+    }
+}
+
+/// Quickhull in space of the points `candidates`, returning the facets and an interior point.
+fn quickhull_3d<F: Float>(
+    p: &[[F; 3]],
+    candidates: &[usize],
+    eps: F,
+) -> Result<(Vec<Facet<F>>, [F; 3])> {
+    let flat = || {
+        Err(Error::InvalidArg {
+            arg: "points".into(),
+            reason: "The points are coplanar, the initial simplex is flat.".into(),
+        })
+    };
+    let farthest = |f: &dyn Fn(usize) -> F| {
+        candidates
+            .iter()
+            .map(|&i| (i, f(i)))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .unwrap()
+    };
+
+    // Initial tetrahedron: the extremes along the widest axis, the point farthest from their
+    // line, and the point farthest from the plane of the three.
+    let extremes = (0..3).map(|d| {
+        let low = farthest(&|i| -p[i][d]).0;
+        let high = farthest(&|i| p[i][d]).0;
+        (p[high][d] - p[low][d], low, high)
+    });
+    let (extent, i0, i1) = extremes
+        .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+        .unwrap();
+    if extent <= eps {
+        return flat();
+    }
+    let axis = sub(p[i1], p[i0]);
+    let (i2, d2) = farthest(&|i| {
+        let c = cross(axis, sub(p[i], p[i0]));
+        dot(&c, &c).sqrt() / dot(&axis, &axis).sqrt()
+    });
+    if d2 <= eps {
+        return flat();
+    }
+    let base = Facet::new(p, [i0, i1, i2]);
+    let (i3, d3) = farthest(&|i| base.distance(p[i]).abs());
+    if d3 <= eps {
+        return flat();
+    }
+    let simplex = [i0, i1, i2, i3];
+    let center = (0..3).map(|d| simplex.iter().fold(F::zero(), |acc, &i| acc + p[i][d]));
+    let center: Vec<F> = center.map(|x| x / F::from(4).unwrap()).collect();
+    let center = [center[0], center[1], center[2]];
+
+    let mut facets: Vec<Facet<F>> = [[i0, i1, i2], [i0, i1, i3], [i0, i2, i3], [i1, i2, i3]]
+        .into_iter()
+        .map(|[a, b, c]| {
+            let facet = Facet::new(p, [a, b, c]);
+            if facet.distance(center) > F::zero() {
+                Facet::new(p, [a, c, b])
+            } else {
+                facet
+            }
+        })
+        .collect();
+    for &i in candidates.iter().filter(|i| !simplex.contains(i)) {
+        if let Some(facet) = facets.iter_mut().find(|f| f.distance(p[i]) > eps) {
+            facet.outside.push(i);
+        }
+    }
+
+    while let Some(f) = facets.iter().position(|f| f.alive && !f.outside.is_empty()) {
+        let eye = *facets[f]
+            .outside
+            .iter()
+            .max_by(|&&a, &&b| {
+                let (da, db) = (facets[f].distance(p[a]), facets[f].distance(p[b]));
+                da.partial_cmp(&db).unwrap()
+            })
+            .unwrap();
+        let visible: Vec<usize> = (0..facets.len())
+            .filter(|&g| facets[g].alive && (g == f || facets[g].distance(p[eye]) > eps))
+            .collect();
+        let mut edges = BTreeSet::new();
+        let mut orphans = Vec::new();
+        for &g in &visible {
+            let [a, b, c] = facets[g].vertices;
+            edges.extend([(a, b), (b, c), (c, a)]);
+            orphans.append(&mut facets[g].outside);
+            facets[g].alive = false;
+        }
+        // The horizon, the edges between a visible facet and a hidden one, keeps its orientation
+        // in the new facets.
+        let first = facets.len();
+        for &(a, b) in &edges {
+            if !edges.contains(&(b, a)) {
+                facets.push(Facet::new(p, [a, b, eye]));
+            }
+        }
+        for i in orphans.into_iter().filter(|&i| i != eye) {
+            if let Some(facet) = facets[first..].iter_mut().find(|f| f.distance(p[i]) > eps) {
+                facet.outside.push(i);
+            }
+        }
+    }
+
+    facets.retain(|f| f.alive);
+    Ok((facets, center))
+}
+
+/// Whether the hull vertex `v` is a corner of the polytope, rather than on the inside of one of
+/// its faces or edges: the planes of the facets through it must not all share a line.
+fn is_corner<F: Float>(p: &[[F; 3]], facets: &[Facet<F>], v: usize, eps: F) -> bool {
+    let normals: Vec<[F; 3]> = facets
+        .iter()
+        .filter(|f| f.distance(p[v]).abs() <= eps)
+        .map(|f| f.normal)
+        .collect();
+    let Some(&first) = normals.first() else {
+        return false;
+    };
+    let norm = |x: [F; 3]| dot(&x, &x).sqrt();
+    let line = normals
+        .iter()
+        .map(|&n| cross(first, n))
+        .max_by(|a, b| norm(*a).partial_cmp(&norm(*b)).unwrap())
+        .unwrap();
+    let tolerance = F::epsilon().sqrt();
+    norm(line) > tolerance && normals.iter().any(|&n| dot(&line, &n).abs() > tolerance)
+}
+
+/// Quickhull in space. The points added to the hull before the corners around them are dropped
+/// by a second pass over the corners only.
+fn hull_3d<F: Float>(p: &[[F; 3]], eps: F) -> Result<Hull<F>> {
+    let all: Vec<usize> = (0..p.len()).collect();
+    let (mut facets, mut center) = quickhull_3d(p, &all, eps)?;
+    let vertices: BTreeSet<usize> = facets.iter().flat_map(|f| f.vertices).collect();
+    let corners: Vec<usize> = vertices
+        .iter()
+        .copied()
+        .filter(|&v| is_corner(p, &facets, v, eps))
+        .collect();
+    if corners.len() < vertices.len() {
+        (facets, center) = quickhull_3d(p, &corners, eps)?;
+    }
+
+    let mut simplices = Array2::zeros((facets.len(), 3));
+    let mut equations = Array2::zeros((facets.len(), 4));
+    let (mut area, mut volume) = (F::zero(), F::zero());
+    let mut vertices = BTreeSet::new();
+    for (k, facet) in facets.iter().enumerate() {
+        for d in 0..3 {
+            simplices[[k, d]] = facet.vertices[d];
+            equations[[k, d]] = facet.normal[d];
+        }
+        equations[[k, 3]] = facet.offset;
+        vertices.extend(facet.vertices);
+        let a = facet.area(p);
+        area = area + a;
+        // Pyramid from the interior point.
+        volume = volume - a * facet.distance(center) / F::from(3).unwrap();
+    }
+    let vertices = Array1::from_iter(vertices);
+    Ok((vertices, simplices, equations, area, volume))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    /// Every point is inside every facet, and every vertex is on one.
+    fn check_equations(hull: &ConvexHull<f64>) {
+        for (i, point) in hull.points.rows().into_iter().enumerate() {
+            let distances: Vec<f64> = hull
+                .equations
+                .rows()
+                .into_iter()
+                .map(|e| {
+                    let n = e.len() - 1;
+                    (0..n).map(|d| e[d] * point[d]).sum::<f64>() + e[n]
+                })
+                .collect();
+            assert!(distances.iter().all(|&d| d < 1e-12));
+            let on = distances.iter().any(|d| d.abs() < 1e-12);
+            assert!(!hull.vertices.iter().any(|&v| v == i) || on);
+        }
+    }
+
+    #[test]
+    fn planar() {
+        let mut rng = Rng::new(5);
+        // The vertices of a regular hexagon, and points in the unit disc inside it.
+        let points = Array2::from_shape_fn((206, 2), |(i, d)| {
+            let (r, angle) = if i < 6 {
+                (2., core::f64::consts::FRAC_PI_3 * i as f64)
+            } else {
+                (rng.random::<f64>().sqrt(), 7. * rng.random::<f64>())
+            };
+            r * [angle.cos(), angle.sin()][d]
+        });
+        let hull = ConvexHull::new(&points).unwrap();
+        assert_eq!(hull.vertices, array![3, 4, 5, 0, 1, 2]);
+        assert_eq!(hull.simplices.row(0), array![3, 4]);
+        assert_abs_diff_eq!(hull.area, 12., epsilon = 1e-13);
+        assert_abs_diff_eq!(hull.volume, 6. * 3f64.sqrt(), epsilon = 1e-13);
+        check_equations(&hull);
+
+        // Points along the edges.
+        let square = array![
+            [0., 0.],
+            [1., 0.],
+            [2., 0.],
+            [2., 2.],
+            [1., 2.],
+            [0., 2.],
+            [0., 1.]
+        ];
+        let hull = ConvexHull::new(&square).unwrap();
+        assert_eq!(hull.vertices, array![0, 2, 3, 5]);
+        assert_eq!((hull.area, hull.volume), (8., 4.));
+
+        let collinear = array![[0., 0.], [1., 1.], [2., 2.], [3., 3.]];
+        assert!(ConvexHull::new(&collinear).is_err());
+        assert!(ConvexHull::new(&array![[0., 0.], [1., 0.]]).is_err());
+        assert!(ConvexHull::new(&array![[0.], [1.], [2.]]).is_err());
+    }
+
+    #[test]
+    fn spatial() {
+        // The unit cube with points inside and on its faces.
+        let mut points = Array2::from_shape_fn((8, 3), |(i, d)| ((i >> d) & 1) as f64);
+        let mut rng = Rng::new(9);
+        let inside = Array2::from_shape_simple_fn((50, 3), || rng.random::<f64>());
+        let on_face = array![[0.5, 0.5, 1.], [0., 0.3, 0.6], [1., 1., 0.5]];
+        points.append(ndarray::Axis(0), inside.view()).unwrap();
+        points.append(ndarray::Axis(0), on_face.view()).unwrap();
+        let hull = ConvexHull::new(&points).unwrap();
+        assert_eq!(hull.vertices, Array1::from_iter(0..8));
+        assert_eq!(hull.simplices.nrows(), 12);
+        assert_abs_diff_eq!(hull.area, 6., epsilon = 1e-14);
+        assert_abs_diff_eq!(hull.volume, 1., epsilon = 1e-14);
+        check_equations(&hull);
+
+        // Points on a sphere are all vertices, of a triangulation with 2n - 4 facets.
+        let sphere = Array2::from_shape_fn((200, 3), |(i, d)| {
+            let z = 1. - (2 * i + 1) as f64 / 200.;
+            let angle = i as f64 * 2.399963229728653;
+            let r = (1. - z * z).sqrt();
+            [r * angle.cos(), r * angle.sin(), z][d]
+        });
+        let hull = ConvexHull::new(&sphere).unwrap();
+        assert_eq!(hull.vertices.len(), 200);
+        assert_eq!(hull.simplices.nrows(), 396);
+        assert!(hull.volume < 4. / 3. * core::f64::consts::PI && hull.volume > 4.);
+        check_equations(&hull);
+
+        let coplanar = array![[0., 0., 1.], [1., 0., 1.], [0., 1., 1.], [1., 1., 1.]];
+        assert!(ConvexHull::new(&coplanar).is_err());
+    }
+}
@@ -0,0 +1,453 @@
+//! Distance computations, from the
+//! [`scipy.spatial.distance`](https://docs.scipy.org/doc/scipy/reference/spatial.distance.html)
+//! module.
+
+use crate::linalg::inv;
+use alloc::{format, vec::Vec};
+use ndarray::{
+    concatenate, Array1, Array2, ArrayBase, ArrayD, ArrayView1, ArrayView2, Axis, Data, Dimension,
+    Ix2,
+};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Distance between two vectors `u` and `v` computed by [pdist] and [cdist].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum DistanceMetric<F> {
+    /// `sqrt(sum((u - v)^2))`
+    #[default]
+    Euclidean,
+    /// `sum(|u - v|)`, the Manhattan distance.
+    Cityblock,
+    /// `max(|u - v|)`
+    Chebyshev,
+    /// `sum(|u - v|^p)^(1 / p)`, for `p > 0` including infinity.
+    Minkowski(F),
+    /// `1 - u.v / (|u| |v|)`
+    Cosine,
+    /// The cosine distance of the vectors centered on their means.
+    Correlation,
+    /// The proportion of the components which differ.
+    Hamming,
+    /// The proportion of the components which differ, among those where either vector is nonzero,
+    /// or 0 if both vectors are zero.
+    Jaccard,
+    /// `sqrt((u - v)^T VI (u - v))`, with the inverse of the covariance matrix `VI`. By default
+    /// the inverse of the sample covariance of all the vectors.
+    Mahalanobis(Option<Array2<F>>),
+}
+
+impl<F: Float> DistanceMetric<F> {
+    /// Check the parameters, and compute the default inverse covariance of the observations.
+    fn resolve(&self, observations: ArrayView2<F>) -> Result<Option<Array2<F>>> {
+        let m = observations.ncols();
+        match self {
+            DistanceMetric::Minkowski(p) if p.is_nan() || *p <= F::zero() => {
+                Err(Error::InvalidArg {
+                    arg: "metric".into(),
+                    reason: "p must be greater than 0.".into(),
+                })
+            }
+            DistanceMetric::Mahalanobis(Some(vi)) if vi.dim() != (m, m) => Err(Error::InvalidArg {
+                arg: "metric".into(),
+                reason: format!("VI must have shape ({}, {}).", m, m),
+            }),
+            DistanceMetric::Mahalanobis(Some(vi)) => Ok(Some(vi.clone())),
+            DistanceMetric::Mahalanobis(None) => {
+                let n = observations.nrows();
+                if n <= m {
+                    return Err(Error::InvalidArg {
+                        arg: "metric".into(),
+                        reason: format!(
+                            "The number of observations ({}) is too small; the covariance matrix \
+                             is singular. For observations with {} dimensions, at least {} \
+                             observations are required.",
+                            n,
+                            m,
+                            m + 1
+                        ),
+                    });
+                }
+                let count = F::from(n).unwrap();
+                let mean: Vec<F> = observations
+                    .columns()
+                    .into_iter()
+                    .map(|column| column.fold(F::zero(), |acc, &x| acc + x) / count)
+                    .collect();
+                let mut cov = Array2::zeros((m, m));
+                for row in observations.rows() {
+                    for ((i, j), c) in cov.indexed_iter_mut() {
+                        *c = *c + (row[i] - mean[i]) * (row[j] - mean[j]);
+                    }
+                }
+                let ddof = count - F::one();
+                cov.mapv_inplace(|c| c / ddof);
+                inv(&cov).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Distance between `u` and `v`, with the inverse covariance from [Self::resolve].
+    fn eval(&self, vi: Option<&Array2<F>>, u: ArrayView1<F>, v: ArrayView1<F>) -> F {
+        let diffs = || u.iter().zip(&v).map(|(&u, &v)| u - v);
+        let len = F::from(u.len()).unwrap();
+        match self {
+            DistanceMetric::Euclidean => diffs().fold(F::zero(), |acc, d| acc + d * d).sqrt(),
+            DistanceMetric::Cityblock => diffs().fold(F::zero(), |acc, d| acc + d.abs()),
+            DistanceMetric::Chebyshev => diffs().fold(F::zero(), |acc, d| acc.max(d.abs())),
+            DistanceMetric::Minkowski(p) if p.is_infinite() => {
+                DistanceMetric::Chebyshev.eval(vi, u, v)
+            }
+            DistanceMetric::Minkowski(p) => diffs()
+                .fold(F::zero(), |acc, d| acc + d.abs().powf(*p))
+                .powf(p.recip()),
+            DistanceMetric::Cosine => cosine(u.iter().copied(), v.iter().copied()),
+            DistanceMetric::Correlation => {
+                let (mu, mv) = (u.sum() / len, v.sum() / len);
+                cosine(u.iter().map(|&u| u - mu), v.iter().map(|&v| v - mv))
+            }
+            DistanceMetric::Hamming => {
+                F::from(u.iter().zip(&v).filter(|(u, v)| u != v).count()).unwrap() / len
+            }
+            DistanceMetric::Jaccard => {
+                let nonzero = u
+                    .iter()
+                    .zip(&v)
+                    .filter(|(u, v)| !u.is_zero() || !v.is_zero());
+                let (differ, total) = nonzero.fold((0, 0), |(differ, total), (u, v)| {
+                    (differ + usize::from(u != v), total + 1)
+                });
+                if total == 0 {
+                    F::zero()
+                } else {
+                    F::from(differ).unwrap() / F::from(total).unwrap()
+                }
+            }
+            DistanceMetric::Mahalanobis(_) => {
+                let vi = vi.expect("the inverse covariance is resolved");
+                let d: Vec<F> = diffs().collect();
+                let form = vi
+                    .indexed_iter()
+                    .fold(F::zero(), |acc, ((i, j), &w)| acc + d[i] * w * d[j]);
+                form.max(F::zero()).sqrt()
+            }
+        }
+    }
+}
+
+/// `1 - u.v / (|u| |v|)`.
+fn cosine<F: Float>(u: impl Iterator<Item = F>, v: impl Iterator<Item = F>) -> F {
+    let (uv, uu, vv) = u
+        .zip(v)
+        .fold((F::zero(), F::zero(), F::zero()), |(uv, uu, vv), (u, v)| {
+            (uv + u * v, uu + u * u, vv + v * v)
+        });
+    F::one() - uv / (uu * vv).sqrt()
+}
+
+/// Pairwise distances between observations in n-dimensional space.
+///
+/// ## Parameters
+/// * `x`: The `m` observations of dimension `n`, of shape `(m, n)`.
+/// * `metric`: The distance metric, [DistanceMetric::Euclidean] by default.
+///
+/// ## Returns
+/// The condensed distance matrix, of length `m (m - 1) / 2`: the distances `dist(x[i], x[j])`
+/// for `i < j`, in the order of `i` then `j`. [squareform] converts it to the square matrix.
+///
+/// ## Errors
+/// If the parameter of the metric is invalid, or the inverse covariance of the Mahalanobis
+/// distance cannot be computed.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.spatial.distance import pdist
+/// x = np.array([[0., 0.], [3., 4.], [6., 0.]])
+/// pdist(x)
+/// # array([5., 6., 5.])
+/// pdist(x, 'cityblock')
+/// # array([7., 6., 7.])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::spatial::distance::{pdist, DistanceMetric};
+///
+/// let x = array![[0., 0.], [3., 4.], [6., 0.]];
+/// assert_eq!(pdist(&x, None).unwrap(), array![5., 6., 5.]);
+/// let cityblock = pdist(&x, Some(DistanceMetric::Cityblock)).unwrap();
+/// assert_eq!(cityblock, array![7., 6., 7.]);
+/// ```
+pub fn pdist<F, S>(x: &ArrayBase<S, Ix2>, metric: Option<DistanceMetric<F>>) -> Result<Array1<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let metric = metric.unwrap_or_default();
+    let vi = metric.resolve(x.view())?;
+    let m = x.nrows();
+    let mut out = Vec::with_capacity(m * m.saturating_sub(1) / 2);
+    for i in 0..m {
+        for j in i + 1..m {
+            out.push(metric.eval(vi.as_ref(), x.row(i), x.row(j)));
+        }
+    }
+    Ok(Array1::from_vec(out))
+}
+
+/// Distance between each pair of the two collections of inputs.
+///
+/// ## Parameters
+/// * `xa`: The `ma` observations of dimension `n`, of shape `(ma, n)`.
+/// * `xb`: The `mb` observations of dimension `n`, of shape `(mb, n)`.
+/// * `metric`: The distance metric, [DistanceMetric::Euclidean] by default. The default inverse
+///   covariance of the Mahalanobis distance is that of the observations of both collections.
+///
+/// ## Returns
+/// The distances `dist(xa[i], xb[j])`, of shape `(ma, mb)`.
+///
+/// ## Errors
+/// If the observations do not have the same dimension, the parameter of the metric is invalid, or
+/// the inverse covariance of the Mahalanobis distance cannot be computed.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.spatial.distance import cdist
+/// cdist([[0., 0.], [1., 1.]], [[1., 0.], [0., 2.], [2., 2.]], 'chebyshev')
+/// # array([[1., 2., 2.],
+/// #        [1., 1., 1.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::spatial::distance::{cdist, DistanceMetric};
+///
+/// let xa = array![[0., 0.], [1., 1.]];
+/// let xb = array![[1., 0.], [0., 2.], [2., 2.]];
+/// let d = cdist(&xa, &xb, Some(DistanceMetric::Chebyshev)).unwrap();
+/// assert_eq!(d, array![[1., 2., 2.], [1., 1., 1.]]);
+/// ```
+pub fn cdist<F, S, T>(
+    xa: &ArrayBase<S, Ix2>,
+    xb: &ArrayBase<T, Ix2>,
+    metric: Option<DistanceMetric<F>>,
+) -> Result<Array2<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+{
+    if xa.ncols() != xb.ncols() {
+        return Err(Error::InvalidArg {
+            arg: "xb".into(),
+            reason: "xa and xb must have the same number of columns (i.e. feature dimension.)"
+                .into(),
+        });
+    }
+    let metric = metric.unwrap_or_default();
+    let vi = match metric {
+        DistanceMetric::Mahalanobis(None) => {
+            let both = concatenate(Axis(0), &[xa.view(), xb.view()]).unwrap();
+            metric.resolve(both.view())?
+        }
+        _ => metric.resolve(xa.view())?,
+    };
+    Ok(Array2::from_shape_fn((xa.nrows(), xb.nrows()), |(i, j)| {
+        metric.eval(vi.as_ref(), xa.row(i), xb.row(j))
+    }))
+}
+
+/// Convert a vector-form distance vector to a square-form distance matrix, and vice-versa.
+///
+/// ## Parameters
+/// * `x`: A condensed distance matrix as returned by [pdist], or a square, symmetric distance
+///   matrix with zeros on the diagonal.
+///
+/// ## Returns
+/// The square distance matrix of a condensed one, or the condensed distance matrix of a square
+/// one.
+///
+/// ## Errors
+/// If `x` is neither 1-D nor 2-D, a condensed matrix does not have a triangular number of
+/// elements, or a square matrix is not symmetric with a zero diagonal.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.spatial.distance import squareform
+/// squareform(np.array([1., 2., 3.]))
+/// # array([[0., 1., 2.],
+/// #        [1., 0., 3.],
+/// #        [2., 3., 0.]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::spatial::distance::squareform;
+///
+/// let square = squareform(&array![1., 2., 3.]).unwrap();
+/// assert_eq!(square, array![[0., 1., 2.], [1., 0., 3.], [2., 3., 0.]].into_dyn());
+/// assert_eq!(squareform(&square).unwrap(), array![1., 2., 3.].into_dyn());
+/// ```
+pub fn squareform<F, S, D>(x: &ArrayBase<S, D>) -> Result<ArrayD<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let x = x.view().into_dyn();
+    match x.ndim() {
+        1 => {
+            let k = x.len();
+            // The n with n (n - 1) / 2 = k.
+            let n = ((1. + 8. * k as f64).sqrt() as usize).div_ceil(2);
+            if n * (n - 1) / 2 != k {
+                return Err(Error::InvalidArg { arg: "x".into(), reason: "Incompatible vector size. It must be a binomial coefficient n choose 2 for \
+                     some integer n >= 2.".into() });
+            }
+            let mut square = Array2::zeros((n, n));
+            let mut values = x.iter();
+            for i in 0..n {
+                for j in i + 1..n {
+                    let &value = values.next().expect("there are n (n - 1) / 2 values");
+                    square[[i, j]] = value;
+                    square[[j, i]] = value;
+                }
+            }
+            Ok(square.into_dyn())
+        }
+        2 => {
+            let (n, m) = (x.shape()[0], x.shape()[1]);
+            if n != m {
+                return Err(Error::InvalidArg {
+                    arg: "x".into(),
+                    reason: "The matrix argument must be square.".into(),
+                });
+            }
+            let mut condensed = Vec::with_capacity(n * n.saturating_sub(1) / 2);
+            for i in 0..n {
+                if !x[[i, i]].is_zero() {
+                    return Err(Error::InvalidArg {
+                        arg: "x".into(),
+                        reason: "Distance matrix must have a zero diagonal.".into(),
+                    });
+                }
+                for j in i + 1..n {
+                    if x[[i, j]] != x[[j, i]] {
+                        return Err(Error::InvalidArg {
+                            arg: "x".into(),
+                            reason: "Distance matrix must be symmetric.".into(),
+                        });
+                    }
+                    condensed.push(x[[i, j]]);
+                }
+            }
+            Ok(Array1::from_vec(condensed).into_dyn())
+        }
+        _ => Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "The first argument must be one or two dimensional array.".into(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn metrics() {
+        let x = array![[1., 0., 2., 0.], [0., 0., 3., 1.], [2., 1., 2., 0.]];
+        let cases = [
+            (
+                DistanceMetric::Euclidean,
+                [3f64.sqrt(), 2f64.sqrt(), 7f64.sqrt()],
+            ),
+            (DistanceMetric::Cityblock, [3., 2., 5.]),
+            (DistanceMetric::Chebyshev, [1., 1., 2.]),
+            (
+                DistanceMetric::Minkowski(3.),
+                [3f64.cbrt(), 2f64.cbrt(), 11f64.cbrt()],
+            ),
+            (DistanceMetric::Minkowski(f64::INFINITY), [1., 1., 2.]),
+            (
+                DistanceMetric::Cosine,
+                [
+                    1. - 6. / (5f64 * 10.).sqrt(),
+                    1. - 6. / (5f64 * 9.).sqrt(),
+                    1. - 6. / (10f64 * 9.).sqrt(),
+                ],
+            ),
+            (DistanceMetric::Hamming, [0.75, 0.5, 1.]),
+            (DistanceMetric::Jaccard, [1., 2. / 3., 1.]),
+        ];
+        for (metric, expected) in cases {
+            let d = pdist(&x, Some(metric)).unwrap();
+            for (d, e) in d.iter().zip(expected) {
+                assert_abs_diff_eq!(*d, e, epsilon = 1e-15);
+            }
+        }
+        // Pearson correlations of (1, 0, 2, 0) with (0, 0, 3, 1), and of (1, 2) with (2, 4).
+        let r = 3. / (2.75f64 * 6.).sqrt();
+        let d = pdist(&x, Some(DistanceMetric::Correlation)).unwrap();
+        assert_abs_diff_eq!(d[0], 1. - r, epsilon = 1e-15);
+        let d = pdist(
+            &array![[1., 2.], [2., 4.]],
+            Some(DistanceMetric::Correlation),
+        )
+        .unwrap();
+        assert_abs_diff_eq!(d[0], 0., epsilon = 1e-15);
+        let zeros = Array2::<f64>::zeros((2, 3));
+        assert_eq!(pdist(&zeros, Some(DistanceMetric::Jaccard)).unwrap()[0], 0.);
+        assert!(pdist(&x, Some(DistanceMetric::Minkowski(0.))).is_err());
+    }
+
+    #[test]
+    fn mahalanobis() {
+        let xa = array![[0., 1.], [2., 0.], [1., 3.]];
+        let xb = array![[4., 1.], [1., 1.]];
+        let identity = Some(DistanceMetric::Mahalanobis(Some(Array2::eye(2))));
+        let euclidean = cdist(&xa, &xb, None).unwrap();
+        assert_eq!(cdist(&xa, &xb, identity).unwrap(), euclidean);
+        // Sample covariance of the five points [[2.3, -0.4], [-0.4, 1.2]], and its inverse.
+        let vi = array![[1.2, 0.4], [0.4, 2.3]] / 2.6;
+        let metric = Some(DistanceMetric::Mahalanobis(Some(vi.clone())));
+        let expected = cdist(&xa, &xb, metric).unwrap();
+        let d = cdist(&xa, &xb, Some(DistanceMetric::Mahalanobis(None))).unwrap();
+        for (d, e) in d.iter().zip(&expected) {
+            assert_abs_diff_eq!(*d, *e, epsilon = 1e-14);
+        }
+        let d = xa.row(0).to_owned() - xb.row(0);
+        assert_abs_diff_eq!(expected[[0, 0]], d.dot(&vi.dot(&d)).sqrt(), epsilon = 1e-15);
+        // Too few observations for the covariance to be invertible.
+        let metric = Some(DistanceMetric::Mahalanobis(None));
+        assert!(pdist(&array![[0., 1.], [2., 0.]], metric).is_err());
+        assert!(cdist(&xa, &array![[1., 2., 3.]], None).is_err());
+    }
+
+    #[test]
+    fn square_and_condensed() {
+        let x = array![[0., 0.], [3., 4.], [6., 0.], [1., 1.]];
+        let condensed = pdist(&x, None).unwrap();
+        let square = squareform(&condensed).unwrap();
+        assert_eq!(square, cdist(&x, &x, None).unwrap().into_dyn());
+        assert_eq!(squareform(&square).unwrap(), condensed.into_dyn());
+        assert_eq!(
+            squareform(&Array1::<f64>::zeros(0)).unwrap(),
+            Array2::<f64>::zeros((1, 1)).into_dyn()
+        );
+        assert!(squareform(&Array1::<f64>::zeros(4)).is_err());
+        assert!(squareform(&array![[0., 1.], [2., 0.]]).is_err());
+        assert!(squareform(&array![[1., 1.], [1., 0.]]).is_err());
+        assert!(squareform(&Array2::<f64>::zeros((2, 3))).is_err());
+    }
+}
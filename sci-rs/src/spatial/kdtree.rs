@@ -0,0 +1,708 @@
+use alloc::{format, vec, vec::Vec};
+use core::cmp::Ordering;
+use ndarray::{Array2, ArrayBase, Data, Ix2};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Minkowski distance of order `p`, handled as its `p`-th power, or as is for `p = inf`, so that
+/// no roots are taken in the comparisons.
+#[derive(Debug, Clone, Copy)]
+struct Minkowski<F> {
+    p: F,
+}
+
+impl<F: Float> Minkowski<F> {
+    fn new(p: Option<F>) -> Result<Self> {
+        let p = p.unwrap_or_else(|| F::from(2).unwrap());
+        if p.is_nan() || p < F::one() {
+            return Err(Error::InvalidArg {
+                arg: "p".into(),
+                reason: "Only p-norms with 1 <= p <= inf are supported.".into(),
+            });
+        }
+        Ok(Minkowski { p })
+    }
+
+    /// The `p`-th power of a distance.
+    fn pow(&self, r: F) -> F {
+        if self.p == F::one() || self.p.is_infinite() {
+            r
+        } else if self.p == F::from(2).unwrap() {
+            r * r
+        } else {
+            r.powf(self.p)
+        }
+    }
+
+    /// The distance from its `p`-th power.
+    fn root(&self, s: F) -> F {
+        if self.p == F::one() || self.p.is_infinite() {
+            s
+        } else if self.p == F::from(2).unwrap() {
+            s.sqrt()
+        } else {
+            s.powf(self.p.recip())
+        }
+    }
+
+    /// Accumulate the contributions of the differences along every axis.
+    fn sum(&self, diffs: impl Iterator<Item = F>) -> F {
+        diffs.fold(F::zero(), |acc, d| {
+            if self.p.is_infinite() {
+                acc.max(d.abs())
+            } else {
+                acc + self.pow(d.abs())
+            }
+        })
+    }
+
+    fn distance(&self, a: &[F], b: &[F]) -> F {
+        self.sum(a.iter().zip(b).map(|(&a, &b)| a - b))
+    }
+
+    /// Smallest distance between the points of two boxes.
+    fn min_distance(&self, a: (&[F], &[F]), b: (&[F], &[F])) -> F {
+        let diffs = (0..a.0.len()).map(|i| (a.0[i] - b.1[i]).max(b.0[i] - a.1[i]).max(F::zero()));
+        self.sum(diffs)
+    }
+
+    /// Largest distance between the points of two boxes.
+    fn max_distance(&self, a: (&[F], &[F]), b: (&[F], &[F])) -> F {
+        let diffs = (0..a.0.len()).map(|i| (a.1[i] - b.0[i]).max(b.1[i] - a.0[i]));
+        self.sum(diffs)
+    }
+}
+
+/// Node of a [KDTree], over the points `indices[start..end]` of bounding box `[mins, maxes]`.
+#[derive(Debug, Clone)]
+struct Node<F> {
+    start: usize,
+    end: usize,
+    mins: Vec<F>,
+    maxes: Vec<F>,
+    /// The nodes below and above the median along the widest axis of the box.
+    children: Option<(usize, usize)>,
+}
+
+impl<F> Node<F> {
+    fn bounds(&self) -> (&[F], &[F]) {
+        (&self.mins, &self.maxes)
+    }
+
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+/// kd-tree for quick nearest-neighbor lookup.
+///
+/// The points are split recursively at the median along the widest axis of the bounding box of
+/// the node, down to leaves of at most `leafsize` points, and every node keeps the bounding box of
+/// its points. The queries measure distances with the Minkowski `p`-norm,
+/// `sum(|x[i] - y[i]|^p)^(1 / p)` for `1 <= p < inf` and `max(|x[i] - y[i]|)` for `p = inf`,
+/// 2 by default, and prune the nodes by the distances to their boxes.
+///
+/// ## References
+/// [1] Maneewongvatana, S. and Mount, D.M., 1999. Analysis of approximate nearest neighbor
+///     searching with clustered point sets. arXiv:cs/9901013.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.spatial import cKDTree
+/// x, y = np.mgrid[0:5, 2:8]
+/// tree = cKDTree(np.c_[x.ravel(), y.ravel()])
+/// tree.query([[0, 0], [2.2, 2.9]], k=2)
+/// # (array([[2.        , 2.23606798],
+/// #         [0.2236068 , 0.80622577]]),
+/// #  array([[ 0,  6],
+/// #         [13, 19]]))
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{array, Array2};
+/// use sci_rs::spatial::KDTree;
+///
+/// let points = Array2::from_shape_fn((30, 2), |(i, j)| [i / 6, i % 6 + 2][j] as f64);
+/// let tree = KDTree::new(&points, None).unwrap();
+/// let (d, i) = tree.query(&array![[0., 0.], [2.2, 2.9]], Some(2), None, None, None).unwrap();
+/// assert_eq!(i, array![[0, 6], [13, 19]]);
+/// assert_abs_diff_eq!(d[[0, 1]], 5f64.sqrt(), epsilon = 1e-15);
+/// assert_abs_diff_eq!(d[[1, 0]], 0.05f64.sqrt(), epsilon = 1e-15);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KDTree<F> {
+    data: Array2<F>,
+    /// Permutation of the points, contiguous over every node.
+    indices: Vec<usize>,
+    /// The nodes, the root first.
+    nodes: Vec<Node<F>>,
+    leafsize: usize,
+}
+
+impl<F: Float> KDTree<F> {
+    /// Build the kd-tree of some points.
+    ///
+    /// ## Parameters
+    /// * `data`: The `n` data points of dimension `m` to be indexed, of shape `(n, m)`.
+    /// * `leafsize`: The number of points at which the algorithm switches over to brute force.
+    ///   16 by default.
+    ///
+    /// ## Errors
+    /// If `leafsize` is 0 or the points are not finite.
+    pub fn new<S: Data<Elem = F>>(
+        data: &ArrayBase<S, Ix2>,
+        leafsize: Option<usize>,
+    ) -> Result<Self> {
+        let leafsize = leafsize.unwrap_or(16);
+        if leafsize == 0 {
+            return Err(Error::InvalidArg {
+                arg: "leafsize".into(),
+                reason: "leafsize must be at least 1.".into(),
+            });
+        }
+        if data.iter().any(|x| !x.is_finite()) {
+            return Err(Error::InvalidArg {
+                arg: "data".into(),
+                reason: "data must be finite, check for nan or inf values.".into(),
+            });
+        }
+        let mut tree = KDTree {
+            data: data.as_standard_layout().into_owned(),
+            indices: (0..data.nrows()).collect(),
+            nodes: Vec::new(),
+            leafsize,
+        };
+        tree.build(0, data.nrows());
+        Ok(tree)
+    }
+
+    /// The data points, of shape `(n, m)`.
+    pub fn data(&self) -> &Array2<F> {
+        &self.data
+    }
+
+    fn point(&self, i: usize) -> &[F] {
+        self.data
+            .row(i)
+            .to_slice()
+            .expect("the data are in standard layout")
+    }
+
+    /// Add the node over `indices[start..end]` and its descendants, returning its index.
+    fn build(&mut self, start: usize, end: usize) -> usize {
+        let m = self.data.ncols();
+        let (mut mins, mut maxes) = (vec![F::infinity(); m], vec![F::neg_infinity(); m]);
+        for &i in &self.indices[start..end] {
+            for (dim, &x) in self.data.row(i).iter().enumerate() {
+                mins[dim] = mins[dim].min(x);
+                maxes[dim] = maxes[dim].max(x);
+            }
+        }
+        let widest = (0..m).max_by(|&a, &b| {
+            (maxes[a] - mins[a])
+                .partial_cmp(&(maxes[b] - mins[b]))
+                .unwrap_or(Ordering::Equal)
+        });
+        let id = self.nodes.len();
+        self.nodes.push(Node {
+            start,
+            end,
+            mins,
+            maxes,
+            children: None,
+        });
+        let node = &self.nodes[id];
+        match widest {
+            Some(dim) if end - start > self.leafsize && node.maxes[dim] > node.mins[dim] => {
+                let mid = start + (end - start) / 2;
+                let data = &self.data;
+                self.indices[start..end].select_nth_unstable_by(mid - start, |&a, &b| {
+                    data[[a, dim]]
+                        .partial_cmp(&data[[b, dim]])
+                        .unwrap_or(Ordering::Equal)
+                });
+                let less = self.build(start, mid);
+                let greater = self.build(mid, end);
+                self.nodes[id].children = Some((less, greater));
+            }
+            _ => {}
+        }
+        id
+    }
+
+    /// Rows of `x` as points, checking their dimension.
+    fn query_points<S: Data<Elem = F>>(&self, x: &ArrayBase<S, Ix2>) -> Result<Vec<Vec<F>>> {
+        if x.ncols() != self.data.ncols() {
+            return Err(Error::InvalidArg {
+                arg: "x".into(),
+                reason: format!(
+                    "x must consist of vectors of length {} but has shape {:?}.",
+                    self.data.ncols(),
+                    x.shape()
+                ),
+            });
+        }
+        Ok(x.rows().into_iter().map(|row| row.to_vec()).collect())
+    }
+
+    /// Query the kd-tree for nearest neighbors.
+    ///
+    /// ## Parameters
+    /// * `x`: The points to query, of shape `(q, m)`.
+    /// * `k`: The number of nearest neighbors to return. 1 by default.
+    /// * `eps`: Return approximate nearest neighbors: the `k`-th returned value is guaranteed to
+    ///   be no further than `1 + eps` times the distance to the real `k`-th nearest neighbor. 0 by
+    ///   default.
+    /// * `p`: Which Minkowski `p`-norm to use, `1 <= p <= inf`. 2 by default.
+    /// * `distance_upper_bound`: Return only neighbors closer than this distance, which also
+    ///   prunes the search. Infinite by default.
+    ///
+    /// ## Returns
+    /// The distances to the nearest neighbors, and their indices in the data, both of shape
+    /// `(q, k)` and sorted by increasing distance. Missing neighbors are indicated by an infinite
+    /// distance and the index `n`, the number of data points.
+    ///
+    /// ## Errors
+    /// If `k` is 0, `eps` is negative, `p < 1`, or `x` does not have the dimension of the data.
+    pub fn query<S: Data<Elem = F>>(
+        &self,
+        x: &ArrayBase<S, Ix2>,
+        k: Option<usize>,
+        eps: Option<F>,
+        p: Option<F>,
+        distance_upper_bound: Option<F>,
+    ) -> Result<(Array2<F>, Array2<usize>)> {
+        let k = k.unwrap_or(1);
+        if k == 0 {
+            return Err(Error::InvalidArg {
+                arg: "k".into(),
+                reason: "k must be at least 1.".into(),
+            });
+        }
+        let metric = Minkowski::new(p)?;
+        let factor = self.eps_factor(&metric, eps)?;
+        let points = self.query_points(x)?;
+        let bound = metric.pow(distance_upper_bound.unwrap_or_else(F::infinity));
+
+        let mut distances = Array2::from_elem((points.len(), k), F::infinity());
+        let mut indices = Array2::from_elem((points.len(), k), self.data.nrows());
+        let mut best = Vec::with_capacity(k + 1);
+        for (q, x) in points.iter().enumerate() {
+            best.clear();
+            self.nearest(0, x, k, &metric, bound, factor, &mut best);
+            for (j, &(d, i)) in best.iter().enumerate() {
+                distances[[q, j]] = metric.root(d);
+                indices[[q, j]] = i;
+            }
+        }
+        Ok((distances, indices))
+    }
+
+    /// Merge the points of `node` closer to `x` than `bound` into the `k` best, sorted by
+    /// distance, visiting the nearer child first.
+    #[allow(clippy::too_many_arguments)]
+    fn nearest(
+        &self,
+        node: usize,
+        x: &[F],
+        k: usize,
+        metric: &Minkowski<F>,
+        bound: F,
+        factor: F,
+        best: &mut Vec<(F, usize)>,
+    ) {
+        let current = |best: &Vec<(F, usize)>| match best.get(k - 1) {
+            Some(&(d, _)) => d,
+            None => bound,
+        };
+        let node = &self.nodes[node];
+        if metric.min_distance((x, x), node.bounds()) * factor >= current(best) {
+            return;
+        }
+        match node.children {
+            Some((less, greater)) => {
+                let to = |child: usize| metric.min_distance((x, x), self.nodes[child].bounds());
+                let (near, far) = if to(less) <= to(greater) {
+                    (less, greater)
+                } else {
+                    (greater, less)
+                };
+                self.nearest(near, x, k, metric, bound, factor, best);
+                self.nearest(far, x, k, metric, bound, factor, best);
+            }
+            None => {
+                for &i in &self.indices[node.start..node.end] {
+                    let d = metric.distance(x, self.point(i));
+                    if d < current(best) {
+                        let at = best.partition_point(|&(e, _)| e <= d);
+                        best.insert(at, (d, i));
+                        best.truncate(k);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Find all points within distance `r` of the points `x`.
+    ///
+    /// ## Parameters
+    /// * `x`: The points to search for neighbors of, of shape `(q, m)`.
+    /// * `r`: The radius of the balls.
+    /// * `p`: Which Minkowski `p`-norm to use, `1 <= p <= inf`. 2 by default.
+    /// * `eps`: Approximate search. Branches of the tree are not explored if their nearest points
+    ///   are further than `r / (1 + eps)`, and branches are added in bulk if their furthest
+    ///   points are nearer than `r * (1 + eps)`. 0 by default.
+    ///
+    /// ## Returns
+    /// For every point of `x`, the sorted indices of its neighbors in the data.
+    ///
+    /// ## Errors
+    /// If `eps` is negative, `p < 1`, or `x` does not have the dimension of the data.
+    ///
+    /// ## Examples
+    /// ```
+    /// use ndarray::{array, Array2};
+    /// use sci_rs::spatial::KDTree;
+    ///
+    /// let points = Array2::from_shape_fn((25, 2), |(i, j)| [i / 5, i % 5][j] as f64);
+    /// let tree = KDTree::new(&points, None).unwrap();
+    /// let balls = tree.query_ball_point(&array![[2., 2.]], 1., None, None).unwrap();
+    /// assert_eq!(balls[0], [7, 11, 12, 13, 17]);
+    /// ```
+    pub fn query_ball_point<S: Data<Elem = F>>(
+        &self,
+        x: &ArrayBase<S, Ix2>,
+        r: F,
+        p: Option<F>,
+        eps: Option<F>,
+    ) -> Result<Vec<Vec<usize>>> {
+        let metric = Minkowski::new(p)?;
+        let factor = self.eps_factor(&metric, eps)?;
+        let points = self.query_points(x)?;
+        let r = metric.pow(r);
+        Ok(points
+            .iter()
+            .map(|x| {
+                let mut ball = Vec::new();
+                self.ball(0, x, &metric, r, factor, &mut ball);
+                ball.sort_unstable();
+                ball
+            })
+            .collect())
+    }
+
+    /// `(1 + eps)^p`, checking `eps`.
+    fn eps_factor(&self, metric: &Minkowski<F>, eps: Option<F>) -> Result<F> {
+        let eps = eps.unwrap_or_else(F::zero);
+        if eps.is_nan() || eps < F::zero() {
+            return Err(Error::InvalidArg {
+                arg: "eps".into(),
+                reason: "eps must be non-negative.".into(),
+            });
+        }
+        Ok(metric.pow(F::one() + eps))
+    }
+
+    fn ball(
+        &self,
+        node: usize,
+        x: &[F],
+        metric: &Minkowski<F>,
+        r: F,
+        factor: F,
+        out: &mut Vec<usize>,
+    ) {
+        let node = &self.nodes[node];
+        if metric.min_distance((x, x), node.bounds()) > r / factor {
+            return;
+        }
+        let all = &self.indices[node.start..node.end];
+        if metric.max_distance((x, x), node.bounds()) <= r * factor {
+            out.extend_from_slice(all);
+            return;
+        }
+        match node.children {
+            Some((less, greater)) => {
+                self.ball(less, x, metric, r, factor, out);
+                self.ball(greater, x, metric, r, factor, out);
+            }
+            None => out.extend(
+                all.iter()
+                    .filter(|&&i| metric.distance(x, self.point(i)) <= r),
+            ),
+        }
+    }
+
+    /// Find all pairs of points in the kd-tree whose distance is at most `r`.
+    ///
+    /// ## Parameters
+    /// * `r`: The maximum distance.
+    /// * `p`: Which Minkowski `p`-norm to use, `1 <= p <= inf`. 2 by default.
+    /// * `eps`: Approximate search, as in [query_ball_point](KDTree::query_ball_point). 0 by
+    ///   default.
+    ///
+    /// ## Returns
+    /// The sorted pairs of indices `(i, j)` with `i < j` of the close points.
+    ///
+    /// ## Errors
+    /// If `eps` is negative or `p < 1`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use ndarray::array;
+    /// use sci_rs::spatial::KDTree;
+    ///
+    /// let points = array![[0., 0.], [0., 1.], [3., 0.], [1., 1.], [10., 10.]];
+    /// let tree = KDTree::new(&points, None).unwrap();
+    /// let pairs = tree.query_pairs(1., None, None).unwrap();
+    /// assert_eq!(pairs, [(0, 1), (1, 3)]);
+    /// ```
+    pub fn query_pairs(&self, r: F, p: Option<F>, eps: Option<F>) -> Result<Vec<(usize, usize)>> {
+        let metric = Minkowski::new(p)?;
+        let factor = self.eps_factor(&metric, eps)?;
+        let mut pairs = Vec::new();
+        self.pairs(0, 0, &metric, metric.pow(r), factor, &mut pairs);
+        pairs.sort_unstable();
+        Ok(pairs)
+    }
+
+    /// Pairs of close points between two nodes, each pair once if they are the same.
+    fn pairs(
+        &self,
+        a: usize,
+        b: usize,
+        metric: &Minkowski<F>,
+        r: F,
+        factor: F,
+        out: &mut Vec<(usize, usize)>,
+    ) {
+        let (na, nb) = (&self.nodes[a], &self.nodes[b]);
+        if metric.min_distance(na.bounds(), nb.bounds()) > r / factor {
+            return;
+        }
+        let bulk = metric.max_distance(na.bounds(), nb.bounds()) <= r * factor;
+        match (na.children, nb.children) {
+            (Some((less, greater)), _) if a == b && !bulk => {
+                self.pairs(less, less, metric, r, factor, out);
+                self.pairs(less, greater, metric, r, factor, out);
+                self.pairs(greater, greater, metric, r, factor, out);
+            }
+            (Some((less, greater)), children)
+                if !bulk && (children.is_none() || na.len() >= nb.len()) =>
+            {
+                self.pairs(less, b, metric, r, factor, out);
+                self.pairs(greater, b, metric, r, factor, out);
+            }
+            (_, Some((less, greater))) if !bulk => {
+                self.pairs(a, less, metric, r, factor, out);
+                self.pairs(a, greater, metric, r, factor, out);
+            }
+            _ => {
+                let ib = &self.indices[nb.start..nb.end];
+                for (n, &i) in self.indices[na.start..na.end].iter().enumerate() {
+                    // Within a node, only the points after `i`.
+                    let others = if a == b { &ib[n + 1..] } else { ib };
+                    for &j in others {
+                        if bulk || metric.distance(self.point(i), self.point(j)) <= r {
+                            out.push((i.min(j), i.max(j)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Count how many nearby pairs can be formed with the points of another tree.
+    ///
+    /// ## Parameters
+    /// * `other`: The tree to draw the second points of the pairs from. It can be the same tree.
+    /// * `r`: The distances to count the pairs within.
+    /// * `p`: Which Minkowski `p`-norm to use, `1 <= p <= inf`. 2 by default.
+    ///
+    /// ## Returns
+    /// For every radius of `r`, the number of pairs `(x1, x2)` with `x1` from this tree and `x2`
+    /// from `other` at distance at most `r`, cumulative over the radii like the default
+    /// `cumulative=True` of Scipy.
+    ///
+    /// ## Errors
+    /// If `p < 1` or the trees do not have the same dimension.
+    ///
+    /// ## Examples
+    /// ```
+    /// use ndarray::array;
+    /// use sci_rs::spatial::KDTree;
+    ///
+    /// let a = KDTree::new(&array![[0., 0.], [1., 0.]], None).unwrap();
+    /// let b = KDTree::new(&array![[0., 1.], [3., 0.], [0.5, 0.]], None).unwrap();
+    /// let counts = a.count_neighbors(&b, &[0.5, 1., 2.], None).unwrap();
+    /// assert_eq!(counts, [2, 3, 5]);
+    /// ```
+    pub fn count_neighbors(&self, other: &KDTree<F>, r: &[F], p: Option<F>) -> Result<Vec<usize>> {
+        let metric = Minkowski::new(p)?;
+        if other.data.ncols() != self.data.ncols() {
+            return Err(Error::InvalidArg {
+                arg: "other".into(),
+                reason: "The trees must have the same dimension.".into(),
+            });
+        }
+        Ok(r.iter()
+            .map(|&r| self.count(other, 0, 0, &metric, metric.pow(r)))
+            .collect())
+    }
+
+    /// Number of pairs of close points between a node of this tree and one of `other`.
+    fn count(&self, other: &KDTree<F>, a: usize, b: usize, metric: &Minkowski<F>, r: F) -> usize {
+        let (na, nb) = (&self.nodes[a], &other.nodes[b]);
+        if metric.min_distance(na.bounds(), nb.bounds()) > r {
+            return 0;
+        }
+        if metric.max_distance(na.bounds(), nb.bounds()) <= r {
+            return na.len() * nb.len();
+        }
+        match (na.children, nb.children) {
+            (Some((less, greater)), children) if children.is_none() || na.len() >= nb.len() => {
+                self.count(other, less, b, metric, r) + self.count(other, greater, b, metric, r)
+            }
+            (_, Some((less, greater))) => {
+                self.count(other, a, less, metric, r) + self.count(other, a, greater, metric, r)
+            }
+            _ => {
+                let ib = &other.indices[nb.start..nb.end];
+                self.indices[na.start..na.end]
+                    .iter()
+                    .map(|&i| {
+                        ib.iter()
+                            .filter(|&&j| metric.distance(self.point(i), other.point(j)) <= r)
+                            .count()
+                    })
+                    .sum()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+    use ndarray::{array, Array2};
+
+    fn random_points(rng: &mut Rng, n: usize, m: usize) -> Array2<f64> {
+        Array2::from_shape_simple_fn((n, m), || rng.random())
+    }
+
+    fn brute_distances(a: &Array2<f64>, x: &[f64], metric: &Minkowski<f64>) -> Vec<f64> {
+        a.rows()
+            .into_iter()
+            .map(|row| metric.root(metric.distance(row.as_slice().unwrap(), x)))
+            .collect()
+    }
+
+    #[test]
+    fn nearest_neighbors() {
+        let mut rng = Rng::new(7);
+        let data = random_points(&mut rng, 300, 3);
+        let x = random_points(&mut rng, 20, 3);
+        let tree = KDTree::new(&data, Some(5)).unwrap();
+        for p in [1., 2., 3.5, f64::INFINITY] {
+            let metric = Minkowski::new(Some(p)).unwrap();
+            let (d, i) = tree.query(&x, Some(4), None, Some(p), None).unwrap();
+            for (q, row) in x.rows().into_iter().enumerate() {
+                let mut all = brute_distances(&data, row.as_slice().unwrap(), &metric);
+                for j in 0..4 {
+                    assert_eq!(all[i[[q, j]]], d[[q, j]]);
+                }
+                all.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                assert_eq!(d.row(q).to_vec(), all[..4]);
+            }
+        }
+        // The bound leaves missing neighbors.
+        let (d, i) = tree.query(&x, Some(300), None, None, Some(0.2)).unwrap();
+        let metric = Minkowski::new(None).unwrap();
+        for (q, row) in x.rows().into_iter().enumerate() {
+            let all = brute_distances(&data, row.as_slice().unwrap(), &metric);
+            let within = all.iter().filter(|&&d| d < 0.2).count();
+            assert!(d[[q, within - 1]] < 0.2 && d[[q, within]] == f64::INFINITY);
+            assert_eq!(i[[q, within]], 300);
+        }
+        // Approximate neighbors are within the factor of the exact ones.
+        let (exact, _) = tree.query(&x, Some(3), None, None, None).unwrap();
+        let (approx, _) = tree.query(&x, Some(3), Some(0.5), None, None).unwrap();
+        for (e, a) in exact.iter().zip(&approx) {
+            assert!(e <= a && *a <= 1.5 * e);
+        }
+        assert!(tree.query(&x, Some(0), None, None, None).is_err());
+        assert!(tree.query(&x, None, None, Some(0.5), None).is_err());
+        assert!(tree
+            .query(&array![[0., 0.]], None, None, None, None)
+            .is_err());
+        assert!(KDTree::new(&array![[0., f64::NAN]], None).is_err());
+        let empty = KDTree::new(&Array2::<f64>::zeros((0, 3)), None).unwrap();
+        let (d, i) = empty.query(&x, Some(2), None, None, None).unwrap();
+        assert!(d.iter().all(|d| d.is_infinite()) && i.iter().all(|&i| i == 0));
+    }
+
+    #[test]
+    fn balls_and_pairs() {
+        let mut rng = Rng::new(11);
+        let data = random_points(&mut rng, 200, 2);
+        let x = random_points(&mut rng, 10, 2);
+        let tree = KDTree::new(&data, Some(3)).unwrap();
+        for p in [1., 2., f64::INFINITY] {
+            let metric = Minkowski::new(Some(p)).unwrap();
+            let balls = tree.query_ball_point(&x, 0.15, Some(p), None).unwrap();
+            for (row, ball) in x.rows().into_iter().zip(&balls) {
+                let all = brute_distances(&data, row.as_slice().unwrap(), &metric);
+                let expected: Vec<usize> = (0..200).filter(|&i| all[i] <= 0.15).collect();
+                assert_eq!(*ball, expected);
+            }
+            let pairs = tree.query_pairs(0.05, Some(p), None).unwrap();
+            let mut expected = Vec::new();
+            for i in 0..200 {
+                let all = brute_distances(&data, data.row(i).as_slice().unwrap(), &metric);
+                expected.extend((i + 1..200).filter(|&j| all[j] <= 0.05).map(|j| (i, j)));
+            }
+            assert_eq!(pairs, expected);
+        }
+        // Duplicated points are at distance 0.
+        let tree = KDTree::new(&array![[1., 1.], [1., 1.], [1., 1.]], Some(1)).unwrap();
+        assert_eq!(
+            tree.query_pairs(0., None, None).unwrap(),
+            [(0, 1), (0, 2), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn neighbor_counts() {
+        let mut rng = Rng::new(3);
+        let a = random_points(&mut rng, 150, 3);
+        let b = random_points(&mut rng, 80, 3);
+        let (ta, tb) = (
+            KDTree::new(&a, Some(4)).unwrap(),
+            KDTree::new(&b, None).unwrap(),
+        );
+        let r = [0., 0.1, 0.3, 0.6, 2.];
+        for p in [1., 2., f64::INFINITY] {
+            let metric = Minkowski::new(Some(p)).unwrap();
+            let counts = ta.count_neighbors(&tb, &r, Some(p)).unwrap();
+            let distances: Vec<f64> = a
+                .rows()
+                .into_iter()
+                .flat_map(|row| brute_distances(&b, row.as_slice().unwrap(), &metric))
+                .collect();
+            for (&r, &count) in r.iter().zip(&counts) {
+                assert_eq!(count, distances.iter().filter(|&&d| d <= r).count());
+            }
+        }
+        // With itself, every point pairs with itself and the close pairs count twice.
+        let counts = ta.count_neighbors(&ta, &[0.1], None).unwrap();
+        let pairs = ta.query_pairs(0.1, None, None).unwrap();
+        assert_eq!(counts[0], 150 + 2 * pairs.len());
+        assert!(ta
+            .count_neighbors(&KDTree::new(&array![[0.]], None).unwrap(), &r, None)
+            .is_err());
+    }
+}
@@ -0,0 +1,10 @@
+//! Functions from the
+//! [`scipy.spatial`](https://docs.scipy.org/doc/scipy/reference/spatial.html) module.
+
+mod convex_hull;
+pub mod distance;
+mod kdtree;
+pub mod transform;
+
+pub use convex_hull::*;
+pub use kdtree::*;
@@ -0,0 +1,770 @@
+//! Spatial transformations, from the
+//! [`scipy.spatial.transform`](https://docs.scipy.org/doc/scipy/reference/spatial.transform.html)
+//! module.
+
+use crate::linalg::{cross, dot};
+use alloc::{format, vec::Vec};
+use core::ops::Mul;
+use nalgebra::{Matrix3, RealField};
+use ndarray::{Array2, ArrayBase, Data, Ix2};
+use num_traits::{Float, FloatConst};
+use sci_rs_core::{Error, Result};
+
+/// Rows of an array of shape `(n, 3)`.
+fn vectors<F: Float, S: Data<Elem = F>>(arg: &str, x: &ArrayBase<S, Ix2>) -> Result<Vec<[F; 3]>> {
+    if x.ncols() != 3 {
+        return Err(Error::InvalidArg {
+            arg: arg.into(),
+            reason: format!("Expected input of shape (N, 3), got {:?}.", x.shape()),
+        });
+    }
+    Ok(x.rows().into_iter().map(|r| [r[0], r[1], r[2]]).collect())
+}
+
+/// Rotation in 3 dimensions.
+///
+/// The rotation is stored as a unit quaternion `[x, y, z, w]`, in the scalar-last convention of
+/// Scipy. Unlike Scipy, a [Rotation] is a single rotation, and stacks of rotations are slices of
+/// them.
+///
+/// Rotations compose with `*`, `r1 * r2` applying `r2` first, like the composition of their
+/// matrices.
+///
+/// ## References
+/// [1] Markley, F.L., 2008. Unit quaternion from rotation matrix. Journal of Guidance, Control,
+///     and Dynamics, 31(2), pp. 440-442.
+///
+/// [2] Bernardes, E. and Viollet, S., 2022. Quaternion to Euler angles conversion: A direct,
+///     general and computationally efficient method. PLoS ONE, 17(11), e0276302.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.spatial.transform import Rotation as R
+/// r = R.from_euler('z', 90, degrees=True)
+/// r.apply([1., 0., 0.])
+/// # array([0., 1., 0.])
+/// (r * r).as_rotvec()
+/// # array([0.        , 0.        , 3.14159265])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::spatial::transform::Rotation;
+///
+/// let r = Rotation::from_euler("z", &[90.], true).unwrap();
+/// let v = r.apply(&array![[1., 0., 0.]], None).unwrap();
+/// for (v, e) in v.iter().zip([0., 1., 0.]) {
+///     assert_abs_diff_eq!(*v, e, epsilon = 1e-15);
+/// }
+/// let half_turn = (r * r).as_rotvec();
+/// assert_abs_diff_eq!(half_turn[2], core::f64::consts::PI, epsilon = 1e-15);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotation<F> {
+    quat: [F; 4],
+}
+
+impl<F: Float + FloatConst> Rotation<F> {
+    /// The identity rotation.
+    pub fn identity() -> Self {
+        Rotation {
+            quat: [F::zero(), F::zero(), F::zero(), F::one()],
+        }
+    }
+
+    /// Initialize from a quaternion `[x, y, z, w]`, which is normalized.
+    ///
+    /// ## Errors
+    /// If the quaternion has zero norm, or is not finite.
+    pub fn from_quat(quat: [F; 4]) -> Result<Self> {
+        let norm = quat.iter().fold(F::zero(), |acc, &q| acc + q * q).sqrt();
+        if !(norm.is_finite() && norm > F::zero()) {
+            return Err(Error::InvalidArg {
+                arg: "quat".into(),
+                reason: "Found zero norm quaternions in `quat`.".into(),
+            });
+        }
+        Ok(Rotation {
+            quat: quat.map(|q| q / norm),
+        })
+    }
+
+    /// Initialize from a rotation matrix.
+    ///
+    /// ## Notes
+    /// * The matrix need not be exactly orthogonal: the quaternion is computed by the method of
+    ///   Markley, from the largest of the diagonal elements and the trace, and normalized.
+    ///
+    /// ## Errors
+    /// If the matrix is not of shape `(3, 3)`, or it does not give a quaternion.
+    pub fn from_matrix<S: Data<Elem = F>>(matrix: &ArrayBase<S, Ix2>) -> Result<Self> {
+        if matrix.dim() != (3, 3) {
+            return Err(Error::InvalidArg {
+                arg: "matrix".into(),
+                reason: format!(
+                    "Expected `matrix` to have shape (3, 3), got {:?}.",
+                    matrix.shape()
+                ),
+            });
+        }
+        let m = |i: usize, j: usize| matrix[[i, j]];
+        let two = F::from(2).unwrap();
+        let trace = m(0, 0) + m(1, 1) + m(2, 2);
+        let decision = [m(0, 0), m(1, 1), m(2, 2), trace];
+        let choice = (0..4).fold(0, |best, i| {
+            if decision[i] > decision[best] {
+                i
+            } else {
+                best
+            }
+        });
+        let mut quat = [F::zero(); 4];
+        if choice == 3 {
+            quat = [
+                m(2, 1) - m(1, 2),
+                m(0, 2) - m(2, 0),
+                m(1, 0) - m(0, 1),
+                F::one() + trace,
+            ];
+        } else {
+            let (i, j, k) = (choice, (choice + 1) % 3, (choice + 2) % 3);
+            quat[i] = F::one() - trace + two * m(i, i);
+            quat[j] = m(j, i) + m(i, j);
+            quat[k] = m(k, i) + m(i, k);
+            quat[3] = m(k, j) - m(j, k);
+        }
+        Self::from_quat(quat).map_err(|_| Error::InvalidArg {
+            arg: "matrix".into(),
+            reason: "The matrix is not a rotation.".into(),
+        })
+    }
+
+    /// Initialize from a rotation vector, along the axis of rotation with the angle in radians as
+    /// its norm.
+    pub fn from_rotvec(rotvec: [F; 3]) -> Self {
+        let angle = dot(&rotvec, &rotvec).sqrt();
+        let half = F::from(0.5).unwrap();
+        // Taylor series of sin(angle / 2) / angle, without the cancellation near 0.
+        let scale = if angle <= F::from(1e-3).unwrap() {
+            let a2 = angle * angle;
+            half - a2 / F::from(48).unwrap() + a2 * a2 / F::from(3840).unwrap()
+        } else {
+            (half * angle).sin() / angle
+        };
+        Rotation {
+            quat: [
+                rotvec[0] * scale,
+                rotvec[1] * scale,
+                rotvec[2] * scale,
+                (half * angle).cos(),
+            ],
+        }
+    }
+
+    /// Initialize from Euler angles.
+    ///
+    /// ## Parameters
+    /// * `seq`: The axes of the successive rotations, up to three characters among `'x'`, `'y'`
+    ///   and `'z'` for extrinsic rotations about the axes of the fixed frame, or among `'X'`,
+    ///   `'Y'` and `'Z'` for intrinsic rotations about the axes of the rotating frame.
+    ///   Consecutive axes must differ.
+    /// * `angles`: The angle of each rotation, one per axis of `seq`.
+    /// * `degrees`: Whether the angles are in degrees rather than radians.
+    ///
+    /// ## Errors
+    /// If `seq` is invalid or `angles` does not have its length.
+    pub fn from_euler(seq: &str, angles: &[F], degrees: bool) -> Result<Self> {
+        let (axes, extrinsic) = parse_seq(seq, 1)?;
+        if angles.len() != axes.len() {
+            return Err(Error::InvalidArg {
+                arg: "angles".into(),
+                reason: format!("Expected {} angles for the sequence {:?}.", axes.len(), seq),
+            });
+        }
+        let half = F::from(0.5).unwrap();
+        let scale = if degrees {
+            F::PI() / F::from(180).unwrap()
+        } else {
+            F::one()
+        };
+        let elementary = axes.iter().zip(angles).map(|(&axis, &angle)| {
+            let angle = angle * scale * half;
+            let mut quat = [F::zero(), F::zero(), F::zero(), angle.cos()];
+            quat[axis] = angle.sin();
+            Rotation { quat }
+        });
+        Ok(elementary.fold(Self::identity(), |acc, r| {
+            // Intrinsic rotations apply in the rotated frame, on the right.
+            if extrinsic {
+                r * acc
+            } else {
+                acc * r
+            }
+        }))
+    }
+
+    /// The quaternion `[x, y, z, w]` of the rotation, of unit norm.
+    pub fn as_quat(&self) -> [F; 4] {
+        self.quat
+    }
+
+    /// The rotation matrix, of shape `(3, 3)`.
+    pub fn as_matrix(&self) -> Array2<F> {
+        let [x, y, z, w] = self.quat;
+        let two = F::from(2).unwrap();
+        let one = F::one();
+        Array2::from_shape_vec(
+            (3, 3),
+            alloc::vec![
+                one - two * (y * y + z * z),
+                two * (x * y - z * w),
+                two * (x * z + y * w),
+                two * (x * y + z * w),
+                one - two * (x * x + z * z),
+                two * (y * z - x * w),
+                two * (x * z - y * w),
+                two * (y * z + x * w),
+                one - two * (x * x + y * y),
+            ],
+        )
+        .unwrap()
+    }
+
+    /// The rotation vector, along the axis of rotation with the angle in `[0, pi]` as its norm.
+    pub fn as_rotvec(&self) -> [F; 3] {
+        // The quaternions q and -q are the same rotation, of angle at most pi for w >= 0.
+        let [x, y, z, w] = if self.quat[3] < F::zero() {
+            self.quat.map(|q| -q)
+        } else {
+            self.quat
+        };
+        let angle = F::from(2).unwrap() * (x * x + y * y + z * z).sqrt().atan2(w);
+        // Taylor series of angle / sin(angle / 2) near 0.
+        let scale = if angle <= F::from(1e-3).unwrap() {
+            let a2 = angle * angle;
+            F::from(2).unwrap()
+                + a2 / F::from(12).unwrap()
+                + F::from(7).unwrap() * a2 * a2 / F::from(2880).unwrap()
+        } else {
+            angle / (angle / F::from(2).unwrap()).sin()
+        };
+        [x * scale, y * scale, z * scale]
+    }
+
+    /// The Euler angles of the rotation, the inverse of [from_euler](Rotation::from_euler).
+    ///
+    /// ## Parameters
+    /// * `seq`: Three axes, as in [from_euler](Rotation::from_euler).
+    /// * `degrees`: Whether to return the angles in degrees rather than radians.
+    ///
+    /// ## Returns
+    /// The first and third angles in `[-pi, pi]`. The second angle is in `[0, pi]` if the first
+    /// and third axes are the same, and in `[-pi / 2, pi / 2]` otherwise.
+    ///
+    /// ## Notes
+    /// * At the gimbal lock, where the second angle is at a bound of its range and the other two
+    ///   rotate about the same axis, the third angle is set to 0.
+    ///
+    /// ## Errors
+    /// If `seq` is not three valid axes.
+    pub fn as_euler(&self, seq: &str, degrees: bool) -> Result<[F; 3]> {
+        let (mut axes, extrinsic) = parse_seq(seq, 3)?;
+        if axes.len() != 3 {
+            return Err(Error::InvalidArg {
+                arg: "seq".into(),
+                reason: "Expected 3 axes.".into(),
+            });
+        }
+        // The method is for extrinsic rotations, which are the intrinsic ones in reverse.
+        if !extrinsic {
+            axes.reverse();
+        }
+        let (i, j) = (axes[0], axes[1]);
+        let symmetric = i == axes[2];
+        let k = if symmetric { 3 - i - j } else { axes[2] };
+        // The parity of the permutation (i, j, k).
+        let sign = if (i + 1) % 3 == j {
+            F::one()
+        } else {
+            -F::one()
+        };
+        let q = self.quat;
+        // A Tait-Bryan sequence is a proper Euler sequence in a frame rotated about axis j.
+        let (a, b, c, d) = if symmetric {
+            (q[3], q[i], q[j], q[k] * sign)
+        } else {
+            (
+                q[3] - q[j],
+                q[i] + q[k] * sign,
+                q[j] + q[3],
+                q[k] * sign - q[i],
+            )
+        };
+        let two = F::from(2).unwrap();
+        let pi = F::PI();
+        let mut angles = [F::zero(); 3];
+        angles[1] = two * c.hypot(d).atan2(a.hypot(b));
+        let (half_sum, half_diff) = (b.atan2(a), d.atan2(c));
+        // At the gimbal lock, the angle of the last axis of `seq` is set to 0.
+        let free = if extrinsic { 0 } else { 2 };
+        let eps = F::from(1e-7).unwrap();
+        if angles[1].abs() <= eps {
+            angles[free] = two * half_sum;
+        } else if (angles[1] - pi).abs() <= eps {
+            angles[free] = if extrinsic { -two } else { two } * half_diff;
+        } else {
+            angles[0] = half_sum - half_diff;
+            angles[2] = half_sum + half_diff;
+        }
+        if !symmetric {
+            angles[2] = angles[2] * sign;
+            angles[1] = angles[1] - pi / two;
+        }
+        if !extrinsic {
+            angles.swap(0, 2);
+        }
+        let scale = if degrees {
+            F::from(180).unwrap() / pi
+        } else {
+            F::one()
+        };
+        Ok(angles.map(|angle| {
+            let wrapped = if angle < -pi {
+                angle + two * pi
+            } else if angle > pi {
+                angle - two * pi
+            } else {
+                angle
+            };
+            wrapped * scale
+        }))
+    }
+
+    /// The inverse rotation.
+    pub fn inv(&self) -> Self {
+        let [x, y, z, w] = self.quat;
+        Rotation {
+            quat: [-x, -y, -z, w],
+        }
+    }
+
+    /// The angle of the rotation in radians, in `[0, pi]`.
+    pub fn magnitude(&self) -> F {
+        let [x, y, z, w] = self.quat;
+        F::from(2).unwrap() * (x * x + y * y + z * z).sqrt().atan2(w.abs())
+    }
+
+    /// Apply the rotation to some vectors.
+    ///
+    /// ## Parameters
+    /// * `vectors`: The vectors, of shape `(n, 3)`.
+    /// * `inverse`: Whether to apply the inverse rotation instead. False by default.
+    ///
+    /// ## Returns
+    /// The rotated vectors, of shape `(n, 3)`.
+    ///
+    /// ## Errors
+    /// If `vectors` is not of shape `(n, 3)`.
+    pub fn apply<S: Data<Elem = F>>(
+        &self,
+        vectors: &ArrayBase<S, Ix2>,
+        inverse: Option<bool>,
+    ) -> Result<Array2<F>> {
+        let rows = self::vectors("vectors", vectors)?;
+        let rotation = if inverse.unwrap_or(false) {
+            self.inv()
+        } else {
+            *self
+        };
+        let matrix = rotation.as_matrix();
+        Ok(Array2::from_shape_fn((rows.len(), 3), |(n, i)| {
+            (0..3).fold(F::zero(), |acc, j| acc + matrix[[i, j]] * rows[n][j])
+        }))
+    }
+
+    /// Spherical linear interpolation to another rotation, at constant angular velocity about a
+    /// fixed axis.
+    ///
+    /// ## Parameters
+    /// * `other`: The rotation at `t = 1`.
+    /// * `t`: The fraction of the way from this rotation, at `t = 0`, to `other`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use sci_rs::spatial::transform::Rotation;
+    ///
+    /// let a = Rotation::from_euler("x", &[10.], true).unwrap();
+    /// let b = Rotation::from_euler("x", &[70.], true).unwrap();
+    /// let angle = a.slerp(&b, 0.25).as_euler("xyz", true).unwrap()[0];
+    /// assert_abs_diff_eq!(angle, 25., epsilon = 1e-12);
+    /// ```
+    pub fn slerp(&self, other: &Rotation<F>, t: F) -> Self {
+        let delta = (*other * self.inv()).as_rotvec();
+        Self::from_rotvec(delta.map(|x| x * t)) * *self
+    }
+
+    /// Shortest rotation taking the direction of `b` to that of `a`.
+    fn shortest_arc(a: [F; 3], b: [F; 3]) -> Result<Self> {
+        let (na, nb) = (dot(&a, &a).sqrt(), dot(&b, &b).sqrt());
+        if !(na > F::zero() && nb > F::zero()) {
+            return Err(Error::InvalidArg {
+                arg: "a".into(),
+                reason: "Cannot align zero length primary vectors.".into(),
+            });
+        }
+        let (a, b) = (a.map(|x| x / na), b.map(|x| x / nb));
+        let axis = cross(b, a);
+        let (sin, cos) = (dot(&axis, &axis).sqrt(), dot(&a, &b));
+        if sin <= F::epsilon() && cos < F::zero() {
+            // Antiparallel vectors, a half turn about any perpendicular axis.
+            let other = if b[0].abs() < F::from(0.9).unwrap() {
+                [F::one(), F::zero(), F::zero()]
+            } else {
+                [F::zero(), F::one(), F::zero()]
+            };
+            let axis = cross(b, other);
+            let norm = dot(&axis, &axis).sqrt();
+            return Ok(Self::from_rotvec(axis.map(|x| x / norm * F::PI())));
+        }
+        // The quaternion of half the angle between the vectors.
+        Self::from_quat([axis[0], axis[1], axis[2], F::one() + cos])
+    }
+}
+
+impl<F: Float + FloatConst + RealField> Rotation<F> {
+    /// Estimate the rotation that best aligns two sets of vectors.
+    ///
+    /// Find the rotation `C` minimizing `sum(w[i] |a[i] - C b[i]|^2)`, by the Kabsch algorithm:
+    /// the singular value decomposition of `sum(w[i] a[i] b[i]^T)`.
+    ///
+    /// ## Parameters
+    /// * `a`: The vectors in the initial frame, of shape `(n, 3)`.
+    /// * `b`: The vectors in the other frame, of shape `(n, 3)`.
+    /// * `weights`: The non-negative weights of the pairs of vectors, all 1 by default.
+    ///
+    /// ## Returns
+    /// The rotation taking `b` to `a`, and the square root of the weighted sum of the squared
+    /// distances between the aligned vectors.
+    ///
+    /// ## Notes
+    /// * A single pair of vectors gives the shortest rotation aligning their directions.
+    ///
+    /// ## Errors
+    /// If the shapes do not match, or the weights are negative.
+    ///
+    /// ## Examples
+    /// ```custom,{class=language-python}
+    /// import numpy as np
+    /// from scipy.spatial.transform import Rotation as R
+    /// a = np.array([[0., 1., 0.], [-1., 0., 0.]])
+    /// b = np.array([[1., 0., 0.], [0., 1., 0.]])
+    /// rot, rssd = R.align_vectors(a, b)
+    /// rot.as_euler('xyz', degrees=True)
+    /// # array([ 0.,  0., 90.])
+    /// ```
+    ///
+    /// Sci-rs:
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use sci_rs::spatial::transform::Rotation;
+    ///
+    /// let a = array![[0., 1., 0.], [-1., 0., 0.]];
+    /// let b = array![[1., 0., 0.], [0., 1., 0.]];
+    /// let (rot, rssd) = Rotation::align_vectors(&a, &b, None).unwrap();
+    /// let angles = rot.as_euler("xyz", true).unwrap();
+    /// for (angle, e) in angles.iter().zip([0., 0., 90.]) {
+    ///     assert_abs_diff_eq!(*angle, e, epsilon = 1e-12);
+    /// }
+    /// assert_abs_diff_eq!(rssd, 0., epsilon = 1e-12);
+    /// ```
+    pub fn align_vectors<S, T>(
+        a: &ArrayBase<S, Ix2>,
+        b: &ArrayBase<T, Ix2>,
+        weights: Option<&[F]>,
+    ) -> Result<(Self, F)>
+    where
+        S: Data<Elem = F>,
+        T: Data<Elem = F>,
+    {
+        let (a, b) = (vectors("a", a)?, vectors("b", b)?);
+        if a.len() != b.len() || a.is_empty() {
+            return Err(Error::InvalidArg {
+                arg: "b".into(),
+                reason: "Expected inputs `a` and `b` to have the same, nonzero, shape.".into(),
+            });
+        }
+        let weights = match weights {
+            Some(w) if w.len() != a.len() => {
+                return Err(Error::InvalidArg {
+                    arg: "weights".into(),
+                    reason: "Expected one weight per pair of vectors.".into(),
+                })
+            }
+            Some(w) if w.iter().any(|&w| w.is_nan() || w < F::zero()) => {
+                return Err(Error::InvalidArg {
+                    arg: "weights".into(),
+                    reason: "`weights` may not contain negative values.".into(),
+                })
+            }
+            Some(w) => w.to_vec(),
+            None => alloc::vec![F::one(); a.len()],
+        };
+        let rotation = if a.len() == 1 {
+            Self::shortest_arc(a[0], b[0])?
+        } else {
+            let mut h = Matrix3::<F>::zeros();
+            for ((a, b), &w) in a.iter().zip(&b).zip(&weights) {
+                for i in 0..3 {
+                    for j in 0..3 {
+                        h[(i, j)] += w * a[i] * b[j];
+                    }
+                }
+            }
+            let svd = h.svd(true, true);
+            let (u, v_t): (Matrix3<F>, Matrix3<F>) = (svd.u.unwrap(), svd.v_t.unwrap());
+            // A reflection for a negative determinant is turned into the nearest rotation.
+            let mut d = Matrix3::identity();
+            if (u * v_t).determinant() < F::zero() {
+                d[(2, 2)] = -F::one();
+            }
+            let c = u * d * v_t;
+            let matrix = Array2::from_shape_fn((3, 3), |(i, j)| c[(i, j)]);
+            Self::from_matrix(&matrix)?
+        };
+        let matrix = rotation.as_matrix();
+        let sum = a
+            .iter()
+            .zip(&b)
+            .zip(&weights)
+            .fold(F::zero(), |acc, ((a, b), &w)| {
+                let squared = (0..3).fold(F::zero(), |acc, i| {
+                    let cb = (0..3).fold(F::zero(), |acc, j| acc + matrix[[i, j]] * b[j]);
+                    acc + (a[i] - cb) * (a[i] - cb)
+                });
+                acc + w * squared
+            });
+        Ok((rotation, Float::sqrt(sum)))
+    }
+}
+
+/// Composition, `self * rhs` applying `rhs` first.
+impl<F: Float> Mul for Rotation<F> {
+    type Output = Rotation<F>;
+
+    fn mul(self, rhs: Rotation<F>) -> Rotation<F> {
+        let [px, py, pz, pw] = self.quat;
+        let [qx, qy, qz, qw] = rhs.quat;
+        let v = cross([px, py, pz], [qx, qy, qz]);
+        Rotation {
+            quat: [
+                pw * qx + qw * px + v[0],
+                pw * qy + qw * py + v[1],
+                pw * qz + qw * pz + v[2],
+                pw * qw - (px * qx + py * qy + pz * qz),
+            ],
+        }
+    }
+}
+
+/// Indices of the axes of an Euler sequence of `min` to 3 rotations, and whether they are
+/// extrinsic.
+fn parse_seq(seq: &str, min: usize) -> Result<(Vec<usize>, bool)> {
+    let bad = || {
+        Err(Error::InvalidArg {
+            arg: "seq".into(),
+            reason:
+                "Expected axis specification to be a non-empty string of up to 3 characters, all \
+             from 'xyz' or all from 'XYZ', with no two consecutive axes the same."
+                    .into(),
+        })
+    };
+    let extrinsic = seq.chars().all(|c| "xyz".contains(c));
+    let intrinsic = seq.chars().all(|c| "XYZ".contains(c));
+    let axes: Vec<usize> = seq
+        .chars()
+        .map(|c| match c.to_ascii_lowercase() {
+            'x' => 0,
+            'y' => 1,
+            _ => 2,
+        })
+        .collect();
+    if !(extrinsic || intrinsic) || axes.len() < min || axes.len() > 3 {
+        return bad();
+    }
+    if axes.windows(2).any(|w| w[0] == w[1]) {
+        return bad();
+    }
+    Ok((axes, extrinsic))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+    use alloc::{vec, vec::Vec};
+    use approx::assert_abs_diff_eq;
+    use core::f64::consts::{FRAC_PI_2, PI};
+    use ndarray::array;
+
+    fn assert_same(a: Rotation<f64>, b: Rotation<f64>) {
+        for (x, y) in a.as_matrix().iter().zip(&b.as_matrix()) {
+            assert_abs_diff_eq!(*x, *y, epsilon = 1e-12);
+        }
+    }
+
+    fn random_rotation(rng: &mut Rng) -> Rotation<f64> {
+        let q = [(); 4].map(|_| rng.standard_normal::<f64>());
+        Rotation::from_quat(q).unwrap()
+    }
+
+    #[test]
+    fn representations() {
+        let mut rng = Rng::new(2);
+        for _ in 0..20 {
+            let r = random_rotation(&mut rng);
+            assert_same(Rotation::from_matrix(&r.as_matrix()).unwrap(), r);
+            assert_same(Rotation::from_rotvec(r.as_rotvec()), r);
+            assert_abs_diff_eq!(
+                r.magnitude(),
+                r.as_rotvec().map(|x| x * x).iter().sum::<f64>().sqrt(),
+                epsilon = 1e-12
+            );
+            // Orthogonal with determinant 1.
+            let m = r.as_matrix();
+            let product = m.dot(&m.t());
+            for (x, e) in product.iter().zip(&ndarray::Array2::<f64>::eye(3)) {
+                assert_abs_diff_eq!(*x, *e, epsilon = 1e-14);
+            }
+            assert_same(r * r.inv(), Rotation::identity());
+        }
+        // A quarter turn about z, as a matrix and a vector.
+        let r = Rotation::from_rotvec([0., 0., FRAC_PI_2]);
+        let m = array![[0., -1., 0.], [1., 0., 0.], [0., 0., 1.]];
+        for (x, e) in r.as_matrix().iter().zip(&m) {
+            assert_abs_diff_eq!(*x, *e, epsilon = 1e-15);
+        }
+        assert_same(Rotation::from_matrix(&m).unwrap(), r);
+        // Small and half turn rotation vectors.
+        let tiny = Rotation::from_rotvec([1e-9, 0., 0.]);
+        assert_abs_diff_eq!(tiny.as_rotvec()[0], 1e-9, epsilon = 1e-24);
+        let half = Rotation::from_rotvec([0., PI, 0.]);
+        assert_abs_diff_eq!(half.as_rotvec()[1].abs(), PI, epsilon = 1e-15);
+        assert!(Rotation::from_quat([0., 0., 0., 0.]).is_err());
+        assert!(Rotation::from_matrix(&ndarray::Array2::<f64>::eye(2)).is_err());
+    }
+
+    #[test]
+    fn euler_angles() {
+        let mut rng = Rng::new(4);
+        let sequences = [
+            "xyz", "zyx", "xzx", "zxz", "XYZ", "ZYX", "YXY", "ZXZ", "yzx", "XZY",
+        ];
+        for seq in sequences {
+            let symmetric = seq.as_bytes()[0] == seq.as_bytes()[2];
+            for _ in 0..20 {
+                let mut angles = [(); 3].map(|_| rng.uniform(-PI, PI));
+                angles[1] = if symmetric {
+                    rng.uniform(0., PI)
+                } else {
+                    rng.uniform(-FRAC_PI_2, FRAC_PI_2)
+                };
+                let r = Rotation::from_euler(seq, &angles, false).unwrap();
+                let back = r.as_euler(seq, false).unwrap();
+                for (a, b) in angles.iter().zip(back) {
+                    assert_abs_diff_eq!(*a, b, epsilon = 1e-10);
+                }
+            }
+            // At the gimbal lock, the angles give the same rotation.
+            let lock = if symmetric { 0. } else { FRAC_PI_2 };
+            let r = Rotation::from_euler(seq, &[0.3, lock, 0.5], false).unwrap();
+            let back = r.as_euler(seq, false).unwrap();
+            assert_eq!(back[2], 0.);
+            assert_same(Rotation::from_euler(seq, &back, false).unwrap(), r);
+        }
+        // Extrinsic rotations are the intrinsic ones in reverse.
+        let angles = [10., 20., 30.];
+        let extrinsic = Rotation::from_euler("xyz", &angles, true).unwrap();
+        let intrinsic = Rotation::from_euler("ZYX", &[30., 20., 10.], true).unwrap();
+        assert_same(extrinsic, intrinsic);
+        let composed = Rotation::from_euler("z", &[30.], true).unwrap()
+            * Rotation::from_euler("y", &[20.], true).unwrap()
+            * Rotation::from_euler("x", &[10.], true).unwrap();
+        assert_same(extrinsic, composed);
+        assert!(Rotation::<f64>::from_euler("xxy", &angles, true).is_err());
+        assert!(Rotation::<f64>::from_euler("xYz", &angles, true).is_err());
+        assert!(Rotation::<f64>::from_euler("xy", &angles, true).is_err());
+        assert!(extrinsic.as_euler("xy", true).is_err());
+    }
+
+    #[test]
+    fn apply_and_slerp() {
+        let r = Rotation::from_euler("x", &[90.], true).unwrap();
+        let v = array![[0., 1., 0.], [1., 2., 3.]];
+        let rotated = r.apply(&v, None).unwrap();
+        for (x, e) in rotated.iter().zip([0., 0., 1., 1., -3., 2.]) {
+            assert_abs_diff_eq!(*x, e, epsilon = 1e-15);
+        }
+        let back = r.apply(&rotated, Some(true)).unwrap();
+        for (x, e) in back.iter().zip(&v) {
+            assert_abs_diff_eq!(*x, *e, epsilon = 1e-15);
+        }
+        assert!(r.apply(&array![[1., 2.]], None).is_err());
+
+        let mut rng = Rng::new(8);
+        let (a, b) = (random_rotation(&mut rng), random_rotation(&mut rng));
+        assert_same(a.slerp(&b, 0.), a);
+        assert_same(a.slerp(&b, 1.), b);
+        let (first, second) = (a.slerp(&b, 0.3), a.slerp(&b, 0.6));
+        let total = (b * a.inv()).magnitude();
+        assert_abs_diff_eq!((first * a.inv()).magnitude(), 0.3 * total, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            (second * first.inv()).magnitude(),
+            0.3 * total,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn alignment() {
+        let mut rng = Rng::new(6);
+        let r = random_rotation(&mut rng);
+        let b = Array2::from_shape_simple_fn((10, 3), || rng.standard_normal::<f64>());
+        let a = r.apply(&b, None).unwrap();
+        let (estimate, rssd) = Rotation::align_vectors(&a, &b, None).unwrap();
+        assert_same(estimate, r);
+        assert_abs_diff_eq!(rssd, 0., epsilon = 1e-12);
+        // With noise and weights, the residual is that of the estimate.
+        let noisy =
+            &a + &Array2::from_shape_simple_fn((10, 3), || 0.01 * rng.standard_normal::<f64>());
+        let weights: Vec<f64> = (1..=10).map(|w| w as f64).collect();
+        let (estimate, rssd) = Rotation::align_vectors(&noisy, &b, Some(&weights)).unwrap();
+        let residual = &noisy - &estimate.apply(&b, None).unwrap();
+        let expected = residual
+            .rows()
+            .into_iter()
+            .zip(&weights)
+            .map(|(row, w)| w * row.dot(&row))
+            .sum::<f64>()
+            .sqrt();
+        assert_abs_diff_eq!(rssd, expected, epsilon = 1e-12);
+        assert!((estimate * r.inv()).magnitude() < 0.01);
+
+        // A single pair of vectors, including antiparallel ones.
+        let (rot, _) =
+            Rotation::align_vectors(&array![[0., 0., 2.]], &array![[1., 0., 0.]], None).unwrap();
+        assert_abs_diff_eq!(rot.magnitude(), FRAC_PI_2, epsilon = 1e-15);
+        let rotated = rot.apply(&array![[1., 0., 0.]], None).unwrap();
+        assert_abs_diff_eq!(rotated[[0, 2]], 1., epsilon = 1e-15);
+        let (rot, rssd) =
+            Rotation::align_vectors(&array![[-1., 0., 0.]], &array![[1., 0., 0.]], None).unwrap();
+        assert_abs_diff_eq!(rot.magnitude(), PI, epsilon = 1e-15);
+        assert_abs_diff_eq!(rssd, 0., epsilon = 1e-15);
+        assert!(Rotation::align_vectors(&a, &b.slice(ndarray::s![..5, ..]), None).is_err());
+        assert!(Rotation::align_vectors(&a, &b, Some(&[-1.; 10])).is_err());
+    }
+}
@@ -1,9 +1,74 @@
+use super::xsf;
+#[cfg(feature = "alloc")]
+use crate::optimize::brentq;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use ndarray::ArrayD;
 use num_traits::real::Real;
 
-/// All [functions located in the `Faster versions of common Bessel
+/// All [functions located in the `Bessel
+/// functions`](<https://docs.scipy.org/doc/scipy/reference/special.html#bessel-functions>) and
+/// [`Faster versions of common Bessel
 /// functions.`](<https://docs.scipy.org/doc/scipy/reference/special.html#faster-versions-of-common-bessel-functions>)
+/// of real order.
+///
+/// ## Notes
+/// * The functions of arbitrary order follow the method of Temme for `x < 2` and the continued
+///   fractions of Steed above, as in Numerical Recipes, Section 6.7, and Hankel's asymptotic
+///   expansion for `x > 20` large compared to the square of the order.
+/// * Negative arguments give NaN where the result would be complex.
 pub trait Bessel {
+    /// Bessel function of the first kind of order 0.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((1f64.j0() - 0.7651976865579666).abs() < 1e-15);
+    /// ```
+    fn j0(&self) -> Self;
+
+    /// Bessel function of the first kind of order 1.
+    fn j1(&self) -> Self;
+
+    /// Bessel function of the first kind of integer order `n`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((10f64.jn(1) - 0.04347274616886144).abs() < 1e-15);
+    /// // J_-n(x) = (-1)^n J_n(x)
+    /// assert_eq!(10f64.jn(-1), -10f64.jn(1));
+    /// ```
+    fn jn(&self, n: i32) -> Self;
+
+    /// Bessel function of the first kind of real order `v`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((0.1f64.jv(2.5) - 1.680887190033413e-4).abs() < 1e-18);
+    /// ```
+    fn jv(&self, v: f64) -> Self;
+
+    /// Bessel function of the second kind of order 0.
+    fn y0(&self) -> Self;
+
+    /// Bessel function of the second kind of order 1.
+    fn y1(&self) -> Self;
+
+    /// Bessel function of the second kind of integer order `n`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((20f64.yn(3) - 0.1496732627133941).abs() < 1e-15);
+    /// assert_eq!(0f64.yn(3), f64::NEG_INFINITY);
+    /// ```
+    fn yn(&self, n: i32) -> Self;
+
+    /// Bessel function of the second kind of real order `v`.
+    fn yv(&self, v: f64) -> Self;
+
     /// Modified Bessel function of order 0.
     ///
     /// ## Notes
@@ -24,6 +89,84 @@ pub trait Bessel {
     ///   as a supposed wrapper over the Cephes routine. We try to define it over reasonable types in
     ///   the impl.
     fn i0e(&self) -> Self;
+
+    /// Modified Bessel function of the first kind of order 1.
+    fn i1(&self) -> Self;
+
+    /// Modified Bessel function of the first kind of real order `v`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((10f64.iv(1.) / 2670.9883037012546 - 1.).abs() < 1e-14);
+    /// ```
+    fn iv(&self, v: f64) -> Self;
+
+    /// Modified Bessel function of the second kind of order 0.
+    fn k0(&self) -> Self;
+
+    /// Modified Bessel function of the second kind of order 1.
+    fn k1(&self) -> Self;
+
+    /// Modified Bessel function of the second kind of real order `v`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((10f64.kv(2.5) / 2.393132586462789e-5 - 1.).abs() < 1e-14);
+    /// ```
+    fn kv(&self, v: f64) -> Self;
+}
+
+/// Compute the zeros of the integer-order Bessel functions `J_n`.
+///
+/// ## Parameters
+/// * `n`: Order of the Bessel function. `J_-n` has the zeros of `J_n`.
+/// * `nt`: Number of zeros to return.
+///
+/// ## Returns
+/// The first `nt` positive zeros of `J_n`, in ascending order.
+///
+/// ## Notes
+/// * The zeros are bracketed on a grid of unit steps from `x = n`, below the first zero, which
+///   is finer than their spacing of at least 2.4, and refined by [brentq].
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import special
+/// special.jn_zeros(1, 3)
+/// # array([ 3.83170597,  7.01558667, 10.17346814])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::special::{jn_zeros, Bessel};
+///
+/// let zeros = jn_zeros(1, 3);
+/// assert_relative_eq!(zeros[0], 3.8317059702075125, max_relative = 1e-14);
+/// for x in zeros {
+///     assert!(x.j1().abs() < 1e-14);
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn jn_zeros(n: i32, nt: usize) -> Vec<f64> {
+    let v = n.unsigned_abs() as f64;
+    let f = |x: f64| xsf::jv(v, x);
+    let mut zeros = Vec::with_capacity(nt);
+    let (mut a, mut fa) = (v, f(v));
+    while zeros.len() < nt {
+        let b = a + 1.;
+        let fb = f(b);
+        if fb == 0. {
+            zeros.push(b);
+        } else if fa * fb < 0. {
+            let root = brentq(f, a, b, Some(f64::EPSILON), None, None);
+            zeros.push(root.map_or(0.5 * (a + b), |r| r.root));
+        }
+        (a, fa) = (b, fb);
+    }
+    zeros
 }
 
 #[cfg(feature = "std")]
@@ -31,6 +174,38 @@ impl<T> Bessel for Vec<T>
 where
     T: Bessel,
 {
+    fn j0(&self) -> Self {
+        self.iter().map(Bessel::j0).collect()
+    }
+
+    fn j1(&self) -> Self {
+        self.iter().map(Bessel::j1).collect()
+    }
+
+    fn jn(&self, n: i32) -> Self {
+        self.iter().map(|x| x.jn(n)).collect()
+    }
+
+    fn jv(&self, v: f64) -> Self {
+        self.iter().map(|x| x.jv(v)).collect()
+    }
+
+    fn y0(&self) -> Self {
+        self.iter().map(Bessel::y0).collect()
+    }
+
+    fn y1(&self) -> Self {
+        self.iter().map(Bessel::y1).collect()
+    }
+
+    fn yn(&self, n: i32) -> Self {
+        self.iter().map(|x| x.yn(n)).collect()
+    }
+
+    fn yv(&self, v: f64) -> Self {
+        self.iter().map(|x| x.yv(v)).collect()
+    }
+
     fn i0(&self) -> Self {
         self.iter().map(Bessel::i0).collect()
     }
@@ -38,6 +213,26 @@ where
     fn i0e(&self) -> Self {
         self.iter().map(Bessel::i0e).collect()
     }
+
+    fn i1(&self) -> Self {
+        self.iter().map(Bessel::i1).collect()
+    }
+
+    fn iv(&self, v: f64) -> Self {
+        self.iter().map(|x| x.iv(v)).collect()
+    }
+
+    fn k0(&self) -> Self {
+        self.iter().map(Bessel::k0).collect()
+    }
+
+    fn k1(&self) -> Self {
+        self.iter().map(Bessel::k1).collect()
+    }
+
+    fn kv(&self, v: f64) -> Self {
+        self.iter().map(|x| x.kv(v)).collect()
+    }
 }
 
 #[cfg(feature = "std")]
@@ -45,6 +240,38 @@ impl<T> Bessel for ArrayD<T>
 where
     T: Bessel,
 {
+    fn j0(&self) -> Self {
+        self.map(Bessel::j0)
+    }
+
+    fn j1(&self) -> Self {
+        self.map(Bessel::j1)
+    }
+
+    fn jn(&self, n: i32) -> Self {
+        self.map(|x| x.jn(n))
+    }
+
+    fn jv(&self, v: f64) -> Self {
+        self.map(|x| x.jv(v))
+    }
+
+    fn y0(&self) -> Self {
+        self.map(Bessel::y0)
+    }
+
+    fn y1(&self) -> Self {
+        self.map(Bessel::y1)
+    }
+
+    fn yn(&self, n: i32) -> Self {
+        self.map(|x| x.yn(n))
+    }
+
+    fn yv(&self, v: f64) -> Self {
+        self.map(|x| x.yv(v))
+    }
+
     fn i0(&self) -> Self {
         self.map(Bessel::i0)
     }
@@ -52,6 +279,26 @@ where
     fn i0e(&self) -> Self {
         self.map(Bessel::i0e)
     }
+
+    fn i1(&self) -> Self {
+        self.map(Bessel::i1)
+    }
+
+    fn iv(&self, v: f64) -> Self {
+        self.map(|x| x.iv(v))
+    }
+
+    fn k0(&self) -> Self {
+        self.map(Bessel::k0)
+    }
+
+    fn k1(&self) -> Self {
+        self.map(Bessel::k1)
+    }
+
+    fn kv(&self, v: f64) -> Self {
+        self.map(|x| x.kv(v))
+    }
 }
 
 #[cfg(test)]
@@ -59,6 +306,34 @@ mod tests {
     use super::*;
     use approx::assert_relative_eq;
 
+    #[test]
+    fn bessel_zeros() {
+        // From mpmath.besseljzero.
+        let zeros = jn_zeros(0, 3);
+        let expected = [2.404825557695773, 5.520078110286311, 8.653727912911013];
+        for (&z, e) in zeros.iter().zip(expected) {
+            assert_relative_eq!(z, e, max_relative = 1e-15);
+        }
+        assert_relative_eq!(jn_zeros(-2, 2)[1], 8.417244140399864, max_relative = 1e-15);
+        assert_relative_eq!(
+            jn_zeros(10, 50)[49],
+            171.7116629147209,
+            max_relative = 1e-14
+        );
+    }
+
+    #[test]
+    fn bessel_f64() {
+        // From mpmath.
+        assert_relative_eq!(2f64.j1(), 0.5767248077568734, max_relative = 1e-15);
+        assert_relative_eq!(2f64.y0(), 0.5103756726497451, max_relative = 1e-15);
+        assert_relative_eq!(2f64.y1(), -0.10703243154093754, max_relative = 1e-14);
+        assert_relative_eq!(2f64.i1(), 1.590636854637329, max_relative = 1e-14);
+        assert_relative_eq!(2f64.k0(), 0.11389387274953344, max_relative = 1e-15);
+        assert_relative_eq!(2f64.k1(), 0.13986588181652243, max_relative = 1e-15);
+        assert_relative_eq!(1.5f32.yv(0.5), -0.046083166, max_relative = 1e-6);
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn i0_vec_f64() {
@@ -1,5 +1,6 @@
+use super::xsf;
 use nalgebra::min;
-use num_traits::{FromPrimitive, PrimInt};
+use num_traits::{Float, FromPrimitive, PrimInt};
 
 /// Various combinatorics functions for integer types.
 pub trait Combinatoric {
@@ -90,6 +91,44 @@ pub trait Combinatoric {
     ///
     /// [wiki]: https://en.wikipedia.org/wiki/Stirling_numbers_of_the_second_kind
     fn stirling2(self, k: Self) -> Self;
+
+    /// Checked [comb], returning `None` if the result overflows.
+    ///
+    /// This is the `exact=True` path of Scipy, without big integers. The intermediate products
+    /// never exceed the result, so `None` means that the result itself does not fit. For large
+    /// arguments, see the floating point [comb](crate::special::comb()).
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Combinatoric;
+    /// assert_eq!(66_u64.checked_comb(33), Some(7219428434016265740));
+    /// assert_eq!(68_u64.checked_comb(34), None);
+    /// ```
+    ///
+    /// # Notes
+    /// When `n < 0` or `k < 0` or `n < k`, then `Some(0)` is returned.
+    ///
+    /// [comb]: crate::special::Combinatoric::comb
+    fn checked_comb(self, k: Self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Checked [perm], returning `None` if the result overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Combinatoric;
+    /// assert_eq!(12_u16.checked_perm(4), Some(11880));
+    /// assert_eq!(12_u16.checked_perm(5), None);
+    /// ```
+    ///
+    /// # Notes
+    /// When `n < 0` or `k < 0` or `n < k`, then `Some(0)` is returned.
+    ///
+    /// [perm]: crate::special::Combinatoric::perm
+    fn checked_perm(self, k: Self) -> Option<Self>
+    where
+        Self: Sized;
 }
 
 macro_rules! combinatoric_primint_impl {
@@ -115,6 +154,16 @@ macro_rules! combinatoric_primint_impl {
                 primint_stirling2(self, k)
             }
 
+            #[inline(always)]
+            fn checked_comb(self, k: Self) -> Option<Self> {
+                primint_checked_comb(self, k)
+            }
+
+            #[inline(always)]
+            fn checked_perm(self, k: Self) -> Option<Self> {
+                primint_checked_perm(self, k)
+            }
+
         }
     )*)
 }
@@ -182,6 +231,134 @@ where
     k * primint_stirling2(n - Int::one(), k) + primint_stirling2(n - Int::one(), k - Int::one())
 }
 
+fn gcd<Int: PrimInt>(mut a: Int, mut b: Int) -> Int {
+    while !b.is_zero() {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn primint_checked_comb<Int>(n: Int, k: Int) -> Option<Int>
+where
+    Int: PrimInt + FromPrimitive,
+{
+    if k > n || n < Int::zero() || k < Int::zero() {
+        return Some(Int::zero());
+    }
+    let k = min(k, n - k);
+    let mut result = Int::one();
+    let mut i = Int::one();
+    while i <= k {
+        // result * (n - i + 1) / i is C(n, i), so i / gcd(result, i) divides n - i + 1.
+        let g = gcd(result, i);
+        result = (result / g).checked_mul(&((n - (i - Int::one())) / (i / g)))?;
+        i = i + Int::one();
+    }
+    Some(result)
+}
+
+fn primint_checked_perm<Int>(n: Int, k: Int) -> Option<Int>
+where
+    Int: PrimInt + FromPrimitive,
+{
+    if k > n || n < Int::zero() || k < Int::zero() {
+        return Some(Int::zero());
+    }
+    let mut result = Int::one();
+    let mut factor = n;
+    while factor > n - k {
+        result = result.checked_mul(&factor)?;
+        factor = factor - Int::one();
+    }
+    Some(result)
+}
+
+/// The number of combinations of `n` taken `k` at a time, from the beta function.
+///
+/// This is the `exact=False` path of Scipy, which extends the binomial coefficient to real
+/// numbers as `1 / ((n + 1) B(n - k + 1, k + 1))`. Integer `k` of at most 20 use the product
+/// formula instead, for its precision. For exact integers, see [Combinatoric::checked_comb].
+///
+/// # Parameters
+/// * `n`, `k`: Number of things, and of elements taken.
+/// * `repetition`: Count the combinations with repetition, `comb(n + k - 1, k)`. False by
+///   default.
+///
+/// # Examples
+/// ```
+/// use sci_rs::special::comb;
+/// assert!((comb(10., 3., None) - 120.).abs() < 1e-12);
+/// assert!((comb(10., 3., Some(true)) - 220.).abs() < 1e-12);
+/// assert!((comb(1000., 500., None) / 2.702882409454366e299 - 1.).abs() < 1e-12);
+/// ```
+///
+/// # Notes
+/// When `n < 0` or `k < 0` or `n < k`, then `0` is returned.
+pub fn comb(n: f64, k: f64, repetition: Option<bool>) -> f64 {
+    if n.is_nan() || k.is_nan() {
+        return f64::NAN;
+    }
+    if repetition.unwrap_or(false) {
+        return comb(n + k - 1., k, None);
+    }
+    if k > n || n < 0. || k < 0. {
+        return 0.;
+    }
+    let mut kx = k;
+    if k == k.floor() {
+        if n == n.floor() && kx > n / 2. {
+            kx = n - kx;
+        }
+        if kx < 20. {
+            let (mut num, mut den) = (1., 1.);
+            for i in 1..=kx as usize {
+                num *= i as f64 + n - kx;
+                den *= i as f64;
+                if num.abs() > 1e50 {
+                    num /= den;
+                    den = 1.;
+                }
+            }
+            return num / den;
+        }
+    }
+    1. / (n + 1.) / xsf::beta(n - k + 1., k + 1.)
+}
+
+/// Number of permutations of `n` things taken `k` at a time, from the gamma function.
+///
+/// This is the `exact=False` path of Scipy, `gamma(n + 1) / gamma(n - k + 1)`, which overflows to
+/// infinity rather than wrapping. For exact integers, see [Combinatoric::checked_perm].
+///
+/// # Examples
+/// ```
+/// use sci_rs::special::perm;
+/// assert!((perm(10., 3.) - 720.).abs() < 1e-12);
+/// assert_eq!(perm(200., 180.), f64::INFINITY);
+/// ```
+///
+/// # Notes
+/// When `n < 0` or `k < 0` or `n < k`, then `0` is returned.
+pub fn perm(n: f64, k: f64) -> f64 {
+    if n.is_nan() || k.is_nan() {
+        return f64::NAN;
+    }
+    if k > n || n < 0. || k < 0. {
+        return 0.;
+    }
+    if k == k.floor() {
+        let mut result = 1.;
+        for i in 0..k as usize {
+            result *= n - i as f64;
+            if result.is_infinite() {
+                break;
+            }
+        }
+        return result;
+    }
+    (xsf::gammaln(n + 1.) - xsf::gammaln(n - k + 1.)).exp()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -317,4 +494,61 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn checked() {
+        for n in 0..20_i64 {
+            for k in -2..22 {
+                assert_eq!(n.checked_comb(k), Some(n.comb(k)));
+                assert_eq!(n.checked_perm(k), Some(n.perm(k)));
+            }
+        }
+        // C(67, 33) is the largest central coefficient of u64, and its intermediate products
+        // overflow with the plain formula.
+        assert_eq!(67_u64.checked_comb(33), Some(14226520737620288370));
+        assert_eq!(u64::MAX.checked_comb(1), Some(u64::MAX));
+        assert_eq!(u64::MAX.checked_comb(2), None);
+        assert_eq!(127_i8.checked_comb(126), Some(127));
+        assert_eq!(20_u64.checked_perm(20), Some(2432902008176640000));
+        assert_eq!(21_u64.checked_perm(21), None);
+    }
+
+    #[test]
+    fn floating() {
+        use approx::assert_relative_eq;
+
+        for n in 0..30_u64 {
+            for k in 0..30_u64 {
+                let comb = n.checked_comb(k).unwrap() as f64;
+                let perm = n.checked_perm(k).map_or(0., |p| p as f64);
+                assert_relative_eq!(
+                    super::comb(n as f64, k as f64, None),
+                    comb,
+                    max_relative = 1e-14
+                );
+                if n.checked_perm(k).is_some() {
+                    assert_relative_eq!(
+                        super::perm(n as f64, k as f64),
+                        perm,
+                        max_relative = 1e-14
+                    );
+                }
+            }
+        }
+        // From mpmath.
+        assert_relative_eq!(
+            super::comb(100., 50., None),
+            1.008913445455642e29,
+            max_relative = 1e-13
+        );
+        assert_relative_eq!(super::comb(5.5, 2.5, None), 14.4375, max_relative = 1e-13);
+        assert_relative_eq!(super::comb(5., 3., Some(true)), 35., max_relative = 1e-14);
+        assert_relative_eq!(
+            super::perm(5.5, 2.5),
+            47.98087963584073,
+            max_relative = 1e-13
+        );
+        assert_eq!(super::comb(3., 5., None), 0.);
+        assert_eq!(super::perm(-1., 0.), 0.);
+    }
 }
@@ -0,0 +1,205 @@
+use super::xsf;
+#[cfg(feature = "std")]
+use ndarray::ArrayD;
+
+/// The [error function and its
+/// inverses](<https://docs.scipy.org/doc/scipy/reference/special.html#error-function-and-fresnel-integrals>),
+/// with the [cumulative distribution function of the standard normal distribution and its
+/// inverse](<https://docs.scipy.org/doc/scipy/reference/special.html#raw-statistical-functions>).
+pub trait Erf {
+    /// Error function, `2 / sqrt(pi) * integral(exp(-t^2), t = 0..x)`.
+    ///
+    /// ## Notes
+    /// * Rational approximation of Cephes for `|x| <= 1`, and `1 - erfc(x)` above.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Erf;
+    /// // `Erf::erf` rather than the method, which clashes with the unstable `f64::erf`.
+    /// assert!((Erf::erf(&0.5f64) - 0.5204998778130465).abs() < 1e-15);
+    /// ```
+    fn erf(&self) -> Self;
+
+    /// Complementary error function, `1 - erf(x)`, without the cancellation for large `x`.
+    ///
+    /// ## Notes
+    /// * Rational approximations of Cephes of `exp(x^2) erfc(x)` on `[1, 8]` and above, where
+    ///   `exp(-x^2)` is computed without the rounding error of `x^2`.
+    /// * It underflows to 0 beyond `x = 27.3`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Erf;
+    /// assert!((Erf::erfc(&10f64) / 2.088487583762545e-45 - 1.).abs() < 1e-15);
+    /// ```
+    fn erfc(&self) -> Self;
+
+    /// Inverse of the error function, NaN outside of `[-1, 1]`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Erf;
+    /// assert!((0.5f64.erfinv() - 0.4769362762044699).abs() < 1e-15);
+    /// ```
+    fn erfinv(&self) -> Self;
+
+    /// Inverse of the complementary error function, NaN outside of `[0, 2]`.
+    ///
+    /// ## Notes
+    /// * Small arguments keep their relative precision, unlike `erfinv(1 - y)`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Erf;
+    /// assert!((1e-100f64.erfcinv() - 15.065574702592645).abs() < 1e-13);
+    /// ```
+    fn erfcinv(&self) -> Self;
+
+    /// Cumulative distribution function of the standard normal distribution.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Erf;
+    /// assert!((1.96f64.ndtr() - 0.9750021048517795).abs() < 1e-15);
+    /// ```
+    fn ndtr(&self) -> Self;
+
+    /// Inverse of [ndtr](Erf::ndtr), NaN outside of `[0, 1]`.
+    ///
+    /// ## Notes
+    /// * Rational approximation of Acklam, refined by a step of Halley's method.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Erf;
+    /// assert!((0.975f64.ndtri() - 1.959963984540054).abs() < 1e-14);
+    /// ```
+    fn ndtri(&self) -> Self;
+}
+
+impl Erf for f64 {
+    fn erf(&self) -> Self {
+        xsf::erf(*self)
+    }
+
+    fn erfc(&self) -> Self {
+        xsf::erfc(*self)
+    }
+
+    fn erfinv(&self) -> Self {
+        xsf::erfinv(*self)
+    }
+
+    fn erfcinv(&self) -> Self {
+        xsf::erfcinv(*self)
+    }
+
+    fn ndtr(&self) -> Self {
+        xsf::ndtr(*self)
+    }
+
+    fn ndtri(&self) -> Self {
+        xsf::ndtri(*self)
+    }
+}
+
+impl Erf for f32 {
+    fn erf(&self) -> Self {
+        xsf::erf(*self as f64) as f32
+    }
+
+    fn erfc(&self) -> Self {
+        xsf::erfc(*self as f64) as f32
+    }
+
+    fn erfinv(&self) -> Self {
+        xsf::erfinv(*self as f64) as f32
+    }
+
+    fn erfcinv(&self) -> Self {
+        xsf::erfcinv(*self as f64) as f32
+    }
+
+    fn ndtr(&self) -> Self {
+        xsf::ndtr(*self as f64) as f32
+    }
+
+    fn ndtri(&self) -> Self {
+        xsf::ndtri(*self as f64) as f32
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Erf for Vec<T>
+where
+    T: Erf,
+{
+    fn erf(&self) -> Self {
+        self.iter().map(Erf::erf).collect()
+    }
+
+    fn erfc(&self) -> Self {
+        self.iter().map(Erf::erfc).collect()
+    }
+
+    fn erfinv(&self) -> Self {
+        self.iter().map(Erf::erfinv).collect()
+    }
+
+    fn erfcinv(&self) -> Self {
+        self.iter().map(Erf::erfcinv).collect()
+    }
+
+    fn ndtr(&self) -> Self {
+        self.iter().map(Erf::ndtr).collect()
+    }
+
+    fn ndtri(&self) -> Self {
+        self.iter().map(Erf::ndtri).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Erf for ArrayD<T>
+where
+    T: Erf,
+{
+    fn erf(&self) -> Self {
+        self.map(Erf::erf)
+    }
+
+    fn erfc(&self) -> Self {
+        self.map(Erf::erfc)
+    }
+
+    fn erfinv(&self) -> Self {
+        self.map(Erf::erfinv)
+    }
+
+    fn erfcinv(&self) -> Self {
+        self.map(Erf::erfcinv)
+    }
+
+    fn ndtr(&self) -> Self {
+        self.map(Erf::ndtr)
+    }
+
+    fn ndtri(&self) -> Self {
+        self.map(Erf::ndtri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn erfinv_vec_f32() {
+        let y = vec![-0.9f32, 0., 0.25, 0.999];
+        for (&y, x) in y.iter().zip(y.erfinv()) {
+            assert_relative_eq!(Erf::erf(&x), y, max_relative = 1e-6);
+        }
+    }
+}
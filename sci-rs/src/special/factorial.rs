@@ -1,4 +1,5 @@
-use num_traits::{FromPrimitive, PrimInt};
+use super::xsf;
+use num_traits::{Float, FromPrimitive, PrimInt};
 
 /// Factorial and related functions
 ///
@@ -114,6 +115,43 @@ pub trait Factorial {
     ///
     /// [factorial]: crate::special::Factorial::factorial
     fn factorialk(self, k: Self) -> Self;
+
+    /// Checked [factorial], returning `None` if the result overflows.
+    ///
+    /// This is the `exact=True` path of Scipy, without big integers. For large arguments, see the
+    /// floating point [factorial](crate::special::factorial()).
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Factorial;
+    ///
+    /// assert_eq!(20_u64.checked_factorial(), Some(2432902008176640000));
+    /// assert_eq!(21_u64.checked_factorial(), None);
+    /// ```
+    /// # Notes
+    /// If `n < 0`, returns `Some(0)`.
+    ///
+    /// [factorial]: crate::special::Factorial::factorial
+    fn checked_factorial(self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Checked [factorial2], returning `None` if the result overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Factorial;
+    ///
+    /// assert_eq!(7_u8.checked_factorial2(), Some(105));
+    /// assert_eq!(8_u8.checked_factorial2(), None);
+    /// ```
+    /// # Notes
+    /// If `n < 0`, returns `Some(0)`.
+    ///
+    /// [factorial2]: crate::special::Factorial::factorial2
+    fn checked_factorial2(self) -> Option<Self>
+    where
+        Self: Sized;
 }
 
 macro_rules! factorial_primint_impl {
@@ -133,6 +171,16 @@ macro_rules! factorial_primint_impl {
             fn factorialk(self, k: Self) -> Self {
                 primint_factorialk(self, k)
             }
+
+            #[inline(always)]
+            fn checked_factorial(self) -> Option<Self> {
+                primint_checked_factorialk(self, 1)
+            }
+
+            #[inline(always)]
+            fn checked_factorial2(self) -> Option<Self> {
+                primint_checked_factorialk(self, 2)
+            }
         }
     )*)
 }
@@ -255,6 +303,85 @@ where
     }
 }
 
+fn primint_checked_factorialk<Int>(n: Int, k: u8) -> Option<Int>
+where
+    Int: PrimInt + FromPrimitive,
+{
+    if n < Int::zero() {
+        return Some(Int::zero());
+    }
+    let k = Int::from_u8(k).unwrap();
+    let mut result = Int::one();
+    let mut factor = n;
+    while factor > Int::one() {
+        result = result.checked_mul(&factor)?;
+        factor = factor - k;
+    }
+    Some(result)
+}
+
+/// Factorial of `n`, from the gamma function, as `gamma(n + 1)`.
+///
+/// This is the `exact=False` path of Scipy, which extends the factorial to real numbers and
+/// overflows to infinity beyond `n = 170`. For exact integers, see
+/// [Factorial::checked_factorial].
+///
+/// # Examples
+/// ```
+/// use sci_rs::special::factorial;
+///
+/// assert!((factorial(5.) - 120.).abs() < 1e-12);
+/// assert!((factorial(0.5) - 0.886226925452758).abs() < 1e-15);
+/// assert_eq!(factorial(171.), f64::INFINITY);
+/// ```
+/// # Notes
+/// If `n < 0`, returns `0`.
+pub fn factorial(n: f64) -> f64 {
+    if n.is_nan() {
+        return f64::NAN;
+    }
+    if n < 0. {
+        return 0.;
+    }
+    xsf::gamma(n + 1.)
+}
+
+/// Double factorial of `n`, from the gamma function.
+///
+/// This is the `exact=False` path of Scipy. For even `n`,
+/// $$
+/// n!! = 2^{n/2} \Gamma(n/2 + 1),
+/// $$
+/// and otherwise, which extends the odd values to real numbers,
+/// $$
+/// n!! = 2^{(n+1)/2} \Gamma(n/2 + 1) / \sqrt{\pi}.
+/// $$
+/// For exact integers, see [Factorial::checked_factorial2].
+///
+/// # Examples
+/// ```
+/// use sci_rs::special::factorial2;
+///
+/// assert!((factorial2(6.) - 48.).abs() < 1e-12);
+/// assert!((factorial2(7.) - 105.).abs() < 1e-12);
+/// ```
+/// # Notes
+/// If `n < 0`, returns `0`.
+pub fn factorial2(n: f64) -> f64 {
+    if n.is_nan() {
+        return f64::NAN;
+    }
+    if n < 0. {
+        return 0.;
+    }
+    let half = n / 2.;
+    if half == half.floor() {
+        2f64.powf(half) * xsf::gamma(half + 1.)
+    } else {
+        2f64.powf(half + 0.5) * xsf::gamma(half + 1.) / core::f64::consts::PI.sqrt()
+    }
+}
+
 /// Computes the product between `start` and `stop` stepping with `step`.
 ///
 /// $$
@@ -456,6 +583,48 @@ mod test {
         check_factorial2::<i128>(57);
     }
 
+    #[test]
+    fn checked_factorial() {
+        assert_eq!(5_u8.checked_factorial(), Some(120));
+        assert_eq!(6_u8.checked_factorial(), None);
+        assert_eq!(7_i16.checked_factorial(), Some(5040));
+        assert_eq!(8_i16.checked_factorial(), None);
+        assert_eq!(
+            33_i128.checked_factorial(),
+            Some(ABSOLUTE_KNOWN_FACTORIAL_VALUES[33] as i128)
+        );
+        assert_eq!(34_i128.checked_factorial(), None);
+        assert_eq!((-3_i32).checked_factorial(), Some(0));
+
+        assert_eq!(33_u64.checked_factorial2(), Some(6332659870762850625));
+        assert_eq!(34_u64.checked_factorial2(), None);
+        assert_eq!(0_u8.checked_factorial2(), Some(1));
+        assert_eq!(1_u8.checked_factorial2(), Some(1));
+    }
+
+    #[test]
+    fn floating_factorial() {
+        use approx::assert_relative_eq;
+
+        for (n, &exact) in ABSOLUTE_KNOWN_FACTORIAL_VALUES.iter().enumerate() {
+            assert_relative_eq!(
+                super::factorial(n as f64),
+                exact as f64,
+                max_relative = 1e-14
+            );
+        }
+        for (n, &exact) in ABSOLUTE_KNOWN_FACTORIAL2_VALUES.iter().enumerate() {
+            assert_relative_eq!(
+                super::factorial2(n as f64),
+                exact as f64,
+                max_relative = 1e-14
+            );
+        }
+        assert_eq!(super::factorial(-1.), 0.);
+        assert_eq!(super::factorial2(-2.), 0.);
+        assert!(super::factorial(f64::NAN).is_nan());
+    }
+
     #[test]
     fn factorialk() {
         const REF_VALUES: [[usize; 14]; 15] = [
@@ -0,0 +1,208 @@
+use super::xsf;
+#[cfg(feature = "std")]
+use ndarray::{ArrayD, Zip};
+
+/// All [functions located in the `Gamma and related
+/// functions`](<https://docs.scipy.org/doc/scipy/reference/special.html#gamma-and-related-functions>)
+/// which are of a single type.
+pub trait Gamma {
+    /// Gamma function.
+    ///
+    /// ## Notes
+    /// * Lanczos approximation for `x >= 1/2`, and the reflection formula
+    ///   `gamma(x) gamma(1 - x) = pi / sin(pi x)` below.
+    /// * The poles at the non-positive integers give NaN, and it overflows to infinity beyond
+    ///   `x = 171.62`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Gamma;
+    /// // `Gamma::gamma` rather than the method, which clashes with the unstable `f64::gamma`.
+    /// assert!((Gamma::gamma(&5f64) - 24.).abs() < 1e-12);
+    /// assert!((Gamma::gamma(&0.5f64) - core::f64::consts::PI.sqrt()).abs() < 1e-14);
+    /// ```
+    fn gamma(&self) -> Self;
+
+    /// Natural logarithm of the absolute value of the gamma function, `ln|gamma(x)|`.
+    ///
+    /// ## Notes
+    /// * Taylor series around the zeros at 1 and 2, rational approximation below 13 and Stirling
+    ///   series above, from Cephes, and the reflection formula for `x < 0`.
+    /// * The poles at the non-positive integers give infinity.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Gamma;
+    /// assert!((100f64.gammaln() - 359.1342053695754).abs() < 1e-12);
+    /// ```
+    fn gammaln(&self) -> Self;
+
+    /// Digamma function, the logarithmic derivative `gamma'(x) / gamma(x)`.
+    ///
+    /// ## Notes
+    /// * Taylor series around its positive zero `x = 1.4616...`, asymptotic series for large `x`
+    ///   with the recurrence below, and the reflection formula for `x < 0`.
+    /// * NaN at the negative integers, and infinite at zero.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Gamma;
+    /// // Minus the Euler-Mascheroni constant.
+    /// assert!((1f64.digamma() + 0.5772156649015329).abs() < 1e-15);
+    /// ```
+    fn digamma(&self) -> Self;
+
+    /// Beta function, `gamma(a) gamma(b) / gamma(a + b)`.
+    ///
+    /// ## Notes
+    /// * Computed from the logarithms when the gamma functions overflow, and from an asymptotic
+    ///   expansion when one argument is much larger than the other.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Gamma;
+    /// assert!((2f64.beta(&3.) - 1. / 12.).abs() < 1e-15);
+    /// ```
+    fn beta(&self, b: &Self) -> Self;
+
+    /// Natural logarithm of the absolute value of the beta function, `ln|beta(a, b)|`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use sci_rs::special::Gamma;
+    /// assert!((200f64.betaln(&300.) + 337.98011306546467).abs() < 1e-10);
+    /// ```
+    fn betaln(&self, b: &Self) -> Self;
+}
+
+impl Gamma for f64 {
+    fn gamma(&self) -> Self {
+        xsf::gamma(*self)
+    }
+
+    fn gammaln(&self) -> Self {
+        xsf::gammaln(*self)
+    }
+
+    fn digamma(&self) -> Self {
+        xsf::digamma(*self)
+    }
+
+    fn beta(&self, b: &Self) -> Self {
+        xsf::beta(*self, *b)
+    }
+
+    fn betaln(&self, b: &Self) -> Self {
+        xsf::betaln(*self, *b)
+    }
+}
+
+impl Gamma for f32 {
+    fn gamma(&self) -> Self {
+        xsf::gamma(*self as f64) as f32
+    }
+
+    fn gammaln(&self) -> Self {
+        xsf::gammaln(*self as f64) as f32
+    }
+
+    fn digamma(&self) -> Self {
+        xsf::digamma(*self as f64) as f32
+    }
+
+    fn beta(&self, b: &Self) -> Self {
+        xsf::beta(*self as f64, *b as f64) as f32
+    }
+
+    fn betaln(&self, b: &Self) -> Self {
+        xsf::betaln(*self as f64, *b as f64) as f32
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Gamma for Vec<T>
+where
+    T: Gamma,
+{
+    fn gamma(&self) -> Self {
+        self.iter().map(Gamma::gamma).collect()
+    }
+
+    fn gammaln(&self) -> Self {
+        self.iter().map(Gamma::gammaln).collect()
+    }
+
+    fn digamma(&self) -> Self {
+        self.iter().map(Gamma::digamma).collect()
+    }
+
+    /// Elementwise, up to the length of the shortest.
+    fn beta(&self, b: &Self) -> Self {
+        self.iter().zip(b).map(|(a, b)| a.beta(b)).collect()
+    }
+
+    /// Elementwise, up to the length of the shortest.
+    fn betaln(&self, b: &Self) -> Self {
+        self.iter().zip(b).map(|(a, b)| a.betaln(b)).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Gamma for ArrayD<T>
+where
+    T: Gamma,
+{
+    fn gamma(&self) -> Self {
+        self.map(Gamma::gamma)
+    }
+
+    fn gammaln(&self) -> Self {
+        self.map(Gamma::gammaln)
+    }
+
+    fn digamma(&self) -> Self {
+        self.map(Gamma::digamma)
+    }
+
+    /// Elementwise, panics if the shapes differ.
+    fn beta(&self, b: &Self) -> Self {
+        Zip::from(self).and(b).map_collect(|a, b| a.beta(b))
+    }
+
+    /// Elementwise, panics if the shapes differ.
+    fn betaln(&self, b: &Self) -> Self {
+        Zip::from(self).and(b).map_collect(|a, b| a.betaln(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn gamma_f32() {
+        assert_relative_eq!(Gamma::gamma(&4f32), 6., max_relative = 1e-6);
+        assert_relative_eq!((-1.5f32).gammaln(), 0.860_047, max_relative = 1e-6);
+        assert_relative_eq!(
+            0.5f32.beta(&0.5),
+            core::f32::consts::PI,
+            max_relative = 1e-6
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gamma_vec_f64() {
+        let a = vec![0.5, 1., 3.5, 10.];
+        let b = vec![1., 2., 0.5, 10.];
+        let gamma = [1.772453850905516, 1., 3.3233509704478426, 362880.];
+        let beta = [2., 0.5, 0.9817477042468103, 1.0825088224469029e-06];
+        for (&r, e) in gamma.iter().zip(a.gamma()) {
+            assert_relative_eq!(r, e, max_relative = 1e-14);
+        }
+        for (&r, e) in beta.iter().zip(a.beta(&b)) {
+            assert_relative_eq!(r, e, max_relative = 1e-14);
+        }
+    }
+}
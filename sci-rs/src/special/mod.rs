@@ -1,19 +1,37 @@
 //! Special mathematical functions
 //!
 //! # Available Functions
-//! - Factorial, double factorial, and `k`-factorial
-//! - Combinatorics (choice and permutations)
+//! - Factorial, double factorial, and `k`-factorial, exact with overflow checks or floating
+//! - Combinatorics (choice and permutations), exact with overflow checks or floating
+//! - Gamma, log-gamma, digamma and beta functions
+//! - Error function, its complement and their inverses, and the normal distribution function
+//! - Bessel functions of the first and second kind, their modified counterparts, and the zeros
+//!   of the integer orders
+//! - Legendre, Chebyshev, Hermite and Laguerre polynomials, and the nodes and weights of their
+//!   Gauss quadratures
 
 mod combinatorics;
 mod factorial;
+mod orthogonal;
 
 pub use combinatorics::*;
 pub use factorial::*;
+pub use orthogonal::*;
 
 /// Adds the [Bessel] trait.
 mod bessel;
+#[cfg(feature = "alloc")]
+pub use bessel::jn_zeros;
 pub use bessel::Bessel;
 
+/// Adds the [Gamma] trait.
+mod gamma;
+pub use gamma::Gamma;
+
+/// Adds the [Erf] trait.
+mod erf;
+pub use erf::Erf;
+
 // Name is from special/xsf folder, which is Scipy has designated as X special functions (written
 // in C++) that are not exposed to Python. We keep these set of functions as being crate internal.
 pub(crate) mod xsf;
@@ -0,0 +1,386 @@
+#[cfg(feature = "alloc")]
+use crate::{integrate::leggauss, linalg::eigh};
+#[cfg(feature = "alloc")]
+use core::f64::consts::PI;
+#[cfg(feature = "alloc")]
+use ndarray::{Array1, Array2};
+#[cfg(feature = "alloc")]
+use sci_rs_core::{Error, Result};
+
+/// Evaluate a polynomial of degree `n` from its three-term recurrence,
+/// `p_k+1 = a(k) p_k - b(k) p_k-1`, with `p_0 = 1` and `p_1 = p1`.
+fn recurrence(n: usize, p1: f64, a: impl Fn(f64) -> f64, b: impl Fn(f64) -> f64) -> f64 {
+    if n == 0 {
+        return 1.;
+    }
+    let (mut p, mut prev) = (p1, 1.);
+    for k in 1..n {
+        let k = k as f64;
+        (p, prev) = (a(k) * p - b(k) * prev, p);
+    }
+    p
+}
+
+/// Evaluate the Legendre polynomial `P_n` at a point.
+///
+/// ## Notes
+/// * Bonnet's recurrence, `(k + 1) P_k+1(x) = (2k + 1) x P_k(x) - k P_k-1(x)`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import special
+/// special.eval_legendre(3, 0.5)
+/// # -0.4375
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use sci_rs::special::eval_legendre;
+/// assert_eq!(eval_legendre(3, 0.5), -0.4375);
+/// ```
+pub fn eval_legendre(n: usize, x: f64) -> f64 {
+    recurrence(n, x, |k| (2. * k + 1.) * x / (k + 1.), |k| k / (k + 1.))
+}
+
+/// Evaluate the Chebyshev polynomial of the first kind `T_n` at a point.
+///
+/// ## Notes
+/// * Recurrence `T_k+1(x) = 2x T_k(x) - T_k-1(x)`, which gives `T_n(cos t) = cos(n t)`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import special
+/// special.eval_chebyt(4, 0.3)
+/// # 0.3448
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use sci_rs::special::eval_chebyt;
+/// assert!((eval_chebyt(4, 0.3) - 0.3448).abs() < 1e-15);
+/// ```
+pub fn eval_chebyt(n: usize, x: f64) -> f64 {
+    recurrence(n, x, |_| 2. * x, |_| 1.)
+}
+
+/// Evaluate the physicists' Hermite polynomial `H_n` at a point, orthogonal for the weight
+/// `exp(-x^2)`.
+///
+/// ## Notes
+/// * Recurrence `H_k+1(x) = 2x H_k(x) - 2k H_k-1(x)`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import special
+/// special.eval_hermite(3, 2.)
+/// # 40.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use sci_rs::special::eval_hermite;
+/// assert_eq!(eval_hermite(3, 2.), 40.);
+/// ```
+pub fn eval_hermite(n: usize, x: f64) -> f64 {
+    recurrence(n, 2. * x, |_| 2. * x, |k| 2. * k)
+}
+
+/// Evaluate the Laguerre polynomial `L_n` at a point, orthogonal for the weight `exp(-x)` on
+/// `[0, inf)`.
+///
+/// ## Notes
+/// * Recurrence `(k + 1) L_k+1(x) = (2k + 1 - x) L_k(x) - k L_k-1(x)`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import special
+/// special.eval_laguerre(2, 1.)
+/// # -0.5
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use sci_rs::special::eval_laguerre;
+/// assert_eq!(eval_laguerre(2, 1.), -0.5);
+/// ```
+pub fn eval_laguerre(n: usize, x: f64) -> f64 {
+    recurrence(
+        n,
+        1. - x,
+        |k| (2. * k + 1. - x) / (k + 1.),
+        |k| k / (k + 1.),
+    )
+}
+
+#[cfg(feature = "alloc")]
+fn check_order(n: usize) -> Result<()> {
+    if n == 0 {
+        return Err(Error::InvalidArg {
+            arg: "n".into(),
+            reason: "must be positive.".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Gauss quadrature by the method of Golub and Welsch, for the orthonormal polynomials
+/// `b(k + 1) p_k+1 = (x - a(k)) p_k - b(k) p_k-1` of a weight of total mass `mu0`.
+///
+/// The eigenvalues of the Jacobi matrix are polished by a Newton step on `p_n`, and the weights
+/// are the Christoffel numbers `mu0 / sum(p_k(x)^2, k < n)`, which keep their relative precision
+/// where they are small.
+#[cfg(feature = "alloc")]
+fn golub_welsch(
+    n: usize,
+    a: impl Fn(usize) -> f64,
+    b: impl Fn(usize) -> f64,
+    mu0: f64,
+) -> Result<(Array1<f64>, Array1<f64>)> {
+    check_order(n)?;
+    let jacobi = Array2::from_shape_fn((n, n), |(i, j)| match i.abs_diff(j) {
+        0 => a(i),
+        1 => b(i.max(j)),
+        _ => 0.,
+    });
+    let mut x = eigh(&jacobi, None)?.eigenvalues;
+    let mut w = Array1::zeros(n);
+    for (x, w) in x.iter_mut().zip(&mut w) {
+        for step in 0..2 {
+            let (mut p, mut prev, mut dp, mut dprev, mut norm) = (1., 0., 0., 0., 0.);
+            for k in 0..n {
+                norm += p * p;
+                let next = ((*x - a(k)) * p - b(k) * prev) / b(k + 1);
+                let dnext = (p + (*x - a(k)) * dp - b(k) * dprev) / b(k + 1);
+                (p, prev, dp, dprev) = (next, p, dnext, dp);
+            }
+            if step == 0 && dp != 0. && p.is_finite() && dp.is_finite() {
+                *x -= p / dp;
+            } else {
+                *w = mu0 / norm;
+                break;
+            }
+        }
+    }
+    Ok((x, w))
+}
+
+/// Compute the sample points and weights of Gauss-Legendre quadrature.
+///
+/// ## Parameters
+/// * `n`: Number of sample points.
+///
+/// ## Returns
+/// The sample points in ascending order, the roots of `P_n`, and their weights. The rule
+/// integrates polynomials of degree up to `2n - 1` over `[-1, 1]` exactly.
+///
+/// ## Errors
+/// If `n` is 0.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import special
+/// special.roots_legendre(2)
+/// # (array([-0.57735027,  0.57735027]), array([1., 1.]))
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use sci_rs::special::roots_legendre;
+///
+/// let (x, w) = roots_legendre(2).unwrap();
+/// assert_abs_diff_eq!(x[1], 1. / 3f64.sqrt(), epsilon = 1e-15);
+/// assert_abs_diff_eq!(w[0], 1., epsilon = 1e-15);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn roots_legendre(n: usize) -> Result<(Array1<f64>, Array1<f64>)> {
+    check_order(n)?;
+    leggauss(n)
+}
+
+/// Compute the sample points and weights of Gauss-Chebyshev quadrature.
+///
+/// ## Parameters
+/// * `n`: Number of sample points.
+///
+/// ## Returns
+/// The sample points in ascending order, the roots `cos((2k - 1) pi / (2n))` of `T_n`, and
+/// their weights, all `pi / n`. The rule integrates polynomials of degree up to `2n - 1` over
+/// `[-1, 1]` exactly, for the weight `1 / sqrt(1 - x^2)`.
+///
+/// ## Errors
+/// If `n` is 0.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import special
+/// special.roots_chebyt(3)
+/// # (array([-0.8660254,  0.       ,  0.8660254]),
+/// #  array([1.04719755, 1.04719755, 1.04719755]))
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use sci_rs::special::roots_chebyt;
+///
+/// let (x, w) = roots_chebyt(3).unwrap();
+/// assert_abs_diff_eq!(x[2], 0.75f64.sqrt(), epsilon = 1e-15);
+/// assert_abs_diff_eq!(w[0], core::f64::consts::FRAC_PI_3, epsilon = 1e-15);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn roots_chebyt(n: usize) -> Result<(Array1<f64>, Array1<f64>)> {
+    check_order(n)?;
+    let x = Array1::from_shape_fn(n, |k| {
+        // The sine of the complementary angle, exactly antisymmetric about the center.
+        let t = (n as f64 - 1. - 2. * k as f64) * PI / (2 * n) as f64;
+        -t.sin()
+    });
+    Ok((x, Array1::from_elem(n, PI / n as f64)))
+}
+
+/// Compute the sample points and weights of Gauss-Hermite quadrature.
+///
+/// ## Parameters
+/// * `n`: Number of sample points.
+///
+/// ## Returns
+/// The sample points in ascending order, the roots of the physicists' Hermite polynomial `H_n`,
+/// and their weights. The rule integrates polynomials of degree up to `2n - 1` over
+/// `(-inf, inf)` exactly, for the weight `exp(-x^2)`.
+///
+/// ## Errors
+/// If `n` is 0.
+///
+/// ## Notes
+/// * The weights of the outermost points underflow to 0 past about `n = 350`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import special
+/// special.roots_hermite(3)
+/// # (array([-1.22474487,  0.        ,  1.22474487]),
+/// #  array([0.29540898, 1.18163590, 0.29540898]))
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use sci_rs::special::roots_hermite;
+///
+/// let (x, w) = roots_hermite(3).unwrap();
+/// assert_abs_diff_eq!(x[2], 1.5f64.sqrt(), epsilon = 1e-15);
+/// assert_abs_diff_eq!(w[1], 2. / 3. * core::f64::consts::PI.sqrt(), epsilon = 1e-15);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn roots_hermite(n: usize) -> Result<(Array1<f64>, Array1<f64>)> {
+    golub_welsch(n, |_| 0., |k| (0.5 * k as f64).sqrt(), PI.sqrt())
+}
+
+/// Compute the sample points and weights of Gauss-Laguerre quadrature.
+///
+/// ## Parameters
+/// * `n`: Number of sample points.
+///
+/// ## Returns
+/// The sample points in ascending order, the roots of `L_n`, and their weights. The rule
+/// integrates polynomials of degree up to `2n - 1` over `[0, inf)` exactly, for the weight
+/// `exp(-x)`.
+///
+/// ## Errors
+/// If `n` is 0.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import special
+/// special.roots_laguerre(2)
+/// # (array([0.58578644, 3.41421356]), array([0.85355339, 0.14644661]))
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use sci_rs::special::roots_laguerre;
+///
+/// let (x, w) = roots_laguerre(2).unwrap();
+/// assert_abs_diff_eq!(x[0], 2. - 2f64.sqrt(), epsilon = 1e-15);
+/// assert_abs_diff_eq!(w[0], 0.25 * (2. + 2f64.sqrt()), epsilon = 1e-15);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn roots_laguerre(n: usize) -> Result<(Array1<f64>, Array1<f64>)> {
+    golub_welsch(n, |k| (2 * k + 1) as f64, |k| k as f64, 1.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn evaluation() {
+        // From mpmath.legendre, chebyt, hermite and laguerre.
+        assert_eq!(eval_legendre(0, 0.3), 1.);
+        assert_relative_eq!(eval_legendre(5, 0.3), 0.34538625, max_relative = 1e-15);
+        assert_relative_eq!(
+            eval_legendre(40, 0.9),
+            0.036987418421876216,
+            max_relative = 1e-13
+        );
+        assert_relative_eq!(
+            eval_chebyt(7, 0.6),
+            (7. * 0.6f64.acos()).cos(),
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(eval_chebyt(3, 2.), 26., max_relative = 1e-15);
+        assert_relative_eq!(eval_hermite(10, 1.5), -85401., max_relative = 1e-15);
+        assert_relative_eq!(
+            eval_laguerre(5, 2.5),
+            1.0325520833333333,
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(
+            eval_laguerre(30, 10.),
+            -12.716795749432531,
+            max_relative = 1e-12
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn gauss_quadrature() {
+        type Roots = fn(usize) -> Result<(Array1<f64>, Array1<f64>)>;
+        let roots: [Roots; 4] = [roots_legendre, roots_chebyt, roots_hermite, roots_laguerre];
+        let evals: [fn(usize, f64) -> f64; 4] =
+            [eval_legendre, eval_chebyt, eval_hermite, eval_laguerre];
+        // Total mass of the weight, and the integral of x^2 against it.
+        let moments = [
+            (2., 2. / 3.),
+            (PI, PI / 2.),
+            (PI.sqrt(), PI.sqrt() / 2.),
+            (1., 2.),
+        ];
+        for ((roots, eval), (mass, second)) in roots.iter().zip(evals).zip(moments) {
+            for n in [1, 5, 20] {
+                let (x, w) = roots(n).unwrap();
+                assert!(x.windows(2).into_iter().all(|x| x[0] < x[1]));
+                let scale = x.iter().fold(1f64, |m, x| m.max(eval(n - 1, *x).abs()));
+                for &x in &x {
+                    assert!(eval(n, x).abs() < 1e-12 * scale);
+                }
+                assert_relative_eq!(w.sum(), mass, max_relative = 1e-13);
+                if n > 1 {
+                    let s = x.iter().zip(&w).map(|(x, w)| w * x * x).sum::<f64>();
+                    assert_relative_eq!(s, second, max_relative = 1e-13);
+                }
+            }
+            assert!(roots(0).is_err());
+        }
+        // From mpmath.gauss_quadrature.
+        let (x, w) = roots_laguerre(10).unwrap();
+        assert_relative_eq!(x[0], 0.13779347054049243, max_relative = 1e-14);
+        assert_relative_eq!(w[9], 9.911827219609008e-13, max_relative = 1e-12);
+        let (x, w) = roots_hermite(100).unwrap();
+        assert_relative_eq!(x[99], 13.40648733814491, max_relative = 1e-14);
+        assert_relative_eq!(w[99], 5.908067865031207e-79, max_relative = 1e-11);
+    }
+}
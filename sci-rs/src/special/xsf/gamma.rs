@@ -0,0 +1,456 @@
+use super::polevl;
+use core::f64::consts::PI;
+use num_traits::Float;
+
+// Lanczos approximation with g = 7 and n = 9, accurate to about 15 digits for x >= 1/2.
+const LANCZOS_G: f64 = 7.;
+#[allow(clippy::excessive_precision)]
+const LANCZOS_COEFFS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Largest argument of the gamma function which does not overflow.
+const MAXGAM: f64 = 171.624_376_956_302_7;
+/// Euler-Mascheroni constant.
+#[allow(clippy::excessive_precision)]
+const EULER: f64 = 0.577215664901532860606512090082402431;
+/// `ln(sqrt(2 pi))`.
+#[allow(clippy::excessive_precision)]
+const LS2PI: f64 = 0.91893853320467274178;
+
+// Cephes rational approximation of ln(gamma(2 + x)) / x on [0, 1].
+#[allow(clippy::excessive_precision)]
+const LGAM_B: [f64; 6] = [
+    -1.37825152569120859100E3,
+    -3.88016315134637840924E4,
+    -3.31612992738871184744E5,
+    -1.16237097492762307383E6,
+    -1.72173700820839662146E6,
+    -8.53555664245765465627E5,
+];
+#[allow(clippy::excessive_precision)]
+const LGAM_C: [f64; 7] = [
+    1.,
+    -3.51815701436523470549E2,
+    -1.70642106651881159223E4,
+    -2.20528590553854454839E5,
+    -1.13933444367982507207E6,
+    -2.53252307177582951285E6,
+    -2.01889141433532773231E6,
+];
+// Cephes correction of the Stirling series of ln(gamma(x)) for x >= 13.
+#[allow(clippy::excessive_precision)]
+const LGAM_A: [f64; 5] = [
+    8.11614167470508450300E-4,
+    -5.95061904284301438324E-4,
+    7.93650340457716943945E-4,
+    -2.77777777730099687205E-3,
+    8.33333333333331927722E-2,
+];
+
+// zeta(k) for k = 2, 3, ..., the coefficients of the Taylor series of ln(gamma(1 + x)).
+#[allow(clippy::excessive_precision)]
+const ZETA: [f64; 25] = [
+    1.6449340668482264365,
+    1.2020569031595942854,
+    1.0823232337111381915,
+    1.0369277551433699263,
+    1.0173430619844491397,
+    1.0083492773819228268,
+    1.0040773561979443394,
+    1.0020083928260822144,
+    1.0009945751278180853,
+    1.0004941886041194646,
+    1.0002460865533080483,
+    1.0001227133475784891,
+    1.0000612481350587048,
+    1.0000305882363070205,
+    1.0000152822594086519,
+    1.0000076371976378998,
+    1.0000038172932649998,
+    1.0000019082127165539,
+    1.0000009539620338728,
+    1.0000004769329867878,
+    1.0000002384505027277,
+    1.0000001192199259653,
+    1.0000000596081890513,
+    1.0000000298035035147,
+    1.0000000149015548284,
+];
+
+// The positive zero of the digamma function, split in two doubles.
+const DIGAMMA_ROOT: (f64, f64) = (1.4616321449683622, 9.549995429965697e-17);
+// Hurwitz zeta(k + 1, root) for k = 1, 2, ..., the coefficients of the Taylor series of the
+// digamma function at its positive zero.
+#[allow(clippy::excessive_precision)]
+const DIGAMMA_ZETA: [f64; 30] = [
+    0.96767224544762117043,
+    0.44276316898359210609,
+    0.25849976095565101062,
+    0.1639427054424065275,
+    0.10782405069126236576,
+    0.072199561256454710926,
+    0.048804288164143107225,
+    0.033161126474847359292,
+    0.02259764823221810466,
+    0.015424765904948959139,
+    0.010538791616612175388,
+    0.007204534386356868241,
+    0.0049267813957298534464,
+    0.0033698016554393280828,
+    0.0023051263267349278369,
+    0.0015769367714301972593,
+    0.0010788252019162965807,
+    0.00073807093899600512957,
+    0.00050495326583460203518,
+    0.00034546802510630769956,
+    0.00023635601564027052792,
+    0.00016170622091974803449,
+    0.00011063372768747410904,
+    0.000075691795821950659192,
+    0.00005178575795222080869,
+    0.000035430070947659606316,
+    0.000024240066118601317653,
+    0.000016584242271854133375,
+    0.000011346384584663849807,
+    7.7628176684620944253e-6,
+];
+// B_2k / 2k, the coefficients of the asymptotic series of the digamma function, from the
+// highest degree.
+const DIGAMMA_A: [f64; 7] = [
+    1. / 12.,
+    -691. / 32760.,
+    1. / 132.,
+    -1. / 240.,
+    1. / 252.,
+    -1. / 120.,
+    1. / 12.,
+];
+
+/// Lanczos sum and `t = x + g - 1/2` for `x >= 1/2`.
+fn lanczos(x: f64) -> (f64, f64) {
+    let x = x - 1.;
+    let series = LANCZOS_COEFFS[1..]
+        .iter()
+        .enumerate()
+        .fold(LANCZOS_COEFFS[0], |acc, (i, &c)| {
+            acc + c / (x + i as f64 + 1.)
+        });
+    (series, x + LANCZOS_G + 0.5)
+}
+
+/// `sin(pi x)`, exact at the integers.
+pub(crate) fn sinpi(x: f64) -> f64 {
+    let r = x.rem_euclid(2.);
+    let (sign, r) = if r > 1. { (-1., r - 1.) } else { (1., r) };
+    sign * (PI * r.min(1. - r)).sin()
+}
+
+/// Whether `x` is a pole of the gamma function.
+fn is_pole(x: f64) -> bool {
+    x <= 0. && x == x.floor()
+}
+
+/// Gamma function, with the reflection formula for `x < 1/2`. Poles at the non-positive integers
+/// give NaN.
+pub(crate) fn gamma(x: f64) -> f64 {
+    if is_pole(x) {
+        return f64::NAN;
+    }
+    if x < 0.5 {
+        return PI / (sinpi(x) * gamma(1. - x));
+    }
+    if x > MAXGAM {
+        return f64::INFINITY;
+    }
+    let (series, t) = lanczos(x);
+    // Split the power so that it does not overflow before the exponential brings it down.
+    let p = t.powf((x - 0.5) / 2.);
+    (2. * PI).sqrt() * p * (p * (-t).exp()) * series
+}
+
+/// Sign of the gamma function, 0 at its poles.
+pub(crate) fn gammasgn(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x > 0. {
+        1.
+    } else if x == x.floor() {
+        0.
+    } else if x.floor() % 2. == 0. {
+        1.
+    } else {
+        -1.
+    }
+}
+
+/// `ln(gamma(1 + x))` for `|x| <= 1/2`, from its Taylor series.
+fn lgam1p_taylor(x: f64) -> f64 {
+    let mut res = -EULER * x;
+    let mut xfac = -x;
+    for (n, &zeta) in ZETA.iter().enumerate() {
+        xfac *= -x;
+        let term = zeta * xfac / (n + 2) as f64;
+        res += term;
+        if term.abs() < f64::EPSILON * res.abs() {
+            break;
+        }
+    }
+    res
+}
+
+/// Natural logarithm of the absolute value of the gamma function. Poles at the non-positive
+/// integers give infinity.
+pub(crate) fn gammaln(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x.is_infinite() || is_pole(x) {
+        return f64::INFINITY;
+    }
+    if x < 0. {
+        // Reflection, ln|gamma(x)| = ln(pi / |sin(pi x)|) - ln(gamma(1 - x)).
+        return PI.ln() - sinpi(x).abs().ln() - gammaln(1. - x);
+    }
+    // The Taylor series near the zeros at 1 and 2 keep the relative precision.
+    if (x - 1.).abs() < 0.2 {
+        return lgam1p_taylor(x - 1.);
+    }
+    if (x - 2.).abs() < 0.2 {
+        return (x - 1.).ln() + lgam1p_taylor(x - 2.);
+    }
+    if x < 13. {
+        // Shift into [2, 3) with the recurrence.
+        let (mut z, mut u) = (1., x);
+        while u >= 3. {
+            u -= 1.;
+            z *= u;
+        }
+        while u < 2. {
+            z /= u;
+            u += 1.;
+        }
+        let u = u - 2.;
+        return z.ln() + u * polevl(u, &LGAM_B) / polevl(u, &LGAM_C);
+    }
+    let q = (x - 0.5) * x.ln() - x + LS2PI;
+    if x > 1e8 {
+        return q;
+    }
+    q + polevl(1. / (x * x), &LGAM_A) / x
+}
+
+/// Digamma function, the logarithmic derivative of the gamma function. It is NaN at the negative
+/// integers, and infinite at zero.
+pub(crate) fn digamma(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY {
+        return f64::NAN;
+    }
+    if x == 0. {
+        return if x.is_sign_negative() {
+            f64::INFINITY
+        } else {
+            f64::NEG_INFINITY
+        };
+    }
+    if is_pole(x) {
+        return f64::NAN;
+    }
+    if x < 0. {
+        // Reflection, psi(x) = psi(1 - x) - pi / tan(pi x).
+        let r = x - x.floor();
+        return digamma(1. - x) - PI / (PI * r).tan();
+    }
+    let dx = (x - DIGAMMA_ROOT.0) - DIGAMMA_ROOT.1;
+    if dx.abs() < 0.3 {
+        let (mut res, mut coeff) = (0., -1.);
+        for &zeta in DIGAMMA_ZETA.iter() {
+            coeff *= -dx;
+            let term = coeff * zeta;
+            res += term;
+            if term.abs() < f64::EPSILON * res.abs() {
+                break;
+            }
+        }
+        return res;
+    }
+    if x <= 10. && x == x.floor() {
+        return (1..x as usize).fold(-EULER, |acc, k| acc + 1. / k as f64);
+    }
+    let (mut x, mut w) = (x, 0.);
+    while x < 10. {
+        w += 1. / x;
+        x += 1.;
+    }
+    let y = if x < 1e17 {
+        let z = 1. / (x * x);
+        z * polevl(z, &DIGAMMA_A)
+    } else {
+        0.
+    };
+    x.ln() - 0.5 / x - y - w
+}
+
+/// Beta function `gamma(a) gamma(b) / gamma(a + b)`.
+pub(crate) fn beta(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        return f64::NAN;
+    }
+    if is_pole(a) {
+        return beta_negint(a, b);
+    }
+    if is_pole(b) {
+        return beta_negint(b, a);
+    }
+    let (a, b) = if a.abs() < b.abs() { (b, a) } else { (a, b) };
+    if a.abs() > 1e6 * b.abs() && a > 1e6 {
+        let (y, sign) = lbeta_asymp(a, b);
+        return sign * y.exp();
+    }
+    let y = a + b;
+    if y.abs() > MAXGAM || a.abs() > MAXGAM || b.abs() > MAXGAM {
+        let sign = gammasgn(a) * gammasgn(b) * gammasgn(y);
+        return sign * (gammaln(a) + gammaln(b) - gammaln(y)).exp();
+    }
+    let (y, a, b) = (gamma(y), gamma(a), gamma(b));
+    if y == 0. {
+        return f64::INFINITY;
+    }
+    // Divide the value closest to gamma(a + b) first, against overflow.
+    if (a - y).abs() > (b - y).abs() {
+        b / y * a
+    } else {
+        a / y * b
+    }
+}
+
+/// Natural logarithm of the absolute value of the beta function.
+pub(crate) fn betaln(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        return f64::NAN;
+    }
+    if is_pole(a) {
+        return beta_negint(a, b).abs().ln();
+    }
+    if is_pole(b) {
+        return beta_negint(b, a).abs().ln();
+    }
+    let (a, b) = if a.abs() < b.abs() { (b, a) } else { (a, b) };
+    if a.abs() > 1e6 * b.abs() && a > 1e6 {
+        return lbeta_asymp(a, b).0;
+    }
+    let y = a + b;
+    if y.abs() > MAXGAM || a.abs() > MAXGAM || b.abs() > MAXGAM {
+        return gammaln(a) + gammaln(b) - gammaln(y);
+    }
+    beta(a, b).abs().ln()
+}
+
+/// `ln|beta(a, b)|` and the sign of `beta(a, b)` for `a` large compared to `b`, from the
+/// asymptotic expansion of `gamma(a) / gamma(a + b)`.
+fn lbeta_asymp(a: f64, b: f64) -> (f64, f64) {
+    let mut r = gammaln(b) - b * a.ln();
+    r += b * (1. - b) / (2. * a);
+    r += b * (1. - b) * (1. - 2. * b) / (12. * a * a);
+    r += -b * b * (1. - b) * (1. - b) / (12. * a * a * a);
+    (r, gammasgn(b))
+}
+
+/// Beta function at the pole `a` of the gamma function, which has a finite limit if `b` and
+/// `1 - a - b` are positive integers, and is infinite otherwise.
+fn beta_negint(a: f64, b: f64) -> f64 {
+    if b == b.floor() && 1. - a - b > 0. {
+        let sign = if b % 2. == 0. { 1. } else { -1. };
+        sign * beta(1. - a - b, b)
+    } else {
+        f64::INFINITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn gamma_function() {
+        assert_relative_eq!(gamma(1.), 1., max_relative = 1e-14);
+        assert_relative_eq!(gamma(5.), 24., max_relative = 1e-14);
+        assert_relative_eq!(gamma(0.5), PI.sqrt(), max_relative = 1e-14);
+        assert_relative_eq!(gamma(-0.5), -2. * PI.sqrt(), max_relative = 1e-14);
+        assert_relative_eq!(gamma(171.), 7.257415615307998e306, max_relative = 1e-12);
+        assert!(gamma(-2.).is_nan());
+
+        assert_relative_eq!(gammaln(0.5), PI.sqrt().ln(), max_relative = 1e-14);
+        assert_relative_eq!(gammaln(100.), 359.1342053695754, max_relative = 1e-14);
+        assert_relative_eq!(gammaln(1e10), 220258509288.81058, max_relative = 1e-14);
+        assert_relative_eq!(gammaln(-2.5), -0.05624371649767457, max_relative = 1e-12);
+        assert_eq!(gammaln(0.), f64::INFINITY);
+        assert_relative_eq!(betaln(2., 3.), (1f64 / 12.).ln(), max_relative = 1e-14);
+    }
+
+    #[test]
+    fn log_gamma_near_zeros() {
+        // From mpmath.loggamma.
+        assert_relative_eq!(
+            gammaln(1.0001),
+            -5.771334222047127e-05,
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(gammaln(0.99), 0.005854806764709781, max_relative = 1e-14);
+        assert_relative_eq!(
+            gammaln(1.9999),
+            -4.227520877215346e-05,
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(
+            gammaln(2.0001),
+            4.2281658112919945e-05,
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(gammaln(13.1), 20.240212723401434, max_relative = 1e-15);
+        assert_eq!(gammaln(1.), 0.);
+        assert_eq!(gammaln(2.), 0.);
+    }
+
+    #[test]
+    fn digamma_function() {
+        assert_relative_eq!(digamma(1.), -EULER, max_relative = 1e-15);
+        assert_relative_eq!(digamma(0.5), -EULER - 2. * 2f64.ln(), max_relative = 1e-15);
+        assert_relative_eq!(digamma(1.5), 0.03648997397857652, max_relative = 1e-14);
+        assert_relative_eq!(digamma(1.46), -0.001580561987083452, max_relative = 1e-13);
+        assert_relative_eq!(digamma(-2.5), 1.103_156_640_645_243, max_relative = 1e-14);
+        assert_relative_eq!(digamma(30.), 3.384438132685525, max_relative = 1e-15);
+        assert!(digamma(-3.).is_nan());
+        assert_eq!(digamma(0.), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn beta_function() {
+        assert_relative_eq!(beta(2., 3.), 1. / 12., max_relative = 1e-14);
+        assert_relative_eq!(beta(0.5, 0.5), PI, max_relative = 1e-14);
+        assert_relative_eq!(beta(-0.5, 2.), -4., max_relative = 1e-14);
+        assert_relative_eq!(beta(-3., 2.), 1. / 6., max_relative = 1e-14);
+        assert_eq!(beta(-3., 0.5), f64::INFINITY);
+        assert_relative_eq!(
+            beta(200., 300.),
+            1.6485491608664746e-147,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(betaln(1e8, 2.), -36.84136149790473, max_relative = 1e-14);
+        assert_relative_eq!(
+            betaln(200., 300.),
+            -337.98011306546467,
+            max_relative = 1e-13
+        );
+    }
+}
@@ -74,6 +74,58 @@ const I0_B_F64: [f64; 25] = [
 ];
 
 impl Bessel for f64 {
+    fn j0(&self) -> Self {
+        xsf::jv(0., *self)
+    }
+
+    fn j1(&self) -> Self {
+        xsf::jv(1., *self)
+    }
+
+    fn jn(&self, n: i32) -> Self {
+        xsf::jv(n as f64, *self)
+    }
+
+    fn jv(&self, v: f64) -> Self {
+        xsf::jv(v, *self)
+    }
+
+    fn y0(&self) -> Self {
+        xsf::yv(0., *self)
+    }
+
+    fn y1(&self) -> Self {
+        xsf::yv(1., *self)
+    }
+
+    fn yn(&self, n: i32) -> Self {
+        xsf::yv(n as f64, *self)
+    }
+
+    fn yv(&self, v: f64) -> Self {
+        xsf::yv(v, *self)
+    }
+
+    fn i1(&self) -> Self {
+        xsf::iv(1., *self)
+    }
+
+    fn iv(&self, v: f64) -> Self {
+        xsf::iv(v, *self)
+    }
+
+    fn k0(&self) -> Self {
+        xsf::kv(0., *self)
+    }
+
+    fn k1(&self) -> Self {
+        xsf::kv(1., *self)
+    }
+
+    fn kv(&self, v: f64) -> Self {
+        xsf::kv(v, *self)
+    }
+
     fn i0(&self) -> Self {
         let x = self.abs();
         if x <= 8. {
@@ -165,6 +217,58 @@ const I0_B_F32: [f32; 25] = [
 ];
 
 impl Bessel for f32 {
+    fn j0(&self) -> Self {
+        xsf::jv(0., *self as f64) as f32
+    }
+
+    fn j1(&self) -> Self {
+        xsf::jv(1., *self as f64) as f32
+    }
+
+    fn jn(&self, n: i32) -> Self {
+        xsf::jv(n as f64, *self as f64) as f32
+    }
+
+    fn jv(&self, v: f64) -> Self {
+        xsf::jv(v, *self as f64) as f32
+    }
+
+    fn y0(&self) -> Self {
+        xsf::yv(0., *self as f64) as f32
+    }
+
+    fn y1(&self) -> Self {
+        xsf::yv(1., *self as f64) as f32
+    }
+
+    fn yn(&self, n: i32) -> Self {
+        xsf::yv(n as f64, *self as f64) as f32
+    }
+
+    fn yv(&self, v: f64) -> Self {
+        xsf::yv(v, *self as f64) as f32
+    }
+
+    fn i1(&self) -> Self {
+        xsf::iv(1., *self as f64) as f32
+    }
+
+    fn iv(&self, v: f64) -> Self {
+        xsf::iv(v, *self as f64) as f32
+    }
+
+    fn k0(&self) -> Self {
+        xsf::kv(0., *self as f64) as f32
+    }
+
+    fn k1(&self) -> Self {
+        xsf::kv(1., *self as f64) as f32
+    }
+
+    fn kv(&self, v: f64) -> Self {
+        xsf::kv(v, *self as f64) as f32
+    }
+
     fn i0(&self) -> Self {
         let x = self.abs();
         if x <= 8. {
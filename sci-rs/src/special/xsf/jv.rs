@@ -0,0 +1,386 @@
+//! Bessel functions of the first and second kind of real order, following the method of Temme
+//! for small arguments and of Steed for larger ones, as in Numerical Recipes, Section 6.7.
+
+use super::{gammasgn, sinpi};
+use core::f64::consts::{FRAC_2_PI, PI};
+use num_traits::Float;
+
+pub(super) const EPS: f64 = f64::EPSILON;
+/// Floor of the modified Lentz algorithm for the continued fractions.
+pub(super) const FPMIN: f64 = 1e-300;
+/// Starting value of the backward recurrences, rescaled when it grows past `RESCALE`.
+pub(super) const START: f64 = 1e-30;
+pub(super) const RESCALE: f64 = 1e250;
+pub(super) const MAXIT: usize = 1_000_000;
+/// Below this argument the Temme series is used, above the continued fraction of Steed.
+pub(super) const XMIN: f64 = 2.;
+
+// Taylor coefficients of 1 / gamma(1 + x) at 0.
+#[allow(clippy::excessive_precision)]
+const RGAMMA_TAYLOR: [f64; 27] = [
+    1.,
+    0.57721566490153286061,
+    -0.65587807152025388108,
+    -0.042002635034095235529,
+    0.1665386113822914895,
+    -0.042197734555544336748,
+    -0.0096219715278769735621,
+    0.0072189432466630995424,
+    -0.0011651675918590651121,
+    -0.00021524167411495097282,
+    0.00012805028238811618615,
+    -0.000020134854780788238656,
+    -1.2504934821426706573e-6,
+    1.1330272319816958824e-6,
+    -2.0563384169776071035e-7,
+    6.1160951044814158179e-9,
+    5.0020076444692229301e-9,
+    -1.1812745704870201446e-9,
+    1.0434267116911005105e-10,
+    7.782263439905071254e-12,
+    -3.6968056186422057082e-12,
+    5.100370287454475979e-13,
+    -2.0583260535665067832e-14,
+    -5.3481225394230179824e-15,
+    1.2267786282382607902e-15,
+    -1.1812593016974587695e-16,
+    1.1866922547516003326e-18,
+];
+
+/// The gamma function terms of the Temme series for `|mu| <= 1/2`: `(1 / gamma(1 - mu) - 1 /
+/// gamma(1 + mu)) / (2 mu)`, `(1 / gamma(1 - mu) + 1 / gamma(1 + mu)) / 2`, `1 / gamma(1 + mu)`
+/// and `1 / gamma(1 - mu)`.
+pub(super) fn temme_gamma(mu: f64) -> (f64, f64, f64, f64) {
+    let (mut even, mut odd) = (0., 0.);
+    for (k, &c) in RGAMMA_TAYLOR.iter().enumerate().rev() {
+        if k % 2 == 0 {
+            even = even * mu * mu + c;
+        } else {
+            odd = odd * mu * mu + c;
+        }
+    }
+    (-odd, even, even + mu * odd, even - mu * odd)
+}
+
+/// `cos(pi x)`, exact at the half-integers.
+pub(super) fn cospi(x: f64) -> f64 {
+    sinpi(x + 0.5)
+}
+
+/// Hankel's asymptotic expansion of `J_nu(x)` and `Y_nu(x)`, for `x` large compared to `nu^2`.
+fn jy_asymptotic(nu: f64, x: f64) -> (f64, f64) {
+    let mu = 4. * nu * nu;
+    let (mut p, mut q) = (1., 0.);
+    let mut term = 1f64;
+    for k in 1..MAXIT {
+        let odd = (2 * k - 1) as f64;
+        let next = term * (mu - odd * odd) / (k as f64 * 8. * x);
+        if next.abs() > term.abs() && k > 2 {
+            // The series diverges from here.
+            break;
+        }
+        term = next;
+        let sign = if (k / 2) % 2 == 0 { 1. } else { -1. };
+        if k % 2 == 0 {
+            p += sign * term;
+        } else {
+            q += sign * term;
+        }
+        if term.abs() < EPS * (p.abs() + q.abs()) {
+            break;
+        }
+    }
+    // cos and sin of x - (nu / 2 + 1 / 4) pi, expanded to keep the precision of those of x.
+    let (sin_phi, cos_phi) = (sinpi(nu / 2. + 0.25), cospi(nu / 2. + 0.25));
+    let (sin_x, cos_x) = x.sin_cos();
+    let cos_chi = cos_x * cos_phi + sin_x * sin_phi;
+    let sin_chi = sin_x * cos_phi - cos_x * sin_phi;
+    let scale = (FRAC_2_PI / x).sqrt();
+    (
+        scale * (p * cos_chi - q * sin_chi),
+        scale * (p * sin_chi + q * cos_chi),
+    )
+}
+
+/// `J_nu(x)` and `Y_nu(x)` for `nu >= 0` and `x > 0`.
+fn jy(nu: f64, x: f64) -> (f64, f64) {
+    if x > 20. && nu * nu < 1.5 * x {
+        return jy_asymptotic(nu, x);
+    }
+    let nl = if x < XMIN {
+        (nu + 0.5) as usize
+    } else {
+        (nu - x + 1.5).max(0.) as usize
+    };
+    let xmu = nu - nl as f64;
+    let xmu2 = xmu * xmu;
+    let xi = 1. / x;
+    let xi2 = 2. * xi;
+    let w = xi2 / PI;
+
+    // CF1, J'_nu / J_nu by the modified Lentz algorithm, and the sign of J_nu.
+    let mut isign = 1.;
+    let mut h = (nu * xi).max(FPMIN);
+    let mut d = 0.;
+    let mut c = h;
+    for i in 1..MAXIT {
+        // Computed afresh, as the accumulated sum loses precision over the O(x) iterations.
+        let b = xi2 * (nu + i as f64);
+        d = b - d;
+        if d.abs() < FPMIN {
+            d = FPMIN;
+        }
+        c = b - 1. / c;
+        if c.abs() < FPMIN {
+            c = FPMIN;
+        }
+        d = 1. / d;
+        let del = c * d;
+        h *= del;
+        if d < 0. {
+            isign = -isign;
+        }
+        if (del - 1.).abs() < EPS {
+            break;
+        }
+    }
+
+    // Downward recurrence of unnormalized J and J' to the order mu.
+    let mut rjl = isign * START;
+    let mut rjpl = h * rjl;
+    let (mut rjl1, mut rjp1) = (rjl, rjpl);
+    for l in 0..nl {
+        let rjtemp = (nu - l as f64) * xi * rjl + rjpl;
+        let fact = (nu - l as f64 - 1.) * xi;
+        rjpl = fact * rjtemp - rjl;
+        rjl = rjtemp;
+        if rjl.abs() > RESCALE {
+            rjl /= RESCALE;
+            rjpl /= RESCALE;
+            rjl1 /= RESCALE;
+            rjp1 /= RESCALE;
+        }
+    }
+    if rjl == 0. {
+        rjl = EPS;
+    }
+    let f = rjpl / rjl;
+
+    let (rjmu, rymu, mut ry1) = if x < XMIN {
+        // Temme's series for Y_mu and Y_mu+1, then J_mu from the Wronskian.
+        let x2 = 0.5 * x;
+        let pimu = PI * xmu;
+        let fact = if pimu.abs() < EPS {
+            1.
+        } else {
+            pimu / pimu.sin()
+        };
+        let d = -x2.ln();
+        let e = xmu * d;
+        let fact2 = if e.abs() < EPS { 1. } else { e.sinh() / e };
+        let (gam1, gam2, gampl, gammi) = temme_gamma(xmu);
+        let mut ff = FRAC_2_PI * fact * (gam1 * e.cosh() + gam2 * fact2 * d);
+        let e = e.exp();
+        let mut p = e / (gampl * PI);
+        let mut q = 1. / (e * PI * gammi);
+        let pimu2 = 0.5 * pimu;
+        let fact3 = if pimu2.abs() < EPS {
+            1.
+        } else {
+            pimu2.sin() / pimu2
+        };
+        let r = PI * pimu2 * fact3 * fact3;
+        let mut c = 1.;
+        let d = -x2 * x2;
+        let mut sum = ff + r * q;
+        let mut sum1 = p;
+        for i in 1..MAXIT {
+            let i = i as f64;
+            ff = (i * ff + p + q) / (i * i - xmu2);
+            c *= d / i;
+            p /= i - xmu;
+            q /= i + xmu;
+            let del = c * (ff + r * q);
+            sum += del;
+            sum1 += c * p - i * del;
+            if del.abs() < (1. + sum.abs()) * EPS {
+                break;
+            }
+        }
+        let rymu = -sum;
+        let ry1 = -sum1 * xi2;
+        let rymup = xmu * xi * rymu - ry1;
+        (w / (rymup - f * rymu), rymu, ry1)
+    } else {
+        // CF2, p + iq = (J'_mu + iY'_mu) / (J_mu + iY_mu) by the modified Lentz algorithm.
+        let mut a = 0.25 - xmu2;
+        let mut p = -0.5 * xi;
+        let mut q = 1.;
+        let br = 2. * x;
+        let mut bi = 2.;
+        let mut fact = a * xi / (p * p + q * q);
+        let mut cr = br + q * fact;
+        let mut ci = bi + p * fact;
+        let mut den = br * br + bi * bi;
+        let mut dr = br / den;
+        let mut di = -bi / den;
+        let mut dlr = cr * dr - ci * di;
+        let mut dli = cr * di + ci * dr;
+        let mut temp = p * dlr - q * dli;
+        q = p * dli + q * dlr;
+        p = temp;
+        for i in 2..MAXIT {
+            a += (2 * (i - 1)) as f64;
+            bi += 2.;
+            dr = a * dr + br;
+            di = a * di + bi;
+            if dr.abs() + di.abs() < FPMIN {
+                dr = FPMIN;
+            }
+            fact = a / (cr * cr + ci * ci);
+            cr = br + cr * fact;
+            ci = bi - ci * fact;
+            if cr.abs() + ci.abs() < FPMIN {
+                cr = FPMIN;
+            }
+            den = dr * dr + di * di;
+            dr /= den;
+            di /= -den;
+            dlr = cr * dr - ci * di;
+            dli = cr * di + ci * dr;
+            temp = p * dlr - q * dli;
+            q = p * dli + q * dlr;
+            p = temp;
+            if (dlr - 1.).abs() + dli.abs() < EPS {
+                break;
+            }
+        }
+        let gam = (p - f) / q;
+        let rjmu = (w / ((p - f) * gam + q)).sqrt().copysign(rjl);
+        let rymu = rjmu * gam;
+        let rymup = rymu * (p + q / gam);
+        (rjmu, rymu, xmu * xi * rymu - rymup)
+    };
+
+    // Normalize J, and recur Y upwards to the order nu.
+    let rj = rjl1 * (rjmu / rjl);
+    let mut ry = rymu;
+    for i in 1..=nl {
+        let rytemp = (xmu + i as f64) * xi2 * ry1 - ry;
+        ry = ry1;
+        ry1 = rytemp;
+    }
+    (rj, ry)
+}
+
+/// Whether `v` is an integer, and its parity.
+pub(super) fn integer_parity(v: f64) -> Option<f64> {
+    (v == v.floor()).then(|| if v % 2. == 0. { 1. } else { -1. })
+}
+
+/// Bessel function of the first kind of real order `v`.
+pub(crate) fn jv(v: f64, x: f64) -> f64 {
+    if v.is_nan() || x.is_nan() {
+        return f64::NAN;
+    }
+    if v < 0. {
+        if let Some(sign) = integer_parity(v) {
+            return sign * jv(-v, x);
+        }
+    }
+    if x < 0. {
+        // Real only for the integer orders.
+        return match integer_parity(v) {
+            Some(sign) => sign * jv(v, -x),
+            None => f64::NAN,
+        };
+    }
+    if x == 0. {
+        return if v == 0. {
+            1.
+        } else if v > 0. {
+            0.
+        } else {
+            gammasgn(1. + v) * f64::INFINITY
+        };
+    }
+    if x.is_infinite() {
+        return 0.;
+    }
+    if v < 0. {
+        // J_-nu = cos(nu pi) J_nu - sin(nu pi) Y_nu.
+        let (j, y) = jy(-v, x);
+        return cospi(v) * j + sinpi(v) * y;
+    }
+    jy(v, x).0
+}
+
+/// Bessel function of the second kind of real order `v`, NaN for negative `x`.
+pub(crate) fn yv(v: f64, x: f64) -> f64 {
+    if v.is_nan() || x.is_nan() || x < 0. {
+        return f64::NAN;
+    }
+    if v < 0. {
+        if let Some(sign) = integer_parity(v) {
+            return sign * yv(-v, x);
+        }
+    }
+    if x == 0. {
+        return f64::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return 0.;
+    }
+    if v < 0. {
+        // Y_-nu = sin(nu pi) J_nu + cos(nu pi) Y_nu.
+        let (j, y) = jy(-v, x);
+        return -sinpi(v) * j + cospi(v) * y;
+    }
+    jy(v, x).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn temme_gamma_terms() {
+        use super::super::gamma;
+        let mu = 0.3;
+        let (gam1, gam2, gampl, gammi) = temme_gamma(mu);
+        assert_relative_eq!(gampl, 1. / gamma(1. + mu), max_relative = 1e-15);
+        assert_relative_eq!(gammi, 1. / gamma(1. - mu), max_relative = 1e-15);
+        assert_relative_eq!(gam1, (gammi - gampl) / (2. * mu), max_relative = 1e-13);
+        assert_relative_eq!(gam2, (gammi + gampl) / 2., max_relative = 1e-15);
+    }
+
+    #[test]
+    fn bessel_j() {
+        // From mpmath.besselj.
+        assert_relative_eq!(jv(0., 1.), 0.7651976865579666, max_relative = 1e-15);
+        assert_relative_eq!(jv(1., 10.), 0.04347274616886144, max_relative = 1e-14);
+        assert_relative_eq!(jv(2.5, 0.1), 1.680887190033413e-4, max_relative = 1e-14);
+        assert_relative_eq!(jv(50., 1.), 2.9060049481732392e-80, max_relative = 1e-13);
+        assert_relative_eq!(jv(-1.5, 3.), 0.08700809072083528, max_relative = 1e-14);
+        assert_relative_eq!(jv(3., -2.), -0.12894324947440206, max_relative = 1e-14);
+        assert_relative_eq!(jv(0.5, 5000.), -0.011148007472939753, max_relative = 1e-12);
+        assert_relative_eq!(jv(100., 150.), -0.015359526118405391, max_relative = 1e-12);
+        assert_eq!(jv(0., 0.), 1.);
+        assert_eq!(jv(2., 0.), 0.);
+        assert!(jv(0.5, -1.).is_nan());
+    }
+
+    #[test]
+    fn bessel_y() {
+        // From mpmath.bessely.
+        assert_relative_eq!(yv(0., 1.), 0.08825696421567696, max_relative = 1e-14);
+        assert_relative_eq!(yv(1., 0.01), -63.67859628206065, max_relative = 1e-14);
+        assert_relative_eq!(yv(3., 20.), 0.1496732627133941, max_relative = 1e-13);
+        assert_relative_eq!(yv(-2., 3.), -0.16040039348492374, max_relative = 1e-14);
+        assert_relative_eq!(yv(-0.5, 2.), 0.5130161365618278, max_relative = 1e-14);
+        assert_relative_eq!(yv(1., 3000.), 0.007793896749908748, max_relative = 1e-12);
+        assert_eq!(yv(1., 0.), f64::NEG_INFINITY);
+        assert!(yv(1., -1.).is_nan());
+    }
+}
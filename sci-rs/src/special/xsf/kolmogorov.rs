@@ -0,0 +1,93 @@
+use core::f64::consts::PI;
+use num_traits::Float;
+
+/// Complementary cumulative distribution function of the Kolmogorov distribution, the
+/// limiting distribution of `sqrt(n) D_n` for the two-sided Kolmogorov-Smirnov statistic `D_n`.
+pub(crate) fn kolmogorov(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x <= 0. {
+        return 1.;
+    }
+    if x < 1. {
+        // The theta function form of the distribution converges quickly for small x.
+        let w = -PI * PI / (8. * x * x);
+        let mut cdf = 0.;
+        for k in 1..100 {
+            let term = (w * ((2 * k - 1) * (2 * k - 1)) as f64).exp();
+            cdf += term;
+            if term <= cdf * f64::EPSILON {
+                break;
+            }
+        }
+        return 1. - (2. * PI).sqrt() / x * cdf;
+    }
+    let mut sf = 0.;
+    for k in 1..100 {
+        let term = (-2. * (k * k) as f64 * x * x).exp();
+        sf += if k % 2 == 1 { term } else { -term };
+        if term <= sf * f64::EPSILON {
+            break;
+        }
+    }
+    2. * sf
+}
+
+/// Complementary cumulative distribution function of the one-sided Kolmogorov-Smirnov
+/// statistic `D_n^+` for `n` samples, from the exact formula of Birnbaum and Tingey.
+pub(crate) fn smirnov(n: usize, d: f64) -> f64 {
+    if d.is_nan() || n == 0 {
+        return f64::NAN;
+    }
+    if d <= 0. {
+        return 1.;
+    }
+    if d >= 1. {
+        return 0.;
+    }
+    let nf = n as f64;
+    // d * sum_j binom(n, j) (1 - d - j/n)^(n-j) (d + j/n)^(j-1), in log space.
+    let mut lbinom = 0.;
+    let mut sum = 0.;
+    for j in 0..n {
+        let a = ((n - j) as f64 - nf * d) / nf;
+        if a <= 0. {
+            break;
+        }
+        let b = d + j as f64 / nf;
+        sum += (lbinom + (n - j) as f64 * a.ln() + (j as f64 - 1.) * b.ln()).exp();
+        lbinom += ((n - j) as f64 / (j + 1) as f64).ln();
+    }
+    (d * sum).min(1.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn kolmogorov_smirnov() {
+        assert_relative_eq!(kolmogorov(0.3), 0.9999906941986654, max_relative = 1e-14);
+        assert_relative_eq!(kolmogorov(0.5), 0.9639452436648751, max_relative = 1e-14);
+        assert_relative_eq!(kolmogorov(1.2), 0.11224966667072498, max_relative = 1e-14);
+        assert_relative_eq!(kolmogorov(3.), 3.045995948942526e-8, max_relative = 1e-13);
+        assert_eq!(kolmogorov(0.), 1.);
+
+        assert_relative_eq!(smirnov(10, 0.3), 0.1354635556, max_relative = 1e-13);
+        assert_relative_eq!(
+            smirnov(1000, 0.05),
+            0.006506037390545166,
+            max_relative = 1e-11
+        );
+        assert_relative_eq!(smirnov(5, 0.9), 1e-5, max_relative = 1e-13);
+        assert_relative_eq!(
+            smirnov(100, 0.001),
+            0.9988959883139653,
+            max_relative = 1e-13
+        );
+        assert_relative_eq!(smirnov(1, 0.25), 0.75, max_relative = 1e-15);
+        assert_eq!(smirnov(10, 1.), 0.);
+    }
+}
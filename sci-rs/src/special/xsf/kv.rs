@@ -0,0 +1,227 @@
+//! Modified Bessel functions of the first and second kind of real order, following the method of
+//! Temme for small arguments and of Steed for larger ones, as in Numerical Recipes, Section 6.7.
+
+use super::jv::{integer_parity, temme_gamma, EPS, FPMIN, MAXIT, RESCALE, START, XMIN};
+use super::{gammasgn, sinpi};
+use core::f64::consts::{FRAC_2_PI, PI};
+use num_traits::Float;
+
+/// `exp(x) K_mu(x)` and `exp(x) K_mu+1(x)` for `|mu| <= 1/2` and `x > 0`.
+fn k_mu_scaled(xmu: f64, x: f64) -> (f64, f64) {
+    let xmu2 = xmu * xmu;
+    let xi = 1. / x;
+    if x < XMIN {
+        // Temme's series.
+        let x2 = 0.5 * x;
+        let pimu = PI * xmu;
+        let fact = if pimu.abs() < EPS {
+            1.
+        } else {
+            pimu / pimu.sin()
+        };
+        let d = -x2.ln();
+        let e = xmu * d;
+        let fact2 = if e.abs() < EPS { 1. } else { e.sinh() / e };
+        let (gam1, gam2, gampl, gammi) = temme_gamma(xmu);
+        let mut ff = fact * (gam1 * e.cosh() + gam2 * fact2 * d);
+        let mut sum = ff;
+        let e = e.exp();
+        let mut p = 0.5 * e / gampl;
+        let mut q = 0.5 / (e * gammi);
+        let mut c = 1.;
+        let d = x2 * x2;
+        let mut sum1 = p;
+        for i in 1..MAXIT {
+            let i = i as f64;
+            ff = (i * ff + p + q) / (i * i - xmu2);
+            c *= d / i;
+            p /= i - xmu;
+            q /= i + xmu;
+            let del = c * ff;
+            sum += del;
+            sum1 += c * (p - i * ff);
+            if del.abs() < sum.abs() * EPS {
+                break;
+            }
+        }
+        let scale = x.exp();
+        return (sum * scale, sum1 * 2. * xi * scale);
+    }
+    // CF2 of Steed, with the normalization of Temme.
+    let mut b = 2. * (1. + x);
+    let mut d = 1. / b;
+    let mut h = d;
+    let mut delh = d;
+    let mut q1 = 0.;
+    let mut q2 = 1.;
+    let a1 = 0.25 - xmu2;
+    let mut q = a1;
+    let mut c = a1;
+    let mut a = -a1;
+    let mut s = 1. + q * delh;
+    for i in 2..MAXIT {
+        a -= (2 * (i - 1)) as f64;
+        c = -a * c / i as f64;
+        let qnew = (q1 - b * q2) / a;
+        q1 = q2;
+        q2 = qnew;
+        q += c * qnew;
+        b += 2.;
+        d = 1. / (b + a * d);
+        delh *= b * d - 1.;
+        h += delh;
+        let dels = q * delh;
+        s += dels;
+        if (dels / s).abs() < EPS {
+            break;
+        }
+    }
+    h *= a1;
+    let kmu = (PI / (2. * x)).sqrt() / s;
+    (kmu, kmu * (xmu + x + 0.5 - h) * xi)
+}
+
+/// `exp(x) K_nu(x)` and `exp(x) K_nu+1(x)` for `nu >= 0` and `x > 0`, by upward recurrence.
+fn k_scaled(nu: f64, x: f64) -> (f64, f64) {
+    let nl = (nu + 0.5) as usize;
+    let xmu = nu - nl as f64;
+    let (mut k, mut k1) = k_mu_scaled(xmu, x);
+    for i in 1..=nl {
+        let ktemp = (xmu + i as f64) * (2. / x) * k1 + k;
+        k = k1;
+        k1 = ktemp;
+    }
+    (k, k1)
+}
+
+/// `exp(-x) I_nu(x)` for `nu >= 0` and `x > 0`, from the ratio `I'_nu / I_nu` and the Wronskian
+/// with `K`.
+fn i_scaled(nu: f64, x: f64) -> f64 {
+    let nl = (nu + 0.5) as usize;
+    let xmu = nu - nl as f64;
+    let xi = 1. / x;
+    let xi2 = 2. * xi;
+
+    // CF1, I'_nu / I_nu by the modified Lentz algorithm.
+    let mut h = (nu * xi).max(FPMIN);
+    let mut d = 0.;
+    let mut c = h;
+    for i in 1..MAXIT {
+        // Computed afresh, as the accumulated sum loses precision over the O(x) iterations.
+        let b = xi2 * (nu + i as f64);
+        d = 1. / (b + d);
+        c = b + 1. / c;
+        let del = c * d;
+        h *= del;
+        if (del - 1.).abs() < EPS {
+            break;
+        }
+    }
+
+    // Downward recurrence of unnormalized I and I' to the order mu.
+    let mut ril = START;
+    let mut ripl = h * ril;
+    let mut ril1 = ril;
+    for l in 0..nl {
+        let ritemp = (nu - l as f64) * xi * ril + ripl;
+        let fact = (nu - l as f64 - 1.) * xi;
+        ripl = fact * ritemp + ril;
+        ril = ritemp;
+        if ril > RESCALE {
+            ril /= RESCALE;
+            ripl /= RESCALE;
+            ril1 /= RESCALE;
+        }
+    }
+    let f = ripl / ril;
+
+    let (kmu, k1) = k_mu_scaled(xmu, x);
+    let kmup = xmu * xi * kmu - k1;
+    let imu = xi / (f * kmu - kmup);
+    imu * ril1 / ril
+}
+
+/// Modified Bessel function of the first kind of real order `v`.
+pub(crate) fn iv(v: f64, x: f64) -> f64 {
+    if v.is_nan() || x.is_nan() {
+        return f64::NAN;
+    }
+    if v < 0. && v == v.floor() {
+        return iv(-v, x);
+    }
+    if x < 0. {
+        // Real only for the integer orders.
+        return match integer_parity(v) {
+            Some(sign) => sign * iv(v, -x),
+            None => f64::NAN,
+        };
+    }
+    if x == 0. {
+        return if v == 0. {
+            1.
+        } else if v > 0. {
+            0.
+        } else {
+            gammasgn(1. + v) * f64::INFINITY
+        };
+    }
+    if x.is_infinite() || (x > 1000. && x > v * v) {
+        // Past the overflow of exp(x).
+        return f64::INFINITY;
+    }
+    let nu = v.abs();
+    let i = i_scaled(nu, x) * x.exp();
+    if v < 0. {
+        // I_-nu = I_nu + 2 / pi sin(nu pi) K_nu.
+        return i - FRAC_2_PI * sinpi(v) * k_scaled(nu, x).0 * (-x).exp();
+    }
+    i
+}
+
+/// Modified Bessel function of the second kind of real order `v`, NaN for negative `x`.
+pub(crate) fn kv(v: f64, x: f64) -> f64 {
+    if v.is_nan() || x.is_nan() || x < 0. {
+        return f64::NAN;
+    }
+    if x == 0. {
+        return f64::INFINITY;
+    }
+    if x.is_infinite() {
+        return 0.;
+    }
+    // K_-nu = K_nu.
+    let (k, _) = k_scaled(v.abs(), x);
+    k * (-x).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn modified_bessel_i() {
+        // From mpmath.besseli.
+        assert_relative_eq!(iv(0., 1.), 1.2660658777520084, max_relative = 1e-15);
+        assert_relative_eq!(iv(1., 10.), 2670.9883037012546, max_relative = 1e-14);
+        assert_relative_eq!(iv(2.5, 0.1), 1.6832901734888534e-4, max_relative = 1e-14);
+        assert_relative_eq!(iv(30., 2.), 3.8935196641831644e-33, max_relative = 1e-13);
+        assert_relative_eq!(iv(-0.5, 3.), 4.637757757861503, max_relative = 1e-14);
+        assert_relative_eq!(iv(3., -2.), -0.21273995923985264, max_relative = 1e-14);
+        assert_relative_eq!(iv(1., 700.), 1.5285003902339006e302, max_relative = 1e-13);
+        assert_eq!(iv(1., 800.), f64::INFINITY);
+        assert!(iv(0.5, -1.).is_nan());
+    }
+
+    #[test]
+    fn modified_bessel_k() {
+        // From mpmath.besselk.
+        assert_relative_eq!(kv(0., 1.), 0.42102443824070834, max_relative = 1e-15);
+        assert_relative_eq!(kv(1., 0.01), 99.97389411829624, max_relative = 1e-14);
+        assert_relative_eq!(kv(2.5, 10.), 2.393132586462789e-5, max_relative = 1e-14);
+        assert_relative_eq!(kv(-3., 3.), 0.12217037575718356, max_relative = 1e-14);
+        assert_relative_eq!(kv(1., 700.), 4.6731107967079664e-306, max_relative = 1e-13);
+        assert_eq!(kv(0., 0.), f64::INFINITY);
+        assert!(kv(1., -1.).is_nan());
+    }
+}
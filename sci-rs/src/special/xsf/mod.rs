@@ -1,4 +1,28 @@
 mod chbevl;
 pub(crate) use chbevl::*;
 
+mod gamma;
+pub(crate) use gamma::*;
+
+mod igam;
+pub(crate) use igam::*;
+
+mod incbet;
+pub(crate) use incbet::*;
+
+mod jv;
+pub(crate) use jv::*;
+
+mod kv;
+pub(crate) use kv::*;
+
+mod kolmogorov;
+pub(crate) use kolmogorov::*;
+
+mod ndtr;
+pub(crate) use ndtr::*;
+
+mod polevl;
+pub(crate) use polevl::*;
+
 mod i0;
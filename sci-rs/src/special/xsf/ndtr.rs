@@ -0,0 +1,274 @@
+use super::polevl;
+use core::f64::consts::{PI, SQRT_2};
+use num_traits::Float;
+
+// Cephes rational approximation of erf(x) / x on [0, 1], in x^2.
+#[allow(clippy::excessive_precision)]
+const ERF_T: [f64; 5] = [
+    9.60497373987051638749E0,
+    9.00260197203842689217E1,
+    2.23200534594684319226E3,
+    7.00332514112805075473E3,
+    5.55923013010394962768E4,
+];
+#[allow(clippy::excessive_precision)]
+const ERF_U: [f64; 6] = [
+    1.,
+    3.35617141647503099647E1,
+    5.21357949780152679795E2,
+    4.59432382970980127987E3,
+    2.26290000613890934246E4,
+    4.92673942608635921086E4,
+];
+// Cephes rational approximations of exp(x^2) erfc(x) on [1, 8] and [8, inf).
+#[allow(clippy::excessive_precision)]
+const ERFC_P: [f64; 9] = [
+    2.46196981473530512524E-10,
+    5.64189564831068821977E-1,
+    7.46321056442269912687E0,
+    4.86371970985681366614E1,
+    1.96520832956077098242E2,
+    5.26445194995477358631E2,
+    9.34528527171957607540E2,
+    1.02755188689515710272E3,
+    5.57535335369399327526E2,
+];
+#[allow(clippy::excessive_precision)]
+const ERFC_Q: [f64; 9] = [
+    1.,
+    1.32281951154744992508E1,
+    8.67072140885989742329E1,
+    3.54937778887819891062E2,
+    9.75708501743205489753E2,
+    1.82390916687909736289E3,
+    2.24633760818710981792E3,
+    1.65666309194161350182E3,
+    5.57535340817727675546E2,
+];
+#[allow(clippy::excessive_precision)]
+const ERFC_R: [f64; 6] = [
+    5.64189583547755073984E-1,
+    1.27536670759978104416E0,
+    5.01905042251180477414E0,
+    6.16021097993053585195E0,
+    7.40974269950448939160E0,
+    2.97886665372100240670E0,
+];
+#[allow(clippy::excessive_precision)]
+const ERFC_S: [f64; 7] = [
+    1.,
+    2.26052863220117276590E0,
+    9.39603524938001434673E0,
+    1.20489539808096656605E1,
+    1.70814450747565897222E1,
+    9.60896809063285878198E0,
+    3.36907645100081516050E0,
+];
+
+/// `exp(-x^2)`, without the rounding error of `x^2` which would be amplified by `x^2` in the
+/// result, by splitting `x` into a multiple of 1/128 and a remainder.
+fn exp_neg_x2(x: f64) -> f64 {
+    let x = x.abs();
+    let m = (128. * x + 0.5).floor() / 128.;
+    let f = x - m;
+    (-m * m).exp() * (-(2. * m + f) * f).exp()
+}
+
+/// Error function.
+pub(crate) fn erf(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x.abs() > 1. {
+        return 1. - erfc(x);
+    }
+    let z = x * x;
+    x * polevl(z, &ERF_T) / polevl(z, &ERF_U)
+}
+
+/// Complementary error function `1 - erf(x)`, without cancellation for large `x`.
+pub(crate) fn erfc(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    let a = x.abs();
+    if a < 1. {
+        return 1. - erf(x);
+    }
+    // Past this, the result underflows.
+    if a > 27.3 {
+        return if x < 0. { 2. } else { 0. };
+    }
+    let (p, q) = if a < 8. {
+        (polevl(a, &ERFC_P), polevl(a, &ERFC_Q))
+    } else {
+        (polevl(a, &ERFC_R), polevl(a, &ERFC_S))
+    };
+    let y = exp_neg_x2(a) * p / q;
+    if x < 0. {
+        2. - y
+    } else {
+        y
+    }
+}
+
+/// Cumulative distribution function of the standard normal distribution.
+pub(crate) fn ndtr(x: f64) -> f64 {
+    0.5 * erfc(-x / SQRT_2)
+}
+
+// Coefficients of the rational approximations of Acklam for the inverse of the normal
+// distribution, with a relative error below 1.15e-9.
+#[allow(clippy::excessive_precision)]
+const NDTRI_A: [f64; 6] = [
+    -3.969683028665376e+01,
+    2.209460984245205e+02,
+    -2.759285104469687e+02,
+    1.383577518672690e+02,
+    -3.066479806614716e+01,
+    2.506628277459239e+00,
+];
+#[allow(clippy::excessive_precision)]
+const NDTRI_B: [f64; 5] = [
+    -5.447609879822406e+01,
+    1.615858368580409e+02,
+    -1.556989798598866e+02,
+    6.680131188771972e+01,
+    -1.328068155288572e+01,
+];
+#[allow(clippy::excessive_precision)]
+const NDTRI_C: [f64; 6] = [
+    -7.784894002430293e-03,
+    -3.223964580411365e-01,
+    -2.400758277161838e+00,
+    -2.549732539343734e+00,
+    4.374664141464968e+00,
+    2.938163982698783e+00,
+];
+#[allow(clippy::excessive_precision)]
+const NDTRI_D: [f64; 4] = [
+    7.784695709041462e-03,
+    3.224671290700398e-01,
+    2.445134137142996e+00,
+    3.754408661907416e+00,
+];
+
+/// Inverse of [ndtr], from the approximation of Acklam refined by Halley's method.
+pub(crate) fn ndtri(p: f64) -> f64 {
+    if p.is_nan() || !(0. ..=1.).contains(&p) {
+        return f64::NAN;
+    }
+    if p == 0. {
+        return f64::NEG_INFINITY;
+    }
+    if p == 1. {
+        return f64::INFINITY;
+    }
+    const P_LOW: f64 = 0.02425;
+    let mut x = if p < P_LOW {
+        let q = (-2. * p.ln()).sqrt();
+        polevl(q, &NDTRI_C) / (polevl(q, &NDTRI_D) * q + 1.)
+    } else if p <= 1. - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        polevl(r, &NDTRI_A) * q / (polevl(r, &NDTRI_B) * r + 1.)
+    } else {
+        let q = (-2. * (-p).ln_1p()).sqrt();
+        -polevl(q, &NDTRI_C) / (polevl(q, &NDTRI_D) * q + 1.)
+    };
+    // The error of the approximation is small enough for a single step to reach full precision.
+    // The residual is evaluated in the tail where p keeps its relative precision.
+    let e = if x < 0. {
+        ndtr(x) - p
+    } else {
+        (1. - p) - ndtr(-x)
+    };
+    let u = e * (2. * PI).sqrt() * (x * x / 2.).exp();
+    x -= u / (1. + x * u / 2.);
+    x
+}
+
+/// Inverse of [erf]. Outside of `[-1, 1]` it is NaN.
+pub(crate) fn erfinv(y: f64) -> f64 {
+    if y.is_nan() || !(-1. ..=1.).contains(&y) {
+        return f64::NAN;
+    }
+    if y.abs() > 0.5 {
+        // 1 - |y| is exact, and the tail keeps its relative precision.
+        return y.signum() * erfcinv(1. - y.abs());
+    }
+    if y == 0. {
+        return y;
+    }
+    // The initial guess is accurate in absolute terms, and Halley's method on erf recovers the
+    // relative precision of small results.
+    let mut x = ndtri(0.5 * (1. + y)) / SQRT_2;
+    for _ in 0..2 {
+        let u = (erf(x) - y) * PI.sqrt() / 2. * (x * x).exp();
+        x -= u / (1. + x * u);
+    }
+    x
+}
+
+/// Inverse of [erfc]. Outside of `[0, 2]` it is NaN.
+pub(crate) fn erfcinv(y: f64) -> f64 {
+    if y.is_nan() || !(0. ..=2.).contains(&y) {
+        return f64::NAN;
+    }
+    if (0.5..=1.5).contains(&y) {
+        // 1 - y is exact.
+        return erfinv(1. - y);
+    }
+    -ndtri(0.5 * y) / SQRT_2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn normal() {
+        assert_relative_eq!(erf(0.5), 0.5204998778130465, max_relative = 1e-14);
+        assert_relative_eq!(erf(-1e-10), -1.1283791670955126e-10, max_relative = 1e-14);
+        assert_relative_eq!(erfc(5.), 1.537459794428035e-12, max_relative = 1e-13);
+        assert_relative_eq!(erfc(-1.), 1.8427007929497148, max_relative = 1e-14);
+        assert_relative_eq!(ndtr(-10.), 7.619853024160526e-24, max_relative = 1e-12);
+        assert_relative_eq!(ndtr(1.96), 0.9750021048517795, max_relative = 1e-14);
+
+        assert_relative_eq!(ndtri(0.975), 1.959963984540054, max_relative = 1e-14);
+        assert_relative_eq!(ndtri(1e-20), -9.262340089798408, max_relative = 1e-14);
+        assert_eq!(ndtri(0.5), 0.);
+        for p in [1e-300, 1e-8, 0.01, 0.2, 0.7, 0.99] {
+            assert_relative_eq!(ndtr(ndtri(p)), p, max_relative = 1e-13);
+        }
+        assert!(ndtri(-0.1).is_nan());
+    }
+
+    #[test]
+    fn erf_tails() {
+        // From mpmath.
+        assert_relative_eq!(erf(0.9), 0.7969082124228322, max_relative = 1e-15);
+        assert_relative_eq!(erf(-3.), -0.9999779095030014, max_relative = 1e-15);
+        assert_relative_eq!(erfc(10.), 2.088487583762545e-45, max_relative = 1e-15);
+        assert_relative_eq!(erfc(26.), 5.663192408856143e-296, max_relative = 1e-15);
+        assert_eq!(erfc(30.), 0.);
+        assert_eq!(erfc(-30.), 2.);
+    }
+
+    #[test]
+    fn erf_inverses() {
+        // From mpmath.
+        assert_relative_eq!(erfinv(0.5), 0.4769362762044699, max_relative = 1e-15);
+        assert_relative_eq!(erfinv(-0.999), -2.3267537655135246, max_relative = 1e-15);
+        assert_relative_eq!(erfinv(1e-300), 8.86226925452758e-301, max_relative = 1e-15);
+        assert_relative_eq!(erfcinv(1e-100), 15.065574702592645, max_relative = 1e-15);
+        assert_relative_eq!(erfcinv(1.3), -0.2724627147267544, max_relative = 1e-15);
+        assert_eq!(erfinv(1.), f64::INFINITY);
+        assert_eq!(erfcinv(2.), f64::NEG_INFINITY);
+        assert!(erfinv(1.5).is_nan());
+        for y in [-0.9, -1e-5, 0.3, 0.75, 0.999999] {
+            assert_relative_eq!(erf(erfinv(y)), y, max_relative = 1e-15);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+/// Evaluate the polynomial with coefficients `coef` from the highest degree by Horner's method.
+pub(crate) fn polevl(x: f64, coef: &[f64]) -> f64 {
+    coef.iter().fold(0., |acc, &c| acc * x + c)
+}
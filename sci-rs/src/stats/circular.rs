@@ -0,0 +1,409 @@
+use super::descriptive::reduce_axis;
+use super::{NanPolicy, SignificanceResult};
+use core::f64::consts::TAU;
+use ndarray::{Array, ArrayBase, ArrayView1, Data, Ix1, RemoveAxis};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// The range `(high, low)` of the samples, `(2 pi, 0)` by default.
+fn check_range<F: Float>(high: Option<F>, low: Option<F>) -> Result<(F, F)> {
+    let high = high.unwrap_or(F::from(TAU).unwrap());
+    let low = low.unwrap_or(F::zero());
+    if high > low && (high - low).is_finite() {
+        Ok((high, low))
+    } else {
+        Err(Error::InvalidArg {
+            arg: "high".into(),
+            reason: "must be finite and greater than low.".into(),
+        })
+    }
+}
+
+fn check_nan<F: Float, S: Data<Elem = F>, D: RemoveAxis>(
+    samples: &ArrayBase<S, D>,
+    nan_policy: NanPolicy,
+) -> Result<()> {
+    if nan_policy == NanPolicy::Raise && samples.iter().any(|v| v.is_nan()) {
+        return Err(Error::InvalidArg {
+            arg: "samples".into(),
+            reason: "the input contains NaN.".into(),
+        });
+    }
+    Ok(())
+}
+
+/// The mean sine and cosine of the samples of `x` as angles, and their number, following
+/// `nan_policy`. The mean sine and cosine are NaN if a NaN propagates or there are no samples.
+fn mean_resultant<F: Float>(
+    x: ArrayView1<F>,
+    high: F,
+    low: F,
+    nan_policy: NanPolicy,
+) -> (F, F, usize) {
+    let scale = F::from(TAU).unwrap() / (high - low);
+    let (sin, cos, n) = x
+        .iter()
+        .filter(|v| nan_policy != NanPolicy::Omit || !v.is_nan())
+        .fold((F::zero(), F::zero(), 0), |(sin, cos, n), &v| {
+            let angle = (v - low) * scale;
+            (sin + angle.sin(), cos + angle.cos(), n + 1)
+        });
+    let n_f = F::from(n).unwrap();
+    (sin / n_f, cos / n_f, n)
+}
+
+/// The mean resultant length of the mean sine and cosine, at most 1 despite the rounding, and
+/// NaN if they are.
+fn resultant_length<F: Float>(sin: F, cos: F) -> F {
+    let r = sin.hypot(cos);
+    if r > F::one() {
+        F::one()
+    } else {
+        r
+    }
+}
+
+/// Compute the circular mean of samples in a range.
+///
+/// ## Parameters
+/// * `samples`: Input array.
+/// * `high`: High boundary for the sample range, `2 pi` by default.
+/// * `low`: Low boundary for the sample range, 0 by default.
+/// * `axis`: Axis along which the means are computed, 0 by default.
+/// * `nan_policy`: How to handle NaN in the input, [NanPolicy::Propagate] by default.
+///
+/// ## Returns
+/// The circular mean along `axis`, within `[low, high)`. It is NaN for a lane without
+/// samples.
+///
+/// ## Errors
+/// If `high` is not finite and greater than `low`, `axis` is out of range, or `samples`
+/// contains NaN with [NanPolicy::Raise].
+///
+/// ## Notes
+/// The samples are mapped to the angles `2 pi (x - low) / (high - low)`, and the circular
+/// mean is the angle of the mean of their unit vectors, mapped back to the range.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import circmean
+/// circmean([355, 5, 2, 359, 10, 350], high=360)
+/// # 0.16769014642577604
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::circmean;
+///
+/// let angles = array![355., 5., 2., 359., 10., 350.];
+/// let mean = circmean(&angles, Some(360.), None, None, None).unwrap();
+/// assert_abs_diff_eq!(mean.into_scalar(), 0.16769014642577604, epsilon = 1e-11);
+/// ```
+pub fn circmean<F, S, D>(
+    samples: &ArrayBase<S, D>,
+    high: Option<F>,
+    low: Option<F>,
+    axis: Option<isize>,
+    nan_policy: Option<NanPolicy>,
+) -> Result<Array<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let (high, low) = check_range(high, low)?;
+    let nan_policy = nan_policy.unwrap_or_default();
+    check_nan(samples, nan_policy)?;
+    let tau = F::from(TAU).unwrap();
+    reduce_axis(samples, axis, |x| {
+        let (sin, cos, _) = mean_resultant(x, high, low, nan_policy);
+        let angle = sin.atan2(cos);
+        let angle = if angle < F::zero() {
+            angle + tau
+        } else {
+            angle
+        };
+        let mean = angle * (high - low) / tau + low;
+        // Keep a mean rounded up to high within the range.
+        if mean >= high {
+            low
+        } else {
+            mean
+        }
+    })
+}
+
+/// Compute the circular variance of samples in a range.
+///
+/// ## Parameters
+/// * `samples`: Input array.
+/// * `high`: High boundary for the sample range, `2 pi` by default.
+/// * `low`: Low boundary for the sample range, 0 by default.
+/// * `axis`: Axis along which the variances are computed, 0 by default.
+/// * `nan_policy`: How to handle NaN in the input, [NanPolicy::Propagate] by default.
+///
+/// ## Returns
+/// The circular variance along `axis`, within `[0, 1]`. It is NaN for a lane without
+/// samples.
+///
+/// ## Errors
+/// If `high` is not finite and greater than `low`, `axis` is out of range, or `samples`
+/// contains NaN with [NanPolicy::Raise].
+///
+/// ## Notes
+/// The circular variance is `1 - R`, where `R` is the mean resultant length, the length of
+/// the mean of the unit vectors of the samples as angles. It does not depend on `high` and
+/// `low` other than through these angles. It is close to 0 for samples concentrated around
+/// an angle, and to 1 for samples spread around the circle.
+///
+/// ## References
+/// 1. K. V. Mardia and P. E. Jupp, Directional Statistics, John Wiley & Sons (2000).
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import circvar
+/// circvar([0.1, 6.483185307179586, 19.14955592153876])
+/// # 0.0033305564813160826
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use core::f64::consts::TAU;
+/// use ndarray::array;
+/// use sci_rs::stats::circvar;
+///
+/// let angles = array![0.1, TAU + 0.2, 3. * TAU + 0.3];
+/// let var = circvar(&angles, None, None, None, None).unwrap();
+/// assert_abs_diff_eq!(var.into_scalar(), 0.0033305564813160826, epsilon = 1e-14);
+/// ```
+pub fn circvar<F, S, D>(
+    samples: &ArrayBase<S, D>,
+    high: Option<F>,
+    low: Option<F>,
+    axis: Option<isize>,
+    nan_policy: Option<NanPolicy>,
+) -> Result<Array<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let (high, low) = check_range(high, low)?;
+    let nan_policy = nan_policy.unwrap_or_default();
+    check_nan(samples, nan_policy)?;
+    reduce_axis(samples, axis, |x| {
+        let (sin, cos, _) = mean_resultant(x, high, low, nan_policy);
+        F::one() - resultant_length(sin, cos)
+    })
+}
+
+/// Compute the circular standard deviation of samples in a range.
+///
+/// ## Parameters
+/// * `samples`: Input array.
+/// * `high`: High boundary for the sample range, `2 pi` by default.
+/// * `low`: Low boundary for the sample range, 0 by default.
+/// * `axis`: Axis along which the standard deviations are computed, 0 by default.
+/// * `nan_policy`: How to handle NaN in the input, [NanPolicy::Propagate] by default.
+/// * `normalize`: If `true`, the standard deviation is in radians, as for the default range.
+///   If `false`, it is scaled by `(high - low) / (2 pi)` to the units of the samples. `false`
+///   by default.
+///
+/// ## Returns
+/// The circular standard deviation along `axis`. It is NaN for a lane without samples.
+///
+/// ## Errors
+/// If `high` is not finite and greater than `low`, `axis` is out of range, or `samples`
+/// contains NaN with [NanPolicy::Raise].
+///
+/// ## Notes
+/// The circular standard deviation is `sqrt(-2 ln R)`, where `R` is the mean resultant
+/// length. It approaches the standard deviation of the samples when they are concentrated
+/// within a small part of the range.
+///
+/// ## References
+/// 1. K. V. Mardia and P. E. Jupp, Directional Statistics, John Wiley & Sons (2000).
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import circstd
+/// circstd([20, 21, 22, 18, 19, 20.5, 19.2], high=24)
+/// # 1.251915966948328
+/// circstd([20, 21, 22, 18, 19, 20.5, 19.2], high=24, normalize=True)
+/// # 0.32775083372305247
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::circstd;
+///
+/// let hours = array![20., 21., 22., 18., 19., 20.5, 19.2];
+/// let std = circstd(&hours, Some(24.), None, None, None, None).unwrap();
+/// assert_abs_diff_eq!(std.into_scalar(), 1.251915966948328, epsilon = 1e-13);
+/// let std = circstd(&hours, Some(24.), None, None, None, Some(true)).unwrap();
+/// assert_abs_diff_eq!(std.into_scalar(), 0.32775083372305247, epsilon = 1e-14);
+/// ```
+pub fn circstd<F, S, D>(
+    samples: &ArrayBase<S, D>,
+    high: Option<F>,
+    low: Option<F>,
+    axis: Option<isize>,
+    nan_policy: Option<NanPolicy>,
+    normalize: Option<bool>,
+) -> Result<Array<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let (high, low) = check_range(high, low)?;
+    let nan_policy = nan_policy.unwrap_or_default();
+    check_nan(samples, nan_policy)?;
+    let scale = if normalize.unwrap_or(false) {
+        F::one()
+    } else {
+        (high - low) / F::from(TAU).unwrap()
+    };
+    reduce_axis(samples, axis, |x| {
+        let (sin, cos, _) = mean_resultant(x, high, low, nan_policy);
+        let r = resultant_length(sin, cos);
+        (-F::from(2).unwrap() * r.ln()).sqrt() * scale
+    })
+}
+
+/// Perform the Rayleigh test for the uniformity of circular data.
+///
+/// The Rayleigh test tests the null hypothesis that the samples are uniformly distributed
+/// around the circle, against the alternative of a unimodal distribution.
+///
+/// ## Parameters
+/// * `samples`: The samples.
+/// * `high`: High boundary for the sample range, `2 pi` by default.
+/// * `low`: Low boundary for the sample range, 0 by default.
+/// * `nan_policy`: How to handle NaN in the input, [NanPolicy::Propagate] by default.
+///
+/// ## Returns
+/// The Rayleigh statistic `z = n R^2`, where `n` is the number of samples and `R` their mean
+/// resultant length, and its p-value, as a [SignificanceResult]. They are NaN if a NaN
+/// propagates or there are no samples.
+///
+/// ## Errors
+/// If `high` is not finite and greater than `low`, or `samples` contains NaN with
+/// [NanPolicy::Raise].
+///
+/// ## Notes
+/// The p-value is the approximation of Zar, `exp(-z)` for 50 or more samples, with a
+/// correction in `1 / n` and `1 / n^2` for fewer samples.
+///
+/// ## References
+/// 1. J. H. Zar, Biostatistical Analysis, 4th Ed, Prentice Hall, pp. 616-617 (1999).
+/// 2. N. I. Fisher, Statistical Analysis of Circular Data, Cambridge University Press (1993).
+///
+/// ## Examples
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::rayleightest;
+///
+/// let angles = array![1.8, 1.1, -0.2, 2.4, 0.9, 0.3, 1.5, 2.9, 0.6, 1.2];
+/// let res = rayleightest(&angles, None, None, None).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 4.33183712814701, epsilon = 1e-13);
+/// assert_abs_diff_eq!(res.pvalue, 0.00953419387665606, epsilon = 1e-15);
+/// ```
+pub fn rayleightest<F, S>(
+    samples: &ArrayBase<S, Ix1>,
+    high: Option<F>,
+    low: Option<F>,
+    nan_policy: Option<NanPolicy>,
+) -> Result<SignificanceResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let (high, low) = check_range(high, low)?;
+    let nan_policy = nan_policy.unwrap_or_default();
+    check_nan(samples, nan_policy)?;
+    let (sin, cos, n) = mean_resultant(samples.view(), high, low, nan_policy);
+    let n = F::from(n).unwrap();
+    let z = n * (sin * sin + cos * cos);
+    let c = |v: f64| F::from(v).unwrap();
+    let correction = if n < c(50.) {
+        let (z2, z3, z4) = (z * z, z * z * z, z * z * z * z);
+        F::one() + (c(2.) * z - z2) / (c(4.) * n)
+            - (c(24.) * z - c(132.) * z2 + c(76.) * z3 - c(9.) * z4) / (c(288.) * n * n)
+    } else {
+        F::one()
+    };
+    let pvalue = ((-z).exp() * correction).max(F::zero()).min(F::one());
+    Ok(SignificanceResult {
+        statistic: z,
+        pvalue: if z.is_nan() { z } else { pvalue },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array2};
+
+    #[test]
+    fn circular_lanes() {
+        let a = array![[355., 20.], [5., 21.], [2., 22.], [359., 18.]];
+        let mean = circmean(&a, Some(360.), None, None, None).unwrap();
+        assert_abs_diff_eq!(mean[0], 0.25043373065967284, epsilon = 1e-11);
+        assert_abs_diff_eq!(mean[1], 20.250071410568914, epsilon = 1e-11);
+        let mean = circmean(&a, Some(360.), None, Some(1), None).unwrap();
+        assert_abs_diff_eq!(mean[1], 13., epsilon = 1e-12);
+
+        // The angles of the same hours on a 24 hour clock.
+        let hours = array![20., 21., 22., 18., 19., 20.5, 19.2];
+        let mean = circmean(&hours, Some(24.), None, None, None).unwrap();
+        assert_abs_diff_eq!(mean.into_scalar(), 19.955441674964277, epsilon = 1e-12);
+        let var = circvar(&hours, Some(24.), None, None, None).unwrap();
+        assert_abs_diff_eq!(var.into_scalar(), 0.05229338692356933, epsilon = 1e-14);
+        let shifted = hours.mapv(|h| h - 12.);
+        let mean = circmean(&shifted, Some(12.), Some(-12.), None, None).unwrap();
+        assert_abs_diff_eq!(
+            mean.into_scalar(),
+            19.955441674964277 - 12.,
+            epsilon = 1e-12
+        );
+
+        let empty = Array2::<f64>::zeros((0, 2));
+        assert!(circstd(&empty, None, None, None, None, None)
+            .unwrap()
+            .iter()
+            .all(|v| v.is_nan()));
+        assert!(circmean(&hours, Some(0.), None, None, None).is_err());
+        assert!(circvar(&hours, None, None, Some(1), None).is_err());
+    }
+
+    #[test]
+    fn nan_policies() {
+        let a = array![1.8, 1.1, f64::NAN, 2.4, 0.9];
+        let mean = circmean(&a, None, None, None, None).unwrap();
+        assert!(mean.into_scalar().is_nan());
+        let var = circvar(&a, None, None, None, None).unwrap();
+        assert!(var.into_scalar().is_nan());
+        let mean = circmean(&a, None, None, None, Some(NanPolicy::Omit)).unwrap();
+        assert_abs_diff_eq!(mean.into_scalar(), 1.5375307944163727, epsilon = 1e-14);
+        let var = circvar(&a, None, None, None, Some(NanPolicy::Omit)).unwrap();
+        assert_abs_diff_eq!(var.into_scalar(), 0.16857874857278554, epsilon = 1e-14);
+        let std = circstd(&a, None, None, None, Some(NanPolicy::Omit), None).unwrap();
+        assert_abs_diff_eq!(std.into_scalar(), 0.607649062331321, epsilon = 1e-14);
+        let res = rayleightest(&a, None, None, Some(NanPolicy::Omit)).unwrap();
+        assert_abs_diff_eq!(res.statistic, 2.7650451892991814, epsilon = 1e-13);
+        assert_abs_diff_eq!(res.pvalue, 0.05276530126287996, epsilon = 1e-14);
+        let res = rayleightest(&a, None, None, None).unwrap();
+        assert!(res.statistic.is_nan() && res.pvalue.is_nan());
+        assert!(circstd(&a, None, None, None, Some(NanPolicy::Raise), None).is_err());
+        assert!(rayleightest(&a, None, None, Some(NanPolicy::Raise)).is_err());
+    }
+}
@@ -0,0 +1,361 @@
+use super::{
+    Alternative, Chi2, ContinuousDistribution, DiscreteDistribution, Hypergeom, SignificanceResult,
+};
+use alloc::vec::Vec;
+use ndarray::{Array, ArrayBase, Data, Dimension, Ix2, IxDyn};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Result of [chi2_contingency].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chi2ContingencyResult<F, D: Dimension> {
+    /// The test statistic.
+    pub statistic: F,
+    /// The p-value of the test.
+    pub pvalue: F,
+    /// The degrees of freedom.
+    pub dof: usize,
+    /// The expected frequencies, based on the marginal sums of the table.
+    pub expected_freq: Array<F, D>,
+}
+
+/// The power divergence statistic of Cressie and Read between the frequencies `observed` and
+/// `expected`, for the power `lambda`.
+fn power_divergence<F: Float>(observed: &[F], expected: &[F], lambda: F) -> F {
+    let (zero, one, two) = (F::zero(), F::one(), F::from(2).unwrap());
+    let terms = observed.iter().zip(expected);
+    if lambda == one {
+        // Pearson's chi-squared statistic.
+        terms.fold(zero, |acc, (&o, &e)| acc + (o - e) * (o - e) / e)
+    } else if lambda == zero {
+        // The log-likelihood ratio, or G-test.
+        let sum = terms
+            .filter(|(&o, _)| o != zero)
+            .fold(zero, |acc, (&o, &e)| acc + o * (o / e).ln());
+        two * sum
+    } else if lambda == -one {
+        // The modified log-likelihood ratio.
+        two * terms.fold(zero, |acc, (&o, &e)| acc + e * (e / o).ln())
+    } else {
+        let sum = terms.fold(zero, |acc, (&o, &e)| acc + o * ((o / e).powf(lambda) - one));
+        two / (lambda * (lambda + one)) * sum
+    }
+}
+
+/// Chi-square test of independence of variables in a contingency table.
+///
+/// This function computes the chi-square statistic and p-value for the hypothesis test of
+/// independence of the observed frequencies in the contingency table `observed`. The expected
+/// frequencies are computed based on the marginal sums under the assumption of independence.
+///
+/// ## Parameters
+/// * `observed`: The contingency table, of any number of dimensions, containing the observed
+///   frequencies (i.e. number of occurrences) in each category.
+/// * `correction`: If `true`, and the degrees of freedom is 1, apply Yates' correction for
+///   continuity, which moves each observed value 0.5 towards its expected value. `true` by
+///   default.
+/// * `lambda`: The power of the Cressie-Read power divergence statistic, scipy's `lambda_`.
+///   `1` by default, which is Pearson's chi-squared statistic. `0` gives the log-likelihood
+///   ratio, `-1` the modified log-likelihood ratio, `-1/2` the Freeman-Tukey statistic, `-2`
+///   Neyman's statistic, and `2/3` the statistic recommended by Cressie and Read.
+///
+/// ## Returns
+/// The [Chi2ContingencyResult]. If the degrees of freedom is 0, the statistic is 0 and the
+/// p-value is 1.
+///
+/// ## Errors
+/// If `observed` is empty or contains negative values, or if an expected frequency is zero.
+///
+/// ## Notes
+/// The degrees of freedom is `observed.len() - sum(observed.shape()) + observed.ndim() - 1`.
+/// The p-value comes from the chi-squared distribution with these degrees of freedom, which
+/// is only accurate when the expected frequencies are not too small, as a rule of thumb at
+/// least 5.
+///
+/// ## References
+/// 1. N. Cressie and T. R. C. Read, Multinomial Goodness-of-Fit Tests, Journal of the Royal
+///    Statistical Society. Series B 46.3, pp. 440-464 (1984).
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy.stats import chi2_contingency
+/// obs = np.array([[10, 10, 20], [20, 20, 20]])
+/// res = chi2_contingency(obs)
+/// res.statistic, res.pvalue, res.dof, res.expected_freq
+/// # (2.7777777777777777, 0.24935220877729622, 2, array([[12., 12., 16.], [18., 18., 24.]]))
+/// chi2_contingency(obs, lambda_="log-likelihood").statistic
+/// # 2.7688587616781319
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::chi2_contingency;
+///
+/// let obs = array![[10., 10., 20.], [20., 20., 20.]];
+/// let res = chi2_contingency(&obs, None, None).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 2.7777777777777777, epsilon = 1e-14);
+/// assert_abs_diff_eq!(res.pvalue, 0.24935220877729622, epsilon = 1e-15);
+/// assert_eq!(res.dof, 2);
+/// assert_eq!(res.expected_freq, array![[12., 12., 16.], [18., 18., 24.]]);
+/// let res = chi2_contingency(&obs, None, Some(0.)).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 2.7688587616781319, epsilon = 1e-14);
+/// ```
+pub fn chi2_contingency<F, S, D>(
+    observed: &ArrayBase<S, D>,
+    correction: Option<bool>,
+    lambda: Option<F>,
+) -> Result<Chi2ContingencyResult<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    if observed.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "observed".into(),
+            reason: "must not be empty.".into(),
+        });
+    }
+    if observed.iter().any(|&v| v < F::zero()) {
+        return Err(Error::InvalidArg {
+            arg: "observed".into(),
+            reason: "all values must be nonnegative.".into(),
+        });
+    }
+    let observed = observed.view().into_dyn();
+    // The sums of the table along all the axes but one, for each axis.
+    let mut margins: Vec<Vec<F>> = observed
+        .shape()
+        .iter()
+        .map(|&n| alloc::vec![F::zero(); n])
+        .collect();
+    for (index, &v) in observed.indexed_iter() {
+        for (axis, margin) in margins.iter_mut().enumerate() {
+            margin[index[axis]] = margin[index[axis]] + v;
+        }
+    }
+    let total = margins[0].iter().fold(F::zero(), |acc, &v| acc + v);
+    let ndim = observed.ndim();
+    let expected = Array::from_shape_fn(observed.raw_dim(), |index: IxDyn| {
+        let product = margins
+            .iter()
+            .enumerate()
+            .fold(F::one(), |acc, (axis, margin)| acc * margin[index[axis]]);
+        product / total.powi(ndim as i32 - 1)
+    });
+    if expected.iter().any(|&e| e == F::zero()) {
+        return Err(Error::InvalidArg {
+            arg: "observed".into(),
+            reason: "the expected frequencies must not be zero, so no marginal sum may be zero."
+                .into(),
+        });
+    }
+
+    let dof = observed.len() + ndim - 1 - observed.shape().iter().sum::<usize>();
+    let (statistic, pvalue) = if dof == 0 {
+        (F::zero(), F::one())
+    } else {
+        let half = F::from(0.5).unwrap();
+        let corrected: Vec<F> = observed
+            .iter()
+            .zip(&expected)
+            .map(|(&o, &e)| {
+                if correction.unwrap_or(true) && dof == 1 {
+                    let diff = e - o;
+                    o + diff.abs().min(half) * diff.signum()
+                } else {
+                    o
+                }
+            })
+            .collect();
+        let expected: Vec<F> = expected.iter().copied().collect();
+        let statistic = power_divergence(&corrected, &expected, lambda.unwrap_or(F::one()));
+        let dist = Chi2::new(F::from(dof).unwrap(), None, None)?;
+        (statistic, dist.sf(statistic))
+    };
+    Ok(Chi2ContingencyResult {
+        statistic,
+        pvalue,
+        dof,
+        expected_freq: expected.into_dimensionality::<D>().unwrap(),
+    })
+}
+
+/// Perform a Fisher exact test on a 2x2 contingency table.
+///
+/// The null hypothesis is that the true odds ratio of the populations underlying the
+/// observations is one, and the observations were sampled from these populations under a
+/// condition: the marginals of the resulting table must equal those of the observed table.
+///
+/// ## Parameters
+/// * `table`: A 2x2 contingency table, whose elements must be non-negative integers.
+/// * `alternative`: Defines the [Alternative] hypothesis, two-sided by default. For
+///   [Alternative::Less], the odds ratio of the underlying population is less than one, and
+///   for [Alternative::Greater], it is greater.
+///
+/// ## Returns
+/// The prior odds ratio `table[[0, 0]] * table[[1, 1]] / (table[[0, 1]] * table[[1, 0]])`,
+/// and the p-value, as a [SignificanceResult]. The odds ratio is infinite if its denominator
+/// is zero. If a row or column of `table` sums to zero, the odds ratio is NaN and the p-value
+/// is 1.
+///
+/// ## Errors
+/// If `table` is not 2x2, or its elements are not non-negative integers.
+///
+/// ## Notes
+/// Under the null hypothesis, `table[[0, 0]]` follows the [Hypergeom] distribution of the
+/// marginals. The one-sided p-values are its tails beyond `table[[0, 0]]`, and the two-sided
+/// p-value sums the probabilities of all the tables which are at most as likely as the
+/// observed one.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import fisher_exact
+/// res = fisher_exact([[8, 2], [1, 5]])
+/// res.statistic, res.pvalue
+/// # (20.0, 0.03496503496503495)
+/// fisher_exact([[8, 2], [1, 5]], alternative='greater').pvalue
+/// # 0.024475524475524476
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::{fisher_exact, Alternative};
+///
+/// let table = array![[8., 2.], [1., 5.]];
+/// let res = fisher_exact(&table, None).unwrap();
+/// assert_eq!(res.statistic, 20.);
+/// assert_abs_diff_eq!(res.pvalue, 0.03496503496503495, epsilon = 1e-14);
+/// let res = fisher_exact(&table, Some(Alternative::Greater)).unwrap();
+/// assert_abs_diff_eq!(res.pvalue, 0.024475524475524476, epsilon = 1e-14);
+/// ```
+pub fn fisher_exact<F, S>(
+    table: &ArrayBase<S, Ix2>,
+    alternative: Option<Alternative>,
+) -> Result<SignificanceResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    if table.shape() != [2, 2] {
+        return Err(Error::InvalidArg {
+            arg: "table".into(),
+            reason: "the input must be a 2x2 array.".into(),
+        });
+    }
+    if table
+        .iter()
+        .any(|&v| v < F::zero() || v.fract() != F::zero())
+    {
+        return Err(Error::InvalidArg {
+            arg: "table".into(),
+            reason: "all values must be non-negative integers.".into(),
+        });
+    }
+    let c = table.mapv(|v| v.to_usize().unwrap());
+    let (n1, n2, n) = (
+        c[[0, 0]] + c[[0, 1]],
+        c[[1, 0]] + c[[1, 1]],
+        c[[0, 0]] + c[[1, 0]],
+    );
+    if n1 == 0 || n2 == 0 || n == 0 || n == n1 + n2 {
+        return Ok(SignificanceResult {
+            statistic: F::nan(),
+            pvalue: F::one(),
+        });
+    }
+    let statistic = if c[[1, 0]] > 0 && c[[0, 1]] > 0 {
+        F::from(c[[0, 0]] * c[[1, 1]]).unwrap() / F::from(c[[1, 0]] * c[[0, 1]]).unwrap()
+    } else {
+        F::infinity()
+    };
+
+    let dist = Hypergeom::<f64>::new(n1 + n2, n1, n, None)?;
+    let x = c[[0, 0]] as f64;
+    let pvalue = match alternative.unwrap_or_default() {
+        Alternative::Less => dist.cdf(x),
+        Alternative::Greater => dist.sf(x - 1.),
+        Alternative::TwoSided => {
+            // The tables at most as likely as the observed one, up to a relative tolerance.
+            let pexact = dist.pmf(x) * (1. + 1e-14);
+            let (low, high) = dist.support();
+            (low as usize..=high as usize)
+                .map(|k| dist.pmf(k as f64))
+                .filter(|&p| p <= pexact)
+                .sum::<f64>()
+        }
+    };
+    Ok(SignificanceResult {
+        statistic,
+        pvalue: F::from(pvalue.min(1.)).unwrap(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array3};
+
+    #[test]
+    fn chi2_tables() {
+        let obs = array![[10., 10., 20.], [20., 20., 20.]];
+        let res = chi2_contingency(&obs, None, Some(2. / 3.)).unwrap();
+        assert_abs_diff_eq!(res.statistic, 2.7729644219220577, epsilon = 1e-14);
+        assert_abs_diff_eq!(res.pvalue, 0.24995304195038462, epsilon = 1e-15);
+
+        // Yates' correction of a 2x2 table.
+        let obs = array![[12., 3.], [4., 9.]];
+        let res = chi2_contingency(&obs, None, None).unwrap();
+        assert_eq!(res.dof, 1);
+        assert_abs_diff_eq!(res.statistic, 5.02863247863248, epsilon = 1e-13);
+        assert_abs_diff_eq!(res.pvalue, 0.024931576731126226, epsilon = 1e-15);
+        let res = chi2_contingency(&obs, Some(false), None).unwrap();
+        assert_abs_diff_eq!(res.statistic, 6.892307692307694, epsilon = 1e-13);
+        assert_abs_diff_eq!(res.pvalue, 0.008656744127246045, epsilon = 1e-15);
+
+        // A table of independent variables in 3 dimensions.
+        let obs =
+            Array3::from_shape_fn((2, 3, 2), |(i, j, k)| ((i + 1) * (j + 1) * (k + 2)) as f64);
+        let res = chi2_contingency(&obs, None, None).unwrap();
+        assert_eq!(res.dof, 7);
+        for (&e, &o) in res.expected_freq.iter().zip(&obs) {
+            assert_abs_diff_eq!(e, o, epsilon = 1e-12);
+        }
+        assert_abs_diff_eq!(res.statistic, 0., epsilon = 1e-12);
+
+        let res = chi2_contingency(&array![1., 2., 3.], None, None).unwrap();
+        assert_eq!((res.dof, res.statistic, res.pvalue), (0, 0., 1.));
+        assert!(chi2_contingency(&array![[1., 0.], [2., 0.]], None, None).is_err());
+        assert!(chi2_contingency(&array![[1., -1.], [2., 3.]], None, None).is_err());
+    }
+
+    #[test]
+    fn fisher_tables() {
+        let test =
+            |table: Array<f64, Ix2>, alternative| fisher_exact(&table, Some(alternative)).unwrap();
+        let res = test(array![[8., 2.], [1., 5.]], Alternative::Less);
+        assert_abs_diff_eq!(res.pvalue, 0.9991258741258741, epsilon = 1e-14);
+        let res = test(array![[6., 2.], [1., 4.]], Alternative::Greater);
+        assert_eq!(res.statistic, 12.);
+        assert_abs_diff_eq!(res.pvalue, 0.08624708624708624, epsilon = 1e-14);
+        let res = test(array![[7., 17.], [15., 5.]], Alternative::TwoSided);
+        assert_abs_diff_eq!(res.statistic, 0.13725490196078433, epsilon = 1e-14);
+        assert_abs_diff_eq!(res.pvalue, 0.005768386750469949, epsilon = 1e-14);
+        let res = test(array![[3., 0.], [0., 3.]], Alternative::TwoSided);
+        assert_eq!(res.statistic, f64::INFINITY);
+        assert_abs_diff_eq!(res.pvalue, 0.1, epsilon = 1e-14);
+
+        let res = test(array![[0., 0.], [2., 3.]], Alternative::TwoSided);
+        assert!(res.statistic.is_nan());
+        assert_eq!(res.pvalue, 1.);
+        assert!(fisher_exact(&array![[1., 2., 3.], [4., 5., 6.]], None).is_err());
+        assert!(fisher_exact(&array![[1., 2.5], [4., 5.]], None).is_err());
+    }
+}
@@ -0,0 +1,585 @@
+use super::hypothesis::{check_confidence_level, paired, pvalue};
+use super::transform::rank_of;
+use super::{
+    nan_result, Alternative, Beta, ConfidenceInterval, ContinuousDistribution, NanPolicy, Norm,
+    RankMethod, SignificanceResult, StudentT,
+};
+use alloc::vec::Vec;
+use ndarray::{ArrayBase, ArrayView1, Data, Ix1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Result of [pearsonr].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PearsonRResult<F> {
+    /// Pearson product-moment correlation coefficient.
+    pub statistic: F,
+    /// The p-value associated with the given alternative.
+    pub pvalue: F,
+    /// Number of observations the coefficient was computed from.
+    n: usize,
+    alternative: Alternative,
+}
+
+impl<F: Float> PearsonRResult<F> {
+    /// Confidence interval of the correlation coefficient, from the Fisher transformation.
+    ///
+    /// The interval is one-sided for the one-sided alternatives, with a bound of -1 or 1. It is
+    /// `(-1, 1)` for less than 4 observations.
+    ///
+    /// ## Parameters
+    /// * `confidence_level`: Confidence level of the interval, 0.95 by default.
+    ///
+    /// ## Errors
+    /// If `confidence_level` is not within `(0, 1)`.
+    pub fn confidence_interval(
+        &self,
+        confidence_level: Option<F>,
+    ) -> Result<ConfidenceInterval<F>> {
+        let confidence_level = check_confidence_level(confidence_level)?;
+        let one = F::one();
+        if self.n <= 3 {
+            return Ok(ConfidenceInterval {
+                low: -one,
+                high: one,
+            });
+        }
+        let z = self.statistic.atanh();
+        let se = F::from(self.n - 3).unwrap().sqrt().recip();
+        let norm = Norm::new(None, None)?;
+        let (low, high) = match self.alternative {
+            Alternative::TwoSided => {
+                let h = norm.ppf((one + confidence_level) / F::from(2).unwrap()) * se;
+                ((z - h).tanh(), (z + h).tanh())
+            }
+            Alternative::Less => (-one, (z + norm.ppf(confidence_level) * se).tanh()),
+            Alternative::Greater => ((z - norm.ppf(confidence_level) * se).tanh(), one),
+        };
+        Ok(ConfidenceInterval { low, high })
+    }
+}
+
+/// Pearson correlation coefficient of `x` and `y`, NaN if either is constant.
+fn pearson_of<F: Float>(x: &[F], y: &[F]) -> F {
+    let centered = |x: &[F]| {
+        let mean = x.iter().fold(F::zero(), |acc, &v| acc + v) / F::from(x.len()).unwrap();
+        let x: Vec<F> = x.iter().map(|&v| v - mean).collect();
+        let norm = x.iter().fold(F::zero(), |acc, &v| acc + v * v).sqrt();
+        x.into_iter().map(move |v| v / norm)
+    };
+    let r = centered(x)
+        .zip(centered(y))
+        .fold(F::zero(), |acc, (a, b)| acc + a * b);
+    // Rounding may take the coefficient slightly out of [-1, 1].
+    if r.abs() > F::one() {
+        r.signum()
+    } else {
+        r
+    }
+}
+
+/// Pearson correlation coefficient and p-value for testing non-correlation.
+///
+/// The Pearson correlation coefficient measures the linear relationship between two datasets.
+/// It varies between -1 and +1 with 0 implying no correlation. Correlations of -1 or +1 imply
+/// an exact linear relationship.
+///
+/// The p-value is exact under the assumption that `x` and `y` are drawn from independent
+/// normal distributions, as the coefficient then follows a beta distribution on `[-1, 1]` of
+/// shapes `n/2 - 1`.
+///
+/// ## Parameters
+/// * `x`, `y`: The observations, of the same length.
+/// * `nan_policy`: How to handle NaN in the input, [NanPolicy::Propagate] by default. With
+///   [NanPolicy::Omit], the pairs with a NaN are omitted.
+/// * `alternative`: Defines the [Alternative] hypothesis, two-sided by default. For
+///   [Alternative::Less], the correlation is negative, and for [Alternative::Greater], it is
+///   positive.
+///
+/// ## Returns
+/// The [PearsonRResult]. The statistic and p-value are NaN if either input is constant, or
+/// for less than two observations after omitting the NaNs.
+///
+/// ## Errors
+/// If `x` and `y` differ in length or have less than two observations, or if they contain a
+/// NaN with [NanPolicy::Raise].
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// x = [2.0, 4.1, 3.3, 5.8, 7.2, 6.1, 8.4, 9.0]
+/// y = [1.1, 2.9, 3.8, 4.2, 6.5, 5.9, 7.7, 9.6]
+/// res = stats.pearsonr(x, y)
+/// res.statistic, res.pvalue
+/// # (0.9611885218937951, 0.00014193590345086202)
+/// res.confidence_interval()
+/// # ConfidenceInterval(low=0.7949621918724331, high=0.993166446446531)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::pearsonr;
+///
+/// let x = array![2.0, 4.1, 3.3, 5.8, 7.2, 6.1, 8.4, 9.0];
+/// let y = array![1.1, 2.9, 3.8, 4.2, 6.5, 5.9, 7.7, 9.6];
+/// let res = pearsonr(&x, &y, None, None).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 0.9611885218937951, epsilon = 1e-14);
+/// assert_abs_diff_eq!(res.pvalue, 0.00014193590345086202, epsilon = 1e-15);
+/// let ci = res.confidence_interval(None).unwrap();
+/// assert_abs_diff_eq!(ci.low, 0.7949621918724331, epsilon = 1e-13);
+/// assert_abs_diff_eq!(ci.high, 0.993166446446531, epsilon = 1e-13);
+/// ```
+pub fn pearsonr<F, S, T>(
+    x: &ArrayBase<S, Ix1>,
+    y: &ArrayBase<T, Ix1>,
+    nan_policy: Option<NanPolicy>,
+    alternative: Option<Alternative>,
+) -> Result<PearsonRResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+{
+    if x.len() < 2 {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "must have at least 2 observations.".into(),
+        });
+    }
+    let alternative = alternative.unwrap_or_default();
+    let pairs = paired(x.view(), y.view(), nan_policy.unwrap_or_default())?;
+    let (n, SignificanceResult { statistic, pvalue }) = match pairs {
+        Some((x, y)) if x.len() >= 2 => (x.len(), pearson_test(&x, &y, alternative)),
+        pairs => (pairs.map_or(x.len(), |(x, _)| x.len()), nan_result()),
+    };
+    Ok(PearsonRResult {
+        statistic,
+        pvalue,
+        n,
+        alternative,
+    })
+}
+
+fn pearson_test<F: Float>(x: &[F], y: &[F], alternative: Alternative) -> SignificanceResult<F> {
+    let r = pearson_of(x, y);
+    if r.is_nan() {
+        return nan_result();
+    }
+    if x.len() == 2 {
+        // Two points are always on a line.
+        return SignificanceResult {
+            statistic: r.signum(),
+            pvalue: F::one(),
+        };
+    }
+    let one = F::one();
+    let ab = F::from(x.len()).unwrap() / F::from(2).unwrap() - one;
+    let dist = Beta::new(ab, ab, Some(-one), Some(F::from(2).unwrap()));
+    SignificanceResult {
+        statistic: r,
+        pvalue: dist.map_or(F::nan(), |dist| pvalue(&dist, r, alternative)),
+    }
+}
+
+/// Calculate a Spearman correlation coefficient with associated p-value.
+///
+/// The Spearman rank-order correlation coefficient is a nonparametric measure of the
+/// monotonicity of the relationship between two datasets. It is the Pearson correlation
+/// coefficient of the ranks of the observations, with the ties given their average rank.
+///
+/// ## Parameters
+/// * `x`, `y`: The observations, of the same length.
+/// * `nan_policy`: How to handle NaN in the input, [NanPolicy::Propagate] by default. With
+///   [NanPolicy::Omit], the pairs with a NaN are omitted.
+/// * `alternative`: Defines the [Alternative] hypothesis, two-sided by default. For
+///   [Alternative::Less], the correlation is negative, and for [Alternative::Greater], it is
+///   positive.
+///
+/// ## Returns
+/// The coefficient and p-value as a [SignificanceResult]. They are NaN if either input is
+/// constant, or for less than two observations.
+///
+/// ## Errors
+/// If `x` and `y` differ in length, or if they contain a NaN with [NanPolicy::Raise].
+///
+/// ## Notes
+/// The p-value is computed from the t-distribution with `n - 2` degrees of freedom, which is
+/// only reasonable for datasets larger than about 500 observations.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// res = stats.spearmanr([1, 2, 2, 3, 4, 5, 5, 6, 7], [2, 1, 4, 3, 3, 6, 5, 8, 7])
+/// res.statistic, res.pvalue
+/// # (0.8776449433749015, 0.0018680475576458122)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::spearmanr;
+///
+/// let x = array![1., 2., 2., 3., 4., 5., 5., 6., 7.];
+/// let y = array![2., 1., 4., 3., 3., 6., 5., 8., 7.];
+/// let res = spearmanr(&x, &y, None, None).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 0.8776449433749015, epsilon = 1e-14);
+/// assert_abs_diff_eq!(res.pvalue, 0.0018680475576458122, epsilon = 1e-15);
+/// ```
+pub fn spearmanr<F, S, T>(
+    x: &ArrayBase<S, Ix1>,
+    y: &ArrayBase<T, Ix1>,
+    nan_policy: Option<NanPolicy>,
+    alternative: Option<Alternative>,
+) -> Result<SignificanceResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+{
+    let Some((x, y)) = paired(x.view(), y.view(), nan_policy.unwrap_or_default())? else {
+        return Ok(nan_result());
+    };
+    if x.len() < 2 {
+        return Ok(nan_result());
+    }
+    let r = pearson_of(
+        &rank_of(&x, RankMethod::Average),
+        &rank_of(&y, RankMethod::Average),
+    );
+    let one = F::one();
+    let df = F::from(x.len() - 2).unwrap();
+    let t = r * (df / ((r + one) * (one - r))).max(F::zero()).sqrt();
+    let pvalue = StudentT::new(df, None, None).map_or(F::nan(), |dist| {
+        pvalue(&dist, t, alternative.unwrap_or_default())
+    });
+    Ok(SignificanceResult {
+        statistic: r,
+        pvalue,
+    })
+}
+
+/// Variant of Kendall's tau, which differ in their handling of ties.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KendallVariant {
+    /// Tau-b, adjusted for the ties in either input.
+    #[default]
+    B,
+    /// Tau-c, also called Stuart-Kendall tau-c, suited to rectangular contingency tables.
+    C,
+}
+
+/// Method to compute the p-value of Kendall's tau.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KendallMethod {
+    /// [KendallMethod::Exact] without ties and for up to 33 observations, or when the
+    /// p-value is tiny anyway, [KendallMethod::Asymptotic] otherwise.
+    #[default]
+    Auto,
+    /// Normal approximation of the statistic, with a variance corrected for the ties.
+    Asymptotic,
+    /// Exact distribution of the statistic, only available without ties.
+    Exact,
+}
+
+/// Dense ranks of `x` from 0, and the number of distinct values.
+fn dense_ranks<F: Float>(x: &[F]) -> (Vec<usize>, usize) {
+    let mut order: Vec<usize> = (0..x.len()).collect();
+    order.sort_by(|&i, &j| x[i].partial_cmp(&x[j]).unwrap());
+    let mut ranks = alloc::vec![0; x.len()];
+    let mut rank = 0;
+    for (k, &i) in order.iter().enumerate() {
+        if k > 0 && x[i] != x[order[k - 1]] {
+            rank += 1;
+        }
+        ranks[i] = rank;
+    }
+    (ranks, rank + 1)
+}
+
+/// Sort `x`, returning the number of pairs `i < j` with `x[i] > x[j]`.
+fn inversions(x: &mut [usize]) -> u64 {
+    if x.len() < 2 {
+        return 0;
+    }
+    let mid = x.len() / 2;
+    let mut count = inversions(&mut x[..mid]) + inversions(&mut x[mid..]);
+    let mut merged = Vec::with_capacity(x.len());
+    let (mut i, mut j) = (0, mid);
+    while i < mid && j < x.len() {
+        if x[j] < x[i] {
+            count += (mid - i) as u64;
+            merged.push(x[j]);
+            j += 1;
+        } else {
+            merged.push(x[i]);
+            i += 1;
+        }
+    }
+    merged.extend_from_slice(&x[i..mid]);
+    merged.extend_from_slice(&x[j..]);
+    x.copy_from_slice(&merged);
+    count
+}
+
+/// Statistics of the ties of the dense `ranks`, over the tie counts `t`: the sums of
+/// `t(t-1)/2`, `t(t-1)(t-2)` and `t(t-1)(2t+5)`.
+fn tie_sums(ranks: &[usize], distinct: usize) -> (u64, f64, f64) {
+    let mut counts = alloc::vec![0u64; distinct];
+    ranks.iter().for_each(|&r| counts[r] += 1);
+    counts
+        .into_iter()
+        .filter(|&t| t > 1)
+        .fold((0, 0., 0.), |(pairs, s0, s1), t| {
+            let t_ = t as f64;
+            (
+                pairs + t * (t - 1) / 2,
+                s0 + t_ * (t_ - 1.) * (t_ - 2.),
+                s1 + t_ * (t_ - 1.) * (2. * t_ + 5.),
+            )
+        })
+}
+
+/// Probability that a random permutation of `n` elements has at most `k` inversions.
+fn inversions_cdf(n: usize, k: usize) -> f64 {
+    // The distribution of the inversions over j elements is that over j - 1 elements,
+    // convolved with the uniform distribution on 0..j.
+    let mut dist = alloc::vec![0.; k + 1];
+    dist[0] = 1.;
+    for j in 2..=n {
+        let mut window = 0.;
+        let previous = dist.clone();
+        for i in 0..=k {
+            window += previous[i];
+            if i >= j {
+                window -= previous[i - j];
+            }
+            dist[i] = window / j as f64;
+        }
+    }
+    dist.iter().sum::<f64>().min(1.)
+}
+
+/// Calculate Kendall's tau, a correlation measure for ordinal data.
+///
+/// Kendall's tau is a measure of the correspondence between two rankings. Values close to 1
+/// indicate strong agreement, and values close to -1 indicate strong disagreement. With `P`
+/// the number of concordant pairs, `Q` the number of discordant pairs, `T` the number of
+/// ties only in `x` and `U` the number of ties only in `y`, tau-b is
+/// `(P - Q) / sqrt((P + Q + T) * (P + Q + U))`, and tau-c is `2 (P - Q) / (n^2 (m - 1) / m)`
+/// with `m` the smaller number of distinct values in either input.
+///
+/// ## Parameters
+/// * `x`, `y`: The rankings, of the same length.
+/// * `nan_policy`: How to handle NaN in the input, [NanPolicy::Propagate] by default. With
+///   [NanPolicy::Omit], the pairs with a NaN are omitted.
+/// * `method`: The [KendallMethod] of the p-value, [KendallMethod::Auto] by default.
+/// * `variant`: The [KendallVariant] of the statistic, [KendallVariant::B] by default.
+/// * `alternative`: Defines the [Alternative] hypothesis, two-sided by default. For
+///   [Alternative::Less], the correlation is negative, and for [Alternative::Greater], it is
+///   positive.
+///
+/// ## Returns
+/// The statistic and p-value as a [SignificanceResult]. They are NaN if either input is
+/// constant, or for less than two observations.
+///
+/// ## Errors
+/// If `x` and `y` differ in length, if they contain a NaN with [NanPolicy::Raise], or if
+/// [KendallMethod::Exact] is requested for inputs with ties.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// res = stats.kendalltau([1, 2, 2, 3, 4, 5, 5, 6, 7], [2, 1, 4, 3, 3, 6, 5, 8, 7])
+/// res.statistic, res.pvalue
+/// # (0.7247137945655605, 0.008069020982974614)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::kendalltau;
+///
+/// let x = array![1., 2., 2., 3., 4., 5., 5., 6., 7.];
+/// let y = array![2., 1., 4., 3., 3., 6., 5., 8., 7.];
+/// let res = kendalltau(&x, &y, None, None, None, None).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 0.7247137945655605, epsilon = 1e-14);
+/// assert_abs_diff_eq!(res.pvalue, 0.008069020982974614, epsilon = 1e-15);
+/// ```
+pub fn kendalltau<F, S, T>(
+    x: &ArrayBase<S, Ix1>,
+    y: &ArrayBase<T, Ix1>,
+    nan_policy: Option<NanPolicy>,
+    method: Option<KendallMethod>,
+    variant: Option<KendallVariant>,
+    alternative: Option<Alternative>,
+) -> Result<SignificanceResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+{
+    let Some((x, y)) = paired(x.view(), y.view(), nan_policy.unwrap_or_default())? else {
+        return Ok(nan_result());
+    };
+    let n = x.len();
+    let ((rx, nx), (ry, ny)) = (dense_ranks(&x), dense_ranks(&y));
+    let (x_ties, x0, x1) = tie_sums(&rx, nx);
+    let (y_ties, y0, y1) = tie_sums(&ry, ny);
+    let total = (n * n.saturating_sub(1) / 2) as u64;
+    if x_ties == total || y_ties == total {
+        return Ok(nan_result());
+    }
+
+    // The discordant pairs are the inversions of y ordered by x, then y.
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_unstable_by_key(|&i| (rx[i], ry[i]));
+    let both_ties = order
+        .chunk_by(|&i, &j| rx[i] == rx[j] && ry[i] == ry[j])
+        .map(|c| (c.len() * (c.len() - 1) / 2) as u64)
+        .sum::<u64>();
+    let mut sorted_y: Vec<usize> = order.iter().map(|&i| ry[i]).collect();
+    let discordant = inversions(&mut sorted_y);
+    let con_minus_dis = (total + both_ties) as f64 - (x_ties + y_ties + 2 * discordant) as f64;
+
+    let tau = match variant.unwrap_or_default() {
+        KendallVariant::B => {
+            con_minus_dis / ((total - x_ties) as f64).sqrt() / ((total - y_ties) as f64).sqrt()
+        }
+        KendallVariant::C => {
+            let m = nx.min(ny) as f64;
+            2. * con_minus_dis / ((n * n) as f64 * (m - 1.) / m)
+        }
+    };
+    let tau = tau.clamp(-1., 1.);
+
+    let ties = x_ties != 0 || y_ties != 0;
+    let exact = match method.unwrap_or_default() {
+        KendallMethod::Exact if ties => {
+            return Err(Error::InvalidArg {
+                arg: "method".into(),
+                reason: "the exact method is only available without ties.".into(),
+            })
+        }
+        KendallMethod::Exact => true,
+        KendallMethod::Asymptotic => false,
+        KendallMethod::Auto => !ties && (n <= 33 || discordant.min(total - discordant) <= 1),
+    };
+    let alternative = alternative.unwrap_or_default();
+    let pvalue = if exact {
+        // The inversions are symmetric about total / 2.
+        let (discordant, total) = (discordant as usize, total as usize);
+        match alternative {
+            Alternative::Greater => inversions_cdf(n, discordant),
+            Alternative::Less => inversions_cdf(n, total - discordant),
+            Alternative::TwoSided => {
+                (2. * inversions_cdf(n, discordant.min(total - discordant))).min(1.)
+            }
+        }
+    } else {
+        let (n, x_ties, y_ties) = (n as f64, x_ties as f64, y_ties as f64);
+        let m = n * (n - 1.);
+        let var = (m * (2. * n + 5.) - x1 - y1) / 18.
+            + 2. * x_ties * y_ties / m
+            + x0 * y0 / (9. * m * (n - 2.));
+        let z = con_minus_dis / var.sqrt();
+        pvalue(&Norm::<f64>::new(None, None)?, z, alternative)
+    };
+    Ok(SignificanceResult {
+        statistic: F::from(tau).unwrap(),
+        pvalue: F::from(pvalue).unwrap(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn pearson() {
+        let x = array![2.0, 4.1, 3.3, 5.8, 7.2, 6.1, 8.4, 9.0];
+        let y = array![1.1, 2.9, 3.8, 4.2, 6.5, 5.9, 7.7, 9.6];
+        let res = pearsonr(&x, &y, None, Some(Alternative::Less)).unwrap();
+        assert_abs_diff_eq!(res.pvalue, 0.9999290320482746, epsilon = 1e-14);
+        let ci = res.confidence_interval(Some(0.9)).unwrap();
+        assert_eq!(ci.low, -1.);
+        assert_abs_diff_eq!(ci.high, 0.9874992582863307, epsilon = 1e-13);
+        let res = pearsonr(&x, &y, None, Some(Alternative::Greater)).unwrap();
+        assert_abs_diff_eq!(res.pvalue, 7.096795172543101e-5, epsilon = 1e-16);
+        let ci = res.confidence_interval(None).unwrap();
+        assert_abs_diff_eq!(ci.low, 0.8413258038653097, epsilon = 1e-13);
+        assert_eq!(ci.high, 1.);
+
+        let res = pearsonr(&array![1., 2.], &array![3., 1.], None, None).unwrap();
+        assert_eq!((res.statistic, res.pvalue), (-1., 1.));
+        let res = pearsonr(&array![1., 2., 3.], &array![2., 2., 2.], None, None).unwrap();
+        assert!(res.statistic.is_nan() && res.pvalue.is_nan());
+    }
+
+    #[test]
+    fn nan_policies() {
+        let x = array![1., 2., f64::NAN, 4., 5., 7.];
+        let y = array![2., 1., 3., 5., 4., 8.];
+        let res = spearmanr(&x, &y, None, None).unwrap();
+        assert!(res.statistic.is_nan() && res.pvalue.is_nan());
+        assert!(kendalltau(&x, &y, Some(NanPolicy::Raise), None, None, None).is_err());
+        let omitted = array![1., 2., 4., 5., 7.];
+        let y_omitted = array![2., 1., 5., 4., 8.];
+        assert_eq!(
+            pearsonr(&x, &y, Some(NanPolicy::Omit), None)
+                .unwrap()
+                .statistic,
+            pearsonr(&omitted, &y_omitted, None, None)
+                .unwrap()
+                .statistic
+        );
+        assert_eq!(
+            kendalltau(&x, &y, Some(NanPolicy::Omit), None, None, None).unwrap(),
+            kendalltau(&omitted, &y_omitted, None, None, None, None).unwrap()
+        );
+    }
+
+    #[test]
+    fn ranks() {
+        let x = array![1., 2., 2., 3., 4., 5., 5., 6., 7.];
+        let y = array![2., 1., 4., 3., 3., 6., 5., 8., 7.];
+        let res = spearmanr(&x, &y, None, Some(Alternative::Greater)).unwrap();
+        assert_abs_diff_eq!(res.pvalue, 0.000934023778822908, epsilon = 1e-15);
+        let res = kendalltau(&x, &y, None, None, Some(KendallVariant::C), None).unwrap();
+        assert_abs_diff_eq!(res.statistic, 0.720164609053498, epsilon = 1e-14);
+        assert_abs_diff_eq!(res.pvalue, 0.008069020982974614, epsilon = 1e-15);
+        let res = kendalltau(&x, &y, None, None, None, Some(Alternative::Less)).unwrap();
+        assert_abs_diff_eq!(res.pvalue, 0.9959654895085127, epsilon = 1e-14);
+        assert!(kendalltau(&x, &y, None, Some(KendallMethod::Exact), None, None).is_err());
+
+        // Without ties, the p-value is exact for few observations.
+        let x = array![2.0, 4.1, 3.3, 5.8, 7.2, 6.1, 8.4, 9.0];
+        let y = array![1.1, 2.9, 3.8, 4.2, 6.5, 5.9, 7.7, 9.6];
+        let res = kendalltau(&x, &y, None, None, None, None).unwrap();
+        assert_abs_diff_eq!(res.statistic, 0.9285714285714286, epsilon = 1e-15);
+        assert_abs_diff_eq!(res.pvalue, 0.0003968253968253968, epsilon = 1e-16);
+        let greater = kendalltau(&x, &y, None, None, None, Some(Alternative::Greater)).unwrap();
+        assert_abs_diff_eq!(greater.pvalue, 0.0001984126984126984, epsilon = 1e-16);
+        let less = kendalltau(&x, &y, None, None, None, Some(Alternative::Less)).unwrap();
+        assert_abs_diff_eq!(less.pvalue, 0.9999751984126984, epsilon = 1e-15);
+        let asymptotic =
+            kendalltau(&x, &y, None, Some(KendallMethod::Asymptotic), None, None).unwrap();
+        assert_abs_diff_eq!(asymptotic.pvalue, 0.001296895798959064, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn invalid_args() {
+        let x = array![1., 2., 3.];
+        assert!(pearsonr(&x, &array![1., 2.], None, None).is_err());
+        assert!(pearsonr(&array![1.], &array![1.], None, None).is_err());
+        assert!(spearmanr(&x, &array![1., 2.], None, None).is_err());
+        let res = pearsonr(&x, &array![1., 2., 4.], None, None).unwrap();
+        assert!(res.confidence_interval(Some(0.)).is_err());
+    }
+}
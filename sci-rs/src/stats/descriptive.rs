@@ -0,0 +1,569 @@
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use alloc::vec::Vec;
+use ndarray::{Array, ArrayBase, ArrayView1, Axis, Data, Dimension, RemoveAxis};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Apply `f` to every 1-D lane of `a` along `axis`, 0 by default, reducing that axis.
+pub(crate) fn reduce_axis<F, B, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    f: impl FnMut(ArrayView1<F>) -> B,
+) -> Result<Array<B, D::Smaller>>
+where
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let axis = check_and_get_axis_dyn(Some(axis.unwrap_or(0)), a)?;
+    Ok(a.map_axis(Axis(axis), f))
+}
+
+/// Arithmetic mean of `x`.
+pub(crate) fn mean_of<F: Float>(x: ArrayView1<F>) -> F {
+    x.sum() / F::from(x.len()).unwrap()
+}
+
+/// Central moment of order `k` of `x` about `center`.
+fn central_moment<F: Float>(x: ArrayView1<F>, k: usize, center: F) -> F {
+    match k {
+        0 => F::one(),
+        _ => mean_of(x.mapv(|x| (x - center).powi(k as i32)).view()),
+    }
+}
+
+/// Variance of `x` with `ddof` delta degrees of freedom.
+pub(crate) fn var_of<F: Float>(x: ArrayView1<F>, ddof: usize) -> F {
+    let mean = mean_of(x);
+    let ss = x.fold(F::zero(), |acc, &x| acc + (x - mean) * (x - mean));
+    ss / F::from(x.len() as f64 - ddof as f64).unwrap()
+}
+
+/// Whether the second moment `m2` is zero up to the resolution of `F`, relative to `mean`.
+fn is_degenerate<F: Float>(m2: F, mean: F) -> bool {
+    let digits = (-F::epsilon().log10()).floor().to_i32().unwrap();
+    let resolution = F::from(10).unwrap().powi(-digits);
+    m2 <= (resolution * mean) * (resolution * mean)
+}
+
+pub(crate) fn skew_of<F: Float>(x: ArrayView1<F>, bias: bool) -> F {
+    let n = F::from(x.len()).unwrap();
+    let mean = mean_of(x);
+    let m2 = central_moment(x, 2, mean);
+    let m3 = central_moment(x, 3, mean);
+    if is_degenerate(m2, mean) {
+        return F::nan();
+    }
+    let two = F::from(2).unwrap();
+    let skew = m3 / m2.powf(F::from(1.5).unwrap());
+    if !bias && n > two {
+        ((n - F::one()) * n).sqrt() / (n - two) * skew
+    } else {
+        skew
+    }
+}
+
+pub(crate) fn kurtosis_of<F: Float>(x: ArrayView1<F>, fisher: bool, bias: bool) -> F {
+    let n = F::from(x.len()).unwrap();
+    let mean = mean_of(x);
+    let m2 = central_moment(x, 2, mean);
+    let m4 = central_moment(x, 4, mean);
+    if is_degenerate(m2, mean) {
+        return F::nan();
+    }
+    let (one, two, three) = (F::one(), F::from(2).unwrap(), F::from(3).unwrap());
+    let kurtosis = if !bias && n > three {
+        one / (n - two) / (n - three)
+            * ((n * n - one) * m4 / (m2 * m2) - three * (n - one) * (n - one))
+            + three
+    } else {
+        m4 / (m2 * m2)
+    };
+    if fisher {
+        kurtosis - three
+    } else {
+        kurtosis
+    }
+}
+
+/// Linear interpolation of the `q`-th percentile of the sorted `x`, as numpy's default method.
+pub(crate) fn percentile_of_sorted<F: Float>(x: &[F], q: F) -> F {
+    let h = F::from(x.len() - 1).unwrap() * q / F::from(100).unwrap();
+    let lower = h.floor().to_usize().unwrap().min(x.len() - 1);
+    let upper = (lower + 1).min(x.len() - 1);
+    x[lower] + (h - F::from(lower).unwrap()) * (x[upper] - x[lower])
+}
+
+/// Sorted copy of `x`, with the NaNs last.
+pub(crate) fn sorted<F: Float>(x: ArrayView1<F>) -> Vec<F> {
+    let mut x = x.to_vec();
+    x.sort_by(|a, b| {
+        a.partial_cmp(b)
+            .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+    });
+    x
+}
+
+/// Calculate the nth moment about the mean for a sample.
+///
+/// A moment is a specific quantitative measure of the shape of a set of points. It is often
+/// used to calculate coefficients of skewness and kurtosis due to its close relationship with
+/// them.
+///
+/// ## Parameters
+/// * `a`: Input array.
+/// * `order`: Order of the central moment, 1 by default.
+/// * `axis`: Axis along which the central moment is computed, 0 by default.
+/// * `center`: Point about which the moment is taken, the sample mean by default.
+///
+/// ## Returns
+/// The moment of order `order` along `axis`.
+///
+/// ## Errors
+/// If `axis` is out of range.
+///
+/// ## Notes
+/// The k-th moment of a data sample is `1/n sum((x_i - c)^k)`, where `c` is the center. The
+/// first central moment is therefore zero, and the zeroth moment is one.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import moment
+/// moment([1, 2, 3, 4, 5], order=1)
+/// # 0.0
+/// moment([1, 2, 3, 4, 5], order=2)
+/// # 2.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::stats::moment;
+///
+/// let a = array![1., 2., 3., 4., 5.];
+/// assert_eq!(moment(&a, Some(1), None, None).unwrap().into_scalar(), 0.);
+/// assert_eq!(moment(&a, Some(2), None, None).unwrap().into_scalar(), 2.);
+/// ```
+pub fn moment<F, S, D>(
+    a: &ArrayBase<S, D>,
+    order: Option<usize>,
+    axis: Option<isize>,
+    center: Option<F>,
+) -> Result<Array<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let order = order.unwrap_or(1);
+    reduce_axis(a, axis, |x| {
+        let center = center.unwrap_or_else(|| mean_of(x));
+        central_moment(x, order, center)
+    })
+}
+
+/// Compute the sample skewness of a data set.
+///
+/// For normally distributed data, the skewness should be about zero. For unimodal continuous
+/// distributions, a skewness value greater than zero means that there is more weight in the
+/// right tail of the distribution.
+///
+/// ## Parameters
+/// * `a`: Input array.
+/// * `axis`: Axis along which skewness is calculated, 0 by default.
+/// * `bias`: If `false`, then the calculations are corrected for statistical bias. `true` by
+///   default.
+///
+/// ## Returns
+/// The skewness of values along `axis`, which is NaN where all values are equal.
+///
+/// ## Errors
+/// If `axis` is out of range.
+///
+/// ## Notes
+/// The sample skewness is computed as the Fisher-Pearson coefficient of skewness
+/// `g_1 = m_3 / m_2^(3/2)`, where `m_i` is the biased sample i-th central [moment]. If `bias`
+/// is `false`, the calculations are corrected for bias and the value computed is the adjusted
+/// Fisher-Pearson standardized moment coefficient `G_1 = sqrt(n (n - 1)) / (n - 2) g_1`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import skew
+/// skew([1, 2, 3, 4, 5])
+/// # 0.0
+/// skew([2, 8, 0, 4, 1, 9, 9, 0])
+/// # 0.2650554122698573
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::skew;
+///
+/// let s = skew(&array![1., 2., 3., 4., 5.], None, None).unwrap();
+/// assert_abs_diff_eq!(s.into_scalar(), 0.);
+/// let s = skew(&array![2., 8., 0., 4., 1., 9., 9., 0.], None, None).unwrap();
+/// assert_abs_diff_eq!(s.into_scalar(), 0.2650554122698573, epsilon = 1e-15);
+/// ```
+pub fn skew<F, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    bias: Option<bool>,
+) -> Result<Array<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let bias = bias.unwrap_or(true);
+    reduce_axis(a, axis, |x| skew_of(x, bias))
+}
+
+/// Compute the kurtosis (Fisher or Pearson) of a dataset.
+///
+/// Kurtosis is the fourth central moment divided by the square of the variance. If Fisher's
+/// definition is used, then 3.0 is subtracted from the result to give 0.0 for a normal
+/// distribution.
+///
+/// ## Parameters
+/// * `a`: Input array.
+/// * `axis`: Axis along which the kurtosis is calculated, 0 by default.
+/// * `fisher`: If `true` (default), Fisher's definition is used (normal ==> 0.0). If `false`,
+///   Pearson's definition is used (normal ==> 3.0).
+/// * `bias`: If `false`, then the calculations are corrected for statistical bias. `true` by
+///   default.
+///
+/// ## Returns
+/// The kurtosis of values along `axis`, which is NaN where all values are equal.
+///
+/// ## Errors
+/// If `axis` is out of range.
+///
+/// ## Notes
+/// The unbiased estimator of the excess kurtosis, used if `bias` is `false` and there are more
+/// than 3 values, is
+/// `1 / ((n - 2) (n - 3)) ((n^2 - 1) m_4 / m_2^2 - 3 (n - 1)^2)`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import kurtosis
+/// kurtosis([2, 8, 0, 4, 1, 9, 9, 0])
+/// # -1.6660010752838508
+/// kurtosis([2, 8, 0, 4, 1, 9, 9, 0], fisher=False, bias=False)
+/// # 0.9013977419039129
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::kurtosis;
+///
+/// let a = array![2., 8., 0., 4., 1., 9., 9., 0.];
+/// let k = kurtosis(&a, None, None, None).unwrap();
+/// assert_abs_diff_eq!(k.into_scalar(), -1.6660010752838508, epsilon = 1e-14);
+/// let k = kurtosis(&a, None, Some(false), Some(false)).unwrap();
+/// assert_abs_diff_eq!(k.into_scalar(), 0.9013977419039129, epsilon = 1e-14);
+/// ```
+pub fn kurtosis<F, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    fisher: Option<bool>,
+    bias: Option<bool>,
+) -> Result<Array<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let fisher = fisher.unwrap_or(true);
+    let bias = bias.unwrap_or(true);
+    reduce_axis(a, axis, |x| kurtosis_of(x, fisher, bias))
+}
+
+/// Compute standard error of the mean.
+///
+/// Calculate the standard error of the mean (or standard error of measurement) of the values in
+/// the input array.
+///
+/// ## Parameters
+/// * `a`: Input array.
+/// * `axis`: Axis along which to operate, 0 by default.
+/// * `ddof`: Delta degrees-of-freedom. How many degrees of freedom to adjust for bias in limited
+///   samples relative to the population estimate of variance. 1 by default.
+///
+/// ## Returns
+/// The standard error of the mean in the sample(s), along `axis`.
+///
+/// ## Errors
+/// If `axis` is out of range.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// a = np.arange(20).reshape(5,4)
+/// stats.sem(a)
+/// # array([ 2.8284,  2.8284,  2.8284,  2.8284])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::Array;
+/// use sci_rs::stats::sem;
+///
+/// let a = Array::range(0., 20., 1.).into_shape_with_order((5, 4)).unwrap();
+/// for s in sem(&a, None, None).unwrap() {
+///     assert_abs_diff_eq!(s, 8f64.sqrt(), epsilon = 1e-14);
+/// }
+/// ```
+pub fn sem<F, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    ddof: Option<usize>,
+) -> Result<Array<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let ddof = ddof.unwrap_or(1);
+    reduce_axis(a, axis, |x| {
+        (var_of(x, ddof) / F::from(x.len()).unwrap()).sqrt()
+    })
+}
+
+/// Compute the interquartile range of the data along the specified axis.
+///
+/// The interquartile range (IQR) is the difference between the 75th and 25th percentile of
+/// the data. It is a measure of the dispersion similar to standard deviation or variance, but
+/// is much more robust against outliers.
+///
+/// ## Parameters
+/// * `x`: Input array.
+/// * `axis`: Axis along which the range is computed, 0 by default.
+/// * `rng`: Percentiles over which to compute the range, each within `[0, 100]`. `(25, 75)` by
+///   default.
+/// * `scale`: The numerical value of scale will be divided out of the final result, 1 by
+///   default. The scale `1.3489795003921634` makes the range a consistent estimator of the
+///   standard deviation of normally distributed data.
+///
+/// ## Returns
+/// The interquartile range along `axis`. The percentiles are interpolated linearly between the
+/// sorted values, and the range is NaN where the data contains NaNs.
+///
+/// ## Errors
+/// If `axis` is out of range, or `rng` is not within `[0, 100]`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import iqr
+/// x = np.array([[10, 7, 4], [3, 2, 1]])
+/// iqr(x)
+/// # 4.0
+/// iqr(x, axis=0)
+/// # array([ 3.5,  2.5,  1.5])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::stats::iqr;
+///
+/// let x = array![[10., 7., 4.], [3., 2., 1.]];
+/// let r = iqr(&x, None, None, None).unwrap();
+/// assert_eq!(r.as_slice().unwrap(), &[3.5, 2.5, 1.5]);
+/// ```
+pub fn iqr<F, S, D>(
+    x: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    rng: Option<(F, F)>,
+    scale: Option<F>,
+) -> Result<Array<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let hundred = F::from(100).unwrap();
+    let (low, high) = rng.unwrap_or((F::from(25).unwrap(), F::from(75).unwrap()));
+    let valid = |q: F| q >= F::zero() && q <= hundred;
+    if !valid(low) || !valid(high) {
+        return Err(Error::InvalidArg {
+            arg: "rng".into(),
+            reason: "range must be within [0, 100].".into(),
+        });
+    }
+    let scale = scale.unwrap_or(F::one());
+    reduce_axis(x, axis, |x| {
+        if x.is_empty() || x.iter().any(|x| x.is_nan()) {
+            return F::nan();
+        }
+        let sorted = sorted(x);
+        (percentile_of_sorted(&sorted, high) - percentile_of_sorted(&sorted, low)) / scale
+    })
+}
+
+/// Result of [describe].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescribeResult<F, D: Dimension> {
+    /// Number of observations, the length of the data along the axis.
+    pub nobs: usize,
+    /// Minimum and maximum values of the data.
+    pub minmax: (Array<F, D>, Array<F, D>),
+    /// Mean of the data.
+    pub mean: Array<F, D>,
+    /// Variance of the data, with `ddof` delta degrees of freedom.
+    pub variance: Array<F, D>,
+    /// Skewness of the data, as computed by [skew].
+    pub skewness: Array<F, D>,
+    /// Kurtosis of the data, as computed by [kurtosis] with Fisher's definition.
+    pub kurtosis: Array<F, D>,
+}
+
+/// Compute several descriptive statistics of the passed array.
+///
+/// ## Parameters
+/// * `a`: Input data.
+/// * `axis`: Axis along which statistics are calculated, 0 by default.
+/// * `ddof`: Delta degrees of freedom of the variance, 1 by default.
+/// * `bias`: If `false`, then the skewness and kurtosis calculations are corrected for
+///   statistical bias. `true` by default.
+///
+/// ## Returns
+/// The [DescribeResult], whose statistics have the shape of `a` without `axis`.
+///
+/// ## Errors
+/// If `axis` is out of range, or `a` is empty along it.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// a = np.arange(10)
+/// stats.describe(a)
+/// # DescribeResult(nobs=10, minmax=(0, 9), mean=4.5,
+/// #                variance=9.166666666666666, skewness=0.0,
+/// #                kurtosis=-1.2242424242424244)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::Array;
+/// use sci_rs::stats::describe;
+///
+/// let a = Array::range(0., 10., 1.);
+/// let d = describe(&a, None, None, None).unwrap();
+/// assert_eq!(d.nobs, 10);
+/// assert_eq!((d.minmax.0.into_scalar(), d.minmax.1.into_scalar()), (0., 9.));
+/// assert_eq!(d.mean.into_scalar(), 4.5);
+/// assert_abs_diff_eq!(d.variance.into_scalar(), 9.166666666666666, epsilon = 1e-14);
+/// assert_abs_diff_eq!(d.skewness.into_scalar(), 0.);
+/// assert_abs_diff_eq!(d.kurtosis.into_scalar(), -1.2242424242424244, epsilon = 1e-14);
+/// ```
+pub fn describe<F, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    ddof: Option<usize>,
+    bias: Option<bool>,
+) -> Result<DescribeResult<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let ax = check_and_get_axis_dyn(Some(axis.unwrap_or(0)), a)?;
+    let nobs = a.len_of(Axis(ax));
+    if nobs == 0 {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "The input must not be empty.".into(),
+        });
+    }
+    let ddof = ddof.unwrap_or(1);
+    let bias = bias.unwrap_or(true);
+    let min = reduce_axis(a, axis, |x| x.fold(F::infinity(), |acc, &x| acc.min(x)))?;
+    let max = reduce_axis(a, axis, |x| x.fold(F::neg_infinity(), |acc, &x| acc.max(x)))?;
+    Ok(DescribeResult {
+        nobs,
+        minmax: (min, max),
+        mean: reduce_axis(a, axis, mean_of)?,
+        variance: reduce_axis(a, axis, |x| var_of(x, ddof))?,
+        skewness: skew(a, axis, Some(bias))?,
+        kurtosis: kurtosis(a, axis, None, Some(bias))?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn moments() {
+        let a = array![2., 8., 0., 4., 1., 9., 9., 0.];
+        assert_eq!(moment(&a, Some(0), None, None).unwrap().into_scalar(), 1.);
+        assert_abs_diff_eq!(
+            moment(&a, Some(2), None, None).unwrap().into_scalar(),
+            13.859375,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            moment(&a, Some(1), None, Some(0.)).unwrap().into_scalar(),
+            4.125,
+            epsilon = 1e-12
+        );
+        let s = skew(&a, None, Some(false)).unwrap().into_scalar();
+        assert_abs_diff_eq!(s, 0.3305821804079746, epsilon = 1e-14);
+        let k = kurtosis(&a, None, None, Some(false)).unwrap().into_scalar();
+        assert_abs_diff_eq!(k, -2.098602258096087, epsilon = 1e-13);
+        let e = sem(&a, None, None).unwrap().into_scalar();
+        assert_abs_diff_eq!(e, 1.407093001292279, epsilon = 1e-14);
+
+        // Constant data has no skewness or kurtosis.
+        let c = array![3., 3., 3.];
+        assert!(skew(&c, None, None).unwrap().into_scalar().is_nan());
+        assert!(kurtosis(&c, None, None, None)
+            .unwrap()
+            .into_scalar()
+            .is_nan());
+    }
+
+    #[test]
+    fn axes() {
+        let a = array![[1., 2., 4.], [3., 8., 5.], [2., 0., 9.], [4., 6., 7.]];
+        let m = moment(&a, Some(2), Some(1), None).unwrap();
+        for (i, row) in a.rows().into_iter().enumerate() {
+            let mean = row.sum() / 3.;
+            let m2 = row.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / 3.;
+            assert_abs_diff_eq!(m[i], m2, epsilon = 1e-12);
+        }
+        let d = describe(&a, Some(-1), Some(0), None).unwrap();
+        assert_eq!(d.nobs, 3);
+        assert_eq!(d.minmax.0.as_slice().unwrap(), &[1., 3., 0., 4.]);
+        assert_eq!(d.minmax.1.as_slice().unwrap(), &[4., 8., 9., 7.]);
+        assert_abs_diff_eq!(
+            d.variance.as_slice().unwrap(),
+            m.as_slice().unwrap(),
+            epsilon = 1e-12
+        );
+        let r = iqr(&a, None, None, None).unwrap();
+        assert_abs_diff_eq!(r.as_slice().unwrap(), [1.5, 5., 2.75].as_slice());
+        let r = iqr(&a, None, Some((0., 100.)), Some(2.)).unwrap();
+        assert_abs_diff_eq!(r.as_slice().unwrap(), [1.5, 4., 2.5].as_slice());
+    }
+
+    #[test]
+    fn invalid_args() {
+        let a = array![[1., 2.], [3., 4.]];
+        assert!(skew(&a, Some(2), None).is_err());
+        assert!(iqr(&a, None, Some((-1., 50.)), None).is_err());
+        assert!(describe(&ndarray::Array2::<f64>::zeros((0, 2)), None, None, None).is_err());
+        assert!(iqr(&array![1., f64::NAN], None, None, None)
+            .unwrap()
+            .into_scalar()
+            .is_nan());
+    }
+}
@@ -0,0 +1,949 @@
+use super::{f64_of, loc_scale, of_f64, positive, xlog1py, xlogy, ContinuousDistribution};
+use crate::random::Rng;
+use crate::special::xsf;
+use core::f64::consts::PI;
+use num_traits::Float;
+use sci_rs_core::Result;
+
+/// A normal continuous random variable.
+///
+/// The probability density function of the standardized distribution is
+/// `f(x) = exp(-x^2 / 2) / sqrt(2 pi)`. The location is the mean and the scale the standard
+/// deviation.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import norm
+/// norm.sf(3)
+/// # 0.0013498980316300946
+/// norm.isf(1e-10)
+/// # 6.361340902404056
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{ContinuousDistribution, Norm};
+///
+/// let rv = Norm::new(None, None).unwrap();
+/// assert_relative_eq!(rv.sf(3.), 0.0013498980316300946, max_relative = 1e-14);
+/// assert_relative_eq!(rv.isf(1e-10), 6.361340902404056, max_relative = 1e-14);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Norm<F> {
+    loc: F,
+    scale: F,
+}
+
+impl<F: Float> Norm<F> {
+    /// Normal distribution with mean `loc`, 0 by default, and standard deviation `scale`, 1 by
+    /// default.
+    ///
+    /// ## Errors
+    /// If `loc` is not finite, or `scale` is not positive and finite.
+    pub fn new(loc: Option<F>, scale: Option<F>) -> Result<Self> {
+        let (loc, scale) = loc_scale(loc, scale)?;
+        Ok(Norm { loc, scale })
+    }
+}
+
+impl<F: Float> ContinuousDistribution<F> for Norm<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn scale(&self) -> F {
+        self.scale
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        (F::neg_infinity(), F::infinity())
+    }
+
+    fn standard_logpdf(&self, x: F) -> F {
+        let x = f64_of(x);
+        of_f64(-x * x / 2. - (2. * PI).sqrt().ln())
+    }
+
+    fn standard_cdf(&self, x: F) -> F {
+        of_f64(xsf::ndtr(f64_of(x)))
+    }
+
+    fn standard_sf(&self, x: F) -> F {
+        of_f64(xsf::ndtr(-f64_of(x)))
+    }
+
+    fn standard_ppf(&self, q: F) -> F {
+        of_f64(xsf::ndtri(f64_of(q)))
+    }
+
+    fn standard_isf(&self, q: F) -> F {
+        of_f64(-xsf::ndtri(f64_of(q)))
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        (F::zero(), F::one(), F::zero(), F::zero())
+    }
+
+    fn standard_sample(&self, rng: &mut Rng) -> F {
+        rng.standard_normal()
+    }
+}
+
+/// A Student's t continuous random variable.
+///
+/// The probability density function of the standardized distribution is
+/// `f(x) = Gamma((df + 1) / 2) / (sqrt(pi df) Gamma(df / 2)) (1 + x^2 / df)^(-(df + 1) / 2)`,
+/// for `df` degrees of freedom.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import t
+/// t.sf(2.5, df=10)
+/// # 0.01572342211830441
+/// t.ppf(0.975, df=10)
+/// # 2.228138851986275
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{ContinuousDistribution, StudentT};
+///
+/// let rv = StudentT::new(10., None, None).unwrap();
+/// assert_relative_eq!(rv.sf(2.5), 0.01572342211830441, max_relative = 1e-12);
+/// assert_relative_eq!(rv.ppf(0.975), 2.228138851986275, max_relative = 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StudentT<F> {
+    df: F,
+    loc: F,
+    scale: F,
+}
+
+impl<F: Float> StudentT<F> {
+    /// Student's t distribution with `df` degrees of freedom, shifted by `loc` and scaled by
+    /// `scale`.
+    ///
+    /// ## Errors
+    /// If `df` is not positive and finite, `loc` is not finite, or `scale` is not positive and
+    /// finite.
+    pub fn new(df: F, loc: Option<F>, scale: Option<F>) -> Result<Self> {
+        let df = positive("df", df)?;
+        let (loc, scale) = loc_scale(loc, scale)?;
+        Ok(StudentT { df, loc, scale })
+    }
+}
+
+impl<F: Float> ContinuousDistribution<F> for StudentT<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn scale(&self) -> F {
+        self.scale
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        (F::neg_infinity(), F::infinity())
+    }
+
+    fn standard_logpdf(&self, x: F) -> F {
+        let (df, x) = (f64_of(self.df), f64_of(x));
+        of_f64(
+            xsf::gammaln((df + 1.) / 2.)
+                - xsf::gammaln(df / 2.)
+                - 0.5 * (df * PI).ln()
+                - (df + 1.) / 2. * (x * x / df).ln_1p(),
+        )
+    }
+
+    fn standard_cdf(&self, x: F) -> F {
+        let (df, t) = (f64_of(self.df), f64_of(x));
+        // Probability of the tail beyond |t|.
+        let tail = 0.5 * xsf::betainc(df / 2., 0.5, df / (df + t * t));
+        of_f64(if t < 0. { tail } else { 1. - tail })
+    }
+
+    fn standard_sf(&self, x: F) -> F {
+        self.standard_cdf(-x)
+    }
+
+    fn standard_ppf(&self, q: F) -> F {
+        let (df, q) = (f64_of(self.df), f64_of(q));
+        let t = if (0.25..0.75).contains(&q) {
+            // Near the median, from the central probability to keep the precision.
+            let z = xsf::betaincinv(0.5, df / 2., (2. * q - 1.).abs());
+            (df * z / (1. - z)).sqrt()
+        } else {
+            let z = xsf::betaincinv(df / 2., 0.5, 2. * q.min(1. - q));
+            (df / z - df).sqrt()
+        };
+        of_f64(if q < 0.5 { -t } else { t })
+    }
+
+    fn standard_isf(&self, q: F) -> F {
+        -self.standard_ppf(q)
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        let df = f64_of(self.df);
+        let mean = if df > 1. { 0. } else { f64::NAN };
+        let var = if df > 2. {
+            df / (df - 2.)
+        } else if df > 1. {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        };
+        let skew = if df > 3. { 0. } else { f64::NAN };
+        let kurtosis = if df > 4. {
+            6. / (df - 4.)
+        } else if df > 2. {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        };
+        (of_f64(mean), of_f64(var), of_f64(skew), of_f64(kurtosis))
+    }
+}
+
+/// A chi-squared continuous random variable.
+///
+/// The probability density function of the standardized distribution is
+/// `f(x) = x^(df / 2 - 1) exp(-x / 2) / (2^(df / 2) Gamma(df / 2))` for `x >= 0`, with `df`
+/// degrees of freedom.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import chi2
+/// chi2.sf(20, df=5)
+/// # 0.0012497305630313753
+/// chi2.ppf(0.95, df=5)
+/// # 11.070497693516353
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{Chi2, ContinuousDistribution};
+///
+/// let rv = Chi2::new(5., None, None).unwrap();
+/// assert_relative_eq!(rv.sf(20.), 0.0012497305630313753, max_relative = 1e-12);
+/// assert_relative_eq!(rv.ppf(0.95), 11.070497693516353, max_relative = 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Chi2<F> {
+    df: F,
+    loc: F,
+    scale: F,
+}
+
+impl<F: Float> Chi2<F> {
+    /// Chi-squared distribution with `df` degrees of freedom, shifted by `loc` and scaled by
+    /// `scale`.
+    ///
+    /// ## Errors
+    /// If `df` is not positive and finite, `loc` is not finite, or `scale` is not positive and
+    /// finite.
+    pub fn new(df: F, loc: Option<F>, scale: Option<F>) -> Result<Self> {
+        let df = positive("df", df)?;
+        let (loc, scale) = loc_scale(loc, scale)?;
+        Ok(Chi2 { df, loc, scale })
+    }
+}
+
+impl<F: Float> ContinuousDistribution<F> for Chi2<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn scale(&self) -> F {
+        self.scale
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        (F::zero(), F::infinity())
+    }
+
+    fn standard_logpdf(&self, x: F) -> F {
+        let (a, x) = (f64_of(self.df) / 2., f64_of(x));
+        of_f64(xlogy(a - 1., x) - x / 2. - a * 2f64.ln() - xsf::gammaln(a))
+    }
+
+    fn standard_cdf(&self, x: F) -> F {
+        of_f64(xsf::gammainc(f64_of(self.df) / 2., f64_of(x) / 2.))
+    }
+
+    fn standard_sf(&self, x: F) -> F {
+        of_f64(xsf::gammaincc(f64_of(self.df) / 2., f64_of(x) / 2.))
+    }
+
+    fn standard_ppf(&self, q: F) -> F {
+        of_f64(2. * xsf::gammaincinv(f64_of(self.df) / 2., f64_of(q)))
+    }
+
+    fn standard_isf(&self, q: F) -> F {
+        of_f64(2. * xsf::gammainccinv(f64_of(self.df) / 2., f64_of(q)))
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        let df = f64_of(self.df);
+        (
+            self.df,
+            of_f64(2. * df),
+            of_f64((8. / df).sqrt()),
+            of_f64(12. / df),
+        )
+    }
+}
+
+/// An F continuous random variable.
+///
+/// The probability density function of the standardized distribution is
+/// `f(x) = dfd^(dfd / 2) dfn^(dfn / 2) x^(dfn / 2 - 1) / ((dfd + dfn x)^((dfn + dfd) / 2)
+/// B(dfn / 2, dfd / 2))` for `x >= 0`, with `dfn` and `dfd` degrees of freedom of the numerator
+/// and denominator.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import f
+/// f.sf(3.5, dfn=3, dfd=12)
+/// # 0.049640537979886816
+/// f.ppf(0.95, dfn=3, dfd=12)
+/// # 3.490294819497606
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{ContinuousDistribution, FDist};
+///
+/// let rv = FDist::new(3., 12., None, None).unwrap();
+/// assert_relative_eq!(rv.sf(3.5), 0.049640537979886816, max_relative = 1e-12);
+/// assert_relative_eq!(rv.ppf(0.95), 3.490294819497606, max_relative = 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FDist<F> {
+    dfn: F,
+    dfd: F,
+    loc: F,
+    scale: F,
+}
+
+impl<F: Float> FDist<F> {
+    /// F distribution with `dfn` and `dfd` degrees of freedom, shifted by `loc` and scaled by
+    /// `scale`.
+    ///
+    /// ## Errors
+    /// If `dfn` or `dfd` is not positive and finite, `loc` is not finite, or `scale` is not
+    /// positive and finite.
+    pub fn new(dfn: F, dfd: F, loc: Option<F>, scale: Option<F>) -> Result<Self> {
+        let dfn = positive("dfn", dfn)?;
+        let dfd = positive("dfd", dfd)?;
+        let (loc, scale) = loc_scale(loc, scale)?;
+        Ok(FDist {
+            dfn,
+            dfd,
+            loc,
+            scale,
+        })
+    }
+}
+
+impl<F: Float> ContinuousDistribution<F> for FDist<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn scale(&self) -> F {
+        self.scale
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        (F::zero(), F::infinity())
+    }
+
+    fn standard_logpdf(&self, x: F) -> F {
+        let (m, n, x) = (f64_of(self.dfn), f64_of(self.dfd), f64_of(x));
+        of_f64(
+            (m * m.ln() + n * n.ln()) / 2. + xlogy(m / 2. - 1., x)
+                - (m + n) / 2. * (n + m * x).ln()
+                - xsf::betaln(m / 2., n / 2.),
+        )
+    }
+
+    fn standard_cdf(&self, x: F) -> F {
+        let (m, n, x) = (f64_of(self.dfn), f64_of(self.dfd), f64_of(x));
+        of_f64(xsf::betainc(m / 2., n / 2., m * x / (m * x + n)))
+    }
+
+    fn standard_sf(&self, x: F) -> F {
+        let (m, n, x) = (f64_of(self.dfn), f64_of(self.dfd), f64_of(x));
+        of_f64(xsf::betainc(n / 2., m / 2., n / (n + m * x)))
+    }
+
+    fn standard_ppf(&self, q: F) -> F {
+        let (m, n) = (f64_of(self.dfn), f64_of(self.dfd));
+        let w = xsf::betaincinv(m / 2., n / 2., f64_of(q));
+        of_f64(n * w / (m * (1. - w)))
+    }
+
+    fn standard_isf(&self, q: F) -> F {
+        let (m, n) = (f64_of(self.dfn), f64_of(self.dfd));
+        let w = xsf::betaincinv(n / 2., m / 2., f64_of(q));
+        of_f64(n * (1. - w) / (m * w))
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        let (m, n) = (f64_of(self.dfn), f64_of(self.dfd));
+        let mean = if n > 2. { n / (n - 2.) } else { f64::INFINITY };
+        let var = if n > 4. {
+            2. * n * n * (m + n - 2.) / (m * (n - 2.).powi(2) * (n - 4.))
+        } else {
+            f64::INFINITY
+        };
+        let skew = if n > 6. {
+            (2. * m + n - 2.) * (8. * (n - 4.)).sqrt() / ((n - 6.) * (m * (m + n - 2.)).sqrt())
+        } else {
+            f64::NAN
+        };
+        let kurtosis = if n > 8. {
+            12. * (m * (5. * n - 22.) * (m + n - 2.) + (n - 4.) * (n - 2.).powi(2))
+                / (m * (n - 6.) * (n - 8.) * (m + n - 2.))
+        } else {
+            f64::NAN
+        };
+        (of_f64(mean), of_f64(var), of_f64(skew), of_f64(kurtosis))
+    }
+}
+
+/// A gamma continuous random variable.
+///
+/// The probability density function of the standardized distribution is
+/// `f(x) = x^(a - 1) exp(-x) / Gamma(a)` for `x >= 0`, with the shape parameter `a`. The scale
+/// is the inverse of the rate parameter.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import gamma
+/// gamma.cdf(2, a=3, scale=0.5)
+/// # 0.7618966944464557
+/// gamma.ppf(0.5, a=3)
+/// # 2.6740603137235603
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{ContinuousDistribution, Gamma};
+///
+/// let rv = Gamma::new(3., None, Some(0.5)).unwrap();
+/// assert_relative_eq!(rv.cdf(2.), 0.7618966944464557, max_relative = 1e-12);
+/// let rv = Gamma::new(3., None, None).unwrap();
+/// assert_relative_eq!(rv.ppf(0.5), 2.6740603137235603, max_relative = 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gamma<F> {
+    a: F,
+    loc: F,
+    scale: F,
+}
+
+impl<F: Float> Gamma<F> {
+    /// Gamma distribution with the shape parameter `a`, shifted by `loc` and scaled by `scale`.
+    ///
+    /// ## Errors
+    /// If `a` is not positive and finite, `loc` is not finite, or `scale` is not positive and
+    /// finite.
+    pub fn new(a: F, loc: Option<F>, scale: Option<F>) -> Result<Self> {
+        let a = positive("a", a)?;
+        let (loc, scale) = loc_scale(loc, scale)?;
+        Ok(Gamma { a, loc, scale })
+    }
+}
+
+impl<F: Float> ContinuousDistribution<F> for Gamma<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn scale(&self) -> F {
+        self.scale
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        (F::zero(), F::infinity())
+    }
+
+    fn standard_logpdf(&self, x: F) -> F {
+        let (a, x) = (f64_of(self.a), f64_of(x));
+        of_f64(xlogy(a - 1., x) - x - xsf::gammaln(a))
+    }
+
+    fn standard_cdf(&self, x: F) -> F {
+        of_f64(xsf::gammainc(f64_of(self.a), f64_of(x)))
+    }
+
+    fn standard_sf(&self, x: F) -> F {
+        of_f64(xsf::gammaincc(f64_of(self.a), f64_of(x)))
+    }
+
+    fn standard_ppf(&self, q: F) -> F {
+        of_f64(xsf::gammaincinv(f64_of(self.a), f64_of(q)))
+    }
+
+    fn standard_isf(&self, q: F) -> F {
+        of_f64(xsf::gammainccinv(f64_of(self.a), f64_of(q)))
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        let a = f64_of(self.a);
+        (self.a, self.a, of_f64(2. / a.sqrt()), of_f64(6. / a))
+    }
+}
+
+/// A beta continuous random variable.
+///
+/// The probability density function of the standardized distribution is
+/// `f(x) = x^(a - 1) (1 - x)^(b - 1) / B(a, b)` for `0 <= x <= 1`, with the shape parameters
+/// `a` and `b`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import beta
+/// beta.cdf(0.3, a=2, b=5)
+/// # 0.579825
+/// beta.ppf(0.9, a=2, b=5)
+/// # 0.5103163065514916
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{Beta, ContinuousDistribution};
+///
+/// let rv = Beta::new(2., 5., None, None).unwrap();
+/// assert_relative_eq!(rv.cdf(0.3), 0.579825, max_relative = 1e-12);
+/// assert_relative_eq!(rv.ppf(0.9), 0.5103163065514916, max_relative = 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Beta<F> {
+    a: F,
+    b: F,
+    loc: F,
+    scale: F,
+}
+
+impl<F: Float> Beta<F> {
+    /// Beta distribution with the shape parameters `a` and `b`, shifted by `loc` and scaled by
+    /// `scale`.
+    ///
+    /// ## Errors
+    /// If `a` or `b` is not positive and finite, `loc` is not finite, or `scale` is not positive
+    /// and finite.
+    pub fn new(a: F, b: F, loc: Option<F>, scale: Option<F>) -> Result<Self> {
+        let a = positive("a", a)?;
+        let b = positive("b", b)?;
+        let (loc, scale) = loc_scale(loc, scale)?;
+        Ok(Beta { a, b, loc, scale })
+    }
+}
+
+impl<F: Float> ContinuousDistribution<F> for Beta<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn scale(&self) -> F {
+        self.scale
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        (F::zero(), F::one())
+    }
+
+    fn standard_logpdf(&self, x: F) -> F {
+        let (a, b, x) = (f64_of(self.a), f64_of(self.b), f64_of(x));
+        of_f64(xlogy(a - 1., x) + xlog1py(b - 1., -x) - xsf::betaln(a, b))
+    }
+
+    fn standard_cdf(&self, x: F) -> F {
+        of_f64(xsf::betainc(f64_of(self.a), f64_of(self.b), f64_of(x)))
+    }
+
+    fn standard_sf(&self, x: F) -> F {
+        let x = f64_of(x);
+        of_f64(xsf::betainc(f64_of(self.b), f64_of(self.a), 1. - x))
+    }
+
+    fn standard_ppf(&self, q: F) -> F {
+        of_f64(xsf::betaincinv(f64_of(self.a), f64_of(self.b), f64_of(q)))
+    }
+
+    fn standard_isf(&self, q: F) -> F {
+        of_f64(1. - xsf::betaincinv(f64_of(self.b), f64_of(self.a), f64_of(q)))
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        let (a, b) = (f64_of(self.a), f64_of(self.b));
+        let s = a + b;
+        let mean = a / s;
+        let var = a * b / (s * s * (s + 1.));
+        let skew = 2. * (b - a) * (s + 1.).sqrt() / ((s + 2.) * (a * b).sqrt());
+        let kurtosis =
+            6. * ((a - b).powi(2) * (s + 1.) - a * b * (s + 2.)) / (a * b * (s + 2.) * (s + 3.));
+        (of_f64(mean), of_f64(var), of_f64(skew), of_f64(kurtosis))
+    }
+}
+
+/// An exponential continuous random variable.
+///
+/// The probability density function of the standardized distribution is `f(x) = exp(-x)` for
+/// `x >= 0`. The scale is the inverse of the rate parameter.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import expon
+/// expon.cdf(1, scale=2)
+/// # 0.3934693402873666
+/// expon.isf(0.01)
+/// # 4.605170185988091
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{ContinuousDistribution, Expon};
+///
+/// let rv = Expon::new(None, Some(2.)).unwrap();
+/// assert_relative_eq!(rv.cdf(1.), 0.3934693402873666, max_relative = 1e-14);
+/// let rv = Expon::new(None, None).unwrap();
+/// assert_relative_eq!(rv.isf(0.01), 4.605170185988091, max_relative = 1e-14);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Expon<F> {
+    loc: F,
+    scale: F,
+}
+
+impl<F: Float> Expon<F> {
+    /// Exponential distribution shifted by `loc`, 0 by default, and scaled by `scale`, 1 by
+    /// default.
+    ///
+    /// ## Errors
+    /// If `loc` is not finite, or `scale` is not positive and finite.
+    pub fn new(loc: Option<F>, scale: Option<F>) -> Result<Self> {
+        let (loc, scale) = loc_scale(loc, scale)?;
+        Ok(Expon { loc, scale })
+    }
+}
+
+impl<F: Float> ContinuousDistribution<F> for Expon<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn scale(&self) -> F {
+        self.scale
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        (F::zero(), F::infinity())
+    }
+
+    fn standard_logpdf(&self, x: F) -> F {
+        -x
+    }
+
+    fn standard_cdf(&self, x: F) -> F {
+        -(-x).exp_m1()
+    }
+
+    fn standard_sf(&self, x: F) -> F {
+        (-x).exp()
+    }
+
+    fn standard_ppf(&self, q: F) -> F {
+        -(-q).ln_1p()
+    }
+
+    fn standard_isf(&self, q: F) -> F {
+        -q.ln()
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        (F::one(), F::one(), of_f64(2.), of_f64(6.))
+    }
+}
+
+/// A log-normal continuous random variable.
+///
+/// The probability density function of the standardized distribution is
+/// `f(x) = exp(-ln(x)^2 / (2 s^2)) / (s x sqrt(2 pi))` for `x > 0`, with the shape parameter
+/// `s`. If `Y` is normally distributed with mean `mu` and standard deviation `sigma`, `exp(Y)`
+/// is log-normally distributed with `s = sigma` and `scale = exp(mu)`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import lognorm
+/// lognorm.cdf(2, s=0.5)
+/// # 0.9171714809983015
+/// lognorm.mean(s=0.5, scale=3)
+/// # 3.399445359200479
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{ContinuousDistribution, LogNorm};
+///
+/// let rv = LogNorm::new(0.5, None, None).unwrap();
+/// assert_relative_eq!(rv.cdf(2.), 0.9171714809983015, max_relative = 1e-14);
+/// let rv = LogNorm::new(0.5, None, Some(3.)).unwrap();
+/// assert_relative_eq!(rv.mean(), 3.399445359200479, max_relative = 1e-14);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogNorm<F> {
+    s: F,
+    loc: F,
+    scale: F,
+}
+
+impl<F: Float> LogNorm<F> {
+    /// Log-normal distribution with the shape parameter `s`, shifted by `loc` and scaled by
+    /// `scale`.
+    ///
+    /// ## Errors
+    /// If `s` is not positive and finite, `loc` is not finite, or `scale` is not positive and
+    /// finite.
+    pub fn new(s: F, loc: Option<F>, scale: Option<F>) -> Result<Self> {
+        let s = positive("s", s)?;
+        let (loc, scale) = loc_scale(loc, scale)?;
+        Ok(LogNorm { s, loc, scale })
+    }
+}
+
+impl<F: Float> ContinuousDistribution<F> for LogNorm<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn scale(&self) -> F {
+        self.scale
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        (F::zero(), F::infinity())
+    }
+
+    fn standard_logpdf(&self, x: F) -> F {
+        let (s, x) = (f64_of(self.s), f64_of(x));
+        if x == 0. {
+            return F::neg_infinity();
+        }
+        let y = x.ln() / s;
+        of_f64(-y * y / 2. - (s * x * (2. * PI).sqrt()).ln())
+    }
+
+    fn standard_cdf(&self, x: F) -> F {
+        of_f64(xsf::ndtr(f64_of(x).ln() / f64_of(self.s)))
+    }
+
+    fn standard_sf(&self, x: F) -> F {
+        of_f64(xsf::ndtr(-f64_of(x).ln() / f64_of(self.s)))
+    }
+
+    fn standard_ppf(&self, q: F) -> F {
+        of_f64((f64_of(self.s) * xsf::ndtri(f64_of(q))).exp())
+    }
+
+    fn standard_isf(&self, q: F) -> F {
+        of_f64((-f64_of(self.s) * xsf::ndtri(f64_of(q))).exp())
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        let p = f64_of(self.s).powi(2).exp();
+        let mean = p.sqrt();
+        let var = (p - 1.) * p;
+        let skew = (p + 2.) * (p - 1.).sqrt();
+        let kurtosis = p.powi(4) + 2. * p.powi(3) + 3. * p * p - 6.;
+        (of_f64(mean), of_f64(var), of_f64(skew), of_f64(kurtosis))
+    }
+
+    fn standard_sample(&self, rng: &mut Rng) -> F {
+        (self.s * rng.standard_normal()).exp()
+    }
+}
+
+/// A uniform continuous random variable.
+///
+/// The standardized distribution is uniform over `[0, 1]`, so that the distribution is uniform
+/// over `[loc, loc + scale]`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import uniform
+/// uniform.cdf(3, loc=2, scale=4)
+/// # 0.25
+/// uniform.var(loc=2, scale=4)
+/// # 1.3333333333333333
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{ContinuousDistribution, Uniform};
+///
+/// let rv = Uniform::new(Some(2.), Some(4.)).unwrap();
+/// assert_eq!(rv.cdf(3.), 0.25);
+/// assert_relative_eq!(rv.var(), 1.3333333333333333, max_relative = 1e-15);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Uniform<F> {
+    loc: F,
+    scale: F,
+}
+
+impl<F: Float> Uniform<F> {
+    /// Uniform distribution over `[loc, loc + scale]`, `[0, 1]` by default.
+    ///
+    /// ## Errors
+    /// If `loc` is not finite, or `scale` is not positive and finite.
+    pub fn new(loc: Option<F>, scale: Option<F>) -> Result<Self> {
+        let (loc, scale) = loc_scale(loc, scale)?;
+        Ok(Uniform { loc, scale })
+    }
+}
+
+impl<F: Float> ContinuousDistribution<F> for Uniform<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn scale(&self) -> F {
+        self.scale
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        (F::zero(), F::one())
+    }
+
+    fn standard_logpdf(&self, x: F) -> F {
+        F::zero()
+    }
+
+    fn standard_cdf(&self, x: F) -> F {
+        x
+    }
+
+    fn standard_sf(&self, x: F) -> F {
+        F::one() - x
+    }
+
+    fn standard_ppf(&self, q: F) -> F {
+        q
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        (of_f64(0.5), of_f64(1. / 12.), F::zero(), of_f64(-1.2))
+    }
+
+    fn standard_sample(&self, rng: &mut Rng) -> F {
+        rng.random()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use sci_rs_core::Error;
+
+    /// Check the consistency of the functions of `rv` at `x`, and its moments against a large
+    /// sample.
+    fn check<D: ContinuousDistribution<f64>>(rv: &D, x: f64) {
+        let (cdf, sf) = (rv.cdf(x), rv.sf(x));
+        assert_relative_eq!(cdf + sf, 1., max_relative = 1e-13);
+        assert_relative_eq!(rv.ppf(cdf), x, max_relative = 1e-9);
+        assert_relative_eq!(rv.isf(sf), x, max_relative = 1e-9);
+        assert_relative_eq!(rv.logpdf(x).exp(), rv.pdf(x), max_relative = 1e-13);
+        // The density is the derivative of the CDF.
+        let h = 1e-6 * x.abs().max(1.);
+        let slope = (rv.cdf(x + h) - rv.cdf(x - h)) / (2. * h);
+        assert_relative_eq!(slope, rv.pdf(x), max_relative = 1e-6);
+
+        let n = 200_000;
+        let sample = rv.rvs(n, Some(&mut Rng::new(3)));
+        let mean = sample.mean().unwrap();
+        let (m, v, _, _) = rv.moments();
+        assert_relative_eq!(mean, m, epsilon = 5. * (v / n as f64).sqrt());
+        assert!(sample
+            .iter()
+            .all(|&x| x >= rv.support().0 && x <= rv.support().1));
+    }
+
+    #[test]
+    fn distributions() {
+        check(&Norm::new(Some(1.), Some(2.)).unwrap(), -0.5);
+        check(&StudentT::new(7., Some(-1.), Some(0.5)).unwrap(), 0.2);
+        check(&StudentT::new(2.5, None, None).unwrap(), -3.);
+        check(&Chi2::new(3., None, None).unwrap(), 1.5);
+        check(&FDist::new(4., 9., None, None).unwrap(), 0.8);
+        check(&Gamma::new(0.7, Some(1.), Some(3.)).unwrap(), 2.);
+        check(&Beta::new(0.6, 2.5, None, None).unwrap(), 0.3);
+        check(&Expon::new(Some(-2.), None).unwrap(), 0.4);
+        check(&LogNorm::new(0.8, None, Some(2.)).unwrap(), 1.1);
+        check(&Uniform::new(Some(-1.), Some(3.)).unwrap(), 0.5);
+    }
+
+    #[test]
+    fn values() {
+        let t = StudentT::new(3., None, None).unwrap();
+        assert_relative_eq!(t.pdf(1.), 0.20674833578317206, max_relative = 1e-13);
+        assert_relative_eq!(t.cdf(-40.), 1.7190340394579263e-05, max_relative = 1e-11);
+        assert_relative_eq!(t.ppf(0.6), 0.2766706623326899, max_relative = 1e-12);
+        let (mean, var, skew, kurtosis) = t.moments();
+        assert_eq!((mean, var, kurtosis), (0., 3., f64::INFINITY));
+        assert!(skew.is_nan());
+
+        let rv = Gamma::new(1., None, None).unwrap();
+        assert_eq!(rv.pdf(0.), 1.);
+        assert_eq!(rv.pdf(-1.), 0.);
+        assert_eq!(rv.logpdf(-1.), f64::NEG_INFINITY);
+        assert_eq!((rv.cdf(-1.), rv.sf(-1.)), (0., 1.));
+        assert_eq!((rv.ppf(0.), rv.ppf(1.)), (0., f64::INFINITY));
+        assert!(rv.ppf(1.5).is_nan() && rv.isf(-0.5).is_nan() && rv.cdf(f64::NAN).is_nan());
+
+        let rv = Chi2::new(10., None, None).unwrap();
+        assert_relative_eq!(rv.sf(100.), 5.4497019829205246e-17, max_relative = 1e-10);
+        assert_relative_eq!(rv.isf(1e-12), 78.47164656283849, max_relative = 1e-10);
+
+        let rv = FDist::new(2., 3., None, None).unwrap();
+        assert_eq!(rv.mean(), 3.);
+        assert_eq!(rv.var(), f64::INFINITY);
+
+        let rv = Beta::new(2., 3., Some(1.), Some(2.)).unwrap();
+        assert_eq!(rv.support(), (1., 3.));
+        assert_relative_eq!(rv.mean(), 1.8, max_relative = 1e-15);
+        assert_relative_eq!(rv.median(), 1.771455136264779, max_relative = 1e-12);
+
+        let rv = Norm::new(None, None).unwrap();
+        let (low, high) = rv.interval(0.99);
+        assert_relative_eq!(high, 2.5758293035489004, max_relative = 1e-14);
+        assert_eq!(low, -high);
+        assert_eq!(rv.std(), 1.);
+    }
+
+    #[test]
+    fn invalid_args() {
+        fn is_invalid<T>(res: Result<T>, name: &str) -> bool {
+            matches!(res, Err(Error::InvalidArg { arg, .. }) if arg == name)
+        }
+        assert!(is_invalid(Norm::new(None, Some(0.)), "scale"));
+        assert!(is_invalid(Norm::new(Some(f64::NAN), None), "loc"));
+        assert!(is_invalid(StudentT::new(-1., None, None), "df"));
+        assert!(is_invalid(FDist::new(1., f64::INFINITY, None, None), "dfd"));
+        assert!(is_invalid(Beta::new(1., 0., None, None), "b"));
+    }
+}
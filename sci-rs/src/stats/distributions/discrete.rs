@@ -0,0 +1,580 @@
+use super::{f64_of, integer_loc, of_f64, positive, xlog1py, xlogy, DiscreteDistribution};
+use crate::special::xsf;
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Logarithm of the binomial coefficient `n choose k`.
+fn lbinom(n: f64, k: f64) -> f64 {
+    xsf::gammaln(n + 1.) - xsf::gammaln(k + 1.) - xsf::gammaln(n - k + 1.)
+}
+
+/// Check that `p` is a probability, within `[0, 1]`, or `(0, 1]` if `zero` is `false`.
+fn probability<F: Float>(p: F, zero: bool) -> Result<F> {
+    let low_ok = if zero { p >= F::zero() } else { p > F::zero() };
+    if low_ok && p <= F::one() {
+        Ok(p)
+    } else if zero {
+        Err(Error::InvalidArg {
+            arg: "p".into(),
+            reason: "must be within [0, 1].".into(),
+        })
+    } else {
+        Err(Error::InvalidArg {
+            arg: "p".into(),
+            reason: "must be within (0, 1].".into(),
+        })
+    }
+}
+
+/// A binomial discrete random variable.
+///
+/// The probability mass function of the standardized distribution is
+/// `f(k) = (n choose k) p^k (1 - p)^(n - k)` for `k` in `0..=n`, the number of successes in `n`
+/// independent trials of probability `p`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import binom
+/// binom.sf(15, n=20, p=0.5)
+/// # 0.005908966064453125
+/// binom.mean(n=20, p=0.5)
+/// # 10.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{Binom, DiscreteDistribution};
+///
+/// let rv = Binom::new(20, 0.5, None).unwrap();
+/// assert_relative_eq!(rv.sf(15.), 0.005908966064453125, max_relative = 1e-12);
+/// assert_eq!(rv.mean(), 10.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binom<F> {
+    n: F,
+    p: F,
+    loc: F,
+}
+
+impl<F: Float> Binom<F> {
+    /// Binomial distribution of `n` trials of probability `p`, shifted by `loc`.
+    ///
+    /// ## Errors
+    /// If `p` is not within `[0, 1]`, or `loc` is not an integer.
+    pub fn new(n: usize, p: F, loc: Option<F>) -> Result<Self> {
+        let p = probability(p, true)?;
+        let loc = integer_loc(loc)?;
+        Ok(Binom {
+            n: F::from(n).unwrap(),
+            p,
+            loc,
+        })
+    }
+}
+
+impl<F: Float> DiscreteDistribution<F> for Binom<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        (F::zero(), self.n)
+    }
+
+    fn standard_logpmf(&self, k: F) -> F {
+        let (n, p, k) = (f64_of(self.n), f64_of(self.p), f64_of(k));
+        of_f64(lbinom(n, k) + xlogy(k, p) + xlog1py(n - k, -p))
+    }
+
+    fn standard_cdf(&self, k: F) -> F {
+        let (n, p, k) = (f64_of(self.n), f64_of(self.p), f64_of(k));
+        of_f64(xsf::betainc(n - k, k + 1., 1. - p))
+    }
+
+    fn standard_sf(&self, k: F) -> F {
+        let (n, p, k) = (f64_of(self.n), f64_of(self.p), f64_of(k));
+        of_f64(xsf::betainc(k + 1., n - k, p))
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        let (n, p) = (f64_of(self.n), f64_of(self.p));
+        let q = 1. - p;
+        let var = n * p * q;
+        (
+            self.n * self.p,
+            of_f64(var),
+            of_f64((q - p) / var.sqrt()),
+            of_f64((1. - 6. * p * q) / var),
+        )
+    }
+}
+
+/// A Poisson discrete random variable.
+///
+/// The probability mass function of the standardized distribution is
+/// `f(k) = exp(-mu) mu^k / k!` for `k >= 0`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import poisson
+/// poisson.pmf(2, mu=3.5)
+/// # 0.18495897346170082
+/// poisson.ppf(0.99, mu=3.5)
+/// # 8.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{DiscreteDistribution, Poisson};
+///
+/// let rv = Poisson::new(3.5, None).unwrap();
+/// assert_relative_eq!(rv.pmf(2.), 0.18495897346170082, max_relative = 1e-13);
+/// assert_eq!(rv.ppf(0.99), 8.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Poisson<F> {
+    mu: F,
+    loc: F,
+}
+
+impl<F: Float> Poisson<F> {
+    /// Poisson distribution of mean `mu`, shifted by `loc`.
+    ///
+    /// ## Errors
+    /// If `mu` is negative or not finite, or `loc` is not an integer.
+    pub fn new(mu: F, loc: Option<F>) -> Result<Self> {
+        if !(mu.is_finite() && mu >= F::zero()) {
+            return Err(Error::InvalidArg {
+                arg: "mu".into(),
+                reason: "must be non-negative and finite.".into(),
+            });
+        }
+        let loc = integer_loc(loc)?;
+        Ok(Poisson { mu, loc })
+    }
+}
+
+impl<F: Float> DiscreteDistribution<F> for Poisson<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        (F::zero(), F::infinity())
+    }
+
+    fn standard_logpmf(&self, k: F) -> F {
+        let (mu, k) = (f64_of(self.mu), f64_of(k));
+        of_f64(xlogy(k, mu) - xsf::gammaln(k + 1.) - mu)
+    }
+
+    fn standard_cdf(&self, k: F) -> F {
+        of_f64(xsf::gammaincc(f64_of(k) + 1., f64_of(self.mu)))
+    }
+
+    fn standard_sf(&self, k: F) -> F {
+        of_f64(xsf::gammainc(f64_of(k) + 1., f64_of(self.mu)))
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        let mu = f64_of(self.mu);
+        (self.mu, self.mu, of_f64(1. / mu.sqrt()), of_f64(1. / mu))
+    }
+}
+
+/// A geometric discrete random variable.
+///
+/// The probability mass function of the standardized distribution is
+/// `f(k) = (1 - p)^(k - 1) p` for `k >= 1`, the number of trials up to the first success of
+/// independent trials of probability `p`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import geom
+/// geom.cdf(3, p=0.2)
+/// # 0.488
+/// geom.ppf(0.5, p=0.2)
+/// # 4.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{DiscreteDistribution, Geom};
+///
+/// let rv = Geom::new(0.2, None).unwrap();
+/// assert_relative_eq!(rv.cdf(3.), 0.488, max_relative = 1e-14);
+/// assert_eq!(rv.ppf(0.5), 4.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geom<F> {
+    p: F,
+    loc: F,
+}
+
+impl<F: Float> Geom<F> {
+    /// Geometric distribution of trials of probability `p`, shifted by `loc`.
+    ///
+    /// ## Errors
+    /// If `p` is not within `(0, 1]`, or `loc` is not an integer.
+    pub fn new(p: F, loc: Option<F>) -> Result<Self> {
+        let p = probability(p, false)?;
+        let loc = integer_loc(loc)?;
+        Ok(Geom { p, loc })
+    }
+}
+
+impl<F: Float> DiscreteDistribution<F> for Geom<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        (F::one(), F::infinity())
+    }
+
+    fn standard_logpmf(&self, k: F) -> F {
+        let (p, k) = (f64_of(self.p), f64_of(k));
+        of_f64(xlog1py(k - 1., -p) + p.ln())
+    }
+
+    fn standard_cdf(&self, k: F) -> F {
+        let (p, k) = (f64_of(self.p), f64_of(k));
+        of_f64(-(k * (-p).ln_1p()).exp_m1())
+    }
+
+    fn standard_sf(&self, k: F) -> F {
+        let (p, k) = (f64_of(self.p), f64_of(k));
+        of_f64((k * (-p).ln_1p()).exp())
+    }
+
+    fn standard_ppf(&self, q: F) -> F {
+        let k = (q.neg().ln_1p() / self.p.neg().ln_1p())
+            .ceil()
+            .max(F::one());
+        // Correct the rounding of the logarithms.
+        if k > F::one() && self.standard_cdf(k - F::one()) >= q {
+            k - F::one()
+        } else {
+            k
+        }
+    }
+
+    fn standard_isf(&self, q: F) -> F {
+        let k = (q.ln() / self.p.neg().ln_1p()).ceil().max(F::one());
+        if k > F::one() && self.standard_sf(k - F::one()) <= q {
+            k - F::one()
+        } else {
+            k
+        }
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        let p = f64_of(self.p);
+        (
+            of_f64(1. / p),
+            of_f64((1. - p) / (p * p)),
+            of_f64((2. - p) / (1. - p).sqrt()),
+            of_f64(6. + p * p / (1. - p)),
+        )
+    }
+}
+
+/// A negative binomial discrete random variable.
+///
+/// The probability mass function of the standardized distribution is
+/// `f(k) = Gamma(k + n) / (Gamma(n) k!) p^n (1 - p)^k` for `k >= 0`, the number of failures
+/// before the `n`-th success of independent trials of probability `p`. `n` may be real.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import nbinom
+/// nbinom.pmf(4, n=3, p=0.4)
+/// # 0.124416
+/// nbinom.var(n=3, p=0.4)
+/// # 11.25
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{DiscreteDistribution, NBinom};
+///
+/// let rv = NBinom::new(3., 0.4, None).unwrap();
+/// assert_relative_eq!(rv.pmf(4.), 0.124416, max_relative = 1e-13);
+/// assert_relative_eq!(rv.var(), 11.25, max_relative = 1e-14);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NBinom<F> {
+    n: F,
+    p: F,
+    loc: F,
+}
+
+impl<F: Float> NBinom<F> {
+    /// Negative binomial distribution of the number of failures before `n` successes of trials
+    /// of probability `p`, shifted by `loc`.
+    ///
+    /// ## Errors
+    /// If `n` is not positive and finite, `p` is not within `(0, 1]`, or `loc` is not an
+    /// integer.
+    pub fn new(n: F, p: F, loc: Option<F>) -> Result<Self> {
+        let n = positive("n", n)?;
+        let p = probability(p, false)?;
+        let loc = integer_loc(loc)?;
+        Ok(NBinom { n, p, loc })
+    }
+}
+
+impl<F: Float> DiscreteDistribution<F> for NBinom<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        (F::zero(), F::infinity())
+    }
+
+    fn standard_logpmf(&self, k: F) -> F {
+        let (n, p, k) = (f64_of(self.n), f64_of(self.p), f64_of(k));
+        let coeff = xsf::gammaln(k + n) - xsf::gammaln(n) - xsf::gammaln(k + 1.);
+        of_f64(coeff + n * p.ln() + xlog1py(k, -p))
+    }
+
+    fn standard_cdf(&self, k: F) -> F {
+        let (n, p, k) = (f64_of(self.n), f64_of(self.p), f64_of(k));
+        of_f64(xsf::betainc(n, k + 1., p))
+    }
+
+    fn standard_sf(&self, k: F) -> F {
+        let (n, p, k) = (f64_of(self.n), f64_of(self.p), f64_of(k));
+        of_f64(xsf::betainc(k + 1., n, 1. - p))
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        let (n, p) = (f64_of(self.n), f64_of(self.p));
+        let q = 1. - p;
+        (
+            of_f64(n * q / p),
+            of_f64(n * q / (p * p)),
+            of_f64((2. - p) / (n * q).sqrt()),
+            of_f64(6. / n + p * p / (n * q)),
+        )
+    }
+}
+
+/// A hypergeometric discrete random variable.
+///
+/// The probability mass function of the standardized distribution is
+/// `f(k) = (n choose k) (M - n choose N - k) / (M choose N)`, the number of successes in `N`
+/// draws without replacement from a population of `M` objects, `n` of which are successes. Its
+/// support is `max(0, N - (M - n))..=min(n, N)`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import hypergeom
+/// hypergeom.pmf(1, M=20, n=7, N=12)
+/// # 0.0043343653250774
+/// hypergeom.cdf(4, M=20, n=7, N=12)
+/// # 0.608359133126935
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{DiscreteDistribution, Hypergeom};
+///
+/// let rv = Hypergeom::new(20, 7, 12, None).unwrap();
+/// assert_relative_eq!(rv.pmf(1.), 0.0043343653250774, max_relative = 1e-12);
+/// assert_relative_eq!(rv.cdf(4.), 0.608359133126935, max_relative = 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hypergeom<F> {
+    total: F,
+    successes: F,
+    draws: F,
+    loc: F,
+}
+
+impl<F: Float> Hypergeom<F> {
+    /// Hypergeometric distribution of `draws` from a population of `total` objects, of which
+    /// `successes` are successes, shifted by `loc`. These are scipy's `N`, `M` and `n`.
+    ///
+    /// ## Errors
+    /// If `successes` or `draws` exceeds `total`, or `loc` is not an integer.
+    pub fn new(total: usize, successes: usize, draws: usize, loc: Option<F>) -> Result<Self> {
+        if successes > total {
+            return Err(Error::InvalidArg {
+                arg: "successes".into(),
+                reason: "must not exceed total.".into(),
+            });
+        }
+        if draws > total {
+            return Err(Error::InvalidArg {
+                arg: "draws".into(),
+                reason: "must not exceed total.".into(),
+            });
+        }
+        let loc = integer_loc(loc)?;
+        Ok(Hypergeom {
+            total: F::from(total).unwrap(),
+            successes: F::from(successes).unwrap(),
+            draws: F::from(draws).unwrap(),
+            loc,
+        })
+    }
+
+    /// Sum of the probabilities of the integers within `[low, high]`.
+    fn sum_pmf(&self, low: F, high: F) -> F {
+        let mut sum = F::zero();
+        let mut k = low;
+        while k <= high {
+            sum = sum + self.standard_pmf(k);
+            k = k + F::one();
+        }
+        sum
+    }
+}
+
+impl<F: Float> DiscreteDistribution<F> for Hypergeom<F> {
+    fn loc(&self) -> F {
+        self.loc
+    }
+
+    fn standard_support(&self) -> (F, F) {
+        let (m, n, draws) = (self.total, self.successes, self.draws);
+        ((draws - (m - n)).max(F::zero()), n.min(draws))
+    }
+
+    fn standard_logpmf(&self, k: F) -> F {
+        let (m, n, draws, k) = (
+            f64_of(self.total),
+            f64_of(self.successes),
+            f64_of(self.draws),
+            f64_of(k),
+        );
+        of_f64(lbinom(n, k) + lbinom(m - n, draws - k) - lbinom(m, draws))
+    }
+
+    fn standard_cdf(&self, k: F) -> F {
+        self.sum_pmf(self.standard_support().0, k).min(F::one())
+    }
+
+    fn standard_sf(&self, k: F) -> F {
+        self.sum_pmf(k + F::one(), self.standard_support().1)
+            .min(F::one())
+    }
+
+    fn standard_moments(&self) -> (F, F, F, F) {
+        let (m, n, draws) = (
+            f64_of(self.total),
+            f64_of(self.successes),
+            f64_of(self.draws),
+        );
+        let failures = m - n;
+        let mean = draws * n / m;
+        let var = failures * n * draws * (m - draws) / (m * m * (m - 1.));
+        let skew = (failures - n) * (m - 2. * draws) / (m - 2.)
+            * ((m - 1.) / (failures * n * draws * (m - draws))).sqrt();
+        let kurtosis =
+            ((m * (m + 1.) - 6. * draws * (m - draws) - 6. * n * failures) * (m - 1.) * m * m
+                + 6. * n * draws * (m - draws) * failures * (5. * m - 6.))
+                / (n * draws * (m - draws) * failures * (m - 2.) * (m - 3.));
+        (of_f64(mean), of_f64(var), of_f64(skew), of_f64(kurtosis))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+    use approx::assert_relative_eq;
+    use sci_rs_core::Error;
+
+    /// Check the consistency of the functions of `rv` over its support, and its moments
+    /// against the probabilities and a large sample.
+    fn check<D: DiscreteDistribution<f64>>(rv: &D, last: f64) {
+        let (low, _) = rv.support();
+        let mut cdf = 0.;
+        let (mut m1, mut m2) = (0., 0.);
+        let mut k = low;
+        while k <= last {
+            let pmf = rv.pmf(k);
+            assert_relative_eq!(rv.logpmf(k).exp(), pmf, max_relative = 1e-12);
+            cdf += pmf;
+            (m1, m2) = (m1 + k * pmf, m2 + k * k * pmf);
+            assert_relative_eq!(rv.cdf(k), cdf, max_relative = 1e-10);
+            assert_relative_eq!(rv.cdf(k) + rv.sf(k), 1., max_relative = 1e-12);
+            assert_eq!(rv.cdf(k + 0.5), rv.cdf(k));
+            assert_eq!(rv.pmf(k + 0.5), 0.);
+            if rv.pmf(k + 1.) > 1e-8 {
+                assert_eq!(rv.ppf(rv.cdf(k) + 1e-9), k + 1.);
+            }
+            if pmf > 1e-12 {
+                assert_eq!(rv.ppf(rv.cdf(k)), k);
+                assert_eq!(rv.isf(rv.sf(k)), k);
+            }
+            k += 1.;
+        }
+        let (mean, var, _, _) = rv.moments();
+        assert_relative_eq!(m1, mean, max_relative = 1e-8);
+        assert_relative_eq!(m2 - m1 * m1, var, max_relative = 1e-8);
+
+        let n = 100_000;
+        let sample = rv.rvs(n, Some(&mut Rng::new(5)));
+        assert_relative_eq!(
+            sample.mean().unwrap(),
+            mean,
+            epsilon = 5. * (var / n as f64).sqrt()
+        );
+    }
+
+    #[test]
+    fn distributions() {
+        check(&Binom::new(12, 0.35, Some(2.)).unwrap(), 14.);
+        check(&Poisson::new(4.2, None).unwrap(), 60.);
+        check(&Geom::new(0.3, Some(-1.)).unwrap(), 120.);
+        check(&NBinom::new(2.5, 0.45, None).unwrap(), 150.);
+        check(&Hypergeom::new(30, 12, 9, None).unwrap(), 9.);
+        check(&Hypergeom::new(10, 7, 6, Some(1.)).unwrap(), 7.);
+    }
+
+    #[test]
+    fn values() {
+        let rv = Binom::new(100, 0.01, None).unwrap();
+        assert_relative_eq!(rv.sf(10.), 6.255518382834089e-09, max_relative = 1e-10);
+        assert_eq!(rv.support(), (0., 100.));
+        assert_eq!((rv.ppf(0.), rv.ppf(1.), rv.isf(0.)), (-1., 100., 100.));
+        assert!(rv.ppf(1.1).is_nan() && rv.pmf(f64::NAN).is_nan());
+
+        let rv = Binom::new(5, 1., None).unwrap();
+        assert_eq!((rv.pmf(5.), rv.cdf(4.), rv.ppf(0.3)), (1., 0., 5.));
+
+        let rv = Poisson::new(0.5, Some(3.)).unwrap();
+        assert_eq!((rv.pmf(2.), rv.cdf(2.), rv.sf(2.)), (0., 0., 1.));
+        assert_relative_eq!(rv.logpmf(3.), -0.5, max_relative = 1e-15);
+        assert_eq!(rv.mean(), 3.5);
+        assert_eq!(rv.interval(0.9), (3., 5.));
+
+        let rv = Geom::new(1., None).unwrap();
+        assert_eq!((rv.pmf(1.), rv.ppf(0.5), rv.isf(0.5)), (1., 1., 1.));
+    }
+
+    #[test]
+    fn invalid_args() {
+        fn is_invalid<T>(res: Result<T>, name: &str) -> bool {
+            matches!(res, Err(Error::InvalidArg { arg, .. }) if arg == name)
+        }
+        assert!(is_invalid(Binom::new(3, 1.5, None), "p"));
+        assert!(is_invalid(Binom::new(3, 0.5, Some(0.5)), "loc"));
+        assert!(is_invalid(Poisson::new(-1., None), "mu"));
+        assert!(is_invalid(Geom::new(0., None), "p"));
+        assert!(is_invalid(NBinom::new(0., 0.5, None), "n"));
+        assert!(is_invalid(
+            Hypergeom::<f64>::new(5, 6, 2, None),
+            "successes"
+        ));
+    }
+}
@@ -0,0 +1,547 @@
+use crate::random::{default_rng, Rng};
+use ndarray::Array1;
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+mod continuous;
+pub use continuous::*;
+
+mod discrete;
+pub use discrete::*;
+
+fn f64_of<F: Float>(x: F) -> f64 {
+    x.to_f64().unwrap()
+}
+
+fn of_f64<F: Float>(x: f64) -> F {
+    F::from(x).unwrap()
+}
+
+/// `a ln(x)`, which is zero for `a = 0` even where the logarithm is infinite.
+fn xlogy(a: f64, x: f64) -> f64 {
+    if a == 0. && !x.is_nan() {
+        0.
+    } else {
+        a * x.ln()
+    }
+}
+
+/// `a ln(1 + x)`, which is zero for `a = 0` even where the logarithm is infinite.
+fn xlog1py(a: f64, x: f64) -> f64 {
+    if a == 0. && !x.is_nan() {
+        0.
+    } else {
+        a * x.ln_1p()
+    }
+}
+
+/// Check the location and scale parameters, 0 and 1 by default.
+fn loc_scale<F: Float>(loc: Option<F>, scale: Option<F>) -> Result<(F, F)> {
+    let loc = loc.unwrap_or(F::zero());
+    let scale = scale.unwrap_or(F::one());
+    if !loc.is_finite() {
+        return Err(Error::InvalidArg {
+            arg: "loc".into(),
+            reason: "must be finite.".into(),
+        });
+    }
+    if !(scale.is_finite() && scale > F::zero()) {
+        return Err(Error::InvalidArg {
+            arg: "scale".into(),
+            reason: "must be positive and finite.".into(),
+        });
+    }
+    Ok((loc, scale))
+}
+
+/// Check that the shape parameter `arg` is positive and finite.
+fn positive<F: Float>(arg: &str, value: F) -> Result<F> {
+    if value.is_finite() && value > F::zero() {
+        Ok(value)
+    } else {
+        Err(Error::InvalidArg {
+            arg: arg.into(),
+            reason: "must be positive and finite.".into(),
+        })
+    }
+}
+
+/// Check that the location parameter of a discrete distribution is an integer, 0 by default.
+fn integer_loc<F: Float>(loc: Option<F>) -> Result<F> {
+    let loc = loc.unwrap_or(F::zero());
+    if loc.is_finite() && loc == loc.floor() {
+        Ok(loc)
+    } else {
+        Err(Error::InvalidArg {
+            arg: "loc".into(),
+            reason: "must be a finite integer.".into(),
+        })
+    }
+}
+
+/// Smallest integer `k` within `[low, high]` for which the monotone predicate `pred` holds,
+/// which is assumed to hold at `high` without evaluating it there. The search doubles its steps
+/// from `low`, then bisects.
+fn search<F: Float>(low: F, high: F, pred: impl Fn(F) -> bool) -> F {
+    let two = F::from(2).unwrap();
+    let (mut lo, mut hi) = (low - F::one(), low);
+    let mut step = F::one();
+    while hi < high && !pred(hi) {
+        lo = hi;
+        hi = (low + step).min(high);
+        step = step * two;
+    }
+    while hi - lo > F::one() {
+        let mid = ((lo + hi) / two).floor();
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
+
+/// A continuous random variable, as scipy's `rv_continuous`.
+///
+/// A distribution is implemented in its standardized form, with the `standard_*` methods, and
+/// the provided methods shift and scale it: if `X` follows the standardized distribution,
+/// `loc + scale X` follows the distribution. The `standard_*` methods are only called with
+/// arguments within the support and probabilities within `(0, 1)`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import norm
+/// rv = norm(loc=1, scale=2)
+/// rv.cdf(3)
+/// # 0.8413447460685429
+/// rv.ppf(0.975)
+/// # 4.919927969080108
+/// rv.interval(0.95)
+/// # (-2.919927969080108, 4.919927969080108)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use sci_rs::stats::{ContinuousDistribution, Norm};
+///
+/// let rv = Norm::new(Some(1.), Some(2.)).unwrap();
+/// assert_abs_diff_eq!(rv.cdf(3.), 0.8413447460685429, epsilon = 1e-15);
+/// assert_abs_diff_eq!(rv.ppf(0.975), 4.919927969080108, epsilon = 1e-14);
+/// let (low, high) = rv.interval(0.95);
+/// assert_abs_diff_eq!(low, -2.919927969080108, epsilon = 1e-14);
+/// assert_abs_diff_eq!(high, 4.919927969080108, epsilon = 1e-14);
+/// ```
+pub trait ContinuousDistribution<F: Float> {
+    /// Location parameter.
+    fn loc(&self) -> F;
+
+    /// Scale parameter.
+    fn scale(&self) -> F;
+
+    /// Lower and upper bounds of the support of the standardized distribution.
+    fn standard_support(&self) -> (F, F);
+
+    /// Logarithm of the probability density function of the standardized distribution.
+    fn standard_logpdf(&self, x: F) -> F;
+
+    /// Probability density function of the standardized distribution.
+    fn standard_pdf(&self, x: F) -> F {
+        self.standard_logpdf(x).exp()
+    }
+
+    /// Cumulative distribution function of the standardized distribution.
+    fn standard_cdf(&self, x: F) -> F;
+
+    /// Survival function of the standardized distribution.
+    fn standard_sf(&self, x: F) -> F {
+        F::one() - self.standard_cdf(x)
+    }
+
+    /// Percent point function of the standardized distribution, the inverse of its CDF.
+    fn standard_ppf(&self, q: F) -> F;
+
+    /// Inverse survival function of the standardized distribution.
+    fn standard_isf(&self, q: F) -> F {
+        self.standard_ppf(F::one() - q)
+    }
+
+    /// Mean, variance, skewness and excess kurtosis of the standardized distribution.
+    fn standard_moments(&self) -> (F, F, F, F);
+
+    /// Draw a sample of the standardized distribution, by inversion of its CDF unless a
+    /// distribution has a better method.
+    fn standard_sample(&self, rng: &mut Rng) -> F {
+        loop {
+            let u: F = rng.random();
+            if u > F::zero() {
+                return self.standard_ppf(u);
+            }
+        }
+    }
+
+    /// Lower and upper bounds of the support.
+    fn support(&self) -> (F, F) {
+        let (low, high) = self.standard_support();
+        (
+            self.loc() + self.scale() * low,
+            self.loc() + self.scale() * high,
+        )
+    }
+
+    /// Probability density function at `x`.
+    fn pdf(&self, x: F) -> F {
+        let z = (x - self.loc()) / self.scale();
+        let (low, high) = self.standard_support();
+        if z.is_nan() {
+            F::nan()
+        } else if z < low || z > high {
+            F::zero()
+        } else {
+            self.standard_pdf(z) / self.scale()
+        }
+    }
+
+    /// Logarithm of the probability density function at `x`.
+    fn logpdf(&self, x: F) -> F {
+        let z = (x - self.loc()) / self.scale();
+        let (low, high) = self.standard_support();
+        if z.is_nan() {
+            F::nan()
+        } else if z < low || z > high {
+            F::neg_infinity()
+        } else {
+            self.standard_logpdf(z) - self.scale().ln()
+        }
+    }
+
+    /// Cumulative distribution function at `x`.
+    fn cdf(&self, x: F) -> F {
+        let z = (x - self.loc()) / self.scale();
+        let (low, high) = self.standard_support();
+        if z.is_nan() {
+            F::nan()
+        } else if z <= low {
+            F::zero()
+        } else if z >= high {
+            F::one()
+        } else {
+            self.standard_cdf(z)
+        }
+    }
+
+    /// Survival function `1 - cdf(x)` at `x`, which is more accurate in the upper tail.
+    fn sf(&self, x: F) -> F {
+        let z = (x - self.loc()) / self.scale();
+        let (low, high) = self.standard_support();
+        if z.is_nan() {
+            F::nan()
+        } else if z <= low {
+            F::one()
+        } else if z >= high {
+            F::zero()
+        } else {
+            self.standard_sf(z)
+        }
+    }
+
+    /// Percent point function, the inverse of the CDF, at the probability `q`. It is NaN for
+    /// `q` outside `[0, 1]`.
+    fn ppf(&self, q: F) -> F {
+        let (low, high) = self.support();
+        if q.is_nan() || q < F::zero() || q > F::one() {
+            F::nan()
+        } else if q.is_zero() {
+            low
+        } else if q == F::one() {
+            high
+        } else {
+            self.loc() + self.scale() * self.standard_ppf(q)
+        }
+    }
+
+    /// Inverse survival function at the probability `q`. It is NaN for `q` outside `[0, 1]`.
+    fn isf(&self, q: F) -> F {
+        let (low, high) = self.support();
+        if q.is_nan() || q < F::zero() || q > F::one() {
+            F::nan()
+        } else if q.is_zero() {
+            high
+        } else if q == F::one() {
+            low
+        } else {
+            self.loc() + self.scale() * self.standard_isf(q)
+        }
+    }
+
+    /// Draw `size` random variates.
+    ///
+    /// A generator seeded from the operating system is used if `rng` is not given.
+    fn rvs(&self, size: usize, rng: Option<&mut Rng>) -> Array1<F> {
+        let mut default = None;
+        let rng = rng.unwrap_or_else(|| default.insert(default_rng()));
+        Array1::from_shape_simple_fn(size, || {
+            self.loc() + self.scale() * self.standard_sample(rng)
+        })
+    }
+
+    /// Mean, variance, skewness and excess kurtosis, as scipy's `stats(moments='mvsk')`.
+    ///
+    /// A moment is infinite if the integral defining it diverges to infinity, and NaN if it does
+    /// not exist.
+    fn moments(&self) -> (F, F, F, F) {
+        let (mean, var, skew, kurtosis) = self.standard_moments();
+        let scale = self.scale();
+        (
+            self.loc() + scale * mean,
+            scale * scale * var,
+            skew,
+            kurtosis,
+        )
+    }
+
+    /// Mean of the distribution.
+    fn mean(&self) -> F {
+        self.moments().0
+    }
+
+    /// Variance of the distribution.
+    fn var(&self) -> F {
+        self.moments().1
+    }
+
+    /// Standard deviation of the distribution.
+    fn std(&self) -> F {
+        self.var().sqrt()
+    }
+
+    /// Median of the distribution.
+    fn median(&self) -> F {
+        self.ppf(F::from(0.5).unwrap())
+    }
+
+    /// Confidence interval with equal areas around the median, which contains the fraction
+    /// `confidence` of the distribution. It is NaN for `confidence` outside `[0, 1]`.
+    fn interval(&self, confidence: F) -> (F, F) {
+        let alpha = (F::one() - confidence) / F::from(2).unwrap();
+        (self.ppf(alpha), self.isf(alpha))
+    }
+}
+
+/// A discrete random variable, as scipy's `rv_discrete`.
+///
+/// As for [ContinuousDistribution], a distribution is implemented in its standardized form,
+/// with the `standard_*` methods, and the provided methods shift it by the integer `loc`. The
+/// support is a range of integers, and the `standard_*` methods are only called with integers
+/// within it and probabilities within `(0, 1)`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import binom
+/// rv = binom(n=10, p=0.3)
+/// rv.pmf(3)
+/// # 0.2668279319999998
+/// rv.cdf(3)
+/// # 0.6496107184
+/// rv.ppf(0.9)
+/// # 5.0
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_relative_eq;
+/// use sci_rs::stats::{Binom, DiscreteDistribution};
+///
+/// let rv = Binom::new(10, 0.3, None).unwrap();
+/// assert_relative_eq!(rv.pmf(3.), 0.2668279319999998, max_relative = 1e-13);
+/// assert_relative_eq!(rv.cdf(3.), 0.6496107184, max_relative = 1e-13);
+/// assert_eq!(rv.ppf(0.9), 5.);
+/// ```
+pub trait DiscreteDistribution<F: Float> {
+    /// Location parameter, an integer.
+    fn loc(&self) -> F;
+
+    /// Lower and upper bounds of the support of the standardized distribution, which are
+    /// integers or infinite.
+    fn standard_support(&self) -> (F, F);
+
+    /// Logarithm of the probability mass function of the standardized distribution.
+    fn standard_logpmf(&self, k: F) -> F;
+
+    /// Probability mass function of the standardized distribution.
+    fn standard_pmf(&self, k: F) -> F {
+        self.standard_logpmf(k).exp()
+    }
+
+    /// Cumulative distribution function of the standardized distribution.
+    fn standard_cdf(&self, k: F) -> F;
+
+    /// Survival function of the standardized distribution.
+    fn standard_sf(&self, k: F) -> F {
+        F::one() - self.standard_cdf(k)
+    }
+
+    /// Percent point function of the standardized distribution, the smallest `k` for which
+    /// `cdf(k) >= q`. It searches the support by default.
+    fn standard_ppf(&self, q: F) -> F {
+        let (low, high) = self.standard_support();
+        search(low, high, |k| self.standard_cdf(k) >= q)
+    }
+
+    /// Inverse survival function of the standardized distribution, the smallest `k` for which
+    /// `sf(k) <= q`. It searches the support by default.
+    fn standard_isf(&self, q: F) -> F {
+        let (low, high) = self.standard_support();
+        search(low, high, |k| self.standard_sf(k) <= q)
+    }
+
+    /// Mean, variance, skewness and excess kurtosis of the standardized distribution.
+    fn standard_moments(&self) -> (F, F, F, F);
+
+    /// Draw a sample of the standardized distribution, by inversion of its CDF unless a
+    /// distribution has a better method.
+    fn standard_sample(&self, rng: &mut Rng) -> F {
+        loop {
+            let u: F = rng.random();
+            if u > F::zero() {
+                return self.standard_ppf(u);
+            }
+        }
+    }
+
+    /// Lower and upper bounds of the support.
+    fn support(&self) -> (F, F) {
+        let (low, high) = self.standard_support();
+        (self.loc() + low, self.loc() + high)
+    }
+
+    /// Probability mass function at `k`, which is zero if `k` is not an integer.
+    fn pmf(&self, k: F) -> F {
+        let z = k - self.loc();
+        let (low, high) = self.standard_support();
+        if z.is_nan() {
+            F::nan()
+        } else if z < low || z > high || z != z.floor() {
+            F::zero()
+        } else {
+            self.standard_pmf(z)
+        }
+    }
+
+    /// Logarithm of the probability mass function at `k`.
+    fn logpmf(&self, k: F) -> F {
+        let z = k - self.loc();
+        let (low, high) = self.standard_support();
+        if z.is_nan() {
+            F::nan()
+        } else if z < low || z > high || z != z.floor() {
+            F::neg_infinity()
+        } else {
+            self.standard_logpmf(z)
+        }
+    }
+
+    /// Cumulative distribution function at `k`, the probability of the values up to `k`
+    /// included.
+    fn cdf(&self, k: F) -> F {
+        let z = (k - self.loc()).floor();
+        let (low, high) = self.standard_support();
+        if z.is_nan() {
+            F::nan()
+        } else if z < low {
+            F::zero()
+        } else if z >= high {
+            F::one()
+        } else {
+            self.standard_cdf(z)
+        }
+    }
+
+    /// Survival function `1 - cdf(k)` at `k`, the probability of the values above `k`.
+    fn sf(&self, k: F) -> F {
+        let z = (k - self.loc()).floor();
+        let (low, high) = self.standard_support();
+        if z.is_nan() {
+            F::nan()
+        } else if z < low {
+            F::one()
+        } else if z >= high {
+            F::zero()
+        } else {
+            self.standard_sf(z)
+        }
+    }
+
+    /// Percent point function, the smallest `k` for which `cdf(k) >= q`. It is NaN for `q`
+    /// outside `[0, 1]`, and one less than the lower bound of the support for `q = 0`.
+    fn ppf(&self, q: F) -> F {
+        let (low, high) = self.support();
+        if q.is_nan() || q < F::zero() || q > F::one() {
+            F::nan()
+        } else if q.is_zero() {
+            low - F::one()
+        } else if q == F::one() {
+            high
+        } else {
+            self.loc() + self.standard_ppf(q)
+        }
+    }
+
+    /// Inverse survival function, the smallest `k` for which `sf(k) <= q`. It is NaN for `q`
+    /// outside `[0, 1]`.
+    fn isf(&self, q: F) -> F {
+        let (low, high) = self.support();
+        if q.is_nan() || q < F::zero() || q > F::one() {
+            F::nan()
+        } else if q.is_zero() {
+            high
+        } else if q == F::one() {
+            low - F::one()
+        } else {
+            self.loc() + self.standard_isf(q)
+        }
+    }
+
+    /// Draw `size` random variates.
+    ///
+    /// A generator seeded from the operating system is used if `rng` is not given.
+    fn rvs(&self, size: usize, rng: Option<&mut Rng>) -> Array1<F> {
+        let mut default = None;
+        let rng = rng.unwrap_or_else(|| default.insert(default_rng()));
+        Array1::from_shape_simple_fn(size, || self.loc() + self.standard_sample(rng))
+    }
+
+    /// Mean, variance, skewness and excess kurtosis, as scipy's `stats(moments='mvsk')`.
+    fn moments(&self) -> (F, F, F, F) {
+        let (mean, var, skew, kurtosis) = self.standard_moments();
+        (self.loc() + mean, var, skew, kurtosis)
+    }
+
+    /// Mean of the distribution.
+    fn mean(&self) -> F {
+        self.moments().0
+    }
+
+    /// Variance of the distribution.
+    fn var(&self) -> F {
+        self.moments().1
+    }
+
+    /// Standard deviation of the distribution.
+    fn std(&self) -> F {
+        self.var().sqrt()
+    }
+
+    /// Median of the distribution.
+    fn median(&self) -> F {
+        self.ppf(F::from(0.5).unwrap())
+    }
+
+    /// Interval with equal areas around the median, which contains at least the fraction
+    /// `confidence` of the distribution. It is NaN for `confidence` outside `[0, 1]`.
+    fn interval(&self, confidence: F) -> (F, F) {
+        let alpha = (F::one() - confidence) / F::from(2).unwrap();
+        (self.ppf(alpha), self.isf(alpha))
+    }
+}
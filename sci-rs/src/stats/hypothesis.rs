@@ -0,0 +1,135 @@
+use super::ContinuousDistribution;
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use alloc::vec::Vec;
+use ndarray::{Array, ArrayBase, ArrayView1, Axis, Data, RemoveAxis, Zip};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// The alternative hypothesis of a statistical test.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Alternative {
+    /// The statistic differs from its value under the null hypothesis, in either direction.
+    #[default]
+    TwoSided,
+    /// The statistic is less than its value under the null hypothesis.
+    Less,
+    /// The statistic is greater than its value under the null hypothesis.
+    Greater,
+}
+
+/// How to handle NaN in the input of a statistical test.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// A NaN in the input makes the statistic and p-value NaN.
+    #[default]
+    Propagate,
+    /// A NaN in the input is an error.
+    Raise,
+    /// The NaNs are omitted from the input, along with the observations paired to them.
+    Omit,
+}
+
+/// Result of a statistical test, of a statistic and its p-value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignificanceResult<F> {
+    /// The test statistic.
+    pub statistic: F,
+    /// The p-value associated with the given alternative.
+    pub pvalue: F,
+}
+
+/// A confidence interval, of the bounds `low` and `high`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfidenceInterval<T> {
+    /// Lower bound.
+    pub low: T,
+    /// Upper bound.
+    pub high: T,
+}
+
+/// P-value of `statistic`, following `dist` under the null hypothesis, against `alternative`.
+pub(crate) fn pvalue<F: Float>(
+    dist: &impl ContinuousDistribution<F>,
+    statistic: F,
+    alternative: Alternative,
+) -> F {
+    // `min` would turn a NaN statistic into a p-value of 1.
+    if statistic.is_nan() {
+        return F::nan();
+    }
+    match alternative {
+        Alternative::Less => dist.cdf(statistic),
+        Alternative::Greater => dist.sf(statistic),
+        Alternative::TwoSided => (F::from(2).unwrap() * dist.sf(statistic.abs())).min(F::one()),
+    }
+}
+
+/// Check that `confidence_level` is within `(0, 1)`, 0.95 by default.
+pub(crate) fn check_confidence_level<F: Float>(confidence_level: Option<F>) -> Result<F> {
+    let confidence_level = confidence_level.unwrap_or(F::from(0.95).unwrap());
+    if confidence_level > F::zero() && confidence_level < F::one() {
+        Ok(confidence_level)
+    } else {
+        Err(Error::InvalidArg {
+            arg: "confidence_level".into(),
+            reason: "must be within (0, 1).".into(),
+        })
+    }
+}
+
+/// Apply `f` to the pairs of 1-D lanes of `a` and `b` along `axis`, 0 by default, reducing that
+/// axis. `a` and `b` may only differ in length along `axis`.
+pub(crate) fn reduce_axis2<F, B, S, T, D>(
+    a: &ArrayBase<S, D>,
+    b: &ArrayBase<T, D>,
+    axis: Option<isize>,
+    f: impl FnMut(ArrayView1<F>, ArrayView1<F>) -> B,
+) -> Result<Array<B, D::Smaller>>
+where
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let axis = check_and_get_axis_dyn(Some(axis.unwrap_or(0)), a)?;
+    let same_shape = a.ndim() == b.ndim()
+        && (0..a.ndim()).all(|i| i == axis || a.len_of(Axis(i)) == b.len_of(Axis(i)));
+    if !same_shape {
+        return Err(Error::InvalidArg {
+            arg: "b".into(),
+            reason: "must have the shape of a, except along axis.".into(),
+        });
+    }
+    Ok(Zip::from(a.lanes(Axis(axis)))
+        .and(b.lanes(Axis(axis)))
+        .map_collect(f))
+}
+
+/// The pairs of observations of `x` and `y` following `nan_policy`, or `None` if a NaN
+/// propagates.
+pub(crate) fn paired<F: Float>(
+    x: ArrayView1<F>,
+    y: ArrayView1<F>,
+    nan_policy: NanPolicy,
+) -> Result<Option<(Vec<F>, Vec<F>)>> {
+    if x.len() != y.len() {
+        return Err(Error::InvalidArg {
+            arg: "y".into(),
+            reason: "must have the length of x.".into(),
+        });
+    }
+    let has_nan = x.iter().chain(y.iter()).any(|v| v.is_nan());
+    match nan_policy {
+        NanPolicy::Propagate if has_nan => Ok(None),
+        NanPolicy::Raise if has_nan => Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "the input contains NaN.".into(),
+        }),
+        _ => Ok(Some(
+            x.iter()
+                .zip(y.iter())
+                .filter(|(a, b)| !a.is_nan() && !b.is_nan())
+                .map(|(&a, &b)| (a, b))
+                .unzip(),
+        )),
+    }
+}
@@ -0,0 +1,562 @@
+use super::descriptive::sorted;
+use super::{nan_result, Alternative, SignificanceResult};
+use crate::special::xsf::{gammaln, kolmogorov, smirnov};
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use ndarray::{ArrayBase, Data, Ix1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Result of the Kolmogorov-Smirnov tests [kstest] and [ks_2samp].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KstestResult<F> {
+    /// The KS statistic.
+    pub statistic: F,
+    /// The p-value associated with the given alternative.
+    pub pvalue: F,
+    /// The value of the observations at which the statistic is attained.
+    pub statistic_location: F,
+    /// 1 if the statistic is the maximum positive difference between the empirical and the
+    /// reference (or second empirical) cumulative distribution functions, -1 if it is the
+    /// maximum negative difference.
+    pub statistic_sign: i8,
+}
+
+/// Method to compute the p-value of a Kolmogorov-Smirnov test.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KsMethod {
+    /// [KsMethod::Exact] for [kstest], and for [ks_2samp] unless a sample has more than 10000
+    /// observations, [KsMethod::Asymp] otherwise.
+    #[default]
+    Auto,
+    /// Exact distribution of the statistic.
+    Exact,
+    /// Asymptotic distribution of the statistic.
+    Asymp,
+}
+
+/// The [nan_result] of a test, without a location of the statistic.
+fn nan_kstest_result<F: Float>() -> KstestResult<F> {
+    let SignificanceResult { statistic, pvalue } = nan_result();
+    KstestResult {
+        statistic,
+        pvalue,
+        statistic_location: F::nan(),
+        statistic_sign: 1,
+    }
+}
+
+/// Cumulative distribution function of the two-sided statistic `D_n` for `n` samples, from
+/// the matrix method of Durbin as implemented by Marsaglia, Tsang and Wang.
+fn kolmogn_durbin(n: usize, d: f64) -> f64 {
+    let k = (n as f64 * d) as usize + 1;
+    let m = 2 * k - 1;
+    let h = k as f64 - n as f64 * d;
+    let mut a = alloc::vec![0.; m * m];
+    for i in 0..m {
+        for j in 0..=(i + 1).min(m - 1) {
+            a[i * m + j] = 1.;
+        }
+    }
+    for i in 0..m {
+        a[i * m] -= h.powi(i as i32 + 1);
+        a[(m - 1) * m + i] -= h.powi((m - i) as i32);
+    }
+    if 2. * h > 1. {
+        a[(m - 1) * m] += (2. * h - 1.).powi(m as i32);
+    }
+    for i in 0..m {
+        for j in 0..=i.min(m - 1) {
+            for g in 1..=(i - j + 1) {
+                a[i * m + j] /= g as f64;
+            }
+        }
+    }
+
+    // Raise to the power n, keeping the decimal exponent apart to avoid overflows.
+    let multiply = |x: &[f64], y: &[f64]| {
+        let mut z = alloc::vec![0.; m * m];
+        for i in 0..m {
+            for l in 0..m {
+                let xil = x[i * m + l];
+                for j in 0..m {
+                    z[i * m + j] += xil * y[l * m + j];
+                }
+            }
+        }
+        z
+    };
+    let mut power = a.clone();
+    let mut exponent = 0;
+    for bit in (0..usize::BITS - 1 - n.leading_zeros()).rev() {
+        power = multiply(&power, &power);
+        exponent *= 2;
+        if (n >> bit) & 1 == 1 {
+            power = multiply(&a, &power);
+        }
+        if power[(m / 2) * m + m / 2] > 1e140 {
+            power.iter_mut().for_each(|v| *v *= 1e-140);
+            exponent += 140;
+        }
+    }
+    let mut s = power[(k - 1) * m + k - 1];
+    for i in 1..=n {
+        s = s * i as f64 / n as f64;
+        if s < 1e-140 {
+            s *= 1e140;
+            exponent -= 140;
+        }
+    }
+    s * 10f64.powi(exponent)
+}
+
+/// Pelz-Good asymptotic approximation of the complementary cumulative distribution function of
+/// the two-sided statistic `D_n` for `n` samples, to order `1/n^1.5`.
+fn kolmogn_pelz_good(n: usize, x: f64) -> f64 {
+    let (pi2, pi4, pi6) = (PI * PI, PI.powi(4), PI.powi(6));
+    let nf = n as f64;
+    let z = nf.sqrt() * x;
+    let (z2, z3, z4, z6) = (z * z, z.powi(3), z.powi(4), z.powi(6));
+    let qlog = -pi2 / 8. / z2;
+    if qlog < f64::MIN_POSITIVE.ln() {
+        return 1.;
+    }
+    let q = qlog.exp();
+
+    let (k1a, k1b) = (-z2, pi2 / 4.);
+    let k2a = 6. * z6 + 2. * z4;
+    let k2b = (2. * z4 - 5. * z2) * pi2 / 4.;
+    let k2c = pi4 * (1. - 2. * z2) / 16.;
+    let k3a = -30. * z6 - 90. * z.powi(8);
+    let k3b = pi2 * (135. * z4 - 96. * z6) / 4.;
+    let k3c = pi4 * (-60. * z2 + 212. * z4) / 16.;
+    let k3d = pi6 * (5. - 30. * z2) / 64.;
+
+    // Sums over the odd integers of c_i q^(i^2), with a Horner scheme.
+    let mut terms = [0.; 4];
+    let maxk = (16. * z / PI).ceil() as i32;
+    for k in (1..=maxk).rev() {
+        let m = (2 * k - 1) as f64;
+        let (m2, m4, m6) = (m * m, m.powi(4), m.powi(6));
+        let qpower = q.powi(8 * k);
+        let coefs = [
+            1.,
+            k1a + k1b * m2,
+            k2a + k2b * m2 + k2c * m4,
+            k3a + k3b * m2 + k3c * m4 + k3d * m6,
+        ];
+        for (t, c) in terms.iter_mut().zip(coefs) {
+            *t = *t * qpower + c;
+        }
+    }
+    let denominators = [z, 6. * z4, 72. * z.powi(7), 6480. * z.powi(10)];
+    for (t, den) in terms.iter_mut().zip(denominators) {
+        *t *= q * (2. * PI).sqrt() / den;
+    }
+
+    // The other sums, over all the integers.
+    let q = (-pi2 / 2. / z2).exp();
+    let (mut k2extra, mut k3extra) = (0., 0.);
+    for k in 1..=maxk {
+        let kf = k as f64;
+        let qpower = q.powi(k * k);
+        k2extra += kf * kf * qpower;
+        k3extra += (3. * z2 - pi2 * kf * kf) * kf * kf * qpower;
+    }
+    terms[2] += k2extra * pi2 * (2. * PI).sqrt() / (-36. * z3);
+    terms[3] += k3extra * pi2 * (2. * PI).sqrt() / (216. * z6);
+
+    let cdf: f64 = terms
+        .iter()
+        .enumerate()
+        .map(|(i, t)| t / nf.powf(i as f64 / 2.))
+        .sum();
+    1. - cdf
+}
+
+/// Complementary cumulative distribution function of the two-sided statistic `D_n` for `n`
+/// samples.
+fn kolmogn(n: usize, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    let nf = n as f64;
+    let t = nf * x;
+    if t <= 0.5 {
+        return 1.;
+    }
+    if x >= 1. {
+        return 0.;
+    }
+    let sf = if t <= 1. {
+        1. - (gammaln(nf + 1.) + nf * ((2. * t - 1.) / nf).ln()).exp()
+    } else if t >= nf - 1. {
+        2. * (1. - x).powi(n as i32)
+    } else if x >= 0.5 {
+        // The one-sided events are then disjoint.
+        2. * smirnov(n, x)
+    } else if n <= 140 {
+        if t * x >= 4. {
+            2. * smirnov(n, x)
+        } else {
+            1. - kolmogn_durbin(n, x)
+        }
+    } else if t * x >= 370. {
+        0.
+    } else if t * x >= 2.2 {
+        2. * smirnov(n, x)
+    } else {
+        kolmogn_pelz_good(n, x)
+    };
+    sf.clamp(0., 1.)
+}
+
+/// Performs the one-sample Kolmogorov-Smirnov test for goodness of fit.
+///
+/// This test compares the underlying distribution F(x) of a sample against a given continuous
+/// distribution G(x).
+///
+/// ## Parameters
+/// * `rvs`: The sample.
+/// * `cdf`: The cumulative distribution function G(x) of the reference distribution.
+/// * `alternative`: Defines the [Alternative] hypothesis, two-sided by default. For
+///   [Alternative::Less], F(x) < G(x) for at least one x, and for [Alternative::Greater],
+///   F(x) > G(x) for at least one x. The null hypothesis is F(x) = G(x) for all x for the
+///   two-sided alternative, and respectively F(x) >= G(x) and F(x) <= G(x) for all x for the
+///   one-sided ones.
+/// * `method`: The [KsMethod] of the p-value for the two-sided alternative,
+///   [KsMethod::Auto] by default. The p-value is always exact for the one-sided
+///   alternatives.
+///
+/// ## Returns
+/// The [KstestResult]. All its values are NaN if `rvs` contains NaN.
+///
+/// ## Errors
+/// If `rvs` is empty.
+///
+/// ## Notes
+/// The exact two-sided p-value is computed with the method of Durbin for up to 140
+/// observations, and otherwise from the Pelz-Good approximation or twice the one-sided
+/// p-value, whose error is then negligible. The asymptotic p-value is that of the Kolmogorov
+/// distribution.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// x = [0.61, -1.23, 0.35, 1.92, -0.48, 0.07, 1.31, -0.89, 0.52, 2.44]
+/// res = stats.kstest(x, stats.norm.cdf)
+/// res.statistic, res.pvalue, res.statistic_location, res.statistic_sign
+/// # (0.23683065117561906, 0.5521791848419182, 0.35, -1)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::{kstest, ContinuousDistribution, Norm};
+///
+/// let x = array![0.61, -1.23, 0.35, 1.92, -0.48, 0.07, 1.31, -0.89, 0.52, 2.44];
+/// let norm = Norm::new(None, None).unwrap();
+/// let res = kstest(&x, |v| norm.cdf(v), None, None).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 0.23683065117561906, epsilon = 1e-15);
+/// assert_abs_diff_eq!(res.pvalue, 0.5521791848419182, epsilon = 1e-13);
+/// assert_eq!((res.statistic_location, res.statistic_sign), (0.35, -1));
+/// ```
+pub fn kstest<F, S>(
+    rvs: &ArrayBase<S, Ix1>,
+    cdf: impl Fn(F) -> F,
+    alternative: Option<Alternative>,
+    method: Option<KsMethod>,
+) -> Result<KstestResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    if rvs.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "rvs".into(),
+            reason: "must not be empty.".into(),
+        });
+    }
+    if rvs.iter().any(|v| v.is_nan()) {
+        return Ok(nan_kstest_result());
+    }
+    let x = sorted(rvs.view());
+    let n = x.len();
+    let nf = F::from(n).unwrap();
+    let (mut d_plus, mut d_minus) = ((F::neg_infinity(), x[0]), (F::neg_infinity(), x[0]));
+    for (i, &v) in x.iter().enumerate() {
+        let g = cdf(v);
+        let plus = F::from(i + 1).unwrap() / nf - g;
+        let minus = g - F::from(i).unwrap() / nf;
+        if plus > d_plus.0 {
+            d_plus = (plus, v);
+        }
+        if minus > d_minus.0 {
+            d_minus = (minus, v);
+        }
+    }
+    let f64_of = |v: F| v.to_f64().unwrap();
+    let ((statistic, statistic_location), statistic_sign, pvalue) =
+        match alternative.unwrap_or_default() {
+            Alternative::Greater => (d_plus, 1, smirnov(n, f64_of(d_plus.0))),
+            Alternative::Less => (d_minus, -1, smirnov(n, f64_of(d_minus.0))),
+            Alternative::TwoSided => {
+                let (d, sign) = if d_plus.0 > d_minus.0 {
+                    (d_plus, 1)
+                } else {
+                    (d_minus, -1)
+                };
+                let pvalue = match method.unwrap_or_default() {
+                    KsMethod::Auto | KsMethod::Exact => kolmogn(n, f64_of(d.0)),
+                    KsMethod::Asymp => kolmogorov((n as f64).sqrt() * f64_of(d.0)),
+                };
+                (d, sign, pvalue)
+            }
+        };
+    Ok(KstestResult {
+        statistic,
+        pvalue: F::from(pvalue).unwrap(),
+        statistic_location,
+        statistic_sign,
+    })
+}
+
+/// Probability that the two-sample statistic is at least `bound / (n1 n2)` under the null
+/// hypothesis, from the lattice paths of the merged samples which cross the bound.
+fn ks_2samp_exact(n1: usize, n2: usize, bound: i64, alternative: Alternative) -> f64 {
+    let total = (n1 + n2) as f64;
+    let outside = |i: usize, j: usize| {
+        // (i / n1 - j / n2) n1 n2
+        let diff = (i * n2) as i64 - (j * n1) as i64;
+        match alternative {
+            Alternative::Greater => diff >= bound,
+            Alternative::Less => -diff >= bound,
+            Alternative::TwoSided => diff.abs() >= bound,
+        }
+    };
+    // Probability of reaching (i, j) without crossing the bound, updated in place row by row.
+    let mut reach = alloc::vec![0.; n2 + 1];
+    let mut pvalue = 0.;
+    for i in 0..=n1 {
+        for j in 0..=n2 {
+            if i == 0 && j == 0 {
+                reach[0] = 1.;
+                continue;
+            }
+            let remaining = total - (i + j) as f64 + 1.;
+            let mut p = 0.;
+            if i > 0 {
+                p += reach[j] * (n1 - i + 1) as f64 / remaining;
+            }
+            if j > 0 {
+                p += reach[j - 1] * (n2 - j + 1) as f64 / remaining;
+            }
+            if outside(i, j) {
+                pvalue += p;
+                reach[j] = 0.;
+            } else {
+                reach[j] = p;
+            }
+        }
+    }
+    pvalue
+}
+
+/// Performs the two-sample Kolmogorov-Smirnov test for goodness of fit.
+///
+/// This test compares the underlying continuous distributions F(x) and G(x) of two
+/// independent samples.
+///
+/// ## Parameters
+/// * `data1`, `data2`: The two samples, which may differ in size.
+/// * `alternative`: Defines the [Alternative] hypothesis, two-sided by default. For
+///   [Alternative::Less], F(x) < G(x) for at least one x, and for [Alternative::Greater],
+///   F(x) > G(x) for at least one x. The null hypothesis is F(x) = G(x) for all x for the
+///   two-sided alternative, and respectively F(x) >= G(x) and F(x) <= G(x) for all x for the
+///   one-sided ones.
+/// * `method`: The [KsMethod] of the p-value, [KsMethod::Auto] by default.
+///
+/// ## Returns
+/// The [KstestResult]. All its values are NaN if either sample contains NaN.
+///
+/// ## Errors
+/// If either sample is empty.
+///
+/// ## Notes
+/// The exact p-value counts the orderings of the merged samples, in `O(n1 n2)` time, and
+/// assumes that there are no ties. The asymptotic p-value is that of the one-sample test
+/// with `n1 n2 / (n1 + n2)` observations for the two-sided alternative, and the approximation
+/// of Hodges for the one-sided ones.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// a = [1.2, 3.4, 0.5, 2.2, 4.1, 1.8]
+/// b = [2.9, 5.1, 3.3, 4.4, 6.0, 2.5, 5.5, 3.8]
+/// res = stats.ks_2samp(a, b)
+/// res.statistic, res.pvalue, res.statistic_location, res.statistic_sign
+/// # (0.6666666666666667, 0.06060606060606061, 2.2, 1)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::ks_2samp;
+///
+/// let a = array![1.2, 3.4, 0.5, 2.2, 4.1, 1.8];
+/// let b = array![2.9, 5.1, 3.3, 4.4, 6.0, 2.5, 5.5, 3.8];
+/// let res = ks_2samp(&a, &b, None, None).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 0.6666666666666667, epsilon = 1e-15);
+/// assert_abs_diff_eq!(res.pvalue, 0.06060606060606061, epsilon = 1e-15);
+/// assert_eq!((res.statistic_location, res.statistic_sign), (2.2, 1));
+/// ```
+pub fn ks_2samp<F, S, T>(
+    data1: &ArrayBase<S, Ix1>,
+    data2: &ArrayBase<T, Ix1>,
+    alternative: Option<Alternative>,
+    method: Option<KsMethod>,
+) -> Result<KstestResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+{
+    for (arg, data) in [("data1", data1.view()), ("data2", data2.view())] {
+        if data.is_empty() {
+            return Err(Error::InvalidArg {
+                arg: arg.into(),
+                reason: "must not be empty.".into(),
+            });
+        }
+    }
+    if data1.iter().chain(data2.iter()).any(|v| v.is_nan()) {
+        return Ok(nan_kstest_result());
+    }
+    let (x1, x2) = (sorted(data1.view()), sorted(data2.view()));
+    let (n1, n2) = (x1.len(), x2.len());
+    let ecdf = |x: &[F], v: F| F::from(x.partition_point(|&u| u <= v)).unwrap();
+    let (nf1, nf2) = (F::from(n1).unwrap(), F::from(n2).unwrap());
+    let (mut max, mut min) = ((F::neg_infinity(), x1[0]), (F::infinity(), x1[0]));
+    for &v in x1.iter().chain(x2.iter()) {
+        let diff = ecdf(&x1, v) / nf1 - ecdf(&x2, v) / nf2;
+        if diff > max.0 {
+            max = (diff, v);
+        }
+        if diff < min.0 {
+            min = (diff, v);
+        }
+    }
+    let min = (-min.0.min(F::zero()), min.1);
+    let alternative = alternative.unwrap_or_default();
+    let ((statistic, statistic_location), statistic_sign) = if alternative == Alternative::Less
+        || (alternative == Alternative::TwoSided && min.0 > max.0)
+    {
+        (min, -1)
+    } else {
+        (max, 1)
+    };
+
+    let d = statistic.to_f64().unwrap();
+    let exact = match method.unwrap_or_default() {
+        KsMethod::Auto => n1.max(n2) <= 10000,
+        KsMethod::Exact => true,
+        KsMethod::Asymp => false,
+    };
+    let pvalue = if exact {
+        let bound = (d * (n1 * n2) as f64).round() as i64;
+        if bound == 0 {
+            1.
+        } else {
+            ks_2samp_exact(n1, n2, bound, alternative)
+        }
+    } else {
+        let (m, n) = (n1.max(n2) as f64, n1.min(n2) as f64);
+        let en = m * n / (m + n);
+        match alternative {
+            Alternative::TwoSided => kolmogn(en.round() as usize, d),
+            _ => {
+                let z = en.sqrt() * d;
+                (-2. * z * z - 2. * z * (m + 2. * n) / (m * n * (m + n)).sqrt() / 3.).exp()
+            }
+        }
+    };
+    Ok(KstestResult {
+        statistic,
+        pvalue: F::from(pvalue.clamp(0., 1.)).unwrap(),
+        statistic_location,
+        statistic_sign,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::{ContinuousDistribution, Norm};
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn kolmogorov_smirnov_distribution() {
+        assert_abs_diff_eq!(kolmogn(10, 0.3), 0.2705355748, epsilon = 1e-14);
+        assert_abs_diff_eq!(kolmogn(10, 0.55), 0.0022805103214843725, epsilon = 1e-16);
+        // Pelz-Good approximation.
+        assert_abs_diff_eq!(kolmogn(150, 0.1), 0.09292120413826134, epsilon = 1e-6);
+        assert_abs_diff_eq!(kolmogn(200, 0.05), 0.6802627254395576, epsilon = 1e-6);
+        assert_abs_diff_eq!(kolmogn(300, 0.03), 0.9424658715715344, epsilon = 1e-6);
+        assert_eq!(kolmogn(10, 0.04), 1.);
+        assert_eq!(kolmogn(10, 1.), 0.);
+    }
+
+    #[test]
+    fn one_sample() {
+        let x = array![0.61, -1.23, 0.35, 1.92, -0.48, 0.07, 1.31, -0.89, 0.52, 2.44];
+        let norm = Norm::new(None, None).unwrap();
+        let cdf = |v| norm.cdf(v);
+        let res = kstest(&x, cdf, Some(Alternative::Greater), None).unwrap();
+        assert_abs_diff_eq!(res.statistic, 0.013267056962827378, epsilon = 1e-15);
+        assert_abs_diff_eq!(res.pvalue, 0.9850620877866954, epsilon = 1e-14);
+        assert_eq!((res.statistic_location, res.statistic_sign), (-0.89, 1));
+        let res = kstest(&x, cdf, Some(Alternative::Less), None).unwrap();
+        assert_abs_diff_eq!(res.pvalue, 0.2813228705488176, epsilon = 1e-14);
+        let res = kstest(&x, cdf, None, Some(KsMethod::Asymp)).unwrap();
+        assert_abs_diff_eq!(res.pvalue, 0.628978237460951, epsilon = 1e-14);
+
+        let shifted = &x + 0.8;
+        let res = kstest(&shifted, cdf, None, None).unwrap();
+        assert_abs_diff_eq!(res.statistic, 0.5078497978963038, epsilon = 1e-15);
+        assert_abs_diff_eq!(res.pvalue, 0.006478886849960918, epsilon = 1e-15);
+        assert_abs_diff_eq!(res.statistic_location, 0.87, epsilon = 1e-15);
+
+        let res = kstest(&array![0.1, f64::NAN], cdf, None, None).unwrap();
+        assert!(res.statistic.is_nan() && res.pvalue.is_nan());
+        assert!(kstest(&array![], cdf, None, None).is_err());
+    }
+
+    #[test]
+    fn two_samples() {
+        let a = array![1.2, 3.4, 0.5, 2.2, 4.1, 1.8];
+        let b = array![2.9, 5.1, 3.3, 4.4, 6.0, 2.5, 5.5, 3.8];
+        let res = ks_2samp(&a, &b, Some(Alternative::Greater), None).unwrap();
+        assert_abs_diff_eq!(res.pvalue, 0.030303030303030304, epsilon = 1e-15);
+        let res = ks_2samp(&a, &b, Some(Alternative::Less), None).unwrap();
+        assert_eq!((res.statistic, res.pvalue), (0., 1.));
+        assert_eq!((res.statistic_location, res.statistic_sign), (6.0, -1));
+        // The samples are symmetric in the two-sided test.
+        let res = ks_2samp(&b, &a, None, None).unwrap();
+        assert_abs_diff_eq!(res.pvalue, 0.06060606060606061, epsilon = 1e-15);
+        assert_eq!(res.statistic_sign, -1);
+
+        // The asymptotic p-values, for 24/7 observations.
+        let res = ks_2samp(&a, &b, None, Some(KsMethod::Asymp)).unwrap();
+        assert_abs_diff_eq!(res.pvalue, kolmogn(3, 2. / 3.), epsilon = 1e-15);
+        let res = ks_2samp(&a, &b, Some(Alternative::Greater), Some(KsMethod::Asymp)).unwrap();
+        let z = (24f64 / 7.).sqrt() * 2. / 3.;
+        let hodges = (-2. * z * z - 2. * z * 20. / 672f64.sqrt() / 3.).exp();
+        assert_abs_diff_eq!(res.pvalue, hodges, epsilon = 1e-15);
+
+        assert!(ks_2samp(&a, &array![], None, None).is_err());
+    }
+}
@@ -5,6 +5,97 @@ use num_traits::{Float, Num, NumCast, Signed};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+#[cfg(feature = "alloc")]
+mod descriptive;
+#[cfg(feature = "alloc")]
+pub use descriptive::*;
+
+#[cfg(feature = "alloc")]
+mod distributions;
+#[cfg(feature = "alloc")]
+pub use distributions::*;
+
+#[cfg(feature = "alloc")]
+mod hypothesis;
+#[cfg(feature = "alloc")]
+pub use hypothesis::{Alternative, ConfidenceInterval, NanPolicy, SignificanceResult};
+
+#[cfg(feature = "alloc")]
+mod circular;
+#[cfg(feature = "alloc")]
+pub use circular::*;
+
+#[cfg(feature = "alloc")]
+mod contingency;
+#[cfg(feature = "alloc")]
+pub use contingency::*;
+
+#[cfg(feature = "alloc")]
+mod correlation;
+#[cfg(feature = "alloc")]
+pub use correlation::*;
+
+#[cfg(feature = "alloc")]
+mod ks;
+#[cfg(feature = "alloc")]
+pub use ks::*;
+
+#[cfg(feature = "alloc")]
+mod nonparametric;
+#[cfg(feature = "alloc")]
+pub use nonparametric::*;
+
+#[cfg(feature = "alloc")]
+mod normality;
+#[cfg(feature = "alloc")]
+pub use normality::*;
+
+#[cfg(feature = "alloc")]
+mod regression;
+#[cfg(feature = "alloc")]
+pub use regression::*;
+
+#[cfg(feature = "alloc")]
+mod resampling;
+#[cfg(feature = "alloc")]
+pub use resampling::*;
+
+#[cfg(feature = "alloc")]
+mod transform;
+#[cfg(feature = "alloc")]
+pub use transform::*;
+
+#[cfg(feature = "alloc")]
+mod ttest;
+#[cfg(feature = "alloc")]
+pub use ttest::*;
+
+/// The result of a test that is undefined for the given samples.
+#[cfg(feature = "alloc")]
+pub(crate) fn nan_result<F: Float>() -> SignificanceResult<F> {
+    SignificanceResult {
+        statistic: F::nan(),
+        pvalue: F::nan(),
+    }
+}
+
+/// The result of a test computed in `f64`.
+#[cfg(feature = "alloc")]
+pub(crate) fn f64_result<F: Float>(statistic: f64, pvalue: f64) -> SignificanceResult<F> {
+    SignificanceResult {
+        statistic: F::from(statistic).unwrap(),
+        pvalue: F::from(pvalue).unwrap(),
+    }
+}
+
+/// The values of `x` as `f64`, or `None` if any is NaN.
+#[cfg(feature = "alloc")]
+pub(crate) fn not_nan<F: Float>(x: ndarray::ArrayView1<F>) -> Option<Vec<f64>> {
+    x.iter()
+        .map(|v| v.to_f64().filter(|v| !v.is_nan()))
+        .collect()
+}
+
 // Quick select finds the `i`th smallest element with 2N comparisons
 #[cfg(feature = "alloc")]
 fn quickselect<B, T>(y: &[B], k: usize) -> T
@@ -356,6 +447,8 @@ where
 ///
 /// Compute the z score of each value in the sample, relative to the sample mean and standard deviation.
 ///
+/// This is the allocation free counterpart of [zscore], formerly named `zscore`.
+///
 /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.stats.zscore.html>
 ///
 /// # Arguments
@@ -365,11 +458,11 @@ where
 /// # Examples
 ///
 /// ```
-/// use sci_rs::stats::zscore;
+/// use sci_rs::stats::zscore_iter;
 /// use approx::assert_relative_eq;
 ///
 /// let y: [f32; 5] = [1.,2.,3.,4.,5.];
-/// let z : Vec<f32> = zscore(y.iter()).collect::<Vec<_>>();
+/// let z : Vec<f32> = zscore_iter(y.iter()).collect::<Vec<_>>();
 /// let answer: [f32; 5] = [-1.4142135, -0.70710677, 0.,  0.70710677,  1.4142135];
 /// for i in 0..5 {
 ///     assert_relative_eq!(answer[i], z[i], epsilon = 1e-6);
@@ -377,13 +470,13 @@ where
 ///
 /// // Example from scipy docs
 /// let a: [f32; 10] = [ 0.7972,  0.0767,  0.4383,  0.7866,  0.8091, 0.1954,  0.6307,  0.6599,  0.1065,  0.0508];
-/// let z : Vec<f32> = zscore(a.iter()).collect::<Vec<_>>();
+/// let z : Vec<f32> = zscore_iter(a.iter()).collect::<Vec<_>>();
 /// let answer: [f32; 10] =[ 1.12724554, -1.2469956 , -0.05542642,  1.09231569,  1.16645923, -0.8558472 ,  0.57858329,  0.67480514, -1.14879659, -1.33234306];
 /// for i in 0..10 {
 ///    assert_relative_eq!(answer[i], z[i], epsilon = 1e-6);
 /// }
 /// ```
-pub fn zscore<YI, F>(y: YI) -> impl Iterator<Item = F>
+pub fn zscore_iter<YI, F>(y: YI) -> impl Iterator<Item = F>
 where
     F: Float + Default + Copy + Add + Sum,
     YI: Iterator + Clone,
@@ -0,0 +1,629 @@
+use super::hypothesis::pvalue;
+use super::transform::rank_of;
+use super::{
+    f64_result, nan_result, not_nan, Alternative, Chi2, ContinuousDistribution, Norm, RankMethod,
+    SignificanceResult,
+};
+use alloc::vec::Vec;
+use ndarray::{ArrayBase, ArrayView1, Data, Ix1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Sum of `t^3 - t` over the numbers of ties `t` of the sorted `x`.
+fn tie_term(x: &mut [f64]) -> f64 {
+    x.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    x.chunk_by(|a, b| a == b)
+        .map(|c| (c.len() as f64).powi(3) - c.len() as f64)
+        .sum()
+}
+
+fn chi2_sf(x: f64, df: f64) -> f64 {
+    Chi2::new(df, None, None).map_or(f64::NAN, |dist| dist.sf(x))
+}
+
+/// Method to compute the p-value of [mannwhitneyu] and [wilcoxon].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RankTestMethod {
+    /// [RankTestMethod::Exact] for small samples without ties in [mannwhitneyu] or zeros in
+    /// [wilcoxon], [RankTestMethod::Asymptotic] otherwise.
+    #[default]
+    Auto,
+    /// Normal approximation of the statistic, corrected for the ties.
+    Asymptotic,
+    /// Exact distribution of the statistic, assuming that there are no ties.
+    Exact,
+}
+
+/// Survival function `P(U >= u)` of the Mann-Whitney U statistic for samples of sizes `m` and
+/// `n`, whose distribution is that of the Gaussian binomial coefficient.
+fn mannwhitney_sf(m: usize, n: usize, u: f64) -> f64 {
+    let (m, n) = (m.min(n), m.max(n));
+    // The probabilities for sizes (i, n), from those for (i - 1, n), as the generating
+    // function [n + i choose i]_q = [n + i - 1 choose i - 1]_q (1 - q^(n + i)) / (1 - q^i).
+    let mut pmf = alloc::vec![0.; m * n + 1];
+    pmf[0] = 1.;
+    for i in 1..=m {
+        let len = i * n + 1;
+        for k in (n + i..len).rev() {
+            pmf[k] -= pmf[k - n - i];
+        }
+        for k in i..len {
+            pmf[k] += pmf[k - i];
+        }
+        let scale = i as f64 / (n + i) as f64;
+        pmf[..len].iter_mut().for_each(|p| *p *= scale);
+    }
+    let first = u.ceil().max(0.) as usize;
+    pmf.get(first..)
+        .map_or(0., |tail| tail.iter().sum::<f64>())
+        .clamp(0., 1.)
+}
+
+/// Perform the Mann-Whitney U rank test on two independent samples.
+///
+/// The Mann-Whitney U test is a nonparametric test of the null hypothesis that the
+/// distribution underlying sample `x` is the same as the distribution underlying sample `y`.
+/// It is often used as a test of difference in location between distributions.
+///
+/// ## Parameters
+/// * `x`, `y`: The samples, which may differ in size.
+/// * `use_continuity`: Whether a continuity correction (1/2) should be applied with the
+///   asymptotic method, `true` by default.
+/// * `alternative`: Defines the [Alternative] hypothesis, two-sided by default. For
+///   [Alternative::Less], the distribution underlying `x` is stochastically less than the
+///   distribution underlying `y`, and for [Alternative::Greater], it is stochastically
+///   greater.
+/// * `method`: The [RankTestMethod] of the p-value, [RankTestMethod::Auto] by default, which
+///   is exact when either sample has at most 8 observations and there are no ties.
+///
+/// ## Returns
+/// The Mann-Whitney U statistic of `x` and the p-value as a [SignificanceResult]. They are
+/// NaN if either sample contains NaN.
+///
+/// ## Errors
+/// If either sample is empty.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import mannwhitneyu
+/// males = [19, 22, 16, 29, 24]
+/// females = [20, 11, 17, 12]
+/// mannwhitneyu(males, females)
+/// # MannwhitneyuResult(statistic=17.0, pvalue=0.1111111111111111)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::mannwhitneyu;
+///
+/// let males = array![19., 22., 16., 29., 24.];
+/// let females = array![20., 11., 17., 12.];
+/// let res = mannwhitneyu(&males, &females, None, None, None).unwrap();
+/// assert_eq!(res.statistic, 17.);
+/// assert_abs_diff_eq!(res.pvalue, 0.1111111111111111, epsilon = 1e-15);
+/// ```
+pub fn mannwhitneyu<F, S, T>(
+    x: &ArrayBase<S, Ix1>,
+    y: &ArrayBase<T, Ix1>,
+    use_continuity: Option<bool>,
+    alternative: Option<Alternative>,
+    method: Option<RankTestMethod>,
+) -> Result<SignificanceResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+{
+    if x.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "must not be empty.".into(),
+        });
+    }
+    if y.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "y".into(),
+            reason: "must not be empty.".into(),
+        });
+    }
+    let (Some(x), Some(y)) = (not_nan(x.view()), not_nan(y.view())) else {
+        return Ok(nan_result());
+    };
+    let (n1, n2) = (x.len() as f64, y.len() as f64);
+    let mut all = [x.as_slice(), y.as_slice()].concat();
+    let ranks = rank_of(&all, RankMethod::Average);
+    let u1 = ranks[..x.len()].iter().sum::<f64>() - n1 * (n1 + 1.) / 2.;
+    let u2 = n1 * n2 - u1;
+    let alternative = alternative.unwrap_or_default();
+    let (u, factor) = match alternative {
+        Alternative::Greater => (u1, 1.),
+        Alternative::Less => (u2, 1.),
+        Alternative::TwoSided => (u1.max(u2), 2.),
+    };
+
+    let ties = tie_term(&mut all);
+    let exact = match method.unwrap_or_default() {
+        RankTestMethod::Auto => x.len().min(y.len()) <= 8 && ties == 0.,
+        RankTestMethod::Exact => true,
+        RankTestMethod::Asymptotic => false,
+    };
+    let p = if exact {
+        mannwhitney_sf(x.len(), y.len(), u)
+    } else {
+        let n = n1 + n2;
+        let s = (n1 * n2 / 12. * ((n + 1.) - ties / (n * (n - 1.)))).sqrt();
+        let mut numerator = u - n1 * n2 / 2.;
+        if use_continuity.unwrap_or(true) {
+            numerator -= 0.5;
+        }
+        Norm::new(None, None)?.sf(numerator / s)
+    };
+    Ok(f64_result(u1, (factor * p).clamp(0., 1.)))
+}
+
+/// How to treat the zero differences in [wilcoxon].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ZeroMethod {
+    /// Drop the zero differences.
+    #[default]
+    Wilcox,
+    /// Include the zero differences in the ranking, but drop their ranks.
+    Pratt,
+    /// Include the zero differences in the ranking, and split their ranks between the
+    /// positive and negative ones.
+    Zsplit,
+}
+
+/// Cumulative distribution function `P(T <= t)` of the Wilcoxon signed-rank statistic for `n`
+/// observations.
+fn wilcoxon_cdf(n: usize, t: f64) -> f64 {
+    if t < 0. {
+        return 0.;
+    }
+    // The statistic is the sum of the ranks 1 to n, each included with probability 1/2.
+    let max = n * (n + 1) / 2;
+    let mut pmf = alloc::vec![0.; max + 1];
+    pmf[0] = 1.;
+    for j in 1..=n {
+        for k in (0..=j * (j + 1) / 2).rev() {
+            pmf[k] = (pmf[k] + if k >= j { pmf[k - j] } else { 0. }) / 2.;
+        }
+    }
+    let last = (t.floor() as usize).min(max);
+    pmf[..=last].iter().sum::<f64>().min(1.)
+}
+
+/// Calculate the Wilcoxon signed-rank test.
+///
+/// The Wilcoxon signed-rank test tests the null hypothesis that two related paired samples
+/// come from the same distribution. In particular, it tests whether the distribution of the
+/// differences `x - y` is symmetric about zero. It is a non-parametric version of the paired
+/// T-test.
+///
+/// ## Parameters
+/// * `x`: Either the first set of measurements, or the differences between two sets of
+///   measurements when `y` is `None`.
+/// * `y`: The second set of measurements, of the length of `x`.
+/// * `zero_method`: The [ZeroMethod] for the zero differences, [ZeroMethod::Wilcox] by
+///   default.
+/// * `correction`: Whether to apply a continuity correction by adjusting the statistic by 0.5
+///   towards the mean with the asymptotic method, `false` by default.
+/// * `alternative`: Defines the [Alternative] hypothesis, two-sided by default. For
+///   [Alternative::Less], the distribution underlying the differences is stochastically less
+///   than a distribution symmetric about zero, and for [Alternative::Greater], it is
+///   stochastically greater.
+/// * `method`: The [RankTestMethod] of the p-value, [RankTestMethod::Auto] by default, which
+///   is exact for up to 50 differences. The asymptotic method is used whenever there are zero
+///   differences.
+///
+/// ## Returns
+/// The statistic and p-value as a [SignificanceResult]. The statistic is the smaller of the
+/// sums of the ranks above and below zero for the two-sided alternative, and the sum of the
+/// ranks above zero otherwise. They are NaN if the input contains NaN or has no non-zero
+/// difference.
+///
+/// ## Errors
+/// If `x` is empty, or if `y` differs in length.
+///
+/// ## Notes
+/// With ties, the exact p-value rounds the statistic conservatively, as the exact distribution
+/// is only defined for integer statistics.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import wilcoxon
+/// d = [6, 8, 14, 16, 23, 24, 28, 29, 41, -48, 49, 56, 60, -67, 75]
+/// wilcoxon(d)
+/// # WilcoxonResult(statistic=24.0, pvalue=0.041259765625)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::stats::wilcoxon;
+///
+/// let d = array![6., 8., 14., 16., 23., 24., 28., 29., 41., -48., 49., 56., 60., -67., 75.];
+/// let res = wilcoxon(&d, None, None, None, None, None).unwrap();
+/// assert_eq!((res.statistic, res.pvalue), (24., 0.041259765625));
+/// ```
+pub fn wilcoxon<F, S>(
+    x: &ArrayBase<S, Ix1>,
+    y: Option<&ArrayBase<S, Ix1>>,
+    zero_method: Option<ZeroMethod>,
+    correction: Option<bool>,
+    alternative: Option<Alternative>,
+    method: Option<RankTestMethod>,
+) -> Result<SignificanceResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    if x.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "must not be empty.".into(),
+        });
+    }
+    let d = match y {
+        Some(y) if y.len() != x.len() => {
+            return Err(Error::InvalidArg {
+                arg: "y".into(),
+                reason: "must have the length of x.".into(),
+            })
+        }
+        Some(y) => x - y,
+        None => x.to_owned(),
+    };
+    let Some(mut d) = not_nan(d.view()) else {
+        return Ok(nan_result());
+    };
+    let zero_method = zero_method.unwrap_or_default();
+    let n_zero = d.iter().filter(|&&v| v == 0.).count() as f64;
+    if zero_method == ZeroMethod::Wilcox {
+        d.retain(|&v| v != 0.);
+    }
+    if d.iter().all(|&v| v == 0.) {
+        return Ok(nan_result());
+    }
+    let exact = match method.unwrap_or_default() {
+        RankTestMethod::Auto => d.len() <= 50,
+        RankTestMethod::Exact => true,
+        RankTestMethod::Asymptotic => false,
+    } && n_zero == 0.;
+
+    let mut abs: Vec<f64> = d.iter().map(|v| v.abs()).collect();
+    let ranks = rank_of(&abs, RankMethod::Average);
+    let sum_ranks = |sign: f64| {
+        d.iter()
+            .zip(&ranks)
+            .filter(|(&v, _)| v * sign > 0.)
+            .map(|(_, &r)| r)
+            .sum::<f64>()
+    };
+    let (mut r_plus, mut r_minus) = (sum_ranks(1.), sum_ranks(-1.));
+    let count = d.len() as f64;
+    let mut mean = count * (count + 1.) / 4.;
+    let mut se = count * (count + 1.) * (2. * count + 1.);
+    match zero_method {
+        ZeroMethod::Zsplit => {
+            let zeros = ranks
+                .iter()
+                .zip(&d)
+                .filter(|(_, &v)| v == 0.)
+                .map(|(&r, _)| r)
+                .sum::<f64>();
+            r_plus += zeros / 2.;
+            r_minus += zeros / 2.;
+        }
+        ZeroMethod::Pratt => {
+            mean -= n_zero * (n_zero + 1.) / 4.;
+            se -= n_zero * (n_zero + 1.) * (2. * n_zero + 1.);
+            // The zeros are not part of the tie correction.
+            abs.retain(|&v| v != 0.);
+        }
+        ZeroMethod::Wilcox => {}
+    }
+    se = ((se - tie_term(&mut abs) / 2.) / 24.).sqrt();
+
+    let alternative = alternative.unwrap_or_default();
+    let p = if exact {
+        let n = d.len();
+        let less = || wilcoxon_cdf(n, r_plus.floor());
+        // P(T >= t) = P(T <= n(n + 1)/2 - t) by symmetry.
+        let greater = || wilcoxon_cdf(n, count * (count + 1.) / 2. - r_plus.ceil());
+        match alternative {
+            Alternative::Less => less(),
+            Alternative::Greater => greater(),
+            Alternative::TwoSided => (2. * less().min(greater())).min(1.),
+        }
+    } else {
+        let mut z = (r_plus - mean) / se;
+        if correction.unwrap_or(false) {
+            let sign = match alternative {
+                Alternative::Greater => 1.,
+                Alternative::Less => -1.,
+                Alternative::TwoSided if z == 0. => 0.,
+                Alternative::TwoSided => z.signum(),
+            };
+            z -= sign * 0.5 / se;
+        }
+        pvalue(&Norm::new(None, None)?, z, alternative)
+    };
+    let statistic = match alternative {
+        Alternative::TwoSided => r_plus.min(r_minus),
+        _ => r_plus,
+    };
+    Ok(f64_result(statistic, p))
+}
+
+/// Compute the Kruskal-Wallis H-test for independent samples.
+///
+/// The Kruskal-Wallis H-test tests the null hypothesis that the population median of all of
+/// the groups are equal. It is a non-parametric version of ANOVA. The test works on 2 or more
+/// independent samples, which may have different sizes.
+///
+/// ## Parameters
+/// * `samples`: Two or more samples.
+///
+/// ## Returns
+/// The Kruskal-Wallis H statistic, corrected for ties, and the p-value from the chi-squared
+/// distribution with one degree of freedom less than the number of groups, as a
+/// [SignificanceResult]. They are NaN if any sample is empty or contains NaN.
+///
+/// ## Errors
+/// If there are less than two samples, or if all the values are identical.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// x = [1, 3, 5, 7, 9]
+/// y = [2, 4, 6, 8, 10]
+/// stats.kruskal(x, y)
+/// # KruskalResult(statistic=0.2727272727272734, pvalue=0.6015081344405895)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::kruskal;
+///
+/// let x = array![1., 3., 5., 7., 9.];
+/// let y = array![2., 4., 6., 8., 10.];
+/// let res = kruskal(&[x.view(), y.view()]).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 0.2727272727272734, epsilon = 1e-14);
+/// assert_abs_diff_eq!(res.pvalue, 0.6015081344405895, epsilon = 1e-14);
+/// ```
+pub fn kruskal<F: Float>(samples: &[ArrayView1<F>]) -> Result<SignificanceResult<F>> {
+    if samples.len() < 2 {
+        return Err(Error::InvalidArg {
+            arg: "samples".into(),
+            reason: "need at least two groups.".into(),
+        });
+    }
+    let Some(groups) = samples
+        .iter()
+        .map(|s| not_nan(s.view()).filter(|s| !s.is_empty()))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Ok(nan_result());
+    };
+    let mut all = groups.concat();
+    let ranks = rank_of(&all, RankMethod::Average);
+    let n = all.len() as f64;
+    let ties = 1. - tie_term(&mut all) / (n.powi(3) - n);
+    if ties == 0. {
+        return Err(Error::InvalidArg {
+            arg: "samples".into(),
+            reason: "all numbers are identical.".into(),
+        });
+    }
+    let mut start = 0;
+    let mut ssbn = 0.;
+    for g in &groups {
+        ssbn += ranks[start..start + g.len()].iter().sum::<f64>().powi(2) / g.len() as f64;
+        start += g.len();
+    }
+    let h = (12. / (n * (n + 1.)) * ssbn - 3. * (n + 1.)) / ties;
+    Ok(f64_result(h, chi2_sf(h, (groups.len() - 1) as f64)))
+}
+
+/// Compute the Friedman test for repeated samples.
+///
+/// The Friedman test tests the null hypothesis that repeated samples of the same individuals
+/// have the same distribution. It is often used to test for consistency among samples
+/// obtained in different ways.
+///
+/// ## Parameters
+/// * `samples`: Three or more samples of the same length, where `samples[j][i]` is the
+///   measurement of the individual `i` in the sample `j`.
+///
+/// ## Returns
+/// The test statistic, corrected for ties, and the p-value from the chi-squared distribution
+/// with one degree of freedom less than the number of samples, as a [SignificanceResult].
+/// They are NaN if any sample contains NaN.
+///
+/// ## Errors
+/// If there are less than three samples, or if they differ in length.
+///
+/// ## Notes
+/// Due to the assumption that the test statistic has a chi squared distribution, the p-value
+/// is only reliable for more than 10 measurements and more than 6 samples.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// before = [72, 96, 88, 92, 74, 76, 82]
+/// immediately_after = [120, 120, 132, 120, 101, 96, 112]
+/// five_min_after = [76, 95, 104, 96, 84, 72, 76]
+/// stats.friedmanchisquare(before, immediately_after, five_min_after)
+/// # FriedmanchisquareResult(statistic=10.57142857142857, pvalue=0.005063414171757498)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::friedmanchisquare;
+///
+/// let before = array![72., 96., 88., 92., 74., 76., 82.];
+/// let immediately_after = array![120., 120., 132., 120., 101., 96., 112.];
+/// let five_min_after = array![76., 95., 104., 96., 84., 72., 76.];
+/// let samples = [before.view(), immediately_after.view(), five_min_after.view()];
+/// let res = friedmanchisquare(&samples).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 10.57142857142857, epsilon = 1e-13);
+/// assert_abs_diff_eq!(res.pvalue, 0.005063414171757498, epsilon = 1e-15);
+/// ```
+pub fn friedmanchisquare<F: Float>(samples: &[ArrayView1<F>]) -> Result<SignificanceResult<F>> {
+    let k = samples.len();
+    if k < 3 {
+        return Err(Error::InvalidArg {
+            arg: "samples".into(),
+            reason: "at least 3 sets of samples must be given.".into(),
+        });
+    }
+    let n = samples[0].len();
+    if samples.iter().any(|s| s.len() != n) {
+        return Err(Error::InvalidArg {
+            arg: "samples".into(),
+            reason: "unequal N in friedmanchisquare.".into(),
+        });
+    }
+    let Some(samples) = samples
+        .iter()
+        .map(|s| not_nan(s.view()))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Ok(nan_result());
+    };
+    // Rank the measurements of each individual across the samples.
+    let mut rank_sums = alloc::vec![0.; k];
+    let mut ties = 0.;
+    for i in 0..n {
+        let mut row: Vec<f64> = samples.iter().map(|s| s[i]).collect();
+        let ranks = rank_of(&row, RankMethod::Average);
+        rank_sums.iter_mut().zip(ranks).for_each(|(s, r)| *s += r);
+        ties += tie_term(&mut row);
+    }
+    let (kf, nf) = (k as f64, n as f64);
+    let c = 1. - ties / (kf * (kf * kf - 1.) * nf);
+    let ssbn: f64 = rank_sums.iter().map(|s| s * s).sum();
+    let chisq = (12. / (kf * nf * (kf + 1.)) * ssbn - 3. * nf * (kf + 1.)) / c;
+    Ok(f64_result(chisq, chi2_sf(chisq, kf - 1.)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn mann_whitney() {
+        let x = array![19., 22., 16., 29., 24.];
+        let y = array![20., 11., 17., 12.];
+        let test = |alternative, method| {
+            mannwhitneyu(&x, &y, None, Some(alternative), method)
+                .unwrap()
+                .pvalue
+        };
+        assert_abs_diff_eq!(
+            test(Alternative::Less, None),
+            0.9682539682539683,
+            epsilon = 1e-15
+        );
+        assert_abs_diff_eq!(
+            test(Alternative::Greater, None),
+            0.05555555555555555,
+            epsilon = 1e-15
+        );
+
+        let x = array![3.1, 2.4, 5.6, 4.4, 3.3, 2.9, 4.8, 5.1, 3.8, 4.0];
+        let y = array![2.2, 3.3, 1.9, 2.8, 3.5, 2.5, 2.0, 3.0, 2.6];
+        let res = mannwhitneyu(&x, &y, None, None, None).unwrap();
+        assert_eq!(res.statistic, 77.5);
+        assert_abs_diff_eq!(res.pvalue, 0.008950586032810949, epsilon = 1e-15);
+        let less = mannwhitneyu(&x, &y, Some(false), Some(Alternative::Less), None).unwrap();
+        assert_abs_diff_eq!(less.pvalue, 0.9960319752441472, epsilon = 1e-15);
+        let exact = mannwhitneyu(&x, &y, None, None, Some(RankTestMethod::Exact)).unwrap();
+        assert_abs_diff_eq!(exact.pvalue, 0.005672346229621771, epsilon = 1e-15);
+
+        assert!(mannwhitneyu(&x, &array![], None, None, None).is_err());
+        let res = mannwhitneyu(&x, &array![f64::NAN], None, None, None).unwrap();
+        assert!(res.statistic.is_nan() && res.pvalue.is_nan());
+    }
+
+    #[test]
+    fn signed_ranks() {
+        let d = array![6., 8., 14., 16., 23., 24., 28., 29., 41., -48., 49., 56., 60., -67., 75.];
+        let test = |alternative, method, correction| {
+            wilcoxon(&d, None, None, correction, Some(alternative), method).unwrap()
+        };
+        let res = test(Alternative::Greater, None, None);
+        assert_eq!((res.statistic, res.pvalue), (96., 0.0206298828125));
+        let res = test(
+            Alternative::TwoSided,
+            Some(RankTestMethod::Asymptotic),
+            None,
+        );
+        assert_eq!(res.statistic, 24.);
+        assert_abs_diff_eq!(res.pvalue, 0.040888132911855905, epsilon = 1e-15);
+        let res = test(
+            Alternative::Less,
+            Some(RankTestMethod::Asymptotic),
+            Some(true),
+        );
+        assert_abs_diff_eq!(res.pvalue, 0.9809166843332186, epsilon = 1e-15);
+
+        let x = array![1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30];
+        let y = array![0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.15, 1.29];
+        let res = wilcoxon(&x, Some(&y), None, None, None, None).unwrap();
+        assert_eq!((res.statistic, res.pvalue), (5., 0.0390625));
+        assert!(wilcoxon(&x, Some(&array![1.]), None, None, None, None).is_err());
+
+        // The zeros switch to the asymptotic method.
+        let d = array![1., 2., 0., -3., 2., 4., 0., 5., -1., 3.];
+        for (zero_method, statistic, pvalue) in [
+            (ZeroMethod::Wilcox, 7., 0.12210398539050584),
+            (ZeroMethod::Pratt, 11., 0.12307019493256193),
+            (ZeroMethod::Zsplit, 12.5, 0.12529349970570546),
+        ] {
+            let res = wilcoxon(&d, None, Some(zero_method), None, None, None).unwrap();
+            assert_eq!(res.statistic, statistic);
+            assert_abs_diff_eq!(res.pvalue, pvalue, epsilon = 1e-15);
+        }
+    }
+
+    #[test]
+    fn kruskal_friedman() {
+        let (a, b, c) = (
+            array![2.9, 3.0, 2.5, 2.6, 3.2],
+            array![3.8, 2.7, 4.0, 2.4],
+            array![2.8, 3.4, 3.7, 2.2, 2.0],
+        );
+        let res = kruskal(&[a.view(), b.view(), c.view()]).unwrap();
+        assert_abs_diff_eq!(res.statistic, 0.7714285714285714, epsilon = 1e-14);
+        assert_abs_diff_eq!(res.pvalue, 0.6799647735788938, epsilon = 1e-14);
+        let (a, b) = (array![1., 1., 1.], array![2., 2., 2.]);
+        let res = kruskal(&[a.view(), b.view(), b.view()]).unwrap();
+        assert_abs_diff_eq!(res.statistic, 8., epsilon = 1e-14);
+        assert_abs_diff_eq!(res.pvalue, 0.01831563888873418, epsilon = 1e-15);
+        assert!(kruskal(&[a.view(), a.view()]).is_err());
+        assert!(kruskal(&[a.view()]).is_err());
+
+        let (a, b, c) = (
+            array![1., 2., 3., 3.],
+            array![2., 2., 1., 4.],
+            array![3., 1., 2., 4.],
+        );
+        let res = friedmanchisquare(&[a.view(), b.view(), c.view()]).unwrap();
+        assert_abs_diff_eq!(res.statistic, 0.14285714285714285, epsilon = 1e-14);
+        assert_abs_diff_eq!(res.pvalue, 0.9310627797040228, epsilon = 1e-14);
+        assert!(friedmanchisquare(&[a.view(), b.view()]).is_err());
+        let d = array![1., 2.];
+        assert!(friedmanchisquare(&[a.view(), b.view(), d.view()]).is_err());
+    }
+}
@@ -0,0 +1,555 @@
+use super::descriptive::{kurtosis_of, mean_of, skew_of, var_of};
+use super::{
+    f64_result, nan_result, not_nan, Chi2, ContinuousDistribution, Norm, SignificanceResult,
+};
+use alloc::vec::Vec;
+use ndarray::{Array1, ArrayBase, ArrayView1, Data, Ix1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// The polynomial of the coefficients `c`, in increasing powers, at `x`.
+fn poly(c: &[f64], x: f64) -> f64 {
+    c.iter().rev().fold(0., |acc, &c| acc * x + c)
+}
+
+/// The coefficients `a_1, ..., a_{n/2}` of the largest observations in the Shapiro-Wilk
+/// statistic of `n` observations, from the approximation of Royston (1992).
+fn shapiro_coefficients(n: usize, norm: &Norm<f64>) -> Vec<f64> {
+    let n2 = n / 2;
+    if n == 3 {
+        return alloc::vec![core::f64::consts::FRAC_1_SQRT_2];
+    }
+    const C1: [f64; 6] = [0., 0.221157, -0.147981, -2.07119, 4.434685, -2.706056];
+    const C2: [f64; 6] = [0., 0.042981, -0.293762, -1.752461, 5.682633, -3.582633];
+    let an = n as f64;
+    // The expected normal order statistics, of Blom's approximation.
+    let m: Vec<f64> = (1..=n2)
+        .map(|i| norm.ppf((i as f64 - 0.375) / (an + 0.25)))
+        .collect();
+    let summ2 = 2. * m.iter().map(|m| m * m).sum::<f64>();
+    let ssumm2 = summ2.sqrt();
+    let rsn = 1. / an.sqrt();
+    let a1 = poly(&C1, rsn) - m[0] / ssumm2;
+    let mut a = alloc::vec![a1];
+    let fac = if n > 5 {
+        let a2 = -m[1] / ssumm2 + poly(&C2, rsn);
+        a.push(a2);
+        ((summ2 - 2. * m[0] * m[0] - 2. * m[1] * m[1]) / (1. - 2. * a1 * a1 - 2. * a2 * a2)).sqrt()
+    } else {
+        ((summ2 - 2. * m[0] * m[0]) / (1. - 2. * a1 * a1)).sqrt()
+    };
+    a.extend(m[a.len()..].iter().map(|m| -m / fac));
+    a
+}
+
+/// Perform the Shapiro-Wilk test for normality.
+///
+/// The Shapiro-Wilk test tests the null hypothesis that the data was drawn from a normal
+/// distribution.
+///
+/// ## Parameters
+/// * `x`: Array of sample data.
+///
+/// ## Returns
+/// The test statistic `W` and the p-value as a [SignificanceResult]. They are NaN if `x`
+/// contains NaN, and both 1 if all of its values are equal.
+///
+/// ## Errors
+/// If `x` has less than 3 observations.
+///
+/// ## Notes
+/// The algorithm used is described in Royston (1995), and is that of scipy. The statistic is
+/// the squared correlation between the sorted data and the approximate coefficients of the
+/// best linear unbiased estimator of the standard deviation. For 3 observations, the p-value
+/// is exact. Otherwise it comes from a normalizing transformation of `W`, whose accuracy has
+/// only been established for up to 5000 observations.
+///
+/// ## References
+/// 1. P. Royston, Approximating the Shapiro-Wilk W-test for non-normality, Statistics and
+///    Computing 2, pp. 117-119 (1992).
+/// 2. P. Royston, Remark AS R94: A Remark on Algorithm AS 181: The W-test for Normality,
+///    Journal of the Royal Statistical Society. Series C 44.4, pp. 547-551 (1995).
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// x = [148, 154, 158, 160, 161, 162, 166, 170, 182, 195, 236]
+/// stats.shapiro(x)
+/// # ShapiroResult(statistic=0.7888146948631716, pvalue=0.006703814061482509)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::shapiro;
+///
+/// let x = array![148., 154., 158., 160., 161., 162., 166., 170., 182., 195., 236.];
+/// let res = shapiro(&x).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 0.7888146948631716, epsilon = 1e-9);
+/// assert_abs_diff_eq!(res.pvalue, 0.006703814061482509, epsilon = 1e-9);
+/// ```
+pub fn shapiro<F, S>(x: &ArrayBase<S, Ix1>) -> Result<SignificanceResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let n = x.len();
+    if n < 3 {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "must contain at least 3 observations.".into(),
+        });
+    }
+    let Some(mut y) = not_nan(x.view()) else {
+        return Ok(nan_result());
+    };
+    y.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let range = y[n - 1] - y[0];
+    if range < 1e-19 {
+        return Ok(f64_result(1., 1.));
+    }
+    // Centered on the median and scaled by the range, to keep the precision of the sums.
+    let median = y[n / 2];
+    y.iter_mut().for_each(|v| *v = (*v - median) / range);
+
+    let norm = Norm::new(None, None)?;
+    let a = shapiro_coefficients(n, &norm);
+    let coefficient = |i: usize| match i {
+        i if i < n / 2 => -a[i],
+        i if n - 1 - i < n / 2 => a[n - 1 - i],
+        _ => 0.,
+    };
+    // The statistic is the squared correlation between the data and the coefficients.
+    let an = n as f64;
+    let mean_a = (0..n).map(coefficient).sum::<f64>() / an;
+    let mean_y = y.iter().sum::<f64>() / an;
+    let (ssa, ssy, say) = y
+        .iter()
+        .enumerate()
+        .fold((0., 0., 0.), |(ssa, ssy, say), (i, &v)| {
+            let (da, dy) = (coefficient(i) - mean_a, v - mean_y);
+            (ssa + da * da, ssy + dy * dy, say + da * dy)
+        });
+    let r = (ssa * ssy).sqrt();
+    let w1 = (r - say) * (r + say) / (ssa * ssy);
+    let w = 1. - w1;
+
+    if n == 3 {
+        const PI6: f64 = 6. / core::f64::consts::PI;
+        const STQR: f64 = core::f64::consts::FRAC_PI_3;
+        return Ok(f64_result(w, (PI6 * (w.sqrt().asin() - STQR)).max(0.)));
+    }
+    let y = w1.ln();
+    let (y, m, s) = if n <= 11 {
+        let gamma = poly(&[-2.273, 0.459], an);
+        if y >= gamma {
+            return Ok(f64_result(w, 1e-99));
+        }
+        let m = poly(&[0.544, -0.39978, 0.025054, -6.714e-4], an);
+        let s = poly(&[1.3822, -0.77857, 0.062767, -0.0020322], an).exp();
+        (-(gamma - y).ln(), m, s)
+    } else {
+        let ln_n = an.ln();
+        let m = poly(&[-1.5861, -0.31082, -0.083751, 0.0038915], ln_n);
+        let s = poly(&[-0.4803, -0.082676, 0.0030302], ln_n).exp();
+        (y, m, s)
+    };
+    Ok(f64_result(w, norm.sf((y - m) / s)))
+}
+
+/// Distribution of the null hypothesis of [anderson].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AndersonDist {
+    /// The normal distribution, of the sample mean and standard deviation.
+    #[default]
+    Norm,
+    /// The exponential distribution, of the sample mean.
+    Expon,
+}
+
+/// Result of [anderson].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AndersonResult<F> {
+    /// The Anderson-Darling test statistic.
+    pub statistic: F,
+    /// The critical values of the statistic for the distribution.
+    pub critical_values: Array1<F>,
+    /// The significance levels, in percent, of the critical values.
+    pub significance_level: Array1<F>,
+}
+
+/// Anderson-Darling test for data coming from a particular distribution.
+///
+/// The Anderson-Darling test tests the null hypothesis that a sample is drawn from a
+/// population that follows a particular distribution, whose parameters are estimated from the
+/// sample.
+///
+/// ## Parameters
+/// * `x`: Array of sample data.
+/// * `dist`: The [AndersonDist] to test against, [AndersonDist::Norm] by default.
+///
+/// ## Returns
+/// The [AndersonResult]. If the statistic is larger than the critical value of a significance
+/// level, the null hypothesis can be rejected at that level. The statistic is NaN if `x`
+/// contains NaN.
+///
+/// ## Errors
+/// If `x` has less than 2 observations.
+///
+/// ## Notes
+/// The critical values are those of Stephens (1974), for the significance levels of 15%, 10%,
+/// 5%, 2.5% and 1%, adjusted for the sample size.
+///
+/// ## References
+/// 1. M. A. Stephens, EDF Statistics for Goodness of Fit and Some Comparisons, Journal of the
+///    American Statistical Association 69, pp. 730-737 (1974).
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// x = [0.11, 7.87, 4.61, 10.14, 7.95, 3.14, 0.46, 4.43, 0.21, 4.75,
+///      0.71, 1.52, 3.24, 0.93, 0.42, 4.97, 9.53, 4.55, 0.47, 6.66]
+/// res = stats.anderson(x)
+/// res.statistic, res.critical_values
+/// # (0.6793295266405224, array([0.506, 0.577, 0.692, 0.807, 0.96 ]))
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::anderson;
+///
+/// let x = array![
+///     0.11, 7.87, 4.61, 10.14, 7.95, 3.14, 0.46, 4.43, 0.21, 4.75,
+///     0.71, 1.52, 3.24, 0.93, 0.42, 4.97, 9.53, 4.55, 0.47, 6.66
+/// ];
+/// let res = anderson(&x, None).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 0.6793295266405224, epsilon = 1e-13);
+/// assert_eq!(res.critical_values, array![0.506, 0.577, 0.692, 0.807, 0.96]);
+/// assert_eq!(res.significance_level, array![15., 10., 5., 2.5, 1.]);
+/// ```
+pub fn anderson<F, S>(
+    x: &ArrayBase<S, Ix1>,
+    dist: Option<AndersonDist>,
+) -> Result<AndersonResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    let n = x.len();
+    if n < 2 {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "must contain at least 2 observations.".into(),
+        });
+    }
+    let an = n as f64;
+    let dist = dist.unwrap_or_default();
+    let (critical, factor) = match dist {
+        AndersonDist::Norm => (
+            [0.576, 0.656, 0.787, 0.918, 1.092],
+            1. + 4. / an - 25. / (an * an),
+        ),
+        AndersonDist::Expon => ([0.922, 1.078, 1.341, 1.606, 1.957], 1. + 0.6 / an),
+    };
+    let of = |v: &[f64]| v.iter().map(|&v| F::from(v).unwrap()).collect();
+    let critical_values = of(&critical.map(|c| (c / factor * 1000.).round() / 1000.));
+    let significance_level = of(&[15., 10., 5., 2.5, 1.]);
+
+    let statistic = match not_nan(x.view()) {
+        None => F::nan(),
+        Some(mut y) => {
+            y.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let y = ArrayView1::from(&y);
+            let mean = mean_of(y);
+            // The logarithms of the cdf and sf of the fitted distribution.
+            let (logcdf, logsf): (Vec<f64>, Vec<f64>) = match dist {
+                AndersonDist::Norm => {
+                    let norm = Norm::new(Some(mean), Some(var_of(y, 1).sqrt()))?;
+                    y.iter()
+                        .map(|&v| (norm.cdf(v).ln(), norm.sf(v).ln()))
+                        .unzip()
+                }
+                AndersonDist::Expon => y
+                    .iter()
+                    .map(|&v| ((-(-v / mean).exp_m1()).ln(), -v / mean))
+                    .unzip(),
+            };
+            let sum = (0..n)
+                .map(|i| (2 * i + 1) as f64 / an * (logcdf[i] + logsf[n - 1 - i]))
+                .sum::<f64>();
+            F::from(-an - sum).unwrap()
+        }
+    };
+    Ok(AndersonResult {
+        statistic,
+        critical_values,
+        significance_level,
+    })
+}
+
+/// The z-score of the sample skewness of `x`, of D'Agostino's transformation.
+fn skew_z(x: ArrayView1<f64>) -> f64 {
+    let n = x.len() as f64;
+    let b2 = skew_of(x, true);
+    let y = b2 * ((n + 1.) * (n + 3.) / (6. * (n - 2.))).sqrt();
+    let beta2 = 3. * (n * n + 27. * n - 70.) * (n + 1.) * (n + 3.)
+        / ((n - 2.) * (n + 5.) * (n + 7.) * (n + 9.));
+    let w2 = -1. + (2. * (beta2 - 1.)).sqrt();
+    let delta = 1. / (0.5 * w2.ln()).sqrt();
+    let alpha = (2. / (w2 - 1.)).sqrt();
+    let y = if y == 0. { 1. } else { y };
+    delta * (y / alpha).asinh()
+}
+
+/// The z-score of the sample kurtosis of `x`, of the transformation of Anscombe and Glynn.
+fn kurtosis_z(x: ArrayView1<f64>) -> f64 {
+    let n = x.len() as f64;
+    let b2 = kurtosis_of(x, false, true);
+    let e = 3. * (n - 1.) / (n + 1.);
+    let varb2 = 24. * n * (n - 2.) * (n - 3.) / ((n + 1.) * (n + 1.) * (n + 3.) * (n + 5.));
+    let x = (b2 - e) / varb2.sqrt();
+    let sqrtbeta1 = 6. * (n * n - 5. * n + 2.) / ((n + 7.) * (n + 9.))
+        * (6. * (n + 3.) * (n + 5.) / (n * (n - 2.) * (n - 3.))).sqrt();
+    let a = 6. + 8. / sqrtbeta1 * (2. / sqrtbeta1 + (1. + 4. / (sqrtbeta1 * sqrtbeta1)).sqrt());
+    let term1 = 1. - 2. / (9. * a);
+    let denom = 1. + x * (2. / (a - 4.)).sqrt();
+    let term2 = if denom == 0. {
+        f64::NAN
+    } else {
+        denom.signum() * ((1. - 2. / a) / denom.abs()).cbrt()
+    };
+    (term1 - term2) / (2. / (9. * a)).sqrt()
+}
+
+/// Test whether a sample differs from a normal distribution.
+///
+/// This function tests the null hypothesis that a sample comes from a normal distribution. It
+/// is based on D'Agostino and Pearson's test that combines skew and kurtosis to produce an
+/// omnibus test of normality.
+///
+/// ## Parameters
+/// * `a`: Array of sample data.
+///
+/// ## Returns
+/// The statistic `s^2 + k^2`, where `s` is the z-score of the skewness and `k` the z-score of
+/// the kurtosis, and its p-value from the chi-squared distribution with two degrees of
+/// freedom, as a [SignificanceResult]. They are NaN if `a` contains NaN or all of its values
+/// are equal.
+///
+/// ## Errors
+/// If `a` has less than 8 observations.
+///
+/// ## Notes
+/// The z-score of the kurtosis is only accurate for 20 or more observations.
+///
+/// ## References
+/// 1. R. B. D'Agostino, An omnibus test of normality for moderate and large sample size,
+///    Biometrika 58, pp. 341-348 (1971).
+/// 2. R. B. D'Agostino and E. S. Pearson, Tests for departure from normality, Biometrika 60,
+///    pp. 613-622 (1973).
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// x = [1.36, 1.14, 2.92, 2.55, 1.46, 1.06, 5.27, -1.11, 3.48, 1.10,
+///      0.88, -0.51, 1.46, 0.52, 6.20, 1.69, 0.08, 3.67, 2.81, 3.49]
+/// stats.normaltest(x)
+/// # NormaltestResult(statistic=1.8934554867300584, pvalue=0.3880086121411849)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::normaltest;
+///
+/// let x = array![
+///     1.36, 1.14, 2.92, 2.55, 1.46, 1.06, 5.27, -1.11, 3.48, 1.10,
+///     0.88, -0.51, 1.46, 0.52, 6.20, 1.69, 0.08, 3.67, 2.81, 3.49
+/// ];
+/// let res = normaltest(&x).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 1.8934554867300584, epsilon = 1e-13);
+/// assert_abs_diff_eq!(res.pvalue, 0.3880086121411849, epsilon = 1e-13);
+/// ```
+pub fn normaltest<F, S>(a: &ArrayBase<S, Ix1>) -> Result<SignificanceResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    if a.len() < 8 {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "must contain at least 8 observations.".into(),
+        });
+    }
+    let Some(x) = not_nan(a.view()) else {
+        return Ok(nan_result());
+    };
+    let x = ArrayView1::from(&x);
+    let (s, k) = (skew_z(x), kurtosis_z(x));
+    let k2 = s * s + k * k;
+    Ok(f64_result(k2, Chi2::new(2., None, None)?.sf(k2)))
+}
+
+/// Perform the Jarque-Bera goodness of fit test on sample data.
+///
+/// The Jarque-Bera test tests whether the sample data has the skewness and kurtosis matching a
+/// normal distribution.
+///
+/// ## Parameters
+/// * `x`: Observations of a random variable.
+///
+/// ## Returns
+/// The statistic `n / 6 (s^2 + k^2 / 4)`, where `s` is the sample skewness and `k` the sample
+/// excess kurtosis, and its p-value from the chi-squared distribution with two degrees of
+/// freedom, as a [SignificanceResult]. They are NaN if `x` contains NaN or all of its values
+/// are equal.
+///
+/// ## Errors
+/// If `x` is empty.
+///
+/// ## Notes
+/// The chi-squared distribution of the statistic is asymptotic, and the test is only
+/// reliable for large samples.
+///
+/// ## References
+/// 1. C. M. Jarque and A. K. Bera, Efficient tests for normality, homoscedasticity and serial
+///    independence of regression residuals, Economics Letters 6.3, pp. 255-259 (1980).
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// x = [0.11, 7.87, 4.61, 10.14, 7.95, 3.14, 0.46, 4.43, 0.21, 4.75,
+///      0.71, 1.52, 3.24, 0.93, 0.42, 4.97, 9.53, 4.55, 0.47, 6.66]
+/// stats.jarque_bera(x)
+/// # SignificanceResult(statistic=1.5445275849728313, pvalue=0.46196608832591135)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::jarque_bera;
+///
+/// let x = array![
+///     0.11, 7.87, 4.61, 10.14, 7.95, 3.14, 0.46, 4.43, 0.21, 4.75,
+///     0.71, 1.52, 3.24, 0.93, 0.42, 4.97, 9.53, 4.55, 0.47, 6.66
+/// ];
+/// let res = jarque_bera(&x).unwrap();
+/// assert_abs_diff_eq!(res.statistic, 1.5445275849728313, epsilon = 1e-13);
+/// assert_abs_diff_eq!(res.pvalue, 0.46196608832591135, epsilon = 1e-13);
+/// ```
+pub fn jarque_bera<F, S>(x: &ArrayBase<S, Ix1>) -> Result<SignificanceResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+{
+    if x.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "must not be empty.".into(),
+        });
+    }
+    let Some(x) = not_nan(x.view()) else {
+        return Ok(nan_result());
+    };
+    let x = ArrayView1::from(&x);
+    let n = x.len() as f64;
+    let (s, k) = (skew_of(x, true), kurtosis_of(x, true, true));
+    let jb = n / 6. * (s * s + k * k / 4.);
+    Ok(f64_result(jb, Chi2::new(2., None, None)?.sf(jb)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    // The samples of scipy's test suite of shapiro.
+    #[allow(clippy::approx_constant)]
+    fn x1() -> Array1<f64> {
+        array![
+            0.11, 7.87, 4.61, 10.14, 7.95, 3.14, 0.46, 4.43, 0.21, 4.75, 0.71, 1.52, 3.24, 0.93,
+            0.42, 4.97, 9.53, 4.55, 0.47, 6.66
+        ]
+    }
+
+    fn x2() -> Array1<f64> {
+        array![
+            1.36, 1.14, 2.92, 2.55, 1.46, 1.06, 5.27, -1.11, 3.48, 1.10, 0.88, -0.51, 1.46, 0.52,
+            6.20, 1.69, 0.08, 3.67, 2.81, 3.49
+        ]
+    }
+
+    #[test]
+    fn shapiro_wilk() {
+        let x = x1();
+        let res = shapiro(&x).unwrap();
+        assert_abs_diff_eq!(res.statistic, 0.9004729986190796, epsilon = 1e-6);
+        assert_abs_diff_eq!(res.pvalue, 0.04208974540233612, epsilon = 1e-6);
+        let x = x2();
+        let res = shapiro(&x).unwrap();
+        assert_abs_diff_eq!(res.statistic, 0.9590270, epsilon = 1e-6);
+        assert_abs_diff_eq!(res.pvalue, 0.52460, epsilon = 1e-3);
+
+        // The exact p-value of 3 observations, and the small sample transformation.
+        let res = shapiro(&array![1., 2., 4.]).unwrap();
+        assert_abs_diff_eq!(res.statistic, 0.9642857142857142, epsilon = 1e-12);
+        assert_abs_diff_eq!(res.pvalue, 0.6368868450289632, epsilon = 1e-12);
+        let res = shapiro(&array![2., 4., 1., 7., 3.]).unwrap();
+        assert_abs_diff_eq!(res.statistic, 0.9427295840702411, epsilon = 1e-9);
+        assert_abs_diff_eq!(res.pvalue, 0.6852955128124528, epsilon = 1e-9);
+
+        assert_eq!(
+            shapiro(&array![2., 2., 2., 2.]).unwrap(),
+            f64_result(1., 1.)
+        );
+        assert!(shapiro(&array![1., f64::NAN, 3.]).unwrap().pvalue.is_nan());
+        assert!(shapiro(&array![1., 2.]).is_err());
+    }
+
+    #[test]
+    fn anderson_darling() {
+        let x = x2();
+        let res = anderson(&x, None).unwrap();
+        assert_abs_diff_eq!(res.statistic, 0.3777753308535985, epsilon = 1e-13);
+
+        let y = x1();
+        let res = anderson(&y, Some(AndersonDist::Expon)).unwrap();
+        assert_abs_diff_eq!(res.statistic, 0.7220577950980775, epsilon = 1e-13);
+        assert_eq!(res.critical_values, array![0.895, 1.047, 1.302, 1.559, 1.9]);
+
+        assert!(anderson(&array![1., f64::NAN], None)
+            .unwrap()
+            .statistic
+            .is_nan());
+        assert!(anderson(&array![1.], None).is_err());
+    }
+
+    #[test]
+    fn skewness_kurtosis() {
+        let x = x1();
+        assert_abs_diff_eq!(skew_z(x.view()), 1.0680136946612895, epsilon = 1e-13);
+        assert_abs_diff_eq!(kurtosis_z(x.view()), -1.0035604895956696, epsilon = 1e-13);
+        let res = normaltest(&x).unwrap();
+        assert_abs_diff_eq!(res.statistic, 2.147786908261558, epsilon = 1e-13);
+        assert_abs_diff_eq!(res.pvalue, 0.34167562594934214, epsilon = 1e-13);
+        assert!(normaltest(&array![1., 2., 3., 4., 5., 6., 7.]).is_err());
+        assert!(normaltest(&Array1::from_elem(10, 1.))
+            .unwrap()
+            .statistic
+            .is_nan());
+
+        let x = x2();
+        let res = jarque_bera(&x).unwrap();
+        assert_abs_diff_eq!(res.statistic, 1.1088310666035064, epsilon = 1e-13);
+        assert_abs_diff_eq!(res.pvalue, 0.5744078853865195, epsilon = 1e-13);
+        assert!(jarque_bera(&array![f64::NAN, 1.]).unwrap().pvalue.is_nan());
+        assert!(jarque_bera::<f64, _>(&array![]).is_err());
+    }
+}
@@ -0,0 +1,184 @@
+use super::descriptive::mean_of;
+use super::hypothesis::pvalue;
+use super::{Alternative, StudentT};
+use ndarray::{ArrayBase, Data, Ix1, Zip};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Result of [linregress].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinregressResult<F> {
+    /// Slope of the regression line.
+    pub slope: F,
+    /// Intercept of the regression line.
+    pub intercept: F,
+    /// The Pearson correlation coefficient. The square of `rvalue` is equal to the coefficient
+    /// of determination.
+    pub rvalue: F,
+    /// The p-value for a hypothesis test whose null hypothesis is that the slope is zero,
+    /// using Wald Test with t-distribution of the test statistic.
+    pub pvalue: F,
+    /// Standard error of the estimated slope, under the assumption of residual normality.
+    pub stderr: F,
+    /// Standard error of the estimated intercept, under the assumption of residual normality.
+    pub intercept_stderr: F,
+}
+
+/// Calculate a linear least-squares regression for two sets of measurements.
+///
+/// ## Parameters
+/// * `x`, `y`: The measurements, of the same length.
+/// * `alternative`: Defines the [Alternative] hypothesis, two-sided by default. For
+///   [Alternative::Less], the slope of the regression line is less than zero, and for
+///   [Alternative::Greater], it is greater.
+///
+/// ## Returns
+/// The [LinregressResult]. For two measurements, the standard errors are zero, and the p-value
+/// is 1 if `y` is constant and 0 otherwise.
+///
+/// ## Errors
+/// If `x` and `y` differ in length, have less than two measurements, or if all the values of
+/// `x` are identical.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// x = [1.5, 2.0, 3.1, 4.2, 5.0, 6.3, 7.1]
+/// y = [3.2, 4.1, 6.5, 8.4, 9.9, 12.8, 14.0]
+/// res = stats.linregress(x, y)
+/// res.slope, res.intercept, res.rvalue
+/// # (1.9508957133717212, 0.2762635956493916, 0.9993647952353527)
+/// res.pvalue, res.stderr, res.intercept_stderr
+/// # (1.9524971398876833e-08, 0.03111199782687776, 0.14334689310732273)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::linregress;
+///
+/// let x = array![1.5, 2.0, 3.1, 4.2, 5.0, 6.3, 7.1];
+/// let y = array![3.2, 4.1, 6.5, 8.4, 9.9, 12.8, 14.0];
+/// let res = linregress(&x, &y, None).unwrap();
+/// assert_abs_diff_eq!(res.slope, 1.9508957133717212, epsilon = 1e-14);
+/// assert_abs_diff_eq!(res.intercept, 0.2762635956493916, epsilon = 1e-14);
+/// assert_abs_diff_eq!(res.rvalue, 0.9993647952353527, epsilon = 1e-15);
+/// assert_abs_diff_eq!(res.pvalue, 1.9524971398876833e-08, epsilon = 1e-18);
+/// assert_abs_diff_eq!(res.stderr, 0.03111199782687776, epsilon = 1e-14);
+/// assert_abs_diff_eq!(res.intercept_stderr, 0.14334689310732273, epsilon = 1e-13);
+/// ```
+pub fn linregress<F, S, T>(
+    x: &ArrayBase<S, Ix1>,
+    y: &ArrayBase<T, Ix1>,
+    alternative: Option<Alternative>,
+) -> Result<LinregressResult<F>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+{
+    if x.len() != y.len() {
+        return Err(Error::InvalidArg {
+            arg: "y".into(),
+            reason: "must have the length of x.".into(),
+        });
+    }
+    if x.len() < 2 {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "must have at least 2 measurements.".into(),
+        });
+    }
+    let one = F::one();
+    let n = F::from(x.len()).unwrap();
+    let (x_mean, y_mean) = (mean_of(x.view()), mean_of(y.view()));
+    // The biased covariance matrix of x and y.
+    let (ssxm, ssxym, ssym) =
+        Zip::from(x)
+            .and(y)
+            .fold((F::zero(), F::zero(), F::zero()), |(xx, xy, yy), &a, &b| {
+                let (a, b) = (a - x_mean, b - y_mean);
+                (xx + a * a, xy + a * b, yy + b * b)
+            });
+    let (ssxm, ssxym, ssym) = (ssxm / n, ssxym / n, ssym / n);
+    if ssxm == F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "cannot calculate a linear regression if all x values are identical.".into(),
+        });
+    }
+    let rvalue = if ssym == F::zero() {
+        F::zero()
+    } else {
+        (ssxym / (ssxm * ssym).sqrt()).max(-one).min(one)
+    };
+    let slope = ssxym / ssxm;
+    let intercept = y_mean - slope * x_mean;
+
+    if x.len() == 2 {
+        let pvalue = if y[0] == y[1] { one } else { F::zero() };
+        return Ok(LinregressResult {
+            slope,
+            intercept,
+            rvalue,
+            pvalue,
+            stderr: F::zero(),
+            intercept_stderr: F::zero(),
+        });
+    }
+    let df = n - F::from(2).unwrap();
+    // A tiny offset keeps the statistic finite for a perfect fit.
+    let tiny = F::from(1e-20).unwrap();
+    let t = rvalue * (df / ((one - rvalue + tiny) * (one + rvalue + tiny))).sqrt();
+    let pvalue = pvalue(
+        &StudentT::new(df, None, None)?,
+        t,
+        alternative.unwrap_or_default(),
+    );
+    let stderr = ((one - rvalue * rvalue) * ssym / ssxm / df).sqrt();
+    Ok(LinregressResult {
+        slope,
+        intercept,
+        rvalue,
+        pvalue,
+        stderr,
+        intercept_stderr: stderr * (ssxm + x_mean * x_mean).sqrt(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn regression() {
+        let x = array![1.5, 2.0, 3.1, 4.2, 5.0, 6.3, 7.1];
+        let y = array![3.2, 4.1, 6.5, 8.4, 9.9, 12.8, 14.0];
+        let res = linregress(&x, &y, Some(Alternative::Less)).unwrap();
+        assert_abs_diff_eq!(res.pvalue, 0.9999999902375143, epsilon = 1e-15);
+
+        // A perfect fit.
+        let res = linregress(&x, &(&x * 2. - 1.), None).unwrap();
+        assert_abs_diff_eq!(res.slope, 2., epsilon = 1e-14);
+        assert_abs_diff_eq!(res.intercept, -1., epsilon = 1e-14);
+        assert_abs_diff_eq!(res.rvalue, 1., epsilon = 1e-15);
+        assert_abs_diff_eq!(res.stderr, 0., epsilon = 1e-7);
+        assert!(res.pvalue < 1e-12);
+
+        let res = linregress(&array![1., 3.], &array![2., 2.], None).unwrap();
+        assert_eq!((res.slope, res.rvalue, res.pvalue), (0., 0., 1.));
+        let res = linregress(&array![1., 3.], &array![2., 6.], None).unwrap();
+        assert_eq!((res.slope, res.intercept, res.pvalue), (2., 0., 0.));
+    }
+
+    #[test]
+    fn invalid_args() {
+        assert!(linregress(&array![1., 2., 3.], &array![1., 2.], None).is_err());
+        assert!(linregress(&array![1.], &array![1.], None).is_err());
+        assert!(linregress(&array![2., 2., 2.], &array![1., 2., 3.], None).is_err());
+    }
+}
@@ -0,0 +1,866 @@
+use super::descriptive::{percentile_of_sorted, sorted, var_of};
+use super::hypothesis::check_confidence_level;
+use super::{Alternative, ConfidenceInterval, ContinuousDistribution, Norm};
+use crate::random::{default_rng, Rng};
+use alloc::vec;
+use alloc::vec::Vec;
+use ndarray::{Array1, ArrayView1};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Method of the confidence interval of [bootstrap].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BootstrapMethod {
+    /// Percentiles of the bootstrap distribution.
+    Percentile,
+    /// The percentiles reflected around the observed value of the statistic, also known as the
+    /// reverse percentile interval.
+    Basic,
+    /// The bias-corrected and accelerated percentiles.
+    #[default]
+    BCa,
+}
+
+/// Options of [bootstrap].
+///
+/// Every field left as `None` takes the default of the method, which is also what [Default]
+/// gives.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BootstrapOptions<F> {
+    /// Number of resamples forming the bootstrap distribution, `9999` by default.
+    pub n_resamples: Option<usize>,
+    /// If `true`, the samples are paired, and resampled with the same indices. It is `false` by
+    /// default, where each sample is resampled independently.
+    pub paired: Option<bool>,
+    /// Confidence level of the interval, `0.95` by default.
+    pub confidence_level: Option<F>,
+    /// For the default [Alternative::TwoSided], the interval is two-sided. For
+    /// [Alternative::Less], the lower bound is `-inf`, and for [Alternative::Greater], the upper
+    /// bound is `inf`.
+    pub alternative: Option<Alternative>,
+    /// Method of the interval, [BootstrapMethod::BCa] by default.
+    pub method: Option<BootstrapMethod>,
+    /// Maximum number of threads evaluating the statistic on the resamples, which needs the
+    /// `parallel` feature. Defaults to 1.
+    pub workers: Option<usize>,
+}
+
+/// Result of [bootstrap].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BootstrapResult<F> {
+    /// The bootstrap confidence interval of the statistic.
+    pub confidence_interval: ConfidenceInterval<F>,
+    /// The statistic of each resample.
+    pub bootstrap_distribution: Array1<F>,
+    /// The bootstrap standard error, the sample standard deviation of the bootstrap
+    /// distribution.
+    pub standard_error: F,
+}
+
+/// How [permutation_test] permutes the data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PermutationType {
+    /// The observations are exchanged between the samples, whose sizes are kept. This tests
+    /// that the samples come from the same distribution.
+    #[default]
+    Independent,
+    /// The observations of the same index are exchanged between the paired samples. With a
+    /// single sample, the signs of its observations are flipped instead.
+    Samples,
+    /// The observations are permuted within each of the paired samples, which tests that the
+    /// pairings are not related.
+    Pairings,
+}
+
+/// Options of [permutation_test].
+///
+/// Every field left as `None` takes the default of the test, which is also what [Default]
+/// gives.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PermutationTestOptions {
+    /// How the data are permuted, [PermutationType::Independent] by default.
+    pub permutation_type: Option<PermutationType>,
+    /// Number of random permutations forming the null distribution, `9999` by default. If it is
+    /// at least the number of distinct permutations, they are all enumerated instead.
+    pub n_resamples: Option<usize>,
+    /// Defines the [Alternative] hypothesis, two-sided by default. For [Alternative::Less], the
+    /// statistic is less under the alternative than under the null hypothesis, and for
+    /// [Alternative::Greater], it is greater.
+    pub alternative: Option<Alternative>,
+    /// Maximum number of threads evaluating the statistic on the permutations, which needs the
+    /// `parallel` feature. Defaults to 1.
+    pub workers: Option<usize>,
+}
+
+/// Result of [permutation_test].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermutationTestResult<F> {
+    /// The observed statistic of the data.
+    pub statistic: F,
+    /// The p-value for the given alternative.
+    pub pvalue: F,
+    /// The statistic of each permutation of the data.
+    pub null_distribution: Array1<F>,
+}
+
+fn check_workers(workers: Option<usize>) -> Result<usize> {
+    match workers.unwrap_or(1) {
+        0 => Err(Error::InvalidArg {
+            arg: "workers".into(),
+            reason: "must not be zero.".into(),
+        }),
+        workers => Ok(workers),
+    }
+}
+
+/// Values of `f` at `0..n`, computed on a pool of `workers` threads with the `parallel` feature.
+fn evaluate<F, G>(n: usize, workers: usize, f: G) -> Result<Array1<F>>
+where
+    F: Send,
+    G: Fn(usize) -> F + Sync,
+{
+    #[cfg(feature = "parallel")]
+    if workers > 1 {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(workers)
+            .build()
+            .map_err(|e| Error::InvalidArg {
+                arg: "workers".into(),
+                reason: e.to_string(),
+            })?;
+        let values: Vec<F> = pool.install(|| (0..n).into_par_iter().map(&f).collect());
+        return Ok(Array1::from(values));
+    }
+    Ok((0..n).map(f).collect())
+}
+
+fn views<F>(samples: &[Array1<F>]) -> Vec<ArrayView1<'_, F>> {
+    samples.iter().map(|x| x.view()).collect()
+}
+
+/// `x` without its `j`-th observation.
+fn without<F: Copy>(x: ArrayView1<F>, j: usize) -> Array1<F> {
+    x.iter()
+        .enumerate()
+        .filter(|&(i, _)| i != j)
+        .map(|(_, &v)| v)
+        .collect()
+}
+
+/// Acceleration of the BCa interval, from the jackknife distribution of the statistic.
+fn acceleration<F, G>(data: &[ArrayView1<F>], statistic: &G, paired: bool) -> F
+where
+    F: Float,
+    G: Fn(&[ArrayView1<F>]) -> F,
+{
+    let jackknife = |theta: Vec<F>| {
+        let mean = theta.iter().fold(F::zero(), |acc, &t| acc + t) / F::from(theta.len()).unwrap();
+        let (num, den) = theta.iter().fold((F::zero(), F::zero()), |(num, den), &t| {
+            let d = mean - t;
+            (num + d * d * d, den + d * d)
+        });
+        num / (F::from(6).unwrap() * den.powf(F::from(1.5).unwrap()))
+    };
+    if paired {
+        let theta = (0..data[0].len())
+            .map(|j| {
+                let samples: Vec<_> = data.iter().map(|x| without(x.view(), j)).collect();
+                statistic(&views(&samples))
+            })
+            .collect();
+        return jackknife(theta);
+    }
+    (0..data.len()).fold(F::zero(), |acc, i| {
+        let theta = (0..data[i].len())
+            .map(|j| {
+                let removed = without(data[i].view(), j);
+                let mut samples: Vec<_> = data.iter().map(|x| x.view()).collect();
+                samples[i] = removed.view();
+                statistic(&samples)
+            })
+            .collect();
+        acc + jackknife(theta)
+    })
+}
+
+/// Compute a two-sided bootstrap confidence interval of a statistic.
+///
+/// ## Parameters
+/// * `data`: The samples, each of them being resampled with replacement.
+/// * `statistic`: The statistic of the samples, whose confidence interval is computed.
+/// * `rng`: The random number generator drawing the resamples. If `None`, a generator seeded
+///   from the operating system is used.
+/// * `options`: The [BootstrapOptions].
+///
+/// ## Returns
+/// The [BootstrapResult].
+///
+/// ## Errors
+/// If `data` is empty, a sample has less than two observations, or the paired samples differ
+/// in length. If `n_resamples`, `confidence_level` or `workers` are invalid.
+///
+/// ## Notes
+/// The statistic is computed on `n_resamples` resamples of the data, forming the bootstrap
+/// distribution. The percentile interval takes its percentiles at `(1 - confidence_level) / 2`
+/// and `(1 + confidence_level) / 2`. The BCa interval shifts these levels to correct the bias
+/// of the bootstrap distribution, estimated from the fraction of it below the observed
+/// statistic, and its skewness, estimated by the jackknife.
+///
+/// Each resample is drawn from a generator seeded in turn from `rng`, so that the result for a
+/// given seed does not depend on the number of workers.
+///
+/// ## References
+/// 1. B. Efron and R. J. Tibshirani, An Introduction to the Bootstrap, Chapman & Hall/CRC,
+///    Boca Raton, FL, USA (1993)
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import stats
+/// x = [2.1, 3.4, 1.9, 5.6, 4.2, 3.3, 2.8, 4.9, 3.7, 2.5]
+/// res = stats.bootstrap((x,), np.mean, rng=np.random.default_rng(0))
+/// res.confidence_interval
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::{array, ArrayView1};
+/// use sci_rs::random::Rng;
+/// use sci_rs::stats::bootstrap;
+///
+/// let x = array![2.1, 3.4, 1.9, 5.6, 4.2, 3.3, 2.8, 4.9, 3.7, 2.5];
+/// let mean = |data: &[ArrayView1<f64>]| data[0].mean().unwrap();
+/// let mut rng = Rng::new(0);
+/// let res = bootstrap(&[x.view()], mean, Some(&mut rng), Default::default()).unwrap();
+/// let ci = res.confidence_interval;
+/// assert!(ci.low < 3.44 && 3.44 < ci.high);
+/// assert!(2.5 < ci.low && ci.high < 4.5);
+/// assert_eq!(res.bootstrap_distribution.len(), 9999);
+/// ```
+pub fn bootstrap<F, G>(
+    data: &[ArrayView1<F>],
+    statistic: G,
+    rng: Option<&mut Rng>,
+    options: BootstrapOptions<F>,
+) -> Result<BootstrapResult<F>>
+where
+    F: Float + Send + Sync,
+    G: Fn(&[ArrayView1<F>]) -> F + Sync,
+{
+    let n_resamples = options.n_resamples.unwrap_or(9999);
+    let paired = options.paired.unwrap_or(false);
+    let confidence_level = check_confidence_level(options.confidence_level)?;
+    let alternative = options.alternative.unwrap_or_default();
+    let method = options.method.unwrap_or_default();
+    let workers = check_workers(options.workers)?;
+    if data.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "data".into(),
+            reason: "must contain at least one sample.".into(),
+        });
+    }
+    if data.iter().any(|x| x.len() < 2) {
+        return Err(Error::InvalidArg {
+            arg: "data".into(),
+            reason: "each sample must contain two or more observations.".into(),
+        });
+    }
+    if paired && data.iter().any(|x| x.len() != data[0].len()) {
+        return Err(Error::InvalidArg {
+            arg: "data".into(),
+            reason: "paired samples must have the same length.".into(),
+        });
+    }
+    if n_resamples == 0 {
+        return Err(Error::InvalidArg {
+            arg: "n_resamples".into(),
+            reason: "must be positive.".into(),
+        });
+    }
+
+    let mut default = None;
+    let rng = rng.unwrap_or_else(|| default.insert(default_rng()));
+    let seeds: Vec<u64> = (0..n_resamples).map(|_| rng.next_u64()).collect();
+    let resample = |i: usize| {
+        let mut rng = Rng::new(seeds[i]);
+        let samples: Vec<Array1<F>> = if paired {
+            let n = data[0].len();
+            let indices: Vec<usize> = (0..n).map(|_| rng.integers(0, n)).collect();
+            data.iter()
+                .map(|x| indices.iter().map(|&j| x[j]).collect())
+                .collect()
+        } else {
+            data.iter()
+                .map(|x| (0..x.len()).map(|_| x[rng.integers(0, x.len())]).collect())
+                .collect()
+        };
+        statistic(&views(&samples))
+    };
+    let bootstrap_distribution = evaluate(n_resamples, workers, resample)?;
+
+    let one = F::one();
+    let two = F::from(2).unwrap();
+    let theta_hat = statistic(data);
+    let alpha = match alternative {
+        Alternative::TwoSided => (one - confidence_level) / two,
+        _ => one - confidence_level,
+    };
+    let sorted_distribution = sorted(bootstrap_distribution.view());
+    let percentile = |q: F| {
+        if q.is_nan() {
+            F::nan()
+        } else {
+            percentile_of_sorted(&sorted_distribution, q * F::from(100).unwrap())
+        }
+    };
+    let (mut low, mut high) = match method {
+        BootstrapMethod::Percentile => (percentile(alpha), percentile(one - alpha)),
+        BootstrapMethod::Basic => {
+            let (low, high) = (percentile(alpha), percentile(one - alpha));
+            (two * theta_hat - high, two * theta_hat - low)
+        }
+        BootstrapMethod::BCa => {
+            let norm = Norm::new(None, None)?;
+            let below = bootstrap_distribution
+                .iter()
+                .map(|&t| usize::from(t < theta_hat) + usize::from(t <= theta_hat))
+                .sum::<usize>();
+            let z0 = norm.ppf(F::from(below).unwrap() / F::from(2 * n_resamples).unwrap());
+            let a_hat = acceleration(data, &statistic, paired);
+            let level = |z_alpha: F| {
+                let num = z0 + z_alpha;
+                norm.cdf(z0 + num / (one - a_hat * num))
+            };
+            let z_alpha = norm.ppf(alpha);
+            (percentile(level(z_alpha)), percentile(level(-z_alpha)))
+        }
+    };
+    match alternative {
+        Alternative::Less => low = F::neg_infinity(),
+        Alternative::Greater => high = F::infinity(),
+        Alternative::TwoSided => {}
+    }
+    let standard_error = var_of(bootstrap_distribution.view(), 1).sqrt();
+    Ok(BootstrapResult {
+        confidence_interval: ConfidenceInterval { low, high },
+        bootstrap_distribution,
+        standard_error,
+    })
+}
+
+/// All the permutations of `0..m`, in lexicographic order.
+fn permutations(m: usize) -> Vec<Vec<usize>> {
+    let mut perms = vec![(0..m).collect::<Vec<_>>()];
+    loop {
+        let mut p = perms[perms.len() - 1].clone();
+        // The next permutation: swap the last ascent with its smallest greater successor, then
+        // reverse the tail.
+        let Some(i) = (1..m).rev().find(|&i| p[i - 1] < p[i]) else {
+            return perms;
+        };
+        let j = (i..m).rev().find(|&j| p[j] > p[i - 1]).unwrap();
+        p.swap(i - 1, j);
+        p[i..].reverse();
+        perms.push(p);
+    }
+}
+
+/// All the assignments of the pooled observations to groups of the given `sizes`.
+fn partitions(sizes: &[usize]) -> Vec<Vec<usize>> {
+    fn assign(
+        labels: &mut Vec<usize>,
+        left: &mut [usize],
+        total: usize,
+        out: &mut Vec<Vec<usize>>,
+    ) {
+        if labels.len() == total {
+            out.push(labels.clone());
+            return;
+        }
+        for g in 0..left.len() {
+            if left[g] > 0 {
+                left[g] -= 1;
+                labels.push(g);
+                assign(labels, left, total, out);
+                labels.pop();
+                left[g] += 1;
+            }
+        }
+    }
+    let mut out = Vec::new();
+    let total = sizes.iter().sum();
+    assign(
+        &mut Vec::with_capacity(total),
+        &mut sizes.to_vec(),
+        total,
+        &mut out,
+    );
+    out
+}
+
+/// Number of distinct permutations of `data`, as a float which may overflow to infinity.
+fn count_permutations<F>(data: &[ArrayView1<F>], permutation_type: PermutationType) -> f64 {
+    let factorial = |m: usize| (1..=m).fold(1., |acc, i| acc * i as f64);
+    let n = data[0].len() as i32;
+    match permutation_type {
+        PermutationType::Independent => {
+            // The multinomial coefficient, as a product of binomial coefficients.
+            let mut total = 0;
+            data.iter().fold(1., |acc, x| {
+                total += x.len();
+                (0..x.len()).fold(acc, |acc, i| {
+                    acc * (total - i) as f64 / (x.len() - i) as f64
+                })
+            })
+        }
+        PermutationType::Samples if data.len() == 1 => 2f64.powi(n),
+        PermutationType::Samples => factorial(data.len()).powi(n),
+        PermutationType::Pairings => factorial(data[0].len()).powi(data.len() as i32),
+    }
+}
+
+/// Perform a permutation test of a given statistic on the data.
+///
+/// ## Parameters
+/// * `data`: The samples.
+/// * `statistic`: The test statistic of the samples.
+/// * `rng`: The random number generator drawing the permutations. If `None`, a generator
+///   seeded from the operating system is used.
+/// * `options`: The [PermutationTestOptions].
+///
+/// ## Returns
+/// The [PermutationTestResult].
+///
+/// ## Errors
+/// If a sample is empty, there are less than two samples for [PermutationType::Independent],
+/// or none for the other types, whose samples must have the same length. If `n_resamples` or
+/// `workers` are invalid.
+///
+/// ## Notes
+/// The null distribution of the statistic is formed by its values on the permutations of the
+/// data, where the null hypothesis makes all of them equally likely. If there are at most
+/// `n_resamples` distinct permutations, they are all enumerated and the test is exact.
+/// Otherwise, the statistic is computed on `n_resamples` random permutations, and the p-value
+/// counts the observed data as one of them, so that it is never zero.
+///
+/// The values of the null distribution within a relative tolerance of `100 * eps` of the
+/// observed statistic are considered equal to it. The two-sided p-value is twice the smallest
+/// one-sided p-value, clipped to 1.
+///
+/// Each permutation is drawn from a generator seeded in turn from `rng`, so that the result for
+/// a given seed does not depend on the number of workers.
+///
+/// ## References
+/// 1. R. A. Fisher, The Design of Experiments, 6th Ed (1951).
+/// 2. B. Phipson and G. K. Smyth, Permutation P-values Should Never Be Zero: Calculating Exact
+///    P-values When Permutations Are Randomly Drawn, Statistical Applications in Genetics and
+///    Molecular Biology 9.1 (2010).
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// import numpy as np
+/// from scipy import stats
+/// def statistic(x, y):
+///     return np.mean(x) - np.mean(y)
+/// res = stats.permutation_test(([1., 2., 3.], [4., 5., 6.]), statistic, vectorized=False)
+/// res.statistic, res.pvalue
+/// # (-3.0, 0.1)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::{array, ArrayView1};
+/// use sci_rs::stats::permutation_test;
+///
+/// let x = array![1., 2., 3.];
+/// let y = array![4., 5., 6.];
+/// let statistic = |data: &[ArrayView1<f64>]| data[0].mean().unwrap() - data[1].mean().unwrap();
+/// let res = permutation_test(&[x.view(), y.view()], statistic, None, Default::default()).unwrap();
+/// assert_eq!(res.statistic, -3.);
+/// assert_abs_diff_eq!(res.pvalue, 0.1, epsilon = 1e-15);
+/// assert_eq!(res.null_distribution.len(), 20);
+/// ```
+pub fn permutation_test<F, G>(
+    data: &[ArrayView1<F>],
+    statistic: G,
+    rng: Option<&mut Rng>,
+    options: PermutationTestOptions,
+) -> Result<PermutationTestResult<F>>
+where
+    F: Float + Send + Sync,
+    G: Fn(&[ArrayView1<F>]) -> F + Sync,
+{
+    let permutation_type = options.permutation_type.unwrap_or_default();
+    let n_resamples = options.n_resamples.unwrap_or(9999);
+    let alternative = options.alternative.unwrap_or_default();
+    let workers = check_workers(options.workers)?;
+    if data.is_empty() || data.iter().any(|x| x.is_empty()) {
+        return Err(Error::InvalidArg {
+            arg: "data".into(),
+            reason: "must contain at least one sample, none of them empty.".into(),
+        });
+    }
+    match permutation_type {
+        PermutationType::Independent if data.len() < 2 => {
+            return Err(Error::InvalidArg {
+                arg: "data".into(),
+                reason: "must contain at least two samples.".into(),
+            });
+        }
+        PermutationType::Samples | PermutationType::Pairings
+            if data.iter().any(|x| x.len() != data[0].len()) =>
+        {
+            return Err(Error::InvalidArg {
+                arg: "data".into(),
+                reason: "paired samples must have the same length.".into(),
+            });
+        }
+        _ => {}
+    }
+    if n_resamples == 0 {
+        return Err(Error::InvalidArg {
+            arg: "n_resamples".into(),
+            reason: "must be positive.".into(),
+        });
+    }
+
+    let k = data.len();
+    let n = data[0].len();
+    let pooled: Vec<F> = data.iter().flat_map(|x| x.iter().copied()).collect();
+    let sizes: Vec<usize> = data.iter().map(|x| x.len()).collect();
+    let exact = count_permutations(data, permutation_type) <= n_resamples as f64;
+    // The permutations of the groups (or of the observations) enumerated by the exact test.
+    let (labels, perms) = match (exact, permutation_type) {
+        (false, _) => (Vec::new(), Vec::new()),
+        (true, PermutationType::Independent) => (partitions(&sizes), Vec::new()),
+        (true, PermutationType::Samples) => (Vec::new(), permutations(k)),
+        (true, PermutationType::Pairings) => (Vec::new(), permutations(n)),
+    };
+    let count = if exact {
+        count_permutations(data, permutation_type) as usize
+    } else {
+        n_resamples
+    };
+    let seeds: Vec<u64> = if exact {
+        Vec::new()
+    } else {
+        let mut default = None;
+        let rng = rng.unwrap_or_else(|| default.insert(default_rng()));
+        (0..n_resamples).map(|_| rng.next_u64()).collect()
+    };
+
+    let split = |labels: &[usize]| {
+        let mut samples = vec![Vec::new(); k];
+        for (&g, &v) in labels.iter().zip(&pooled) {
+            samples[g].push(v);
+        }
+        samples
+    };
+    let permute = |i: usize| {
+        let samples: Vec<Vec<F>> = match (permutation_type, exact) {
+            (PermutationType::Independent, true) => split(&labels[i]),
+            (PermutationType::Independent, false) => {
+                let mut rng = Rng::new(seeds[i]);
+                let mut labels: Vec<usize> = (0..k)
+                    .flat_map(|g| core::iter::repeat_n(g, sizes[g]))
+                    .collect();
+                rng.shuffle(&mut labels);
+                split(&labels)
+            }
+            (PermutationType::Samples, _) if k == 1 => {
+                let mut rng = (!exact).then(|| Rng::new(seeds[i]));
+                let flips = (0..n).map(|j| match rng.as_mut() {
+                    Some(rng) => rng.next_u64() >> 63 == 1,
+                    None => (i >> j) & 1 == 1,
+                });
+                vec![data[0]
+                    .iter()
+                    .zip(flips)
+                    .map(|(&v, flip)| if flip { -v } else { v })
+                    .collect()]
+            }
+            (PermutationType::Samples, _) => {
+                let mut rng = (!exact).then(|| Rng::new(seeds[i]));
+                let mut rest = i;
+                // The sample each group takes its observation from, at each index.
+                let orders: Vec<Vec<usize>> = (0..n)
+                    .map(|_| match rng.as_mut() {
+                        Some(rng) => {
+                            let mut order: Vec<usize> = (0..k).collect();
+                            rng.shuffle(&mut order);
+                            order
+                        }
+                        None => {
+                            let order = perms[rest % perms.len()].clone();
+                            rest /= perms.len();
+                            order
+                        }
+                    })
+                    .collect();
+                (0..k)
+                    .map(|g| {
+                        orders
+                            .iter()
+                            .enumerate()
+                            .map(|(j, order)| data[order[g]][j])
+                            .collect()
+                    })
+                    .collect()
+            }
+            (PermutationType::Pairings, _) => {
+                let mut rng = (!exact).then(|| Rng::new(seeds[i]));
+                let mut rest = i;
+                let mut order: Vec<usize> = (0..n).collect();
+                data.iter()
+                    .map(|x| {
+                        match rng.as_mut() {
+                            Some(rng) => rng.shuffle(&mut order),
+                            None => {
+                                order.clone_from(&perms[rest % perms.len()]);
+                                rest /= perms.len();
+                            }
+                        }
+                        order.iter().map(|&j| x[j]).collect()
+                    })
+                    .collect()
+            }
+        };
+        let samples: Vec<Array1<F>> = samples.into_iter().map(Array1::from).collect();
+        statistic(&views(&samples))
+    };
+    let null_distribution = evaluate(count, workers, permute)?;
+
+    let observed = statistic(data);
+    // Values of the null distribution within a relative tolerance of the observed statistic
+    // are counted as equal to it.
+    let gamma = (F::epsilon() * F::from(100).unwrap() * observed).abs();
+    let adjustment = usize::from(!exact);
+    let fraction = |count: usize| {
+        F::from(count + adjustment).unwrap()
+            / F::from(null_distribution.len() + adjustment).unwrap()
+    };
+    let less = || {
+        fraction(
+            null_distribution
+                .iter()
+                .filter(|&&t| t <= observed + gamma)
+                .count(),
+        )
+    };
+    let greater = || {
+        fraction(
+            null_distribution
+                .iter()
+                .filter(|&&t| t >= observed - gamma)
+                .count(),
+        )
+    };
+    let pvalue = match alternative {
+        Alternative::Less => less(),
+        Alternative::Greater => greater(),
+        Alternative::TwoSided => (F::from(2).unwrap() * less().min(greater())).min(F::one()),
+    };
+    Ok(PermutationTestResult {
+        statistic: observed,
+        pvalue,
+        null_distribution,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    fn mean(x: ArrayView1<f64>) -> f64 {
+        x.mean().unwrap()
+    }
+
+    #[test]
+    fn bootstrap_intervals() {
+        let x = array![2.1, 3.4, 1.9, 5.6, 4.2, 3.3, 2.8, 4.9, 3.7, 2.5];
+        let statistic = |data: &[ArrayView1<f64>]| mean(data[0]);
+        let run = |method, alternative, workers| {
+            let options = BootstrapOptions {
+                method: Some(method),
+                alternative: Some(alternative),
+                workers: Some(workers),
+                ..Default::default()
+            };
+            bootstrap(&[x.view()], statistic, Some(&mut Rng::new(7)), options).unwrap()
+        };
+        let percentile = run(BootstrapMethod::Percentile, Alternative::TwoSided, 1);
+        let basic = run(BootstrapMethod::Basic, Alternative::TwoSided, 1);
+        let bca = run(BootstrapMethod::BCa, Alternative::TwoSided, 1);
+        // The resamples only depend on the seed.
+        assert_eq!(
+            percentile.bootstrap_distribution,
+            bca.bootstrap_distribution
+        );
+        assert_eq!(bca, run(BootstrapMethod::BCa, Alternative::TwoSided, 4));
+
+        // The basic interval reflects the percentile one around the mean.
+        let (pi, bi) = (percentile.confidence_interval, basic.confidence_interval);
+        assert_abs_diff_eq!(bi.low, 2. * 3.44 - pi.high, epsilon = 1e-12);
+        assert_abs_diff_eq!(bi.high, 2. * 3.44 - pi.low, epsilon = 1e-12);
+        for ci in [&pi, &bi, &bca.confidence_interval] {
+            assert!(2.5 < ci.low && ci.low < 3.44 && 3.44 < ci.high && ci.high < 4.5);
+        }
+        // The standard error of the mean is close to s / sqrt(n).
+        let sem = (var_of(x.view(), 1) / 10.).sqrt();
+        assert_abs_diff_eq!(percentile.standard_error, sem, epsilon = 0.05);
+
+        let less = run(BootstrapMethod::Percentile, Alternative::Less, 1);
+        assert_eq!(less.confidence_interval.low, f64::NEG_INFINITY);
+        assert!(less.confidence_interval.high < pi.high);
+        let greater = run(BootstrapMethod::BCa, Alternative::Greater, 1);
+        assert_eq!(greater.confidence_interval.high, f64::INFINITY);
+        assert!(greater.confidence_interval.low > bca.confidence_interval.low);
+    }
+
+    #[test]
+    fn bootstrap_paired() {
+        let x = array![1., 2., 3., 4., 5., 6., 7., 8.];
+        let y = array![1.1, 2.3, 2.9, 4.2, 4.8, 6.1, 7.2, 7.9];
+        // The difference of means of paired samples, whose differences are small.
+        let statistic = |data: &[ArrayView1<f64>]| mean(data[0]) - mean(data[1]);
+        let options = BootstrapOptions {
+            paired: Some(true),
+            n_resamples: Some(2000),
+            ..Default::default()
+        };
+        let res = bootstrap(
+            &[x.view(), y.view()],
+            statistic,
+            Some(&mut Rng::new(3)),
+            options,
+        )
+        .unwrap();
+        let ci = res.confidence_interval;
+        assert!(-0.2 < ci.low && ci.low < -0.0625 && -0.0625 < ci.high && ci.high < 0.1);
+        assert_eq!(res.bootstrap_distribution.len(), 2000);
+    }
+
+    #[test]
+    fn permutation_exact() {
+        let x = array![1., 2., 3.];
+        let y = array![4., 5., 6.];
+        let statistic = |data: &[ArrayView1<f64>]| mean(data[0]) - mean(data[1]);
+        let test = |permutation_type, alternative| {
+            let options = PermutationTestOptions {
+                permutation_type: Some(permutation_type),
+                alternative: Some(alternative),
+                ..Default::default()
+            };
+            permutation_test(&[x.view(), y.view()], statistic, None, options).unwrap()
+        };
+        let res = test(PermutationType::Independent, Alternative::Less);
+        assert_abs_diff_eq!(res.pvalue, 0.05, epsilon = 1e-15);
+        let res = test(PermutationType::Independent, Alternative::Greater);
+        assert_abs_diff_eq!(res.pvalue, 1., epsilon = 1e-15);
+
+        // Exchanging the pairs: the statistic is -3 only if none is exchanged.
+        let res = test(PermutationType::Samples, Alternative::Less);
+        assert_eq!(res.null_distribution.len(), 8);
+        assert_abs_diff_eq!(res.pvalue, 0.125, epsilon = 1e-15);
+        // The pairings do not change the means.
+        let res = test(PermutationType::Pairings, Alternative::TwoSided);
+        assert_eq!(res.null_distribution.len(), 36);
+        assert_eq!(res.pvalue, 1.);
+
+        // The sign flips of a single sample test that its mean is zero.
+        let d = array![0.5, 1.2, 0.8, -0.1, 0.9];
+        let options = PermutationTestOptions {
+            permutation_type: Some(PermutationType::Samples),
+            alternative: Some(Alternative::Greater),
+            ..Default::default()
+        };
+        let res = permutation_test(&[d.view()], |data| mean(data[0]), None, options).unwrap();
+        assert_eq!(res.null_distribution.len(), 32);
+        // Only the observed signs and flipping the sign of -0.1 give a mean of at least 0.66.
+        assert_abs_diff_eq!(res.pvalue, 2. / 32., epsilon = 1e-15);
+    }
+
+    #[test]
+    fn permutation_random() {
+        let x = array![3.1, 2.4, 4.8, 3.9, 5.2, 4.4, 3.6, 2.9, 4.1, 5.0];
+        let y = array![4.2, 5.6, 4.9, 6.3, 5.8, 4.7, 6.1, 5.3, 5.5, 6.0];
+        let statistic = |data: &[ArrayView1<f64>]| mean(data[0]) - mean(data[1]);
+        let test = |workers| {
+            let options = PermutationTestOptions {
+                n_resamples: Some(5000),
+                workers: Some(workers),
+                ..Default::default()
+            };
+            let data = [x.view(), y.view()];
+            permutation_test(&data, statistic, Some(&mut Rng::new(5)), options).unwrap()
+        };
+        let res = test(1);
+        assert_eq!(res, test(3));
+        assert_eq!(res.null_distribution.len(), 5000);
+        // The samples clearly differ, and the p-value is never zero.
+        assert!(res.pvalue > 0. && res.pvalue < 0.01);
+        // The null distribution of the difference of means is symmetric around 0.
+        assert_abs_diff_eq!(mean(res.null_distribution.view()), 0., epsilon = 0.05);
+
+        // The pairings of correlated samples.
+        let options = PermutationTestOptions {
+            permutation_type: Some(PermutationType::Pairings),
+            alternative: Some(Alternative::Greater),
+            n_resamples: Some(2000),
+            ..Default::default()
+        };
+        let a = array![1., 2., 3., 4., 5., 6., 7., 8., 9.];
+        let b = array![1.5, 1.9, 3.2, 4.5, 4.9, 6.6, 6.8, 8.3, 9.1];
+        let dot = |data: &[ArrayView1<f64>]| data[0].dot(&data[1]);
+        let data = [a.view(), b.view()];
+        let res = permutation_test(&data, dot, Some(&mut Rng::new(2)), options).unwrap();
+        assert!(res.pvalue < 0.01);
+    }
+
+    #[test]
+    fn invalid_args() {
+        let x = array![1., 2., 3.];
+        let statistic = |data: &[ArrayView1<f64>]| mean(data[0]);
+        let boot =
+            |data: &[ArrayView1<f64>], options| bootstrap(data, statistic, None, options).is_err();
+        assert!(boot(&[], Default::default()));
+        assert!(boot(&[array![1.].view()], Default::default()));
+        let options = BootstrapOptions {
+            paired: Some(true),
+            ..Default::default()
+        };
+        assert!(boot(&[x.view(), array![1., 2.].view()], options));
+        let options = BootstrapOptions {
+            confidence_level: Some(1.),
+            ..Default::default()
+        };
+        assert!(boot(&[x.view()], options));
+        let options = BootstrapOptions {
+            workers: Some(0),
+            ..Default::default()
+        };
+        assert!(boot(&[x.view()], options));
+
+        let perm = |data: &[ArrayView1<f64>], permutation_type| {
+            let options = PermutationTestOptions {
+                permutation_type: Some(permutation_type),
+                ..Default::default()
+            };
+            permutation_test(data, statistic, None, options).is_err()
+        };
+        assert!(perm(&[x.view()], PermutationType::Independent));
+        assert!(perm(
+            &[x.view(), array![].view()],
+            PermutationType::Independent
+        ));
+        assert!(perm(
+            &[x.view(), array![1.].view()],
+            PermutationType::Pairings
+        ));
+    }
+}
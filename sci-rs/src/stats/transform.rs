@@ -0,0 +1,476 @@
+use super::descriptive::{mean_of, reduce_axis, sorted, var_of};
+use super::NanPolicy;
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use alloc::vec::Vec;
+use ndarray::{Array, ArrayBase, ArrayView1, Axis, Data, Dimension, RemoveAxis, Zip};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Apply `f` to every 1-D lane of `a` along `axis`, or to the flattened `a` for `None`, into an
+/// array of the shape of `a`.
+fn map_lanes<F, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    mut f: impl FnMut(ArrayView1<F>) -> Vec<F>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    match axis {
+        None => {
+            let flat: Vec<F> = a.iter().copied().collect();
+            let out = f(ArrayView1::from(&flat));
+            Ok(Array::from_shape_vec(a.raw_dim(), out).unwrap())
+        }
+        Some(axis) => {
+            let axis = Axis(check_and_get_axis_dyn(Some(axis), a)?);
+            let mut out = Array::zeros(a.raw_dim());
+            Zip::from(out.lanes_mut(axis))
+                .and(a.lanes(axis))
+                .for_each(|mut o, x| {
+                    o.iter_mut().zip(f(x)).for_each(|(o, v)| *o = v);
+                });
+            Ok(out)
+        }
+    }
+}
+
+/// Method to assign ranks to tied elements in [rankdata].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RankMethod {
+    /// The average of the ranks that would have been assigned to all the tied values.
+    #[default]
+    Average,
+    /// The minimum of the ranks that would have been assigned to all the tied values.
+    Min,
+    /// The maximum of the ranks that would have been assigned to all the tied values.
+    Max,
+    /// Like [RankMethod::Min], but the rank of the next highest element is the rank
+    /// immediately after those assigned to the tied elements.
+    Dense,
+    /// All values are given a distinct rank, corresponding to the order in which they occur.
+    Ordinal,
+}
+
+/// Ranks of `x` from 1, following `method` for the ties. `x` must not contain NaN.
+pub(crate) fn rank_of<F: Float>(x: &[F], method: RankMethod) -> Vec<F> {
+    let mut order: Vec<usize> = (0..x.len()).collect();
+    // The sort is stable, for the ordinal ranks.
+    order.sort_by(|&i, &j| x[i].partial_cmp(&x[j]).unwrap());
+    let mut ranks = alloc::vec![F::zero(); x.len()];
+    let (mut start, mut group) = (0, 0);
+    while start < order.len() {
+        let end = start + order[start..].partition_point(|&i| x[i] == x[order[start]]);
+        group += 1;
+        for (k, &i) in order[start..end].iter().enumerate() {
+            let rank = match method {
+                RankMethod::Average => (start + end + 1) as f64 / 2.,
+                RankMethod::Min => (start + 1) as f64,
+                RankMethod::Max => end as f64,
+                RankMethod::Dense => group as f64,
+                RankMethod::Ordinal => (start + k + 1) as f64,
+            };
+            ranks[i] = F::from(rank).unwrap();
+        }
+        start = end;
+    }
+    ranks
+}
+
+/// Assign ranks to data, dealing with ties appropriately.
+///
+/// Ranks begin at 1. The `method` argument controls how ranks are assigned to equal values.
+///
+/// ## Parameters
+/// * `a`: The array of values to be ranked.
+/// * `method`: The [RankMethod] for the ties, [RankMethod::Average] by default.
+/// * `axis`: Axis along which to rank the values. By default, `a` is ranked as a whole.
+/// * `nan_policy`: How to handle NaN in the input, [NanPolicy::Propagate] by default. With
+///   [NanPolicy::Propagate], the ranks of the values ranked together with a NaN are all NaN.
+///   With [NanPolicy::Omit], the NaNs are ranked NaN and the other values are ranked among
+///   themselves.
+///
+/// ## Returns
+/// The ranks, of the shape of `a`.
+///
+/// ## Errors
+/// If `axis` is out of range, or if `a` contains NaN with [NanPolicy::Raise].
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats import rankdata
+/// rankdata([0, 2, 3, 2])
+/// # array([ 1. ,  2.5,  4. ,  2.5])
+/// rankdata([0, 2, 3, 2], method='dense')
+/// # array([1, 2, 3, 2])
+/// rankdata([[0, 2, 2], [3, 2, 5]], axis=1)
+/// # array([[1. , 2.5, 2.5],
+/// #        [2. , 1. , 3. ]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::stats::{rankdata, RankMethod};
+///
+/// let a = array![0., 2., 3., 2.];
+/// assert_eq!(rankdata(&a, None, None, None).unwrap(), array![1., 2.5, 4., 2.5]);
+/// let dense = rankdata(&a, Some(RankMethod::Dense), None, None).unwrap();
+/// assert_eq!(dense, array![1., 2., 3., 2.]);
+/// let a = array![[0., 2., 2.], [3., 2., 5.]];
+/// let ranks = rankdata(&a, None, Some(1), None).unwrap();
+/// assert_eq!(ranks, array![[1., 2.5, 2.5], [2., 1., 3.]]);
+/// ```
+pub fn rankdata<F, S, D>(
+    a: &ArrayBase<S, D>,
+    method: Option<RankMethod>,
+    axis: Option<isize>,
+    nan_policy: Option<NanPolicy>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let method = method.unwrap_or_default();
+    let nan_policy = nan_policy.unwrap_or_default();
+    if nan_policy == NanPolicy::Raise && a.iter().any(|v| v.is_nan()) {
+        return Err(Error::InvalidArg {
+            arg: "a".into(),
+            reason: "the input contains NaN.".into(),
+        });
+    }
+    map_lanes(a, axis, |x| {
+        let values: Vec<F> = x.iter().copied().filter(|v| !v.is_nan()).collect();
+        if values.len() == x.len() {
+            return rank_of(&values, method);
+        }
+        if nan_policy == NanPolicy::Propagate {
+            return alloc::vec![F::nan(); x.len()];
+        }
+        let mut ranks = rank_of(&values, method).into_iter();
+        x.iter()
+            .map(|v| match v.is_nan() {
+                true => F::nan(),
+                false => ranks.next().unwrap(),
+            })
+            .collect()
+    })
+}
+
+/// Compute the z score.
+///
+/// Compute the z score of each value in the sample, relative to the sample mean and standard
+/// deviation along `axis`.
+///
+/// ## Parameters
+/// * `a`: The sample.
+/// * `axis`: Axis along which to operate, 0 by default.
+/// * `ddof`: Degrees of freedom correction in the calculation of the standard deviation, 0 by
+///   default.
+///
+/// ## Returns
+/// The z scores, of the shape of `a`.
+///
+/// ## Errors
+/// If `axis` is out of range.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// b = np.array([[0.3148, 0.0478, 0.6243, 0.4608],
+///               [0.7149, 0.0775, 0.6072, 0.9656],
+///               [0.6341, 0.1403, 0.9759, 0.4064]])
+/// stats.zscore(b, axis=1, ddof=1)
+/// # array([[-0.19264823, -1.28415119,  1.07259584,  0.40420358],
+/// #        [ 0.33048416, -1.37380874,  0.04251374,  1.00081084],
+/// #        [ 0.26796377, -1.12598418,  1.23283094, -0.37481053]])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::zscore;
+///
+/// let b = array![
+///     [0.3148, 0.0478, 0.6243, 0.4608],
+///     [0.7149, 0.0775, 0.6072, 0.9656],
+///     [0.6341, 0.1403, 0.9759, 0.4064]
+/// ];
+/// let z = zscore(&b, Some(1), Some(1)).unwrap();
+/// let expected = array![
+///     [-0.19264823, -1.28415119, 1.07259584, 0.40420358],
+///     [0.33048416, -1.37380874, 0.04251374, 1.00081084],
+///     [0.26796377, -1.12598418, 1.23283094, -0.37481053]
+/// ];
+/// assert_abs_diff_eq!(z.as_slice().unwrap(), expected.as_slice().unwrap(), epsilon = 1e-8);
+///
+/// let z = zscore(&array![1f32, 2., 3., 4., 5.], None, None).unwrap();
+/// let answer: [f32; 5] = [-1.4142135, -0.70710677, 0., 0.70710677, 1.4142135];
+/// assert_abs_diff_eq!(z.as_slice().unwrap(), &answer[..], epsilon = 1e-6);
+///
+/// // Example from scipy docs
+/// let a = array![
+///     0.7972f32, 0.0767, 0.4383, 0.7866, 0.8091, 0.1954, 0.6307, 0.6599, 0.1065, 0.0508
+/// ];
+/// let z = zscore(&a, None, None).unwrap();
+/// let answer: [f32; 10] = [
+///     1.1272455, -1.2469956, -0.05542642, 1.0923157, 1.1664592, -0.8558472, 0.5785833,
+///     0.67480516, -1.1487966, -1.3323431,
+/// ];
+/// assert_abs_diff_eq!(z.as_slice().unwrap(), &answer[..], epsilon = 1e-6);
+/// ```
+pub fn zscore<F, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    ddof: Option<usize>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let ddof = ddof.unwrap_or(0);
+    map_lanes(a, Some(axis.unwrap_or(0)), |x| {
+        let (mean, std) = (mean_of(x), var_of(x, ddof).sqrt());
+        x.iter().map(|&v| (v - mean) / std).collect()
+    })
+}
+
+/// Return mean of array after trimming a specified fraction of extreme values.
+///
+/// Removes the specified proportion of elements from each end of the sorted array, then
+/// computes the mean of the remaining elements.
+///
+/// ## Parameters
+/// * `a`: Input array.
+/// * `proportiontocut`: Fraction of the most positive and most negative elements to remove.
+///   When it does not result in an integer number of elements, the number of elements removed
+///   is rounded down.
+/// * `axis`: Axis along which the trimmed means are computed, 0 by default.
+///
+/// ## Returns
+/// The trimmed means along `axis`.
+///
+/// ## Errors
+/// If `axis` is out of range, or if `proportiontocut` is negative or cuts all the elements.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// x = np.arange(20)
+/// stats.trim_mean(x, 0.1)
+/// # 9.5
+/// x2 = x.reshape(5, 4)
+/// stats.trim_mean(x2, 0.25)
+/// # array([  8.,   9.,  10.,  11.])
+/// stats.trim_mean(x2, 0.25, axis=1)
+/// # array([  1.5,   5.5,   9.5,  13.5,  17.5])
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::Array;
+/// use sci_rs::stats::trim_mean;
+///
+/// let x = Array::range(0., 20., 1.);
+/// assert_eq!(trim_mean(&x, 0.1, None).unwrap().into_scalar(), 9.5);
+/// let x2 = x.into_shape_with_order((5, 4)).unwrap();
+/// let means = trim_mean(&x2, 0.25, None).unwrap();
+/// assert_eq!(means.as_slice().unwrap(), &[8., 9., 10., 11.]);
+/// let means = trim_mean(&x2, 0.25, Some(1)).unwrap();
+/// assert_eq!(means.as_slice().unwrap(), &[1.5, 5.5, 9.5, 13.5, 17.5]);
+/// ```
+pub fn trim_mean<F, S, D>(
+    a: &ArrayBase<S, D>,
+    proportiontocut: F,
+    axis: Option<isize>,
+) -> Result<Array<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    if proportiontocut.is_nan() || proportiontocut < F::zero() {
+        return Err(Error::InvalidArg {
+            arg: "proportiontocut".into(),
+            reason: "must be non-negative.".into(),
+        });
+    }
+    let axis_ = check_and_get_axis_dyn(Some(axis.unwrap_or(0)), a)?;
+    let nobs = a.len_of(Axis(axis_));
+    let lowercut = (proportiontocut * F::from(nobs).unwrap())
+        .to_usize()
+        .unwrap_or(usize::MAX);
+    if lowercut.saturating_mul(2) > nobs {
+        return Err(Error::InvalidArg {
+            arg: "proportiontocut".into(),
+            reason: "proportion too big.".into(),
+        });
+    }
+    reduce_axis(a, axis, |x| {
+        let x = sorted(x);
+        mean_of(ArrayView1::from(&x[lowercut..nobs - lowercut]))
+    })
+}
+
+/// Returns a Winsorized version of the input array.
+///
+/// The lowest and highest values are replaced by the nearest values that are kept, rather than
+/// removed as in [trim_mean].
+///
+/// ## Parameters
+/// * `a`: Input array.
+/// * `limits`: The proportions of the lowest and highest values to replace. The number of
+///   values replaced at either end is rounded down.
+/// * `inclusive`: Whether the number of values replaced at the low and high ends is rounded
+///   down, or to the nearest integer. `(true, true)` by default.
+/// * `axis`: Axis along which to winsorize. By default, `a` is winsorized as a whole.
+///
+/// ## Returns
+/// The winsorized array, of the shape of `a`.
+///
+/// ## Errors
+/// If `axis` is out of range, or if either limit is not within `[0, 1]`.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy.stats.mstats import winsorize
+/// a = np.array([10, 4, 9, 8, 5, 3, 7, 2, 1, 6])
+/// winsorize(a, limits=[0.1, 0.2])
+/// # masked_array(data=[8, 4, 8, 8, 5, 3, 7, 2, 2, 6],
+/// #              mask=False,
+/// #        fill_value=999999)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use ndarray::array;
+/// use sci_rs::stats::winsorize;
+///
+/// let a = array![10., 4., 9., 8., 5., 3., 7., 2., 1., 6.];
+/// let w = winsorize(&a, (0.1, 0.2), None, None).unwrap();
+/// assert_eq!(w, array![8., 4., 8., 8., 5., 3., 7., 2., 2., 6.]);
+/// ```
+pub fn winsorize<F, S, D>(
+    a: &ArrayBase<S, D>,
+    limits: (F, F),
+    inclusive: Option<(bool, bool)>,
+    axis: Option<isize>,
+) -> Result<Array<F, D>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let in_unit = |l: F| l >= F::zero() && l <= F::one();
+    if !in_unit(limits.0) || !in_unit(limits.1) {
+        return Err(Error::InvalidArg {
+            arg: "limits".into(),
+            reason: "must be within [0, 1].".into(),
+        });
+    }
+    let (low_inclusive, high_inclusive) = inclusive.unwrap_or((true, true));
+    map_lanes(a, axis, |x| {
+        let n = F::from(x.len()).unwrap();
+        let count = |limit: F, inclusive: bool| {
+            let count = limit * n;
+            match inclusive {
+                true => count.floor(),
+                false => count.round(),
+            }
+            .to_usize()
+            .unwrap()
+        };
+        let mut order: Vec<usize> = (0..x.len()).collect();
+        order.sort_by(|&i, &j| {
+            x[i].partial_cmp(&x[j])
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+        let low = count(limits.0, low_inclusive);
+        let high = x.len() - count(limits.1, high_inclusive).min(x.len());
+        let mut out = x.to_vec();
+        if low < x.len() {
+            order[..low].iter().for_each(|&i| out[i] = x[order[low]]);
+        }
+        if high > 0 {
+            order[high..]
+                .iter()
+                .for_each(|&i| out[i] = x[order[high - 1]]);
+        }
+        out
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn ranks() {
+        let a = array![0., 2., 3., 2.];
+        let rank = |method| rankdata(&a, Some(method), None, None).unwrap();
+        assert_eq!(rank(RankMethod::Min), array![1., 2., 4., 2.]);
+        assert_eq!(rank(RankMethod::Max), array![1., 3., 4., 3.]);
+        assert_eq!(rank(RankMethod::Ordinal), array![1., 2., 4., 3.]);
+
+        let a = array![[0., 2.], [3., 2.]];
+        let ranks = rankdata(&a, None, None, None).unwrap();
+        assert_eq!(ranks, array![[1., 2.5], [4., 2.5]]);
+        let ranks = rankdata(&a, Some(RankMethod::Dense), Some(0), None).unwrap();
+        assert_eq!(ranks, array![[1., 1.], [2., 1.]]);
+    }
+
+    #[test]
+    fn nan_policies() {
+        let a = array![[3., f64::NAN, 1.], [2., 2., 5.]];
+        let ranks = rankdata(&a, None, Some(1), None).unwrap();
+        assert!(ranks.row(0).iter().all(|r| r.is_nan()));
+        assert_eq!(ranks.row(1), array![1.5, 1.5, 3.]);
+        let ranks = rankdata(&a, None, Some(1), Some(NanPolicy::Omit)).unwrap();
+        assert_eq!(ranks[[0, 0]], 2.);
+        assert!(ranks[[0, 1]].is_nan());
+        assert_eq!(ranks[[0, 2]], 1.);
+        assert!(rankdata(&a, None, None, Some(NanPolicy::Raise)).is_err());
+    }
+
+    #[test]
+    fn standardize_and_trim() {
+        let a = array![[1., 4.], [3., 8.], [5., 6.]];
+        let z = zscore(&a, None, None).unwrap();
+        let s = (8f64 / 3.).sqrt();
+        assert_abs_diff_eq!(
+            &z.column(0).to_vec()[..],
+            &[-2. / s, 0., 2. / s][..],
+            epsilon = 1e-15
+        );
+        let z = zscore(&a, Some(-1), Some(1)).unwrap();
+        let s = 2f64.sqrt();
+        assert_abs_diff_eq!(
+            &z.row(2).to_vec()[..],
+            &[-1. / s, 1. / s][..],
+            epsilon = 1e-15
+        );
+
+        let x = array![1., 9., 2., 100., 3., -50.];
+        assert_eq!(trim_mean(&x, 0.2, None).unwrap().into_scalar(), 3.75);
+        assert_eq!(trim_mean(&x, 0., None).unwrap().into_scalar(), 65. / 6.);
+        assert!(trim_mean(&x, 0.5, None).unwrap().into_scalar().is_nan());
+        assert!(trim_mean(&x, 0.7, None).is_err());
+        assert!(trim_mean(&x, -0.1, None).is_err());
+
+        let w = winsorize(&x, (0.2, 0.25), Some((true, false)), None).unwrap();
+        assert_eq!(w, array![1., 3., 2., 3., 3., 1.]);
+        let w = winsorize(&a, (0., 0.4), None, Some(1)).unwrap();
+        assert_eq!(w, a);
+        let w = winsorize(&a, (0.4, 0.), None, Some(0)).unwrap();
+        assert_eq!(w, array![[3., 6.], [3., 8.], [5., 6.]]);
+        assert!(winsorize(&x, (0.1, 1.5), None, None).is_err());
+    }
+}
@@ -0,0 +1,363 @@
+use super::descriptive::{mean_of, reduce_axis, var_of};
+use super::hypothesis::{check_confidence_level, pvalue, reduce_axis2};
+use super::{Alternative, ConfidenceInterval, ContinuousDistribution, StudentT};
+use ndarray::{Array, ArrayBase, ArrayView1, Data, Dimension, RemoveAxis, Zip};
+use num_traits::Float;
+use sci_rs_core::{Error, Result};
+
+/// Result of the t-tests [ttest_1samp], [ttest_ind] and [ttest_rel], of the shape of the input
+/// without the axis of the samples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TtestResult<F, D: Dimension> {
+    /// The t-statistic.
+    pub statistic: Array<F, D>,
+    /// The p-value associated with the given alternative.
+    pub pvalue: Array<F, D>,
+    /// The number of degrees of freedom used in the calculation of the t-statistic.
+    pub df: Array<F, D>,
+    /// Estimate of the quantity the confidence interval is about.
+    estimate: Array<F, D>,
+    /// Standard error of the estimate.
+    standard_error: Array<F, D>,
+    alternative: Alternative,
+}
+
+impl<F: Float, D: Dimension> TtestResult<F, D> {
+    /// Confidence interval of the population mean for [ttest_1samp], of the difference of the
+    /// population means for [ttest_ind], and of the mean of the differences for [ttest_rel].
+    ///
+    /// The interval is one-sided for the one-sided alternatives, with an infinite bound.
+    ///
+    /// ## Parameters
+    /// * `confidence_level`: Confidence level of the interval, 0.95 by default.
+    ///
+    /// ## Errors
+    /// If `confidence_level` is not within `(0, 1)`.
+    pub fn confidence_interval(
+        &self,
+        confidence_level: Option<F>,
+    ) -> Result<ConfidenceInterval<Array<F, D>>> {
+        let confidence_level = check_confidence_level(confidence_level)?;
+        let two = F::from(2).unwrap();
+        let bounds = |estimate: F, se: F, df: F| {
+            let ppf = |q| StudentT::new(df, None, None).map_or(F::nan(), |t| t.ppf(q));
+            match self.alternative {
+                Alternative::TwoSided => {
+                    let t = ppf((F::one() + confidence_level) / two);
+                    (estimate - t * se, estimate + t * se)
+                }
+                Alternative::Less => (F::neg_infinity(), estimate + ppf(confidence_level) * se),
+                Alternative::Greater => (estimate - ppf(confidence_level) * se, F::infinity()),
+            }
+        };
+        let bounds = Zip::from(&self.estimate)
+            .and(&self.standard_error)
+            .and(&self.df)
+            .map_collect(|&estimate, &se, &df| bounds(estimate, se, df));
+        Ok(ConfidenceInterval {
+            low: bounds.mapv(|b| b.0),
+            high: bounds.mapv(|b| b.1),
+        })
+    }
+}
+
+/// Statistics of a t-test on a lane: the t-statistic, the degrees of freedom, the estimate and
+/// its standard error.
+type Lane<F> = (F, F, F, F);
+
+/// One-sample t-test of `x` against `popmean`.
+fn one_sample<F: Float>(x: ArrayView1<F>, popmean: F) -> Lane<F> {
+    let n = F::from(x.len()).unwrap();
+    let mean = mean_of(x);
+    let se = (var_of(x, 1) / n).sqrt();
+    ((mean - popmean) / se, n - F::one(), mean, se)
+}
+
+fn result<F: Float, D: Dimension>(
+    lanes: Array<Lane<F>, D>,
+    alternative: Alternative,
+) -> TtestResult<F, D> {
+    let pvalues = lanes.mapv(|(t, df, _, _)| {
+        StudentT::new(df, None, None).map_or(F::nan(), |dist| pvalue(&dist, t, alternative))
+    });
+    TtestResult {
+        statistic: lanes.mapv(|l| l.0),
+        pvalue: pvalues,
+        df: lanes.mapv(|l| l.1),
+        estimate: lanes.mapv(|l| l.2),
+        standard_error: lanes.mapv(|l| l.3),
+        alternative,
+    }
+}
+
+/// Calculate the T-test for the mean of ONE group of scores.
+///
+/// This is a test for the null hypothesis that the expected value (mean) of a sample of
+/// independent observations `a` is equal to the given population mean, `popmean`.
+///
+/// ## Parameters
+/// * `a`: Sample observation.
+/// * `popmean`: Expected value in null hypothesis.
+/// * `axis`: Axis along which to compute the test, 0 by default.
+/// * `alternative`: Defines the [Alternative] hypothesis, two-sided by default. For
+///   [Alternative::Less], the mean of the underlying distribution of the sample is less than
+///   `popmean`, and for [Alternative::Greater], it is greater.
+///
+/// ## Returns
+/// The [TtestResult] along `axis`. The statistic and p-value are NaN for less than two
+/// observations.
+///
+/// ## Errors
+/// If `axis` is out of range.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// res = stats.ttest_1samp([5.1, 4.9, 5.6, 5.8, 6.0, 5.3], popmean=5.0)
+/// res.statistic, res.pvalue
+/// # (2.6053232999393194, 0.047939924666334295)
+/// res.confidence_interval()
+/// # ConfidenceInterval(low=5.006000659855426, high=5.893999340144574)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::ttest_1samp;
+///
+/// let res = ttest_1samp(&array![5.1, 4.9, 5.6, 5.8, 6.0, 5.3], 5.0, None, None).unwrap();
+/// let ci = res.confidence_interval(None).unwrap();
+/// assert_abs_diff_eq!(res.statistic.into_scalar(), 2.6053232999393194, epsilon = 1e-13);
+/// assert_abs_diff_eq!(res.pvalue.into_scalar(), 0.047939924666334295, epsilon = 1e-13);
+/// assert_abs_diff_eq!(ci.low.into_scalar(), 5.006000659855426, epsilon = 1e-12);
+/// assert_abs_diff_eq!(ci.high.into_scalar(), 5.893999340144574, epsilon = 1e-12);
+/// ```
+pub fn ttest_1samp<F, S, D>(
+    a: &ArrayBase<S, D>,
+    popmean: F,
+    axis: Option<isize>,
+    alternative: Option<Alternative>,
+) -> Result<TtestResult<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let lanes = reduce_axis(a, axis, |x| one_sample(x, popmean))?;
+    Ok(result(lanes, alternative.unwrap_or_default()))
+}
+
+/// Calculate the T-test for the means of two independent samples of scores.
+///
+/// This is a test for the null hypothesis that 2 independent samples have identical average
+/// (expected) values. This test assumes that the populations have identical variances by
+/// default.
+///
+/// ## Parameters
+/// * `a`, `b`: The samples, which must have the same shape except along `axis`.
+/// * `axis`: Axis along which to compute the test, 0 by default.
+/// * `equal_var`: If `true`, perform a standard independent 2 sample test that assumes equal
+///   population variances. If `false`, perform Welch's t-test, which does not assume equal
+///   population variance. `true` by default.
+/// * `alternative`: Defines the [Alternative] hypothesis, two-sided by default. For
+///   [Alternative::Less], the mean of the distribution underlying `a` is less than the mean of
+///   the distribution underlying `b`, and for [Alternative::Greater], it is greater.
+///
+/// ## Returns
+/// The [TtestResult] along `axis`.
+///
+/// ## Errors
+/// If `axis` is out of range, or the shapes of `a` and `b` differ other than along `axis`.
+///
+/// ## Notes
+/// For Welch's t-test, the degrees of freedom are given by the Welch-Satterthwaite equation,
+/// and they are not an integer in general.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// a = [19.8, 20.4, 19.6, 17.8, 18.5, 18.9, 18.3, 18.9, 19.5, 22.0]
+/// b = [28.2, 26.6, 20.1, 23.3, 25.2, 22.1, 17.7, 27.6, 20.6, 13.7, 23.2, 17.5, 20.6, 18.0]
+/// res = stats.ttest_ind(a, b, equal_var=False)
+/// res.statistic, res.pvalue, res.df
+/// # (-1.9816725476945585, 0.06519195742925442, 15.798901536937402)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::ttest_ind;
+///
+/// let a = array![19.8, 20.4, 19.6, 17.8, 18.5, 18.9, 18.3, 18.9, 19.5, 22.0];
+/// let b = array![
+///     28.2, 26.6, 20.1, 23.3, 25.2, 22.1, 17.7, 27.6, 20.6, 13.7, 23.2, 17.5, 20.6, 18.0
+/// ];
+/// let res = ttest_ind(&a, &b, None, Some(false), None).unwrap();
+/// assert_abs_diff_eq!(res.statistic.into_scalar(), -1.9816725476945585, epsilon = 1e-13);
+/// assert_abs_diff_eq!(res.pvalue.into_scalar(), 0.06519195742925442, epsilon = 1e-13);
+/// assert_abs_diff_eq!(res.df.into_scalar(), 15.798901536937402, epsilon = 1e-12);
+/// ```
+pub fn ttest_ind<F, S, T, D>(
+    a: &ArrayBase<S, D>,
+    b: &ArrayBase<T, D>,
+    axis: Option<isize>,
+    equal_var: Option<bool>,
+    alternative: Option<Alternative>,
+) -> Result<TtestResult<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let equal_var = equal_var.unwrap_or(true);
+    let lanes = reduce_axis2(a, b, axis, |x, y| {
+        let (n1, n2) = (F::from(x.len()).unwrap(), F::from(y.len()).unwrap());
+        let (v1, v2) = (var_of(x, 1), var_of(y, 1));
+        let (df, se) = if equal_var {
+            let df = n1 + n2 - F::from(2).unwrap();
+            let pooled = ((n1 - F::one()) * v1 + (n2 - F::one()) * v2) / df;
+            (df, (pooled * (n1.recip() + n2.recip())).sqrt())
+        } else {
+            let (vn1, vn2) = (v1 / n1, v2 / n2);
+            let df = (vn1 + vn2).powi(2)
+                / (vn1.powi(2) / (n1 - F::one()) + vn2.powi(2) / (n2 - F::one()));
+            // The degrees of freedom are undefined when both variances are zero, and the
+            // statistic is then NaN anyway.
+            let df = if df.is_nan() { F::one() } else { df };
+            (df, (vn1 + vn2).sqrt())
+        };
+        let estimate = mean_of(x) - mean_of(y);
+        (estimate / se, df, estimate, se)
+    })?;
+    Ok(result(lanes, alternative.unwrap_or_default()))
+}
+
+/// Calculate the t-test on TWO RELATED samples of scores, a and b.
+///
+/// This is a test for the null hypothesis that two related or repeated samples have identical
+/// average (expected) values. It is the one-sample t-test of the differences `a - b` against
+/// zero.
+///
+/// ## Parameters
+/// * `a`, `b`: The paired samples, which must have the same shape.
+/// * `axis`: Axis along which to compute the test, 0 by default.
+/// * `alternative`: Defines the [Alternative] hypothesis, two-sided by default. For
+///   [Alternative::Less], the mean of the distribution underlying the differences is less than
+///   zero, and for [Alternative::Greater], it is greater.
+///
+/// ## Returns
+/// The [TtestResult] along `axis`.
+///
+/// ## Errors
+/// If `axis` is out of range, or the shapes of `a` and `b` differ.
+///
+/// ## Examples
+/// ```custom,{class=language-python}
+/// from scipy import stats
+/// before = [72.0, 80.5, 65.2, 90.1, 77.3, 68.8]
+/// after = [70.1, 78.0, 66.0, 86.4, 75.0, 67.9]
+/// res = stats.ttest_rel(before, after, alternative='greater')
+/// res.statistic, res.pvalue
+/// # (2.7768450898381882, 0.019523788227169582)
+/// ```
+///
+/// Sci-rs:
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use sci_rs::stats::{ttest_rel, Alternative};
+///
+/// let before = array![72.0, 80.5, 65.2, 90.1, 77.3, 68.8];
+/// let after = array![70.1, 78.0, 66.0, 86.4, 75.0, 67.9];
+/// let res = ttest_rel(&before, &after, None, Some(Alternative::Greater)).unwrap();
+/// assert_abs_diff_eq!(res.statistic.into_scalar(), 2.7768450898381882, epsilon = 1e-13);
+/// assert_abs_diff_eq!(res.pvalue.into_scalar(), 0.019523788227169582, epsilon = 1e-13);
+/// ```
+pub fn ttest_rel<F, S, T, D>(
+    a: &ArrayBase<S, D>,
+    b: &ArrayBase<T, D>,
+    axis: Option<isize>,
+    alternative: Option<Alternative>,
+) -> Result<TtestResult<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    T: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    if a.shape() != b.shape() {
+        return Err(Error::InvalidArg {
+            arg: "b".into(),
+            reason: "must have the shape of a.".into(),
+        });
+    }
+    let lanes = reduce_axis2(a, b, axis, |x, y| one_sample((&x - &y).view(), F::zero()))?;
+    Ok(result(lanes, alternative.unwrap_or_default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn ttests() {
+        let a = array![19.8, 20.4, 19.6, 17.8, 18.5, 18.9, 18.3, 18.9, 19.5, 22.0];
+        let b = array![28.2, 26.6, 20.1, 23.3, 25.2, 22.1, 17.7, 27.6, 20.6, 13.7, 23.2];
+        let res = ttest_ind(&a, &b, None, None, Some(Alternative::Less)).unwrap();
+        assert_eq!(res.df[()], 19.);
+        assert_abs_diff_eq!(res.statistic[()], -2.2222312228656147, epsilon = 1e-13);
+        assert_abs_diff_eq!(res.pvalue[()], 0.019303929212387883, epsilon = 1e-13);
+        let ci = res.confidence_interval(Some(0.9)).unwrap();
+        assert_eq!(ci.low.into_scalar(), f64::NEG_INFINITY);
+        assert_abs_diff_eq!(ci.high.into_scalar(), -1.289176917541476, epsilon = 1e-12);
+
+        // A single observation has no variance.
+        let res = ttest_1samp(&array![1.], 0., None, None).unwrap();
+        assert!(res.statistic.into_scalar().is_nan() && res.pvalue.into_scalar().is_nan());
+        // Nor does a constant sample, whose p-value is NaN for every alternative.
+        for alternative in [
+            Alternative::TwoSided,
+            Alternative::Less,
+            Alternative::Greater,
+        ] {
+            let res = ttest_1samp(&array![2., 2., 2.], 2., None, Some(alternative)).unwrap();
+            assert!(res.statistic.into_scalar().is_nan() && res.pvalue.into_scalar().is_nan());
+        }
+    }
+
+    #[test]
+    fn axes() {
+        let a = array![[1., 2., 4.], [3., 8., 5.], [2., 0., 9.], [4., 6., 7.]];
+        let b = array![[2., 1.], [5., 4.], [1., 3.], [7., 6.]];
+        let res = ttest_ind(&a, &b, Some(1), Some(false), None).unwrap();
+        for i in 0..4 {
+            let row = ttest_ind(&a.row(i), &b.row(i), None, Some(false), None).unwrap();
+            assert_eq!(res.statistic[i], row.statistic.into_scalar());
+            assert_eq!(res.pvalue[i], row.pvalue.into_scalar());
+        }
+        let res = ttest_1samp(&a, 2., None, Some(Alternative::Greater)).unwrap();
+        let ci = res.confidence_interval(None).unwrap();
+        assert_eq!(ci.high.as_slice().unwrap(), &[f64::INFINITY; 3]);
+        for j in 0..3 {
+            let col = ttest_1samp(&a.column(j), 2., None, Some(Alternative::Greater)).unwrap();
+            assert_eq!(res.pvalue[j], col.pvalue.into_scalar());
+        }
+    }
+
+    #[test]
+    fn invalid_args() {
+        let a = array![[1., 2.], [3., 4.]];
+        assert!(ttest_1samp(&a, 0., Some(2), None).is_err());
+        assert!(ttest_ind(&a, &array![[1., 2., 3.]], Some(0), None, None).is_err());
+        assert!(ttest_ind(&a, &array![[1., 2.]], Some(0), None, None).is_ok());
+        assert!(ttest_rel(&a, &array![[1., 2.]], None, None).is_err());
+        let res = ttest_1samp(&array![1., 2., 4.], 0., None, None).unwrap();
+        assert!(res.confidence_interval(Some(1.)).is_err());
+    }
+}